@@ -10,6 +10,8 @@ import subprocess
 from glob import glob
 
 import daft
+from daft.daft import generate_tpch_table
+from daft.recordbatch import RecordBatch
 
 logger = logging.getLogger(__name__)
 
@@ -342,6 +344,42 @@ def gen_parquet(csv_files_location: str) -> str:
     return PARQUET_FILE_PATH
 
 
+def gen_parquet_native(basedir: str, num_parts: int, scale_factor: float) -> str:
+    """Generates Parquet files with Daft's native TPC-H generator, without building dbgen.
+
+    Args:
+        basedir (str): path to generate files into
+        num_parts (int): number of parts to generate per table
+        scale_factor (float): scale factor for generation
+
+    Returns:
+        str: path to generated Parquet files
+    """
+    parquet_file_path = os.path.join(
+        basedir, (f"{scale_factor:.1f}").replace(".", "_"), str(num_parts), "native", "parquet"
+    )
+    for tab_name in SCHEMA.keys():
+        table_parquet_path = os.path.join(parquet_file_path, tab_name)
+        if os.path.exists(table_parquet_path):
+            logger.info(
+                "Cached Parquet files for table %s already exists at %s, skipping Parquet file generation",
+                tab_name,
+                table_parquet_path,
+            )
+            continue
+
+        logger.info("Natively generating Parquet Files for %s", tab_name)
+        # Region and nation are fixed-size tables, so they are always generated as a single part.
+        table_num_parts = 1 if tab_name in ("region", "nation") else num_parts
+        parts = [
+            RecordBatch._from_pyrecordbatch(generate_tpch_table(tab_name, scale_factor, i, table_num_parts))
+            for i in range(table_num_parts)
+        ]
+        df = daft.from_arrow([part.to_arrow_table() for part in parts])
+        df.write_parquet(table_parquet_path)
+    return parquet_file_path
+
+
 if __name__ == "__main__":
     parser = argparse.ArgumentParser()
     parser.add_argument(
@@ -359,6 +397,11 @@ if __name__ == "__main__":
     parser.add_argument(
         "--generate_parquet", action="store_true", help="Whether to generate Parquet files or not, defaults to False"
     )
+    parser.add_argument(
+        "--native",
+        action="store_true",
+        help="Generate Parquet files with Daft's native generator instead of dbgen, defaults to False",
+    )
     args = parser.parse_args()
     num_parts = math.ceil(args.scale_factor) if args.num_parts is None else args.num_parts
 
@@ -371,6 +414,10 @@ if __name__ == "__main__":
         args.generate_parquet,
     )
 
+    if args.native:
+        gen_parquet_native(basedir=args.tpch_gen_folder, num_parts=num_parts, scale_factor=args.scale_factor)
+        raise SystemExit(0)
+
     csv_folder = gen_csv_files(basedir=args.tpch_gen_folder, scale_factor=args.scale_factor, num_parts=num_parts)
     if args.generate_parquet:
         gen_parquet(csv_folder)
@@ -65,6 +65,9 @@ from daft.context import (
     set_planning_config,
     execution_config_ctx,
     planning_config_ctx,
+    get_config,
+    set_config,
+    config_ctx,
 )
 from daft.convert import (
     from_arrow,
@@ -82,6 +85,7 @@ from daft.expressions import Expression, col, element, lit, interval
 from daft.series import Series
 from daft.session import (
     Session,
+    analyze_table,
     attach,
     attach_catalog,
     attach_provider,
@@ -129,15 +133,24 @@ from daft.io import (
     from_glob_path,
     _range as range,
     read_lance,
+    read_archives,
     read_csv,
     read_deltalake,
+    read_dicom,
     read_hudi,
     read_iceberg,
     read_json,
+    read_las,
+    read_npy,
+    read_npz,
     read_parquet,
+    read_pcd,
+    read_safetensors,
     read_sql,
+    read_tfrecord,
     read_video_frames,
     read_warc,
+    read_webdataset,
     read_huggingface,
     read_mcap,
 )
@@ -145,6 +158,7 @@ from daft.runners import get_or_create_runner, get_or_infer_runner_type, set_run
 from daft.sql import sql, sql_expr
 from daft.viz import register_viz_hook
 from daft.window import Window
+from daft.broadcast import Broadcast, broadcast
 from daft.file import File, VideoFile, AudioFile
 
 import daft.context as context
@@ -156,6 +170,7 @@ import daft.gravitino as gravitino
 
 __all__ = [
     "AudioFile",
+    "Broadcast",
     "Catalog",
     "DataCatalogTable",
     "DataCatalogType",
@@ -177,13 +192,16 @@ __all__ = [
     "TimeUnit",
     "VideoFile",
     "Window",
+    "analyze_table",
     "attach",
     "attach_catalog",
     "attach_function",
     "attach_provider",
     "attach_table",
+    "broadcast",
     "cls",
     "col",
+    "config_ctx",
     "context",
     "create_namespace",
     "create_namespace_if_not_exists",
@@ -214,6 +232,7 @@ __all__ = [
     "func",
     "functions",
     "get_catalog",
+    "get_config",
     "get_or_create_runner",
     "get_or_infer_runner_type",
     "get_provider",
@@ -232,24 +251,34 @@ __all__ = [
     "metrics",
     "planning_config_ctx",
     "range",
+    "read_archives",
     "read_csv",
     "read_deltalake",
+    "read_dicom",
     "read_hudi",
     "read_huggingface",
     "read_iceberg",
     "read_json",
     "read_lance",
+    "read_las",
     "read_mcap",
+    "read_npy",
+    "read_npz",
     "read_parquet",
+    "read_pcd",
+    "read_safetensors",
     "read_sql",
     "read_table",
+    "read_tfrecord",
     "read_video_frames",
     "read_warc",
+    "read_webdataset",
     "refresh_logger",
     "register_viz_hook",
     "runners",
     "session",
     "set_catalog",
+    "set_config",
     "set_execution_config",
     "set_model",
     "set_namespace",
@@ -0,0 +1,145 @@
+"""Broadcast side inputs: small read-only values shipped to each worker once, rather than with every task."""
+
+from __future__ import annotations
+
+import threading
+import uuid
+from collections import OrderedDict
+from typing import TYPE_CHECKING, Any, Callable, Generic, TypeVar
+
+from daft.utils import detect_ray_state
+
+if TYPE_CHECKING:
+    from collections.abc import Mapping
+
+    from daft.daft import PySeries
+
+T = TypeVar("T")
+
+# The number of broadcasts whose values, and lookup tables, each process keeps cached. The least recently used
+# broadcasts are evicted beyond it, so that a long-lived worker doesn't keep the values of every query it ran.
+_MAX_CACHED = 16
+
+# Values of broadcasts fetched from the Ray object store by this process, keyed by broadcast id.
+_RESOLVED: OrderedDict[str, Any] = OrderedDict()
+# Keys and values of broadcast mappings which were converted for native lookups, keyed by broadcast id.
+_LOOKUP_TABLES: OrderedDict[str, tuple[PySeries, PySeries]] = OrderedDict()
+_CACHE_LOCK = threading.Lock()
+
+
+def _cached(cache: OrderedDict[str, Any], key: str, compute: Callable[[], Any]) -> Any:
+    with _CACHE_LOCK:
+        if key in cache:
+            cache.move_to_end(key)
+            return cache[key]
+        value = compute()
+        cache[key] = value
+        if len(cache) > _MAX_CACHED:
+            cache.popitem(last=False)
+        return value
+
+
+class Broadcast(Generic[T]):
+    """A handle to a small read-only value, such as a lookup table or a model config, that UDFs can use.
+
+    Closing over a large Python object in a UDF serializes the object with every task that runs the UDF. Closing
+    over a ``Broadcast`` of that object instead serializes only a reference to it: on Ray, the value is put into the
+    object store once, and each worker process fetches and caches it the first time a task accesses ``.value``. Each
+    process caches the values of the most recently used broadcasts only.
+
+    Create broadcasts with [`daft.broadcast`][daft.broadcast].
+    """
+
+    def __init__(self, value: T, *, _id: str | None = None) -> None:
+        self._id = _id or uuid.uuid4().hex
+        self._value = value
+        self._ref: Any = None
+
+    @property
+    def value(self) -> T:
+        """The broadcast value."""
+        return self._value
+
+    def unpersist(self) -> None:
+        """Drops this process's cached copies of the value, and its reference to the value in the Ray object store.
+
+        The broadcast can still be used afterwards, in which case the value is cached and put into the object store
+        again.
+        """
+        with _CACHE_LOCK:
+            _RESOLVED.pop(self._id, None)
+            _LOOKUP_TABLES.pop(self._id, None)
+        self._ref = None
+
+    def __repr__(self) -> str:
+        return f"Broadcast({type(self._value).__name__}, id={self._id})"
+
+    def __reduce__(self) -> tuple[Any, ...]:
+        ray_enabled, in_ray_worker = detect_ray_state()
+        if not ray_enabled or in_ray_worker:
+            return (_resolve_local, (self._id, self._value))
+
+        import ray
+
+        if self._ref is None:
+            self._ref = ray.put(self._value)
+        return (_resolve_ray, (self._id, self._ref))
+
+
+def _resolve_local(broadcast_id: str, value: Any) -> Broadcast[Any]:
+    return Broadcast(value, _id=broadcast_id)
+
+
+def _resolve_ray(broadcast_id: str, ref: Any) -> Broadcast[Any]:
+    import ray
+
+    value = _cached(_RESOLVED, broadcast_id, lambda: ray.get(ref))
+    broadcast = Broadcast(value, _id=broadcast_id)
+    broadcast._ref = ref
+    return broadcast
+
+
+def broadcast(value: T) -> Broadcast[T]:
+    """Register a value as a broadcast side input, which is shipped once per worker instead of once per task.
+
+    Use broadcasts for small lookup tables, model configs, or other read-only objects that UDFs need. Access the
+    value in a UDF through ``.value``. A broadcast of a mapping can also be used directly in the native
+    [`daft.functions.lookup`][daft.functions.lookup] expression.
+
+    Args:
+        value: The value to broadcast. It must be picklable and must not be mutated after it is broadcast.
+
+    Returns:
+        Broadcast: A handle to the value.
+
+    Examples:
+        >>> import daft
+        >>> labels = daft.broadcast({1: "low", 2: "high"})
+        >>>
+        >>> @daft.func
+        ... def label(code: int) -> str:
+        ...     return labels.value.get(code, "unknown")
+        >>>
+        >>> df = daft.from_pydict({"code": [1, 2, 3]})
+        >>> df.select(label(df["code"])).to_pydict()
+        {'code': ['low', 'high', 'unknown']}
+
+    """
+    return Broadcast(value)
+
+
+def _as_mapping(table: Mapping[Any, Any] | Broadcast[Mapping[Any, Any]]) -> Mapping[Any, Any]:
+    return table.value if isinstance(table, Broadcast) else table
+
+
+def _lookup_table(broadcast: Broadcast[Mapping[Any, Any]]) -> tuple[PySeries, PySeries]:
+    """Returns the keys and values of a broadcast mapping as series, converting them once per process."""
+    from daft.series import item_to_series
+
+    def convert() -> tuple[PySeries, PySeries]:
+        mapping = broadcast.value
+        keys = item_to_series("keys", list(mapping.keys()))
+        values = item_to_series("values", list(mapping.values()))
+        return keys._series, values._series
+
+    return _cached(_LOOKUP_TABLES, broadcast._id, convert)
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import contextlib
+import json
 import logging
 import threading
 from dataclasses import dataclass
@@ -90,6 +91,86 @@ def get_context() -> DaftContext:
     return DaftContext(_get_context())
 
 
+def _with_config_values(
+    planning_config: PyDaftPlanningConfig, execution_config: PyDaftExecutionConfig, values: dict[str, Any]
+) -> tuple[PyDaftPlanningConfig, PyDaftExecutionConfig]:
+    values = dict(values)
+    if "default_io_config" in values:
+        planning_config = planning_config.with_config_values(default_io_config=values.pop("default_io_config"))
+    planning_names = json.loads(planning_config.values_json()).keys()
+    planning_values = {name: value for name, value in values.items() if name in planning_names}
+    execution_values = {name: value for name, value in values.items() if name not in planning_names}
+    return (
+        planning_config.with_values_json(json.dumps(planning_values)),
+        execution_config.with_values_json(json.dumps(execution_values)),
+    )
+
+
+def get_config() -> dict[str, Any]:
+    """Returns the effective values of the planning and execution configs by name.
+
+    Each value comes from the latest of these layers to set it: the defaults, the environment (variables named
+    `DAFT_<NAME>`, e.g. `DAFT_NUM_PREVIEW_ROWS`), the session (`set_config`, `set_planning_config` and
+    `set_execution_config`) and the overrides of the current query (`config_ctx`).
+
+    Returns:
+        dict[str, Any]: The value of every config by name, e.g. `{"num_preview_rows": 8, ...}`.
+    """
+    ctx = get_context()
+    planning_config = ctx.daft_planning_config
+    execution_config = ctx.daft_execution_config
+    return {
+        "default_io_config": planning_config.default_io_config,
+        **json.loads(planning_config.values_json()),
+        **json.loads(execution_config.values_json()),
+    }
+
+
+def set_config(**values: Any) -> DaftContext:
+    """Globally sets planning and execution configs by name, e.g. `set_config(num_preview_rows=20)`.
+
+    Any config returned by `get_config` can be set, including those without a parameter of their own in
+    `set_planning_config` or `set_execution_config`.
+
+    Args:
+        **values: The values of the configs to set by name.
+
+    Raises:
+        ValueError: If a config doesn't exist, or a value is invalid for it.
+    """
+    ctx = get_context()
+    with ctx._lock:
+        planning_config, execution_config = _with_config_values(
+            ctx._ctx._daft_planning_config, ctx._ctx._daft_execution_config, values
+        )
+        ctx._ctx._daft_planning_config = planning_config
+        ctx._ctx._daft_execution_config = execution_config
+        return ctx
+
+
+@contextlib.contextmanager
+def config_ctx(**values: Any) -> Generator[None, None, None]:
+    """Context manager that overrides planning and execution configs by name for the queries run within it.
+
+    The configs are reset to their original values afterwards. Queries capture the execution config when they start
+    running and send it along with their tasks, so the workers of a distributed query run with the same overrides as
+    the driver.
+
+    Args:
+        **values: The values of the configs to override by name, as for `set_config`.
+    """
+    ctx = get_context()
+    original_planning_config = ctx._ctx._daft_planning_config
+    original_execution_config = ctx._ctx._daft_execution_config
+    try:
+        set_config(**values)
+        yield
+    finally:
+        with ctx._lock:
+            ctx._ctx._daft_planning_config = original_planning_config
+            ctx._ctx._daft_execution_config = original_execution_config
+
+
 @contextlib.contextmanager
 def planning_config_ctx(**kwargs: Any) -> Generator[None, None, None]:
     """Context manager that wraps set_planning_config to reset the config to its original setting afternwards."""
@@ -148,6 +229,8 @@ def set_execution_config(
     broadcast_join_size_bytes_threshold: int | None = None,
     parquet_split_row_groups_max_files: int | None = None,
     hash_join_partition_size_leniency: float | None = None,
+    enable_bloom_filter_join_pruning: bool | None = None,
+    bloom_filter_join_false_positive_rate: float | None = None,
     sample_size_for_sort: int | None = None,
     num_preview_rows: int | None = None,
     parquet_target_filesize: int | None = None,
@@ -162,6 +245,8 @@ def set_execution_config(
     read_sql_partition_size_bytes: int | None = None,
     default_morsel_size: int | None = None,
     shuffle_algorithm: str | None = None,
+    shuffle_compression: str | None = None,
+    shuffle_compression_level: int | None = None,
     pre_shuffle_merge_threshold: int | None = None,
     scantask_max_parallel: int | None = None,
     native_parquet_writer: bool | None = None,
@@ -170,6 +255,10 @@ def set_execution_config(
     maintain_order: bool | None = None,
     enable_dynamic_batching: bool | None = None,
     dynamic_batching_strategy: str | None = None,
+    collect_spill_threshold_bytes: int | None = None,
+    scan_read_ahead: int | None = None,
+    scan_io_concurrency: int | None = None,
+    staged_writes: bool | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control various aspects of Daft execution.
 
@@ -189,6 +278,10 @@ def set_execution_config(
         hash_join_partition_size_leniency: If the left side of a hash join is already correctly partitioned and the right side isn't,
             and the ratio between the left and right size is at least this value, then the right side is repartitioned to have an equal
             number of partitions as the left. Defaults to 0.5.
+        enable_bloom_filter_join_pruning: Whether to prune the larger side of distributed inner and semi hash joins with a Bloom filter
+            built from the keys of the smaller side, before the larger side is shuffled. Defaults to False.
+        bloom_filter_join_false_positive_rate: False positive rate of the Bloom filters used for join pruning. Lower rates prune more
+            rows but make the filters larger. Defaults to 0.01.
         sample_size_for_sort: number of elements to sample from each partition when running sort,
             Default is 20.
         num_preview_rows: number of rows to when showing a dataframe preview,
@@ -205,6 +298,8 @@ def set_execution_config(
         read_sql_partition_size_bytes: Target size of partition when reading from SQL databases. Defaults to 512MB
         default_morsel_size: Default size of morsels used for the new local executor. Defaults to 131072 rows.
         shuffle_algorithm: The shuffle algorithm to use. Defaults to "auto", which will let Daft determine the algorithm. Options are "map_reduce" and "pre_shuffle_merge".
+        shuffle_compression: The codec used to compress partitions shuffled between Ray workers and partitions of collected results spilled to disk. Options are "none", "lz4" and "zstd". Partitions whose bytes are mostly already-compressed binary data, such as encoded images or video, are not compressed again. Defaults to "none".
+        shuffle_compression_level: The level of the `shuffle_compression` codec, from 0 to 16 for "lz4" and from 0 to 22 for "zstd". Defaults to 0, the codec's default level.
         pre_shuffle_merge_threshold: Memory threshold in bytes for pre-shuffle merge. Defaults to 1GB
         scantask_max_parallel: Set the max parallelism for running scan tasks simultaneously. Currently, this only works for Native Runner. If set to 0, all available CPUs will be used. Defaults to 8.
         native_parquet_writer: Whether to use the native parquet writer vs the pyarrow parquet writer. Defaults to `True`.
@@ -212,7 +307,11 @@ def set_execution_config(
         actor_udf_ready_timeout: Timeout for UDF actors to be ready. Defaults to 120 seconds.
         maintain_order: Whether to maintain order during execution. Defaults to True. Some blocking sink operators (e.g. write_parquet) won't respect this flag and will always keep maintain_order as false, and propagate to child operators. It's useful to set this to False for running df.collect() when no ordering is required.
         enable_dynamic_batching: Whether to enable dynamic batching. Defaults to False.
-        dynamic_batching_strategy: The strategy to use for dynamic batching. Defaults to 'auto'.
+        dynamic_batching_strategy: The strategy to use for dynamic batching, either 'auto' or 'latency_constrained'. With 'auto', the batches of UDFs grow and shrink to stay within both a target latency and a target memory footprint per batch, up to the batch size of the UDF. Defaults to 'auto'.
+        collect_spill_threshold_bytes: Size in bytes of the results of a `collect()` kept in memory, above which further partitions are spilled to a local directory in Arrow IPC format and only read back into memory when they're accessed, so that results larger than memory can still be collected. Each query spills to its own directory, which is removed once its results are no longer referenced. Defaults to 0, which disables spilling. Only applies to the native runner.
+        scan_read_ahead: Number of scan tasks whose files are fetched into memory ahead of being decoded, so that fetching the next files overlaps with decoding the current ones. Only files on remote storage no larger than 256MiB, which are read whole, are fetched ahead. The number of scan tasks decoded at once is still set by `scantask_max_parallel`. Defaults to 0, which disables fetching ahead. Only applies to the native runner.
+        scan_io_concurrency: Maximum number of files fetched ahead at once when `scan_read_ahead` is set. Defaults to 8.
+        staged_writes: Whether the tasks of a file write stage their files in a `_daft_staging` directory under the root directory, and the files are only moved to the root directory once every task has succeeded, so that a failed write doesn't leave partial output behind. Each attempt of a retried task stages its files in its own directory, and only the files of the latest attempt of each task are moved, so retries don't duplicate rows. Files are moved by renaming them on local filesystems and by copying them server-side and deleting the staged copies on object stores. The commit is not atomic: a reader can see some of the moved files before the commit finishes, and globs skip the `_daft_staging` directory so that staged files are never read as part of the output. Defaults to False. Only applies to the Ray runner.
     """
     # Replace values in the DaftExecutionConfig with user-specified overrides
     ctx = get_context()
@@ -227,6 +326,8 @@ def set_execution_config(
             parquet_split_row_groups_max_files=parquet_split_row_groups_max_files,
             broadcast_join_size_bytes_threshold=broadcast_join_size_bytes_threshold,
             hash_join_partition_size_leniency=hash_join_partition_size_leniency,
+            enable_bloom_filter_join_pruning=enable_bloom_filter_join_pruning,
+            bloom_filter_join_false_positive_rate=bloom_filter_join_false_positive_rate,
             sample_size_for_sort=sample_size_for_sort,
             num_preview_rows=num_preview_rows,
             parquet_target_filesize=parquet_target_filesize,
@@ -241,6 +342,8 @@ def set_execution_config(
             read_sql_partition_size_bytes=read_sql_partition_size_bytes,
             default_morsel_size=default_morsel_size,
             shuffle_algorithm=shuffle_algorithm,
+            shuffle_compression=shuffle_compression,
+            shuffle_compression_level=shuffle_compression_level,
             pre_shuffle_merge_threshold=pre_shuffle_merge_threshold,
             scantask_max_parallel=scantask_max_parallel,
             native_parquet_writer=native_parquet_writer,
@@ -249,6 +352,10 @@ def set_execution_config(
             maintain_order=maintain_order,
             enable_dynamic_batching=enable_dynamic_batching,
             dynamic_batching_strategy=dynamic_batching_strategy,
+            collect_spill_threshold_bytes=collect_spill_threshold_bytes,
+            scan_read_ahead=scan_read_ahead,
+            scan_io_concurrency=scan_io_concurrency,
+            staged_writes=staged_writes,
         )
 
         ctx._ctx._daft_execution_config = new_daft_execution_config
@@ -167,6 +167,7 @@ class JoinStrategy(Enum):
     Hash = 1
     SortMerge = 2
     Broadcast = 3
+    SortedLookup = 4
 
     @staticmethod
     def from_join_strategy_str(join_strategy: str) -> JoinStrategy:
@@ -1036,6 +1037,7 @@ class PyPushdowns:
     filters: PyExpr | None
     partition_filters: PyExpr | None
     limit: int | None
+    file_limit: int | None
     aggregation: PyExpr | None
 
     def __init__(
@@ -1134,6 +1136,12 @@ def read_json_schema(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ) -> PySchema: ...
+def generate_tpch_table(
+    table: str,
+    scale_factor: float,
+    partition_idx: int = 0,
+    num_partitions: int = 1,
+) -> PyRecordBatch: ...
 
 class PyTimeUnit:
     @staticmethod
@@ -1181,6 +1189,8 @@ class PyDataType:
     @staticmethod
     def string() -> PyDataType: ...
     @staticmethod
+    def categorical() -> PyDataType: ...
+    @staticmethod
     def decimal128(precision: int, size: int) -> PyDataType: ...
     @staticmethod
     def date() -> PyDataType: ...
@@ -1242,6 +1252,7 @@ class PyDataType:
     def is_binary(self) -> builtins.bool: ...
     def is_fixed_size_binary(self) -> builtins.bool: ...
     def is_string(self) -> builtins.bool: ...
+    def is_categorical(self) -> builtins.bool: ...
     def is_list(self) -> builtins.bool: ...
     def is_fixed_size_list(self) -> builtins.bool: ...
     def is_struct(self) -> builtins.bool: ...
@@ -1255,6 +1266,7 @@ class PyDataType:
     def is_sparse_tensor(self) -> builtins.bool: ...
     def is_fixed_shape_sparse_tensor(self) -> builtins.bool: ...
     def is_python(self) -> builtins.bool: ...
+    def contains_python(self) -> builtins.bool: ...
     def is_numeric(self) -> builtins.bool: ...
     def is_integer(self) -> builtins.bool: ...
     def is_logical(self) -> builtins.bool: ...
@@ -1330,6 +1342,8 @@ class PyExpr:
     def agg_concat(self) -> PyExpr: ...
     def over(self, window_spec: WindowSpec) -> PyExpr: ...
     def offset(self, offset: int, default: PyExpr | None = None) -> PyExpr: ...
+    def session_id(self, gap: PyExpr) -> PyExpr: ...
+    def window_fill_null(self, strategy: str) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
     def __sub__(self, other: PyExpr) -> PyExpr: ...
     def __mul__(self, other: PyExpr) -> PyExpr: ...
@@ -1477,6 +1491,7 @@ def batch_udf(
     expr_args: list[PyExpr],
 ) -> PyExpr: ...
 def initialize_udfs(expression: PyExpr) -> PyExpr: ...
+def call_udf_hook(expression: PyExpr, hook: str) -> None: ...
 def resolve_expr(expr: PyExpr, schema: PySchema) -> tuple[PyExpr, PyField]: ...
 def row_number() -> PyExpr: ...
 def rank() -> PyExpr: ...
@@ -1654,6 +1669,15 @@ class PyRecordBatch:
         right: PyRecordBatch,
         left_on: list[PyExpr],
         right_on: list[PyExpr],
+        how: JoinType,
+        is_sorted: bool,
+    ) -> PyRecordBatch: ...
+    def sorted_lookup_join(
+        self,
+        right: PyRecordBatch,
+        left_on: list[PyExpr],
+        right_on: list[PyExpr],
+        how: JoinType,
         is_sorted: bool,
     ) -> PyRecordBatch: ...
     def explode(self, to_explode: list[PyExpr]) -> PyRecordBatch: ...
@@ -1715,7 +1739,10 @@ class PyMicroPartition:
     def concat_or_empty(tables: list[PyMicroPartition], schema: PySchema) -> PyMicroPartition: ...
     @staticmethod
     def read_from_ipc_stream(bytes: bytes) -> PyMicroPartition: ...
-    def write_to_ipc_stream(self) -> bytes: ...
+    def write_to_ipc_stream(self, compression: str | None = None, compression_level: int = 0) -> bytes: ...
+    @staticmethod
+    def read_ipc_file(path: str) -> PyMicroPartition: ...
+    def write_ipc_file(self, path: str, compression: str | None = None, compression_level: int = 0) -> None: ...
     def slice(self, start: int, end: int) -> PyMicroPartition: ...
     def to_record_batch(self) -> PyRecordBatch: ...
     def cast_to_schema(self, schema: PySchema) -> PyMicroPartition: ...
@@ -1746,6 +1773,7 @@ class PyMicroPartition:
         right: PyMicroPartition,
         left_on: list[PyExpr],
         right_on: list[PyExpr],
+        how: JoinType,
         is_sorted: bool,
     ) -> PyMicroPartition: ...
     def cross_join(
@@ -1861,7 +1889,10 @@ class LogicalPlanBuilder:
     def limit(self, limit: int, eager: bool) -> LogicalPlanBuilder: ...
     def offset(self, offset: int) -> LogicalPlanBuilder: ...
     def shard(self, strategy: str, world_size: int, rank: int) -> LogicalPlanBuilder: ...
-    def explode(self, to_explode: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def preview(self, rows_per_file: int, max_files: int) -> LogicalPlanBuilder: ...
+    def explode(
+        self, to_explode: list[PyExpr], index_column: str | None = None, pad_mismatched: bool = False
+    ) -> LogicalPlanBuilder: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1892,6 +1923,14 @@ class LogicalPlanBuilder:
         agg_expr: PyExpr,
         names: list[str],
     ) -> LogicalPlanBuilder: ...
+    def resample(
+        self,
+        ts_column: PyExpr,
+        every: PyExpr,
+        by: list[PyExpr],
+        agg_exprs: list[PyExpr],
+        fill: str | None = None,
+    ) -> LogicalPlanBuilder: ...
     def join(
         self,
         right: LogicalPlanBuilder,
@@ -1949,11 +1988,20 @@ class LogicalPlanBuilder:
     def schema(self) -> PySchema: ...
     def describe(self) -> LogicalPlanBuilder: ...
     def summarize(self) -> LogicalPlanBuilder: ...
+    def analyze(self, num_buckets: int) -> LogicalPlanBuilder: ...
     def optimize(self, execution_config: PyDaftExecutionConfig) -> LogicalPlanBuilder: ...
+    def optimizer_trace(self, execution_config: PyDaftExecutionConfig) -> str: ...
+    def fingerprint(self, execution_config: PyDaftExecutionConfig) -> str | None: ...
+    def column_lineage(self) -> str: ...
     def repr_ascii(self, simple: bool) -> str: ...
     def repr_mermaid(self, options: MermaidOptions) -> str: ...
+    def repr_dot(self, simple: bool = False, bottom_up: bool = False) -> str: ...
+    def repr_graph_json(self, simple: bool = False) -> str: ...
     def repr_json(self, include_schema: bool) -> str: ...
 
+def plan_cache_entries() -> list[tuple[str, int, str]]: ...
+def evict_from_plan_cache(fingerprint: str | None = None) -> int: ...
+
 class DistributedPhysicalPlan:
     @staticmethod
     def from_logical_plan_builder(
@@ -2006,6 +2054,8 @@ class RaySwordfishWorker:
         total_memory_bytes: int,
     ) -> None: ...
 
+def build_bloom_filter(partition: PyMicroPartition, num_items: int, false_positive_rate: float) -> bytes: ...
+
 class PyExecutionEngineResult:
     def __aiter__(self) -> PyExecutionEngineResult: ...
     async def __anext__(self) -> PyMicroPartition | None: ...
@@ -2021,6 +2071,7 @@ class NativeExecutor:
         results_buffer_size: int | None = None,
         context: dict[str, str] | None = None,
     ) -> PyExecutionEngineResult: ...
+    def cancel(self) -> None: ...
     @staticmethod
     def repr_ascii(builder: LogicalPlanBuilder, daft_execution_config: PyDaftExecutionConfig, simple: bool) -> str: ...
     @staticmethod
@@ -2028,6 +2079,17 @@ class NativeExecutor:
         builder: LogicalPlanBuilder, daft_execution_config: PyDaftExecutionConfig, options: MermaidOptions
     ) -> str: ...
     @staticmethod
+    def repr_dot(
+        builder: LogicalPlanBuilder,
+        daft_execution_config: PyDaftExecutionConfig,
+        simple: bool = False,
+        bottom_up: bool = False,
+    ) -> str: ...
+    @staticmethod
+    def repr_graph_json(
+        builder: LogicalPlanBuilder, daft_execution_config: PyDaftExecutionConfig, simple: bool = False
+    ) -> str: ...
+    @staticmethod
     def get_relationship_info(
         logical_plan_builder: LogicalPlanBuilder,
         daft_execution_config: PyDaftExecutionConfig,
@@ -2044,6 +2106,8 @@ class RelationshipNode:
 class PyDaftExecutionConfig:
     @staticmethod
     def from_env() -> PyDaftExecutionConfig: ...
+    def values_json(self) -> str: ...
+    def with_values_json(self, values: str) -> PyDaftExecutionConfig: ...
     def with_config_values(
         self,
         enable_scan_task_split_and_merge: bool | None = None,
@@ -2053,6 +2117,8 @@ class PyDaftExecutionConfig:
         parquet_split_row_groups_max_files: int | None = None,
         broadcast_join_size_bytes_threshold: int | None = None,
         hash_join_partition_size_leniency: float | None = None,
+        enable_bloom_filter_join_pruning: bool | None = None,
+        bloom_filter_join_false_positive_rate: float | None = None,
         sample_size_for_sort: int | None = None,
         num_preview_rows: int | None = None,
         parquet_target_filesize: int | None = None,
@@ -2067,6 +2133,8 @@ class PyDaftExecutionConfig:
         read_sql_partition_size_bytes: int | None = None,
         default_morsel_size: int | None = None,
         shuffle_algorithm: str | None = None,
+        shuffle_compression: str | None = None,
+        shuffle_compression_level: int | None = None,
         pre_shuffle_merge_threshold: int | None = None,
         scantask_max_parallel: int | None = None,
         native_parquet_writer: bool | None = None,
@@ -2075,6 +2143,10 @@ class PyDaftExecutionConfig:
         maintain_order: bool | None = None,
         enable_dynamic_batching: bool | None = None,
         dynamic_batching_strategy: str | None = None,
+        collect_spill_threshold_bytes: int | None = None,
+        scan_read_ahead: int | None = None,
+        scan_io_concurrency: int | None = None,
+        staged_writes: bool | None = None,
     ) -> PyDaftExecutionConfig: ...
     @property
     def enable_scan_task_split_and_merge(self) -> bool: ...
@@ -2091,6 +2163,10 @@ class PyDaftExecutionConfig:
     @property
     def hash_join_partition_size_leniency(self) -> float: ...
     @property
+    def enable_bloom_filter_join_pruning(self) -> bool: ...
+    @property
+    def bloom_filter_join_false_positive_rate(self) -> float: ...
+    @property
     def sample_size_for_sort(self) -> int: ...
     @property
     def num_preview_rows(self) -> int: ...
@@ -2119,6 +2195,10 @@ class PyDaftExecutionConfig:
     @property
     def shuffle_algorithm(self) -> str: ...
     @property
+    def shuffle_compression(self) -> str: ...
+    @property
+    def shuffle_compression_level(self) -> int: ...
+    @property
     def pre_shuffle_merge_threshold(self) -> int: ...
     @property
     def flight_shuffle_dirs(self) -> list[str]: ...
@@ -2132,10 +2212,20 @@ class PyDaftExecutionConfig:
     def enable_dynamic_batching(self) -> bool: ...
     @property
     def dynamic_batching_strategy(self) -> str: ...
+    @property
+    def collect_spill_threshold_bytes(self) -> int: ...
+    @property
+    def scan_read_ahead(self) -> int: ...
+    @property
+    def scan_io_concurrency(self) -> int: ...
+    @property
+    def staged_writes(self) -> bool: ...
 
 class PyDaftPlanningConfig:
     @staticmethod
     def from_env() -> PyDaftPlanningConfig: ...
+    def values_json(self) -> str: ...
+    def with_values_json(self, values: str) -> PyDaftPlanningConfig: ...
     def with_config_values(
         self,
         default_io_config: IOConfig | None = None,
@@ -2164,7 +2254,12 @@ class PyQueryMetadata:
     output_schema: PySchema
     unoptimized_plan: str
 
-    def __init__(self, output_schema: PySchema, unoptimized_plan: str) -> None: ...
+    def __init__(
+        self,
+        output_schema: PySchema,
+        unoptimized_plan: str,
+        execution_config: PyDaftExecutionConfig | None = None,
+    ) -> None: ...
 
 class PyQueryResult:
     end_state: QueryEndState
@@ -2300,6 +2395,8 @@ class PySession:
     def set_namespace(self, ident: PyIdentifier | None) -> None: ...
     def set_provider(self, ident: str | None) -> None: ...
     def set_model(self, ident: str | None) -> None: ...
+    def set_table_stats(self, ident: PyIdentifier, stats: PyRecordBatch, builder: LogicalPlanBuilder) -> None: ...
+    def with_table_stats(self, ident: PyIdentifier, builder: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
 
 class InProgressShuffleCache:
     @staticmethod
@@ -2346,6 +2443,8 @@ def get_function_from_registry(name: str) -> PyScalarFunction: ...
 def to_from_proto(builder: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
 
 class PyFileReference:
+    url: str
+
     @staticmethod
     def _from_tuple(tuple: tuple[Any]) -> PyFileReference: ...
     def __enter__(self) -> PyDaftFile: ...
@@ -24,13 +24,14 @@ from daft.daft import DistributedPhysicalPlan, FileFormat, IOConfig, JoinStrateg
 from daft.dataframe.display import MermaidOptions
 from daft.dataframe.preview import Preview, PreviewAlign, PreviewColumn, PreviewFormat, PreviewFormatter
 from daft.datatype import DataType
-from daft.errors import ExpressionTypeError
+from daft.errors import ExecutionTimeoutError, ExpressionTypeError
 from daft.execution.native_executor import NativeExecutor
 from daft.expressions import Expression, ExpressionsProjection, col, lit
 from daft.logical.builder import LogicalPlanBuilder
 from daft.recordbatch import MicroPartition
 from daft.runners import get_or_create_runner
 from daft.runners.partitioning import (
+    ExecutionCompleteness,
     LocalPartitionSet,
     MaterializedResult,
     PartitionCacheEntry,
@@ -52,6 +53,7 @@ if TYPE_CHECKING:
     from daft.io.catalog import DataCatalogTable
     from daft.io.sink import WriteResultType
     from daft.unity_catalog import UnityCatalogTable
+    from daft.window import Window
 
 from daft.schema import Schema
 
@@ -198,7 +200,12 @@ class DataFrame:
 
     @DataframePublicAPI
     def explain(
-        self, show_all: bool = False, format: str = "ascii", simple: bool = False, file: io.IOBase | None = None
+        self,
+        show_all: bool = False,
+        format: str = "ascii",
+        simple: bool = False,
+        file: io.IOBase | None = None,
+        trace_optimizer: bool = False,
     ) -> Any:
         r"""Prints the (logical and physical) plans that will be executed to produce this DataFrame.
 
@@ -208,12 +215,15 @@ class DataFrame:
         Args:
             show_all (bool): Whether to show the optimized logical plan and the physical plan in addition to the
                 unoptimized logical plan.
-            format (str): The format to print the plan in. one of 'ascii' or 'mermaid'
+            format (str): The format to print the plan in. one of 'ascii', 'mermaid', 'dot' (Graphviz), or 'json'
             simple (bool): Whether to only show the type of op for each node in the plan, rather than showing details
                 of how each op is configured.
 
             file (Optional[io.IOBase]): Location to print the output to, or defaults to None which defaults to the default location for
                 print (in Python, that should be sys.stdout)
+            trace_optimizer (bool): Whether to also show each rewrite which the query optimizer makes to the logical
+                plan, as a diff of the plan attributed to the optimizer rule that made it. This runs the query
+                optimizer, and is only supported with the 'ascii' format.
 
         Returns:
             Union[None, str, MermaidFormatter]:
@@ -245,6 +255,14 @@ class DataFrame:
             Set `show_all=True` to also see the Optimized and Physical plans. This will run the query optimizer.
 
         """
+        if format not in ("ascii", "mermaid", "dot", "json"):
+            raise ValueError(f"Unknown format: {format}")
+        if trace_optimizer and format != "ascii":
+            raise ValueError(f"The optimizer trace is only supported with the 'ascii' format, got {format!r}")
+        # Check the format before printing anything, rather than after printing the logical plans.
+        if show_all and format in ("dot", "json") and get_or_create_runner().name != "native":
+            raise ValueError(f"Format {format!r} is not supported for distributed physical plans")
+
         is_cached = self._result_cache is not None
         if format == "mermaid":
             from daft.dataframe.display import MermaidFormatter
@@ -273,11 +291,14 @@ class DataFrame:
         builder = self.__builder
         print_to_file("== Unoptimized Logical Plan ==\n")
         print_to_file(builder.pretty_print(simple, format=format))
+        if trace_optimizer:
+            print_to_file("\n== Optimizer Trace ==\n")
+            print_to_file(builder.optimizer_trace(get_context().daft_execution_config))
         if show_all:
             print_to_file("\n== Optimized Logical Plan ==\n")
             execution_config = get_context().daft_execution_config
             builder = builder.optimize(execution_config)
-            print_to_file(builder.pretty_print(simple))
+            print_to_file(builder.pretty_print(simple, format=format))
             print_to_file("\n== Physical Plan ==\n")
             if get_or_create_runner().name != "native":
                 from daft.daft import DistributedPhysicalPlan
@@ -380,6 +401,20 @@ class DataFrame:
         """
         return [col(field.name) for field in self.__builder.schema()]
 
+    @property
+    def completeness(self) -> ExecutionCompleteness | None:
+        """Returns how complete the results of the DataFrame are, if it was collected with a `max_execution_time`.
+
+        A DataFrame which finished in time is complete, while the `partial_result` DataFrame of an
+        `ExecutionTimeoutError` isn't.
+
+        Returns:
+            ExecutionCompleteness | None: Whether the query finished before its max execution time, how long it ran for
+            and the number of rows in each partition of the results, or None if the DataFrame wasn't collected with a
+            max execution time.
+        """
+        return self._result_cache.completeness if self._result_cache is not None else None
+
     @DataframePublicAPI
     def __iter__(self) -> Iterator[dict[str, Any]]:
         """Alias of `self.iter_rows()` with default arguments for convenient access of data.
@@ -739,6 +774,23 @@ class DataFrame:
         df._populate_preview()
         return df
 
+    def _from_partial_results(
+        self, pset: PartitionSet[Any], completeness: ExecutionCompleteness | None
+    ) -> "DataFrame":
+        """Creates a DataFrame of the results of this DataFrame's query which were produced before it was stopped."""
+        cache_entry = get_or_create_runner().put_partition_set_into_cache(pset)
+        cache_entry.completeness = completeness
+        builder = LogicalPlanBuilder.from_in_memory_scan(
+            cache_entry,
+            self.schema(),
+            pset.num_partitions(),
+            pset.size_bytes() or 0,
+            num_rows=len(pset),
+        )
+        df = DataFrame(builder)
+        df._result_cache = cache_entry
+        return df
+
     @classmethod
     def _from_schema(cls, schema: Schema) -> "DataFrame":
         """Creates a Daft DataFrom from a Schema.
@@ -767,17 +819,29 @@ class DataFrame:
         write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
         partition_cols: list[ColumnInputType] | None = None,
         io_config: IOConfig | None = None,
+        manifest: bool = False,
     ) -> "DataFrame":
         """Writes the DataFrame as parquet files, returning a new DataFrame with paths to the files that were written.
 
         Files will be written to `<root_dir>/*` with randomly generated UUIDs as the file names.
 
+        With `manifest=True`, the written files are committed to a manifest in the `_daft_manifest` directory under
+        the root directory once they're all written, and reading the directory with
+        [`daft.read_parquet(root_dir, manifest=True)`][daft.read_parquet] only reads the committed files. Each write
+        commits all of its files at once, so concurrent writers can append to the same directory, and readers see
+        each write entirely or not at all, without the files of writes which are still running or failed.
+
         Args:
             root_dir (str): root file path to write parquet files to.
             compression (str, optional): compression algorithm. Defaults to "snappy".
             write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
             partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
             io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+            manifest (bool, optional): Whether to commit the written files to the manifest of the root directory.
+                Only `append` and `overwrite` writes can be committed. An `overwrite` commits its files as a snapshot
+                which replaces the earlier commits, and only removes them and their files once it's committed, so the
+                previous snapshot is still read if it fails. It removes every file which isn't committed, so it isn't
+                safe to run concurrently with other writes. Defaults to False.
 
         Returns:
             DataFrame: The filenames that were written out as strings.
@@ -789,6 +853,7 @@ class DataFrame:
             >>> import daft
             >>> df = daft.from_pydict({"x": [1, 2, 3], "y": ["a", "b", "c"]})
             >>> df.write_parquet("output_dir", write_mode="overwrite")  # doctest: +SKIP
+            >>> df.write_parquet("output_dir", manifest=True)  # doctest: +SKIP
 
         Tip:
             See also [`df.write_csv()`][daft.DataFrame.write_csv] and [`df.write_json()`][daft.DataFrame.write_json]
@@ -800,6 +865,8 @@ class DataFrame:
             )
         if write_mode == "overwrite-partitions" and partition_cols is None:
             raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+        if write_mode == "overwrite-partitions" and manifest:
+            raise ValueError("`overwrite-partitions` mode can't be used with a manifest.")
 
         io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
@@ -807,10 +874,12 @@ class DataFrame:
         if partition_cols is not None:
             cols = self.__column_input_to_expression(tuple(partition_cols))
 
+        # An overwrite committed to the manifest only removes the files it replaces once it's committed.
+        overwrite_manifest = manifest and write_mode == "overwrite"
         builder = self._builder.write_tabular(
             root_dir=root_dir,
             partition_cols=cols,
-            write_mode=WriteMode.from_str(write_mode),
+            write_mode=WriteMode.from_str("append" if overwrite_manifest else write_mode),
             file_format=FileFormat.Parquet,
             compression=compression,
             io_config=io_config,
@@ -820,6 +889,17 @@ class DataFrame:
         write_df.collect()
         assert write_df._result is not None
 
+        if manifest:
+            from daft.io._manifest import commit_files
+
+            commit_files(
+                root_dir,
+                write_df.to_pydict()["path"],
+                num_partition_cols=len(cols) if cols is not None else 0,
+                io_config=io_config,
+                overwrite=overwrite_manifest,
+            )
+
         # Populate and return a new disconnected DataFrame
         result_df = DataFrame(write_df._builder)
         result_df._result_cache = write_df._result_cache
@@ -969,10 +1049,17 @@ class DataFrame:
             io_config (IOConfig, optional): A custom IOConfig to use when accessing Iceberg object storage data. If provided, configurations set in `table` are ignored.
 
         Returns:
-            DataFrame: The operations that occurred with this write.
+            DataFrame: The operations that occurred with this write. For a partitioned table, its `partitioning` column
+                holds the partition values of each file, such as its bucket number, keyed by the names of the source
+                columns of the partition fields. When several partition fields have the same source column, the
+                values of all but the first of them are keyed by the names of the partition fields.
 
         Note:
-            This call is **blocking** and will execute the DataFrame when called
+            This call is **blocking** and will execute the DataFrame when called. When the table is bucketed, the rows
+            of each partition are shuffled to a single task before they're written on the Ray runner, so each
+            partition is written to as few files as possible. Writes partitioned by
+            [`partition_iceberg_bucket`][daft.functions.partition_iceberg_bucket], such as Hive-style bucketed
+            `write_parquet` writes, are shuffled the same way.
 
         Examples:
             >>> import pyiceberg
@@ -1026,19 +1113,27 @@ class DataFrame:
         else:
             deleted_files = []
 
+        spec = table.spec()
         schema = table.schema()
-        partitioning: dict[str, list[Any]] = {
-            schema.find_field(field.source_id).name: [] for field in table.spec().fields
-        }
+        partition_keys: list[str] = []
+        for field in spec.fields:
+            source_name = schema.find_field(field.source_id).name
+            partition_keys.append(field.name if source_name in partition_keys else source_name)
+        partitioning: dict[str, list[Any]] = {key: [] for key in partition_keys}
+
+        def record_partition(data_file: Any) -> None:
+            # Partition records are positional in newer versions of pyiceberg, and files written with an older
+            # partition spec of the table have different fields.
+            same_spec = data_file.spec_id == spec.spec_id
+            for i, values in enumerate(partitioning.values()):
+                values.append(data_file.partition[i] if same_spec else None)
 
         for data_file in data_files:
             operations.append("ADD")
             path.append(data_file.file_path)
             rows.append(data_file.record_count)
             size.append(data_file.file_size_in_bytes)
-
-            for field in partitioning.keys():
-                partitioning[field].append(getattr(data_file.partition, field, None))
+            record_partition(data_file)
 
         for pf in deleted_files:
             data_file = pf.file
@@ -1046,9 +1141,7 @@ class DataFrame:
             path.append(data_file.file_path)
             rows.append(data_file.record_count)
             size.append(data_file.file_size_in_bytes)
-
-            for field in partitioning.keys():
-                partitioning[field].append(getattr(data_file.partition, field, None))
+            record_partition(data_file)
 
         if parse(pyiceberg.__version__) >= parse("0.7.0"):
             from pyiceberg.table import ALWAYS_TRUE, TableProperties
@@ -1384,12 +1477,16 @@ class DataFrame:
         """
         sink.start()
 
-        builder = self._builder.write_datasink(sink.name(), sink)
-        write_df = DataFrame(builder)
-        write_df.collect()
-
-        results = write_df.to_pydict()
-        assert "write_results" in results
+        try:
+            builder = self._builder.write_datasink(sink.name(), sink)
+            write_df = DataFrame(builder)
+            write_df.collect()
+
+            results = write_df.to_pydict()
+            assert "write_results" in results
+        except BaseException:
+            sink.abort()
+            raise
         micropartition = sink.finalize(results["write_results"])
         if micropartition.schema() != sink.schema():
             raise ValueError(
@@ -2216,6 +2313,85 @@ class DataFrame:
         builder = self._builder.sample(fraction, size, with_replacement, seed)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def split(
+        self,
+        fractions: list[float],
+        seed: int = 0,
+        stratify_by: ManyColumnsInputType | None = None,
+    ) -> list["DataFrame"]:
+        """Randomly splits the rows of the DataFrame into several DataFrames, such as a train and a test set.
+
+        Rows are assigned to splits by a seeded hash of all of their values, along with the number of the occurrence
+        of each row among its duplicates, so that duplicate rows are split like any other rows. The assignment is
+        deterministic and does not depend on how the data is partitioned. Without ``stratify_by``, each row is
+        assigned independently, so split sizes only approximately match ``fractions``. With ``stratify_by``, the rows
+        of each stratum are split in exactly the given proportions (up to rounding), so every split has the same mix
+        of strata.
+
+        Note:
+            This call is **blocking**: the DataFrame is executed once and the rows are assigned to splits, so that the
+            splits read the materialized rows instead of each executing the DataFrame again.
+
+        Args:
+            fractions (list[float]): relative sizes of the splits. They are normalized to sum to 1.
+            seed (int): seed of the hash that assigns rows to splits. Defaults to 0.
+            stratify_by (Optional[ManyColumnsInputType]): columns whose combinations of values are split
+                proportionally, such as a label column. Defaults to None.
+
+        Returns:
+            list[DataFrame]: one DataFrame per fraction, in the same order.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"id": list(range(100)), "label": [i % 2 for i in range(100)]})
+            >>> train, test = df.split([0.8, 0.2], seed=42, stratify_by="label")
+            >>> train.count_rows(), test.count_rows()
+            (80, 20)
+            >>> test.where(daft.col("label") == 1).count_rows()
+            10
+        """
+        from daft.functions import hash, row_number
+        from daft.window import Window
+
+        if len(fractions) == 0:
+            raise ValueError("split requires at least one fraction")
+        if any(fraction < 0 for fraction in fractions) or sum(fractions) <= 0:
+            raise ValueError(f"split fractions must be non-negative with a positive sum, but got {fractions}")
+
+        columns = [col(name) for name in self.column_names]
+        row_hash, occurrence, position = "__split_hash", "__split_occurrence", "__split_position"
+        # Duplicate rows have the same hash, so they're told apart by their occurrence among the rows with their hash.
+        # Duplicates are interchangeable, so it doesn't matter which of them gets which occurrence.
+        assigned = self.with_column(row_hash, hash(*columns, seed=seed))
+        assigned = assigned.with_column(
+            occurrence, row_number().over(Window().partition_by(row_hash).order_by(row_hash))
+        )
+        split_key = hash(*columns, col(occurrence), seed=seed)
+        if stratify_by is None:
+            assigned = assigned.with_column(position, split_key.cast(DataType.float64()) / float(2**64))
+        else:
+            stratum = Window().partition_by(*column_inputs_to_expressions(stratify_by))
+            rank = "__split_rank"
+            # A hash with another seed breaks the ties of rows whose split keys collide.
+            tiebreak = hash(*columns, col(occurrence), seed=seed + 1)
+            assigned = assigned.with_column(rank, row_number().over(stratum.order_by(split_key, tiebreak)))
+            assigned = assigned.with_column(
+                position, (col(rank) - 1).cast(DataType.float64()) / col(rank).max().over(stratum)
+            ).exclude(rank)
+        # Every split filters the same assignment, which is materialized once rather than recomputed for each split.
+        assigned = assigned.exclude(row_hash, occurrence).collect(num_preview_rows=None)
+
+        total = sum(fractions)
+        bounds = [sum(fractions[:i]) / total for i in range(len(fractions) + 1)]
+        splits = []
+        for i, (lower, upper) in enumerate(zip(bounds, bounds[1:])):
+            predicate = col(position) >= lower
+            if i < len(fractions) - 1:
+                predicate = predicate & (col(position) < upper)
+            splits.append(assigned.where(predicate).exclude(position))
+        return splits
+
     @DataframePublicAPI
     def exclude(self, *names: str) -> "DataFrame":
         """Drops columns from the current DataFrame by name.
@@ -2481,6 +2657,7 @@ class DataFrame:
         by: ColumnInputType | list[ColumnInputType],
         desc: bool | list[bool] = False,
         nulls_first: bool | list[bool] | None = None,
+        keep_keys: bool = False,
     ) -> "DataFrame":
         """Sorts DataFrame globally.
 
@@ -2488,6 +2665,9 @@ class DataFrame:
             by (Union[ColumnInputType, List[ColumnInputType]]): column to sort by. Can be `str` or expression as well as a list of either.
             desc (Union[bool, List[bool]), optional): Sort by descending order. Defaults to False.
             nulls_first (Union[bool, List[bool]), optional): Sort by nulls first. Defaults to nulls being treated as the greatest value.
+            keep_keys (bool, optional): Whether to add the keys computed from expressions, rather than existing columns,
+                to the sorted DataFrame as columns named after the expressions. Defaults to False, which only uses them
+                to sort.
 
         Returns:
             DataFrame: Sorted DataFrame.
@@ -2495,6 +2675,9 @@ class DataFrame:
         Note:
             * Since this a global sort, this requires an expensive repartition which can be quite slow.
             * Supports multicolumn sorts and can have unique `descending` and `nulls_first` flags per column.
+            * Keys can be computed expressions, which are evaluated while sorting without projecting them first.
+            * A sort followed by a [`.limit()`][daft.DataFrame.limit] is executed as a top-k, which keeps only the
+              first rows of each partition instead of sorting all of them.
 
         Examples:
             >>> import daft
@@ -2558,6 +2741,12 @@ class DataFrame:
             ╰───────┴───────╯
             <BLANKLINE>
             (Showing first 5 of 5 rows)
+
+            You can also keep the computed keys as columns:
+
+            >>> df = daft.from_pydict({"x": [3, 2, 1], "y": [6, 4, 5]})
+            >>> df.sort((df["x"] * df["y"]).alias("area"), keep_keys=True).to_pydict()
+            {'x': [1, 2, 3], 'y': [5, 4, 6], 'area': [5, 8, 18]}
         """
         if not isinstance(by, list):
             by = [
@@ -2569,6 +2758,20 @@ class DataFrame:
 
         sort_by = self.__column_input_to_expression(by)
 
+        if keep_keys:
+            computed = [e for e in sort_by if not e.is_column()]
+            names = [e.name() for e in computed]
+            clashes = {name for name in names if name in self.column_names or names.count(name) > 1}
+            if clashes:
+                raise ValueError(
+                    f"Cannot keep the computed sort keys named {sorted(clashes)}, which clash with other columns or "
+                    "keys; alias them to unique names first"
+                )
+            if computed:
+                with_keys = self.with_columns({name: e for name, e in zip(names, computed)})
+                sort_by = [e if e.is_column() else col(e.name()) for e in sort_by]
+                return with_keys.sort(sort_by, desc=desc, nulls_first=nulls_first)
+
         builder = self._builder.sort(sort_by=sort_by, descending=desc, nulls_first=nulls_first)
         return DataFrame(builder)
 
@@ -2613,6 +2816,10 @@ class DataFrame:
     def offset(self, num: int) -> "DataFrame":
         """Returns a new DataFrame by skipping the first ``N`` rows, similar to a SQL ``Offset``.
 
+        Combined with [`.sort()`][daft.DataFrame.sort] and [`.limit()`][daft.DataFrame.limit], this can be used to
+        paginate results: sorting and limiting are fused into a single top-``(limit + offset)`` operation, and the
+        rows before the offset are skipped in the order they are produced, even if ``maintain_order`` is disabled.
+
         Args:
             num (int): the number of rows to skip
 
@@ -2788,7 +2995,7 @@ class DataFrame:
         left_on: list[ColumnInputType] | ColumnInputType | None = None,
         right_on: list[ColumnInputType] | ColumnInputType | None = None,
         how: Literal["inner", "left", "right", "outer", "anti", "semi", "cross"] = "inner",
-        strategy: Literal["hash", "sort_merge", "broadcast"] | None = None,
+        strategy: Literal["hash", "sort_merge", "broadcast", "sorted_lookup"] | None = None,
         prefix: str | None = None,
         suffix: str | None = None,
     ) -> "DataFrame":
@@ -2803,8 +3010,14 @@ class DataFrame:
             left_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on left DataFrame. Defaults to None.
             right_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on right DataFrame. Defaults to None.
             how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "anti", "semi", and "cross" are supported. Defaults to "inner".
-            strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", and None are supported, where None
+            strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", "sorted_lookup", and None are supported, where None
                 chooses the join strategy automatically during query optimization. The default is None.
+                "sort_merge" streams both sides in order while merging them, sorting each side first unless it's already sorted on its
+                join keys in ascending order with nulls last. It's chosen automatically when both sides are already sorted that way.
+                "sorted_lookup" binary searches the right side, sorted on its join keys, for the keys of each left row, which suits a small
+                right side such as a lookup table. It's only supported for inner, left, semi and anti joins on the native runner, and is
+                chosen automatically there when the right side is already sorted on its join keys in ascending order with nulls last,
+                unless the left side is sorted that way too.
             suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
             prefix (Optional[str], optional): Prefix to add to the column names in case of a name collision. Defaults to "right.".
 
@@ -2857,9 +3070,7 @@ class DataFrame:
         join_type = JoinType.from_join_type_str(how)
         join_strategy = JoinStrategy.from_join_strategy_str(strategy) if strategy is not None else None
 
-        if join_strategy == JoinStrategy.SortMerge and join_type != JoinType.Inner:
-            raise ValueError("Sort merge join only supports inner joins")
-        elif join_strategy == JoinStrategy.Broadcast and join_type == JoinType.Outer:
+        if join_strategy == JoinStrategy.Broadcast and join_type == JoinType.Outer:
             raise ValueError("Broadcast join does not support outer joins")
 
         left_exprs = self.__column_input_to_expression(tuple(left_on) if isinstance(left_on, list) else (left_on,))
@@ -2875,6 +3086,62 @@ class DataFrame:
         )
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def cross_join_sample(
+        self,
+        other: "DataFrame",
+        n: int,
+        with_replacement: bool = False,
+        seed: int | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> "DataFrame":
+        """Samples rows uniformly at random from the cross join of this DataFrame with another, without computing the full cross join.
+
+        ``n`` positions are drawn from the cartesian product of both DataFrames, and only the rows at those positions
+        are taken from each side, so the cost grows with the size of the inputs and of the sample, rather than with the
+        size of the cartesian product. This is useful for pairwise-comparison workloads, such as evaluating a
+        similarity model on random pairs of items.
+
+        Args:
+            other (DataFrame): the right DataFrame of the cross join
+            n (int): number of rows to sample. Sampling more rows than the cross join has without replacement fails
+                when the DataFrame is executed.
+            with_replacement (bool, optional): whether the same pair of rows may be sampled more than once.
+                Defaults to False.
+            seed (Optional[int], optional): random seed. Defaults to None.
+            prefix (Optional[str], optional): Prefix to add to the column names of the right DataFrame that
+                conflict with the left, as in [`join`][daft.DataFrame.join]. Defaults to None.
+            suffix (Optional[str], optional): Suffix to add to the column names of the right DataFrame that
+                conflict with the left, as in [`join`][daft.DataFrame.join]. Defaults to None.
+
+        Returns:
+            DataFrame: ``n`` rows of the cross join, in no particular order.
+
+        Note:
+            Like sampling by size, this only works on the native runner right now.
+
+        Examples:
+            >>> import daft
+            >>> items = daft.from_pydict({"item": list(range(1000))})
+            >>> users = daft.from_pydict({"user": list(range(1000))})
+            >>> pairs = items.cross_join_sample(users, n=5, seed=0)
+            >>> pairs.count_rows()
+            5
+        """
+        if n < 0:
+            raise ValueError(f"n should be non-negative, but got {n}")
+        if get_or_create_runner().name == "ray":
+            raise ValueError(
+                "cross_join_sample only works on the native runner right now. "
+                "Please use `daft.set_runner_native()` to switch to the native runner."
+            )
+
+        # A sample by size of a cross join is executed by sampling positions of the cartesian product.
+        joined = self.join(other, how="cross", prefix=prefix, suffix=suffix)
+        builder = joined._builder.sample(None, n, with_replacement, seed)
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def concat(self, other: "DataFrame") -> "DataFrame":
         """Concatenates two DataFrames together in a "vertical" concatenation.
@@ -3034,15 +3301,23 @@ class DataFrame:
         return self.where(~reduce(lambda x, y: x | y, (x.is_null() for x in columns)))
 
     @DataframePublicAPI
-    def explode(self, *columns: ColumnInputType) -> "DataFrame":
+    def explode(
+        self, *columns: ColumnInputType, index_column: str | None = None, pad_mismatched: bool = False
+    ) -> "DataFrame":
         """Explodes a List column, where every element in each row's List becomes its own row, and all other columns in the DataFrame are duplicated across rows.
 
-        If multiple columns are specified, each row must contain the same number of items in each specified column.
+        If multiple columns are specified, they are exploded in lockstep, so each row must contain the same number of
+        items in each specified column unless ``pad_mismatched`` is set.
 
         Exploding Null values or empty lists will create a single Null entry (see example below).
 
         Args:
             *columns (ColumnInputType): columns to explode
+            index_column (str, optional): if set, adds a UInt64 column of this name with the position of each item
+                within its list, like ``posexplode`` in Spark. The position is Null for rows from Null values or
+                empty lists.
+            pad_mismatched (bool): if True, lists of different lengths in the same row are padded with Nulls to the
+                longest one. Otherwise, mismatched lengths raise an error. Defaults to False.
 
         Returns:
             DataFrame: DataFrame with exploded column
@@ -3126,11 +3401,195 @@ class DataFrame:
             <BLANKLINE>
             (Showing first 5 of 5 rows)
 
+            Example with positions and lists of different lengths:
+
+            >>> df3 = daft.from_pydict({"id": [1, 2], "x": [[1, 2], [3]], "y": [["a"], ["b", "c"]]})
+            >>> df3.explode(df3["x"], df3["y"], index_column="pos", pad_mismatched=True).collect()
+            ╭───────┬───────┬────────┬────────╮
+            │ id    ┆ x     ┆ y      ┆ pos    │
+            │ ---   ┆ ---   ┆ ---    ┆ ---    │
+            │ Int64 ┆ Int64 ┆ String ┆ UInt64 │
+            ╞═══════╪═══════╪════════╪════════╡
+            │ 1     ┆ 1     ┆ a      ┆ 0      │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ 1     ┆ 2     ┆ None   ┆ 1      │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ 2     ┆ 3     ┆ b      ┆ 0      │
+            ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ 2     ┆ None  ┆ c      ┆ 1      │
+            ╰───────┴───────┴────────┴────────╯
+            <BLANKLINE>
+            (Showing first 4 of 4 rows)
+
         """
         parsed_exprs = self.__column_input_to_expression(columns)
-        builder = self._builder.explode(parsed_exprs)
+        builder = self._builder.explode(parsed_exprs, index_column=index_column, pad_mismatched=pad_mismatched)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def sessionize(
+        self,
+        key_cols: ManyColumnsInputType,
+        ts_col: ColumnInputType,
+        gap: Any,
+        session_col: str = "session_id",
+    ) -> "DataFrame":
+        """Groups the rows of each key into sessions, splitting a session wherever consecutive timestamps are more than ``gap`` apart.
+
+        The DataFrame is repartitioned by the key columns and each partition is sorted by the timestamp column, so
+        the session ids are computed in a single ordered scan per key. Session ids count up from 0 for each key, so a
+        session is identified by its key columns together with its session id. Rows with a Null timestamp are each
+        placed in their own session.
+
+        Args:
+            key_cols (ManyColumnsInputType): columns to sessionize the rows of separately, such as a user id
+            ts_col (ColumnInputType): the column of timestamps (or any other ordered values) to sessionize by
+            gap: the largest difference between consecutive timestamps of one session, such as a
+                ``datetime.timedelta`` for a Timestamp column
+            session_col (str): name of the added UInt64 session id column. Defaults to "session_id".
+
+        Returns:
+            DataFrame: DataFrame with an additional session id column
+
+        Examples:
+            >>> import daft
+            >>> df = daft.from_pydict({"user": ["a", "b", "a", "a", "b"], "ts": [1, 2, 3, 20, 30]})
+            >>> df = df.sessionize("user", "ts", gap=10)
+            >>> df.sort(["user", "ts"]).to_pydict()
+            {'user': ['a', 'a', 'a', 'b', 'b'], 'ts': [1, 3, 20, 2, 30], 'session_id': [0, 0, 1, 0, 1]}
+        """
+        from daft.functions.window import session_id
+        from daft.window import Window
+
+        keys = column_inputs_to_expressions(key_cols)
+        if len(keys) == 0:
+            raise ValueError("sessionize requires at least one key column")
+        ts = self.__column_input_to_expression([ts_col])[0]
+
+        window = Window().partition_by(*keys).order_by(ts)
+        return self.with_column(session_col, session_id(ts, gap).over(window))
+
+    @DataframePublicAPI
+    def resample(
+        self,
+        ts_col: ColumnInputType,
+        every: Any,
+        agg_exprs: Expression | Iterable[Expression],
+        by: ManyColumnsInputType | None = None,
+        fill: Literal["forward", "backward", "linear"] | None = None,
+    ) -> "DataFrame":
+        """Aggregates a time series into fixed intervals, with a row for every interval between the first and last intervals of each key.
+
+        Each row falls into the interval which starts at the largest multiple of ``every`` at or before its timestamp,
+        and the rows of each interval are aggregated with ``agg_exprs`` for each combination of ``by`` columns.
+        Intervals without any rows fill the gaps, with their aggregated values left Null or filled by ``fill``:
+
+        * ``"forward"``: the values of the last interval before the gap.
+        * ``"backward"``: the values of the first interval after the gap.
+        * ``"linear"``: numeric values interpolated between the intervals around the gap, which turns integer columns
+          into Float64 columns. Other values are left Null.
+
+        The DataFrame is partitioned by the ``by`` columns, so all of the intervals of a key are filled together, or
+        gathered into a single partition when there are no ``by`` columns. Rows with a Null timestamp are dropped.
+
+        Args:
+            ts_col (ColumnInputType): the Timestamp, Date or integer column to resample, which holds the start of each
+                interval in the result
+            every: the length of the intervals, such as a ``datetime.timedelta`` for a Timestamp or Date column, or an
+                integer for an integer column
+            agg_exprs (Expression | Iterable[Expression]): aggregations of the rows of each interval
+            by (Optional[ManyColumnsInputType]): columns to resample the rows of separately, such as a sensor id
+            fill (Optional[str]): how to fill the values of the intervals without any rows, one of "forward",
+                "backward" or "linear". Defaults to None, which leaves them Null.
+
+        Returns:
+            DataFrame: DataFrame with the ``by`` columns, the timestamp column and the aggregations, with one row per
+                key and interval
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict(
+            ...     {"sensor": ["a", "a", "a", "b", "b"], "ts": [0, 2, 7, 1, 4], "value": [1, 3, 10, 5, 8]}
+            ... )
+            >>> df = df.resample("ts", every=2, agg_exprs=col("value").sum(), by="sensor", fill="linear")
+            >>> df.sort(["sensor", "ts"]).to_pydict()
+            {'sensor': ['a', 'a', 'a', 'a', 'b', 'b', 'b'], 'ts': [0, 2, 4, 6, 0, 2, 4], 'value': [1.0, 3.0, 6.5, 10.0, 5.0, 6.5, 8.0]}
+        """
+        ts = self.__column_input_to_expression([ts_col])[0]
+        by_exprs = column_inputs_to_expressions(by) if by is not None else []
+        agg_list = [agg_exprs] if isinstance(agg_exprs, Expression) else list(agg_exprs)
+        if len(agg_list) == 0:
+            raise ValueError("resample requires at least one aggregation")
+        for expr in agg_list:
+            if not isinstance(expr, Expression):
+                raise ValueError(f"DataFrame.resample() only accepts expression type, received: {type(expr)}")
+        every_expr = every if isinstance(every, Expression) else lit(every)
+
+        builder = self._builder.resample(ts, every_expr, by_exprs, agg_list, fill)
+        return DataFrame(builder)
+
+    @DataframePublicAPI
+    def rolling(
+        self,
+        window: Any,
+        on: ColumnInputType,
+        by: ManyColumnsInputType,
+        min_periods: int = 1,
+    ) -> "RollingDataFrame":
+        """Prepares rolling aggregations over a sliding window of the rows of each key, ordered by the ``on`` column.
+
+        Call [RollingDataFrame.agg][daft.dataframe.dataframe.RollingDataFrame.agg] on the result to compute the
+        aggregations of the window ending at each row. The window is either a number of rows, or a range of values of
+        the ``on`` column:
+
+        * An integer ``window`` spans the current row and the ``window - 1`` rows before it.
+        * Any other ``window``, such as a ``datetime.timedelta`` for a Timestamp column, spans the rows whose ``on``
+          value is at most ``window`` before the current row's, inclusive.
+
+        Sums, means and counts are updated as rows enter and leave the window, and mins and maxes keep the candidates
+        for the extremum of the window in a monotonic deque, so each window is computed in constant amortized time.
+
+        Args:
+            window: the number of rows in each window, or the range of ``on`` values it spans
+            on (ColumnInputType): the column to order the rows of each key by, such as a timestamp
+            by (ManyColumnsInputType): columns to compute the windows of separately, such as a sensor id
+            min_periods (int): the least number of rows in a window to compute its aggregations, which are Null for
+                windows with fewer rows. Defaults to 1.
+
+        Returns:
+            RollingDataFrame: DataFrame to compute rolling aggregations of
+
+        Examples:
+            >>> import daft
+            >>> from daft import col
+            >>> df = daft.from_pydict(
+            ...     {"sensor": ["a", "a", "a", "b", "b"], "ts": [1, 2, 3, 1, 2], "value": [1, 2, 3, 10, 20]}
+            ... )
+            >>> df = df.rolling(2, on="ts", by="sensor").agg(
+            ...     col("value").sum().alias("sum"), col("value").max().alias("max")
+            ... )
+            >>> df.sort(["sensor", "ts"]).to_pydict()
+            {'sensor': ['a', 'a', 'a', 'b', 'b'], 'ts': [1, 2, 3, 1, 2], 'sum': [1, 3, 5, 10, 30], 'max': [1, 2, 3, 10, 20]}
+        """
+        from daft.window import Window
+
+        keys = column_inputs_to_expressions(by)
+        if len(keys) == 0:
+            raise ValueError("rolling requires at least one key column")
+        on_expr = self.__column_input_to_expression([on])[0]
+        if min_periods < 1:
+            raise ValueError(f"rolling requires min_periods to be at least 1, received: {min_periods}")
+
+        spec = Window().partition_by(*keys).order_by(on_expr)
+        if isinstance(window, int) and not isinstance(window, bool):
+            if window < 1:
+                raise ValueError(f"rolling requires a window of at least 1 row, received: {window}")
+            spec = spec.rows_between(-(window - 1), Window.current_row, min_periods=min_periods)
+        else:
+            spec = spec.range_between(-window, Window.current_row, min_periods=min_periods)
+        return RollingDataFrame(self, ExpressionsProjection(keys), on_expr, spec)
+
     @DataframePublicAPI
     def unpivot(
         self,
@@ -4087,20 +4546,30 @@ class DataFrame:
         builder = self._builder.except_all(other._builder)
         return DataFrame(builder)
 
-    def _materialize_results(self) -> None:
+    def _materialize_results(self, max_execution_time: float | None = None) -> None:
         """Materializes the results of for this DataFrame and hold a pointer to the results."""
         if self._result is None:
-            self._result_cache = get_or_create_runner().run(self._builder)
+            try:
+                self._result_cache = get_or_create_runner().run(self._builder, max_execution_time=max_execution_time)
+            except ExecutionTimeoutError as e:
+                # The partial results are handed over as a DataFrame of their own, leaving this one unmaterialized.
+                if isinstance(e.partial_result, PartitionSet):
+                    e.partial_result = self._from_partial_results(e.partial_result, e.completeness)
+                raise
             result = self._result
             assert result is not None
             result.wait()
 
     @DataframePublicAPI
-    def collect(self, num_preview_rows: int | None = 8) -> "DataFrame":
+    def collect(self, num_preview_rows: int | None = 8, max_execution_time: float | None = None) -> "DataFrame":
         """Executes the entire DataFrame and materializes the results.
 
         Args:
             num_preview_rows: Number of rows to preview. Defaults to 8.
+            max_execution_time: Number of seconds after which execution is stopped. A stopped query raises
+                `ExecutionTimeoutError`, whose `partial_result` is a separate DataFrame of the results produced so
+                far and whose `completeness` tells how complete they are, while this DataFrame stays unmaterialized.
+                Defaults to None, which runs the query to completion.
 
         Returns:
             DataFrame: DataFrame with materialized results.
@@ -4127,7 +4596,9 @@ class DataFrame:
             <BLANKLINE>
             (Showing first 3 of 3 rows)
         """
-        self._materialize_results()
+        if max_execution_time is not None and max_execution_time <= 0:
+            raise ValueError(f"max_execution_time must be positive, got {max_execution_time}")
+        self._materialize_results(max_execution_time)
         assert self._result is not None
         dataframe_len = len(self._result)
         if num_preview_rows is not None:
@@ -4136,6 +4607,37 @@ class DataFrame:
             self._num_preview_rows = dataframe_len
         return self
 
+    @DataframePublicAPI
+    def preview(self, n_rows_per_file: int = 8, max_files: int = 8) -> "DataFrame":
+        """Executes the DataFrame over a sample of its files, giving a quick look at its schema and data.
+
+        Every scan reads only the first ``n_rows_per_file`` rows of at most ``max_files`` files, stopping its file
+        listing early where it can, and the output is limited to ``n_rows_per_file * max_files`` rows. Each file is
+        read on its own, rather than merged with other small files as usual, so that the row limit applies to every
+        file. Sources which read several files at once apply the row limit to each such read. Operations such as
+        filters, joins and aggregations are computed over the sample only, so their results differ from those of the
+        full DataFrame.
+
+        Args:
+            n_rows_per_file (int): maximum number of rows to read from each file. Defaults to 8.
+            max_files (int): maximum number of files to read from each scan. Defaults to 8.
+
+        Returns:
+            DataFrame: DataFrame with the materialized results of the sample.
+
+        Examples:
+            >>> import daft
+            >>> df = daft.read_parquet("s3://bucket/huge-dataset/**/*.parquet")  # doctest: +SKIP
+            >>> df.where(df["x"] > 0).preview(n_rows_per_file=5, max_files=2).show()  # doctest: +SKIP
+        """
+        if n_rows_per_file <= 0 or max_files <= 0:
+            raise ValueError(
+                f"n_rows_per_file and max_files must be positive, got n_rows_per_file={n_rows_per_file} and "
+                f"max_files={max_files}"
+            )
+        builder = self._builder.preview(n_rows_per_file, max_files)
+        return DataFrame(builder).collect()
+
     def _construct_show_preview(self, n: int) -> Preview:
         """Helper for .show() which will construct the underlying Preview object."""
         preview_partition = self._preview.partition
@@ -4952,3 +5454,35 @@ class GroupedDataFrame:
 
         """
         return self.df._map_groups(udf, group_by=self.group_by)
+
+
+@dataclass
+class RollingDataFrame:
+    df: DataFrame
+    by: ExpressionsProjection
+    on: Expression
+    window: "Window"
+
+    def agg(self, *to_agg: Expression | Iterable[Expression]) -> DataFrame:
+        """Computes aggregations over the window ending at each row of this RollingDataFrame.
+
+        Args:
+            *to_agg (Union[Expression, Iterable[Expression]]): aggregation expressions
+
+        Returns:
+            DataFrame: DataFrame with the key columns, the ``on`` column and the aggregations, with one row per row of
+                the original DataFrame
+        """
+        to_agg_list = (
+            list(to_agg[0])
+            if (len(to_agg) == 1 and not isinstance(to_agg[0], Expression))
+            else list(typing.cast("tuple[Expression]", to_agg))
+        )
+        if len(to_agg_list) == 0:
+            raise ValueError("RollingDataFrame.agg() requires at least one aggregation")
+
+        for expr in to_agg_list:
+            if not isinstance(expr, Expression):
+                raise ValueError(f"RollingDataFrame.agg() only accepts expression type, received: {type(expr)}")
+
+        return self.df.select(*self.by, self.on, *[expr.over(self.window) for expr in to_agg_list])
@@ -511,6 +511,16 @@ class DataType:
         """Create a String DataType: A string of UTF8 characters."""
         return cls._from_pydatatype(PyDataType.string())
 
+    @classmethod
+    def categorical(cls) -> DataType:
+        """Create a Categorical DataType: Strings with few distinct values, stored as codes into a dictionary of the distinct strings.
+
+        Categoricals are grouped, joined and compared for equality by their codes, which is much faster than comparing
+        strings. They're converted to Arrow dictionary arrays, and Arrow dictionary arrays of strings are read as
+        categoricals.
+        """
+        return cls._from_pydatatype(PyDataType.categorical())
+
     @classmethod
     def bool(cls) -> DataType:
         """Create the Boolean DataType: Either ``True`` or ``False``."""
@@ -749,6 +759,10 @@ class DataType:
             return cls.float64()
         elif pa.types.is_string(arrow_type) or pa.types.is_large_string(arrow_type):
             return cls.string()
+        elif pa.types.is_dictionary(arrow_type) and (
+            pa.types.is_string(arrow_type.value_type) or pa.types.is_large_string(arrow_type.value_type)
+        ):
+            return cls.categorical()
         elif pa.types.is_binary(arrow_type) or pa.types.is_large_binary(arrow_type):
             return cls.binary()
         elif pa.types.is_fixed_size_binary(arrow_type):
@@ -1067,6 +1081,16 @@ class DataType:
         """
         return self._dtype.is_string()
 
+    def is_categorical(self) -> builtins.bool:
+        """Check if this is a categorical type.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.categorical()
+            >>> assert dtype.is_categorical()
+        """
+        return self._dtype.is_categorical()
+
     def is_list(self) -> builtins.bool:
         """Check if this is a list type.
 
@@ -1197,6 +1221,17 @@ class DataType:
         """
         return self._dtype.is_python()
 
+    def contains_python(self) -> builtins.bool:
+        """Check if this is a python object type, or a nested type containing one.
+
+        Examples:
+            >>> import daft
+            >>> dtype = daft.DataType.list(daft.DataType.python())
+            >>> assert not dtype.is_python()
+            >>> assert dtype.contains_python()
+        """
+        return self._dtype.contains_python()
+
     def is_numeric(self) -> builtins.bool:
         """Check if this is a numeric type.
 
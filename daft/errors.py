@@ -1,10 +1,12 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 if TYPE_CHECKING:
     from traceback import TracebackException
 
+    from daft.runners.partitioning import ExecutionCompleteness
+
 
 class ExpressionTypeError(Exception):
     pass
@@ -48,3 +50,25 @@ class RetryAfterError(Exception):
         super().__init__(str(original) if original else "RetryAfterError")
         self.retry_after = retry_after
         self.__cause__ = original
+
+
+class ExecutionTimeoutError(Exception):
+    """Raised when a query is stopped at its max execution time.
+
+    The results which were produced before the deadline are never cached as the results of the query. Instead, they're
+    attached to the error as `partial_result`, along with their `completeness`:
+    - `Runner.run` attaches the partition set of the partial results
+    - `DataFrame.collect` attaches a separate DataFrame of the partial results, leaving the collected DataFrame
+      unmaterialized
+    """
+
+    def __init__(
+        self,
+        max_execution_time: float,
+        partial_result: Any = None,
+        completeness: ExecutionCompleteness | None = None,
+    ) -> None:
+        super().__init__(f"Query stopped after reaching its max execution time of {max_execution_time}s")
+        self.max_execution_time = max_execution_time
+        self.partial_result = partial_result
+        self.completeness = completeness
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import asyncio
+import time
 from typing import TYPE_CHECKING
 
 from daft.daft import (
@@ -29,6 +31,7 @@ if TYPE_CHECKING:
 class NativeExecutor:
     def __init__(self) -> None:
         self._executor = _NativeExecutor()
+        self.timed_out = False
 
     def run(
         self,
@@ -37,7 +40,13 @@ class NativeExecutor:
         ctx: DaftContext,
         results_buffer_size: int | None,
         context: dict[str, str] | None,
+        deadline: float | None = None,
     ) -> Iterator[LocalMaterializedResult]:
+        """Runs a plan, yielding its results as they're produced.
+
+        If a deadline, as given by `time.monotonic()`, is set, the execution is cancelled once it passes and the
+        results end with the partitions produced so far. `timed_out` tells whether this happened.
+        """
         from daft.runners.partitioning import LocalMaterializedResult
 
         psets_mp = {
@@ -58,11 +67,24 @@ class NativeExecutor:
             finally:
                 _ = await result_handle.finish()
 
+        async def next_before_deadline() -> PyMicroPartition | None:
+            next_part = asyncio.ensure_future(async_exec.__anext__())
+            assert deadline is not None
+            done, _ = await asyncio.wait({next_part}, timeout=max(deadline - time.monotonic(), 0))
+            if not done:
+                # Cancelling ends the results, so a partition which was in flight is still returned.
+                self.timed_out = True
+                self._executor.cancel()
+            return await next_part
+
         event_loop = get_or_init_event_loop()
         async_exec = stream_results()
         try:
-            while True:
-                part = event_loop.run(async_exec.__anext__())
+            while not self.timed_out:
+                if deadline is None:
+                    part = event_loop.run(async_exec.__anext__())
+                else:
+                    part = event_loop.run(next_before_deadline())
                 if part is None:
                     break
                 yield LocalMaterializedResult(MicroPartition._from_pymicropartition(part))
@@ -81,5 +103,9 @@ class NativeExecutor:
             return _NativeExecutor.repr_ascii(builder._builder, daft_execution_config, simple)
         elif format == "mermaid":
             return _NativeExecutor.repr_mermaid(builder._builder, daft_execution_config, MermaidOptions(simple))
+        elif format == "dot":
+            return _NativeExecutor.repr_dot(builder._builder, daft_execution_config, simple)
+        elif format == "json":
+            return _NativeExecutor.repr_graph_json(builder._builder, daft_execution_config, simple)
         else:
             raise ValueError(f"Unknown format: {format}")
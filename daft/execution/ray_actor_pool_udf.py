@@ -1,9 +1,11 @@
 from __future__ import annotations
 
 import asyncio
+import logging
 import uuid
 from typing import TYPE_CHECKING, Any
 
+from daft.execution.udf import close_udfs, open_udfs
 from daft.expressions.expressions import Expression, ExpressionsProjection
 from daft.recordbatch.micropartition import MicroPartition
 from daft.runners.ray_compat import validate_and_normalize_ray_options
@@ -20,6 +22,10 @@ except ImportError:
 
 MAX_UDFACTOR_ACTOR_RESTARTS = 4
 MAX_UDFACTOR_ACTOR_TASK_RETRIES = 4
+# How long to wait for the UDFs of an actor to be torn down, before the actor is killed.
+UDFACTOR_TEARDOWN_TIMEOUT_SECONDS = 60
+
+logger = logging.getLogger(__name__)
 
 
 @ray.remote(
@@ -29,15 +35,24 @@ MAX_UDFACTOR_ACTOR_TASK_RETRIES = 4
 class UDFActor:
     def __init__(self, uninitialized_projection: ExpressionsProjection) -> None:
         self.projection = ExpressionsProjection([e._initialize_udfs() for e in uninitialized_projection])
+        self.opened = False
 
     def get_node_id(self) -> str:
         return ray.get_runtime_context().get_node_id()
 
     def eval_input(self, input: PyMicroPartition) -> PyMicroPartition:
+        if not self.opened:
+            open_udfs(self.projection)
+            self.opened = True
         mp = MicroPartition._from_pymicropartition(input)
         res = mp.eval_expression_list(self.projection)
         return res._micropartition
 
+    def teardown(self) -> None:
+        if self.opened:
+            close_udfs(self.projection)
+            self.opened = False
+
 
 class UDFActorHandle:
     def __init__(self, actor_ref: RayActorHandle) -> None:
@@ -50,6 +65,10 @@ class UDFActorHandle:
         return await self.actor.eval_input.remote(input)
 
     def teardown(self) -> None:
+        try:
+            ray.get(self.actor.teardown.remote(), timeout=UDFACTOR_TEARDOWN_TIMEOUT_SECONDS)
+        except Exception:
+            logger.exception("Error tearing down UDF actor %s", self.actor_id())
         ray.kill(self.actor)
 
 
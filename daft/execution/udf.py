@@ -28,6 +28,30 @@ _ERROR = "error"
 _OUTPUT_DIVIDER = b"_DAFT_OUTPUT_DIVIDER_\n"
 _SENTINEL = ("__EXIT__", 0)
 
+# Lifecycle hooks of the classes of UDFs, see `ClsBase` in `daft/udf/udf_v2.py`.
+_OPEN_PARTITION_HOOK = "_daft_open_partition"
+_CLOSE_PARTITION_HOOK = "_daft_close_partition"
+_TEARDOWN_HOOK = "_daft_teardown"
+
+
+def open_udfs(projection: ExpressionsProjection) -> None:
+    """Call the hooks of the UDFs in a projection before its first batch is evaluated."""
+    for expr in projection:
+        expr._call_udf_hook(_OPEN_PARTITION_HOOK)
+
+
+def close_udfs(projection: ExpressionsProjection) -> None:
+    """Call the hooks of the UDFs in a projection once they are done, after they were opened.
+
+    Errors are logged rather than raised, since all of the batches have already been evaluated.
+    """
+    for hook in (_CLOSE_PARTITION_HOOK, _TEARDOWN_HOOK):
+        for expr in projection:
+            try:
+                expr._call_udf_hook(hook)
+            except Exception:
+                logger.exception("Error calling the %s hook of UDF %s", hook, expr)
+
 
 class SharedMemoryTransport:
     def write_and_close(self, data: bytes) -> tuple[str, int]:
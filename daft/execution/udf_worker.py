@@ -19,6 +19,8 @@ from daft.execution.udf import (
     _SUCCESS,
     _UDF_ERROR,
     SharedMemoryTransport,
+    close_udfs,
+    open_udfs,
 )
 from daft.expressions.expressions import ExpressionsProjection
 from daft.recordbatch import RecordBatch
@@ -47,12 +49,15 @@ def udf_event_loop(
         while True:
             name, size = conn.recv()
             if (name, size) == _SENTINEL:
+                if expression_projection is not None:
+                    close_udfs(expression_projection)
                 break
 
             # We initialize after ready to avoid blocking the main thread
             if expression_projection is None:
                 uninitialized_projection: ExpressionsProjection = daft.pickle.loads(expr_projection_bytes)
                 expression_projection = ExpressionsProjection([e._initialize_udfs() for e in uninitialized_projection])
+                open_udfs(expression_projection)
 
             input_bytes = transport.read_and_release(name, size)
             input = RecordBatch.from_ipc_stream(input_bytes)
@@ -17,6 +17,7 @@ from daft.daft import (
     ImageMode,
     ImageProperty,
     ResourceRequest,
+    call_udf_hook,
     initialize_udfs,
     resolved_col,
     unresolved_col,
@@ -497,6 +498,16 @@ class Expression:
 
         return cast(self, dtype)
 
+    def try_cast(self, dtype: DataTypeLike, max_errors: int | None = None) -> Expression:
+        """Casts an expression to the given datatype, collecting the values which can't be cast instead of failing.
+
+        Tip: See Also
+            [`daft.functions.try_cast`](https://docs.daft.ai/en/stable/api/functions/try_cast/)
+        """
+        from daft.functions import try_cast
+
+        return try_cast(self, dtype, max_errors=max_errors)
+
     def ceil(self) -> Expression:
         """The ceiling of a numeric expression.
 
@@ -1398,6 +1409,9 @@ class Expression:
     def _initialize_udfs(self) -> Expression:
         return Expression._from_pyexpr(initialize_udfs(self._expr))
 
+    def _call_udf_hook(self, hook: builtins.str) -> None:
+        call_udf_hook(self._expr, hook)
+
     def parse_url(self) -> Expression:
         """Parse string URLs and extract URL components.
 
@@ -1938,6 +1952,7 @@ class Expression:
         lowercase: bool = False,
         nfd_unicode: bool = False,
         white_space: bool = False,
+        form: Literal["NFC", "NFD", "NFKC", "NFKD"] | None = None,
     ) -> Expression:
         """Normalizes a string for more useful deduplication.
 
@@ -1947,9 +1962,97 @@ class Expression:
         from daft.functions import normalize
 
         return normalize(
-            self, remove_punct=remove_punct, lowercase=lowercase, nfd_unicode=nfd_unicode, white_space=white_space
+            self,
+            remove_punct=remove_punct,
+            lowercase=lowercase,
+            nfd_unicode=nfd_unicode,
+            white_space=white_space,
+            form=form,
         )
 
+    def strip_accents(self) -> Expression:
+        """Removes accents and other combining marks from each string.
+
+        Tip: See Also
+            [`daft.functions.strip_accents`](https://docs.daft.ai/en/stable/api/functions/strip_accents/)
+        """
+        from daft.functions import strip_accents
+
+        return strip_accents(self)
+
+    def transliterate_to_ascii(self, replacement: builtins.str = "?") -> Expression:
+        """Transliterates each string to ASCII.
+
+        Tip: See Also
+            [`daft.functions.transliterate_to_ascii`](https://docs.daft.ai/en/stable/api/functions/transliterate_to_ascii/)
+        """
+        from daft.functions import transliterate_to_ascii
+
+        return transliterate_to_ascii(self, replacement)
+
+    def detect_pii(
+        self,
+        kinds: Literal["email", "phone", "credit_card", "ssn"]
+        | list[Literal["email", "phone", "credit_card", "ssn"]]
+        | None = None,
+    ) -> Expression:
+        """Detects personally identifiable information (PII) in each string.
+
+        Tip: See Also
+            [`daft.functions.detect_pii`](https://docs.daft.ai/en/stable/api/functions/detect_pii/)
+        """
+        from daft.functions import detect_pii
+
+        return detect_pii(self, kinds)
+
+    def redact_pii(
+        self,
+        kinds: Literal["email", "phone", "credit_card", "ssn"]
+        | list[Literal["email", "phone", "credit_card", "ssn"]]
+        | None = None,
+        strategy: Literal["tag", "mask", "remove"] = "tag",
+    ) -> Expression:
+        """Redacts personally identifiable information (PII) in each string.
+
+        Tip: See Also
+            [`daft.functions.redact_pii`](https://docs.daft.ai/en/stable/api/functions/redact_pii/)
+        """
+        from daft.functions import redact_pii
+
+        return redact_pii(self, kinds, strategy)
+
+    def length_graphemes(self) -> Expression:
+        """Retrieves the length of each string in grapheme clusters, i.e. user-perceived characters.
+
+        Tip: See Also
+            [`daft.functions.length_graphemes`](https://docs.daft.ai/en/stable/api/functions/length_graphemes/)
+        """
+        from daft.functions import length_graphemes
+
+        return length_graphemes(self)
+
+    def substr_graphemes(self, start: int, length: int | None = None) -> Expression:
+        """Extracts a substring of each string in grapheme clusters.
+
+        Tip: See Also
+            [`daft.functions.substr_graphemes`](https://docs.daft.ai/en/stable/api/functions/substr_graphemes/)
+        """
+        from daft.functions import substr_graphemes
+
+        return substr_graphemes(self, start, length)
+
+    def collation_key(
+        self, collation: Literal["binary", "case_insensitive", "accent_insensitive"] | builtins.str = "binary"
+    ) -> Expression:
+        """Returns the collation key of each string, for sorting, comparing, grouping or joining strings under a collation.
+
+        Tip: See Also
+            [`daft.functions.collation_key`](https://docs.daft.ai/en/stable/api/functions/collation_key/)
+        """
+        from daft.functions import collation_key
+
+        return collation_key(self, collation)
+
     def tokenize_encode(
         self,
         tokens_path: builtins.str,
@@ -2331,6 +2434,26 @@ class Expression:
 
         return list_append(self, other)
 
+    def list_contains(self, item: Any) -> Expression:
+        """Checks whether each list contains a value.
+
+        Tip: See Also
+            [`daft.functions.list_contains`](https://docs.daft.ai/en/stable/api/functions/list_contains/)
+        """
+        from daft.functions import list_contains
+
+        return list_contains(self, item)
+
+    def list_slice(self, start: int | Expression, end: int | Expression | None = None) -> Expression:
+        """Slices each list from `start` (inclusive) to `end` (exclusive).
+
+        Tip: See Also
+            [`daft.functions.list_slice`](https://docs.daft.ai/en/stable/api/functions/list_slice/)
+        """
+        from daft.functions import list_slice
+
+        return list_slice(self, start, end)
+
     def get(self, index: int | builtins.str | Expression, default: Any = None) -> Expression:
         """Get an index from a list expression or a field from a struct expression.
 
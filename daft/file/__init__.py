@@ -2,6 +2,6 @@ from __future__ import annotations
 
 from .file import File
 from .audio import AudioFile
-from .video import VideoFile
+from .video import VideoContainerError, VideoDecodeError, VideoError, VideoFile
 
-__all__ = ["AudioFile", "File", "VideoFile"]
+__all__ = ["AudioFile", "File", "VideoContainerError", "VideoDecodeError", "VideoError", "VideoFile"]
@@ -3,7 +3,7 @@ from __future__ import annotations
 from typing import TYPE_CHECKING, Any
 
 from daft.datatype import MediaType
-from daft.dependencies import librosa, np, sf
+from daft.dependencies import av, librosa, np, sf
 from daft.file import File
 from daft.file.typing import AudioMetadata
 
@@ -77,3 +77,79 @@ class AudioFile(File):
                 return resampled_data
             else:
                 return data
+
+    def waveform(self, sample_rate: int | None = None, mono: bool = False) -> np.ndarray[Any, np.dtype[np.float32]]:
+        """Decode the first audio stream of the file with FFmpeg into its samples.
+
+        Args:
+            sample_rate (int | None): The sample rate to resample the audio to as it's decoded. Defaults to None, which
+                keeps the sample rate of the file.
+            mono (bool): Whether to mix the channels down to a single channel. Defaults to False.
+
+        Returns:
+            np.ndarray: The samples as 32-bit floats between -1 and 1, with the shape `(channels, samples)`.
+
+        """
+        if not av.module_available():
+            raise ImportError("The 'av' module is required to decode audio files.")
+        if sample_rate is not None and sample_rate <= 0:
+            raise ValueError(f"sample_rate must be positive, got {sample_rate}")
+        with self.open() as f, av.open(f) as container:
+            if not container.streams.audio:
+                raise ValueError(f"No audio stream found in {self}")
+            stream = container.streams.audio[0]
+            # Planar floats decode into an array of `(channels, samples)` per frame.
+            resampler = av.AudioResampler(format="fltp", layout="mono" if mono else None, rate=sample_rate)
+            chunks = [
+                resampled.to_ndarray()
+                for frame in container.decode(stream)
+                for resampled in resampler.resample(frame)
+            ]
+            # Flushing the resampler returns the samples which it holds back.
+            chunks.extend(resampled.to_ndarray() for resampled in resampler.resample(None))
+        if not chunks:
+            channels = 1 if mono else stream.codec_context.channels
+            return np.zeros((channels, 0), dtype=np.float32)
+        return np.concatenate(chunks, axis=1).astype(np.float32, copy=False)
+
+    def spectrogram(
+        self, n_fft: int = 400, hop: int | None = None, sample_rate: int | None = None
+    ) -> np.ndarray[Any, np.dtype[np.float32]]:
+        """Compute the power spectrogram of the audio, mixed down to a single channel.
+
+        The audio is split into frames of `n_fft` samples every `hop` samples, each weighted by a Hann window, and the
+        power of each frequency in each frame is the squared magnitude of its Fourier transform. The audio is padded
+        with `n_fft // 2` zeros at both ends, so each frame is centered on its sample, like librosa's and torchaudio's
+        defaults.
+
+        Args:
+            n_fft (int): The number of samples in each frame. Defaults to 400.
+            hop (int | None): The number of samples between the starts of frames. Defaults to None, which is
+                `n_fft // 4`.
+            sample_rate (int | None): The sample rate to resample the audio to before it's transformed. Defaults to
+                None, which keeps the sample rate of the file.
+
+        Returns:
+            np.ndarray: The power of each frequency in each frame, with the shape `(n_fft // 2 + 1, frames)`.
+
+        """
+        if n_fft <= 0:
+            raise ValueError(f"n_fft must be positive, got {n_fft}")
+        hop = n_fft // 4 if hop is None else hop
+        if hop <= 0:
+            raise ValueError(f"hop must be positive, got {hop}")
+        signal = self.waveform(sample_rate=sample_rate, mono=True)[0]
+        return _power_spectrogram(signal, n_fft, hop)
+
+
+def _power_spectrogram(
+    signal: np.ndarray[Any, np.dtype[np.float32]], n_fft: int, hop: int
+) -> np.ndarray[Any, np.dtype[np.float32]]:
+    padded = np.pad(signal, (n_fft // 2, n_fft // 2))
+    if len(padded) < n_fft:
+        padded = np.pad(padded, (0, n_fft - len(padded)))
+    frames = np.lib.stride_tricks.sliding_window_view(padded, n_fft)[::hop]
+    # The periodic Hann window, whose overlapping frames sum to a constant.
+    window = np.hanning(n_fft + 1)[:-1].astype(np.float32)
+    spectrum = np.fft.rfft(frames * window, axis=-1)
+    return (np.abs(spectrum) ** 2).T.astype(np.float32)
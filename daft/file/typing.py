@@ -3,13 +3,43 @@ from __future__ import annotations
 from typing import TypedDict
 
 
+class VideoStreamInfo(TypedDict):
+    index: int
+    codec: str | None
+    width: int | None
+    height: int | None
+    fps: float | None
+    language: str | None
+
+
 class VideoMetadata(TypedDict):
     width: int | None
     height: int | None
     fps: float | None
     duration: float | None
     frame_count: int | None
+    frames_estimated: bool
     time_base: float | None
+    rotation: int | None
+    streams: list[VideoStreamInfo]
+
+
+class VideoChunk(TypedDict):
+    """A range of a video stream which starts at a keyframe, and so can be decoded without the frames before it."""
+
+    index: int
+    start_time: float
+    end_time: float | None
+    start_byte: int | None
+    end_byte: int | None
+    start_frame: int
+    frame_count: int
+
+
+class Subtitle(TypedDict):
+    start: float
+    end: float | None
+    text: str
 
 
 class AudioMetadata(TypedDict):
@@ -1,11 +1,16 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+import bisect
+import functools
+import re
+from contextlib import contextmanager
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any, ClassVar, Literal
 
 from daft.datatype import MediaType
-from daft.dependencies import av
+from daft.dependencies import av, np, pil_image
 from daft.file import File
-from daft.file.typing import VideoMetadata
+from daft.file.typing import Subtitle, VideoChunk, VideoMetadata, VideoStreamInfo
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
@@ -16,6 +21,117 @@ if TYPE_CHECKING:
     from daft.io import IOConfig
 
 
+class VideoError(Exception):
+    """An error reading a video, caused by the FFmpeg error which is its `__cause__`.
+
+    The subclasses tell whether the error is recoverable: a `VideoDecodeError` is raised while decoding part of a video,
+    which other parts of the video may still be decoded around, while a `VideoContainerError` means the video can't be
+    read at all.
+    """
+
+    recoverable: ClassVar[bool] = False
+    _action: ClassVar[str] = "Failed to read video"
+
+    def __init__(
+        self,
+        url: str,
+        reason: str,
+        code: int | None = None,
+        stream: int | None = None,
+        timestamp: float | None = None,
+    ) -> None:
+        location = ""
+        if stream is not None:
+            location += f" in stream {stream}"
+        if timestamp is not None:
+            location += f" at {timestamp:.3f}s"
+        super().__init__(f"{self._action} {url}{location}: {reason}" + (f" (error code {code})" if code else ""))
+        self.url = url
+        self.reason = reason
+        self.code = code
+        """The FFmpeg error code, which is a negated errno or an FFmpeg tag such as `AVERROR_INVALIDDATA`."""
+        self.stream = stream
+        """The index of the stream in the container which was being decoded, if any."""
+        self.timestamp = timestamp
+        """The time, in seconds, of the last frame decoded or seeked to before the error, if any."""
+
+
+class VideoContainerError(VideoError):
+    """An error opening or probing a video's container, such as a missing or unsupported file. It isn't recoverable."""
+
+    _action = "Failed to open video"
+
+
+class VideoDecodeError(VideoError):
+    """An error decoding a video's stream, such as a corrupt packet. Frames away from the error may still decode."""
+
+    recoverable = True
+    _action = "Failed to decode video"
+
+
+@dataclass
+class _ReadState:
+    """Where a video is being read, to describe the errors of reading it."""
+
+    stream: int | None = None
+    timestamp: float | None = None
+    decoding: bool = False
+
+    def decode(self, stream: av.stream.Stream) -> None:
+        self.stream = stream.index
+        self.decoding = True
+
+    def track(self, frames: Iterator[av.VideoFrame]) -> Iterator[av.VideoFrame]:
+        for frame in frames:
+            if frame.time is not None:
+                self.timestamp = frame.time
+            yield frame
+
+    def error(self, url: str, e: av.FFmpegError) -> VideoError:
+        """Describe an FFmpeg error raised while reading the video at a URL."""
+        error_type = VideoDecodeError if self.decoding else VideoContainerError
+        return error_type(url, e.strerror or str(e), code=e.errno, stream=self.stream, timestamp=self.timestamp)
+
+
+# The options with which FFmpeg finds the parameters of a container's streams from its headers, decoding no frames:
+# the packets it analyzes are capped at 1 microsecond of the streams, and the frames it finds frame rates from at none.
+_PROBE_OPTIONS = {"analyzeduration": "1", "fpsprobesize": "0"}
+
+# The fewest bytes FFmpeg probes the format of a container from.
+_MIN_PROBE_SIZE = 32
+
+
+class _PrefetchedFile:
+    """A video file whose first bytes are read in one request, which the reads within them are served from."""
+
+    def __init__(self, file: Any, size: int) -> None:
+        self._file = file
+        self._prefix = file.read(size)
+        self._position = 0
+
+    def read(self, size: int = -1) -> bytes:
+        end = self._position + size
+        if size >= 0 and end <= len(self._prefix):
+            data = self._prefix[self._position : end]
+        else:
+            self._file.seek(self._position)
+            data = self._file.read(size)
+        self._position += len(data)
+        return data
+
+    def seek(self, offset: int, whence: int = 0) -> int:
+        if whence == 0:
+            self._position = offset
+        elif whence == 1:
+            self._position += offset
+        else:
+            self._position = self._file.seek(offset, whence)
+        return self._position
+
+    def tell(self) -> int:
+        return self._position
+
+
 class VideoFile(File):
     """A video-specific file interface that provides video operations."""
 
@@ -34,89 +150,634 @@ class VideoFile(File):
         if not self.is_video():
             raise ValueError(f"File {self} is not a video file")
 
-    def metadata(self) -> VideoMetadata:
+    @contextmanager
+    def _read(
+        self, prefetch: int | None = None, **options: Any
+    ) -> Iterator[tuple[av.container.InputContainer, _ReadState]]:
+        """Open the video's container, turning the FFmpeg errors of reading it into `VideoError`s.
+
+        If `prefetch` is set, that many bytes from the start of the video are read in one request, and the reads
+        within them are served from memory.
+        """
+        state = _ReadState()
+        try:
+            with self.open() as f:
+                source = _PrefetchedFile(f, prefetch) if prefetch is not None else f
+                with av.open(source, mode="r", **options) as container:
+                    yield container, state
+        except av.FFmpegError as e:
+            raise state.error(self._inner.url, e) from e
+
+    def metadata(
+        self, stream: int | str | None = None, count_frames: bool = False, probe_size: int | None = None
+    ) -> VideoMetadata:
         """Extract basic video metadata from container headers.
 
+        Headers often lack the frame count of fragmented MP4 and variable frame rate videos, in which case the frame
+        count is estimated from the duration and frame rate, and `frames_estimated` is True.
+
+        Args:
+            stream: The video stream to describe, by its index among the video streams of the video or by its language
+                tag. Defaults to the first video stream.
+            count_frames: Whether to count the frames of the stream by reading its packets, without decoding them,
+                rather than trusting the headers. The fps of a variable frame rate stream is then its average frame
+                rate over the timestamps of its frames. Defaults to False.
+            probe_size: If set, only probe the container: read this many bytes from the start of the video in one
+                request, which FFmpeg probes the container's format and packets within, and find the parameters of
+                the streams from the container's headers without decoding any frames, rather than letting FFmpeg
+                decode the first frames of each stream to fill in the parameters which the headers lack. The metadata
+                of a remote video whose headers fit in its first `probe_size` bytes, such as an MP4 whose moov atom
+                comes first, is then read with a single request; the rest of the video is still read as needed, such
+                as the headers at the end of other MP4s. Can't be used with `count_frames`. Defaults to None.
+
         Returns:
-            VideoMetadata: Video metadata object containing width, height, fps, frame_count, time_base, keyframe_pts, keyframe_indices
+            VideoMetadata: Video metadata object containing width, height, fps, frame_count, frames_estimated,
+                time_base and rotation of the stream, and the video streams of the video, which can be selected by
+                their index. The width and height are of the frames as stored, before they are rotated to be displayed.
 
         """
-        with self.open() as f:
-            with av.open(f, mode="r", metadata_encoding="utf-8") as container:
-                video = next(
-                    (stream for stream in container.streams if stream.type == "video"),
-                    None,
+        options: dict[str, Any] = {}
+        if probe_size is not None:
+            if count_frames:
+                raise ValueError("`count_frames` can't be used with `probe_size`, which doesn't read the packets")
+            if probe_size <= 0:
+                raise ValueError(f"`probe_size` must be positive, got {probe_size}")
+            options["prefetch"] = probe_size
+            options["container_options"] = {**_PROBE_OPTIONS, "probesize": str(max(probe_size, _MIN_PROBE_SIZE))}
+        with self._read(metadata_encoding="utf-8", **options) as (container, _):
+            streams = [_stream_info(index, video) for index, video in enumerate(_video_streams(container))]
+            if not streams and stream is None:
+                return VideoMetadata(
+                    width=None,
+                    height=None,
+                    fps=None,
+                    duration=None,
+                    frame_count=None,
+                    frames_estimated=False,
+                    time_base=None,
+                    rotation=None,
+                    streams=streams,
                 )
-                if video is None:
-                    return VideoMetadata(
-                        width=None,
-                        height=None,
-                        fps=None,
-                        duration=None,
-                        frame_count=None,
-                        time_base=None,
-                    )
-
-                # Basic stream properties ----------
-                width = video.width
-                height = video.height
-                time_base = float(video.time_base) if video.time_base else None
-
-                # Frame rate -----------------------
-                fps = None
-                if video.average_rate:
-                    fps = float(video.average_rate)
-                elif video.guessed_rate:
-                    fps = float(video.guessed_rate)
-
-                # Duration -------------------------
-                duration = None
-                if container.duration and container.duration > 0:
-                    duration = container.duration / 1_000_000.0
-                elif video.duration:
-                    # Fallback time_base only for duration computation if missing
-                    tb_for_dur = float(video.time_base) if video.time_base else (1.0 / 1_000_000.0)
-                    duration = float(video.duration * tb_for_dur)
-
-                # Frame count -----------------------
-                frame_count = video.frames
-                if not frame_count or frame_count <= 0:
-                    if duration and fps:
-                        frame_count = int(round(duration * fps))
+            video = _video_stream(container, stream)
+
+            # Basic stream properties ----------
+            width = video.width
+            height = video.height
+            time_base = float(video.time_base) if video.time_base else None
+
+            # Frame rate -----------------------
+            fps = None
+            if video.average_rate:
+                fps = float(video.average_rate)
+            elif video.guessed_rate:
+                fps = float(video.guessed_rate)
+
+            # Duration -------------------------
+            duration = None
+            if container.duration and container.duration > 0:
+                duration = container.duration / 1_000_000.0
+            elif video.duration:
+                # Fallback time_base only for duration computation if missing
+                tb_for_dur = float(video.time_base) if video.time_base else (1.0 / 1_000_000.0)
+                duration = float(video.duration * tb_for_dur)
+
+            # Frame count -----------------------
+            frame_count = video.frames if video.frames and video.frames > 0 else None
+            frames_estimated = False
+            if count_frames:
+                frame_count, variable_fps = _count_frames(container, video)
+                if variable_fps is not None:
+                    fps = variable_fps
+            elif frame_count is None and duration and fps:
+                frame_count = int(round(duration * fps))
+                frames_estimated = True
+
+            return VideoMetadata(
+                width=width,
+                height=height,
+                fps=fps,
+                duration=duration,
+                frame_count=frame_count,
+                frames_estimated=frames_estimated,
+                time_base=time_base,
+                rotation=_rotation(video),
+                streams=streams,
+            )
+
+    def keyframes(
+        self,
+        start_time: float = 0,
+        end_time: float | None = None,
+        stream: int | str | None = None,
+        autorotate: bool = True,
+    ) -> Iterator[PIL.Image.Image]:
+        """Lazy iterator of keyframes as PIL Images within time range.
+
+        The keyframes are of the given video stream, by its index among the video streams of the video or by its
+        language tag, and of the first video stream by default. Unless `autorotate` is False, keyframes are rotated as
+        the video is displayed, such as the videos recorded by phones held upright.
+        """
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            # Seek to start time
+            if start_time > 0:
+                container.seek(_to_pts(start_time, video), stream=video)
+
+            # skip non keyframes
+            video.codec_context.skip_frame = "NONKEY"
+            for frame in state.track(container.decode(video)):
+                # Not every decoder honors `skip_frame`.
+                if not frame.key_frame:
+                    continue
+                # Check end time if specified
+                if end_time is not None:
+                    frame_time = frame.time
+                    if frame_time and frame_time > end_time:
+                        break
+
+                image = frame.to_image()
+                yield _rotate_image(image, _rotation(video, frame)) if autorotate else image
+
+    def frame_at(self, timestamp: float, stream: int | str | None = None, autorotate: bool = True) -> PIL.Image.Image:
+        """Decode the frame shown at a time, in seconds, without decoding the frames before it.
+
+        This seeks to the keyframe at or before the time, which discards the state of the decoder, and decodes forward
+        from the keyframe to the frame at the time. Like [`keyframes`][daft.VideoFile.keyframes], the frame is of the
+        first video stream unless another `stream` is given, and is rotated as it's displayed unless `autorotate` is
+        False.
+        """
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            state.timestamp = timestamp
+            frame = _decode_frame_at(container, video, timestamp)
+            return _rotate_image(frame.to_image(), _rotation(video, frame)) if autorotate else frame.to_image()
+
+    def frame_at_index(self, index: int, stream: int | str | None = None, autorotate: bool = True) -> PIL.Image.Image:
+        """Decode the frame at an index, without decoding the frames before it.
+
+        The time of the frame is found from the frame rate of the video, so this is exact for constant frame rate
+        videos. Like [`frame_at`][daft.VideoFile.frame_at], this seeks to the keyframe at or before the frame.
+        """
+        if index < 0:
+            raise ValueError(f"Frame index must be non-negative, got {index}")
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            rate = video.average_rate or video.guessed_rate
+            if not rate:
+                raise ValueError("Cannot seek to a frame index in a video without a frame rate")
+            start = float(video.start_time * video.time_base) if video.start_time else 0.0
+            state.decode(video)
+            state.timestamp = start + index / float(rate)
+            frame = _decode_frame_at(container, video, state.timestamp)
+            return _rotate_image(frame.to_image(), _rotation(video, frame)) if autorotate else frame.to_image()
+
+    def iter_frames(self, stream: int | str | None = None, autorotate: bool = True) -> Iterator[PIL.Image.Image]:
+        """Decode the frames of the video one at a time, in presentation order.
+
+        The video is read and decoded as the iterator is advanced, so a long video can be consumed without holding all
+        of its frames in memory, and without an event loop. The video is closed once the iterator is exhausted or
+        closed, and FFmpeg errors are raised from the iterator as `VideoError`s.
+
+        Args:
+            stream: The video stream to decode, by its index among the video streams of the video or by its language
+                tag. Defaults to the first video stream.
+            autorotate: Whether to rotate the frames as the video is displayed. Defaults to True.
+        """
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            for frame in state.track(container.decode(video)):
+                image = frame.to_image()
+                yield _rotate_image(image, _rotation(video, frame)) if autorotate else image
+
+    def extract_frames(
+        self,
+        every_n: int | None = None,
+        fps: float | None = None,
+        timestamps: list[float] | None = None,
+        stream: int | str | None = None,
+        autorotate: bool = True,
+    ) -> list[PIL.Image.Image]:
+        """Decode a sample of the frames of the video, chosen by exactly one of `every_n`, `fps` or `timestamps`.
+
+        Args:
+            every_n: Extract every nth frame, starting from the first frame.
+            fps: Extract frames at this rate, in frames per second, by taking the first frame at or after each time
+                which is a multiple of `1 / fps` seconds from the first frame.
+            timestamps: Extract the frame shown at each of these times, in seconds, in the given order. Each frame is
+                found by seeking like [`frame_at`][daft.VideoFile.frame_at].
+            stream: The video stream to extract frames of, by its index among the video streams of the video or by its
+                language tag. Defaults to the first video stream.
+            autorotate: Whether to rotate the frames as the video is displayed. Defaults to True.
+        """
+        if sum(arg is not None for arg in (every_n, fps, timestamps)) != 1:
+            raise ValueError("Exactly one of every_n, fps or timestamps must be given to extract frames")
+        if every_n is not None and every_n < 1:
+            raise ValueError(f"every_n must be positive, got {every_n}")
+        if fps is not None and fps <= 0:
+            raise ValueError(f"fps must be positive, got {fps}")
+
+        images = []
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            if timestamps is not None:
+                frames: Iterator[av.VideoFrame] = state.track(_decode_frame_at(container, video, t) for t in timestamps)
+            else:
+                frames = _sample_frames(state.track(container.decode(video)), every_n, fps)
+            for frame in frames:
+                image = frame.to_image()
+                images.append(_rotate_image(image, _rotation(video, frame)) if autorotate else image)
+        return images
+
+    def chunks(
+        self,
+        target_duration: float | None = None,
+        target_size: int | None = None,
+        stream: int | str | None = None,
+    ) -> list[VideoChunk]:
+        """Split a video stream into chunks of whole groups of pictures, which can each be decoded independently.
+
+        The packets of the stream are read without being decoded, to find its keyframes. A chunk starts at a keyframe
+        and ends at the keyframe which starts the next chunk, once the chunk is at least `target_duration` seconds or
+        `target_size` bytes long, so the chunks of a video can be decoded in parallel, by opening the video and seeking
+        to the start of each chunk, which reads the header of the container and then the byte range of the chunk.
+
+        Args:
+            target_duration: The duration, in seconds, which each chunk is at least, except for the last chunk.
+            target_size: The size, in bytes, which each chunk is at least, except for the last chunk.
+            stream: The video stream to split, by its index among the video streams of the video or by its language
+                tag. Defaults to the first video stream.
+
+        Returns:
+            list[VideoChunk]: The chunks, in order. The frames of a chunk are the frames shown from its start time
+                until its end time, which is None for the last chunk, and the index of its first frame is its
+                start_frame. The byte ranges are of the packets of the chunk in the file, if they're known.
+        """
+        if target_duration is None and target_size is None:
+            raise ValueError("Either target_duration or target_size must be given to split a video into chunks")
+        if target_duration is not None and target_duration <= 0:
+            raise ValueError(f"target_duration must be positive, got {target_duration}")
+        if target_size is not None and target_size <= 0:
+            raise ValueError(f"target_size must be positive, got {target_size}")
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            return _chunks(container, video, target_duration, target_size)
+
+    def subtitles(self, stream_index: int | None = None) -> list[Subtitle]:
+        """Extract the subtitles of a subtitle stream embedded in the video, such as an SRT, ASS or mov_text stream.
+
+        Each subtitle has the times, in seconds, at which it starts and ends being shown, and its text without any
+        formatting. The end is None if it isn't known.
+
+        Args:
+            stream_index: The index of the subtitle stream among the subtitle streams of the video. Defaults to the
+                first subtitle stream. A video without subtitle streams has no subtitles.
+        """
+        subtitles: list[Subtitle] = []
+        with self._read() as (container, state):
+            streams = container.streams.subtitles
+            if stream_index is None and not streams:
+                return subtitles
+            if stream_index is not None and not 0 <= stream_index < len(streams):
+                raise ValueError(f"Subtitle stream {stream_index} not found, the video has {len(streams)}")
+            stream = streams[stream_index or 0]
+            state.decode(stream)
+            time_base = stream.time_base
+            for packet in container.demux(stream):
+                if packet.pts is None or time_base is None:
+                    continue
+                packet_time = float(packet.pts * time_base)
+                state.timestamp = packet_time
+                for subtitle_set in packet.decode():
+                    start = packet_time + subtitle_set.start_display_time / 1000
+                    if subtitle_set.end_display_time:
+                        end = packet_time + subtitle_set.end_display_time / 1000
+                    elif packet.duration:
+                        end = packet_time + float(packet.duration * time_base)
                     else:
-                        frame_count = None
+                        end = None
+                    text = "\n".join(filter(None, (_subtitle_text(subtitle) for subtitle in subtitle_set)))
+                    if text:
+                        subtitles.append(Subtitle(start=start, end=end, text=text))
+        return subtitles
 
-                return VideoMetadata(
-                    width=width,
-                    height=height,
-                    fps=fps,
-                    duration=duration,
-                    frame_count=frame_count,
-                    time_base=time_base,
-                )
+    def scene_changes(self, threshold: float = 0.3, stream: int | str | None = None) -> list[float]:
+        """Detect the times, in seconds, of the frames which start a new scene.
 
-    def keyframes(self, start_time: float = 0, end_time: float | None = None) -> Iterator[PIL.Image.Image]:
-        """Lazy iterator of keyframes as PIL Images within time range."""
-        with self.open() as f:
-            with av.open(f) as container:
-                video = next(
-                    (stream for stream in container.streams if stream.type == "video"),
-                    None,
-                )
-                if video is None:
-                    raise ValueError("No video stream found")
-                # Seek to start time
-                if start_time > 0:
-                    seek_timestamp = int(start_time * video.time_base)
-                    container.seek(seek_timestamp)
-
-                # skip non keyframes
-                video.codec_context.skip_frame = "NONKEY"
-                for frame in container.decode(video):
-                    # Check end time if specified
-                    if end_time is not None:
-                        frame_time = frame.time
-                        if frame_time and frame_time > end_time:
-                            break
-
-                    yield frame.to_image()
+        Each frame is compared with the frame before it by the mean absolute difference of their pixels, on small
+        grayscale versions of the frames, which is scaled to between 0 and 1. A frame whose difference is above the
+        threshold is the start of a new scene, like the scene score of ffmpeg's `select` filter.
+        """
+        if not 0 <= threshold <= 1:
+            raise ValueError(f"Scene change threshold must be between 0 and 1, got {threshold}")
+        times = []
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            previous = None
+            for frame in state.track(container.decode(video)):
+                small = frame.reformat(width=_SCENE_FRAME_SIZE, height=_SCENE_FRAME_SIZE, format="gray")
+                pixels = small.to_ndarray().astype(np.float32)
+                if previous is not None and frame.time is not None:
+                    score = float(np.abs(pixels - previous).mean()) / 255
+                    if score > threshold:
+                        times.append(frame.time)
+                previous = pixels
+        return times
+
+    def phash_frames(
+        self, every_n: int = 1, method: Literal["dhash", "phash"] = "dhash", stream: int | str | None = None
+    ) -> list[int]:
+        """Compute a 64-bit perceptual hash of every nth frame, starting from the first frame.
+
+        Each frame is scaled down to a small grayscale version by FFmpeg as it's decoded, so no images are created.
+        Similar frames have hashes which differ in few bits, so near-duplicate frames can be found by the Hamming
+        distance between their hashes.
+
+        Args:
+            every_n: Hash every nth frame. Defaults to 1, which hashes every frame.
+            method: `"dhash"` sets each bit by whether a pixel is brighter than the pixel to its right, on a 9x8
+                version of the frame. `"phash"` sets each bit by whether a low frequency of a discrete cosine
+                transform of a 32x32 version of the frame is above their median, which is more robust to changes of
+                brightness and contrast but slower. Defaults to `"dhash"`.
+            stream: The video stream to hash the frames of, by its index among the video streams of the video or by
+                its language tag. Defaults to the first video stream.
+        """
+        if every_n < 1:
+            raise ValueError(f"every_n must be positive, got {every_n}")
+        if method not in ("dhash", "phash"):
+            raise ValueError(f"Perceptual hash method must be 'dhash' or 'phash', got {method!r}")
+        hashes = []
+        with self._read() as (container, state):
+            video = _video_stream(container, stream)
+            state.decode(video)
+            for frame in _sample_frames(state.track(container.decode(video)), every_n, None):
+                hashes.append(_dhash(frame) if method == "dhash" else _phash(frame))
+        return hashes
+
+
+# ASS override codes such as `{\i1}`, which format the text of a subtitle.
+_ASS_OVERRIDE = re.compile(r"\{[^}]*\}")
+
+
+def _subtitle_text(subtitle: Any) -> str:
+    """Get the text of a decoded subtitle, without formatting. Bitmap subtitles have no text."""
+    kind = subtitle.type.decode() if isinstance(subtitle.type, bytes) else subtitle.type
+    if kind == "text":
+        return bytes(subtitle.text).decode("utf-8", errors="replace").strip()
+    if kind == "ass":
+        line = bytes(subtitle.ass).decode("utf-8", errors="replace")
+        # Decoded ASS events are `ReadOrder,Layer,Style,Name,MarginL,MarginR,MarginV,Effect,Text`, and older versions
+        # of FFmpeg prefix them with `Dialogue: `, which adds a field.
+        fields = 10 if line.startswith("Dialogue:") else 9
+        text = line.split(",", fields - 1)[-1]
+        return _ASS_OVERRIDE.sub("", text).replace("\\N", "\n").replace("\\n", "\n").strip()
+    return ""
+
+
+# The size frames are scaled down to when they are compared, which ignores noise and small movements between frames.
+_SCENE_FRAME_SIZE = 64
+
+
+# The number of bits on each side of the square of bits of a perceptual hash, which has 64 bits.
+_HASH_SIZE = 8
+# The size frames are scaled down to for the discrete cosine transform of a pHash, of which the lowest frequencies are
+# kept.
+_PHASH_FRAME_SIZE = 32
+
+
+def _hash_bits(bits: np.ndarray[Any, Any]) -> int:
+    """Pack a square of bits into an integer, with the first bit as the most significant."""
+    return int.from_bytes(np.packbits(bits.flatten()).tobytes(), "big")
+
+
+def _dhash(frame: av.VideoFrame) -> int:
+    # One more column than bits, so each pixel is compared with the pixel to its right.
+    small = frame.reformat(width=_HASH_SIZE + 1, height=_HASH_SIZE, format="gray")
+    pixels = small.to_ndarray().astype(np.int16)
+    return _hash_bits(pixels[:, :-1] > pixels[:, 1:])
+
+
+@functools.cache
+def _dct_matrix(n: int) -> np.ndarray[Any, Any]:
+    """The orthonormal DCT-II matrix, which transforms the columns of a matrix it's multiplied with."""
+    k = np.arange(n)[:, None]
+    i = np.arange(n)[None, :]
+    matrix = np.sqrt(2 / n) * np.cos(np.pi * (2 * i + 1) * k / (2 * n))
+    matrix[0] /= np.sqrt(2)
+    return matrix
+
+
+def _phash(frame: av.VideoFrame) -> int:
+    small = frame.reformat(width=_PHASH_FRAME_SIZE, height=_PHASH_FRAME_SIZE, format="gray")
+    pixels = small.to_ndarray().astype(np.float64)
+    dct = _dct_matrix(_PHASH_FRAME_SIZE)
+    low = (dct @ pixels @ dct.T)[:_HASH_SIZE, :_HASH_SIZE]
+    # The first coefficient is the mean brightness of the frame, which would skew the median of the others.
+    return _hash_bits(low > np.median(low.flatten()[1:]))
+
+
+def _video_streams(container: av.container.InputContainer) -> list[av.video.stream.VideoStream]:
+    return [stream for stream in container.streams if stream.type == "video"]
+
+
+def _video_stream(
+    container: av.container.InputContainer, stream: int | str | None = None
+) -> av.video.stream.VideoStream:
+    """Select a video stream by its index among the video streams, or by its language tag, or the first by default.
+
+    Containers with several video streams, such as multi-angle videos, tag each stream with its language.
+    """
+    videos = _video_streams(container)
+    if not videos:
+        raise ValueError("No video stream found")
+    if stream is None:
+        return videos[0]
+    if isinstance(stream, int):
+        if not 0 <= stream < len(videos):
+            raise ValueError(f"Video stream {stream} not found, the video has {len(videos)}")
+        return videos[stream]
+    video = next((video for video in videos if video.metadata.get("language") == stream), None)
+    if video is None:
+        languages = [video.metadata.get("language") for video in videos]
+        raise ValueError(f"No video stream with language {stream!r} found, the video streams have {languages}")
+    return video
+
+
+def _stream_info(index: int, video: av.video.stream.VideoStream) -> VideoStreamInfo:
+    rate = video.average_rate or video.guessed_rate
+    return VideoStreamInfo(
+        index=index,
+        codec=video.codec_context.name if video.codec_context else None,
+        width=video.width or None,
+        height=video.height or None,
+        fps=float(rate) if rate else None,
+        language=video.metadata.get("language"),
+    )
+
+
+def _rotation(video: av.video.stream.VideoStream, frame: av.VideoFrame | None = None) -> int:
+    """Get the clockwise rotation, in degrees, with which the frames of a video stream are displayed.
+
+    Phones store the frames of videos recorded upright as they are captured by the sensor, sideways, with a display
+    matrix which rotates them when they are shown. Older files have a `rotate` tag instead. The display matrix is read
+    from the stream, or from a decoded frame if the stream doesn't have it, and is a counterclockwise rotation.
+    """
+    clockwise = 0.0
+    side_data = getattr(video, "side_data", None) or {}
+    if "DISPLAYMATRIX" in side_data:
+        clockwise = -float(side_data["DISPLAYMATRIX"])
+    elif "rotate" in video.metadata:
+        try:
+            clockwise = float(video.metadata["rotate"])
+        except ValueError:
+            clockwise = 0.0
+    elif frame is not None and getattr(frame, "rotation", None):
+        clockwise = -float(frame.rotation)
+    # Only rotations by right angles are supported, like FFmpeg's autorotation.
+    return round(clockwise / 90) % 4 * 90
+
+
+# The transposes of PIL images which undo the clockwise rotations of videos.
+_ROTATE_TRANSPOSES = {90: "ROTATE_270", 180: "ROTATE_180", 270: "ROTATE_90"}
+
+
+def _rotate_image(image: PIL.Image.Image, rotation: int) -> PIL.Image.Image:
+    """Rotate an image clockwise by a right angle. The rotations of PIL's transposes are counterclockwise."""
+    if rotation not in _ROTATE_TRANSPOSES:
+        return image
+    return image.transpose(getattr(pil_image.Transpose, _ROTATE_TRANSPOSES[rotation]))
+
+
+def _to_pts(timestamp: float, video: av.video.stream.VideoStream) -> int:
+    """Convert a time in seconds to a timestamp in the time base of a stream."""
+    return int(timestamp / video.time_base)
+
+
+def _count_frames(
+    container: av.container.InputContainer, video: av.video.stream.VideoStream
+) -> tuple[int, float | None]:
+    """Count the frames of a video stream by reading its packets, without decoding them.
+
+    Returns the number of frames, and the average frame rate of the stream over the timestamps of its frames if its
+    frame rate is variable, or None if it's constant.
+    """
+    count = 0
+    timestamps = []
+    for packet in container.demux(video):
+        # The packets which flush the demuxer are empty.
+        if packet.size == 0:
+            continue
+        count += 1
+        if packet.pts is not None:
+            timestamps.append(packet.pts)
+    timestamps.sort()
+
+    if len(timestamps) < 3 or not video.time_base:
+        return count, None
+    intervals = [after - before for before, after in zip(timestamps, timestamps[1:])]
+    # Timestamps are rounded to the time base, so the intervals of a constant frame rate can differ by a tick.
+    if max(intervals) - min(intervals) <= 1:
+        return count, None
+    span = float((timestamps[-1] - timestamps[0]) * video.time_base)
+    return count, (len(timestamps) - 1) / span
+
+
+def _chunks(
+    container: av.container.InputContainer,
+    video: av.video.stream.VideoStream,
+    target_duration: float | None,
+    target_size: int | None,
+) -> list[VideoChunk]:
+    # The time of every frame, which counts the frames before each chunk, and the time and position of each keyframe.
+    times = []
+    keyframes = []
+    for packet in container.demux(video):
+        # The packets which flush the demuxer have no timestamps.
+        if packet.pts is None:
+            continue
+        time = float(packet.pts * video.time_base)
+        times.append(time)
+        if packet.is_keyframe:
+            keyframes.append((time, packet.pos if packet.pos is not None and packet.pos >= 0 else None))
+    times.sort()
+
+    starts: list[tuple[float, int | None]] = []
+    for time, pos in keyframes:
+        if starts:
+            start_time, start_pos = starts[-1]
+            long_enough = target_duration is not None and time - start_time >= target_duration
+            big_enough = (
+                target_size is not None and pos is not None and start_pos is not None and pos - start_pos >= target_size
+            )
+            if not (long_enough or big_enough):
+                continue
+        starts.append((time, pos))
+
+    if starts and times[0] < starts[0][0]:
+        # The first chunk also has the frames shown before the first keyframe, which are decoded from it.
+        starts[0] = (times[0], starts[0][1])
+
+    chunks = []
+    for index, (start_time, start_byte) in enumerate(starts):
+        end_time, end_byte = starts[index + 1] if index + 1 < len(starts) else (None, None)
+        start_frame = bisect.bisect_left(times, start_time)
+        end_frame = bisect.bisect_left(times, end_time) if end_time is not None else len(times)
+        chunks.append(
+            VideoChunk(
+                index=index,
+                start_time=start_time,
+                end_time=end_time,
+                start_byte=start_byte,
+                end_byte=end_byte,
+                start_frame=start_frame,
+                frame_count=end_frame - start_frame,
+            )
+        )
+    return chunks
+
+
+def _sample_frames(frames: Iterator[av.VideoFrame], every_n: int | None, fps: float | None) -> Iterator[av.VideoFrame]:
+    """Select every nth frame, or the first frame at or after each sample time at a rate of `fps`."""
+    interval = 1 / fps if fps is not None else 0.0
+    next_time = None
+    for index, frame in enumerate(frames):
+        if every_n is not None:
+            if index % every_n == 0:
+                yield frame
+            continue
+        if frame.time is None:
+            continue
+        if next_time is None:
+            next_time = frame.time
+        # Allow for frame times which are rounded from the sample times.
+        if frame.time + 1e-6 >= next_time:
+            yield frame
+            # A frame which is shown for longer than the sample interval covers several sample times.
+            while next_time <= frame.time + 1e-6:
+                next_time += interval
+
+
+def _decode_frame_at(
+    container: av.container.InputContainer, video: av.video.stream.VideoStream, timestamp: float
+) -> av.VideoFrame:
+    # Seeking backward lands on the keyframe at or before the timestamp, which the frames after it are decoded from.
+    container.seek(_to_pts(timestamp, video), stream=video, backward=True)
+    # Allow for timestamps which are rounded from the exact time of a frame.
+    tolerance = float(video.time_base) / 2
+    previous = None
+    for frame in container.decode(video):
+        if frame.time is None or frame.time + tolerance >= timestamp:
+            # A frame after the timestamp means the timestamp falls within the previous frame.
+            if previous is not None and frame.time is not None and frame.time - tolerance > timestamp:
+                return previous
+            return frame
+        previous = frame
+    if previous is None:
+        raise ValueError(f"No frame found at {timestamp}s")
+    # The timestamp is after the start of the last frame.
+    return previous
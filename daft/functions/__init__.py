@@ -21,7 +21,20 @@ from .agg import (
     string_agg,
     product,
 )
-from .binary import encode, try_encode, compress, try_compress, decode, try_decode, decompress, try_decompress
+from .binary import (
+    encode,
+    try_encode,
+    compress,
+    try_compress,
+    decode,
+    try_decode,
+    decompress,
+    try_decompress,
+    detect_encoding,
+    decode_text,
+    extract_pdf_text,
+    archive_members,
+)
 from .bitwise import bitwise_and, bitwise_or, bitwise_xor, shift_left, shift_right
 from .columnar import (
     columns_sum,
@@ -63,7 +76,7 @@ from .datetime import (
 )
 from .embedding import cosine_distance
 
-from .file_ import file, file_size, video_file, audio_file
+from .file_ import file, file_size, video_file, audio_file, blob_file, blob_read
 
 from .image import (
     resize,
@@ -94,6 +107,8 @@ from .list import (
     list_map,
     explode,
     list_append,
+    list_contains,
+    list_slice,
     to_list,
 )
 from .llm import llm_generate
@@ -101,6 +116,7 @@ from .misc import (
     monotonically_increasing_id,
     eq_null_safe,
     cast,
+    try_cast,
     is_null,
     not_null,
     fill_null,
@@ -112,6 +128,8 @@ from .misc import (
     coalesce,
     get,
     map_get,
+    lookup,
+    map_lookup,
     slice,
     when,
 )
@@ -173,6 +191,7 @@ from .str import (
     jq,
     format,
     contains,
+    contains_any,
     split,
     lower,
     upper,
@@ -191,6 +210,16 @@ from .str import (
     endswith,
     startswith,
     normalize,
+    collation_key,
+    detect_pii,
+    redact_pii,
+    detect_language,
+    html_to_text,
+    markdown_to_text,
+    strip_accents,
+    transliterate_to_ascii,
+    length_graphemes,
+    substr_graphemes,
     tokenize_decode,
     tokenize_encode,
     count_matches,
@@ -204,10 +233,21 @@ from .str import (
     regexp_replace,
     find,
 )
+from .encoding import one_hot, feature_hash, fold_assign, label_encode, LabelEncoder
 from .struct import unnest, to_struct
 from .url import download, upload, parse_url
-from .audio import audio_metadata, resample
-from .video import video_metadata, video_keyframes
+from .audio import audio_metadata, audio_spectrogram, audio_waveform, resample
+from .video import (
+    encode_video,
+    video_extract_frames,
+    video_extract_subtitles,
+    video_frame_at,
+    video_metadata,
+    video_keyframes,
+    video_phash_frames,
+    video_scene_changes,
+)
+from .point_cloud import voxel_downsample, crop_points
 from .window import (
     row_number,
     rank,
@@ -215,15 +255,27 @@ from .window import (
     over,
     lag,
     lead,
+    session_id,
+    zscore,
+    iqr_outlier,
+    fill_forward,
+    fill_backward,
+    interpolate,
+    cumsum,
+    cummin,
+    cummax,
+    cumcount,
 )
 
 __all__ = [
+    "LabelEncoder",
     "abs",
     "any_value",
     "approx_count_distinct",
     "approx_percentiles",
     "arccos",
     "arccosh",
+    "archive_members",
     "arcsin",
     "arcsinh",
     "arctan",
@@ -231,11 +283,15 @@ __all__ = [
     "arctanh",
     "audio_file",
     "audio_metadata",
+    "audio_spectrogram",
+    "audio_waveform",
     "avg",
     "between",
     "bitwise_and",
     "bitwise_or",
     "bitwise_xor",
+    "blob_file",
+    "blob_read",
     "bool_and",
     "bool_or",
     "capitalize",
@@ -247,6 +303,7 @@ __all__ = [
     "classify_text",
     "clip",
     "coalesce",
+    "collation_key",
     "columns_avg",
     "columns_max",
     "columns_mean",
@@ -255,6 +312,7 @@ __all__ = [
     "compress",
     "concat",
     "contains",
+    "contains_any",
     "convert_image",
     "cos",
     "cosh",
@@ -264,7 +322,12 @@ __all__ = [
     "count_distinct",
     "count_matches",
     "crop",
+    "crop_points",
     "csc",
+    "cumcount",
+    "cummax",
+    "cummin",
+    "cumsum",
     "date",
     "date_trunc",
     "day",
@@ -273,30 +336,41 @@ __all__ = [
     "day_of_year",
     "decode",
     "decode_image",
+    "decode_text",
     "decompress",
     "degrees",
     "dense_rank",
     "deserialize",
+    "detect_encoding",
+    "detect_language",
+    "detect_pii",
     "download",
     "embed_image",
     "embed_text",
     "encode",
     "encode_image",
+    "encode_video",
     "endswith",
     "eq_null_safe",
     "exp",
     "explode",
     "expm1",
+    "extract_pdf_text",
     "file",
     "file_size",
+    "feature_hash",
+    "fill_backward",
+    "fill_forward",
     "fill_nan",
     "fill_null",
     "find",
     "floor",
+    "fold_assign",
     "format",
     "get",
     "hash",
     "hour",
+    "html_to_text",
     "ilike",
     "image_attribute",
     "image_channel",
@@ -304,22 +378,27 @@ __all__ = [
     "image_mode",
     "image_to_tensor",
     "image_width",
+    "interpolate",
+    "iqr_outlier",
     "is_in",
     "is_inf",
     "is_nan",
     "is_null",
     "jq",
+    "label_encode",
     "lag",
     "lead",
     "left",
     "length",
     "length_bytes",
+    "length_graphemes",
     "like",
     "list_agg",
     "list_agg_distinct",
     "list_append",
     "list_bool_and",
     "list_bool_or",
+    "list_contains",
     "list_count",
     "list_distinct",
     "list_join",
@@ -327,6 +406,7 @@ __all__ = [
     "list_max",
     "list_mean",
     "list_min",
+    "list_slice",
     "list_sort",
     "list_sum",
     "llm_generate",
@@ -335,10 +415,13 @@ __all__ = [
     "log1p",
     "log2",
     "log10",
+    "lookup",
     "lower",
     "lpad",
     "lstrip",
     "map_get",
+    "map_lookup",
+    "markdown_to_text",
     "max",
     "mean",
     "microsecond",
@@ -353,6 +436,7 @@ __all__ = [
     "normalize",
     "not_nan",
     "not_null",
+    "one_hot",
     "over",
     "parse_url",
     "partition_days",
@@ -368,6 +452,7 @@ __all__ = [
     "quarter",
     "radians",
     "rank",
+    "redact_pii",
     "regexp",
     "regexp_count",
     "regexp_extract",
@@ -387,6 +472,7 @@ __all__ = [
     "sec",
     "second",
     "serialize",
+    "session_id",
     "shift_left",
     "shift_right",
     "sign",
@@ -400,7 +486,9 @@ __all__ = [
     "stddev",
     "strftime",
     "string_agg",
+    "strip_accents",
     "substr",
+    "substr_graphemes",
     "sum",
     "tan",
     "tanh",
@@ -419,6 +507,8 @@ __all__ = [
     "total_minutes",
     "total_nanoseconds",
     "total_seconds",
+    "transliterate_to_ascii",
+    "try_cast",
     "try_compress",
     "try_decode",
     "try_decompress",
@@ -429,10 +519,17 @@ __all__ = [
     "upload",
     "upper",
     "value_counts",
+    "video_extract_frames",
+    "video_extract_subtitles",
     "video_file",
+    "video_frame_at",
     "video_keyframes",
     "video_metadata",
+    "video_phash_frames",
+    "video_scene_changes",
+    "voxel_downsample",
     "week_of_year",
     "when",
     "year",
+    "zscore",
 ]
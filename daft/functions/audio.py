@@ -85,3 +85,99 @@ def resample(
         Expression (Tensor[Python] Expression): The resampled audio file.
     """
     return resample_fn(file_expr, sample_rate)
+
+
+def waveform_impl(
+    file: daft.AudioFile,
+    sample_rate: int | None,
+    mono: bool,
+) -> Any:
+    return file.waveform(sample_rate=sample_rate, mono=mono)
+
+
+audio_waveform_fn = Func._from_func(
+    waveform_impl,
+    return_dtype=daft.DataType.tensor(daft.DataType.float32()),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def audio_waveform(
+    file_expr: Expression,
+    sample_rate: int | None = None,
+    mono: bool = False,
+) -> Expression:
+    """Decode an audio file with FFmpeg into its samples.
+
+    Args:
+        file_expr (AudioFile Expression): The audio file to decode.
+        sample_rate (int | None): The sample rate to resample the audio to. Defaults to None, which keeps the sample
+            rate of each file.
+        mono (bool): Whether to mix the channels down to a single channel. Defaults to False.
+
+    Returns:
+        Expression (Tensor[Float32] Expression): The samples of each file as floats between -1 and 1, with the shape
+            `(channels, samples)`.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import audio_file, audio_waveform
+        >>> df = daft.from_pydict({"path": ["tests/assets/sample_audio.mp3"]})
+        >>> df = df.select(audio_waveform(audio_file(df["path"]), sample_rate=16000, mono=True).alias("waveform"))
+    """
+    return audio_waveform_fn(file_expr, sample_rate, mono)
+
+
+def spectrogram_impl(
+    file: daft.AudioFile,
+    n_fft: int,
+    hop: int | None,
+    sample_rate: int | None,
+) -> Any:
+    return file.spectrogram(n_fft=n_fft, hop=hop, sample_rate=sample_rate)
+
+
+audio_spectrogram_fn = Func._from_func(
+    spectrogram_impl,
+    return_dtype=daft.DataType.tensor(daft.DataType.float32()),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def audio_spectrogram(
+    file_expr: Expression,
+    n_fft: int = 400,
+    hop: int | None = None,
+    sample_rate: int | None = None,
+) -> Expression:
+    """Compute the power spectrogram of an audio file, decoded with FFmpeg and mixed down to a single channel.
+
+    Each frame of `n_fft` samples is weighted by a Hann window and centered on its sample, matching the defaults of
+    librosa and torchaudio.
+
+    Args:
+        file_expr (AudioFile Expression): The audio file to transform.
+        n_fft (int): The number of samples in each frame. Defaults to 400.
+        hop (int | None): The number of samples between the starts of frames. Defaults to None, which is `n_fft // 4`.
+        sample_rate (int | None): The sample rate to resample the audio to before it's transformed. Defaults to None,
+            which keeps the sample rate of each file.
+
+    Returns:
+        Expression (Tensor[Float32] Expression): The power of each frequency in each frame, with the shape
+            `(n_fft // 2 + 1, frames)`.
+    """
+    if n_fft <= 0:
+        raise ValueError(f"n_fft must be positive, got {n_fft}")
+    if hop is not None and hop <= 0:
+        raise ValueError(f"hop must be positive, got {hop}")
+    return audio_spectrogram_fn(file_expr, n_fft, hop, sample_rate)
@@ -2,7 +2,7 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.expressions import Expression
 
@@ -161,3 +161,116 @@ def try_decompress(expr: Expression, codec: COMPRESSION_CODEC) -> Expression:
         [`daft.functions.decompress`](https://docs.daft.ai/en/stable/api/functions/decompress/)
     """
     return Expression._call_builtin_scalar_fn("try_decode", expr, codec=codec)
+
+
+def detect_encoding(bytes: Expression) -> Expression:
+    """Guesses the character encoding of binary values, such as text crawled from the web with unknown encodings.
+
+    Args:
+        bytes (Binary Expression): The expression to inspect.
+
+    Returns:
+        Expression (String Expression): The WHATWG name of the most likely encoding of each value, e.g. "UTF-8",
+            "windows-1252" or "Shift_JIS".
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import detect_encoding
+        >>> df = daft.from_pydict({"bytes": ["héllo wörld".encode(), "Привет, как дела?".encode("cp1251")]})
+        >>> df.select(detect_encoding(df["bytes"])).to_pydict()
+        {'bytes': ['UTF-8', 'windows-1251']}
+
+    """
+    return Expression._call_builtin_scalar_fn("detect_encoding", bytes)
+
+
+def decode_text(bytes: Expression, encoding: str | None = None) -> Expression:
+    """Decodes binary values in a character encoding to strings.
+
+    Unlike `decode`, which only supports UTF-8 text, this supports all the encodings of the WHATWG Encoding Standard,
+    such as "latin1", "windows-1251", "shift_jis" or "gbk". Malformed byte sequences are replaced with U+FFFD, and a
+    byte order mark takes precedence over the given encoding.
+
+    Args:
+        bytes (Binary Expression): The expression to decode.
+        encoding (str, optional): The label of the encoding, or None to detect the encoding of each value as in
+            `detect_encoding`. Defaults to None.
+
+    Returns:
+        Expression (String Expression): The decoded strings.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import decode_text
+        >>> df = daft.from_pydict({"bytes": ["café".encode("latin1")]})
+        >>> df.select(decode_text(df["bytes"], "latin1")).to_pydict()
+        {'bytes': ['café']}
+
+    """
+    return Expression._call_builtin_scalar_fn("decode_text", bytes, encoding=encoding)
+
+
+def extract_pdf_text(bytes: Expression, on_error: Literal["raise", "null"] = "raise") -> Expression:
+    """Extracts the text of each page of PDF documents.
+
+    Text is extracted natively from the content streams of each page, so scanned documents without a text layer yield
+    empty pages. Rendering pages to images is not supported.
+
+    Args:
+        bytes (Binary Expression): The PDF documents, e.g. as read with `url.download()`.
+        on_error (str, default="raise"):
+            Whether to raise when encountering a malformed document, or log a warning and return a null
+
+    Returns:
+        Expression (List[String] Expression): The text of each page of the documents.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import extract_pdf_text
+        >>> df = daft.from_glob_path("reports/*.pdf")  # doctest: +SKIP
+        >>> df = df.with_column("pages", extract_pdf_text(df["path"].url.download()))  # doctest: +SKIP
+
+    """
+    return Expression._call_builtin_scalar_fn("extract_pdf_text", bytes, on_error=on_error)
+
+
+def archive_members(
+    bytes: Expression,
+    format: Literal["tar", "tar.gz", "zip"] | None = None,
+    on_error: Literal["raise", "null"] = "raise",
+) -> Expression:
+    """Extracts the files within tar or zip archives.
+
+    Each archive becomes a list of structs with the `path`, `bytes`, `size` and `mtime` of its regular files, in the
+    order they are stored. Use `explode` and `unnest` to get a row per file, or `daft.read_archives` to read archives
+    from storage directly.
+
+    Members larger than 2 GiB fail to extract. 7z archives aren't supported, since their solid compression can't be
+    extracted member by member.
+
+    Args:
+        bytes (Binary Expression): The archives.
+        format (str, optional): The format of the archives, either "tar", "tar.gz" or "zip", or None to infer the
+            format of each archive from its contents. Defaults to None.
+        on_error (str, default="raise"):
+            Whether to raise when encountering a malformed archive, or log a warning and return a null
+
+    Returns:
+        Expression (List[Struct] Expression): The files within the archives.
+
+    Examples:
+        >>> import io
+        >>> import zipfile
+        >>> import daft
+        >>> from daft.functions import archive_members
+        >>> buf = io.BytesIO()
+        >>> with zipfile.ZipFile(buf, "w") as zf:
+        ...     zf.writestr("a.txt", "hello")
+        ...     zf.writestr("b.txt", "world")
+        >>> df = daft.from_pydict({"archive": [buf.getvalue()]})
+        >>> df = df.select(archive_members(df["archive"]).explode().alias("member"))
+        >>> df.select(df["member"]["path"], df["member"]["bytes"]).to_pydict()
+        {'path': ['a.txt', 'b.txt'], 'bytes': [b'hello', b'world']}
+
+    """
+    return Expression._call_builtin_scalar_fn("archive_members", bytes, format=format, on_error=on_error)
@@ -0,0 +1,212 @@
+"""Feature Encoding Functions."""
+
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Any
+
+import daft.daft as native
+from daft.datatype import DataType
+from daft.expressions import Expression, col, lit
+from daft.functions.misc import _lookup
+from daft.functions.misc import hash as _hash
+from daft.series import item_to_series
+from daft.utils import column_input_to_expression
+
+if TYPE_CHECKING:
+    import os
+    from collections.abc import Sequence
+
+    from daft.dataframe import DataFrame
+    from daft.utils import ColumnInputType
+
+
+def one_hot(expr: Expression, vocabulary: Sequence[Any], sparse: bool = False) -> Expression:
+    """One-hot encodes each value against a vocabulary.
+
+    Each value becomes a fixed-size list with one entry per vocabulary value, which is 1 at the position of the value
+    in the vocabulary and 0 everywhere else. Values that are not in the vocabulary, including nulls, become all 0s.
+
+    With ``sparse=True``, each value instead becomes a struct of the vocabulary ``size``, and of the ``indices`` and
+    ``values`` of its non-zero entries, which suits large vocabularies. Values that are not in the vocabulary have no
+    non-zero entries.
+
+    Args:
+        expr: The expression to encode.
+        vocabulary: The values to encode, in the order of their positions in the output. Must not be empty.
+        sparse: Whether to encode each value as a sparse struct rather than a fixed-size list. Defaults to False.
+
+    Returns:
+        Expression: A ``FixedSizeList[UInt8; len(vocabulary)]`` expression, or with ``sparse=True``, a
+            ``Struct[size: UInt64, indices: List[UInt64], values: List[UInt8]]`` expression.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import one_hot
+        >>> df = daft.from_pydict({"color": ["red", "blue", "green", None]})
+        >>> df.select(one_hot(df["color"], ["red", "green", "blue"])).to_pydict()
+        {'color': [[1, 0, 0], [0, 0, 1], [0, 1, 0], [0, 0, 0]]}
+        >>> df.select(one_hot(df["color"], ["red", "green", "blue"], sparse=True)).to_pydict()["color"][:2]
+        [{'size': 3, 'indices': [0], 'values': [1]}, {'size': 3, 'indices': [2], 'values': [1]}]
+
+    """
+    vocabulary = list(vocabulary)
+    if len(vocabulary) == 0:
+        raise ValueError("one_hot requires a non-empty vocabulary")
+    vocabulary_series = item_to_series("vocabulary", vocabulary)
+    return Expression._call_builtin_scalar_fn(
+        "one_hot",
+        expr,
+        Expression._from_pyexpr(native.list_lit(vocabulary_series._series)),
+        sparse=sparse,
+    )
+
+
+def feature_hash(*exprs: Expression, n_buckets: int) -> Expression:
+    """Hashes the values of one or more expressions into a fixed number of buckets, also known as the hashing trick.
+
+    Unlike [`one_hot`][daft.functions.one_hot] and [`label_encode`][daft.functions.label_encode], this needs no
+    vocabulary, at the cost of unrelated values sometimes sharing a bucket. Multiple expressions are hashed together
+    into a single bucket per row. Nulls are hashed like any other value.
+
+    Args:
+        *exprs: The expressions to hash.
+        n_buckets: The number of buckets. Must be positive.
+
+    Returns:
+        Expression: A UInt64 expression of bucket indices in ``[0, n_buckets)``.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import feature_hash
+        >>> df = daft.from_pydict({"city": ["Paris", "Tokyo", "Paris"], "device": ["ios", "web", "ios"]})
+        >>> buckets = df.select(feature_hash(df["city"], df["device"], n_buckets=8).alias("bucket")).to_pydict()
+        >>> buckets["bucket"][0] == buckets["bucket"][2]
+        True
+
+    """
+    if n_buckets <= 0:
+        raise ValueError(f"feature_hash requires a positive number of buckets, got {n_buckets}")
+    return _hash(*exprs) % lit(n_buckets).cast(DataType.uint64())
+
+
+def fold_assign(*exprs: Expression, k: int, seed: int = 0) -> Expression:
+    """Assigns each row to one of ``k`` folds for k-fold cross validation, based on a hash of the given expressions.
+
+    The fold of a row depends only on its values of ``exprs`` and on ``seed``, so it is the same however the data
+    is partitioned or distributed, and rows with equal values always share a fold. Pass a unique row identifier, or
+    all columns of the DataFrame, to spread rows evenly, or a grouping key such as a user id to keep each group in a
+    single fold.
+
+    Args:
+        *exprs: The expressions to assign folds by.
+        k: The number of folds. Must be positive.
+        seed: The seed of the hash. Use a different seed for a different assignment. Defaults to 0.
+
+    Returns:
+        Expression: A UInt64 expression of fold ids in ``[0, k)``.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import fold_assign
+        >>> df = daft.from_pydict({"id": list(range(100))})
+        >>> df = df.with_column("fold", fold_assign(df["id"], k=5, seed=42))
+        >>> sorted(df.select("fold").distinct().to_pydict()["fold"])
+        [0, 1, 2, 3, 4]
+
+    """
+    if k <= 0:
+        raise ValueError(f"fold_assign requires a positive number of folds, got {k}")
+    return _hash(*exprs, seed=seed) % lit(k).cast(DataType.uint64())
+
+
+class LabelEncoder:
+    """A fitted vocabulary that encodes each of its values as an integer label, its position in the vocabulary.
+
+    Fit an encoder on a column with [`LabelEncoder.fit`][daft.functions.LabelEncoder.fit], then apply it with
+    [`label_encode`][daft.functions.label_encode]. Save the encoder alongside a model with
+    [`save`][daft.functions.LabelEncoder.save] and [`load`][daft.functions.LabelEncoder.load] it again to encode new
+    data with the same labels.
+    """
+
+    def __init__(self, vocabulary: Sequence[Any]) -> None:
+        self._vocabulary = list(vocabulary)
+        if len(set(self._vocabulary)) != len(self._vocabulary):
+            raise ValueError("LabelEncoder vocabulary must not contain duplicate values")
+
+    @classmethod
+    def fit(cls, df: DataFrame, column: ColumnInputType) -> LabelEncoder:
+        """Fits an encoder to the distinct non-null values of a column, in sorted order.
+
+        Args:
+            df: The DataFrame to fit the encoder on.
+            column: The column to fit the encoder on.
+
+        Returns:
+            LabelEncoder: The fitted encoder.
+        """
+        expr = column_input_to_expression(column).alias("value")
+        values = df.select(expr).where(col("value").not_null()).distinct().sort("value").to_pydict()
+        return cls(values["value"])
+
+    @property
+    def vocabulary(self) -> list[Any]:
+        """The values of the encoder, in the order of their labels."""
+        return list(self._vocabulary)
+
+    def __len__(self) -> int:
+        return len(self._vocabulary)
+
+    def __repr__(self) -> str:
+        return f"LabelEncoder(vocabulary={self._vocabulary!r})"
+
+    def save(self, path: str | os.PathLike[str]) -> None:
+        """Saves the vocabulary of the encoder as JSON. The vocabulary's values must be JSON serializable.
+
+        Args:
+            path: The file to save the encoder to.
+        """
+        with open(path, "w") as f:
+            json.dump({"vocabulary": self._vocabulary}, f)
+
+    @classmethod
+    def load(cls, path: str | os.PathLike[str]) -> LabelEncoder:
+        """Loads an encoder saved with [`save`][daft.functions.LabelEncoder.save].
+
+        Args:
+            path: The file to load the encoder from.
+
+        Returns:
+            LabelEncoder: The loaded encoder.
+        """
+        with open(path) as f:
+            return cls(json.load(f)["vocabulary"])
+
+
+def label_encode(expr: Expression, encoder: LabelEncoder | Sequence[Any], default: int | None = None) -> Expression:
+    """Encodes each value as its integer label, the position of the value in a fitted vocabulary.
+
+    Args:
+        expr: The expression to encode.
+        encoder: A [`LabelEncoder`][daft.functions.LabelEncoder], or a vocabulary of values to encode.
+        default: The label of values that are not in the vocabulary, including nulls. Defaults to null.
+
+    Returns:
+        Expression: A UInt64 expression of labels.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import LabelEncoder, label_encode
+        >>> train = daft.from_pydict({"color": ["red", "blue", "red", None]})
+        >>> encoder = LabelEncoder.fit(train, "color")
+        >>> encoder.vocabulary
+        ['blue', 'red']
+        >>> df = daft.from_pydict({"color": ["red", "green", "blue"]})
+        >>> df.select(label_encode(df["color"], encoder)).to_pydict()
+        {'color': [1, None, 0]}
+
+    """
+    if not isinstance(encoder, LabelEncoder):
+        encoder = LabelEncoder(encoder)
+    vocabulary = encoder.vocabulary
+    return _lookup(expr, vocabulary, list(range(len(vocabulary))), default).cast(DataType.uint64())
@@ -59,6 +59,45 @@ def audio_file(url: Expression, verify: bool = False, io_config: IOConfig | None
     return url._eval_expressions("audio_file", verify=verify, io_config=io_config)
 
 
+def blob_file(
+    data: Expression, location: str, max_connections: int = 32, io_config: IOConfig | None = None
+) -> Expression:
+    """Uploads each binary value as a blob under a location and converts it to a `daft.File` reference to the blob.
+
+    Large media values such as video bytes can be kept out of line this way, so that partitions only carry the
+    references through shuffles and consumers fetch the bytes lazily when they open the files.
+
+    Args:
+        data (Binary Expression): the bytes to upload
+        location (str): the folder to upload the blobs to, where each blob is named with a UUID
+        max_connections (int, default=32): the maximum number of concurrent uploads per CPU
+        io_config (IOConfig, default=None): The IO configuration used both to upload and to read the blobs.
+
+    Returns:
+        Expression (File Expression): An expression containing references to the uploaded blobs, which
+            [`blob_read`][daft.functions.blob_read] reads back.
+
+    """
+    return data._eval_expressions("blob_file", location=location, max_connections=max_connections, io_config=io_config)
+
+
+def blob_read(file: Expression, max_connections: int = 32) -> Expression:
+    """Reads the bytes of each file into a binary value, such as the blobs uploaded by [`blob_file`][daft.functions.blob_file].
+
+    The files are fetched concurrently with the IO configuration of each file, so this is the bulk counterpart of
+    opening each file and reading it.
+
+    Args:
+        file (File Expression): the files to read
+        max_connections (int, default=32): the maximum number of concurrent downloads per CPU
+
+    Returns:
+        Expression (Binary Expression): An expression containing the bytes of the files.
+
+    """
+    return file._eval_expressions("blob_read", max_connections=max_connections)
+
+
 def file_size(file: Expression) -> Expression:
     """Returns the size of the file in bytes.
 
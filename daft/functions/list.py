@@ -2,7 +2,7 @@
 
 from __future__ import annotations
 
-from typing import Literal
+from typing import Any, Literal
 
 from daft.daft import CountMode, list_
 from daft.expressions import Expression
@@ -458,6 +458,74 @@ def list_append(list_expr: Expression, other: Expression) -> Expression:
     return Expression._call_builtin_scalar_fn("list_append", list_expr, other)
 
 
+def list_contains(list_expr: Expression, item: Any) -> Expression:
+    """Checks whether each list contains a value.
+
+    Args:
+        list_expr (List Expression): expression to search
+        item (Expression): A value, or a column with a value to search for in each list
+
+    Returns:
+        Expression (Boolean Expression): an expression that is true if the list contains the item. It is null if
+        either the list or the item is null.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import list_contains
+        >>>
+        >>> df = daft.from_pydict({"a": [[1, 2], [3, 4, 5], None], "b": [2, 6, 1]})
+        >>> df.select(list_contains(df["a"], df["b"]).alias("contains")).show()
+        ╭──────────╮
+        │ contains │
+        │ ---      │
+        │ Bool     │
+        ╞══════════╡
+        │ true     │
+        ├╌╌╌╌╌╌╌╌╌╌┤
+        │ false    │
+        ├╌╌╌╌╌╌╌╌╌╌┤
+        │ None     │
+        ╰──────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+    """
+    return Expression._call_builtin_scalar_fn("list_contains", list_expr, item)
+
+
+def list_slice(list_expr: Expression, start: int | Expression, end: int | Expression | None = None) -> Expression:
+    """Slices each list from `start` (inclusive) to `end` (exclusive).
+
+    Both bounds can be expressions, to slice each row differently. Negative bounds count from the end of the list.
+
+    Args:
+        list_expr (List Expression): expression to slice
+        start (int | Int Expression): index of the first element to keep
+        end (int | Int Expression | None): index after the last element to keep. Defaults to the end of the list.
+
+    Returns:
+        Expression (List Expression): an expression with the sliced lists
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import list_slice
+        >>>
+        >>> df = daft.from_pydict({"a": [[1, 2, 3], [4, 5, 6, 7]], "n": [1, 3]})
+        >>> df.select(list_slice(df["a"], 0, df["n"])).show()
+        ╭─────────────╮
+        │ a           │
+        │ ---         │
+        │ List[Int64] │
+        ╞═════════════╡
+        │ [1]         │
+        ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+        │ [4, 5, 6]   │
+        ╰─────────────╯
+        <BLANKLINE>
+        (Showing first 2 of 2 rows)
+    """
+    return Expression._call_builtin_scalar_fn("list_slice", list_expr, start, end=end)
+
+
 def to_list(*items: Expression) -> Expression:
     """Constructs a list from the item expressions.
 
@@ -2,7 +2,7 @@
 
 from __future__ import annotations
 
-from typing import Any, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import daft.daft as native
 from daft.datatype import DataType, DataTypeLike
@@ -10,6 +10,12 @@ from daft.expressions import Expression, col
 from daft.expressions.expressions import WhenExpr
 from daft.series import item_to_series
 
+if TYPE_CHECKING:
+    from collections.abc import Mapping
+
+    from daft.broadcast import Broadcast
+    from daft.dataframe import DataFrame
+
 
 def monotonically_increasing_id() -> Expression:
     """Generates a column of monotonically increasing unique ids.
@@ -129,6 +135,53 @@ def cast(expr: Expression, dtype: DataTypeLike) -> Expression:
     return Expression._from_pyexpr(expr._expr.cast(dtype._dtype))
 
 
+def try_cast(expr: Expression, dtype: DataTypeLike, max_errors: int | None = None) -> Expression:
+    """Casts an expression to the given datatype, collecting the values which can't be cast instead of failing.
+
+    Each row becomes a struct of the cast `value`, which is null for the values which can't be cast, and an `error`,
+    which is null for the rows which were cast, and otherwise has the `value` which couldn't be cast, as a string, and
+    the `reason`. Unnest the struct, or get its fields, to keep the errors in their own column.
+
+    Args:
+        expr: The expression to cast.
+        dtype: The datatype to cast to.
+        max_errors (int | None): If set, the cast fails once more than this many values of all the rows that a query
+            casts can't be cast. A `max_errors` of 0 makes the cast strict, failing on the first value which can't be
+            cast. On distributed runners, each worker counts the values it casts on its own.
+
+    Returns:
+        Expression (Struct Expression): A struct of the cast `value` and the `error` of casting it.
+
+    Tip: See Also
+        [`cast`](https://docs.daft.ai/en/stable/api/functions/cast/), which nulls the values which can't be cast.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import try_cast
+        >>>
+        >>> df = daft.from_pydict({"a": ["1", "x", None]})
+        >>> df = df.select(try_cast(df["a"], daft.DataType.int64()).unnest())
+        >>> df.select("value", df["error"]["value"].alias("invalid")).show()
+        ╭───────┬─────────╮
+        │ value ┆ invalid │
+        │ ---   ┆ ---     │
+        │ Int64 ┆ String  │
+        ╞═══════╪═════════╡
+        │ 1     ┆ None    │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ None  ┆ x       │
+        ├╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+        │ None  ┆ None    │
+        ╰───────┴─────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+    """
+    if max_errors is not None and max_errors < 0:
+        raise ValueError(f"max_errors must be non-negative, got {max_errors}")
+    dtype = DataType._infer(dtype)
+    return Expression._call_builtin_scalar_fn("try_cast", expr, dtype=dtype._dtype, max_errors=max_errors)
+
+
 def is_null(expr: Expression) -> Expression:
     """Checks if values in the Expression are Null (a special value indicating missing data).
 
@@ -667,6 +720,137 @@ def map_get(expr: Expression, key: Expression) -> Expression:
     return Expression._from_pyexpr(expr._expr.map_get(key_expr._expr))
 
 
+def lookup(
+    expr: Expression, table: Mapping[Any, Any] | Broadcast[Mapping[Any, Any]], default: Any = None
+) -> Expression:
+    """Maps each value to its value in a small static lookup table, or null if it is not in the table.
+
+    The table is evaluated with a native hash lookup, so it should be small, e.g. an enum or code table. A mapping is
+    embedded in the query plan, while a broadcast is only referenced by it and is fetched and converted once per
+    worker. Keys and values must each have a consistent type.
+
+    Args:
+        expr: the expression to look up
+        table: a mapping from keys to values, or a [`daft.broadcast`][daft.broadcast] of one
+        default: the value for inputs that are not in the table. Defaults to null.
+
+    Returns:
+        Expression: an expression of the table's value type
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import lookup
+        >>>
+        >>> df = daft.from_pydict({"code": [1, 2, 3]})
+        >>> df = df.select(lookup(df["code"], {1: "low", 2: "high"}))
+        >>> df.show()
+        ╭────────╮
+        │ code   │
+        │ ---    │
+        │ String │
+        ╞════════╡
+        │ low    │
+        ├╌╌╌╌╌╌╌╌┤
+        │ high   │
+        ├╌╌╌╌╌╌╌╌┤
+        │ None   │
+        ╰────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    """
+    from daft.broadcast import Broadcast, _lookup_table
+    from daft.series import Series
+
+    if isinstance(table, Broadcast):
+        # The table's value type is needed to plan the query, so it's converted here, and again by each worker which
+        # evaluates the lookup.
+        _, values = _lookup_table(table)
+        return Expression._call_builtin_scalar_fn(
+            "broadcast_lookup",
+            expr,
+            table=table,
+            dtype=Series._from_pyseries(values).datatype()._dtype,
+            default=default,
+        )
+    return _lookup(expr, list(table.keys()), list(table.values()), default)
+
+
+def map_lookup(
+    expr: Expression,
+    table: DataFrame | Mapping[str, list[Any]] | Broadcast[Mapping[str, list[Any]]],
+    key: str,
+    value: str,
+    default: Any = None,
+) -> Expression:
+    """Maps each value to the `value` column of the row of a small static table whose `key` column matches it.
+
+    This is equivalent to a left join against the table that keeps only its `value` column, but the table is read
+    once when the expression is built, and the lookup is evaluated as a native hash lookup without a join. Use it
+    for small enum or code tables. If the table has duplicate keys, the first matching row is used.
+
+    Args:
+        expr: the expression to look up
+        table: the lookup table, as a DataFrame, a dict of column names to values, or a
+            [`daft.broadcast`][daft.broadcast] of such a dict. A DataFrame is collected immediately.
+        key: the name of the table's key column
+        value: the name of the table's value column
+        default: the value for inputs that are not in the table. Defaults to null.
+
+    Returns:
+        Expression: an expression of the type of the table's value column
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import map_lookup
+        >>>
+        >>> countries = daft.from_pydict({"code": ["FR", "JP"], "name": ["France", "Japan"]})
+        >>> df = daft.from_pydict({"country": ["JP", "FR", "XX"]})
+        >>> df = df.select(map_lookup(df["country"], countries, key="code", value="name", default="Unknown"))
+        >>> df.show()
+        ╭─────────╮
+        │ country │
+        │ ---     │
+        │ String  │
+        ╞═════════╡
+        │ Japan   │
+        ├╌╌╌╌╌╌╌╌╌┤
+        │ France  │
+        ├╌╌╌╌╌╌╌╌╌┤
+        │ Unknown │
+        ╰─────────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    """
+    from daft.broadcast import _as_mapping
+    from daft.dataframe import DataFrame
+
+    if isinstance(table, DataFrame):
+        columns = table.select(key, value).to_pydict()
+    else:
+        columns = _as_mapping(table)
+        for name in (key, value):
+            if name not in columns:
+                raise ValueError(f"Column `{name}` not found in lookup table with columns {list(columns)}")
+    return _lookup(expr, list(columns[key]), list(columns[value]), default)
+
+
+def _lookup(expr: Expression, keys: list[Any], values: list[Any], default: Any) -> Expression:
+    if len(keys) != len(values):
+        raise ValueError(f"Lookup table keys and values must have the same length, got {len(keys)} and {len(values)}")
+    keys_series = item_to_series("keys", keys)
+    values_series = item_to_series("values", values)
+    args = [
+        expr,
+        Expression._from_pyexpr(native.list_lit(keys_series._series)),
+        Expression._from_pyexpr(native.list_lit(values_series._series)),
+    ]
+    if default is not None:
+        args.append(Expression._to_expression(default))
+    return Expression._call_builtin_scalar_fn("lookup", *args)
+
+
 def slice(expr: Expression, start: int | Expression, end: int | Expression | None = None) -> Expression:
     r"""Get a subset of each list or binary value.
 
@@ -0,0 +1,76 @@
+"""Point Cloud Functions."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import daft.daft as native
+from daft.expressions import Expression
+from daft.series import item_to_series
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+
+def _bound_lit(name: str, bound: Sequence[float]) -> Expression:
+    bound = [float(v) for v in bound]
+    if len(bound) != 3:
+        raise ValueError(f"crop_points expects {name} to have 3 coordinates, got {len(bound)}")
+    return Expression._from_pyexpr(native.list_lit(item_to_series(name, bound)._series))
+
+
+def voxel_downsample(points: Expression, voxel_size: float) -> Expression:
+    """Downsamples point clouds to one point per voxel of a regular grid.
+
+    Each point cloud is a list of structs with numeric ``x``, ``y`` and ``z`` fields, such as those read by
+    [`read_las`][daft.read_las] and [`read_pcd`][daft.read_pcd]. The points in each voxel are replaced by a single
+    point at their centroid, whose other fields, such as ``intensity``, are taken from the first point in the voxel.
+    Points with null coordinates are dropped.
+
+    Args:
+        points: The point clouds to downsample.
+        voxel_size: The edge length of the voxels, in the units of the coordinates. Must be positive.
+
+    Returns:
+        Expression: An expression of the same type as ``points``.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import voxel_downsample
+        >>> points = [{"x": 0.1, "y": 0.1, "z": 0.0}, {"x": 0.3, "y": 0.1, "z": 0.0}, {"x": 1.5, "y": 0.0, "z": 0.0}]
+        >>> df = daft.from_pydict({"points": [points]})
+        >>> df.select(voxel_downsample(df["points"], voxel_size=1.0)).to_pydict()
+        {'points': [[{'x': 0.2, 'y': 0.1, 'z': 0.0}, {'x': 1.5, 'y': 0.0, 'z': 0.0}]]}
+
+    """
+    if voxel_size <= 0:
+        raise ValueError(f"voxel_downsample expects a positive voxel_size, got {voxel_size}")
+    return Expression._call_builtin_scalar_fn("voxel_downsample", points, voxel_size=float(voxel_size))
+
+
+def crop_points(points: Expression, min_bound: Sequence[float], max_bound: Sequence[float]) -> Expression:
+    """Filters point clouds to the points within an axis-aligned bounding box.
+
+    Each point cloud is a list of structs with numeric ``x``, ``y`` and ``z`` fields, such as those read by
+    [`read_las`][daft.read_las] and [`read_pcd`][daft.read_pcd]. Points on the faces of the box are kept, while points
+    with null coordinates are dropped.
+
+    Args:
+        points: The point clouds to filter.
+        min_bound: The minimum ``(x, y, z)`` coordinates of the box.
+        max_bound: The maximum ``(x, y, z)`` coordinates of the box.
+
+    Returns:
+        Expression: An expression of the same type as ``points``.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import crop_points
+        >>> df = daft.from_pydict({"points": [[{"x": 0.0, "y": 0.0, "z": 0.0}, {"x": 5.0, "y": 0.0, "z": 0.0}]]})
+        >>> df.select(crop_points(df["points"], (-1, -1, -1), (1, 1, 1))).to_pydict()
+        {'points': [[{'x': 0.0, 'y': 0.0, 'z': 0.0}]]}
+
+    """
+    return Expression._call_builtin_scalar_fn(
+        "crop_points", points, _bound_lit("min_bound", min_bound), _bound_lit("max_bound", max_bound)
+    )
@@ -184,6 +184,53 @@ def contains(expr: Expression, substr: str | Expression) -> Expression:
     return Expression._call_builtin_scalar_fn("utf8_contains", expr, substr)
 
 
+def contains_any(expr: Expression, patterns: str | list[str], *, case_sensitive: bool = True) -> Expression:
+    """Checks whether each string contains any of the given substrings.
+
+    All of the substrings are searched for at once, so this is faster than combining a `contains` per substring with
+    `|` when there are many substrings.
+
+    If case_sensitive is false, then case will be ignored. This only applies to ASCII
+    characters; unicode uppercase/lowercase will still be considered distinct.
+
+    Args:
+        expr: The expression to check.
+        patterns: A substring or a list of substrings to search for.
+        case_sensitive: Whether the matching should be case sensitive. Defaults to true.
+
+    Returns:
+        Expression: a Boolean expression indicating whether each value contains any of the substrings
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import contains_any
+        >>> df = daft.from_pydict({"x": ["foo", "bar", "baz"]})
+        >>> df = df.select(contains_any(df["x"], ["oo", "ar"]))
+        >>> df.show()
+        ╭───────╮
+        │ x     │
+        │ ---   │
+        │ Bool  │
+        ╞═══════╡
+        │ true  │
+        ├╌╌╌╌╌╌╌┤
+        │ true  │
+        ├╌╌╌╌╌╌╌┤
+        │ false │
+        ╰───────╯
+        <BLANKLINE>
+        (Showing first 3 of 3 rows)
+
+    """
+    if isinstance(patterns, str):
+        patterns = [patterns]
+    if not isinstance(patterns, Expression):
+        series = item_to_series("items", patterns)
+        patterns = Expression._from_pyexpr(list_lit(series._series))
+
+    return Expression._call_builtin_scalar_fn("contains_any", expr, patterns, case_sensitive=case_sensitive)
+
+
 def split(expr: Expression, split_on: str | Expression) -> Expression:
     r"""Splits each string on the given string, into a list of strings.
 
@@ -726,6 +773,7 @@ def normalize(
     lowercase: bool = False,
     nfd_unicode: bool = False,
     white_space: bool = False,
+    form: Literal["NFC", "NFD", "NFKC", "NFKD"] | None = None,
 ) -> Expression:
     r"""Normalizes a string for more useful deduplication.
 
@@ -735,6 +783,9 @@ def normalize(
         lowercase: Whether to convert the string to lowercase.
         nfd_unicode: Whether to normalize and decompose Unicode characters according to NFD.
         white_space: Whether to normalize whitespace, replacing newlines etc with spaces and removing double spaces.
+        form: The Unicode normalization form to convert the string to, applied after all other processing. NFC and NFKC
+            compose characters, e.g. "e" followed by a combining accent becomes "é", while NFKC and NFKD also replace
+            compatibility characters with their equivalents, e.g. "ﬁ" becomes "fi".
 
     Returns:
         Expression: a String expression which is normalized.
@@ -771,9 +822,264 @@ def normalize(
         lowercase=lowercase,
         nfd_unicode=nfd_unicode,
         white_space=white_space,
+        form=form,
     )
 
 
+def strip_accents(expr: Expression) -> Expression:
+    """Removes accents and other combining marks from each string.
+
+    Returns:
+        Expression: a String expression with the accents removed
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import strip_accents
+        >>> df = daft.from_pydict({"x": ["Crème brûlée", "naïve", "Ångström"]})
+        >>> df.select(strip_accents(df["x"])).to_pydict()
+        {'x': ['Creme brulee', 'naive', 'Angstrom']}
+
+    """
+    return Expression._call_builtin_scalar_fn("strip_accents", expr)
+
+
+def transliterate_to_ascii(expr: Expression, replacement: str = "?") -> Expression:
+    """Transliterates each string to ASCII.
+
+    Accented letters lose their accents, ligatures and compatibility characters are expanded, and common letters and
+    punctuation without a decomposition (e.g. "ß", "ø" or curly quotes) are replaced with their usual ASCII spelling.
+    Characters without an ASCII equivalent, such as CJK characters, are replaced with `replacement`.
+
+    Args:
+        expr: The string expression to transliterate.
+        replacement: The string to replace characters without an ASCII equivalent with. Defaults to "?".
+
+    Returns:
+        Expression: a String expression with only ASCII characters
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import transliterate_to_ascii
+        >>> df = daft.from_pydict({"x": ["Straße", "Łódź", "ﬁancé", "東京"]})
+        >>> df.select(transliterate_to_ascii(df["x"])).to_pydict()
+        {'x': ['Strasse', 'Lodz', 'fiance', '??']}
+
+    """
+    return Expression._call_builtin_scalar_fn("transliterate_to_ascii", expr, replacement=replacement)
+
+
+def length_graphemes(expr: Expression) -> Expression:
+    """Retrieves the length of each string in grapheme clusters, i.e. user-perceived characters.
+
+    Unlike `length`, which counts Unicode code points, a letter followed by combining accents or an emoji with a skin
+    tone modifier counts as a single character.
+
+    Returns:
+        Expression: an UInt64 expression with the number of grapheme clusters in each string
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import length, length_graphemes
+        >>> df = daft.from_pydict({"x": ["cafe\u0301", "👍🏽", "abc"]})
+        >>> df.select(length(df["x"]).alias("chars"), length_graphemes(df["x"]).alias("graphemes")).to_pydict()
+        {'chars': [5, 2, 3], 'graphemes': [4, 1, 3]}
+
+    """
+    return Expression._call_builtin_scalar_fn("length_graphemes", expr)
+
+
+def substr_graphemes(expr: Expression, start: int, length: int | None = None) -> Expression:
+    """Extracts a substring of each string in grapheme clusters, so that multi-code-point characters are never split.
+
+    Args:
+        expr: The string expression to extract from.
+        start: The index of the first grapheme cluster to extract, starting from 0.
+        length: The number of grapheme clusters to extract. Defaults to the rest of the string.
+
+    Returns:
+        Expression: a String expression with the extracted substrings
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import substr_graphemes
+        >>> df = daft.from_pydict({"x": ["👍🏽👎🏽", "cafe\u0301s"]})
+        >>> df.select(substr_graphemes(df["x"], 1, 1)).to_pydict()
+        {'x': ['👎🏽', 'a']}
+
+    """
+    if start < 0 or (length is not None and length < 0):
+        raise ValueError("Expected start and length of substr_graphemes to be non-negative")
+    return Expression._call_builtin_scalar_fn("substr_graphemes", expr, start=start, length=length)
+
+
+PiiKind = Literal["email", "phone", "credit_card", "ssn"]
+
+
+def detect_pii(expr: Expression, kinds: PiiKind | list[PiiKind] | None = None) -> Expression:
+    """Detects personally identifiable information (PII) in each string.
+
+    Emails, phone numbers, credit card numbers and US social security numbers are found with regular expressions, and
+    candidates are validated to weed out false positives, e.g. credit card numbers must pass the Luhn checksum.
+
+    Args:
+        expr: The string expression to search.
+        kinds: The kinds of PII to detect, out of "email", "phone", "credit_card" and "ssn". Defaults to all kinds.
+
+    Returns:
+        Expression: a List[String] expression with the kind of each piece of PII found, in order of appearance
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import detect_pii
+        >>> df = daft.from_pydict({"x": ["mail me at jane@example.com", "call 555-123-4567 or 555-765-4321", "hi"]})
+        >>> df.select(detect_pii(df["x"])).to_pydict()
+        {'x': [['email'], ['phone', 'phone'], []]}
+
+    """
+    return Expression._call_builtin_scalar_fn("detect_pii", expr, kinds=kinds)
+
+
+def redact_pii(
+    expr: Expression,
+    kinds: PiiKind | list[PiiKind] | None = None,
+    strategy: Literal["tag", "mask", "remove"] = "tag",
+) -> Expression:
+    """Redacts personally identifiable information (PII) in each string.
+
+    PII is detected in the same way as `detect_pii`.
+
+    Args:
+        expr: The string expression to redact.
+        kinds: The kinds of PII to redact, out of "email", "phone", "credit_card" and "ssn". Defaults to all kinds.
+        strategy: How to redact PII. "tag" replaces it with its kind, e.g. "[EMAIL]", "mask" replaces its letters and
+            digits with "*", keeping its format, and "remove" removes it. Defaults to "tag".
+
+    Returns:
+        Expression: a String expression with the PII redacted
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import redact_pii
+        >>> df = daft.from_pydict({"x": ["mail me at jane@example.com", "call 555-123-4567"]})
+        >>> df.select(redact_pii(df["x"])).to_pydict()
+        {'x': ['mail me at [EMAIL]', 'call [PHONE]']}
+        >>> df.select(redact_pii(df["x"], kinds="phone", strategy="mask")).to_pydict()
+        {'x': ['mail me at jane@example.com', 'call ***-***-****']}
+
+    """
+    return Expression._call_builtin_scalar_fn("redact_pii", expr, kinds=kinds, strategy=strategy)
+
+
+def html_to_text(expr: Expression, remove_boilerplate: bool = False) -> Expression:
+    r"""Extracts the visible text of each HTML document.
+
+    Tags, comments and the contents of non-visible elements such as `<head>`, `<script>` and `<style>` are removed,
+    character references are decoded, and whitespace is collapsed the way browsers render it, with block elements such
+    as paragraphs and headings separated by newlines. Documents are processed in a single streaming pass.
+
+    Args:
+        expr: The String or Binary expression with the HTML documents. Binary values are decoded as UTF-8.
+        remove_boilerplate: Whether to also remove the contents of elements that usually aren't the main content of a
+            page, i.e. `<nav>`, `<header>`, `<footer>`, `<aside>`, `<form>` and `<menu>`. Defaults to False.
+
+    Returns:
+        Expression: a String expression with the text of each document
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import html_to_text
+        >>> df = daft.from_pydict({"html": ["<nav>Home</nav><h1>Title</h1><p>Some <b>bold</b> text &amp; more.</p>"]})
+        >>> df.select(html_to_text(df["html"])).to_pydict()
+        {'html': ['Home\n\nTitle\n\nSome bold text & more.']}
+        >>> df.select(html_to_text(df["html"], remove_boilerplate=True)).to_pydict()
+        {'html': ['Title\n\nSome bold text & more.']}
+
+    """
+    return Expression._call_builtin_scalar_fn("html_to_text", expr, remove_boilerplate=remove_boilerplate)
+
+
+def markdown_to_text(expr: Expression) -> Expression:
+    r"""Extracts the plain text of each Markdown document.
+
+    Formatting such as emphasis, headings, list markers and blockquote markers is removed, links and images are replaced
+    by their text, code blocks keep their contents, and table rows become tab-separated cells.
+
+    Args:
+        expr: The String or Binary expression with the Markdown documents. Binary values are decoded as UTF-8.
+
+    Returns:
+        Expression: a String expression with the text of each document
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import markdown_to_text
+        >>> df = daft.from_pydict({"md": ["# Title\n\nSome **bold** text with a [link](https://daft.ai)."]})
+        >>> df.select(markdown_to_text(df["md"])).to_pydict()
+        {'md': ['Title\n\nSome bold text with a link.']}
+
+    """
+    return Expression._call_builtin_scalar_fn("markdown_to_text", expr)
+
+
+def detect_language(expr: Expression, min_confidence: float = 0.0) -> Expression:
+    """Detects the language of each string with a compact trigram model.
+
+    Args:
+        expr: The string expression to classify.
+        min_confidence: The minimum confidence, between 0 and 1, of a detection. Strings whose language can't be detected
+            with at least this confidence, e.g. because they are too short, are null. Defaults to 0.
+
+    Returns:
+        Expression: a String expression with the ISO 639-3 code of the language of each string, e.g. "eng" or "deu"
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import detect_language
+        >>> df = daft.from_pydict({"x": ["The quick brown fox jumps over the lazy dog.", "これは日本語の文章です。"]})
+        >>> df.select(detect_language(df["x"])).to_pydict()
+        {'x': ['eng', 'jpn']}
+
+    """
+    if not 0.0 <= min_confidence <= 1.0:
+        raise ValueError(f"Expected min_confidence to be between 0 and 1, got {min_confidence}")
+    return Expression._call_builtin_scalar_fn("detect_language", expr, min_confidence=min_confidence)
+
+
+def collation_key(
+    expr: Expression,
+    collation: Literal["binary", "case_insensitive", "accent_insensitive"] | str = "binary",
+) -> Expression:
+    """Returns the collation key of each string, for sorting, comparing, grouping or joining strings under a collation.
+
+    Two strings are equal under the collation if and only if their keys are equal, and sorting by the keys orders the
+    strings under the collation, so the keys can be used in place of the strings as sort, group-by or join keys. The
+    keys aren't meant to be shown in place of the strings, which is why SQL's `COLLATE` only applies them to
+    comparisons and to ORDER BY and GROUP BY items.
+
+    Args:
+        expr: The string expression to compute collation keys of.
+        collation: The collation to use. `"binary"` compares the UTF-8 bytes, which is the default ordering of strings,
+            `"case_insensitive"` ignores case, and `"accent_insensitive"` ignores case and accents. Any other value is
+            a locale such as `"de-DE"` or `"sv"`, whose rules are used to compare strings, which requires Daft to be
+            built with the `icu` feature.
+
+    Returns:
+        Expression: a String expression with the collation key of each string, or a Binary expression for locale
+            collations.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import collation_key
+        >>> df = daft.from_pydict({"x": ["banana", "Apple", "émile", "Cherry"]})
+        >>> df.sort(df["x"]).to_pydict()
+        {'x': ['Apple', 'Cherry', 'banana', 'émile']}
+        >>> df.sort(collation_key(df["x"], "accent_insensitive")).to_pydict()
+        {'x': ['Apple', 'banana', 'Cherry', 'émile']}
+
+    """
+    return Expression._call_builtin_scalar_fn("collation_key", expr, collation=collation)
+
+
 def tokenize_encode(
     expr: Expression,
     tokens_path: str,
@@ -2,22 +2,29 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+import io
+from fractions import Fraction
+from typing import TYPE_CHECKING, Any, Literal
 
 import daft
+from daft.dependencies import av, np
 from daft.udf.udf_v2 import Func
 
 if TYPE_CHECKING:
     import PIL
 
     from daft import Expression
-    from daft.file.typing import VideoMetadata
+    from daft.file.typing import Subtitle, VideoMetadata
 
 
 def get_metadata_impl(
     file: daft.VideoFile,
+    *,
+    stream: int | str | None = None,
+    count_frames: bool = False,
+    probe_size: int | None = None,
 ) -> VideoMetadata:
-    return file.metadata()
+    return file.metadata(stream, count_frames, probe_size)
 
 
 video_metadata_fn = Func._from_func(
@@ -28,7 +35,21 @@ video_metadata_fn = Func._from_func(
             "height": daft.DataType.int64(),
             "fps": daft.DataType.float64(),
             "frame_count": daft.DataType.int64(),
+            "frames_estimated": daft.DataType.bool(),
             "time_base": daft.DataType.float64(),
+            "rotation": daft.DataType.int64(),
+            "streams": daft.DataType.list(
+                daft.DataType.struct(
+                    {
+                        "index": daft.DataType.int64(),
+                        "codec": daft.DataType.string(),
+                        "width": daft.DataType.int64(),
+                        "height": daft.DataType.int64(),
+                        "fps": daft.DataType.float64(),
+                        "language": daft.DataType.string(),
+                    }
+                )
+            ),
         }
     ),
     unnest=False,
@@ -42,22 +63,47 @@ video_metadata_fn = Func._from_func(
 
 def video_metadata(
     file_expr: Expression,
+    *,
+    stream: int | str | None = None,
+    count_frames: bool = False,
+    probe_size: int | None = None,
 ) -> Expression:
     """Get metadata for a video file.
 
     Args:
         file_expr (VideoFile Expression): The video file to get metadata for.
+        stream (int | str | None, optional): The video stream to get metadata for, by its index among the video streams
+            of the video or by its language tag. Defaults to the first video stream.
+        count_frames (bool, optional): Whether to count the frames of the stream by reading its packets, without
+            decoding them, rather than trusting the container's headers, which often lack or get wrong the frame count
+            of fragmented MP4 and variable frame rate videos. The fps of a variable frame rate stream is then its
+            average frame rate over the timestamps of its frames. Defaults to False.
+        probe_size (int | None, optional): If set, only probe the container of each video: read this many bytes from
+            its start in one request, and find the parameters of its streams from its headers without decoding any
+            frames. This makes the metadata of many remote videos much cheaper to read, such as MP4s whose headers
+            come first, whose metadata is read with a single request. Parameters which the headers lack, such as the
+            frame rate of raw H.264 streams, aren't filled in by decoding. Can't be used with `count_frames`. Defaults
+            to None.
 
     Returns:
-        Expression (Struct Expression): A struct containing the metadata (width, height, fps, frame_count, time_base)
+        Expression (Struct Expression): A struct containing the metadata of the stream (width, height, fps, frame_count,
+            frames_estimated, time_base, rotation), and the video streams of the video (index, codec, width, height,
+            fps, language). The rotation is the clockwise rotation, in degrees, with which the frames are displayed.
+            frames_estimated is whether the frame count was estimated from the duration and fps, because the headers
+            lack it.
     """
-    return video_metadata_fn(file_expr)
+    return video_metadata_fn(file_expr, stream=stream, count_frames=count_frames, probe_size=probe_size)  # type: ignore
 
 
 def keyframes_impl(
-    file: daft.VideoFile, *, start_time: float = 0, end_time: float | None = None
+    file: daft.VideoFile,
+    *,
+    start_time: float = 0,
+    end_time: float | None = None,
+    stream: int | str | None = None,
+    autorotate: bool = True,
 ) -> list[PIL.Image.Image]:
-    return list(file.keyframes(start_time, end_time))
+    return list(file.keyframes(start_time, end_time, stream, autorotate))
 
 
 video_keyframes_fn = Func._from_func(
@@ -77,6 +123,8 @@ def video_keyframes(
     *,
     start_time: float = 0,
     end_time: float | None = None,
+    stream: int | str | None = None,
+    autorotate: bool = True,
 ) -> Expression:
     """Get keyframes for a video file.
 
@@ -84,8 +132,339 @@ def video_keyframes(
     file (VideoFile): The video file to get keyframes for.
     start_time (float, optional): The start time of the keyframes. Defaults to 0.
     end_time (float | None, optional): The end time of the keyframes. Defaults to None.
+    stream (int | str | None, optional): The video stream to get keyframes of, by its index among the video streams
+        of the video or by its language tag. Defaults to the first video stream.
+    autorotate (bool, optional): Whether to rotate the keyframes as the video is displayed, for videos with rotation
+        metadata such as videos recorded by phones. Defaults to True.
 
     Returns:
     Expression (List Expression): List of keyframes.
     """
-    return video_keyframes_fn(file_expr, start_time=start_time, end_time=end_time)  # type: ignore
+    return video_keyframes_fn(  # type: ignore
+        file_expr, start_time=start_time, end_time=end_time, stream=stream, autorotate=autorotate
+    )
+
+
+def extract_frames_impl(
+    file: daft.VideoFile,
+    *,
+    every_n: int | None = None,
+    fps: float | None = None,
+    timestamps: list[float] | None = None,
+    stream: int | str | None = None,
+    autorotate: bool = True,
+) -> list[PIL.Image.Image]:
+    return file.extract_frames(every_n, fps, timestamps, stream, autorotate)
+
+
+video_extract_frames_fn = Func._from_func(
+    extract_frames_impl,
+    return_dtype=daft.DataType.list(daft.DataType.image()),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def video_extract_frames(
+    file_expr: Expression,
+    *,
+    every_n: int | None = None,
+    fps: float | None = None,
+    timestamps: list[float] | None = None,
+    stream: int | str | None = None,
+    autorotate: bool = True,
+) -> Expression:
+    """Extract a sample of the frames of a video file, chosen by exactly one of `every_n`, `fps` or `timestamps`.
+
+    Args:
+        file_expr (VideoFile Expression): The video file to extract frames from.
+        every_n (int | None, optional): Extract every nth frame, starting from the first frame.
+        fps (float | None, optional): Extract frames at this rate, in frames per second.
+        timestamps (list[float] | None, optional): Extract the frame shown at each of these times, in seconds.
+        stream (int | str | None, optional): The video stream to extract frames of, by its index among the video
+            streams of the video or by its language tag. Defaults to the first video stream.
+        autorotate (bool, optional): Whether to rotate the frames as the video is displayed. Defaults to True.
+
+    Returns:
+        Expression (List[Image] Expression): The extracted frames of each video, in order.
+    """
+    if sum(arg is not None for arg in (every_n, fps, timestamps)) != 1:
+        raise ValueError("Exactly one of every_n, fps or timestamps must be given to extract frames")
+    return video_extract_frames_fn(  # type: ignore
+        file_expr, every_n=every_n, fps=fps, timestamps=timestamps, stream=stream, autorotate=autorotate
+    )
+
+
+def frame_at_impl(
+    file: daft.VideoFile,
+    *,
+    timestamps: list[float] | None,
+    stream: int | str | None = None,
+    autorotate: bool = True,
+) -> list[PIL.Image.Image] | None:
+    if timestamps is None:
+        return None
+    return file.extract_frames(timestamps=timestamps, stream=stream, autorotate=autorotate)
+
+
+video_frame_at_fn = Func._from_func(
+    frame_at_impl,
+    return_dtype=daft.DataType.list(daft.DataType.image()),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def video_frame_at(
+    file_expr: Expression,
+    timestamps: list[float] | Expression,
+    *,
+    stream: int | str | None = None,
+    autorotate: bool = True,
+) -> Expression:
+    """Get the frames of a video file shown at each of a list of times.
+
+    Each frame is found by seeking to the keyframe at or before its time and decoding forward from it, so only the
+    frames near the requested times are decoded. Times after the end of the video get its last frame.
+
+    Args:
+        file_expr (VideoFile Expression): The video file to get frames of.
+        timestamps (list[float] | List[Float64] Expression): The times, in seconds, of the frames to get. These can be
+            the same for every video, or a list per video, such as fractions of the duration from `video_metadata`.
+        stream (int | str | None, optional): The video stream to get frames of, by its index among the video streams
+            of the video or by its language tag. Defaults to the first video stream.
+        autorotate (bool, optional): Whether to rotate the frames as the video is displayed. Defaults to True.
+
+    Returns:
+        Expression (List[Image] Expression): The frame shown at each time, in the order of the times.
+    """
+    return video_frame_at_fn(file_expr, timestamps=timestamps, stream=stream, autorotate=autorotate)  # type: ignore
+
+
+def scene_changes_impl(file: daft.VideoFile, *, threshold: float = 0.3) -> list[float]:
+    return file.scene_changes(threshold)
+
+
+video_scene_changes_fn = Func._from_func(
+    scene_changes_impl,
+    return_dtype=daft.DataType.list(daft.DataType.float64()),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def video_scene_changes(
+    file_expr: Expression,
+    *,
+    threshold: float = 0.3,
+) -> Expression:
+    """Detect the scene changes of a video file.
+
+    Consecutive frames are compared by the mean absolute difference of their pixels, which is scaled to between 0 and 1.
+
+    Args:
+        file_expr (VideoFile Expression): The video file to detect scene changes in.
+        threshold (float, optional): The difference above which a frame starts a new scene. Defaults to 0.3.
+
+    Returns:
+        Expression (List[Float64] Expression): The times, in seconds, of the frames which start a new scene.
+    """
+    return video_scene_changes_fn(file_expr, threshold=threshold)  # type: ignore
+
+
+def phash_frames_impl(
+    file: daft.VideoFile,
+    *,
+    every_n: int = 1,
+    method: Literal["dhash", "phash"] = "dhash",
+    stream: int | str | None = None,
+) -> list[int]:
+    return file.phash_frames(every_n, method, stream)
+
+
+video_phash_frames_fn = Func._from_func(
+    phash_frames_impl,
+    return_dtype=daft.DataType.list(daft.DataType.uint64()),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def video_phash_frames(
+    file_expr: Expression,
+    every_n: int = 1,
+    *,
+    method: Literal["dhash", "phash"] = "dhash",
+    stream: int | str | None = None,
+) -> Expression:
+    """Compute a 64-bit perceptual hash of every nth frame of a video file.
+
+    The frames are hashed as they're decoded, without creating images, so near-duplicate videos can be found at scale
+    by the Hamming distance between the hashes of their frames.
+
+    Args:
+        file_expr (VideoFile Expression): The video file to hash the frames of.
+        every_n (int, optional): Hash every nth frame, starting from the first frame. Defaults to 1.
+        method (str, optional): `"dhash"`, which compares neighbouring pixels, or `"phash"`, which compares the low
+            frequencies of a discrete cosine transform and is more robust to changes of brightness. Defaults to
+            `"dhash"`.
+        stream (int | str | None, optional): The video stream to hash the frames of, by its index among the video
+            streams of the video or by its language tag. Defaults to the first video stream.
+
+    Returns:
+        Expression (List[UInt64] Expression): The hash of each sampled frame, in order.
+    """
+    if every_n < 1:
+        raise ValueError(f"every_n must be positive, got {every_n}")
+    if method not in ("dhash", "phash"):
+        raise ValueError(f"Perceptual hash method must be 'dhash' or 'phash', got {method!r}")
+    return video_phash_frames_fn(file_expr, every_n=every_n, method=method, stream=stream)  # type: ignore
+
+
+def subtitles_impl(file: daft.VideoFile, *, stream_index: int | None = None) -> list[Subtitle]:
+    return file.subtitles(stream_index)
+
+
+video_extract_subtitles_fn = Func._from_func(
+    subtitles_impl,
+    return_dtype=daft.DataType.list(
+        daft.DataType.struct(
+            {
+                "start": daft.DataType.float64(),
+                "end": daft.DataType.float64(),
+                "text": daft.DataType.string(),
+            }
+        )
+    ),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def video_extract_subtitles(
+    file_expr: Expression,
+    *,
+    stream_index: int | None = None,
+) -> Expression:
+    """Extract the subtitles embedded in a video file, from an SRT, ASS or mov_text subtitle stream.
+
+    Args:
+        file_expr (VideoFile Expression): The video file to extract subtitles from.
+        stream_index (int | None, optional): The index of the subtitle stream among the subtitle streams of the video.
+            Defaults to the first subtitle stream.
+
+    Returns:
+        Expression (List[Struct] Expression): The subtitles as structs of their start and end times, in seconds, and
+            their text. Videos without subtitle streams have no subtitles.
+    """
+    return video_extract_subtitles_fn(file_expr, stream_index=stream_index)  # type: ignore
+
+
+_DEFAULT_CODECS = {"mp4": "libx264", "webm": "libvpx-vp9"}
+
+
+def _to_rgb24(frame: Any) -> np.ndarray:
+    """Convert a frame, which is either a PIL image or an array from an Image column, to an RGB array of bytes."""
+    if hasattr(frame, "convert"):
+        frame = frame.convert("RGB")
+    array = np.asarray(frame)
+    if array.ndim == 2:
+        array = np.stack([array] * 3, axis=-1)
+    elif array.ndim == 3 and array.shape[-1] == 1:
+        array = np.concatenate([array] * 3, axis=-1)
+    elif array.ndim == 3 and array.shape[-1] == 4:
+        array = array[..., :3]
+    if array.ndim != 3 or array.shape[-1] != 3:
+        raise ValueError(f"Cannot encode a frame of shape {array.shape}, expected an RGB, RGBA or grayscale image")
+    return np.ascontiguousarray(array, dtype=np.uint8)
+
+
+def encode_video_impl(
+    frames: list[Any] | None, *, fps: float, format: str = "mp4", codec: str | None = None
+) -> bytes | None:
+    if frames is None:
+        return None
+    if not frames:
+        raise ValueError("Cannot encode a video without frames")
+    codec = codec or _DEFAULT_CODECS.get(format)
+    if codec is None:
+        raise ValueError(f"No default codec for format {format!r}, expected one of {list(_DEFAULT_CODECS)} or a codec")
+
+    arrays = [_to_rgb24(frame) for frame in frames]
+    height, width = arrays[0].shape[:2]
+    # YUV 4:2:0 stores the chroma of each 2x2 block of pixels, so the frames must have even dimensions.
+    if height % 2 or width % 2:
+        raise ValueError(f"Frames must have an even width and height to be encoded, got {width}x{height}")
+
+    buffer = io.BytesIO()
+    with av.open(buffer, mode="w", format=format) as container:
+        stream = container.add_stream(codec, rate=Fraction(fps).limit_denominator(1001))
+        stream.width = width
+        stream.height = height
+        stream.pix_fmt = "yuv420p"
+        for array in arrays:
+            if array.shape[:2] != (height, width):
+                raise ValueError(
+                    f"All frames must have the same size to be encoded, got {array.shape[1]}x{array.shape[0]} "
+                    f"after {width}x{height}"
+                )
+            frame = av.VideoFrame.from_ndarray(array, format="rgb24")
+            container.mux(stream.encode(frame))
+        # Flush the frames buffered by the encoder.
+        container.mux(stream.encode())
+    return buffer.getvalue()
+
+
+encode_video_fn = Func._from_func(
+    encode_video_impl,
+    return_dtype=daft.DataType.binary(),
+    unnest=False,
+    use_process=None,
+    is_batch=False,
+    batch_size=None,
+    max_retries=None,
+    on_error=None,
+)
+
+
+def encode_video(
+    frames_expr: Expression,
+    *,
+    fps: float,
+    format: Literal["mp4", "webm"] | str = "mp4",
+    codec: str | None = None,
+) -> Expression:
+    """Encode a list of frames into a video.
+
+    Args:
+        frames_expr (List[Image] Expression): The frames of each video, in order. All frames of a video must have the
+            same even width and height.
+        fps (float): The frame rate of the videos.
+        format (str, optional): The container format of the videos. Defaults to "mp4".
+        codec (str | None, optional): The codec to encode the frames with. Defaults to H.264 for mp4 and VP9 for webm.
+
+    Returns:
+        Expression (Binary Expression): The encoded videos.
+
+    """
+    return encode_video_fn(frames_expr, fps=fps, format=format, codec=codec)  # type: ignore
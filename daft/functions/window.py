@@ -2,12 +2,13 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import daft.daft as native
 from daft.expressions import Expression
 
 if TYPE_CHECKING:
+    from daft.utils import ManyColumnsInputType
     from daft.window import Window
 
 
@@ -305,3 +306,301 @@ def lead(expr: Expression, offset: int = 1, default: Expression | None = None) -
     if default is not None:
         default = Expression._to_expression(default)
     return Expression._from_pyexpr(expr._expr.offset(offset, default._expr if default is not None else None))
+
+
+def session_id(expr: Expression, gap: Any) -> Expression:
+    """Assign a session id to each row of a window partition, starting a new session after every gap larger than ``gap``.
+
+    Rows are sessionized in the window's order: a row starts a new session if its value of ``expr`` differs from
+    the previous row's by more than ``gap``. Session ids count up from 0 within each partition. Rows with a null value
+    always start a new session.
+
+    Args:
+        expr: The expression to sessionize, usually a timestamp column.
+        gap: The largest difference between consecutive rows of one session, such as a ``datetime.timedelta`` for
+            timestamps.
+
+    Returns:
+        Expression: A UInt64 session id, which must be evaluated over a window with an ordering.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import session_id
+        >>>
+        >>> df = daft.from_pydict({"user": ["a", "a", "a", "b"], "ts": [1, 3, 10, 2]})
+        >>> window = daft.Window().partition_by("user").order_by("ts")
+        >>> df = df.with_column("session", session_id(df["ts"], 5).over(window))
+        >>> df.sort(["user", "ts"]).to_pydict()
+        {'user': ['a', 'a', 'a', 'b'], 'ts': [1, 3, 10, 2], 'session': [0, 0, 1, 0]}
+    """
+    gap = Expression._to_expression(gap)
+    return Expression._from_pyexpr(expr._expr.session_id(gap._expr))
+
+
+def _ordered_window(over: ManyColumnsInputType | None, order_by: ManyColumnsInputType) -> Window:
+    from daft.expressions import lit
+    from daft.window import Window
+
+    # Without partition columns, the rows are ordered within a single partition holding all of them.
+    return Window().partition_by(lit(True) if over is None else over).order_by(order_by)
+
+
+def fill_forward(
+    expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None
+) -> Expression:
+    """Fill each null with the last valid value before it, in the order of ``order_by`` within each partition.
+
+    Values are never carried across partitions, so the nulls at the start of a partition stay null.
+
+    Args:
+        expr: The expression whose nulls to fill.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to fill the nulls of separately, such as a sensor id. Defaults to None, which uses all rows.
+
+    Returns:
+        Expression: An expression of the same type as ``expr``, with its nulls filled.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import fill_forward
+        >>> df = daft.from_pydict(
+        ...     {"sensor": ["a", "a", "a", "b", "b"], "ts": [1, 2, 3, 1, 2], "v": [1, None, 3, None, 5]}
+        ... )
+        >>> df = df.with_column("filled", fill_forward(df["v"], order_by="ts", over="sensor"))
+        >>> df.sort(["sensor", "ts"]).to_pydict()["filled"]
+        [1, 1, 3, None, 5]
+    """
+    return Expression._from_pyexpr(expr._expr.window_fill_null("forward")).over(_ordered_window(over, order_by))
+
+
+def fill_backward(
+    expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None
+) -> Expression:
+    """Fill each null with the first valid value after it, in the order of ``order_by`` within each partition.
+
+    Values are never carried across partitions, so the nulls at the end of a partition stay null.
+
+    Args:
+        expr: The expression whose nulls to fill.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to fill the nulls of separately, such as a sensor id. Defaults to None, which uses all rows.
+
+    Returns:
+        Expression: An expression of the same type as ``expr``, with its nulls filled.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import fill_backward
+        >>> df = daft.from_pydict(
+        ...     {"sensor": ["a", "a", "a", "b", "b"], "ts": [1, 2, 3, 1, 2], "v": [1, None, 3, 4, None]}
+        ... )
+        >>> df = df.with_column("filled", fill_backward(df["v"], order_by="ts", over="sensor"))
+        >>> df.sort(["sensor", "ts"]).to_pydict()["filled"]
+        [1, 3, 3, 4, None]
+    """
+    return Expression._from_pyexpr(expr._expr.window_fill_null("backward")).over(_ordered_window(over, order_by))
+
+
+def interpolate(
+    expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None
+) -> Expression:
+    """Fill each null by linear interpolation between the valid values around it, in the order of ``order_by``.
+
+    Values are interpolated by the positions of the rows within each partition, rather than by the values of
+    ``order_by``, and never across partitions, so the nulls before the first and after the last valid values of a
+    partition stay null.
+
+    Args:
+        expr: The numeric expression whose nulls to fill.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to fill the nulls of separately, such as a sensor id. Defaults to None, which uses all rows.
+
+    Returns:
+        Expression: An expression with the nulls filled, which is Float64 for integer inputs and of the same type as
+            ``expr`` for floating point inputs.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import interpolate
+        >>> df = daft.from_pydict({"ts": [1, 2, 3, 4, 5], "v": [None, 1, None, None, 7]})
+        >>> df = df.with_column("filled", interpolate(df["v"], order_by="ts"))
+        >>> df.sort("ts").to_pydict()["filled"]
+        [None, 1.0, 3.0, 5.0, 7.0]
+    """
+    return Expression._from_pyexpr(expr._expr.window_fill_null("linear")).over(_ordered_window(over, order_by))
+
+
+def _cumulative_window(over: ManyColumnsInputType | None, order_by: ManyColumnsInputType) -> Window:
+    from daft.window import Window
+
+    # A row frame, so rows with the same order by values still get the running value up to themselves.
+    return _ordered_window(over, order_by).rows_between(Window.unbounded_preceding, Window.current_row)
+
+
+def cumsum(expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None) -> Expression:
+    """Compute the running total of the values up to each row, in the order of ``order_by`` within each partition.
+
+    This is the sum over a window frame from the start of the partition to the current row. Nulls are skipped, so
+    the running total is null until the first valid value of a partition.
+
+    Args:
+        expr: The numeric expression to sum.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to compute the running totals of separately, such as a user id. Defaults to None, which uses
+            all rows.
+
+    Returns:
+        Expression: An expression of the same type as ``expr.sum()``.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import cumsum
+        >>> df = daft.from_pydict({"user": ["a", "a", "a", "b", "b"], "ts": [1, 2, 3, 1, 2], "v": [1, 2, 3, 4, 5]})
+        >>> df = df.with_column("total", cumsum(df["v"], order_by="ts", over="user"))
+        >>> df.sort(["user", "ts"]).to_pydict()["total"]
+        [1, 3, 6, 4, 9]
+    """
+    return expr.sum().over(_cumulative_window(over, order_by))
+
+
+def cummin(expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None) -> Expression:
+    """Compute the smallest value up to each row, in the order of ``order_by`` within each partition.
+
+    Args:
+        expr: The expression to take the running minimum of.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to compute the running minimums of separately, such as a user id. Defaults to None, which uses
+            all rows.
+
+    Returns:
+        Expression: An expression of the same type as ``expr``, which is null until the first valid value of a
+            partition.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import cummin
+        >>> df = daft.from_pydict({"ts": [1, 2, 3, 4], "v": [3, 5, 1, 2]})
+        >>> df.with_column("low", cummin(df["v"], order_by="ts")).sort("ts").to_pydict()["low"]
+        [3, 3, 1, 1]
+    """
+    return expr.min().over(_cumulative_window(over, order_by))
+
+
+def cummax(expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None) -> Expression:
+    """Compute the largest value up to each row, in the order of ``order_by`` within each partition.
+
+    Args:
+        expr: The expression to take the running maximum of.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to compute the running maximums of separately, such as a user id. Defaults to None, which uses
+            all rows.
+
+    Returns:
+        Expression: An expression of the same type as ``expr``, which is null until the first valid value of a
+            partition.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import cummax
+        >>> df = daft.from_pydict({"ts": [1, 2, 3, 4], "v": [3, 5, 1, 7]})
+        >>> df.with_column("high", cummax(df["v"], order_by="ts")).sort("ts").to_pydict()["high"]
+        [3, 5, 5, 7]
+    """
+    return expr.max().over(_cumulative_window(over, order_by))
+
+
+def cumcount(
+    expr: Expression, *, order_by: ManyColumnsInputType, over: ManyColumnsInputType | None = None
+) -> Expression:
+    """Count the valid values up to each row, in the order of ``order_by`` within each partition.
+
+    Args:
+        expr: The expression whose valid values to count.
+        order_by: Columns to order the rows of each partition by, such as a timestamp column.
+        over: Columns to count the values of separately, such as a user id. Defaults to None, which uses all rows.
+
+    Returns:
+        Expression: A UInt64 expression counting the non-null values of ``expr`` from the start of the partition to
+            the current row, inclusive.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import cumcount
+        >>> df = daft.from_pydict({"ts": [1, 2, 3, 4], "v": [1, None, 3, 4]})
+        >>> df.with_column("seen", cumcount(df["v"], order_by="ts")).sort("ts").to_pydict()["seen"]
+        [1, 1, 2, 3]
+    """
+    return expr.count().over(_cumulative_window(over, order_by))
+
+
+def _stats_window(expr: Expression, partition_by: ManyColumnsInputType | None) -> Window:
+    from daft.window import Window
+
+    # Without partition columns, the statistics are computed over all rows with a window which only orders them, by the
+    # values whose statistics are computed, as the order doesn't change the statistics.
+    return Window().order_by(expr) if partition_by is None else Window().partition_by(partition_by)
+
+
+def zscore(expr: Expression, partition_by: ManyColumnsInputType | None = None) -> Expression:
+    """Compute the z-score of each value: its distance from the mean, in standard deviations.
+
+    The mean and standard deviation are computed over all rows, or over the rows of each partition if
+    ``partition_by`` is given, in a first pass, and each value is then scored against them, so no manual join of
+    the aggregates back onto the rows is needed.
+
+    Args:
+        expr: The numeric expression to score.
+        partition_by: Columns to compute the statistics separately for. Defaults to None, which uses all rows.
+
+    Returns:
+        Expression: A Float64 z-score, which is null for null values and for partitions with a standard deviation of 0.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import zscore
+        >>> df = daft.from_pydict({"sensor": ["a"] * 8 + ["b"] * 2, "reading": [2, 4, 4, 4, 5, 5, 7, 9, 10, 10]})
+        >>> df = df.with_column("z", zscore(df["reading"], partition_by="sensor"))
+        >>> df.sort(["sensor", "reading"]).select("z").to_pydict()
+        {'z': [-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0, None, None]}
+    """
+    from daft.functions.misc import when
+
+    window = _stats_window(expr, partition_by)
+    mean = expr.mean().over(window)
+    stddev = expr.stddev().over(window)
+    return when(stddev > 0, (expr - mean) / stddev)
+
+
+def iqr_outlier(expr: Expression, partition_by: ManyColumnsInputType | None = None, k: float = 1.5) -> Expression:
+    """Flag the values that are more than ``k`` interquartile ranges below the first quartile or above the third.
+
+    This is Tukey's fences outlier test. The quartiles are computed over all rows, or over the rows of each
+    partition if ``partition_by`` is given, in a first pass, and each value is then compared against the fences,
+    so no manual join of the aggregates back onto the rows is needed. The quartiles are approximate, as computed by
+    [`approx_percentiles`][daft.functions.approx_percentiles].
+
+    Args:
+        expr: The numeric expression to check.
+        partition_by: Columns to compute the quartiles separately for. Defaults to None, which uses all rows.
+        k: The number of interquartile ranges beyond the quartiles that a value must be to be an outlier.
+            Defaults to 1.5.
+
+    Returns:
+        Expression: A Boolean expression that is true for outliers, and null for null values.
+
+    Examples:
+        >>> import daft
+        >>> from daft.functions import iqr_outlier
+        >>> df = daft.from_pydict({"latency": [10, 12, 11, 13, 12, 95]})
+        >>> df.with_column("outlier", iqr_outlier(df["latency"])).sort("latency").to_pydict()["outlier"]
+        [False, False, False, False, False, True]
+    """
+    if k < 0:
+        raise ValueError(f"iqr_outlier requires a non-negative k, but got {k}")
+
+    window = _stats_window(expr, partition_by)
+    quartiles = expr.approx_percentiles([0.25, 0.75]).over(window)
+    q1 = quartiles[0]
+    q3 = quartiles[1]
+    iqr = q3 - q1
+    return (expr < q1 - k * iqr) | (expr > q3 + k * iqr)
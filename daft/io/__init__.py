@@ -11,21 +11,29 @@ from daft.daft import (
     UnityConfig,
     HuggingFaceConfig,
 )
+from daft.io._archive import read_archives
 from daft.io._csv import read_csv
+from daft.io._dicom import read_dicom
 from daft.io.delta_lake._deltalake import read_deltalake
 from daft.io.hudi._hudi import read_hudi
 from daft.io.iceberg._iceberg import read_iceberg
 from daft.io.lance._lance import read_lance, merge_columns, merge_columns_df
 from daft.io._json import read_json
+from daft.io._numpy import read_npy, read_npz
 from daft.io._parquet import read_parquet
+from daft.io._point_cloud import read_las, read_pcd
+from daft.io._safetensors import read_safetensors
 from daft.io._sql import read_sql
+from daft.io._tfrecord import read_tfrecord
+from daft.io._vacuum import vacuum
 from daft.io._warc import read_warc
+from daft.io._webdataset import read_webdataset
 from daft.io.huggingface import read_huggingface
 from daft.io.mcap._mcap import read_mcap
 from daft.io._range import _range
 from daft.io.catalog import DataCatalogTable, DataCatalogType
 from daft.io.file_path import from_glob_path
-from daft.io.sink import DataSink
+from daft.io.sink import DataSink, DataSinkWriter
 from daft.io.source import DataSource, DataSourceTask
 from daft.io.av import read_video_frames
 
@@ -34,6 +42,7 @@ __all__ = [
     "DataCatalogTable",
     "DataCatalogType",
     "DataSink",
+    "DataSinkWriter",
     "DataSource",
     "DataSourceTask",
     "GCSConfig",
@@ -48,16 +57,26 @@ __all__ = [
     "from_glob_path",
     "merge_columns",
     "merge_columns_df",
+    "read_archives",
     "read_csv",
     "read_deltalake",
+    "read_dicom",
     "read_hudi",
     "read_huggingface",
     "read_iceberg",
     "read_json",
     "read_lance",
+    "read_las",
     "read_mcap",
+    "read_npy",
+    "read_npz",
     "read_parquet",
+    "read_pcd",
+    "read_safetensors",
     "read_sql",
+    "read_tfrecord",
     "read_video_frames",
     "read_warc",
+    "read_webdataset",
+    "vacuum",
 ]
@@ -0,0 +1,207 @@
+from __future__ import annotations
+
+import tarfile
+import zipfile
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from typing import TYPE_CHECKING, Any, Literal
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.datatype import DataType, TimeUnit
+from daft.dependencies import pa
+from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.io.source import DataSource, DataSourceTask
+from daft.recordbatch import MicroPartition
+from daft.schema import Schema
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+    from daft.io.pushdowns import Pushdowns
+
+ARCHIVE_FORMAT = Literal["tar", "tar.gz", "zip"]
+
+
+@dataclass
+class _Member:
+    """A regular file within an archive."""
+
+    path: str
+    data: bytes
+    mtime: datetime | None
+
+
+def _iter_tar_members(file: Any) -> Iterator[_Member]:
+    """Reads the regular files of a tar archive, which may be compressed, as a stream.
+
+    Members are never all held in memory, and the file doesn't need to be seekable.
+    """
+    with tarfile.open(fileobj=file, mode="r|*") as archive:
+        for info in archive:
+            if not info.isfile():
+                continue
+            member = archive.extractfile(info)
+            if member is None:
+                continue
+            yield _Member(
+                path=info.name,
+                data=member.read(),
+                mtime=datetime.fromtimestamp(info.mtime, tz=timezone.utc),
+            )
+
+
+@dataclass
+class _ArchiveSource(DataSource):
+    """DataSource which expands archives into a row per member, with a task per archive."""
+
+    paths: list[str]
+    format: ARCHIVE_FORMAT | None
+    io_config: IOConfig | None
+
+    @property
+    def name(self) -> str:
+        return "ArchiveSource"
+
+    @property
+    def schema(self) -> Schema:
+        return _schema()
+
+    def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
+        for path in self.paths:
+            file_infos = glob_path_with_stats(path, file_format=None, io_config=self.io_config)
+            for file_path in file_infos.file_paths:
+                yield _ArchiveSourceTask(path=file_path, format=self.format, io_config=self.io_config)
+
+
+@dataclass
+class _ArchiveSourceTask(DataSourceTask):
+    """DataSourceTask which streams the members of an archive as micropartitions."""
+
+    path: str
+    format: ARCHIVE_FORMAT | None
+    io_config: IOConfig | None
+
+    _max_partition_size = 64 * 1024 * 1024  # 64 MB
+
+    @property
+    def schema(self) -> Schema:
+        return _schema()
+
+    def _format(self) -> ARCHIVE_FORMAT:
+        if self.format is not None:
+            return self.format
+        path = self.path.lower()
+        if path.endswith(".zip"):
+            return "zip"
+        if path.endswith((".tar.gz", ".tgz")):
+            return "tar.gz"
+        return "tar"
+
+    def _list_members(self, file: Any) -> Iterator[_Member]:
+        if self._format() == "zip":
+            # Zip archives are indexed by a central directory at their end, so they need a seekable file.
+            with zipfile.ZipFile(file) as archive:
+                for info in archive.infolist():
+                    if info.is_dir():
+                        continue
+                    yield _Member(
+                        path=info.filename,
+                        data=archive.read(info),
+                        mtime=datetime(*info.date_time, tzinfo=timezone.utc),
+                    )
+        else:
+            yield from _iter_tar_members(file)
+
+    def get_micro_partitions(self) -> Iterator[MicroPartition]:
+        fp, fs, _ = _infer_filesystem(self.path, io_config=self.io_config)
+        with fs.open_input_file(fp) as file:
+            buffer: list[_Member] = []
+            size = 0
+            for member in self._list_members(file):
+                buffer.append(member)
+                size += len(member.data)
+                # yield when full
+                if size >= self._max_partition_size:
+                    yield self._to_micropartition(buffer)
+                    buffer = []
+                    size = 0
+            # yield if non-empty
+            if buffer:
+                yield self._to_micropartition(buffer)
+
+    def _to_micropartition(self, members: list[_Member]) -> MicroPartition:
+        return MicroPartition.from_arrow(
+            pa.table(
+                {
+                    "archive_path": pa.array([self.path] * len(members), type=pa.large_string()),
+                    "member_path": pa.array([m.path for m in members], type=pa.large_string()),
+                    "bytes": pa.array([m.data for m in members], type=pa.large_binary()),
+                    "size": pa.array([len(m.data) for m in members], type=pa.uint64()),
+                    "mtime": pa.array([m.mtime for m in members], type=pa.timestamp("us", tz="UTC")),
+                }
+            )
+        )
+
+
+def _schema() -> Schema:
+    return Schema.from_pydict(
+        {
+            "archive_path": DataType.string(),
+            "member_path": DataType.string(),
+            "bytes": DataType.binary(),
+            "size": DataType.uint64(),
+            "mtime": DataType.timestamp(TimeUnit.us(), timezone="UTC"),
+        }
+    )
+
+
+@PublicAPI
+def read_archives(
+    path: str | list[str],
+    format: ARCHIVE_FORMAT | None = None,
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame with a row for each file within tar or zip archives, such as WebDataset shards.
+
+    This produces a DataFrame with the following fields:
+        * archive_path (string): path to the archive that contains the file.
+        * member_path (string): path of the file within the archive.
+        * bytes (binary): contents of the file.
+        * size (uint64): size of the file in bytes.
+        * mtime (timestamp): modification time of the file.
+
+    Directories and links within the archives are skipped. Tar archives, which may be compressed with gzip, bzip2 or
+    xz, are streamed so only a partition's worth of members is held in memory at a time. 7z archives aren't
+    supported, since their solid compression can't be extracted member by member.
+
+    Note:
+        To expand archives which are already in a binary column, use `daft.functions.archive_members`. To group the
+        files of WebDataset shards into samples, use `daft.read_webdataset`.
+
+    Args:
+        path (str|list[str]): Path(s) to the archive(s), which allows wildcards.
+        format (str, optional): The format of the archives, either "tar", "tar.gz" or "zip", or None to infer it from
+            the file extension. Defaults to None.
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a row per file within the archives.
+
+    Examples:
+        >>> df = daft.read_archives("s3://bucket/shards/shard-*.tar")  # doctest: +SKIP
+        >>> df = df.where(df["member_path"].endswith(".jpg"))  # doctest: +SKIP
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from empty list of archive paths")
+    if format is not None and format not in ("tar", "tar.gz", "zip"):
+        raise ValueError(f"Unsupported archive format: {format}, expected one of 'tar', 'tar.gz' or 'zip'")
+
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    return _ArchiveSource(
+        paths=[path] if isinstance(path, str) else path,
+        format=format,
+        io_config=io_config,
+    ).read()
@@ -0,0 +1,277 @@
+from __future__ import annotations
+
+import hashlib
+from collections.abc import Sequence
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any, Literal
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.datatype import DataType
+from daft.dependencies import np, pa
+from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.io.source import DataSource, DataSourceTask
+from daft.recordbatch import MicroPartition
+from daft.schema import Schema
+from daft.series import Series
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+    from daft.io.pushdowns import Pushdowns
+
+PIXEL_FORMAT = Literal["tensor", "image", "none"]
+ANONYMIZE = Literal["remove", "hash"]
+
+# The standard tags which are read into the metadata struct, by their DICOM keyword.
+_STRING_TAGS = [
+    "PatientID",
+    "PatientName",
+    "PatientSex",
+    "PatientAge",
+    "PatientBirthDate",
+    "InstitutionName",
+    "ReferringPhysicianName",
+    "AccessionNumber",
+    "StudyInstanceUID",
+    "SeriesInstanceUID",
+    "SOPInstanceUID",
+    "SOPClassUID",
+    "StudyDate",
+    "StudyTime",
+    "StudyDescription",
+    "SeriesDescription",
+    "Modality",
+    "Manufacturer",
+    "BodyPartExamined",
+    "PhotometricInterpretation",
+]
+_INT_TAGS = ["Rows", "Columns", "NumberOfFrames", "SamplesPerPixel", "BitsAllocated", "SeriesNumber", "InstanceNumber"]
+_FLOAT_TAGS = ["SliceThickness", "RescaleSlope", "RescaleIntercept"]
+_FLOAT_LIST_TAGS = ["PixelSpacing", "ImagePositionPatient", "ImageOrientationPatient", "WindowCenter", "WindowWidth"]
+
+# Tags which identify patients, following the basic profile of DICOM PS3.15 Annex E.
+_IDENTIFYING_TAGS = {
+    "PatientID",
+    "PatientName",
+    "PatientBirthDate",
+    "InstitutionName",
+    "ReferringPhysicianName",
+    "AccessionNumber",
+}
+# Identifying tags which are kept as hashes with `anonymize="hash"`, so records of the same patient can still be linked.
+_LINKABLE_TAGS = {"PatientID", "AccessionNumber"}
+
+
+def _metadata_type() -> Any:
+    return pa.struct(
+        [pa.field(tag, pa.large_string()) for tag in _STRING_TAGS]
+        + [pa.field(tag, pa.int64()) for tag in _INT_TAGS]
+        + [pa.field(tag, pa.float64()) for tag in _FLOAT_TAGS]
+        + [pa.field(tag, pa.large_list(pa.float64())) for tag in _FLOAT_LIST_TAGS]
+    )
+
+
+def _pixel_dtype(pixel_format: PIXEL_FORMAT) -> DataType | None:
+    if pixel_format == "tensor":
+        return DataType.tensor(DataType.float32())
+    if pixel_format == "image":
+        return DataType.image()
+    return None
+
+
+def _schema(pixel_format: PIXEL_FORMAT) -> Schema:
+    fields = {"path": DataType.string()}
+    if (dtype := _pixel_dtype(pixel_format)) is not None:
+        fields["pixels"] = dtype
+    fields["metadata"] = DataType.from_arrow_type(_metadata_type())
+    return Schema.from_pydict(fields)
+
+
+def _as_list(value: Any) -> list[Any]:
+    """Returns the values of a possibly multi-valued element, which are sequences unlike single values."""
+    return list(value) if isinstance(value, Sequence) and not isinstance(value, str) else [value]
+
+
+def _read_metadata(ds: Any, anonymize: ANONYMIZE | None) -> dict[str, Any]:
+    def get(tag: str) -> Any:
+        value = ds.get(tag)
+        return None if value is None or value == "" else value
+
+    metadata: dict[str, Any] = {}
+    for tag in _STRING_TAGS:
+        value = get(tag)
+        metadata[tag] = None if value is None else str(value)
+    for tag in _INT_TAGS:
+        value = get(tag)
+        metadata[tag] = None if value is None else int(value)
+    for tag in _FLOAT_TAGS:
+        value = get(tag)
+        metadata[tag] = None if value is None else float(value)
+    for tag in _FLOAT_LIST_TAGS:
+        value = get(tag)
+        if value is None:
+            metadata[tag] = None
+        else:
+            metadata[tag] = [float(v) for v in _as_list(value)]
+
+    if anonymize is not None:
+        for tag in _IDENTIFYING_TAGS:
+            if anonymize == "hash" and tag in _LINKABLE_TAGS and metadata[tag] is not None:
+                metadata[tag] = hashlib.sha256(metadata[tag].encode()).hexdigest()
+            else:
+                metadata[tag] = None
+    return metadata
+
+
+def _read_pixels(ds: Any, pixel_format: PIXEL_FORMAT) -> Any:
+    """Decodes the pixel data of a dataset, applying the modality rescale, e.g. to Hounsfield units for CT."""
+    if "PixelData" not in ds:
+        return None
+    pixels = ds.pixel_array.astype(np.float32)
+    slope = float(ds.get("RescaleSlope", 1) or 1)
+    intercept = float(ds.get("RescaleIntercept", 0) or 0)
+    pixels = pixels * slope + intercept
+    if pixel_format == "tensor":
+        return pixels
+
+    # Images are scaled to 8 bits with the first window if there is one, else with the range of the pixel values.
+    center, width = ds.get("WindowCenter"), ds.get("WindowWidth")
+    if center is not None and width is not None:
+        center = float(_as_list(center)[0])
+        width = float(_as_list(width)[0])
+        low, high = center - width / 2, center + width / 2
+    else:
+        low, high = float(pixels.min()), float(pixels.max())
+    scaled = np.clip((pixels - low) / max(high - low, 1e-6), 0, 1) * 255
+    if ds.get("PhotometricInterpretation") == "MONOCHROME1":
+        # MONOCHROME1 images display low values as white.
+        scaled = 255 - scaled
+    image = scaled.astype(np.uint8)
+    if image.ndim == (3 if int(ds.get("SamplesPerPixel", 1)) == 1 else 4):
+        # Only the first frame of multi-frame images is kept as an image.
+        image = image[0]
+    return image
+
+
+@dataclass
+class _DicomSource(DataSource):
+    """DataSource which reads DICOM files into rows of pixel data and metadata, with a task per batch of files."""
+
+    paths: list[str]
+    pixel_format: PIXEL_FORMAT
+    anonymize: ANONYMIZE | None
+    io_config: IOConfig | None
+
+    _files_per_task = 64
+
+    @property
+    def name(self) -> str:
+        return "DicomSource"
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.pixel_format)
+
+    def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
+        for i in range(0, len(self.paths), self._files_per_task):
+            yield _DicomSourceTask(
+                paths=self.paths[i : i + self._files_per_task],
+                pixel_format=self.pixel_format,
+                anonymize=self.anonymize,
+                io_config=self.io_config,
+            )
+
+
+@dataclass
+class _DicomSourceTask(DataSourceTask):
+    """DataSourceTask which reads a batch of DICOM files into a micropartition."""
+
+    paths: list[str]
+    pixel_format: PIXEL_FORMAT
+    anonymize: ANONYMIZE | None
+    io_config: IOConfig | None
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.pixel_format)
+
+    def get_micro_partitions(self) -> Iterator[MicroPartition]:
+        import pydicom
+
+        pixels = []
+        metadata = []
+        for path in self.paths:
+            fp, fs, _ = _infer_filesystem(path, io_config=self.io_config)
+            with fs.open_input_file(fp) as file:
+                ds = pydicom.dcmread(file, stop_before_pixels=self.pixel_format == "none")
+            metadata.append(_read_metadata(ds, self.anonymize))
+            if self.pixel_format != "none":
+                pixels.append(_read_pixels(ds, self.pixel_format))
+
+        columns = {"path": Series.from_pylist(self.paths, name="path", dtype=DataType.string())}
+        if (dtype := _pixel_dtype(self.pixel_format)) is not None:
+            columns["pixels"] = Series.from_pylist(pixels, name="pixels", dtype=dtype)
+        columns["metadata"] = Series.from_arrow(pa.array(metadata, type=_metadata_type()), name="metadata")
+        yield MicroPartition.from_pydict(columns)
+
+
+@PublicAPI
+def read_dicom(
+    path: str | list[str],
+    pixel_format: PIXEL_FORMAT = "tensor",
+    anonymize: ANONYMIZE | None = None,
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from DICOM file(s), with a row per file.
+
+    This produces a DataFrame with the following fields:
+        * path (string): path to the DICOM file.
+        * pixels (tensor or image): the decoded pixel data, or null for files without pixel data. This column is
+          omitted with ``pixel_format="none"``.
+        * metadata (struct): standard tags such as ``PatientID``, ``StudyInstanceUID``, ``Modality``, ``Rows``,
+          ``Columns``, ``PixelSpacing`` and ``RescaleSlope``, which are null if missing.
+
+    Warning:
+        This requires pydicom which can be installed with `pip install pydicom`. Compressed transfer syntaxes may
+        require additional decoders such as `pylibjpeg`.
+
+    Args:
+        path (str|list[str]): Path(s) to the DICOM file(s), which allows wildcards.
+        pixel_format (str): How to decode the pixel data: "tensor" for a float32 tensor in the modality's units,
+            e.g. Hounsfield units for CT, "image" for an 8-bit image scaled with the first window of the file, or
+            "none" to skip reading pixel data. Defaults to "tensor".
+        anonymize (str, optional): How to anonymize the tags which identify patients, such as their name, ID and
+            birth date: "remove" to replace them with nulls, or "hash" to also replace patient IDs and accession
+            numbers with their SHA-256 hashes, so records of the same patient can still be linked. Defaults to None,
+            which keeps them as they are.
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a row per DICOM file.
+
+    Examples:
+        >>> df = daft.read_dicom("s3://bucket/studies/**/*.dcm", anonymize="hash")  # doctest: +SKIP
+        >>> df = df.where(df["metadata"]["Modality"] == "CT")  # doctest: +SKIP
+    """
+    try:
+        import pydicom  # noqa: F401
+    except ImportError as e:
+        raise ImportError("read_dicom requires pydicom. Please install it with `pip install pydicom`.") from e
+    if pixel_format not in ("tensor", "image", "none"):
+        raise ValueError(f"Unsupported pixel_format: {pixel_format}, expected one of 'tensor', 'image' or 'none'")
+    if anonymize is not None and anonymize not in ("remove", "hash"):
+        raise ValueError(f"Unsupported anonymize option: {anonymize}, expected 'remove', 'hash' or None")
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from empty list of DICOM filepaths")
+
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = []
+    for p in [path] if isinstance(path, str) else path:
+        paths.extend(glob_path_with_stats(p, file_format=None, io_config=io_config).file_paths)
+    if not paths:
+        raise FileNotFoundError(f"No DICOM files found at {path}")
+
+    return _DicomSource(paths=paths, pixel_format=pixel_format, anonymize=anonymize, io_config=io_config).read()
@@ -0,0 +1,147 @@
+"""Manifests of the files committed to plain Parquet directories.
+
+Each write to a directory with a manifest commits its files by adding a commit file to the `_daft_manifest`
+directory under the root directory, which lists the paths of its files relative to the root directory. A commit file
+is written under a temporary name and then moved into place, so readers see all of a commit's files or none of them,
+and concurrent writers never conflict because each of them adds a file of its own. Readers list the commit files once,
+so they read a consistent snapshot of the directory, without the files of writes which are still running or failed.
+
+An overwrite commits its files like any other write, with a commit file which is marked as an overwrite, so readers skip
+the commits before it. The earlier commits and their files are only removed once it's committed, so an overwrite which
+fails before its commit leaves the previous snapshot in place, and one which fails while removing them still leaves a
+directory which is read as the new snapshot.
+"""
+
+from __future__ import annotations
+
+import json
+import pathlib
+import time
+import uuid
+from typing import TYPE_CHECKING
+
+from daft.dependencies import pafs
+from daft.filesystem import _resolve_paths_and_filesystem, join_path
+
+if TYPE_CHECKING:
+    from daft.daft import IOConfig
+
+MANIFEST_DIR_NAME = "_daft_manifest"
+
+# The versions of the format of commit files, which readers check so that they don't misread commits of later versions.
+# Overwrite commits are written with the version which introduced them, so that readers which don't skip the commits
+# before them refuse to read the directory rather than read the overwritten files too.
+_MANIFEST_VERSION = 1
+_OVERWRITE_MANIFEST_VERSION = 2
+
+
+def commit_files(
+    root_dir: str | pathlib.Path,
+    written_paths: list[str],
+    num_partition_cols: int,
+    io_config: IOConfig | None,
+    overwrite: bool = False,
+) -> None:
+    """Commits the files written to a directory to its manifest.
+
+    Args:
+        root_dir: The root directory of the write.
+        written_paths: The paths of the written files, as returned by the write.
+        num_partition_cols: The number of partition columns of the write, which is the number of directories between
+            the root directory and each file.
+        io_config: The IOConfig to write the commit file with.
+        overwrite: Whether the files replace those of the earlier commits, which are removed once they're committed.
+    """
+    # Written paths may or may not have a scheme, or be resolved from a relative root directory, so they're made
+    # relative to the root directory by their partition directories and file name.
+    files = ["/".join(path.split("/")[-(num_partition_cols + 1) :]) for path in written_paths]
+
+    [resolved_root_dir], fs = _resolve_paths_and_filesystem(str(root_dir), io_config=io_config)
+    manifest_dir = join_path(fs, resolved_root_dir, MANIFEST_DIR_NAME)
+    fs.create_dir(manifest_dir, recursive=True)
+
+    commit = (
+        {"version": _OVERWRITE_MANIFEST_VERSION, "overwrite": True, "files": files}
+        if overwrite
+        else {"version": _MANIFEST_VERSION, "files": files}
+    )
+    # Commit files are named by the time they were committed, so that they're listed in the order of their commits.
+    name = f"{time.time_ns():020d}-{uuid.uuid4().hex}.json"
+    temp_path = join_path(fs, manifest_dir, f".{name}.tmp")
+    commit_path = join_path(fs, manifest_dir, name)
+    with fs.open_output_stream(temp_path) as f:
+        f.write(json.dumps(commit).encode())
+    fs.move(temp_path, commit_path)
+
+    if overwrite:
+        remove_overwritten_files(fs, resolved_root_dir, commit_path)
+
+
+def remove_overwritten_files(fs: pafs.FileSystem, root_dir: str, overwrite_commit: str) -> None:
+    """Removes the commits before an overwrite commit, then the files which aren't listed by the remaining commits.
+
+    Readers already skip the commits before the overwrite commit, so this only frees up the directory, and it can fail
+    part of the way through without changing what's read.
+    """
+    manifest_dir = join_path(fs, root_dir, MANIFEST_DIR_NAME)
+    infos = fs.get_file_info(pafs.FileSelector(manifest_dir, allow_not_found=True))
+    commits = sorted(info.path for info in infos if is_commit_file(info))
+    files = {file for commit in commits if commit >= overwrite_commit for file in _read_commit(fs, commit)["files"]}
+    for commit in commits:
+        if commit < overwrite_commit:
+            fs.delete_file(commit)
+
+    root_dir = root_dir.rstrip("/")
+    for info in fs.get_file_info(pafs.FileSelector(root_dir, recursive=True)):
+        if info.type != pafs.FileType.File:
+            continue
+        file = info.path[len(root_dir) + 1 :]
+        if file.split("/")[0] != MANIFEST_DIR_NAME and file not in files:
+            fs.delete_file(info.path)
+
+
+def committed_files(root_dir: str | pathlib.Path, io_config: IOConfig | None) -> list[str]:
+    """Returns the paths of the files committed to the manifest of a directory, in the order of their commits.
+
+    Raises:
+        FileNotFoundError: If the directory doesn't have a manifest.
+    """
+    root_dir = str(root_dir).rstrip("/")
+    [resolved_root_dir], fs = _resolve_paths_and_filesystem(root_dir, io_config=io_config)
+    files = read_manifest(fs, resolved_root_dir)
+    if files is None:
+        raise FileNotFoundError(f"{root_dir} has no {MANIFEST_DIR_NAME} directory of committed files")
+    return [f"{root_dir}/{file}" for file in files]
+
+
+def read_manifest(fs: pafs.FileSystem, root_dir: str) -> list[str] | None:
+    """Returns the paths of the files committed to the manifest of a directory, relative to the directory.
+
+    Returns None if the directory doesn't have a manifest.
+    """
+    manifest_dir = join_path(fs, root_dir, MANIFEST_DIR_NAME)
+    infos = fs.get_file_info(pafs.FileSelector(manifest_dir, allow_not_found=True))
+    commits = sorted(info.path for info in infos if is_commit_file(info))
+    if not commits:
+        return None
+
+    files = []
+    for commit in commits:
+        manifest = _read_commit(fs, commit)
+        if manifest.get("overwrite", False):
+            files = []
+        files.extend(manifest["files"])
+    return files
+
+
+def _read_commit(fs: pafs.FileSystem, commit: str) -> dict:
+    with fs.open_input_stream(commit) as f:
+        manifest = json.loads(f.read())
+    if manifest.get("version") not in (_MANIFEST_VERSION, _OVERWRITE_MANIFEST_VERSION):
+        raise ValueError(f"Unsupported version {manifest.get('version')} of the manifest commit {commit}")
+    return manifest
+
+
+def is_commit_file(info: pafs.FileInfo) -> bool:
+    """Whether a file in a manifest directory is a commit, rather than a commit which is still being written."""
+    return info.type == pafs.FileType.File and info.base_name.endswith(".json") and not info.base_name.startswith(".")
@@ -0,0 +1,281 @@
+from __future__ import annotations
+
+import math
+import zipfile
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.datatype import DataType
+from daft.dependencies import np, pa
+from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.io.source import DataSource, DataSourceTask
+from daft.recordbatch import MicroPartition
+from daft.schema import Schema
+from daft.series import Series
+
+if TYPE_CHECKING:
+    from collections.abc import Callable, Iterator
+
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+    from daft.io.pushdowns import Pushdowns
+
+# npy format details see: https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html
+
+_MAX_PARTITION_SIZE = 64 * 1024 * 1024  # 64 MB
+
+
+@dataclass(frozen=True)
+class _ArraySpec:
+    """The dtype and shape of an array whose first axis is read as rows."""
+
+    dtype: Any
+    shape: tuple[int, ...]
+
+    @property
+    def row_shape(self) -> tuple[int, ...]:
+        return self.shape[1:]
+
+    @property
+    def num_rows(self) -> int:
+        return self.shape[0] if self.shape else 1
+
+    @property
+    def row_nbytes(self) -> int:
+        return math.prod(self.row_shape) * self.dtype.itemsize
+
+
+def _column_dtype(spec: _ArraySpec) -> DataType:
+    """Returns the column type of an array: a fixed-shape tensor for each row, or a scalar for 1-D arrays."""
+    dtype = DataType.from_numpy_dtype(_storage_dtype(spec.dtype))
+    if len(spec.shape) <= 1:
+        return dtype
+    return DataType.tensor(dtype, spec.row_shape)
+
+
+def _storage_dtype(dtype: Any) -> Any:
+    # Daft has no half-precision floats, so they are widened.
+    return np.dtype(np.float32) if dtype == np.float16 else dtype
+
+
+def _to_series(name: str, array: Any) -> Series:
+    """Converts an array to a column with a row per element of its first axis."""
+    array = np.ascontiguousarray(array, dtype=_storage_dtype(array.dtype))
+    if array.ndim == 0:
+        array = array.reshape(1)
+    if array.ndim == 1:
+        return Series.from_arrow(pa.array(array), name=name)
+    spec = _ArraySpec(dtype=array.dtype, shape=array.shape)
+    row_size = math.prod(spec.row_shape)
+    flat = pa.FixedSizeListArray.from_arrays(pa.array(array.reshape(-1)), row_size)
+    return Series.from_arrow(flat, name=name).cast(_column_dtype(spec))
+
+
+def _check_num_rows(path: str, specs: dict[str, _ArraySpec]) -> int:
+    num_rows = {spec.num_rows for spec in specs.values()}
+    if len(num_rows) > 1:
+        lengths = ", ".join(f"{key}: {spec.num_rows}" for key, spec in specs.items())
+        raise ValueError(
+            f"The arrays of {path} must have the same length along their first axis to be read as columns, but got "
+            f"{lengths}. Select arrays of the same length with `columns`."
+        )
+    return num_rows.pop() if num_rows else 0
+
+
+def _select(specs: dict[str, _ArraySpec], columns: dict[str, str] | list[str] | None, path: str) -> dict[str, str]:
+    """Returns the arrays to read as a mapping of their keys to their column names."""
+    if columns is None:
+        return {key: key for key in specs}
+    if isinstance(columns, list):
+        columns = {key: key for key in columns}
+    if missing := [key for key in columns if key not in specs]:
+        raise ValueError(f"Arrays {missing} not found in {path}, found: {list(specs)}")
+    return dict(columns)
+
+
+def _read_npy_spec(file: Any) -> _ArraySpec:
+    version = np.lib.format.read_magic(file)
+    if version == (1, 0):
+        shape, _, dtype = np.lib.format.read_array_header_1_0(file)
+    else:
+        shape, _, dtype = np.lib.format.read_array_header_2_0(file)
+    return _ArraySpec(dtype=dtype, shape=shape)
+
+
+def _read_npz_specs(file: Any) -> dict[str, _ArraySpec]:
+    with zipfile.ZipFile(file) as archive:
+        specs = {}
+        for name in archive.namelist():
+            if name.endswith(".npy"):
+                with archive.open(name) as member:
+                    specs[name.removesuffix(".npy")] = _read_npy_spec(member)
+        return specs
+
+
+@dataclass
+class _ArrayFileSource(DataSource):
+    """DataSource which reads the arrays of npy, npz or safetensors files as columns, with a task per file."""
+
+    source_name: str
+    paths: list[str]
+    # The arrays to read by their key, and their column names.
+    columns: dict[str, str]
+    schema_: Schema
+    read_arrays: Callable[[Any, list[str]], dict[str, Any]]
+    io_config: IOConfig | None
+
+    @property
+    def name(self) -> str:
+        return self.source_name
+
+    @property
+    def schema(self) -> Schema:
+        return self.schema_
+
+    def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
+        for path in self.paths:
+            yield _ArrayFileSourceTask(
+                path=path,
+                columns=self.columns,
+                schema_=self.schema_,
+                read_arrays=self.read_arrays,
+                io_config=self.io_config,
+            )
+
+
+@dataclass
+class _ArrayFileSourceTask(DataSourceTask):
+    """DataSourceTask which reads the arrays of a file and yields micropartitions of their rows."""
+
+    path: str
+    columns: dict[str, str]
+    schema_: Schema
+    read_arrays: Callable[[Any, list[str]], dict[str, Any]]
+    io_config: IOConfig | None
+
+    @property
+    def schema(self) -> Schema:
+        return self.schema_
+
+    def get_micro_partitions(self) -> Iterator[MicroPartition]:
+        fp, fs, _ = _infer_filesystem(self.path, io_config=self.io_config)
+        with fs.open_input_file(fp) as file:
+            arrays = self.read_arrays(file, list(self.columns))
+        specs = {key: _ArraySpec(dtype=array.dtype, shape=array.shape) for key, array in arrays.items()}
+        num_rows = _check_num_rows(self.path, specs)
+        row_nbytes = sum(spec.row_nbytes for spec in specs.values())
+        rows_per_partition = max(1, _MAX_PARTITION_SIZE // max(1, row_nbytes))
+        for start in range(0, num_rows, rows_per_partition):
+            end = min(start + rows_per_partition, num_rows)
+            yield MicroPartition.from_pydict(
+                {
+                    column: _to_series(column, arrays[key][start:end] if arrays[key].ndim > 0 else arrays[key])
+                    for key, column in self.columns.items()
+                }
+            )
+
+
+def _glob_paths(path: str | list[str], io_config: IOConfig | None, kind: str) -> list[str]:
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError(f"Cannot read DataFrame from empty list of {kind} filepaths")
+    paths = []
+    for p in [path] if isinstance(path, str) else path:
+        paths.extend(glob_path_with_stats(p, file_format=None, io_config=io_config).file_paths)
+    if not paths:
+        raise FileNotFoundError(f"No {kind} files found at {path}")
+    return paths
+
+
+def _read_array_files(
+    source_name: str,
+    paths: list[str],
+    specs: dict[str, _ArraySpec],
+    columns: dict[str, str],
+    read_arrays: Callable[[Any, list[str]], dict[str, Any]],
+    io_config: IOConfig | None,
+) -> DataFrame:
+    _check_num_rows(paths[0], {key: specs[key] for key in columns})
+    schema = Schema.from_pydict({column: _column_dtype(specs[key]) for key, column in columns.items()})
+    return _ArrayFileSource(
+        source_name=source_name,
+        paths=paths,
+        columns=columns,
+        schema_=schema,
+        read_arrays=read_arrays,
+        io_config=io_config,
+    ).read()
+
+
+def _read_npy_arrays(file: Any, keys: list[str]) -> dict[str, Any]:
+    return {keys[0]: np.lib.format.read_array(file, allow_pickle=False)}
+
+
+def _read_npz_arrays(file: Any, keys: list[str]) -> dict[str, Any]:
+    with np.load(file, allow_pickle=False) as npz:
+        return {key: npz[key] for key in keys}
+
+
+@PublicAPI
+def read_npy(
+    path: str | list[str],
+    column: str = "data",
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from NumPy .npy file(s), with a row for each element along the first axis of the arrays.
+
+    Rows of multi-dimensional arrays become fixed-shape tensors, e.g. an array of embeddings with shape `(N, 768)` is
+    read as N rows of `Tensor[float32, (768,)]`, while 1-dimensional arrays are read as scalars. All files must have
+    the same dtype and shape after their first axis.
+
+    Args:
+        path (str|list[str]): Path(s) to the .npy file(s), which allows wildcards.
+        column (str): The name of the column. Defaults to "data".
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a single column of the rows of the arrays.
+
+    Examples:
+        >>> df = daft.read_npy("s3://bucket/embeddings-*.npy", column="embedding")  # doctest: +SKIP
+    """
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = _glob_paths(path, io_config, "npy")
+    fp, fs, _ = _infer_filesystem(paths[0], io_config=io_config)
+    with fs.open_input_file(fp) as file:
+        spec = _read_npy_spec(file)
+    return _read_array_files("NpySource", paths, {column: spec}, {column: column}, _read_npy_arrays, io_config)
+
+
+@PublicAPI
+def read_npz(
+    path: str | list[str],
+    columns: dict[str, str] | list[str] | None = None,
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from NumPy .npz file(s), with a column for each array and a row for each element along their first axis.
+
+    Rows of multi-dimensional arrays become fixed-shape tensors, while 1-dimensional arrays are read as scalars, so
+    e.g. the arrays `ids` with shape `(N,)` and `embeddings` with shape `(N, 768)` are read as N rows of `ids` and
+    `embeddings` columns. The arrays which are read must have the same length along their first axis.
+
+    Args:
+        path (str|list[str]): Path(s) to the .npz file(s), which allows wildcards.
+        columns (dict[str, str] | list[str], optional): The arrays to read, either as a list of their keys or as a
+            mapping of their keys to column names. Defaults to None, which reads all the arrays of the first file.
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a column for each array.
+
+    Examples:
+        >>> df = daft.read_npz("s3://bucket/dump-*.npz", columns={"arr_0": "id", "arr_1": "embedding"})  # doctest: +SKIP
+    """
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = _glob_paths(path, io_config, "npz")
+    fp, fs, _ = _infer_filesystem(paths[0], io_config=io_config)
+    with fs.open_input_file(fp) as file:
+        specs = _read_npz_specs(file)
+    selected = _select(specs, columns, paths[0])
+    return _read_array_files("NpzSource", paths, specs, selected, _read_npz_arrays, io_config)
@@ -12,6 +12,7 @@ from daft.daft import (
 )
 from daft.dataframe import DataFrame
 from daft.datatype import DataType, TimeUnit
+from daft.io._manifest import committed_files
 from daft.io.common import get_tabular_files_scan
 
 
@@ -25,6 +26,7 @@ def read_parquet(
     file_path_column: str | None = None,
     hive_partitioning: bool = False,
     coerce_int96_timestamp_unit: str | TimeUnit | None = None,
+    manifest: bool = False,
     _multithreaded_io: bool | None = None,
     _chunk_size: int | None = None,  # A hidden parameter for testing purposes.
 ) -> DataFrame:
@@ -39,6 +41,10 @@ def read_parquet(
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
         coerce_int96_timestamp_unit: TimeUnit to coerce Int96 TimeStamps to. e.g.: [ns, us, ms], Defaults to None.
+        manifest: Whether `path` is a directory written with `manifest=True` by
+            [`df.write_parquet()`][daft.DataFrame.write_parquet], whose files are read from its manifest rather than by
+            listing it. This reads a consistent snapshot of the directory, with the files of the writes which were
+            committed when it's read. Defaults to False.
         _multithreaded_io: Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
             the amount of system resources (number of connections and thread contention) when running in the Ray runner.
             Defaults to None, which will let Daft decide based on the runner it is currently using.
@@ -51,6 +57,7 @@ def read_parquet(
         >>> df = daft.read_parquet("/path/to/file.parquet")
         >>> df = daft.read_parquet("/path/to/directory")
         >>> df = daft.read_parquet("/path/to/files-*.parquet")
+        >>> df = daft.read_parquet("/path/to/directory", manifest=True)
 
         Read a Parquet file from a public S3 bucket:
         >>> from daft.io import S3Config, IOConfig
@@ -60,6 +67,11 @@ def read_parquet(
     """
     io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
+    if manifest:
+        if not isinstance(path, str):
+            raise ValueError("Only a single directory can be read from its manifest")
+        path = committed_files(path, io_config)
+
     if isinstance(path, list) and len(path) == 0:
         raise ValueError("Cannot read DataFrame from from empty list of Parquet filepaths")
 
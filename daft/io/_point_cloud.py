@@ -0,0 +1,341 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import TYPE_CHECKING, Any, Literal
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.datatype import DataType
+from daft.dependencies import np, pa
+from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.io.source import DataSource, DataSourceTask
+from daft.recordbatch import MicroPartition
+from daft.schema import Schema
+from daft.series import Series
+
+if TYPE_CHECKING:
+    from collections.abc import Callable, Iterator
+
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+    from daft.io.pushdowns import Pushdowns
+
+# PCD format details see: https://pointclouds.org/documentation/tutorials/pcd_file_format.html
+
+LAYOUT = Literal["struct", "tensor"]
+
+# The numpy dtypes of PCD fields, by their TYPE and SIZE.
+_PCD_TYPES = {
+    ("I", 1): "i1",
+    ("I", 2): "<i2",
+    ("I", 4): "<i4",
+    ("I", 8): "<i8",
+    ("U", 1): "u1",
+    ("U", 2): "<u2",
+    ("U", 4): "<u4",
+    ("U", 8): "<u8",
+    ("F", 4): "<f4",
+    ("F", 8): "<f8",
+}
+
+
+@dataclass
+class _Points:
+    """The coordinates of the points of a point cloud, and their intensities if it has any."""
+
+    x: Any
+    y: Any
+    z: Any
+    intensity: Any | None
+
+
+def _points_dtype(layout: LAYOUT) -> DataType:
+    if layout == "tensor":
+        return DataType.tensor(DataType.float64())
+    return DataType.list(
+        DataType.struct(
+            {
+                "x": DataType.float64(),
+                "y": DataType.float64(),
+                "z": DataType.float64(),
+                "intensity": DataType.float32(),
+            }
+        )
+    )
+
+
+def _schema(layout: LAYOUT) -> Schema:
+    return Schema.from_pydict({"path": DataType.string(), "points": _points_dtype(layout)})
+
+
+def _to_series(points: _Points, layout: LAYOUT) -> Series:
+    num_points = len(points.x)
+    if layout == "tensor":
+        # Point clouds without intensities have NaN intensities, so every tensor has a column for them.
+        intensity = np.full(num_points, np.nan) if points.intensity is None else points.intensity
+        tensor = np.stack([points.x, points.y, points.z, intensity], axis=1).astype(np.float64)
+        return Series.from_pylist([tensor], name="points", dtype=_points_dtype(layout))
+
+    intensity = (
+        pa.nulls(num_points, type=pa.float32())
+        if points.intensity is None
+        else pa.array(np.asarray(points.intensity, dtype=np.float32))
+    )
+    struct = pa.StructArray.from_arrays(
+        [
+            pa.array(np.asarray(points.x, dtype=np.float64)),
+            pa.array(np.asarray(points.y, dtype=np.float64)),
+            pa.array(np.asarray(points.z, dtype=np.float64)),
+            intensity,
+        ],
+        names=["x", "y", "z", "intensity"],
+    )
+    lists = pa.LargeListArray.from_arrays(pa.array([0, num_points], type=pa.int64()), struct)
+    return Series.from_arrow(lists, name="points")
+
+
+def _read_las(file: Any) -> _Points:
+    import laspy
+
+    las = laspy.read(file)
+    intensity = np.asarray(las.intensity) if "intensity" in las.point_format.dimension_names else None
+    return _Points(x=np.asarray(las.x), y=np.asarray(las.y), z=np.asarray(las.z), intensity=intensity)
+
+
+def _lzf_decompress(data: bytes, size: int) -> bytes:
+    """Decompresses LZF data, which is how the data of `binary_compressed` PCD files is compressed."""
+    out = bytearray()
+    i = 0
+    while i < len(data):
+        ctrl = data[i]
+        i += 1
+        if ctrl < 32:
+            # A run of ctrl + 1 literal bytes.
+            out += data[i : i + ctrl + 1]
+            i += ctrl + 1
+        else:
+            # A back-reference of at least 3 bytes, which may overlap the bytes it writes.
+            length = ctrl >> 5
+            if length == 7:
+                length += data[i]
+                i += 1
+            ref = len(out) - ((ctrl & 0x1F) << 8) - data[i] - 1
+            i += 1
+            if ref < 0:
+                raise ValueError("Invalid LZF data: back-reference before the start of the output")
+            for _ in range(length + 2):
+                out.append(out[ref])
+                ref += 1
+    if len(out) != size:
+        raise ValueError(f"Invalid LZF data: expected {size} bytes, got {len(out)}")
+    return bytes(out)
+
+
+def _read_pcd(file: Any) -> _Points:
+    data = file.read()
+    header: dict[str, list[str]] = {}
+    offset = 0
+    while "DATA" not in header:
+        end = data.find(b"\n", offset)
+        if end < 0:
+            raise ValueError("Invalid PCD file: missing DATA header line")
+        line = data[offset:end].decode("ascii").strip()
+        offset = end + 1
+        if line and not line.startswith("#"):
+            key, *values = line.split()
+            header[key.upper()] = values
+
+    names = header["FIELDS"]
+    sizes = [int(s) for s in header["SIZE"]]
+    types = header["TYPE"]
+    counts = [int(c) for c in header["COUNT"]] if "COUNT" in header else [1] * len(names)
+    num_points = int(header["POINTS"][0]) if "POINTS" in header else int(header["WIDTH"][0]) * int(header["HEIGHT"][0])
+    for name, size, type_ in zip(names, sizes, types):
+        if (type_, size) not in _PCD_TYPES:
+            raise ValueError(f"Unsupported PCD field {name} with type {type_} and size {size}")
+    dtypes = [np.dtype(_PCD_TYPES[(type_, size)]) for type_, size in zip(types, sizes)]
+
+    columns: dict[str, Any] = {}
+    encoding = header["DATA"][0].lower()
+    if encoding == "ascii":
+        values = np.loadtxt(data[offset:].decode("ascii").splitlines(), dtype=np.float64, ndmin=2)
+        column = 0
+        for name, count in zip(names, counts):
+            columns[name] = values[:num_points, column]
+            column += count
+    elif encoding == "binary":
+        # Points are stored one after another, with their fields interleaved.
+        record_dtype = np.dtype(
+            {
+                "names": [f"f{i}" for i in range(len(names))],
+                "formats": [(dtype, (count,)) if count > 1 else dtype for dtype, count in zip(dtypes, counts)],
+            }
+        )
+        records = np.frombuffer(data, dtype=record_dtype, count=num_points, offset=offset)
+        for i, name in enumerate(names):
+            columns[name] = records[f"f{i}"]
+    elif encoding == "binary_compressed":
+        # The values of each field are stored one after another, after compressing them all with LZF.
+        compressed_size, size = np.frombuffer(data, dtype="<u4", count=2, offset=offset)
+        begin = offset + 8
+        decompressed = _lzf_decompress(data[begin : begin + int(compressed_size)], int(size))
+        field_offset = 0
+        for name, dtype, count in zip(names, dtypes, counts):
+            values = np.frombuffer(decompressed, dtype=dtype, count=num_points * count, offset=field_offset)
+            columns[name] = values.reshape(num_points, count) if count > 1 else values
+            field_offset += num_points * count * dtype.itemsize
+    else:
+        raise ValueError(f"Unsupported PCD data encoding: {encoding}")
+
+    if missing := [name for name in ("x", "y", "z") if name not in columns]:
+        raise ValueError(f"PCD file is missing the fields {missing}, found: {names}")
+    return _Points(x=columns["x"], y=columns["y"], z=columns["z"], intensity=columns.get("intensity"))
+
+
+@dataclass
+class _PointCloudSource(DataSource):
+    """DataSource which reads point cloud files into rows of points, with a task per file."""
+
+    source_name: str
+    paths: list[str]
+    layout: LAYOUT
+    read_points: Callable[[Any], _Points]
+    io_config: IOConfig | None
+
+    @property
+    def name(self) -> str:
+        return self.source_name
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.layout)
+
+    def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
+        for path in self.paths:
+            yield _PointCloudSourceTask(
+                path=path, layout=self.layout, read_points=self.read_points, io_config=self.io_config
+            )
+
+
+@dataclass
+class _PointCloudSourceTask(DataSourceTask):
+    """DataSourceTask which reads the points of a point cloud file into a micropartition with a single row."""
+
+    path: str
+    layout: LAYOUT
+    read_points: Callable[[Any], _Points]
+    io_config: IOConfig | None
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.layout)
+
+    def get_micro_partitions(self) -> Iterator[MicroPartition]:
+        fp, fs, _ = _infer_filesystem(self.path, io_config=self.io_config)
+        with fs.open_input_file(fp) as file:
+            points = self.read_points(file)
+        yield MicroPartition.from_pydict(
+            {
+                "path": Series.from_pylist([self.path], name="path", dtype=DataType.string()),
+                "points": _to_series(points, self.layout),
+            }
+        )
+
+
+def _read_point_clouds(
+    source_name: str,
+    path: str | list[str],
+    layout: LAYOUT,
+    read_points: Callable[[Any], _Points],
+    io_config: IOConfig | None,
+    kind: str,
+) -> DataFrame:
+    if layout not in ("struct", "tensor"):
+        raise ValueError(f"Unsupported layout: {layout}, expected 'struct' or 'tensor'")
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError(f"Cannot read DataFrame from empty list of {kind} filepaths")
+
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = []
+    for p in [path] if isinstance(path, str) else path:
+        paths.extend(glob_path_with_stats(p, file_format=None, io_config=io_config).file_paths)
+    if not paths:
+        raise FileNotFoundError(f"No {kind} files found at {path}")
+
+    return _PointCloudSource(
+        source_name=source_name, paths=paths, layout=layout, read_points=read_points, io_config=io_config
+    ).read()
+
+
+@PublicAPI
+def read_las(
+    path: str | list[str],
+    layout: LAYOUT = "struct",
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from LAS or LAZ lidar file(s), with a row per file.
+
+    This produces a DataFrame with the following fields:
+        * path (string): path to the LAS or LAZ file.
+        * points: the points of the file, either as a list of structs with float64 ``x``, ``y`` and ``z`` coordinates,
+          which are scaled and offset into real-world units, and float32 ``intensity`` fields with
+          ``layout="struct"``, or as a float64 tensor of shape ``(num_points, 4)`` with ``layout="tensor"``.
+
+    Point clouds in the struct layout can be processed with [`voxel_downsample`][daft.functions.voxel_downsample] and
+    [`crop_points`][daft.functions.crop_points].
+
+    Warning:
+        This requires laspy which can be installed with `pip install laspy`. LAZ files also require a LAZ backend,
+        which can be installed with `pip install "laspy[lazrs]"`.
+
+    Args:
+        path (str|list[str]): Path(s) to the LAS or LAZ file(s), which allows wildcards.
+        layout (str): The layout of the points: "struct" or "tensor". Defaults to "struct".
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a row per point cloud.
+
+    Examples:
+        >>> from daft.functions import voxel_downsample
+        >>> df = daft.read_las("s3://bucket/tiles/*.laz")  # doctest: +SKIP
+        >>> df = df.with_column("points", voxel_downsample(df["points"], voxel_size=0.5))  # doctest: +SKIP
+    """
+    try:
+        import laspy  # noqa: F401
+    except ImportError as e:
+        raise ImportError("read_las requires laspy. Please install it with `pip install laspy`.") from e
+    return _read_point_clouds("LasSource", path, layout, _read_las, io_config, "LAS")
+
+
+@PublicAPI
+def read_pcd(
+    path: str | list[str],
+    layout: LAYOUT = "struct",
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from Point Cloud Data (PCD) file(s), with a row per file.
+
+    This produces a DataFrame with the following fields:
+        * path (string): path to the PCD file.
+        * points: the points of the file, either as a list of structs with float64 ``x``, ``y`` and ``z`` and float32
+          ``intensity`` fields with ``layout="struct"``, or as a float64 tensor of shape ``(num_points, 4)`` with
+          ``layout="tensor"``. Intensities are null, or NaN in tensors, for files without an ``intensity`` field.
+
+    The ``ascii``, ``binary`` and ``binary_compressed`` encodings are supported. Fields other than the coordinates and
+    intensity are not read.
+
+    Args:
+        path (str|list[str]): Path(s) to the PCD file(s), which allows wildcards.
+        layout (str): The layout of the points: "struct" or "tensor". Defaults to "struct".
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a row per point cloud.
+
+    Examples:
+        >>> from daft.functions import crop_points
+        >>> df = daft.read_pcd("scans/*.pcd")  # doctest: +SKIP
+        >>> df = df.with_column("points", crop_points(df["points"], (-10, -10, -2), (10, 10, 2)))  # doctest: +SKIP
+    """
+    return _read_point_clouds("PcdSource", path, layout, _read_pcd, io_config, "PCD")
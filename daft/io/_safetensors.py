@@ -0,0 +1,107 @@
+from __future__ import annotations
+
+import json
+import struct
+from typing import TYPE_CHECKING, Any
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.dependencies import np
+from daft.filesystem import _infer_filesystem
+from daft.io._numpy import _ArraySpec, _glob_paths, _read_array_files, _select
+
+if TYPE_CHECKING:
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+
+# safetensors format details see: https://github.com/huggingface/safetensors#format
+
+_DTYPES = {
+    "F64": "<f8",
+    "F32": "<f4",
+    "F16": "<f2",
+    "I64": "<i8",
+    "I32": "<i4",
+    "I16": "<i2",
+    "I8": "i1",
+    "U64": "<u8",
+    "U32": "<u4",
+    "U16": "<u2",
+    "U8": "u1",
+    "BOOL": "?",
+}
+
+
+def _numpy_dtype(dtype: str) -> Any:
+    # bfloat16 has no numpy equivalent, so it is widened to float32 when read.
+    if dtype == "BF16":
+        return np.dtype(np.float32)
+    if dtype not in _DTYPES:
+        raise ValueError(f"Unsupported safetensors dtype: {dtype}")
+    return np.dtype(_DTYPES[dtype])
+
+
+def _read_header(file: Any) -> tuple[dict[str, Any], int]:
+    """Reads the header of a safetensors file, returning its tensors by name and the offset of their data."""
+    (header_size,) = struct.unpack("<Q", file.read(8))
+    header = json.loads(file.read(header_size))
+    header.pop("__metadata__", None)
+    return header, 8 + header_size
+
+
+def _read_safetensors_specs(file: Any) -> dict[str, _ArraySpec]:
+    header, _ = _read_header(file)
+    return {
+        name: _ArraySpec(dtype=_numpy_dtype(info["dtype"]), shape=tuple(info["shape"])) for name, info in header.items()
+    }
+
+
+def _read_safetensors_arrays(file: Any, keys: list[str]) -> dict[str, Any]:
+    header, data_offset = _read_header(file)
+    arrays = {}
+    for key in keys:
+        info = header[key]
+        begin, end = info["data_offsets"]
+        file.seek(data_offset + begin)
+        data = file.read(end - begin)
+        if info["dtype"] == "BF16":
+            # bfloat16 values are the upper halves of float32 values.
+            array = (np.frombuffer(data, dtype="<u2").astype(np.uint32) << 16).view(np.float32)
+        else:
+            array = np.frombuffer(data, dtype=_numpy_dtype(info["dtype"]))
+        arrays[key] = array.reshape(info["shape"])
+    return arrays
+
+
+@PublicAPI
+def read_safetensors(
+    path: str | list[str],
+    columns: dict[str, str] | list[str] | None = None,
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from safetensors file(s), with a column for each tensor and a row for each element along their first axis.
+
+    Rows of multi-dimensional tensors become fixed-shape tensors, while 1-dimensional tensors are read as scalars, so
+    e.g. the tensors `ids` with shape `(N,)` and `embeddings` with shape `(N, 768)` are read as N rows of `ids` and
+    `embeddings` columns. The tensors which are read must have the same length along their first axis, and bfloat16
+    tensors are read as float32.
+
+    Args:
+        path (str|list[str]): Path(s) to the safetensors file(s), which allows wildcards.
+        columns (dict[str, str] | list[str], optional): The tensors to read, either as a list of their names or as a
+            mapping of their names to column names. Defaults to None, which reads all the tensors of the first file.
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a column for each tensor.
+
+    Examples:
+        >>> df = daft.read_safetensors("model.safetensors", columns={"embed_tokens.weight": "embedding"})  # doctest: +SKIP
+    """
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = _glob_paths(path, io_config, "safetensors")
+    fp, fs, _ = _infer_filesystem(paths[0], io_config=io_config)
+    with fs.open_input_file(fp) as file:
+        specs = _read_safetensors_specs(file)
+    selected = _select(specs, columns, paths[0])
+    return _read_array_files("SafetensorsSource", paths, specs, selected, _read_safetensors_arrays, io_config)
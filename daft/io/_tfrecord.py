@@ -0,0 +1,285 @@
+from __future__ import annotations
+
+import struct
+from dataclasses import dataclass
+from itertools import islice
+from typing import TYPE_CHECKING, Any, Literal
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.datatype import DataType
+from daft.dependencies import pa
+from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.io.source import DataSource, DataSourceTask
+from daft.recordbatch import MicroPartition
+from daft.schema import Schema
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+    from daft.io.pushdowns import Pushdowns
+
+# TFRecord format details see: https://www.tensorflow.org/tutorials/load_data/tfrecord#tfrecords_format_details
+# Example message see: https://github.com/tensorflow/tensorflow/blob/master/tensorflow/core/example/feature.proto
+
+TFRECORD_COMPRESSION = Literal["gzip"]
+# The kinds of feature lists in an Example, by their field number in the `Feature` message.
+_FEATURE_KINDS = {1: "bytes", 2: "float", 3: "int64"}
+# The number of records of the first file which are read to infer the features of the examples.
+_NUM_RECORDS_TO_INFER = 64
+
+
+def _iter_records(file: Any) -> Iterator[bytes]:
+    """Reads the records of a TFRecord file, each framed by its length and CRCs, which aren't verified."""
+    while True:
+        header = file.read(12)
+        if not header:
+            return
+        if len(header) < 12:
+            raise ValueError("Truncated TFRecord file: incomplete record header")
+        (length,) = struct.unpack("<Q", header[:8])
+        data = file.read(length + 4)
+        if len(data) < length + 4:
+            raise ValueError("Truncated TFRecord file: incomplete record")
+        yield data[:length]
+
+
+def _read_varint(buf: bytes, pos: int) -> tuple[int, int]:
+    result = 0
+    shift = 0
+    while True:
+        b = buf[pos]
+        pos += 1
+        result |= (b & 0x7F) << shift
+        if not b & 0x80:
+            return result, pos
+        shift += 7
+
+
+def _iter_fields(buf: bytes) -> Iterator[tuple[int, int, Any]]:
+    """Decodes the fields of a protobuf message as (field number, wire type, value) tuples."""
+    pos = 0
+    while pos < len(buf):
+        tag, pos = _read_varint(buf, pos)
+        number, wire_type = tag >> 3, tag & 0x7
+        if wire_type == 0:
+            value, pos = _read_varint(buf, pos)
+        elif wire_type == 1:
+            value, pos = buf[pos : pos + 8], pos + 8
+        elif wire_type == 2:
+            length, pos = _read_varint(buf, pos)
+            value, pos = buf[pos : pos + length], pos + length
+        elif wire_type == 5:
+            value, pos = buf[pos : pos + 4], pos + 4
+        else:
+            raise ValueError(f"Unsupported protobuf wire type: {wire_type}")
+        yield number, wire_type, value
+
+
+def _to_int64(value: int) -> int:
+    return value - (1 << 64) if value >= 1 << 63 else value
+
+
+def _parse_feature(buf: bytes) -> tuple[str, list[Any]] | None:
+    """Parses a `Feature` message, which holds a single list of bytes, floats or int64s."""
+    for number, _, feature_list in _iter_fields(buf):
+        kind = _FEATURE_KINDS.get(number)
+        if kind is None:
+            continue
+        values: list[Any] = []
+        for _, wire_type, value in _iter_fields(feature_list):
+            if kind == "bytes":
+                values.append(bytes(value))
+            elif kind == "float" and wire_type == 2:
+                values.extend(struct.unpack(f"<{len(value) // 4}f", value))
+            elif kind == "float":
+                values.append(struct.unpack("<f", value)[0])
+            elif wire_type == 2:
+                pos = 0
+                while pos < len(value):
+                    v, pos = _read_varint(value, pos)
+                    values.append(_to_int64(v))
+            else:
+                values.append(_to_int64(value))
+        return kind, values
+    return None
+
+
+def _parse_example(record: bytes) -> dict[str, tuple[str, list[Any]]]:
+    """Parses an `Example` message into its features by name, without depending on TensorFlow or protobuf."""
+    features: dict[str, tuple[str, list[Any]]] = {}
+    for number, _, features_msg in _iter_fields(record):
+        if number != 1:
+            continue
+        for entry_number, _, entry in _iter_fields(features_msg):
+            if entry_number != 1:
+                continue
+            name = None
+            feature = None
+            for field_number, _, value in _iter_fields(entry):
+                if field_number == 1:
+                    name = bytes(value).decode("utf-8")
+                elif field_number == 2:
+                    feature = _parse_feature(value)
+            if name is not None and feature is not None:
+                features[name] = feature
+    return features
+
+
+def _dtype(kind: str) -> DataType:
+    return {
+        "bytes": DataType.list(DataType.binary()),
+        "float": DataType.list(DataType.float32()),
+        "int64": DataType.list(DataType.int64()),
+    }[kind]
+
+
+def _open(path: str, compression: TFRECORD_COMPRESSION | None, io_config: IOConfig | None) -> Any:
+    fp, fs, _ = _infer_filesystem(path, io_config=io_config)
+    if compression is None and path.endswith(".gz"):
+        compression = "gzip"
+    return fs.open_input_stream(fp, compression=compression)
+
+
+def _infer_features(path: str, compression: TFRECORD_COMPRESSION | None, io_config: IOConfig | None) -> dict[str, str]:
+    features: dict[str, str] = {}
+    with _open(path, compression, io_config) as file:
+        for record in islice(_iter_records(file), _NUM_RECORDS_TO_INFER):
+            for name, (kind, _) in _parse_example(record).items():
+                features.setdefault(name, kind)
+    return features
+
+
+def _schema(features: dict[str, str] | None) -> Schema:
+    if features is None:
+        return Schema.from_pydict({"record": DataType.binary()})
+    return Schema.from_pydict({name: _dtype(kind) for name, kind in features.items()})
+
+
+@dataclass
+class _TFRecordSource(DataSource):
+    """DataSource which reads the records of TFRecord files, with a task per file."""
+
+    paths: list[str]
+    features: dict[str, str] | None
+    compression: TFRECORD_COMPRESSION | None
+    io_config: IOConfig | None
+
+    @property
+    def name(self) -> str:
+        return "TFRecordSource"
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.features)
+
+    def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
+        for path in self.paths:
+            yield _TFRecordSourceTask(
+                path=path,
+                features=self.features,
+                compression=self.compression,
+                io_config=self.io_config,
+            )
+
+
+@dataclass
+class _TFRecordSourceTask(DataSourceTask):
+    """DataSourceTask which streams the records of a TFRecord file as micropartitions."""
+
+    path: str
+    features: dict[str, str] | None
+    compression: TFRECORD_COMPRESSION | None
+    io_config: IOConfig | None
+
+    _max_partition_size = 64 * 1024 * 1024  # 64 MB
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.features)
+
+    def get_micro_partitions(self) -> Iterator[MicroPartition]:
+        with _open(self.path, self.compression, self.io_config) as file:
+            buffer: list[bytes] = []
+            size = 0
+            for record in _iter_records(file):
+                buffer.append(record)
+                size += len(record)
+                # yield when full
+                if size >= self._max_partition_size:
+                    yield self._to_micropartition(buffer)
+                    buffer = []
+                    size = 0
+            # yield if non-empty
+            if buffer:
+                yield self._to_micropartition(buffer)
+
+    def _to_micropartition(self, records: list[bytes]) -> MicroPartition:
+        if self.features is None:
+            return MicroPartition.from_arrow(pa.table({"record": pa.array(records, type=pa.large_binary())}))
+
+        columns: dict[str, list[Any]] = {name: [] for name in self.features}
+        for record in records:
+            example = _parse_example(record)
+            for name, kind in self.features.items():
+                feature = example.get(name)
+                # Features of another kind than inferred are treated as missing rather than coerced.
+                columns[name].append(feature[1] if feature is not None and feature[0] == kind else None)
+        return MicroPartition.from_arrow(
+            pa.table(
+                {
+                    name: pa.array(values, type=_dtype(self.features[name]).to_arrow_dtype())
+                    for name, values in columns.items()
+                }
+            )
+        )
+
+
+@PublicAPI
+def read_tfrecord(
+    path: str | list[str],
+    parse_examples: bool = True,
+    compression: TFRECORD_COMPRESSION | None = None,
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from TFRecord file(s).
+
+    By default, each record is parsed as a `tf.train.Example`, with a column for each of its features: a list of
+    binary, float32 or int64 values depending on the kind of the feature. Features are inferred from the first records
+    of the first file, features which only appear later are ignored, and missing features are null. Parsing doesn't
+    require TensorFlow.
+
+    Note:
+        Record CRCs are not verified.
+
+    Args:
+        path (str|list[str]): Path(s) to the TFRecord file(s), which allows wildcards.
+        parse_examples (bool): Whether to parse the records as `tf.train.Example` messages. If False, the DataFrame
+            has a single binary column "record" with the serialized records. Defaults to True.
+        compression (str, optional): The compression of the files, either "gzip" or None to infer it from the file
+            extension. Defaults to None.
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a row per record.
+
+    Examples:
+        >>> df = daft.read_tfrecord("s3://bucket/train-*.tfrecord")  # doctest: +SKIP
+        >>> df = daft.read_tfrecord("s3://bucket/train-*.tfrecord.gz", parse_examples=False)  # doctest: +SKIP
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from empty list of TFRecord filepaths")
+    if compression is not None and compression != "gzip":
+        raise ValueError(f"Unsupported TFRecord compression: {compression}, expected 'gzip' or None")
+
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = []
+    for p in [path] if isinstance(path, str) else path:
+        paths.extend(glob_path_with_stats(p, file_format=None, io_config=io_config).file_paths)
+    if not paths:
+        raise FileNotFoundError(f"No TFRecord files found at {path}")
+
+    features = _infer_features(paths[0], compression, io_config) if parse_examples else None
+    return _TFRecordSource(paths=paths, features=features, compression=compression, io_config=io_config).read()
@@ -0,0 +1,207 @@
+# ruff: noqa: I002
+# isort: dont-add-import: from __future__ import annotations
+import datetime
+import math
+import os
+import pathlib
+from typing import TYPE_CHECKING, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.dataframe import DataFrame
+from daft.dependencies import pa, pafs
+from daft.filesystem import _resolve_paths_and_filesystem, _unwrap_protocol, get_protocol_from_path, join_path
+from daft.io._manifest import MANIFEST_DIR_NAME, is_commit_file, read_manifest
+
+if TYPE_CHECKING:
+    import deltalake
+    from pyiceberg.table import Table as PyIcebergTable
+
+    from daft.daft import IOConfig
+
+# The directory under the root directory of a write that staged writes write their files to until they're committed.
+_STAGING_DIR_NAME = "_daft_staging"
+
+
+@PublicAPI
+def vacuum(
+    table: Union[str, pathlib.Path, "deltalake.DeltaTable", "PyIcebergTable"],
+    retention: datetime.timedelta = datetime.timedelta(days=7),
+    dry_run: bool = True,
+    io_config: "IOConfig | None" = None,
+) -> DataFrame:
+    """Removes the data files of a table which no version of it references, and which are older than a retention window.
+
+    What's removed depends on the table:
+
+    * Delta Lake tables, given as a `deltalake.DeltaTable` or as the URI of a directory with a `_delta_log`, are
+        vacuumed by Delta Lake, which removes the files that were removed from the table before the retention window.
+    * Iceberg tables, given as a PyIceberg table, have the files in their data directory removed which no snapshot of
+        the table references, such as the files of failed writes and of expired snapshots.
+    * Other directories have the files of staged writes which never committed removed from their `_daft_staging`
+        directory. If the directory was written with `manifest=True`, the Parquet files which no commit to its
+        manifest references, and the commit files which were never moved into place, are removed too.
+
+    Files written within the retention window are never removed, so that the files of writes which haven't committed
+    yet, and of versions which are still being read, are kept.
+
+    Args:
+        table: The table or directory to vacuum.
+        retention: How old files must be to be removed. For Delta Lake tables, this is rounded up to whole hours and
+            replaces the table's `delta.deletedFileRetentionDuration`. Defaults to 7 days.
+        dry_run: Whether to only list the files which would be removed, without removing them. Defaults to True.
+        io_config: A custom IOConfig to use when accessing the table's storage.
+
+    Returns:
+        DataFrame: The files which were removed, or would be removed on a dry run, with their `path`, `size` in bytes
+            and `last_modified` time, which are null if they're unknown.
+
+    Examples:
+        >>> df = daft.io.vacuum("s3://my-bucket/my-deltalake-table")  # doctest: +SKIP
+        >>> from datetime import timedelta
+        >>> df = daft.io.vacuum("/path/to/directory", retention=timedelta(days=1), dry_run=False)  # doctest: +SKIP
+    """
+    from daft import from_arrow
+
+    if retention < datetime.timedelta(0):
+        raise ValueError(f"retention must be non-negative, got {retention}")
+    cutoff = datetime.datetime.now(datetime.timezone.utc) - retention
+
+    if isinstance(table, (str, pathlib.Path)) or _is_deltalake_table(table):
+        io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    if isinstance(table, (str, pathlib.Path)):
+        table_uri = os.path.expanduser(str(table)).rstrip("/")
+        [root_dir], fs = _resolve_paths_and_filesystem(table_uri, io_config=io_config)
+        if fs.get_file_info(join_path(fs, root_dir, "_delta_log")).type == pafs.FileType.Directory:
+            files = _vacuum_deltalake(table_uri, retention, dry_run, io_config)
+        else:
+            files = _vacuum_directory(table_uri, cutoff, dry_run, io_config)
+    elif _is_deltalake_table(table):
+        files = _vacuum_deltalake(table, retention, dry_run, io_config)
+    else:
+        files = _vacuum_iceberg(table, cutoff, dry_run, io_config)
+
+    return from_arrow(
+        pa.table(
+            {
+                "path": [path for path, _ in files],
+                "size": [info.size if info is not None else None for _, info in files],
+                "last_modified": [info.mtime if info is not None else None for _, info in files],
+            },
+            schema=pa.schema(
+                [("path", pa.string()), ("size", pa.int64()), ("last_modified", pa.timestamp("us", tz="UTC"))]
+            ),
+        )
+    )
+
+
+def _is_deltalake_table(table: object) -> bool:
+    try:
+        import deltalake
+    except ImportError:
+        return False
+    return isinstance(table, deltalake.DeltaTable)
+
+
+def _with_protocol(uri: str, path: str) -> str:
+    """Returns a path listed from the filesystem of a URI with the URI's protocol, which the filesystem drops."""
+    protocol = get_protocol_from_path(uri)
+    return path if protocol == "file" else f"{protocol}://{path}"
+
+
+def _remove(
+    uri: str, fs: pafs.FileSystem, candidates: list[pafs.FileInfo], dry_run: bool
+) -> list[tuple[str, pafs.FileInfo]]:
+    if not dry_run:
+        for info in candidates:
+            fs.delete_file(info.path)
+    return [(_with_protocol(uri, info.path), info) for info in candidates]
+
+
+def _is_expired(info: pafs.FileInfo, cutoff: datetime.datetime) -> bool:
+    # A file whose modification time is unknown may have just been written, so it's kept.
+    return info.type == pafs.FileType.File and info.mtime is not None and info.mtime < cutoff
+
+
+def _vacuum_directory(
+    uri: str, cutoff: datetime.datetime, dry_run: bool, io_config: "IOConfig | None"
+) -> list[tuple[str, pafs.FileInfo]]:
+    [root_dir], fs = _resolve_paths_and_filesystem(uri, io_config=io_config)
+    staging_dir = join_path(fs, root_dir, _STAGING_DIR_NAME)
+    manifest_dir = join_path(fs, root_dir, MANIFEST_DIR_NAME)
+
+    committed = read_manifest(fs, root_dir)
+    referenced = {join_path(fs, root_dir, *file.split("/")) for file in committed} if committed is not None else None
+
+    candidates = []
+    for info in fs.get_file_info(pafs.FileSelector(root_dir, recursive=True, allow_not_found=True)):
+        if not _is_expired(info, cutoff):
+            continue
+        if info.path.startswith(staging_dir + "/"):
+            candidates.append(info)
+        elif info.path.startswith(manifest_dir + "/"):
+            if not is_commit_file(info) and info.base_name.endswith(".tmp"):
+                candidates.append(info)
+        elif referenced is not None and info.path.endswith(".parquet") and info.path not in referenced:
+            candidates.append(info)
+    return _remove(uri, fs, candidates, dry_run)
+
+
+def _vacuum_deltalake(
+    table: Union[str, "deltalake.DeltaTable"],
+    retention: datetime.timedelta,
+    dry_run: bool,
+    io_config: "IOConfig | None",
+) -> list[tuple[str, pafs.FileInfo | None]]:
+    import deltalake
+
+    from daft.io.object_store_options import io_config_to_storage_options
+
+    if isinstance(table, str):
+        table = deltalake.DeltaTable(table, storage_options=io_config_to_storage_options(io_config, table) or {})
+    table_uri = table.table_uri.rstrip("/")
+    retention_hours = math.ceil(retention.total_seconds() / 3600)
+
+    # Delta Lake returns the paths of the files relative to the table, so they're listed before they're removed.
+    def vacuum(dry_run: bool) -> list[str]:
+        return [
+            path if "://" in path or path.startswith("/") else f"{table_uri}/{path}"
+            for path in table.vacuum(retention_hours=retention_hours, dry_run=dry_run, enforce_retention_duration=False)
+        ]
+
+    candidates = vacuum(dry_run=True)
+    infos = {}
+    if candidates:
+        resolved, fs = _resolve_paths_and_filesystem(candidates, io_config=io_config)
+        infos = dict(zip(candidates, fs.get_file_info(resolved)))
+    files = candidates if dry_run else vacuum(dry_run=False)
+    return [(path, infos.get(path)) for path in files]
+
+
+def _vacuum_iceberg(
+    table: "PyIcebergTable",
+    cutoff: datetime.datetime,
+    dry_run: bool,
+    io_config: "IOConfig | None",
+) -> list[tuple[str, pafs.FileInfo]]:
+    from daft.io.iceberg._iceberg import _convert_iceberg_file_io_properties_to_io_config
+
+    if io_config is None:
+        io_config = _convert_iceberg_file_io_properties_to_io_config(table.io.properties)
+
+    # Every file in a manifest of a snapshot is referenced, including the files which the snapshot deleted, which the
+    # snapshots before it may still reference.
+    referenced = set()
+    for snapshot in table.metadata.snapshots:
+        for manifest in snapshot.manifests(table.io):
+            for entry in manifest.fetch_manifest_entry(table.io, discard_deleted=False):
+                referenced.add(_unwrap_protocol(entry.data_file.file_path))
+
+    data_dir = table.properties.get("write.data.path", f"{table.location().rstrip('/')}/data")
+    [resolved_data_dir], fs = _resolve_paths_and_filesystem(data_dir, io_config=io_config)
+    candidates = [
+        info
+        for info in fs.get_file_info(pafs.FileSelector(resolved_data_dir, recursive=True, allow_not_found=True))
+        if _is_expired(info, cutoff) and _unwrap_protocol(info.path) not in referenced
+    ]
+    return _remove(data_dir, fs, candidates, dry_run)
@@ -0,0 +1,187 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from itertools import islice
+from typing import TYPE_CHECKING, Any
+
+from daft.api_annotations import PublicAPI
+from daft.context import get_context
+from daft.datatype import DataType
+from daft.dependencies import pa
+from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.io._archive import _iter_tar_members
+from daft.io.source import DataSource, DataSourceTask
+from daft.recordbatch import MicroPartition
+from daft.schema import Schema
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+    from daft.daft import IOConfig
+    from daft.dataframe import DataFrame
+    from daft.io.pushdowns import Pushdowns
+
+# WebDataset format details see: https://github.com/webdataset/webdataset#the-webdataset-format
+
+# The number of samples of the first shard which are read to infer the fields of the samples.
+_NUM_SAMPLES_TO_INFER = 64
+
+
+def _split_key(path: str) -> tuple[str, str]:
+    """Splits a member path into its sample key and field, i.e. its extension after the first dot of its basename."""
+    dirname, _, basename = path.rpartition("/")
+    stem, dot, extension = basename.partition(".")
+    if not dot:
+        return path, ""
+    return f"{dirname}/{stem}" if dirname else stem, extension
+
+
+def _iter_samples(file: Any) -> Iterator[tuple[str, dict[str, bytes]]]:
+    """Groups consecutive members of a shard which share a key into samples."""
+    key = None
+    sample: dict[str, bytes] = {}
+    for member in _iter_tar_members(file):
+        # Files starting with a dot, e.g. macOS metadata, and files without an extension aren't part of any sample.
+        if member.path.rpartition("/")[2].startswith("."):
+            continue
+        member_key, field = _split_key(member.path)
+        if not field:
+            continue
+        if member_key != key:
+            if key is not None:
+                yield key, sample
+            key, sample = member_key, {}
+        sample[field] = member.data
+    if key is not None:
+        yield key, sample
+
+
+def _infer_fields(path: str, io_config: IOConfig | None) -> list[str]:
+    fp, fs, _ = _infer_filesystem(path, io_config=io_config)
+    fields: dict[str, None] = {}
+    with fs.open_input_stream(fp) as file:
+        for _, sample in islice(_iter_samples(file), _NUM_SAMPLES_TO_INFER):
+            fields.update(dict.fromkeys(sample))
+    return list(fields)
+
+
+def _schema(fields: list[str]) -> Schema:
+    return Schema.from_pydict(
+        {
+            "__key__": DataType.string(),
+            "__url__": DataType.string(),
+            **{field: DataType.binary() for field in fields},
+        }
+    )
+
+
+@dataclass
+class _WebDatasetSource(DataSource):
+    """DataSource which reads the samples of WebDataset shards, with a task per shard."""
+
+    paths: list[str]
+    fields: list[str]
+    io_config: IOConfig | None
+
+    @property
+    def name(self) -> str:
+        return "WebDatasetSource"
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.fields)
+
+    def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
+        for path in self.paths:
+            yield _WebDatasetSourceTask(path=path, fields=self.fields, io_config=self.io_config)
+
+
+@dataclass
+class _WebDatasetSourceTask(DataSourceTask):
+    """DataSourceTask which streams the samples of a shard as micropartitions."""
+
+    path: str
+    fields: list[str]
+    io_config: IOConfig | None
+
+    _max_partition_size = 64 * 1024 * 1024  # 64 MB
+
+    @property
+    def schema(self) -> Schema:
+        return _schema(self.fields)
+
+    def get_micro_partitions(self) -> Iterator[MicroPartition]:
+        fp, fs, _ = _infer_filesystem(self.path, io_config=self.io_config)
+        with fs.open_input_stream(fp) as file:
+            buffer: list[tuple[str, dict[str, bytes]]] = []
+            size = 0
+            for key, sample in _iter_samples(file):
+                buffer.append((key, sample))
+                size += sum(len(data) for data in sample.values())
+                # yield when full
+                if size >= self._max_partition_size:
+                    yield self._to_micropartition(buffer)
+                    buffer = []
+                    size = 0
+            # yield if non-empty
+            if buffer:
+                yield self._to_micropartition(buffer)
+
+    def _to_micropartition(self, samples: list[tuple[str, dict[str, bytes]]]) -> MicroPartition:
+        columns = {
+            "__key__": pa.array([key for key, _ in samples], type=pa.large_string()),
+            "__url__": pa.array([self.path] * len(samples), type=pa.large_string()),
+        }
+        for field in self.fields:
+            columns[field] = pa.array([sample.get(field) for _, sample in samples], type=pa.large_binary())
+        return MicroPartition.from_arrow(pa.table(columns))
+
+
+@PublicAPI
+def read_webdataset(
+    path: str | list[str],
+    fields: list[str] | None = None,
+    io_config: IOConfig | None = None,
+) -> DataFrame:
+    """Creates a DataFrame from the samples of WebDataset tar shards.
+
+    A WebDataset shard is a tar archive in which the files of each sample are stored consecutively and share a key,
+    their path up to the first dot of their basename, e.g. `images/0001.jpg` and `images/0001.json` are the `jpg` and
+    `json` fields of sample `images/0001`. Each sample becomes a row with the following fields:
+        * __key__ (string): the key of the sample.
+        * __url__ (string): path to the shard that contains the sample.
+        * a binary column for each field, which is null for samples without that field.
+
+    Shards, which may be compressed with gzip, bzip2 or xz, are streamed so only a partition's worth of samples is
+    held in memory at a time.
+
+    Args:
+        path (str|list[str]): Path(s) to the shard(s), which allows wildcards.
+        fields (list[str], optional): The fields of the samples to read, e.g. `["jpg", "json"]`. If None, they are
+            inferred from the first samples of the first shard, and fields which only appear later are ignored.
+        io_config (IOConfig, optional): Config to be used with the native downloader.
+
+    Returns:
+        DataFrame: a DataFrame with a row per sample.
+
+    Examples:
+        >>> df = daft.read_webdataset("s3://bucket/shards/shard-*.tar")  # doctest: +SKIP
+        >>> df = daft.read_webdataset("s3://bucket/shards/*.tar", fields=["jpg", "cls"])  # doctest: +SKIP
+        >>> df = df.with_column("image", df["jpg"].decode_image())  # doctest: +SKIP
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from empty list of WebDataset shards")
+
+    io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    paths = []
+    for p in [path] if isinstance(path, str) else path:
+        paths.extend(glob_path_with_stats(p, file_format=None, io_config=io_config).file_paths)
+    if not paths:
+        raise FileNotFoundError(f"No WebDataset shards found at {path}")
+
+    if fields is None:
+        fields = _infer_fields(paths[0], io_config)
+    elif reserved := {"__key__", "__url__"} & set(fields):
+        raise ValueError(f"WebDataset fields can't be named {sorted(reserved)}")
+
+    return _WebDatasetSource(paths=paths, fields=fields, io_config=io_config).read()
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Literal
 
 from daft.context import get_context
 
@@ -28,6 +28,21 @@ def read_video_frames(
     image_width: int,
     is_key_frame: bool | None = None,
     io_config: IOConfig | None = None,
+    sample_every_n: int | None = None,
+    target_fps: float | None = None,
+    max_frames: int | None = None,
+    start_time: float | None = None,
+    end_time: float | None = None,
+    frame_buffer_size: int | None = None,
+    pixel_format: Literal["rgb24", "rgba", "gray8", "rgb48", "gray16", "yuv"] = "rgb24",
+    tone_map: Literal["hable", "reinhard", "clip"] | None = "hable",
+    crop: tuple[int, int, int, int] | None = None,
+    stream: int | str | None = None,
+    autorotate: bool = True,
+    chunk_duration: float | None = None,
+    export_mvs: bool = False,
+    on_error: Literal["fail", "skip", "null"] = "fail",
+    streaming: bool = False,
 ) -> DataFrame:
     """Creates a DataFrame by reading the frames of one or more video files.
 
@@ -40,6 +55,9 @@ def read_video_frames(
         * frame_dts (int): frame decoding timestamp in time_base units.
         * frame_duration (int): frame duration in time_base units.
         * is_key_frame (bool): true iff this is a key frame.
+        * data (image|tensor): the frame, in the given pixel format.
+        * motion_vectors (list[struct]): the motion vectors of the frame, only if `export_mvs` is set.
+        * picture_type (str): the type of the frame's picture, such as "I", "P" or "B", only if `export_mvs` is set.
 
     Warning:
         This requires PyAV which can be installed with `pip install av`.
@@ -50,11 +68,63 @@ def read_video_frames(
         read_glob_path and url.download.
 
     Args:
-        path (str|list[str]): Path(s) to the video file(s) which allows wildcards.
+        path (str|list[str]): Path(s) to the video file(s) which allows wildcards. A video within a tar archive, which
+            may be compressed, or within a zip archive, is given by its path within the archive chained to the path of
+            the archive, such as `tar://clips/a.mp4::s3://bucket/shard.tar.gz` or `zip://a.mp4::/path/to/videos.zip`,
+            and is read as a stream as it's extracted, without extracting the archive.
         image_height (int): Height to which each frame will be resized.
         image_width (int): Width to which each frame will be resized.
         is_key_frame (bool|None): If True, only include key frames; if False, only non-key frames; if None, include all frames.
         io_config (IOConfig|None): Optional IOConfig.
+        sample_every_n (int|None): If set, only read every nth frame of each video, starting with the first.
+        target_fps (float|None): If set, read at most this many frames per second of each video.
+        max_frames (int|None): If set, read at most this many frames of each video.
+        start_time (float|None): If set, only read the frames at or after this time, in seconds. This seeks to the key
+            frame at or before the time, so the frames before it aren't decoded.
+        end_time (float|None): If set, only read the frames at or before this time, in seconds.
+        frame_buffer_size (int|None): If set, decode frames on a background thread, at most this many frames ahead of
+            the frames being read. Decoding pauses while the buffer is full, which bounds the memory held by decoded
+            frames.
+        pixel_format (str): The pixel format of the frames: "rgb24" for RGB images, "rgba" for RGBA images which keep
+            the alpha channel, "gray8" for grayscale images, "rgb48" and "gray16" for RGB and grayscale images with 16
+            bits per channel, which keep the precision of 10-bit and HDR videos, or "yuv" for the frames as decoded,
+            without converting them to RGB, as tensors of bytes. Defaults to "rgb24".
+        tone_map (str|None): How the frames of HDR videos, which use the PQ or HLG transfer functions, are tone-mapped
+            when they're read into 8-bit images: "hable" for a filmic curve which keeps detail in highlights,
+            "reinhard" for a curve which keeps midtones brighter, or "clip" to clip everything brighter than SDR white.
+            If None, HDR frames are converted as they are, which washes out their colors. This doesn't apply to 16-bit
+            images, which keep the signal of the video. Defaults to "hable".
+        crop (tuple[int, int, int, int]|None): If set, the rectangle of each frame to keep, as `(x, y, width, height)`
+            in pixels of the frames as displayed. Frames are cropped and then resized as they are decoded, so the full
+            resolution frames are never converted to images.
+        stream (int|str|None): The video stream to read from videos with several video streams, such as multi-angle
+            videos, by its index among the video streams or by its language tag. Defaults to the first video stream.
+            The video streams of a video are listed by `daft.functions.video_metadata`.
+        autorotate (bool): Whether to rotate frames as they are displayed, for videos with rotation metadata such as
+            videos recorded by phones held upright, before they are cropped and resized. Defaults to True.
+        chunk_duration (float|None): If set, split each video into chunks of at least this many seconds, which start
+            at key frames, and decode the chunks in parallel tasks rather than each video in a single task. The key
+            frames of each video are found when the read is planned, by reading its packets without decoding them.
+            This can't be used with `max_frames`.
+        export_mvs (bool): Whether to read the motion vectors which the decoder exports for each frame, and the type
+            of each frame's picture, into the `motion_vectors` and `picture_type` columns. These are much cheaper to
+            compute on than the frames themselves, such as to find the parts of videos with motion. Each motion vector
+            has the size of its block (`width` and `height`), whether it refers to a past (-1) or future (1) frame
+            (`source`), the positions of its block in the reference frame and in this frame (`src_x`, `src_y`,
+            `dst_x` and `dst_y`), and its motion in units of 1/`motion_scale` pixels (`motion_x` and `motion_y`).
+            Positions are in pixels of the frames as coded, before they're rotated, cropped or resized. Key frames
+            have no motion vectors. Only some decoders, such as those of H.264 and MPEG-4 Part 2, export them.
+            Defaults to False.
+        on_error (str): What to do with the frames which fail to decode, such as the frames of a corrupt packet, so
+            that a corrupt video doesn't fail a read of many videos: "fail" to raise the error, "skip" to leave the
+            frames out and carry on decoding the frames after them, or "null" to read each of them as a row whose
+            frame and timestamps are null. The frames which failed to decode are counted in the decoder's statistics,
+            and logged as a warning. Errors opening a video always fail. Defaults to "fail".
+        streaming (bool): Whether to read each video once from its start to its end as a stream, without seeking, so
+            that it's decoded as it's downloaded, such as from a server which doesn't support range requests, rather
+            than being buffered in full first. The container of a streamed video must be streamable, such as MPEG-TS,
+            Matroska or an MP4 whose moov atom comes before its media data. The frames before `start_time` are then
+            decoded and skipped, and this can't be used with `chunk_duration`. Defaults to False.
 
     Returns:
         DataFrame: dataframe of images.
@@ -64,9 +134,15 @@ def read_video_frames(
         >>> df = daft.read_video_frames("/path/to/directory", image_height=480, image_width=640)
         >>> df = daft.read_video_frames("/path/to/files-*.mp4", image_height=480, image_width=640)
         >>> df = daft.read_video_frames("s3://path/to/files-*.mp4", image_height=480, image_width=640)
+        >>> df = daft.read_video_frames("/path/to/file.mp4", image_height=480, image_width=640, target_fps=1)
+        >>> df = daft.read_video_frames("/path/to/file.mp4", image_height=480, image_width=640, pixel_format="gray8")
+        >>> df = daft.read_video_frames("/path/to/hdr.mp4", image_height=480, image_width=640, pixel_format="rgb48")
+        >>> df = daft.read_video_frames("/path/to/file.mp4", image_height=224, image_width=224, crop=(280, 0, 720, 720))
+        >>> df = daft.read_video_frames("/path/to/file.mp4", image_height=224, image_width=224, export_mvs=True)
+        >>> df = daft.read_video_frames("tar://a.mp4::s3://bucket/shard.tar.gz", image_height=224, image_width=224)
     """
     try:
-        from daft.io.av._read_video_frames import _VideoFramesSource
+        from daft.io.av._read_video_frames import DecodeOptions, _VideoFramesSource
     except ImportError as e:
         raise ImportError("read_video_frames requires PyAV. Please install it with `pip install av`.") from e
 
@@ -77,4 +153,21 @@ def read_video_frames(
         image_width=image_width,
         is_key_frame=is_key_frame,
         io_config=io_config,
+        decode_options=DecodeOptions(
+            sample_every_n=sample_every_n,
+            target_fps=target_fps,
+            max_frames=max_frames,
+            start_time=start_time,
+            end_time=end_time,
+            frame_buffer_size=frame_buffer_size,
+            on_error=on_error,
+        ),
+        pixel_format=pixel_format,
+        tone_map=tone_map,
+        crop=crop,
+        stream=stream,
+        autorotate=autorotate,
+        chunk_duration=chunk_duration,
+        export_mvs=export_mvs,
+        streaming=streaming,
     ).read()
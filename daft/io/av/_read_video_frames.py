@@ -1,39 +1,355 @@
 from __future__ import annotations
 
+import logging
+import math
 import os
+import queue
+import re
+import tarfile
 import tempfile
-from contextlib import contextmanager
-from dataclasses import dataclass
-from typing import TYPE_CHECKING, Any, TypeAlias
+import threading
+import zipfile
+from contextlib import closing, contextmanager
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any, Literal, TypeAlias
 from urllib.parse import urlparse
 
 import av
+from av.filter import Graph
 
-from daft.daft import FileInfos, ImageMode
+from daft.daft import ImageMode
 from daft.datatype import DataType
-from daft.filesystem import _infer_filesystem, glob_path_with_stats
+from daft.dependencies import np, pa
+from daft.file import File
+from daft.file.video import VideoDecodeError, _ReadState, _chunks, _rotation, _video_stream
+from daft.filesystem import _resolve_paths_and_filesystem, glob_path_with_stats
 from daft.io import DataSource, DataSourceTask
 from daft.recordbatch import MicroPartition
 from daft.schema import Schema
+from daft.series import Series
 
 if TYPE_CHECKING:
-    from collections.abc import Generator, Iterator
+    from collections.abc import Generator, Iterator, Sequence
     from fractions import Fraction
 
     from av.video import VideoFrame
 
     from daft.daft import IOConfig
+    from daft.file.typing import VideoChunk
     from daft.io.pushdowns import Pushdowns
 
+logger = logging.getLogger(__name__)
 
 if TYPE_CHECKING:
-    from daft.dependencies import np
-
-    _VideoFrameData: TypeAlias = np.typing.NDArray[Any]
+    _VideoFrameData: TypeAlias = np.typing.NDArray[Any] | VideoFrame
 else:
     _VideoFrameData: TypeAlias = Any
 
 
+OutputPixelFormat: TypeAlias = Literal["rgb24", "rgba", "gray8", "rgb48", "gray16", "yuv"]
+"""The pixel format of decoded frames.
+
+* `rgb24`: RGB images.
+* `rgba`: RGBA images, which keep the alpha channel of videos which have one.
+* `gray8`: grayscale images, a third of the size of RGB images.
+* `rgb48`: RGB images with 16 bits per channel, which keep the precision of videos with more than 8 bits per channel,
+  such as HDR videos. The pixels keep the transfer function of the video, so HDR frames aren't tone-mapped.
+* `gray16`: grayscale images with 16 bits per pixel.
+* `yuv`: the frames as decoded, without converting them to RGB, as tensors of bytes. For example, planar YUV 4:2:0
+  frames have the shape `(height * 3 / 2, width)`, with the Y plane followed by the U and V planes. Frames with more
+  than 8 bits per sample are converted to the same layout with 8 bits per sample.
+"""
+
+# The formats which frames are converted to, their image modes, their number of channels and their bytes per channel.
+_IMAGE_FORMATS = {
+    "rgb24": ("rgb24", ImageMode.RGB, 3, 1),
+    "rgba": ("rgba", ImageMode.RGBA, 4, 1),
+    "gray8": ("gray", ImageMode.L, 1, 1),
+    "rgb48": ("rgb48le", ImageMode.RGB16, 3, 2),
+    "gray16": ("gray16le", ImageMode.L16, 1, 2),
+}
+
+
+def _check_pixel_format(pixel_format: str) -> None:
+    if pixel_format != "yuv" and pixel_format not in _IMAGE_FORMATS:
+        raise ValueError(f"pixel_format must be one of {[*_IMAGE_FORMATS, 'yuv']}, got {pixel_format!r}")
+
+
+ToneMap: TypeAlias = Literal["hable", "reinhard", "clip"]
+"""How the frames of HDR videos are tone-mapped to 8-bit images.
+
+* `hable`: the filmic curve of Uncharted 2, which keeps detail in highlights at the cost of darker midtones.
+* `reinhard`: a simpler curve, which keeps midtones brighter than `hable` does.
+* `clip`: clips everything brighter than SDR white, which keeps midtones as they are and blows out highlights.
+"""
+
+_TONE_MAPS = ("hable", "reinhard", "clip")
+
+
+def _check_tone_map(tone_map: str | None) -> None:
+    if tone_map is not None and tone_map not in _TONE_MAPS:
+        raise ValueError(f"tone_map must be one of {[*_TONE_MAPS, None]}, got {tone_map!r}")
+
+
+OnDecodeError: TypeAlias = Literal["fail", "skip", "null"]
+"""What to do with the frames of a video which fail to decode, such as the frames of a corrupt packet.
+
+* `fail`: raise the error, which fails the read.
+* `skip`: leave the frames out, and carry on decoding the frames after them.
+* `null`: read each frame as a row whose frame and timestamps are Null, and carry on decoding the frames after them.
+"""
+
+_ON_DECODE_ERRORS = ("fail", "skip", "null")
+
+# A video which fails to decode this many times in a row is given up on, since the rest of it is likely unreadable,
+# such as the end of a truncated file.
+_MAX_CONSECUTIVE_DECODE_ERRORS = 32
+
+
+CropRectangle: TypeAlias = tuple[int, int, int, int]
+"""The rectangle of a frame to keep, as the `(x, y, width, height)` in pixels of the frame as displayed."""
+
+
+def _check_crop(crop: CropRectangle | None) -> None:
+    if crop is None:
+        return
+    if len(crop) != 4:
+        raise ValueError(f"crop must be a rectangle of (x, y, width, height), got {crop}")
+    x, y, width, height = crop
+    if x < 0 or y < 0 or width <= 0 or height <= 0:
+        raise ValueError(f"crop must have a non-negative position and a positive size, got {crop}")
+
+
+# The fields of FFmpeg's AVMotionVector which are read, by the names of the fields of the motion vectors column.
+_MOTION_VECTOR_FIELDS = {
+    "source": "source",
+    "width": "w",
+    "height": "h",
+    "src_x": "src_x",
+    "src_y": "src_y",
+    "dst_x": "dst_x",
+    "dst_y": "dst_y",
+    "motion_x": "motion_x",
+    "motion_y": "motion_y",
+    "motion_scale": "motion_scale",
+}
+
+_MOTION_VECTORS_DTYPE = DataType.list(DataType.struct({name: DataType.int32() for name in _MOTION_VECTOR_FIELDS}))
+
+
+def _motion_vectors(frame: VideoFrame) -> pa.StructArray:
+    """Returns the motion vectors which the decoder exported for a frame, which key frames don't have."""
+    vectors = frame.side_data.get("MOTION_VECTORS")
+    array = vectors.to_ndarray() if vectors is not None else None
+    return pa.StructArray.from_arrays(
+        [
+            pa.array(array[av_name].astype(np.int32) if array is not None else [], type=pa.int32())
+            for av_name in _MOTION_VECTOR_FIELDS.values()
+        ],
+        names=list(_MOTION_VECTOR_FIELDS),
+    )
+
+
+def _picture_type(frame: VideoFrame) -> str | None:
+    """Returns the type of a frame's picture, such as "I", "P" or "B", or None if the decoder doesn't set it."""
+    name = getattr(frame.pict_type, "name", None) or str(frame.pict_type)
+    return None if name.upper() == "NONE" else name
+
+
+# The filters which rotate frames clockwise by right angles, like FFmpeg's autorotation.
+_ROTATE_FILTERS: dict[int, list[tuple[str, str | None]]] = {
+    90: [("transpose", "clock")],
+    180: [("hflip", None), ("vflip", None)],
+    270: [("transpose", "cclock")],
+}
+
+
+class _FrameFilter:
+    """Rotates and crops decoded frames with FFmpeg's filters.
+
+    Frames are rotated as they are displayed before they are cropped, so crops are of the frames as displayed. The
+    `crop` filter only moves the pointers to the planes of a frame, so cropping before frames are resized means only
+    the pixels which are kept are scaled, and frames are never converted at their full resolution.
+    """
+
+    def __init__(self, stream: Any, crop: CropRectangle | None, rotation: int):
+        filters = list(_ROTATE_FILTERS.get(rotation, []))
+        if crop is not None:
+            x, y, width, height = crop
+            # Rotating by a quarter turn swaps the width and height of frames.
+            frame_width, frame_height = (
+                (stream.height, stream.width) if rotation in (90, 270) else (stream.width, stream.height)
+            )
+            if frame_width and frame_height and (x + width > frame_width or y + height > frame_height):
+                raise ValueError(f"crop {crop} is outside of the frames, which are {frame_width}x{frame_height}")
+            filters.append(("crop", f"w={width}:h={height}:x={x}:y={y}"))
+        self._graph = Graph()
+        previous = self._graph.add_buffer(template=stream)
+        for name, args in filters:
+            node = self._graph.add(name, args)
+            previous.link_to(node)
+            previous = node
+        previous.link_to(self._graph.add("buffersink"))
+        self._graph.configure()
+
+    def __call__(self, frame: VideoFrame) -> VideoFrame:
+        self._graph.push(frame)
+        return self._graph.pull()
+
+
+# FFmpeg's values of the color properties of frames which HDR videos are read by.
+_AVCOL_PRI_BT2020 = 9
+_AVCOL_TRC_SMPTE2084 = 16  # PQ
+_AVCOL_TRC_ARIB_STD_B67 = 18  # HLG
+_AVCOL_UNSPECIFIED = 2
+
+# The AVColorSpace values of the matrices which FFmpeg's scaler knows, whose values match its SWS_CS_* colorspaces.
+_SCALER_COLORSPACES = frozenset({1, 4, 5, 6, 7, 9, 10})
+
+
+def _color_property(frame: VideoFrame, stream: Any, name: str) -> int:
+    """Returns a color property of a frame, or of its stream if the frame doesn't specify it."""
+    for source in (frame, stream.codec_context):
+        value = getattr(source, name, None)
+        if value is not None and int(value) != _AVCOL_UNSPECIFIED:
+            return int(value)
+    return _AVCOL_UNSPECIFIED
+
+
+def _bit_depth(frame: VideoFrame) -> int:
+    return max((component.bits for component in frame.format.components), default=8)
+
+
+def _yuv_format_8bit(pixel_format: str) -> str:
+    """Returns the pixel format with 8 bits per sample which has the same layout as a pixel format with more."""
+    if pixel_format.startswith(("p010", "p012", "p016")):
+        return "nv12"
+    match = re.fullmatch(r"(yuva?4\d\dp|gray)\d+(le|be)", pixel_format)
+    return match.group(1) if match else "yuv420p"
+
+
+class _ToneMapper:
+    """Tone-maps the frames of HDR videos to SDR, for videos which are read into 8-bit images.
+
+    Frames are converted to 16-bit RGB by FFmpeg, then decoded to linear light with the transfer function of the
+    video, converted from BT.2020 to BT.709 primaries, compressed into the SDR range by the tone curve, and encoded with
+    the BT.709 gamma. SDR white is HDR reference white, which is 203 nits per ITU-R BT.2408, and the curves map a peak
+    of 1000 nits, which most HDR videos are mastered at, to full brightness.
+    """
+
+    _REFERENCE_WHITE = 203.0
+    _PEAK = 1000.0
+
+    def __init__(self, tone_map: ToneMap, transfer: int, bt2020: bool):
+        self._tone_map = tone_map
+        self._transfer = transfer
+        self._bt2020_to_bt709 = (
+            np.array(
+                [[1.6605, -0.5876, -0.0728], [-0.1246, 1.1329, -0.0083], [-0.0182, -0.1006, 1.1187]],
+                dtype=np.float32,
+            )
+            if bt2020
+            else None
+        )
+
+    def __call__(self, rgb: np.typing.NDArray[Any], channels: int) -> np.typing.NDArray[Any]:
+        """Tone-maps an array of 16-bit RGB pixels to 8-bit pixels with the given number of channels."""
+        signal = rgb.astype(np.float32) / 65535
+        if self._transfer == _AVCOL_TRC_SMPTE2084:
+            # The PQ EOTF of SMPTE ST 2084, whose signal is absolute, up to 10000 nits.
+            m1, m2 = 2610 / 16384, 2523 / 4096 * 128
+            c1, c2, c3 = 3424 / 4096, 2413 / 4096 * 32, 2392 / 4096 * 32
+            power = signal ** (1 / m2)
+            nits = 10000 * (np.maximum(power - c1, 0) / (c2 - c3 * power)) ** (1 / m1)
+        else:
+            # The inverse HLG OETF of ITU-R BT.2100 gives scene light, which its OOTF maps to the light of a display
+            # with the peak brightness.
+            a = 0.17883277
+            b, c = 1 - 4 * a, 0.5 - a * math.log(4 * a)
+            scene = np.where(signal <= 0.5, signal**2 / 3, (np.exp((signal - c) / a) + b) / 12)
+            luminance = scene @ np.array([0.2627, 0.6780, 0.0593], dtype=np.float32)
+            nits = self._PEAK * scene * luminance[..., np.newaxis] ** 0.2
+        light = nits / self._REFERENCE_WHITE
+        if self._bt2020_to_bt709 is not None:
+            # Colors outside of the BT.709 gamut are clipped to it.
+            light = np.maximum(light @ self._bt2020_to_bt709.T, 0)
+
+        white = self._PEAK / self._REFERENCE_WHITE
+        if self._tone_map == "hable":
+            light = _hable(light) / _hable(white)
+        elif self._tone_map == "reinhard":
+            light = light * (1 + light / white**2) / (1 + light)
+        pixels = np.clip(light, 0, 1) ** (1 / 2.4)
+
+        if channels == 1:
+            pixels = pixels @ np.array([0.2126, 0.7152, 0.0722], dtype=np.float32)
+        elif channels == 4:
+            pixels = np.concatenate([pixels, np.ones_like(pixels[..., :1])], axis=-1)
+        return np.rint(pixels * 255).astype(np.uint8)
+
+
+def _hable(x: Any) -> Any:
+    """The filmic tone curve of John Hable, from Uncharted 2."""
+    a, b, c, d, e, f = 0.15, 0.50, 0.10, 0.20, 0.02, 0.30
+    return (x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f) - e / f
+
+
+class _FrameConverter:
+    """Resizes and converts the frames of a video to the pixel format which is read.
+
+    Frames are resized and converted in a single pass of FFmpeg's scaler. The scaler assumes YUV frames are BT.601
+    unless it's told otherwise, which suits most 8-bit videos, but videos with more than 8 bits per sample are nearly
+    always BT.709 or BT.2020, so it's given their colorspace and range. The frames of HDR videos, whose signal reaches
+    far brighter than SDR white, are tone-mapped when they're read into 8-bit images, since their colors are washed
+    out otherwise.
+    """
+
+    def __init__(
+        self,
+        frame: VideoFrame,
+        stream: Any,
+        image_height: int,
+        image_width: int,
+        pixel_format: OutputPixelFormat,
+        tone_map: ToneMap | None,
+    ):
+        self._size = {"width": image_width, "height": image_height}
+        self._pixel_format = pixel_format
+        high_bit_depth = _bit_depth(frame) > 8
+
+        self._scaler_options: dict[str, Any] = {}
+        if high_bit_depth:
+            colorspace = _color_property(frame, stream, "colorspace")
+            if colorspace in _SCALER_COLORSPACES:
+                self._scaler_options["src_colorspace"] = colorspace
+            color_range = getattr(frame, "color_range", None) or getattr(stream.codec_context, "color_range", None)
+            if color_range:
+                self._scaler_options["src_color_range"] = int(color_range)
+
+        self._yuv_format = _yuv_format_8bit(frame.format.name) if high_bit_depth else None
+
+        self._tone_mapper: _ToneMapper | None = None
+        transfer = _color_property(frame, stream, "color_trc")
+        is_hdr = transfer in (_AVCOL_TRC_SMPTE2084, _AVCOL_TRC_ARIB_STD_B67)
+        # 16-bit images keep the signal of HDR videos as it is.
+        is_8bit = pixel_format != "yuv" and _IMAGE_FORMATS[pixel_format][3] == 1
+        if is_hdr and is_8bit and tone_map is not None:
+            bt2020 = _color_property(frame, stream, "color_primaries") in (_AVCOL_PRI_BT2020, _AVCOL_UNSPECIFIED)
+            self._tone_mapper = _ToneMapper(tone_map, transfer, bt2020)
+
+    def __call__(self, frame: VideoFrame) -> _VideoFrameData:
+        if self._pixel_format == "yuv":
+            return frame.reformat(**self._size, format=self._yuv_format).to_ndarray()
+        _, _, channels, _ = _IMAGE_FORMATS[self._pixel_format]
+        if self._tone_mapper is not None:
+            rgb = frame.reformat(**self._size, format="rgb48le", **self._scaler_options).to_ndarray()
+            return self._tone_mapper(rgb, channels)
+        # Frames are resized and converted in a single pass, and their pixels are left in FFmpeg's buffer until
+        # they're copied into the partition which they're read into.
+        av_format, _, _, _ = _IMAGE_FORMATS[self._pixel_format]
+        return frame.reformat(**self._size, format=av_format, **self._scaler_options)
+
+
 @dataclass
 class _VideoFrame:
     """Represents a single video frame.
@@ -45,13 +361,112 @@ class _VideoFrame:
 
     path: str
     frame_index: int
-    frame_time: float
-    frame_time_base: Fraction
-    frame_pts: int
+    frame_time: float | None
+    frame_time_base: Fraction | None
+    frame_pts: int | None
     frame_dts: int | None
     frame_duration: int | None
-    is_key_frame: bool
-    data: _VideoFrameData
+    is_key_frame: bool | None
+    data: _VideoFrameData | None
+    motion_vectors: pa.StructArray | None = None
+    picture_type: str | None = None
+
+
+@dataclass(frozen=True)
+class DecodeOptions:
+    """Which frames of a video to decode into images.
+
+    Frames which aren't selected are skipped before they are resized and converted, which is most of the cost of
+    decoding a frame. A frame is selected if it is selected by every option which is set.
+
+    Attributes:
+        sample_every_n (int|None): If set, only select every nth frame, starting with the first.
+        target_fps (float|None): If set, select at most this many frames per second of video, by selecting the first
+            frame at or after each multiple of `1 / target_fps` seconds.
+        max_frames (int|None): If set, stop decoding after this many frames are selected.
+        start_time (float|None): If set, only select frames at or after this time, in seconds. Decoding starts from
+            the key frame at or before it, rather than from the start of the video.
+        end_time (float|None): If set, only select frames at or before this time, in seconds, and stop decoding after
+            it.
+        frame_buffer_size (int|None): If set, frames are decoded on a background thread ahead of being consumed, into
+            a buffer of at most this many frames. Decoding blocks while the buffer is full, so a slow consumer doesn't
+            cause decoded frames to pile up in memory. If None, frames are decoded as they are consumed.
+        on_error (OnDecodeError): What to do with the frames which fail to decode, such as the frames of a corrupt
+            packet: "fail" to raise the error, "skip" to leave them out, or "null" to read them as rows of Nulls.
+            Errors opening a video always fail.
+    """
+
+    sample_every_n: int | None = None
+    target_fps: float | None = None
+    max_frames: int | None = None
+    start_time: float | None = None
+    end_time: float | None = None
+    frame_buffer_size: int | None = None
+    on_error: OnDecodeError = "fail"
+
+    def __post_init__(self) -> None:
+        if self.sample_every_n is not None and self.sample_every_n < 1:
+            raise ValueError(f"sample_every_n must be at least 1, got {self.sample_every_n}")
+        if self.target_fps is not None and self.target_fps <= 0:
+            raise ValueError(f"target_fps must be positive, got {self.target_fps}")
+        if self.max_frames is not None and self.max_frames < 0:
+            raise ValueError(f"max_frames must be non-negative, got {self.max_frames}")
+        if self.start_time is not None and self.start_time < 0:
+            raise ValueError(f"start_time must be non-negative, got {self.start_time}")
+        if self.end_time is not None and self.end_time < (self.start_time or 0):
+            raise ValueError(f"end_time must not be before start_time, got {self.end_time}")
+        if self.frame_buffer_size is not None and self.frame_buffer_size < 1:
+            raise ValueError(f"frame_buffer_size must be at least 1, got {self.frame_buffer_size}")
+        if self.on_error not in _ON_DECODE_ERRORS:
+            raise ValueError(f"on_error must be one of {list(_ON_DECODE_ERRORS)}, got {self.on_error!r}")
+
+
+@dataclass
+class DecodeStats:
+    """Counts of the frames of a video which were decoded, and of the frames which failed to decode.
+
+    Attributes:
+        frames_decoded (int): The number of frames which were decoded, including those which weren't selected.
+        frames_skipped (int): The number of frames which failed to decode, and were skipped or read as rows of Nulls.
+        last_error (VideoDecodeError|None): The error of the last frame which failed to decode, if any.
+    """
+
+    frames_decoded: int = 0
+    frames_skipped: int = 0
+    last_error: VideoDecodeError | None = None
+
+
+class _FrameSampler:
+    """Selects the frames of a video to decode, in decoding order, as described by a `DecodeOptions`."""
+
+    def __init__(self, options: DecodeOptions, start_time: float | None = None):
+        self._options = options
+        self._next_time: float | None = None
+        self._selected = 0
+        if options.target_fps is not None and start_time:
+            # Decoding from a chunk of the video selects the frames at the same multiples of the interval as decoding
+            # the whole video does.
+            interval = 1.0 / options.target_fps
+            self._next_time = math.ceil(start_time / interval - 1e-9) * interval
+
+    def done(self) -> bool:
+        return self._options.max_frames is not None and self._selected >= self._options.max_frames
+
+    def select(self, frame_index: int, frame_time: float | None) -> bool:
+        options = self._options
+        if options.start_time is not None and frame_time is not None and frame_time < options.start_time:
+            return False
+        if options.sample_every_n is not None and frame_index % options.sample_every_n != 0:
+            return False
+        if options.target_fps is not None and frame_time is not None:
+            if self._next_time is not None and frame_time < self._next_time:
+                return False
+            # The next frame is selected at the next multiple of the interval, so frames are evenly spaced even if
+            # the frame rate of the video isn't a multiple of the target.
+            interval = 1.0 / options.target_fps
+            self._next_time = (int(frame_time / interval) + 1) * interval
+        self._selected += 1
+        return True
 
 
 @dataclass
@@ -67,6 +482,19 @@ class _VideoFramesSource(DataSource):
         image_width (int): Width to which each frame will be resized.
         is_key_frame (bool|None): If True, only include key frames; if False, only non-key frames; if None, include all frames.
         io_config (IOConfig|None): Optional IOConfig.
+        decode_options (DecodeOptions): Which of the frames to decode.
+        pixel_format (OutputPixelFormat): The pixel format of the decoded frames.
+        tone_map (ToneMap|None): How the frames of HDR videos are tone-mapped to 8-bit images, or None to convert them
+            without tone-mapping them.
+        crop (CropRectangle|None): If set, the rectangle of each frame to keep, before it's resized.
+        stream (int|str|None): The video stream to read, by its index among the video streams or by its language tag.
+        autorotate (bool): Whether to rotate frames as they are displayed, for videos with rotation metadata.
+        chunk_duration (float|None): If set, split each video into chunks of whole groups of pictures which are at
+            least this many seconds long, and decode each chunk in its own task.
+        export_mvs (bool): Whether to read the motion vectors which the decoder exports and the picture type of each
+            frame, into the `motion_vectors` and `picture_type` columns.
+        streaming (bool): Whether to read each video once from its start to its end, without seeking, as its bytes
+            arrive. Videos within archives are always read this way.
     """
 
     paths: list[str]
@@ -74,6 +502,27 @@ class _VideoFramesSource(DataSource):
     image_width: int
     is_key_frame: bool | None = None
     io_config: IOConfig | None = None
+    decode_options: DecodeOptions = field(default_factory=DecodeOptions)
+    pixel_format: OutputPixelFormat = "rgb24"
+    tone_map: ToneMap | None = "hable"
+    crop: CropRectangle | None = None
+    stream: int | str | None = None
+    autorotate: bool = True
+    chunk_duration: float | None = None
+    export_mvs: bool = False
+    streaming: bool = False
+
+    def __post_init__(self) -> None:
+        _check_pixel_format(self.pixel_format)
+        _check_tone_map(self.tone_map)
+        _check_crop(self.crop)
+        if self.chunk_duration is not None:
+            if self.chunk_duration <= 0:
+                raise ValueError(f"chunk_duration must be positive, got {self.chunk_duration}")
+            if self.decode_options.max_frames is not None:
+                raise ValueError("max_frames can't be used with chunk_duration, which decodes each chunk separately")
+            if self.streaming:
+                raise ValueError("streaming can't be used with chunk_duration, which seeks to the start of each chunk")
 
     @property
     def name(self) -> str:
@@ -84,12 +533,10 @@ class _VideoFramesSource(DataSource):
         return _schema(
             image_height=self.image_height,
             image_width=self.image_width,
+            pixel_format=self.pixel_format,
+            export_mvs=self.export_mvs,
         )
 
-    def _list_file_infos(self) -> Generator[FileInfos]:
-        for file_paths in self.paths:
-            yield glob_path_with_stats(file_paths, file_format=None, io_config=self.io_config)
-
     def _list_file_paths(self) -> Generator[str]:
         all_youtube = all(_is_youtube_url(p) for p in self.paths)
         any_youtube = any(_is_youtube_url(p) for p in self.paths)
@@ -99,18 +546,52 @@ class _VideoFramesSource(DataSource):
         elif any_youtube:
             raise ValueError("Either all or none of the paths must be YouTube URLs.")
         else:
-            for file_infos in self._list_file_infos():
-                yield from file_infos.file_paths
+            for path in self.paths:
+                # The videos within archives aren't listed, since archives are only read as their videos are decoded.
+                if _is_archive_member_url(path):
+                    yield path
+                else:
+                    yield from glob_path_with_stats(path, file_format=None, io_config=self.io_config).file_paths
+
+    def _list_chunks(self, path: str) -> Iterator[VideoChunk | None]:
+        if self.chunk_duration is None:
+            yield None
+            return
+        if _is_youtube_url(path):
+            raise ValueError("chunk_duration isn't supported for YouTube URLs")
+        if _is_archive_member_url(path):
+            raise ValueError(f"chunk_duration isn't supported for videos within archives, which are streamed: {path}")
+        with File(path, io_config=self.io_config).open() as file, av.open(file) as container:
+            chunks = _chunks(container, _video_stream(container, self.stream), self.chunk_duration, None)
+        options = self.decode_options
+        for chunk in chunks:
+            # Chunks outside of the time range which is read aren't decoded.
+            end_time = chunk["end_time"]
+            if options.start_time is not None and end_time is not None and end_time <= options.start_time:
+                continue
+            if options.end_time is not None and chunk["start_time"] > options.end_time:
+                continue
+            yield chunk
 
     def get_tasks(self, pushdowns: Pushdowns) -> Iterator[DataSourceTask]:
         for path in self._list_file_paths():
-            yield _VideoFramesSourceTask(
-                path=path,
-                image_height=self.image_height,
-                image_width=self.image_width,
-                is_key_frame=self.is_key_frame,
-                io_config=self.io_config,
-            )
+            for chunk in self._list_chunks(path):
+                yield _VideoFramesSourceTask(
+                    path=path,
+                    image_height=self.image_height,
+                    image_width=self.image_width,
+                    is_key_frame=self.is_key_frame,
+                    io_config=self.io_config,
+                    decode_options=self.decode_options,
+                    pixel_format=self.pixel_format,
+                    tone_map=self.tone_map,
+                    crop=self.crop,
+                    stream=self.stream,
+                    autorotate=self.autorotate,
+                    chunk=chunk,
+                    export_mvs=self.export_mvs,
+                    streaming=self.streaming,
+                )
 
 
 @dataclass
@@ -122,6 +603,17 @@ class _VideoFramesSourceTask(DataSourceTask):
     image_width: int
     is_key_frame: bool | None
     io_config: IOConfig | None
+    decode_options: DecodeOptions = field(default_factory=DecodeOptions)
+    pixel_format: OutputPixelFormat = "rgb24"
+    tone_map: ToneMap | None = "hable"
+    crop: CropRectangle | None = None
+    stream: int | str | None = None
+    autorotate: bool = True
+    chunk: VideoChunk | None = None
+    export_mvs: bool = False
+    streaming: bool = False
+    stats: DecodeStats = field(default_factory=DecodeStats, init=False, repr=False)
+    """The statistics of decoding the video, which are updated as its frames are read."""
 
     _max_partition_size = 10 * 1024 * 1024  # 10 MB
 
@@ -130,37 +622,123 @@ class _VideoFramesSourceTask(DataSourceTask):
         return _schema(
             image_height=self.image_height,
             image_width=self.image_width,
+            pixel_format=self.pixel_format,
+            export_mvs=self.export_mvs,
         )
 
     def _list_frames(self, path: str, file: Any) -> Generator[_VideoFrame]:
         container = None
+        state = _ReadState()
+        stats = self.stats
         try:
             container = av.open(file)
 
             # TODO support reading frames for multiple video streams
-            stream = next((s for s in container.streams if s.type == "video"), None)
-            if stream is None:
+            if not any(s.type == "video" for s in container.streams):
                 container.close()
                 raise RuntimeError(f"No video stream found in file: {path}")
+            stream = _video_stream(container, self.stream)
+            state.decode(stream)
 
             if self.is_key_frame:
+                # The decoder discards non-key frames without decoding them, which makes reading only key frames much
+                # faster than reading all frames.
                 # https://pyav.org/docs/develop/cookbook/basics.html#saving-keyframes
                 stream.codec_context.skip_frame = "NONKEY"
+            if self.export_mvs:
+                # The decoder exports the motion vectors it decodes as side data of the frames, which costs nothing
+                # beyond decoding them.
+                stream.codec_context.options = {**stream.codec_context.options, "flags2": "+export_mvs"}
+
+            options = self.decode_options
+            chunk = self.chunk
+            # A streamed video can't be seeked, so the frames before its start time are decoded and skipped instead.
+            seek_to_start = bool(options.start_time) and not self._is_streamed()
+            if chunk is not None:
+                # A chunk starts at a key frame, so its frames are decoded from the key frame without any before it.
+                if chunk["start_time"] > 0:
+                    container.seek(round(chunk["start_time"] / stream.time_base), stream=stream, backward=True)
+            elif seek_to_start:
+                # Seeking backward lands on the key frame at or before the start time, which the frames after it are
+                # decoded from.
+                container.seek(int(options.start_time / stream.time_base), stream=stream, backward=True)
+            # Allow for frame times which are rounded from the times of the chunk.
+            tolerance = float(stream.time_base) / 2
 
-            frame_index: int = 0
+            sampler = _FrameSampler(options, chunk["start_time"] if chunk is not None else None)
+            # The filter and converter are made for the first selected frame, since the rotation and colors of a
+            # video may only be known from its decoded frames.
+            frame_filter: _FrameFilter | None = None
+            convert: _FrameConverter | None = None
+            frame_index: int = chunk["start_frame"] - 1 if chunk is not None else -1
             frame: VideoFrame
-            while True:
+            consecutive_errors = 0
+            while not sampler.done():
                 try:
                     frame = next(container.decode(stream))
                 except av.EOFError:
                     break
                 except StopIteration:
                     break
+                except av.FFmpegError as e:
+                    consecutive_errors += 1
+                    if options.on_error == "fail" or consecutive_errors > _MAX_CONSECUTIVE_DECODE_ERRORS:
+                        raise
+                    # The packet which failed to decode is dropped, so decoding carries on from the next packet.
+                    stats.frames_skipped += 1
+                    stats.last_error = state.error(path, e)
+                    frame_index += 1
+                    # The time of a frame which failed to decode isn't known, so it's only selected by its index.
+                    if options.on_error == "null" and self.is_key_frame is None and sampler.select(frame_index, None):
+                        yield _VideoFrame(
+                            path=path,
+                            frame_index=frame_index,
+                            frame_time=None,
+                            frame_time_base=None,
+                            frame_pts=None,
+                            frame_dts=None,
+                            frame_duration=None,
+                            is_key_frame=None,
+                            data=None,
+                        )
+                    continue
+                consecutive_errors = 0
+                stats.frames_decoded += 1
+                if frame.time is not None:
+                    state.timestamp = frame.time
 
-                frame = frame.reformat(
-                    width=self.image_width,
-                    height=self.image_height,
-                )
+                if chunk is not None and frame.time is not None:
+                    # The frames of the chunks around this one, which are decoded along with its frames, are read by
+                    # the tasks of those chunks.
+                    if frame.time + tolerance < chunk["start_time"]:
+                        continue
+                    if chunk["end_time"] is not None and frame.time + tolerance >= chunk["end_time"]:
+                        break
+                if options.end_time is not None and frame.time is not None and frame.time > options.end_time:
+                    break
+                if chunk is None and frame_index < 0 and seek_to_start:
+                    # The frames before the first one decoded after seeking aren't counted, so its index is found from
+                    # its time.
+                    frame_index = _frame_index_at(stream, frame.time)
+                else:
+                    frame_index += 1
+                # Not every decoder honors `skip_frame`, so frames are also filtered by their key frame flag, before
+                # they are resized and converted.
+                if self.is_key_frame is not None and frame.key_frame != self.is_key_frame:
+                    continue
+                if not sampler.select(frame_index, frame.time):
+                    continue
+
+                # Frames are rotated, cropped and resized by FFmpeg as they are decoded, so that only the pixels of
+                # the output images are converted.
+                if convert is None:
+                    rotation = _rotation(stream, frame) if self.autorotate else 0
+                    if rotation or self.crop is not None:
+                        frame_filter = _FrameFilter(stream, self.crop, rotation)
+                    convert = _FrameConverter(
+                        frame, stream, self.image_height, self.image_width, self.pixel_format, self.tone_map
+                    )
+                image = frame_filter(frame) if frame_filter is not None else frame
 
                 yield _VideoFrame(
                     path=path,
@@ -171,19 +749,35 @@ class _VideoFramesSourceTask(DataSourceTask):
                     frame_dts=frame.dts,
                     frame_duration=frame.duration,
                     is_key_frame=frame.key_frame,
-                    data=frame.to_ndarray(format="rgb24"),
+                    data=convert(image),
+                    motion_vectors=_motion_vectors(frame) if self.export_mvs else None,
+                    picture_type=_picture_type(frame) if self.export_mvs else None,
                 )
-                frame_index += 1
+        except av.FFmpegError as e:
+            raise state.error(path, e) from e
         finally:
             if container:
                 container.close()
+            if stats.frames_skipped:
+                logger.warning(
+                    "Skipped %d of the frames of %s which failed to decode, the last with: %s",
+                    stats.frames_skipped,
+                    path,
+                    stats.last_error,
+                )
+
+    def _is_streamed(self) -> bool:
+        return self.streaming or _is_archive_member_url(self.path)
 
     def _open(self) -> Any:
         if _is_youtube_url(self.path):
             return self._open_youtube_file()
+        elif self._is_streamed():
+            return _open_stream(self.path, self.io_config)
         else:
-            fp, fs, _ = _infer_filesystem(self.path, io_config=self.io_config)
-            return fs.open_input_file(fp)
+            # Files are read through daft-io with ranged reads as the container is demuxed, so remote videos are
+            # streamed rather than downloaded in full before decoding.
+            return File(self.path, io_config=self.io_config).open()
 
     @contextmanager
     def _open_youtube_file(self) -> Any:
@@ -240,12 +834,15 @@ class _VideoFramesSourceTask(DataSourceTask):
                 os.remove(temp_file)
 
     def get_micro_partitions(self) -> Iterator[MicroPartition]:
-        with self._open() as file:
+        with self._open() as file, closing(self._decode_frames(file)) as frames:
             buffer = _VideoFramesBuffer(
                 image_height=self.image_height,
                 image_width=self.image_width,
+                pixel_format=self.pixel_format,
+                max_size=self._max_partition_size,
+                export_mvs=self.export_mvs,
             )
-            for frame in self._list_frames(self.path, file):
+            for frame in frames:
                 buffer.append(frame)
                 # yield when full
                 if buffer.size() >= self._max_partition_size:
@@ -256,6 +853,124 @@ class _VideoFramesSourceTask(DataSourceTask):
                 yield buffer.to_micropartition()
 
 
+    def _decode_frames(self, file: Any) -> Generator[_VideoFrame]:
+        frames = self._list_frames(self.path, file)
+        if self.decode_options.frame_buffer_size is None:
+            return frames
+        return _decode_in_background(frames, self.decode_options.frame_buffer_size)
+
+
+@dataclass
+class _DecodeError:
+    error: BaseException
+
+
+_DECODE_DONE = object()
+
+
+def _put(buffer: queue.Queue[Any], stopped: threading.Event, item: Any) -> bool:
+    """Puts an item into a bounded buffer, blocking while it's full. Returns False if decoding was stopped instead."""
+    while not stopped.is_set():
+        try:
+            buffer.put(item, timeout=0.1)
+            return True
+        except queue.Full:
+            continue
+    return False
+
+
+def _decode_in_background(frames: Generator[_VideoFrame], buffer_size: int) -> Generator[_VideoFrame]:
+    """Decodes frames on a background thread into a bounded buffer, and yields them from the buffer.
+
+    The decoding thread blocks while the buffer is full. It stops, closing `frames`, once the returned generator is
+    closed or all of the frames are decoded.
+    """
+    buffer: queue.Queue[Any] = queue.Queue(maxsize=buffer_size)
+    stopped = threading.Event()
+
+    def decode() -> None:
+        try:
+            for frame in frames:
+                if not _put(buffer, stopped, frame):
+                    return
+            _put(buffer, stopped, _DECODE_DONE)
+        except BaseException as e:
+            _put(buffer, stopped, _DecodeError(e))
+        finally:
+            frames.close()
+
+    thread = threading.Thread(target=decode, name="daft-video-decoder", daemon=True)
+    thread.start()
+    try:
+        while (item := buffer.get()) is not _DECODE_DONE:
+            if isinstance(item, _DecodeError):
+                raise item.error
+            yield item
+    finally:
+        stopped.set()
+        thread.join()
+
+
+def decode_many(
+    sources: Sequence[_VideoFramesSourceTask], max_concurrency: int, buffer_size: int | None = None
+) -> Generator[tuple[int, _VideoFrame]]:
+    """Decodes the frames of many videos, with at most `max_concurrency` of the videos open at once.
+
+    The videos are decoded on `max_concurrency` background threads, which each open one video at a time, into a buffer
+    of at most `buffer_size` frames which is shared by the threads, and defaults to `max_concurrency` frames. Each frame
+    is yielded with the index of its video in `sources`. The frames of a video are yielded in order, but the frames of
+    the videos which are decoded at once are interleaved.
+
+    Decoding stops, closing the open videos, once the returned generator is closed or a video fails to decode, whose
+    error is raised.
+    """
+    if max_concurrency < 1:
+        raise ValueError(f"max_concurrency must be at least 1, got {max_concurrency}")
+    if buffer_size is not None and buffer_size < 1:
+        raise ValueError(f"buffer_size must be at least 1, got {buffer_size}")
+    buffer: queue.Queue[Any] = queue.Queue(maxsize=buffer_size or max_concurrency)
+    stopped = threading.Event()
+    pending = iter(enumerate(sources))
+    pending_lock = threading.Lock()
+
+    def decode() -> None:
+        try:
+            while not stopped.is_set():
+                with pending_lock:
+                    index, source = next(pending, (-1, None))
+                if source is None:
+                    break
+                with source._open() as file, closing(source._decode_frames(file)) as frames:
+                    for frame in frames:
+                        if not _put(buffer, stopped, (index, frame)):
+                            return
+        except BaseException as e:
+            _put(buffer, stopped, _DecodeError(e))
+        finally:
+            _put(buffer, stopped, _DECODE_DONE)
+
+    threads = [
+        threading.Thread(target=decode, name="daft-video-decoder", daemon=True)
+        for _ in range(min(max_concurrency, len(sources)))
+    ]
+    for thread in threads:
+        thread.start()
+    try:
+        running = len(threads)
+        while running:
+            item = buffer.get()
+            if item is _DECODE_DONE:
+                running -= 1
+            elif isinstance(item, _DecodeError):
+                raise item.error
+            else:
+                yield item
+    finally:
+        stopped.set()
+        for thread in threads:
+            thread.join()
+
+
 class _VideoFramesBuffer:
     """A micropartition buffer/builder for video frames.
 
@@ -266,10 +981,15 @@ class _VideoFramesBuffer:
         is implemented e.g. open-cv vs. PyAV or other library is not
         important, just that we have an Iterable of frames which this
         builder and the source and stream as appropriately sized partitions.
+
+    The pixels of frames which are converted to images are copied straight from FFmpeg's buffers into a pre-allocated
+    buffer, which is laid out like the values of a fixed-shape image column, with `height * width * channels` bytes
+    per frame. The column is made over the buffer without copying it, so the pixels of each frame are copied once.
     """
 
     image_height: int
     image_width: int
+    pixel_format: OutputPixelFormat
 
     _arr_path: list[str]
     _arr_frame_index: list[int]
@@ -278,14 +998,34 @@ class _VideoFramesBuffer:
     _arr_frame_pts: list[int]
     _arr_frame_dts: list[int | None]
     _arr_frame_duration: list[int | None]
-    _arr_is_key_frame: list[bool]
-    _arr_data: list[_VideoFrameData]
+    _arr_is_key_frame: list[bool | None]
+    _arr_data: list[_VideoFrameData | None]
+    _arr_valid: list[bool]
+    _arr_motion_vectors: list[pa.StructArray | None]
+    _arr_picture_type: list[str | None]
+    _pixels: np.typing.NDArray[Any] | None
     _size_in_bytes: int
     _size_of_metadata = 64
 
-    def __init__(self, image_height: int, image_width: int):
+    def __init__(
+        self,
+        image_height: int,
+        image_width: int,
+        pixel_format: OutputPixelFormat = "rgb24",
+        max_size: int | None = None,
+        export_mvs: bool = False,
+    ):
         self.image_height = image_height
         self.image_width = image_width
+        self.pixel_format = pixel_format
+        self.export_mvs = export_mvs
+        channels, self._sample_size = (0, 1) if pixel_format == "yuv" else _IMAGE_FORMATS[pixel_format][2:]
+        # The size of the rows of an image, in bytes.
+        self._row_size = image_width * channels * self._sample_size
+        # The partitions are flushed once they reach the max size, so a buffer of pixels which holds that many frames
+        # is never grown.
+        frame_size = image_height * self._row_size + self._size_of_metadata
+        self._capacity = max(1, -(-max_size // frame_size)) if max_size else 1
         self.clear()
 
     def clear(self) -> None:
@@ -298,6 +1038,11 @@ class _VideoFramesBuffer:
         self._arr_frame_duration = []
         self._arr_is_key_frame = []
         self._arr_data = []
+        self._arr_valid = []
+        self._arr_motion_vectors = []
+        self._arr_picture_type = []
+        # The pixels of the last partition are owned by its image column, so the next partition gets a new buffer.
+        self._pixels = None
         self._size_in_bytes = 0
 
     def size(self) -> int:
@@ -311,55 +1056,144 @@ class _VideoFramesBuffer:
             We encode the time_base as a fraction string.
             See: https://github.com/Eventual-Inc/Daft/issues/4971
         """
+        if self.pixel_format == "yuv":
+            self._arr_data.append(frame.data)
+            self._size_in_bytes += (frame.data.nbytes if frame.data is not None else 0) + self._size_of_metadata
+        else:
+            self._append_pixels(frame.data)
+            self._size_in_bytes += self.image_height * self._row_size + self._size_of_metadata
+        self._arr_valid.append(frame.data is not None)
         self._arr_path.append(frame.path)
         self._arr_frame_index.append(frame.frame_index)
         self._arr_frame_time.append(frame.frame_time)
-        self._arr_frame_time_base.append(str(frame.frame_time_base))
+        self._arr_frame_time_base.append(str(frame.frame_time_base) if frame.frame_time_base is not None else None)
         self._arr_frame_pts.append(frame.frame_pts)
         self._arr_frame_dts.append(frame.frame_dts)
         self._arr_frame_duration.append(frame.frame_duration)
         self._arr_is_key_frame.append(frame.is_key_frame)
-        self._arr_data.append(frame.data)
-        self._size_in_bytes += frame.data.nbytes + self._size_of_metadata
+        if self.export_mvs:
+            self._arr_motion_vectors.append(frame.motion_vectors)
+            self._arr_picture_type.append(frame.picture_type)
+            if frame.motion_vectors is not None:
+                self._size_in_bytes += frame.motion_vectors.nbytes
 
-    def to_micropartition(self) -> MicroPartition:
-        """Returns a MicroPartition for this builder."""
-        return MicroPartition.from_pydict(
-            {
-                "path": self._arr_path,
-                "frame_index": self._arr_frame_index,
-                "frame_time": self._arr_frame_time,
-                "frame_time_base": self._arr_frame_time_base,
-                "frame_pts": self._arr_frame_pts,
-                "frame_dts": self._arr_frame_dts,
-                "frame_duration": self._arr_frame_duration,
-                "is_key_frame": self._arr_is_key_frame,
-                "data": self._arr_data,
-            }
+    def _append_pixels(self, image: _VideoFrameData | None) -> None:
+        num_frames = len(self._arr_path)
+        if self._pixels is None:
+            self._pixels = np.empty((self._capacity, self.image_height, self._row_size), dtype=np.uint8)
+        elif num_frames == len(self._pixels):
+            self._pixels = np.concatenate([self._pixels, np.empty_like(self._pixels)])
+        if image is None:
+            # The pixels of frames which failed to decode are left as they are, under the validity of the column.
+            return
+        if isinstance(image, np.ndarray):
+            # Tone-mapped frames are converted to arrays of 8-bit pixels.
+            self._pixels[num_frames] = image.reshape(self.image_height, self._row_size)
+            return
+        # The rows of a plane may be padded past the pixels of the image.
+        plane = image.planes[0]
+        rows = np.frombuffer(plane, dtype=np.uint8, count=plane.line_size * self.image_height)
+        rows = rows.reshape(self.image_height, plane.line_size)
+        self._pixels[num_frames] = rows[:, : self._row_size]
+
+    def _data(self) -> Series:
+        if self.pixel_format == "yuv":
+            return Series.from_pylist(self._arr_data, name="data")
+        assert self._pixels is not None
+        values = self._pixels[: len(self._arr_path)].reshape(-1)
+        if self._sample_size == 2:
+            # The pixels of 16-bit images are copied from FFmpeg's little-endian formats.
+            values = values.view(np.uint16)
+        list_size = self.image_height * self._row_size // self._sample_size
+        validity = None
+        if not all(self._arr_valid):
+            validity = pa.py_buffer(np.packbits(np.array(self._arr_valid, dtype=bool), bitorder="little"))
+        images = pa.Array.from_buffers(
+            pa.list_(pa.from_numpy_dtype(values.dtype), list_size),
+            len(self._arr_path),
+            [validity],
+            children=[pa.array(values)],
+        )
+        _, mode, _, _ = _IMAGE_FORMATS[self.pixel_format]
+        return Series.from_arrow(images, name="data").cast(
+            DataType.image(height=self.image_height, width=self.image_width, mode=mode)
         )
 
+    def _motion_vectors(self) -> Series:
+        lengths = [len(vectors) if vectors is not None else 0 for vectors in self._arr_motion_vectors]
+        offsets = pa.array(np.concatenate([[0], np.cumsum(lengths)]), type=pa.int32())
+        values = [vectors for vectors in self._arr_motion_vectors if vectors is not None]
+        mask = None
+        if len(values) < len(self._arr_motion_vectors):
+            mask = pa.array([vectors is None for vectors in self._arr_motion_vectors])
+        lists = pa.ListArray.from_arrays(
+            offsets,
+            pa.concat_arrays(values) if values else _motion_vectors_empty(),
+            mask=mask,
+        )
+        return Series.from_arrow(lists, name="motion_vectors").cast(_MOTION_VECTORS_DTYPE)
 
-def _schema(image_height: int, image_width: int) -> Schema:
-    """Returns the common schema which is needed in several places."""
-    return Schema.from_pydict(
-        {
-            "path": DataType.string(),
-            "frame_index": DataType.int64(),
-            "frame_time": DataType.float64(),
-            "frame_time_base": DataType.string(),
-            "frame_pts": DataType.int64(),
-            "frame_dts": DataType.int64(),
-            "frame_duration": DataType.int64(),
-            "is_key_frame": DataType.bool(),
-            "data": DataType.image(
-                height=image_height,
-                width=image_width,
-                mode=ImageMode.RGB,  # make configurable at later time
-            ),
+    def to_micropartition(self) -> MicroPartition:
+        """Returns a MicroPartition for this builder."""
+        columns = {
+            "path": self._arr_path,
+            "frame_index": self._arr_frame_index,
+            "frame_time": self._arr_frame_time,
+            "frame_time_base": self._arr_frame_time_base,
+            "frame_pts": self._arr_frame_pts,
+            "frame_dts": self._arr_frame_dts,
+            "frame_duration": self._arr_frame_duration,
+            "is_key_frame": self._arr_is_key_frame,
+            "data": self._data(),
         }
+        if self.export_mvs:
+            columns["motion_vectors"] = self._motion_vectors()
+            columns["picture_type"] = self._arr_picture_type
+        return MicroPartition.from_pydict(columns)
+
+
+def _motion_vectors_empty() -> pa.StructArray:
+    return pa.StructArray.from_arrays(
+        [pa.array([], type=pa.int32()) for _ in _MOTION_VECTOR_FIELDS], names=list(_MOTION_VECTOR_FIELDS)
     )
 
 
+def _schema(
+    image_height: int, image_width: int, pixel_format: OutputPixelFormat = "rgb24", export_mvs: bool = False
+) -> Schema:
+    """Returns the common schema which is needed in several places."""
+    if pixel_format == "yuv":
+        # The shape of a decoded frame depends on the pixel format of the video.
+        data = DataType.tensor(DataType.uint8())
+    else:
+        _, mode, _, _ = _IMAGE_FORMATS[pixel_format]
+        data = DataType.image(height=image_height, width=image_width, mode=mode)
+    fields = {
+        "path": DataType.string(),
+        "frame_index": DataType.int64(),
+        "frame_time": DataType.float64(),
+        "frame_time_base": DataType.string(),
+        "frame_pts": DataType.int64(),
+        "frame_dts": DataType.int64(),
+        "frame_duration": DataType.int64(),
+        "is_key_frame": DataType.bool(),
+        "data": data,
+    }
+    if export_mvs:
+        fields["motion_vectors"] = _MOTION_VECTORS_DTYPE
+        fields["picture_type"] = DataType.string()
+    return Schema.from_pydict(fields)
+
+
+def _frame_index_at(stream: Any, frame_time: float | None) -> int:
+    """Returns the index of the frame at a time in a stream, assuming a constant frame rate."""
+    rate = stream.average_rate or stream.guessed_rate
+    if not rate or frame_time is None:
+        return 0
+    start = float(stream.start_time * stream.time_base) if stream.start_time else 0.0
+    return max(round((frame_time - start) * float(rate)), 0)
+
+
 def _is_youtube_url(url: str) -> bool:
     hostname = urlparse(url).hostname
     if hostname is None:
@@ -367,6 +1201,58 @@ def _is_youtube_url(url: str) -> bool:
     return hostname in {"www.youtube.com", "youtube.com", "youtu.be"}
 
 
+# The URL of a video within an archive chains the path of the video within the archive to the URL of the archive, like
+# fsspec's chained URLs do, such as `tar://clips/a.mp4::s3://bucket/shard.tar.gz` or `zip://a.mp4::s3://bucket/a.zip`.
+_ARCHIVE_MEMBER_URL = re.compile(r"^(tar|zip)://(.+?)::(.+)$")
+
+
+def _is_archive_member_url(url: str) -> bool:
+    return _ARCHIVE_MEMBER_URL.match(url) is not None
+
+
+class _StreamingFile:
+    """A video which is read once from its start to its end, such as a download or an archive member being extracted.
+
+    It has no `seek`, so PyAV opens it with a non-seekable IO context, which FFmpeg demuxes the video from as its bytes
+    are read, rather than the whole video being buffered first.
+    """
+
+    def __init__(self, file: Any) -> None:
+        self._file = file
+
+    def read(self, size: int = -1) -> bytes:
+        return self._file.read(size if size >= 0 else None)
+
+
+@contextmanager
+def _open_stream(url: str, io_config: IOConfig | None) -> Iterator[_StreamingFile]:
+    """Opens a video, or a video within a tar or zip archive, as a stream which is read from its start to its end."""
+    member = _ARCHIVE_MEMBER_URL.match(url)
+    archive_url = member.group(3) if member is not None else url
+    [path], fs = _resolve_paths_and_filesystem(archive_url, io_config=io_config)
+    if member is None:
+        with fs.open_input_stream(path) as f:
+            yield _StreamingFile(f)
+    elif member.group(1) == "tar":
+        # Tar archives, which may be compressed, are read up to the member, which is then extracted as it's read.
+        with fs.open_input_stream(path) as f, tarfile.open(fileobj=f, mode="r|*") as archive:
+            for info in archive:
+                if info.isfile() and info.name == member.group(2):
+                    yield _StreamingFile(archive.extractfile(info))
+                    return
+        raise FileNotFoundError(f"No file {member.group(2)} in the archive {archive_url}")
+    else:
+        # Zip archives are indexed by a central directory at their end, so the archive is read with ranged reads,
+        # while the member is decompressed as it's read.
+        with fs.open_input_file(path) as f, zipfile.ZipFile(f) as archive:
+            try:
+                info = archive.getinfo(member.group(2))
+            except KeyError as e:
+                raise FileNotFoundError(f"No file {member.group(2)} in the archive {archive_url}") from e
+            with archive.open(info) as file:
+                yield _StreamingFile(file)
+
+
 def _assert_youtube_available() -> None:
     try:
         import yt_dlp  # noqa: F401
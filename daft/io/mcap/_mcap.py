@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from dataclasses import dataclass
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any, Literal
 
 from daft.api_annotations import PublicAPI
 from daft.dependencies import pafs
@@ -12,7 +12,7 @@ from daft.recordbatch.micropartition import MicroPartition
 
 if TYPE_CHECKING:
     import pathlib
-    from collections.abc import Iterator
+    from collections.abc import Iterable, Iterator
 
     from daft import DataFrame
     from daft.io import IOConfig
@@ -73,6 +73,50 @@ def list_files(
     return [file_info.path for file_info in file_infos if file_info.type == pafs.FileType.File]
 
 
+MESSAGE_FORMAT = Literal["string", "struct", "raw"]
+
+_BASE_COLUMNS = {"topic", "log_time", "publish_time", "sequence"}
+# The number of messages of each topic of the first file which are read to infer the types of decoded messages.
+_NUM_MESSAGES_TO_INFER = 100
+
+
+def _is_rosbag2_db3(path: str) -> bool:
+    return path.endswith(".db3")
+
+
+def _to_pyobj(value: Any) -> Any:
+    """Converts a decoded message to plain Python values, so its type can be inferred as a struct."""
+    if isinstance(value, (str, bytes, bool, int, float)) or value is None:
+        return value
+    if isinstance(value, dict):
+        return {k: _to_pyobj(v) for k, v in value.items()}
+    if isinstance(value, (list, tuple)):
+        return [_to_pyobj(v) for v in value]
+    if hasattr(value, "DESCRIPTOR") and hasattr(value.DESCRIPTOR, "fields"):
+        # Protobuf messages, whose fields are known from their descriptor.
+        return {field.name: _to_pyobj(getattr(value, field.name)) for field in value.DESCRIPTOR.fields}
+    if hasattr(value, "__slots__"):
+        # ROS 2 messages, which are generated from their message definitions.
+        return {slot: _to_pyobj(getattr(value, slot)) for slot in value.__slots__}
+    if hasattr(value, "tolist"):
+        return value.tolist()
+    if hasattr(value, "__iter__"):
+        # Repeated protobuf fields.
+        return [_to_pyobj(v) for v in value]
+    return str(value)
+
+
+def _base_fields() -> list[Any]:
+    import pyarrow as pa
+
+    return [
+        pa.field("topic", pa.string()),
+        pa.field("log_time", pa.int64()),
+        pa.field("publish_time", pa.int64()),
+        pa.field("sequence", pa.int32()),
+    ]
+
+
 @PublicAPI
 def read_mcap(
     path: str,
@@ -81,19 +125,41 @@ def read_mcap(
     end_time: int | None = None,
     topics: list[str] | None = None,
     batch_size: int = 1000,
+    message_format: MESSAGE_FORMAT = "string",
 ) -> DataFrame:
-    """Read mcap file.
+    """Read MCAP files or rosbag2 recordings, with a row per message.
+
+    Messages are decoded with the schemas recorded in the files, which may be ROS 2 message definitions, Protobuf
+    descriptors or JSON, depending on ``message_format``:
+        * "string": a "data" column with the string representation of each decoded message.
+        * "struct": a struct column for each topic, named after the topic, which holds the decoded messages of that
+          topic and is null for messages of other topics. The types of the structs are inferred from the first messages
+          of each topic in the first file.
+        * "raw": a binary "data" column with the undecoded messages, and "schema_name" and "message_encoding" columns to
+          decode them with. This doesn't decode messages, so it's much faster, e.g. to filter LiDAR or video payloads.
+
+    rosbag2 recordings in the MCAP storage format are read like any other MCAP files. Recordings in the SQLite storage
+    format (.db3 files) don't record message definitions, so they can only be read with ``message_format="raw"``.
 
     Args:
-        path: mcap file path
-        start_time: Start time in milliseconds to filter messages.
-        end_time: End time in milliseconds to filter messages.
+        path: Path to an MCAP or .db3 file, or to a directory of them such as a rosbag2 recording.
+        io_config: Config to be used with the native downloader.
+        start_time: Start time in nanoseconds to filter messages.
+        end_time: End time in nanoseconds to filter messages.
         topics: List of topics to filter messages.
         batch_size: Number of messages to read in each batch.
+        message_format: How to represent messages, either "string", "struct" or "raw". Defaults to "string".
 
     Returns:
         DataFrame: DataFrame with the schema converted from the specified MCAP file.
+
+    Examples:
+        >>> df = daft.read_mcap("/path/to/recording.mcap", topics=["/imu"], message_format="struct")  # doctest: +SKIP
+        >>> df = df.select("log_time", df["/imu"]["linear_acceleration"])  # doctest: +SKIP
     """
+    if message_format not in ("string", "struct", "raw"):
+        raise ValueError(f"Unsupported message_format: {message_format}, expected one of 'string', 'struct' or 'raw'")
+
     return MCAPSource(
         file_path=path,
         start_time=start_time,
@@ -101,6 +167,7 @@ def read_mcap(
         topics=topics,
         batch_size=batch_size,
         io_config=io_config,
+        message_format=message_format,
     ).read()
 
 
@@ -113,20 +180,30 @@ class MCAPSource(DataSource):
         topics: list[str] | None = None,
         batch_size: int = 1000,
         io_config: IOConfig | None = None,
+        message_format: MESSAGE_FORMAT = "string",
     ):
         self._start_time = start_time
         self._end_time = end_time
         self._topics = topics
         self._batch_size = batch_size
+        self._io_config = io_config
+        self._message_format = message_format
         self._file_paths = [
-            normalize_storage_path(file_path, io_config) for file_path in list_files(file_path, io_config)
+            normalize_storage_path(file_path, io_config)
+            for file_path in list_files(file_path, io_config)
+            # rosbag2 recordings are directories with a metadata.yaml file besides their storage files.
+            if not file_path.endswith("metadata.yaml")
         ]
 
         if self._file_paths is None or len(self._file_paths) == 0:
             raise FileNotFoundError(f"Path not found: {file_path}")
+        if message_format != "raw" and any(_is_rosbag2_db3(p) for p in self._file_paths):
+            raise ValueError(
+                "rosbag2 recordings in the SQLite storage format don't record message definitions to decode messages "
+                "with, please read them with message_format='raw'"
+            )
 
         self._schema = self._infer_schema(self._file_paths[0])
-        self._io_config = io_config
 
     @property
     def name(self) -> str:
@@ -137,7 +214,7 @@ class MCAPSource(DataSource):
         return self._schema
 
     def display_name(self) -> str:
-        return f"MCAPSource({self._file_paths}, start_time={self._start_time}, end_time={self._end_time}, topics={self._topics})"
+        return f"MCAPSource({self._file_paths}, start_time={self._start_time}, end_time={self._end_time}, topics={self._topics}, message_format={self._message_format})"
 
     def multiline_display(self) -> list[str]:
         return [
@@ -148,16 +225,44 @@ class MCAPSource(DataSource):
     def _infer_schema(self, sample_path: str) -> Schema:
         import pyarrow as pa
 
-        schema = pa.schema(
-            [
-                pa.field("topic", pa.string()),
-                pa.field("log_time", pa.int64()),
-                pa.field("publish_time", pa.int64()),
-                pa.field("sequence", pa.int32()),
-                pa.field("data", pa.string()),
-            ]
+        fields = _base_fields()
+        if self._message_format == "string":
+            fields.append(pa.field("data", pa.string()))
+        elif self._message_format == "raw":
+            fields.extend(
+                [
+                    pa.field("schema_name", pa.string()),
+                    pa.field("message_encoding", pa.string()),
+                    pa.field("data", pa.large_binary()),
+                ]
+            )
+        else:
+            fields.extend(self._infer_topic_fields(sample_path))
+        return Schema.from_pyarrow_schema(pa.schema(fields))
+
+    def _infer_topic_fields(self, sample_path: str) -> list[Any]:
+        """Infers the struct type of the decoded messages of each topic from its first messages."""
+        import pyarrow as pa
+
+        task = MCAPSourceTask(
+            _file_path=sample_path,
+            _schema=Schema.from_pyarrow_schema(pa.schema(_base_fields())),
+            _start_time=self._start_time,
+            _end_time=self._end_time,
+            _topics=self._topics,
+            _io_config=self._io_config,
+            _message_format="struct",
         )
-        return Schema.from_pyarrow_schema(schema)
+        topics = task._summary_topics()
+        samples: dict[str, list[Any]] = {}
+        for topic, _, message in task._iter_decoded_messages():
+            topic_samples = samples.setdefault(topic, [])
+            if len(topic_samples) < _NUM_MESSAGES_TO_INFER:
+                topic_samples.append(message)
+            # Stop once there are enough samples of every topic, if the file's summary lists its topics.
+            if topics is not None and all(len(samples.get(t, [])) >= _NUM_MESSAGES_TO_INFER for t in topics):
+                break
+        return [pa.field(topic, pa.array(values).type) for topic, values in samples.items()]
 
     def get_tasks(self, pushdowns: Pushdowns | None = None) -> Iterator[MCAPSourceTask]:
         for file_path in self._file_paths:
@@ -169,6 +274,7 @@ class MCAPSource(DataSource):
                 _end_time=self._end_time,
                 _topics=self._topics,
                 _io_config=self._io_config,
+                _message_format=self._message_format,
             )
 
 
@@ -181,8 +287,28 @@ class MCAPSourceTask(DataSourceTask):
     _end_time: int | None = None
     _topics: list[str] | None = None
     _io_config: IOConfig | None = None
+    _message_format: MESSAGE_FORMAT = "string"
 
-    def execute(self) -> Iterator[MicroPartition]:
+    def _summary_topics(self) -> set[str] | None:
+        """Returns the topics of the file's channels from its summary, or None if it has no summary."""
+        from mcap.reader import make_reader
+
+        from daft.filesystem import _infer_filesystem
+
+        resolved_path, fs, _ = _infer_filesystem(self._file_path, self._io_config)
+
+        with fs.open_input_file(resolved_path) as file_obj:
+            summary = make_reader(file_obj).get_summary()
+        if summary is None:
+            return None
+        return {
+            channel.topic
+            for channel in summary.channels.values()
+            if self._topics is None or channel.topic in self._topics
+        }
+
+    def _iter_decoded_messages(self) -> Iterator[tuple[str, Any, Any]]:
+        """Yields the topic, MCAP message record and decoded message of each message in log time order."""
         from mcap.reader import make_reader
         from mcap_protobuf.decoder import DecoderFactory as ProtobufDecoderFactory
         from mcap_ros2.decoder import DecoderFactory as Ros2DecoderFactory
@@ -197,27 +323,103 @@ class MCAPSourceTask(DataSourceTask):
             reader = make_reader(
                 file_obj, decoder_factories=[Ros2DecoderFactory(), ProtobufDecoderFactory(), JsonDecoderFactory()]
             )
+            for _, channel, message, decoded in reader.iter_decoded_messages(
+                topics=self._topics, start_time=self._start_time, end_time=self._end_time, log_time_order=True
+            ):
+                yield channel.topic, message, _to_pyobj(decoded) if self._message_format == "struct" else decoded
+
+    def _iter_raw_messages(self) -> Iterator[dict[str, Any]]:
+        from mcap.reader import make_reader
 
-            buffer = []
-            for _, channel, message, ros_msg in reader.iter_decoded_messages(
+        from daft.filesystem import _infer_filesystem
+
+        resolved_path, fs, _ = _infer_filesystem(self._file_path, self._io_config)
+
+        with fs.open_input_file(resolved_path) as file_obj:
+            reader = make_reader(file_obj)
+            for schema, channel, message in reader.iter_messages(
                 topics=self._topics, start_time=self._start_time, end_time=self._end_time, log_time_order=True
             ):
-                buffer.append(
-                    {
-                        "topic": channel.topic,
-                        "log_time": message.log_time,
-                        "publish_time": message.publish_time,
-                        "sequence": message.sequence,
-                        "data": str(ros_msg),
-                    }
-                )
-
-                if len(buffer) >= self._batch_size:
-                    yield self._create_micropartition(buffer)
-                    buffer.clear()
-
-            if buffer:
+                yield {
+                    "topic": channel.topic,
+                    "log_time": message.log_time,
+                    "publish_time": message.publish_time,
+                    "sequence": message.sequence,
+                    "schema_name": schema.name if schema is not None else None,
+                    "message_encoding": channel.message_encoding,
+                    "data": message.data,
+                }
+
+    def _iter_db3_messages(self) -> Iterator[dict[str, Any]]:
+        """Reads the messages of a rosbag2 recording in the SQLite storage format, which must be a local file."""
+        import sqlite3
+
+        query = (
+            "SELECT topics.name, messages.timestamp, topics.type, topics.serialization_format, messages.data "
+            "FROM messages JOIN topics ON messages.topic_id = topics.id"
+        )
+        conditions = []
+        params: list[Any] = []
+        if self._topics is not None:
+            conditions.append(f"topics.name IN ({', '.join('?' * len(self._topics))})")
+            params.extend(self._topics)
+        if self._start_time is not None:
+            conditions.append("messages.timestamp >= ?")
+            params.append(self._start_time)
+        if self._end_time is not None:
+            conditions.append("messages.timestamp < ?")
+            params.append(self._end_time)
+        if conditions:
+            query += " WHERE " + " AND ".join(conditions)
+        query += " ORDER BY messages.timestamp"
+
+        with sqlite3.connect(f"file:{self._file_path.removeprefix('file://')}?mode=ro", uri=True) as conn:
+            for topic, timestamp, schema_name, encoding, data in conn.execute(query, params):
+                # rosbag2 records the receive time of messages only.
+                yield {
+                    "topic": topic,
+                    "log_time": timestamp,
+                    "publish_time": timestamp,
+                    "sequence": 0,
+                    "schema_name": schema_name,
+                    "message_encoding": encoding,
+                    "data": data,
+                }
+
+    def _iter_rows(self) -> Iterable[dict[str, Any]]:
+        if self._message_format == "raw":
+            if _is_rosbag2_db3(self._file_path):
+                yield from self._iter_db3_messages()
+            else:
+                yield from self._iter_raw_messages()
+            return
+
+        topic_columns = [name for name in self._schema.column_names() if name not in _BASE_COLUMNS]
+        for topic, message, decoded in self._iter_decoded_messages():
+            row: dict[str, Any] = {
+                "topic": topic,
+                "log_time": message.log_time,
+                "publish_time": message.publish_time,
+                "sequence": message.sequence,
+            }
+            if self._message_format == "string":
+                row["data"] = str(decoded)
+            else:
+                for column in topic_columns:
+                    row[column] = decoded if column == topic else None
+            yield row
+
+    def execute(self) -> Iterator[MicroPartition]:
+        buffer = []
+        for row in self._iter_rows():
+            buffer.append(row)
+
+            if len(buffer) >= self._batch_size:
                 yield self._create_micropartition(buffer)
+                buffer.clear()
+
+        if buffer:
+            yield self._create_micropartition(buffer)
 
     def _create_micropartition(self, data: list[dict[str, object]]) -> MicroPartition:
         import pyarrow as pa
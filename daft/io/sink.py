@@ -28,6 +28,36 @@ class WriteResult(Generic[WriteResultType]):
     rows_written: int
 
 
+class DataSinkWriter(ABC, Generic[WriteResultType]):
+    """Writes the micropartitions of a single partition of a DataFrame to a sink.
+
+    A writer is created by [`DataSink.writer`][daft.io.sink.DataSink.writer] for each partition which is written, and
+    can hold a resource such as a connection, a multipart upload or a staged file for the lifetime of the partition.
+    Its micropartitions are passed to `.write()`, after which either `.commit()` is called once to complete the write of
+    the partition, or `.abort()` is called if the write of the partition failed.
+
+    Warning:
+        This API is early in its development and is subject to change.
+    """
+
+    @abstractmethod
+    def write(self, micropartition: MicroPartition) -> None:
+        """Writes a micropartition of the partition."""
+        raise NotImplementedError
+
+    @abstractmethod
+    def commit(self) -> WriteResult[WriteResultType]:
+        """Completes the write of the partition and returns its result, which is passed to `DataSink.finalize()`."""
+        raise NotImplementedError
+
+    def abort(self) -> None:
+        """Optional callback for when the write of the partition failed, to clean up what was written so far.
+
+        The default implementation does nothing.
+        """
+        pass
+
+
 class DataSink(ABC, Generic[WriteResultType]):
     """Interface for writing data to a sink that is not built-in.
 
@@ -38,7 +68,14 @@ class DataSink(ABC, Generic[WriteResultType]):
     3. The sink's `.write()` method is invoked on each micropartition, potentially in parallel
        and distributed across multiple tasks or workers.
     4. After all writes complete, the resulting `WriteOutput` objects are gathered on a single node.
-    5. The `.finalize()` method is then called with all write outputs to produce a final `MicroPartition`.
+    5. The `.finalize()` method is then called with all write outputs to produce a final `MicroPartition`,
+       whose schema is given by `.schema()`. This is where the writes can be committed, e.g. by committing a
+       transaction or publishing a manifest of the written files.
+    6. If the write fails, the sink's `.abort()` method is called instead of `.finalize()`.
+
+    Instead of implementing `.write()`, a sink can implement `.writer()` to write each partition with a
+    [`DataSinkWriter`][daft.io.sink.DataSinkWriter], which is committed once all micropartitions of the partition
+    are written, or aborted if the write of the partition fails.
 
     Warning:
         This API is early in its development and is subject to change.
@@ -64,12 +101,22 @@ class DataSink(ABC, Generic[WriteResultType]):
         """
         pass
 
-    @abstractmethod
+    def writer(self) -> DataSinkWriter[WriteResultType]:
+        """Creates a writer for a partition of the DataFrame being written.
+
+        Sinks which implement this method don't need to implement `.write()`.
+
+        Returns:
+            DataSinkWriter[WriteResultType]: A writer for the micropartitions of a single partition.
+        """
+        raise NotImplementedError(f"{type(self).__name__} must implement either `.write()` or `.writer()`")
+
     def write(self, micropartitions: Iterator[MicroPartition]) -> Iterator[WriteResult[WriteResultType]]:
         """Writes a stream of micropartitions to the sink.
 
         This method should handle the ingestion of each micropartition and yield a result
-        (e.g. metadata) for each successful write.
+        (e.g. metadata) for each successful write. The default implementation writes the
+        micropartitions with a single writer from `.writer()`.
 
         Args:
             micropartitions (Iterator[MicroPartition]): An iterator of micropartitions to be written.
@@ -77,7 +124,14 @@ class DataSink(ABC, Generic[WriteResultType]):
         Returns:
             Iterator[WriteResult[WriteResultType]]: An iterator of write results wrapped in a WriteOutput.
         """
-        raise NotImplementedError
+        writer = self.writer()
+        try:
+            for micropartition in micropartitions:
+                writer.write(micropartition)
+        except Exception:
+            writer.abort()
+            raise
+        yield writer.commit()
 
     def safe_write(self, micropartitions: Iterator[MicroPartition]) -> Iterator[WriteResult[WriteResultType]]:
         """This method wraps the abstract `write()` method with a try block to reraise potentially unserializable exceptions.
@@ -94,7 +148,16 @@ class DataSink(ABC, Generic[WriteResultType]):
         try:
             yield from self.write(micropartitions)
         except Exception as e:
-            raise RuntimeError(f"Exception occurred while writing to {self.name()}: {type(e).__name__}: {e!s}") from e
+            raise self._write_error(e) from e
+
+    def _write_error(self, e: Exception) -> RuntimeError:
+        return RuntimeError(f"Exception occurred while writing to {self.name()}: {type(e).__name__}: {e!s}")
+
+    def _open_partition(self) -> _PartitionWrite[WriteResultType]:
+        """Starts the write of a partition, which is driven by the writers of the execution engine."""
+        if type(self).writer is DataSink.writer:
+            return _MicroPartitionWrite(self)
+        return _WriterWrite(self, self.writer())
 
     @abstractmethod
     def finalize(self, write_results: list[WriteResult[WriteResultType]]) -> MicroPartition:
@@ -110,3 +173,75 @@ class DataSink(ABC, Generic[WriteResultType]):
             MicroPartition: A final, single micropartition representing the result of all writes.
         """
         raise NotImplementedError
+
+    def abort(self) -> None:
+        """Optional callback for when a write operation fails, instead of `.finalize()`.
+
+        For example, this can be used to roll back a transaction started in `.start()` or to delete staged data.
+        The default implementation does nothing.
+        """
+        pass
+
+
+class _PartitionWrite(ABC, Generic[WriteResultType]):
+    """The write of a partition to a sink by the execution engine.
+
+    The micropartitions of the partition are written as they are produced, after which the write of the partition is
+    either committed, or aborted if it failed. Exceptions are wrapped like in `DataSink.safe_write()`.
+    """
+
+    @abstractmethod
+    def write(self, micropartition: MicroPartition) -> list[WriteResult[WriteResultType]]: ...
+
+    @abstractmethod
+    def commit(self) -> list[WriteResult[WriteResultType]]: ...
+
+    @abstractmethod
+    def abort(self) -> None: ...
+
+
+class _MicroPartitionWrite(_PartitionWrite[WriteResultType]):
+    """Writes each micropartition with `DataSink.write()`, for sinks which don't implement `DataSink.writer()`."""
+
+    def __init__(self, sink: DataSink[WriteResultType]) -> None:
+        self._sink = sink
+
+    def write(self, micropartition: MicroPartition) -> list[WriteResult[WriteResultType]]:
+        return list(self._sink.safe_write(iter([micropartition])))
+
+    def commit(self) -> list[WriteResult[WriteResultType]]:
+        return []
+
+    def abort(self) -> None:
+        pass
+
+
+class _WriterWrite(_PartitionWrite[WriteResultType]):
+    """Writes the micropartitions of a partition with a single `DataSinkWriter`, which is aborted at most once."""
+
+    def __init__(self, sink: DataSink[WriteResultType], writer: DataSinkWriter[WriteResultType]) -> None:
+        self._sink = sink
+        self._writer = writer
+        self._done = False
+
+    def write(self, micropartition: MicroPartition) -> list[WriteResult[WriteResultType]]:
+        try:
+            self._writer.write(micropartition)
+        except Exception as e:
+            self.abort()
+            raise self._sink._write_error(e) from e
+        return []
+
+    def commit(self) -> list[WriteResult[WriteResultType]]:
+        try:
+            result = self._writer.commit()
+        except Exception as e:
+            self.abort()
+            raise self._sink._write_error(e) from e
+        self._done = True
+        return [result]
+
+    def abort(self) -> None:
+        if not self._done:
+            self._done = True
+            self._writer.abort()
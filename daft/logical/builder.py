@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import functools
+import json
 from typing import TYPE_CHECKING, Any
 
 from daft.context import get_context
@@ -69,6 +70,10 @@ class LogicalPlanBuilder:
         builder = self._builder.summarize()
         return LogicalPlanBuilder(builder)
 
+    def analyze(self, num_buckets: int) -> LogicalPlanBuilder:
+        builder = self._builder.analyze(num_buckets)
+        return LogicalPlanBuilder(builder)
+
     def pretty_print(self, simple: bool = False, format: str = "ascii") -> str:
         """Pretty prints the current underlying logical plan."""
         from daft.dataframe.display import MermaidOptions
@@ -77,6 +82,10 @@ class LogicalPlanBuilder:
             return self._builder.repr_ascii(simple)
         elif format == "mermaid":
             return self._builder.repr_mermaid(MermaidOptions(simple))
+        elif format == "dot":
+            return self._builder.repr_dot(simple)
+        elif format == "json":
+            return self._builder.repr_graph_json(simple)
         else:
             raise ValueError(f"Unknown format: {format}")
 
@@ -92,6 +101,22 @@ class LogicalPlanBuilder:
         builder = self._builder.optimize(execution_config)
         return LogicalPlanBuilder(builder)
 
+    def optimizer_trace(self, execution_config: PyDaftExecutionConfig) -> str:
+        """Optimize the underlying logical plan and return a step-by-step diff of each rewrite, attributed to the rule that made it."""
+        return self._builder.optimizer_trace(execution_config)
+
+    def fingerprint(self, execution_config: PyDaftExecutionConfig) -> str | None:
+        """The fingerprint which the optimized plan is cached by, or None if the plan can't be cached."""
+        return self._builder.fingerprint(execution_config)
+
+    def column_lineage(self) -> dict[str, Any]:
+        """Returns the column-level lineage of the current logical plan, which should already be optimized.
+
+        The lineage has the descriptions of the plan's sources, and for each output column, the source columns which
+        contribute to it and the expressions which transform them.
+        """
+        return json.loads(self._builder.column_lineage())
+
     @classmethod
     @_apply_daft_planning_config_to_initializer
     def from_in_memory_scan(
@@ -177,9 +202,15 @@ class LogicalPlanBuilder:
         builder = self._builder.shard(strategy, world_size, rank)
         return LogicalPlanBuilder(builder)
 
-    def explode(self, explode_expressions: list[Expression]) -> LogicalPlanBuilder:
+    def preview(self, rows_per_file: int, max_files: int) -> LogicalPlanBuilder:
+        builder = self._builder.preview(rows_per_file, max_files)
+        return LogicalPlanBuilder(builder)
+
+    def explode(
+        self, explode_expressions: list[Expression], index_column: str | None = None, pad_mismatched: bool = False
+    ) -> LogicalPlanBuilder:
         explode_pyexprs = [expr._expr for expr in explode_expressions]
-        builder = self._builder.explode(explode_pyexprs)
+        builder = self._builder.explode(explode_pyexprs, index_column, pad_mismatched)
         return LogicalPlanBuilder(builder)
 
     def unpivot(
@@ -278,6 +309,19 @@ class LogicalPlanBuilder:
         builder = self._builder.pivot(group_by_pyexprs, pivot_col._expr, value_col._expr, agg_fn._expr, names)
         return LogicalPlanBuilder(builder)
 
+    def resample(
+        self,
+        ts_col: Expression,
+        every: Expression,
+        by: list[Expression],
+        agg_exprs: list[Expression],
+        fill: str | None = None,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.resample(
+            ts_col._expr, every._expr, [expr._expr for expr in by], [expr._expr for expr in agg_exprs], fill
+        )
+        return LogicalPlanBuilder(builder)
+
     def join(
         self,
         right: LogicalPlanBuilder,
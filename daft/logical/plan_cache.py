@@ -0,0 +1,42 @@
+"""Inspection and eviction of the cache of optimized logical plans.
+
+Optimized plans are cached by the fingerprint of the unoptimized plan and of the configs which they're optimized with,
+so executing the same dataframe again skips optimization. The number of cached plans is set by the
+`DAFT_PLAN_CACHE_SIZE` environment variable. The cache is off by default, since executing the same dataframe again
+would reuse the scan tasks which were planned when the files it reads were first listed.
+"""
+
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+from daft.daft import evict_from_plan_cache as _evict_from_plan_cache
+from daft.daft import plan_cache_entries as _plan_cache_entries
+
+
+@dataclass(frozen=True)
+class PlanCacheEntry:
+    """An optimized plan in the cache."""
+
+    fingerprint: str
+    """The fingerprint of the unoptimized plan and of the configs which it was optimized with."""
+    hits: int
+    """The number of times the optimized plan was reused."""
+    plan: str
+    """The optimized plan, displayed as a tree."""
+
+
+def plan_cache_entries() -> list[PlanCacheEntry]:
+    """Returns the optimized plans in the cache, from the most to the least recently used."""
+    return [PlanCacheEntry(fingerprint, hits, plan) for fingerprint, hits, plan in _plan_cache_entries()]
+
+
+def evict_from_plan_cache(fingerprint: str | None = None) -> int:
+    """Evicts the optimized plan with a fingerprint from the cache, or every plan if no fingerprint is given.
+
+    The fingerprint of a dataframe's plan is given by `df._builder.fingerprint(execution_config)`.
+
+    Returns:
+        int: The number of plans which were evicted.
+    """
+    return _evict_from_plan_cache(fingerprint)
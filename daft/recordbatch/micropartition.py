@@ -33,6 +33,7 @@ logger = logging.getLogger(__name__)
 
 class MicroPartition:
     _micropartition: _PyMicroPartition
+    _pickle_compression: tuple[str, int] | None = None
 
     def __init__(self) -> None:
         raise NotImplementedError("We do not support creating a MicroPartition via __init__ ")
@@ -174,8 +175,11 @@ class MicroPartition:
             coerce_temporal_nanoseconds=coerce_temporal_nanoseconds,
         )
 
-    def to_ipc_stream(self) -> bytes:
-        return self._micropartition.write_to_ipc_stream()
+    def to_ipc_stream(self, compression: str | None = None, compression_level: int = 0) -> bytes:
+        return self._micropartition.write_to_ipc_stream(compression, compression_level)
+
+    def write_ipc_file(self, path: str, compression: str | None = None, compression_level: int = 0) -> None:
+        self._micropartition.write_ipc_file(path, compression, compression_level)
 
     ###
     # Compute methods (MicroPartition -> MicroPartition)
@@ -327,8 +331,6 @@ class MicroPartition:
         how: JoinType = JoinType.Inner,
         is_sorted: bool = False,
     ) -> MicroPartition:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -342,7 +344,7 @@ class MicroPartition:
 
         return MicroPartition._from_pymicropartition(
             self._micropartition.sort_merge_join(
-                right._micropartition, left_on=left_exprs, right_on=right_exprs, is_sorted=is_sorted
+                right._micropartition, left_on=left_exprs, right_on=right_exprs, how=how, is_sorted=is_sorted
             )
         )
 
@@ -441,7 +443,15 @@ class MicroPartition:
             raise TypeError(f"Expected a bool, list[bool] or None for `nulls_first` but got {type(nulls_first)}")
         return Series._from_pyseries(self._micropartition.argsort(pyexprs, descending, nulls_first))
 
+    def _compress_when_pickled(self, compression: str, compression_level: int) -> MicroPartition:
+        """Makes pickling this partition, such as to send it to another Ray worker, write a compressed Arrow IPC stream."""
+        if compression != "none" and not any(field.dtype.contains_python() for field in self.schema()):
+            self._pickle_compression = (compression, compression_level)
+        return self
+
     def __reduce__(self) -> tuple[Callable, tuple]:  # type: ignore[type-arg]
+        if self._pickle_compression is not None:
+            return MicroPartition.from_ipc_stream, (self.to_ipc_stream(*self._pickle_compression),)
         batches = self.get_record_batches()
         if len(batches) == 0:
             return MicroPartition.empty, (self.schema(),)
@@ -366,8 +366,6 @@ class RecordBatch:
         how: JoinType = JoinType.Inner,
         is_sorted: bool = False,
     ) -> RecordBatch:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -384,6 +382,36 @@ class RecordBatch:
                 right._recordbatch,
                 left_on=left_exprs,
                 right_on=right_exprs,
+                how=how,
+                is_sorted=is_sorted,
+            )
+        )
+
+    def sorted_lookup_join(
+        self,
+        right: RecordBatch,
+        left_on: ExpressionsProjection,
+        right_on: ExpressionsProjection,
+        how: JoinType = JoinType.Inner,
+        is_sorted: bool = False,
+    ) -> RecordBatch:
+        if len(left_on) != len(right_on):
+            raise ValueError(
+                f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
+            )
+
+        if not isinstance(right, RecordBatch):
+            raise TypeError(f"Expected a Table for `right` in join but got {type(right)}")
+
+        left_exprs = [e._expr for e in left_on]
+        right_exprs = [e._expr for e in right_on]
+
+        return RecordBatch._from_pyrecordbatch(
+            self._recordbatch.sorted_lookup_join(
+                right._recordbatch,
+                left_on=left_exprs,
+                right_on=right_exprs,
+                how=how,
                 is_sorted=is_sorted,
             )
         )
@@ -3,6 +3,7 @@ from __future__ import annotations
 import asyncio
 import logging
 import os
+import time
 import uuid
 from dataclasses import dataclass
 from typing import TYPE_CHECKING, NamedTuple
@@ -18,6 +19,7 @@ from daft.daft import (
     RaySwordfishTask,
     RaySwordfishWorker,
     RayTaskResult,
+    build_bloom_filter,
     set_compute_runtime_num_worker_threads,
 )
 from daft.event_loop import set_event_loop
@@ -30,7 +32,7 @@ from daft.runners.partitioning import (
 from daft.runners.profiler import profile
 
 if TYPE_CHECKING:
-    from collections.abc import AsyncGenerator, AsyncIterator, Iterator
+    from collections.abc import AsyncGenerator, AsyncIterator, Generator
 
     from daft.runners.ray_runner import RayMaterializedResult
 
@@ -87,7 +89,7 @@ class RaySwordfishActor:
                     break
                 mp = MicroPartition._from_pymicropartition(partition)
                 metas.append(PartitionMetadata.from_table(mp))
-                yield mp
+                yield mp._compress_when_pickled(exec_cfg.shuffle_compression, exec_cfg.shuffle_compression_level)
 
             stats = await result_handle.finish()
             yield SwordfishTaskMetadata(partition_metadatas=metas, stats=stats)
@@ -121,6 +123,20 @@ async def get_boundaries(
     return await get_boundaries_remote.remote(sort_by, descending, nulls_first, num_quantiles, *samples)
 
 
+@ray.remote  # type: ignore[untyped-decorator]
+def build_bloom_filter_remote(num_items: int, false_positive_rate: float, partition: MicroPartition) -> bytes:
+    return build_bloom_filter(partition._micropartition, num_items, false_positive_rate)
+
+
+async def build_bloom_filters(
+    partitions: list[ray.ObjectRef], num_items: int, false_positive_rate: float
+) -> list[bytes]:
+    """Builds a Bloom filter from each partition of join keys where it is stored, so only the filters are fetched."""
+    return await asyncio.gather(
+        *(build_bloom_filter_remote.remote(num_items, false_positive_rate, partition) for partition in partitions)
+    )
+
+
 @dataclass
 class RaySwordfishTaskHandle:
     """RaySwordfishTaskHandle is a handle to a task that is running on a swordfish actor.
@@ -281,6 +297,11 @@ class RemoteFlotillaRunner:
         )
         return materialized_result
 
+    def cancel_plan(self, plan_id: str) -> None:
+        # Dropping the plan's results stream stops the plan.
+        self.curr_plans.pop(plan_id, None)
+        self.curr_result_gens.pop(plan_id, None)
+
 
 FLOTILLA_RUNNER_NAMESPACE = "daft"
 FLOTILLA_RUNNER_NAME = "flotilla-plan-runner"
@@ -358,11 +379,20 @@ class FlotillaRunner:
         self,
         plan: DistributedPhysicalPlan,
         partition_sets: dict[str, PartitionSet[ray.ObjectRef]],
-    ) -> Iterator[RayMaterializedResult]:
+        deadline: float | None = None,
+    ) -> Generator[RayMaterializedResult, None, bool]:
+        """Yields the results of a plan, and returns whether the plan was cancelled for running past `deadline`."""
         plan_id = plan.idx()
         ray.get(self.runner.run_plan.remote(plan, partition_sets))
         while True:
-            materialized_result = ray.get(self.runner.get_next_partition.remote(plan_id))
+            next_partition = self.runner.get_next_partition.remote(plan_id)
+            if deadline is not None:
+                ready, _ = ray.wait([next_partition], timeout=max(deadline - time.monotonic(), 0))
+                if not ready:
+                    ray.cancel(next_partition)
+                    ray.get(self.runner.cancel_plan.remote(plan_id))
+                    return True
+            materialized_result = ray.get(next_partition)
             if materialized_result is None:
-                break
+                return False
             yield materialized_result
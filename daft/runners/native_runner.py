@@ -1,7 +1,12 @@
 from __future__ import annotations
 
 import logging
+import os
+import shutil
+import tempfile
+import time
 import uuid
+import weakref
 from typing import TYPE_CHECKING
 
 from daft.context import get_context
@@ -15,16 +20,18 @@ from daft.daft import (
     QueryEndState,
     set_compute_runtime_num_worker_threads,
 )
-from daft.errors import UDFException
+from daft.errors import ExecutionTimeoutError, UDFException
 from daft.execution.native_executor import NativeExecutor
 from daft.filesystem import glob_path_with_stats
 from daft.recordbatch import MicroPartition
 from daft.runners import runner_io
 from daft.runners.partitioning import (
+    ExecutionCompleteness,
     LocalMaterializedResult,
     LocalPartitionSet,
     PartitionCacheEntry,
     PartitionSetCache,
+    SpilledMaterializedResult,
 )
 from daft.runners.runner import LOCAL_PARTITION_SET_CACHE, Runner
 from daft.scarf_telemetry import track_runner_on_scarf
@@ -73,22 +80,64 @@ class NativeRunner(Runner[MicroPartition]):
     def runner_io(self) -> NativeRunnerIO:
         return NativeRunnerIO()
 
-    def run(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry:
-        results = list(self.run_iter(builder))
+    def run(self, builder: LogicalPlanBuilder, max_execution_time: float | None = None) -> PartitionCacheEntry:
+        execution_config = get_context().daft_execution_config
+        spill_threshold = execution_config.collect_spill_threshold_bytes
+        # Each query spills to its own directory, which is removed along with its results.
+        spill_dir = tempfile.mkdtemp(prefix="daft-spill-") if spill_threshold > 0 else None
 
+        start = time.monotonic()
         result_pset = LocalPartitionSet()
-        for i, result in enumerate(results):
-            result_pset.set_partition(i, result)
+        in_memory_bytes = 0
+        try:
+            for i, result in enumerate(self.run_iter(builder, max_execution_time=max_execution_time)):
+                # Once the results kept in memory exceed the spill threshold, the remaining partitions are written
+                # straight from the executor's output to disk, and dropped from memory.
+                if spill_dir is not None:
+                    part = result.partition()
+                    if in_memory_bytes > spill_threshold and not any(
+                        field.dtype.contains_python() for field in part.schema()
+                    ):
+                        result = SpilledMaterializedResult.spill(
+                            part,
+                            os.path.join(spill_dir, f"{i}.arrow"),
+                            execution_config.shuffle_compression,
+                            execution_config.shuffle_compression_level,
+                        )
+                    else:
+                        in_memory_bytes += part.size_bytes() or 0
+                result_pset.set_partition(i, result)
+        except ExecutionTimeoutError as e:
+            # Partial results aren't cached, as they'd otherwise be mistaken for the results of the query.
+            if spill_dir is not None:
+                weakref.finalize(result_pset, shutil.rmtree, spill_dir, ignore_errors=True)
+            completeness = ExecutionCompleteness.of(result_pset, False, time.monotonic() - start)
+            raise ExecutionTimeoutError(e.max_execution_time, result_pset, completeness) from None
+        except BaseException:
+            if spill_dir is not None:
+                shutil.rmtree(spill_dir, ignore_errors=True)
+            raise
+        if spill_dir is not None:
+            weakref.finalize(result_pset, shutil.rmtree, spill_dir, ignore_errors=True)
 
         pset_entry = self.put_partition_set_into_cache(result_pset)
+        if max_execution_time is not None:
+            pset_entry.completeness = ExecutionCompleteness.of(result_pset, True, time.monotonic() - start)
         return pset_entry
 
     def run_iter(
         self,
         builder: LogicalPlanBuilder,
         results_buffer_size: int | None = None,
+        max_execution_time: float | None = None,
     ) -> Iterator[LocalMaterializedResult]:
+        """Yields the results of a plan as they're produced.
+
+        If `max_execution_time` is set, the query is cancelled once it has run for that many seconds, and
+        `ExecutionTimeoutError` is raised after the results produced before then.
+        """
         track_runner_on_scarf(runner=self.name)
+        deadline = time.monotonic() + max_execution_time if max_execution_time is not None else None
 
         # NOTE: Freeze and use this same execution config for the entire execution
         ctx = get_context()
@@ -96,7 +145,8 @@ class NativeRunner(Runner[MicroPartition]):
         output_schema = builder.schema()
 
         # Optimize the logical plan.
-        ctx._notify_query_start(query_id, PyQueryMetadata(output_schema._schema, builder.repr_json()))
+        metadata = PyQueryMetadata(output_schema._schema, builder.repr_json(), ctx.daft_execution_config)
+        ctx._notify_query_start(query_id, metadata)
         ctx._notify_optimization_start(query_id)
         builder = builder.optimize(ctx.daft_execution_config)
         ctx._notify_optimization_end(query_id, builder.repr_json())
@@ -109,6 +159,7 @@ class NativeRunner(Runner[MicroPartition]):
             ctx,
             results_buffer_size,
             {"query_id": query_id},
+            deadline=deadline,
         )
 
         try:
@@ -130,6 +181,11 @@ class NativeRunner(Runner[MicroPartition]):
             ctx._notify_query_end(query_id, query_result)
             raise e
         else:
+            if executor.timed_out:
+                assert max_execution_time is not None
+                timeout = ExecutionTimeoutError(max_execution_time)
+                ctx._notify_query_end(query_id, PyQueryResult(QueryEndState.Canceled, str(timeout)))
+                raise timeout
             query_result = PyQueryResult(QueryEndState.Finished, "")
             ctx._notify_query_end(query_id, query_result)
 
@@ -3,11 +3,11 @@ from __future__ import annotations
 import threading
 import weakref
 from abc import abstractmethod
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from typing import TYPE_CHECKING, Any, Generic, TypeVar
 from uuid import uuid4
 
-from daft.daft import PyMicroPartitionSet
+from daft.daft import PyMicroPartition, PyMicroPartitionSet
 from daft.datatype import TimeUnit
 from daft.recordbatch import MicroPartition
 
@@ -273,10 +273,13 @@ class PartitionSet(Generic[PartitionT]):
 
 class LocalPartitionSet(PartitionSet[MicroPartition]):
     _pset: PyMicroPartitionSet
+    # Partitions which were spilled to local files, which are kept out of memory until they're accessed.
+    _spilled: dict[PartID, SpilledMaterializedResult]
 
     def __init__(self) -> None:
         super().__init__()
         self._pset = PyMicroPartitionSet()
+        self._spilled = {}
 
     @classmethod
     def _from_micropartition_set(cls, pset: PyMicroPartitionSet) -> LocalPartitionSet:
@@ -285,15 +288,26 @@ class LocalPartitionSet(PartitionSet[MicroPartition]):
         return s
 
     def _get_merged_micropartition(self, _schema: Schema) -> MicroPartition:
-        return MicroPartition._from_pymicropartition(self._pset.get_merged_micropartition())
+        if not self._spilled:
+            return MicroPartition._from_pymicropartition(self._pset.get_merged_micropartition())
+        return MicroPartition.concat([part.partition() for _, part in self.items()])
 
     def _get_preview_micropartitions(self, num_rows: int) -> list[MicroPartition]:
-        return [
-            MicroPartition._from_pymicropartition(part) for part in self._pset.get_preview_micropartitions(num_rows)
-        ]
+        if not self._spilled:
+            return [
+                MicroPartition._from_pymicropartition(part)
+                for part in self._pset.get_preview_micropartitions(num_rows)
+            ]
+        preview = []
+        for _, part in self.items():
+            if num_rows <= 0:
+                break
+            preview.append(part.partition().head(num_rows))
+            num_rows -= len(preview[-1])
+        return preview
 
     def items(self) -> list[tuple[PartID, MaterializedResult[MicroPartition]]]:
-        return [
+        items: list[tuple[PartID, MaterializedResult[MicroPartition]]] = [
             (
                 idx,
                 LocalMaterializedResult(
@@ -303,31 +317,46 @@ class LocalPartitionSet(PartitionSet[MicroPartition]):
             )
             for idx, part in self._pset.items()
         ]
+        items.extend(self._spilled.items())
+        return sorted(items, key=lambda item: item[0])
 
     def get_partition(self, idx: PartID) -> MaterializedResult[MicroPartition]:
+        if idx in self._spilled:
+            return self._spilled[idx]
         part = MicroPartition._from_pymicropartition(self._pset.get_partition(idx))
         return LocalMaterializedResult(part, PartitionMetadata.from_table(part))
 
     def set_partition(self, idx: PartID, part: MaterializedResult[MicroPartition]) -> None:
-        self._pset.set_partition(idx, part.partition()._micropartition)
+        if isinstance(part, SpilledMaterializedResult):
+            if self._pset.has_partition(idx):
+                self._pset.delete_partition(idx)
+            self._spilled[idx] = part
+        else:
+            self._spilled.pop(idx, None)
+            self._pset.set_partition(idx, part.partition()._micropartition)
 
     def set_partition_from_table(self, idx: PartID, part: MicroPartition) -> None:
+        self._spilled.pop(idx, None)
         self._pset.set_partition(idx, part._micropartition)
 
     def delete_partition(self, idx: PartID) -> None:
-        self._pset.delete_partition(idx)
+        if self._spilled.pop(idx, None) is None:
+            self._pset.delete_partition(idx)
 
     def has_partition(self, idx: PartID) -> bool:
-        return self._pset.has_partition(idx)
+        return idx in self._spilled or self._pset.has_partition(idx)
 
     def __len__(self) -> int:
-        return len(self._pset)
+        return len(self._pset) + sum(part.metadata().num_rows for part in self._spilled.values())
 
     def size_bytes(self) -> int | None:
-        return self._pset.size_bytes()
+        size_bytes = self._pset.size_bytes()
+        if size_bytes is None:
+            return None
+        return size_bytes + sum(part.metadata().size_bytes or 0 for part in self._spilled.values())
 
     def num_partitions(self) -> int:
-        return self._pset.num_partitions()
+        return self._pset.num_partitions() + len(self._spilled)
 
     def wait(self) -> None:
         pass
@@ -356,10 +385,79 @@ class LocalMaterializedResult(MaterializedResult[MicroPartition]):
         return None
 
 
+@dataclass
+class SpilledMaterializedResult(MaterializedResult[MicroPartition]):
+    """A result partition which was spilled to a local Arrow IPC file.
+
+    The partition isn't kept in memory: the file is memory-mapped when the partition is accessed, or decompressed if
+    it was spilled with compression, and the partition is reused for as long as a caller still holds a reference to it.
+    """
+
+    _path: str
+    _schema: Schema
+    _metadata: PartitionMetadata
+    _cached: weakref.ref[MicroPartition] | None = field(default=None, repr=False, compare=False)
+
+    @classmethod
+    def spill(
+        cls, part: MicroPartition, path: str, compression: str | None = None, compression_level: int = 0
+    ) -> SpilledMaterializedResult:
+        part.write_ipc_file(path, compression, compression_level)
+        return cls(path, part.schema(), PartitionMetadata.from_table(part))
+
+    def partition(self) -> MicroPartition:
+        cached = self._cached() if self._cached is not None else None
+        if cached is not None:
+            return cached
+        part = PyMicroPartition.read_ipc_file(self._path).cast_to_schema(self._schema._schema)
+        result = MicroPartition._from_pymicropartition(part)
+        self._cached = weakref.ref(result)
+        return result
+
+    def micropartition(self) -> MicroPartition:
+        return self.partition()
+
+    def metadata(self) -> PartitionMetadata:
+        return self._metadata
+
+    def cancel(self) -> None:
+        return None
+
+    def _noop(self, _: MicroPartition) -> None:
+        return None
+
+
+@dataclass(frozen=True)
+class ExecutionCompleteness:
+    """How complete the results of a query which was run with a max execution time are."""
+
+    complete: bool
+    """Whether the query finished before its max execution time, rather than being stopped with partial results."""
+    elapsed_seconds: float
+    """How long the query ran for."""
+    partition_num_rows: list[int]
+    """The number of rows in each partition of the results, in partition order."""
+
+    @property
+    def num_partitions(self) -> int:
+        return len(self.partition_num_rows)
+
+    @property
+    def num_rows(self) -> int:
+        return sum(self.partition_num_rows)
+
+    @classmethod
+    def of(cls, pset: PartitionSet[Any], complete: bool, elapsed_seconds: float) -> ExecutionCompleteness:
+        partition_num_rows = [part.metadata().num_rows for _, part in sorted(pset.items(), key=lambda item: item[0])]
+        return cls(complete=complete, elapsed_seconds=elapsed_seconds, partition_num_rows=partition_num_rows)
+
+
 @dataclass(eq=False, repr=False)
 class PartitionCacheEntry:
     key: str
     value: PartitionSet[Any] | None
+    completeness: ExecutionCompleteness | None = None
+    """Set if the partition set holds the results of a query which was run with a max execution time."""
 
     def __eq__(self, other: object) -> bool:
         return isinstance(other, PartitionCacheEntry) and self.key == other.key
@@ -376,6 +474,7 @@ class PartitionCacheEntry:
     def __setstate__(self, key: str) -> None:
         self.key = key
         self.value = None
+        self.completeness = None
 
     def num_partitions(self) -> int | None:
         return self.value.num_partitions() if self.value is not None else None
@@ -17,6 +17,7 @@ from daft.context import get_context
 from daft.daft import DistributedPhysicalPlan
 from daft.daft import PyRecordBatch as _PyRecordBatch
 from daft.dependencies import np
+from daft.errors import ExecutionTimeoutError
 from daft.recordbatch import RecordBatch
 from daft.runners.flotilla import FlotillaRunner
 from daft.scarf_telemetry import track_runner_on_scarf
@@ -51,6 +52,7 @@ from daft.filesystem import glob_path_with_stats
 from daft.recordbatch import MicroPartition
 from daft.runners import runner_io
 from daft.runners.partitioning import (
+    ExecutionCompleteness,
     LocalPartitionSet,
     MaterializedResult,
     PartID,
@@ -540,9 +542,18 @@ class RayRunner(Runner[ray.ObjectRef]):
         return PartitionSetCache()
 
     def run_iter(
-        self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
+        self,
+        builder: LogicalPlanBuilder,
+        results_buffer_size: int | None = None,
+        max_execution_time: float | None = None,
     ) -> Iterator[RayMaterializedResult]:
+        """Yields the results of a plan as they're produced.
+
+        If `max_execution_time` is set, the plan is cancelled once it has run for that many seconds, and
+        `ExecutionTimeoutError` is raised after the results produced before then.
+        """
         track_runner_on_scarf(runner=self.name)
+        deadline = time.monotonic() + max_execution_time if max_execution_time is not None else None
 
         # Grab and freeze the current context
         ctx = get_context()
@@ -558,9 +569,12 @@ class RayRunner(Runner[ray.ObjectRef]):
         if self.flotilla_plan_runner is None:
             self.flotilla_plan_runner = FlotillaRunner()
 
-        yield from self.flotilla_plan_runner.stream_plan(
-            distributed_plan, self._part_set_cache.get_all_partition_sets()
+        timed_out = yield from self.flotilla_plan_runner.stream_plan(
+            distributed_plan, self._part_set_cache.get_all_partition_sets(), deadline=deadline
         )
+        if timed_out:
+            assert max_execution_time is not None
+            raise ExecutionTimeoutError(max_execution_time)
 
     def run_iter_tables(
         self, builder: LogicalPlanBuilder, results_buffer_size: int | None = None
@@ -568,19 +582,29 @@ class RayRunner(Runner[ray.ObjectRef]):
         for result in self.run_iter(builder, results_buffer_size=results_buffer_size):
             yield ray.get(result.partition())
 
-    def _collect_into_cache(self, results_iter: Iterator[RayMaterializedResult]) -> PartitionCacheEntry:
+    def _collect_into_cache(
+        self, results_iter: Iterator[RayMaterializedResult], max_execution_time: float | None = None
+    ) -> PartitionCacheEntry:
+        start = time.monotonic()
         result_pset = RayPartitionSet()
 
-        for i, result in enumerate(results_iter):
-            result_pset.set_partition(i, result)
+        try:
+            for i, result in enumerate(results_iter):
+                result_pset.set_partition(i, result)
+        except ExecutionTimeoutError as e:
+            # Partial results aren't cached, as they'd otherwise be mistaken for the results of the query.
+            completeness = ExecutionCompleteness.of(result_pset, False, time.monotonic() - start)
+            raise ExecutionTimeoutError(e.max_execution_time, result_pset, completeness) from None
 
         pset_entry = self._part_set_cache.put_partition_set(result_pset)
+        if max_execution_time is not None:
+            pset_entry.completeness = ExecutionCompleteness.of(result_pset, True, time.monotonic() - start)
 
         return pset_entry
 
-    def run(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry:
-        results_iter = self.run_iter(builder)
-        return self._collect_into_cache(results_iter)
+    def run(self, builder: LogicalPlanBuilder, max_execution_time: float | None = None) -> PartitionCacheEntry:
+        results_iter = self.run_iter(builder, max_execution_time=max_execution_time)
+        return self._collect_into_cache(results_iter, max_execution_time)
 
     def put_partition_set_into_cache(self, pset: PartitionSet[ray.ObjectRef]) -> PartitionCacheEntry:
         if isinstance(pset, LocalPartitionSet):
@@ -41,7 +41,15 @@ class Runner(Generic[PartitionT]):
     def runner_io(self) -> RunnerIO: ...
 
     @abstractmethod
-    def run(self, builder: LogicalPlanBuilder) -> PartitionCacheEntry: ...
+    def run(self, builder: LogicalPlanBuilder, max_execution_time: float | None = None) -> PartitionCacheEntry:
+        """Runs a plan and caches its results.
+
+        Args:
+            builder: the builder for the LogicalPlan that is to be executed
+            max_execution_time: if set, the number of seconds after which the query is stopped. A stopped query
+                raises `ExecutionTimeoutError` with the results produced so far attached, without caching them, while
+                the cache entry of a query which finishes in time has its `completeness` set.
+        """
 
     @abstractmethod
     def run_iter(
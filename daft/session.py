@@ -11,6 +11,7 @@ from daft.daft import PySession, PyTableSource, sql_exec
 from daft.dataframe import DataFrame
 from daft.logical.builder import LogicalPlanBuilder
 from daft.logical.schema import Schema
+from daft.recordbatch import RecordBatch
 from daft.udf import UDF
 
 if TYPE_CHECKING:
@@ -18,6 +19,7 @@ if TYPE_CHECKING:
 
 __all__ = [
     "Session",
+    "analyze_table",
     "attach",
     "attach_catalog",
     "attach_function",
@@ -160,6 +162,52 @@ class Session:
         else:
             raise ValueError(f"Unsupported return type from sql exec: {type(py_object)}")
 
+    ###
+    # analyze
+    ###
+
+    def analyze_table(
+        self,
+        identifier: Identifier | str,
+        columns: list[str] | None = None,
+        num_buckets: int = 32,
+    ) -> DataFrame:
+        """Scans the table to collect column statistics, which the optimizer uses when later reading the table.
+
+        The statistics are the approximate number of distinct values, the fraction of nulls, and, for numeric
+        columns, the minimum, maximum and an equi-depth histogram of each column. They are merged into the statistics
+        previously collected for the table, replacing those of the columns which are analyzed again. If the
+        `DAFT_TABLE_STATS_DIR` environment variable is set, the statistics of catalog tables are persisted to a JSON
+        file per table in that directory, so that later sessions use them too.
+
+        Args:
+            identifier (Identifier|str): table identifier
+            columns (list[str]|None): columns to collect statistics for, defaults to all columns
+            num_buckets (int): number of buckets in the histograms, defaults to 32
+
+        Returns:
+            DataFrame: the collected statistics, with one row per column.
+
+        Raises:
+            ValueError: If the table isn't read as is from a source, e.g. if it's a view of a filtered dataframe.
+
+        Examples:
+            >>> import daft
+            >>> from daft.session import Session
+            >>>
+            >>> sess = Session()
+            >>> sess.create_temp_table("T", daft.from_pydict({"x": [1, 2, 3]}))
+            >>> sess.analyze_table("T").show()  # doctest: +SKIP
+        """
+        if isinstance(identifier, str):
+            identifier = Identifier.from_str(identifier)
+        table_df = self.get_table(identifier).read()
+        df = table_df.select(*columns) if columns is not None else table_df
+        stats = DataFrame(df._builder.analyze(num_buckets)).collect()
+        batch = RecordBatch.from_arrow_table(stats.to_arrow())
+        self._session.set_table_stats(identifier._ident, batch._recordbatch, table_df._builder._builder)
+        return stats
+
     ###
     # attach & detach
     ###
@@ -566,7 +614,12 @@ class Session:
         Raises:
             ValueError: If the tables does not exist.
         """
-        return self.get_table(identifier).read(**options)
+        if isinstance(identifier, str):
+            identifier = Identifier.from_str(identifier)
+        df = self.get_table(identifier).read(**options)
+        # attach any statistics collected by analyze_table for the optimizer.
+        builder = self._session.with_table_stats(identifier._ident, df._builder._builder)
+        return DataFrame(LogicalPlanBuilder(builder))
 
     ###
     # set_*
@@ -665,6 +718,20 @@ def _session() -> Session:
         return _SESSION
 
 
+###
+# analyze
+###
+
+
+def analyze_table(
+    identifier: Identifier | str,
+    columns: list[str] | None = None,
+    num_buckets: int = 32,
+) -> DataFrame:
+    """Scans the table to collect column statistics for the optimizer of the current session."""
+    return _session().analyze_table(identifier, columns, num_buckets)
+
+
 ###
 # attach & detach
 ###
@@ -195,6 +195,8 @@ class _FuncDecorator:
 
             Unnesting multiple return fields
 
+            The function is evaluated once per row, and each field of the struct it returns becomes a column.
+
             >>> import daft
             >>> from daft import DataType
             >>> @daft.func(
@@ -358,6 +360,16 @@ def cls(
 
     Methods in a Daft class can be used as Daft functions. Use the `@daft.method` decorator to override default arguments.
 
+    A Daft class can define lifecycle hooks, to release resources such as database connections or GPU sessions once
+    they are no longer needed, rather than when the instance is garbage collected:
+
+    * `open_partition(self)` is called by each worker before it evaluates the first batch of a partition.
+    * `close_partition(self)` is called by each worker after it evaluates the last batch of a partition.
+    * `teardown(self)` is called once the instance is no longer used, after which it's discarded. Classes without a
+      `teardown` method keep their instance, which is reused by later queries in the same process.
+
+    Errors raised by `close_partition` and `teardown` are logged, since the results of the query are already computed.
+
     Examples:
         Basic Usage
 
@@ -413,6 +425,26 @@ def cls(
         ...         "batch_classified": my_model.batch_classify(df["prompt"]),
         ...     }
         ... )
+
+        Lifecycle Hooks
+
+        >>> import daft
+        >>> @daft.cls  # doctest: +SKIP
+        ... class Lookup:
+        ...     def __init__(self, dsn: str):
+        ...         self.pool = connect_pool(dsn)
+        ...
+        ...     def open_partition(self):
+        ...         self.conn = self.pool.acquire()
+        ...
+        ...     def close_partition(self):
+        ...         self.pool.release(self.conn)
+        ...
+        ...     def teardown(self):
+        ...         self.pool.close()
+        ...
+        ...     def __call__(self, key: str) -> str:
+        ...         return self.conn.get(key)
     """
 
     def partial_cls(c: type) -> type:
@@ -34,6 +34,11 @@ BATCH_SIZE_ATTR = "_daft_batch_size"
 MAX_RETRIES_ATTR = "_daft_max_retries"
 ON_ERROR_ATTR = "_daft_on_error"
 
+# Optional methods of a Daft class which are called by the workers which run it.
+OPEN_PARTITION_METHOD = "open_partition"
+CLOSE_PARTITION_METHOD = "close_partition"
+TEARDOWN_METHOD = "teardown"
+
 P = ParamSpec("P")
 T = TypeVar("T")
 C = TypeVar("C")
@@ -313,6 +318,16 @@ class ClsBase(ABC, Generic[C]):
     @abstractmethod
     def _daft_get_instance(self) -> C: ...
 
+    # Lifecycle hooks, which are called by the workers which run the Daft class.
+    def _daft_open_partition(self) -> None:
+        """Called by a worker before it evaluates the first batch of a partition."""
+
+    def _daft_close_partition(self) -> None:
+        """Called by a worker after it evaluates the last batch of a partition."""
+
+    def _daft_teardown(self) -> None:
+        """Called once the local instance of the Daft class is no longer used."""
+
     def _daft_bind_method(self, method: Callable[Concatenate[C, P], T]) -> Callable[P, T]:
         """Bind a method to the local instance of the Daft class."""
         local_instance = self._daft_get_instance()
@@ -377,4 +392,21 @@ def wrap_cls(
 
             return self._daft_local_instance
 
+        def _daft_open_partition(self) -> None:
+            open_partition = getattr(cls, OPEN_PARTITION_METHOD, None)
+            if open_partition is not None:
+                open_partition(self._daft_get_instance())
+
+        def _daft_close_partition(self) -> None:
+            close_partition = getattr(cls, CLOSE_PARTITION_METHOD, None)
+            if close_partition is not None and self._daft_local_instance is not None:
+                close_partition(self._daft_local_instance)
+
+        def _daft_teardown(self) -> None:
+            # Classes without a teardown method keep their instance, so that it's reused by later queries.
+            teardown = getattr(cls, TEARDOWN_METHOD, None)
+            if teardown is not None and self._daft_local_instance is not None:
+                instance, self._daft_local_instance = self._daft_local_instance, None
+                teardown(instance)
+
     return Cls
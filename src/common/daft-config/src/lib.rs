@@ -1,5 +1,9 @@
+mod values;
+
+use common_error::{DaftError, DaftResult};
 pub use common_io_config::IOConfig;
 use serde::{Deserialize, Serialize};
+pub use values::ConfigValues;
 
 /// Resolve and parse the boolean configuration item from the Env. It's considered true when the
 /// value is "1" or "true"; otherwise, it is false.
@@ -43,9 +47,11 @@ fn parse_number_from_env_with_custom_parser<T: std::str::FromStr + std::fmt::Dis
             return Some(parsed);
         }
 
-        eprintln!(
+        log::warn!(
             "Invalid {} value: {}, using default {}",
-            env_var, val, default_val
+            env_var,
+            val,
+            default_val
         );
         return Some(default_val);
     }
@@ -79,7 +85,7 @@ impl DaftPlanningConfig {
 
     #[must_use]
     pub fn from_env() -> Self {
-        let mut cfg: Self = Default::default();
+        let mut cfg = Self::default().with_env_values();
 
         if let Some(val) = parse_bool_from_env(Self::ENV_DAFT_DEV_DISABLE_JOIN_REORDERING) {
             cfg.disable_join_reordering = val;
@@ -112,6 +118,8 @@ pub struct DaftExecutionConfig {
     pub parquet_split_row_groups_max_files: usize,
     pub broadcast_join_size_bytes_threshold: usize,
     pub hash_join_partition_size_leniency: f64,
+    pub enable_bloom_filter_join_pruning: bool,
+    pub bloom_filter_join_false_positive_rate: f64,
     pub sample_size_for_sort: usize,
     pub num_preview_rows: usize,
     pub parquet_target_filesize: usize,
@@ -127,6 +135,8 @@ pub struct DaftExecutionConfig {
     pub read_sql_partition_size_bytes: usize,
     pub default_morsel_size: usize,
     pub shuffle_algorithm: String,
+    pub shuffle_compression: String,
+    pub shuffle_compression_level: usize,
     pub pre_shuffle_merge_threshold: usize,
     pub scantask_max_parallel: usize,
     pub native_parquet_writer: bool,
@@ -135,6 +145,10 @@ pub struct DaftExecutionConfig {
     pub maintain_order: bool,
     pub enable_dynamic_batching: bool,
     pub dynamic_batching_strategy: String,
+    pub collect_spill_threshold_bytes: usize,
+    pub scan_read_ahead: usize,
+    pub scan_io_concurrency: usize,
+    pub staged_writes: bool,
 }
 
 #[cfg(not(debug_assertions))]
@@ -154,6 +168,8 @@ impl Default for DaftExecutionConfig {
             parquet_split_row_groups_max_files: 10,
             broadcast_join_size_bytes_threshold: 10 * 1024 * 1024, // 10 MiB
             hash_join_partition_size_leniency: 0.5,
+            enable_bloom_filter_join_pruning: false,
+            bloom_filter_join_false_positive_rate: 0.01,
             sample_size_for_sort: 20,
             num_preview_rows: 8,
             parquet_target_filesize: 512 * 1024 * 1024, // 512MB
@@ -169,6 +185,8 @@ impl Default for DaftExecutionConfig {
             read_sql_partition_size_bytes: 512 * 1024 * 1024, // 512MB
             default_morsel_size: 128 * 1024,
             shuffle_algorithm: "auto".to_string(),
+            shuffle_compression: "none".to_string(),
+            shuffle_compression_level: 0,
             pre_shuffle_merge_threshold: 1024 * 1024 * 1024, // 1GB
             scantask_max_parallel: 8,
             native_parquet_writer: true,
@@ -177,12 +195,17 @@ impl Default for DaftExecutionConfig {
             maintain_order: true,
             enable_dynamic_batching: false,
             dynamic_batching_strategy: "auto".to_string(),
+            collect_spill_threshold_bytes: 0,
+            scan_read_ahead: 0,
+            scan_io_concurrency: 8,
+            staged_writes: false,
         }
     }
 }
 
 impl DaftExecutionConfig {
     const ENV_DAFT_SHUFFLE_ALGORITHM: &'static str = "DAFT_SHUFFLE_ALGORITHM";
+    const ENV_DAFT_SHUFFLE_COMPRESSION: &'static str = "DAFT_SHUFFLE_COMPRESSION";
     const ENV_DAFT_SCANTASK_MAX_PARALLEL: &'static str = "DAFT_SCANTASK_MAX_PARALLEL";
     const ENV_DAFT_NATIVE_PARQUET_WRITER: &'static str = "DAFT_NATIVE_PARQUET_WRITER";
     const ENV_DAFT_MIN_CPU_PER_TASK: &'static str = "DAFT_MIN_CPU_PER_TASK";
@@ -191,15 +214,20 @@ impl DaftExecutionConfig {
     const ENV_CSV_INFLATION_FACTOR: &'static str = "DAFT_CSV_INFLATION_FACTOR";
     const ENV_JSON_INFLATION_FACTOR: &'static str = "DAFT_JSON_INFLATION_FACTOR";
     const ENV_DAFT_MAINTAIN_ORDER: &'static str = "DAFT_MAINTAIN_ORDER";
+    const ENV_DAFT_SCAN_READ_AHEAD: &'static str = "DAFT_SCAN_READ_AHEAD";
 
     #[must_use]
     pub fn from_env() -> Self {
-        let mut cfg = Self::default();
+        let mut cfg = Self::default().with_env_values();
 
         if let Some(val) = parse_string_from_env(Self::ENV_DAFT_SHUFFLE_ALGORITHM, true) {
             cfg.shuffle_algorithm = val;
         }
 
+        if let Some(val) = parse_string_from_env(Self::ENV_DAFT_SHUFFLE_COMPRESSION, true) {
+            cfg.shuffle_compression = val;
+        }
+
         if let Some(val) = parse_number_from_env_with_custom_parser(
             Self::ENV_DAFT_SCANTASK_MAX_PARALLEL,
             cfg.scantask_max_parallel,
@@ -250,8 +278,64 @@ impl DaftExecutionConfig {
             cfg.json_inflation_factor = val;
         }
 
+        if let Some(val) =
+            parse_number_from_env(Self::ENV_DAFT_SCAN_READ_AHEAD, cfg.scan_read_ahead)
+        {
+            cfg.scan_read_ahead = val;
+        }
+
         cfg
     }
+
+    /// Checks that the knobs of this config have valid values.
+    pub fn validate(&self) -> DaftResult<()> {
+        if !(self.bloom_filter_join_false_positive_rate > 0.0
+            && self.bloom_filter_join_false_positive_rate < 1.0)
+        {
+            return Err(DaftError::ValueError(format!(
+                "bloom_filter_join_false_positive_rate must be between 0 and 1 exclusive, got {}",
+                self.bloom_filter_join_false_positive_rate
+            )));
+        }
+        if !matches!(
+            self.shuffle_algorithm.as_str(),
+            "map_reduce" | "pre_shuffle_merge" | "flight_shuffle" | "auto"
+        ) {
+            return Err(DaftError::ValueError(
+                "shuffle_algorithm must be 'auto', 'map_reduce', 'pre_shuffle_merge', or 'flight_shuffle'".to_string(),
+            ));
+        }
+        if !matches!(self.shuffle_compression.as_str(), "none" | "lz4" | "zstd") {
+            return Err(DaftError::ValueError(
+                "shuffle_compression must be 'none', 'lz4', or 'zstd'".to_string(),
+            ));
+        }
+        let max_compression_level = match self.shuffle_compression.as_str() {
+            "lz4" => 16,
+            "zstd" => 22,
+            _ => 0,
+        };
+        if self.shuffle_compression_level > max_compression_level {
+            return Err(DaftError::ValueError(format!(
+                "shuffle_compression_level for '{}' must be between 0 and {max_compression_level}",
+                self.shuffle_compression
+            )));
+        }
+        if !matches!(
+            self.dynamic_batching_strategy.as_str(),
+            "latency_constrained" | "auto"
+        ) {
+            return Err(DaftError::ValueError(
+                "dynamic_batching_strategy must be 'auto' or 'latency_constrained'".to_string(),
+            ));
+        }
+        if self.scan_io_concurrency == 0 {
+            return Err(DaftError::ValueError(
+                "scan_io_concurrency must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "python")]
@@ -367,6 +451,22 @@ mod tests {
             }
         }
 
+        // ENV_DAFT_SHUFFLE_COMPRESSION
+        {
+            let cfg = DaftExecutionConfig::from_env();
+            assert_eq!(cfg.shuffle_compression, "none");
+
+            unsafe {
+                std::env::set_var(DaftExecutionConfig::ENV_DAFT_SHUFFLE_COMPRESSION, "zstd ");
+            }
+            let cfg = DaftExecutionConfig::from_env();
+            assert_eq!(cfg.shuffle_compression, "zstd");
+
+            unsafe {
+                std::env::remove_var(DaftExecutionConfig::ENV_DAFT_SHUFFLE_COMPRESSION);
+            }
+        }
+
         // ENV_DAFT_SCANTASK_MAX_PARALLEL
         {
             let cfg = DaftExecutionConfig::from_env();
@@ -488,5 +588,21 @@ mod tests {
                 std::env::remove_var(DaftExecutionConfig::ENV_DAFT_MAINTAIN_ORDER);
             }
         }
+
+        // ENV_DAFT_SCAN_READ_AHEAD
+        {
+            let cfg = DaftExecutionConfig::from_env();
+            assert_eq!(cfg.scan_read_ahead, 0);
+
+            unsafe {
+                std::env::set_var(DaftExecutionConfig::ENV_DAFT_SCAN_READ_AHEAD, "4");
+            }
+            let cfg = DaftExecutionConfig::from_env();
+            assert_eq!(cfg.scan_read_ahead, 4);
+
+            unsafe {
+                std::env::remove_var(DaftExecutionConfig::ENV_DAFT_SCAN_READ_AHEAD);
+            }
+        }
     }
 }
@@ -2,10 +2,10 @@ use std::sync::Arc;
 
 use common_io_config::python::IOConfig as PyIOConfig;
 use common_py_serde::impl_bincode_py_state_serialization;
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
 use serde::{Deserialize, Serialize};
 
-use crate::{DaftExecutionConfig, DaftPlanningConfig};
+use crate::{ConfigValues, DaftExecutionConfig, DaftPlanningConfig};
 
 #[derive(Clone, Default, Serialize, Deserialize)]
 #[pyclass(module = "daft.daft")]
@@ -50,6 +50,20 @@ impl PyDaftPlanningConfig {
         })
     }
 
+    /// Returns the knobs of this config by name, as a JSON object.
+    fn values_json(&self) -> String {
+        serde_json::Value::Object(self.config.to_values()).to_string()
+    }
+
+    /// Returns a copy of this config with the knobs of a JSON object set.
+    fn with_values_json(&self, values: &str) -> PyResult<Self> {
+        let values: ConfigValues = serde_json::from_str(values)
+            .map_err(|e| PyValueError::new_err(format!("Invalid config values: {e}")))?;
+        Ok(Self {
+            config: Arc::new(self.config.with_values(&values)?),
+        })
+    }
+
     #[getter(default_io_config)]
     fn default_io_config(&self) -> PyResult<PyIOConfig> {
         Ok(PyIOConfig {
@@ -96,6 +110,8 @@ impl PyDaftExecutionConfig {
         parquet_split_row_groups_max_files=None,
         broadcast_join_size_bytes_threshold=None,
         hash_join_partition_size_leniency=None,
+        enable_bloom_filter_join_pruning=None,
+        bloom_filter_join_false_positive_rate=None,
         sample_size_for_sort=None,
         num_preview_rows=None,
         parquet_target_filesize=None,
@@ -110,6 +126,8 @@ impl PyDaftExecutionConfig {
         read_sql_partition_size_bytes=None,
         default_morsel_size=None,
         shuffle_algorithm=None,
+        shuffle_compression=None,
+        shuffle_compression_level=None,
         pre_shuffle_merge_threshold=None,
         scantask_max_parallel=None,
         native_parquet_writer=None,
@@ -118,6 +136,10 @@ impl PyDaftExecutionConfig {
         maintain_order=None,
         enable_dynamic_batching=None,
         dynamic_batching_strategy=None,
+        collect_spill_threshold_bytes=None,
+        scan_read_ahead=None,
+        scan_io_concurrency=None,
+        staged_writes=None,
     ))]
     fn with_config_values(
         &self,
@@ -128,6 +150,8 @@ impl PyDaftExecutionConfig {
         parquet_split_row_groups_max_files: Option<usize>,
         broadcast_join_size_bytes_threshold: Option<usize>,
         hash_join_partition_size_leniency: Option<f64>,
+        enable_bloom_filter_join_pruning: Option<bool>,
+        bloom_filter_join_false_positive_rate: Option<f64>,
         sample_size_for_sort: Option<usize>,
         num_preview_rows: Option<usize>,
         parquet_target_filesize: Option<usize>,
@@ -142,6 +166,8 @@ impl PyDaftExecutionConfig {
         read_sql_partition_size_bytes: Option<usize>,
         default_morsel_size: Option<usize>,
         shuffle_algorithm: Option<&str>,
+        shuffle_compression: Option<&str>,
+        shuffle_compression_level: Option<usize>,
         pre_shuffle_merge_threshold: Option<usize>,
         scantask_max_parallel: Option<usize>,
         native_parquet_writer: Option<bool>,
@@ -150,6 +176,10 @@ impl PyDaftExecutionConfig {
         maintain_order: Option<bool>,
         enable_dynamic_batching: Option<bool>,
         dynamic_batching_strategy: Option<&str>,
+        collect_spill_threshold_bytes: Option<usize>,
+        scan_read_ahead: Option<usize>,
+        scan_io_concurrency: Option<usize>,
+        staged_writes: Option<bool>,
     ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
@@ -174,6 +204,12 @@ impl PyDaftExecutionConfig {
         if let Some(hash_join_partition_size_leniency) = hash_join_partition_size_leniency {
             config.hash_join_partition_size_leniency = hash_join_partition_size_leniency;
         }
+        if let Some(enable_bloom_filter_join_pruning) = enable_bloom_filter_join_pruning {
+            config.enable_bloom_filter_join_pruning = enable_bloom_filter_join_pruning;
+        }
+        if let Some(bloom_filter_join_false_positive_rate) = bloom_filter_join_false_positive_rate {
+            config.bloom_filter_join_false_positive_rate = bloom_filter_join_false_positive_rate;
+        }
         if let Some(sample_size_for_sort) = sample_size_for_sort {
             config.sample_size_for_sort = sample_size_for_sort;
         }
@@ -217,16 +253,14 @@ impl PyDaftExecutionConfig {
             config.default_morsel_size = default_morsel_size;
         }
         if let Some(shuffle_algorithm) = shuffle_algorithm {
-            if !matches!(
-                shuffle_algorithm,
-                "map_reduce" | "pre_shuffle_merge" | "flight_shuffle" | "auto"
-            ) {
-                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "shuffle_algorithm must be 'auto', 'map_reduce', 'pre_shuffle_merge', or 'flight_shuffle'",
-                ));
-            }
             config.shuffle_algorithm = shuffle_algorithm.to_string();
         }
+        if let Some(shuffle_compression) = shuffle_compression {
+            config.shuffle_compression = shuffle_compression.to_string();
+        }
+        if let Some(shuffle_compression_level) = shuffle_compression_level {
+            config.shuffle_compression_level = shuffle_compression_level;
+        }
         if let Some(pre_shuffle_merge_threshold) = pre_shuffle_merge_threshold {
             config.pre_shuffle_merge_threshold = pre_shuffle_merge_threshold;
         }
@@ -254,19 +288,41 @@ impl PyDaftExecutionConfig {
             config.enable_dynamic_batching = enable_dynamic_batching;
         }
         if let Some(dynamic_batching_strategy) = dynamic_batching_strategy {
-            if !matches!(dynamic_batching_strategy, "latency_constrained" | "auto") {
-                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "dynamic_batching_strategy must be 'auto' or 'latency_constrained'",
-                ));
-            }
             config.dynamic_batching_strategy = dynamic_batching_strategy.to_string();
         }
+        if let Some(collect_spill_threshold_bytes) = collect_spill_threshold_bytes {
+            config.collect_spill_threshold_bytes = collect_spill_threshold_bytes;
+        }
+        if let Some(scan_read_ahead) = scan_read_ahead {
+            config.scan_read_ahead = scan_read_ahead;
+        }
+        if let Some(scan_io_concurrency) = scan_io_concurrency {
+            config.scan_io_concurrency = scan_io_concurrency;
+        }
+        if let Some(staged_writes) = staged_writes {
+            config.staged_writes = staged_writes;
+        }
+        config.validate()?;
 
         Ok(Self {
             config: Arc::new(config),
         })
     }
 
+    /// Returns the knobs of this config by name, as a JSON object.
+    fn values_json(&self) -> String {
+        serde_json::Value::Object(self.config.to_values()).to_string()
+    }
+
+    /// Returns a copy of this config with the knobs of a JSON object set.
+    fn with_values_json(&self, values: &str) -> PyResult<Self> {
+        let values: ConfigValues = serde_json::from_str(values)
+            .map_err(|e| PyValueError::new_err(format!("Invalid config values: {e}")))?;
+        Ok(Self {
+            config: Arc::new(self.config.with_values(&values)?),
+        })
+    }
+
     #[getter]
     fn get_enable_scan_task_split_and_merge(&self) -> PyResult<bool> {
         Ok(self.config.enable_scan_task_split_and_merge)
@@ -301,6 +357,16 @@ impl PyDaftExecutionConfig {
         Ok(self.config.hash_join_partition_size_leniency)
     }
 
+    #[getter]
+    fn get_enable_bloom_filter_join_pruning(&self) -> PyResult<bool> {
+        Ok(self.config.enable_bloom_filter_join_pruning)
+    }
+
+    #[getter]
+    fn get_bloom_filter_join_false_positive_rate(&self) -> PyResult<f64> {
+        Ok(self.config.bloom_filter_join_false_positive_rate)
+    }
+
     #[getter]
     fn get_sample_size_for_sort(&self) -> PyResult<usize> {
         Ok(self.config.sample_size_for_sort)
@@ -369,6 +435,14 @@ impl PyDaftExecutionConfig {
         Ok(self.config.shuffle_algorithm.as_str())
     }
     #[getter]
+    fn shuffle_compression(&self) -> PyResult<&str> {
+        Ok(self.config.shuffle_compression.as_str())
+    }
+    #[getter]
+    fn shuffle_compression_level(&self) -> PyResult<usize> {
+        Ok(self.config.shuffle_compression_level)
+    }
+    #[getter]
     fn pre_shuffle_merge_threshold(&self) -> PyResult<usize> {
         Ok(self.config.pre_shuffle_merge_threshold)
     }
@@ -401,6 +475,26 @@ impl PyDaftExecutionConfig {
     fn dynamic_batching_strategy(&self) -> PyResult<&str> {
         Ok(self.config.dynamic_batching_strategy.as_str())
     }
+
+    #[getter]
+    fn collect_spill_threshold_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.collect_spill_threshold_bytes)
+    }
+
+    #[getter]
+    fn scan_read_ahead(&self) -> PyResult<usize> {
+        Ok(self.config.scan_read_ahead)
+    }
+
+    #[getter]
+    fn scan_io_concurrency(&self) -> PyResult<usize> {
+        Ok(self.config.scan_io_concurrency)
+    }
+
+    #[getter]
+    fn staged_writes(&self) -> PyResult<bool> {
+        Ok(self.config.staged_writes)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftExecutionConfig);
@@ -0,0 +1,217 @@
+//! Reading and overriding the knobs of a config by name.
+//!
+//! A config is built up in layers: its defaults, then the environment, then the values set for the session, then the
+//! overrides of a single query. The knobs of a config are named after their fields, so that every layer can set any
+//! of them, including knobs without an environment variable or setter of their own. Only knobs whose values are
+//! booleans, numbers or strings are named; the default IO config of the planning config has a setter of its own.
+
+use common_error::{DaftError, DaftResult};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::{DaftExecutionConfig, DaftPlanningConfig, IOConfig};
+
+/// The knobs of a config by name.
+pub type ConfigValues = serde_json::Map<String, Value>;
+
+/// Returns the fields of a config by name.
+fn to_fields<T: Serialize>(config: &T) -> ConfigValues {
+    match serde_json::to_value(config).expect("Configs should be serializable to JSON") {
+        Value::Object(fields) => fields,
+        _ => unreachable!("Configs should serialize to JSON objects"),
+    }
+}
+
+fn is_knob(value: &Value) -> bool {
+    matches!(value, Value::Bool(_) | Value::Number(_) | Value::String(_))
+}
+
+/// Returns the knobs of a config by name.
+fn to_values<T: Serialize>(config: &T) -> ConfigValues {
+    to_fields(config)
+        .into_iter()
+        .filter(|(_, value)| is_knob(value))
+        .collect()
+}
+
+/// Returns a copy of a config with some of its knobs set, failing if a knob doesn't exist or a value has the wrong type.
+fn with_values<T: Serialize + DeserializeOwned>(
+    config: &T,
+    values: &ConfigValues,
+) -> DaftResult<T> {
+    let mut merged = to_fields(config);
+    for (name, value) in values {
+        let Some(current) = merged.get_mut(name).filter(|current| is_knob(current)) else {
+            let mut names = to_values(config)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            names.sort_unstable();
+            return Err(DaftError::ValueError(format!(
+                "Unknown config `{name}`, expected one of: {}",
+                names.join(", ")
+            )));
+        };
+        *current = value.clone();
+    }
+    serde_json::from_value(Value::Object(merged))
+        .map_err(|e| DaftError::ValueError(format!("Invalid config value: {e}")))
+}
+
+/// Parses the value of a knob from an environment variable, according to the type of its current value.
+fn parse_env_value(current: &Value, val: &str) -> Option<Value> {
+    let val = val.trim();
+    match current {
+        Value::Bool(_) => match val.to_lowercase().as_str() {
+            "1" | "true" => Some(Value::Bool(true)),
+            "0" | "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        Value::Number(n) if n.is_f64() => val.parse::<f64>().ok().map(Value::from),
+        Value::Number(_) => val.parse::<u64>().ok().map(Value::from),
+        Value::String(_) => Some(Value::String(val.to_string())),
+        _ => None,
+    }
+}
+
+/// Sets the knobs of a config from the environment variables named `DAFT_<KNOB>`, e.g. `DAFT_NUM_PREVIEW_ROWS`.
+/// Values which can't be parsed, including booleans other than "1", "true", "0" or "false", are ignored with a warning.
+fn with_env_values<T: Serialize + DeserializeOwned>(config: T) -> T {
+    let mut values = ConfigValues::new();
+    for (name, current) in to_values(&config) {
+        let env_var = format!("DAFT_{}", name.to_uppercase());
+        let Ok(val) = std::env::var(&env_var) else {
+            continue;
+        };
+        match parse_env_value(&current, &val) {
+            Some(value) => {
+                values.insert(name, value);
+            }
+            None => log::warn!("Invalid {env_var} value: {val}, ignoring it"),
+        }
+    }
+    with_values(&config, &values).unwrap_or(config)
+}
+
+impl DaftPlanningConfig {
+    /// Returns a copy of this config without its default IO config, which may hold credential providers that can't be
+    /// serialized to JSON.
+    fn without_io_config(&self) -> Self {
+        Self {
+            default_io_config: IOConfig::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the knobs of this config by name.
+    #[must_use]
+    pub fn to_values(&self) -> ConfigValues {
+        to_values(&self.without_io_config())
+    }
+
+    /// Returns a copy of this config with the given knobs set.
+    pub fn with_values(&self, values: &ConfigValues) -> DaftResult<Self> {
+        Ok(Self {
+            default_io_config: self.default_io_config.clone(),
+            ..with_values(&self.without_io_config(), values)?
+        })
+    }
+
+    pub(crate) fn with_env_values(self) -> Self {
+        Self {
+            default_io_config: self.default_io_config.clone(),
+            ..with_env_values(self.without_io_config())
+        }
+    }
+}
+
+impl DaftExecutionConfig {
+    /// Returns the knobs of this config by name.
+    #[must_use]
+    pub fn to_values(&self) -> ConfigValues {
+        to_values(self)
+    }
+
+    /// Returns a copy of this config with the given knobs set.
+    pub fn with_values(&self, values: &ConfigValues) -> DaftResult<Self> {
+        let config = with_values(self, values)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub(crate) fn with_env_values(self) -> Self {
+        with_env_values(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::ConfigValues;
+    use crate::DaftExecutionConfig;
+
+    fn values(value: serde_json::Value) -> ConfigValues {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn test_with_values_round_trips() {
+        let cfg = DaftExecutionConfig::default()
+            .with_values(&values(json!({
+                "num_preview_rows": 3,
+                "maintain_order": false,
+                "shuffle_algorithm": "map_reduce",
+                "parquet_inflation_factor": 2.5,
+            })))
+            .unwrap();
+        assert_eq!(cfg.num_preview_rows, 3);
+        assert!(!cfg.maintain_order);
+        assert_eq!(cfg.shuffle_algorithm, "map_reduce");
+        assert_eq!(cfg.parquet_inflation_factor, 2.5);
+
+        let round_tripped = DaftExecutionConfig::default()
+            .with_values(&cfg.to_values())
+            .unwrap();
+        assert_eq!(round_tripped.to_values(), cfg.to_values());
+    }
+
+    #[test]
+    fn test_with_values_rejects_invalid_values() {
+        let cfg = DaftExecutionConfig::default();
+        let err = cfg
+            .with_values(&values(json!({"num_preview_rowz": 3})))
+            .unwrap_err();
+        assert!(err.to_string().contains("num_preview_rowz"));
+
+        assert!(
+            cfg.with_values(&values(json!({"num_preview_rows": "three"})))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_env_values() {
+        unsafe {
+            std::env::set_var("DAFT_NUM_PREVIEW_ROWS", "3");
+            std::env::set_var("DAFT_STAGED_WRITES", "true");
+            std::env::set_var("DAFT_SCAN_IO_CONCURRENCY", "many");
+            std::env::set_var("DAFT_MAINTAIN_ORDER", "flase");
+        }
+        let cfg = DaftExecutionConfig::default().with_env_values();
+        unsafe {
+            std::env::remove_var("DAFT_NUM_PREVIEW_ROWS");
+            std::env::remove_var("DAFT_STAGED_WRITES");
+            std::env::remove_var("DAFT_SCAN_IO_CONCURRENCY");
+            std::env::remove_var("DAFT_MAINTAIN_ORDER");
+        }
+        assert_eq!(cfg.num_preview_rows, 3);
+        assert!(cfg.staged_writes);
+        assert_eq!(
+            cfg.scan_io_concurrency,
+            DaftExecutionConfig::default().scan_io_concurrency
+        );
+        // A misspelled boolean is ignored rather than read as false.
+        assert!(cfg.maintain_order);
+    }
+}
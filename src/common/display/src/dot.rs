@@ -0,0 +1,96 @@
+use std::fmt;
+
+use crate::{DisplayLevel, tree::TreeDisplay};
+
+pub trait DotDisplay: TreeDisplay {
+    fn repr_dot(&self, options: DotDisplayOptions) -> String;
+}
+
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct DotDisplayOptions {
+    /// simple mode only shows the node's name instead of its full display string.
+    pub simple: bool,
+    /// Display the root node at the bottom of the graph or at the top
+    pub bottom_up: bool,
+}
+
+impl<T: TreeDisplay> DotDisplay for T {
+    fn repr_dot(&self, options: DotDisplayOptions) -> String {
+        to_dot(self, options)
+    }
+}
+
+/// Render `node` and its subtree as a Graphviz DOT digraph.
+pub fn to_dot(node: &dyn TreeDisplay, options: DotDisplayOptions) -> String {
+    let mut s = String::new();
+    let level = match options.simple {
+        true => DisplayLevel::Compact,
+        false => DisplayLevel::Default,
+    };
+    let mut visitor = DotDisplayVisitor::new(&mut s, level, options.bottom_up);
+    let _ = visitor.fmt(node);
+    s
+}
+
+/// Emits a tree as a Graphviz DOT digraph, with edges pointing from children to parents in
+/// the direction that data flows.
+pub struct DotDisplayVisitor<'a, W> {
+    output: &'a mut W,
+    level: DisplayLevel,
+    bottom_up: bool,
+    node_count: usize,
+}
+
+impl<'a, W> DotDisplayVisitor<'a, W> {
+    pub fn new(w: &'a mut W, level: DisplayLevel, bottom_up: bool) -> Self {
+        Self {
+            output: w,
+            level,
+            bottom_up,
+            node_count: 0,
+        }
+    }
+}
+
+impl<W> DotDisplayVisitor<'_, W>
+where
+    W: fmt::Write,
+{
+    fn escape(text: &str) -> String {
+        text.trim_end()
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\l")
+            + "\\l"
+    }
+
+    /// Writes `node` and its subtree, returning the DOT id of `node`.
+    fn fmt_node(&mut self, node: &dyn TreeDisplay) -> Result<String, fmt::Error> {
+        let id = format!("n{}", self.node_count);
+        self.node_count += 1;
+        writeln!(
+            self.output,
+            r#"  {id} [label="{}"];"#,
+            Self::escape(&node.display_as(self.level))
+        )?;
+
+        for child in node.get_children() {
+            let child_id = self.fmt_node(child)?;
+            writeln!(self.output, "  {child_id} -> {id};")?;
+        }
+        Ok(id)
+    }
+
+    pub fn fmt(&mut self, node: &dyn TreeDisplay) -> fmt::Result {
+        writeln!(self.output, "digraph plan {{")?;
+        writeln!(
+            self.output,
+            "  rankdir={};",
+            if self.bottom_up { "BT" } else { "TB" }
+        )?;
+        writeln!(self.output, r#"  node [shape=box, fontname="monospace"];"#)?;
+        self.fmt_node(node)?;
+        writeln!(self.output, "}}")
+    }
+}
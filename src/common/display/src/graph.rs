@@ -0,0 +1,64 @@
+use serde_json::json;
+
+use crate::{DisplayLevel, tree::TreeDisplay};
+
+pub trait GraphDisplay: TreeDisplay {
+    /// Describe the tree as a flat graph of nodes and edges.
+    ///
+    /// Each node is given an integer id that is unique within the graph, along with its name, its
+    /// display string at the given `level`, and the node's `repr_graph_details` under `details`.
+    /// Edges point from each child to its parent.
+    /// This is the format expected by external tools that render interactive plan graphs.
+    fn repr_graph_json(&self, level: DisplayLevel) -> serde_json::Value;
+}
+
+impl<T: TreeDisplay> GraphDisplay for T {
+    fn repr_graph_json(&self, level: DisplayLevel) -> serde_json::Value {
+        to_graph_json(self, level)
+    }
+}
+
+/// Describe `node` as its `repr_json`, without its embedded children.
+pub fn repr_json_without_children<T: TreeDisplay + ?Sized>(node: &T) -> serde_json::Value {
+    let mut json = node.repr_json();
+    if let Some(json) = json.as_object_mut() {
+        json.remove("children");
+    }
+    json
+}
+
+/// Describe `node` and its subtree as a flat graph of nodes and edges.
+/// See [`GraphDisplay::repr_graph_json`] for the format.
+pub fn to_graph_json(node: &dyn TreeDisplay, level: DisplayLevel) -> serde_json::Value {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    add_node(node, level, &mut nodes, &mut edges);
+    json!({
+        "nodes": nodes,
+        "edges": edges,
+    })
+}
+
+fn add_node(
+    node: &dyn TreeDisplay,
+    level: DisplayLevel,
+    nodes: &mut Vec<serde_json::Value>,
+    edges: &mut Vec<serde_json::Value>,
+) -> usize {
+    let id = nodes.len();
+    nodes.push(json!({
+        "id": id,
+        "name": node.get_name(),
+        "display": node.display_as(level),
+        "details": node.repr_graph_details(),
+    }));
+
+    for child in node.get_children() {
+        let child_id = add_node(child, level, nodes, edges);
+        edges.push(json!({
+            "source": child_id,
+            "target": id,
+        }));
+    }
+    id
+}
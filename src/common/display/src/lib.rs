@@ -1,4 +1,6 @@
 pub mod ascii;
+pub mod dot;
+pub mod graph;
 pub mod mermaid;
 pub mod table_display;
 pub mod tree;
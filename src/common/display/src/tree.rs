@@ -17,6 +17,13 @@ pub trait TreeDisplay {
     /// Children will be auto-embedded.
     fn repr_json(&self) -> serde_json::Value;
 
+    /// Describe the node, without its children, for the flat graph of [`crate::graph::GraphDisplay`].
+    /// Defaults to `repr_json` without the embedded children, but may add details which only the graph
+    /// shows, such as the node's schema and statistics.
+    fn repr_graph_details(&self) -> serde_json::Value {
+        crate::graph::repr_json_without_children(self)
+    }
+
     /// Get a unique identifier for this node.
     /// No two nodes should have the same id.
     /// The default implementation uses the node's name and memory address.
@@ -46,6 +53,10 @@ impl TreeDisplay for Arc<dyn TreeDisplay> {
         self.as_ref().repr_json()
     }
 
+    fn repr_graph_details(&self) -> serde_json::Value {
+        self.as_ref().repr_graph_details()
+    }
+
     fn get_name(&self) -> String {
         self.as_ref().get_name()
     }
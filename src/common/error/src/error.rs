@@ -60,6 +60,12 @@ pub enum DaftError {
     NotImplemented(String),
     #[error("DaftError::CatalogError {0}")]
     CatalogError(String),
+    /// An error with context about where it happened, see [`DaftError::with_context`].
+    #[error("{context}: {source}")]
+    WithContext {
+        context: String,
+        source: Box<DaftError>,
+    },
 }
 
 impl DaftError {
@@ -69,6 +75,51 @@ impl DaftError {
     pub fn type_error<T: std::fmt::Display>(msg: T) -> Self {
         Self::TypeError(msg.to_string())
     }
+
+    /// Adds context about where the error happened, such as the operator, the file or the row which failed.
+    ///
+    /// Errors which carry a message are wrapped in [`DaftError::WithContext`], which keeps the original error as its
+    /// source, and Python errors get the context as a note. Other errors are returned unchanged, so that errors such
+    /// as transient IO errors keep their variant and can still be retried.
+    #[must_use]
+    pub fn with_context<T: std::fmt::Display>(self, context: T) -> Self {
+        match self {
+            Self::AmbiguousReference(_)
+            | Self::FieldNotFound(_)
+            | Self::SchemaMismatch(_)
+            | Self::TypeError(_)
+            | Self::ComputeError(_)
+            | Self::ParquetError(_)
+            | Self::ValueError(_)
+            | Self::InternalError(_)
+            | Self::NotImplemented(_)
+            | Self::CatalogError(_)
+            | Self::WithContext { .. } => Self::WithContext {
+                context: context.to_string(),
+                source: Box::new(self),
+            },
+            #[cfg(feature = "python")]
+            Self::PyO3Error(err) => {
+                pyo3::Python::attach(|py| {
+                    // Notes are only supported from Python 3.11, so older versions keep the error as it is.
+                    let _ = err
+                        .value(py)
+                        .call_method1(pyo3::intern!(py, "add_note"), (context.to_string(),));
+                });
+                Self::PyO3Error(err)
+            }
+            err => err,
+        }
+    }
+
+    /// Returns the error without the context added to it by [`DaftError::with_context`].
+    #[must_use]
+    pub fn without_context(&self) -> &Self {
+        match self {
+            Self::WithContext { source, .. } => source.without_context(),
+            err => err,
+        }
+    }
 }
 
 #[macro_export]
@@ -112,6 +163,29 @@ impl<'py> From<pyo3::pyclass::PyClassGuardError<'_, 'py>> for DaftError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_with_context() {
+        let err = DaftError::ComputeError("Cannot cast a to Int64".to_string())
+            .with_context("On row 3")
+            .with_context("In Project (node 2)");
+        assert_eq!(
+            err.to_string(),
+            "In Project (node 2): On row 3: DaftError::ComputeError Cannot cast a to Int64"
+        );
+        assert!(matches!(
+            err.without_context(),
+            DaftError::ComputeError(msg) if msg == "Cannot cast a to Int64"
+        ));
+        assert_eq!(
+            std::error::Error::source(&err).map(ToString::to_string),
+            Some("On row 3: DaftError::ComputeError Cannot cast a to Int64".to_string())
+        );
+
+        // Transient errors keep their variant, so that they can still be retried.
+        let err = DaftError::ByteStreamError("connection reset".into()).with_context("In Scan");
+        assert!(matches!(err, DaftError::ByteStreamError(_)));
+    }
+
     #[test]
     fn test_arrow_io_error_conversion() {
         // Ensure that arrow2 IO errors get converted into transient Byte Stream errors.
@@ -19,6 +19,10 @@ impl std::convert::From<DaftError> for pyo3::PyErr {
                 PyFileNotFoundError::new_err(format!("File: {path} not found\n{source}"))
             }
             DaftError::TypeError(err) => DaftTypeError::new_err(err),
+            DaftError::WithContext { .. } => match err.without_context() {
+                DaftError::TypeError(_) => DaftTypeError::new_err(err.to_string()),
+                _ => DaftCoreException::new_err(err.to_string()),
+            },
             DaftError::ConnectTimeout(err) => ConnectTimeoutError::new_err(err.to_string()),
             DaftError::ReadTimeout(err) => ReadTimeoutError::new_err(err.to_string()),
             DaftError::ByteStreamError(err) => ByteStreamError::new_err(err.to_string()),
@@ -24,6 +24,7 @@ pub enum NodeType {
     IntoBatches,
     Project,
     Sample,
+    SortedLookupJoin,
     UDFProject,
     Unpivot,
     VLLMProject,
@@ -39,7 +40,9 @@ pub enum NodeType {
     IntoPartitions,
     Pivot,
     Repartition,
+    Resample,
     Sort,
+    SortedLookupBuild,
     TopN,
     WindowOrderByOnly,
     WindowPartitionAndDynamicFrame,
@@ -56,7 +59,7 @@ pub enum NodeType {
     Limit,
     MonotonicallyIncreasingId,
     OuterHashJoinProbe,
-    SortMergeJoinProbe,
+    SortMergeJoin,
 }
 
 impl Display for NodeType {
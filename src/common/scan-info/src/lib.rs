@@ -19,7 +19,7 @@ pub use pushdowns::{Pushdowns, SupportsPushdownFilters};
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use scan_operator::{ScanOperator, ScanOperatorRef};
-pub use scan_task::{SPLIT_AND_MERGE_PASS, ScanTaskLike, ScanTaskLikeRef};
+pub use scan_task::{SPLIT_AND_MERGE_PASS, ScanTaskLike, ScanTaskLikeRef, ScanTaskStream};
 use serde::{Deserialize, Serialize};
 pub use sharder::{Sharder, ShardingStrategy};
 
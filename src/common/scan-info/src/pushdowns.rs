@@ -22,6 +22,9 @@ pub struct Pushdowns {
     pub columns: Option<Arc<Vec<String>>>,
     /// Optional number of rows to read.
     pub limit: Option<usize>,
+    /// Optional number of files to read, such as when previewing a scan. With a file limit, the tasks of the scan read
+    /// one file each and aren't split or merged, so that `limit` is the number of rows read from each file.
+    pub file_limit: Option<usize>,
     /// Sharding information.
     pub sharder: Option<Sharder>,
     /// Optional filters that have been pushed down to the scan operator.
@@ -56,6 +59,7 @@ impl Pushdowns {
             partition_filters,
             columns,
             limit,
+            file_limit: None,
             sharder,
             pushed_filters: None,
             aggregation,
@@ -68,6 +72,7 @@ impl Pushdowns {
             && self.partition_filters.is_none()
             && self.columns.is_none()
             && self.limit.is_none()
+            && self.file_limit.is_none()
     }
 
     #[must_use]
@@ -77,6 +82,21 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit,
+            file_limit: self.file_limit,
+            sharder: self.sharder.clone(),
+            pushed_filters: self.pushed_filters.clone(),
+            aggregation: self.aggregation.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_file_limit(&self, file_limit: Option<usize>) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            partition_filters: self.partition_filters.clone(),
+            columns: self.columns.clone(),
+            limit: self.limit,
+            file_limit,
             sharder: self.sharder.clone(),
             pushed_filters: self.pushed_filters.clone(),
             aggregation: self.aggregation.clone(),
@@ -90,6 +110,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            file_limit: self.file_limit,
             sharder: self.sharder.clone(),
             pushed_filters: self.pushed_filters.clone(),
             aggregation: self.aggregation.clone(),
@@ -103,6 +124,7 @@ impl Pushdowns {
             partition_filters,
             columns: self.columns.clone(),
             limit: self.limit,
+            file_limit: self.file_limit,
             sharder: self.sharder.clone(),
             pushed_filters: self.pushed_filters.clone(),
             aggregation: self.aggregation.clone(),
@@ -116,6 +138,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns,
             limit: self.limit,
+            file_limit: self.file_limit,
             sharder: self.sharder.clone(),
             pushed_filters: self.pushed_filters.clone(),
             aggregation: self.aggregation.clone(),
@@ -129,6 +152,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            file_limit: self.file_limit,
             sharder,
             pushed_filters: self.pushed_filters.clone(),
             aggregation: self.aggregation.clone(),
@@ -142,6 +166,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            file_limit: self.file_limit,
             sharder: self.sharder.clone(),
             pushed_filters,
             aggregation: self.aggregation.clone(),
@@ -155,6 +180,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            file_limit: self.file_limit,
             sharder: self.sharder.clone(),
             pushed_filters: self.pushed_filters.clone(),
             aggregation,
@@ -176,6 +202,9 @@ impl Pushdowns {
         if let Some(limit) = self.limit {
             res.push(format!("Limit pushdown = {limit}"));
         }
+        if let Some(file_limit) = self.file_limit {
+            res.push(format!("File limit pushdown = {file_limit}"));
+        }
         if let Some(sharder) = &self.sharder {
             res.push(format!("Sharder = {sharder}"));
         }
@@ -213,6 +242,9 @@ impl DisplayAs for Pushdowns {
                 if let Some(limit) = self.limit {
                     sub_items.push(format!("limit: {limit}"));
                 }
+                if let Some(file_limit) = self.file_limit {
+                    sub_items.push(format!("file_limit: {file_limit}"));
+                }
                 if let Some(sharder) = &self.sharder {
                     sub_items.push(format!("sharder: {sharder}"));
                 }
@@ -195,6 +195,12 @@ pub mod pylib {
             self.0.limit
         }
 
+        #[getter]
+        #[must_use]
+        pub fn file_limit(&self) -> Option<usize> {
+            self.0.file_limit
+        }
+
         #[getter]
         #[must_use]
         pub fn filters(&self) -> Option<PyExpr> {
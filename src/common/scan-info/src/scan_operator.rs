@@ -9,11 +9,23 @@ use daft_schema::schema::SchemaRef;
 
 use crate::{PartitionField, Pushdowns, ScanTaskLikeRef, SupportsPushdownFilters};
 
+/// A source of data which Daft can scan, e.g. a table format or an external storage system.
+///
+/// Scan operators plug into planning with `LogicalPlanBuilder::table_scan`, which takes a [`ScanOperatorRef`]. During
+/// optimization, the planner negotiates pushdowns with the scan operator through the `can_absorb_*` methods and
+/// [`ScanOperator::as_pushdown_filter`], then calls [`ScanOperator::to_scan_tasks`] with the pushdowns it settled on.
+/// The scan tasks are read by both the native and distributed runners: scan tasks of sources other than Daft's own read
+/// themselves with [`crate::ScanTaskLike::read`].
+///
+/// Statistics used for planning, such as row counts and sizes, are reported per scan task.
 pub trait ScanOperator: Send + Sync + Debug {
     fn name(&self) -> &str;
 
+    /// The schema of the source, without generated fields.
     fn schema(&self) -> SchemaRef;
+    /// The partitioning of the source, which lets the planner prune scan tasks by their partition values.
     fn partitioning_keys(&self) -> &[PartitionField];
+    /// The name of a column with the path of the file each row was read from, if requested.
     fn file_path_column(&self) -> Option<&str>;
     // Although generated fields are often added to the partition spec, generated fields and
     // partition fields are handled differently:
@@ -27,9 +39,15 @@ pub trait ScanOperator: Send + Sync + Debug {
     // Thus, we maintain separate representations for partitioning keys and generated fields.
     fn generated_fields(&self) -> Option<SchemaRef>;
 
+    /// Whether the scan tasks of this operator apply their filter pushdowns themselves. Filters on the data are pushed
+    /// into scans either way and removed from the plan, so scan tasks which don't apply them are filtered by the
+    /// executors. Filters on the partitioning keys are passed as partition filters, which should prune scan tasks.
     fn can_absorb_filter(&self) -> bool;
+    /// Whether the scan tasks of this operator only return the columns of their column pushdowns.
     fn can_absorb_select(&self) -> bool;
+    /// Whether the scan tasks of this operator apply their limit pushdowns exactly.
     fn can_absorb_limit(&self) -> bool;
+    /// Whether this operator can split its scan tasks by a sharding pushdown.
     fn can_absorb_shard(&self) -> bool;
     fn multiline_display(&self) -> Vec<String>;
 
@@ -45,9 +63,17 @@ pub trait ScanOperator: Send + Sync + Debug {
     /// (merging, splitting) to the outputted scan tasks
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>>;
 
+    /// Returns this operator as a [`SupportsPushdownFilters`] if it can accept some filters but not others.
     fn as_pushdown_filter(&self) -> Option<&dyn SupportsPushdownFilters> {
         None
     }
+
+    /// Hashes the versions of the data which this operator scans, such as the entity tags of its files, so that cached
+    /// plans over the operator aren't reused once its data changes. Operators which always scan the snapshot of their
+    /// source which they were created for don't need to hash anything.
+    fn hash_source_versions(&self, _state: &mut dyn Hasher) -> DaftResult<()> {
+        Ok(())
+    }
 }
 
 impl Display for dyn ScanOperator {
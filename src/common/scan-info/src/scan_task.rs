@@ -7,37 +7,75 @@ use std::{
 
 use common_daft_config::DaftExecutionConfig;
 use common_display::DisplayAs;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use common_file_formats::FileFormatConfig;
+use daft_recordbatch::RecordBatch;
 use daft_schema::schema::SchemaRef;
+use futures::stream::BoxStream;
 
 use crate::Pushdowns;
 
+/// The record batches read by a scan task.
+pub type ScanTaskStream = BoxStream<'static, DaftResult<RecordBatch>>;
+
+/// A unit of work of a scan, which is read by a single task of the executors.
+///
+/// Scan tasks are sent to the workers of the distributed runner, so implementations must be registered with
+/// `#[typetag::serde]`, which works from any crate:
+///
+/// ```ignore
+/// #[typetag::serde]
+/// impl ScanTaskLike for MyScanTask { ... }
+/// ```
+///
+/// Scan tasks of Daft's own sources are read by its built-in readers according to their
+/// [`ScanTaskLike::file_format_config`]. Scan tasks of other sources are read with [`ScanTaskLike::read`].
 #[typetag::serde(tag = "type")]
 pub trait ScanTaskLike: Debug + DisplayAs + Send + Sync {
     fn as_any(&self) -> &dyn Any;
     fn as_any_arc(self: Arc<Self>) -> Arc<dyn Any + Send + Sync>;
     fn dyn_eq(&self, other: &dyn ScanTaskLike) -> bool;
     fn dyn_hash(&self, state: &mut dyn Hasher);
+    /// The schema of the record batches read by this task, after its pushdowns are applied.
     #[must_use]
     fn materialized_schema(&self) -> SchemaRef;
+    /// The exact number of rows read by this task, if known without reading it.
     #[must_use]
     fn num_rows(&self) -> Option<usize>;
+    /// An estimate of the number of rows read by this task, used for planning.
     #[must_use]
     fn approx_num_rows(&self, config: Option<&DaftExecutionConfig>) -> Option<f64>;
+    /// An upper bound on the number of rows read by this task, e.g. its limit pushdown.
     #[must_use]
     fn upper_bound_rows(&self) -> Option<usize>;
     #[must_use]
     fn size_bytes_on_disk(&self) -> Option<usize>;
+    /// An estimate of the size of the data read by this task once in memory, used for planning.
     #[must_use]
     fn estimate_in_memory_size_bytes(&self, config: Option<&DaftExecutionConfig>) -> Option<usize>;
+    /// The format of the data read by this task, which selects the built-in reader of a scan task of Daft's own
+    /// sources. Scan tasks which are read with [`ScanTaskLike::read`] only use it for display.
     #[must_use]
     fn file_format_config(&self) -> Arc<FileFormatConfig>;
     #[must_use]
     fn pushdowns(&self) -> &Pushdowns;
+    /// The schema of the source before the pushdowns of this task are applied.
     #[must_use]
     fn schema(&self) -> SchemaRef;
     fn get_file_paths(&self) -> Vec<String>;
+
+    /// Reads the record batches of this task, for scan tasks of sources other than Daft's own.
+    ///
+    /// The executors apply the pushdowns of this task to the record batches it returns, so implementations may apply
+    /// them partially or not at all, even if their scan operator absorbs them. The record batches must have the
+    /// columns of the [`ScanTaskLike::materialized_schema`] of this task and the columns read by its filters, and any
+    /// other columns are dropped.
+    fn read(&self) -> DaftResult<ScanTaskStream> {
+        Err(DaftError::NotImplemented(format!(
+            "Reading scan tasks of type {}",
+            std::any::type_name::<Self>()
+        )))
+    }
 }
 
 pub type ScanTaskLikeRef = Arc<dyn ScanTaskLike>;
@@ -1,6 +1,8 @@
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
-use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind};
+use sysinfo::{
+    CpuRefreshKind, MemoryRefreshKind, ProcessRefreshKind, ProcessesToUpdate, RefreshKind,
+};
 
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", frozen))]
 pub struct SystemInfo {
@@ -33,6 +35,18 @@ impl SystemInfo {
     }
 }
 
+/// Returns the resident memory of the current process in bytes, if it can be read.
+pub fn process_memory_usage() -> Option<u64> {
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut system = sysinfo::System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    system.process(pid).map(|process| process.memory())
+}
+
 #[cfg(feature = "python")]
 #[pymethods]
 impl SystemInfo {
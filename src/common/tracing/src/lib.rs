@@ -1,4 +1,5 @@
 use std::{
+    net::SocketAddr,
     sync::{LazyLock, Mutex},
     time::Duration,
 };
@@ -25,26 +26,42 @@ pub static GLOBAL_LOGGER_PROVIDER: LazyLock<Mutex<Option<SdkLoggerProvider>>> =
     LazyLock::new(|| Mutex::new(None));
 
 const OTEL_EXPORTER_OTLP_ENDPOINT: &str = "DAFT_DEV_OTEL_EXPORTER_OTLP_ENDPOINT";
+/// Address to serve metrics on in the Prometheus text format, at `/metrics`, e.g. `0.0.0.0:9464`.
+const PROMETHEUS_METRICS_ADDR: &str = "DAFT_DEV_PROMETHEUS_METRICS_ADDR";
 
 pub fn should_enable_opentelemetry() -> bool {
     std::env::var(OTEL_EXPORTER_OTLP_ENDPOINT).is_ok()
 }
 
+pub fn should_serve_prometheus_metrics() -> bool {
+    std::env::var(PROMETHEUS_METRICS_ADDR).is_ok()
+}
+
 pub fn init_opentelemetry_providers() {
-    if !should_enable_opentelemetry() {
+    if !should_enable_opentelemetry() && !should_serve_prometheus_metrics() {
         return;
     }
 
-    let otlp_endpoint = match std::env::var(OTEL_EXPORTER_OTLP_ENDPOINT) {
-        Ok(endpoint) => endpoint,
-        Err(_) => return,
+    let otlp_endpoint = std::env::var(OTEL_EXPORTER_OTLP_ENDPOINT).ok();
+    let prometheus_addr = match std::env::var(PROMETHEUS_METRICS_ADDR)
+        .ok()
+        .map(|addr| addr.parse::<SocketAddr>())
+        .transpose()
+    {
+        Ok(addr) => addr,
+        Err(e) => {
+            log::warn!("Invalid {PROMETHEUS_METRICS_ADDR}, not serving Prometheus metrics: {e}");
+            None
+        }
     };
 
     let ioruntime = get_io_runtime(true);
     ioruntime.block_on_current_thread(async {
-        init_otlp_metrics_provider(&otlp_endpoint).await;
-        init_otlp_tracer_provider(&otlp_endpoint).await;
-        init_otlp_logger_provider(&otlp_endpoint).await;
+        init_metrics_provider(otlp_endpoint.as_deref(), prometheus_addr).await;
+        if let Some(otlp_endpoint) = &otlp_endpoint {
+            init_otlp_tracer_provider(otlp_endpoint).await;
+            init_otlp_logger_provider(otlp_endpoint).await;
+        }
     });
 }
 
@@ -77,7 +94,7 @@ async fn init_otlp_logger_provider(otlp_endpoint: &str) {
     *lg = Some(logger_provider);
 }
 
-async fn init_otlp_metrics_provider(otlp_endpoint: &str) {
+async fn init_metrics_provider(otlp_endpoint: Option<&str>, prometheus_addr: Option<SocketAddr>) {
     let mut mg = GLOBAL_METER_PROVIDER.lock().unwrap();
     assert!(mg.is_none(), "Expected meter provider to be None on init");
 
@@ -85,34 +102,76 @@ async fn init_otlp_metrics_provider(otlp_endpoint: &str) {
         .with_attribute(KeyValue::new("service.name", "daft"))
         .build();
 
-    let metrics_exporter = opentelemetry_otlp::MetricExporter::builder()
-        .with_tonic()
-        .with_endpoint(otlp_endpoint)
-        .with_timeout(Duration::from_secs(10))
-        .build()
-        .expect("Failed to build OTLP metric exporter for tracing");
+    let mut builder =
+        opentelemetry_sdk::metrics::SdkMeterProvider::builder().with_resource(resource);
 
-    let metrics_reader = PeriodicReader::builder(metrics_exporter)
-        .with_interval(Duration::from_millis(500))
-        .build();
+    if let Some(otlp_endpoint) = otlp_endpoint {
+        let metrics_exporter = opentelemetry_otlp::MetricExporter::builder()
+            .with_tonic()
+            .with_endpoint(otlp_endpoint)
+            .with_timeout(Duration::from_secs(10))
+            .build()
+            .expect("Failed to build OTLP metric exporter for tracing");
 
-    let metrics_provider = opentelemetry_sdk::metrics::SdkMeterProvider::builder()
         // To customize the export interval, set the **"OTEL_METRIC_EXPORT_INTERVAL"** environment variable (in milliseconds).
-        .with_reader(metrics_reader)
-        .with_resource(resource)
-        .build();
+        let metrics_reader = PeriodicReader::builder(metrics_exporter)
+            .with_interval(Duration::from_millis(500))
+            .build();
+        builder = builder.with_reader(metrics_reader);
+    }
+
+    if let Some(prometheus_addr) = prometheus_addr {
+        let registry = prometheus::Registry::new();
+        let exporter = opentelemetry_prometheus::exporter()
+            .with_registry(registry.clone())
+            .build()
+            .expect("Failed to build Prometheus metric exporter");
+        builder = builder.with_reader(exporter);
+        tokio::spawn(serve_prometheus_metrics(prometheus_addr, registry));
+    }
+
+    let metrics_provider = builder.build();
 
     global::set_meter_provider(metrics_provider.clone());
 
     *mg = Some(metrics_provider);
 }
 
+/// Serve the metrics in `registry` at `/metrics` on `addr` until the process exits.
+///
+/// Failing to bind is not fatal, since several Daft processes on the same host (e.g. Ray workers)
+/// may be configured with the same address.
+async fn serve_prometheus_metrics(addr: SocketAddr, registry: prometheus::Registry) {
+    let app = axum::Router::new().route(
+        "/metrics",
+        axum::routing::get(move || {
+            let registry = registry.clone();
+            async move {
+                prometheus::TextEncoder::new()
+                    .encode_to_string(&registry.gather())
+                    .unwrap_or_else(|e| format!("# Failed to encode metrics: {e}\n"))
+            }
+        }),
+    );
+
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::warn!("Failed to bind Prometheus metrics endpoint to {addr}: {e}");
+            return;
+        }
+    };
+    if let Err(e) = axum::serve(listener, app).await {
+        log::warn!("Prometheus metrics endpoint on {addr} failed: {e}");
+    }
+}
+
 pub fn flush_oltp_metrics_provider() {
     let mg = GLOBAL_METER_PROVIDER.lock().unwrap();
     if let Some(meter_provider) = mg.as_ref()
         && let Err(e) = meter_provider.force_flush()
     {
-        eprintln!("Failed to flush OTLP metrics provider: {}", e);
+        log::warn!("Failed to flush OTLP metrics provider: {}", e);
     }
 }
 
@@ -121,7 +180,7 @@ pub fn flush_oltp_logger_provider() {
     if let Some(logger_provider) = lg.as_ref()
         && let Err(e) = logger_provider.force_flush()
     {
-        eprintln!("Failed to flush OTLP logger provider: {}", e);
+        log::warn!("Failed to flush OTLP logger provider: {}", e);
     }
 }
 
@@ -162,6 +221,6 @@ fn flush_oltp_tracer_provider() {
     if let Some(tracer_provider) = mg.as_ref()
         && let Err(e) = tracer_provider.force_flush()
     {
-        eprintln!("Failed to flush OTLP tracer provider: {}", e);
+        log::warn!("Failed to flush OTLP tracer provider: {}", e);
     }
 }
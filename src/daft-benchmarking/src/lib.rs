@@ -0,0 +1,16 @@
+//! Native TPC-H data generation for benchmarking the Daft engine, which generates any partition of
+//! any table at a scale factor without building `dbgen`.
+
+#[cfg(feature = "python")]
+pub mod python;
+pub mod tpch;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use tpch::TpchTable;
+
+#[cfg(feature = "python")]
+pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
+    parent.add_function(wrap_pyfunction!(python::generate_tpch_table, parent)?)?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use daft_recordbatch::python::PyRecordBatch;
+use pyo3::prelude::*;
+
+use crate::TpchTable;
+
+/// Generates one partition of a TPC-H table at the given scale factor.
+#[pyfunction(signature = (table, scale_factor, partition_idx=0, num_partitions=1))]
+pub fn generate_tpch_table(
+    py: Python,
+    table: &str,
+    scale_factor: f64,
+    partition_idx: usize,
+    num_partitions: usize,
+) -> PyResult<PyRecordBatch> {
+    let table: TpchTable = table.parse()?;
+    py.detach(|| {
+        Ok(table
+            .generate(scale_factor, partition_idx, num_partitions)?
+            .into())
+    })
+}
@@ -0,0 +1,1023 @@
+//! Native TPC-H data generation.
+//!
+//! The generator follows the cardinalities, key relationships and value domains of the
+//! TPC-H specification (and of `dbgen`) closely enough that the 22 benchmark queries exercise
+//! the same selectivities, while being purely in-process. Every row is derived from a
+//! deterministic hash of `(table, row)`, so any partition of any table can be generated
+//! independently and produces the same rows regardless of the number of partitions.
+
+use std::{fmt::Display, ops::Range, str::FromStr, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_recordbatch::RecordBatch;
+
+/// Days since the unix epoch of 1992-01-01, the first order date.
+const START_DATE: i32 = 8035;
+/// Days since the unix epoch of 1995-06-17, the "current date" used for line statuses.
+const CURRENT_DATE: i32 = 9298;
+/// Days since the unix epoch of 1998-08-02, the last order date (ENDDATE - 151 days).
+const LAST_ORDER_DATE: i32 = 10440;
+
+const REGIONS: [&str; 5] = ["AFRICA", "AMERICA", "ASIA", "EUROPE", "MIDDLE EAST"];
+
+const NATIONS: [(&str, i64); 25] = [
+    ("ALGERIA", 0),
+    ("ARGENTINA", 1),
+    ("BRAZIL", 1),
+    ("CANADA", 1),
+    ("EGYPT", 4),
+    ("ETHIOPIA", 0),
+    ("FRANCE", 3),
+    ("GERMANY", 3),
+    ("INDIA", 2),
+    ("INDONESIA", 2),
+    ("IRAN", 4),
+    ("IRAQ", 4),
+    ("JAPAN", 2),
+    ("JORDAN", 4),
+    ("KENYA", 0),
+    ("MOROCCO", 0),
+    ("MOZAMBIQUE", 0),
+    ("PERU", 1),
+    ("CHINA", 2),
+    ("ROMANIA", 3),
+    ("SAUDI ARABIA", 4),
+    ("VIETNAM", 2),
+    ("RUSSIA", 3),
+    ("UNITED KINGDOM", 3),
+    ("UNITED STATES", 1),
+];
+
+const MKT_SEGMENTS: [&str; 5] = [
+    "AUTOMOBILE",
+    "BUILDING",
+    "FURNITURE",
+    "MACHINERY",
+    "HOUSEHOLD",
+];
+
+const ORDER_PRIORITIES: [&str; 5] = ["1-URGENT", "2-HIGH", "3-MEDIUM", "4-NOT SPECIFIED", "5-LOW"];
+
+const SHIP_INSTRUCTIONS: [&str; 4] = [
+    "DELIVER IN PERSON",
+    "COLLECT COD",
+    "NONE",
+    "TAKE BACK RETURN",
+];
+
+const SHIP_MODES: [&str; 7] = ["REG AIR", "AIR", "RAIL", "SHIP", "TRUCK", "MAIL", "FOB"];
+
+const TYPE_SYLLABLE_1: [&str; 6] = ["STANDARD", "SMALL", "MEDIUM", "LARGE", "ECONOMY", "PROMO"];
+const TYPE_SYLLABLE_2: [&str; 5] = ["ANODIZED", "BURNISHED", "PLATED", "POLISHED", "BRUSHED"];
+const TYPE_SYLLABLE_3: [&str; 5] = ["TIN", "NICKEL", "BRASS", "STEEL", "COPPER"];
+
+const CONTAINER_SYLLABLE_1: [&str; 5] = ["SM", "LG", "MED", "JUMBO", "WRAP"];
+const CONTAINER_SYLLABLE_2: [&str; 8] = ["CASE", "BOX", "BAG", "JAR", "PKG", "PACK", "CAN", "DRUM"];
+
+/// The `colors` distribution of `dbgen`, five distinct words of which make up a part name.
+const PART_NAME_WORDS: [&str; 92] = [
+    "almond",
+    "antique",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanched",
+    "blue",
+    "blush",
+    "brown",
+    "burlywood",
+    "burnished",
+    "chartreuse",
+    "chiffon",
+    "chocolate",
+    "coral",
+    "cornflower",
+    "cornsilk",
+    "cream",
+    "cyan",
+    "dark",
+    "deep",
+    "dim",
+    "dodger",
+    "drab",
+    "firebrick",
+    "floral",
+    "forest",
+    "frosted",
+    "gainsboro",
+    "ghost",
+    "goldenrod",
+    "green",
+    "grey",
+    "honeydew",
+    "hot",
+    "indian",
+    "ivory",
+    "khaki",
+    "lace",
+    "lavender",
+    "lawn",
+    "lemon",
+    "light",
+    "lime",
+    "linen",
+    "magenta",
+    "maroon",
+    "medium",
+    "metallic",
+    "midnight",
+    "mint",
+    "misty",
+    "moccasin",
+    "navajo",
+    "navy",
+    "olive",
+    "orange",
+    "orchid",
+    "pale",
+    "papaya",
+    "peach",
+    "peru",
+    "pink",
+    "plum",
+    "powder",
+    "puff",
+    "purple",
+    "red",
+    "rose",
+    "rosy",
+    "royal",
+    "saddle",
+    "salmon",
+    "sandy",
+    "seashell",
+    "sienna",
+    "sky",
+    "slate",
+    "smoke",
+    "snow",
+    "spring",
+    "steel",
+    "tan",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "yellow",
+];
+
+const COMMENT_WORDS: [&str; 24] = [
+    "furiously",
+    "special",
+    "requests",
+    "carefully",
+    "final",
+    "deposits",
+    "pending",
+    "accounts",
+    "ironic",
+    "packages",
+    "regular",
+    "blithely",
+    "quickly",
+    "express",
+    "slyly",
+    "bold",
+    "unusual",
+    "theodolites",
+    "foxes",
+    "pinto",
+    "beans",
+    "instructions",
+    "dependencies",
+    "platelets",
+];
+
+/// The eight tables of the TPC-H schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TpchTable {
+    Region,
+    Nation,
+    Supplier,
+    Customer,
+    Part,
+    PartSupp,
+    Orders,
+    LineItem,
+}
+
+impl TpchTable {
+    pub const ALL: [Self; 8] = [
+        Self::Region,
+        Self::Nation,
+        Self::Supplier,
+        Self::Customer,
+        Self::Part,
+        Self::PartSupp,
+        Self::Orders,
+        Self::LineItem,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Region => "region",
+            Self::Nation => "nation",
+            Self::Supplier => "supplier",
+            Self::Customer => "customer",
+            Self::Part => "part",
+            Self::PartSupp => "partsupp",
+            Self::Orders => "orders",
+            Self::LineItem => "lineitem",
+        }
+    }
+
+    /// Seed mixed into the row hash so that tables sharing row indices don't share values.
+    fn seed(&self) -> u64 {
+        match self {
+            Self::Region => 0x5245_4749_4f4e,
+            Self::Nation => 0x4e41_5449_4f4e,
+            Self::Supplier => 0x5355_5050_4c49,
+            Self::Customer => 0x4355_5354_4f4d,
+            Self::Part => 0x5041_5254,
+            Self::PartSupp => 0x5041_5254_5355,
+            // Orders and line items are generated together so they share a seed.
+            Self::Orders | Self::LineItem => 0x4f52_4445_5253,
+        }
+    }
+
+    pub fn schema(&self) -> Schema {
+        use DataType::{Date, Float64, Int32, Int64, Utf8};
+
+        let fields: &[(&str, DataType)] = match self {
+            Self::Region => &[
+                ("R_REGIONKEY", Int64),
+                ("R_NAME", Utf8),
+                ("R_COMMENT", Utf8),
+            ],
+            Self::Nation => &[
+                ("N_NATIONKEY", Int64),
+                ("N_NAME", Utf8),
+                ("N_REGIONKEY", Int64),
+                ("N_COMMENT", Utf8),
+            ],
+            Self::Supplier => &[
+                ("S_SUPPKEY", Int64),
+                ("S_NAME", Utf8),
+                ("S_ADDRESS", Utf8),
+                ("S_NATIONKEY", Int64),
+                ("S_PHONE", Utf8),
+                ("S_ACCTBAL", Float64),
+                ("S_COMMENT", Utf8),
+            ],
+            Self::Customer => &[
+                ("C_CUSTKEY", Int64),
+                ("C_NAME", Utf8),
+                ("C_ADDRESS", Utf8),
+                ("C_NATIONKEY", Int64),
+                ("C_PHONE", Utf8),
+                ("C_ACCTBAL", Float64),
+                ("C_MKTSEGMENT", Utf8),
+                ("C_COMMENT", Utf8),
+            ],
+            Self::Part => &[
+                ("P_PARTKEY", Int64),
+                ("P_NAME", Utf8),
+                ("P_MFGR", Utf8),
+                ("P_BRAND", Utf8),
+                ("P_TYPE", Utf8),
+                ("P_SIZE", Int32),
+                ("P_CONTAINER", Utf8),
+                ("P_RETAILPRICE", Float64),
+                ("P_COMMENT", Utf8),
+            ],
+            Self::PartSupp => &[
+                ("PS_PARTKEY", Int64),
+                ("PS_SUPPKEY", Int64),
+                ("PS_AVAILQTY", Int32),
+                ("PS_SUPPLYCOST", Float64),
+                ("PS_COMMENT", Utf8),
+            ],
+            Self::Orders => &[
+                ("O_ORDERKEY", Int64),
+                ("O_CUSTKEY", Int64),
+                ("O_ORDERSTATUS", Utf8),
+                ("O_TOTALPRICE", Float64),
+                ("O_ORDERDATE", Date),
+                ("O_ORDERPRIORITY", Utf8),
+                ("O_CLERK", Utf8),
+                ("O_SHIPPRIORITY", Int32),
+                ("O_COMMENT", Utf8),
+            ],
+            Self::LineItem => &[
+                ("L_ORDERKEY", Int64),
+                ("L_PARTKEY", Int64),
+                ("L_SUPPKEY", Int64),
+                ("L_LINENUMBER", Int32),
+                ("L_QUANTITY", Float64),
+                ("L_EXTENDEDPRICE", Float64),
+                ("L_DISCOUNT", Float64),
+                ("L_TAX", Float64),
+                ("L_RETURNFLAG", Utf8),
+                ("L_LINESTATUS", Utf8),
+                ("L_SHIPDATE", Date),
+                ("L_COMMITDATE", Date),
+                ("L_RECEIPTDATE", Date),
+                ("L_SHIPINSTRUCT", Utf8),
+                ("L_SHIPMODE", Utf8),
+                ("L_COMMENT", Utf8),
+            ],
+        };
+        Schema::new(
+            fields
+                .iter()
+                .map(|(name, dtype)| Field::new(*name, dtype.clone())),
+        )
+    }
+
+    /// Number of "generator units" of this table at the given scale factor.
+    ///
+    /// This is the number of rows for every table except `partsupp` and `lineitem`, which are
+    /// generated per part and per order respectively.
+    fn num_units(&self, scale_factor: f64) -> usize {
+        let scaled = |base: f64| ((base * scale_factor) as usize).max(1);
+        match self {
+            Self::Region => REGIONS.len(),
+            Self::Nation => NATIONS.len(),
+            Self::Supplier => scaled(10_000.0),
+            Self::Customer => scaled(150_000.0),
+            Self::Part | Self::PartSupp => scaled(200_000.0),
+            Self::Orders | Self::LineItem => scaled(1_500_000.0),
+        }
+    }
+
+    /// Generates partition `partition_idx` out of `num_partitions` of this table.
+    pub fn generate(
+        &self,
+        scale_factor: f64,
+        partition_idx: usize,
+        num_partitions: usize,
+    ) -> DaftResult<RecordBatch> {
+        if !scale_factor.is_finite() || scale_factor <= 0.0 {
+            return Err(DaftError::ValueError(format!(
+                "TPC-H scale factor must be a positive number, got: {scale_factor}"
+            )));
+        }
+        if num_partitions == 0 || partition_idx >= num_partitions {
+            return Err(DaftError::ValueError(format!(
+                "Invalid TPC-H partition {partition_idx} of {num_partitions}"
+            )));
+        }
+        let generator = Generator::new(scale_factor);
+        let units = self.num_units(scale_factor);
+        let range =
+            units * partition_idx / num_partitions..units * (partition_idx + 1) / num_partitions;
+
+        let columns = match self {
+            Self::Region => generator.region(range),
+            Self::Nation => generator.nation(range),
+            Self::Supplier => generator.supplier(range),
+            Self::Customer => generator.customer(range),
+            Self::Part => generator.part(range),
+            Self::PartSupp => generator.partsupp(range),
+            Self::Orders => generator.orders(range),
+            Self::LineItem => generator.lineitem(range),
+        };
+        let num_rows = columns.first().map_or(0, Series::len);
+        let schema = Arc::new(self.schema());
+        RecordBatch::new_with_size(schema, columns, num_rows)
+    }
+}
+
+impl Display for TpchTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TpchTable {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|table| table.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| DaftError::ValueError(format!("Unknown TPC-H table: {s}")))
+    }
+}
+
+/// A deterministic random stream for a single row, keyed by `(table seed, row index)`.
+struct RowRng {
+    state: u64,
+}
+
+impl RowRng {
+    fn new(table: TpchTable, row: usize) -> Self {
+        Self {
+            state: table.seed() ^ (row as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        }
+    }
+
+    /// splitmix64
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform integer in the inclusive range `[low, high]`.
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % ((high - low + 1) as u64)) as i64
+    }
+
+    /// Uniform amount with two decimal places in `[low, high]`.
+    fn money(&mut self, low: f64, high: f64) -> f64 {
+        self.range((low * 100.0) as i64, (high * 100.0) as i64) as f64 / 100.0
+    }
+
+    fn choose<'a>(&mut self, values: &[&'a str]) -> &'a str {
+        values[self.range(0, values.len() as i64 - 1) as usize]
+    }
+
+    /// Chooses `n` distinct values, in the order they were drawn.
+    fn choose_distinct<'a>(&mut self, values: &[&'a str], n: usize) -> Vec<&'a str> {
+        let mut chosen = Vec::with_capacity(n);
+        while chosen.len() < n.min(values.len()) {
+            let value = self.choose(values);
+            if !chosen.contains(&value) {
+                chosen.push(value);
+            }
+        }
+        chosen
+    }
+
+    fn text(&mut self, min_words: i64, max_words: i64) -> String {
+        let n = self.range(min_words, max_words);
+        (0..n)
+            .map(|_| self.choose(&COMMENT_WORDS))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn alphanumeric(&mut self, min_len: i64, max_len: i64) -> String {
+        const CHARS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ,";
+        let n = self.range(min_len, max_len);
+        (0..n)
+            .map(|_| CHARS[self.range(0, CHARS.len() as i64 - 1) as usize] as char)
+            .collect()
+    }
+
+    fn phone(&mut self, nation_key: i64) -> String {
+        format!(
+            "{}-{}-{}-{}",
+            nation_key + 10,
+            self.range(100, 999),
+            self.range(100, 999),
+            self.range(1000, 9999)
+        )
+    }
+}
+
+/// A single line of an order, shared by the `orders` and `lineitem` generators so that
+/// order totals and statuses agree with their line items.
+struct LineItem {
+    part_key: i64,
+    supp_key: i64,
+    line_number: i32,
+    quantity: f64,
+    extended_price: f64,
+    discount: f64,
+    tax: f64,
+    return_flag: &'static str,
+    line_status: &'static str,
+    ship_date: i32,
+    commit_date: i32,
+    receipt_date: i32,
+    ship_instruct: &'static str,
+    ship_mode: &'static str,
+    comment: String,
+}
+
+struct Order {
+    order_key: i64,
+    cust_key: i64,
+    order_date: i32,
+    priority: &'static str,
+    clerk: String,
+    comment: String,
+    lines: Vec<LineItem>,
+}
+
+impl Order {
+    fn status(&self) -> &'static str {
+        if self.lines.iter().all(|l| l.line_status == "F") {
+            "F"
+        } else if self.lines.iter().all(|l| l.line_status == "O") {
+            "O"
+        } else {
+            "P"
+        }
+    }
+
+    fn total_price(&self) -> f64 {
+        let total: f64 = self
+            .lines
+            .iter()
+            .map(|l| l.extended_price * (1.0 + l.tax) * (1.0 - l.discount))
+            .sum();
+        (total * 100.0).round() / 100.0
+    }
+}
+
+struct Generator {
+    num_suppliers: i64,
+    num_customers: i64,
+    num_parts: i64,
+    num_clerks: i64,
+}
+
+impl Generator {
+    fn new(scale_factor: f64) -> Self {
+        Self {
+            num_suppliers: TpchTable::Supplier.num_units(scale_factor) as i64,
+            num_customers: TpchTable::Customer.num_units(scale_factor) as i64,
+            num_parts: TpchTable::Part.num_units(scale_factor) as i64,
+            num_clerks: ((scale_factor * 1000.0) as i64).max(1),
+        }
+    }
+
+    fn region(&self, rows: Range<usize>) -> Vec<Series> {
+        let mut comments = Vec::with_capacity(rows.len());
+        for row in rows.clone() {
+            comments.push(RowRng::new(TpchTable::Region, row).text(4, 12));
+        }
+        vec![
+            int64_series("R_REGIONKEY", rows.clone().map(|r| r as i64).collect()),
+            utf8_series("R_NAME", &rows.map(|r| REGIONS[r]).collect::<Vec<_>>()),
+            utf8_series("R_COMMENT", &comments),
+        ]
+    }
+
+    fn nation(&self, rows: Range<usize>) -> Vec<Series> {
+        let mut comments = Vec::with_capacity(rows.len());
+        for row in rows.clone() {
+            comments.push(RowRng::new(TpchTable::Nation, row).text(4, 12));
+        }
+        vec![
+            int64_series("N_NATIONKEY", rows.clone().map(|r| r as i64).collect()),
+            utf8_series(
+                "N_NAME",
+                &rows.clone().map(|r| NATIONS[r].0).collect::<Vec<_>>(),
+            ),
+            int64_series("N_REGIONKEY", rows.map(|r| NATIONS[r].1).collect()),
+            utf8_series("N_COMMENT", &comments),
+        ]
+    }
+
+    fn supplier(&self, rows: Range<usize>) -> Vec<Series> {
+        let n = rows.len();
+        let (mut keys, mut names, mut addresses, mut nations) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut phones, mut balances, mut comments) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for row in rows {
+            let mut rng = RowRng::new(TpchTable::Supplier, row);
+            let key = row as i64 + 1;
+            let nation = rng.range(0, 24);
+            keys.push(key);
+            names.push(format!("Supplier#{key:09}"));
+            addresses.push(rng.alphanumeric(10, 40));
+            nations.push(nation);
+            phones.push(rng.phone(nation));
+            balances.push(rng.money(-999.99, 9999.99));
+            // dbgen plants "Customer ... Complaints" / "Customer ... Recommends" in a handful
+            // of supplier comments, which Q16 filters on.
+            let mut comment = rng.text(4, 10);
+            match rng.range(0, 1999) {
+                0 => comment.push_str(" Customer unusual Complaints"),
+                1 => comment.push_str(" Customer final Recommends"),
+                _ => {}
+            }
+            comments.push(comment);
+        }
+        vec![
+            int64_series("S_SUPPKEY", keys),
+            utf8_series("S_NAME", &names),
+            utf8_series("S_ADDRESS", &addresses),
+            int64_series("S_NATIONKEY", nations),
+            utf8_series("S_PHONE", &phones),
+            float64_series("S_ACCTBAL", balances),
+            utf8_series("S_COMMENT", &comments),
+        ]
+    }
+
+    fn customer(&self, rows: Range<usize>) -> Vec<Series> {
+        let n = rows.len();
+        let (mut keys, mut names, mut addresses, mut nations) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut phones, mut balances, mut segments, mut comments) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for row in rows {
+            let mut rng = RowRng::new(TpchTable::Customer, row);
+            let key = row as i64 + 1;
+            let nation = rng.range(0, 24);
+            keys.push(key);
+            names.push(format!("Customer#{key:09}"));
+            addresses.push(rng.alphanumeric(10, 40));
+            nations.push(nation);
+            phones.push(rng.phone(nation));
+            balances.push(rng.money(-999.99, 9999.99));
+            segments.push(rng.choose(&MKT_SEGMENTS));
+            comments.push(rng.text(4, 14));
+        }
+        vec![
+            int64_series("C_CUSTKEY", keys),
+            utf8_series("C_NAME", &names),
+            utf8_series("C_ADDRESS", &addresses),
+            int64_series("C_NATIONKEY", nations),
+            utf8_series("C_PHONE", &phones),
+            float64_series("C_ACCTBAL", balances),
+            utf8_series("C_MKTSEGMENT", &segments),
+            utf8_series("C_COMMENT", &comments),
+        ]
+    }
+
+    fn part(&self, rows: Range<usize>) -> Vec<Series> {
+        let n = rows.len();
+        let (mut keys, mut names, mut mfgrs, mut brands, mut types) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut sizes, mut containers, mut prices, mut comments) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for row in rows {
+            let mut rng = RowRng::new(TpchTable::Part, row);
+            let key = row as i64 + 1;
+            let mfgr = rng.range(1, 5);
+            keys.push(key);
+            names.push(rng.choose_distinct(&PART_NAME_WORDS, 5).join(" "));
+            mfgrs.push(format!("Manufacturer#{mfgr}"));
+            brands.push(format!("Brand#{mfgr}{}", rng.range(1, 5)));
+            types.push(format!(
+                "{} {} {}",
+                rng.choose(&TYPE_SYLLABLE_1),
+                rng.choose(&TYPE_SYLLABLE_2),
+                rng.choose(&TYPE_SYLLABLE_3)
+            ));
+            sizes.push(rng.range(1, 50) as i32);
+            containers.push(format!(
+                "{} {}",
+                rng.choose(&CONTAINER_SYLLABLE_1),
+                rng.choose(&CONTAINER_SYLLABLE_2)
+            ));
+            prices.push(retail_price(key));
+            comments.push(rng.text(2, 6));
+        }
+        vec![
+            int64_series("P_PARTKEY", keys),
+            utf8_series("P_NAME", &names),
+            utf8_series("P_MFGR", &mfgrs),
+            utf8_series("P_BRAND", &brands),
+            utf8_series("P_TYPE", &types),
+            int32_series("P_SIZE", sizes),
+            utf8_series("P_CONTAINER", &containers),
+            float64_series("P_RETAILPRICE", prices),
+            utf8_series("P_COMMENT", &comments),
+        ]
+    }
+
+    /// Generates the four `partsupp` rows of each part in `parts`.
+    fn partsupp(&self, parts: Range<usize>) -> Vec<Series> {
+        let n = parts.len() * 4;
+        let (mut part_keys, mut supp_keys, mut quantities, mut costs, mut comments) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for part in parts {
+            let mut rng = RowRng::new(TpchTable::PartSupp, part);
+            let part_key = part as i64 + 1;
+            for i in 0..4 {
+                part_keys.push(part_key);
+                supp_keys.push(self.supplier_for_part(part_key, i));
+                quantities.push(rng.range(1, 9999) as i32);
+                costs.push(rng.money(1.0, 1000.0));
+                comments.push(rng.text(8, 20));
+            }
+        }
+        vec![
+            int64_series("PS_PARTKEY", part_keys),
+            int64_series("PS_SUPPKEY", supp_keys),
+            int32_series("PS_AVAILQTY", quantities),
+            float64_series("PS_SUPPLYCOST", costs),
+            utf8_series("PS_COMMENT", &comments),
+        ]
+    }
+
+    fn orders(&self, rows: Range<usize>) -> Vec<Series> {
+        let n = rows.len();
+        let (mut keys, mut custs, mut statuses, mut totals, mut dates) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut priorities, mut clerks, mut comments) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for row in rows {
+            let order = self.order(row);
+            keys.push(order.order_key);
+            custs.push(order.cust_key);
+            statuses.push(order.status());
+            totals.push(order.total_price());
+            dates.push(order.order_date);
+            priorities.push(order.priority);
+            clerks.push(order.clerk);
+            comments.push(order.comment);
+        }
+        vec![
+            int64_series("O_ORDERKEY", keys),
+            int64_series("O_CUSTKEY", custs),
+            utf8_series("O_ORDERSTATUS", &statuses),
+            float64_series("O_TOTALPRICE", totals),
+            date_series("O_ORDERDATE", dates),
+            utf8_series("O_ORDERPRIORITY", &priorities),
+            utf8_series("O_CLERK", &clerks),
+            int32_series("O_SHIPPRIORITY", vec![0; n]),
+            utf8_series("O_COMMENT", &comments),
+        ]
+    }
+
+    /// Generates the line items of each order in `orders`.
+    fn lineitem(&self, orders: Range<usize>) -> Vec<Series> {
+        // Orders have four lines on average.
+        let n = orders.len() * 4;
+        let (mut order_keys, mut part_keys, mut supp_keys, mut line_numbers) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut quantities, mut prices, mut discounts, mut taxes) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut return_flags, mut line_statuses, mut ship_dates, mut commit_dates) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        let (mut receipt_dates, mut instructions, mut modes, mut comments) = (
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+            Vec::with_capacity(n),
+        );
+        for row in orders {
+            let order = self.order(row);
+            for line in order.lines {
+                order_keys.push(order.order_key);
+                part_keys.push(line.part_key);
+                supp_keys.push(line.supp_key);
+                line_numbers.push(line.line_number);
+                quantities.push(line.quantity);
+                prices.push(line.extended_price);
+                discounts.push(line.discount);
+                taxes.push(line.tax);
+                return_flags.push(line.return_flag);
+                line_statuses.push(line.line_status);
+                ship_dates.push(line.ship_date);
+                commit_dates.push(line.commit_date);
+                receipt_dates.push(line.receipt_date);
+                instructions.push(line.ship_instruct);
+                modes.push(line.ship_mode);
+                comments.push(line.comment);
+            }
+        }
+        vec![
+            int64_series("L_ORDERKEY", order_keys),
+            int64_series("L_PARTKEY", part_keys),
+            int64_series("L_SUPPKEY", supp_keys),
+            int32_series("L_LINENUMBER", line_numbers),
+            float64_series("L_QUANTITY", quantities),
+            float64_series("L_EXTENDEDPRICE", prices),
+            float64_series("L_DISCOUNT", discounts),
+            float64_series("L_TAX", taxes),
+            utf8_series("L_RETURNFLAG", &return_flags),
+            utf8_series("L_LINESTATUS", &line_statuses),
+            date_series("L_SHIPDATE", ship_dates),
+            date_series("L_COMMITDATE", commit_dates),
+            date_series("L_RECEIPTDATE", receipt_dates),
+            utf8_series("L_SHIPINSTRUCT", &instructions),
+            utf8_series("L_SHIPMODE", &modes),
+            utf8_series("L_COMMENT", &comments),
+        ]
+    }
+
+    /// The `i`-th (0..4) supplier of a part, per the TPC-H partsupp key formula.
+    fn supplier_for_part(&self, part_key: i64, i: i64) -> i64 {
+        let s = self.num_suppliers;
+        (part_key + i * (s / 4 + (part_key - 1) / s)) % s + 1
+    }
+
+    fn order(&self, row: usize) -> Order {
+        let mut rng = RowRng::new(TpchTable::Orders, row);
+        // Order keys are sparse: only the first 8 of every 32 keys are populated.
+        let order_key = (row as i64 / 8) * 32 + (row as i64 % 8) + 1;
+        // A third of the customers never place an order.
+        let mut cust_key = rng.range(1, self.num_customers);
+        if self.num_customers >= 3 && cust_key % 3 == 0 {
+            cust_key = cust_key % self.num_customers + 1;
+        }
+        let order_date = rng.range(START_DATE as i64, LAST_ORDER_DATE as i64) as i32;
+        let priority = rng.choose(&ORDER_PRIORITIES);
+        let clerk = format!("Clerk#{:09}", rng.range(1, self.num_clerks));
+        let comment = rng.text(4, 12);
+
+        let num_lines = rng.range(1, 7);
+        let lines = (1..=num_lines)
+            .map(|line_number| {
+                let part_key = rng.range(1, self.num_parts);
+                let supp_key = self.supplier_for_part(part_key, rng.range(0, 3));
+                let quantity = rng.range(1, 50) as f64;
+                let extended_price = (quantity * retail_price(part_key) * 100.0).round() / 100.0;
+                let discount = rng.range(0, 10) as f64 / 100.0;
+                let tax = rng.range(0, 8) as f64 / 100.0;
+                let ship_date = order_date + rng.range(1, 121) as i32;
+                let commit_date = order_date + rng.range(30, 90) as i32;
+                let receipt_date = ship_date + rng.range(1, 30) as i32;
+                let return_flag = if receipt_date <= CURRENT_DATE {
+                    if rng.range(0, 1) == 0 { "R" } else { "A" }
+                } else {
+                    "N"
+                };
+                let line_status = if ship_date > CURRENT_DATE { "O" } else { "F" };
+                LineItem {
+                    part_key,
+                    supp_key,
+                    line_number: line_number as i32,
+                    quantity,
+                    extended_price,
+                    discount,
+                    tax,
+                    return_flag,
+                    line_status,
+                    ship_date,
+                    commit_date,
+                    receipt_date,
+                    ship_instruct: rng.choose(&SHIP_INSTRUCTIONS),
+                    ship_mode: rng.choose(&SHIP_MODES),
+                    comment: rng.text(2, 6),
+                }
+            })
+            .collect();
+
+        Order {
+            order_key,
+            cust_key,
+            order_date,
+            priority,
+            clerk,
+            comment,
+            lines,
+        }
+    }
+}
+
+/// The retail price of a part, which is a pure function of its key in TPC-H.
+fn retail_price(part_key: i64) -> f64 {
+    (90_000 + (part_key / 10) % 20_001 + 100 * (part_key % 1000)) as f64 / 100.0
+}
+
+fn int32_series(name: &str, values: Vec<i32>) -> Series {
+    Int32Array::from((name, values)).into_series()
+}
+
+fn int64_series(name: &str, values: Vec<i64>) -> Series {
+    Int64Array::from((name, values)).into_series()
+}
+
+fn float64_series(name: &str, values: Vec<f64>) -> Series {
+    Float64Array::from((name, values)).into_series()
+}
+
+fn utf8_series<S: AsRef<str>>(name: &str, values: &[S]) -> Series {
+    Utf8Array::from_values(name, values.iter()).into_series()
+}
+
+fn date_series(name: &str, days: Vec<i32>) -> Series {
+    DateArray::new(
+        Field::new(name, DataType::Date),
+        Int32Array::from((name, days)),
+    )
+    .into_series()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_tables() -> DaftResult<()> {
+        let region = TpchTable::Region.generate(1.0, 0, 1)?;
+        assert_eq!(region.len(), 5);
+        let nation = TpchTable::Nation.generate(0.01, 0, 1)?;
+        assert_eq!(nation.len(), 25);
+        assert_eq!(nation.schema.as_ref(), &TpchTable::Nation.schema());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scaled_cardinalities() -> DaftResult<()> {
+        let sf = 0.001;
+        assert_eq!(TpchTable::Supplier.generate(sf, 0, 1)?.len(), 10);
+        assert_eq!(TpchTable::Customer.generate(sf, 0, 1)?.len(), 150);
+        assert_eq!(TpchTable::Part.generate(sf, 0, 1)?.len(), 200);
+        assert_eq!(TpchTable::PartSupp.generate(sf, 0, 1)?.len(), 800);
+        assert_eq!(TpchTable::Orders.generate(sf, 0, 1)?.len(), 1500);
+        let lineitem = TpchTable::LineItem.generate(sf, 0, 1)?.len();
+        assert!((1500..=1500 * 7).contains(&lineitem));
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_names() -> DaftResult<()> {
+        let part = TpchTable::Part.generate(0.01, 0, 1)?;
+        let names = part.get_column(1).utf8()?;
+        let mut has_green = false;
+        for name in names.into_iter().flatten() {
+            let words = name.split(' ').collect::<Vec<_>>();
+            assert_eq!(words.len(), 5);
+            assert!(words.iter().all(|word| PART_NAME_WORDS.contains(word)));
+            assert!(
+                words
+                    .iter()
+                    .all(|word| words.iter().filter(|w| *w == word).count() == 1)
+            );
+            has_green |= words.contains(&"green");
+        }
+        // Q9 filters on part names containing "green".
+        assert!(has_green);
+        Ok(())
+    }
+
+    #[test]
+    fn test_partitions_are_consistent() -> DaftResult<()> {
+        let sf = 0.001;
+        let whole = TpchTable::LineItem.generate(sf, 0, 1)?;
+        let parts = (0..3)
+            .map(|i| TpchTable::LineItem.generate(sf, i, 3))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let combined = RecordBatch::concat(&parts)?;
+        assert_eq!(whole, combined);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(TpchTable::Orders.generate(0.0, 0, 1).is_err());
+        assert!(TpchTable::Orders.generate(1.0, 2, 2).is_err());
+        assert!("not_a_table".parse::<TpchTable>().is_err());
+        assert_eq!(
+            "LINEITEM".parse::<TpchTable>().unwrap(),
+            TpchTable::LineItem
+        );
+    }
+}
@@ -12,6 +12,7 @@ use common_daft_config::{DaftExecutionConfig, DaftPlanningConfig, IOConfig};
 use common_error::{DaftError, DaftResult};
 use common_metrics::{QueryID, QueryPlan};
 use daft_micropartition::MicroPartitionRef;
+use daft_stats::pruning::{start_recording_pruning, take_pruning_decisions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
@@ -133,6 +134,8 @@ impl DaftContext {
         query_id: QueryID,
         metadata: Arc<QueryMetadata>,
     ) -> DaftResult<()> {
+        // Scans record what they prune for the queries which are running, from their planning to their end.
+        start_recording_pruning(&query_id);
         self.with_state(|state| {
             for subscriber in state.subscribers.values() {
                 subscriber.on_query_start(query_id.clone(), metadata.clone())?;
@@ -142,6 +145,7 @@ impl DaftContext {
     }
 
     pub fn notify_query_end(&self, query_id: QueryID, result: QueryResult) -> DaftResult<()> {
+        let _ = take_pruning_decisions(&query_id);
         self.with_state(move |state| {
             for subscriber in state.subscribers.values() {
                 subscriber.on_query_end(query_id.clone(), result.clone())?;
@@ -18,11 +18,16 @@ pub struct PyQueryMetadata(pub(crate) Arc<QueryMetadata>);
 #[pymethods]
 impl PyQueryMetadata {
     #[new]
-    #[pyo3(signature = (output_schema, unoptimized_plan))]
-    fn __new__(output_schema: PySchema, unoptimized_plan: &str) -> Self {
+    #[pyo3(signature = (output_schema, unoptimized_plan, execution_config=None))]
+    fn __new__(
+        output_schema: PySchema,
+        unoptimized_plan: &str,
+        execution_config: Option<PyDaftExecutionConfig>,
+    ) -> Self {
         Self(Arc::new(QueryMetadata {
             output_schema: output_schema.into(),
             unoptimized_plan: unoptimized_plan.into(),
+            execution_config: execution_config.map(|config| config.config),
         }))
     }
     #[getter]
@@ -2,10 +2,12 @@ mod dashboard;
 mod debug;
 #[cfg(feature = "python")]
 pub mod python;
+pub mod query_log;
 
 use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
+use common_daft_config::DaftExecutionConfig;
 use common_error::DaftResult;
 use common_metrics::{NodeID, QueryEndState, QueryID, QueryPlan, StatSnapshot};
 use daft_core::prelude::SchemaRef;
@@ -14,6 +16,8 @@ use daft_micropartition::MicroPartitionRef;
 pub struct QueryMetadata {
     pub output_schema: SchemaRef,
     pub unoptimized_plan: QueryPlan,
+    /// The execution config which the query runs with, if it's known when the query starts.
+    pub execution_config: Option<Arc<DaftExecutionConfig>>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,6 +42,10 @@ pub trait Subscriber: Send + Sync + std::fmt::Debug + 'static {
     ) -> DaftResult<()>;
     async fn on_exec_operator_end(&self, query_id: QueryID, node_id: NodeID) -> DaftResult<()>;
     async fn on_exec_end(&self, query_id: QueryID) -> DaftResult<()>;
+    /// Called with the resident memory of the process each time the runtime stats of an executing query are emitted.
+    async fn on_exec_memory_usage(&self, _query_id: QueryID, _bytes: u64) -> DaftResult<()> {
+        Ok(())
+    }
 }
 
 pub fn default_subscribers() -> HashMap<String, Arc<dyn Subscriber>> {
@@ -54,6 +62,17 @@ pub fn default_subscribers() -> HashMap<String, Arc<dyn Subscriber>> {
         _ => {}
     }
 
+    // Query log subscriber
+    match query_log::QueryLogSubscriber::try_new() {
+        Ok(Some(s)) => {
+            subscribers.insert("_query_log".to_string(), Arc::new(s));
+        }
+        Err(e) => {
+            log::error!("Failed to open the query log: {}", e);
+        }
+        _ => {}
+    }
+
     #[cfg(debug_assertions)]
     if let Ok(s) = std::env::var("DAFT_DEV_ENABLE_RUNTIME_STATS_DBG") {
         let s = s.to_lowercase();
@@ -0,0 +1,329 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    sync::{Arc, Mutex},
+    time::{Instant, SystemTime},
+};
+
+use async_trait::async_trait;
+use common_daft_config::IOConfig;
+use common_error::{DaftError, DaftResult};
+use common_metrics::{NodeID, QueryEndState, QueryID, QueryPlan, StatSnapshot};
+use common_runtime::get_io_runtime;
+use daft_io::{IOClient, get_io_client};
+use daft_micropartition::MicroPartitionRef;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::subscribers::{QueryMetadata, QueryResult, Subscriber};
+
+/// Environment variable holding the query log sink: either a local file that records are appended
+/// to as JSON lines, or a remote URL prefix (e.g. `s3://bucket/query-log`) under which one JSON
+/// object is written per query.
+pub const QUERY_LOG_ENV_VAR: &str = "DAFT_QUERY_LOG";
+
+/// A structured record of a single query, written when the query ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryLogRecord {
+    pub query_id: String,
+    /// XXH3 hash of the JSON representation of the unoptimized plan, identifying repeated runs of the same query
+    /// across processes.
+    pub fingerprint: String,
+    pub start_time_ms: u64,
+    pub end_time_ms: u64,
+    pub wall_time_ms: u64,
+    pub planning_time_ms: Option<u64>,
+    pub rows_out: usize,
+    pub bytes_out: usize,
+    /// Peak resident memory of the process while the query executed, sampled with its runtime stats.
+    pub peak_memory_bytes: Option<u64>,
+    pub end_state: QueryEndState,
+    pub error_message: Option<String>,
+    /// The execution config the query ran with.
+    pub config: Option<serde_json::Value>,
+}
+
+/// Fingerprint a plan by hashing its JSON representation.
+pub fn plan_fingerprint(plan: &str) -> String {
+    format!("{:016x}", xxh3_64(plan.as_bytes()))
+}
+
+fn millis_since_epoch(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+struct InFlightQuery {
+    fingerprint: String,
+    start_time: SystemTime,
+    started: Instant,
+    optimization_started: Option<Instant>,
+    planning_time_ms: Option<u64>,
+    rows_out: usize,
+    bytes_out: usize,
+    peak_memory_bytes: Option<u64>,
+    config: Option<serde_json::Value>,
+}
+
+enum QueryLogSink {
+    File(Mutex<File>),
+    Remote {
+        prefix: String,
+        io_client: Arc<IOClient>,
+    },
+}
+
+impl QueryLogSink {
+    fn write(&self, record: &QueryLogRecord) -> DaftResult<()> {
+        match self {
+            Self::File(file) => {
+                let mut line = serde_json::to_string(record)?;
+                line.push('\n');
+                file.lock().unwrap().write_all(line.as_bytes())?;
+            }
+            Self::Remote { prefix, io_client } => {
+                let dest = format!("{}/{}.json", prefix.trim_end_matches('/'), record.query_id);
+                let data = serde_json::to_vec(record)?;
+                let io_client = io_client.clone();
+                get_io_runtime(true).block_on_current_thread(async move {
+                    io_client.single_url_put(&dest, data.into(), None).await
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Subscriber that logs one [`QueryLogRecord`] per query to a file or object store.
+pub struct QueryLogSubscriber {
+    sink: QueryLogSink,
+    queries: DashMap<QueryID, InFlightQuery>,
+}
+
+impl std::fmt::Debug for QueryLogSubscriber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sink = match &self.sink {
+            QueryLogSink::File(_) => "file",
+            QueryLogSink::Remote { .. } => "remote",
+        };
+        f.debug_struct("QueryLogSubscriber")
+            .field("sink", &sink)
+            .field("num_queries", &self.queries.len())
+            .finish()
+    }
+}
+
+impl QueryLogSubscriber {
+    pub fn try_new() -> DaftResult<Option<Self>> {
+        let Ok(location) = std::env::var(QUERY_LOG_ENV_VAR) else {
+            return Ok(None);
+        };
+        Self::try_new_with_location(&location).map(Some)
+    }
+
+    pub fn try_new_with_location(location: &str) -> DaftResult<Self> {
+        let sink = match location.split_once("://") {
+            Some((scheme, _)) if scheme != "file" => QueryLogSink::Remote {
+                prefix: location.to_string(),
+                io_client: get_io_client(true, Arc::new(IOConfig::default()))?,
+            },
+            _ => {
+                let path = location.strip_prefix("file://").unwrap_or(location);
+                let file = OpenOptions::new().create(true).append(true).open(path)?;
+                QueryLogSink::File(Mutex::new(file))
+            }
+        };
+        Ok(Self {
+            sink,
+            queries: DashMap::new(),
+        })
+    }
+
+    fn query_not_found(query_id: &QueryID) -> DaftError {
+        DaftError::ValueError(format!(
+            "Query `{}` not started or already ended in QueryLogSubscriber",
+            query_id
+        ))
+    }
+}
+
+#[async_trait]
+impl Subscriber for QueryLogSubscriber {
+    fn on_query_start(&self, query_id: QueryID, metadata: Arc<QueryMetadata>) -> DaftResult<()> {
+        let config = metadata
+            .execution_config
+            .as_ref()
+            .and_then(|config| serde_json::to_value(config.as_ref()).ok());
+
+        self.queries.insert(
+            query_id,
+            InFlightQuery {
+                fingerprint: plan_fingerprint(&metadata.unoptimized_plan),
+                start_time: SystemTime::now(),
+                started: Instant::now(),
+                optimization_started: None,
+                planning_time_ms: None,
+                rows_out: 0,
+                bytes_out: 0,
+                peak_memory_bytes: None,
+                config,
+            },
+        );
+        Ok(())
+    }
+
+    fn on_query_end(&self, query_id: QueryID, result: QueryResult) -> DaftResult<()> {
+        let Some((_, query)) = self.queries.remove(&query_id) else {
+            return Err(Self::query_not_found(&query_id));
+        };
+
+        let record = QueryLogRecord {
+            query_id: query_id.to_string(),
+            fingerprint: query.fingerprint,
+            start_time_ms: millis_since_epoch(query.start_time),
+            end_time_ms: millis_since_epoch(SystemTime::now()),
+            wall_time_ms: query.started.elapsed().as_millis() as u64,
+            planning_time_ms: query.planning_time_ms,
+            rows_out: query.rows_out,
+            bytes_out: query.bytes_out,
+            peak_memory_bytes: query.peak_memory_bytes,
+            end_state: result.end_state,
+            error_message: result.error_message,
+            config: query.config,
+        };
+
+        // Failing to log a query should never fail the query itself.
+        if let Err(e) = self.sink.write(&record) {
+            log::warn!("Failed to write query log record for `{}`: {}", query_id, e);
+        }
+        Ok(())
+    }
+
+    fn on_result_out(&self, query_id: QueryID, result: MicroPartitionRef) -> DaftResult<()> {
+        let Some(mut query) = self.queries.get_mut(&query_id) else {
+            return Err(Self::query_not_found(&query_id));
+        };
+        query.rows_out += result.len();
+        query.bytes_out += result.size_bytes();
+        Ok(())
+    }
+
+    fn on_optimization_start(&self, query_id: QueryID) -> DaftResult<()> {
+        if let Some(mut query) = self.queries.get_mut(&query_id) {
+            query.optimization_started = Some(Instant::now());
+        }
+        Ok(())
+    }
+
+    fn on_optimization_end(&self, query_id: QueryID, _optimized_plan: QueryPlan) -> DaftResult<()> {
+        if let Some(mut query) = self.queries.get_mut(&query_id) {
+            query.planning_time_ms = query
+                .optimization_started
+                .map(|started| started.elapsed().as_millis() as u64);
+        }
+        Ok(())
+    }
+
+    fn on_exec_start(&self, _query_id: QueryID, _physical_plan: QueryPlan) -> DaftResult<()> {
+        Ok(())
+    }
+
+    async fn on_exec_operator_start(&self, _query_id: QueryID, _node_id: NodeID) -> DaftResult<()> {
+        Ok(())
+    }
+
+    async fn on_exec_emit_stats(
+        &self,
+        _query_id: QueryID,
+        _stats: &[(NodeID, StatSnapshot)],
+    ) -> DaftResult<()> {
+        Ok(())
+    }
+
+    async fn on_exec_operator_end(&self, _query_id: QueryID, _node_id: NodeID) -> DaftResult<()> {
+        Ok(())
+    }
+
+    async fn on_exec_end(&self, _query_id: QueryID) -> DaftResult<()> {
+        Ok(())
+    }
+
+    async fn on_exec_memory_usage(&self, query_id: QueryID, bytes: u64) -> DaftResult<()> {
+        if let Some(mut query) = self.queries.get_mut(&query_id) {
+            query.peak_memory_bytes = Some(query.peak_memory_bytes.unwrap_or(0).max(bytes));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use common_metrics::QueryEndState;
+    use common_runtime::get_io_runtime;
+    use daft_core::prelude::Schema;
+
+    use super::{QueryLogRecord, QueryLogSubscriber, plan_fingerprint};
+    use crate::subscribers::{QueryMetadata, QueryResult, Subscriber};
+
+    #[test]
+    fn test_query_log_appends_records() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("query_log.jsonl");
+        let subscriber = QueryLogSubscriber::try_new_with_location(path.to_str().unwrap())?;
+
+        for (query_id, error) in [("q1", None), ("q2", Some("boom".to_string()))] {
+            subscriber.on_query_start(
+                query_id.into(),
+                Arc::new(QueryMetadata {
+                    output_schema: Arc::new(Schema::empty()),
+                    unoptimized_plan: "{}".into(),
+                    execution_config: Some(Arc::new(DaftExecutionConfig::default())),
+                }),
+            )?;
+            if error.is_none() {
+                get_io_runtime(true).block_on_current_thread(async {
+                    for bytes in [100, 300, 200] {
+                        subscriber
+                            .on_exec_memory_usage(query_id.into(), bytes)
+                            .await?;
+                    }
+                    DaftResult::Ok(())
+                })?;
+            }
+            subscriber.on_query_end(
+                query_id.into(),
+                QueryResult {
+                    end_state: if error.is_some() {
+                        QueryEndState::Failed
+                    } else {
+                        QueryEndState::Finished
+                    },
+                    error_message: error,
+                },
+            )?;
+        }
+
+        let records = std::fs::read_to_string(&path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<QueryLogRecord>, _>>()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].query_id, "q1");
+        assert_eq!(records[0].fingerprint, plan_fingerprint("{}"));
+        assert_eq!(
+            records[0].config,
+            Some(serde_json::to_value(DaftExecutionConfig::default())?)
+        );
+        assert_eq!(records[0].peak_memory_bytes, Some(300));
+        assert_eq!(records[1].peak_memory_bytes, None);
+        assert_eq!(records[1].end_state, QueryEndState::Failed);
+        assert_eq!(records[1].error_message.as_deref(), Some("boom"));
+        Ok(())
+    }
+}
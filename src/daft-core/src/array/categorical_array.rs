@@ -0,0 +1,337 @@
+//! Categorical arrays, which store strings as [`u32`] codes into a dictionary of the distinct strings.
+//!
+//! The dictionary is shared by every categorical array in the process, so equal strings have equal codes in all of
+//! them. Categorical arrays are therefore concatenated, filtered, grouped and joined by their codes without merging
+//! any dictionaries, and compared for equality by their codes rather than their strings.
+//!
+//! Codes only mean something in the process that assigned them. Categorical arrays are exported to Arrow, which is
+//! how they're serialized and shuffled between workers, as dictionary arrays holding only the strings of their own
+//! partition, and the dictionary of an imported array is merged into the dictionary of the process that reads it.
+//! Hashes and orderings are computed from the strings too, so that they agree across processes.
+
+use std::sync::{Arc, LazyLock, RwLock};
+
+use arrow::array::{ArrayRef, AsArray};
+use common_error::{DaftError, DaftResult};
+use daft_hash::HashFunctionKind;
+use fnv::FnvHashMap;
+
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{
+        DataType, Field, FieldRef, Int32Array, UInt32Array, UInt64Array, Utf8Array,
+        logical::CategoricalArray,
+    },
+};
+
+static DICTIONARY: LazyLock<RwLock<CategoricalDictionary>> = LazyLock::new(Default::default);
+
+/// The distinct strings of every categorical array in the process, where the code of a string is its index.
+#[derive(Default)]
+struct CategoricalDictionary {
+    values: Vec<Arc<str>>,
+    codes: FnvHashMap<Arc<str>, u32>,
+}
+
+impl CategoricalDictionary {
+    fn code(&mut self, value: &str) -> DaftResult<u32> {
+        if let Some(code) = self.codes.get(value) {
+            return Ok(*code);
+        }
+        let code = u32::try_from(self.values.len()).map_err(|_| {
+            DaftError::ComputeError(
+                "Too many distinct categories, at most 2^32 are supported".to_string(),
+            )
+        })?;
+        let value: Arc<str> = value.into();
+        self.values.push(value.clone());
+        self.codes.insert(value, code);
+        Ok(code)
+    }
+}
+
+/// Returns the codes of `values`, adding the strings which aren't in the dictionary yet.
+fn codes_of(values: &[&str]) -> DaftResult<Vec<u32>> {
+    {
+        let dictionary = DICTIONARY.read().unwrap();
+        let codes = values
+            .iter()
+            .map(|value| dictionary.codes.get(*value).copied())
+            .collect::<Option<Vec<_>>>();
+        if let Some(codes) = codes {
+            return Ok(codes);
+        }
+    }
+    let mut dictionary = DICTIONARY.write().unwrap();
+    values.iter().map(|value| dictionary.code(value)).collect()
+}
+
+/// Returns the codes of the rows of a dictionary-encoded array, given the index of each row into its dictionary.
+///
+/// Only the strings of the dictionary are interned, rather than the string of each row.
+fn codes_from_dictionary<'a>(
+    name: &str,
+    keys: impl Iterator<Item = Option<usize>>,
+    dictionary: impl Iterator<Item = Option<&'a str>>,
+) -> DaftResult<UInt32Array> {
+    let dictionary = dictionary.collect::<Vec<_>>();
+    let non_null = dictionary.iter().flatten().copied().collect::<Vec<_>>();
+    let mut non_null_codes = codes_of(&non_null)?.into_iter();
+    let dictionary_codes = dictionary
+        .iter()
+        .map(|value| value.and_then(|_| non_null_codes.next()))
+        .collect::<Vec<_>>();
+    UInt32Array::from_regular_iter(
+        Field::new(name, DataType::UInt32),
+        keys.map(|key| key.and_then(|key| dictionary_codes.get(key).copied().flatten())),
+    )
+}
+
+/// Returns the index of each code among the distinct codes in `codes`, along with the strings of the distinct codes,
+/// which together are the dictionary encoding of `codes` with a dictionary of their own.
+fn local_dictionary(codes: impl Iterator<Item = Option<u32>>) -> (Vec<Option<u32>>, Vec<Arc<str>>) {
+    let dictionary = DICTIONARY.read().unwrap();
+    let mut local_codes = FnvHashMap::<u32, u32>::default();
+    let mut values = Vec::new();
+    let keys = codes
+        .map(|code| {
+            code.map(|code| {
+                *local_codes.entry(code).or_insert_with(|| {
+                    values.push(dictionary.values[code as usize].clone());
+                    (values.len() - 1) as u32
+                })
+            })
+        })
+        .collect();
+    (keys, values)
+}
+
+/// Converts the codes of a categorical array into a dictionary array of its strings.
+pub(crate) fn codes_to_arrow2_dictionary(
+    codes: &dyn daft_arrow::array::Array,
+) -> Box<dyn daft_arrow::array::Array> {
+    let codes = codes
+        .as_any()
+        .downcast_ref::<daft_arrow::array::PrimitiveArray<u32>>()
+        .expect("Categorical codes should be u32");
+    let (keys, values) = local_dictionary(codes.iter().map(|code| code.copied()));
+    let values = daft_arrow::array::Utf8Array::<i64>::from_iter_values(values.iter());
+    Box::new(
+        daft_arrow::array::DictionaryArray::try_from_keys(
+            daft_arrow::array::PrimitiveArray::<u32>::from(keys),
+            values.boxed(),
+        )
+        .expect("Keys should index into the dictionary"),
+    )
+}
+
+/// Converts the codes of a categorical array into a dictionary array of its strings.
+pub(crate) fn codes_to_arrow_dictionary(codes: &dyn arrow::array::Array) -> DaftResult<ArrayRef> {
+    let codes = codes.as_primitive::<arrow::datatypes::UInt32Type>();
+    let (keys, values) = local_dictionary(codes.iter());
+    let values = arrow::array::LargeStringArray::from_iter_values(values.iter());
+    Ok(Arc::new(arrow::array::DictionaryArray::try_new(
+        arrow::array::UInt32Array::from(keys),
+        Arc::new(values),
+    )?))
+}
+
+impl CategoricalArray {
+    /// Encodes strings as categories.
+    pub fn from_utf8(array: &Utf8Array) -> DaftResult<Self> {
+        let mut local_codes = FnvHashMap::<&str, usize>::default();
+        let mut distinct = Vec::new();
+        let keys = array
+            .into_iter()
+            .map(|value| {
+                value.map(|value| {
+                    *local_codes.entry(value).or_insert_with(|| {
+                        distinct.push(value);
+                        distinct.len() - 1
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        let codes = codes_from_dictionary(
+            array.name(),
+            keys.into_iter(),
+            distinct.into_iter().map(Some),
+        )?;
+        Ok(Self::new(
+            Field::new(array.name(), DataType::Categorical),
+            codes,
+        ))
+    }
+
+    /// Wraps codes which were assigned by this process, such as the physical values of another categorical array.
+    pub fn from_codes(codes: UInt32Array) -> DaftResult<Self> {
+        let num_categories = DICTIONARY.read().unwrap().values.len();
+        if let Some(code) = codes
+            .as_arrow2()
+            .iter()
+            .flatten()
+            .find(|code| **code as usize >= num_categories)
+        {
+            return Err(DaftError::ValueError(format!(
+                "Invalid categorical code {code}, only {num_categories} categories exist"
+            )));
+        }
+        Ok(Self::new(
+            Field::new(codes.name(), DataType::Categorical),
+            codes,
+        ))
+    }
+
+    /// Imports an Arrow array of strings, which keeps the dictionary of a dictionary array rather than decoding it.
+    pub(crate) fn from_arrow2_strings(
+        field: FieldRef,
+        array: &dyn daft_arrow::array::Array,
+    ) -> DaftResult<Self> {
+        let array = daft_arrow::compute::cast::cast(
+            array,
+            &DataType::Categorical.to_arrow2()?,
+            daft_arrow::compute::cast::CastOptions {
+                wrapped: true,
+                partial: false,
+            },
+        )?;
+        let array = array
+            .as_any()
+            .downcast_ref::<daft_arrow::array::DictionaryArray<u32>>()
+            .expect("Strings should have been cast to a dictionary array");
+        let values = array
+            .values()
+            .as_any()
+            .downcast_ref::<daft_arrow::array::Utf8Array<i64>>()
+            .expect("Dictionary values should have been cast to strings");
+        let codes = codes_from_dictionary(
+            &field.name,
+            array.keys().iter().map(|key| key.map(|key| *key as usize)),
+            values.iter(),
+        )?;
+        Ok(Self::new(field, codes))
+    }
+
+    /// Imports an Arrow array of strings, which keeps the dictionary of a dictionary array rather than decoding it.
+    pub(crate) fn from_arrow_strings(
+        field: FieldRef,
+        array: &dyn arrow::array::Array,
+    ) -> DaftResult<Self> {
+        let array = arrow::compute::cast(array, &DataType::Categorical.to_arrow()?)?;
+        let array = array.as_dictionary::<arrow::datatypes::UInt32Type>();
+        let codes = codes_from_dictionary(
+            &field.name,
+            array.keys().iter().map(|key| key.map(|key| key as usize)),
+            array.values().as_string::<i64>().iter(),
+        )?;
+        Ok(Self::new(field, codes))
+    }
+
+    /// Decodes the categories into strings.
+    pub fn to_utf8(&self) -> DaftResult<Utf8Array> {
+        let dictionary = DICTIONARY.read().unwrap();
+        Ok(Utf8Array::from_iter(
+            self.name(),
+            self.physical
+                .as_arrow2()
+                .iter()
+                .map(|code| code.map(|code| dictionary.values[*code as usize].as_ref())),
+        ))
+    }
+
+    /// Returns the category of a row.
+    pub fn get(&self, idx: usize) -> Option<Arc<str>> {
+        let code = self.physical.get(idx)?;
+        Some(DICTIONARY.read().unwrap().values[code as usize].clone())
+    }
+
+    pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        self.hash_with(seed, HashFunctionKind::XxHash3_64)
+    }
+
+    /// Hashes the categories as strings, since codes differ between processes, and each distinct category is only
+    /// hashed once.
+    pub fn hash_with(
+        &self,
+        seed: Option<&UInt64Array>,
+        hash_function: HashFunctionKind,
+    ) -> DaftResult<UInt64Array> {
+        if seed.is_some() {
+            return self.to_utf8()?.hash_with(seed, hash_function);
+        }
+        let (keys, values) = local_dictionary(self.physical.as_arrow2().iter().map(|c| c.copied()));
+        // Nulls are hashed as the last entry of the dictionary, so that they hash the same as null strings.
+        let null_key = values.len() as u64;
+        let values = values
+            .iter()
+            .map(|value| Some(value.as_ref()))
+            .chain(std::iter::once(None))
+            .collect::<Vec<_>>();
+        let hashes =
+            Utf8Array::from_iter(self.name(), values.into_iter()).hash_with(None, hash_function)?;
+        let keys = keys
+            .into_iter()
+            .map(|key| key.map_or(null_key, u64::from))
+            .collect::<Vec<_>>();
+        hashes.take(&UInt64Array::from((self.name(), keys)))
+    }
+
+    pub fn murmur3_32(&self) -> DaftResult<Int32Array> {
+        self.to_utf8()?.murmur3_32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, logical::CategoricalArray},
+        prelude::{FromArrow, Utf8Array},
+    };
+
+    #[test]
+    fn test_categorical_roundtrip() -> DaftResult<()> {
+        let strings =
+            Utf8Array::from_iter("s", [Some("b"), None, Some("a"), Some("b")].into_iter());
+        let categories = CategoricalArray::from_utf8(&strings)?;
+        assert_eq!(categories.physical.get(0), categories.physical.get(3));
+        assert_ne!(categories.physical.get(0), categories.physical.get(2));
+        assert_eq!(categories.physical.get(1), None);
+        assert_eq!(categories.to_utf8()?, strings);
+
+        // Other arrays with the same strings get the same codes, so they're compared without merging dictionaries.
+        let other = CategoricalArray::from_utf8(&Utf8Array::from_values("s", ["a"].into_iter()))?;
+        assert_eq!(other.physical.get(0), categories.physical.get(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_categorical_arrow_dictionary() -> DaftResult<()> {
+        let strings =
+            Utf8Array::from_iter("s", [Some("x"), Some("y"), None, Some("x")].into_iter());
+        let categories = CategoricalArray::from_utf8(&strings)?;
+
+        // Exported arrays only hold the strings of their own rows.
+        let exported = categories.to_arrow()?;
+        let dictionary = exported
+            .as_any()
+            .downcast_ref::<arrow::array::DictionaryArray<arrow::datatypes::UInt32Type>>()
+            .unwrap();
+        assert_eq!(dictionary.values().len(), 2);
+
+        let imported =
+            CategoricalArray::from_arrow(Field::new("s", DataType::Categorical), exported)?;
+        assert_eq!(imported.physical, categories.physical);
+        Ok(())
+    }
+
+    #[test]
+    fn test_categorical_hash_matches_strings() -> DaftResult<()> {
+        let strings =
+            Utf8Array::from_iter("s", [Some("x"), None, Some("y"), Some("x")].into_iter());
+        let categories = CategoricalArray::from_utf8(&strings)?;
+        assert_eq!(categories.hash(None)?, strings.hash(None)?);
+        Ok(())
+    }
+}
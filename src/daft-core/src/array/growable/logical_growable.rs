@@ -73,6 +73,7 @@ macro_rules! impl_logical_growable {
 
 impl_logical_growable!(LogicalTimestampGrowable, TimestampType);
 impl_logical_growable!(LogicalDurationGrowable, DurationType);
+impl_logical_growable!(LogicalCategoricalGrowable, CategoricalType);
 impl_logical_growable!(LogicalDateGrowable, DateType);
 impl_logical_growable!(LogicalTimeGrowable, TimeType);
 impl_logical_growable!(LogicalEmbeddingGrowable, EmbeddingType);
@@ -178,6 +178,10 @@ impl_growable_array!(
     logical_growable::LogicalTimestampGrowable<'a>
 );
 impl_growable_array!(DurationArray, logical_growable::LogicalDurationGrowable<'a>);
+impl_growable_array!(
+    CategoricalArray,
+    logical_growable::LogicalCategoricalGrowable<'a>
+);
 
 impl_growable_array!(
     IntervalArray,
@@ -134,6 +134,12 @@ pub fn build_multi_array_is_equal(
     let mut fn_list = Vec::with_capacity(left.len());
 
     for (idx, (l, r)) in left.iter().zip(right.iter()).enumerate() {
+        // Equal categories have equal codes, which are much cheaper to compare than their strings.
+        let (l, r) = if l.data_type().is_categorical() && r.data_type().is_categorical() {
+            (l.as_physical()?, r.as_physical()?)
+        } else {
+            (l.clone(), r.clone())
+        };
         fn_list.push(build_is_equal(
             l.to_arrow2().as_ref(),
             r.to_arrow2().as_ref(),
@@ -31,7 +31,7 @@ use crate::{
         DaftArrayType, DaftArrowBackedType, DataType, Field, FileArray, ImageMode, Int64Array,
         NullArray, TimeUnit, UInt64Array, Utf8Array,
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
@@ -80,6 +80,19 @@ where
             DataType::Python => {
                 Series::try_from((self.name(), self.data.clone()))?.cast_to_python()
             }
+            DataType::Categorical => {
+                let series = self.clone().into_series();
+                match self.data_type() {
+                    DataType::Utf8 => {
+                        Ok(CategoricalArray::from_utf8(series.utf8()?)?.into_series())
+                    }
+                    // Like other logical types, casting from the physical type reinterprets the codes.
+                    DataType::UInt32 => {
+                        Ok(CategoricalArray::from_codes(series.u32()?.clone())?.into_series())
+                    }
+                    _ => series.cast(&DataType::Utf8)?.cast(dtype),
+                }
+            }
             _ => {
                 // Cast from DataArray to the target DataType
                 // by using Arrow's casting mechanisms.
@@ -359,6 +372,22 @@ impl TimeArray {
     }
 }
 
+impl CategoricalArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Null => {
+                Ok(NullArray::full_null(self.name(), dtype, self.len()).into_series())
+            }
+            dtype if dtype == self.data_type() => Ok(self.clone().into_series()),
+            DataType::UInt32 => Ok(self.physical.clone().into_series()),
+            DataType::Utf8 => Ok(self.to_utf8()?.into_series()),
+            #[cfg(feature = "python")]
+            DataType::Python => self.clone().into_series().cast_to_python(),
+            _ => self.to_utf8()?.cast(dtype),
+        }
+    }
+}
+
 impl DurationArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match dtype {
@@ -528,6 +528,29 @@ impl FromArrow for LogicalArray<TimeType> {
         Ok(Self::new(field, physical))
     }
 }
+impl FromArrow for LogicalArray<CategoricalType> {
+    fn from_arrow2(
+        field: FieldRef,
+        arrow_arr: Box<dyn daft_arrow::array::Array>,
+    ) -> DaftResult<Self> {
+        // Physical arrays already hold the codes of this process, rather than strings.
+        if arrow_arr.data_type() == &daft_arrow::datatypes::DataType::UInt32 {
+            let physical = UInt32Array::from_arrow2(Arc::new(field.to_physical()), arrow_arr)?;
+            return Ok(Self::new(field, physical));
+        }
+        Self::from_arrow2_strings(field, arrow_arr.as_ref())
+    }
+
+    fn from_arrow<F: Into<FieldRef>>(field: F, arrow_arr: ArrayRef) -> DaftResult<Self> {
+        let field: FieldRef = field.into();
+        if arrow_arr.data_type() == &arrow::datatypes::DataType::UInt32 {
+            let physical = UInt32Array::from_arrow(Arc::new(field.to_physical()), arrow_arr)?;
+            return Ok(Self::new(field, physical));
+        }
+        Self::from_arrow_strings(field, arrow_arr.as_ref())
+    }
+}
+
 impl_logical_from_arrow!(DurationType);
 impl_logical_from_arrow!(ImageType);
 impl_logical_from_arrow!(TimestampType);
@@ -54,6 +54,15 @@ impl StructArray {
     }
 }
 
+impl CategoricalArray {
+    pub fn get_lit(&self, idx: usize) -> Literal {
+        match self.get(idx) {
+            Some(v) => Literal::Utf8(v.to_string()),
+            None => Literal::Null,
+        }
+    }
+}
+
 impl TensorArray {
     pub fn get_lit(&self, idx: usize) -> Literal {
         assert!(
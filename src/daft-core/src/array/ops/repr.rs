@@ -10,7 +10,7 @@ use crate::{
         FileArray, FixedSizeBinaryArray, IntervalArray, IntervalValue, NullArray, UInt64Array,
         Utf8Array,
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
@@ -219,6 +219,14 @@ impl DurationArray {
     }
 }
 
+impl CategoricalArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        Ok(self
+            .get(idx)
+            .map_or_else(|| "None".to_string(), |val| val.to_string()))
+    }
+}
+
 impl IntervalArray {
     pub fn str_value(&self, idx: usize) -> DaftResult<String> {
         let res = self.get(idx).map_or_else(
@@ -533,6 +541,7 @@ impl_array_html_value!(Decimal128Array);
 impl_array_html_value!(DateArray);
 impl_array_html_value!(TimeArray);
 impl_array_html_value!(DurationArray);
+impl_array_html_value!(CategoricalArray);
 impl_array_html_value!(IntervalArray);
 impl_array_html_value!(TimestampArray);
 impl_array_html_value!(EmbeddingArray);
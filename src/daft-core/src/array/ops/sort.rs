@@ -14,7 +14,7 @@ use crate::{
         ExtensionArray, FileArray, FixedSizeBinaryArray, Float32Array, Float64Array, IntervalArray,
         NullArray, Utf8Array,
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
@@ -683,6 +683,14 @@ impl TimeArray {
     }
 }
 
+impl CategoricalArray {
+    pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        // Categories are sorted by their strings, since codes are assigned in the order categories are first seen.
+        let indices = self.to_utf8()?.argsort(descending, nulls_first)?;
+        self.take(&indices)
+    }
+}
+
 impl DurationArray {
     pub fn sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
         let new_array = self.physical.sort(descending, nulls_first)?;
@@ -1,7 +1,11 @@
 use arrow::datatypes::ArrowNativeType;
 use common_error::{DaftError, DaftResult};
+use fnv::FnvHashMap;
 
-use crate::prelude::{AsArrow, Utf8Array};
+use crate::prelude::{AsArrow, UInt32Array, Utf8Array};
+
+/// The number of strings seen before [`Utf8Array::intern`] checks whether the strings are worth interning.
+const INTERN_SAMPLE_SIZE: usize = 1024;
 
 impl Utf8Array {
     /// Convert the Utf8Array into Vec<String>
@@ -29,11 +33,32 @@ impl Utf8Array {
             })
             .collect::<Vec<String>>())
     }
+
+    /// Interns the strings of this array, returning a code for each string such that equal strings have equal codes,
+    /// and nulls share a code of their own.
+    ///
+    /// Interning is only worth it for low-cardinality strings, whose codes are compared and hashed much faster than the
+    /// strings themselves. Once at least [`INTERN_SAMPLE_SIZE`] strings are seen, this gives up and returns None as
+    /// soon as more than half of the strings seen are distinct.
+    #[must_use]
+    pub fn intern(&self) -> Option<UInt32Array> {
+        let mut codes_by_value = FnvHashMap::<Option<&str>, u32>::default();
+        let mut codes = Vec::with_capacity(self.len());
+        for (i, value) in self.into_iter().enumerate() {
+            let next_code = codes_by_value.len() as u32;
+            codes.push(*codes_by_value.entry(value).or_insert(next_code));
+            let seen = i + 1;
+            if seen >= INTERN_SAMPLE_SIZE && codes_by_value.len() * 2 > seen {
+                return None;
+            }
+        }
+        Some(UInt32Array::from((self.name(), codes)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::Utf8Array;
+    use crate::prelude::{AsArrow, Utf8Array};
 
     #[test]
     fn test_into_values() {
@@ -53,4 +78,26 @@ mod tests {
                 .contains("Utf8Array::into_values with nulls")
         );
     }
+
+    #[test]
+    fn test_intern() {
+        let array = Utf8Array::from_iter(
+            "test",
+            vec![Some("a"), Some("b"), None, Some("a"), None].into_iter(),
+        );
+        let codes = array.intern().unwrap();
+        assert_eq!(codes.name(), "test");
+        assert_eq!(codes.as_arrow2().values().as_slice(), &[0, 1, 2, 0, 2]);
+    }
+
+    #[test]
+    fn test_intern_gives_up_on_high_cardinality() {
+        let values = (0..2048).map(|i| i.to_string()).collect::<Vec<_>>();
+        let array = Utf8Array::from_values("test", values.iter());
+        assert!(array.intern().is_none());
+
+        let values = (0..2048).map(|i| (i % 10).to_string()).collect::<Vec<_>>();
+        let array = Utf8Array::from_values("test", values.iter());
+        assert!(array.intern().is_some());
+    }
 }
@@ -117,6 +117,11 @@ impl InferDataType<'_> {
         let left = &self.0;
         let other = &other.0;
         let evaluator = || match (left, other) {
+            // Codes aren't ordered like the strings they encode, so categoricals are compared as strings.
+            (DataType::Categorical, DataType::Categorical | DataType::Utf8 | DataType::Null)
+            | (DataType::Utf8 | DataType::Null, DataType::Categorical) => {
+                Ok((DataType::Boolean, Some(DataType::Utf8), DataType::Utf8))
+            }
             (s, o) if s == o => Ok((DataType::Boolean, None, s.to_physical())),
             (DataType::Utf8, o) | (o, DataType::Utf8) if o.is_numeric() => {
                 Err(DaftError::TypeError(format!(
@@ -4,7 +4,7 @@ use arrow::array::ArrayRef;
 use common_error::DaftResult;
 
 use super::{
-    DaftArrayType, DaftDataType, DataArray, DataType, DurationType, EmbeddingType,
+    CategoricalType, DaftArrayType, DaftDataType, DataArray, DataType, DurationType, EmbeddingType,
     FixedShapeImageType, FixedShapeSparseTensorType, FixedShapeTensorType, FixedSizeListArray,
     ImageType, MapType, SparseTensorType, TensorType, TimeType, TimestampType,
 };
@@ -99,6 +99,10 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, DataArray<L::PhysicalType>> {
         let physical_arrow_array = self.physical.data();
         use crate::datatypes::DataType::*;
         match daft_type {
+            // Codes only mean something in this process, so they're exported along with the strings they stand for.
+            Categorical => {
+                crate::array::categorical_array::codes_to_arrow2_dictionary(physical_arrow_array)
+            }
             // For wrapped primitive types, switch the datatype label on the arrow2 Array.
             Decimal128(..) | Date | Timestamp(..) | Duration(..) | Time(..) => {
                 with_match_daft_logical_primitive_types!(daft_type, |$P| {
@@ -126,8 +130,11 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, DataArray<L::PhysicalType>> {
     }
 
     pub fn to_arrow(&self) -> DaftResult<ArrayRef> {
-        let arrow_field = self.field().to_arrow()?;
         let physical = arrow::array::make_array(self.physical.to_data());
+        if self.data_type().is_categorical() {
+            return crate::array::categorical_array::codes_to_arrow_dictionary(physical.as_ref());
+        }
+        let arrow_field = self.field().to_arrow()?;
 
         Ok(arrow::compute::cast(
             physical.as_ref(),
@@ -264,6 +271,7 @@ pub type LogicalArray<L> =
     LogicalArrayImpl<L, <<L as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType>;
 // pub type Decimal128Array = LogicalArray<Decimal128Type>;
 pub type DateArray = LogicalArray<DateType>;
+pub type CategoricalArray = LogicalArray<CategoricalType>;
 pub type TimeArray = LogicalArray<TimeType>;
 pub type DurationArray = LogicalArray<DurationType>;
 pub type ImageArray = LogicalArray<ImageType>;
@@ -14,6 +14,7 @@ macro_rules! with_match_daft_types {
             // Float16 => unimplemented!("Array for Float16 DataType not implemented"),
             DataType::Binary => __with_ty__! { BinaryType },
             DataType::Boolean => __with_ty__! { BooleanType },
+            DataType::Categorical => __with_ty__! { CategoricalType },
             DataType::Date => __with_ty__! { DateType },
             DataType::Decimal128(..) => __with_ty__! { Decimal128Type },
             DataType::Duration(_) => __with_ty__! { DurationType },
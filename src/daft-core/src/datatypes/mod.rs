@@ -248,6 +248,7 @@ impl_nested_datatype!(ListType, ListArray);
 
 impl_daft_logical_data_array_datatype!(TimestampType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(DateType, Date, Int32Type);
+impl_daft_logical_data_array_datatype!(CategoricalType, Categorical, UInt32Type);
 impl_daft_logical_data_array_datatype!(TimeType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(DurationType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(ImageType, Unknown, StructType);
@@ -20,7 +20,7 @@ pub use super::{
     DaftNumericType, DaftPhysicalType,
 };
 pub use crate::datatypes::{
-    DateType, Decimal128Type, DurationType, EmbeddingType, FixedShapeImageType,
+    CategoricalType, DateType, Decimal128Type, DurationType, EmbeddingType, FixedShapeImageType,
     FixedShapeSparseTensorType, FixedShapeTensorType, ImageType, IntervalType, MapType,
     SparseTensorType, TensorType, TimeType, TimestampType, logical::DaftImageryType,
 };
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::datatypes::DataType;
+
+/// How the values of the rows which fill the gaps of a resampled time series are filled.
+///
+/// | Forward  - Take the values of the last row before the gap.
+/// | Backward - Take the values of the first row after the gap.
+/// | Linear   - Interpolate numeric values linearly between the rows around the gap, by their timestamps.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub enum GapFill {
+    #[display("forward")]
+    Forward,
+    #[display("backward")]
+    Backward,
+    #[display("linear")]
+    Linear,
+}
+
+impl GapFill {
+    pub fn iterator() -> std::slice::Iter<'static, Self> {
+        static GAP_FILLS: [GapFill; 3] = [GapFill::Forward, GapFill::Backward, GapFill::Linear];
+        GAP_FILLS.iter()
+    }
+
+    /// Returns the type of a column whose gaps are filled, which is Float64 for integer columns which are
+    /// interpolated.
+    pub fn filled_dtype(&self, dtype: &DataType) -> DataType {
+        match self {
+            Self::Linear if dtype.is_integer() => DataType::Float64,
+            _ => dtype.clone(),
+        }
+    }
+}
+
+impl FromStr for GapFill {
+    type Err = DaftError;
+
+    fn from_str(gap_fill: &str) -> DaftResult<Self> {
+        match gap_fill.to_lowercase().as_str() {
+            "forward" => Ok(Self::Forward),
+            "backward" => Ok(Self::Backward),
+            "linear" => Ok(Self::Linear),
+            _ => Err(DaftError::ValueError(format!(
+                "Gap fill {} is not supported; only the following fills are supported: {:?}",
+                gap_fill,
+                Self::iterator().as_slice()
+            ))),
+        }
+    }
+}
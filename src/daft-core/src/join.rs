@@ -92,6 +92,8 @@ pub enum JoinStrategy {
     Hash,
     SortMerge,
     Broadcast,
+    /// Binary searches the sorted keys of the right side for the keys of each left row.
+    SortedLookup,
     /// only used internally, do not let users to specify
     Cross,
 }
@@ -116,10 +118,11 @@ impl_bincode_py_state_serialization!(JoinStrategy);
 
 impl JoinStrategy {
     pub fn iterator() -> std::slice::Iter<'static, Self> {
-        static JOIN_STRATEGIES: [JoinStrategy; 3] = [
+        static JOIN_STRATEGIES: [JoinStrategy; 4] = [
             JoinStrategy::Hash,
             JoinStrategy::SortMerge,
             JoinStrategy::Broadcast,
+            JoinStrategy::SortedLookup,
         ];
         JOIN_STRATEGIES.iter()
     }
@@ -133,6 +136,7 @@ impl FromStr for JoinStrategy {
             "hash" => Ok(Self::Hash),
             "sort_merge" => Ok(Self::SortMerge),
             "broadcast" => Ok(Self::Broadcast),
+            "sorted_lookup" => Ok(Self::SortedLookup),
             _ => Err(DaftError::TypeError(format!(
                 "Join strategy {} is not supported; only the following strategies are supported: {:?}",
                 join_strategy,
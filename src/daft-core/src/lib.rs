@@ -6,6 +6,7 @@ pub mod array;
 pub mod count_mode;
 pub mod datatypes;
 pub mod file;
+pub mod gap_fill;
 pub mod join;
 pub mod kernels;
 pub mod lit;
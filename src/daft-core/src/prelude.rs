@@ -12,6 +12,8 @@ pub use daft_schema::schema::{Schema, SchemaRef};
 pub use crate::count_mode::CountMode;
 // Re-export common data types and arrays
 pub use crate::datatypes::prelude::*;
+// Re-export the gap fills of resampling
+pub use crate::gap_fill::GapFill;
 // Re-export join-related types
 pub use crate::join::{JoinStrategy, JoinType};
 pub use crate::{
@@ -136,6 +136,14 @@ macro_rules! impl_series_like_for_logical_array {
 
             fn min(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
                 use crate::array::ops::DaftCompareAggable;
+                // Categories are ordered by their strings rather than their codes.
+                if self.0.data_type().is_categorical() {
+                    return self
+                        .0
+                        .cast(&DataType::Utf8)?
+                        .min(groups)?
+                        .cast(self.data_type());
+                }
                 let data_array = match groups {
                     Some(groups) => DaftCompareAggable::grouped_min(&self.0.physical, groups)?,
                     None => DaftCompareAggable::min(&self.0.physical)?,
@@ -144,6 +152,13 @@ macro_rules! impl_series_like_for_logical_array {
             }
             fn max(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
                 use crate::array::ops::DaftCompareAggable;
+                if self.0.data_type().is_categorical() {
+                    return self
+                        .0
+                        .cast(&DataType::Utf8)?
+                        .max(groups)?
+                        .cast(self.data_type());
+                }
                 let data_array = match groups {
                     Some(groups) => DaftCompareAggable::grouped_max(&self.0.physical, groups)?,
                     None => DaftCompareAggable::max(&self.0.physical)?,
@@ -193,6 +208,7 @@ macro_rules! impl_series_like_for_logical_array {
 impl_series_like_for_logical_array!(DateArray);
 impl_series_like_for_logical_array!(TimeArray);
 impl_series_like_for_logical_array!(DurationArray);
+impl_series_like_for_logical_array!(CategoricalArray);
 
 impl_series_like_for_logical_array!(TimestampArray);
 impl_series_like_for_logical_array!(ImageArray);
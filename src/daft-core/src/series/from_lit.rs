@@ -32,6 +32,7 @@ use crate::{
 fn downcast_to_lit_compatible(dtype: DataType) -> DaftResult<DataType> {
     Ok(match dtype {
         DataType::FixedSizeBinary(..) => DataType::Binary,
+        DataType::Categorical => DataType::Utf8,
         DataType::FixedSizeList(dtype, _) => DataType::List(dtype),
         DataType::FixedShapeImage(mode, _, _) => DataType::Image(Some(mode)),
         DataType::FixedShapeTensor(dtype, _) => DataType::Tensor(dtype),
@@ -489,6 +490,7 @@ pub fn series_from_literals_iter<I: ExactSizeIterator<Item = DaftResult<Literal>
         | DataType::FixedShapeImage(..)
         | DataType::FixedShapeTensor(..)
         | DataType::FixedShapeSparseTensor(..)
+        | DataType::Categorical
         | DataType::Unknown => unreachable!("Literal should never have data type: {dtype}"),
     };
 
@@ -188,6 +188,16 @@ impl Series {
         }
     }
 
+    /// Like [`Series::as_physical`], but ordered like the logical values, which isn't the case for the codes of
+    /// categoricals, so categoricals are decoded into strings instead.
+    pub fn as_ordered_physical(&self) -> DaftResult<Self> {
+        if self.data_type().is_categorical() {
+            self.cast(&DataType::Utf8)
+        } else {
+            self.as_physical()
+        }
+    }
+
     pub fn to_comfy_table(&self) -> comfy_table::Table {
         let field = self.field();
         let field_disp = format!("{}\n---\n{}", field.name, field.dtype);
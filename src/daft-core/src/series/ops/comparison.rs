@@ -12,9 +12,31 @@ use crate::{
     with_match_comparable_daft_types,
 };
 
+/// Compares categoricals by their codes, which is only valid for equality since codes aren't ordered like their
+/// strings. Strings are only encoded when they're a single value, to avoid growing the dictionary with every string of
+/// a column.
+fn categorical_codes(lhs: &Series, rhs: &Series) -> DaftResult<Option<(Series, Series)>> {
+    let encodable = |s: &Series| {
+        s.data_type().is_categorical() || (s.data_type() == &DataType::Utf8 && s.len() == 1)
+    };
+    if !(lhs.data_type().is_categorical() || rhs.data_type().is_categorical())
+        || !encodable(lhs)
+        || !encodable(rhs)
+    {
+        return Ok(None);
+    }
+    Ok(Some((
+        lhs.cast(&DataType::Categorical)?.as_physical()?,
+        rhs.cast(&DataType::Categorical)?.as_physical()?,
+    )))
+}
+
 macro_rules! impl_compare_method {
-    ($fname:ident, $pyoperator:expr) => {
+    ($fname:ident, $pyoperator:expr, $compare_codes:literal) => {
         fn $fname(&self, rhs: &Series) -> Self::Output {
+            if $compare_codes && let Some((lhs, rhs)) = categorical_codes(self, rhs)? {
+                return lhs.u32()?.$fname(rhs.u32()?);
+            }
             let lhs = self;
             let (output_type, intermediate_type, comparison_type) =
                 InferDataType::from(self.data_type())
@@ -59,11 +81,11 @@ macro_rules! impl_compare_method {
 
 impl DaftCompare<&Self> for Series {
     type Output = DaftResult<BooleanArray>;
-    impl_compare_method!(equal, eq);
-    impl_compare_method!(not_equal, ne);
-    impl_compare_method!(lt, lt);
-    impl_compare_method!(lte, le);
-    impl_compare_method!(gt, gt);
-    impl_compare_method!(gte, ge);
-    impl_compare_method!(eq_null_safe, eq_null_safe);
+    impl_compare_method!(equal, eq, true);
+    impl_compare_method!(not_equal, ne, true);
+    impl_compare_method!(lt, lt, false);
+    impl_compare_method!(lte, le, false);
+    impl_compare_method!(gt, gt, false);
+    impl_compare_method!(gte, ge, false);
+    impl_compare_method!(eq_null_safe, eq_null_safe, true);
 }
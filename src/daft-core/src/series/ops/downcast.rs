@@ -4,7 +4,7 @@ use logical::{
     TensorArray,
 };
 
-use self::logical::{DurationArray, ImageArray, MapArray};
+use self::logical::{CategoricalArray, DurationArray, ImageArray, MapArray};
 #[cfg(feature = "python")]
 use crate::prelude::PythonArray;
 use crate::{
@@ -139,6 +139,10 @@ impl Series {
         self.downcast()
     }
 
+    pub fn categorical(&self) -> DaftResult<&CategoricalArray> {
+        self.downcast()
+    }
+
     pub fn interval(&self) -> DaftResult<&IntervalArray> {
         self.downcast()
     }
@@ -10,6 +10,9 @@ use crate::{
 
 impl Series {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
+        if self.data_type().is_categorical() {
+            return self.categorical()?.hash(seed);
+        }
         let s = self.as_physical()?;
         with_match_hashable_daft_types!(s.data_type(), |$T| {
             let downcasted = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -22,6 +25,9 @@ impl Series {
         seed: Option<&UInt64Array>,
         hash_function: HashFunctionKind,
     ) -> DaftResult<UInt64Array> {
+        if self.data_type().is_categorical() {
+            return self.categorical()?.hash_with(seed, hash_function);
+        }
         let s = self.as_physical()?;
         with_match_hashable_daft_types!(s.data_type(), |$T| {
             let downcasted = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -57,6 +63,7 @@ impl Series {
             Time(..) => self.time()?.murmur3_32(),
             Timestamp(..) => self.timestamp()?.murmur3_32(),
             Decimal128(..) => self.decimal128()?.murmur3_32(),
+            Categorical => self.categorical()?.murmur3_32(),
             v => panic!("murmur3 hash not implemented for datatype: {v}"),
         }
     }
@@ -0,0 +1,145 @@
+use common_error::{DaftError, DaftResult};
+use indexmap::map::RawEntryApiV1;
+
+use crate::{
+    array::ops::{arrow::comparison::build_is_equal, full::FullNull},
+    datatypes::{DataType, Field, UInt64Array},
+    prelude::AsArrow,
+    series::{IntoSeries, Series},
+    utils::supertype::try_get_supertype,
+};
+
+impl Series {
+    /// Map each element of this [`Series`] to the value paired with its key in a small static table.
+    ///
+    /// `keys` and `values` must be the same length, and pair up element-wise. If a key appears more than once,
+    /// its first value is used. Elements with no matching key, as well as `NULL`s, map to `default`, or to `NULL`
+    /// if there is no default. `default` must be of length 1 or the same length as this [`Series`].
+    pub fn lookup(&self, keys: &Self, values: &Self, default: Option<&Self>) -> DaftResult<Self> {
+        if keys.len() != values.len() {
+            return Err(DaftError::ValueError(format!(
+                "Lookup table keys and values must have the same length, got {} and {}",
+                keys.len(),
+                values.len()
+            )));
+        }
+
+        let indices = self.lookup_indices(keys)?;
+        let found = values.take(&indices)?;
+        let output = match default {
+            Some(default) => found.if_else(default, &indices.into_series().not_null()?)?,
+            None => found,
+        };
+        Ok(output.rename(self.name()))
+    }
+
+    /// For each element of this [`Series`], the index of the first equal element of `keys`, or `NULL` if there is none.
+    fn lookup_indices(&self, keys: &Self) -> DaftResult<UInt64Array> {
+        let dtype = try_get_supertype(self.data_type(), keys.data_type())?;
+        if matches!(dtype, DataType::Null) || keys.is_empty() {
+            return Ok(UInt64Array::full_null(
+                "indices",
+                &DataType::UInt64,
+                self.len(),
+            ));
+        }
+        let input = self.cast(&dtype)?;
+        let keys = keys.cast(&dtype)?;
+
+        let probe_table = keys.build_probe_table_without_nulls()?;
+        let comparator = build_is_equal(&*input.to_arrow2(), &*keys.to_arrow2(), false, false)?;
+
+        let hashes = input.hash_with_validity(None)?;
+        let indices = hashes
+            .as_arrow2()
+            .iter()
+            .enumerate()
+            .map(|(idx, hash)| {
+                let hash = *hash?;
+                probe_table
+                    .raw_entry_v1()
+                    .from_hash(hash, |other| {
+                        (hash == other.hash) && comparator(idx, other.idx as _)
+                    })
+                    .map(|(other, ())| other.idx)
+            })
+            .collect::<Vec<_>>();
+        Ok(UInt64Array::from_iter(
+            Field::new("indices", DataType::UInt64),
+            indices.into_iter(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        datatypes::{DataType, Field, Int64Array, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    #[test]
+    fn test_lookup() -> DaftResult<()> {
+        let input = Int64Array::from_iter(
+            Field::new("code", DataType::Int64),
+            vec![Some(1), Some(3), None, Some(2), Some(1)].into_iter(),
+        )
+        .into_series();
+        let keys = Int64Array::from_iter(
+            Field::new("keys", DataType::Int64),
+            vec![Some(1), Some(2), Some(1)].into_iter(),
+        )
+        .into_series();
+        let values =
+            Utf8Array::from_iter("values", vec![Some("a"), Some("b"), Some("c")].into_iter())
+                .into_series();
+
+        let output = input.lookup(&keys, &values, None)?;
+        let expected = Utf8Array::from_iter(
+            "code",
+            vec![Some("a"), None, None, Some("b"), Some("a")].into_iter(),
+        );
+        assert_eq!(output.name(), "code");
+        assert_eq!(output.utf8()?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_default() -> DaftResult<()> {
+        let input = Utf8Array::from_iter("code", vec![Some("x"), None, Some("y")].into_iter())
+            .into_series();
+        let keys = Utf8Array::from_iter("keys", vec![Some("x")].into_iter()).into_series();
+        let values =
+            Int64Array::from_iter(Field::new("values", DataType::Int64), std::iter::once(None))
+                .into_series();
+        let default = Int64Array::from_iter(
+            Field::new("default", DataType::Int64),
+            std::iter::once(Some(-1)),
+        )
+        .into_series();
+
+        // A key that maps to a null value is a match, so only the unmatched rows take the default.
+        let output = input.lookup(&keys, &values, Some(&default))?;
+        let expected = Int64Array::from_iter(
+            Field::new("code", DataType::Int64),
+            vec![None, Some(-1), Some(-1)].into_iter(),
+        );
+        assert_eq!(output.name(), "code");
+        assert_eq!(output.i64()?, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lookup_mismatched_table() {
+        let input = Series::empty("code", &DataType::Int64);
+        let keys = Series::empty("keys", &DataType::Int64);
+        let values = Int64Array::from_iter(
+            Field::new("values", DataType::Int64),
+            std::iter::once(Some(1)),
+        )
+        .into_series();
+        assert!(input.lookup(&keys, &values, None).is_err());
+    }
+}
@@ -22,6 +22,7 @@ pub mod is_in;
 pub mod len;
 pub mod log;
 pub mod logical;
+pub mod lookup;
 pub mod map;
 pub mod minhash;
 pub mod not;
@@ -11,8 +11,8 @@ impl Series {
         let casted_series = cast_series_to_supertype(&[self, keys])?;
         assert!(casted_series.len() == 2);
 
-        let lhs = casted_series[0].as_physical()?;
-        let rhs = casted_series[1].as_physical()?;
+        let lhs = casted_series[0].as_ordered_physical()?;
+        let rhs = casted_series[1].as_ordered_physical()?;
 
         with_match_comparable_daft_types!(lhs.data_type(), |$T| {
             let lhs = lhs.downcast::<<$T as DaftDataType>::ArrayType>().unwrap();
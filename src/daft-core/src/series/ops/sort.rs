@@ -4,7 +4,7 @@ use crate::{prelude::UInt64Array, series::Series, with_match_comparable_daft_typ
 
 impl Series {
     pub fn argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<UInt64Array> {
-        let series = self.as_physical()?;
+        let series = self.as_ordered_physical()?;
         with_match_comparable_daft_types!(series.data_type(), |$T| {
             let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
             downcasted.argsort(descending, nulls_first)
@@ -31,10 +31,14 @@ impl Series {
                 .argsort(*descending.first().unwrap(), *nulls_first.first().unwrap());
         }
 
-        let first = sort_keys.first().unwrap().as_physical()?;
+        let first = sort_keys.first().unwrap().as_ordered_physical()?;
+        let others = sort_keys[1..]
+            .iter()
+            .map(Self::as_ordered_physical)
+            .collect::<DaftResult<Vec<_>>>()?;
         with_match_comparable_daft_types!(first.data_type(), |$T| {
             let downcasted = first.downcast::<<$T as DaftDataType>::ArrayType>()?;
-            downcasted.argsort_multikey(&sort_keys[1..], descending, nulls_first)
+            downcasted.argsort_multikey(&others, descending, nulls_first)
         })
     }
 
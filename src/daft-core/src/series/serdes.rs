@@ -10,7 +10,7 @@ use crate::{
     },
     datatypes::{
         logical::{
-            DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
+            CategoricalArray, DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray,
             FixedShapeSparseTensorArray, FixedShapeTensorArray, ImageArray, MapArray,
             SparseTensorArray, TensorArray, TimeArray, TimestampArray,
         },
@@ -25,6 +25,17 @@ impl serde::Serialize for Series {
     where
         S: serde::Serializer,
     {
+        // Codes are only meaningful within this process, so categoricals are serialized as their strings.
+        if self.data_type().is_categorical() {
+            use serde::ser::SerializeMap;
+
+            let array = self.categorical().map_err(serde::ser::Error::custom)?;
+            let strings = array.to_utf8().map_err(serde::ser::Error::custom)?;
+            let mut s = serializer.serialize_map(Some(2))?;
+            s.serialize_entry("field", array.field())?;
+            s.serialize_entry("values", &strings.into_series())?;
+            return s.end();
+        }
         with_match_daft_types!(self.data_type(), |$T| {
             let array = self.downcast::<<$T as DaftDataType>::ArrayType>().unwrap();
             array.serialize(serializer)
@@ -259,6 +270,13 @@ impl<'d> serde::Deserialize<'d> for Series {
                             .into_series(),
                         )
                     }
+                    DataType::Categorical => {
+                        let strings = map.next_value::<Series>()?;
+                        let strings = strings.utf8().map_err(serde::de::Error::custom)?;
+                        Ok(CategoricalArray::from_utf8(strings)
+                            .map_err(serde::de::Error::custom)?
+                            .into_series())
+                    }
                     DataType::Interval => Ok(IntervalArray::from_iter(
                         field.name.as_str(),
                         map.next_value::<Vec<Option<months_days_ns>>>()?.into_iter(),
@@ -36,6 +36,20 @@ fn coerce_to_daft_compatible_type(
         daft_arrow::datatypes::DataType::Binary => {
             Some(daft_arrow::datatypes::DataType::LargeBinary)
         }
+        // Dictionaries of strings are read as categoricals, so they're decoded straight into the categorical type.
+        daft_arrow::datatypes::DataType::Dictionary(key, values, _)
+            if matches!(
+                values.as_ref(),
+                daft_arrow::datatypes::DataType::Utf8 | daft_arrow::datatypes::DataType::LargeUtf8
+            ) && !(key == &daft_arrow::datatypes::IntegerType::UInt32
+                && values.as_ref() == &daft_arrow::datatypes::DataType::LargeUtf8) =>
+        {
+            Some(daft_arrow::datatypes::DataType::Dictionary(
+                daft_arrow::datatypes::IntegerType::UInt32,
+                Box::new(daft_arrow::datatypes::DataType::LargeUtf8),
+                false,
+            ))
+        }
         daft_arrow::datatypes::DataType::List(field) => {
             let new_field = match coerce_to_daft_compatible_type(field.data_type()) {
                 Some(new_inner_dtype) => Box::new(
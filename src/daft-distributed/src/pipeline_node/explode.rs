@@ -15,6 +15,8 @@ pub(crate) struct ExplodeNode {
     config: PipelineNodeConfig,
     context: PipelineNodeContext,
     to_explode: Vec<BoundExpr>,
+    index_column: Option<String>,
+    pad_mismatched: bool,
     child: DistributedPipelineNode,
 }
 
@@ -25,6 +27,8 @@ impl ExplodeNode {
         node_id: NodeID,
         plan_config: &PlanConfig,
         to_explode: Vec<BoundExpr>,
+        index_column: Option<String>,
+        pad_mismatched: bool,
         schema: SchemaRef,
         child: DistributedPipelineNode,
     ) -> Self {
@@ -43,6 +47,8 @@ impl ExplodeNode {
             config,
             context,
             to_explode,
+            index_column,
+            pad_mismatched,
             child,
         }
     }
@@ -67,10 +73,17 @@ impl PipelineNodeImpl for ExplodeNode {
 
     fn multiline_display(&self, _verbose: bool) -> Vec<String> {
         use itertools::Itertools;
-        vec![format!(
+        let mut res = vec![format!(
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
-        )]
+        )];
+        if let Some(index_column) = &self.index_column {
+            res.push(format!("Index column = {index_column}"));
+        }
+        if self.pad_mismatched {
+            res.push("Pad mismatched = true".to_string());
+        }
+        res
     }
 
     fn produce_tasks(
@@ -79,12 +92,16 @@ impl PipelineNodeImpl for ExplodeNode {
     ) -> SubmittableTaskStream {
         let input_node = self.child.clone().produce_tasks(plan_context);
         let to_explode = self.to_explode.clone();
+        let index_column = self.index_column.clone();
+        let pad_mismatched = self.pad_mismatched;
         let schema = self.config.schema.clone();
         let node_id = self.node_id();
         input_node.pipeline_instruction(self, move |input| {
             LocalPhysicalPlan::explode(
                 input,
                 to_explode.clone(),
+                index_column.clone(),
+                pad_mismatched,
                 schema.clone(),
                 StatsState::NotMaterialized,
                 LocalNodeContext {
@@ -0,0 +1,467 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_dsl::{bound_col, expr::bound_expr::BoundExpr};
+use daft_functions::bloom_filter::{BloomFilter, bloom_filter_might_contain};
+use daft_local_plan::{LocalNodeContext, LocalPhysicalPlan};
+use daft_logical_plan::stats::StatsState;
+use daft_schema::schema::{Schema, SchemaRef};
+use futures::{StreamExt, TryStreamExt, future::try_join_all};
+use tokio::sync::watch;
+
+use crate::{
+    pipeline_node::{
+        DistributedPipelineNode, MaterializedOutput, NodeID, NodeName, PipelineNodeConfig,
+        PipelineNodeContext, PipelineNodeImpl, SubmittableTaskStream,
+        make_in_memory_task_from_materialized_outputs, make_new_task_from_materialized_outputs,
+    },
+    plan::{PlanConfig, PlanExecutionContext, TaskIDCounter},
+    scheduling::{
+        scheduler::{SchedulerHandle, SubmittableTask, SubmittedTask},
+        task::{SwordfishTask, TaskContext},
+    },
+    utils::channel::{Sender, create_channel},
+};
+
+/// Bloom filters are only built if the build side has at most this many rows, which bounds the size of the filter
+/// that is shipped with every task of the probe side.
+const MAX_BLOOM_FILTER_KEYS: usize = 10_000_000;
+
+/// The Bloom filter built by a [`BloomFilterBuildNode`] and used by its [`BloomFilterProbeNode`].
+///
+/// Holds `None` until the build side is materialized, and then `Some(None)` if no filter was built.
+#[derive(Clone)]
+pub(crate) struct SharedBloomFilter(Arc<watch::Sender<Option<Option<Arc<BloomFilter>>>>>);
+
+impl SharedBloomFilter {
+    pub fn new() -> Self {
+        Self(Arc::new(watch::channel(None).0))
+    }
+
+    fn publish(&self, filter: Option<Arc<BloomFilter>>) {
+        self.0.send_replace(Some(filter));
+    }
+
+    async fn wait(&self) -> Option<Arc<BloomFilter>> {
+        let mut rx = self.0.subscribe();
+        rx.wait_for(Option::is_some)
+            .await
+            .ok()
+            .and_then(|filter| filter.clone().flatten())
+    }
+}
+
+/// Materializes one side of a join, builds a Bloom filter from its join keys, and then passes the materialized data
+/// through unchanged.
+pub(crate) struct BloomFilterBuildNode {
+    config: PipelineNodeConfig,
+    context: PipelineNodeContext,
+    keys: Vec<BoundExpr>,
+    filter: SharedBloomFilter,
+    child: DistributedPipelineNode,
+}
+
+impl BloomFilterBuildNode {
+    const NODE_NAME: NodeName = "BloomFilterBuild";
+
+    pub fn new(
+        node_id: NodeID,
+        plan_config: &PlanConfig,
+        keys: Vec<BoundExpr>,
+        filter: SharedBloomFilter,
+        child: DistributedPipelineNode,
+    ) -> Self {
+        let context = PipelineNodeContext::new(
+            plan_config.query_idx,
+            plan_config.query_id.clone(),
+            node_id,
+            Self::NODE_NAME,
+        );
+        let config = PipelineNodeConfig::new(
+            child.config().schema.clone(),
+            plan_config.config.clone(),
+            child.config().clustering_spec.clone(),
+        );
+        Self {
+            config,
+            context,
+            keys,
+            filter,
+            child,
+        }
+    }
+
+    pub fn into_node(self) -> DistributedPipelineNode {
+        DistributedPipelineNode::new(Arc::new(self))
+    }
+
+    /// Creates tasks that compute the distinct join keys of each materialized output.
+    fn create_distinct_key_tasks(
+        self: &Arc<Self>,
+        materialized_outputs: Vec<MaterializedOutput>,
+        key_schema: SchemaRef,
+        task_id_counter: &TaskIDCounter,
+        scheduler_handle: &SchedulerHandle<SwordfishTask>,
+    ) -> DaftResult<Vec<SubmittedTask>> {
+        let node = self.clone() as Arc<dyn PipelineNodeImpl>;
+        let node_id = self.node_id();
+        let projection = self
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| BoundExpr::new_unchecked(key.inner().alias(format!("key_{i}"))))
+            .collect::<Vec<_>>();
+        let dedup_columns = key_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| BoundExpr::new_unchecked(bound_col(i, field.clone())))
+            .collect::<Vec<_>>();
+
+        materialized_outputs
+            .into_iter()
+            .map(|mo| {
+                let projection = projection.clone();
+                let dedup_columns = dedup_columns.clone();
+                let key_schema = key_schema.clone();
+                let task = make_new_task_from_materialized_outputs(
+                    TaskContext::from((&self.context, task_id_counter.next())),
+                    vec![mo],
+                    self.config.schema.clone(),
+                    &node,
+                    move |input| {
+                        let keys = LocalPhysicalPlan::project(
+                            input,
+                            projection,
+                            key_schema.clone(),
+                            StatsState::NotMaterialized,
+                            LocalNodeContext {
+                                origin_node_id: Some(node_id as usize),
+                                additional: None,
+                            },
+                        );
+                        LocalPhysicalPlan::dedup(
+                            keys,
+                            dedup_columns,
+                            key_schema,
+                            StatsState::NotMaterialized,
+                            LocalNodeContext {
+                                origin_node_id: Some(node_id as usize),
+                                additional: None,
+                            },
+                        )
+                    },
+                    None,
+                );
+                task.submit(scheduler_handle)
+            })
+            .collect::<DaftResult<Vec<_>>>()
+    }
+
+    async fn build_filter(
+        self: &Arc<Self>,
+        materialized_outputs: &[MaterializedOutput],
+        task_id_counter: &TaskIDCounter,
+        scheduler_handle: &SchedulerHandle<SwordfishTask>,
+    ) -> DaftResult<Option<Arc<BloomFilter>>> {
+        let num_rows = materialized_outputs
+            .iter()
+            .map(MaterializedOutput::num_rows)
+            .sum::<usize>();
+        if num_rows > MAX_BLOOM_FILTER_KEYS {
+            return Ok(None);
+        }
+
+        let key_schema = Arc::new(Schema::new(
+            self.keys
+                .iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    key.inner()
+                        .alias(format!("key_{i}"))
+                        .to_field(&self.config.schema)
+                })
+                .collect::<DaftResult<Vec<_>>>()?,
+        ));
+        let key_tasks = self.create_distinct_key_tasks(
+            materialized_outputs.to_vec(),
+            key_schema,
+            task_id_counter,
+            scheduler_handle,
+        )?;
+        let key_outputs = try_join_all(key_tasks)
+            .await?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        // The number of rows bounds the number of distinct keys, which is what the filters are sized for.
+        let false_positive_rate = self
+            .config
+            .execution_config
+            .bloom_filter_join_false_positive_rate;
+        let mut filter = BloomFilter::new(num_rows, false_positive_rate)?;
+        for partial_filter in
+            build_partial_filters(key_outputs, num_rows, false_positive_rate).await?
+        {
+            filter.merge(&partial_filter)?;
+        }
+        Ok(Some(Arc::new(filter)))
+    }
+
+    async fn execution_loop(
+        self: Arc<Self>,
+        input: SubmittableTaskStream,
+        task_id_counter: TaskIDCounter,
+        result_tx: Sender<SubmittableTask<SwordfishTask>>,
+        scheduler_handle: SchedulerHandle<SwordfishTask>,
+    ) -> DaftResult<()> {
+        // The probe side waits for the filter, so it has to be published even if building it fails.
+        let materialized_outputs = match input
+            .materialize(scheduler_handle.clone())
+            .try_collect::<Vec<_>>()
+            .await
+        {
+            Ok(materialized_outputs) => materialized_outputs,
+            Err(e) => {
+                self.filter.publish(None);
+                return Err(e);
+            }
+        };
+        match self
+            .build_filter(&materialized_outputs, &task_id_counter, &scheduler_handle)
+            .await
+        {
+            Ok(filter) => self.filter.publish(filter),
+            Err(e) => {
+                self.filter.publish(None);
+                return Err(e);
+            }
+        }
+
+        let node = self.clone() as Arc<dyn PipelineNodeImpl>;
+        for mo in materialized_outputs {
+            let task = make_in_memory_task_from_materialized_outputs(
+                TaskContext::from((&self.context, task_id_counter.next())),
+                vec![mo],
+                self.config.schema.clone(),
+                &node,
+                None,
+            );
+            if result_tx.send(task).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PipelineNodeImpl for BloomFilterBuildNode {
+    fn context(&self) -> &PipelineNodeContext {
+        &self.context
+    }
+
+    fn config(&self) -> &PipelineNodeConfig {
+        &self.config
+    }
+
+    fn children(&self) -> Vec<DistributedPipelineNode> {
+        vec![self.child.clone()]
+    }
+
+    fn multiline_display(&self, _verbose: bool) -> Vec<String> {
+        use itertools::Itertools;
+        vec![format!(
+            "Bloom Filter Build: On {}",
+            self.keys.iter().map(|e| e.to_string()).join(", ")
+        )]
+    }
+
+    fn produce_tasks(
+        self: Arc<Self>,
+        plan_context: &mut PlanExecutionContext,
+    ) -> SubmittableTaskStream {
+        let input_node = self.child.clone().produce_tasks(plan_context);
+        let (result_tx, result_rx) = create_channel(1);
+        plan_context.spawn(self.execution_loop(
+            input_node,
+            plan_context.task_id_counter(),
+            result_tx,
+            plan_context.scheduler_handle(),
+        ));
+        SubmittableTaskStream::from(result_rx)
+    }
+}
+
+/// Drops the rows of one side of a join whose keys are not in the Bloom filter built from the other side.
+pub(crate) struct BloomFilterProbeNode {
+    config: PipelineNodeConfig,
+    context: PipelineNodeContext,
+    keys: Vec<BoundExpr>,
+    filter: SharedBloomFilter,
+    child: DistributedPipelineNode,
+}
+
+impl BloomFilterProbeNode {
+    const NODE_NAME: NodeName = "BloomFilterProbe";
+
+    pub fn new(
+        node_id: NodeID,
+        plan_config: &PlanConfig,
+        keys: Vec<BoundExpr>,
+        filter: SharedBloomFilter,
+        child: DistributedPipelineNode,
+    ) -> Self {
+        let context = PipelineNodeContext::new(
+            plan_config.query_idx,
+            plan_config.query_id.clone(),
+            node_id,
+            Self::NODE_NAME,
+        );
+        let config = PipelineNodeConfig::new(
+            child.config().schema.clone(),
+            plan_config.config.clone(),
+            child.config().clustering_spec.clone(),
+        );
+        Self {
+            config,
+            context,
+            keys,
+            filter,
+            child,
+        }
+    }
+
+    pub fn into_node(self) -> DistributedPipelineNode {
+        DistributedPipelineNode::new(Arc::new(self))
+    }
+
+    async fn execution_loop(
+        self: Arc<Self>,
+        input: SubmittableTaskStream,
+        result_tx: Sender<SubmittableTask<SwordfishTask>>,
+    ) -> DaftResult<()> {
+        let mut input = match self.filter.wait().await {
+            Some(filter) => {
+                let predicate = BoundExpr::new_unchecked(bloom_filter_might_contain(
+                    filter,
+                    self.keys.iter().map(|key| key.inner().clone()).collect(),
+                ));
+                let node_id = self.node_id();
+                input.pipeline_instruction(self.clone(), move |input| {
+                    LocalPhysicalPlan::filter(
+                        input,
+                        predicate.clone(),
+                        StatsState::NotMaterialized,
+                        LocalNodeContext {
+                            origin_node_id: Some(node_id as usize),
+                            additional: None,
+                        },
+                    )
+                })
+            }
+            None => input,
+        };
+        while let Some(task) = input.next().await {
+            if result_tx.send(task).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PipelineNodeImpl for BloomFilterProbeNode {
+    fn context(&self) -> &PipelineNodeContext {
+        &self.context
+    }
+
+    fn config(&self) -> &PipelineNodeConfig {
+        &self.config
+    }
+
+    fn children(&self) -> Vec<DistributedPipelineNode> {
+        vec![self.child.clone()]
+    }
+
+    fn multiline_display(&self, _verbose: bool) -> Vec<String> {
+        use itertools::Itertools;
+        vec![format!(
+            "Bloom Filter Probe: On {}",
+            self.keys.iter().map(|e| e.to_string()).join(", ")
+        )]
+    }
+
+    fn produce_tasks(
+        self: Arc<Self>,
+        plan_context: &mut PlanExecutionContext,
+    ) -> SubmittableTaskStream {
+        let input_node = self.child.clone().produce_tasks(plan_context);
+        let (result_tx, result_rx) = create_channel(1);
+        plan_context.spawn(self.execution_loop(input_node, result_tx));
+        SubmittableTaskStream::from(result_rx)
+    }
+}
+
+/// Builds a Bloom filter from each materialized output of join keys on the worker that holds it, so that only the
+/// filters, rather than the keys, are fetched. The filters are all sized for `num_items` keys, so they can be merged.
+#[cfg(feature = "python")]
+async fn build_partial_filters(
+    outputs: Vec<MaterializedOutput>,
+    num_items: usize,
+    false_positive_rate: f64,
+) -> DaftResult<Vec<BloomFilter>> {
+    use pyo3::prelude::*;
+
+    let ray_partition_refs = outputs
+        .into_iter()
+        .flat_map(|mo| mo.into_inner().0)
+        .map(|pr| {
+            let ray_partition_ref = pr
+                .as_any()
+                .downcast_ref::<crate::python::ray::RayPartitionRef>()
+                .ok_or(DaftError::InternalError(
+                    "Failed to downcast partition ref".to_string(),
+                ))?;
+            Ok(ray_partition_ref.clone())
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    if ray_partition_refs.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let serialized_filters =
+        common_runtime::python::execute_python_coroutine::<_, Vec<Vec<u8>>>(move |py| {
+            let flotilla_module = py.import(pyo3::intern!(py, "daft.runners.flotilla"))?;
+            let py_object_refs = ray_partition_refs
+                .into_iter()
+                .map(|pr| pr.get_object_ref(py))
+                .collect::<Vec<_>>();
+            flotilla_module.call_method1(
+                pyo3::intern!(py, "build_bloom_filters"),
+                (py_object_refs, num_items, false_positive_rate),
+            )
+        })
+        .await?;
+
+    serialized_filters
+        .iter()
+        .map(|bytes| {
+            bincode::serde::decode_from_slice(bytes, bincode::config::legacy())
+                .map(|(filter, _)| filter)
+                .map_err(|e| {
+                    DaftError::InternalError(format!("Failed to deserialize Bloom filter: {e}"))
+                })
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "python"))]
+async fn build_partial_filters(
+    _outputs: Vec<MaterializedOutput>,
+    _num_items: usize,
+    _false_positive_rate: f64,
+) -> DaftResult<Vec<BloomFilter>> {
+    Err(DaftError::InternalError(
+        "Distributed Bloom filter joins require the Python feature to be enabled".to_string(),
+    ))
+}
@@ -28,6 +28,7 @@ pub(crate) struct BroadcastJoinNode {
     left_on: Vec<BoundExpr>,
     right_on: Vec<BoundExpr>,
     null_equals_nulls: Option<Vec<bool>>,
+    residual: Option<BoundExpr>,
     join_type: JoinType,
     is_swapped: bool,
 
@@ -46,6 +47,7 @@ impl BroadcastJoinNode {
         left_on: Vec<BoundExpr>,
         right_on: Vec<BoundExpr>,
         null_equals_nulls: Option<Vec<bool>>,
+        residual: Option<BoundExpr>,
         join_type: JoinType,
         is_swapped: bool,
         broadcaster: DistributedPipelineNode,
@@ -74,6 +76,7 @@ impl BroadcastJoinNode {
             left_on,
             right_on,
             null_equals_nulls,
+            residual,
             join_type,
             is_swapped,
             broadcaster,
@@ -127,6 +130,7 @@ impl BroadcastJoinNode {
                 self.right_on.clone(),
                 Some(build_on_left),
                 self.null_equals_nulls.clone(),
+                self.residual.clone(),
                 self.join_type,
                 self.config.schema.clone(),
                 StatsState::NotMaterialized,
@@ -189,6 +193,9 @@ impl PipelineNodeImpl for BroadcastJoinNode {
                 null_equals_nulls.iter().map(|b| b.to_string()).join(", ")
             ));
         }
+        if let Some(residual) = &self.residual {
+            res.push(format!("Residual: {residual}"));
+        }
         res
     }
 
@@ -26,7 +26,9 @@ pub(crate) struct HashJoinNode {
     left_on: Vec<BoundExpr>,
     right_on: Vec<BoundExpr>,
     null_equals_nulls: Option<Vec<bool>>,
+    residual: Option<BoundExpr>,
     join_type: JoinType,
+    build_on_left: bool,
 
     left: DistributedPipelineNode,
     right: DistributedPipelineNode,
@@ -42,7 +44,9 @@ impl HashJoinNode {
         left_on: Vec<BoundExpr>,
         right_on: Vec<BoundExpr>,
         null_equals_nulls: Option<Vec<bool>>,
+        residual: Option<BoundExpr>,
         join_type: JoinType,
+        build_on_left: bool,
         num_partitions: usize,
         left: DistributedPipelineNode,
         right: DistributedPipelineNode,
@@ -71,7 +75,9 @@ impl HashJoinNode {
             left_on,
             right_on,
             null_equals_nulls,
+            residual,
             join_type,
+            build_on_left,
             left,
             right,
         }
@@ -94,8 +100,9 @@ impl HashJoinNode {
             right_plan,
             self.left_on.clone(),
             self.right_on.clone(),
-            None,
+            Some(self.build_on_left),
             self.null_equals_nulls.clone(),
+            self.residual.clone(),
             self.join_type,
             self.config.schema.clone(),
             StatsState::NotMaterialized,
@@ -145,6 +152,11 @@ impl PipelineNodeImpl for HashJoinNode {
             "Right on: {}",
             self.right_on.iter().map(|e| e.to_string()).join(", ")
         ));
+        if let Some(residual) = &self.residual {
+            res.push(format!("Residual: {residual}"));
+        }
+        res.push(format!("Join type: {}", self.join_type));
+        res.push(format!("Build on left: {}", self.build_on_left));
         res
     }
 
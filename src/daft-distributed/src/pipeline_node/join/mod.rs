@@ -1,9 +1,11 @@
+pub(crate) mod bloom_filter;
 mod broadcast_join;
 pub(crate) mod cross_join;
 pub(crate) mod hash_join;
 pub(crate) mod sort_merge_join;
 pub(crate) mod translate_join;
 
+pub(crate) use bloom_filter::{BloomFilterBuildNode, BloomFilterProbeNode, SharedBloomFilter};
 pub(crate) use broadcast_join::BroadcastJoinNode;
 pub(crate) use cross_join::CrossJoinNode;
 pub(crate) use hash_join::HashJoinNode;
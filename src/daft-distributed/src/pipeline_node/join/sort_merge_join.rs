@@ -1,4 +1,4 @@
-use std::{collections::HashMap, future, sync::Arc};
+use std::{cmp::max, collections::HashMap, future, sync::Arc};
 
 use common_error::DaftResult;
 use daft_dsl::expr::bound_expr::BoundExpr;
@@ -89,6 +89,7 @@ impl SortMergeJoinNode {
     fn multiline_display(&self) -> Vec<String> {
         use itertools::Itertools;
         let mut res = vec!["Sort Merge Join".to_string()];
+        res.push(format!("Join type: {}", self.join_type));
         res.push(format!(
             "Left on: {}",
             self.left_on.iter().map(|e| e.to_string()).join(", ")
@@ -145,6 +146,9 @@ impl SortMergeJoinNode {
             self.left_on.clone(),
             self.right_on.clone(),
             self.join_type,
+            // Range partitioning groups the rows of each side by their join keys, but doesn't sort them
+            false,
+            false,
             self.config.schema.clone(),
             StatsState::NotMaterialized,
             LocalNodeContext {
@@ -311,8 +315,38 @@ impl SortMergeJoinNode {
             .try_collect::<Vec<_>>()
             .await?;
 
-        // Handle empty inputs
+        // Handle empty inputs: the join type determines whether the rows of the other side are still output,
+        // in which case each of its partitions is joined against an empty partition
         if left_materialized.is_empty() || right_materialized.is_empty() {
+            let keeps_left = matches!(
+                self.join_type,
+                JoinType::Left | JoinType::Outer | JoinType::Anti
+            );
+            let keeps_right = matches!(self.join_type, JoinType::Right | JoinType::Outer);
+            let (left_groups, right_groups) =
+                match (left_materialized.is_empty(), right_materialized.is_empty()) {
+                    (false, true) if keeps_left => (
+                        left_materialized.into_iter().map(|p| vec![p]).collect(),
+                        vec![],
+                    ),
+                    (true, false) if keeps_right => (
+                        vec![],
+                        right_materialized.into_iter().map(|p| vec![p]).collect(),
+                    ),
+                    _ => return Ok(()),
+                };
+            let num_tasks = max(left_groups.len(), right_groups.len());
+            let mut left_groups = left_groups.into_iter();
+            let mut right_groups = right_groups.into_iter();
+            for _ in 0..num_tasks {
+                self.create_and_submit_join_task(
+                    left_groups.next().unwrap_or_default(),
+                    right_groups.next().unwrap_or_default(),
+                    &task_id_counter,
+                    &result_tx,
+                )
+                .await?;
+            }
             return Ok(());
         }
 
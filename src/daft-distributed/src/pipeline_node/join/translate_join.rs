@@ -1,9 +1,9 @@
 use std::cmp::max;
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_dsl::{ExprRef, expr::bound_expr::BoundExpr, is_partition_compatible};
 use daft_logical_plan::{
-    ClusteringSpec, JoinStrategy, JoinType,
+    ClusteringSpec, JoinSide, JoinStrategy, JoinType,
     ops::Join,
     partitioning::{HashRepartitionConfig, RepartitionSpec},
     stats::ApproxStats,
@@ -12,27 +12,56 @@ use daft_schema::schema::SchemaRef;
 
 use crate::pipeline_node::{
     DistributedPipelineNode,
-    join::{BroadcastJoinNode, CrossJoinNode, HashJoinNode, SortMergeJoinNode},
+    filter::FilterNode,
+    join::{
+        BloomFilterBuildNode, BloomFilterProbeNode, BroadcastJoinNode, CrossJoinNode, HashJoinNode,
+        SharedBloomFilter, SortMergeJoinNode,
+    },
     translate::LogicalPlanToPipelineNodeTranslator,
 };
 
 impl LogicalPlanToPipelineNodeTranslator {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn determine_join_strategy(
         &self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        null_equals_nulls: &[bool],
+        has_residual: bool,
         join_type: &JoinType,
         join_strategy: Option<JoinStrategy>,
         left_stats: &ApproxStats,
         right_stats: &ApproxStats,
+        left_spec: &ClusteringSpec,
+        right_spec: &ClusteringSpec,
     ) -> JoinStrategy {
-        // If join strategy is explicitly specified, use it
+        let is_cross_join =
+            left_on.is_empty() && right_on.is_empty() && *join_type == JoinType::Inner;
+        // Merge joins never match null keys and only match on the join keys, so they can't be used for null-safe
+        // equality or joins with non-equality predicates
+        let is_null_safe_join = null_equals_nulls.iter().any(|b| *b);
+
+        // If join strategy is explicitly specified, use it if it supports this join, otherwise fall back
+        // to choosing a strategy as if there were no hint
         if let Some(strategy) = join_strategy {
-            return strategy;
+            let unsupported_reason = match strategy {
+                _ if is_cross_join => Some("cross joins"),
+                JoinStrategy::Broadcast if *join_type == JoinType::Outer => Some("outer joins"),
+                JoinStrategy::SortMerge if is_null_safe_join => Some("null-safe equality"),
+                JoinStrategy::SortMerge if has_residual => Some("non-equality predicates"),
+                JoinStrategy::SortedLookup => Some("the distributed runner"),
+                _ => None,
+            };
+            match unsupported_reason {
+                None => return strategy,
+                Some(reason) => tracing::warn!(
+                    "Join strategy hint {strategy:?} is not supported for {reason}, falling back to the planner's choice of strategy"
+                ),
+            }
         }
 
         // Check for cross join
-        if left_on.is_empty() && right_on.is_empty() && *join_type == JoinType::Inner {
+        if is_cross_join {
             return JoinStrategy::Cross;
         }
 
@@ -55,10 +84,104 @@ impl LogicalPlanToPipelineNodeTranslator {
         if smaller_size_bytes <= self.plan_config.config.broadcast_join_size_bytes_threshold
             && smaller_side_is_broadcastable
         {
-            JoinStrategy::Broadcast
+            return JoinStrategy::Broadcast;
+        }
+
+        // If both sides are already range partitioned on the join keys, a sort-merge join can reuse that ordering
+        // instead of hash partitioning both sides again
+        if !is_null_safe_join && !has_residual {
+            let is_range_partitioned = |spec: &ClusteringSpec, on: &[ExprRef]| {
+                matches!(spec, ClusteringSpec::Range(..))
+                    && is_partition_compatible(&spec.partition_by(), on)
+            };
+            if is_range_partitioned(left_spec, left_on)
+                && is_range_partitioned(right_spec, right_on)
+            {
+                return JoinStrategy::SortMerge;
+            }
+        }
+
         // Otherwise, use a hash join
+        JoinStrategy::Hash
+    }
+
+    /// Determine whether a hash join should build its probe table on the left side, based on the estimated sizes
+    /// of the sides.
+    ///
+    /// Building on the smaller side keeps the probe table small. However, building on a side whose unmatched rows
+    /// are kept requires tracking the matched rows in a bitmap and emitting the unmatched ones after all input has
+    /// been probed, so that side is only built on if it is sufficiently smaller than the other side.
+    pub(crate) fn determine_hash_join_build_side(
+        join_type: JoinType,
+        left_stats: &ApproxStats,
+        right_stats: &ApproxStats,
+    ) -> bool {
+        const KEPT_SIDE_BUILD_FACTOR: f64 = 1.5;
+
+        let left_size = left_stats.size_bytes as f64;
+        let right_size = right_stats.size_bytes as f64;
+        match join_type {
+            JoinType::Inner | JoinType::Outer => left_size <= right_size,
+            JoinType::Left | JoinType::Anti | JoinType::Semi => {
+                right_size > left_size * KEPT_SIDE_BUILD_FACTOR
+            }
+            JoinType::Right => right_size * KEPT_SIDE_BUILD_FACTOR >= left_size,
+        }
+    }
+
+    /// Determine whether the larger side of a hash join should be pruned with a Bloom filter over the keys of the
+    /// smaller side.
+    ///
+    /// This is only correct if unmatched rows are dropped from the larger side, whichever side that is, and if null
+    /// keys never match, since null keys are not added to the filter.
+    fn should_prune_with_bloom_filter(
+        &self,
+        join_type: JoinType,
+        null_equals_nulls: &[bool],
+    ) -> bool {
+        self.plan_config.config.enable_bloom_filter_join_pruning
+            && matches!(join_type, JoinType::Inner | JoinType::Semi)
+            && !null_equals_nulls.iter().any(|b| *b)
+    }
+
+    /// Wraps the smaller side of a join with a node that builds a Bloom filter from its keys, and the larger side with a
+    /// node that drops the rows whose keys are not in the filter.
+    fn gen_bloom_filter_nodes(
+        &mut self,
+        left: DistributedPipelineNode,
+        right: DistributedPipelineNode,
+        left_on: &[BoundExpr],
+        right_on: &[BoundExpr],
+        left_stats: &ApproxStats,
+        right_stats: &ApproxStats,
+    ) -> (DistributedPipelineNode, DistributedPipelineNode) {
+        let filter = SharedBloomFilter::new();
+        let build_on_left = left_stats.size_bytes <= right_stats.size_bytes;
+        let (build, build_on, probe, probe_on) = if build_on_left {
+            (left, left_on, right, right_on)
+        } else {
+            (right, right_on, left, left_on)
+        };
+        let build = BloomFilterBuildNode::new(
+            self.get_next_pipeline_node_id(),
+            &self.plan_config,
+            build_on.to_vec(),
+            filter.clone(),
+            build,
+        )
+        .into_node();
+        let probe = BloomFilterProbeNode::new(
+            self.get_next_pipeline_node_id(),
+            &self.plan_config,
+            probe_on.to_vec(),
+            filter,
+            probe,
+        )
+        .into_node();
+        if build_on_left {
+            (build, probe)
         } else {
-            JoinStrategy::Hash
+            (probe, build)
         }
     }
 
@@ -70,7 +193,10 @@ impl LogicalPlanToPipelineNodeTranslator {
         left_on: Vec<BoundExpr>,
         right_on: Vec<BoundExpr>,
         null_equals_nulls: Vec<bool>,
+        residual: Option<BoundExpr>,
         join_type: JoinType,
+        left_stats: &ApproxStats,
+        right_stats: &ApproxStats,
         output_schema: SchemaRef,
     ) -> DaftResult<DistributedPipelineNode> {
         let left_spec = left.config().clustering_spec.as_ref();
@@ -112,9 +238,22 @@ impl LogicalPlanToPipelineNodeTranslator {
             (_, _, a, b) => max(a, b),
         };
 
-        let left = if num_left_partitions != num_partitions
-            || (num_partitions > 1 && !is_left_hash_partitioned)
+        let shuffle_left = num_left_partitions != num_partitions
+            || (num_partitions > 1 && !is_left_hash_partitioned);
+        let shuffle_right = num_right_partitions != num_partitions
+            || (num_partitions > 1 && !is_right_hash_partitioned);
+
+        // Prune the larger side before it is shuffled, if both sides are going to be shuffled anyway
+        let (left, right) = if shuffle_left
+            && shuffle_right
+            && self.should_prune_with_bloom_filter(join_type, &null_equals_nulls)
         {
+            self.gen_bloom_filter_nodes(left, right, &left_on, &right_on, left_stats, right_stats)
+        } else {
+            (left, right)
+        };
+
+        let left = if shuffle_left {
             self.gen_shuffle_node(
                 RepartitionSpec::Hash(HashRepartitionConfig::new(
                     Some(num_partitions),
@@ -127,9 +266,7 @@ impl LogicalPlanToPipelineNodeTranslator {
             left
         };
 
-        let right = if num_right_partitions != num_partitions
-            || (num_partitions > 1 && !is_right_hash_partitioned)
-        {
+        let right = if shuffle_right {
             self.gen_shuffle_node(
                 RepartitionSpec::Hash(HashRepartitionConfig::new(
                     Some(num_partitions),
@@ -142,13 +279,18 @@ impl LogicalPlanToPipelineNodeTranslator {
             right
         };
 
+        let build_on_left =
+            Self::determine_hash_join_build_side(join_type, left_stats, right_stats);
+
         Ok(HashJoinNode::new(
             self.get_next_pipeline_node_id(),
             &self.plan_config,
             left_on,
             right_on,
             Some(null_equals_nulls),
+            residual,
             join_type,
+            build_on_left,
             num_partitions,
             left,
             right,
@@ -163,7 +305,9 @@ impl LogicalPlanToPipelineNodeTranslator {
         left_on: Vec<BoundExpr>,
         right_on: Vec<BoundExpr>,
         null_equals_nulls: Vec<bool>,
+        residual: Option<BoundExpr>,
         join_type: JoinType,
+        broadcast_side: Option<JoinSide>,
         left_node: DistributedPipelineNode,
         right_node: DistributedPipelineNode,
         left_stats: &ApproxStats,
@@ -187,6 +331,26 @@ impl LogicalPlanToPipelineNodeTranslator {
             (JoinType::Semi, _) => true,
         };
 
+        // A broadcast hint names the side to broadcast, which is used if the join type allows broadcasting it
+        let is_swapped = match broadcast_side {
+            Some(JoinSide::Left) if matches!(join_type, JoinType::Inner | JoinType::Right) => false,
+            Some(JoinSide::Right)
+                if matches!(
+                    join_type,
+                    JoinType::Inner | JoinType::Left | JoinType::Anti | JoinType::Semi
+                ) =>
+            {
+                true
+            }
+            Some(side) => {
+                tracing::warn!(
+                    "The {side} side of a {join_type} join cannot be broadcast, broadcasting the other side instead"
+                );
+                is_swapped
+            }
+            None => is_swapped,
+        };
+
         let (broadcaster, receiver) = if is_swapped {
             (right_node, left_node)
         } else {
@@ -200,6 +364,7 @@ impl LogicalPlanToPipelineNodeTranslator {
             left_on,
             right_on,
             Some(null_equals_nulls),
+            residual,
             join_type,
             is_swapped,
             broadcaster,
@@ -269,9 +434,8 @@ impl LogicalPlanToPipelineNodeTranslator {
         right_node: DistributedPipelineNode,
     ) -> DaftResult<DistributedPipelineNode> {
         let (remaining_on, left_on, right_on, null_equals_nulls) = join.on.split_eq_preds();
-        if !remaining_on.is_empty() {
-            todo!("FLOTILLA_MS?: Implement non-equality joins")
-        }
+        let residual = remaining_on
+            .bind_join_sides(&left_node.config().schema, &right_node.config().schema)?;
 
         // Normalize join keys
         let (left_on, right_on) = daft_dsl::join::normalize_join_keys(
@@ -289,24 +453,39 @@ impl LogicalPlanToPipelineNodeTranslator {
         let join_strategy = self.determine_join_strategy(
             &left_on,
             &right_on,
+            &null_equals_nulls,
+            residual.is_some(),
             &join.join_type,
             join.join_strategy,
             &left_stats,
             &right_stats,
+            left_node.config().clustering_spec.as_ref(),
+            right_node.config().clustering_spec.as_ref(),
         );
 
+        if join_strategy != JoinStrategy::Cross && left_on.is_empty() && residual.is_some() {
+            return Err(DaftError::not_implemented(format!(
+                "Execution of {} join without any equality predicates",
+                join.join_type
+            )));
+        }
+
         // Bind join keys to schemas
         let left_on = BoundExpr::bind_all(&left_on, &left_node.config().schema)?;
         let right_on = BoundExpr::bind_all(&right_on, &right_node.config().schema)?;
 
         match join_strategy {
-            JoinStrategy::Hash => self.gen_hash_join_nodes(
+            // Sorted lookup joins fall back to the planner's choice of strategy in `determine_join_strategy`
+            JoinStrategy::Hash | JoinStrategy::SortedLookup => self.gen_hash_join_nodes(
                 left_node,
                 right_node,
                 left_on,
                 right_on,
                 null_equals_nulls,
+                residual,
                 join.join_type,
+                &left_stats,
+                &right_stats,
                 join.output_schema.clone(),
             ),
             JoinStrategy::SortMerge => self.gen_sort_merge_join_node(
@@ -321,7 +500,9 @@ impl LogicalPlanToPipelineNodeTranslator {
                 left_on,
                 right_on,
                 null_equals_nulls,
+                residual,
                 join.join_type,
+                join.broadcast_side,
                 left_node,
                 right_node,
                 &left_stats,
@@ -329,7 +510,21 @@ impl LogicalPlanToPipelineNodeTranslator {
                 join.output_schema.clone(),
             ),
             JoinStrategy::Cross => {
-                self.gen_cross_join_node(left_node, right_node, join.output_schema.clone())
+                let cross_join =
+                    self.gen_cross_join_node(left_node, right_node, join.output_schema.clone())?;
+                // The output of a cross join is the left columns followed by the right columns, which is the layout
+                // that the residual is bound to.
+                match residual {
+                    Some(residual) => Ok(FilterNode::new(
+                        self.get_next_pipeline_node_id(),
+                        &self.plan_config,
+                        residual,
+                        join.output_schema.clone(),
+                        cross_join,
+                    )
+                    .into_node()),
+                    None => Ok(cross_join),
+                }
             }
         }
     }
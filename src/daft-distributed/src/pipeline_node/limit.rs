@@ -31,14 +31,14 @@ use crate::{
 /// Keeps track of the remaining skip and take.
 ///
 /// Skip is the number of rows to skip if there is an offset.
-/// Take is the number of rows to take for the limit.
+/// Take is the number of rows to take for the limit, or None if all rows after the offset are taken.
 struct LimitState {
     remaining_skip: usize,
-    remaining_take: usize,
+    remaining_take: Option<usize>,
 }
 
 impl LimitState {
-    fn new(limit: usize, offset: Option<usize>) -> Self {
+    fn new(limit: Option<usize>, offset: Option<usize>) -> Self {
         Self {
             remaining_skip: offset.unwrap_or(0),
             remaining_take: limit,
@@ -49,7 +49,7 @@ impl LimitState {
         self.remaining_skip
     }
 
-    fn remaining_take(&self) -> usize {
+    fn remaining_take(&self) -> Option<usize> {
         self.remaining_take
     }
 
@@ -58,7 +58,9 @@ impl LimitState {
     }
 
     fn decrement_take(&mut self, amount: usize) {
-        self.remaining_take = self.remaining_take.saturating_sub(amount);
+        if let Some(remaining_take) = &mut self.remaining_take {
+            *remaining_take = remaining_take.saturating_sub(amount);
+        }
     }
 
     fn is_skip_done(&self) -> bool {
@@ -66,11 +68,11 @@ impl LimitState {
     }
 
     fn is_take_done(&self) -> bool {
-        self.remaining_take == 0
+        self.remaining_take == Some(0)
     }
 
-    fn total_remaining(&self) -> usize {
-        self.remaining_skip + self.remaining_take
+    fn total_remaining(&self) -> Option<usize> {
+        self.remaining_take.map(|take| self.remaining_skip + take)
     }
 }
 
@@ -110,7 +112,7 @@ impl RuntimeStats for LimitStats {
 pub(crate) struct LimitNode {
     config: PipelineNodeConfig,
     context: PipelineNodeContext,
-    limit: usize,
+    limit: Option<usize>,
     offset: Option<usize>,
     child: DistributedPipelineNode,
     stats: Arc<LimitStats>,
@@ -122,7 +124,7 @@ impl LimitNode {
     pub fn new(
         node_id: NodeID,
         plan_config: &PlanConfig,
-        limit: usize,
+        limit: Option<usize>,
         offset: Option<usize>,
         schema: SchemaRef,
         child: DistributedPipelineNode,
@@ -174,7 +176,10 @@ impl LimitNode {
                 num_rows -= skip_num_rows;
             }
 
-            let task = match num_rows.cmp(&limit_state.remaining_take()) {
+            let task = match limit_state
+                .remaining_take()
+                .map_or(Ordering::Less, |take| num_rows.cmp(&take))
+            {
                 Ordering::Less | Ordering::Equal => {
                     limit_state.decrement_take(num_rows);
                     self.stats.add_active_rows_out(num_rows as u64);
@@ -203,7 +208,9 @@ impl LimitNode {
                     )
                 }
                 Ordering::Greater => {
-                    let remaining = limit_state.remaining_take();
+                    let remaining = limit_state
+                        .remaining_take()
+                        .expect("Only a limit can take fewer rows than its input");
                     let task = make_new_task_from_materialized_outputs(
                         TaskContext::from((&self.context, task_id_counter.next())),
                         vec![next_input],
@@ -250,28 +257,41 @@ impl LimitNode {
 
         // Keep submitting local limit tasks as long as we have remaining limit or we have input
         while !input_exhausted {
+            // Without a limit, the remaining input is passed through as is once the offset is skipped.
+            if limit_state.remaining_take().is_none() && limit_state.is_skip_done() {
+                while let Some(task) = input.next().await {
+                    if result_tx.send(task).await.is_err() {
+                        break;
+                    }
+                }
+                return Ok(());
+            }
+
             let mut local_limits = VecDeque::new();
             let local_limit_per_task = limit_state.total_remaining();
 
             // Submit tasks until we have max_concurrent_tasks or we run out of input
             for _ in 0..max_concurrent_tasks {
                 if let Some(task) = input.next().await {
-                    let task_with_limit = append_plan_to_existing_task(
-                        task,
-                        &(self.clone() as Arc<dyn PipelineNodeImpl>),
-                        &move |input| {
-                            LocalPhysicalPlan::limit(
-                                input,
-                                local_limit_per_task as u64,
-                                Some(0),
-                                StatsState::NotMaterialized,
-                                LocalNodeContext {
-                                    origin_node_id: Some(node_id as usize),
-                                    additional: None,
-                                },
-                            )
-                        },
-                    );
+                    let task_with_limit = match local_limit_per_task {
+                        Some(local_limit_per_task) => append_plan_to_existing_task(
+                            task,
+                            &(self.clone() as Arc<dyn PipelineNodeImpl>),
+                            &move |input| {
+                                LocalPhysicalPlan::limit(
+                                    input,
+                                    local_limit_per_task as u64,
+                                    Some(0),
+                                    StatsState::NotMaterialized,
+                                    LocalNodeContext {
+                                        origin_node_id: Some(node_id as usize),
+                                        additional: None,
+                                    },
+                                )
+                            },
+                        ),
+                        None => task,
+                    };
                     let future = task_with_limit.submit(&scheduler_handle)?;
                     local_limits.push_back(future);
                 } else {
@@ -322,7 +342,10 @@ impl LimitNode {
             // Only update if we have remaining limit, and we did get some output
             if !limit_state.is_take_done() && total_num_rows > 0 && num_local_limits > 0 {
                 let rows_per_task = total_num_rows.div_ceil(num_local_limits);
-                max_concurrent_tasks = limit_state.remaining_take().div_ceil(rows_per_task);
+                let rows_needed = limit_state
+                    .remaining_take()
+                    .unwrap_or_else(|| limit_state.remaining_skip());
+                max_concurrent_tasks = rows_needed.div_ceil(rows_per_task).max(1);
             }
         }
 
@@ -344,9 +367,10 @@ impl PipelineNodeImpl for LimitNode {
     }
 
     fn multiline_display(&self, _verbose: bool) -> Vec<String> {
-        match &self.offset {
-            Some(o) => vec![format!("Limit: Num Rows = {}, Offset = {}", self.limit, o)],
-            None => vec![format!("Limit: {}", self.limit)],
+        match (&self.limit, &self.offset) {
+            (Some(l), Some(o)) => vec![format!("Limit: Num Rows = {}, Offset = {}", l, o)],
+            (Some(l), None) => vec![format!("Limit: {}", l)],
+            (None, o) => vec![format!("Offset: {}", o.unwrap_or(0))],
         }
     }
 
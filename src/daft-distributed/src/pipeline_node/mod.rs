@@ -50,6 +50,7 @@ pub(crate) mod materialize;
 mod monotonically_increasing_id;
 mod pivot;
 mod project;
+mod resample;
 mod sample;
 mod scan_source;
 mod shuffles;
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use daft_local_plan::{LocalNodeContext, LocalPhysicalPlan, LocalPhysicalPlanRef};
+use daft_logical_plan::{GapFill, stats::StatsState};
+use daft_schema::schema::SchemaRef;
+
+use super::{PipelineNodeImpl, SubmittableTaskStream};
+use crate::{
+    pipeline_node::{
+        DistributedPipelineNode, NodeID, NodeName, PipelineNodeConfig, PipelineNodeContext,
+    },
+    plan::{PlanConfig, PlanExecutionContext},
+};
+
+/// Fills the gaps of a time series which was aggregated into intervals, whose child partitions it by its keys so
+/// that each partition holds all of the intervals of its keys.
+pub(crate) struct ResampleNode {
+    config: PipelineNodeConfig,
+    context: PipelineNodeContext,
+    num_keys: usize,
+    every: i64,
+    fill: Option<GapFill>,
+    child: DistributedPipelineNode,
+}
+
+impl ResampleNode {
+    const NODE_NAME: NodeName = "Resample";
+
+    pub fn new(
+        node_id: NodeID,
+        plan_config: &PlanConfig,
+        num_keys: usize,
+        every: i64,
+        fill: Option<GapFill>,
+        schema: SchemaRef,
+        child: DistributedPipelineNode,
+    ) -> Self {
+        let context = PipelineNodeContext::new(
+            plan_config.query_idx,
+            plan_config.query_id.clone(),
+            node_id,
+            Self::NODE_NAME,
+        );
+        let config = PipelineNodeConfig::new(
+            schema,
+            plan_config.config.clone(),
+            child.config().clustering_spec.clone(),
+        );
+        Self {
+            config,
+            context,
+            num_keys,
+            every,
+            fill,
+            child,
+        }
+    }
+
+    pub fn into_node(self) -> DistributedPipelineNode {
+        DistributedPipelineNode::new(Arc::new(self))
+    }
+}
+
+impl PipelineNodeImpl for ResampleNode {
+    fn context(&self) -> &PipelineNodeContext {
+        &self.context
+    }
+
+    fn config(&self) -> &PipelineNodeConfig {
+        &self.config
+    }
+
+    fn children(&self) -> Vec<DistributedPipelineNode> {
+        vec![self.child.clone()]
+    }
+
+    fn multiline_display(&self, _verbose: bool) -> Vec<String> {
+        let mut res = vec!["Resample:".to_string(), format!("Every = {}", self.every)];
+        if let Some(fill) = self.fill {
+            res.push(format!("Fill = {fill}"));
+        }
+        res.push(format!(
+            "Output Schema = {}",
+            self.config.schema.short_string()
+        ));
+        res
+    }
+
+    fn produce_tasks(
+        self: Arc<Self>,
+        plan_context: &mut PlanExecutionContext,
+    ) -> SubmittableTaskStream {
+        let input_node = self.child.clone().produce_tasks(plan_context);
+
+        let self_clone = self.clone();
+        let plan_builder = move |input: LocalPhysicalPlanRef| -> LocalPhysicalPlanRef {
+            LocalPhysicalPlan::resample(
+                input,
+                self_clone.num_keys,
+                self_clone.every,
+                self_clone.fill,
+                self_clone.config.schema.clone(),
+                StatsState::NotMaterialized,
+                LocalNodeContext {
+                    origin_node_id: Some(self_clone.node_id() as usize),
+                    additional: None,
+                },
+            )
+        };
+
+        input_node.pipeline_instruction(self, plan_builder)
+    }
+}
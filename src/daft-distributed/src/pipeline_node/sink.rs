@@ -25,6 +25,9 @@ pub(crate) struct SinkNode {
     context: PipelineNodeContext,
     sink_info: Arc<SinkInfo<BoundExpr>>,
     data_schema: SchemaRef,
+    /// The id of the write if its tasks write their files to its staging directory, for them to be moved to the root
+    /// directory by the commit once every task has succeeded.
+    staged_write_id: Option<String>,
     child: DistributedPipelineNode,
 }
 
@@ -50,11 +53,15 @@ impl SinkNode {
             plan_config.config.clone(),
             child.config().clustering_spec.clone(),
         );
+        let staged_write_id = (plan_config.config.staged_writes
+            && matches!(sink_info.as_ref(), SinkInfo::OutputFileInfo(_)))
+        .then(|| format!("{}-{}", plan_config.query_id, node_id));
         Self {
             config,
             context,
             sink_info,
             data_schema,
+            staged_write_id,
             child,
         }
     }
@@ -75,7 +82,10 @@ impl SinkNode {
                 input,
                 data_schema,
                 file_schema,
-                info.clone(),
+                match &self.staged_write_id {
+                    Some(write_id) => info.staged(write_id),
+                    None => info.clone(),
+                },
                 StatsState::NotMaterialized,
                 LocalNodeContext {
                     origin_node_id: Some(node_id as usize),
@@ -133,8 +143,17 @@ impl SinkNode {
     ) -> DaftResult<()> {
         let file_schema = self.config.schema.clone();
         let materialized_stream = input.materialize(scheduler);
-        let materialized = materialized_stream.try_collect::<Vec<_>>().await?;
+        let materialized = match materialized_stream.try_collect::<Vec<_>>().await {
+            Ok(materialized) => materialized,
+            Err(err) => {
+                if let Some(write_id) = &self.staged_write_id {
+                    remove_staged_files(&info, write_id).await;
+                }
+                return Err(err);
+            }
+        };
         let node_id = self.node_id();
+        let staged_write_id = self.staged_write_id.clone();
         let task = make_new_task_from_materialized_outputs(
             TaskContext::from((&self.context, task_id_counter.next())),
             materialized,
@@ -146,6 +165,7 @@ impl SinkNode {
                     data_schema,
                     file_schema,
                     info,
+                    staged_write_id,
                     StatsState::NotMaterialized,
                     LocalNodeContext {
                         origin_node_id: Some(node_id as usize),
@@ -160,6 +180,24 @@ impl SinkNode {
     }
 }
 
+/// Deletes the files staged by the tasks of a failed write, so that they aren't left behind in its root directory.
+async fn remove_staged_files(info: &OutputFileInfo<BoundExpr>, write_id: &str) {
+    let staging_dir = info.staging_dir(write_id);
+    let removed =
+        match daft_io::get_io_client(true, info.io_config.clone().unwrap_or_default().into()) {
+            Ok(io_client) => io_client
+                .delete_dir(&staging_dir, None)
+                .await
+                .map_err(Into::into),
+            Err(err) => Err(err),
+        };
+    if let Err(err) = removed {
+        tracing::warn!(
+            "Failed to delete the staging directory {staging_dir} of a failed write: {err}"
+        );
+    }
+}
+
 impl PipelineNodeImpl for SinkNode {
     fn context(&self) -> &PipelineNodeContext {
         &self.context
@@ -201,6 +239,9 @@ impl PipelineNodeImpl for SinkNode {
                 res.push(format!("Sink: DataSink({})", data_sink_info.name));
             }
         }
+        if let Some(write_id) = &self.staged_write_id {
+            res.push(format!("Staged write = {write_id}"));
+        }
         res.push(format!(
             "Output schema = {}",
             self.config.schema.short_string()
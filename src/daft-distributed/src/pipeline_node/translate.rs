@@ -6,10 +6,12 @@ use common_partitioning::PartitionRef;
 use common_scan_info::{SPLIT_AND_MERGE_PASS, ScanState};
 use common_treenode::{TreeNode, TreeNodeRecursion, TreeNodeVisitor};
 use daft_dsl::{
+    Expr, ExprRef,
     expr::{
         agg::extract_agg_expr,
         bound_expr::{BoundAggExpr, BoundExpr, BoundVLLMExpr, BoundWindowExpr},
     },
+    functions::{FunctionExpr, partitioning::PartitioningExpr},
     is_partition_compatible, resolved_col,
 };
 use daft_logical_plan::{
@@ -25,9 +27,9 @@ use crate::{
         in_memory_source::InMemorySourceNode, into_batches::IntoBatchesNode,
         into_partitions::IntoPartitionsNode, limit::LimitNode,
         monotonically_increasing_id::MonotonicallyIncreasingIdNode, pivot::PivotNode,
-        project::ProjectNode, sample::SampleNode, scan_source::ScanSourceNode, sink::SinkNode,
-        sort::SortNode, top_n::TopNNode, udf::UDFNode, unpivot::UnpivotNode, vllm::VLLMNode,
-        window::WindowNode,
+        project::ProjectNode, resample::ResampleNode, sample::SampleNode,
+        scan_source::ScanSourceNode, sink::SinkNode, sort::SortNode, top_n::TopNNode, udf::UDFNode,
+        unpivot::UnpivotNode, vllm::VLLMNode, window::WindowNode,
     },
     plan::PlanConfig,
 };
@@ -94,6 +96,19 @@ impl LogicalPlanToPipelineNodeTranslator {
     }
 }
 
+/// Returns whether a partition expression buckets its values, such as an Iceberg bucket transform.
+fn is_bucket_partitioning(expr: &ExprRef) -> bool {
+    expr.exists(|e| {
+        matches!(
+            e.as_ref(),
+            Expr::Function {
+                func: FunctionExpr::Partitioning(PartitioningExpr::IcebergBucket(_)),
+                ..
+            }
+        )
+    })
+}
+
 impl TreeNodeVisitor for LogicalPlanToPipelineNodeTranslator {
     type Node = LogicalPlanRef;
 
@@ -220,12 +235,21 @@ impl TreeNodeVisitor for LogicalPlanToPipelineNodeTranslator {
             LogicalPlan::Limit(limit) => LimitNode::new(
                 self.get_next_pipeline_node_id(),
                 &self.plan_config,
-                limit.limit as usize,
+                Some(limit.limit as usize),
                 limit.offset.map(|x| x as usize),
                 node.schema(),
                 self.curr_node.pop().unwrap(),
             )
             .into_node(),
+            LogicalPlan::Offset(offset) => LimitNode::new(
+                self.get_next_pipeline_node_id(),
+                &self.plan_config,
+                None,
+                Some(offset.offset as usize),
+                node.schema(),
+                self.curr_node.pop().unwrap(),
+            )
+            .into_node(),
             LogicalPlan::Project(project) => {
                 let projection = BoundExpr::bind_all(&project.projection, &project.input.schema())?;
                 ProjectNode::new(
@@ -243,6 +267,8 @@ impl TreeNodeVisitor for LogicalPlanToPipelineNodeTranslator {
                     self.get_next_pipeline_node_id(),
                     &self.plan_config,
                     to_explode,
+                    explode.index_column.clone(),
+                    explode.pad_mismatched,
                     node.schema(),
                     self.curr_node.pop().unwrap(),
                 )
@@ -276,13 +302,34 @@ impl TreeNodeVisitor for LogicalPlanToPipelineNodeTranslator {
             .into_node(),
             LogicalPlan::Sink(sink) => {
                 let sink_info = sink.sink_info.bind(&sink.input.schema())?;
+                let input_node = self.curr_node.pop().unwrap();
+                // Each task writes a file for every partition of its rows, and bucketing spreads the rows of every
+                // task over all of the buckets, so the rows of bucketed writes are shuffled by their partition first.
+                let input_node = match sink_info.partition_cols() {
+                    Some(partition_cols)
+                        if partition_cols
+                            .iter()
+                            .any(|e| is_bucket_partitioning(e.inner()))
+                            && !Self::needs_hash_repartition(&input_node, partition_cols)? =>
+                    {
+                        self.gen_shuffle_node(
+                            RepartitionSpec::Hash(HashRepartitionConfig::new(
+                                None,
+                                partition_cols.iter().cloned().map(|e| e.into()).collect(),
+                            )),
+                            sink.input.schema(),
+                            input_node,
+                        )?
+                    }
+                    _ => input_node,
+                };
                 SinkNode::new(
                     self.get_next_pipeline_node_id(),
                     &self.plan_config,
                     sink_info.into(),
                     sink.schema.clone(),
                     sink.input.schema(),
-                    self.curr_node.pop().unwrap(),
+                    input_node,
                 )
                 .into_node()
             }
@@ -545,6 +592,47 @@ impl TreeNodeVisitor for LogicalPlanToPipelineNodeTranslator {
                 )
                 .into_node()
             }
+            LogicalPlan::Resample(resample) => {
+                let input_schema = resample.input.schema();
+                let by = BoundExpr::bind_all(&resample.by, &input_schema)?;
+                let group_by = [resample.by.clone(), vec![resample.bucket()]].concat();
+                let aggregations = resample
+                    .aggregations
+                    .iter()
+                    .map(|expr| {
+                        let agg_expr = extract_agg_expr(expr)?;
+                        BoundAggExpr::try_new(agg_expr, &input_schema)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+
+                // Generate the output schema for the aggregation
+                let output_fields = group_by
+                    .iter()
+                    .chain(&resample.aggregations)
+                    .map(|expr| expr.to_field(&input_schema))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let output_schema = Arc::new(Schema::new(output_fields));
+                let group_by = BoundExpr::bind_all(&group_by, &input_schema)?;
+
+                let input_node = self.curr_node.pop().unwrap();
+
+                // First stage: Aggregate into intervals, partitioned by the keys so that each partition holds all of
+                // the intervals of its keys
+                let agg =
+                    self.gen_agg_nodes(input_node, group_by, aggregations, output_schema, by)?;
+
+                // Final stage: Fill the gaps between the intervals
+                ResampleNode::new(
+                    self.get_next_pipeline_node_id(),
+                    &self.plan_config,
+                    resample.by.len(),
+                    resample.every,
+                    resample.fill,
+                    resample.output_schema.clone(),
+                    agg,
+                )
+                .into_node()
+            }
             LogicalPlan::VLLMProject(vllm_project) => {
                 let input_schema = vllm_project.input.schema();
                 let expr = BoundVLLMExpr::try_new(vllm_project.expr.clone(), &input_schema)?;
@@ -562,8 +650,7 @@ impl TreeNodeVisitor for LogicalPlanToPipelineNodeTranslator {
             LogicalPlan::SubqueryAlias(_)
             | LogicalPlan::Union(_)
             | LogicalPlan::Intersect(_)
-            | LogicalPlan::Shard(_)
-            | LogicalPlan::Offset(_) => {
+            | LogicalPlan::Shard(_) => {
                 panic!(
                     "Logical plan operator {} should be handled by the optimizer",
                     node.name()
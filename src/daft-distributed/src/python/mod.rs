@@ -4,12 +4,15 @@ use std::{collections::HashMap, sync::Arc};
 
 use common_daft_config::PyDaftExecutionConfig;
 use common_display::DisplayLevel;
+use common_error::DaftError;
 use common_partitioning::Partition;
 use common_py_serde::impl_bincode_py_state_serialization;
+use daft_functions::bloom_filter::BloomFilter;
 use daft_logical_plan::PyLogicalPlanBuilder;
+use daft_micropartition::python::PyMicroPartition;
 use futures::StreamExt;
 use progress_bar::FlotillaProgressBar;
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyBytes};
 use ray::{RayPartitionRef, RaySwordfishTask, RaySwordfishWorker, RayWorkerManager};
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
@@ -192,6 +195,24 @@ impl PyDistributedPhysicalPlanRunner {
     }
 }
 
+/// Builds a Bloom filter over the rows of a partition of join keys, sized for `num_items` keys in total so that the
+/// filters built from every partition of the keys can be merged.
+#[pyfunction]
+fn build_bloom_filter<'py>(
+    py: Python<'py>,
+    partition: &PyMicroPartition,
+    num_items: usize,
+    false_positive_rate: f64,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let mut filter = BloomFilter::new(num_items, false_positive_rate)?;
+    for keys in partition.inner.record_batches() {
+        filter.insert_keys(keys.columns())?;
+    }
+    let bytes = bincode::serde::encode_to_vec(&filter, bincode::config::legacy())
+        .map_err(|e| DaftError::InternalError(format!("Failed to serialize Bloom filter: {e}")))?;
+    Ok(PyBytes::new(py, &bytes))
+}
+
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<PyDistributedPhysicalPlan>()?;
     parent.add_class::<PyDistributedPhysicalPlanRunner>()?;
@@ -199,5 +220,6 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<RayPartitionRef>()?;
     parent.add_class::<RaySwordfishWorker>()?;
     parent.add_class::<RayTaskResult>()?;
+    parent.add_function(wrap_pyfunction!(build_bloom_filter, parent)?)?;
     Ok(())
 }
@@ -1,6 +1,7 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
 use common_error::DaftResult;
+use opentelemetry::{global, metrics::Histogram};
 
 use super::{
     scheduler::{PendingTask, ScheduledTask},
@@ -21,6 +22,8 @@ pub(super) struct Dispatcher<W: Worker> {
     // Mapping of joinset task id to the scheduled task
     // The scheduled task is kept here so that we can reschedule the task if it fails
     joinset_id_to_task: HashMap<JoinSetId, ScheduledTask<W::Task>>,
+    // Time from dispatching a task to receiving its result
+    task_duration_ms: Histogram<f64>,
 }
 
 impl<W: Worker> Dispatcher<W> {
@@ -28,6 +31,10 @@ impl<W: Worker> Dispatcher<W> {
         Self {
             task_result_joinset: JoinSet::new(),
             joinset_id_to_task: HashMap::new(),
+            task_duration_ms: global::meter("daft.distributed.scheduler")
+                .f64_histogram("daft.distributed.scheduler.task_duration_ms")
+                .with_unit("ms")
+                .build(),
         }
     }
 
@@ -57,9 +64,13 @@ impl<W: Worker> Dispatcher<W> {
                 .expect("Task should be present in task_context_to_task");
             let result_awaiter =
                 TaskResultAwaiter::new(result_handle, scheduled_task.cancel_token());
-            let id = self
-                .task_result_joinset
-                .spawn(result_awaiter.await_result());
+            let task_duration_ms = self.task_duration_ms.clone();
+            let dispatched_at = Instant::now();
+            let id = self.task_result_joinset.spawn(async move {
+                let status = result_awaiter.await_result().await;
+                task_duration_ms.record(dispatched_at.elapsed().as_secs_f64() * 1000.0, &[]);
+                status
+            });
             self.joinset_id_to_task.insert(id, scheduled_task);
         }
 
@@ -125,11 +136,15 @@ impl<W: Worker> Dispatcher<W> {
                         // Task worker died, add the task to the failed tasks, and mark the worker as dead
                         TaskStatus::WorkerDied => {
                             worker_manager.mark_worker_died(worker_id);
+                            let mut task = task;
+                            task.mark_retried();
                             let schedulable_task = PendingTask::new(task, result_tx, canc);
                             failed_tasks.push(schedulable_task);
                         }
                         // Task worker unavailable, add the task to the failed tasks
                         TaskStatus::WorkerUnavailable => {
+                            let mut task = task;
+                            task.mark_retried();
                             let schedulable_task = PendingTask::new(task, result_tx, canc);
                             failed_tasks.push(schedulable_task);
                         }
@@ -150,6 +165,10 @@ impl<W: Worker> Dispatcher<W> {
     pub fn has_running_tasks(&self) -> bool {
         !self.task_result_joinset.is_empty()
     }
+
+    pub fn num_running_tasks(&self) -> usize {
+        self.task_result_joinset.len()
+    }
 }
 
 struct CompletedTask<T: Task> {
@@ -8,6 +8,7 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use futures::FutureExt;
+use opentelemetry::global;
 use tokio_util::sync::CancellationToken;
 use tracing::instrument;
 
@@ -132,6 +133,17 @@ where
         worker_manager: Arc<dyn WorkerManager<Worker = W>>,
         statistics_manager: StatisticsManagerRef,
     ) -> DaftResult<()> {
+        let meter = global::meter("daft.distributed.scheduler");
+        let pending_tasks_gauge = meter
+            .u64_gauge("daft.distributed.scheduler.pending_tasks")
+            .build();
+        let running_tasks_gauge = meter
+            .u64_gauge("daft.distributed.scheduler.running_tasks")
+            .build();
+        let num_workers_gauge = meter
+            .u64_gauge("daft.distributed.scheduler.num_workers")
+            .build();
+
         let mut input_exhausted = false;
         let mut tick_interval = tokio::time::interval(SCHEDULER_TICK_INTERVAL);
         // Keep running until the input is exhausted, i.e. no more new tasks, and there are no more pending tasks in the scheduler
@@ -147,6 +159,10 @@ where
                 "Received worker snapshots");
             tracing::debug!(target: SCHEDULER_LOG_TARGET, worker_snapshots = %format!("{:#?}", worker_snapshots));
 
+            pending_tasks_gauge.record(scheduler.num_pending_tasks() as u64, &[]);
+            running_tasks_gauge.record(dispatcher.num_running_tasks() as u64, &[]);
+            num_workers_gauge.record(worker_snapshots.len() as u64, &[]);
+
             scheduler.update_worker_state(&worker_snapshots);
 
             // 1: Get all tasks that are ready to be scheduled
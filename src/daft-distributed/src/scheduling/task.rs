@@ -116,6 +116,9 @@ pub(crate) trait Task: Send + Sync + Clone + Debug + 'static {
     }
 
     fn task_name(&self) -> TaskName;
+
+    /// Called when the task is rescheduled because its worker died or was unavailable, before it is run again.
+    fn mark_retried(&mut self) {}
 }
 
 #[derive(Clone)]
@@ -216,6 +219,8 @@ pub(crate) struct SwordfishTask {
     psets: HashMap<String, Vec<PartitionRef>>,
     strategy: SchedulingStrategy,
     context: HashMap<String, String>,
+    /// The number of times the task has been retried, so that the outputs of different attempts can be told apart.
+    attempt: usize,
 }
 
 impl SwordfishTask {
@@ -229,6 +234,7 @@ impl SwordfishTask {
     ) -> Self {
         let resource_request = TaskResourceRequest::new(plan.resource_request());
         context.insert("task_id".to_string(), task_context.task_id.to_string());
+        context.insert("task_attempt".to_string(), "0".to_string());
 
         Self {
             task_context,
@@ -238,6 +244,7 @@ impl SwordfishTask {
             psets,
             strategy,
             context,
+            attempt: 0,
         }
     }
 
@@ -290,6 +297,12 @@ impl Task for SwordfishTask {
             task_id: self.task_context.task_id,
         }
     }
+
+    fn mark_retried(&mut self) {
+        self.attempt += 1;
+        self.context
+            .insert("task_attempt".to_string(), self.attempt.to_string());
+    }
 }
 
 #[derive(Debug)]
@@ -422,6 +422,16 @@ pub enum WindowExpr {
         offset: isize,
         default: Option<ExprRef>,
     },
+
+    // input: the column to sessionize, usually a timestamp
+    // gap: the largest difference between consecutive values of the same session
+    #[display("session_id({input}, {gap})")]
+    SessionId { input: ExprRef, gap: ExprRef },
+
+    // input: the column whose nulls are filled from the valid values around them
+    // strategy: forward or backward fill, or linear interpolation
+    #[display("fill_null({input}, {strategy})")]
+    FillNull { input: ExprRef, strategy: GapFill },
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -840,6 +850,8 @@ impl WindowExpr {
                 offset: _,
                 default: _,
             } => input.name(),
+            Self::SessionId { .. } => "session_id",
+            Self::FillNull { input, .. } => input.name(),
         }
     }
 
@@ -863,6 +875,15 @@ impl WindowExpr {
                 };
                 FieldID::new(format!("{child_id}.offset(offset={offset}{default_part})"))
             }
+            Self::SessionId { input, gap } => {
+                let child_id = input.semantic_id(schema);
+                let gap_id = gap.semantic_id(schema);
+                FieldID::new(format!("{child_id}.session_id(gap={gap_id})"))
+            }
+            Self::FillNull { input, strategy } => {
+                let child_id = input.semantic_id(schema);
+                FieldID::new(format!("{child_id}.fill_null(strategy={strategy})"))
+            }
         }
     }
 
@@ -883,6 +904,8 @@ impl WindowExpr {
                 }
                 children
             }
+            Self::SessionId { input, gap } => vec![input.clone(), gap.clone()],
+            Self::FillNull { input, .. } => vec![input.clone()],
         }
     }
 
@@ -915,6 +938,21 @@ impl WindowExpr {
                     default,
                 }
             }
+            Self::SessionId { .. } => {
+                let [input, gap] = children
+                    .try_into()
+                    .expect("SessionId should have exactly 2 children");
+                Self::SessionId { input, gap }
+            }
+            Self::FillNull { strategy, .. } => {
+                let [input] = children
+                    .try_into()
+                    .expect("FillNull should have exactly 1 child");
+                Self::FillNull {
+                    input,
+                    strategy: *strategy,
+                }
+            }
         }
     }
 
@@ -929,6 +967,18 @@ impl WindowExpr {
                 offset: _,
                 default: _,
             } => input.to_field(schema),
+            Self::SessionId { .. } => Ok(Field::new("session_id", DataType::UInt64)),
+            Self::FillNull { input, strategy } => {
+                let field = input.to_field(schema)?;
+                if *strategy == GapFill::Linear && !field.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Linear interpolation requires a numeric input, got {} of type {}",
+                        field.name, field.dtype
+                    )));
+                }
+                let dtype = strategy.filled_dtype(&field.dtype);
+                Ok(Field::new(field.name, dtype))
+            }
         }
     }
 }
@@ -1132,6 +1182,18 @@ impl Expr {
         .into()
     }
 
+    pub fn session_id(self: ExprRef, gap: ExprRef) -> ExprRef {
+        Self::WindowFunction(WindowExpr::SessionId { input: self, gap }).into()
+    }
+
+    pub fn window_fill_null(self: ExprRef, strategy: GapFill) -> ExprRef {
+        Self::WindowFunction(WindowExpr::FillNull {
+            input: self,
+            strategy,
+        })
+        .into()
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn not(self: ExprRef) -> ExprRef {
         Self::Not(self).into()
@@ -62,7 +62,7 @@ use crate::python::PyExpr;
 use crate::{
     Expr, ExprRef,
     functions::scalar::ScalarFn,
-    python_udf::{BatchPyFn, PyScalarFn},
+    python_udf::{BatchPyFn, PyScalarFn, RowWisePyFn},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -268,6 +268,32 @@ pub fn initialize_udfs(expr: ExprRef) -> DaftResult<ExprRef> {
     .map(|transformed| transformed.data)
 }
 
+/// Calls a lifecycle hook, such as `_daft_teardown`, on the classes of the Python UDFs in the expression.
+///
+/// A class is only called once, even if several of the UDFs in the expression are its methods.
+#[cfg(feature = "python")]
+pub fn call_udf_hook(expr: &ExprRef, hook: &str) -> DaftResult<()> {
+    let mut classes: Vec<RuntimePyObject> = Vec::new();
+    expr.apply(|e| {
+        if let Expr::ScalarFn(ScalarFn::Python(
+            PyScalarFn::RowWise(RowWisePyFn { cls, .. }) | PyScalarFn::Batch(BatchPyFn { cls, .. }),
+        )) = e.as_ref()
+            && !classes.contains(cls)
+        {
+            classes.push(cls.clone());
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+
+    Python::attach(|py| {
+        for cls in &classes {
+            cls.as_ref().call_method0(py, hook)?;
+        }
+        PyResult::Ok(())
+    })?;
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct UDFProperties {
     pub name: String,
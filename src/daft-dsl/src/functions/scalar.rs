@@ -238,6 +238,12 @@ pub trait ScalarUDF: Send + Sync + std::any::Any {
     fn docstring(&self) -> &'static str {
         "No documentation available"
     }
+
+    /// Returns a copy of the function with new state, for functions which keep state across the batches that they're
+    /// called on. The optimizer gives each query its own copy of them, so that queries don't share their state.
+    fn with_new_state(&self) -> Option<Arc<dyn ScalarUDF>> {
+        None
+    }
 }
 
 /// This is a concrete implementation of a ScalarFunction.
@@ -48,6 +48,7 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_function(wrap_pyfunction!(python::row_wise_udf, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::batch_udf, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::initialize_udfs, parent)?)?;
+    parent.add_function(wrap_pyfunction!(python::call_udf_hook, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::eq, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::row_number, parent)?)?;
     parent.add_function(wrap_pyfunction!(python::rank, parent)?)?;
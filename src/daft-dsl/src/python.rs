@@ -357,6 +357,13 @@ pub fn initialize_udfs(expr: PyExpr) -> PyResult<PyExpr> {
     Ok(initialize_udfs(expr.expr)?.into())
 }
 
+/// Calls a lifecycle hook on the classes of the Python UDFs in the expression
+#[pyfunction]
+pub fn call_udf_hook(expr: PyExpr, hook: &str) -> PyResult<()> {
+    crate::functions::python::call_udf_hook(&expr.expr, hook)?;
+    Ok(())
+}
+
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PyExpr {
@@ -681,6 +688,15 @@ impl PyExpr {
         })
     }
 
+    pub fn session_id(&self, gap: &Self) -> PyResult<Self> {
+        Ok(self.expr.clone().session_id(gap.expr.clone()).into())
+    }
+
+    pub fn window_fill_null(&self, strategy: &str) -> PyResult<Self> {
+        let strategy = GapFill::from_str(strategy)?;
+        Ok(self.expr.clone().window_fill_null(strategy).into())
+    }
+
     pub fn accept<'py>(&self, visitor: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
         accept(&self.clone(), visitor)
     }
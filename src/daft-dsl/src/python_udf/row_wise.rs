@@ -232,6 +232,22 @@ impl RowWisePyFn {
         }
     }
 
+    /// Describes the row a UDF failed on by its arguments, which are serialized like in the logs of failed rows.
+    #[cfg(feature = "python")]
+    fn failed_row_context(
+        row: usize,
+        arg_names: &[String],
+        py_args: &[pyo3::Bound<PyAny>],
+    ) -> String {
+        let args = arg_names
+            .iter()
+            .zip(py_args)
+            .take(20)
+            .map(|(name, arg)| format!("{name}={}", Self::serialize_pyobject_for_logging(arg)))
+            .join(", ");
+        format!("On row {row} with arguments {args}")
+    }
+
     #[cfg(feature = "python")]
     pub(crate) fn capture_exception_details<R: LogRecord>(
         py: Python,
@@ -351,7 +367,9 @@ impl RowWisePyFn {
                 let final_res = match retry_result.map(|(lit, _)| lit) {
                     Ok(result) => Ok(result),
                     Err(e) => match on_error {
-                        OnError::Raise => Err(e),
+                        OnError::Raise => {
+                            Err(e.with_context(Self::failed_row_context(i, &arg_names, &py_args)))
+                        }
                         OnError::Log => {
                             let lg = common_tracing::GLOBAL_LOGGER_PROVIDER.lock().unwrap();
                             if let Some(logger_provider) = lg.as_ref() {
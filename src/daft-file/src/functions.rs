@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use common_error::DaftError;
 use daft_core::{
     datatypes::FileArray,
@@ -279,3 +281,185 @@ impl ScalarUDF for Size {
         Ok(Field::new(name, DataType::UInt64))
     }
 }
+
+/// Uploads each binary value as a blob under a location and replaces it with a file reference to the blob.
+///
+/// This keeps large media values out of line, so partitions only carry the references through shuffles and
+/// consumers fetch the bytes lazily when they open the files.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct BlobFile;
+#[derive(FunctionArgs)]
+struct BlobFileArgs<T> {
+    input: T,
+    location: String,
+    #[arg(optional)]
+    max_connections: Option<usize>,
+    #[arg(optional)]
+    io_config: Option<IOConfig>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for BlobFile {
+    fn name(&self) -> &'static str {
+        "blob_file"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let BlobFileArgs {
+            input,
+            location,
+            max_connections,
+            io_config,
+        } = args.try_into()?;
+
+        if !matches!(
+            input.data_type(),
+            DataType::Binary | DataType::FixedSizeBinary(_)
+        ) {
+            return Err(DaftError::ValueError(format!(
+                "Unsupported data type for 'blob_file' function: {}. Expected Binary",
+                input.data_type()
+            )));
+        }
+        let location =
+            daft_core::prelude::Utf8Array::from_iter("location", std::iter::once(Some(location)))
+                .into_series();
+        let urls = daft_functions_uri::upload::url_upload(
+            &input,
+            &location,
+            max_connections.unwrap_or(32),
+            true,
+            true,
+            true,
+            Arc::new(io_config.clone().unwrap_or_default()),
+            None,
+        )?;
+        Ok(FileArray::<MediaTypeUnknown>::new_from_reference_array(
+            input.name(),
+            urls.utf8()?,
+            io_config,
+        )
+        .into_series())
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let BlobFileArgs { input, .. } = args.try_into()?;
+
+        let input = input.to_field(schema)?;
+        if !matches!(input.dtype, DataType::Binary | DataType::FixedSizeBinary(_)) {
+            return Err(DaftError::TypeError(format!(
+                "Expected input to blob_file to be Binary, got {}",
+                input.dtype
+            )));
+        }
+
+        Ok(Field::new(input.name, DataType::File(MediaType::Unknown)))
+    }
+}
+
+/// Reads the bytes of each file into a binary value, which reverses [`BlobFile`].
+///
+/// The files are fetched concurrently, with the IO config of their file references, so consumers of out of line
+/// blobs can fetch them in bulk rather than opening them one at a time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct BlobRead;
+#[derive(FunctionArgs)]
+struct BlobReadArgs<T> {
+    input: T,
+    #[arg(optional)]
+    max_connections: Option<usize>,
+}
+
+/// Downloads the files of `files`, in batches of the files which share an IO config.
+fn read_files(
+    name: &str,
+    files: Vec<Option<FileReference>>,
+    max_connections: usize,
+) -> DaftResult<Series> {
+    let mut groups: Vec<(Option<Arc<IOConfig>>, Vec<usize>)> = Vec::new();
+    for (i, file) in files.iter().enumerate() {
+        let Some(file) = file else { continue };
+        match groups
+            .iter_mut()
+            .find(|(io_config, _)| *io_config == file.io_config)
+        {
+            Some((_, rows)) => rows.push(i),
+            None => groups.push((file.io_config.clone(), vec![i])),
+        }
+    }
+
+    // Rows of the concatenated downloads of every group, followed by a null for the null files.
+    let mut positions = vec![None; files.len()];
+    let mut downloads = Vec::with_capacity(groups.len() + 1);
+    let mut num_downloaded = 0;
+    for (io_config, rows) in groups {
+        let urls = daft_core::prelude::Utf8Array::from_iter(
+            "url",
+            rows.iter()
+                .map(|&row| files[row].as_ref().map(|file| file.url.clone())),
+        );
+        let io_config = io_config.unwrap_or_default();
+        let download = common_runtime::get_io_runtime(true).block_within_async_context(
+            daft_functions_uri::download::url_download(
+                &urls,
+                max_connections,
+                true,
+                true,
+                io_config,
+                None,
+            ),
+        )??;
+        for (offset, &row) in rows.iter().enumerate() {
+            positions[row] = Some((num_downloaded + offset) as u64);
+        }
+        num_downloaded += rows.len();
+        downloads.push(download.into_series());
+    }
+    downloads.push(Series::full_null(name, &DataType::Binary, 1));
+
+    let null_position = num_downloaded as u64;
+    let indices = daft_core::prelude::UInt64Array::from((
+        "indices",
+        positions
+            .into_iter()
+            .map(|position| position.unwrap_or(null_position))
+            .collect::<Vec<_>>(),
+    ));
+    Ok(Series::concat(&downloads.iter().collect::<Vec<_>>())?
+        .take(&indices)?
+        .rename(name))
+}
+
+#[typetag::serde]
+impl ScalarUDF for BlobRead {
+    fn name(&self) -> &'static str {
+        "blob_read"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let BlobReadArgs {
+            input,
+            max_connections,
+        } = args.try_into()?;
+
+        let files = with_match_file_types!(input.data_type(), |$P| {
+            let files = input.file::<$P>()?;
+            (0..files.len()).map(|i| files.get(i)).collect::<Vec<_>>()
+        });
+        read_files(input.name(), files, max_connections.unwrap_or(32))
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let BlobReadArgs { input, .. } = args.try_into()?;
+
+        let input = input.to_field(schema)?;
+        if !matches!(input.dtype, DataType::File(_)) {
+            return Err(DaftError::TypeError(format!(
+                "Expected input to blob_read to be a file, got {}",
+                input.dtype
+            )));
+        }
+
+        Ok(Field::new(input.name, DataType::Binary))
+    }
+}
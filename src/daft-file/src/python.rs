@@ -38,6 +38,11 @@ impl PyFileReference {
         self.inner.as_ref().clone()
     }
 
+    #[getter]
+    fn url(&self) -> &str {
+        &self.inner.url
+    }
+
     fn __exit__(
         &mut self,
         _exc_type: Option<Py<PyAny>>,
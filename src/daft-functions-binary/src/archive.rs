@@ -0,0 +1,383 @@
+use std::{
+    io::{Cursor, Read},
+    str::FromStr,
+};
+
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    array::{ListArray, StructArray},
+    datatypes::{DataType, Field, TimeUnit},
+    prelude::{BinaryArray, Int64Array, Schema, TimestampArray, UInt64Array, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::pdf::raise_on_error;
+
+/// ```text
+/// archive_members(input)
+/// archive_members(input, format='tar')
+/// ```
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ArchiveMembers;
+
+#[derive(common_macros::FunctionArgs)]
+struct ArchiveMembersArgs<T> {
+    input: T,
+    #[arg(optional)]
+    format: Option<String>,
+    #[arg(optional)]
+    on_error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    /// A gzip-compressed tar archive.
+    TarGz,
+    Zip,
+}
+
+impl FromStr for ArchiveFormat {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "tar" => Ok(Self::Tar),
+            "tar.gz" | "tgz" => Ok(Self::TarGz),
+            "zip" => Ok(Self::Zip),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported archive format: {s}, expected one of 'tar', 'tar.gz' or 'zip'"
+            ))),
+        }
+    }
+}
+
+impl ArchiveFormat {
+    /// Infers the format of an archive from its magic bytes, assuming tar if it isn't recognized.
+    fn infer(bytes: &[u8]) -> Self {
+        if bytes.starts_with(b"PK\x03\x04") || bytes.starts_with(b"PK\x05\x06") {
+            Self::Zip
+        } else if bytes.starts_with(&[0x1f, 0x8b]) {
+            Self::TarGz
+        } else {
+            Self::Tar
+        }
+    }
+}
+
+/// A regular file within an archive.
+#[derive(Debug, PartialEq, Eq)]
+struct Member {
+    path: String,
+    bytes: Vec<u8>,
+    /// The modification time in microseconds since the Unix epoch, if recorded.
+    mtime: Option<i64>,
+}
+
+fn member_fields() -> Vec<Field> {
+    vec![
+        Field::new("path", DataType::Utf8),
+        Field::new("bytes", DataType::Binary),
+        Field::new("size", DataType::UInt64),
+        Field::new(
+            "mtime",
+            DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string())),
+        ),
+    ]
+}
+
+fn parse_format(format: Option<&str>) -> DaftResult<Option<ArchiveFormat>> {
+    match format {
+        None => Ok(None),
+        Some(format) if format.eq_ignore_ascii_case("auto") => Ok(None),
+        Some(format) => format.parse().map(Some),
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for ArchiveMembers {
+    fn name(&self) -> &'static str {
+        "archive_members"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let ArchiveMembersArgs {
+            input,
+            format,
+            on_error,
+        } = inputs.try_into()?;
+        parse_format(format.as_deref())?;
+        raise_on_error(on_error.as_deref())?;
+        let input = input.to_field(schema)?;
+        ensure!(
+            matches!(input.dtype, DataType::Binary | DataType::FixedSizeBinary(_)),
+            TypeError: "Expected argument to be a Binary or FixedSizeBinary, but received {}",
+            input.dtype
+        );
+        Ok(Field::new(
+            input.name,
+            DataType::List(Box::new(DataType::Struct(member_fields()))),
+        ))
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let ArchiveMembersArgs {
+            input,
+            format,
+            on_error,
+        } = inputs.try_into()?;
+        let format = parse_format(format.as_deref())?;
+        let raise = raise_on_error(on_error.as_deref())?;
+        let input = input.cast(&DataType::Binary)?;
+        let arr = input.binary()?;
+
+        let mut members = Vec::new();
+        let mut offsets = daft_arrow::offset::Offsets::<i64>::new();
+        let mut validity = daft_arrow::buffer::NullBufferBuilder::new(arr.len());
+        for (index, val) in arr.into_iter().enumerate() {
+            let extracted = val
+                .map(|bytes| {
+                    read_members(bytes, format.unwrap_or_else(|| ArchiveFormat::infer(bytes)))
+                })
+                .transpose();
+            let extracted = match extracted {
+                Ok(extracted) => extracted,
+                Err(err) if raise => return Err(err),
+                Err(err) => {
+                    log::warn!(
+                        "Error occurred during archive extraction at index: {index} {err} (falling back to Null)"
+                    );
+                    None
+                }
+            };
+            match extracted {
+                Some(extracted) => {
+                    offsets.try_push(extracted.len() as i64)?;
+                    members.extend(extracted);
+                    validity.append_non_null();
+                }
+                None => {
+                    offsets.try_push(0)?;
+                    validity.append_null();
+                }
+            }
+        }
+
+        let paths = Utf8Array::from_iter("path", members.iter().map(|m| Some(m.path.as_str())));
+        let sizes = UInt64Array::from_iter(
+            Field::new("size", DataType::UInt64),
+            members.iter().map(|m| Some(m.bytes.len() as u64)),
+        );
+        let mtimes = TimestampArray::new(
+            Field::new(
+                "mtime",
+                DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string())),
+            ),
+            Int64Array::from_iter(
+                Field::new("mtime", DataType::Int64),
+                members.iter().map(|m| m.mtime),
+            ),
+        );
+        let bytes = BinaryArray::from_values("bytes", members.into_iter().map(|m| m.bytes));
+        let flat_child = StructArray::new(
+            Field::new("members", DataType::Struct(member_fields())),
+            vec![
+                paths.into_series(),
+                bytes.into_series(),
+                sizes.into_series(),
+                mtimes.into_series(),
+            ],
+            None,
+        );
+
+        Ok(ListArray::new(
+            Field::new(
+                arr.name(),
+                DataType::List(Box::new(DataType::Struct(member_fields()))),
+            ),
+            flat_child.into_series(),
+            offsets.into(),
+            validity.finish(),
+        )
+        .into_series())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Extracts the files of tar or zip archives as a list of structs of their path, bytes, size and modification time. 7z archives aren't supported."
+    }
+}
+
+/// The size of the largest member which is extracted. Sizes recorded in an archive's headers aren't trusted, since a
+/// malformed archive, or a small compressed one, can claim or expand to far more bytes than it holds.
+const MAX_MEMBER_SIZE: u64 = 1 << 31;
+
+fn archive_error(err: impl std::fmt::Display) -> DaftError {
+    DaftError::ValueError(format!("Failed to read archive: {err}"))
+}
+
+/// Reads the regular files of an archive in the order they're stored, skipping directories and links.
+fn read_members(bytes: &[u8], format: ArchiveFormat) -> DaftResult<Vec<Member>> {
+    read_members_up_to(bytes, format, MAX_MEMBER_SIZE)
+}
+
+fn read_members_up_to(
+    bytes: &[u8],
+    format: ArchiveFormat,
+    max_member_size: u64,
+) -> DaftResult<Vec<Member>> {
+    match format {
+        ArchiveFormat::Tar => read_tar_members(bytes, max_member_size),
+        ArchiveFormat::TarGz => {
+            read_tar_members(flate2::read::GzDecoder::new(bytes), max_member_size)
+        }
+        ArchiveFormat::Zip => read_zip_members(bytes, max_member_size),
+    }
+}
+
+/// Reads the contents of a member, failing if it's larger than `max_member_size`.
+fn read_member_bytes(reader: impl Read, path: &str, max_member_size: u64) -> DaftResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader
+        .take(max_member_size.saturating_add(1))
+        .read_to_end(&mut bytes)
+        .map_err(archive_error)?;
+    if bytes.len() as u64 > max_member_size {
+        return Err(DaftError::ValueError(format!(
+            "Failed to read archive: member {path} is larger than the limit of {max_member_size} bytes"
+        )));
+    }
+    Ok(bytes)
+}
+
+fn read_tar_members(reader: impl Read, max_member_size: u64) -> DaftResult<Vec<Member>> {
+    let mut archive = tar::Archive::new(reader);
+    let mut members = Vec::new();
+    for entry in archive.entries().map_err(archive_error)? {
+        let mut entry = entry.map_err(archive_error)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry
+            .path()
+            .map_err(archive_error)?
+            .to_string_lossy()
+            .into_owned();
+        let mtime = entry
+            .header()
+            .mtime()
+            .ok()
+            .and_then(|secs| i64::try_from(secs).ok())
+            .and_then(|secs| secs.checked_mul(1_000_000));
+        let bytes = read_member_bytes(&mut entry, &path, max_member_size)?;
+        members.push(Member { path, bytes, mtime });
+    }
+    Ok(members)
+}
+
+fn read_zip_members(bytes: &[u8], max_member_size: u64) -> DaftResult<Vec<Member>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(archive_error)?;
+    let mut members = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(archive_error)?;
+        if !file.is_file() {
+            continue;
+        }
+        let path = file.name().to_string();
+        // Zip archives record local times without a time zone, which are read as UTC.
+        let mtime = file
+            .last_modified()
+            .and_then(|mtime| chrono::NaiveDateTime::try_from(mtime).ok())
+            .map(|mtime| mtime.and_utc().timestamp_micros());
+        let bytes = read_member_bytes(&mut file, &path, max_member_size)?;
+        members.push(Member { path, bytes, mtime });
+    }
+    Ok(members)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::{ArchiveFormat, read_members, read_members_up_to};
+
+    fn tar_archive() -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, data) in [("a/0001.jpg", &b"jpeg"[..]), ("a/0001.json", &b"{}"[..])] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mtime(1_700_000_000);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_read_tar_members() {
+        let bytes = tar_archive();
+        assert_eq!(ArchiveFormat::infer(&bytes), ArchiveFormat::Tar);
+        let members = read_members(&bytes, ArchiveFormat::Tar).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].path, "a/0001.jpg");
+        assert_eq!(members[0].bytes, b"jpeg");
+        assert_eq!(members[0].mtime, Some(1_700_000_000_000_000));
+        assert_eq!(members[1].path, "a/0001.json");
+    }
+
+    #[test]
+    fn test_read_tar_gz_members() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(&tar_archive()).unwrap();
+        let bytes = encoder.finish().unwrap();
+        assert_eq!(ArchiveFormat::infer(&bytes), ArchiveFormat::TarGz);
+        let members = read_members(&bytes, ArchiveFormat::TarGz).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[1].bytes, b"{}");
+    }
+
+    #[test]
+    fn test_read_zip_members() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .add_directory("dir/", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer
+            .start_file("dir/hello.txt", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"hello").unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        assert_eq!(ArchiveFormat::infer(&bytes), ArchiveFormat::Zip);
+        let members = read_members(&bytes, ArchiveFormat::Zip).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].path, "dir/hello.txt");
+        assert_eq!(members[0].bytes, b"hello");
+    }
+
+    #[test]
+    fn test_read_oversized_member() {
+        // The first member is 4 bytes, and the second 2 bytes.
+        let bytes = tar_archive();
+        assert!(read_members_up_to(&bytes, ArchiveFormat::Tar, 4).is_ok());
+        let err = read_members_up_to(&bytes, ArchiveFormat::Tar, 3).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("a/0001.jpg is larger than the limit of 3 bytes")
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_archive() {
+        assert!(read_members(b"not an archive", ArchiveFormat::Zip).is_err());
+    }
+}
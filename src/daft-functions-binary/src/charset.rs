@@ -0,0 +1,157 @@
+use chardetng::EncodingDetector;
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    datatypes::{DataType, Field},
+    prelude::{Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use encoding_rs::Encoding;
+use serde::{Deserialize, Serialize};
+
+/// Guesses the character encoding of the bytes, allowing UTF-8.
+fn detect(bytes: &[u8]) -> &'static Encoding {
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    detector.guess(None, true)
+}
+
+fn ensure_binary_input(input: &ExprRef, schema: &Schema) -> DaftResult<Field> {
+    let input = input.to_field(schema)?;
+    ensure!(
+        matches!(input.dtype, DataType::Binary | DataType::FixedSizeBinary(_)),
+        TypeError: "Expected argument to be a Binary or FixedSizeBinary, but received {}",
+        input.dtype
+    );
+    Ok(input)
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DetectEncoding;
+
+#[derive(common_macros::FunctionArgs)]
+struct DetectEncodingArgs<T> {
+    input: T,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DetectEncoding {
+    fn name(&self) -> &'static str {
+        "detect_encoding"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let DetectEncodingArgs { input } = inputs.try_into()?;
+        let input = ensure_binary_input(&input, schema)?;
+        Ok(Field::new(input.name, DataType::Utf8))
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let DetectEncodingArgs { input } = inputs.try_into()?;
+        let input = input.cast(&DataType::Binary)?;
+        let arr = input.binary()?;
+        Ok(Utf8Array::from_iter(
+            arr.name(),
+            arr.into_iter()
+                .map(|val| val.map(|bytes| detect(bytes).name())),
+        )
+        .into_series())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Guesses the character encoding of binary data, returning its WHATWG name, e.g. 'UTF-8' or 'Shift_JIS'."
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DecodeText;
+
+#[derive(common_macros::FunctionArgs)]
+struct DecodeTextArgs<T> {
+    input: T,
+    #[arg(optional)]
+    encoding: Option<String>,
+}
+
+/// Looks up an encoding by its label, or `None` to detect the encoding of each value.
+fn lookup_encoding(encoding: Option<&str>) -> DaftResult<Option<&'static Encoding>> {
+    match encoding {
+        None => Ok(None),
+        Some(label) if label.eq_ignore_ascii_case("auto") => Ok(None),
+        Some(label) => Encoding::for_label(label.as_bytes())
+            .map(Some)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!("Unsupported character encoding: {label}"))
+            }),
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for DecodeText {
+    fn name(&self) -> &'static str {
+        "decode_text"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let DecodeTextArgs { input, encoding } = inputs.try_into()?;
+        lookup_encoding(encoding.as_deref())?;
+        let input = ensure_binary_input(&input, schema)?;
+        Ok(Field::new(input.name, DataType::Utf8))
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let DecodeTextArgs { input, encoding } = inputs.try_into()?;
+        let encoding = lookup_encoding(encoding.as_deref())?;
+        let input = input.cast(&DataType::Binary)?;
+        let arr = input.binary()?;
+        Ok(Utf8Array::from_iter(
+            arr.name(),
+            arr.into_iter().map(|val| {
+                val.map(|bytes| {
+                    // Malformed sequences are replaced with U+FFFD, and a byte order mark overrides the encoding.
+                    let (text, _, _) = encoding.unwrap_or_else(|| detect(bytes)).decode(bytes);
+                    text
+                })
+            }),
+        )
+        .into_series())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Decodes binary data in the given character encoding to a string, detecting the encoding if none is given."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect, lookup_encoding};
+
+    #[test]
+    fn test_detect_encoding() {
+        assert_eq!(detect("héllo wörld".as_bytes()).name(), "UTF-8");
+        // "Привет, как дела? Это проверка." in windows-1251.
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode("Привет, как дела? Это проверка.");
+        assert_eq!(detect(&bytes).name(), "windows-1251");
+    }
+
+    #[test]
+    fn test_lookup_encoding() {
+        assert_eq!(
+            lookup_encoding(Some("latin1")).unwrap().unwrap().name(),
+            "windows-1252"
+        );
+        assert!(lookup_encoding(Some("auto")).unwrap().is_none());
+        assert!(lookup_encoding(Some("klingon")).is_err());
+    }
+}
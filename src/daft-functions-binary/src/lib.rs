@@ -1,19 +1,29 @@
+mod archive;
+mod charset;
 mod codecs;
 mod decode;
 mod encode;
 mod kernels;
+mod pdf;
+pub use archive::ArchiveMembers;
+pub use charset::{DecodeText, DetectEncoding};
 pub use codecs::Codec;
 use daft_dsl::functions::{FunctionModule, FunctionRegistry};
 pub use decode::{BinaryDecode, BinaryTryDecode};
 pub use encode::{BinaryEncode, BinaryTryEncode};
+pub use pdf::ExtractPdfText;
 
 pub struct BinaryFunctions;
 
 impl FunctionModule for BinaryFunctions {
     fn register(parent: &mut FunctionRegistry) {
+        parent.add_fn(archive::ArchiveMembers);
+        parent.add_fn(charset::DetectEncoding);
+        parent.add_fn(charset::DecodeText);
         parent.add_fn(decode::BinaryDecode);
         parent.add_fn(decode::BinaryTryDecode);
         parent.add_fn(encode::BinaryEncode);
         parent.add_fn(encode::BinaryTryEncode);
+        parent.add_fn(pdf::ExtractPdfText);
     }
 }
@@ -0,0 +1,126 @@
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use common_error::{DaftError, DaftResult, ensure};
+use daft_arrow::array::Array;
+use daft_core::{
+    array::ListArray,
+    datatypes::{DataType, Field},
+    prelude::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use serde::{Deserialize, Serialize};
+
+/// ```text
+/// extract_pdf_text(input)
+/// extract_pdf_text(input, on_error='null')
+/// ```
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ExtractPdfText;
+
+#[derive(common_macros::FunctionArgs)]
+struct ExtractPdfTextArgs<T> {
+    input: T,
+    #[arg(optional)]
+    on_error: Option<String>,
+}
+
+/// Parses the `on_error` argument, returning whether errors should be raised rather than replaced by nulls.
+pub(crate) fn raise_on_error(on_error: Option<&str>) -> DaftResult<bool> {
+    match on_error.map(str::to_lowercase).as_deref() {
+        None | Some("raise") => Ok(true),
+        Some("null") => Ok(false),
+        Some(other) => Err(DaftError::ValueError(format!(
+            "Invalid on_error value: {other}"
+        ))),
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for ExtractPdfText {
+    fn name(&self) -> &'static str {
+        "extract_pdf_text"
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let ExtractPdfTextArgs { input, on_error } = inputs.try_into()?;
+        raise_on_error(on_error.as_deref())?;
+        let input = input.to_field(schema)?;
+        ensure!(
+            matches!(input.dtype, DataType::Binary | DataType::FixedSizeBinary(_)),
+            TypeError: "Expected argument to be a Binary or FixedSizeBinary, but received {}",
+            input.dtype
+        );
+        Ok(Field::new(
+            input.name,
+            DataType::List(Box::new(DataType::Utf8)),
+        ))
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let ExtractPdfTextArgs { input, on_error } = inputs.try_into()?;
+        let raise = raise_on_error(on_error.as_deref())?;
+        let input = input.cast(&DataType::Binary)?;
+        let arr = input.binary()?;
+
+        let mut pages = daft_arrow::array::MutableUtf8Array::<i64>::new();
+        let mut offsets = daft_arrow::offset::Offsets::<i64>::new();
+        let mut validity = daft_arrow::buffer::NullBufferBuilder::new(arr.len());
+        for (index, val) in arr.into_iter().enumerate() {
+            let extracted = val.map(extract_pages).transpose();
+            let extracted = match extracted {
+                Ok(extracted) => extracted,
+                Err(err) if raise => return Err(err),
+                Err(err) => {
+                    log::warn!(
+                        "Error occurred during PDF text extraction at index: {index} {err} (falling back to Null)"
+                    );
+                    None
+                }
+            };
+            match extracted {
+                Some(extracted) => {
+                    offsets.try_push(extracted.len() as i64)?;
+                    for page in extracted {
+                        pages.push(Some(page));
+                    }
+                    validity.append_non_null();
+                }
+                None => {
+                    offsets.try_push(0)?;
+                    validity.append_null();
+                }
+            }
+        }
+
+        let pages: daft_arrow::array::Utf8Array<i64> = pages.into();
+        let flat_child = Series::try_from(("pages", pages.to_boxed()))?;
+        Ok(ListArray::new(
+            Field::new(arr.name(), DataType::List(Box::new(DataType::Utf8))),
+            flat_child,
+            offsets.into(),
+            validity.finish(),
+        )
+        .into_series())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Extracts the text of each page of a PDF document."
+    }
+}
+
+/// Extracts the text of each page, turning panics on malformed documents into errors.
+fn extract_pages(bytes: &[u8]) -> DaftResult<Vec<String>> {
+    catch_unwind(AssertUnwindSafe(|| {
+        pdf_extract::extract_text_from_mem_by_pages(bytes)
+    }))
+    .map_err(|_| DaftError::ValueError("Malformed PDF document".to_string()))?
+    .map_err(|e| DaftError::ValueError(format!("Failed to extract text from PDF: {e}")))
+}
@@ -0,0 +1,75 @@
+use common_error::{DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::series::SeriesListExtension;
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ListContains;
+
+#[typetag::serde]
+impl ScalarUDF for ListContains {
+    fn name(&self) -> &'static str {
+        "list_contains"
+    }
+    fn aliases(&self) -> &'static [&'static str] {
+        &["array_contains"]
+    }
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let input = inputs.required((0, "input"))?;
+        let item = inputs.required((1, "item"))?;
+
+        // A single list can be checked against a column of items
+        let input = if input.len() == 1 && item.len() != 1 {
+            &input.broadcast(item.len())?
+        } else {
+            input
+        };
+
+        Ok(input.list_contains(item)?.into_series())
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        ensure!(
+            inputs.len() == 2,
+            SchemaMismatch: "Expected 2 input args, got {}",
+            inputs.len()
+        );
+
+        let input = inputs.required((0, "input"))?.to_field(schema)?;
+        let item = inputs.required((1, "item"))?.to_field(schema)?;
+
+        ensure!(
+            input.dtype.is_list() || input.dtype.is_fixed_size_list(),
+            "Input must be a list"
+        );
+
+        let input_exploded = input.to_exploded_field()?;
+        ensure!(
+            input_exploded.dtype.is_numeric() && item.dtype.is_numeric()
+                || input_exploded.dtype == item.dtype
+                || item.dtype.is_null(),
+            TypeError: "Cannot check whether list of type {} contains value of type {}",
+            input_exploded.dtype,
+            item.dtype
+        );
+
+        Ok(Field::new(input.name, DataType::Boolean))
+    }
+}
+
+#[must_use]
+pub fn list_contains(expr: ExprRef, item: ExprRef) -> ExprRef {
+    ScalarFn::builtin(ListContains {}, vec![expr, item]).into()
+}
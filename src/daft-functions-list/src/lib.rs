@@ -2,6 +2,7 @@ mod append;
 mod bool_and;
 mod bool_or;
 mod chunk;
+mod contains;
 mod count;
 mod count_distinct;
 mod distinct;
@@ -13,6 +14,7 @@ mod list_map;
 mod max;
 mod mean;
 mod min;
+mod slice;
 mod sort;
 mod sum;
 mod value_counts;
@@ -21,6 +23,7 @@ pub use append::{ListAppend, list_append as append};
 pub use bool_and::{ListBoolAnd, list_bool_and as bool_and};
 pub use bool_or::{ListBoolOr, list_bool_or as bool_or};
 pub use chunk::{ListChunk, list_chunk as chunk};
+pub use contains::{ListContains, list_contains as contains};
 pub use count::ListCount;
 pub use count_distinct::{ListCountDistinct, list_count_distinct as count_distinct};
 pub use distinct::{ListDistinct, list_distinct as distinct};
@@ -31,6 +34,7 @@ pub use list_fill::{ListFill, list_fill};
 pub use max::{ListMax, list_max as max};
 pub use mean::{ListMean, list_mean as mean};
 pub use min::{ListMin, list_min as min};
+pub use slice::{ListSlice, list_slice as slice};
 pub use sort::{ListSort, list_sort as sort};
 pub use sum::{ListSum, list_sum as sum};
 pub use value_counts::{ListValueCounts, list_value_counts as value_counts};
@@ -50,6 +54,7 @@ impl FunctionModule for ListFunctions {
         parent.add_fn(ListBoolAnd);
         parent.add_fn(ListBoolOr);
         parent.add_fn(ListChunk);
+        parent.add_fn(ListContains);
         parent.add_fn(ListCount);
         parent.add_fn(ListCountDistinct);
         parent.add_fn(ListDistinct);
@@ -60,6 +65,7 @@ impl FunctionModule for ListFunctions {
         parent.add_fn(ListMax);
         parent.add_fn(ListMean);
         parent.add_fn(ListMin);
+        parent.add_fn(ListSlice);
         parent.add_fn(ListSort);
         parent.add_fn(ListSum);
         parent.add_fn(ListValueCounts);
@@ -3,8 +3,12 @@ use std::sync::Arc;
 use common_error::{DaftError, DaftResult};
 use daft_arrow::offset::OffsetsBuffer;
 use daft_core::{
-    array::{ListArray, growable::make_growable, ops::GroupIndices},
-    prelude::{CountMode, DataType, Field, Int64Array, UInt64Array, Utf8Array},
+    array::{
+        ListArray,
+        growable::make_growable,
+        ops::{DaftCompare, GroupIndices},
+    },
+    prelude::{BooleanArray, CountMode, DataType, Field, Int64Array, UInt64Array, Utf8Array},
     series::{IntoSeries, Series},
 };
 
@@ -29,6 +33,17 @@ pub trait SeriesListExtension: Sized {
     fn list_fill(&self, num: &Int64Array) -> DaftResult<Self>;
     fn list_distinct(&self) -> DaftResult<Self>;
     fn list_append(&self, other: &Self) -> DaftResult<Self>;
+    fn list_contains(&self, item: &Self) -> DaftResult<BooleanArray>;
+    fn list_slice(&self, start: &Int64Array, end: Option<&Int64Array>) -> DaftResult<Self>;
+}
+
+/// Converts a negative index to one from the end of a list of length `len`, and bounds it to `0..=len`.
+fn bound_index(idx: i64, len: usize) -> usize {
+    if idx >= 0 {
+        (idx as usize).min(len)
+    } else {
+        (len as i64 + idx).max(0) as usize
+    }
 }
 
 impl SeriesListExtension for Series {
@@ -368,4 +383,118 @@ impl SeriesListExtension for Series {
 
         Ok(list_array.into_series())
     }
+
+    /// Whether each list contains the corresponding `item`, which may be broadcast from a single value.
+    ///
+    /// Null lists and null items produce null. Null elements never match.
+    fn list_contains(&self, item: &Self) -> DaftResult<BooleanArray> {
+        let input = if let DataType::FixedSizeList(inner_type, _) = self.data_type() {
+            self.cast(&DataType::List(inner_type.clone()))?
+        } else {
+            self.clone()
+        };
+        let list = input.list()?;
+        let len = list.len();
+        if item.len() != 1 && item.len() != len {
+            return Err(DaftError::ValueError(format!(
+                "Expected list_contains item to have length 1 or {len}, got {}",
+                item.len()
+            )));
+        }
+
+        // Align the item with every element of the lists, so that they can be compared in one pass.
+        let offsets = list.offsets();
+        let aligned_item = if item.len() == 1 {
+            item.broadcast(list.flat_child.len())?
+        } else {
+            let mut indices = vec![0u64; list.flat_child.len()];
+            for i in 0..len {
+                let (start, end) = offsets.start_end(i);
+                indices[start..end].fill(i as u64);
+            }
+            item.take(&UInt64Array::from(("indices", indices)))?
+        };
+        let matches = list.flat_child.equal(&aligned_item)?;
+
+        let results = (0..len)
+            .map(|i| {
+                let item_idx = if item.len() == 1 { 0 } else { i };
+                if !input.is_valid(i) || !item.is_valid(item_idx) {
+                    return None;
+                }
+                let (start, end) = offsets.start_end(i);
+                Some((start..end).any(|j| matches.get(j) == Some(true)))
+            })
+            .collect::<Vec<_>>();
+        Ok(BooleanArray::from_iter(self.name(), results.into_iter()))
+    }
+
+    /// Slices each list from `start` (inclusive) to `end` (exclusive), or to the end of the list if there is no `end`.
+    /// The bounds may be broadcast from a single value, and negative bounds count from the end of the list.
+    ///
+    /// Null lists and null bounds produce null. The elements of the slices are copied from the flat child directly,
+    /// without splitting it into a series per list.
+    fn list_slice(&self, start: &Int64Array, end: Option<&Int64Array>) -> DaftResult<Self> {
+        let input = if let DataType::FixedSizeList(inner_type, _) = self.data_type() {
+            self.cast(&DataType::List(inner_type.clone()))?
+        } else {
+            self.clone()
+        };
+        let list = input.list()?;
+        let len = list.len();
+        for (name, bound_len) in [("start", Some(start.len())), ("end", end.map(|e| e.len()))] {
+            if let Some(bound_len) = bound_len
+                && bound_len != 1
+                && bound_len != len
+            {
+                return Err(DaftError::ValueError(format!(
+                    "Expected list_slice {name} to have length 1 or {len}, got {bound_len}"
+                )));
+            }
+        }
+        let bound_at =
+            |bound: &Int64Array, i: usize| bound.get(if bound.len() == 1 { 0 } else { i });
+
+        let child_data_type = list.child_data_type().clone();
+        let mut growable = make_growable(
+            list.flat_child.name(),
+            &child_data_type,
+            vec![&list.flat_child],
+            false,
+            list.flat_child.len(),
+        );
+
+        let list_offsets = list.offsets();
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i64);
+        let mut validity = Vec::with_capacity(len);
+        let mut current_offset = 0i64;
+        for i in 0..len {
+            let list_start = bound_at(start, i);
+            let list_end = end.map(|end| bound_at(end, i));
+            let is_valid = input.is_valid(i) && list_start.is_some() && list_end != Some(None);
+            if is_valid {
+                let (offset, next_offset) = list_offsets.start_end(i);
+                let list_len = next_offset - offset;
+                let slice_start = bound_index(list_start.unwrap(), list_len);
+                let slice_end = list_end
+                    .flatten()
+                    .map_or(list_len, |list_end| bound_index(list_end, list_len));
+                if slice_start < slice_end {
+                    growable.extend(0, offset + slice_start, slice_end - slice_start);
+                    current_offset += (slice_end - slice_start) as i64;
+                }
+            }
+            offsets.push(current_offset);
+            validity.push(is_valid);
+        }
+
+        let list_array = ListArray::new(
+            Arc::new(Field::new(input.name(), input.data_type().clone())),
+            growable.build()?,
+            OffsetsBuffer::try_from(offsets)?,
+            Some(daft_arrow::buffer::NullBuffer::from_iter(validity)),
+        );
+        Ok(list_array.into_series())
+    }
 }
@@ -0,0 +1,81 @@
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::series::SeriesListExtension;
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ListSlice;
+
+#[derive(FunctionArgs)]
+struct ListSliceArgs<T> {
+    input: T,
+    start: T,
+    #[arg(optional)]
+    end: Option<T>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for ListSlice {
+    fn name(&self) -> &'static str {
+        "list_slice"
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let ListSliceArgs { input, start, end } = inputs.try_into()?;
+
+        // A single list can be sliced by columns of bounds
+        let len = start.len().max(end.as_ref().map_or(1, Series::len));
+        let input = if input.len() == 1 && len != 1 {
+            input.broadcast(len)?
+        } else {
+            input
+        };
+
+        let start = start.cast(&DataType::Int64)?;
+        let end = end.map(|end| end.cast(&DataType::Int64)).transpose()?;
+        input.list_slice(start.i64()?, end.as_ref().map(|end| end.i64()).transpose()?)
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let ListSliceArgs { input, start, end } = inputs.try_into()?;
+
+        let input = input.to_field(schema)?;
+        let (DataType::List(inner_dtype) | DataType::FixedSizeList(inner_dtype, _)) = input.dtype
+        else {
+            return Err(DaftError::TypeError(format!(
+                "Input to `list_slice` must be a list, received: {}",
+                input.dtype
+            )));
+        };
+        for (name, bound) in [("start", Some(start)), ("end", end)] {
+            if let Some(bound) = bound {
+                let dtype = bound.get_type(schema)?;
+                ensure!(
+                    dtype.is_integer() || dtype.is_null(),
+                    TypeError: "`{name}` argument to `list_slice` must be an integer, received: {dtype}",
+                );
+            }
+        }
+
+        Ok(Field::new(input.name, DataType::List(inner_dtype)))
+    }
+}
+
+#[must_use]
+pub fn list_slice(expr: ExprRef, start: ExprRef, end: Option<ExprRef>) -> ExprRef {
+    let mut inputs = vec![expr, start];
+    inputs.extend(end);
+    ScalarFn::builtin(ListSlice {}, inputs).into()
+}
@@ -102,7 +102,9 @@ impl AsyncScalarUDF for UrlDownload {
     }
 }
 
-async fn url_download(
+/// Downloads the bytes of each URL of `array`, with at most `max_connections` downloads in flight per CPU when
+/// `multi_thread` is set. Null URLs, and URLs that fail without `raise_error_on_failure`, produce null.
+pub async fn url_download(
     array: &Utf8Array,
     max_connections: usize,
     raise_error_on_failure: bool,
@@ -0,0 +1,245 @@
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+    lit,
+};
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// How strings are compared under a collation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Collation {
+    /// Compares the UTF-8 bytes, which is the default ordering of strings.
+    Binary,
+    /// Compares strings ignoring case.
+    CaseInsensitive,
+    /// Compares strings ignoring case and accents, so that e.g. "Émile" and "emile" are equal.
+    AccentInsensitive,
+    /// Compares strings by the rules of a locale, given as a BCP 47 language tag such as "de-DE" or "sv".
+    ///
+    /// Requires the `icu` feature.
+    Locale(String),
+}
+
+impl FromStr for Collation {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        match s.to_lowercase().as_str() {
+            "binary" => Ok(Self::Binary),
+            "case_insensitive" | "nocase" => Ok(Self::CaseInsensitive),
+            "accent_insensitive" => Ok(Self::AccentInsensitive),
+            _ => Self::parse_locale(s),
+        }
+    }
+}
+
+impl std::fmt::Display for Collation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Binary => write!(f, "binary"),
+            Self::CaseInsensitive => write!(f, "case_insensitive"),
+            Self::AccentInsensitive => write!(f, "accent_insensitive"),
+            Self::Locale(locale) => write!(f, "{locale}"),
+        }
+    }
+}
+
+impl Collation {
+    #[cfg(feature = "icu")]
+    fn parse_locale(s: &str) -> DaftResult<Self> {
+        // Locales are often written with underscores, as in "en_US", which BCP 47 spells "en-US".
+        let locale = icu_locale_core::Locale::try_from_str(&s.replace('_', "-")).map_err(|_| {
+            DaftError::ValueError(format!(
+                "Unsupported collation: {s}, expected one of 'binary', 'case_insensitive', 'accent_insensitive' or a locale such as 'en-US'"
+            ))
+        })?;
+        Ok(Self::Locale(locale.to_string()))
+    }
+
+    #[cfg(not(feature = "icu"))]
+    fn parse_locale(s: &str) -> DaftResult<Self> {
+        Err(DaftError::ValueError(format!(
+            "Unsupported collation: {s}, expected one of 'binary', 'case_insensitive' or 'accent_insensitive'. Locale collations require Daft to be built with the `icu` feature"
+        )))
+    }
+
+    /// Returns the type of the collation keys, which are binary for locale collations and strings otherwise.
+    pub fn key_dtype(&self) -> DataType {
+        match self {
+            Self::Locale(_) => DataType::Binary,
+            _ => DataType::Utf8,
+        }
+    }
+
+    /// Returns the key of a string under this collation, such that two strings are equal under the collation if and
+    /// only if their keys are equal, and ordering the keys orders the strings.
+    ///
+    /// Locale collations have binary keys, which are computed by [`Collation::keys`].
+    pub fn key(&self, s: &str) -> String {
+        match self {
+            Self::Binary | Self::Locale(_) => s.to_string(),
+            Self::CaseInsensitive => s.to_lowercase(),
+            Self::AccentInsensitive => s
+                .nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .collect::<String>()
+                .to_lowercase(),
+        }
+    }
+
+    /// Returns the collation keys of an array of strings.
+    pub fn keys(&self, arr: &Utf8Array) -> DaftResult<Series> {
+        match self {
+            Self::Locale(locale) => locale_keys(locale, arr),
+            _ => Ok(Utf8Array::from_iter(
+                arr.name(),
+                arr.into_iter().map(|val| val.map(|s| self.key(s))),
+            )
+            .into_series()),
+        }
+    }
+}
+
+#[cfg(feature = "icu")]
+fn locale_keys(locale: &str, arr: &Utf8Array) -> DaftResult<Series> {
+    use daft_core::prelude::BinaryArray;
+    use icu_collator::{Collator, CollatorPreferences, options::CollatorOptions};
+
+    let locale = icu_locale_core::Locale::try_from_str(locale)
+        .map_err(|e| DaftError::ValueError(format!("Invalid locale {locale}: {e}")))?;
+    let collator = Collator::try_new(
+        CollatorPreferences::from(&locale),
+        CollatorOptions::default(),
+    )
+    .map_err(|e| DaftError::ValueError(format!("No collation data for locale {locale}: {e}")))?;
+    let keys = arr
+        .into_iter()
+        .map(|val| {
+            val.map(|s| {
+                let mut key = Vec::new();
+                collator.write_sort_key_to(s, &mut key).map_err(|_| {
+                    DaftError::ComputeError(format!(
+                        "Failed to compute the {locale} collation key of {s:?}"
+                    ))
+                })?;
+                Ok(key)
+            })
+            .transpose()
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(BinaryArray::from_iter(arr.name(), keys.into_iter()).into_series())
+}
+
+#[cfg(not(feature = "icu"))]
+fn locale_keys(locale: &str, _arr: &Utf8Array) -> DaftResult<Series> {
+    Err(DaftError::ValueError(format!(
+        "The {locale} collation requires Daft to be built with the `icu` feature"
+    )))
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CollationKey;
+
+#[derive(FunctionArgs)]
+struct CollationKeyArgs<T> {
+    input: T,
+    collation: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for CollationKey {
+    fn name(&self) -> &'static str {
+        "collation_key"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let CollationKeyArgs { input, collation } = inputs.try_into()?;
+        let collation = Collation::from_str(&collation)?;
+        if input.data_type().is_null() {
+            return input.cast(&collation.key_dtype());
+        }
+        input.with_utf8_array(|arr| collation.keys(arr))
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let CollationKeyArgs { input, collation } = inputs.try_into()?;
+        let collation = Collation::from_str(&collation)?;
+        let input = input.to_field(schema)?;
+        ensure!(
+            input.dtype.is_string() || input.dtype.is_null(),
+            TypeError: "Expects input to 'collation_key' to be utf8, but received {}", input.dtype
+        );
+        Ok(Field::new(input.name, collation.key_dtype()))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns the collation key of a string, which can be used to sort, compare, group or join strings under the collation."
+    }
+}
+
+#[must_use]
+pub fn collation_key(input: ExprRef, collation: &Collation) -> ExprRef {
+    ScalarFn::builtin(CollationKey, vec![input, lit(collation.to_string())]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Collation;
+
+    #[test]
+    fn test_collation_keys() {
+        assert_eq!(Collation::Binary.key("Émile"), "Émile");
+        assert_eq!(Collation::CaseInsensitive.key("Émile"), "émile");
+        assert_eq!(Collation::AccentInsensitive.key("Émile"), "emile");
+        assert_eq!(Collation::AccentInsensitive.key("Straße"), "straße");
+    }
+
+    #[test]
+    fn test_collation_from_str() {
+        assert_eq!("BINARY".parse::<Collation>().unwrap(), Collation::Binary);
+        assert_eq!(
+            "nocase".parse::<Collation>().unwrap(),
+            Collation::CaseInsensitive
+        );
+        assert!("not a locale!".parse::<Collation>().is_err());
+    }
+
+    #[cfg(feature = "icu")]
+    #[test]
+    fn test_locale_collation() -> common_error::DaftResult<()> {
+        use daft_core::prelude::Utf8Array;
+
+        assert_eq!(
+            "sv_SE".parse::<Collation>()?,
+            Collation::Locale("sv-SE".to_string())
+        );
+
+        // Swedish sorts "ö" after "z", while German sorts it along with "o".
+        let words = Utf8Array::from_iter("words", ["z", "ö"].into_iter().map(Some));
+        for (locale, ordered) in [("sv", false), ("de", true)] {
+            let keys = Collation::Locale(locale.to_string()).keys(&words)?;
+            let keys = keys.binary()?;
+            assert_eq!(keys.get(1) < keys.get(0), ordered);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "icu"))]
+    #[test]
+    fn test_locale_collation_requires_icu() {
+        let err = "en_US".parse::<Collation>().unwrap_err();
+        assert!(err.to_string().contains("`icu` feature"));
+    }
+}
@@ -0,0 +1,99 @@
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::prelude::*;
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+    lit,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ContainsAny;
+
+const CASE_SENSITIVE_DEFAULT_VALUE: bool = true;
+
+#[typetag::serde]
+impl ScalarUDF for ContainsAny {
+    fn name(&self) -> &'static str {
+        "contains_any"
+    }
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let input = inputs.required((0, "input"))?;
+        let patterns = inputs.required((1, "patterns"))?;
+
+        let case_sensitive = inputs.optional("case_sensitive")?.map(|s| {
+            ensure!(s.data_type().is_boolean() && s.len() == 1, ValueError: "expected boolean literal for 'case_sensitive'");
+            Ok(s.bool().unwrap().get(0).unwrap())
+        }).transpose()?.unwrap_or(CASE_SENSITIVE_DEFAULT_VALUE);
+
+        ensure!(patterns.len() == 1, ValueError: "Cannot set `patterns` in `contains_any` to an Expression. Only string or list of strings are currently supported.");
+
+        input.with_utf8_array(|arr| match patterns.data_type() {
+            DataType::Utf8 => patterns.with_utf8_array(|pattern_arr| {
+                Ok(contains_any_impl(arr, pattern_arr, case_sensitive)?.into_series())
+            }),
+            DataType::List(_) => {
+                patterns
+                    .list()
+                    .unwrap()
+                    .flat_child
+                    .with_utf8_array(|pattern_arr| {
+                        Ok(contains_any_impl(arr, pattern_arr, case_sensitive)?.into_series())
+                    })
+            }
+            patterns_dtype => Err(DaftError::ValueError(format!(
+                "expected string or list of strings for 'patterns', got {}",
+                patterns_dtype
+            ))),
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let input = inputs.required((0, "input"))?.to_field(schema)?;
+        ensure!(input.dtype.is_string(), TypeError: "Expects input to contains_any to be utf8, but received {}", input.dtype);
+        let patterns = inputs.required((1, "patterns"))?.to_field(schema)?;
+        ensure!(matches!(&patterns.dtype, DataType::List(inner_dtype) if inner_dtype.is_string()) | patterns.dtype.is_string(), ValueError: "expected string or list of strings for 'patterns', got {}", patterns.dtype);
+
+        if let Some(case_sensitive) = inputs.optional("case_sensitive")? {
+            case_sensitive
+                .as_literal()
+                .and_then(|lit| lit.as_bool())
+                .ok_or(DaftError::ValueError(
+                    "expected boolean literal for 'case_sensitive'".to_string(),
+                ))?;
+        }
+
+        Ok(Field::new(input.name, DataType::Boolean))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns true if the string contains any of the given substrings."
+    }
+}
+
+#[must_use]
+pub fn contains_any(input: ExprRef, patterns: ExprRef, case_sensitive: bool) -> ExprRef {
+    ScalarFn::builtin(ContainsAny, vec![input, patterns, lit(case_sensitive)]).into()
+}
+
+/// Searches each string for all of the patterns at once, with a single automaton built from the patterns, rather than
+/// searching each string once per pattern.
+fn contains_any_impl(
+    arr: &Utf8Array,
+    patterns: &Utf8Array,
+    case_sensitive: bool,
+) -> DaftResult<BooleanArray> {
+    let ac = aho_corasick::AhoCorasickBuilder::new()
+        .ascii_case_insensitive(!case_sensitive)
+        .build(patterns.into_iter().flatten())
+        .map_err(|e| DaftError::ComputeError(format!("Error creating string automaton: {}", e)))?;
+    let arrow_result: daft_arrow::array::BooleanArray = arr
+        .into_iter()
+        .map(|opt| opt.map(|s| ac.is_match(s)))
+        .collect();
+    Ok(BooleanArray::from((arr.name(), arrow_result)))
+}
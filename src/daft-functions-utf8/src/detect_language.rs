@@ -0,0 +1,105 @@
+use common_error::{DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DetectLanguage;
+
+#[derive(FunctionArgs)]
+struct DetectLanguageArgs<T> {
+    input: T,
+    #[arg(optional)]
+    min_confidence: Option<f64>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DetectLanguage {
+    fn name(&self) -> &'static str {
+        "detect_language"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let DetectLanguageArgs {
+            input,
+            min_confidence,
+        } = inputs.try_into()?;
+        let min_confidence = min_confidence.unwrap_or(0.0);
+        if input.data_type().is_null() {
+            return Ok(input);
+        }
+        input.with_utf8_array(|arr| {
+            Ok(Utf8Array::from_iter(
+                arr.name(),
+                arr.into_iter()
+                    .map(|val| detect_language_impl(val?, min_confidence)),
+            )
+            .into_series())
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let DetectLanguageArgs {
+            input,
+            min_confidence,
+        } = inputs.try_into()?;
+        if let Some(min_confidence) = min_confidence {
+            ensure!(
+                (0.0..=1.0).contains(&min_confidence),
+                ValueError: "Expected min_confidence to be between 0 and 1, got {min_confidence}"
+            );
+        }
+        let input = input.to_field(schema)?;
+        ensure!(
+            input.dtype.is_string() || input.dtype.is_null(),
+            TypeError: "Expects input to '{}' to be utf8, but received {}", self.name(), input.dtype
+        );
+        Ok(Field::new(input.name, DataType::Utf8))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Detects the language of a string, returning its ISO 639-3 code, or null if it can't be detected."
+    }
+}
+
+/// Detects the language with a trigram model, returning `None` for text that is too short or ambiguous.
+fn detect_language_impl(val: &str, min_confidence: f64) -> Option<&'static str> {
+    whatlang::detect(val)
+        .filter(|info| info.confidence() >= min_confidence)
+        .map(|info| info.lang().code())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::detect_language_impl;
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(
+            detect_language_impl("The quick brown fox jumps over the lazy dog.", 0.0),
+            Some("eng")
+        );
+        assert_eq!(
+            detect_language_impl(
+                "Der schnelle braune Fuchs springt über den faulen Hund.",
+                0.0
+            ),
+            Some("deu")
+        );
+        assert_eq!(
+            detect_language_impl("これは日本語の文章です。", 0.0),
+            Some("jpn")
+        );
+        assert_eq!(detect_language_impl("", 0.0), None);
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use common_error::{DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Schema, UInt64Array, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+    lit,
+};
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::utils::{unary_utf8_evaluate, unary_utf8_to_field};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct LengthGraphemes;
+
+#[typetag::serde]
+impl ScalarUDF for LengthGraphemes {
+    fn name(&self) -> &'static str {
+        "length_graphemes"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        unary_utf8_evaluate(inputs, |s| {
+            s.with_utf8_array(|arr| {
+                let iter = arr
+                    .into_iter()
+                    .map(|val| Some(val?.graphemes(true).count() as u64));
+                Ok(
+                    UInt64Array::from_iter(
+                        Arc::new(Field::new(arr.name(), DataType::UInt64)),
+                        iter,
+                    )
+                    .into_series(),
+                )
+            })
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        unary_utf8_to_field(inputs, schema, self.name(), DataType::UInt64)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns the length of the string in grapheme clusters, i.e. user-perceived characters."
+    }
+}
+
+#[must_use]
+pub fn length_graphemes(input: ExprRef) -> ExprRef {
+    ScalarFn::builtin(LengthGraphemes, vec![input]).into()
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SubstrGraphemes;
+
+#[derive(FunctionArgs)]
+struct SubstrGraphemesArgs<T> {
+    input: T,
+    start: usize,
+    #[arg(optional)]
+    length: Option<usize>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for SubstrGraphemes {
+    fn name(&self) -> &'static str {
+        "substr_graphemes"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let SubstrGraphemesArgs {
+            input,
+            start,
+            length,
+        } = inputs.try_into()?;
+        if input.data_type().is_null() {
+            return Ok(input);
+        }
+        input.with_utf8_array(|arr| {
+            Ok(Utf8Array::from_iter(
+                arr.name(),
+                arr.into_iter()
+                    .map(|val| val.map(|s| substr_graphemes_impl(s, start, length))),
+            )
+            .into_series())
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let SubstrGraphemesArgs { input, .. } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        ensure!(
+            input.dtype.is_string() || input.dtype.is_null(),
+            TypeError: "Expects input to '{}' to be utf8, but received {}", self.name(), input.dtype
+        );
+        Ok(input)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Extracts a substring of the given length in grapheme clusters, starting at the given grapheme cluster."
+    }
+}
+
+#[must_use]
+pub fn substr_graphemes(input: ExprRef, start: usize, length: Option<usize>) -> ExprRef {
+    let mut args = vec![input, lit(start as u64)];
+    if let Some(length) = length {
+        args.push(lit(length as u64));
+    }
+    ScalarFn::builtin(SubstrGraphemes, args).into()
+}
+
+fn substr_graphemes_impl(val: &str, start: usize, length: Option<usize>) -> &str {
+    let mut indices = val.grapheme_indices(true).map(|(i, _)| i).skip(start);
+    let Some(begin) = indices.next() else {
+        return "";
+    };
+    let end = match length {
+        Some(0) => begin,
+        Some(length) => indices.nth(length - 1).unwrap_or(val.len()),
+        None => val.len(),
+    };
+    &val[begin..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::substr_graphemes_impl;
+
+    #[test]
+    fn test_substr_graphemes() {
+        // "e" followed by a combining acute accent is a single grapheme cluster.
+        let val = "cafe\u{301} 👍🏽!";
+        assert_eq!(substr_graphemes_impl(val, 3, Some(1)), "e\u{301}");
+        assert_eq!(substr_graphemes_impl(val, 5, Some(1)), "👍🏽");
+        assert_eq!(substr_graphemes_impl(val, 5, None), "👍🏽!");
+        assert_eq!(substr_graphemes_impl(val, 2, Some(0)), "");
+        assert_eq!(substr_graphemes_impl(val, 10, Some(3)), "");
+    }
+}
@@ -0,0 +1,415 @@
+use common_error::{DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HtmlToText;
+
+#[derive(FunctionArgs)]
+struct HtmlToTextArgs<T> {
+    input: T,
+    #[arg(optional)]
+    remove_boilerplate: Option<bool>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for HtmlToText {
+    fn name(&self) -> &'static str {
+        "html_to_text"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let HtmlToTextArgs {
+            input,
+            remove_boilerplate,
+        } = inputs.try_into()?;
+        let remove_boilerplate = remove_boilerplate.unwrap_or(false);
+        map_text(&input, |text| html_to_text(text, remove_boilerplate))
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let HtmlToTextArgs { input, .. } = inputs.try_into()?;
+        text_input_to_field(&input, schema, self.name())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Extracts the visible text of an HTML document, optionally removing boilerplate such as navigation and footers."
+    }
+}
+
+/// Applies a text extraction function to a string or binary series, decoding binary values as UTF-8.
+pub(crate) fn map_text(input: &Series, f: impl Fn(&str) -> String) -> DaftResult<Series> {
+    match input.data_type() {
+        DataType::Null => Ok(Series::full_null(
+            input.name(),
+            &DataType::Utf8,
+            input.len(),
+        )),
+        DataType::Utf8 => {
+            let arr = input.utf8()?;
+            Ok(
+                Utf8Array::from_iter(arr.name(), arr.into_iter().map(|val| val.map(&f)))
+                    .into_series(),
+            )
+        }
+        _ => {
+            let input = input.cast(&DataType::Binary)?;
+            let arr = input.binary()?;
+            Ok(Utf8Array::from_iter(
+                arr.name(),
+                arr.into_iter()
+                    .map(|val| val.map(|bytes| f(&String::from_utf8_lossy(bytes)))),
+            )
+            .into_series())
+        }
+    }
+}
+
+pub(crate) fn text_input_to_field(
+    input: &ExprRef,
+    schema: &Schema,
+    fn_name: &str,
+) -> DaftResult<Field> {
+    let input = input.to_field(schema)?;
+    ensure!(
+        matches!(
+            input.dtype,
+            DataType::Utf8 | DataType::Binary | DataType::FixedSizeBinary(_) | DataType::Null
+        ),
+        TypeError: "Expects input to '{fn_name}' to be utf8 or binary, but received {}", input.dtype
+    );
+    Ok(Field::new(input.name, DataType::Utf8))
+}
+
+/// Elements whose contents are never rendered as text.
+const HIDDEN_ELEMENTS: &[&str] = &[
+    "head", "script", "style", "noscript", "template", "svg", "iframe", "object", "canvas",
+];
+/// Elements whose contents are usually boilerplate rather than the main content of a page.
+const BOILERPLATE_ELEMENTS: &[&str] = &["nav", "header", "footer", "aside", "form", "menu"];
+/// Elements that separate paragraphs.
+const PARAGRAPH_ELEMENTS: &[&str] = &[
+    "p",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "table",
+    "ul",
+    "ol",
+    "dl",
+    "blockquote",
+    "pre",
+    "section",
+    "article",
+    "main",
+    "figure",
+    "title",
+];
+/// Elements that start a new line.
+const LINE_ELEMENTS: &[&str] = &[
+    "br",
+    "div",
+    "li",
+    "tr",
+    "dt",
+    "dd",
+    "hr",
+    "address",
+    "figcaption",
+    "caption",
+    "body",
+    "details",
+    "summary",
+];
+
+/// Builds the text output, collapsing whitespace the way browsers do.
+struct TextWriter {
+    out: String,
+    /// The number of newlines the next text should be preceded by.
+    pending_newlines: usize,
+    pending_space: bool,
+}
+
+impl TextWriter {
+    fn new() -> Self {
+        Self {
+            out: String::new(),
+            pending_newlines: 0,
+            pending_space: false,
+        }
+    }
+
+    fn break_line(&mut self, newlines: usize) {
+        self.pending_newlines = self.pending_newlines.max(newlines);
+    }
+
+    fn flush_pending(&mut self) {
+        if self.out.is_empty() {
+            self.pending_newlines = 0;
+            self.pending_space = false;
+        } else if self.pending_newlines > 0 {
+            for _ in 0..self.pending_newlines {
+                self.out.push('\n');
+            }
+            self.pending_newlines = 0;
+            self.pending_space = false;
+        } else if self.pending_space {
+            self.out.push(' ');
+            self.pending_space = false;
+        }
+    }
+
+    fn push_text(&mut self, text: &str, preformatted: bool) {
+        if preformatted {
+            if !text.is_empty() {
+                self.flush_pending();
+                self.out.push_str(text);
+            }
+            return;
+        }
+        for (i, word) in text.split_ascii_whitespace().enumerate() {
+            if i > 0 || text.starts_with(|c: char| c.is_ascii_whitespace()) {
+                self.pending_space = true;
+            }
+            self.flush_pending();
+            self.out.push_str(word);
+        }
+        if text.ends_with(|c: char| c.is_ascii_whitespace()) {
+            self.pending_space = true;
+        }
+    }
+}
+
+/// Finds the end of a tag starting at `start`, skipping over quoted attribute values, and returns the index after the
+/// closing `>`.
+fn find_tag_end(html: &str, start: usize) -> usize {
+    let bytes = html.as_bytes();
+    let mut quote = None;
+    for (i, b) in bytes.iter().enumerate().skip(start) {
+        match (quote, b) {
+            (None, b'"' | b'\'') => quote = Some(*b),
+            (Some(q), _) if q == *b => quote = None,
+            (None, b'>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+/// Finds the index after the closing tag of a raw text element such as `<script>`, or the end of the document.
+fn find_closing_tag(html: &str, start: usize, name: &str) -> usize {
+    let lower = html[start..].to_ascii_lowercase();
+    match lower.find(&format!("</{name}")) {
+        Some(i) => find_tag_end(html, start + i),
+        None => html.len(),
+    }
+}
+
+/// Extracts the visible text of an HTML document in one pass, without building a DOM.
+pub(crate) fn html_to_text(html: &str, remove_boilerplate: bool) -> String {
+    let mut writer = TextWriter::new();
+    let mut boilerplate_depth = 0usize;
+    let mut pre_depth = 0usize;
+    let mut pos = 0;
+
+    while pos < html.len() {
+        let Some(offset) = html[pos..].find('<') else {
+            if boilerplate_depth == 0 {
+                writer.push_text(&decode_entities(&html[pos..]), pre_depth > 0);
+            }
+            break;
+        };
+        let tag_start = pos + offset;
+        if boilerplate_depth == 0 {
+            writer.push_text(&decode_entities(&html[pos..tag_start]), pre_depth > 0);
+        }
+
+        let rest = &html[tag_start..];
+        if rest.starts_with("<!--") {
+            pos = rest
+                .find("-->")
+                .map_or(html.len(), |end| tag_start + end + 3);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            pos = find_tag_end(html, tag_start);
+            continue;
+        }
+
+        let closing = rest.starts_with("</");
+        let name_start = tag_start + if closing { 2 } else { 1 };
+        let name_len = html[name_start..]
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(html.len() - name_start);
+        if name_len == 0 {
+            // A lone `<` is text.
+            if boilerplate_depth == 0 {
+                writer.push_text("<", pre_depth > 0);
+            }
+            pos = tag_start + 1;
+            continue;
+        }
+        let name = html[name_start..name_start + name_len].to_ascii_lowercase();
+        let tag_end = find_tag_end(html, name_start + name_len);
+        let self_closing = html[..tag_end].ends_with("/>");
+        pos = tag_end;
+
+        if HIDDEN_ELEMENTS.contains(&name.as_str()) {
+            if !closing && !self_closing {
+                pos = find_closing_tag(html, tag_end, &name);
+            }
+            continue;
+        }
+        if remove_boilerplate && BOILERPLATE_ELEMENTS.contains(&name.as_str()) {
+            if closing {
+                boilerplate_depth = boilerplate_depth.saturating_sub(1);
+            } else if !self_closing {
+                boilerplate_depth += 1;
+            }
+            continue;
+        }
+        if name == "pre" {
+            pre_depth = if closing {
+                pre_depth.saturating_sub(1)
+            } else {
+                pre_depth + 1
+            };
+        }
+        if PARAGRAPH_ELEMENTS.contains(&name.as_str()) {
+            writer.break_line(2);
+        } else if LINE_ELEMENTS.contains(&name.as_str()) {
+            writer.break_line(1);
+        } else if matches!(name.as_str(), "td" | "th") && !closing {
+            writer.pending_space = true;
+        }
+    }
+    writer.out
+}
+
+/// Decodes character references, leaving unknown ones as they are.
+pub(crate) fn decode_entities(text: &str) -> std::borrow::Cow<'_, str> {
+    if !text.contains('&') {
+        return std::borrow::Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 32)
+            .and_then(|end| decode_entity(&rest[1..=end]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    std::borrow::Cow::Owned(out)
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(num) = entity.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "hellip" => '…',
+        "mdash" => '—',
+        "ndash" => '–',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "laquo" => '«',
+        "raquo" => '»',
+        "middot" => '·',
+        "bull" => '•',
+        "deg" => '°',
+        "euro" => '€',
+        "pound" => '£',
+        "yen" => '¥',
+        "cent" => '¢',
+        "sect" => '§',
+        "times" => '×',
+        "divide" => '÷',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_entities, html_to_text};
+
+    #[test]
+    fn test_html_to_text() {
+        let html = r#"<!DOCTYPE html>
+            <html><head><title>Title</title><style>p { color: red; }</style></head>
+            <body>
+              <nav><a href="/">Home</a> | <a href="/about">About</a></nav>
+              <h1>Hello,   world!</h1>
+              <!-- a comment -->
+              <p>Some <b>bold</b> text &amp; an <a href="x" title="a > b">link</a>.<br>New line</p>
+              <script>var x = "<p>not text</p>";</script>
+              <ul><li>one</li><li>two</li></ul>
+              <footer>Copyright &copy; 2024</footer>
+            </body></html>"#;
+        assert_eq!(
+            html_to_text(html, false),
+            "Home | About\n\nHello, world!\n\nSome bold text & an link.\nNew line\n\none\ntwo\n\nCopyright © 2024"
+        );
+        assert_eq!(
+            html_to_text(html, true),
+            "Hello, world!\n\nSome bold text & an link.\nNew line\n\none\ntwo"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text_pre() {
+        assert_eq!(
+            html_to_text("<p>a  b</p><pre>x  =  1\ny = 2</pre>", false),
+            "a b\n\nx  =  1\ny = 2"
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; &unknown; & d"),
+            "a <b> 'c' &unknown; & d"
+        );
+    }
+}
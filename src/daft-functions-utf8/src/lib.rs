@@ -1,10 +1,15 @@
 #![allow(deprecated, reason = "arrow2 migration")]
 
 mod capitalize;
+mod collation_key;
 mod contains;
+mod contains_any;
 mod count_matches;
+mod detect_language;
 mod endswith;
 mod find;
+mod graphemes;
+mod html_to_text;
 mod ilike;
 mod left;
 mod length_bytes;
@@ -12,8 +17,11 @@ mod like;
 mod lower;
 mod lpad;
 mod lstrip;
+mod markdown_to_text;
 mod normalize;
 pub(crate) mod pad;
+mod pii;
+mod regex_cache;
 mod regexp_count;
 mod regexp_extract;
 mod regexp_extract_all;
@@ -26,17 +34,24 @@ mod rpad;
 mod rstrip;
 mod split;
 mod startswith;
+mod strip_accents;
 mod substr;
 mod to_date;
 mod to_datetime;
+mod transliterate;
 mod upper;
 pub(crate) mod utils;
 
 pub use capitalize::*;
+pub use collation_key::*;
 pub use contains::*;
+pub use contains_any::*;
 pub use count_matches::*;
+pub use detect_language::*;
 pub use endswith::*;
 pub use find::*;
+pub use graphemes::*;
+pub use html_to_text::HtmlToText;
 pub use ilike::*;
 pub use left::*;
 pub use length_bytes::*;
@@ -44,7 +59,9 @@ pub use like::*;
 pub use lower::*;
 pub use lpad::*;
 pub use lstrip::*;
+pub use markdown_to_text::MarkdownToText;
 pub use normalize::*;
+pub use pii::*;
 pub use regexp_count::*;
 pub use regexp_extract::*;
 pub use regexp_extract_all::*;
@@ -57,9 +74,11 @@ pub use rpad::*;
 pub use rstrip::*;
 pub use split::*;
 pub use startswith::*;
+pub use strip_accents::*;
 pub use substr::*;
 pub use to_date::*;
 pub use to_datetime::*;
+pub use transliterate::*;
 pub use upper::*;
 
 pub struct Utf8Functions;
@@ -67,18 +86,26 @@ pub struct Utf8Functions;
 impl daft_dsl::functions::FunctionModule for Utf8Functions {
     fn register(parent: &mut daft_dsl::functions::FunctionRegistry) {
         parent.add_fn(Capitalize);
+        parent.add_fn(CollationKey);
         parent.add_fn(Contains);
+        parent.add_fn(ContainsAny);
         parent.add_fn(CountMatches);
+        parent.add_fn(DetectLanguage);
         parent.add_fn(EndsWith);
         parent.add_fn(Find);
+        parent.add_fn(HtmlToText);
         parent.add_fn(ILike);
         parent.add_fn(Left);
         parent.add_fn(LengthBytes);
+        parent.add_fn(LengthGraphemes);
         parent.add_fn(Like);
         parent.add_fn(Lower);
         parent.add_fn(LPad);
         parent.add_fn(LStrip);
+        parent.add_fn(MarkdownToText);
         parent.add_fn(Normalize);
+        parent.add_fn(DetectPii);
+        parent.add_fn(RedactPii);
         parent.add_fn(RegexpCount);
         parent.add_fn(RegexpExtract);
         parent.add_fn(RegexpExtractAll);
@@ -93,9 +120,12 @@ impl daft_dsl::functions::FunctionModule for Utf8Functions {
         parent.add_fn(Split);
         parent.add_fn(RegexpSplit);
         parent.add_fn(StartsWith);
+        parent.add_fn(StripAccents);
         parent.add_fn(Substr);
+        parent.add_fn(SubstrGraphemes);
         parent.add_fn(ToDate);
         parent.add_fn(ToDatetime);
+        parent.add_fn(TransliterateToAscii);
         parent.add_fn(Upper);
     }
 }
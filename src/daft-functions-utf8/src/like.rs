@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use ahash::AHashMap;
 use common_error::{DaftError, DaftResult};
 use common_pattern::like_pattern_to_regex;
@@ -11,8 +13,11 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::{
-    binary_utf8_evaluate, binary_utf8_to_field, create_broadcasted_str_iter, parse_inputs,
+use crate::{
+    regex_cache::compile_regex,
+    utils::{
+        binary_utf8_evaluate, binary_utf8_to_field, create_broadcasted_str_iter, parse_inputs,
+    },
 };
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -108,7 +113,7 @@ fn like_impl(arr: &Utf8Array, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
 pub(crate) fn compile_like_regex(
     pattern: &str,
     case_insensitive: bool,
-) -> DaftResult<regex::Regex> {
+) -> DaftResult<Arc<regex::Regex>> {
     let regex_pattern = like_pattern_to_regex(pattern)
         .ok_or_else(|| DaftError::ValueError(format!("Invalid LIKE pattern '{pattern}'")))?;
     let regex_pattern = if case_insensitive {
@@ -116,7 +121,7 @@ pub(crate) fn compile_like_regex(
     } else {
         regex_pattern
     };
-    regex::Regex::new(&regex_pattern).map_err(|e| {
+    compile_regex(&regex_pattern).map_err(|e| {
         DaftError::ValueError(format!(
             "Invalid regex generated from LIKE pattern '{pattern}': {e}"
         ))
@@ -0,0 +1,329 @@
+use common_error::DaftResult;
+use daft_core::{
+    prelude::{Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::html_to_text::{decode_entities, map_text, text_input_to_field};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MarkdownToText;
+
+#[derive(FunctionArgs)]
+struct MarkdownToTextArgs<T> {
+    input: T,
+}
+
+#[typetag::serde]
+impl ScalarUDF for MarkdownToText {
+    fn name(&self) -> &'static str {
+        "markdown_to_text"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let MarkdownToTextArgs { input } = inputs.try_into()?;
+        map_text(&input, markdown_to_text)
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let MarkdownToTextArgs { input } = inputs.try_into()?;
+        text_input_to_field(&input, schema, self.name())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Extracts the plain text of a Markdown document, removing formatting, link targets and markup."
+    }
+}
+
+/// Returns the fence of a fenced code block, i.e. three or more backticks or tildes.
+fn code_fence(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.chars().take_while(|c| *c == marker).count();
+    (len >= 3).then(|| &trimmed[..len])
+}
+
+/// Returns whether a line is a thematic break such as `---` or `* * *`, or a setext heading underline.
+fn is_rule(line: &str) -> bool {
+    let chars = line
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<Vec<_>>();
+    chars.len() >= 3
+        && (chars.iter().all(|c| *c == '-')
+            || chars.iter().all(|c| *c == '*')
+            || chars.iter().all(|c| *c == '_')
+            || chars.iter().all(|c| *c == '='))
+}
+
+/// Returns whether a line is a table delimiter row such as `|---|:---:|`.
+fn is_table_delimiter(line: &str) -> bool {
+    line.contains('-')
+        && line.contains('|')
+        && line
+            .chars()
+            .all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Returns whether a line is a link reference definition such as `[id]: https://example.com`.
+fn is_link_definition(line: &str) -> bool {
+    line.trim_start()
+        .strip_prefix('[')
+        .and_then(|rest| rest.find("]:"))
+        .is_some()
+}
+
+/// Strips the block-level markup at the start of a line: blockquote markers, headings and list markers.
+fn strip_block_markers(line: &str) -> &str {
+    let mut line = line.trim();
+    while let Some(rest) = line.strip_prefix('>') {
+        line = rest.trim_start();
+    }
+    let hashes = line.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&hashes) && line[hashes..].starts_with([' ', '\t']) {
+        return line[hashes..].trim().trim_end_matches('#').trim_end();
+    }
+    if let Some(rest) = line.strip_prefix(['-', '*', '+'])
+        && rest.starts_with([' ', '\t'])
+    {
+        let rest = rest.trim_start();
+        // Task list items.
+        return rest
+            .strip_prefix("[ ] ")
+            .or_else(|| rest.strip_prefix("[x] "))
+            .or_else(|| rest.strip_prefix("[X] "))
+            .unwrap_or(rest);
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if (1..=9).contains(&digits)
+        && line[digits..].starts_with(['.', ')'])
+        && line[digits + 1..].starts_with([' ', '\t'])
+    {
+        return line[digits + 1..].trim_start();
+    }
+    line
+}
+
+/// Finds the index of the bracket that closes the one just before `text`, allowing nested brackets.
+fn find_closing(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in text.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            if depth == 0 {
+                return Some(i);
+            }
+            depth -= 1;
+        }
+    }
+    None
+}
+
+/// Strips inline markup: emphasis, code spans, links, images, autolinks, inline HTML and backslash escapes.
+fn strip_inline(text: &str, out: &mut String) {
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, next)) = chars.next_if(|(_, next)| next.is_ascii_punctuation()) {
+                    out.push(next);
+                } else {
+                    out.push('\\');
+                }
+            }
+            '`' => {
+                let ticks = text[i..].chars().take_while(|c| *c == '`').count();
+                let fence = &text[i..i + ticks];
+                let content_start = i + ticks;
+                if let Some(end) = text[content_start..].find(fence) {
+                    out.push_str(text[content_start..content_start + end].trim());
+                    let next = content_start + end + ticks;
+                    while chars.peek().is_some_and(|(j, _)| *j < next) {
+                        chars.next();
+                    }
+                } else {
+                    out.push_str(fence);
+                    for _ in 1..ticks {
+                        chars.next();
+                    }
+                }
+            }
+            '!' if text[i + 1..].starts_with('[') => {
+                // Images are replaced by their alt text, like links by their text.
+            }
+            '[' => {
+                let rest = &text[i + 1..];
+                let Some(close) = find_closing(rest, '[', ']') else {
+                    out.push('[');
+                    continue;
+                };
+                let after = &rest[close + 1..];
+                let skip = if after.starts_with('(') {
+                    find_closing(&after[1..], '(', ')').map(|end| end + 2)
+                } else if after.starts_with('[') {
+                    after.find(']').map(|end| end + 1)
+                } else {
+                    Some(0)
+                };
+                strip_inline(&rest[..close], out);
+                let next = i + 1 + close + 1 + skip.unwrap_or(0);
+                while chars.peek().is_some_and(|(j, _)| *j < next) {
+                    chars.next();
+                }
+            }
+            '<' => {
+                let rest = &text[i + 1..];
+                let Some(end) = rest.find('>') else {
+                    out.push('<');
+                    continue;
+                };
+                let inner = &rest[..end];
+                if inner.contains("://") || (inner.contains('@') && !inner.contains(' ')) {
+                    // Autolinks keep their target.
+                    out.push_str(inner);
+                } else if !inner.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/') {
+                    out.push('<');
+                    continue;
+                }
+                let next = i + 1 + end + 1;
+                while chars.peek().is_some_and(|(j, _)| *j < next) {
+                    chars.next();
+                }
+            }
+            '*' => {}
+            '~' if text[i + 1..].starts_with('~') || text[..i].ends_with('~') => {}
+            '_' => {
+                // Underscores within words, e.g. in snake_case, aren't emphasis.
+                let prev = text[..i].chars().next_back();
+                let next = text[i + 1..].chars().next();
+                if prev.is_some_and(char::is_alphanumeric)
+                    && next.is_some_and(char::is_alphanumeric)
+                {
+                    out.push('_');
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+}
+
+fn push_line(out: &mut String, line: &str, pending_blank: &mut bool) {
+    if !out.is_empty() {
+        out.push_str(if *pending_blank { "\n\n" } else { "\n" });
+    }
+    *pending_blank = false;
+    out.push_str(line);
+}
+
+/// Extracts the plain text of a Markdown document line by line, keeping paragraphs separated by blank lines.
+pub(crate) fn markdown_to_text(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut fence: Option<String> = None;
+    let mut pending_blank = false;
+
+    for line in markdown.lines() {
+        if let Some(open) = &fence {
+            if code_fence(line).is_some_and(|close| close.starts_with(open.as_str())) {
+                fence = None;
+                pending_blank = true;
+            } else {
+                push_line(&mut out, line, &mut pending_blank);
+            }
+            continue;
+        }
+        if let Some(open) = code_fence(line) {
+            fence = Some(open.to_string());
+            pending_blank = !out.is_empty();
+            continue;
+        }
+        if line.trim().is_empty() {
+            pending_blank = !out.is_empty();
+            continue;
+        }
+        if is_rule(line) || is_table_delimiter(line) || is_link_definition(line) {
+            continue;
+        }
+
+        let line = strip_block_markers(line);
+        let mut text = String::with_capacity(line.len());
+        if line.contains('|') {
+            // Table rows are turned into their cells separated by tabs.
+            let cells = line.trim().trim_matches('|').split('|').map(str::trim);
+            for (i, cell) in cells.enumerate() {
+                if i > 0 {
+                    text.push('\t');
+                }
+                strip_inline(cell, &mut text);
+            }
+        } else {
+            strip_inline(line, &mut text);
+        }
+        let text = decode_entities(&text);
+        if !text.trim().is_empty() {
+            push_line(&mut out, text.trim_end(), &mut pending_blank);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::markdown_to_text;
+
+    #[test]
+    fn test_markdown_to_text() {
+        let markdown = r"# Title
+
+Some **bold**, _italic_ and `code` text with a [link](https://example.com (title)) and snake_case.
+A second line with ![an image](img.png) and <https://daft.ai>.
+
+> A quote
+> continued
+
+- one
+* [x] two
+1. three
+
+---
+
+```python
+def f(x):
+    return x * 2
+```
+
+| a | b |
+|---|:-:|
+| 1 | 2 |
+
+[ref]: https://example.com
+";
+        assert_eq!(
+            markdown_to_text(markdown),
+            "Title\n\n\
+             Some bold, italic and code text with a link and snake_case.\n\
+             A second line with an image and https://daft.ai.\n\n\
+             A quote\ncontinued\n\n\
+             one\ntwo\nthree\n\n\
+             def f(x):\n    return x * 2\n\n\
+             a\tb\n1\t2"
+        );
+    }
+
+    #[test]
+    fn test_escapes_and_html() {
+        assert_eq!(
+            markdown_to_text(r"1 \* 2 = 2, <b>bold</b> &amp; 3 < 4"),
+            "1 * 2 = 2, bold & 3 < 4"
+        );
+    }
+}
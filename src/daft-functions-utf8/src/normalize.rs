@@ -1,4 +1,4 @@
-use common_error::{DaftResult, ensure};
+use common_error::{DaftError, DaftResult, ensure};
 use daft_core::{
     prelude::{Field, Schema, Utf8Array},
     series::{IntoSeries, Series},
@@ -8,7 +8,9 @@ use daft_dsl::{
     functions::{FunctionArgs, ScalarUDF},
 };
 use serde::{Deserialize, Serialize};
-use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfd_quick};
+use unicode_normalization::{
+    IsNormalized, UnicodeNormalization, is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick,
+};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Normalize;
@@ -24,6 +26,41 @@ struct NormalizeArgs<T> {
     nfd_unicode: Option<bool>,
     #[arg(optional)]
     white_space: Option<bool>,
+    #[arg(optional)]
+    form: Option<String>,
+}
+
+/// A Unicode normalization form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn parse(form: &str) -> DaftResult<Self> {
+        match form.to_uppercase().as_str() {
+            "NFC" => Ok(Self::Nfc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFKD" => Ok(Self::Nfkd),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported Unicode normalization form: {form}, expected one of NFC, NFD, NFKC or NFKD"
+            ))),
+        }
+    }
+
+    fn apply(self, s: String) -> String {
+        match self {
+            Self::Nfc if is_nfc_quick(s.chars()) != IsNormalized::Yes => s.nfc().collect(),
+            Self::Nfd if is_nfd_quick(s.chars()) != IsNormalized::Yes => s.nfd().collect(),
+            Self::Nfkc if is_nfkc_quick(s.chars()) != IsNormalized::Yes => s.nfkc().collect(),
+            Self::Nfkd if is_nfkd_quick(s.chars()) != IsNormalized::Yes => s.nfkd().collect(),
+            _ => s,
+        }
+    }
 }
 
 #[typetag::serde]
@@ -43,7 +80,10 @@ impl ScalarUDF for Normalize {
         inputs: FunctionArgs<ExprRef>,
         schema: &Schema,
     ) -> DaftResult<Field> {
-        let NormalizeArgs { input, .. } = inputs.try_into()?;
+        let NormalizeArgs { input, form, .. } = inputs.try_into()?;
+        if let Some(form) = form {
+            NormalizationForm::parse(&form)?;
+        }
 
         let input = input.to_field(schema)?;
 
@@ -60,12 +100,14 @@ fn normalize_impl(
         lowercase,
         nfd_unicode,
         white_space,
+        form,
     }: NormalizeArgs<Series>,
 ) -> DaftResult<Series> {
     let white_space = white_space.unwrap_or(false);
     let lowercase = lowercase.unwrap_or(false);
     let nfd_unicode = nfd_unicode.unwrap_or(false);
     let remove_punct = remove_punct.unwrap_or(false);
+    let form = form.as_deref().map(NormalizationForm::parse).transpose()?;
 
     input.with_utf8_array(|arr| {
         Ok(Utf8Array::from_iter(
@@ -103,6 +145,10 @@ fn normalize_impl(
                     if nfd_unicode && is_nfd_quick(s.chars()) != IsNormalized::Yes {
                         s = s.nfd().collect();
                     }
+
+                    if let Some(form) = form {
+                        s = form.apply(s);
+                    }
                     Some(s)
                 } else {
                     None
@@ -0,0 +1,416 @@
+use std::{str::FromStr, sync::LazyLock};
+
+use common_error::{DaftError, DaftResult, ensure};
+use daft_arrow::array::Array;
+use daft_core::{
+    array::ListArray,
+    lit::{FromLiteral, Literal},
+    prelude::{DataType, Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A kind of personally identifiable information.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PiiKind {
+    Email,
+    CreditCard,
+    Ssn,
+    Phone,
+}
+
+/// All kinds, in the order in which overlapping matches are resolved.
+const ALL_KINDS: [PiiKind; 4] = [
+    PiiKind::Email,
+    PiiKind::CreditCard,
+    PiiKind::Ssn,
+    PiiKind::Phone,
+];
+
+static EMAIL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b[a-z0-9._%+-]+@[a-z0-9-]+(?:\.[a-z0-9-]+)*\.[a-z]{2,}\b").unwrap()
+});
+// Digits are matched as `[0-9]`, since `\d` also matches the digits of other scripts, which the validators don't count.
+static CREDIT_CARD_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b[0-9](?:[ -]?[0-9]){12,18}\b").unwrap());
+static SSN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b[0-9]{3}-[0-9]{2}-[0-9]{4}\b").unwrap());
+static PHONE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:\+[0-9]{1,3}[\s.-]?)?(?:\([0-9]{2,4}\)|\b[0-9]{3})[\s.-]?[0-9]{3}[\s.-]?[0-9]{4}\b",
+    )
+    .unwrap()
+});
+
+impl PiiKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::CreditCard => "credit_card",
+            Self::Ssn => "ssn",
+            Self::Phone => "phone",
+        }
+    }
+
+    fn regex(self) -> &'static Regex {
+        match self {
+            Self::Email => &EMAIL_RE,
+            Self::CreditCard => &CREDIT_CARD_RE,
+            Self::Ssn => &SSN_RE,
+            Self::Phone => &PHONE_RE,
+        }
+    }
+
+    /// Validates a match of the kind's regex, to weed out false positives the regex can't express.
+    fn validate(self, s: &str) -> bool {
+        match self {
+            Self::Email => true,
+            Self::CreditCard => {
+                let digits = digits(s);
+                (13..=19).contains(&digits.len()) && luhn_checksum_valid(&digits)
+            }
+            Self::Ssn => {
+                let digits = digits(s);
+                if digits.len() != 9 {
+                    return false;
+                }
+                let (area, group, serial) = (&digits[0..3], &digits[3..5], &digits[5..9]);
+                area != [0, 0, 0]
+                    && area != [6, 6, 6]
+                    && area[0] != 9
+                    && group != [0, 0]
+                    && serial != [0, 0, 0, 0]
+            }
+            Self::Phone => {
+                let digits = digits(s);
+                (10..=15).contains(&digits.len()) && digits.iter().any(|d| *d != digits[0])
+            }
+        }
+    }
+}
+
+impl FromStr for PiiKind {
+    type Err = DaftError;
+
+    fn from_str(s: &str) -> DaftResult<Self> {
+        ALL_KINDS
+            .into_iter()
+            .find(|kind| kind.as_str() == s.to_lowercase())
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Unsupported PII kind: {s}, expected one of 'email', 'phone', 'credit_card' or 'ssn'"
+                ))
+            })
+    }
+}
+
+fn digits(s: &str) -> Vec<u8> {
+    s.bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|b| b - b'0')
+        .collect()
+}
+
+fn luhn_checksum_valid(digits: &[u8]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            let d = u32::from(*d);
+            if i % 2 == 1 {
+                if d * 2 > 9 { d * 2 - 9 } else { d * 2 }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// The kinds of PII to look for, which is either a single kind or a list of kinds.
+struct PiiKinds(Vec<PiiKind>);
+
+impl FromLiteral for PiiKinds {
+    fn try_from_literal(lit: &Literal) -> DaftResult<Self> {
+        match lit {
+            Literal::Utf8(s) => Ok(Self(vec![s.parse()?])),
+            Literal::List(series) => {
+                let kinds = series
+                    .utf8()?
+                    .into_iter()
+                    .map(|kind| {
+                        kind.ok_or_else(|| {
+                            DaftError::ValueError("Expected PII kinds to be non-null".to_string())
+                        })?
+                        .parse()
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                Ok(Self(kinds))
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected a string or list of strings for the PII kinds, got {lit}"
+            ))),
+        }
+    }
+}
+
+/// Finds the non-overlapping PII in a string, ordered by position.
+fn find_pii(val: &str, kinds: &[PiiKind]) -> Vec<(usize, usize, PiiKind)> {
+    let mut found: Vec<(usize, usize, PiiKind)> = vec![];
+    // Kinds earlier in `ALL_KINDS` take precedence, e.g. a credit card number isn't also a phone number.
+    for kind in ALL_KINDS.into_iter().filter(|kind| kinds.contains(kind)) {
+        for m in kind.regex().find_iter(val) {
+            let overlaps = found
+                .iter()
+                .any(|(start, end, _)| m.start() < *end && *start < m.end());
+            if !overlaps && kind.validate(m.as_str()) {
+                found.push((m.start(), m.end(), kind));
+            }
+        }
+    }
+    found.sort_unstable_by_key(|(start, ..)| *start);
+    found
+}
+
+fn resolve_kinds(kinds: Option<PiiKinds>) -> Vec<PiiKind> {
+    kinds.map_or_else(|| ALL_KINDS.to_vec(), |kinds| kinds.0)
+}
+
+fn ensure_utf8_input(input: &ExprRef, schema: &Schema, fn_name: &str) -> DaftResult<Field> {
+    let input = input.to_field(schema)?;
+    ensure!(
+        input.dtype.is_string(),
+        TypeError: "Expects input to '{fn_name}' to be utf8, but received {}", input.dtype
+    );
+    Ok(input)
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DetectPii;
+
+#[derive(FunctionArgs)]
+struct DetectPiiArgs<T> {
+    input: T,
+    #[arg(optional)]
+    kinds: Option<PiiKinds>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DetectPii {
+    fn name(&self) -> &'static str {
+        "detect_pii"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let DetectPiiArgs { input, kinds } = inputs.try_into()?;
+        let kinds = resolve_kinds(kinds);
+        input.with_utf8_array(|arr| Ok(detect_pii_impl(arr, &kinds)?.into_series()))
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let DetectPiiArgs { input, .. } = inputs.try_into()?;
+        let input = ensure_utf8_input(&input, schema, self.name())?;
+        Ok(Field::new(
+            input.name,
+            DataType::List(Box::new(DataType::Utf8)),
+        ))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Returns the kinds of the personally identifiable information found in a string, one per match."
+    }
+}
+
+fn detect_pii_impl(arr: &Utf8Array, kinds: &[PiiKind]) -> DaftResult<ListArray> {
+    let mut matches = daft_arrow::array::MutableUtf8Array::<i64>::new();
+    let mut offsets = daft_arrow::offset::Offsets::<i64>::new();
+    let mut validity = daft_arrow::buffer::NullBufferBuilder::new(arr.len());
+
+    for val in arr.into_iter() {
+        let mut num_matches = 0i64;
+        if let Some(val) = val {
+            for (_, _, kind) in find_pii(val, kinds) {
+                matches.push(Some(kind.as_str()));
+                num_matches += 1;
+            }
+            validity.append_non_null();
+        } else {
+            validity.append_null();
+        }
+        offsets.try_push(num_matches)?;
+    }
+
+    let matches: daft_arrow::array::Utf8Array<i64> = matches.into();
+    let flat_child = Series::try_from(("kinds", matches.to_boxed()))?;
+    Ok(ListArray::new(
+        Field::new(arr.name(), DataType::List(Box::new(DataType::Utf8))),
+        flat_child,
+        offsets.into(),
+        validity.finish(),
+    ))
+}
+
+/// How redacted PII is replaced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RedactStrategy {
+    /// Replaces PII with its kind, e.g. `[EMAIL]`.
+    Tag,
+    /// Replaces the letters and digits of PII with `*`, keeping its format.
+    Mask,
+    /// Removes PII.
+    Remove,
+}
+
+impl FromLiteral for RedactStrategy {
+    fn try_from_literal(lit: &Literal) -> DaftResult<Self> {
+        match lit {
+            Literal::Utf8(s) => match s.to_lowercase().as_str() {
+                "tag" => Ok(Self::Tag),
+                "mask" => Ok(Self::Mask),
+                "remove" => Ok(Self::Remove),
+                _ => Err(DaftError::ValueError(format!(
+                    "Unsupported redaction strategy: {s}, expected one of 'tag', 'mask' or 'remove'"
+                ))),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected a string literal for the redaction strategy, got {lit}"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RedactPii;
+
+#[derive(FunctionArgs)]
+struct RedactPiiArgs<T> {
+    input: T,
+    #[arg(optional)]
+    kinds: Option<PiiKinds>,
+    #[arg(optional)]
+    strategy: Option<RedactStrategy>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for RedactPii {
+    fn name(&self) -> &'static str {
+        "redact_pii"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let RedactPiiArgs {
+            input,
+            kinds,
+            strategy,
+        } = inputs.try_into()?;
+        let kinds = resolve_kinds(kinds);
+        let strategy = strategy.unwrap_or(RedactStrategy::Tag);
+        input.with_utf8_array(|arr| {
+            Ok(Utf8Array::from_iter(
+                arr.name(),
+                arr.into_iter()
+                    .map(|val| val.map(|s| redact_pii_impl(s, &kinds, strategy))),
+            )
+            .into_series())
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let RedactPiiArgs { input, .. } = inputs.try_into()?;
+        ensure_utf8_input(&input, schema, self.name())
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Redacts the personally identifiable information found in a string."
+    }
+}
+
+fn redact_pii_impl(val: &str, kinds: &[PiiKind], strategy: RedactStrategy) -> String {
+    let mut out = String::with_capacity(val.len());
+    let mut last = 0;
+    for (start, end, kind) in find_pii(val, kinds) {
+        out.push_str(&val[last..start]);
+        match strategy {
+            RedactStrategy::Tag => {
+                out.push('[');
+                out.push_str(&kind.as_str().to_uppercase());
+                out.push(']');
+            }
+            RedactStrategy::Mask => out.extend(
+                val[start..end]
+                    .chars()
+                    .map(|c| if c.is_alphanumeric() { '*' } else { c }),
+            ),
+            RedactStrategy::Remove => {}
+        }
+        last = end;
+    }
+    out.push_str(&val[last..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ALL_KINDS, PiiKind, RedactStrategy, find_pii, redact_pii_impl};
+
+    #[test]
+    fn test_find_pii() {
+        let text = "Mail jane.doe@example.com or call (555) 123-4567, card 4111 1111 1111 1111, ssn 123-45-6789";
+        let kinds = find_pii(text, &ALL_KINDS)
+            .into_iter()
+            .map(|(.., kind)| kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                PiiKind::Email,
+                PiiKind::Phone,
+                PiiKind::CreditCard,
+                PiiKind::Ssn
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validators() {
+        // Fails the Luhn checksum.
+        assert!(find_pii("4111 1111 1111 1112", &[PiiKind::CreditCard]).is_empty());
+        // SSNs never start with 000, 666 or 9.
+        assert!(find_pii("000-12-3456 666-12-3456 912-34-5678", &[PiiKind::Ssn]).is_empty());
+        // Digits of other scripts aren't ASCII digits, so they're neither matched nor counted.
+        assert!(find_pii("١٢٣-٤٥-٦٧٨٩ 12٣-45-6789", &[PiiKind::Ssn]).is_empty());
+        assert!(!PiiKind::Ssn.validate("١٢٣-٤٥-٦٧٨٩"));
+        assert!(find_pii("(５５５) １２３-４５６７", &[PiiKind::Phone]).is_empty());
+    }
+
+    #[test]
+    fn test_redact_pii() {
+        let text = "Email a@b.io or 555-123-4567";
+        assert_eq!(
+            redact_pii_impl(text, &ALL_KINDS, RedactStrategy::Tag),
+            "Email [EMAIL] or [PHONE]"
+        );
+        assert_eq!(
+            redact_pii_impl(text, &[PiiKind::Phone], RedactStrategy::Mask),
+            "Email a@b.io or ***-***-****"
+        );
+        assert_eq!(
+            redact_pii_impl(text, &[PiiKind::Email], RedactStrategy::Remove),
+            "Email  or 555-123-4567"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Caches of compiled regular expressions.
+//!
+//! Regex functions are evaluated batch by batch, and a pattern column may repeat the same few patterns on every row,
+//! so compiling each pattern where it is used recompiles it for every batch and row. Each call keeps the patterns it
+//! compiled in a [`LocalRegexCache`], so that rows only look up patterns in a map which no other thread uses, and each
+//! distinct pattern of the call is looked up once in a process-wide cache shared by all threads.
+//!
+//! The shared cache is split into shards which are locked independently, so threads looking up different patterns
+//! rarely contend, and a shard is only locked to look up and insert patterns, never while compiling them.
+
+use std::{
+    hash::BuildHasher,
+    sync::{Arc, LazyLock, Mutex},
+};
+
+use ahash::{AHashMap, RandomState};
+use regex::Regex;
+
+/// The number of compiled patterns kept by the shared cache.
+const REGEX_CACHE_CAPACITY: usize = 512;
+/// The number of independently locked shards of the shared cache.
+const REGEX_CACHE_SHARDS: usize = 16;
+
+/// Marks the end of the list of entries of a [`LruCache`].
+const NIL: usize = usize::MAX;
+
+struct LruEntry {
+    pattern: Arc<str>,
+    regex: Arc<Regex>,
+    /// The entry which was used before this one.
+    prev: usize,
+    /// The entry which was used after this one.
+    next: usize,
+}
+
+/// A cache of compiled patterns which evicts the least recently used pattern once it is full.
+///
+/// The entries are kept in a list ordered by when they were last used, which is linked through their indices, so
+/// looking up, inserting and evicting patterns all take constant time.
+struct LruCache {
+    capacity: usize,
+    indices: AHashMap<Arc<str>, usize>,
+    entries: Vec<LruEntry>,
+    /// The most recently used entry.
+    head: usize,
+    /// The least recently used entry.
+    tail: usize,
+}
+
+impl LruCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            indices: AHashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.entries[index].prev, self.entries[index].next);
+        match prev {
+            NIL => self.head = next,
+            prev => self.entries[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.entries[next].prev = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.entries[index].prev = NIL;
+        self.entries[index].next = self.head;
+        match self.head {
+            NIL => self.tail = index,
+            head => self.entries[head].prev = index,
+        }
+        self.head = index;
+    }
+
+    fn get(&mut self, pattern: &str) -> Option<Arc<Regex>> {
+        let index = *self.indices.get(pattern)?;
+        self.unlink(index);
+        self.push_front(index);
+        Some(self.entries[index].regex.clone())
+    }
+
+    fn insert(&mut self, pattern: &str, regex: Arc<Regex>) {
+        if let Some(&index) = self.indices.get(pattern) {
+            self.entries[index].regex = regex;
+            self.unlink(index);
+            self.push_front(index);
+            return;
+        }
+        let pattern: Arc<str> = pattern.into();
+        let index = if self.entries.len() < self.capacity {
+            self.entries.push(LruEntry {
+                pattern: pattern.clone(),
+                regex,
+                prev: NIL,
+                next: NIL,
+            });
+            self.entries.len() - 1
+        } else {
+            // The least recently used entry is replaced by the new pattern.
+            let index = self.tail;
+            self.unlink(index);
+            let evicted = std::mem::replace(&mut self.entries[index].pattern, pattern.clone());
+            self.indices.remove(&evicted);
+            self.entries[index].regex = regex;
+            index
+        };
+        self.indices.insert(pattern, index);
+        self.push_front(index);
+    }
+}
+
+/// The process-wide cache, sharded by the hash of the pattern.
+struct ShardedRegexCache {
+    hasher: RandomState,
+    shards: Vec<Mutex<LruCache>>,
+}
+
+impl ShardedRegexCache {
+    fn new(capacity: usize, num_shards: usize) -> Self {
+        Self {
+            hasher: RandomState::new(),
+            shards: (0..num_shards)
+                .map(|_| Mutex::new(LruCache::new(capacity.div_ceil(num_shards))))
+                .collect(),
+        }
+    }
+
+    fn shard(&self, pattern: &str) -> &Mutex<LruCache> {
+        let hash = self.hasher.hash_one(pattern);
+        &self.shards[hash as usize % self.shards.len()]
+    }
+}
+
+static REGEX_CACHE: LazyLock<ShardedRegexCache> =
+    LazyLock::new(|| ShardedRegexCache::new(REGEX_CACHE_CAPACITY, REGEX_CACHE_SHARDS));
+
+/// Returns the compiled regex of a pattern, compiling it only if it isn't in the shared cache.
+///
+/// Functions which compile a pattern per row should use a [`LocalRegexCache`] instead.
+pub(crate) fn compile_regex(pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+    let shard = REGEX_CACHE.shard(pattern);
+    if let Some(regex) = shard.lock().unwrap().get(pattern) {
+        return Ok(regex);
+    }
+    let regex = Arc::new(Regex::new(pattern)?);
+    shard.lock().unwrap().insert(pattern, regex.clone());
+    Ok(regex)
+}
+
+/// The patterns compiled by a single call of a function, such as for each row of a pattern column.
+#[derive(Default)]
+pub(crate) struct LocalRegexCache {
+    regexes: AHashMap<String, Arc<Regex>>,
+}
+
+impl LocalRegexCache {
+    /// Returns the compiled regex of a pattern, only going to the shared cache the first time the call uses it.
+    pub fn compile(&mut self, pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+        if let Some(regex) = self.regexes.get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = compile_regex(pattern)?;
+        self.regexes.insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{LocalRegexCache, LruCache, ShardedRegexCache, compile_regex};
+
+    #[test]
+    fn test_compile_regex_is_cached() {
+        let first = compile_regex(r"^ca(t|r)\d+$").unwrap();
+        let second = compile_regex(r"^ca(t|r)\d+$").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(second.is_match("cat42"));
+
+        let mut local = LocalRegexCache::default();
+        assert!(Arc::ptr_eq(
+            &local.compile(r"^ca(t|r)\d+$").unwrap(),
+            &first
+        ));
+        assert!(local.compile(r"(unclosed").is_err());
+        assert!(compile_regex(r"(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_regex_cache_evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.insert("a", Arc::new(regex::Regex::new("a").unwrap()));
+        cache.insert("b", Arc::new(regex::Regex::new("b").unwrap()));
+        // Using "a" makes "b" the least recently used pattern.
+        assert!(cache.get("a").is_some());
+        cache.insert("c", Arc::new(regex::Regex::new("c").unwrap()));
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+        assert_eq!(cache.indices.len(), 2);
+
+        // "a" is now the least recently used pattern, and reinserting "c" keeps it the most recently used one.
+        cache.insert("c", Arc::new(regex::Regex::new("c").unwrap()));
+        cache.insert("d", Arc::new(regex::Regex::new("d").unwrap()));
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("c").is_some());
+        assert!(cache.get("d").is_some());
+        assert_eq!(cache.entries.len(), 2);
+    }
+
+    #[test]
+    fn test_sharded_regex_cache_bounds_each_shard() {
+        let cache = ShardedRegexCache::new(8, 4);
+        for i in 0..100 {
+            let pattern = format!("pattern{i}");
+            cache
+                .shard(&pattern)
+                .lock()
+                .unwrap()
+                .insert(&pattern, Arc::new(regex::Regex::new(&pattern).unwrap()));
+        }
+        for shard in &cache.shards {
+            assert_eq!(shard.lock().unwrap().indices.len(), 2);
+        }
+    }
+}
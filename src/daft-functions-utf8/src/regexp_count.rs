@@ -9,6 +9,8 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::regex_cache::{LocalRegexCache, compile_regex};
+
 #[derive(FunctionArgs)]
 struct Args<T> {
     input: T,
@@ -63,7 +65,7 @@ fn regexp_count_impl(arr: &Utf8Array, patterns: &Utf8Array) -> DaftResult<UInt64
             .get(0)
             .ok_or_else(|| DaftError::ValueError("Pattern cannot be null".to_string()))?;
 
-        let regex = regex::Regex::new(pattern_str)
+        let regex = compile_regex(pattern_str)
             .map_err(|e| DaftError::ValueError(format!("Invalid regex pattern: {}", e)))?;
 
         let iter = arr
@@ -77,6 +79,7 @@ fn regexp_count_impl(arr: &Utf8Array, patterns: &Utf8Array) -> DaftResult<UInt64
         ))
     } else {
         let mut arr_builder = UInt64Builder::with_capacity(arr.len());
+        let mut regexes = LocalRegexCache::default();
         for (val, pattern) in arr.into_iter().zip(patterns.into_iter()) {
             let Some(val) = val else {
                 arr_builder.append_null();
@@ -86,7 +89,8 @@ fn regexp_count_impl(arr: &Utf8Array, patterns: &Utf8Array) -> DaftResult<UInt64
                 arr_builder.append_null();
                 continue;
             };
-            let regex = regex::Regex::new(pat)
+            let regex = regexes
+                .compile(pat)
                 .map_err(|e| DaftError::ValueError(format!("Invalid regex pattern: {}", e)))?;
 
             arr_builder.append_value(regex.find_iter(val).count() as u64);
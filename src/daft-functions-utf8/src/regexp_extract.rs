@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use common_error::{DaftError, DaftResult, ensure};
 use daft_core::{
     prelude::{DataType, Field, FullNull, Schema, Utf8Array},
@@ -9,7 +11,10 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::{create_broadcasted_str_iter, parse_inputs};
+use crate::{
+    regex_cache::{LocalRegexCache, compile_regex},
+    utils::{create_broadcasted_str_iter, parse_inputs},
+};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct RegexpExtract;
@@ -93,12 +98,15 @@ fn extract_impl(s: &Utf8Array, pattern: &Utf8Array, index: usize) -> DaftResult<
     let self_iter = create_broadcasted_str_iter(s, expected_size);
     let result = match pattern.len() {
         1 => {
-            let regex = regex::Regex::new(pattern.get(0).unwrap());
+            let regex = compile_regex(pattern.get(0).unwrap());
             let regex_iter = std::iter::repeat_n(Some(regex), expected_size);
             regex_extract_first_match(self_iter, regex_iter, index, s.name())?
         }
         _ => {
-            let regex_iter = pattern.into_iter().map(|pat| pat.map(regex::Regex::new));
+            let mut regexes = LocalRegexCache::default();
+            let regex_iter = pattern
+                .into_iter()
+                .map(|pat| pat.map(|pat| regexes.compile(pat)));
             regex_extract_first_match(self_iter, regex_iter, index, s.name())?
         }
     };
@@ -108,7 +116,7 @@ fn extract_impl(s: &Utf8Array, pattern: &Utf8Array, index: usize) -> DaftResult<
 
 fn regex_extract_first_match<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
-    regex_iter: impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>>,
+    regex_iter: impl Iterator<Item = Option<Result<Arc<regex::Regex>, regex::Error>>>,
     index: usize,
     name: &str,
 ) -> DaftResult<Utf8Array> {
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use common_error::{DaftError, DaftResult, ensure};
 use daft_arrow::array::Array;
 use daft_core::{
@@ -11,6 +13,8 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::regex_cache::{LocalRegexCache, compile_regex};
+
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct RegexpExtractAll;
 
@@ -103,12 +107,15 @@ fn extract_all_impl(arr: &Utf8Array, pattern: &Utf8Array, index: usize) -> DaftR
     let self_iter = crate::utils::create_broadcasted_str_iter(arr, expected_size);
     let result = match pattern.len() {
         1 => {
-            let regex = regex::Regex::new(pattern.get(0).unwrap());
+            let regex = compile_regex(pattern.get(0).unwrap());
             let regex_iter = std::iter::repeat_n(Some(regex), expected_size);
             regex_extract_all_matches(self_iter, regex_iter, index, expected_size, arr.name())?
         }
         _ => {
-            let regex_iter = pattern.into_iter().map(|pat| pat.map(regex::Regex::new));
+            let mut regexes = LocalRegexCache::default();
+            let regex_iter = pattern
+                .into_iter()
+                .map(|pat| pat.map(|pat| regexes.compile(pat)));
             regex_extract_all_matches(self_iter, regex_iter, index, expected_size, arr.name())?
         }
     };
@@ -118,7 +125,7 @@ fn extract_all_impl(arr: &Utf8Array, pattern: &Utf8Array, index: usize) -> DaftR
 
 fn regex_extract_all_matches<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
-    regex_iter: impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>>,
+    regex_iter: impl Iterator<Item = Option<Result<Arc<regex::Regex>, regex::Error>>>,
     index: usize,
     len: usize,
     name: &str,
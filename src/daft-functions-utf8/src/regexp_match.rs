@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+
 use common_error::DaftResult;
 use daft_core::{
     prelude::{BooleanArray, DataType, Field, FullNull, Schema, Utf8Array},
@@ -9,7 +11,10 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::{Utf8ArrayUtils, binary_utf8_evaluate, binary_utf8_to_field};
+use crate::{
+    regex_cache::{LocalRegexCache, compile_regex},
+    utils::{Utf8ArrayUtils, binary_utf8_evaluate, binary_utf8_to_field},
+};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct RegexpMatch;
@@ -64,7 +69,7 @@ fn match_impl(arr: &Utf8Array, pattern: &Utf8Array) -> DaftResult<BooleanArray>
                 arr.len(),
             )),
             Some(pattern_v) => {
-                let re = regex::Regex::new(pattern_v)?;
+                let re = compile_regex(pattern_v)?;
                 let arrow_result: daft_arrow::array::BooleanArray = arr
                     .into_iter()
                     .map(|arr_v| Some(re.is_match(arr_v?)))
@@ -74,9 +79,10 @@ fn match_impl(arr: &Utf8Array, pattern: &Utf8Array) -> DaftResult<BooleanArray>
         };
     }
 
+    let regexes = RefCell::new(LocalRegexCache::default());
     arr.binary_broadcasted_compare(
         pattern,
-        |data: &str, pat: &str| Ok(regex::Regex::new(pat)?.is_match(data)),
+        |data: &str, pat: &str| Ok(regexes.borrow_mut().compile(pat)?.is_match(data)),
         "match",
     )
 }
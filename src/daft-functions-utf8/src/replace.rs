@@ -12,7 +12,10 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::{create_broadcasted_str_iter, parse_inputs};
+use crate::{
+    regex_cache::{LocalRegexCache, compile_regex},
+    utils::{create_broadcasted_str_iter, parse_inputs},
+};
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct RegexpReplace;
@@ -138,16 +141,16 @@ fn replace_impl(
 
     let result = match (regex, pattern.len()) {
         (true, 1) => {
-            let regex_val = regex::Regex::new(pattern.get(0).unwrap());
-            let regex = regex_val.as_ref().map_err(|e| e.clone());
+            let regex = compile_regex(pattern.get(0).unwrap());
             let regex_iter = std::iter::repeat_n(Some(regex), expected_size);
             regex_replace(arr_iter, regex_iter, replacement_iter, arr.name())?
         }
         (true, _) => {
+            let mut regexes = LocalRegexCache::default();
             let regex_iter = pattern
                 .as_arrow2()
                 .iter()
-                .map(|pat| pat.map(regex::Regex::new));
+                .map(|pat| pat.map(|pat| regexes.compile(pat)));
             regex_replace(arr_iter, regex_iter, replacement_iter, arr.name())?
         }
         (false, _) => {
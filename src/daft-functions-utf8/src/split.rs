@@ -1,4 +1,6 @@
 #![allow(deprecated, reason = "arrow2 migration")]
+use std::sync::Arc;
+
 use common_error::{DaftError, DaftResult};
 use daft_arrow::array::Array;
 use daft_core::{
@@ -12,8 +14,11 @@ use daft_dsl::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::utils::{
-    binary_utf8_evaluate, binary_utf8_to_field, create_broadcasted_str_iter, parse_inputs,
+use crate::{
+    regex_cache::{LocalRegexCache, compile_regex},
+    utils::{
+        binary_utf8_evaluate, binary_utf8_to_field, create_broadcasted_str_iter, parse_inputs,
+    },
 };
 
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -123,7 +128,7 @@ fn split_impl(arr: &Utf8Array, pattern: &Utf8Array, regex: bool) -> DaftResult<L
     let arr_iter = create_broadcasted_str_iter(arr, expected_size);
     match (regex, pattern.len()) {
         (true, 1) => {
-            let regex = regex::Regex::new(pattern.get(0).unwrap());
+            let regex = compile_regex(pattern.get(0).unwrap());
             let regex_iter = std::iter::repeat_n(Some(regex), expected_size);
             split_array_on_regex(
                 arr_iter,
@@ -134,10 +139,11 @@ fn split_impl(arr: &Utf8Array, pattern: &Utf8Array, regex: bool) -> DaftResult<L
             )?;
         }
         (true, _) => {
+            let mut regexes = LocalRegexCache::default();
             let regex_iter = pattern
                 .as_arrow2()
                 .iter()
-                .map(|pat| pat.map(regex::Regex::new));
+                .map(|pat| pat.map(|pat| regexes.compile(pat)));
             split_array_on_regex(
                 arr_iter,
                 regex_iter,
@@ -175,7 +181,7 @@ fn split_impl(arr: &Utf8Array, pattern: &Utf8Array, regex: bool) -> DaftResult<L
 
 fn split_array_on_regex<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
-    regex_iter: impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>>,
+    regex_iter: impl Iterator<Item = Option<Result<Arc<regex::Regex>, regex::Error>>>,
     splits: &mut daft_arrow::array::MutableUtf8Array<i64>,
     offsets: &mut daft_arrow::offset::Offsets<i64>,
     validity: &mut daft_arrow::buffer::NullBufferBuilder,
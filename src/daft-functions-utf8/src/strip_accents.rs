@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use common_error::DaftResult;
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+use crate::utils::{Utf8ArrayUtils, unary_utf8_evaluate, unary_utf8_to_field};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct StripAccents;
+
+#[typetag::serde]
+impl ScalarUDF for StripAccents {
+    fn name(&self) -> &'static str {
+        "strip_accents"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        unary_utf8_evaluate(inputs, |s| {
+            s.with_utf8_array(|arr| {
+                Ok(arr
+                    .unary_broadcasted_op(|val| strip_accents_impl(val))?
+                    .into_series())
+            })
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        unary_utf8_to_field(inputs, schema, self.name(), DataType::Utf8)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Removes accents and other combining marks from a string."
+    }
+}
+
+#[must_use]
+pub fn strip_accents(input: ExprRef) -> ExprRef {
+    ScalarFn::builtin(StripAccents, vec![input]).into()
+}
+
+/// Decomposes a string and drops the combining marks, recomposing what's left.
+pub(crate) fn strip_accents_impl(val: &str) -> Cow<'_, str> {
+    if val.is_ascii() {
+        return Cow::Borrowed(val);
+    }
+    Cow::Owned(val.nfd().filter(|c| !is_combining_mark(*c)).nfc().collect())
+}
@@ -0,0 +1,124 @@
+use common_error::{DaftResult, ensure};
+use daft_core::{
+    prelude::{Field, Schema, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TransliterateToAscii;
+
+#[derive(FunctionArgs)]
+struct TransliterateArgs<T> {
+    input: T,
+    #[arg(optional)]
+    replacement: Option<String>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for TransliterateToAscii {
+    fn name(&self) -> &'static str {
+        "transliterate_to_ascii"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let TransliterateArgs { input, replacement } = inputs.try_into()?;
+        if input.data_type().is_null() {
+            return Ok(input);
+        }
+        let replacement = replacement.as_deref().unwrap_or("?");
+        input.with_utf8_array(|arr| {
+            Ok(Utf8Array::from_iter(
+                arr.name(),
+                arr.into_iter()
+                    .map(|val| val.map(|s| transliterate_impl(s, replacement))),
+            )
+            .into_series())
+        })
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let TransliterateArgs { input, .. } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        ensure!(
+            input.dtype.is_string() || input.dtype.is_null(),
+            TypeError: "Expects input to '{}' to be utf8, but received {}", self.name(), input.dtype
+        );
+        Ok(input)
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Transliterates a string to ASCII, replacing characters without an ASCII equivalent."
+    }
+}
+
+#[must_use]
+pub fn transliterate_to_ascii(input: ExprRef) -> ExprRef {
+    ScalarFn::builtin(TransliterateToAscii, vec![input]).into()
+}
+
+/// Returns the ASCII equivalent of a letter or punctuation mark that doesn't decompose into one.
+fn ascii_equivalent(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ø' => "O",
+        'ø' => "o",
+        'Đ' | 'Ð' => "D",
+        'đ' | 'ð' => "d",
+        'Ł' => "L",
+        'ł' => "l",
+        'Þ' => "TH",
+        'þ' => "th",
+        'ı' => "i",
+        '‘' | '’' | '‚' | '′' => "'",
+        '“' | '”' | '„' | '«' | '»' | '″' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => "-",
+        '⁄' => "/",
+        '•' | '·' => "*",
+        _ => return None,
+    })
+}
+
+fn transliterate_impl(val: &str, replacement: &str) -> String {
+    if val.is_ascii() {
+        return val.to_string();
+    }
+    let mut out = String::with_capacity(val.len());
+    for c in val.nfkd().filter(|c| !is_combining_mark(*c)) {
+        if c.is_ascii() {
+            out.push(c);
+        } else if let Some(equivalent) = ascii_equivalent(c) {
+            out.push_str(equivalent);
+        } else {
+            out.push_str(replacement);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transliterate_impl;
+
+    #[test]
+    fn test_transliterate() {
+        assert_eq!(transliterate_impl("Crème brûlée", "?"), "Creme brulee");
+        assert_eq!(transliterate_impl("Straße “Łódź”", "?"), "Strasse \"Lodz\"");
+        assert_eq!(transliterate_impl("ﬁ½", "?"), "fi1/2");
+        assert_eq!(transliterate_impl("日本", ""), "");
+    }
+}
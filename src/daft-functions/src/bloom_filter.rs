@@ -0,0 +1,244 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::functions::{prelude::*, scalar::ScalarFn};
+use serde::{Deserialize, Serialize};
+
+/// A Bloom filter over the hashes of rows of one or more key columns.
+///
+/// Rows are hashed the same way as the keys of hash joins, so a filter built from the keys of one side of a join can
+/// be probed with the keys of the other side, as long as both are cast to the same types.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    /// Creates an empty filter sized to hold `num_items` items with the given false positive rate.
+    pub fn new(num_items: usize, false_positive_rate: f64) -> DaftResult<Self> {
+        if !(false_positive_rate > 0.0 && false_positive_rate < 1.0) {
+            return Err(DaftError::ValueError(format!(
+                "Expected the false positive rate of a Bloom filter to be between 0 and 1 exclusive, got {false_positive_rate}"
+            )));
+        }
+        let num_items = num_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-num_items * false_positive_rate.ln() / (ln2 * ln2)).ceil() as usize;
+        let num_words = num_bits.div_ceil(64).max(1);
+        let num_hashes = ((num_words * 64) as f64 / num_items * ln2)
+            .round()
+            .clamp(1.0, 30.0);
+        Ok(Self {
+            bits: vec![0; num_words],
+            num_hashes: num_hashes as u32,
+        })
+    }
+
+    /// Builds a filter over the rows of the key columns, skipping rows where any key is null.
+    pub fn from_keys(keys: &[Series], false_positive_rate: f64) -> DaftResult<Self> {
+        let num_rows = keys.first().map_or(0, Series::len);
+        let mut filter = Self::new(num_rows, false_positive_rate)?;
+        filter.insert_keys(keys)?;
+        Ok(filter)
+    }
+
+    /// Inserts the rows of the key columns, skipping rows where any key is null.
+    pub fn insert_keys(&mut self, keys: &[Series]) -> DaftResult<()> {
+        if keys.first().is_none_or(|key| key.is_empty()) {
+            return Ok(());
+        }
+        let hashes = hash_keys(keys)?;
+        for (i, hash) in hashes.as_slice().iter().enumerate() {
+            if keys.iter().all(|key| key.is_valid(i)) {
+                self.insert_hash(*hash);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the items of another filter to this one, so that filters built from parts of the keys can be combined
+    /// into a filter over all of them. Both filters must have been created with the same size and false positive
+    /// rate.
+    pub fn merge(&mut self, other: &Self) -> DaftResult<()> {
+        if self.bits.len() != other.bits.len() || self.num_hashes != other.num_hashes {
+            return Err(DaftError::InternalError(format!(
+                "Cannot merge a Bloom filter of {} bits and {} hashes into one of {} bits and {} hashes",
+                other.num_bits(),
+                other.num_hashes,
+                self.num_bits(),
+                self.num_hashes
+            )));
+        }
+        for (word, other_word) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= other_word;
+        }
+        Ok(())
+    }
+
+    pub fn num_bits(&self) -> usize {
+        self.bits.len() * 64
+    }
+
+    pub fn insert_hash(&mut self, hash: u64) {
+        for index in self.bit_indices(hash) {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    pub fn might_contain_hash(&self, hash: u64) -> bool {
+        self.bit_indices(hash)
+            .all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Returns whether each row of the key columns might be in the filter. Rows where any key is null are never in
+    /// the filter.
+    pub fn might_contain(&self, keys: &[Series]) -> DaftResult<BooleanArray> {
+        let num_rows = keys.first().map_or(0, Series::len);
+        let hashes = hash_keys(keys)?;
+        let contained = hashes
+            .as_slice()
+            .iter()
+            .enumerate()
+            .map(|(i, hash)| {
+                Some(keys.iter().all(|key| key.is_valid(i)) && self.might_contain_hash(*hash))
+            })
+            .collect::<Vec<_>>();
+        debug_assert_eq!(contained.len(), num_rows);
+        Ok(BooleanArray::from_iter(
+            "bloom_filter",
+            contained.into_iter(),
+        ))
+    }
+
+    /// Derives the bit indices of a hash with double hashing, which is as accurate as using independent hash
+    /// functions.
+    fn bit_indices(&self, hash: u64) -> impl Iterator<Item = usize> + '_ {
+        let num_bits = self.num_bits() as u64;
+        let (h1, h2) = (hash & 0xFFFF_FFFF, hash >> 32);
+        (0..u64::from(self.num_hashes))
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+}
+
+/// Hashes the rows of the key columns, in the same way as `RecordBatch::hash_rows`.
+fn hash_keys(keys: &[Series]) -> DaftResult<UInt64Array> {
+    let (first, rest) = keys.split_first().ok_or_else(|| {
+        DaftError::ValueError("Expected at least one key for a Bloom filter".to_string())
+    })?;
+    let mut hashes = first.hash(None)?;
+    for key in rest {
+        hashes = key.hash(Some(&hashes))?;
+    }
+    Ok(hashes)
+}
+
+/// Returns whether rows might be in a Bloom filter, which is used to prune rows that can't match the other side of a
+/// join before they are shuffled.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct BloomFilterMightContain {
+    pub filter: Arc<BloomFilter>,
+}
+
+#[derive(FunctionArgs)]
+struct Args<T> {
+    #[arg(variadic)]
+    keys: Vec<T>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for BloomFilterMightContain {
+    fn name(&self) -> &'static str {
+        "bloom_filter_might_contain"
+    }
+
+    fn call(&self, inputs: daft_dsl::functions::FunctionArgs<Series>) -> DaftResult<Series> {
+        let Args { keys } = inputs.try_into()?;
+        Ok(self.filter.might_contain(&keys)?.into_series())
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let Args { keys } = inputs.try_into()?;
+        let first = keys.first().ok_or_else(|| {
+            DaftError::ValueError(
+                "bloom_filter_might_contain() requires at least one expression".to_string(),
+            )
+        })?;
+        let name = first.to_field(schema)?.name;
+        Ok(Field::new(name, DataType::Boolean))
+    }
+}
+
+/// Creates a predicate that is true for the rows whose keys might be in the filter.
+#[must_use]
+pub fn bloom_filter_might_contain(filter: Arc<BloomFilter>, keys: Vec<ExprRef>) -> ExprRef {
+    ScalarFn::builtin(BloomFilterMightContain { filter }, keys).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::BloomFilter;
+
+    #[test]
+    fn test_bloom_filter() {
+        let build = Int64Array::from_values("a", (0..1000).map(|i| i * 2)).into_series();
+        let filter = BloomFilter::from_keys(&[build], 0.01).unwrap();
+
+        let probe = Int64Array::from_values("a", 0..2000).into_series();
+        let contained = filter.might_contain(&[probe]).unwrap();
+
+        // Keys that were inserted are always found.
+        assert!((0..1000).all(|i| contained.get(i * 2) == Some(true)));
+        // Keys that weren't inserted are rarely found.
+        let false_positives = (0..1000)
+            .filter(|i| contained.get(i * 2 + 1) == Some(true))
+            .count();
+        assert!(false_positives < 50, "{false_positives} false positives");
+    }
+
+    #[test]
+    fn test_bloom_filter_nulls() {
+        let build = Int64Array::from_iter(
+            Field::new("a", DataType::Int64),
+            vec![Some(1), None].into_iter(),
+        )
+        .into_series();
+        let filter = BloomFilter::from_keys(&[build.clone()], 0.01).unwrap();
+        let contained = filter.might_contain(&[build]).unwrap();
+        assert_eq!(contained.get(0), Some(true));
+        assert_eq!(contained.get(1), Some(false));
+    }
+
+    #[test]
+    fn test_bloom_filter_merge() {
+        let mut filter = BloomFilter::new(200, 0.01).unwrap();
+        let mut other = filter.clone();
+        filter
+            .insert_keys(&[Int64Array::from_values("a", 0..100).into_series()])
+            .unwrap();
+        other
+            .insert_keys(&[Int64Array::from_values("a", 100..200).into_series()])
+            .unwrap();
+        filter.merge(&other).unwrap();
+
+        let contained = filter
+            .might_contain(&[Int64Array::from_values("a", 0..200).into_series()])
+            .unwrap();
+        assert!((0..200).all(|i| contained.get(i) == Some(true)));
+
+        assert!(filter.merge(&BloomFilter::new(10, 0.01).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_false_positive_rate() {
+        assert!(BloomFilter::new(10, 0.0).is_err());
+        assert!(BloomFilter::new(10, 1.0).is_err());
+    }
+}
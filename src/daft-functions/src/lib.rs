@@ -2,28 +2,37 @@
     deprecated,
     reason = "moving over all scalarUDFs to new pattern. Remove once completed!"
 )]
+pub mod bloom_filter;
 pub mod coalesce;
 pub mod distance;
 pub mod float;
 pub mod hash;
 pub mod length;
+pub mod lookup;
 pub mod minhash;
 pub mod monotonically_increasing_id;
 pub mod numeric;
+pub mod one_hot;
+pub mod point_cloud;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod slice;
 pub mod to_struct;
+pub mod try_cast;
 
 use common_error::DaftError;
 use daft_dsl::functions::{FunctionModule, FunctionRegistry};
 use hash::HashFunction;
 use length::Length;
+use lookup::{BroadcastLookup, Lookup};
 use minhash::MinHashFunction;
+use one_hot::OneHot;
+use point_cloud::{CropPoints, VoxelDownsample};
 #[cfg(feature = "python")]
 pub use python::register as register_modules;
 use snafu::Snafu;
 use to_struct::ToStructFunction;
+use try_cast::TryCast;
 
 use crate::slice::Slice;
 
@@ -63,5 +72,11 @@ impl FunctionModule for MiscFunctions {
         parent.add_fn(Length);
         parent.add_fn(ToStructFunction);
         parent.add_fn(Slice);
+        parent.add_fn(Lookup);
+        parent.add_fn(BroadcastLookup);
+        parent.add_fn(OneHot);
+        parent.add_fn(VoxelDownsample);
+        parent.add_fn(CropPoints);
+        parent.add_fn(TryCast::default());
     }
 }
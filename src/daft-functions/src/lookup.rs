@@ -0,0 +1,188 @@
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    lit::Literal,
+    prelude::{DataType, Field, Schema},
+    series::Series,
+    utils::supertype::try_get_supertype,
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+
+/// Looks up each value of the input in a small static table, given as a pair of list literals of keys and values.
+/// Values without a match map to the optional `default`, or to null.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Lookup;
+
+#[derive(FunctionArgs)]
+struct LookupArgs<T> {
+    input: T,
+    keys: T,
+    values: T,
+    #[arg(optional)]
+    default: Option<T>,
+}
+
+/// Unwrap the single list literal that a static table argument, such as a lookup table's keys or values, evaluates to.
+pub(crate) fn list_literal_arg(arg: &Series, function: &str, name: &str) -> DaftResult<Series> {
+    ensure!(
+        arg.len() == 1,
+        ComputeError: "Expected `{name}` argument to `{function}` to be a list literal, got {} rows",
+        arg.len()
+    );
+    match arg.get_lit(0) {
+        Literal::List(series) => Ok(series),
+        Literal::Null => Ok(Series::empty(name, &DataType::Null)),
+        other => Err(DaftError::TypeError(format!(
+            "Expected `{name}` argument to `{function}` to be a list literal, got {other}"
+        ))),
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for Lookup {
+    fn name(&self) -> &'static str {
+        "lookup"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let LookupArgs {
+            input,
+            keys,
+            values,
+            default,
+        } = args.try_into()?;
+        let keys = list_literal_arg(&keys, self.name(), "keys")?;
+        let values = list_literal_arg(&values, self.name(), "values")?;
+        input.lookup(&keys, &values, default.as_ref())
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let LookupArgs {
+            input,
+            keys,
+            values,
+            default,
+        } = args.try_into()?;
+
+        let input_field = input.to_field(schema)?;
+        let DataType::List(_) = keys.get_type(schema)? else {
+            return Err(DaftError::TypeError(
+                "`keys` argument to `lookup` must be a list".to_string(),
+            ));
+        };
+        let DataType::List(value_dtype) = values.get_type(schema)? else {
+            return Err(DaftError::TypeError(
+                "`values` argument to `lookup` must be a list".to_string(),
+            ));
+        };
+        let dtype = match default {
+            Some(default) => try_get_supertype(&value_dtype, &default.get_type(schema)?)?,
+            None => *value_dtype,
+        };
+        Ok(Field::new(input_field.name, dtype))
+    }
+}
+
+/// Looks up each value of the input in a table held by a `daft.broadcast` of a mapping, which is given as a Python
+/// literal so that the plan only holds a reference to it. Each process converts the table once, when it's first
+/// looked up. `dtype` is the type of the table's values.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct BroadcastLookup;
+
+#[derive(FunctionArgs)]
+struct BroadcastLookupArgs<T> {
+    input: T,
+    table: T,
+    dtype: DataType,
+    #[arg(optional)]
+    default: Option<T>,
+}
+
+/// Returns the keys and values of the broadcast mapping that a `table` argument evaluates to.
+#[cfg(feature = "python")]
+fn broadcast_table(arg: &Series) -> DaftResult<(Series, Series)> {
+    use daft_core::python::PySeries;
+    use pyo3::{Python, intern, types::PyAnyMethods};
+
+    ensure!(
+        arg.len() == 1,
+        ComputeError: "Expected `table` argument to `broadcast_lookup` to be a broadcast, got {} rows",
+        arg.len()
+    );
+    let Literal::Python(broadcast) = arg.get_lit(0) else {
+        return Err(DaftError::TypeError(
+            "Expected `table` argument to `broadcast_lookup` to be a broadcast".to_string(),
+        ));
+    };
+    Python::attach(|py| {
+        let (keys, values) = py
+            .import(intern!(py, "daft.broadcast"))?
+            .getattr(intern!(py, "_lookup_table"))?
+            .call1((broadcast.0.as_ref(),))?
+            .extract::<(PySeries, PySeries)>()?;
+        Ok((keys.series, values.series))
+    })
+}
+
+#[cfg(not(feature = "python"))]
+fn broadcast_table(_arg: &Series) -> DaftResult<(Series, Series)> {
+    Err(DaftError::ComputeError(
+        "`broadcast_lookup` requires the python feature".to_string(),
+    ))
+}
+
+#[typetag::serde]
+impl ScalarUDF for BroadcastLookup {
+    fn name(&self) -> &'static str {
+        "broadcast_lookup"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let BroadcastLookupArgs {
+            input,
+            table,
+            dtype,
+            default,
+        } = args.try_into()?;
+        let (keys, values) = broadcast_table(&table)?;
+        input.lookup(&keys, &values.cast(&dtype)?, default.as_ref())
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let BroadcastLookupArgs {
+            input,
+            table,
+            dtype,
+            default,
+        } = args.try_into()?;
+
+        let input_field = input.to_field(schema)?;
+        let DataType::Python = table.get_type(schema)? else {
+            return Err(DaftError::TypeError(
+                "`table` argument to `broadcast_lookup` must be a broadcast".to_string(),
+            ));
+        };
+        let dtype = match default {
+            Some(default) => try_get_supertype(&dtype, &default.get_type(schema)?)?,
+            None => dtype,
+        };
+        Ok(Field::new(input_field.name, dtype))
+    }
+}
+
+#[must_use]
+/// Maps each value of `input` to the value paired with its key in a static lookup table.
+///
+/// # Arguments
+/// * `input` - The expression to look up
+/// * `keys` - A list literal of the table's keys
+/// * `values` - A list literal of the table's values, paired element-wise with `keys`
+/// * `default` - The value for inputs that are not in the table, null if not provided
+pub fn lookup(input: ExprRef, keys: ExprRef, values: ExprRef, default: Option<ExprRef>) -> ExprRef {
+    let mut inputs = vec![input, keys, values];
+    inputs.extend(default);
+    ScalarFn::builtin(Lookup, inputs).into()
+}
@@ -0,0 +1,151 @@
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    lit::Literal,
+    prelude::{
+        DataType, Field, FixedSizeListArray, ListArray, Schema, StructArray, UInt8Array,
+        UInt64Array,
+    },
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    Expr, ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::lookup::list_literal_arg;
+
+/// One-hot encodes each value of the input against a vocabulary, given as a list literal.
+/// Each row becomes a fixed-size list of 0s and 1s with a 1 at the position of its value in the vocabulary.
+/// Values that are not in the vocabulary, including nulls, become all 0s.
+///
+/// With `sparse`, each row instead becomes a struct of the vocabulary `size`, and of the `indices` and `values` of its
+/// non-zero entries, which are empty for values that are not in the vocabulary.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OneHot;
+
+#[derive(FunctionArgs)]
+struct OneHotArgs<T> {
+    input: T,
+    vocabulary: T,
+    #[arg(optional)]
+    sparse: Option<bool>,
+}
+
+fn sparse_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("size", DataType::UInt64),
+        Field::new("indices", DataType::List(Box::new(DataType::UInt64))),
+        Field::new("values", DataType::List(Box::new(DataType::UInt8))),
+    ])
+}
+
+fn dense_one_hot(name: &str, indices: &UInt64Array, size: usize) -> Series {
+    let mut flat_child = vec![0u8; indices.len() * size];
+    for (row, idx) in indices.into_iter().enumerate() {
+        if let Some(idx) = idx {
+            flat_child[row * size + *idx as usize] = 1;
+        }
+    }
+
+    let field = Field::new(
+        name,
+        DataType::FixedSizeList(Box::new(DataType::UInt8), size),
+    );
+    let flat_child = UInt8Array::from(("item", flat_child)).into_series();
+    FixedSizeListArray::new(field, flat_child, None).into_series()
+}
+
+fn sparse_one_hot(name: &str, indices: &UInt64Array, size: usize) -> DaftResult<Series> {
+    let lengths = indices
+        .into_iter()
+        .map(|idx| usize::from(idx.is_some()))
+        .collect::<Vec<_>>();
+    let flat_indices = indices.into_iter().flatten().copied().collect::<Vec<_>>();
+    let flat_values = vec![1u8; flat_indices.len()];
+    let offsets: daft_arrow::offset::OffsetsBuffer<i64> =
+        daft_arrow::offset::Offsets::try_from_lengths(lengths.into_iter())?.into();
+
+    let sizes = UInt64Array::from(("size", vec![size as u64; indices.len()])).into_series();
+    let indices = ListArray::new(
+        Field::new("indices", DataType::List(Box::new(DataType::UInt64))),
+        UInt64Array::from(("item", flat_indices)).into_series(),
+        offsets.clone(),
+        None,
+    )
+    .into_series();
+    let values = ListArray::new(
+        Field::new("values", DataType::List(Box::new(DataType::UInt8))),
+        UInt8Array::from(("item", flat_values)).into_series(),
+        offsets,
+        None,
+    )
+    .into_series();
+    Ok(StructArray::new(
+        Field::new(name, sparse_dtype()),
+        vec![sizes, indices, values],
+        None,
+    )
+    .into_series())
+}
+
+#[typetag::serde]
+impl ScalarUDF for OneHot {
+    fn name(&self) -> &'static str {
+        "one_hot"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let OneHotArgs {
+            input,
+            vocabulary,
+            sparse,
+        } = args.try_into()?;
+        let vocabulary = list_literal_arg(&vocabulary, self.name(), "vocabulary")?;
+        let size = vocabulary.len();
+        ensure!(size > 0, ValueError: "`vocabulary` argument to `one_hot` must not be empty");
+
+        let positions = UInt64Array::from(("positions", (0..size as u64).collect::<Vec<_>>()));
+        let indices = input.lookup(&vocabulary, &positions.into_series(), None)?;
+
+        if sparse.unwrap_or(false) {
+            sparse_one_hot(input.name(), indices.u64()?, size)
+        } else {
+            Ok(dense_one_hot(input.name(), indices.u64()?, size))
+        }
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let OneHotArgs {
+            input,
+            vocabulary,
+            sparse,
+        } = args.try_into()?;
+
+        let input_field = input.to_field(schema)?;
+        // The output size is part of the type, so the vocabulary has to be known when planning.
+        let Expr::Literal(Literal::List(vocabulary)) = vocabulary.as_ref() else {
+            return Err(DaftError::TypeError(
+                "`vocabulary` argument to `one_hot` must be a list literal".to_string(),
+            ));
+        };
+        ensure!(!vocabulary.is_empty(), ValueError: "`vocabulary` argument to `one_hot` must not be empty");
+
+        let dtype = if sparse.unwrap_or(false) {
+            sparse_dtype()
+        } else {
+            DataType::FixedSizeList(Box::new(DataType::UInt8), vocabulary.len())
+        };
+        Ok(Field::new(input_field.name, dtype))
+    }
+}
+
+#[must_use]
+/// One-hot encodes each value of `input` as a fixed-size list of 0s and 1s, with one entry per vocabulary value.
+///
+/// # Arguments
+/// * `input` - The expression to encode
+/// * `vocabulary` - A list literal of the values to encode, in the order of their positions in the output
+pub fn one_hot(input: ExprRef, vocabulary: ExprRef) -> ExprRef {
+    ScalarFn::builtin(OneHot, vec![input, vocabulary]).into()
+}
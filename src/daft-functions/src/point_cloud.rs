@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult, ensure};
+use daft_core::{
+    prelude::{DataType, Field, Float64Array, ListArray, Schema, StructArray, UInt64Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF, scalar::ScalarFn},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::lookup::list_literal_arg;
+
+/// The fields of a point struct which hold its coordinates.
+const COORDINATES: [&str; 3] = ["x", "y", "z"];
+
+/// Checks that a field is a point cloud, i.e. a list of structs with numeric `x`, `y` and `z` fields.
+fn check_points_field(field: &Field, function: &str) -> DaftResult<()> {
+    let fields: &[Field] = match &field.dtype {
+        DataType::List(inner) => match inner.as_ref() {
+            DataType::Struct(fields) => fields,
+            _ => &[],
+        },
+        _ => &[],
+    };
+    for coordinate in COORDINATES {
+        ensure!(
+            fields.iter().any(|f| f.name == coordinate && f.dtype.is_numeric()),
+            TypeError: "Expected input to `{function}` to be a list of structs with numeric `x`, `y` and `z` fields, got {}",
+            field.dtype
+        );
+    }
+    Ok(())
+}
+
+/// A point cloud column, with the coordinates of its points aligned with the flat child of its lists.
+struct PointCloud<'a> {
+    list: &'a ListArray,
+    coordinates: [Float64Array; 3],
+}
+
+impl<'a> PointCloud<'a> {
+    fn try_new(input: &'a Series) -> DaftResult<Self> {
+        let list = input.list()?;
+        let points = list.flat_child.struct_()?;
+        // Getting the fields of a struct also nulls them where the struct is null.
+        let [x, y, z] = COORDINATES.map(|c| points.get(c)?.cast(&DataType::Float64));
+        Ok(Self {
+            list,
+            coordinates: [x?.f64()?.clone(), y?.f64()?.clone(), z?.f64()?.clone()],
+        })
+    }
+
+    fn is_valid(&self, row: usize) -> bool {
+        self.list.validity().is_none_or(|v| v.is_valid(row))
+    }
+
+    /// Returns the coordinates of the point at `idx` of the flat child, or None if any of them is null.
+    fn point(&self, idx: usize) -> Option<[f64; 3]> {
+        let [x, y, z] = &self.coordinates;
+        Some([x.get(idx)?, y.get(idx)?, z.get(idx)?])
+    }
+
+    /// Returns the points of the flat child at `indices`.
+    fn take(&self, indices: Vec<u64>) -> DaftResult<Series> {
+        self.list
+            .flat_child
+            .take(&UInt64Array::from(("indices", indices)))
+    }
+
+    /// Builds a point cloud column from a flat child of points and the length of each list, keeping null lists.
+    fn build(&self, flat_child: Series, lengths: Vec<usize>) -> DaftResult<Series> {
+        let offsets = daft_arrow::offset::Offsets::try_from_lengths(lengths.into_iter())?;
+        Ok(ListArray::new(
+            self.list.field.clone(),
+            flat_child,
+            offsets.into(),
+            self.list.validity().cloned(),
+        )
+        .into_series())
+    }
+}
+
+/// Downsamples each point cloud to one point per voxel of a regular grid, whose coordinates are the centroid of the
+/// points in the voxel. The other fields of each output point are taken from the first point in its voxel.
+/// Null points and points with null coordinates are dropped, and null point clouds stay null.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct VoxelDownsample;
+
+#[derive(FunctionArgs)]
+struct VoxelDownsampleArgs<T> {
+    input: T,
+    voxel_size: f64,
+}
+
+#[typetag::serde]
+impl ScalarUDF for VoxelDownsample {
+    fn name(&self) -> &'static str {
+        "voxel_downsample"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let VoxelDownsampleArgs { input, voxel_size } = args.try_into()?;
+        ensure!(
+            voxel_size.is_finite() && voxel_size > 0.0,
+            ValueError: "`voxel_size` argument to `voxel_downsample` must be positive, got {voxel_size}"
+        );
+        let points = PointCloud::try_new(&input)?;
+        let offsets = points.list.offsets();
+
+        let mut indices = Vec::new();
+        let mut lengths = Vec::with_capacity(points.list.len());
+        let mut sums: Vec<[f64; 3]> = Vec::new();
+        let mut counts: Vec<f64> = Vec::new();
+        let mut voxels: HashMap<[i64; 3], usize> = HashMap::new();
+        for row in 0..points.list.len() {
+            let start = indices.len();
+            if points.is_valid(row) {
+                voxels.clear();
+                let (begin, end) = offsets.start_end(row);
+                for idx in begin..end {
+                    let Some(point) = points.point(idx) else {
+                        continue;
+                    };
+                    let voxel = point.map(|c| (c / voxel_size).floor() as i64);
+                    let slot = *voxels.entry(voxel).or_insert_with(|| {
+                        indices.push(idx as u64);
+                        sums.push([0.0; 3]);
+                        counts.push(0.0);
+                        sums.len() - 1
+                    });
+                    for (sum, c) in sums[slot].iter_mut().zip(point) {
+                        *sum += c;
+                    }
+                    counts[slot] += 1.0;
+                }
+            }
+            lengths.push(indices.len() - start);
+        }
+
+        let taken = points.take(indices)?;
+        let taken = taken.struct_()?;
+        let children = taken
+            .children
+            .iter()
+            .map(
+                |child| match COORDINATES.iter().position(|c| *c == child.name()) {
+                    Some(axis) => {
+                        let centroids = sums
+                            .iter()
+                            .zip(&counts)
+                            .map(|(sum, count)| sum[axis] / count)
+                            .collect::<Vec<_>>();
+                        Float64Array::from((child.name(), centroids))
+                            .into_series()
+                            .cast(child.data_type())
+                    }
+                    None => Ok(child.clone()),
+                },
+            )
+            .collect::<DaftResult<Vec<_>>>()?;
+        let flat_child = StructArray::new(taken.field.clone(), children, taken.validity().cloned())
+            .into_series();
+        points.build(flat_child, lengths)
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let VoxelDownsampleArgs { input, voxel_size } = args.try_into()?;
+        ensure!(
+            voxel_size.is_finite() && voxel_size > 0.0,
+            ValueError: "`voxel_size` argument to `voxel_downsample` must be positive, got {voxel_size}"
+        );
+        let field = input.to_field(schema)?;
+        check_points_field(&field, self.name())?;
+        Ok(field)
+    }
+}
+
+#[must_use]
+/// Downsamples each point cloud to the centroids of the points in each voxel of a grid.
+///
+/// # Arguments
+/// * `input` - A list of structs with numeric `x`, `y` and `z` fields
+/// * `voxel_size` - The edge length of the voxels
+pub fn voxel_downsample(input: ExprRef, voxel_size: ExprRef) -> ExprRef {
+    ScalarFn::builtin(VoxelDownsample, vec![input, voxel_size]).into()
+}
+
+/// Filters each point cloud to the points within an axis-aligned bounding box, given as list literals of its minimum
+/// and maximum `[x, y, z]` coordinates, with the bounds inclusive. Null points and points with null coordinates are
+/// dropped, and null point clouds stay null.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CropPoints;
+
+#[derive(FunctionArgs)]
+struct CropPointsArgs<T> {
+    input: T,
+    min_bound: T,
+    max_bound: T,
+}
+
+fn bound_arg(arg: &Series, name: &str) -> DaftResult<[f64; 3]> {
+    let bound = list_literal_arg(arg, "crop_points", name)?.cast(&DataType::Float64)?;
+    let bound = bound.f64()?;
+    match (bound.len(), bound.get(0), bound.get(1), bound.get(2)) {
+        (3, Some(x), Some(y), Some(z)) => Ok([x, y, z]),
+        (len, ..) => Err(DaftError::ValueError(format!(
+            "Expected `{name}` argument to `crop_points` to be a list of 3 non-null numbers, got {len} values"
+        ))),
+    }
+}
+
+#[typetag::serde]
+impl ScalarUDF for CropPoints {
+    fn name(&self) -> &'static str {
+        "crop_points"
+    }
+
+    fn call(&self, args: FunctionArgs<Series>) -> DaftResult<Series> {
+        let CropPointsArgs {
+            input,
+            min_bound,
+            max_bound,
+        } = args.try_into()?;
+        let min_bound = bound_arg(&min_bound, "min_bound")?;
+        let max_bound = bound_arg(&max_bound, "max_bound")?;
+        let points = PointCloud::try_new(&input)?;
+        let offsets = points.list.offsets();
+
+        let mut indices = Vec::new();
+        let mut lengths = Vec::with_capacity(points.list.len());
+        for row in 0..points.list.len() {
+            let start = indices.len();
+            if points.is_valid(row) {
+                let (begin, end) = offsets.start_end(row);
+                for idx in begin..end {
+                    let inside = points.point(idx).is_some_and(|point| {
+                        (0..3).all(|axis| {
+                            min_bound[axis] <= point[axis] && point[axis] <= max_bound[axis]
+                        })
+                    });
+                    if inside {
+                        indices.push(idx as u64);
+                    }
+                }
+            }
+            lengths.push(indices.len() - start);
+        }
+
+        let flat_child = points.take(indices)?;
+        points.build(flat_child, lengths)
+    }
+
+    fn get_return_field(&self, args: FunctionArgs<ExprRef>, schema: &Schema) -> DaftResult<Field> {
+        let CropPointsArgs { input, .. } = args.try_into()?;
+        let field = input.to_field(schema)?;
+        check_points_field(&field, self.name())?;
+        Ok(field)
+    }
+}
+
+#[must_use]
+/// Filters each point cloud to the points within an axis-aligned bounding box.
+///
+/// # Arguments
+/// * `input` - A list of structs with numeric `x`, `y` and `z` fields
+/// * `min_bound` - A list literal of the minimum `[x, y, z]` coordinates of the box
+/// * `max_bound` - A list literal of the maximum `[x, y, z]` coordinates of the box
+pub fn crop_points(input: ExprRef, min_bound: ExprRef, max_bound: ExprRef) -> ExprRef {
+    ScalarFn::builtin(CropPoints, vec![input, min_bound, max_bound]).into()
+}
@@ -0,0 +1,143 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema, StructArray, Utf8Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{
+    ExprRef,
+    functions::{FunctionArgs, ScalarUDF},
+};
+use serde::{Deserialize, Serialize};
+
+/// Casts values to a data type, nulling the values which can't be cast instead of failing the query.
+///
+/// Each row becomes a struct of the cast `value` and an `error`, which is null for the rows which were cast, and
+/// otherwise holds the value which couldn't be cast, as a string, and the reason. The cast fails once more than
+/// `max_errors` values of all the batches of rows that a query casts can't be cast, so a cast with `max_errors` of 0
+/// is strict.
+///
+/// The values which couldn't be cast are counted by each copy of the function, which the optimizer renews for every
+/// query. Each worker of a distributed query counts the values it casts on its own.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct TryCast {
+    #[serde(skip)]
+    num_errors: Arc<AtomicU64>,
+}
+
+#[derive(FunctionArgs)]
+struct TryCastArgs<T> {
+    input: T,
+    dtype: DataType,
+    #[arg(optional)]
+    max_errors: Option<u64>,
+}
+
+fn error_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new("value", DataType::Utf8),
+        Field::new("reason", DataType::Utf8),
+    ])
+}
+
+fn return_dtype(dtype: &DataType) -> DataType {
+    DataType::Struct(vec![
+        Field::new("value", dtype.clone()),
+        Field::new("error", error_dtype()),
+    ])
+}
+
+#[typetag::serde]
+impl ScalarUDF for TryCast {
+    fn name(&self) -> &'static str {
+        "try_cast"
+    }
+
+    fn call(&self, inputs: FunctionArgs<Series>) -> DaftResult<Series> {
+        let TryCastArgs {
+            input,
+            dtype,
+            max_errors,
+        } = inputs.try_into()?;
+
+        // Values which can't be cast are nulled by the cast, so the errors are the values which became null.
+        let value = input.cast(&dtype)?;
+        let is_error = (0..input.len())
+            .map(|i| input.is_valid(i) && !value.is_valid(i))
+            .collect::<Vec<_>>();
+        let num_errors = is_error.iter().filter(|is_error| **is_error).count();
+
+        // Values which can't be shown as strings are kept as nulls in the errors.
+        let originals = input.cast(&DataType::Utf8).ok();
+        let originals = originals.as_ref().map(|s| s.utf8()).transpose()?;
+        let original = |i: usize| originals.and_then(|originals| originals.get(i));
+
+        if let Some(max_errors) = max_errors
+            && num_errors > 0
+        {
+            let num_errors = self
+                .num_errors
+                .fetch_add(num_errors as u64, Ordering::Relaxed)
+                + num_errors as u64;
+            if num_errors > max_errors {
+                let first = is_error.iter().position(|is_error| *is_error).unwrap();
+                return Err(DaftError::ComputeError(format!(
+                    "Cannot cast {} values of {} from {} to {dtype}, which is more than the {max_errors} errors allowed. The first value which can't be cast is {:?}",
+                    num_errors,
+                    input.name(),
+                    input.data_type(),
+                    original(first).unwrap_or("null"),
+                )));
+            }
+        }
+
+        let reason = format!("Cannot cast {} to {dtype}", input.data_type());
+        let error_values = Utf8Array::from_iter(
+            "value",
+            is_error
+                .iter()
+                .enumerate()
+                .map(|(i, is_error)| if *is_error { original(i) } else { None }),
+        );
+        let reasons = Utf8Array::from_iter(
+            "reason",
+            is_error
+                .iter()
+                .map(|is_error| is_error.then_some(reason.as_str())),
+        );
+        let error = StructArray::new(
+            Field::new("error", error_dtype()),
+            vec![error_values.into_series(), reasons.into_series()],
+            Some(daft_arrow::buffer::NullBuffer::from(is_error)),
+        );
+
+        Ok(StructArray::new(
+            Field::new(input.name(), return_dtype(&dtype)),
+            vec![value.rename("value"), error.into_series()],
+            None,
+        )
+        .into_series())
+    }
+
+    fn get_return_field(
+        &self,
+        inputs: FunctionArgs<ExprRef>,
+        schema: &Schema,
+    ) -> DaftResult<Field> {
+        let TryCastArgs { input, dtype, .. } = inputs.try_into()?;
+        let input = input.to_field(schema)?;
+        Ok(Field::new(input.name, return_dtype(&dtype)))
+    }
+
+    fn docstring(&self) -> &'static str {
+        "Casts values to a data type, returning a struct of the cast value and the error of the values which can't be cast."
+    }
+
+    fn with_new_state(&self) -> Option<Arc<dyn ScalarUDF>> {
+        Some(Arc::new(Self::default()))
+    }
+}
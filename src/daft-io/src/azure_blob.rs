@@ -1,4 +1,4 @@
-use std::{any::Any, sync::Arc};
+use std::{any::Any, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use azure_core::auth::TokenCredential;
@@ -28,6 +28,8 @@ const AZURE_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
 const AZURE_STORAGE_RESOURCE: &str = "https://storage.azure.com/.default";
 const AZURE_STORE_SUFFIX: &str = ".dfs.core.windows.net";
+/// How often a copy which finishes in the background is checked on.
+const COPY_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Debug, Snafu)]
 enum Error {
@@ -65,6 +67,26 @@ enum Error {
         source: azure_storage::Error,
     },
 
+    #[snafu(display("Unable to copy {} to {}: {}", path, dest, source))]
+    UnableToCopyFile {
+        path: String,
+        dest: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Copy of {} to {} ended with status {}", path, dest, status))]
+    CopyFailed {
+        path: String,
+        dest: String,
+        status: String,
+    },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
     #[snafu(display("Not Found: \"{}\"", path))]
     NotFound { path: String },
 
@@ -167,6 +189,24 @@ pub struct AzureBlobSource {
 }
 
 impl AzureBlobSource {
+    /// Returns the client of the blob at `uri`.
+    fn blob_client_for(&self, uri: &str) -> super::Result<BlobClient> {
+        let parsed_uri = parse_azure_uri(uri)?;
+        let (container, key) = parsed_uri
+            .container_and_key
+            .ok_or_else(|| Error::InvalidUrl {
+                path: uri.into(),
+                source: url::ParseError::EmptyHost,
+            })?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        Ok(self
+            .blob_client
+            .container_client(container)
+            .blob_client(key))
+    }
+
     pub async fn get_client(config: &AzureConfig, uri: &str) -> super::Result<Arc<Self>> {
         let parsed_uri = parse_azure_uri(uri)?;
 
@@ -497,6 +537,7 @@ impl AzureBlobSource {
             filepath: format!("{protocol}://{}/", &container.name),
             size: None,
             filetype: FileType::Directory,
+            etag: None,
         }
     }
 
@@ -511,11 +552,13 @@ impl AzureBlobSource {
                 filepath: format!("{protocol}://{}/{}", container_name, &blob.name),
                 size: Some(blob.properties.content_length),
                 filetype: FileType::File,
+                etag: Some(blob.properties.etag.to_string()),
             },
             BlobItem::BlobPrefix(prefix) => FileMetadata {
                 filepath: format!("{protocol}://{}/{}", container_name, &prefix.name),
                 size: None,
                 filetype: FileType::Directory,
+                etag: None,
             },
         }
     }
@@ -594,6 +637,66 @@ impl ObjectSource for AzureBlobSource {
         todo!("PUTs to Azure blob store are not yet supported! Please file an issue.");
     }
 
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_url = self
+            .blob_client_for(src)?
+            .url()
+            .context(UnableToOpenFileSnafu::<String> { path: src.into() })?;
+        let dest_client = self.blob_client_for(dest)?;
+        let copy_context = || UnableToCopyFileSnafu::<String, String> {
+            path: src.into(),
+            dest: dest.into(),
+        };
+
+        let response = dest_client.copy(src_url).await.context(copy_context())?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+        }
+        // Copies within a storage account usually finish right away, but may finish in the background instead.
+        let mut status = response.copy_status;
+        while matches!(status, CopyStatus::Pending) {
+            tokio::time::sleep(COPY_POLL_INTERVAL).await;
+            let properties = dest_client.get_properties().await.context(copy_context())?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1);
+            }
+            status = properties
+                .blob
+                .properties
+                .copy_status
+                .unwrap_or(CopyStatus::Success);
+        }
+        match status {
+            CopyStatus::Success => Ok(()),
+            status => Err(Error::CopyFailed {
+                path: src.into(),
+                dest: dest.into(),
+                status: format!("{status:?}"),
+            }
+            .into()),
+        }
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        match self.blob_client_for(uri)?.delete().await {
+            Ok(_) => {}
+            // Deleting a blob which doesn't exist succeeds, as it does for other sources.
+            Err(err)
+                if err
+                    .as_http_error()
+                    .is_some_and(|e| u16::from(e.status()) == 404) => {}
+            Err(err) => {
+                return Err(UnableToDeleteFileSnafu::<String> { path: uri.into() }
+                    .into_error(err)
+                    .into());
+            }
+        }
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let parsed_uri = parse_azure_uri(uri)?;
         let (container, key) = parsed_uri
@@ -0,0 +1,429 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    time::{Duration, SystemTime},
+};
+
+use async_stream::stream;
+use bytes::Bytes;
+use futures::StreamExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use xxhash_rust::xxh3::{Xxh3, xxh3_64, xxh3_128};
+
+use crate::{Error, object_io::GetResult, range::GetRange};
+
+/// The directory to cache ranges of remote objects in, which is shared by all processes on a node. The cache is
+/// disabled when this isn't set.
+const ENV_DAFT_IO_DISK_CACHE_DIR: &str = "DAFT_IO_DISK_CACHE_DIR";
+/// The maximum total size of the cached ranges of all processes sharing the cache directory, in bytes.
+const ENV_DAFT_IO_DISK_CACHE_SIZE_BYTES: &str = "DAFT_IO_DISK_CACHE_SIZE_BYTES";
+const DEFAULT_CAPACITY_BYTES: usize = 16 * 1024 * 1024 * 1024;
+
+/// Each cached range is followed by the checksum of its bytes.
+const CHECKSUM_LEN: usize = 8;
+/// The size of the chunks that cached ranges are streamed back in.
+const READ_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+/// The file that a process creates while it evicts entries, so that processes don't evict at the same time.
+const EVICTION_LOCK: &str = "eviction.lock";
+/// Eviction locks and temporary files which are older than this were left behind by processes that died.
+const STALE_AFTER: Duration = Duration::from_secs(600);
+const NUM_TRIES: u64 = 3;
+
+/// The cache of the process, shared by all of its IO clients.
+pub(crate) static DISK_CACHE: LazyLock<Option<DiskCache>> = LazyLock::new(DiskCache::from_env);
+
+enum WriteError {
+    /// Reading the range from its source failed, so the read fails.
+    Source(Error),
+    /// Writing the range to the cache failed, so it's read without the cache.
+    Cache(std::io::Error),
+}
+
+/// A cache of ranges of remote objects on local disk, so that rereading the same files, e.g. when a table is scanned
+/// repeatedly, doesn't fetch them again.
+///
+/// The cache directory is shared by all processes on a node. Each range is stored in a file named after the hash of
+/// the url of its object and the requested range, so the cache assumes that remote objects aren't modified, and it
+/// should be cleared when they are. Ranges are streamed to a temporary file which is atomically renamed once it's
+/// complete, so a partially written range is never read, and processes caching the same range concurrently just
+/// replace one another's copy.
+///
+/// The least recently used ranges of all processes are evicted once the cache is larger than its capacity, using the
+/// modification times of the files, which are updated whenever a range is read. Cached ranges are checked against
+/// their checksum when they're read, so a range which was corrupted on disk is fetched again rather than returned.
+pub(crate) struct DiskCache {
+    dir: PathBuf,
+    capacity: usize,
+}
+
+impl DiskCache {
+    /// Ranges larger than this aren't cached.
+    pub const MAX_ENTRY_SIZE: usize = 256 * 1024 * 1024;
+
+    fn from_env() -> Option<Self> {
+        let dir = std::env::var(ENV_DAFT_IO_DISK_CACHE_DIR).ok()?;
+        let capacity = std::env::var(ENV_DAFT_IO_DISK_CACHE_SIZE_BYTES)
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(DEFAULT_CAPACITY_BYTES);
+        match Self::new(PathBuf::from(dir), capacity) {
+            Ok(cache) => Some(cache),
+            Err(err) => {
+                log::warn!(
+                    "Unable to create the IO disk cache in {ENV_DAFT_IO_DISK_CACHE_DIR}: {err}"
+                );
+                None
+            }
+        }
+    }
+
+    /// Creates a cache in `dir`, keeping the ranges which other processes cached in it.
+    pub fn new(dir: PathBuf, capacity: usize) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir, capacity })
+    }
+
+    fn file_name(url: &str, range: Option<&GetRange>) -> String {
+        format!("{:032x}", xxh3_128(format!("{url}\n{range:?}").as_bytes()))
+    }
+
+    /// Whether a range of the given size should be cached.
+    pub fn should_cache(&self, size: usize) -> bool {
+        size <= Self::MAX_ENTRY_SIZE.min(self.capacity)
+    }
+
+    /// Returns a cached range of an object, or `None` if it isn't cached or failed its integrity check.
+    pub async fn get(&self, url: &str, range: Option<&GetRange>) -> Option<Bytes> {
+        let path = self.dir.join(Self::file_name(url, range));
+        let mut data = match tokio::fs::read(&path).await {
+            Ok(data) => Bytes::from(data),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                log::warn!("Unable to read from the IO disk cache: {err}");
+                return None;
+            }
+        };
+        let Some(len) = data.len().checked_sub(CHECKSUM_LEN) else {
+            return self.evict_corrupted(url, &path).await;
+        };
+        let checksum = u64::from_le_bytes(data[len..].try_into().unwrap());
+        data.truncate(len);
+        if xxh3_64(&data) != checksum {
+            return self.evict_corrupted(url, &path).await;
+        }
+        // The modification time of an entry is its last use, which orders entries for eviction.
+        let touched = async {
+            let file = tokio::fs::File::options().write(true).open(&path).await?;
+            file.into_std().await.set_modified(SystemTime::now())
+        };
+        if let Err(err) = touched.await {
+            log::debug!("Unable to update the last use of an IO disk cache entry: {err}");
+        }
+        Some(data)
+    }
+
+    async fn evict_corrupted(&self, url: &str, path: &Path) -> Option<Bytes> {
+        log::warn!("Evicting corrupted IO disk cache entry for {url}");
+        let _ = tokio::fs::remove_file(path).await;
+        None
+    }
+
+    /// Streams a range of an object into the cache, and returns the cached range, evicting the least recently used
+    /// ranges if the cache is full.
+    ///
+    /// Returns `None` if the range couldn't be written to the cache, in which case it has to be read from its source
+    /// again.
+    pub async fn insert(
+        &self,
+        url: &str,
+        range: Option<&GetRange>,
+        get_result: GetResult,
+    ) -> crate::Result<Option<GetResult>> {
+        let file_name = Self::file_name(url, range);
+        let temp_path = self
+            .dir
+            .join(format!("{file_name}.{}.tmp", uuid::Uuid::new_v4()));
+        let cached = async {
+            let len = write_entry(&temp_path, get_result).await?;
+            // The entry is opened before it's renamed into place, so that it can be read even if it's evicted
+            // right away.
+            let file = tokio::fs::File::open(&temp_path)
+                .await
+                .map_err(WriteError::Cache)?;
+            tokio::fs::rename(&temp_path, self.dir.join(&file_name))
+                .await
+                .map_err(WriteError::Cache)?;
+            Ok((file, len))
+        };
+        let (file, len) = match cached.await {
+            Ok(cached) => cached,
+            Err(err) => {
+                let _ = tokio::fs::remove_file(&temp_path).await;
+                return match err {
+                    WriteError::Source(err) => Err(err),
+                    WriteError::Cache(err) => {
+                        log::warn!("Unable to write to the IO disk cache: {err}");
+                        Ok(None)
+                    }
+                };
+            }
+        };
+
+        let dir = self.dir.clone();
+        let capacity = self.capacity;
+        match tokio::task::spawn_blocking(move || evict(&dir, capacity)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => log::warn!("Unable to evict entries of the IO disk cache: {err}"),
+            Err(err) => log::warn!("Unable to evict entries of the IO disk cache: {err}"),
+        }
+
+        let path = self.dir.join(&file_name).to_string_lossy().to_string();
+        let mut reader = file.take(len as u64);
+        let stream = stream! {
+            loop {
+                let mut chunk = Vec::with_capacity(READ_CHUNK_SIZE.min(len));
+                match (&mut reader).take(READ_CHUNK_SIZE as u64).read_to_end(&mut chunk).await {
+                    Ok(0) => break,
+                    Ok(_) => yield Ok(Bytes::from(chunk)),
+                    Err(source) => {
+                        yield Err(Error::UnableToReadBytes { path: path.clone(), source });
+                        break;
+                    }
+                }
+            }
+        };
+        Ok(Some(GetResult::Stream(
+            stream.boxed(),
+            Some(len),
+            None,
+            None,
+        )))
+    }
+}
+
+/// Writes the bytes of a range to `path`, followed by their checksum, and returns the number of bytes of the range.
+/// Reading the range is retried from the start if its stream fails.
+async fn write_entry(path: &Path, get_result: GetResult) -> Result<usize, WriteError> {
+    let GetResult::Stream(mut stream, _, mut permit, retry_params) = get_result else {
+        let data = get_result.bytes().await.map_err(WriteError::Source)?;
+        let mut entry = data.to_vec();
+        entry.extend_from_slice(&xxh3_64(&data).to_le_bytes());
+        tokio::fs::write(path, entry)
+            .await
+            .map_err(WriteError::Cache)?;
+        return Ok(data.len());
+    };
+    for attempt in 1.. {
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(WriteError::Cache)?;
+        let mut hasher = Xxh3::new();
+        let mut len = 0;
+        let streamed = loop {
+            match stream.next().await {
+                Some(Ok(chunk)) => {
+                    hasher.update(&chunk);
+                    len += chunk.len();
+                    file.write_all(&chunk).await.map_err(WriteError::Cache)?;
+                }
+                Some(Err(err)) => break Err(err),
+                None => break Ok(()),
+            }
+        };
+        drop(permit.take());
+        match streamed {
+            Ok(()) => {
+                file.write_all(&hasher.digest().to_le_bytes())
+                    .await
+                    .map_err(WriteError::Cache)?;
+                file.flush().await.map_err(WriteError::Cache)?;
+                return Ok(len);
+            }
+            Err(err) => {
+                let retryable = matches!(
+                    err,
+                    Error::SocketError { .. }
+                        | Error::UnableToReadBytes { .. }
+                        | Error::UnableToOpenFile { .. }
+                ) && attempt < NUM_TRIES;
+                let Some(retry_params) = retry_params.as_ref().filter(|_| retryable) else {
+                    return Err(WriteError::Source(err));
+                };
+                log::warn!(
+                    "Received Socket Error when streaming bytes to the IO disk cache! Attempt {attempt} out of {NUM_TRIES} tries. Trying again\nDetails\n{err}"
+                );
+                tokio::time::sleep(Duration::from_millis(attempt * 1_000)).await;
+                match retry_params.refetch().await.map_err(WriteError::Source)? {
+                    GetResult::Stream(retried, _, retried_permit, _) => {
+                        stream = retried;
+                        permit = retried_permit;
+                    }
+                    GetResult::File(..) => {
+                        unreachable!("Retrying a stream should always be a stream")
+                    }
+                }
+            }
+        }
+    }
+    unreachable!("Streaming to the IO disk cache either returns or retries")
+}
+
+/// Removes the least recently used entries of the cache in `dir` until it fits in `capacity`, along with temporary
+/// files left behind by processes that died while writing them. Only one process evicts at a time, and others skip
+/// eviction while it's running, so the cache may briefly exceed its capacity by the entries that are being written.
+fn evict(dir: &Path, capacity: usize) -> std::io::Result<()> {
+    let is_stale = |modified: SystemTime| {
+        SystemTime::now()
+            .duration_since(modified)
+            .is_ok_and(|age| age > STALE_AFTER)
+    };
+    let lock_path = dir.join(EVICTION_LOCK);
+    match std::fs::File::options()
+        .write(true)
+        .create_new(true)
+        .open(&lock_path)
+    {
+        Ok(_) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+            // Another process is evicting, unless it died while holding the lock, which is then released.
+            if std::fs::metadata(&lock_path)
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(is_stale)
+            {
+                let _ = std::fs::remove_file(&lock_path);
+            }
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    }
+
+    let evicted = (|| -> std::io::Result<()> {
+        let mut entries = vec![];
+        let mut size = 0;
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            // Entries may be evicted by a process which took over a stale lock in the meantime.
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let modified = metadata.modified()?;
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if file_name == EVICTION_LOCK {
+                continue;
+            }
+            if file_name.ends_with(".tmp") {
+                if is_stale(modified) {
+                    let _ = std::fs::remove_file(entry.path());
+                }
+                continue;
+            }
+            size += metadata.len() as usize;
+            entries.push((modified, metadata.len() as usize, entry.path()));
+        }
+        entries.sort_by_key(|(modified, ..)| *modified);
+        for (_, entry_size, path) in entries {
+            if size <= capacity {
+                break;
+            }
+            match std::fs::remove_file(path) {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err),
+                _ => size -= entry_size,
+            }
+        }
+        Ok(())
+    })();
+    let _ = std::fs::remove_file(&lock_path);
+    evicted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use bytes::Bytes;
+
+    use super::{CHECKSUM_LEN, DiskCache};
+    use crate::{in_memory_get_result, range::GetRange};
+
+    async fn insert(cache: &DiskCache, url: &str, range: Option<&GetRange>, data: &'static [u8]) {
+        let cached = cache
+            .insert(url, range, in_memory_get_result(Bytes::from_static(data)))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.bytes().await.unwrap(), data);
+    }
+
+    /// Makes an entry look like it was last used `secs_ago` seconds ago, since eviction is ordered by last use.
+    fn set_last_used(cache: &DiskCache, url: &str, range: Option<&GetRange>, secs_ago: u64) {
+        let path = cache.dir.join(DiskCache::file_name(url, range));
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(secs_ago))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_evicts_least_recently_used() {
+        let dir = tempfile::tempdir().unwrap();
+        // Room for two entries of four bytes, along with their checksums.
+        let cache = DiskCache::new(dir.path().join("cache"), 2 * (4 + CHECKSUM_LEN)).unwrap();
+        let a = Some(GetRange::Bounded(0..4));
+        let b = Some(GetRange::Bounded(4..8));
+
+        insert(&cache, "s3://bucket/a", a.as_ref(), b"aaaa").await;
+        set_last_used(&cache, "s3://bucket/a", a.as_ref(), 20);
+        insert(&cache, "s3://bucket/a", b.as_ref(), b"bbbb").await;
+        set_last_used(&cache, "s3://bucket/a", b.as_ref(), 10);
+        assert_eq!(
+            cache.get("s3://bucket/a", a.as_ref()).await.unwrap(),
+            &b"aaaa"[..]
+        );
+        // The second range is now the least recently used, so it's evicted to make room for the third.
+        insert(&cache, "s3://bucket/b", None, b"cccc").await;
+        assert!(cache.get("s3://bucket/a", b.as_ref()).await.is_none());
+        assert!(cache.get("s3://bucket/a", a.as_ref()).await.is_some());
+        assert!(cache.get("s3://bucket/b", None).await.is_some());
+        // Ranges larger than the cache aren't cached.
+        assert!(!cache.should_cache(2 * (4 + CHECKSUM_LEN) + 1));
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_is_shared_between_processes() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = DiskCache::new(dir.path().join("cache"), 2 * (4 + CHECKSUM_LEN)).unwrap();
+        let second = DiskCache::new(dir.path().join("cache"), 2 * (4 + CHECKSUM_LEN)).unwrap();
+
+        insert(&first, "s3://bucket/a", None, b"aaaa").await;
+        set_last_used(&first, "s3://bucket/a", None, 10);
+        assert_eq!(
+            second.get("s3://bucket/a", None).await.unwrap(),
+            &b"aaaa"[..]
+        );
+
+        // The capacity bounds the entries of both caches together.
+        set_last_used(&first, "s3://bucket/a", None, 10);
+        insert(&second, "s3://bucket/b", None, b"bbbb").await;
+        insert(&first, "s3://bucket/c", None, b"cccc").await;
+        assert!(second.get("s3://bucket/a", None).await.is_none());
+        assert!(second.get("s3://bucket/b", None).await.is_some());
+        assert!(second.get("s3://bucket/c", None).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_drops_corrupted_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path().join("cache"), 1024).unwrap();
+        insert(&cache, "s3://bucket/a", None, b"data").await;
+        let path = cache.dir.join(DiskCache::file_name("s3://bucket/a", None));
+        let mut corrupted = std::fs::read(&path).unwrap();
+        corrupted[3] = b'!';
+        std::fs::write(&path, corrupted).unwrap();
+
+        assert!(cache.get("s3://bucket/a", None).await.is_none());
+        assert!(!path.exists());
+    }
+}
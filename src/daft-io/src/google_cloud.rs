@@ -8,7 +8,10 @@ use google_cloud_storage::{
     client::{Client, ClientConfig, google_cloud_auth::credentials::CredentialsFile},
     http::{
         Error as GError,
-        objects::{get::GetObjectRequest, list::ListObjectsRequest},
+        objects::{
+            delete::DeleteObjectRequest, get::GetObjectRequest, list::ListObjectsRequest,
+            rewrite::RewriteObjectRequest,
+        },
     },
 };
 use google_cloud_token::{TokenSource, TokenSourceProvider};
@@ -40,6 +43,16 @@ enum Error {
     #[snafu(display("Unable to read data from {}: {}", path, source))]
     UnableToReadBytes { path: String, source: GError },
 
+    #[snafu(display("Unable to copy {} to {}: {}", path, dest, source))]
+    UnableToCopyFile {
+        path: String,
+        dest: String,
+        source: GError,
+    },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile { path: String, source: GError },
+
     #[snafu(display("Unable to load Credentials: {}", source))]
     UnableToLoadCredentials {
         source: google_cloud_storage::client::google_cloud_auth::error::Error,
@@ -61,8 +74,9 @@ enum Error {
 impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
         use Error::{
-            NotAFile, NotFound, UnableToCreateClient, UnableToGrabSemaphore, UnableToListObjects,
-            UnableToLoadCredentials, UnableToOpenFile, UnableToReadBytes,
+            NotAFile, NotFound, UnableToCopyFile, UnableToCreateClient, UnableToDeleteFile,
+            UnableToGrabSemaphore, UnableToListObjects, UnableToLoadCredentials, UnableToOpenFile,
+            UnableToReadBytes,
         };
 
         fn from_reqwest_err(path: String, err: reqwest::Error) -> super::Error {
@@ -100,7 +114,9 @@ impl From<Error> for super::Error {
         match error {
             UnableToReadBytes { path, source }
             | UnableToOpenFile { path, source }
-            | UnableToListObjects { path, source } => match source {
+            | UnableToListObjects { path, source }
+            | UnableToCopyFile { path, source, .. }
+            | UnableToDeleteFile { path, source } => match source {
                 GError::HttpClient(err) => from_reqwest_err(path, err),
                 GError::Response(err) => match err.code {
                     404 | 410 => Self::NotFound {
@@ -278,6 +294,86 @@ impl GCSClientWrapper {
         }
         Ok(response.size as usize)
     }
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (src_bucket, src_key) = parse_raw_uri(src)?;
+        let (bucket, key) = parse_raw_uri(dest)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: dest.into() }.into());
+        }
+
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+
+        // Rewriting a large object takes several calls, each of which resumes from the token returned by the last.
+        let mut rewrite_token = None;
+        loop {
+            let req = RewriteObjectRequest {
+                destination_bucket: bucket.into(),
+                destination_object: key.into(),
+                source_bucket: src_bucket.into(),
+                source_object: src_key.into(),
+                rewrite_token: rewrite_token.take(),
+                ..Default::default()
+            };
+            let response =
+                self.client
+                    .rewrite_object(&req)
+                    .await
+                    .context(UnableToCopyFileSnafu {
+                        path: src.to_string(),
+                        dest: dest.to_string(),
+                    })?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_put_requests(1);
+            }
+            if response.done {
+                return Ok(());
+            }
+            rewrite_token = response.rewrite_token;
+        }
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (bucket, key) = parse_raw_uri(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+
+        let req = DeleteObjectRequest {
+            bucket: bucket.into(),
+            object: key.into(),
+            ..Default::default()
+        };
+        match self.client.delete_object(&req).await {
+            Ok(()) => {}
+            // Deleting an object which doesn't exist succeeds, as it does for other sources.
+            Err(GError::Response(err)) if err.code == 404 => {}
+            Err(err) => {
+                return Err(UnableToDeleteFileSnafu {
+                    path: uri.to_string(),
+                }
+                .into_error(err)
+                .into());
+            }
+        }
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn ls_impl(
         &self,
@@ -318,11 +414,13 @@ impl GCSClientWrapper {
             filepath: format!("{GCS_SCHEME}://{}/{}", bucket, obj.name),
             size: Some(obj.size as u64),
             filetype: FileType::File,
+            etag: Some(obj.etag.clone()),
         });
         let dirs = response_prefixes.iter().map(|pref| FileMetadata {
             filepath: format!("{GCS_SCHEME}://{bucket}/{pref}"),
             size: None,
             filetype: FileType::Directory,
+            etag: None,
         });
         Ok(LSResult {
             files: files.chain(dirs).collect(),
@@ -574,6 +672,14 @@ impl ObjectSource for GCSSource {
         self.client.get_size(uri, io_stats).await
     }
 
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.copy(src, dest, io_stats).await
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.delete(uri, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
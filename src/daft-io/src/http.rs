@@ -139,6 +139,7 @@ fn get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<File
                 // for populating `size` if necessary
                 size: None,
                 filetype,
+                etag: None,
             }))
         })
         .collect::<super::Result<Vec<_>>>()?;
@@ -395,6 +396,11 @@ impl ObjectSource for HttpSource {
                     filepath: path.clone(),
                     filetype: FileType::File,
                     size: response.content_length(),
+                    etag: response
+                        .headers()
+                        .get(header::ETAG)
+                        .and_then(|etag| etag.to_str().ok())
+                        .map(str::to_string),
                 }],
                 continuation_token: None,
             }),
@@ -609,6 +609,7 @@ impl ObjectSource for HFSource {
                     filepath,
                     size,
                     filetype,
+                    etag: None,
                 }
             })
             .collect();
@@ -682,6 +683,7 @@ async fn try_parquet_api(
                     filepath: uri,
                     size: None,
                     filetype: FileType::File,
+                    etag: None,
                 })
             });
 
@@ -1,6 +1,7 @@
 #![feature(if_let_guard)]
 mod azure_blob;
 mod counting_reader;
+mod disk_cache;
 mod google_cloud;
 mod http;
 mod huggingface;
@@ -21,6 +22,7 @@ use std::sync::LazyLock;
 use azure_blob::AzureBlobSource;
 use common_file_formats::FileFormat;
 pub use counting_reader::CountingReader;
+use disk_cache::DISK_CACHE;
 use google_cloud::GCSSource;
 use huggingface::HFSource;
 use tos::TosSource;
@@ -37,9 +39,10 @@ use std::{borrow::Cow, collections::HashMap, hash::Hash, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
 pub use common_io_config::{AzureConfig, GCSConfig, HTTPConfig, IOConfig, S3Config, TosConfig};
-use futures::{FutureExt, stream::BoxStream};
+use futures::{FutureExt, TryStreamExt, stream::BoxStream};
 use object_io::StreamingRetryParams;
 pub use object_io::{FileMetadata, FileType, GetResult, ObjectSource};
+use opentelemetry::KeyValue;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use s3_like::{S3LikeSource, S3MultipartWriter, s3_config_from_env};
@@ -201,20 +204,48 @@ impl From<Error> for std::io::Error {
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
+type SourceStore = tokio::sync::RwLock<HashMap<SourceType, Arc<dyn ObjectSource>>>;
+
 #[derive(Default)]
 pub struct IOClient {
-    source_type_to_store: tokio::sync::RwLock<HashMap<SourceType, Arc<dyn ObjectSource>>>,
+    source_type_to_store: Arc<SourceStore>,
     config: Arc<IOConfig>,
+    prefetched: Option<PrefetchedObject>,
+}
+
+/// The contents of an object which have already been fetched, and are served from memory rather than the source.
+struct PrefetchedObject {
+    url: String,
+    data: bytes::Bytes,
 }
 
 impl IOClient {
     pub fn new(config: Arc<IOConfig>) -> Result<Self> {
         Ok(Self {
-            source_type_to_store: tokio::sync::RwLock::new(HashMap::new()),
+            source_type_to_store: Default::default(),
             config,
+            prefetched: None,
         })
     }
 
+    /// Returns a client sharing the sources of this one, which serves reads of `url` from `data` instead of the
+    /// source. This lets an object be fetched ahead of the reader which decodes it.
+    #[must_use]
+    pub fn with_prefetched_object(&self, url: String, data: bytes::Bytes) -> Self {
+        Self {
+            source_type_to_store: self.source_type_to_store.clone(),
+            config: self.config.clone(),
+            prefetched: Some(PrefetchedObject { url, data }),
+        }
+    }
+
+    fn prefetched_object(&self, input: &str) -> Option<&bytes::Bytes> {
+        self.prefetched
+            .as_ref()
+            .filter(|prefetched| prefetched.url == input)
+            .map(|prefetched| &prefetched.data)
+    }
+
     pub fn support_suffix_range(&self) -> bool {
         !self.config.disable_suffix_range
     }
@@ -321,7 +352,28 @@ impl IOClient {
         range: Option<GetRange>,
         io_stats: Option<IOStatsRef>,
     ) -> Result<GetResult> {
-        let (_, path) = parse_url(&input)?;
+        if let Some(data) = self.prefetched_object(&input) {
+            let data = match range {
+                Some(range) => {
+                    let range = range
+                        .as_range(data.len())
+                        .context(InvalidRangeRequestSnafu)?;
+                    data.slice(range)
+                }
+                None => data.clone(),
+            };
+            return Ok(in_memory_get_result(data));
+        }
+        let (source_type, path) = parse_url(&input)?;
+        // Remote objects are read from the disk cache when it's enabled, and ranges which are read whole are cached.
+        let disk_cache = DISK_CACHE
+            .as_ref()
+            .filter(|_| source_type != SourceType::File);
+        if let Some(cache) = disk_cache
+            && let Some(data) = cache.get(&input, range.as_ref()).await
+        {
+            return Ok(in_memory_get_result(data));
+        }
         let source = self.get_source(&input).await?;
 
         if let Some(GetRange::Suffix(_)) = range
@@ -332,10 +384,43 @@ impl IOClient {
             });
         }
 
+        let start = std::time::Instant::now();
         let get_result = source
             .get(path.as_ref(), range.clone(), io_stats.clone())
             .await?;
-        Ok(get_result.with_retry(StreamingRetryParams::new(source, input, range, io_stats)))
+        stats::IO_METRICS.get_latency_ms.record(
+            start.elapsed().as_secs_f64() * 1000.0,
+            &[KeyValue::new("source", source_type.to_string())],
+        );
+        let size = match (&range, &get_result) {
+            (Some(GetRange::Bounded(range)), _) => Some(range.len()),
+            (_, GetResult::Stream(_, size, ..)) => *size,
+            _ => None,
+        };
+        let get_result = get_result.with_retry(StreamingRetryParams::new(
+            source.clone(),
+            input.clone(),
+            range.clone(),
+            io_stats.clone(),
+        ));
+        match disk_cache {
+            Some(cache) if size.is_some_and(|size| cache.should_cache(size)) => {
+                match cache.insert(&input, range.as_ref(), get_result).await? {
+                    Some(cached) => Ok(cached),
+                    // The range couldn't be written to the cache, so it's read again without it.
+                    None => Ok(source
+                        .get(path.as_ref(), range.clone(), io_stats.clone())
+                        .await?
+                        .with_retry(StreamingRetryParams::new(
+                            source,
+                            input.clone(),
+                            range,
+                            io_stats,
+                        ))),
+                }
+            }
+            _ => Ok(get_result),
+        }
     }
 
     pub async fn single_url_put(
@@ -349,16 +434,106 @@ impl IOClient {
         source.put(path.as_ref(), data, io_stats.clone()).await
     }
 
+    /// Moves an object to `dest`, by renaming it for local files and otherwise by copying it server-side and then
+    /// deleting it, so that its bytes never pass through this process.
+    pub async fn single_url_move(
+        &self,
+        src: &str,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (source_type, src_path) = parse_url(src)?;
+        let (_, dest_path) = parse_url(dest)?;
+        if source_type == SourceType::File {
+            let (Some(src_path), Some(dest_path)) = (
+                strip_file_uri_to_path(&src_path),
+                strip_file_uri_to_path(&dest_path),
+            ) else {
+                return Err(Error::InvalidArgument {
+                    msg: format!("Unable to move {src} to {dest}"),
+                });
+            };
+            if let Some(parent) = std::path::Path::new(dest_path).parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .context(UnableToCreateDirSnafu { path: dest })?;
+            }
+            return tokio::fs::rename(src_path, dest_path)
+                .await
+                .context(UnableToWriteToFileSnafu { path: dest });
+        }
+        let source = self.get_source(src).await?;
+        source
+            .copy(src_path.as_ref(), dest_path.as_ref(), io_stats.clone())
+            .await?;
+        source.delete(src_path.as_ref(), io_stats).await
+    }
+
+    /// Deletes all of the objects under `dir`, and the directory itself for local files.
+    pub async fn delete_dir(&self, dir: &str, io_stats: Option<IOStatsRef>) -> Result<()> {
+        let (source_type, dir_path) = parse_url(dir)?;
+        let source = self.get_source(dir).await?;
+        let glob_path = format!("{}/**", dir_path.trim_end_matches('/'));
+        let files = match source
+            .clone()
+            .glob(&glob_path, None, None, None, io_stats.clone(), None)
+            .await
+        {
+            Ok(files) => files,
+            Err(Error::NotFound { .. }) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let files = match files.try_collect::<Vec<_>>().await {
+            Ok(files) => files,
+            Err(Error::NotFound { .. }) => vec![],
+            Err(err) => return Err(err),
+        };
+        for file in files {
+            if matches!(file.filetype, FileType::File) {
+                source.delete(&file.filepath, io_stats.clone()).await?;
+            }
+        }
+        if source_type == SourceType::File
+            && let Some(path) = strip_file_uri_to_path(&dir_path)
+        {
+            match tokio::fs::remove_dir_all(path).await {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(Error::UnableToWriteToFile {
+                        path: dir.into(),
+                        source: err,
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     pub async fn single_url_get_size(
         &self,
         input: String,
         io_stats: Option<IOStatsRef>,
     ) -> Result<usize> {
+        if let Some(data) = self.prefetched_object(&input) {
+            return Ok(data.len());
+        }
         let (_, path) = parse_url(&input)?;
         let source = self.get_source(&input).await?;
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    /// Returns the entity tag of an object, or its modification time for local files, which changes whenever its
+    /// contents do. None if its source doesn't tag its objects.
+    pub async fn single_url_get_etag(
+        &self,
+        input: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<Option<String>> {
+        let (_, path) = parse_url(&input)?;
+        let source = self.get_source(&input).await?;
+        source.get_etag(path.as_ref(), io_stats).await
+    }
+
     pub async fn single_url_download(
         &self,
         input: Option<String>,
@@ -467,6 +642,17 @@ fn strip_leading_slash_before_drive(path: &str) -> &str {
     }
 }
 
+/// Returns a [`GetResult`] which streams data that is already in memory.
+fn in_memory_get_result(data: bytes::Bytes) -> GetResult {
+    let size = data.len();
+    GetResult::Stream(
+        Box::pin(futures::stream::once(async move { Ok(data) })),
+        Some(size),
+        None,
+        None,
+    )
+}
+
 /// Strips the "file://" prefix from a URI and returns the local file path.
 ///
 /// On Windows, also handles the leading slash before drive letters (e.g., "/C:/..." -> "C:/...").
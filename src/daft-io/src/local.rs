@@ -135,6 +135,14 @@ impl From<Error> for super::Error {
     }
 }
 
+/// Local files don't have entity tags, so their modification time, which changes whenever they're written, is used
+/// instead.
+fn modification_tag(meta: &std::fs::Metadata) -> Option<String> {
+    let modified = meta.modified().ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_nanos().to_string())
+}
+
 impl LocalSource {
     pub async fn get_client() -> super::Result<Arc<Self>> {
         Ok(Self {}.into())
@@ -213,6 +221,22 @@ impl ObjectSource for LocalSource {
         }
     }
 
+    async fn get_etag(
+        &self,
+        uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        let Some(uri) = strip_file_uri_to_path(uri) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        let meta = tokio::fs::metadata(uri)
+            .await
+            .context(UnableToFetchFileMetadataSnafu {
+                path: uri.to_string(),
+            })?;
+        Ok(modification_tag(&meta))
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -327,6 +351,7 @@ impl ObjectSource for LocalSource {
                 filepath: format!("{LOCAL_PROTOCOL}{uri}"),
                 size: Some(meta.len()),
                 filetype: object_io::FileType::File,
+                etag: modification_tag(&meta),
             })])
             .boxed());
         }
@@ -374,6 +399,7 @@ impl ObjectSource for LocalSource {
                             path: entry.path().to_string_lossy().to_string(),
                         }
                     })?,
+                    etag: meta.is_file().then(|| modification_tag(&meta)).flatten(),
                 })
             }
         });
@@ -427,6 +453,7 @@ pub async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
 mod tests {
     use std::{default, io::Write};
 
+    use super::modification_tag;
     use crate::{
         HttpSource, LocalSource, Result,
         integrations::test_full_get,
@@ -486,6 +513,7 @@ mod tests {
                 ),
                 size: Some(file1.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: modification_tag(&file1.as_file().metadata().unwrap()),
             },
             FileMetadata {
                 filepath: format!(
@@ -495,6 +523,7 @@ mod tests {
                 ),
                 size: Some(file2.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: modification_tag(&file2.as_file().metadata().unwrap()),
             },
             FileMetadata {
                 filepath: format!(
@@ -504,6 +533,7 @@ mod tests {
                 ),
                 size: Some(file3.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: modification_tag(&file3.as_file().metadata().unwrap()),
             },
         ];
         expected.sort_by(|a, b| a.filepath.cmp(&b.filepath));
@@ -23,6 +23,13 @@ pub struct StreamingRetryParams {
 }
 
 impl StreamingRetryParams {
+    /// Gets the object again, to retry reading it after its stream failed.
+    pub(crate) async fn refetch(&self) -> super::Result<GetResult> {
+        self.source
+            .get(&self.input, self.range.clone(), self.io_stats.clone())
+            .await
+    }
+
     pub(crate) fn new(
         source: Arc<dyn ObjectSource>,
         input: String,
@@ -171,6 +178,9 @@ pub struct FileMetadata {
     pub filepath: String,
     pub size: Option<u64>,
     pub filetype: FileType,
+    /// The entity tag of a file, which changes whenever its contents do, or its modification time for local files.
+    /// None for directories and for sources which don't list one.
+    pub etag: Option<String>,
 }
 #[derive(Debug)]
 pub struct LSResult {
@@ -218,6 +228,16 @@ pub trait ObjectSource: Sync + Send {
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    /// Returns the entity tag of an object, which changes whenever its contents do, or None if the source doesn't
+    /// tag its objects.
+    async fn get_etag(
+        &self,
+        _uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        Ok(None)
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -237,6 +257,18 @@ pub trait ObjectSource: Sync + Send {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<LSResult>;
 
+    /// Copies the object at `src` to `dest` on the server side, without downloading it.
+    async fn copy(
+        &self,
+        _src: &str,
+        _dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::NotImplementedMethod {
+            method: "Copies are not yet supported! Please file an issue.".to_string(),
+        })
+    }
+
     /// Delete the object with the given uri.
     /// Return OK if the object is deleted successfully or the object does not exist.
     async fn delete(&self, _uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<()> {
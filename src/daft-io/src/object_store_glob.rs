@@ -34,6 +34,9 @@ const MARKER_SUFFIXES: [&str; 1] = [".crc"];
 const MARKER_FILES: [&str; 3] = ["_metadata", "_common_metadata", "_success"];
 // NOTE: We use the following prefixes to filter out Spark/Databricks marker files
 const MARKER_PREFIXES: [&str; 2] = ["_started", "_committed"];
+// NOTE: Directories with this prefix hold Daft's own files, such as the files staged by writes, which globs only
+// return when they explicitly name such a directory
+const DAFT_INTERNAL_DIR_PREFIX: &str = "_daft_";
 
 #[derive(Clone)]
 pub struct GlobState {
@@ -335,6 +338,13 @@ fn should_return(fm: &FileMetadata) -> bool {
     }
 }
 
+/// Returns whether any of the directories in `path` is one of Daft's internal directories.
+fn in_daft_internal_dir(path: &str) -> bool {
+    let mut components = path.split(GLOB_DELIMITER);
+    components.next_back();
+    components.any(|dir| dir.starts_with(DAFT_INTERNAL_DIR_PREFIX))
+}
+
 /// Validates the glob pattern before compiling it. The `globset` crate which we use for globbing is
 /// very permissive and does not check for invalid usage of the '**' wildcard. This function ensures
 /// that the glob pattern does not contain invalid usage of '**'.
@@ -391,6 +401,11 @@ pub async fn glob(
     limit: Option<usize>,
     io_stats: Option<IOStatsRef>,
 ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+    // Files under Daft's internal directories are skipped, unless the glob itself names one of them
+    let skip_internal_dirs = !glob
+        .split(GLOB_DELIMITER)
+        .any(|component| component.starts_with(DAFT_INTERNAL_DIR_PREFIX));
+
     // If no special characters, we fall back to ls behavior
     let full_fragment = GlobFragment::new(glob);
     if !full_fragment.has_special_character() {
@@ -404,7 +419,7 @@ pub async fn glob(
                 // If doesn't have a glob character and doesn't end with a delimiter, assume its a file first.
                 let maybe_size = source.get_size(&glob, io_stats.clone()).await;
                 match maybe_size {
-                    Ok(size_bytes) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(size_bytes as u64), filetype: FileType::File  }),
+                    Ok(size_bytes) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(size_bytes as u64), filetype: FileType::File, etag: None}),
                     Err(crate::Error::NotAFile {..} | crate::Error::NotFound { .. } | crate::Error::UnableToDetermineSize { .. }) => {attempt_as_dir = true;},
                     Err(err) => yield Err(err),
                 }
@@ -414,7 +429,7 @@ pub async fn glob(
                 while let Some(result) = results.next().await && remaining_results.is_none_or(|rr| rr > 0) {
                     match result {
                         Ok(fm) => {
-                            if should_return(&fm) {
+                            if should_return(&fm) && !(skip_internal_dirs && in_daft_internal_dir(&fm.filepath)) {
                                 remaining_results = remaining_results.map(|rr| rr - 1);
                                 yield Ok(fm)
                             }
@@ -702,7 +717,7 @@ pub async fn glob(
         let mut remaining_results = limit;
         while remaining_results.is_none_or(|rr| rr > 0) && let Some(v) = to_rtn_rx.recv().await {
 
-            if v.as_ref().is_ok_and(|v| !should_return(v)) {
+            if v.as_ref().is_ok_and(|v| !should_return(v) || (skip_internal_dirs && in_daft_internal_dir(&v.filepath))) {
                 continue
             }
 
@@ -746,4 +761,18 @@ mod tests {
         let err = verify_glob("invalid/\\**blahblah**.txt").unwrap_err();
         assert!(err.to_string().contains("invalid/\\\\**blahblah/**/*.txt")); // Suggests adding delimiters around '**'
     }
+
+    #[test]
+    fn test_in_daft_internal_dir() {
+        assert!(in_daft_internal_dir(
+            "s3://bucket/out/_daft_staging/abc/part-0.parquet"
+        ));
+        assert!(!in_daft_internal_dir("s3://bucket/out/part-0.parquet"));
+        assert!(!in_daft_internal_dir(
+            "s3://bucket/out/_daft_part-0.parquet"
+        ));
+        assert!(!in_daft_internal_dir(
+            "s3://bucket/out/daft_staging/part-0.parquet"
+        ));
+    }
 }
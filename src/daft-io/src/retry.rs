@@ -59,6 +59,8 @@ impl ExponentialBackoff {
                 return res.map_err(RetryError::into_inner);
             }
 
+            crate::stats::IO_METRICS.retries.add(1, &[]);
+
             let jitter = rand::thread_rng()
                 .gen_range(0..((1 << attempts) * self.jitter_ms))
                 .min(self.max_backoff_ms);
@@ -24,9 +24,11 @@ use aws_sdk_s3::{
     error::ProvideErrorMetadata,
     operation::{
         complete_multipart_upload::CompleteMultipartUploadError,
+        copy_object::CopyObjectError,
         create_multipart_upload::CreateMultipartUploadError,
         put_object::PutObjectError,
         upload_part::{UploadPartError, UploadPartOutput},
+        upload_part_copy::UploadPartCopyError,
     },
     primitives::{ByteStream, ByteStreamError},
 };
@@ -66,6 +68,10 @@ use crate::{
 
 const S3_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+/// Largest object that a single CopyObject request can copy.
+const MAX_COPY_OBJECT_SIZE: usize = 5 * 1024 * 1024 * 1024;
+/// Size of the parts that larger objects are copied in.
+const COPY_PART_SIZE: usize = 512 * 1024 * 1024;
 
 #[derive(Debug)]
 pub struct S3LikeSource {
@@ -132,6 +138,32 @@ enum Error {
         key: String,
         source: SdkError<CompleteMultipartUploadError, Response>,
     },
+    #[snafu(display(
+        "Unable to copy {} to {}: {}",
+        path,
+        dest,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCopyFile {
+        path: String,
+        dest: String,
+        source: SdkError<CopyObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to copy part {} of {} to {}: {}",
+        part,
+        path,
+        dest,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCopyPart {
+        path: String,
+        dest: String,
+        part: NonZeroI32,
+        source: SdkError<UploadPartCopyError, Response>,
+    },
+
     #[snafu(display(
         "Unable to delete {}: {}",
         path,
@@ -867,7 +899,7 @@ impl S3LikeSource {
         permit: SemaphorePermit<'async_recursion>,
         uri: &str,
         region: &Region,
-    ) -> super::Result<usize> {
+    ) -> super::Result<(usize, Option<String>)> {
         log::debug!("S3 head at {uri} in region: {region}");
         let ObjectPath {
             scheme: _scheme,
@@ -896,7 +928,7 @@ impl S3LikeSource {
 
             match response {
                 Ok(v) => match v.content_length() {
-                    Some(l) => Ok(l as usize),
+                    Some(l) => Ok((l as usize, v.e_tag().map(str::to_string))),
                     None => Err(Error::HeadObjectOutputEmpty { path: uri.into() }.into()),
                 },
                 Err(SdkError::ServiceError(err)) => {
@@ -989,11 +1021,13 @@ impl S3LikeSource {
                         filepath: format!("{scheme}://{bucket}/{}", d.prefix().unwrap_or_default()),
                         size: None,
                         filetype: FileType::Directory,
+                        etag: None,
                     })
                     .chain(files.iter().map(|f| FileMetadata {
                         filepath: format!("{scheme}://{bucket}/{}", f.key().unwrap_or_default()),
                         size: f.size().map(|size| size as u64),
                         filetype: FileType::File,
+                        etag: f.e_tag().map(str::to_string),
                     }))
                     .collect();
 
@@ -1359,11 +1393,28 @@ impl ObjectSource for S3LikeSource {
             .acquire()
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
-        let head_result = self.head_impl(permit, uri, &self.default_region).await?;
+        let (size, _) = self.head_impl(permit, uri, &self.default_region).await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1);
+        }
+        Ok(size)
+    }
+
+    async fn get_etag(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let (_, etag) = self.head_impl(permit, uri, &self.default_region).await?;
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1);
         }
-        Ok(head_result)
+        Ok(etag)
     }
 
     async fn glob(
@@ -1503,6 +1554,104 @@ impl ObjectSource for S3LikeSource {
         self
     }
 
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let ObjectPath {
+            bucket: src_bucket,
+            key: src_key,
+            ..
+        } = parse_object_url(src)?;
+        let ObjectPath { bucket, key, .. } = parse_object_url(dest)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: dest.into() }.into());
+        }
+
+        let size = self.get_size(src, io_stats.clone()).await?;
+        let copy_source = format!("{src_bucket}/{}", encode_copy_source_key(&src_key));
+        let request_payer = self
+            .s3_config
+            .requester_pays
+            .then_some(s3::types::RequestPayer::Requester);
+        let client = self.get_s3_client(&self.default_region).await?;
+
+        if size <= MAX_COPY_OBJECT_SIZE {
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            client
+                .copy_object()
+                .copy_source(copy_source)
+                .bucket(bucket)
+                .key(key)
+                .set_request_payer(request_payer)
+                .send()
+                .await
+                .context(UnableToCopyFileSnafu { path: src, dest })?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_put_requests(1);
+            }
+            return Ok(());
+        }
+
+        // Objects too large for a single CopyObject request are copied part by part into a multipart upload.
+        let upload = self
+            .create_multipart_upload(&bucket, &key, &self.default_region)
+            .await?;
+        let mut completed_parts = vec![];
+        for (i, start) in (0..size).step_by(COPY_PART_SIZE).enumerate() {
+            let end = (start + COPY_PART_SIZE).min(size) - 1;
+            let part_number = NonZeroI32::new(i as i32 + 1).expect("Part numbers start at 1");
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            let output = client
+                .upload_part_copy()
+                .copy_source(&copy_source)
+                .copy_source_range(format!("bytes={start}-{end}"))
+                .bucket(&bucket)
+                .key(&key)
+                .upload_id(upload.upload_id.as_ref())
+                .part_number(part_number.get())
+                .set_request_payer(request_payer.clone())
+                .send()
+                .await
+                .context(UnableToCopyPartSnafu {
+                    path: src,
+                    dest,
+                    part: part_number,
+                })?;
+            let etag = output
+                .copy_part_result()
+                .and_then(|result| result.e_tag())
+                .map(|etag| etag.to_string().into())
+                .context(MissingEtagForMultipartUploadSnafu {
+                    bucket: bucket.clone(),
+                    key: key.clone(),
+                    upload_id: upload.upload_id.to_string(),
+                    part: part_number,
+                })?;
+            completed_parts.push(CompletedPart { part_number, etag });
+        }
+        self.complete_multipart_upload(
+            key.into(),
+            bucket.into(),
+            upload.upload_id,
+            completed_parts,
+            &upload.region,
+        )
+        .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+        }
+        Ok(())
+    }
+
     async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
         let permit = self
             .connection_pool_sema
@@ -1542,6 +1691,18 @@ impl ObjectSource for S3LikeSource {
     }
 }
 
+/// Percent-encodes an object key for the source of a copy, keeping the `/` delimiters of the key.
+fn encode_copy_source_key(key: &str) -> String {
+    key.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
 /// S3MultipartWriter is responsible for managing multipart uploads to S3.
 ///
 /// It handles the creation of the multipart upload, writing individual parts to S3 and also
@@ -1802,8 +1963,18 @@ mod tests {
 
     use common_io_config::S3Config;
 
+    use super::encode_copy_source_key;
     use crate::{Result, S3LikeSource, integrations::test_full_get, object_io::ObjectSource};
 
+    #[test]
+    fn test_encode_copy_source_key() {
+        assert_eq!(
+            encode_copy_source_key("dir/part=a b/file+1.parquet"),
+            "dir/part%3Da%20b/file%2B1.parquet"
+        );
+        assert_eq!(encode_copy_source_key("é"), "%C3%A9");
+    }
+
     #[tokio::test]
     async fn test_full_get_from_s3() -> Result<()> {
         let parquet_file_path = "s3://daft-public-data/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
@@ -1,13 +1,41 @@
 use std::{
     borrow::Cow,
     sync::{
-        Arc,
+        Arc, LazyLock,
         atomic::{self},
     },
 };
 
+use opentelemetry::{
+    KeyValue, global,
+    metrics::{Counter, Histogram},
+};
+
 pub type IOStatsRef = Arc<IOStatsContext>;
 
+/// Process-wide IO metrics, exported when an OpenTelemetry meter provider is configured.
+pub(crate) struct IOMetrics {
+    requests: Counter<u64>,
+    bytes_read: Counter<u64>,
+    bytes_uploaded: Counter<u64>,
+    pub retries: Counter<u64>,
+    pub get_latency_ms: Histogram<f64>,
+}
+
+pub(crate) static IO_METRICS: LazyLock<IOMetrics> = LazyLock::new(|| {
+    let meter = global::meter("daft.io");
+    IOMetrics {
+        requests: meter.u64_counter("daft.io.requests").build(),
+        bytes_read: meter.u64_counter("daft.io.bytes_read").build(),
+        bytes_uploaded: meter.u64_counter("daft.io.bytes_uploaded").build(),
+        retries: meter.u64_counter("daft.io.retries").build(),
+        get_latency_ms: meter
+            .f64_histogram("daft.io.get_latency_ms")
+            .with_unit("ms")
+            .build(),
+    }
+});
+
 #[derive(Default, Debug)]
 pub struct IOStatsContext {
     name: Cow<'static, str>,
@@ -40,6 +68,23 @@ impl Drop for IOStatsContext {
             bytes_uploaded,
             mean_put_size as i64,
         );
+
+        let metrics = &*IO_METRICS;
+        for (kind, count) in [
+            ("get", num_gets),
+            ("head", self.load_head_requests()),
+            ("list", self.load_list_requests()),
+            ("put", num_puts),
+            ("delete", self.load_delete_requests()),
+        ] {
+            if count > 0 {
+                metrics
+                    .requests
+                    .add(count as u64, &[KeyValue::new("kind", kind)]);
+            }
+        }
+        metrics.bytes_read.add(bytes_read as u64, &[]);
+        metrics.bytes_uploaded.add(bytes_uploaded as u64, &[]);
     }
 }
 
@@ -569,11 +569,13 @@ impl TosSource {
                         filepath: format!("tos://{}/{}", bucket, prefix.prefix()),
                         size: None,
                         filetype: FileType::Directory,
+                        etag: None,
                     })
                     .chain(files.iter().map(|f| FileMetadata {
                         filepath: format!("tos://{}/{}", bucket, f.key()),
                         size: Some(f.size() as u64),
                         filetype: FileType::File,
+                        etag: Some(f.etag().to_string()),
                     }))
                     .collect();
                 let continuation_token = (!r.next_continuation_token().is_empty())
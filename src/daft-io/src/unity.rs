@@ -179,6 +179,15 @@ impl ObjectSource for UnitySource {
         source.get_size(&source_uri, io_stats).await
     }
 
+    async fn get_etag(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<String>> {
+        let (source, source_uri) = self.volume_path_to_source_and_url(uri).await?;
+        source.get_etag(&source_uri, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -0,0 +1,247 @@
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+use crate::{
+    dynamic_batching::{BatchingState, BatchingStrategy},
+    pipeline::MorselSizeRequirement,
+    runtime_stats::RuntimeStats,
+};
+
+/// Batching that targets both a latency and a memory footprint per batch.
+///
+/// This is meant for operators whose cost per row varies widely between queries, such as UDFs over multimodal data,
+/// where a row may be a short string or a large image. The cost of a row is estimated from recent batches: the time
+/// per row and the bytes per row. The next batch size is the largest that is expected to stay within both the target
+/// latency and the target size in bytes.
+///
+/// Batches shrink as soon as either target is exceeded, since the memory target protects against running out of
+/// memory, but only grow by a bounded factor per adjustment, so that a few cheap batches don't cause a huge one.
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct AdaptiveBatchingStrategy {
+    /// Target latency of a batch.
+    pub target_batch_latency: Duration,
+    /// Target memory footprint of a batch, i.e. the bytes of its input and output.
+    pub target_batch_size_bytes: usize,
+    /// The batch size to start with, before any batches have been observed.
+    pub initial_batch_size: usize,
+    /// The maximum factor by which the batch size grows between adjustments.
+    pub max_growth_factor: usize,
+    /// Minimum allowed batch size.
+    pub b_min: usize,
+    /// Maximum allowed batch size.
+    pub b_max: usize,
+}
+
+pub struct AdaptiveBatchingState {
+    /// The batch size currently being used.
+    current_batch_size: usize,
+    /// Rolling window of recent batches, as their number of rows, size in bytes and latency.
+    recent_batches: VecDeque<(usize, usize, Duration)>,
+}
+
+impl AdaptiveBatchingState {
+    /// Window size for recent batches.
+    const WINDOW_SIZE: usize = 8;
+
+    /// Get the recent average latency per row and bytes per row.
+    fn cost_per_row(&self) -> Option<(Duration, f64)> {
+        let rows = self
+            .recent_batches
+            .iter()
+            .map(|(rows, _, _)| rows)
+            .sum::<usize>();
+        if rows == 0 {
+            return None;
+        }
+        let bytes = self
+            .recent_batches
+            .iter()
+            .map(|(_, bytes, _)| bytes)
+            .sum::<usize>();
+        let latency = self
+            .recent_batches
+            .iter()
+            .map(|(_, _, t)| t)
+            .sum::<Duration>();
+        Some((latency / rows as u32, bytes as f64 / rows as f64))
+    }
+}
+
+impl BatchingState for AdaptiveBatchingState {
+    fn record_execution_stat(
+        &mut self,
+        _stats: Arc<dyn RuntimeStats>,
+        batch_size: usize,
+        size_bytes: usize,
+        duration: Duration,
+    ) {
+        self.recent_batches
+            .push_back((batch_size, size_bytes, duration));
+        if self.recent_batches.len() > Self::WINDOW_SIZE {
+            self.recent_batches.pop_front();
+        }
+    }
+}
+
+impl AdaptiveBatchingStrategy {
+    fn clamp(&self, batch_size: usize) -> usize {
+        batch_size.min(self.b_max).max(self.b_min).max(1)
+    }
+}
+
+impl BatchingStrategy for AdaptiveBatchingStrategy {
+    type State = AdaptiveBatchingState;
+
+    fn make_state(&self) -> Self::State {
+        AdaptiveBatchingState {
+            current_batch_size: self.clamp(self.initial_batch_size),
+            recent_batches: VecDeque::with_capacity(AdaptiveBatchingState::WINDOW_SIZE),
+        }
+    }
+
+    fn initial_requirements(&self) -> MorselSizeRequirement {
+        MorselSizeRequirement::Flexible(self.b_min, self.clamp(self.initial_batch_size))
+    }
+
+    fn calculate_new_requirements(&self, state: &mut Self::State) -> MorselSizeRequirement {
+        let Some((latency_per_row, bytes_per_row)) = state.cost_per_row() else {
+            return MorselSizeRequirement::Flexible(self.b_min, state.current_batch_size);
+        };
+
+        let rows_within_latency = if latency_per_row.is_zero() {
+            usize::MAX
+        } else {
+            (self.target_batch_latency.as_nanos() / latency_per_row.as_nanos()) as usize
+        };
+        let rows_within_memory = if bytes_per_row == 0.0 {
+            usize::MAX
+        } else {
+            (self.target_batch_size_bytes as f64 / bytes_per_row) as usize
+        };
+        let target = rows_within_latency.min(rows_within_memory).min(
+            state
+                .current_batch_size
+                .saturating_mul(self.max_growth_factor),
+        );
+        state.current_batch_size = self.clamp(target);
+
+        log::debug!(
+            "[{}] latency_per_row={}us, bytes_per_row={:.0}, new_batch_size={}",
+            std::thread::current().name().unwrap_or("unknown"),
+            latency_per_row.as_micros(),
+            bytes_per_row,
+            state.current_batch_size,
+        );
+        MorselSizeRequirement::Flexible(self.b_min, state.current_batch_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::*;
+    use crate::{dynamic_batching::tests::MockRuntimeStats, runtime_stats::RuntimeStats};
+
+    fn create_strategy() -> AdaptiveBatchingStrategy {
+        AdaptiveBatchingStrategy {
+            target_batch_latency: Duration::from_millis(1000),
+            target_batch_size_bytes: 1024 * 1024,
+            initial_batch_size: 8,
+            max_growth_factor: 2,
+            b_min: 1,
+            b_max: 1024,
+        }
+    }
+
+    fn stats() -> Arc<dyn RuntimeStats> {
+        Arc::new(MockRuntimeStats)
+    }
+
+    #[test]
+    fn test_adaptive_initial_requirements() {
+        let strategy = create_strategy();
+        let mut state = strategy.make_state();
+
+        assert_eq!(
+            strategy.initial_requirements(),
+            MorselSizeRequirement::Flexible(1, 8)
+        );
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(1, 8)
+        );
+    }
+
+    #[test]
+    fn test_adaptive_grows_by_bounded_factor() {
+        let strategy = create_strategy();
+        let mut state = strategy.make_state();
+
+        // Cheap rows: 1ms and 1KiB per row would allow 1000 rows, but growth is bounded.
+        state.record_execution_stat(stats(), 8, 8 * 1024, Duration::from_millis(8));
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(1, 16)
+        );
+        state.record_execution_stat(stats(), 16, 16 * 1024, Duration::from_millis(16));
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(1, 32)
+        );
+    }
+
+    #[test]
+    fn test_adaptive_shrinks_to_latency_target() {
+        let strategy = create_strategy();
+        let mut state = strategy.make_state();
+        state.current_batch_size = 512;
+
+        // 10ms per row allows 100 rows per second.
+        state.record_execution_stat(stats(), 512, 512, Duration::from_millis(5120));
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(1, 100)
+        );
+    }
+
+    #[test]
+    fn test_adaptive_shrinks_to_memory_target() {
+        let strategy = create_strategy();
+        let mut state = strategy.make_state();
+        state.current_batch_size = 512;
+
+        // Fast rows of 64KiB each, of which 16 fit in the target of 1MiB.
+        state.record_execution_stat(stats(), 512, 512 * 64 * 1024, Duration::from_millis(10));
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(1, 16)
+        );
+    }
+
+    #[test]
+    fn test_adaptive_respects_bounds() {
+        let strategy = AdaptiveBatchingStrategy {
+            b_min: 4,
+            b_max: 20,
+            ..create_strategy()
+        };
+        let mut state = strategy.make_state();
+
+        // Rows which are too large for the memory target still make batches of at least the minimum size.
+        state.record_execution_stat(stats(), 8, 8 * 1024 * 1024, Duration::from_millis(8));
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(4, 4)
+        );
+
+        // Cheap rows never make batches larger than the maximum size.
+        state.current_batch_size = 16;
+        state.recent_batches.clear();
+        state.record_execution_stat(stats(), 16, 16, Duration::from_millis(1));
+        assert_eq!(
+            strategy.calculate_new_requirements(&mut state),
+            MorselSizeRequirement::Flexible(4, 20)
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
 };
 pub struct DynBatchingState {
     #[allow(clippy::type_complexity)]
-    record_fn: Box<dyn FnMut(Arc<dyn RuntimeStats>, usize, Duration) + Send + Sync>,
+    record_fn: Box<dyn FnMut(Arc<dyn RuntimeStats>, usize, usize, Duration) + Send + Sync>,
     update_fn: Box<dyn FnMut() -> MorselSizeRequirement + Send + Sync>,
 }
 
@@ -19,9 +19,10 @@ impl BatchingState for DynBatchingState {
         &mut self,
         stats: Arc<dyn RuntimeStats>,
         batch_size: usize,
+        size_bytes: usize,
         duration: Duration,
     ) {
-        (self.record_fn)(stats, batch_size, duration);
+        (self.record_fn)(stats, batch_size, size_bytes, duration);
     }
 }
 
@@ -61,11 +62,11 @@ impl DynBatchingStrategy {
                 let state_for_update = shared_state;
 
                 DynBatchingState {
-                    record_fn: Box::new(move |stats, batch_size, duration| {
+                    record_fn: Box::new(move |stats, batch_size, size_bytes, duration| {
                         state_for_record
                             .lock()
                             .unwrap()
-                            .record_execution_stat(stats, batch_size, duration);
+                            .record_execution_stat(stats, batch_size, size_bytes, duration);
                     }),
                     update_fn: Box::new(move || {
                         let mut state_guard = state_for_update.lock().unwrap();
@@ -130,6 +131,7 @@ mod tests {
             &mut self,
             _stats: Arc<dyn crate::runtime_stats::RuntimeStats>,
             batch_size: usize,
+            _size_bytes: usize,
             _duration: std::time::Duration,
         ) {
             *self += batch_size;
@@ -117,6 +117,7 @@ impl BatchingState for LatencyConstrainedBatchingState {
         &mut self,
         _stats: std::sync::Arc<dyn RuntimeStats>,
         batch_size: usize,
+        _size_bytes: usize,
         duration: Duration,
     ) {
         self.recent_latencies.push_back(duration);
@@ -314,7 +315,7 @@ mod tests {
         state.b_high = 200;
 
         // Latency = 150ms, target = 100ms + 10ms = 110ms tolerance
-        state.record_execution_stat(stats(), 100, Duration::from_millis(150));
+        state.record_execution_stat(stats(), 100, 0, Duration::from_millis(150));
         let _req = strategy.calculate_new_requirements(&mut state);
 
         // Should contract search space (search_high should be reduced)
@@ -331,7 +332,7 @@ mod tests {
         state.b_high = 100;
 
         // Latency = 50ms, target = 100ms - 10ms = 90ms tolerance
-        state.record_execution_stat(stats(), 50, Duration::from_millis(50));
+        state.record_execution_stat(stats(), 50, 0, Duration::from_millis(50));
 
         let _req = strategy.calculate_new_requirements(&mut state);
 
@@ -349,7 +350,7 @@ mod tests {
 
         // Latency = 100ms, exactly at target
 
-        state.record_execution_stat(stats(), 80, Duration::from_millis(100));
+        state.record_execution_stat(stats(), 80, 0, Duration::from_millis(100));
 
         let _req = strategy.calculate_new_requirements(&mut state);
 
@@ -371,7 +372,7 @@ mod tests {
         };
 
         let mut state = strategy.make_state();
-        state.record_execution_stat(stats(), 5, Duration::from_millis(50));
+        state.record_execution_stat(stats(), 5, 0, Duration::from_millis(50));
 
         let _req = strategy.calculate_new_requirements(&mut state);
 
@@ -396,9 +397,9 @@ mod tests {
         let strategy = create_strategy();
         let mut state = strategy.make_state();
 
-        state.record_execution_stat(stats(), 50, Duration::from_millis(80));
-        state.record_execution_stat(stats(), 60, Duration::from_millis(120));
-        state.record_execution_stat(stats(), 70, Duration::from_millis(100));
+        state.record_execution_stat(stats(), 50, 0, Duration::from_millis(80));
+        state.record_execution_stat(stats(), 60, 0, Duration::from_millis(120));
+        state.record_execution_stat(stats(), 70, 0, Duration::from_millis(100));
 
         let _req = strategy.calculate_new_requirements(&mut state);
 
@@ -416,6 +417,7 @@ mod tests {
             state.record_execution_stat(
                 stats(),
                 state.current_batch_size,
+                0,
                 Duration::from_millis(95),
             );
             strategy.calculate_new_requirements(&mut state);
@@ -432,7 +434,7 @@ mod tests {
         let mut state = strategy.make_state();
         state.b_high = strategy.b_max + 100;
 
-        state.record_execution_stat(stats(), 50, Duration::from_millis(50));
+        state.record_execution_stat(stats(), 50, 0, Duration::from_millis(50));
         let _req = strategy.calculate_new_requirements(&mut state);
         assert!(state.b_high <= strategy.b_max);
         assert!(state.current_batch_size <= strategy.b_max);
@@ -1,8 +1,10 @@
+mod adaptive_strategy;
 mod dyn_strategy;
 mod latency_constrained_strategy;
 mod static_strategy;
 use std::{sync::Arc, time::Duration};
 
+pub use adaptive_strategy::*;
 pub use dyn_strategy::*;
 pub use latency_constrained_strategy::*;
 use parking_lot::Mutex;
@@ -34,10 +36,12 @@ pub trait BatchingStrategy: Send + Sync + std::fmt::Debug {
 }
 
 pub trait BatchingState {
+    /// Records the number of rows of a processed batch, the size of the batch in bytes, and how long it took.
     fn record_execution_stat(
         &mut self,
         stats: Arc<dyn RuntimeStats>,
         batch_size: usize,
+        size_bytes: usize,
         duration: Duration,
     );
 }
@@ -54,7 +58,7 @@ pub trait BatchingState {
 /// ```rust,ignore
 /// let manager = BatchManager::new(strategy);
 ///
-/// manager.record_execution_stats(stats, batch_size, duration);
+/// manager.record_execution_stats(stats, batch_size, size_bytes, duration);
 ///
 /// let requirements = manager.calculate_batch_size();
 /// ```
@@ -92,10 +96,11 @@ where
         &self,
         stats: Arc<dyn RuntimeStats>,
         batch_size: usize,
+        size_bytes: usize,
         duration: Duration,
     ) {
         let mut state = self.state.lock();
-        state.record_execution_stat(stats, batch_size, duration);
+        state.record_execution_stat(stats, batch_size, size_bytes, duration);
     }
 
     pub fn initial_requirements(&self) -> MorselSizeRequirement {
@@ -150,6 +155,7 @@ mod tests {
             &mut self,
             _stats: Arc<dyn RuntimeStats>,
             _batch_size: usize,
+            _size_bytes: usize,
             _duration: Duration,
         ) {
             self.measurement_count += 1;
@@ -230,7 +236,12 @@ mod tests {
         let manager = BatchManager::new(strategy.clone());
 
         // Record some execution stats
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 32, Duration::from_millis(100));
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            32,
+            0,
+            Duration::from_millis(100),
+        );
 
         let req = manager.calculate_batch_size();
         assert_eq!(req, MorselSizeRequirement::Flexible(1, 10)); // First state transition
@@ -243,8 +254,18 @@ mod tests {
         let manager = BatchManager::new(strategy.clone());
 
         // Record multiple stats
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 10, Duration::from_millis(50));
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 20, Duration::from_millis(75));
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            10,
+            0,
+            Duration::from_millis(50),
+        );
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            20,
+            0,
+            Duration::from_millis(75),
+        );
 
         let req = manager.calculate_batch_size();
         assert_eq!(req, MorselSizeRequirement::Flexible(5, 20)); // 2 measurements processed
@@ -256,7 +277,7 @@ mod tests {
         let strategy = MockBatchingStrategy::new(MorselSizeRequirement::Flexible(1, 4));
         let manager = BatchManager::new(strategy.clone());
 
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 5, Duration::from_millis(25));
+        manager.record_execution_stats(Arc::new(MockRuntimeStats), 5, 0, Duration::from_millis(25));
 
         let req1 = manager.calculate_batch_size();
         assert_eq!(req1, MorselSizeRequirement::Flexible(1, 10));
@@ -274,13 +295,28 @@ mod tests {
         let manager = BatchManager::new(strategy.clone());
 
         // First measurement
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 10, Duration::from_millis(30));
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            10,
+            0,
+            Duration::from_millis(30),
+        );
         let req1 = manager.calculate_batch_size();
         assert_eq!(req1, MorselSizeRequirement::Flexible(1, 10));
 
         // More measurements
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 15, Duration::from_millis(40));
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 20, Duration::from_millis(60));
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            15,
+            0,
+            Duration::from_millis(40),
+        );
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            20,
+            0,
+            Duration::from_millis(60),
+        );
         let req2 = manager.calculate_batch_size();
         assert_eq!(req2, MorselSizeRequirement::Flexible(5, 20)); // 3 total measurements
 
@@ -296,7 +332,12 @@ mod tests {
         assert_eq!(manager.initial_requirements(), static_req);
 
         // Even after recording stats, static strategy should return same requirement
-        manager.record_execution_stats(Arc::new(MockRuntimeStats), 64, Duration::from_millis(200));
+        manager.record_execution_stats(
+            Arc::new(MockRuntimeStats),
+            64,
+            0,
+            Duration::from_millis(200),
+        );
 
         let req = manager.calculate_batch_size();
         assert_eq!(req, static_req);
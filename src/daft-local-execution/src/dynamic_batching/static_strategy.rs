@@ -21,6 +21,7 @@ impl BatchingState for () {
         &mut self,
         _stats: std::sync::Arc<dyn crate::runtime_stats::RuntimeStats>,
         _batch_size: usize,
+        _size_bytes: usize,
         _duration: std::time::Duration,
     ) {
     }
@@ -16,6 +16,8 @@ pub(crate) struct CrossJoinState {
     bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
     stream_idx: usize,
     collect_idx: usize,
+    // row offset of the next chunk of the current stream table to join
+    stream_offset: usize,
 }
 
 impl CrossJoinState {
@@ -24,30 +26,61 @@ impl CrossJoinState {
             bridge,
             stream_idx: 0,
             collect_idx: 0,
+            stream_offset: 0,
         }
     }
 }
 
+/// The size in bytes above which output morsels are split, so that wide rows don't make them large even when they're
+/// under the row cap.
+const MAX_OUTPUT_BYTES: usize = 64 * 1024 * 1024;
+
 pub struct CrossJoinOperator {
     output_schema: SchemaRef,
     stream_side: JoinSide,
     state_bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+    max_output_rows: usize,
+    max_output_bytes: usize,
 }
 
 impl CrossJoinOperator {
+    /// `max_output_rows` caps the number of rows of each output morsel, and their size is capped at
+    /// [`MAX_OUTPUT_BYTES`]. Each stream table is joined against each collected table in chunks of stream rows small
+    /// enough to stay under both caps, so that the output of a single pair of tables, which is the product of their
+    /// sizes, is never materialized all at once.
     pub(crate) fn new(
         output_schema: SchemaRef,
         stream_side: JoinSide,
         state_bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+        max_output_rows: usize,
     ) -> Self {
         Self {
             output_schema,
             stream_side,
             state_bridge,
+            max_output_rows: max_output_rows.max(1),
+            max_output_bytes: MAX_OUTPUT_BYTES,
         }
     }
 }
 
+/// Returns the number of rows of `stream_tbl` to join against `collect_tbl` at once, so that the output has at most
+/// `max_output_rows` rows and about `max_output_bytes` bytes, but at least one stream row.
+fn stream_chunk_rows(
+    stream_tbl: &RecordBatch,
+    collect_tbl: &RecordBatch,
+    max_output_rows: usize,
+    max_output_bytes: usize,
+) -> usize {
+    let collect_rows = collect_tbl.len().max(1);
+    // Each stream row is repeated once per collect row, next to a copy of the whole collect table.
+    let stream_row_bytes = stream_tbl.size_bytes() / stream_tbl.len().max(1);
+    let output_bytes_per_stream_row = stream_row_bytes * collect_rows + collect_tbl.size_bytes();
+    (max_output_rows / collect_rows)
+        .min(max_output_bytes / output_bytes_per_stream_row.max(1))
+        .max(1)
+}
+
 fn empty_result(
     state: CrossJoinState,
     output_schema: SchemaRef,
@@ -77,6 +110,8 @@ impl IntermediateOperator for CrossJoinOperator {
         }
 
         let stream_side = self.stream_side;
+        let max_output_rows = self.max_output_rows;
+        let max_output_bytes = self.max_output_bytes;
 
         task_spawner
             .spawn(
@@ -91,6 +126,17 @@ impl IntermediateOperator for CrossJoinOperator {
                     let stream_tbl = &stream_tables[state.stream_idx];
                     let collect_tbl = &collect_tables[state.collect_idx];
 
+                    // join as many stream rows at a time as fit under the output caps, but always at least one
+                    let chunk_rows = stream_chunk_rows(
+                        stream_tbl,
+                        collect_tbl,
+                        max_output_rows,
+                        max_output_bytes,
+                    );
+                    let chunk_end = (state.stream_offset + chunk_rows).min(stream_tbl.len());
+                    let stream_chunk = stream_tbl.slice(state.stream_offset, chunk_end)?;
+                    let stream_tbl = &stream_chunk;
+
                     let (left_tbl, right_tbl) = match stream_side {
                         JoinSide::Left => (stream_tbl, collect_tbl),
                         JoinSide::Right => (collect_tbl, stream_tbl),
@@ -104,15 +150,24 @@ impl IntermediateOperator for CrossJoinOperator {
                         None,
                     ));
 
-                    // increment inner loop index
-                    state.collect_idx = (state.collect_idx + 1) % collect_tables.len();
+                    // move onto the next chunk of the stream table, or the next collect table once it is exhausted
+                    state.stream_offset = chunk_end;
+                    if state.stream_offset >= stream_tables[state.stream_idx].len() {
+                        state.stream_offset = 0;
+
+                        // increment inner loop index
+                        state.collect_idx = (state.collect_idx + 1) % collect_tables.len();
 
-                    if state.collect_idx == 0 {
-                        // finished the inner loop, increment outer loop index
-                        state.stream_idx = (state.stream_idx + 1) % stream_tables.len();
+                        if state.collect_idx == 0 {
+                            // finished the inner loop, increment outer loop index
+                            state.stream_idx = (state.stream_idx + 1) % stream_tables.len();
+                        }
                     }
 
-                    let result = if state.stream_idx == 0 && state.collect_idx == 0 {
+                    let result = if state.stream_idx == 0
+                        && state.collect_idx == 0
+                        && state.stream_offset == 0
+                    {
                         // finished the outer loop, move onto next input
                         IntermediateOperatorResult::NeedMoreInput(Some(output_morsel))
                     } else {
@@ -138,6 +193,8 @@ impl IntermediateOperator for CrossJoinOperator {
         vec![
             "CrossJoin:".to_string(),
             format!("Stream Side = {:?}", self.stream_side),
+            format!("Max Output Rows = {}", self.max_output_rows),
+            format!("Max Output Bytes = {}", self.max_output_bytes),
         ]
     }
 
@@ -150,3 +207,36 @@ impl IntermediateOperator for CrossJoinOperator {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
+
+    use super::stream_chunk_rows;
+
+    fn table(num_rows: usize) -> RecordBatch {
+        let values =
+            Int64Array::from(("a", (0..num_rows as i64).collect::<Vec<_>>())).into_series();
+        RecordBatch::from_nonempty_columns(vec![values]).unwrap()
+    }
+
+    #[test]
+    fn test_stream_chunk_rows() {
+        let stream_tbl = table(1000);
+        let collect_tbl = table(100);
+
+        // Only the row cap applies.
+        assert_eq!(
+            stream_chunk_rows(&stream_tbl, &collect_tbl, 1000, usize::MAX),
+            10
+        );
+        // Each stream row makes 100 output rows of 2 Int64s, along with the 100 Int64s of the collect table.
+        assert_eq!(
+            stream_chunk_rows(&stream_tbl, &collect_tbl, usize::MAX, 100 * 16 * 4),
+            4
+        );
+        // At least one stream row is always joined.
+        assert_eq!(stream_chunk_rows(&stream_tbl, &collect_tbl, 1, 1), 1);
+    }
+}
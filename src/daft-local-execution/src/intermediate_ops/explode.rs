@@ -79,10 +79,16 @@ impl RuntimeStats for ExplodeStats {
 
 pub struct ExplodeOperator {
     to_explode: Arc<Vec<BoundExpr>>,
+    index_column: Option<Arc<str>>,
+    pad_mismatched: bool,
 }
 
 impl ExplodeOperator {
-    pub fn new(to_explode: Vec<BoundExpr>) -> Self {
+    pub fn new(
+        to_explode: Vec<BoundExpr>,
+        index_column: Option<String>,
+        pad_mismatched: bool,
+    ) -> Self {
         Self {
             to_explode: Arc::new(
                 to_explode
@@ -90,6 +96,8 @@ impl ExplodeOperator {
                     .map(|expr| BoundExpr::new_unchecked(explode(expr.inner().clone())))
                     .collect(),
             ),
+            index_column: index_column.map(Into::into),
+            pad_mismatched,
         }
     }
 }
@@ -105,10 +113,16 @@ impl IntermediateOperator for ExplodeOperator {
         task_spawner: &ExecutionTaskSpawner,
     ) -> IntermediateOpExecuteResult<Self> {
         let to_explode = self.to_explode.clone();
+        let index_column = self.index_column.clone();
+        let pad_mismatched = self.pad_mismatched;
         task_spawner
             .spawn(
                 async move {
-                    let out = input.explode(&to_explode)?;
+                    let out = input.explode_with_options(
+                        &to_explode,
+                        index_column.as_deref(),
+                        pad_mismatched,
+                    )?;
                     Ok((
                         state,
                         IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(out))),
@@ -120,10 +134,17 @@ impl IntermediateOperator for ExplodeOperator {
     }
 
     fn multiline_display(&self) -> Vec<String> {
-        vec![format!(
+        let mut res = vec![format!(
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
-        )]
+        )];
+        if let Some(index_column) = &self.index_column {
+            res.push(format!("Index column = {index_column}"));
+        }
+        if self.pad_mismatched {
+            res.push("Pad mismatched = true".to_string());
+        }
+        res
     }
 
     fn name(&self) -> NodeName {
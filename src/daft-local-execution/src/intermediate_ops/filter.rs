@@ -1,5 +1,5 @@
 use std::{
-    sync::{Arc, atomic::Ordering},
+    sync::{Arc, Mutex, atomic::Ordering},
     time::Duration,
 };
 
@@ -7,8 +7,16 @@ use common_error::DaftResult;
 use common_metrics::{
     CPU_US_KEY, ROWS_IN_KEY, ROWS_OUT_KEY, Stat, StatSnapshot, ops::NodeType, snapshot,
 };
-use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{
+    Expr, ExprRef, Operator,
+    common_treenode::{TreeNode, TreeNodeRecursion},
+    estimated_selectivity,
+    expr::bound_expr::BoundExpr,
+    functions::{FunctionExpr, scalar::ScalarFn},
+};
 use daft_micropartition::MicroPartition;
+use daft_recordbatch::ConjunctObservation;
 use opentelemetry::{KeyValue, global};
 use tracing::{Span, instrument};
 
@@ -88,13 +96,169 @@ impl RuntimeStats for FilterStats {
     }
 }
 
+/// The number of rows a conjunct must be evaluated on before its observed pass rate and cost are used for ordering.
+const MIN_OBSERVED_ROWS: u64 = 4096;
+
+#[derive(Debug)]
+struct ConjunctStats {
+    estimated_cost: f64,
+    estimated_pass_rate: f64,
+    rows_in: u64,
+    rows_out: u64,
+    elapsed_ns: u64,
+}
+
+impl ConjunctStats {
+    fn is_observed(&self) -> bool {
+        self.rows_in >= MIN_OBSERVED_ROWS
+    }
+
+    fn pass_rate(&self) -> f64 {
+        if self.is_observed() {
+            self.rows_out as f64 / self.rows_in as f64
+        } else {
+            self.estimated_pass_rate
+        }
+    }
+
+    fn observed_cost(&self) -> f64 {
+        self.elapsed_ns as f64 / self.rows_in as f64
+    }
+}
+
+/// Orders the conjuncts of a filter predicate so that those which are cheap and filter out many rows are evaluated
+/// first, and the rest are only evaluated on the rows that remain.
+///
+/// Conjuncts are ranked by their cost per row over the fraction of rows they filter out. The ranks start from static
+/// estimates of the cost and selectivity of each conjunct, and adapt to the pass rates and evaluation times observed
+/// while filtering, so that the order follows the data rather than the estimates.
+struct ConjunctOrder {
+    conjuncts: Vec<BoundExpr>,
+    stats: Mutex<Vec<ConjunctStats>>,
+}
+
+impl ConjunctOrder {
+    fn try_new(predicate: &BoundExpr, schema: &SchemaRef) -> Option<Self> {
+        let conjuncts = split_conjunction(predicate.inner());
+        if conjuncts.len() < 2 {
+            return None;
+        }
+        let stats = conjuncts
+            .iter()
+            .map(|conjunct| ConjunctStats {
+                estimated_cost: estimated_cost(conjunct),
+                estimated_pass_rate: estimated_selectivity(conjunct, schema),
+                rows_in: 0,
+                rows_out: 0,
+                elapsed_ns: 0,
+            })
+            .collect();
+        Some(Self {
+            conjuncts: conjuncts
+                .into_iter()
+                .map(BoundExpr::new_unchecked)
+                .collect(),
+            stats: Mutex::new(stats),
+        })
+    }
+
+    /// Returns the positions of the conjuncts in the order to evaluate them.
+    fn order(&self) -> Vec<usize> {
+        let stats = self.stats.lock().unwrap();
+        // Evaluation times are only comparable with each other, so the estimated costs are used until every
+        // conjunct has been observed.
+        let use_observed_cost = stats.iter().all(ConjunctStats::is_observed);
+        let rank = |stats: &ConjunctStats| {
+            let cost = if use_observed_cost {
+                stats.observed_cost()
+            } else {
+                stats.estimated_cost
+            };
+            cost / (1.0 - stats.pass_rate()).max(f64::EPSILON)
+        };
+        let mut order = (0..stats.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| rank(&stats[*a]).total_cmp(&rank(&stats[*b])));
+        order
+    }
+
+    fn record(&self, observations: &[ConjunctObservation]) {
+        let mut stats = self.stats.lock().unwrap();
+        for observation in observations {
+            let stats = &mut stats[observation.index];
+            stats.rows_in += observation.rows_in as u64;
+            stats.rows_out += observation.rows_out as u64;
+            stats.elapsed_ns += observation.elapsed.as_nanos() as u64;
+        }
+    }
+
+    fn filter(&self, input: &MicroPartition) -> DaftResult<MicroPartition> {
+        let order = self.order();
+        let conjuncts = order
+            .iter()
+            .map(|index| self.conjuncts[*index].clone())
+            .collect::<Vec<_>>();
+        let mut observations = vec![];
+        let output = input.filter_conjuncts(&conjuncts, |observation| {
+            observations.push(ConjunctObservation {
+                index: order[observation.index],
+                ..observation
+            });
+        })?;
+        self.record(&observations);
+        Ok(output)
+    }
+}
+
+fn split_conjunction(expr: &ExprRef) -> Vec<ExprRef> {
+    match expr.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let mut conjuncts = split_conjunction(left);
+            conjuncts.extend(split_conjunction(right));
+            conjuncts
+        }
+        Expr::Alias(expr, _) => split_conjunction(expr),
+        _ => vec![expr.clone()],
+    }
+}
+
+/// A rough estimate of the relative cost per row of evaluating an expression.
+fn estimated_cost(expr: &ExprRef) -> f64 {
+    let mut cost = 0.0;
+    expr.apply(|e| {
+        cost += match e.as_ref() {
+            Expr::Column(..) | Expr::Literal(..) | Expr::Alias(..) => 0.0,
+            Expr::ScalarFn(ScalarFn::Python(..))
+            | Expr::Function {
+                func: FunctionExpr::Python(..),
+                ..
+            } => 1000.0,
+            Expr::ScalarFn(..) | Expr::Function { .. } => 10.0,
+            Expr::IsIn(..) | Expr::Between(..) => 2.0,
+            _ => 1.0,
+        };
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .unwrap();
+    // Every conjunct costs at least as much as reading its mask.
+    cost.max(1.0)
+}
+
 pub struct FilterOperator {
     predicate: BoundExpr,
+    conjunct_order: Option<Arc<ConjunctOrder>>,
 }
 
 impl FilterOperator {
-    pub fn new(predicate: BoundExpr) -> Self {
-        Self { predicate }
+    pub fn new(predicate: BoundExpr, schema: &SchemaRef) -> Self {
+        let conjunct_order = ConjunctOrder::try_new(&predicate, schema).map(Arc::new);
+        Self {
+            predicate,
+            conjunct_order,
+        }
     }
 }
 
@@ -109,10 +273,14 @@ impl IntermediateOperator for FilterOperator {
         task_spawner: &ExecutionTaskSpawner,
     ) -> IntermediateOpExecuteResult<Self> {
         let predicate = self.predicate.clone();
+        let conjunct_order = self.conjunct_order.clone();
         task_spawner
             .spawn(
                 async move {
-                    let out = input.filter(&[predicate])?;
+                    let out = match conjunct_order {
+                        Some(conjunct_order) => conjunct_order.filter(&input)?,
+                        None => input.filter(&[predicate])?,
+                    };
                     Ok((
                         state,
                         IntermediateOperatorResult::NeedMoreInput(Some(Arc::new(out))),
@@ -151,11 +319,21 @@ impl IntermediateOperator for FilterOperator {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::Ordering;
+    use std::sync::{Arc, atomic::Ordering};
+
+    use daft_core::prelude::*;
+    use daft_dsl::{expr::bound_expr::BoundExpr, lit, resolved_col};
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
 
-    use super::FilterStats;
+    use super::{ConjunctOrder, FilterStats};
     use crate::runtime_stats::RuntimeStats;
 
+    fn conjunct_order(predicate: daft_dsl::ExprRef, schema: &SchemaRef) -> ConjunctOrder {
+        let predicate = BoundExpr::try_new(predicate, schema).unwrap();
+        ConjunctOrder::try_new(&predicate, schema).unwrap()
+    }
+
     #[test]
     fn selectivity_updates_after_rows_events() {
         let stats = FilterStats::new(42);
@@ -208,4 +386,45 @@ mod tests {
             final_selectivity
         );
     }
+
+    #[test]
+    fn conjuncts_are_ordered_by_estimates_before_observations() {
+        let schema: SchemaRef = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ])
+        .into();
+        // `a != 1` is estimated to keep more rows than `b IS NULL`, so `b IS NULL` goes first.
+        let order = conjunct_order(
+            resolved_col("a")
+                .not_eq(lit(1))
+                .and(resolved_col("b").is_null()),
+            &schema,
+        );
+        assert_eq!(order.order(), vec![1, 0]);
+    }
+
+    #[test]
+    fn conjuncts_are_reordered_by_observed_pass_rates() {
+        let num_rows = 10_000;
+        let a = Int64Array::from(("a", (0..num_rows).collect::<Vec<_>>())).into_series();
+        let b = Int64Array::from(("b", vec![0; num_rows as usize])).into_series();
+        let batch = RecordBatch::from_nonempty_columns(vec![a, b]).unwrap();
+        let schema = batch.schema.clone();
+        let input = MicroPartition::new_loaded(schema.clone(), Arc::new(vec![batch]), None);
+
+        // `b == 0` is estimated to filter out more rows than `a < 10`, but keeps every row.
+        let order = conjunct_order(
+            resolved_col("b")
+                .eq(lit(0i64))
+                .and(resolved_col("a").lt(lit(10i64))),
+            &schema,
+        );
+        let output = order.filter(&input).unwrap();
+        assert_eq!(output.len(), 10);
+
+        let output = order.filter(&input).unwrap();
+        assert_eq!(output.len(), 10);
+        assert_eq!(order.order(), vec![1, 0]);
+    }
 }
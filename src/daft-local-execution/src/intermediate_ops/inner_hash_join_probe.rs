@@ -2,10 +2,10 @@ use std::sync::Arc;
 
 use common_error::DaftResult;
 use common_metrics::ops::NodeType;
-use daft_core::prelude::{SchemaRef, UInt64Array};
+use daft_core::prelude::SchemaRef;
 use daft_dsl::expr::bound_expr::BoundExpr;
 use daft_micropartition::MicroPartition;
-use daft_recordbatch::{GrowableRecordBatch, ProbeState, get_columns_by_name};
+use daft_recordbatch::{ProbeState, get_columns_by_name};
 use indexmap::IndexSet;
 use itertools::Itertools;
 use tracing::{Span, instrument};
@@ -35,6 +35,7 @@ impl InnerHashJoinProbeState {
 
 struct InnerHashJoinParams {
     probe_on: Vec<BoundExpr>,
+    residual: Option<BoundExpr>,
     common_join_keys: Vec<String>,
     left_non_join_columns: Vec<String>,
     right_non_join_columns: Vec<String>,
@@ -48,10 +49,10 @@ pub struct InnerHashJoinProbeOperator {
 }
 
 impl InnerHashJoinProbeOperator {
-    const DEFAULT_GROWABLE_SIZE: usize = 20;
-
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         probe_on: Vec<BoundExpr>,
+        residual: Option<BoundExpr>,
         left_schema: &SchemaRef,
         right_schema: &SchemaRef,
         build_on_left: bool,
@@ -73,6 +74,7 @@ impl InnerHashJoinProbeOperator {
         Self {
             params: Arc::new(InnerHashJoinParams {
                 probe_on,
+                residual,
                 common_join_keys,
                 left_non_join_columns,
                 right_non_join_columns,
@@ -88,50 +90,25 @@ impl InnerHashJoinProbeOperator {
         input: &Arc<MicroPartition>,
         probe_state: &Arc<ProbeState>,
         probe_on: &[BoundExpr],
+        residual: Option<&BoundExpr>,
         common_join_keys: &[String],
         left_non_join_columns: &[String],
         right_non_join_columns: &[String],
         build_on_left: bool,
         output_schema: &SchemaRef,
     ) -> DaftResult<Arc<MicroPartition>> {
-        let build_side_tables = probe_state.get_record_batches().iter().collect::<Vec<_>>();
-
         let input_tables = input.record_batches();
         let result_tables = input_tables
             .iter()
             .map(|input_table| {
-                let mut build_side_growable = GrowableRecordBatch::new(
-                    &build_side_tables,
-                    false,
-                    Self::DEFAULT_GROWABLE_SIZE,
-                )?;
-                let mut probe_side_idxs = Vec::new();
-
                 let join_keys = input_table.eval_expression_list(probe_on)?;
-                let idx_iter = probe_state.probe_indices(&join_keys)?;
-                for (probe_row_idx, inner_iter) in idx_iter.enumerate() {
-                    if let Some(inner_iter) = inner_iter {
-                        for (build_rb_idx, build_row_idx) in inner_iter {
-                            build_side_growable.extend(
-                                build_rb_idx as usize,
-                                build_row_idx as usize,
-                                1,
-                            );
-                            probe_side_idxs.push(probe_row_idx as u64);
-                        }
-                    }
-                }
-
-                let build_side_table = build_side_growable.build()?;
-                let probe_side_table = {
-                    let indices_arr = UInt64Array::from(("", probe_side_idxs));
-                    input_table.take(&indices_arr)?
-                };
+                let matches =
+                    probe_state.probe_matches(input_table, &join_keys, residual, build_on_left)?;
 
                 let (left_table, right_table) = if build_on_left {
-                    (build_side_table, probe_side_table)
+                    (matches.build_side, matches.probe_side)
                 } else {
-                    (probe_side_table, build_side_table)
+                    (matches.probe_side, matches.build_side)
                 };
 
                 let join_keys_table = get_columns_by_name(&left_table, common_join_keys)?;
@@ -183,6 +160,7 @@ impl IntermediateOperator for InnerHashJoinProbeOperator {
                         &input,
                         &probe_state,
                         &params.probe_on,
+                        params.residual.as_ref(),
                         &params.common_join_keys,
                         &params.left_non_join_columns,
                         &params.right_non_join_columns,
@@ -215,6 +193,9 @@ impl IntermediateOperator for InnerHashJoinProbeOperator {
                 .map(|e| e.to_string())
                 .join(", ")
         ));
+        if let Some(residual) = &self.params.residual {
+            res.push(format!("Residual: {residual}"));
+        }
         res.push(format!("Build on left: {}", self.params.build_on_left));
         res
     }
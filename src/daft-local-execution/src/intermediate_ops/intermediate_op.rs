@@ -6,7 +6,7 @@ use common_error::DaftResult;
 use common_metrics::ops::{NodeCategory, NodeInfo, NodeType};
 use common_runtime::{get_compute_pool_num_threads, get_compute_runtime};
 use daft_core::prelude::SchemaRef;
-use daft_local_plan::LocalNodeContext;
+use daft_local_plan::{LOGICAL_NODE_ID_KEY, LocalNodeContext};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use snafu::ResultExt;
@@ -20,7 +20,9 @@ use crate::{
     },
     dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
     dynamic_batching::{BatchManager, BatchingStrategy},
-    pipeline::{MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext},
+    pipeline::{
+        MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext, pipeline_node_graph_details,
+    },
     resource_manager::MemoryManager,
     runtime_stats::{
         CountingSender, DefaultRuntimeStats, InitializingCountingReceiver, RuntimeStats,
@@ -152,6 +154,7 @@ impl<Op: IntermediateOperator + 'static> IntermediateNode<Op> {
                         batch_manager.record_execution_stats(
                             runtime_stats.clone(),
                             mp.len(),
+                            morsel.size_bytes() + mp.size_bytes(),
                             elapsed,
                         );
 
@@ -167,6 +170,7 @@ impl<Op: IntermediateOperator + 'static> IntermediateNode<Op> {
                         batch_manager.record_execution_stats(
                             runtime_stats.clone(),
                             mp.len(),
+                            morsel.size_bytes() + mp.size_bytes(),
                             elapsed,
                         );
                         if sender.send(mp).await.is_err() {
@@ -199,7 +203,7 @@ impl<Op: IntermediateOperator + 'static> IntermediateNode<Op> {
                     memory_manager.clone(),
                     batch_manager.clone(),
                 ),
-                &self.intermediate_op.name(),
+                &self.node_info,
             );
         }
         output_receiver
@@ -251,10 +255,15 @@ impl<Op: IntermediateOperator + 'static> TreeDisplay for IntermediateNode<Op> {
             "category": "Intermediate",
             "type": self.intermediate_op.op_type().to_string(),
             "name": self.name(),
+            "logical_node_id": self.node_info.context.get(LOGICAL_NODE_ID_KEY),
             "children": children,
         })
     }
 
+    fn repr_graph_details(&self) -> serde_json::Value {
+        pipeline_node_graph_details(self, &self.plan_stats)
+    }
+
     fn get_children(&self) -> Vec<&dyn TreeDisplay> {
         self.children.iter().map(|v| v.as_tree_display()).collect()
     }
@@ -312,15 +321,15 @@ impl<Op: IntermediateOperator + 'static> PipelineNode for IntermediateNode<Op> {
             ));
         }
         let op = self.intermediate_op.clone();
-        let num_workers = op.max_concurrency().context(PipelineExecutionSnafu {
-            node_name: self.name().to_string(),
-        })?;
+        let num_workers = op
+            .max_concurrency()
+            .context(PipelineExecutionSnafu::for_node(&self.node_info))?;
 
         let (destination_sender, destination_receiver) = create_channel(0);
         let counting_sender = CountingSender::new(destination_sender, self.runtime_stats.clone());
-        let strategy = op.batching_strategy().context(PipelineExecutionSnafu {
-            node_name: self.name().to_string(),
-        })?;
+        let strategy = op
+            .batching_strategy()
+            .context(PipelineExecutionSnafu::for_node(&self.node_info))?;
         let batch_manager = Arc::new(BatchManager::new(strategy));
         let dispatch_spawner = self
             .intermediate_op
@@ -332,7 +341,7 @@ impl<Op: IntermediateOperator + 'static> PipelineNode for IntermediateNode<Op> {
         );
         runtime_handle.spawn(
             async move { spawned_dispatch_result.spawned_dispatch_task.await? },
-            &self.name(),
+            &self.node_info,
         );
 
         let mut output_receiver = self.spawn_workers(
@@ -354,7 +363,7 @@ impl<Op: IntermediateOperator + 'static> PipelineNode for IntermediateNode<Op> {
                 stats_manager.finalize_node(node_id);
                 Ok(())
             },
-            &op.name(),
+            &self.node_info,
         );
         Ok(destination_receiver)
     }
@@ -6,5 +6,6 @@ pub mod inner_hash_join_probe;
 pub mod intermediate_op;
 pub mod into_batches;
 pub mod project;
+pub mod sorted_lookup_join;
 pub mod udf;
 pub mod unpivot;
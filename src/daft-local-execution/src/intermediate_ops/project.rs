@@ -10,6 +10,7 @@ use daft_dsl::{
     functions::{BuiltinScalarFn, BuiltinScalarFnVariant, scalar::ScalarFn},
 };
 use daft_micropartition::MicroPartition;
+use daft_recordbatch::FusedProjection;
 use itertools::Itertools;
 use tracing::{Span, instrument};
 
@@ -69,7 +70,8 @@ pub fn try_get_batch_size(exprs: &[BoundExpr]) -> Option<usize> {
 }
 
 pub struct ProjectOperator {
-    projection: Arc<Vec<BoundExpr>>,
+    /// The projection's expressions, whose fused programs are compiled once and shared by every task.
+    projection: Arc<FusedProjection>,
     max_concurrency: usize,
     parallel_exprs: usize,
     batch_size: Option<usize>,
@@ -80,7 +82,7 @@ impl ProjectOperator {
         let (max_concurrency, parallel_exprs) = Self::get_optimal_allocation(&projection)?;
         let batch_size = try_get_batch_size(&projection);
         Ok(Self {
-            projection: Arc::new(projection),
+            projection: Arc::new(FusedProjection::new(projection)),
             max_concurrency,
             parallel_exprs,
             batch_size,
@@ -126,12 +128,10 @@ impl IntermediateOperator for ProjectOperator {
                 async move {
                     let out = if num_parallel_exprs > 1 {
                         input
-                            .par_eval_expression_list(&projection, num_parallel_exprs)
+                            .par_eval_projection(&projection, num_parallel_exprs)
                             .await?
                     } else {
-                        input
-                            .eval_expression_list_async(Arc::unwrap_or_clone(projection))
-                            .await?
+                        input.eval_projection_async(&projection).await?
                     };
                     Ok((
                         state,
@@ -155,7 +155,11 @@ impl IntermediateOperator for ProjectOperator {
         let mut res = vec![];
         res.push(format!(
             "Project: {}",
-            self.projection.iter().map(|e| e.to_string()).join(", ")
+            self.projection
+                .exprs()
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
         ));
         res
     }
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_metrics::ops::NodeType;
+use daft_core::{join::JoinType, prelude::SchemaRef};
+use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_micropartition::MicroPartition;
+use itertools::Itertools;
+use tracing::{Span, instrument};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOperator, IntermediateOperatorResult,
+};
+use crate::{
+    ExecutionTaskSpawner, pipeline::NodeName, sinks::sorted_lookup_build::SortedLookupTable,
+    state_bridge::BroadcastStateBridgeRef,
+};
+
+pub(crate) enum SortedLookupJoinState {
+    Building(BroadcastStateBridgeRef<SortedLookupTable>),
+    Probing(Arc<SortedLookupTable>),
+}
+
+impl SortedLookupJoinState {
+    async fn get_or_await_lookup_table(&mut self) -> Arc<SortedLookupTable> {
+        match self {
+            Self::Building(bridge) => {
+                let lookup_table = bridge.get_state().await;
+                *self = Self::Probing(lookup_table.clone());
+                lookup_table
+            }
+            Self::Probing(lookup_table) => lookup_table.clone(),
+        }
+    }
+}
+
+/// Probes a sorted lookup join with morsels of the left side, by binary searching the sorted right side for the join
+/// keys of each left row.
+///
+/// Each left morsel is joined independently, so only join types whose output for a left row depends on that row
+/// alone are supported: inner, left, semi and anti joins.
+pub struct SortedLookupJoinOperator {
+    left_on: Arc<Vec<BoundExpr>>,
+    join_type: JoinType,
+    output_schema: SchemaRef,
+    state_bridge: BroadcastStateBridgeRef<SortedLookupTable>,
+}
+
+impl SortedLookupJoinOperator {
+    pub(crate) fn new(
+        left_on: Vec<BoundExpr>,
+        join_type: JoinType,
+        output_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<SortedLookupTable>,
+    ) -> Self {
+        Self {
+            left_on: Arc::new(left_on),
+            join_type,
+            output_schema,
+            state_bridge,
+        }
+    }
+}
+
+impl IntermediateOperator for SortedLookupJoinOperator {
+    type State = SortedLookupJoinState;
+    type BatchingStrategy = crate::dynamic_batching::StaticBatchingStrategy;
+    #[instrument(skip_all, name = "SortedLookupJoinOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Self::State,
+        task_spawner: &ExecutionTaskSpawner,
+    ) -> IntermediateOpExecuteResult<Self> {
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.output_schema.clone())));
+            return Ok((
+                state,
+                IntermediateOperatorResult::NeedMoreInput(Some(empty)),
+            ))
+            .into();
+        }
+
+        let left_on = self.left_on.clone();
+        let join_type = self.join_type;
+        let output_schema = self.output_schema.clone();
+        task_spawner
+            .spawn(
+                async move {
+                    let lookup_table = state.get_or_await_lookup_table().await;
+                    let result_tables = input
+                        .record_batches()
+                        .iter()
+                        .map(|input_table| {
+                            input_table.sorted_lookup_join(
+                                &lookup_table.table,
+                                &left_on,
+                                &lookup_table.keys,
+                                join_type,
+                            )
+                        })
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    let output = Arc::new(MicroPartition::new_loaded(
+                        output_schema,
+                        Arc::new(result_tables),
+                        None,
+                    ));
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(output)),
+                    ))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> NodeName {
+        "Sorted Lookup Join".into()
+    }
+
+    fn op_type(&self) -> NodeType {
+        NodeType::SortedLookupJoin
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "Sorted Lookup Join:".to_string(),
+            format!("Join type: {}", self.join_type),
+            format!(
+                "Probe on: [{}]",
+                self.left_on.iter().map(|e| e.to_string()).join(", ")
+            ),
+        ]
+    }
+
+    fn make_state(&self) -> DaftResult<Self::State> {
+        Ok(SortedLookupJoinState::Building(self.state_bridge.clone()))
+    }
+    fn batching_strategy(&self) -> DaftResult<Self::BatchingStrategy> {
+        Ok(crate::dynamic_batching::StaticBatchingStrategy::new(
+            self.morsel_size_requirement().unwrap_or_default(),
+        ))
+    }
+}
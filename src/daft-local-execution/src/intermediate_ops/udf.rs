@@ -18,8 +18,6 @@ use common_metrics::{
 use common_resource_request::ResourceRequest;
 use common_runtime::get_compute_pool_num_threads;
 use daft_core::{prelude::SchemaRef, series::Series};
-#[cfg(feature = "python")]
-use daft_dsl::python::PyExpr;
 use daft_dsl::{
     Column, Expr, ExprRef,
     common_treenode::{Transformed, TreeNode},
@@ -27,6 +25,8 @@ use daft_dsl::{
     functions::python::UDFProperties,
     operator_metrics::OperatorMetrics,
 };
+#[cfg(feature = "python")]
+use daft_dsl::{functions::python::call_udf_hook, python::PyExpr};
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 use itertools::Itertools;
@@ -41,13 +41,30 @@ use super::intermediate_op::{
 };
 use crate::{
     ExecutionTaskSpawner,
+    dispatcher::{DispatchSpawner, DynamicUnorderedDispatcher, RoundRobinDispatcher},
     dynamic_batching::{
-        DynBatchingStrategy, LatencyConstrainedBatchingStrategy, StaticBatchingStrategy,
+        AdaptiveBatchingStrategy, BatchManager, DynBatchingStrategy,
+        LatencyConstrainedBatchingStrategy, StaticBatchingStrategy,
     },
     pipeline::{MorselSizeRequirement, NodeName},
-    runtime_stats::{Counter, RuntimeStats},
+    runtime_stats::{Counter, Gauge, RuntimeStats},
 };
 
+const BATCH_SIZE_KEY: &str = "batch size";
+const BATCH_BYTES_KEY: &str = "batch bytes";
+
+/// The memory footprint targeted by adaptive batching of UDFs, for the input and output of a batch.
+const TARGET_BATCH_SIZE_BYTES: usize = 256 * 1024 * 1024;
+
+/// Lifecycle hooks of the classes of UDFs, see `ClsBase` in `daft/udf/udf_v2.py`.
+/// UDFs which run in a process or an actor call these hooks in the process or actor instead.
+#[cfg(feature = "python")]
+const OPEN_PARTITION_HOOK: &str = "_daft_open_partition";
+#[cfg(feature = "python")]
+const CLOSE_PARTITION_HOOK: &str = "_daft_close_partition";
+#[cfg(feature = "python")]
+const TEARDOWN_HOOK: &str = "_daft_teardown";
+
 /// Given an expression, extract the indexes of used columns and remap them to
 /// new indexes from 0...count-1, where count is the # of used columns.
 ///
@@ -97,6 +114,9 @@ struct UdfRuntimeStats {
     cpu_us: Counter,
     rows_in: Counter,
     rows_out: Counter,
+    /// The number of rows and size in bytes of the latest batch, which show the choice of dynamic batching.
+    batch_size: Gauge,
+    batch_bytes: Gauge,
     custom_counters: Mutex<HashMap<Arc<str>, Counter>>,
 }
 
@@ -107,7 +127,7 @@ impl RuntimeStats for UdfRuntimeStats {
 
     fn build_snapshot(&self, ordering: Ordering) -> StatSnapshot {
         let counters = self.custom_counters.lock().unwrap();
-        let mut entries = SmallVec::with_capacity(3 + counters.len());
+        let mut entries = SmallVec::with_capacity(5 + counters.len());
 
         entries.push((
             CPU_US_KEY.into(),
@@ -118,6 +138,14 @@ impl RuntimeStats for UdfRuntimeStats {
             ROWS_OUT_KEY.into(),
             Stat::Count(self.rows_out.load(ordering)),
         ));
+        let batch_size = self.batch_size.load(ordering);
+        if !batch_size.is_nan() {
+            entries.push((BATCH_SIZE_KEY.into(), Stat::Count(batch_size as u64)));
+            entries.push((
+                BATCH_BYTES_KEY.into(),
+                Stat::Bytes(self.batch_bytes.load(ordering) as u64),
+            ));
+        }
 
         for (name, counter) in counters.iter() {
             entries.push((name.clone().into(), Stat::Count(counter.load(ordering))));
@@ -148,12 +176,21 @@ impl UdfRuntimeStats {
             cpu_us: Counter::new(&meter, CPU_US_KEY.into(), None),
             rows_in: Counter::new(&meter, ROWS_IN_KEY.into(), None),
             rows_out: Counter::new(&meter, ROWS_OUT_KEY.into(), None),
+            batch_size: Gauge::new(&meter, BATCH_SIZE_KEY.into(), None),
+            batch_bytes: Gauge::new(&meter, BATCH_BYTES_KEY.into(), None),
             custom_counters: Mutex::new(HashMap::new()),
             node_kv,
             meter,
         }
     }
 
+    fn update_batch(&self, input: &MicroPartition) {
+        self.batch_size
+            .update(input.len() as f64, self.node_kv.as_slice());
+        self.batch_bytes
+            .update(input.size_bytes() as f64, self.node_kv.as_slice());
+    }
+
     fn update_metrics(&self, metrics: OperatorMetrics) {
         let mut counters = self.custom_counters.lock().unwrap();
         for (name, counter_data) in metrics {
@@ -372,6 +409,31 @@ pub(crate) struct UdfState {
     worker_idx: usize,
     #[cfg(feature = "python")]
     udf_handle: UdfHandle,
+    /// Whether the partition hooks of the UDFs were opened on this thread, and need to be closed.
+    #[cfg(feature = "python")]
+    partition_open: bool,
+}
+
+#[cfg(feature = "python")]
+impl UdfState {
+    fn open_partition(&mut self) -> DaftResult<()> {
+        if matches!(self.udf_handle, UdfHandle::Thread) && !self.partition_open {
+            call_udf_hook(self.expr.inner(), OPEN_PARTITION_HOOK)?;
+            self.partition_open = true;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "python")]
+impl Drop for UdfState {
+    fn drop(&mut self) {
+        if self.partition_open
+            && let Err(e) = call_udf_hook(self.expr.inner(), CLOSE_PARTITION_HOOK)
+        {
+            log::error!("Error closing the partition of UDF: {}", e);
+        }
+    }
 }
 
 pub(crate) struct UdfOperator {
@@ -451,6 +513,17 @@ impl UdfOperator {
     }
 }
 
+// The instances of UDF classes which run on threads are shared by the workers of the operator, so they are torn down
+// once all of the workers are done.
+#[cfg(feature = "python")]
+impl Drop for UdfOperator {
+    fn drop(&mut self) {
+        if let Err(e) = call_udf_hook(self.expr.inner(), TEARDOWN_HOOK) {
+            log::error!("Error tearing down UDF: {}", e);
+        }
+    }
+}
+
 impl IntermediateOperator for UdfOperator {
     type State = UdfState;
     type BatchingStrategy = DynBatchingStrategy;
@@ -469,12 +542,14 @@ impl IntermediateOperator for UdfOperator {
             .as_any_arc()
             .downcast::<UdfRuntimeStats>()
             .expect("Expected UdfRuntimeStats in task_spawner.runtime_stats");
+        runtime_stats.update_batch(&input);
         let params = self.params.clone();
         let fut = task_spawner.spawn_with_memory_request(
             memory_request,
             async move {
                 #[cfg(feature = "python")]
                 {
+                    state.open_partition()?;
                     let result = state.udf_handle.eval_input(
                         &mut state.expr,
                         &params,
@@ -592,6 +667,7 @@ impl IntermediateOperator for UdfOperator {
                 expr: self.expr.clone(),
                 worker_idx: worker_count,
                 udf_handle,
+                partition_open: false,
             })
         }
         #[cfg(not(feature = "python"))]
@@ -611,12 +687,41 @@ impl IntermediateOperator for UdfOperator {
             .map(MorselSizeRequirement::Strict)
     }
 
+    fn dispatch_spawner(
+        &self,
+        batch_manager: Arc<BatchManager<Self::BatchingStrategy>>,
+        maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        // Unlike the default, batches are resized by the batching strategy even if order isn't maintained.
+        if maintain_order {
+            Arc::new(RoundRobinDispatcher::new(batch_manager))
+        } else {
+            Arc::new(DynamicUnorderedDispatcher::new(batch_manager))
+        }
+    }
+
     fn batching_strategy(&self) -> DaftResult<Self::BatchingStrategy> {
         let cfg = daft_context::get_context().execution_config();
 
         Ok(if cfg.enable_dynamic_batching {
             match cfg.dynamic_batching_strategy.as_str() {
-                "latency_constrained" | "auto" => {
+                // The cost of a row of a UDF varies widely with its inputs, e.g. for multimodal data, so batches are
+                // sized to stay within both a latency and a memory target. A batch size set on the UDF is the
+                // largest batch it is given.
+                "auto" => AdaptiveBatchingStrategy {
+                    target_batch_latency: Duration::from_millis(5000),
+                    target_batch_size_bytes: TARGET_BATCH_SIZE_BYTES,
+                    initial_batch_size: 16,
+                    max_growth_factor: 2,
+                    b_min: 1,
+                    b_max: self
+                        .params
+                        .udf_properties
+                        .batch_size
+                        .unwrap_or(cfg.default_morsel_size),
+                }
+                .into(),
+                "latency_constrained" => {
                     // TODO: allow udf to accept a min/max batch size instead of just a strict batch size.
                     let reqs = self.morsel_size_requirement().unwrap_or_default();
                     let MorselSizeRequirement::Flexible(min_batch_size, max_batch_size) = reqs
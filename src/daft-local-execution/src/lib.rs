@@ -20,8 +20,10 @@ use std::{
 
 use arc_swap::ArcSwap;
 use common_error::{DaftError, DaftResult};
+use common_metrics::{NodeID, ops::NodeInfo};
 use common_runtime::{RuntimeRef, RuntimeTask};
 use console::style;
+use daft_local_plan::LOGICAL_NODE_ID_KEY;
 use resource_manager::MemoryManager;
 pub use run::{ExecutionEngineResult, NativeExecutor};
 use runtime_stats::{RuntimeStats, RuntimeStatsManagerHandle, TimedFuture};
@@ -150,11 +152,10 @@ impl ExecutionRuntimeContext {
     pub fn spawn(
         &mut self,
         task: impl std::future::Future<Output = DaftResult<()>> + Send + 'static,
-        node_name: &str,
+        node_info: &NodeInfo,
     ) {
-        let node_name = node_name.to_string();
-        self.worker_set
-            .spawn(task.with_context(|_| PipelineExecutionSnafu { node_name }));
+        let context = PipelineExecutionSnafu::for_node(node_info);
+        self.worker_set.spawn(task.with_context(|_| context));
     }
 
     pub async fn join_next(&mut self) -> Option<Result<crate::Result<()>, Error>> {
@@ -317,15 +318,33 @@ pub enum Error {
         source: DaftError,
         plan_name: String,
     },
-    #[snafu(display("Error when running pipeline node {}: {}", node_name, source))]
+    #[snafu(display(
+        "Error when running pipeline node {} ({}): {}",
+        node_name,
+        node_id,
+        source
+    ))]
     PipelineExecutionError {
         source: DaftError,
         node_name: String,
+        node_id: NodeID,
+        logical_node_id: Option<String>,
     },
     #[snafu(display("ValueError: {}", message))]
     ValueError { message: String },
 }
 
+impl PipelineExecutionSnafu<String, NodeID, Option<String>> {
+    /// Context for the errors of a pipeline node.
+    pub(crate) fn for_node(node_info: &NodeInfo) -> Self {
+        Self {
+            node_name: node_info.name.to_string(),
+            node_id: node_info.id,
+            logical_node_id: node_info.context.get(LOGICAL_NODE_ID_KEY).cloned(),
+        }
+    }
+}
+
 impl From<Error> for DaftError {
     fn from(err: Error) -> Self {
         match err {
@@ -333,9 +352,21 @@ impl From<Error> for DaftError {
                 log::error!("Error creating pipeline from {}", plan_name);
                 source
             }
-            Error::PipelineExecutionError { source, node_name } => {
+            Error::PipelineExecutionError {
+                source,
+                node_name,
+                node_id,
+                logical_node_id,
+            } => {
                 log::error!("Error when running pipeline node {}", node_name);
-                source
+                // Name the operator which failed, by the ids it has in the plans shown by `explain`.
+                let context = match logical_node_id {
+                    Some(logical_node_id) => format!(
+                        "In {node_name} (pipeline node {node_id}, logical plan node {logical_node_id})"
+                    ),
+                    None => format!("In {node_name} (pipeline node {node_id})"),
+                };
+                source.with_context(context)
             }
             Error::ValueError { message } => Self::ValueError(message),
             _ => Self::External(err.into()),
@@ -4,6 +4,8 @@ use common_daft_config::DaftExecutionConfig;
 use common_display::{
     DisplayLevel,
     ascii::fmt_tree_gitstyle,
+    dot::{DotDisplayOptions, to_dot},
+    graph::{repr_json_without_children, to_graph_json},
     mermaid::{MermaidDisplayVisitor, SubgraphOptions},
     tree::TreeDisplay,
 };
@@ -14,20 +16,23 @@ use daft_core::{
     join::JoinSide,
     prelude::{Schema, SchemaRef},
 };
-use daft_dsl::{common_treenode::ConcreteTreeNode, join::get_common_join_cols};
+use daft_dsl::{
+    common_treenode::ConcreteTreeNode, expr::bound_expr::BoundExpr, join::get_common_join_cols,
+};
 use daft_local_plan::{
     CommitWrite, Concat, CrossJoin, Dedup, EmptyScan, Explode, Filter, GlobScan, HashAggregate,
     HashJoin, InMemoryScan, IntoBatches, Limit, LocalNodeContext, LocalPhysicalPlan,
-    MonotonicallyIncreasingId, PhysicalWrite, Pivot, Project, Sample, Sort, SortMergeJoin, TopN,
-    UDFProject, UnGroupedAggregate, Unpivot, VLLMProject, WindowOrderByOnly,
-    WindowPartitionAndDynamicFrame, WindowPartitionAndOrderBy, WindowPartitionOnly,
+    MonotonicallyIncreasingId, PhysicalWrite, Pivot, Project, Resample, Sample, SamplingMethod,
+    Sort, SortMergeJoin, SortedLookupJoin, TopN, UDFProject, UnGroupedAggregate, Unpivot,
+    VLLMProject, WindowOrderByOnly, WindowPartitionAndDynamicFrame, WindowPartitionAndOrderBy,
+    WindowPartitionOnly,
 };
 use daft_logical_plan::{JoinType, stats::StatsState};
 use daft_micropartition::{
     MicroPartition, MicroPartitionRef,
     partitioning::{MicroPartitionSet, PartitionSetCache},
 };
-use daft_scan::ScanTaskRef;
+use daft_scan::{ScanTask, ScanTaskRef};
 use daft_writers::make_physical_writer_factory;
 use indexmap::IndexSet;
 use snafu::ResultExt;
@@ -40,8 +45,8 @@ use crate::{
         distributed_actor_pool_project::DistributedActorPoolProjectOperator,
         explode::ExplodeOperator, filter::FilterOperator,
         inner_hash_join_probe::InnerHashJoinProbeOperator, intermediate_op::IntermediateNode,
-        into_batches::IntoBatchesOperator, project::ProjectOperator, udf::UdfOperator,
-        unpivot::UnpivotOperator,
+        into_batches::IntoBatchesOperator, project::ProjectOperator,
+        sorted_lookup_join::SortedLookupJoinOperator, udf::UdfOperator, unpivot::UnpivotOperator,
     },
     runtime_stats::RuntimeStats,
     sinks::{
@@ -55,7 +60,9 @@ use crate::{
         join_collect::JoinCollectSink,
         pivot::PivotSink,
         repartition::RepartitionSink,
+        resample::ResampleSink,
         sort::SortSink,
+        sorted_lookup_build::SortedLookupBuildSink,
         top_n::TopNSink,
         window_order_by_only::WindowOrderByOnlySink,
         window_partition_and_dynamic_frame::WindowPartitionAndDynamicFrameSink,
@@ -67,10 +74,10 @@ use crate::{
     state_bridge::BroadcastStateBridge,
     streaming_sink::{
         anti_semi_hash_join_probe::AntiSemiProbeSink, async_udf::AsyncUdfSink,
-        base::StreamingSinkNode, concat::ConcatSink, limit::LimitSink,
-        monotonically_increasing_id::MonotonicallyIncreasingIdSink,
+        base::StreamingSinkNode, concat::ConcatSink, cross_join_sample::CrossJoinSampleSink,
+        limit::LimitSink, monotonically_increasing_id::MonotonicallyIncreasingIdSink,
         outer_hash_join_probe::OuterHashJoinProbeSink, sample::SampleSink,
-        sort_merge_join_probe::SortMergeJoinProbe, vllm::VLLMSink,
+        sort_merge_join::SortMergeJoinNode, vllm::VLLMSink,
     },
 };
 
@@ -328,6 +335,33 @@ pub fn viz_pipeline_ascii(root: &dyn PipelineNode, simple: bool) -> String {
     s
 }
 
+pub fn viz_pipeline_dot(root: &dyn PipelineNode, options: DotDisplayOptions) -> String {
+    to_dot(root.as_tree_display(), options)
+}
+
+/// Describes a pipeline node in the graph of its pipeline, along with its output schema and estimated stats.
+pub(crate) fn pipeline_node_graph_details(
+    node: &dyn PipelineNode,
+    plan_stats: &StatsState,
+) -> serde_json::Value {
+    let mut details = repr_json_without_children(node.as_tree_display());
+    details["schema"] = serde_json::json!(node.node_info().output_schema.fields());
+    details["stats"] = match plan_stats {
+        StatsState::Materialized(stats) => serde_json::json!(stats.approx_stats),
+        StatsState::NotMaterialized => serde_json::Value::Null,
+    };
+    details
+}
+
+pub fn viz_pipeline_graph_json(root: &dyn PipelineNode, simple: bool) -> serde_json::Value {
+    let level = if simple {
+        DisplayLevel::Compact
+    } else {
+        DisplayLevel::Default
+    };
+    to_graph_json(root.as_tree_display(), level)
+}
+
 pub fn translate_physical_plan_to_pipeline(
     physical_plan: &LocalPhysicalPlan,
     psets: &(impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized),
@@ -350,7 +384,7 @@ fn physical_plan_to_pipeline(
 ) -> crate::Result<Box<dyn PipelineNode>> {
     use daft_local_plan::PhysicalScan;
 
-    use crate::sources::scan_task::ScanTaskSource;
+    use crate::sources::{custom_scan::CustomScanSource, scan_task::ScanTaskSource};
     let pipeline_node: Box<dyn PipelineNode> = match physical_plan {
         LocalPhysicalPlan::PlaceholderScan(_) => {
             panic!("PlaceholderScan should not be converted to a pipeline node")
@@ -377,15 +411,22 @@ fn physical_plan_to_pipeline(
             stats_state,
             context,
         }) => {
-            let scan_tasks = scan_tasks
+            // Scan tasks of sources other than Daft's own are read by the scan tasks themselves.
+            let scan_task_source = if scan_tasks
                 .iter()
-                .map(|task| task.clone().as_any_arc().downcast().unwrap())
-                .collect::<Vec<ScanTaskRef>>();
-
-            let scan_task_source =
-                ScanTaskSource::new(scan_tasks, pushdowns.clone(), schema.clone(), cfg);
+                .all(|task| task.as_any().downcast_ref::<ScanTask>().is_some())
+            {
+                let scan_tasks = scan_tasks
+                    .iter()
+                    .map(|task| task.clone().as_any_arc().downcast().unwrap())
+                    .collect::<Vec<ScanTaskRef>>();
+                ScanTaskSource::new(scan_tasks, pushdowns.clone(), schema.clone(), cfg).arced()
+            } else {
+                CustomScanSource::new(scan_tasks.to_vec(), pushdowns.clone(), schema.clone())
+                    .arced()
+            };
             SourceNode::new(
-                scan_task_source.arced(),
+                scan_task_source,
                 stats_state.clone(),
                 ctx,
                 schema.clone(),
@@ -653,6 +694,49 @@ fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::Sample(Sample {
+            input,
+            sampling_method: SamplingMethod::Size(size),
+            with_replacement,
+            seed,
+            schema,
+            stats_state,
+            context,
+        }) if matches!(input.as_ref(), LocalPhysicalPlan::CrossJoin(..)) => {
+            // Sample positions of the cartesian product instead of computing the cross join.
+            let LocalPhysicalPlan::CrossJoin(CrossJoin { left, right, .. }) = input.as_ref() else {
+                unreachable!("Input of cross join sample must be a cross join")
+            };
+            let left_node = physical_plan_to_pipeline(left, psets, cfg, ctx)?;
+            let right_node = physical_plan_to_pipeline(right, psets, cfg, ctx)?;
+            let state_bridge = BroadcastStateBridge::new();
+            let collect_node = BlockingSinkNode::new(
+                Arc::new(JoinCollectSink::new(state_bridge.clone())),
+                right_node,
+                right.get_stats_state().clone(),
+                ctx,
+                right.schema().clone(),
+                context,
+            )
+            .boxed();
+            StreamingSinkNode::new(
+                Arc::new(CrossJoinSampleSink::new(
+                    *size,
+                    *with_replacement,
+                    *seed,
+                    left.schema().clone(),
+                    right.schema().clone(),
+                    schema.clone(),
+                    state_bridge,
+                )),
+                vec![collect_node, left_node],
+                stats_state.clone(),
+                ctx,
+                schema.clone(),
+                context,
+            )
+            .boxed()
+        }
         LocalPhysicalPlan::Sample(Sample {
             input,
             sampling_method,
@@ -682,7 +766,7 @@ fn physical_plan_to_pipeline(
             stats_state,
             context,
         }) => {
-            let filter_op = FilterOperator::new(predicate.clone());
+            let filter_op = FilterOperator::new(predicate.clone(), schema);
             let child_node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
             IntermediateNode::new(
                 Arc::new(filter_op),
@@ -717,11 +801,14 @@ fn physical_plan_to_pipeline(
         LocalPhysicalPlan::Explode(Explode {
             input,
             to_explode,
+            index_column,
+            pad_mismatched,
             schema,
             stats_state,
             context,
         }) => {
-            let explode_op = ExplodeOperator::new(to_explode.clone());
+            let explode_op =
+                ExplodeOperator::new(to_explode.clone(), index_column.clone(), *pad_mismatched);
             let child_node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
             IntermediateNode::new(
                 Arc::new(explode_op),
@@ -742,7 +829,7 @@ fn physical_plan_to_pipeline(
             context,
         }) => {
             let (offset, limit) = (*offset, *limit);
-            let sink = LimitSink::new(limit as usize, offset.map(|x| x as usize));
+            let sink = LimitSink::new(limit.map(|x| x as usize), offset.map(|x| x as usize));
             let child_node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
             StreamingSinkNode::new(
                 Arc::new(sink),
@@ -900,6 +987,27 @@ fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::Resample(Resample {
+            input,
+            num_keys,
+            every,
+            fill,
+            schema,
+            stats_state,
+            context,
+        }) => {
+            let child_node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
+            let resample_sink = ResampleSink::new(*num_keys, *every, *fill);
+            BlockingSinkNode::new(
+                Arc::new(resample_sink),
+                child_node,
+                stats_state.clone(),
+                ctx,
+                schema.clone(),
+                context,
+            )
+            .boxed()
+        }
         LocalPhysicalPlan::Sort(Sort {
             input,
             sort_by,
@@ -983,6 +1091,7 @@ fn physical_plan_to_pipeline(
             left_on,
             right_on,
             null_equals_null,
+            residual,
             join_type,
             build_on_left,
             schema,
@@ -1119,14 +1228,17 @@ fn physical_plan_to_pipeline(
 
                 // we should move to a builder pattern
                 let probe_state_bridge = BroadcastStateBridge::new();
+                // Anti/semi joins that probe without a bitmap only need to know whether a match exists,
+                // unless the matches have to be checked against a residual predicate.
                 let track_indices = if matches!(join_type, JoinType::Anti | JoinType::Semi) {
-                    build_on_left
+                    build_on_left || residual.is_some()
                 } else {
                     true
                 };
                 let build_sink = HashJoinBuildSink::new(
                     key_schema,
                     build_on.clone(),
+                    build_schema.clone(),
                     null_equals_null.clone(),
                     track_indices,
                     probe_state_bridge.clone(),
@@ -1148,6 +1260,7 @@ fn physical_plan_to_pipeline(
                     JoinType::Anti | JoinType::Semi => Ok(StreamingSinkNode::new(
                         Arc::new(AntiSemiProbeSink::new(
                             probe_on.clone(),
+                            residual.clone(),
                             join_type,
                             schema,
                             probe_state_bridge,
@@ -1163,6 +1276,7 @@ fn physical_plan_to_pipeline(
                     JoinType::Inner => Ok(IntermediateNode::new(
                         Arc::new(InnerHashJoinProbeOperator::new(
                             probe_on.clone(),
+                            residual.clone(),
                             left_schema,
                             right_schema,
                             build_on_left,
@@ -1181,6 +1295,7 @@ fn physical_plan_to_pipeline(
                         Ok(StreamingSinkNode::new(
                             Arc::new(OuterHashJoinProbeSink::new(
                                 probe_on.clone(),
+                                residual.clone(),
                                 left_schema,
                                 right_schema,
                                 *join_type,
@@ -1258,6 +1373,7 @@ fn physical_plan_to_pipeline(
                     schema.clone(),
                     stream_side,
                     state_bridge,
+                    cfg.default_morsel_size,
                 )),
                 vec![collect_node, stream_child_node],
                 stats_state.clone(),
@@ -1273,34 +1389,90 @@ fn physical_plan_to_pipeline(
             left_on,
             right_on,
             join_type,
+            left_is_sorted,
+            right_is_sorted,
+            schema,
+            stats_state,
+            context,
+        }) => {
+            // Sides that aren't already sorted on their join keys are sorted in ascending order with nulls last,
+            // which is the order the merge expects
+            let sorted_input = |input: &LocalPhysicalPlan,
+                                keys: &[BoundExpr],
+                                is_sorted: bool|
+             -> crate::Result<Box<dyn PipelineNode>> {
+                let node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
+                if is_sorted {
+                    return Ok(node);
+                }
+                Ok(BlockingSinkNode::new(
+                    Arc::new(SortSink::new(
+                        keys.to_vec(),
+                        vec![false; keys.len()],
+                        vec![false; keys.len()],
+                    )),
+                    node,
+                    input.get_stats_state().clone(),
+                    ctx,
+                    input.schema().clone(),
+                    context,
+                )
+                .boxed())
+            };
+            let left_node = sorted_input(left, left_on, *left_is_sorted)?;
+            let right_node = sorted_input(right, right_on, *right_is_sorted)?;
+
+            SortMergeJoinNode::new(
+                left_on.clone(),
+                right_on.clone(),
+                *join_type,
+                left_node,
+                right_node,
+                stats_state.clone(),
+                ctx,
+                schema.clone(),
+                context,
+            )
+            .boxed()
+        }
+        LocalPhysicalPlan::SortedLookupJoin(SortedLookupJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            join_type,
+            right_is_sorted,
             schema,
             stats_state,
             context,
         }) => {
-            let left_schema = left.schema().clone();
             let left_node = physical_plan_to_pipeline(left, psets, cfg, ctx)?;
             let right_node = physical_plan_to_pipeline(right, psets, cfg, ctx)?;
 
             let state_bridge = BroadcastStateBridge::new();
-            let collect_node = BlockingSinkNode::new(
-                Arc::new(JoinCollectSink::new(state_bridge.clone())),
-                left_node,
-                left.get_stats_state().clone(),
+            let build_node = BlockingSinkNode::new(
+                Arc::new(SortedLookupBuildSink::new(
+                    right_on.clone(),
+                    *right_is_sorted,
+                    right.schema().clone(),
+                    state_bridge.clone(),
+                )),
+                right_node,
+                right.get_stats_state().clone(),
                 ctx,
-                left.schema().clone(),
+                right.schema().clone(),
                 context,
             )
             .boxed();
 
-            StreamingSinkNode::new(
-                Arc::new(SortMergeJoinProbe::new(
+            IntermediateNode::new(
+                Arc::new(SortedLookupJoinOperator::new(
                     left_on.clone(),
-                    right_on.clone(),
-                    left_schema,
                     *join_type,
+                    schema.clone(),
                     state_bridge,
                 )),
-                vec![collect_node, right_node],
+                vec![build_node, left_node],
                 stats_state.clone(),
                 ctx,
                 schema.clone(),
@@ -1317,7 +1489,12 @@ fn physical_plan_to_pipeline(
             ..
         }) => {
             let child_node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
-            let writer_factory = make_physical_writer_factory(file_info, input.schema(), cfg)
+            let file_info = match (ctx.context.get("task_id"), ctx.context.get("task_attempt")) {
+                (Some(task_id), Some(attempt)) => file_info.for_task_attempt(task_id, attempt),
+                _ => None,
+            }
+            .unwrap_or_else(|| file_info.clone());
+            let writer_factory = make_physical_writer_factory(&file_info, input.schema(), cfg)
                 .with_context(|_| PipelineCreationSnafu {
                     plan_name: physical_plan.name(),
                 })?;
@@ -1351,12 +1528,17 @@ fn physical_plan_to_pipeline(
             data_schema,
             file_schema,
             file_info,
+            staged_write_id,
             stats_state,
             context,
         }) => {
             let child_node = physical_plan_to_pipeline(input, psets, cfg, ctx)?;
-            let write_sink =
-                CommitWriteSink::new(data_schema.clone(), file_schema.clone(), file_info.clone());
+            let write_sink = CommitWriteSink::new(
+                data_schema.clone(),
+                file_schema.clone(),
+                file_info.clone(),
+                staged_write_id.clone(),
+            );
             BlockingSinkNode::new(
                 Arc::new(write_sink),
                 child_node,
@@ -7,7 +7,7 @@ use std::{
 };
 
 use common_daft_config::DaftExecutionConfig;
-use common_display::{DisplayLevel, mermaid::MermaidDisplayOptions};
+use common_display::{DisplayLevel, dot::DotDisplayOptions, mermaid::MermaidDisplayOptions};
 use common_error::DaftResult;
 use common_metrics::StatSnapshot;
 use common_runtime::RuntimeTask;
@@ -19,6 +19,7 @@ use daft_micropartition::{
     MicroPartition, MicroPartitionRef,
     partitioning::{InMemoryPartitionSetCache, MicroPartitionSet, PartitionSetCache},
 };
+use daft_stats::pruning::{PruneDecision, take_pruning_decisions};
 use futures::Stream;
 use tokio::{runtime::Handle, sync::Mutex};
 use tokio_util::sync::CancellationToken;
@@ -36,7 +37,8 @@ use crate::{
     channel::{Receiver, create_channel},
     pipeline::{
         RelationshipInformation, RuntimeContext, get_pipeline_relationship_mapping,
-        translate_physical_plan_to_pipeline, viz_pipeline_ascii, viz_pipeline_mermaid,
+        translate_physical_plan_to_pipeline, viz_pipeline_ascii, viz_pipeline_dot,
+        viz_pipeline_graph_json, viz_pipeline_mermaid,
     },
     resource_manager::get_or_init_memory_manager,
     runtime_stats::{QueryEndState, RuntimeStatsManager},
@@ -133,6 +135,10 @@ impl PyNativeExecutor {
         Ok(py_execution_result.into_pyobject(py)?.into_any())
     }
 
+    pub fn cancel(&self) {
+        self.executor.cancel();
+    }
+
     #[staticmethod]
     pub fn repr_ascii(
         logical_plan_builder: &PyLogicalPlanBuilder,
@@ -159,6 +165,33 @@ impl PyNativeExecutor {
         ))
     }
 
+    #[staticmethod]
+    #[pyo3(signature = (logical_plan_builder, cfg, simple=false, bottom_up=false))]
+    pub fn repr_dot(
+        logical_plan_builder: &PyLogicalPlanBuilder,
+        cfg: PyDaftExecutionConfig,
+        simple: bool,
+        bottom_up: bool,
+    ) -> PyResult<String> {
+        Ok(NativeExecutor::repr_dot(
+            &logical_plan_builder.builder,
+            cfg.config,
+            DotDisplayOptions { simple, bottom_up },
+        )?)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (logical_plan_builder, cfg, simple=false))]
+    pub fn repr_graph_json(
+        logical_plan_builder: &PyLogicalPlanBuilder,
+        cfg: PyDaftExecutionConfig,
+        simple: bool,
+    ) -> PyResult<String> {
+        let graph =
+            NativeExecutor::repr_graph_json(&logical_plan_builder.builder, cfg.config, simple)?;
+        Ok(serde_json::to_string(&graph).map_err(common_error::DaftError::from)?)
+    }
+
     #[staticmethod]
     pub fn get_relationship_info(
         logical_plan_builder: &PyLogicalPlanBuilder,
@@ -196,6 +229,11 @@ impl NativeExecutor {
         self
     }
 
+    /// Stops the queries which this executor is running. Their results end after the partitions already produced.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
     pub fn run(
         &self,
         local_physical_plan: &LocalPhysicalPlanRef,
@@ -226,7 +264,8 @@ impl NativeExecutor {
 
         // Spawn execution on the global runtime - returns immediately
         let handle = get_global_runtime();
-        let stats_manager = RuntimeStatsManager::try_new(handle, &pipeline, subscribers, query_id)?;
+        let stats_manager =
+            RuntimeStatsManager::try_new(handle, &pipeline, subscribers, query_id.clone())?;
         let task = async move {
             let stats_manager_handle = stats_manager.handle();
             let execution_task = async {
@@ -285,6 +324,30 @@ impl NativeExecutor {
                         Default::default()
                     )
                 )?;
+
+                let pruned = take_pruning_decisions(&query_id);
+                if !pruned.decisions.is_empty() {
+                    let count = |is_kind: fn(&PruneDecision) -> bool| {
+                        pruned.decisions.iter().filter(|d| is_kind(d)).count()
+                    };
+                    let files = count(|d| d.row_group.is_none());
+                    let row_groups = count(|d| d.row_group.is_some() && d.page.is_none());
+                    let pages = count(|d| d.page.is_some());
+                    writeln!(
+                        file,
+                        "\n## Pruned scans\n\nSkipped {files} files, {row_groups} row groups and {pages} pages without reading them.\n"
+                    )?;
+                    for decision in pruned.decisions {
+                        writeln!(file, "- {decision}")?;
+                    }
+                    if pruned.num_dropped > 0 {
+                        writeln!(
+                            file,
+                            "- ... and {} more, which weren't recorded",
+                            pruned.num_dropped
+                        )?;
+                    }
+                }
             }
             flush_opentelemetry_providers();
             result.map(|()| final_stats)
@@ -343,6 +406,42 @@ impl NativeExecutor {
             options.subgraph_options,
         )
     }
+    fn repr_dot(
+        logical_plan_builder: &LogicalPlanBuilder,
+        cfg: Arc<DaftExecutionConfig>,
+        options: DotDisplayOptions,
+    ) -> DaftResult<String> {
+        let logical_plan = logical_plan_builder.build();
+        let physical_plan = translate(&logical_plan)?;
+        let ctx = RuntimeContext::new();
+        let pipeline_node = translate_physical_plan_to_pipeline(
+            &physical_plan,
+            &InMemoryPartitionSetCache::empty(),
+            &cfg,
+            &ctx,
+        )?;
+
+        Ok(viz_pipeline_dot(pipeline_node.as_ref(), options))
+    }
+
+    fn repr_graph_json(
+        logical_plan_builder: &LogicalPlanBuilder,
+        cfg: Arc<DaftExecutionConfig>,
+        simple: bool,
+    ) -> DaftResult<serde_json::Value> {
+        let logical_plan = logical_plan_builder.build();
+        let physical_plan = translate(&logical_plan)?;
+        let ctx = RuntimeContext::new();
+        let pipeline_node = translate_physical_plan_to_pipeline(
+            &physical_plan,
+            &InMemoryPartitionSetCache::empty(),
+            &cfg,
+            &ctx,
+        )?;
+
+        Ok(viz_pipeline_graph_json(pipeline_node.as_ref(), simple))
+    }
+
     fn get_relationship_info(
         logical_plan_builder: &LogicalPlanBuilder,
         cfg: Arc<DaftExecutionConfig>,
@@ -210,10 +210,13 @@ impl RuntimeStatsManager {
                             }
                         }
                         snapshot_container.clear();
+                        emit_memory_usage(&subscribers).await;
                     }
                 }
             }
 
+            // Sample memory once more so that queries which finish within a tick still report it.
+            emit_memory_usage(&subscribers).await;
             for subscriber in subscribers {
                 if let Err(e) = subscriber.finish().await {
                     log::error!("Failed to flush subscriber: {}", e);
@@ -251,6 +254,23 @@ impl RuntimeStatsManager {
     }
 }
 
+async fn emit_memory_usage(subscribers: &[Box<dyn RuntimeStatsSubscriber>]) {
+    let Some(bytes) = common_system_info::process_memory_usage() else {
+        return;
+    };
+    for res in future::join_all(
+        subscribers
+            .iter()
+            .map(|subscriber| subscriber.handle_memory_usage(bytes)),
+    )
+    .await
+    {
+        if let Err(e) = res {
+            log::error!("Failed to handle memory usage: {}", e);
+        }
+    }
+}
+
 #[pin_project::pin_project]
 pub struct TimedFuture<F: Future> {
     start: Option<Instant>,
@@ -16,6 +16,10 @@ pub trait RuntimeStatsSubscriber: Send + Sync + std::fmt::Debug {
     async fn finalize_node(&self, node_id: NodeID) -> DaftResult<()>;
     /// Called each time the manager ticks and when a node finishes.
     async fn handle_event(&self, events: &[(NodeID, StatSnapshot)]) -> DaftResult<()>;
+    /// Called with the resident memory of the process each time the manager ticks.
+    async fn handle_memory_usage(&self, _bytes: u64) -> DaftResult<()> {
+        Ok(())
+    }
     /// Called when the entire pipeline finishes.
     async fn finish(self: Box<Self>) -> DaftResult<()>;
 }
@@ -55,6 +55,12 @@ impl RuntimeStatsSubscriber for SubscriberWrapper {
         Ok(())
     }
 
+    async fn handle_memory_usage(&self, bytes: u64) -> DaftResult<()> {
+        self.inner
+            .on_exec_memory_usage(self.query_id.clone(), bytes)
+            .await
+    }
+
     async fn finish(self: Box<Self>) -> DaftResult<()> {
         self.inner.on_exec_end(self.query_id.clone()).await?;
         Ok(())
@@ -6,7 +6,7 @@ use common_error::DaftResult;
 use common_metrics::ops::{NodeCategory, NodeInfo, NodeType};
 use common_runtime::{get_compute_pool_num_threads, get_compute_runtime};
 use daft_core::prelude::SchemaRef;
-use daft_local_plan::LocalNodeContext;
+use daft_local_plan::{LOGICAL_NODE_ID_KEY, LocalNodeContext};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use tracing::{info_span, instrument};
@@ -15,7 +15,9 @@ use crate::{
     ExecutionRuntimeContext, ExecutionTaskSpawner, OperatorOutput, TaskSet,
     channel::{Receiver, create_channel},
     dispatcher::{DispatchSpawner, UnorderedDispatcher},
-    pipeline::{MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext},
+    pipeline::{
+        MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext, pipeline_node_graph_details,
+    },
     resource_manager::MemoryManager,
     runtime_stats::{
         CountingSender, DefaultRuntimeStats, InitializingCountingReceiver, RuntimeStats,
@@ -215,10 +217,15 @@ impl<Op: BlockingSink + 'static> TreeDisplay for BlockingSinkNode<Op> {
             "category": "BlockingSink",
             "type": self.op.op_type().to_string(),
             "name": self.name(),
+            "logical_node_id": self.node_info.context.get(LOGICAL_NODE_ID_KEY),
             "children": children,
         })
     }
 
+    fn repr_graph_details(&self) -> serde_json::Value {
+        pipeline_node_graph_details(self, &self.plan_stats)
+    }
+
     fn get_children(&self) -> Vec<&dyn TreeDisplay> {
         vec![self.child.as_tree_display()]
     }
@@ -281,7 +288,7 @@ impl<Op: BlockingSink + 'static> PipelineNode for BlockingSinkNode<Op> {
         );
         runtime_handle.spawn(
             async move { spawned_dispatch_result.spawned_dispatch_task.await? },
-            &self.name(),
+            &self.node_info,
         );
 
         let memory_manager = runtime_handle.memory_manager();
@@ -332,7 +339,7 @@ impl<Op: BlockingSink + 'static> PipelineNode for BlockingSinkNode<Op> {
                 stats_manager.finalize_node(node_id);
                 Ok(())
             },
-            &self.name(),
+            &self.node_info,
         );
         Ok(destination_receiver)
     }
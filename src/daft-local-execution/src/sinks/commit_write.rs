@@ -1,16 +1,22 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use common_error::DaftResult;
 use common_file_formats::{FileFormat, WriteMode};
 use common_metrics::ops::NodeType;
-use daft_core::prelude::SchemaRef;
+use daft_core::prelude::{BooleanArray, IntoSeries, SchemaRef, Utf8Array};
 use daft_dsl::expr::bound_expr::BoundExpr;
-use daft_io::{Error, IOClient, get_io_client, parse_url};
-use daft_logical_plan::OutputFileInfo;
+use daft_io::{Error, IOClient, SourceType, get_io_client, parse_url, strip_file_uri_to_path};
+use daft_logical_plan::{
+    OutputFileInfo,
+    sink_info::{STAGING_DIR_NAME, parse_task_attempt_dir},
+};
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 use daft_writers::WriterFactory;
-use futures::TryStreamExt;
+use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use tracing::{Span, instrument};
 
@@ -40,6 +46,7 @@ pub(crate) struct CommitWriteSink {
     data_schema: SchemaRef,
     file_schema: SchemaRef,
     file_info: OutputFileInfo<BoundExpr>,
+    staged_write_id: Option<String>,
 }
 
 impl CommitWriteSink {
@@ -47,11 +54,13 @@ impl CommitWriteSink {
         data_schema: SchemaRef,
         file_schema: SchemaRef,
         file_info: OutputFileInfo<BoundExpr>,
+        staged_write_id: Option<String>,
     ) -> Self {
         Self {
             data_schema,
             file_schema,
             file_info,
+            staged_write_id,
         }
     }
 }
@@ -79,6 +88,7 @@ impl BlockingSink for CommitWriteSink {
         let data_schema = self.data_schema.clone();
         let file_schema = self.file_schema.clone();
         let file_info = self.file_info.clone();
+        let staged_write_id = self.staged_write_id.clone();
         spawner
             .spawn(
                 async move {
@@ -122,6 +132,19 @@ impl BlockingSink for CommitWriteSink {
                         }
                     }
 
+                    if let Some(write_id) = &staged_write_id {
+                        written_file_path_record_batches = commit_staged_files(
+                            &file_info,
+                            write_id,
+                            written_file_path_record_batches,
+                            get_io_client(
+                                true,
+                                file_info.io_config.clone().unwrap_or_default().into(),
+                            )?,
+                        )
+                        .await?;
+                    }
+
                     if matches!(
                         file_info.write_mode,
                         WriteMode::Overwrite | WriteMode::OverwritePartitions
@@ -186,6 +209,9 @@ impl BlockingSink for CommitWriteSink {
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![];
         lines.push("Commit Write".to_string());
+        if let Some(write_id) = &self.staged_write_id {
+            lines.push(format!("Staged write = {write_id}"));
+        }
         if matches!(self.file_info.write_mode, WriteMode::Overwrite) {
             lines.push("Overwrite".to_string());
         } else if matches!(self.file_info.write_mode, WriteMode::OverwritePartitions) {
@@ -247,6 +273,169 @@ async fn glob_files(
     Ok(out)
 }
 
+/// The maximum number of staged files moved at once when a staged write is committed.
+const MAX_CONCURRENT_MOVES: usize = 32;
+
+/// Moves the files of a staged write from its staging directory to the root directory of the write, and returns
+/// `written_file_path_record_batches` with the paths that the files were moved to.
+///
+/// If a file can't be moved, the files which were already moved are deleted along with the staging directory, so
+/// that a failed commit doesn't leave part of the write behind. The commit isn't atomic, since files are moved one at
+/// a time, so readers of the root directory can see some of the moved files before it finishes.
+async fn commit_staged_files(
+    file_info: &OutputFileInfo<BoundExpr>,
+    write_id: &str,
+    written_file_path_record_batches: Vec<RecordBatch>,
+    io_client: Arc<IOClient>,
+) -> DaftResult<Vec<RecordBatch>> {
+    let (_, root_uri) = parse_url(&file_info.root_dir)?;
+    let scheme = root_uri.split("://").next().unwrap_or("file");
+    let with_scheme = |p: &str| {
+        if p.contains("://") {
+            p.to_string()
+        } else {
+            format!("{}://{}", scheme, p)
+        }
+    };
+    // Written paths may or may not include the scheme, so the staged files are matched by the path of their staging
+    // directory relative to the root directory.
+    let staged_segment = format!("/{STAGING_DIR_NAME}/{write_id}/");
+
+    // A task may be retried after some of its files were written, or its results reported by more than one attempt,
+    // so only the files of the latest attempt of each task are committed. The files of the other attempts are left in
+    // the staging directory, which is deleted once the commit is done.
+    let mut committed_attempts: HashMap<String, usize> = HashMap::new();
+    for rb in &written_file_path_record_batches {
+        let paths = rb.get_column(rb.schema.get_index("path")?).utf8()?;
+        for path in paths.into_iter().flatten() {
+            if let Some(StagedFile {
+                task_attempt: Some((task_id, attempt)),
+                ..
+            }) = StagedFile::parse(path, &staged_segment)
+            {
+                let committed_attempt = committed_attempts.entry(task_id).or_default();
+                *committed_attempt = (*committed_attempt).max(attempt);
+            }
+        }
+    }
+
+    let mut moves = vec![];
+    let mut committed = Vec::with_capacity(written_file_path_record_batches.len());
+    for rb in written_file_path_record_batches {
+        let path_index = rb.schema.get_index("path")?;
+        let paths = rb.get_column(path_index).utf8()?;
+        let mut keep = Vec::with_capacity(paths.len());
+        let mut committed_paths = Vec::with_capacity(paths.len());
+        for path in paths {
+            let staged_file = path.and_then(|path| StagedFile::parse(path, &staged_segment));
+            match (path, staged_file) {
+                (Some(path), Some(staged_file)) => {
+                    let is_committed = staged_file.is_committed(&committed_attempts);
+                    if is_committed {
+                        moves.push((with_scheme(path), with_scheme(&staged_file.committed_path)));
+                    }
+                    keep.push(is_committed);
+                    committed_paths.push(Some(staged_file.committed_path));
+                }
+                (path, None) => {
+                    keep.push(true);
+                    committed_paths.push(path.map(str::to_string));
+                }
+            }
+        }
+        let mut columns = rb.columns().to_vec();
+        columns[path_index] =
+            Utf8Array::from_iter(paths.name(), committed_paths.into_iter()).into_series();
+        let rb = RecordBatch::new_with_size(rb.schema.clone(), columns, rb.len())?;
+        let keep = BooleanArray::from(("keep", keep.as_slice())).into_series();
+        committed.push(rb.mask_filter(&keep)?);
+    }
+
+    let results = futures::stream::iter(moves)
+        .map(|(src, dest)| {
+            let io_client = io_client.clone();
+            async move {
+                io_client
+                    .single_url_move(&src, &dest, None)
+                    .await
+                    .map(|()| dest)
+            }
+        })
+        .buffer_unordered(MAX_CONCURRENT_MOVES)
+        .collect::<Vec<_>>()
+        .await;
+    let (moved, errors): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+
+    let staging_dir = file_info.staging_dir(write_id);
+    if let Some(Err(err)) = errors.into_iter().next() {
+        for dest in moved.into_iter().flatten() {
+            let deleted = match io_client.get_source(&dest).await {
+                Ok(source) => source.delete(&dest, None).await,
+                Err(err) => Err(err),
+            };
+            if let Err(err) = deleted {
+                log::warn!("Failed to delete {dest} after a failed commit: {err}");
+            }
+        }
+        if let Err(err) = io_client.delete_dir(&staging_dir, None).await {
+            log::warn!("Failed to delete the staging directory {staging_dir}: {err}");
+        }
+        return Err(err.into());
+    }
+    io_client.delete_dir(&staging_dir, None).await?;
+
+    // The staging directory of local writes is shared by the writes to the same root directory, so it's only removed
+    // once it's empty.
+    let staging_root = format!(
+        "{}/{STAGING_DIR_NAME}",
+        file_info.root_dir.trim_end_matches('/')
+    );
+    if let (SourceType::File, staging_root) = parse_url(&staging_root)?
+        && let Some(path) = strip_file_uri_to_path(&staging_root)
+    {
+        let _ = tokio::fs::remove_dir(path).await;
+    }
+    Ok(committed)
+}
+
+/// A file written to the staging directory of a staged write.
+#[derive(Debug, PartialEq)]
+struct StagedFile {
+    /// The task id and attempt of the task attempt which wrote the file, if it was written to a task attempt directory.
+    task_attempt: Option<(String, usize)>,
+    /// The path of the file once it's moved to the root directory of the write.
+    committed_path: String,
+}
+
+impl StagedFile {
+    /// Parses the path of a file written to the staging directory of a write, which is either
+    /// `<root>/<staging directory>/<task attempt directory>/<file>` or `<root>/<staging directory>/<file>`.
+    fn parse(path: &str, staged_segment: &str) -> Option<Self> {
+        let (root, staged_path) = path.split_once(staged_segment)?;
+        let task_attempt = staged_path.split_once('/').and_then(|(dir, file)| {
+            let (task_id, attempt) = parse_task_attempt_dir(dir)?;
+            Some(((task_id.to_string(), attempt), file))
+        });
+        Some(match task_attempt {
+            Some((task_attempt, file)) => Self {
+                task_attempt: Some(task_attempt),
+                committed_path: format!("{root}/{file}"),
+            },
+            None => Self {
+                task_attempt: None,
+                committed_path: format!("{root}/{staged_path}"),
+            },
+        })
+    }
+
+    /// Whether the file was written by the committed attempt of its task.
+    fn is_committed(&self, committed_attempts: &HashMap<String, usize>) -> bool {
+        self.task_attempt
+            .as_ref()
+            .is_none_or(|(task_id, attempt)| committed_attempts.get(task_id) == Some(attempt))
+    }
+}
+
 async fn overwrite_files(
     root_uri: String,
     new_files: Vec<String>,
@@ -282,3 +471,31 @@ async fn overwrite_files(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::StagedFile;
+
+    #[test]
+    fn test_parse_staged_file() {
+        let staged_segment = "/_daft_staging/query-1/";
+        assert_eq!(
+            StagedFile::parse(
+                "bucket/out/_daft_staging/query-1/task_7_attempt_2/year=2024/a.parquet",
+                staged_segment
+            ),
+            Some(StagedFile {
+                task_attempt: Some(("7".to_string(), 2)),
+                committed_path: "bucket/out/year=2024/a.parquet".to_string(),
+            })
+        );
+        assert_eq!(
+            StagedFile::parse("/out/_daft_staging/query-1/a.parquet", staged_segment),
+            Some(StagedFile {
+                task_attempt: None,
+                committed_path: "/out/a.parquet".to_string(),
+            })
+        );
+        assert_eq!(StagedFile::parse("/out/a.parquet", staged_segment), None);
+    }
+}
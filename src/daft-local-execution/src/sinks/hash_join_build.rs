@@ -27,6 +27,7 @@ use crate::{
 pub(crate) struct ProbeTableState {
     probe_table_builder: Box<dyn ProbeableBuilder>,
     projection: Vec<BoundExpr>,
+    schema: SchemaRef,
     tables: Vec<RecordBatch>,
 }
 
@@ -34,6 +35,7 @@ impl ProbeTableState {
     fn new(
         key_schema: &SchemaRef,
         projection: Vec<BoundExpr>,
+        schema: SchemaRef,
         nulls_equal_aware: Option<&Vec<bool>>,
         track_indices: bool,
     ) -> DaftResult<Self> {
@@ -44,6 +46,7 @@ impl ProbeTableState {
                 track_indices,
             )?,
             projection,
+            schema,
             tables: Vec::new(),
         })
     }
@@ -65,9 +68,15 @@ impl ProbeTableState {
         }
         Ok(())
     }
-    fn finalize(self) -> ProbeState {
+    fn finalize(mut self) -> DaftResult<ProbeState> {
+        // The build side may not have received any input, e.g. for an empty partition. Probing and emitting the
+        // unmatched build rows both expect at least one table, so add an empty one.
+        if self.tables.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(self.schema.clone())));
+            self.add_tables(&empty)?;
+        }
         let pt = self.probe_table_builder.build();
-        ProbeState::new(pt, self.tables)
+        Ok(ProbeState::new(pt, self.tables))
     }
 }
 
@@ -117,6 +126,7 @@ impl RuntimeStats for HashJoinBuildRuntimeStats {
 pub struct HashJoinBuildSink {
     key_schema: SchemaRef,
     projection: Vec<BoundExpr>,
+    build_schema: SchemaRef,
     nulls_equal_aware: Option<Vec<bool>>,
     track_indices: bool,
     probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
@@ -126,6 +136,7 @@ impl HashJoinBuildSink {
     pub(crate) fn new(
         key_schema: SchemaRef,
         projection: Vec<BoundExpr>,
+        build_schema: SchemaRef,
         nulls_equal_aware: Option<Vec<bool>>,
         track_indices: bool,
         probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
@@ -133,6 +144,7 @@ impl HashJoinBuildSink {
         Ok(Self {
             key_schema,
             projection,
+            build_schema,
             nulls_equal_aware,
             track_indices,
             probe_state_bridge,
@@ -190,7 +202,10 @@ impl BlockingSink for HashJoinBuildSink {
     ) -> BlockingSinkFinalizeResult<Self> {
         assert_eq!(states.len(), 1);
         let state = states.into_iter().next().unwrap();
-        let finalized_probe_state = state.finalize();
+        let finalized_probe_state = match state.finalize() {
+            Ok(probe_state) => probe_state,
+            Err(e) => return Err(e).into(),
+        };
         self.probe_state_bridge
             .set_state(finalized_probe_state.into());
         Ok(BlockingSinkFinalizeOutput::Finished(vec![])).into()
@@ -204,6 +219,7 @@ impl BlockingSink for HashJoinBuildSink {
         ProbeTableState::new(
             &self.key_schema,
             self.projection.clone(),
+            self.build_schema.clone(),
             self.nulls_equal_aware.as_ref(),
             self.track_indices,
         )
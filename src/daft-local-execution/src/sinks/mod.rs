@@ -8,7 +8,9 @@ pub mod into_partitions;
 pub mod join_collect;
 pub mod pivot;
 pub mod repartition;
+pub mod resample;
 pub mod sort;
+pub mod sorted_lookup_build;
 pub mod top_n;
 pub mod window_base;
 pub mod window_order_by_only;
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_metrics::ops::NodeType;
+use daft_core::gap_fill::GapFill;
+use daft_micropartition::MicroPartition;
+use tracing::{Span, instrument};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkStatus,
+};
+use crate::{ExecutionTaskSpawner, pipeline::NodeName};
+
+pub(crate) enum ResampleState {
+    Accumulating(Vec<Arc<MicroPartition>>),
+    Done,
+}
+
+impl ResampleState {
+    fn push(&mut self, part: Arc<MicroPartition>) {
+        if let Self::Accumulating(parts) = self {
+            parts.push(part);
+        } else {
+            panic!("ResampleSink should be in Accumulating state");
+        }
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = if let Self::Accumulating(parts) = self {
+            std::mem::take(parts)
+        } else {
+            panic!("ResampleSink should be in Accumulating state");
+        };
+        *self = Self::Done;
+        res
+    }
+}
+
+struct ResampleParams {
+    num_keys: usize,
+    every: i64,
+    fill: Option<GapFill>,
+}
+
+/// Fills the gaps of a time series which was aggregated into intervals, which needs all of the intervals of each
+/// group of keys at once.
+pub struct ResampleSink {
+    resample_params: Arc<ResampleParams>,
+}
+
+impl ResampleSink {
+    pub fn new(num_keys: usize, every: i64, fill: Option<GapFill>) -> Self {
+        Self {
+            resample_params: Arc::new(ResampleParams {
+                num_keys,
+                every,
+                fill,
+            }),
+        }
+    }
+}
+
+impl BlockingSink for ResampleSink {
+    type State = ResampleState;
+
+    #[instrument(skip_all, name = "ResampleSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Self::State,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult<Self> {
+        state.push(input);
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "ResampleSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Self::State>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult<Self> {
+        let resample_params = self.resample_params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let all_parts = states.into_iter().flat_map(|mut state| state.finalize());
+                    let concated = MicroPartition::concat(all_parts)?;
+
+                    let filled = Arc::new(concated.fill_time_gaps(
+                        resample_params.num_keys,
+                        resample_params.every,
+                        resample_params.fill,
+                    )?);
+                    Ok(BlockingSinkFinalizeOutput::Finished(vec![filled]))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> NodeName {
+        "Resample".into()
+    }
+
+    fn op_type(&self) -> NodeType {
+        NodeType::Resample
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut display = vec![];
+        display.push("Resample:".to_string());
+        display.push(format!("Every = {}", self.resample_params.every));
+        if let Some(fill) = self.resample_params.fill {
+            display.push(format!("Fill = {fill}"));
+        }
+        display
+    }
+
+    fn make_state(&self) -> DaftResult<Self::State> {
+        Ok(ResampleState::Accumulating(vec![]))
+    }
+}
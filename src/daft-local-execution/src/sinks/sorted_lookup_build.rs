@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_metrics::ops::NodeType;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tracing::{Span, info_span, instrument};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkStatus,
+};
+use crate::{ExecutionTaskSpawner, pipeline::NodeName, state_bridge::BroadcastStateBridgeRef};
+
+/// The right side of a sorted lookup join, sorted in ascending order with nulls last on its join keys.
+pub(crate) struct SortedLookupTable {
+    pub(crate) table: RecordBatch,
+    /// The join keys evaluated on `table`, which are searched by each left morsel.
+    pub(crate) keys: RecordBatch,
+}
+
+pub(crate) struct SortedLookupBuildState(Vec<RecordBatch>);
+
+struct SortedLookupBuildParams {
+    right_on: Vec<BoundExpr>,
+    right_is_sorted: bool,
+    schema: SchemaRef,
+}
+
+/// Collects the right side of a sorted lookup join, sorting it on the join keys unless it's already sorted.
+pub struct SortedLookupBuildSink {
+    params: Arc<SortedLookupBuildParams>,
+    state_bridge: BroadcastStateBridgeRef<SortedLookupTable>,
+}
+
+impl SortedLookupBuildSink {
+    pub(crate) fn new(
+        right_on: Vec<BoundExpr>,
+        right_is_sorted: bool,
+        schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<SortedLookupTable>,
+    ) -> Self {
+        Self {
+            params: Arc::new(SortedLookupBuildParams {
+                right_on,
+                right_is_sorted,
+                schema,
+            }),
+            state_bridge,
+        }
+    }
+}
+
+impl BlockingSink for SortedLookupBuildSink {
+    type State = SortedLookupBuildState;
+
+    fn name(&self) -> NodeName {
+        "Sorted Lookup Build".into()
+    }
+
+    fn op_type(&self) -> NodeType {
+        NodeType::SortedLookupBuild
+    }
+
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Self::State,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult<Self> {
+        if input.is_empty() {
+            return Ok(BlockingSinkStatus::NeedMoreInput(state)).into();
+        }
+
+        spawner
+            .spawn(
+                async move {
+                    state.0.extend(input.record_batches().iter().cloned());
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                info_span!("SortedLookupBuildSink::sink"),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "SortedLookupBuildSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Self::State>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult<Self> {
+        assert_eq!(states.len(), 1);
+        let state = states.into_iter().next().unwrap();
+        let params = self.params.clone();
+        let state_bridge = self.state_bridge.clone();
+
+        spawner
+            .spawn(
+                async move {
+                    let table = RecordBatch::concat_or_empty(
+                        state.0.as_slice(),
+                        Some(params.schema.clone()),
+                    )?;
+                    let table = if params.right_is_sorted {
+                        table
+                    } else {
+                        let num_keys = params.right_on.len();
+                        table.sort(
+                            &params.right_on,
+                            &vec![false; num_keys],
+                            &vec![false; num_keys],
+                        )?
+                    };
+                    let keys = table.eval_expression_list(&params.right_on)?;
+
+                    state_bridge.set_state(Arc::new(SortedLookupTable { table, keys }));
+                    Ok(BlockingSinkFinalizeOutput::Finished(vec![]))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn make_state(&self) -> DaftResult<Self::State> {
+        Ok(SortedLookupBuildState(Vec::new()))
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "Sorted Lookup Build:".to_string(),
+            format!(
+                "Keys = {}",
+                self.params
+                    .right_on
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            format!("Already Sorted = {}", self.params.right_is_sorted),
+        ]
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+}
@@ -4,7 +4,7 @@ use common_error::{DaftError, DaftResult};
 use common_metrics::ops::NodeType;
 use daft_core::prelude::*;
 use daft_dsl::{
-    WindowExpr,
+    Expr, WindowExpr,
     expr::bound_expr::{BoundExpr, BoundWindowExpr},
 };
 use daft_micropartition::MicroPartition;
@@ -126,58 +126,51 @@ impl BlockingSink for WindowOrderByOnlySink {
                         )]));
                     }
 
-                    // Convert to RecordBatch for window operations
-                    let tables = sorted.record_batches();
-                    let mut out_batches = Vec::with_capacity(tables.len());
-
-                    // Process each batch with window functions
-                    for batch in tables.iter().cloned() {
-                        if batch.is_empty() {
-                            continue;
-                        }
-
-                        let mut result_batch = batch;
-
-                        // Apply each window expression
-                        for (wexpr, name) in params.window_exprs.iter().zip(&params.aliases) {
-                            result_batch = match wexpr.as_ref() {
-                                WindowExpr::RowNumber => {
-                                    result_batch.window_row_number(name.clone())?
-                                }
-                                WindowExpr::Rank => result_batch.window_rank(
-                                    name.clone(),
-                                    &params.order_by,
-                                    false,
-                                )?,
-                                WindowExpr::DenseRank => result_batch.window_rank(
-                                    name.clone(),
-                                    &params.order_by,
-                                    true,
-                                )?,
-                                _ => {
-                                    return Err(DaftError::ValueError(
-                                        format!(
-                                            "Unsupported window function for order by only: {:?}",
-                                            wexpr
-                                        )
-                                        .into(),
-                                    ));
-                                }
-                            };
-                        }
-                        out_batches.push(result_batch);
+                    // All of the rows are in a single partition, so the window functions are computed over all of
+                    // them at once rather than over each of their batches.
+                    let mut result_batch = sorted
+                        .concat_or_get()?
+                        .expect("A non-empty micropartition has a record batch");
+
+                    // Apply each window expression
+                    for (wexpr, name) in params.window_exprs.iter().zip(&params.aliases) {
+                        result_batch = match wexpr.as_ref() {
+                            WindowExpr::Agg(agg_expr) => {
+                                let new_col = result_batch
+                                    .eval_expression(&BoundExpr::new_unchecked(Arc::new(
+                                        Expr::Agg(agg_expr.clone()),
+                                    )))?
+                                    .broadcast(result_batch.len())?
+                                    .rename(name.clone());
+                                result_batch
+                                    .append_column(params.original_schema.clone(), new_col)?
+                            }
+                            WindowExpr::RowNumber => {
+                                result_batch.window_row_number(name.clone())?
+                            }
+                            WindowExpr::Rank => {
+                                result_batch.window_rank(name.clone(), &params.order_by, false)?
+                            }
+                            WindowExpr::DenseRank => {
+                                result_batch.window_rank(name.clone(), &params.order_by, true)?
+                            }
+                            _ => {
+                                return Err(DaftError::ValueError(
+                                    format!(
+                                        "Unsupported window function for order by only: {:?}",
+                                        wexpr
+                                    )
+                                    .into(),
+                                ));
+                            }
+                        };
                     }
 
-                    // Create final output partition
-                    let output = if out_batches.is_empty() {
-                        MicroPartition::empty(Some(params.original_schema.clone()))
-                    } else {
-                        MicroPartition::new_loaded(
-                            params.original_schema.clone(),
-                            out_batches.into(),
-                            None,
-                        )
-                    };
+                    let output = MicroPartition::new_loaded(
+                        params.original_schema.clone(),
+                        vec![result_batch].into(),
+                        None,
+                    );
 
                     Ok(BlockingSinkFinalizeOutput::Finished(vec![Arc::new(output)]))
                 },
@@ -212,6 +212,18 @@ impl BlockingSink for WindowPartitionAndOrderBySink {
                                             *offset,
                                             default.clone().map(BoundExpr::new_unchecked),
                                         )?,
+                                        WindowExpr::SessionId { input, gap } => partition
+                                            .window_session_id(
+                                                name.clone(),
+                                                BoundExpr::new_unchecked(input.clone()),
+                                                BoundExpr::new_unchecked(gap.clone()),
+                                            )?,
+                                        WindowExpr::FillNull { input, strategy } => partition
+                                            .window_fill_null(
+                                                name.clone(),
+                                                BoundExpr::new_unchecked(input.clone()),
+                                                *strategy,
+                                            )?,
                                     }
                                 }
                             }
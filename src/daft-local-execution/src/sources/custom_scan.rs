@@ -0,0 +1,271 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_error::DaftResult;
+use common_metrics::ops::NodeType;
+use common_scan_info::{Pushdowns, ScanTaskLikeRef};
+use daft_core::prelude::SchemaRef;
+use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_io::IOStatsRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use futures::{StreamExt, TryStreamExt, stream::BoxStream};
+use tracing::instrument;
+
+use crate::{
+    pipeline::NodeName,
+    sources::source::{Source, SourceStream},
+};
+
+/// A source which reads scan tasks of sources other than Daft's own with [`common_scan_info::ScanTaskLike::read`].
+///
+/// The scan tasks are read one after the other, and their pushdowns are applied to the record batches they return.
+pub struct CustomScanSource {
+    scan_tasks: Vec<ScanTaskLikeRef>,
+    pushdowns: Pushdowns,
+    schema: SchemaRef,
+}
+
+impl CustomScanSource {
+    pub fn new(scan_tasks: Vec<ScanTaskLikeRef>, pushdowns: Pushdowns, schema: SchemaRef) -> Self {
+        Self {
+            scan_tasks,
+            pushdowns,
+            schema,
+        }
+    }
+
+    pub fn arced(self) -> Arc<dyn Source> {
+        Arc::new(self) as Arc<dyn Source>
+    }
+}
+
+/// Reads a scan task, applying its filters and casting its record batches to the schema of the scan.
+fn read_scan_task(
+    scan_task: ScanTaskLikeRef,
+    schema: SchemaRef,
+) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let filters = scan_task.pushdowns().filters.clone();
+    let batches = scan_task.read()?;
+    Ok(batches
+        .map(move |batch| {
+            let mut batch = batch?;
+            if let Some(filters) = &filters {
+                let filters = BoundExpr::try_new(filters.clone(), &batch.schema)?;
+                batch = batch.filter(&[filters])?;
+            }
+            #[allow(deprecated)]
+            batch.cast_to_schema(&schema)
+        })
+        .boxed())
+}
+
+#[async_trait]
+impl Source for CustomScanSource {
+    #[instrument(name = "CustomScanSource::get_data", level = "info", skip_all)]
+    async fn get_data(
+        &self,
+        _maintain_order: bool,
+        _io_stats: IOStatsRef,
+        _chunk_size: usize,
+    ) -> DaftResult<SourceStream<'static>> {
+        let schema = self.schema.clone();
+        let batches = futures::stream::iter(self.scan_tasks.clone())
+            .map({
+                let schema = schema.clone();
+                move |scan_task| read_scan_task(scan_task, schema.clone())
+            })
+            .try_flatten();
+        // Each scan task applies the limit to its own rows, so the limit is applied across scan tasks here.
+        let stream = batches.scan(self.pushdowns.limit, move |remaining, batch| {
+            if *remaining == Some(0) {
+                return futures::future::ready(None);
+            }
+            let batch = batch.and_then(|batch| {
+                let batch = match remaining {
+                    Some(remaining) => {
+                        let batch = batch.head((*remaining).min(batch.len()))?;
+                        *remaining -= batch.len();
+                        batch
+                    }
+                    None => batch,
+                };
+                Ok(Arc::new(MicroPartition::new_loaded(
+                    schema.clone(),
+                    Arc::new(vec![batch]),
+                    None,
+                )))
+            });
+            futures::future::ready(Some(batch))
+        });
+        Ok(stream.boxed())
+    }
+
+    fn name(&self) -> NodeName {
+        "Custom Scan".into()
+    }
+
+    fn op_type(&self) -> NodeType {
+        NodeType::ScanTask
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push("CustomScan:".to_string());
+        res.push(format!("Num Scan Tasks = {}", self.scan_tasks.len()));
+        res.push(format!("Schema = {}", self.schema.short_string()));
+        res
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::Any,
+        hash::{Hash, Hasher},
+        sync::Arc,
+    };
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_display::{DisplayAs, DisplayLevel};
+    use common_error::DaftResult;
+    use common_file_formats::FileFormatConfig;
+    use common_scan_info::{Pushdowns, ScanTaskLike, ScanTaskLikeRef, ScanTaskStream};
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, resolved_col};
+    use daft_io::IOStatsRef;
+    use daft_recordbatch::RecordBatch;
+    use futures::{StreamExt, TryStreamExt};
+    use serde::{Deserialize, Serialize};
+
+    use super::CustomScanSource;
+    use crate::sources::source::Source;
+
+    /// A scan task of a source outside of Daft, which reads a range of integers.
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Hash)]
+    struct RangeScanTask {
+        start: i64,
+        end: i64,
+        pushdowns: Pushdowns,
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("x", DataType::Int64)]))
+    }
+
+    #[typetag::serde]
+    impl ScanTaskLike for RangeScanTask {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_arc(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+            self
+        }
+
+        fn dyn_eq(&self, other: &dyn ScanTaskLike) -> bool {
+            other.as_any().downcast_ref::<Self>() == Some(self)
+        }
+
+        fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+            self.hash(&mut state);
+        }
+
+        fn materialized_schema(&self) -> SchemaRef {
+            schema()
+        }
+
+        fn num_rows(&self) -> Option<usize> {
+            Some((self.end - self.start) as usize)
+        }
+
+        fn approx_num_rows(&self, _: Option<&DaftExecutionConfig>) -> Option<f64> {
+            self.num_rows().map(|n| n as f64)
+        }
+
+        fn upper_bound_rows(&self) -> Option<usize> {
+            self.num_rows()
+        }
+
+        fn size_bytes_on_disk(&self) -> Option<usize> {
+            None
+        }
+
+        fn estimate_in_memory_size_bytes(&self, _: Option<&DaftExecutionConfig>) -> Option<usize> {
+            self.num_rows().map(|n| n * 8)
+        }
+
+        fn file_format_config(&self) -> Arc<FileFormatConfig> {
+            FileFormatConfig::Json(Default::default()).into()
+        }
+
+        fn pushdowns(&self) -> &Pushdowns {
+            &self.pushdowns
+        }
+
+        fn schema(&self) -> SchemaRef {
+            schema()
+        }
+
+        fn get_file_paths(&self) -> Vec<String> {
+            vec![]
+        }
+
+        fn read(&self) -> DaftResult<ScanTaskStream> {
+            // Each range is read in batches of two rows, without applying the pushdowns.
+            let batches = (self.start..self.end)
+                .step_by(2)
+                .map(|start| {
+                    let values = (start..(start + 2).min(self.end)).collect::<Vec<_>>();
+                    RecordBatch::from_nonempty_columns(vec![
+                        Int64Array::from(("x", values)).into_series(),
+                    ])
+                })
+                .collect::<Vec<_>>();
+            Ok(futures::stream::iter(batches).boxed())
+        }
+    }
+
+    impl DisplayAs for RangeScanTask {
+        fn display_as(&self, _: DisplayLevel) -> String {
+            format!("RangeScanTask({}..{})", self.start, self.end)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_scan_source_applies_pushdowns() -> DaftResult<()> {
+        let pushdowns = Pushdowns::default()
+            .with_filters(Some(resolved_col("x").gt(lit(2i64))))
+            .with_limit(Some(5));
+        let scan_tasks = [(0, 5), (5, 10), (10, 15)]
+            .into_iter()
+            .map(|(start, end)| {
+                Arc::new(RangeScanTask {
+                    start,
+                    end,
+                    pushdowns: pushdowns.clone(),
+                }) as ScanTaskLikeRef
+            })
+            .collect();
+        let source = CustomScanSource::new(scan_tasks, pushdowns, schema());
+
+        let partitions = source
+            .get_data(true, IOStatsRef::default(), 1024)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let mut values = vec![];
+        for partition in partitions {
+            for batch in partition.record_batches() {
+                let x = batch.get_column(0).i64()?;
+                values.extend((0..x.len()).filter_map(|i| x.get(i)));
+            }
+        }
+        assert_eq!(values, vec![3, 4, 5, 6, 7]);
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod custom_scan;
 pub mod empty_scan;
 pub mod glob_scan;
 pub mod in_memory;
@@ -16,7 +16,7 @@ use common_scan_info::{Pushdowns, ScanTaskLike};
 use daft_core::prelude::{AsArrow, Int64Array, SchemaRef, Utf8Array};
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 use daft_dsl::{AggExpr, Expr};
-use daft_io::{GetRange, IOStatsRef};
+use daft_io::{GetRange, IOClient, IOStatsRef, SourceType};
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_micropartition::MicroPartition;
 use daft_parquet::read::{ParquetSchemaInferenceOptions, read_parquet_bulk_async};
@@ -24,6 +24,7 @@ use daft_scan::{ChunkSpec, ScanTask};
 use daft_warc::WarcConvertOptions;
 use futures::{FutureExt, Stream, StreamExt};
 use snafu::ResultExt;
+use tokio::sync::Semaphore;
 use tracing::instrument;
 
 use crate::{
@@ -33,9 +34,15 @@ use crate::{
     sources::source::{Source, SourceStream},
 };
 
+/// Files larger than this are read by their readers rather than fetched ahead, which bounds the memory held by
+/// fetched files waiting to be decoded.
+const MAX_PREFETCH_SIZE_BYTES: u64 = 256 * 1024 * 1024;
+
 pub struct ScanTaskSource {
     scan_tasks: Vec<Arc<ScanTask>>,
     num_parallel_tasks: usize,
+    read_ahead: usize,
+    io_concurrency: usize,
     schema: SchemaRef,
     execution_config: Arc<DaftExecutionConfig>,
 }
@@ -97,9 +104,17 @@ impl ScanTaskSource {
             }
         };
         num_parallel_tasks = num_parallel_tasks.min(scan_tasks.len());
+        // Fetching ahead with a limit would read files which are never needed.
+        let read_ahead = if pushdowns.limit.is_some() {
+            0
+        } else {
+            cfg.scan_read_ahead
+        };
         Self {
             scan_tasks,
             num_parallel_tasks,
+            read_ahead,
+            io_concurrency: cfg.scan_io_concurrency.max(1),
             schema,
             execution_config: Arc::new(cfg.clone()),
         }
@@ -109,7 +124,11 @@ impl ScanTaskSource {
         Arc::new(self) as Arc<dyn Source>
     }
 
-    /// Spawns the background task that processes scan tasks with limited parallelism
+    /// Spawns the background task that processes scan tasks with limited parallelism.
+    ///
+    /// Scan tasks go through two stages: their files are fetched into memory by up to `io_concurrency` tasks at
+    /// once, up to `read_ahead` scan tasks ahead of those being decoded, and then up to `num_parallel_tasks` of them
+    /// are decoded at once. With no read-ahead, the readers fetch their files as they decode them.
     fn spawn_scan_task_processor(
         &self,
         senders: Vec<Sender<Arc<MicroPartition>>>,
@@ -121,16 +140,36 @@ impl ScanTaskSource {
         let io_runtime = get_io_runtime(true);
         let scan_tasks = self.scan_tasks.clone();
         let num_parallel_tasks = self.num_parallel_tasks;
+        let read_ahead = self.read_ahead;
+        let io_permits = Arc::new(Semaphore::new(self.io_concurrency));
 
         io_runtime.spawn(async move {
             let mut task_set = TaskSet::new();
-            let mut scan_task_and_sender_iter = scan_tasks.into_iter().zip(senders.into_iter());
+            let prefetch_io_stats = io_stats.clone();
+            let scan_tasks_and_senders = futures::stream::iter(scan_tasks.into_iter().zip(senders));
+            let mut prefetched_scan_tasks = scan_tasks_and_senders
+                .map(move |(scan_task, sender)| {
+                    let io_stats = prefetch_io_stats.clone();
+                    let io_permits = io_permits.clone();
+                    get_io_runtime(true).spawn(async move {
+                        let io_client = if read_ahead > 0 {
+                            prefetch_scan_task(&scan_task, io_stats, io_permits).await?
+                        } else {
+                            None
+                        };
+                        DaftResult::Ok((scan_task, sender, io_client))
+                    })
+                })
+                // Polling keeps the scan tasks being decoded plus `read_ahead` more in the fetch stage.
+                .buffered((num_parallel_tasks + read_ahead).max(1));
 
             // Start initial batch of parallel tasks
             for _ in 0..num_parallel_tasks {
-                if let Some((scan_task, sender)) = scan_task_and_sender_iter.next() {
+                if let Some(prefetched) = prefetched_scan_tasks.next().await {
+                    let (scan_task, sender, io_client) = prefetched??;
                     task_set.spawn(forward_scan_task_stream(
                         scan_task,
+                        io_client,
                         io_stats.clone(),
                         delete_map.clone(),
                         maintain_order,
@@ -143,9 +182,11 @@ impl ScanTaskSource {
             // Process remaining tasks as previous ones complete
             while let Some(result) = task_set.join_next().await {
                 result??;
-                if let Some((scan_task, sender)) = scan_task_and_sender_iter.next() {
+                if let Some(prefetched) = prefetched_scan_tasks.next().await {
+                    let (scan_task, sender, io_client) = prefetched??;
                     task_set.spawn(forward_scan_task_stream(
                         scan_task,
+                        io_client,
                         io_stats.clone(),
                         delete_map.clone(),
                         maintain_order,
@@ -245,6 +286,9 @@ Estimated Scan Bytes = {total_bytes}
 Num Parallel Scan Tasks = {num_parallel_tasks}
 "
             );
+            if scan.read_ahead > 0 {
+                writeln!(s, "Scan Read Ahead = {}", scan.read_ahead).unwrap();
+            }
             #[cfg(feature = "python")]
             if let FileFormatConfig::Database(config) =
                 scan.scan_tasks[0].file_format_config().as_ref()
@@ -419,24 +463,112 @@ fn flatten_receivers_into_stream(
 
 async fn forward_scan_task_stream(
     scan_task: Arc<ScanTask>,
+    io_client: Option<Arc<IOClient>>,
     io_stats: IOStatsRef,
     delete_map: Option<Arc<HashMap<String, Vec<i64>>>>,
     maintain_order: bool,
     chunk_size: usize,
     sender: Sender<Arc<MicroPartition>>,
 ) -> DaftResult<()> {
-    let mut stream =
-        stream_scan_task(scan_task, io_stats, delete_map, maintain_order, chunk_size).await?;
+    let context = scan_task_context(&scan_task);
+    let mut stream = stream_scan_task(
+        scan_task,
+        io_client,
+        io_stats,
+        delete_map,
+        maintain_order,
+        chunk_size,
+    )
+    .await
+    .map_err(|e| e.with_context(&context))?;
     while let Some(result) = stream.next().await {
-        if sender.send(result?).await.is_err() {
+        if sender
+            .send(result.map_err(|e| e.with_context(&context))?)
+            .await
+            .is_err()
+        {
             break;
         }
     }
     Ok(())
 }
 
+/// Describes the files which a scan task reads, to give the context of its errors.
+fn scan_task_context(scan_task: &ScanTask) -> String {
+    let Some(source) = scan_task.sources.first() else {
+        return "While reading an empty scan task".to_string();
+    };
+    let mut context = format!("While reading {}", source.get_path());
+    if let Some(chunk_spec) = source.get_chunk_spec() {
+        context.push_str(&format!(" ({chunk_spec})"));
+    }
+    if scan_task.sources.len() > 1 {
+        context.push_str(&format!(" and {} other files", scan_task.sources.len() - 1));
+    }
+    context
+}
+
+fn scan_task_io_client(scan_task: &ScanTask) -> DaftResult<Arc<IOClient>> {
+    let io_config = Arc::new(
+        scan_task
+            .storage_config
+            .io_config
+            .clone()
+            .unwrap_or_default(),
+    );
+    daft_io::get_io_client(scan_task.storage_config.multithreaded_io, io_config)
+}
+
+/// Fetches the file of a scan task into memory, returning an IO client which serves the reads of the file from it.
+///
+/// Only whole files on remote storage, of the formats whose readers read through the IO client, are fetched. For
+/// other scan tasks, `None` is returned and their readers fetch their files themselves.
+async fn prefetch_scan_task(
+    scan_task: &ScanTask,
+    io_stats: IOStatsRef,
+    io_permits: Arc<Semaphore>,
+) -> DaftResult<Option<Arc<IOClient>>> {
+    let [source] = scan_task.sources.as_slice() else {
+        return Ok(None);
+    };
+    let is_prefetchable_format = match scan_task.file_format_config.as_ref() {
+        // Count pushdowns only read the metadata of the file.
+        FileFormatConfig::Parquet(_) => scan_task.pushdowns.aggregation.is_none(),
+        FileFormatConfig::Csv(_) | FileFormatConfig::Json(_) | FileFormatConfig::Warc(_) => true,
+        #[cfg(feature = "python")]
+        FileFormatConfig::Database(_) | FileFormatConfig::PythonFunction { .. } => false,
+    };
+    let url = source.get_path();
+    let is_remote = !matches!(daft_io::parse_url(url)?.0, SourceType::File);
+    let is_small_enough = source
+        .get_size_bytes()
+        .is_some_and(|size| size <= MAX_PREFETCH_SIZE_BYTES);
+    if !is_prefetchable_format
+        || !is_remote
+        || !is_small_enough
+        || source.get_chunk_spec().is_some()
+    {
+        return Ok(None);
+    }
+
+    let io_client = scan_task_io_client(scan_task)?;
+    let _permit = io_permits
+        .acquire()
+        .await
+        .map_err(|e| DaftError::InternalError(e.to_string()))?;
+    let data = io_client
+        .single_url_get(url.to_string(), None, Some(io_stats))
+        .await?
+        .bytes()
+        .await?;
+    Ok(Some(Arc::new(
+        io_client.with_prefetched_object(url.to_string(), data),
+    )))
+}
+
 async fn stream_scan_task(
     scan_task: Arc<ScanTask>,
+    io_client: Option<Arc<IOClient>>,
     io_stats: IOStatsRef,
     delete_map: Option<Arc<HashMap<String, Vec<i64>>>>,
     maintain_order: bool,
@@ -477,14 +609,10 @@ async fn stream_scan_task(
     }
     let source = scan_task.sources.first().unwrap();
     let url = source.get_path();
-    let io_config = Arc::new(
-        scan_task
-            .storage_config
-            .io_config
-            .clone()
-            .unwrap_or_default(),
-    );
-    let io_client = daft_io::get_io_client(scan_task.storage_config.multithreaded_io, io_config)?;
+    let io_client = match io_client {
+        Some(io_client) => io_client,
+        None => scan_task_io_client(&scan_task)?,
+    };
     let table_stream = match scan_task.file_format_config.as_ref() {
         FileFormatConfig::Parquet(ParquetSourceConfig {
             coerce_int96_timestamp_unit,
@@ -14,7 +14,7 @@ use common_metrics::{
 };
 use daft_core::prelude::SchemaRef;
 use daft_io::IOStatsRef;
-use daft_local_plan::LocalNodeContext;
+use daft_local_plan::{LOGICAL_NODE_ID_KEY, LocalNodeContext};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use futures::{StreamExt, stream::BoxStream};
@@ -23,7 +23,9 @@ use opentelemetry::{KeyValue, global};
 use crate::{
     ExecutionRuntimeContext,
     channel::{Receiver, create_channel},
-    pipeline::{MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext},
+    pipeline::{
+        MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext, pipeline_node_graph_details,
+    },
     runtime_stats::{Counter, CountingSender, RuntimeStats},
 };
 
@@ -174,9 +176,14 @@ impl TreeDisplay for SourceNode {
             "category": "Source",
             "type": self.source.op_type().to_string(),
             "name": self.name(),
+            "logical_node_id": self.node_info.context.get(LOGICAL_NODE_ID_KEY),
         })
     }
 
+    fn repr_graph_details(&self) -> serde_json::Value {
+        pipeline_node_graph_details(self, &self.plan_stats)
+    }
+
     fn get_children(&self) -> Vec<&dyn TreeDisplay> {
         self.children()
             .iter()
@@ -243,7 +250,7 @@ impl PipelineNode for SourceNode {
                 stats_manager.finalize_node(node_id);
                 Ok(())
             },
-            &self.name(),
+            &self.node_info,
         );
         Ok(destination_receiver)
     }
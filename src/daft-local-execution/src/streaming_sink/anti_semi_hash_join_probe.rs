@@ -53,6 +53,7 @@ impl AntiSemiProbeState {
 
 struct AntiSemiJoinParams {
     probe_on: Vec<BoundExpr>,
+    residual: Option<BoundExpr>,
     is_semi: bool,
 }
 
@@ -66,6 +67,7 @@ pub(crate) struct AntiSemiProbeSink {
 impl AntiSemiProbeSink {
     pub fn new(
         probe_on: Vec<BoundExpr>,
+        residual: Option<BoundExpr>,
         join_type: &JoinType,
         output_schema: &SchemaRef,
         probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
@@ -74,6 +76,7 @@ impl AntiSemiProbeSink {
         Self {
             params: Arc::new(AntiSemiJoinParams {
                 probe_on,
+                residual,
                 is_semi: *join_type == JoinType::Semi,
             }),
             output_schema: output_schema.clone(),
@@ -86,6 +89,7 @@ impl AntiSemiProbeSink {
     // on the right side and are streaming the left side.
     fn probe_anti_semi(
         probe_on: &[BoundExpr],
+        residual: Option<&BoundExpr>,
         probe_state: &Arc<ProbeState>,
         input: &Arc<MicroPartition>,
         is_semi: bool,
@@ -94,7 +98,19 @@ impl AntiSemiProbeSink {
         let mut input_idxs = vec![vec![]; input_tables.len()];
         for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
             let join_keys = table.eval_expression_list(probe_on)?;
-            let iter = probe_state.probe_exists(&join_keys)?;
+            let iter: Box<dyn Iterator<Item = bool> + '_> = match residual {
+                // A probe row only matches if one of its matching build rows also satisfies the residual.
+                Some(residual) => {
+                    let matches =
+                        probe_state.probe_matches(table, &join_keys, Some(residual), false)?;
+                    let mut matched = vec![false; table.len()];
+                    for probe_row_idx in matches.probe_idxs {
+                        matched[probe_row_idx as usize] = true;
+                    }
+                    Box::new(matched.into_iter())
+                }
+                None => Box::new(probe_state.probe_exists(&join_keys)?),
+            };
 
             for (probe_row_idx, matched) in iter.enumerate() {
                 // 1. If this is a semi join, we keep the row if it matches.
@@ -127,6 +143,7 @@ impl AntiSemiProbeSink {
     // emit a final result at the end.
     fn probe_anti_semi_with_bitmap(
         probe_on: &[BoundExpr],
+        residual: Option<&BoundExpr>,
         probe_state: &Arc<ProbeState>,
         bitmap_builder: &mut IndexBitmapBuilder,
         input: &Arc<MicroPartition>,
@@ -134,8 +151,15 @@ impl AntiSemiProbeSink {
         let _loop = info_span!("AntiSemiOperator::eval_and_probe").entered();
         for table in input.record_batches() {
             let join_keys = table.eval_expression_list(probe_on)?;
-            let idx_iter = probe_state.probe_indices(&join_keys)?;
+            if let Some(residual) = residual {
+                let matches = probe_state.probe_matches(table, &join_keys, Some(residual), true)?;
+                for (build_table_idx, build_row_idx) in matches.build_idxs {
+                    bitmap_builder.mark_used(build_table_idx as usize, build_row_idx as usize);
+                }
+                continue;
+            }
 
+            let idx_iter = probe_state.probe_indices(&join_keys)?;
             for inner_iter in idx_iter.flatten() {
                 for (build_table_idx, build_row_idx) in inner_iter {
                     bitmap_builder.mark_used(build_table_idx as usize, build_row_idx as usize);
@@ -228,14 +252,20 @@ impl StreamingSink for AntiSemiProbeSink {
                     if let Some(bm_builder) = bitmap_builder {
                         Self::probe_anti_semi_with_bitmap(
                             &params.probe_on,
+                            params.residual.as_ref(),
                             ps,
                             bm_builder,
                             &input,
                         )?;
                         Ok((state, StreamingSinkOutput::NeedMoreInput(None)))
                     } else {
-                        let res =
-                            Self::probe_anti_semi(&params.probe_on, ps, &input, params.is_semi);
+                        let res = Self::probe_anti_semi(
+                            &params.probe_on,
+                            params.residual.as_ref(),
+                            ps,
+                            &input,
+                            params.is_semi,
+                        );
                         Ok((state, StreamingSinkOutput::NeedMoreInput(Some(res?))))
                     }
                 },
@@ -273,6 +303,9 @@ impl StreamingSink for AntiSemiProbeSink {
                     .join(", ")
             ));
         }
+        if let Some(residual) = &self.params.residual {
+            res.push(format!("Residual: {residual}"));
+        }
         res.push(format!("Build on left: {}", self.build_on_left));
         res
     }
@@ -6,7 +6,7 @@ use common_error::DaftResult;
 use common_metrics::ops::{NodeCategory, NodeInfo, NodeType};
 use common_runtime::{get_compute_pool_num_threads, get_compute_runtime};
 use daft_core::prelude::SchemaRef;
-use daft_local_plan::LocalNodeContext;
+use daft_local_plan::{LOGICAL_NODE_ID_KEY, LocalNodeContext};
 use daft_logical_plan::stats::StatsState;
 use daft_micropartition::MicroPartition;
 use tracing::{info_span, instrument};
@@ -19,7 +19,9 @@ use crate::{
     },
     dispatcher::{DispatchSpawner, DynamicUnorderedDispatcher, RoundRobinDispatcher},
     dynamic_batching::{BatchManager, BatchingStrategy},
-    pipeline::{MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext},
+    pipeline::{
+        MorselSizeRequirement, NodeName, PipelineNode, RuntimeContext, pipeline_node_graph_details,
+    },
     resource_manager::MemoryManager,
     runtime_stats::{
         CountingSender, DefaultRuntimeStats, InitializingCountingReceiver, RuntimeStats,
@@ -93,6 +95,13 @@ pub(crate) trait StreamingSink: Send + Sync {
     fn morsel_size_requirement(&self) -> Option<MorselSizeRequirement> {
         None
     }
+
+    /// Whether the output of this StreamingSink depends on the order of its input, in which case its children
+    /// maintain order even if the query does not require it.
+    fn requires_ordered_input(&self) -> bool {
+        false
+    }
+
     fn batching_strategy(&self) -> Self::BatchingStrategy;
     fn dispatch_spawner(
         &self,
@@ -176,6 +185,8 @@ impl<Op: StreamingSink + 'static> StreamingSinkNode<Op> {
                         batch_manager.record_execution_stats(
                             runtime_stats.clone(),
                             mp.as_ref().map(|mp| mp.len()).unwrap_or(0),
+                            morsel.size_bytes()
+                                + mp.as_ref().map(|mp| mp.size_bytes()).unwrap_or(0),
                             elapsed,
                         );
                         if let Some(mp) = mp
@@ -189,6 +200,8 @@ impl<Op: StreamingSink + 'static> StreamingSinkNode<Op> {
                         batch_manager.record_execution_stats(
                             runtime_stats.clone(),
                             mp.as_ref().map(|mp| mp.len()).unwrap_or(0),
+                            morsel.size_bytes()
+                                + mp.as_ref().map(|mp| mp.size_bytes()).unwrap_or(0),
                             elapsed,
                         );
                         if let Some(mp) = mp
@@ -201,6 +214,8 @@ impl<Op: StreamingSink + 'static> StreamingSinkNode<Op> {
                         batch_manager.record_execution_stats(
                             runtime_stats.clone(),
                             mp.as_ref().map(|mp| mp.len()).unwrap_or(0),
+                            morsel.size_bytes()
+                                + mp.as_ref().map(|mp| mp.size_bytes()).unwrap_or(0),
                             elapsed,
                         );
                         if let Some(mp) = mp {
@@ -284,10 +299,15 @@ impl<Op: StreamingSink + 'static> TreeDisplay for StreamingSinkNode<Op> {
             "category": "StreamingSink",
             "type": self.op.op_type().to_string(),
             "name": self.name(),
+            "logical_node_id": self.node_info.context.get(LOGICAL_NODE_ID_KEY),
             "children": children,
         })
     }
 
+    fn repr_graph_details(&self) -> serde_json::Value {
+        pipeline_node_graph_details(self, &self.plan_stats)
+    }
+
     fn get_children(&self) -> Vec<&dyn TreeDisplay> {
         self.children()
             .iter()
@@ -336,6 +356,7 @@ impl<Op: StreamingSink + 'static> PipelineNode for StreamingSinkNode<Op> {
         maintain_order: bool,
         runtime_handle: &mut ExecutionRuntimeContext,
     ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        let maintain_order = maintain_order || self.op.requires_ordered_input();
         let mut child_result_receivers = Vec::with_capacity(self.children.len());
         for child in &self.children {
             let child_result_receiver = child.start(maintain_order, runtime_handle)?;
@@ -363,7 +384,7 @@ impl<Op: StreamingSink + 'static> PipelineNode for StreamingSinkNode<Op> {
         );
         runtime_handle.spawn(
             async move { spawned_dispatch_result.spawned_dispatch_task.await? },
-            &self.name(),
+            &self.node_info,
         );
 
         let memory_manager = runtime_handle.memory_manager();
@@ -422,7 +443,7 @@ impl<Op: StreamingSink + 'static> PipelineNode for StreamingSinkNode<Op> {
                 stats_manager.finalize_node(node_id);
                 Ok(())
             },
-            &self.name(),
+            &self.node_info,
         );
         Ok(destination_receiver)
     }
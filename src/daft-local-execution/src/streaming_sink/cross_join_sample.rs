@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_metrics::ops::NodeType;
+use daft_core::prelude::SchemaRef;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tracing::{Span, instrument};
+
+use super::base::{
+    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeOutput,
+    StreamingSinkFinalizeResult, StreamingSinkOutput,
+};
+use crate::{
+    ExecutionTaskSpawner, dynamic_batching::StaticBatchingStrategy, pipeline::NodeName,
+    state_bridge::BroadcastStateBridgeRef,
+};
+
+struct CrossJoinSampleParams {
+    size: usize,
+    with_replacement: bool,
+    seed: Option<u64>,
+    left_schema: SchemaRef,
+    right_schema: SchemaRef,
+    output_schema: SchemaRef,
+}
+
+pub(crate) struct CrossJoinSampleState {
+    left_tables: Vec<RecordBatch>,
+}
+
+/// Samples rows uniformly at random from the cross join of its left input with its right input, which is collected
+/// into a state bridge, without computing the cross join.
+///
+/// Only the sampled positions of the cartesian product are taken from each side, so the cost grows with the sizes of
+/// the inputs and of the sample rather than with the size of the product.
+pub struct CrossJoinSampleSink {
+    params: Arc<CrossJoinSampleParams>,
+    right_bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+}
+
+impl CrossJoinSampleSink {
+    pub(crate) fn new(
+        size: usize,
+        with_replacement: bool,
+        seed: Option<u64>,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        output_schema: SchemaRef,
+        right_bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+    ) -> Self {
+        Self {
+            params: Arc::new(CrossJoinSampleParams {
+                size,
+                with_replacement,
+                seed,
+                left_schema,
+                right_schema,
+                output_schema,
+            }),
+            right_bridge,
+        }
+    }
+}
+
+impl StreamingSink for CrossJoinSampleSink {
+    type State = CrossJoinSampleState;
+    type BatchingStrategy = StaticBatchingStrategy;
+
+    #[instrument(skip_all, name = "CrossJoinSampleSink::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Self::State,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkExecuteResult<Self> {
+        state
+            .left_tables
+            .extend(input.record_batches().iter().cloned());
+        Ok((state, StreamingSinkOutput::NeedMoreInput(None))).into()
+    }
+
+    #[instrument(skip_all, name = "CrossJoinSampleSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Self::State>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkFinalizeResult<Self> {
+        let params = self.params.clone();
+        let right_bridge = self.right_bridge.clone();
+        spawner
+            .spawn(
+                async move {
+                    let right_tables = right_bridge.get_state().await;
+                    let left_tables = states
+                        .into_iter()
+                        .flat_map(|state| state.left_tables)
+                        .collect::<Vec<_>>();
+                    let left = RecordBatch::concat_or_empty(
+                        &left_tables,
+                        Some(params.left_schema.clone()),
+                    )?;
+                    let right = RecordBatch::concat_or_empty(
+                        right_tables.as_slice(),
+                        Some(params.right_schema.clone()),
+                    )?;
+                    let sampled = left.cross_join_sample(
+                        &right,
+                        params.size,
+                        params.with_replacement,
+                        params.seed,
+                    )?;
+                    Ok(StreamingSinkFinalizeOutput::Finished(Some(Arc::new(
+                        MicroPartition::new_loaded(
+                            params.output_schema.clone(),
+                            Arc::new(vec![sampled]),
+                            None,
+                        ),
+                    ))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> NodeName {
+        format!("Cross Join Sample Size = {}", self.params.size).into()
+    }
+
+    fn op_type(&self) -> NodeType {
+        NodeType::Sample
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            format!("Cross Join Sample Size = {}", self.params.size),
+            format!("With replacement = {}", self.params.with_replacement),
+            format!("Seed = {:?}", self.params.seed),
+        ]
+    }
+
+    fn make_state(&self) -> DaftResult<Self::State> {
+        Ok(CrossJoinSampleState {
+            left_tables: vec![],
+        })
+    }
+
+    fn batching_strategy(&self) -> Self::BatchingStrategy {
+        StaticBatchingStrategy::new(self.morsel_size_requirement().unwrap_or_default())
+    }
+}
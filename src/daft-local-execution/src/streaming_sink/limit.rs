@@ -6,7 +6,7 @@ use std::{
 use common_error::DaftResult;
 use common_metrics::ops::NodeType;
 use daft_micropartition::MicroPartition;
-use tracing::{Span, instrument};
+use tracing::instrument;
 
 use super::base::{
     StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeOutput,
@@ -17,30 +17,30 @@ use crate::{ExecutionTaskSpawner, pipeline::NodeName};
 pub(crate) struct LimitSinkState {
     // The remaining number of rows to skip
     remaining_skip: usize,
-    // The remaining number of rows to fetch
-    remaining_take: usize,
+    // The remaining number of rows to fetch, or None if all rows after the offset are fetched
+    remaining_take: Option<usize>,
 }
 
 impl LimitSinkState {
-    fn new(limit: usize, offset: Option<usize>) -> Self {
+    fn new(limit: Option<usize>, offset: Option<usize>) -> Self {
         Self {
             remaining_skip: offset.unwrap_or(0),
             remaining_take: limit,
         }
     }
 
-    fn get_state_mut(&mut self) -> (&mut usize, &mut usize) {
+    fn get_state_mut(&mut self) -> (&mut usize, &mut Option<usize>) {
         (&mut self.remaining_skip, &mut self.remaining_take)
     }
 }
 
 pub struct LimitSink {
-    limit: usize,
+    limit: Option<usize>,
     offset: Option<usize>,
 }
 
 impl LimitSink {
-    pub fn new(limit: usize, offset: Option<usize>) -> Self {
+    pub fn new(limit: Option<usize>, offset: Option<usize>) -> Self {
         Self { limit, offset }
     }
 }
@@ -53,7 +53,7 @@ impl StreamingSink for LimitSink {
         &self,
         mut input: Arc<MicroPartition>,
         mut state: LimitSinkState,
-        spawner: &ExecutionTaskSpawner,
+        _spawner: &ExecutionTaskSpawner,
     ) -> StreamingSinkExecuteResult<Self> {
         let mut input_num_rows = input.len();
 
@@ -76,6 +76,12 @@ impl StreamingSink for LimitSink {
             input_num_rows = input.len();
         }
 
+        // Without a limit, all rows after the offset are passed through.
+        let Some(remaining_take) = remaining_take else {
+            return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(input)))).into();
+        };
+
+        // Slicing a micropartition only creates views over its buffers, so it is cheap enough to do inline.
         match input_num_rows.cmp(remaining_take) {
             Less => {
                 *remaining_take -= input_num_rows;
@@ -86,23 +92,20 @@ impl StreamingSink for LimitSink {
                 Ok((state, StreamingSinkOutput::Finished(Some(input)))).into()
             }
             Greater => {
-                let to_head = *remaining_take;
+                let taken = input.head(*remaining_take);
                 *remaining_take = 0;
-                spawner
-                    .spawn(
-                        async move {
-                            let taken = input.head(to_head)?;
-                            Ok((state, StreamingSinkOutput::Finished(Some(taken.into()))))
-                        },
-                        Span::current(),
-                    )
+                taken
+                    .map(|taken| (state, StreamingSinkOutput::Finished(Some(taken.into()))))
                     .into()
             }
         }
     }
 
     fn name(&self) -> NodeName {
-        format!("Limit {}", self.limit).into()
+        match self.limit {
+            Some(limit) => format!("Limit {}", limit).into(),
+            None => format!("Offset {}", self.offset.unwrap_or(0)).into(),
+        }
     }
 
     fn op_type(&self) -> NodeType {
@@ -110,9 +113,10 @@ impl StreamingSink for LimitSink {
     }
 
     fn multiline_display(&self) -> Vec<String> {
-        match &self.offset {
-            Some(o) => vec![format!("Limit: Num Rows = {}, Offset = {}", self.limit, o)],
-            None => vec![format!("Limit: {}", self.limit)],
+        match (&self.limit, &self.offset) {
+            (Some(l), Some(o)) => vec![format!("Limit: Num Rows = {}, Offset = {}", l, o)],
+            (Some(l), None) => vec![format!("Limit: {}", l)],
+            (None, o) => vec![format!("Offset: {}", o.unwrap_or(0))],
         }
     }
 
@@ -131,6 +135,11 @@ impl StreamingSink for LimitSink {
     fn max_concurrency(&self) -> usize {
         1
     }
+
+    /// Skipping rows is only deterministic if the input arrives in order, which pagination relies on.
+    fn requires_ordered_input(&self) -> bool {
+        self.offset.is_some_and(|offset| offset > 0)
+    }
     fn batching_strategy(&self) -> Self::BatchingStrategy {
         crate::dynamic_batching::StaticBatchingStrategy::new(
             self.morsel_size_requirement().unwrap_or_default(),
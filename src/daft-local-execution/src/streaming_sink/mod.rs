@@ -2,9 +2,10 @@ pub mod anti_semi_hash_join_probe;
 pub mod async_udf;
 pub mod base;
 pub mod concat;
+pub mod cross_join_sample;
 pub mod limit;
 pub mod monotonically_increasing_id;
 pub mod outer_hash_join_probe;
 pub mod sample;
-pub mod sort_merge_join_probe;
+pub mod sort_merge_join;
 pub mod vllm;
@@ -119,6 +119,7 @@ impl OuterHashJoinState {
 
 struct OuterHashJoinParams {
     probe_on: Vec<BoundExpr>,
+    residual: Option<BoundExpr>,
     common_join_cols: Vec<String>,
     left_non_join_columns: Vec<String>,
     right_non_join_columns: Vec<String>,
@@ -140,6 +141,7 @@ pub(crate) struct OuterHashJoinProbeSink {
 impl OuterHashJoinProbeSink {
     pub(crate) fn new(
         probe_on: Vec<BoundExpr>,
+        residual: Option<BoundExpr>,
         left_schema: &SchemaRef,
         right_schema: &SchemaRef,
         join_type: JoinType,
@@ -191,6 +193,7 @@ impl OuterHashJoinProbeSink {
         Ok(Self {
             params: Arc::new(OuterHashJoinParams {
                 probe_on,
+                residual,
                 common_join_cols,
                 left_non_join_columns,
                 right_non_join_columns,
@@ -426,6 +429,113 @@ impl OuterHashJoinProbeSink {
         )))
     }
 
+    /// Probes a left, right or outer join that has a residual predicate.
+    ///
+    /// Unlike the probes above, a probe row is only known to be unmatched once all of its candidate matches have been
+    /// checked against the residual, so the unmatched probe rows are emitted after the matching pairs.
+    fn probe_with_residual(
+        input: &Arc<MicroPartition>,
+        probe_state: &ProbeState,
+        mut bitmap_builder: Option<&mut IndexBitmapBuilder>,
+        residual: &BoundExpr,
+        params: &OuterHashJoinParams,
+        output_schema: &SchemaRef,
+    ) -> DaftResult<Arc<MicroPartition>> {
+        let build_side_tables = probe_state.get_record_batches().iter().collect::<Vec<_>>();
+        let keep_unmatched_probe_rows = match params.join_type {
+            JoinType::Left => !params.build_on_left,
+            JoinType::Right => params.build_on_left,
+            _ => true,
+        };
+
+        let final_tables = input
+            .record_batches()
+            .iter()
+            .map(|input_table| {
+                let join_keys = input_table.eval_expression_list(&params.probe_on)?;
+                let matches = probe_state.probe_matches(
+                    input_table,
+                    &join_keys,
+                    Some(residual),
+                    params.build_on_left,
+                )?;
+
+                if let Some(bitmap_builder) = bitmap_builder.as_deref_mut() {
+                    for (build_table_idx, build_row_idx) in &matches.build_idxs {
+                        bitmap_builder
+                            .mark_used(*build_table_idx as usize, *build_row_idx as usize);
+                    }
+                }
+
+                let (build_side_table, probe_side_table) = if keep_unmatched_probe_rows {
+                    let mut matched = vec![false; input_table.len()];
+                    for probe_row_idx in &matches.probe_idxs {
+                        matched[*probe_row_idx as usize] = true;
+                    }
+                    let unmatched_idxs = matched
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, matched)| !**matched)
+                        .map(|(idx, _)| idx as u64)
+                        .collect::<Vec<_>>();
+
+                    // Fill the build side of the unmatched probe rows with nulls.
+                    let mut null_growable =
+                        GrowableRecordBatch::new(&build_side_tables, true, unmatched_idxs.len())?;
+                    null_growable.add_nulls(unmatched_idxs.len());
+                    let unmatched_probe_side =
+                        input_table.take(&UInt64Array::from(("", unmatched_idxs.as_slice())))?;
+
+                    (
+                        RecordBatch::concat(&[matches.build_side, null_growable.build()?])?,
+                        RecordBatch::concat(&[matches.probe_side, unmatched_probe_side])?,
+                    )
+                } else {
+                    (matches.build_side, matches.probe_side)
+                };
+
+                let final_table = if params.join_type == JoinType::Outer {
+                    #[allow(deprecated)]
+                    let join_table =
+                        get_columns_by_name(&probe_side_table, &params.common_join_cols)?
+                            .cast_to_schema(&params.outer_common_col_schema)?;
+                    let left =
+                        get_columns_by_name(&build_side_table, &params.left_non_join_columns)?;
+                    let right =
+                        get_columns_by_name(&probe_side_table, &params.right_non_join_columns)?;
+                    // If we built the probe table on the right, flip the order of union.
+                    let (left, right) = if params.build_on_left {
+                        (left, right)
+                    } else {
+                        (right, left)
+                    };
+                    join_table.union(&left)?.union(&right)?
+                } else {
+                    let (left_table, right_table) = if params.build_on_left {
+                        (&build_side_table, &probe_side_table)
+                    } else {
+                        (&probe_side_table, &build_side_table)
+                    };
+                    let join_table = if params.join_type == JoinType::Left {
+                        get_columns_by_name(left_table, &params.common_join_cols)?
+                    } else {
+                        get_columns_by_name(right_table, &params.common_join_cols)?
+                    };
+                    let left = get_columns_by_name(left_table, &params.left_non_join_columns)?;
+                    let right = get_columns_by_name(right_table, &params.right_non_join_columns)?;
+                    join_table.union(&left)?.union(&right)?
+                };
+                Ok(final_table)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        Ok(Arc::new(MicroPartition::new_loaded(
+            output_schema.clone(),
+            Arc::new(final_tables),
+            None,
+        )))
+    }
+
     async fn merge_bitmaps_and_construct_null_table(
         states: Vec<OuterHashJoinState>,
     ) -> DaftResult<RecordBatch> {
@@ -582,6 +692,18 @@ impl StreamingSink for OuterHashJoinProbeSink {
                 async move {
                     let probe_state = state.get_or_build_probe_state().await;
 
+                    if let Some(residual) = &params.residual {
+                        let out = Self::probe_with_residual(
+                            &input,
+                            &probe_state,
+                            state.get_or_build_bitmap().await.as_mut(),
+                            residual,
+                            &params,
+                            &output_schema,
+                        )?;
+                        return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(out))));
+                    }
+
                     let out = match params.join_type {
                         JoinType::Left | JoinType::Right if needs_bitmap => {
                             Self::probe_left_right_with_bitmap(
@@ -673,6 +795,9 @@ impl StreamingSink for OuterHashJoinProbeSink {
                 .map(|e| e.to_string())
                 .join(", ")
         ));
+        if let Some(residual) = &self.params.residual {
+            res.push(format!("Residual: {residual}"));
+        }
         res.push(format!("Build on left: {}", self.params.build_on_left));
         res
     }
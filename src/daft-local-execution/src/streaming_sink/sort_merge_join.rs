@@ -0,0 +1,345 @@
+use std::sync::Arc;
+
+use capitalize::Capitalize;
+use common_display::tree::TreeDisplay;
+use common_error::DaftResult;
+use common_metrics::ops::{NodeCategory, NodeInfo, NodeType};
+use common_runtime::get_compute_runtime;
+use daft_core::{
+    join::{JoinSide, JoinType},
+    prelude::SchemaRef,
+};
+use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_local_plan::{LOGICAL_NODE_ID_KEY, LocalNodeContext};
+use daft_logical_plan::stats::StatsState;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tracing::{Span, info_span};
+
+use crate::{
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+    channel::{Receiver, create_channel},
+    pipeline::{MorselSizeRequirement, PipelineNode, RuntimeContext, pipeline_node_graph_details},
+    runtime_stats::{
+        CountingSender, DefaultRuntimeStats, InitializingCountingReceiver, RuntimeStats,
+    },
+};
+
+struct SortMergeJoinParams {
+    left_on: Vec<BoundExpr>,
+    right_on: Vec<BoundExpr>,
+    output_schema: SchemaRef,
+    join_type: JoinType,
+}
+
+/// The rows of each side that have been read but not merged yet, since rows still to come from the other side may
+/// match them.
+struct MergeBuffers {
+    left: RecordBatch,
+    right: RecordBatch,
+    left_done: bool,
+    right_done: bool,
+}
+
+impl MergeBuffers {
+    fn push(&mut self, side: JoinSide, input: Option<Arc<MicroPartition>>) -> DaftResult<()> {
+        let (buffer, done) = match side {
+            JoinSide::Left => (&mut self.left, &mut self.left_done),
+            JoinSide::Right => (&mut self.right, &mut self.right_done),
+        };
+        let Some(input) = input else {
+            *done = true;
+            return Ok(());
+        };
+        if !input.is_empty() {
+            let mut tables = vec![buffer.clone()];
+            tables.extend(input.record_batches().iter().cloned());
+            *buffer = RecordBatch::concat(&tables)?;
+        }
+        Ok(())
+    }
+
+    /// Merges the rows of both sides that can't match any row still to come, and returns their output along with
+    /// the side to read next, or `None` once both sides are done.
+    fn merge(
+        &mut self,
+        params: &SortMergeJoinParams,
+    ) -> DaftResult<(Option<Arc<MicroPartition>>, Option<JoinSide>)> {
+        let frontier = self.left.merge_join_frontier(
+            &self.right,
+            &params.left_on,
+            &params.right_on,
+            self.left_done,
+            self.right_done,
+        )?;
+        if frontier.left_len == 0 && frontier.right_len == 0 && frontier.next.is_some() {
+            return Ok((None, frontier.next));
+        }
+
+        let left = self.left.slice(0, frontier.left_len)?;
+        let right = self.right.slice(0, frontier.right_len)?;
+        self.left = self.left.slice(frontier.left_len, self.left.len())?;
+        self.right = self.right.slice(frontier.right_len, self.right.len())?;
+
+        let joined = left.sort_merge_join(
+            &right,
+            &params.left_on,
+            &params.right_on,
+            params.join_type,
+            true,
+        )?;
+        let output = (!joined.is_empty()).then(|| {
+            Arc::new(MicroPartition::new_loaded(
+                params.output_schema.clone(),
+                Arc::new(vec![joined]),
+                None,
+            ))
+        });
+        Ok((output, frontier.next))
+    }
+}
+
+/// Executes a sort-merge join over two inputs which are sorted in ascending order with nulls last on their join keys.
+///
+/// Both inputs are streamed in order, always reading from the side whose buffered rows end at the smaller join key.
+/// Rows are merged as soon as no row still to come from the other side can match them, so only the rows sharing the
+/// largest join key read so far, plus at most the last morsel read from each side, are held in memory.
+pub(crate) struct SortMergeJoinNode {
+    params: Arc<SortMergeJoinParams>,
+    children: Vec<Box<dyn PipelineNode>>,
+    runtime_stats: Arc<dyn RuntimeStats>,
+    plan_stats: StatsState,
+    node_info: Arc<NodeInfo>,
+    morsel_size_requirement: MorselSizeRequirement,
+}
+
+impl SortMergeJoinNode {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        left_on: Vec<BoundExpr>,
+        right_on: Vec<BoundExpr>,
+        join_type: JoinType,
+        left: Box<dyn PipelineNode>,
+        right: Box<dyn PipelineNode>,
+        plan_stats: StatsState,
+        ctx: &RuntimeContext,
+        output_schema: SchemaRef,
+        context: &LocalNodeContext,
+    ) -> Self {
+        let node_info = ctx.next_node_info(
+            "SortMergeJoin".into(),
+            NodeType::SortMergeJoin,
+            NodeCategory::StreamingSink,
+            output_schema.clone(),
+            context,
+        );
+        let runtime_stats = Arc::new(DefaultRuntimeStats::new(node_info.id));
+        Self {
+            params: Arc::new(SortMergeJoinParams {
+                left_on,
+                right_on,
+                output_schema,
+                join_type,
+            }),
+            children: vec![left, right],
+            runtime_stats,
+            plan_stats,
+            node_info: Arc::new(node_info),
+            morsel_size_requirement: MorselSizeRequirement::default(),
+        }
+    }
+
+    pub(crate) fn boxed(self) -> Box<dyn PipelineNode> {
+        Box::new(self)
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "Sort Merge Join".to_string(),
+            format!("Join type: {}", self.params.join_type),
+        ]
+    }
+}
+
+impl TreeDisplay for SortMergeJoinNode {
+    fn id(&self) -> String {
+        self.node_id().to_string()
+    }
+
+    fn display_as(&self, level: common_display::DisplayLevel) -> String {
+        use std::fmt::Write;
+        let mut display = String::new();
+
+        use common_display::DisplayLevel;
+        match level {
+            DisplayLevel::Compact => {
+                writeln!(display, "Sort Merge Join").unwrap();
+            }
+            level => {
+                writeln!(display, "{}", self.multiline_display().join("\n")).unwrap();
+                if let StatsState::Materialized(stats) = &self.plan_stats {
+                    writeln!(display, "Stats = {}", stats).unwrap();
+                }
+                writeln!(display, "Batch Size = {}", self.morsel_size_requirement).unwrap();
+                if matches!(level, DisplayLevel::Verbose) {
+                    let rt_result = self.runtime_stats.snapshot();
+                    for (name, value) in rt_result {
+                        writeln!(display, "{} = {}", name.as_ref().capitalize(), value).unwrap();
+                    }
+                }
+            }
+        }
+        display
+    }
+
+    fn repr_json(&self) -> serde_json::Value {
+        let children: Vec<serde_json::Value> = self
+            .get_children()
+            .iter()
+            .map(|child| child.repr_json())
+            .collect();
+
+        serde_json::json!({
+            "id": self.node_id(),
+            "category": "StreamingSink",
+            "type": self.node_info.node_type.to_string(),
+            "name": self.name(),
+            "logical_node_id": self.node_info.context.get(LOGICAL_NODE_ID_KEY),
+            "children": children,
+        })
+    }
+
+    fn repr_graph_details(&self) -> serde_json::Value {
+        pipeline_node_graph_details(self, &self.plan_stats)
+    }
+
+    fn get_children(&self) -> Vec<&dyn TreeDisplay> {
+        self.children()
+            .iter()
+            .map(|v| v.as_tree_display())
+            .collect()
+    }
+}
+
+impl PipelineNode for SortMergeJoinNode {
+    fn children(&self) -> Vec<&dyn PipelineNode> {
+        self.children
+            .iter()
+            .map(std::convert::AsRef::as_ref)
+            .collect()
+    }
+
+    fn boxed_children(&self) -> Vec<&Box<dyn PipelineNode>> {
+        self.children.iter().collect()
+    }
+
+    fn name(&self) -> Arc<str> {
+        self.node_info.name.clone()
+    }
+
+    fn propagate_morsel_size_requirement(
+        &mut self,
+        downstream_requirement: MorselSizeRequirement,
+        default_requirement: MorselSizeRequirement,
+    ) {
+        self.morsel_size_requirement = downstream_requirement;
+        for child in &mut self.children {
+            child.propagate_morsel_size_requirement(downstream_requirement, default_requirement);
+        }
+    }
+
+    fn start(
+        &self,
+        _maintain_order: bool,
+        runtime_handle: &mut ExecutionRuntimeContext,
+    ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        // Merging relies on each side arriving in sorted order
+        let mut receivers = Vec::with_capacity(self.children.len());
+        for child in &self.children {
+            receivers.push(InitializingCountingReceiver::new(
+                child.start(true, runtime_handle)?,
+                self.node_id(),
+                self.runtime_stats.clone(),
+                runtime_handle.stats_manager(),
+            ));
+        }
+        let right_receiver = receivers.pop().expect("Sort merge join has a right side");
+        let left_receiver = receivers.pop().expect("Sort merge join has a left side");
+
+        let (destination_sender, destination_receiver) = create_channel(0);
+        let counting_sender = CountingSender::new(destination_sender, self.runtime_stats.clone());
+
+        let params = self.params.clone();
+        let spawner = ExecutionTaskSpawner::new(
+            get_compute_runtime(),
+            runtime_handle.memory_manager(),
+            self.runtime_stats.clone(),
+            info_span!("SortMergeJoin::Execute"),
+        );
+        let stats_manager = runtime_handle.stats_manager();
+        let node_id = self.node_id();
+        let left_schema = self.children[0].node_info().output_schema.clone();
+        let right_schema = self.children[1].node_info().output_schema.clone();
+        runtime_handle.spawn(
+            async move {
+                let mut buffers = MergeBuffers {
+                    left: RecordBatch::empty(Some(left_schema)),
+                    right: RecordBatch::empty(Some(right_schema)),
+                    left_done: false,
+                    right_done: false,
+                };
+                let mut next = Some(JoinSide::Left);
+                while let Some(side) = next {
+                    let input = match side {
+                        JoinSide::Left => left_receiver.recv().await,
+                        JoinSide::Right => right_receiver.recv().await,
+                    };
+                    let params = params.clone();
+                    let (merged_buffers, output, next_side) = spawner
+                        .spawn(
+                            async move {
+                                buffers.push(side, input)?;
+                                let (output, next_side) = buffers.merge(&params)?;
+                                Ok((buffers, output, next_side))
+                            },
+                            Span::current(),
+                        )
+                        .await??;
+                    buffers = merged_buffers;
+                    next = next_side;
+
+                    if let Some(output) = output
+                        && counting_sender.send(output).await.is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+
+                stats_manager.finalize_node(node_id);
+                Ok(())
+            },
+            &self.node_info,
+        );
+        Ok(destination_receiver)
+    }
+
+    fn as_tree_display(&self) -> &dyn TreeDisplay {
+        self
+    }
+
+    fn node_id(&self) -> usize {
+        self.node_info.id
+    }
+
+    fn plan_id(&self) -> Arc<str> {
+        Arc::from(self.node_info.context.get("plan_id").unwrap().clone())
+    }
+
+    fn node_info(&self) -> Arc<NodeInfo> {
+        self.node_info.clone()
+    }
+
+    fn runtime_stats(&self) -> Arc<dyn RuntimeStats> {
+        self.runtime_stats.clone()
+    }
+}
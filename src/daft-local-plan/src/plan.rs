@@ -20,18 +20,34 @@ use daft_dsl::{
     functions::python::{RuntimePyObject, UDFProperties, get_resource_request},
 };
 use daft_logical_plan::{
-    InMemoryInfo, OutputFileInfo,
+    InMemoryInfo, LogicalPlan, OutputFileInfo,
     partitioning::RepartitionSpec,
     stats::{PlanStats, StatsState},
 };
 use serde::{Deserialize, Serialize};
 
+/// Key in [`LocalNodeContext::additional`] holding the id of the logical plan node a physical node
+/// was translated from.
+pub const LOGICAL_NODE_ID_KEY: &str = "logical_node_id";
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct LocalNodeContext {
     pub origin_node_id: Option<usize>,
     pub additional: Option<HashMap<String, String>>,
 }
 
+impl LocalNodeContext {
+    /// Context for a node translated from `plan`, recording the logical node id if one is assigned.
+    pub fn from_logical(plan: &LogicalPlan) -> Self {
+        Self {
+            origin_node_id: None,
+            additional: plan.node_id().map(|node_id| {
+                HashMap::from([(LOGICAL_NODE_ID_KEY.to_string(), node_id.to_string())])
+            }),
+        }
+    }
+}
+
 pub type LocalPhysicalPlanRef = Arc<LocalPhysicalPlan>;
 #[derive(strum::IntoStaticStr, Serialize, Deserialize)]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -63,9 +79,11 @@ pub enum LocalPhysicalPlan {
     HashAggregate(HashAggregate),
     Dedup(Dedup),
     Pivot(Pivot),
+    Resample(Resample),
     Concat(Concat),
     HashJoin(HashJoin),
     CrossJoin(CrossJoin),
+    SortedLookupJoin(SortedLookupJoin),
     // BroadcastJoin(BroadcastJoin),
     PhysicalWrite(PhysicalWrite),
     CommitWrite(CommitWrite),
@@ -131,9 +149,11 @@ impl LocalPhysicalPlan {
             | Self::HashAggregate(HashAggregate { stats_state, .. })
             | Self::Dedup(Dedup { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
+            | Self::Resample(Resample { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
             | Self::HashJoin(HashJoin { stats_state, .. })
             | Self::CrossJoin(CrossJoin { stats_state, .. })
+            | Self::SortedLookupJoin(SortedLookupJoin { stats_state, .. })
             | Self::SortMergeJoin(SortMergeJoin { stats_state, .. })
             | Self::PhysicalWrite(PhysicalWrite { stats_state, .. })
             | Self::CommitWrite(CommitWrite { stats_state, .. })
@@ -180,9 +200,11 @@ impl LocalPhysicalPlan {
             | Self::HashAggregate(HashAggregate { context, .. })
             | Self::Dedup(Dedup { context, .. })
             | Self::Pivot(Pivot { context, .. })
+            | Self::Resample(Resample { context, .. })
             | Self::Concat(Concat { context, .. })
             | Self::HashJoin(HashJoin { context, .. })
             | Self::CrossJoin(CrossJoin { context, .. })
+            | Self::SortedLookupJoin(SortedLookupJoin { context, .. })
             | Self::SortMergeJoin(SortMergeJoin { context, .. })
             | Self::PhysicalWrite(PhysicalWrite { context, .. })
             | Self::CommitWrite(CommitWrite { context, .. })
@@ -321,7 +343,7 @@ impl LocalPhysicalPlan {
         let schema = input.schema().clone();
         Self::Limit(Limit {
             input,
-            limit,
+            limit: Some(limit),
             offset,
             schema,
             stats_state,
@@ -330,9 +352,30 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    /// Skips the first `offset` rows of the input and passes through the rest.
+    pub fn offset(
+        input: LocalPhysicalPlanRef,
+        offset: u64,
+        stats_state: StatsState,
+        context: LocalNodeContext,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::Limit(Limit {
+            input,
+            limit: None,
+            offset: Some(offset),
+            schema,
+            stats_state,
+            context,
+        })
+        .arced()
+    }
+
     pub fn explode(
         input: LocalPhysicalPlanRef,
         to_explode: Vec<BoundExpr>,
+        index_column: Option<String>,
+        pad_mismatched: bool,
         schema: SchemaRef,
         stats_state: StatsState,
         context: LocalNodeContext,
@@ -340,6 +383,8 @@ impl LocalPhysicalPlan {
         Self::Explode(Explode {
             input,
             to_explode,
+            index_column,
+            pad_mismatched,
             schema,
             stats_state,
             context,
@@ -618,6 +663,27 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub fn resample(
+        input: LocalPhysicalPlanRef,
+        num_keys: usize,
+        every: i64,
+        fill: Option<GapFill>,
+        schema: SchemaRef,
+        stats_state: StatsState,
+        context: LocalNodeContext,
+    ) -> LocalPhysicalPlanRef {
+        Self::Resample(Resample {
+            input,
+            num_keys,
+            every,
+            fill,
+            schema,
+            stats_state,
+            context,
+        })
+        .arced()
+    }
+
     pub fn sort(
         input: LocalPhysicalPlanRef,
         sort_by: Vec<BoundExpr>,
@@ -713,6 +779,7 @@ impl LocalPhysicalPlan {
         right_on: Vec<BoundExpr>,
         build_on_left: Option<bool>,
         null_equals_null: Option<Vec<bool>>,
+        residual: Option<BoundExpr>,
         join_type: JoinType,
         schema: SchemaRef,
         stats_state: StatsState,
@@ -725,6 +792,7 @@ impl LocalPhysicalPlan {
             right_on,
             build_on_left,
             null_equals_null,
+            residual,
             join_type,
             schema,
             stats_state,
@@ -750,6 +818,9 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    /// A join which merges both sides as they stream in, sorted in ascending order with nulls last on their join
+    /// keys. Each side is sorted before it is merged unless it is already sorted, as `left_is_sorted` and
+    /// `right_is_sorted` state.
     #[allow(clippy::too_many_arguments)]
     pub fn sort_merge_join(
         left: LocalPhysicalPlanRef,
@@ -757,6 +828,8 @@ impl LocalPhysicalPlan {
         left_on: Vec<BoundExpr>,
         right_on: Vec<BoundExpr>,
         join_type: JoinType,
+        left_is_sorted: bool,
+        right_is_sorted: bool,
         schema: SchemaRef,
         stats_state: StatsState,
         context: LocalNodeContext,
@@ -767,6 +840,36 @@ impl LocalPhysicalPlan {
             left_on,
             right_on,
             join_type,
+            left_is_sorted,
+            right_is_sorted,
+            schema,
+            stats_state,
+            context,
+        })
+        .arced()
+    }
+
+    /// A join which binary searches the join keys of the right side, sorted in ascending order with nulls last, for
+    /// the join keys of each left row. The right side is sorted before it is searched unless `right_is_sorted`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sorted_lookup_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: Vec<BoundExpr>,
+        right_on: Vec<BoundExpr>,
+        join_type: JoinType,
+        right_is_sorted: bool,
+        schema: SchemaRef,
+        stats_state: StatsState,
+        context: LocalNodeContext,
+    ) -> LocalPhysicalPlanRef {
+        Self::SortedLookupJoin(SortedLookupJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            join_type,
+            right_is_sorted,
             schema,
             stats_state,
             context,
@@ -815,6 +918,7 @@ impl LocalPhysicalPlan {
         data_schema: SchemaRef,
         file_schema: SchemaRef,
         file_info: OutputFileInfo<BoundExpr>,
+        staged_write_id: Option<String>,
         stats_state: StatsState,
         context: LocalNodeContext,
     ) -> LocalPhysicalPlanRef {
@@ -823,6 +927,7 @@ impl LocalPhysicalPlan {
             data_schema,
             file_schema,
             file_info,
+            staged_write_id,
             stats_state,
             context,
         })
@@ -959,11 +1064,13 @@ impl LocalPhysicalPlan {
             | Self::HashAggregate(HashAggregate { schema, .. })
             | Self::Dedup(Dedup { schema, .. })
             | Self::Pivot(Pivot { schema, .. })
+            | Self::Resample(Resample { schema, .. })
             | Self::Sort(Sort { schema, .. })
             | Self::TopN(TopN { schema, .. })
             | Self::Sample(Sample { schema, .. })
             | Self::HashJoin(HashJoin { schema, .. })
             | Self::CrossJoin(CrossJoin { schema, .. })
+            | Self::SortedLookupJoin(SortedLookupJoin { schema, .. })
             | Self::SortMergeJoin(SortMergeJoin { schema, .. })
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
@@ -1039,6 +1146,7 @@ impl LocalPhysicalPlan {
             | Self::HashAggregate(HashAggregate { input, .. })
             | Self::Dedup(Dedup { input, .. })
             | Self::Pivot(Pivot { input, .. })
+            | Self::Resample(Resample { input, .. })
             | Self::Sort(Sort { input, .. })
             | Self::Sample(Sample { input, .. })
             | Self::Explode(Explode { input, .. })
@@ -1055,6 +1163,9 @@ impl LocalPhysicalPlan {
 
             Self::HashJoin(HashJoin { left, right, .. }) => vec![left.clone(), right.clone()],
             Self::CrossJoin(CrossJoin { left, right, .. }) => vec![left.clone(), right.clone()],
+            Self::SortedLookupJoin(SortedLookupJoin { left, right, .. }) => {
+                vec![left.clone(), right.clone()]
+            }
             Self::SortMergeJoin(SortMergeJoin { left, right, .. }) => {
                 vec![left.clone(), right.clone()]
             }
@@ -1106,7 +1217,7 @@ impl LocalPhysicalPlan {
                     context.clone(),
                 ),
                 Self::Limit(Limit {
-                    limit,
+                    limit: Some(limit),
                     offset,
                     context,
                     ..
@@ -1117,6 +1228,17 @@ impl LocalPhysicalPlan {
                     StatsState::NotMaterialized,
                     context.clone(),
                 ),
+                Self::Limit(Limit {
+                    limit: None,
+                    offset,
+                    context,
+                    ..
+                }) => Self::offset(
+                    new_child.clone(),
+                    offset.unwrap_or(0),
+                    StatsState::NotMaterialized,
+                    context.clone(),
+                ),
                 Self::Project(Project {
                     projection,
                     schema,
@@ -1205,6 +1327,22 @@ impl LocalPhysicalPlan {
                     StatsState::NotMaterialized,
                     context.clone(),
                 ),
+                Self::Resample(Resample {
+                    num_keys,
+                    every,
+                    fill,
+                    schema,
+                    context,
+                    ..
+                }) => Self::resample(
+                    new_child.clone(),
+                    *num_keys,
+                    *every,
+                    *fill,
+                    schema.clone(),
+                    StatsState::NotMaterialized,
+                    context.clone(),
+                ),
                 Self::Sort(Sort {
                     sort_by,
                     descending,
@@ -1237,12 +1375,16 @@ impl LocalPhysicalPlan {
                 ),
                 Self::Explode(Explode {
                     to_explode,
+                    index_column,
+                    pad_mismatched,
                     schema,
                     context,
                     ..
                 }) => Self::explode(
                     new_child.clone(),
                     to_explode.clone(),
+                    index_column.clone(),
+                    *pad_mismatched,
                     schema.clone(),
                     StatsState::NotMaterialized,
                     context.clone(),
@@ -1413,6 +1555,7 @@ impl LocalPhysicalPlan {
                     stats_state,
                     file_schema,
                     file_info,
+                    staged_write_id,
                     context,
                     ..
                 }) => Self::commit_write(
@@ -1420,6 +1563,7 @@ impl LocalPhysicalPlan {
                     data_schema.clone(),
                     file_schema.clone(),
                     file_info.clone(),
+                    staged_write_id.clone(),
                     stats_state.clone(),
                     context.clone(),
                 ),
@@ -1534,6 +1678,11 @@ impl LocalPhysicalPlan {
                 Self::CrossJoin(_) => {
                     panic!("LocalPhysicalPlan::with_new_children: CrossJoin should have 2 children")
                 }
+                Self::SortedLookupJoin(_) => {
+                    panic!(
+                        "LocalPhysicalPlan::with_new_children: SortedLookupJoin should have 2 children"
+                    )
+                }
                 Self::SortMergeJoin(_) => {
                     panic!(
                         "LocalPhysicalPlan::with_new_children: SortMergeJoin should have 2 children"
@@ -1551,6 +1700,7 @@ impl LocalPhysicalPlan {
                     left_on,
                     right_on,
                     null_equals_null,
+                    residual,
                     join_type,
                     build_on_left,
                     schema,
@@ -1564,6 +1714,7 @@ impl LocalPhysicalPlan {
                     right_on.clone(),
                     *build_on_left,
                     null_equals_null.clone(),
+                    residual.clone(),
                     *join_type,
                     schema.clone(),
                     stats_state.clone(),
@@ -1585,6 +1736,8 @@ impl LocalPhysicalPlan {
                     left_on,
                     right_on,
                     join_type,
+                    left_is_sorted,
+                    right_is_sorted,
                     schema,
                     stats_state,
                     context,
@@ -1595,6 +1748,28 @@ impl LocalPhysicalPlan {
                     left_on.clone(),
                     right_on.clone(),
                     *join_type,
+                    *left_is_sorted,
+                    *right_is_sorted,
+                    schema.clone(),
+                    stats_state.clone(),
+                    context.clone(),
+                ),
+                Self::SortedLookupJoin(SortedLookupJoin {
+                    left_on,
+                    right_on,
+                    join_type,
+                    right_is_sorted,
+                    schema,
+                    stats_state,
+                    context,
+                    ..
+                }) => Self::sorted_lookup_join(
+                    new_left.clone(),
+                    new_right.clone(),
+                    left_on.clone(),
+                    right_on.clone(),
+                    *join_type,
+                    *right_is_sorted,
                     schema.clone(),
                     stats_state.clone(),
                     context.clone(),
@@ -1755,7 +1930,8 @@ pub struct IntoBatches {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct Limit {
     pub input: LocalPhysicalPlanRef,
-    pub limit: u64,
+    // None if only the offset is applied.
+    pub limit: Option<u64>,
     pub offset: Option<u64>,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
@@ -1767,6 +1943,8 @@ pub struct Limit {
 pub struct Explode {
     pub input: LocalPhysicalPlanRef,
     pub to_explode: Vec<BoundExpr>,
+    pub index_column: Option<String>,
+    pub pad_mismatched: bool,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
     pub context: LocalNodeContext,
@@ -1886,6 +2064,20 @@ pub struct Pivot {
     pub context: LocalNodeContext,
 }
 
+/// Fills the gaps of a time series which was aggregated into intervals that are `every` apart, with its `num_keys`
+/// key columns first followed by the starts of the intervals.
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct Resample {
+    pub input: LocalPhysicalPlanRef,
+    pub num_keys: usize,
+    pub every: i64,
+    pub fill: Option<GapFill>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+    pub context: LocalNodeContext,
+}
+
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct HashJoin {
@@ -1895,6 +2087,8 @@ pub struct HashJoin {
     pub right_on: Vec<BoundExpr>,
     pub build_on_left: Option<bool>,
     pub null_equals_null: Option<Vec<bool>>,
+    /// Bound to the columns of the left side followed by the columns of the right side.
+    pub residual: Option<BoundExpr>,
     pub join_type: JoinType,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
@@ -1911,6 +2105,20 @@ pub struct CrossJoin {
     pub context: LocalNodeContext,
 }
 
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct SortedLookupJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: Vec<BoundExpr>,
+    pub right_on: Vec<BoundExpr>,
+    pub join_type: JoinType,
+    pub right_is_sorted: bool,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+    pub context: LocalNodeContext,
+}
+
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct SortMergeJoin {
@@ -1919,6 +2127,8 @@ pub struct SortMergeJoin {
     pub left_on: Vec<BoundExpr>,
     pub right_on: Vec<BoundExpr>,
     pub join_type: JoinType,
+    pub left_is_sorted: bool,
+    pub right_is_sorted: bool,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
     pub context: LocalNodeContext,
@@ -1952,6 +2162,9 @@ pub struct CommitWrite {
     pub data_schema: SchemaRef,
     pub file_schema: SchemaRef,
     pub file_info: OutputFileInfo<BoundExpr>,
+    /// The id of the write if its files were written to its staging directory, to be moved to the root directory
+    /// when the write is committed.
+    pub staged_write_id: Option<String>,
     pub stats_state: StatsState,
     pub context: LocalNodeContext,
 }
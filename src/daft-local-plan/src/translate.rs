@@ -2,19 +2,131 @@ use std::sync::Arc;
 
 use common_error::{DaftError, DaftResult};
 use common_scan_info::ScanState;
-use daft_core::join::JoinStrategy;
+use daft_core::join::{JoinSide, JoinStrategy};
 use daft_dsl::{
+    ExprRef,
     expr::{
         agg::extract_agg_expr,
         bound_expr::{BoundAggExpr, BoundExpr, BoundVLLMExpr, BoundWindowExpr},
     },
+    exprs_to_schema,
     join::normalize_join_keys,
     resolved_col, window_to_agg_exprs,
 };
-use daft_logical_plan::{JoinType, LogicalPlan, LogicalPlanRef, SourceInfo, stats::StatsState};
+use daft_logical_plan::{
+    JoinType, LogicalPlan, LogicalPlanRef, SourceInfo, ops::Join, stats::StatsState,
+};
 
 use super::plan::{LocalNodeContext, LocalPhysicalPlan, LocalPhysicalPlanRef, SamplingMethod};
 
+/// Decides whether a join is executed as a sorted lookup join, which it is if it's hinted to be, or if no strategy is
+/// hinted and its right side is already sorted on its join keys in the order that the lookup expects.
+///
+/// Returns whether the right side is already sorted on its join keys in the order that the lookup expects if the join
+/// is executed as a sorted lookup join.
+fn sorted_lookup_join_order(
+    join: &Join,
+    right_on: &[ExprRef],
+    null_equals_nulls: &[bool],
+    has_residual: bool,
+) -> Option<bool> {
+    // Lookups never match null keys, only match on the join keys, and can't tell which right rows were matched by
+    // every left morsel
+    let is_supported = matches!(
+        join.join_type,
+        JoinType::Inner | JoinType::Left | JoinType::Semi | JoinType::Anti
+    ) && !null_equals_nulls.iter().any(|b| *b)
+        && !has_residual;
+
+    let right_is_sorted = right_on
+        .iter()
+        .map(|e| e.input_mapping())
+        .collect::<Option<Vec<_>>>()
+        .is_some_and(|keys| is_sorted_on(&join.right, keys));
+
+    match join.join_strategy {
+        Some(JoinStrategy::SortedLookup) if is_supported => Some(right_is_sorted),
+        Some(JoinStrategy::SortedLookup) => {
+            log::warn!(
+                "Sorted lookup join only supports inner, left, semi and anti joins without null-safe equality or non-equality predicates, falling back to hash join."
+            );
+            None
+        }
+        None if is_supported && right_is_sorted => Some(true),
+        _ => None,
+    }
+}
+
+/// Decides whether a join is executed as a sort-merge join, which it is if it's hinted to be, or if no strategy is
+/// hinted and both sides are already sorted on their join keys, since merging them then needs neither a sort nor a
+/// hash table.
+///
+/// Returns whether the left and right sides are already sorted on their join keys in the order that the merge expects
+/// if the join is executed as a sort-merge join.
+fn sort_merge_join_order(
+    join: &Join,
+    left_on: &[ExprRef],
+    right_on: &[ExprRef],
+    null_equals_nulls: &[bool],
+    has_residual: bool,
+) -> Option<(bool, bool)> {
+    let is_supported = !null_equals_nulls.iter().any(|b| *b) && !has_residual;
+
+    let side_is_sorted = |plan: &LogicalPlan, on: &[ExprRef]| {
+        on.iter()
+            .map(|e| e.input_mapping())
+            .collect::<Option<Vec<_>>>()
+            .is_some_and(|keys| is_sorted_on(plan, keys))
+    };
+    let left_is_sorted = side_is_sorted(&join.left, left_on);
+    let right_is_sorted = side_is_sorted(&join.right, right_on);
+
+    match join.join_strategy {
+        Some(JoinStrategy::SortMerge) if is_supported => Some((left_is_sorted, right_is_sorted)),
+        Some(JoinStrategy::SortMerge) => {
+            log::warn!(
+                "Sort merge join does not support null-safe equality or non-equality predicates, falling back to hash join."
+            );
+            None
+        }
+        None if is_supported && left_is_sorted && right_is_sorted => Some((true, true)),
+        _ => None,
+    }
+}
+
+/// Returns whether the output of a plan is sorted in ascending order with nulls last on the given columns, looking
+/// through the filters and projections which preserve the order of a sort.
+fn is_sorted_on(plan: &LogicalPlan, mut keys: Vec<String>) -> bool {
+    match plan {
+        LogicalPlan::Sort(sort) => {
+            sort.sort_by.len() >= keys.len()
+                && sort
+                    .sort_by
+                    .iter()
+                    .zip(&keys)
+                    .all(|(by, key)| by.input_mapping().as_ref() == Some(key))
+                && !sort.descending[..keys.len()].iter().any(|b| *b)
+                && !sort.nulls_first[..keys.len()].iter().any(|b| *b)
+        }
+        LogicalPlan::Filter(filter) => is_sorted_on(&filter.input, keys),
+        LogicalPlan::Project(project) => {
+            for key in &mut keys {
+                match project
+                    .projection
+                    .iter()
+                    .find(|e| e.name() == key.as_str())
+                    .and_then(|e| e.input_mapping())
+                {
+                    Some(input_key) => *key = input_key,
+                    None => return false,
+                }
+            }
+            is_sorted_on(&project.input, keys)
+        }
+        _ => false,
+    }
+}
+
 pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
     match plan.as_ref() {
         LogicalPlan::Source(source) => {
@@ -22,7 +134,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 SourceInfo::InMemory(info) => Ok(LocalPhysicalPlan::in_memory_scan(
                     info.clone(),
                     source.stats_state.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 )),
                 SourceInfo::Physical(info) => {
                     // We should be able to pass the ScanOperator into the physical plan directly but we need to figure out the serialization story
@@ -35,7 +147,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     if scan_tasks.is_empty() {
                         Ok(LocalPhysicalPlan::empty_scan(
                             source.output_schema.clone(),
-                            LocalNodeContext::default(),
+                            LocalNodeContext::from_logical(plan),
                         ))
                     } else {
                         Ok(LocalPhysicalPlan::physical_scan(
@@ -43,7 +155,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                             info.pushdowns.clone(),
                             source.output_schema.clone(),
                             source.stats_state.clone(),
-                            LocalNodeContext::default(),
+                            LocalNodeContext::from_logical(plan),
                         ))
                     }
                 }
@@ -53,12 +165,12 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     source.output_schema.clone(),
                     source.stats_state.clone(),
                     info.io_config.clone().map(|c| *c),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 )),
                 SourceInfo::PlaceHolder(ph) => Ok(LocalPhysicalPlan::placeholder_scan(
                     ph.source_schema.clone(),
                     StatsState::NotMaterialized,
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 )),
             }
         }
@@ -72,7 +184,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 input,
                 predicate,
                 filter.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::IntoBatches(into_batches) => {
@@ -82,7 +194,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 into_batches.batch_size,
                 false,
                 into_batches.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Limit(limit) => {
@@ -92,7 +204,16 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 limit.limit,
                 limit.offset,
                 limit.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
+            ))
+        }
+        LogicalPlan::Offset(offset) => {
+            let input = translate(&offset.input)?;
+            Ok(LocalPhysicalPlan::offset(
+                input,
+                offset.offset,
+                offset.stats_state.clone(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Project(project) => {
@@ -105,7 +226,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 projection,
                 project.projected_schema.clone(),
                 project.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::UDFProject(udf_project) => {
@@ -122,7 +243,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 passthrough_columns,
                 udf_project.projected_schema.clone(),
                 udf_project.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Sample(sample) => {
@@ -142,7 +263,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 sample.with_replacement,
                 sample.seed,
                 sample.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Aggregate(aggregate) => {
@@ -165,7 +286,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     aggregations,
                     aggregate.output_schema.clone(),
                     aggregate.stats_state.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 ))
             } else {
                 Ok(LocalPhysicalPlan::hash_aggregate(
@@ -174,7 +295,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     groupby,
                     aggregate.output_schema.clone(),
                     aggregate.stats_state.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 ))
             }
         }
@@ -203,7 +324,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                         window.stats_state.clone(),
                         aggregations,
                         window.aliases.clone(),
-                        LocalNodeContext::default(),
+                        LocalNodeContext::from_logical(plan),
                     ))
                 }
                 (true, true, false) => Ok(LocalPhysicalPlan::window_partition_and_order_by(
@@ -216,7 +337,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     window.stats_state.clone(),
                     window_functions,
                     window.aliases.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 )),
                 (true, true, true) => {
                     let aggregations = window_to_agg_exprs(window_functions)?;
@@ -232,7 +353,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                         window.stats_state.clone(),
                         aggregations,
                         window.aliases.clone(),
-                        LocalNodeContext::default(),
+                        LocalNodeContext::from_logical(plan),
                     ))
                 }
                 (false, true, false) => Ok(LocalPhysicalPlan::window_order_by_only(
@@ -244,7 +365,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     window.stats_state.clone(),
                     window_functions,
                     window.aliases.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 )),
                 (false, true, true) => Err(DaftError::not_implemented(
                     "Window with order by and frame not yet implemented",
@@ -268,7 +389,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 unpivot.value_name.clone(),
                 unpivot.output_schema.clone(),
                 unpivot.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Pivot(pivot) => {
@@ -289,7 +410,44 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 true,
                 pivot.output_schema.clone(),
                 pivot.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
+            ))
+        }
+        LogicalPlan::Resample(resample) => {
+            let input = translate(&resample.input)?;
+
+            // Aggregate the rows into their intervals, then fill the intervals between them.
+            let groupby = [resample.by.clone(), vec![resample.bucket()]].concat();
+            let agg_schema = exprs_to_schema(
+                &[groupby.as_slice(), resample.aggregations.as_slice()].concat(),
+                input.schema().clone(),
+            )?;
+            let aggregations = resample
+                .aggregations
+                .iter()
+                .map(|expr| {
+                    let agg_expr = extract_agg_expr(expr)?;
+                    BoundAggExpr::try_new(agg_expr, input.schema())
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            let groupby = BoundExpr::bind_all(&groupby, input.schema())?;
+            let aggregated = LocalPhysicalPlan::hash_aggregate(
+                input,
+                aggregations,
+                groupby,
+                agg_schema,
+                resample.stats_state.clone(),
+                LocalNodeContext::from_logical(plan),
+            );
+
+            Ok(LocalPhysicalPlan::resample(
+                aggregated,
+                resample.by.len(),
+                resample.every,
+                resample.fill,
+                resample.output_schema.clone(),
+                resample.stats_state.clone(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Sort(sort) => {
@@ -303,7 +461,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 sort.descending.clone(),
                 sort.nulls_first.clone(),
                 sort.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::TopN(top_n) => {
@@ -319,60 +477,107 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 top_n.limit,
                 top_n.offset,
                 top_n.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Join(join) => {
-            if let Some(strategy) = join.join_strategy {
-                match strategy {
-                    JoinStrategy::Broadcast => {
-                        log::warn!(
-                            "Broadcast join is not supported on the native runner, falling back to hash join. Please use the ray runner, daft.set_runner_ray(), if you require broadcast joins."
-                        );
-                    }
-                    JoinStrategy::SortMerge => {
-                        log::warn!(
-                            "Sort merge join is not supported on the native runner, falling back to hash join."
-                        );
-                    }
-                    _ => {}
-                }
-            }
             let left = translate(&join.left)?;
             let right = translate(&join.right)?;
 
             let (remaining_on, left_on, right_on, null_equals_nulls) = join.on.split_eq_preds();
-
-            if !remaining_on.is_empty() {
-                return Err(DaftError::not_implemented("Execution of non-equality join"));
-            }
+            let residual = remaining_on.bind_join_sides(left.schema(), right.schema())?;
 
             let (left_on, right_on) =
                 normalize_join_keys(left_on, right_on, join.left.schema(), join.right.schema())?;
 
             if left_on.is_empty() && right_on.is_empty() && join.join_type == JoinType::Inner {
-                Ok(LocalPhysicalPlan::cross_join(
+                let cross_join = LocalPhysicalPlan::cross_join(
+                    left,
+                    right,
+                    join.output_schema.clone(),
+                    join.stats_state.clone(),
+                    LocalNodeContext::from_logical(plan),
+                );
+                // The output of a cross join is the left columns followed by the right columns, which is the layout
+                // that the residual is bound to.
+                Ok(match residual {
+                    Some(residual) => LocalPhysicalPlan::filter(
+                        cross_join,
+                        residual,
+                        join.stats_state.clone(),
+                        LocalNodeContext::from_logical(plan),
+                    ),
+                    None => cross_join,
+                })
+            } else if left_on.is_empty() && residual.is_some() {
+                Err(DaftError::not_implemented(format!(
+                    "Execution of {} join without any equality predicates",
+                    join.join_type
+                )))
+            } else if let Some((left_is_sorted, right_is_sorted)) = sort_merge_join_order(
+                join,
+                &left_on,
+                &right_on,
+                &null_equals_nulls,
+                residual.is_some(),
+            ) {
+                let left_on = BoundExpr::bind_all(&left_on, left.schema())?;
+                let right_on = BoundExpr::bind_all(&right_on, right.schema())?;
+
+                Ok(LocalPhysicalPlan::sort_merge_join(
                     left,
                     right,
+                    left_on,
+                    right_on,
+                    join.join_type,
+                    left_is_sorted,
+                    right_is_sorted,
                     join.output_schema.clone(),
                     join.stats_state.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
+                ))
+            } else if let Some(right_is_sorted) =
+                sorted_lookup_join_order(join, &right_on, &null_equals_nulls, residual.is_some())
+            {
+                let left_on = BoundExpr::bind_all(&left_on, left.schema())?;
+                let right_on = BoundExpr::bind_all(&right_on, right.schema())?;
+
+                Ok(LocalPhysicalPlan::sorted_lookup_join(
+                    left,
+                    right,
+                    left_on,
+                    right_on,
+                    join.join_type,
+                    right_is_sorted,
+                    join.output_schema.clone(),
+                    join.stats_state.clone(),
+                    LocalNodeContext::from_logical(plan),
                 ))
             } else {
                 let left_on = BoundExpr::bind_all(&left_on, left.schema())?;
                 let right_on = BoundExpr::bind_all(&right_on, right.schema())?;
 
+                // There's nothing to broadcast to on a single machine, so a broadcast join is a hash join whose hash table
+                // is built on the side which would be broadcast, if a hint named it.
+                let build_on_left = match join.join_strategy {
+                    Some(JoinStrategy::Broadcast) => {
+                        join.broadcast_side.map(|side| side == JoinSide::Left)
+                    }
+                    _ => None,
+                };
+
                 Ok(LocalPhysicalPlan::hash_join(
                     left,
                     right,
                     left_on,
                     right_on,
-                    None,
+                    build_on_left,
                     Some(null_equals_nulls),
+                    residual,
                     join.join_type,
                     join.output_schema.clone(),
                     join.stats_state.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 ))
             }
         }
@@ -391,7 +596,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 columns,
                 schema,
                 distinct.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Concat(concat) => {
@@ -401,7 +606,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 input,
                 other,
                 concat.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Repartition(repartition) => {
@@ -418,7 +623,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 monotonically_increasing_id.starting_offset,
                 monotonically_increasing_id.schema.clone(),
                 monotonically_increasing_id.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::Sink(sink) => {
@@ -434,7 +639,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                         sink.schema.clone(),
                         bound_info.clone(),
                         sink.stats_state.clone(),
-                        LocalNodeContext::default(),
+                        LocalNodeContext::from_logical(plan),
                     );
 
                     Ok(LocalPhysicalPlan::commit_write(
@@ -442,8 +647,9 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                         data_schema,
                         sink.schema.clone(),
                         bound_info,
+                        None,
                         sink.stats_state.clone(),
-                        LocalNodeContext::default(),
+                        LocalNodeContext::from_logical(plan),
                     ))
                 }
                 #[cfg(feature = "python")]
@@ -456,7 +662,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                             data_schema,
                             sink.schema.clone(),
                             sink.stats_state.clone(),
-                            LocalNodeContext::default(),
+                            LocalNodeContext::from_logical(plan),
                         ))
                     }
                     daft_logical_plan::CatalogType::Lance(info) => {
@@ -466,7 +672,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                             data_schema,
                             sink.schema.clone(),
                             sink.stats_state.clone(),
-                            LocalNodeContext::default(),
+                            LocalNodeContext::from_logical(plan),
                         ))
                     }
                 },
@@ -476,7 +682,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     data_sink_info.clone(),
                     sink.schema.clone(),
                     sink.stats_state.clone(),
-                    LocalNodeContext::default(),
+                    LocalNodeContext::from_logical(plan),
                 )),
             }
         }
@@ -488,9 +694,11 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             Ok(LocalPhysicalPlan::explode(
                 input,
                 to_explode,
+                explode.index_column.clone(),
+                explode.pad_mismatched,
                 explode.exploded_schema.clone(),
                 explode.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
         LogicalPlan::VLLMProject(vllm_project) => {
@@ -503,15 +711,14 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 vllm_project.output_column_name.clone(),
                 vllm_project.output_schema.clone(),
                 vllm_project.stats_state.clone(),
-                LocalNodeContext::default(),
+                LocalNodeContext::from_logical(plan),
             ))
         }
-        LogicalPlan::Intersect(_)
-        | LogicalPlan::Union(_)
-        | LogicalPlan::SubqueryAlias(_)
-        | LogicalPlan::Offset(_) => Err(DaftError::InternalError(format!(
-            "Logical plan operator {} should already be optimized away",
-            plan.name()
-        ))),
+        LogicalPlan::Intersect(_) | LogicalPlan::Union(_) | LogicalPlan::SubqueryAlias(_) => {
+            Err(DaftError::InternalError(format!(
+                "Logical plan operator {} should already be optimized away",
+                plan.name()
+            )))
+        }
     }
 }
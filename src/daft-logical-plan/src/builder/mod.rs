@@ -9,19 +9,23 @@ use std::{
 #[cfg(feature = "python")]
 use common_daft_config::PyDaftExecutionConfig;
 use common_daft_config::{DaftExecutionConfig, DaftPlanningConfig};
-use common_display::mermaid::MermaidDisplayOptions;
+use common_display::{DisplayLevel, dot::DotDisplayOptions, mermaid::MermaidDisplayOptions};
 use common_error::{DaftError, DaftResult};
 use common_file_formats::{FileFormat, WriteMode};
 use common_io_config::IOConfig;
 use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef, Sharder, ShardingStrategy};
-use common_treenode::TreeNode;
+use common_treenode::{Transformed, TreeNode};
 use daft_algebra::boolean::combine_conjunction;
-use daft_core::join::{JoinStrategy, JoinType};
+use daft_core::{
+    gap_fill::GapFill,
+    join::{JoinStrategy, JoinType},
+};
 use daft_dsl::{
     Column, Expr, ExprRef, UnresolvedColumn, WindowSpec, left_col, resolved_col, right_col,
     unresolved_col,
 };
 use daft_schema::schema::{Schema, SchemaRef};
+use daft_stats::AnalyzedTableStatistics;
 use indexmap::IndexSet;
 use resolve_expr::ExprResolver;
 #[cfg(feature = "python")]
@@ -34,20 +38,23 @@ use {
     daft_schema::python::schema::PySchema,
     pyo3::intern,
     pyo3::prelude::*,
+    std::str::FromStr,
 };
 
 use crate::{
     LogicalPlanRef,
     display::json::JsonVisitor,
+    lineage::ColumnLineageGraph,
     logical_plan::{LogicalPlan, SubqueryAlias},
     ops::{
         self, Limit, Offset, SetQuantifier, UnionStrategy,
         join::{JoinOptions, JoinPredicate},
     },
-    optimization::{OptimizerBuilder, OptimizerConfig},
+    optimization::{Optimizer, OptimizerBuilder, OptimizerConfig, OptimizerTrace},
     partitioning::{
         HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
     },
+    plan_cache::{self, PlanFingerprint},
     sink_info::{OutputFileInfo, SinkInfo},
     source_info::{GlobScanInfo, InMemoryInfo, SourceInfo},
 };
@@ -368,13 +375,19 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(ops::Shard::new(self.plan.clone(), sharder)))
     }
 
-    pub fn explode(&self, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
+    pub fn explode(
+        &self,
+        to_explode: Vec<ExprRef>,
+        index_column: Option<String>,
+        pad_mismatched: bool,
+    ) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
         let to_explode = expr_resolver.resolve(to_explode, self.plan.clone())?;
 
         let logical_plan: LogicalPlan =
-            ops::Explode::try_new(self.plan.clone(), to_explode)?.into();
+            ops::Explode::try_new(self.plan.clone(), to_explode, index_column, pad_mismatched)?
+                .into();
         Ok(self.with_new_plan(logical_plan))
     }
 
@@ -503,6 +516,63 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(ops::summarize(self)?))
     }
 
+    /// Computes the statistics collected by `ANALYZE`, with one row per column.
+    pub fn analyze(&self, num_buckets: usize) -> DaftResult<Self> {
+        Ok(self.with_new_plan(ops::analyze(self, num_buckets)?))
+    }
+
+    /// Attaches statistics collected by `ANALYZE` to the plan, which must be a bare source.
+    ///
+    /// Plans that transform their source are rejected, since the statistics may not describe their output.
+    pub fn with_analyzed_stats(&self, stats: Arc<AnalyzedTableStatistics>) -> DaftResult<Self> {
+        match self.plan.as_ref() {
+            LogicalPlan::Source(source) => Ok(self.with_new_plan(LogicalPlan::Source(
+                source.clone().with_analyzed_stats(Some(stats)),
+            ))),
+            plan => Err(DaftError::ValueError(format!(
+                "Statistics collected by ANALYZE can only be used for tables which read a source as is, but this table is read by a {} plan",
+                plan.name()
+            ))),
+        }
+    }
+
+    /// Rewrites the scans of the plan to read at most `rows_per_file` rows from each of at most `max_files` files, and
+    /// limits the output to as many rows as the scans read in total, so that the plan can be previewed quickly.
+    ///
+    /// Scans stop listing files once they've found `max_files` files where they can, so large datasets aren't fully
+    /// listed either. Their tasks aren't merged, so that each reads one file and the row limit is applied per file.
+    pub fn preview(&self, rows_per_file: usize, max_files: usize) -> DaftResult<Self> {
+        let plan = self
+            .plan
+            .clone()
+            .transform_up(|node| {
+                let LogicalPlan::Source(source) = node.as_ref() else {
+                    return Ok(Transformed::no(node));
+                };
+                let SourceInfo::Physical(physical_scan_info) = source.source_info.as_ref() else {
+                    return Ok(Transformed::no(node));
+                };
+                let pushdowns = &physical_scan_info.pushdowns;
+                let limit = pushdowns
+                    .limit
+                    .map_or(rows_per_file, |l| l.min(rows_per_file));
+                let file_limit = pushdowns.file_limit.map_or(max_files, |l| l.min(max_files));
+                let pushdowns = pushdowns
+                    .with_limit(Some(limit))
+                    .with_file_limit(Some(file_limit));
+                let source = ops::Source {
+                    source_info: Arc::new(SourceInfo::Physical(
+                        physical_scan_info.with_pushdowns(pushdowns),
+                    )),
+                    ..source.clone()
+                };
+                Ok(Transformed::yes(LogicalPlan::Source(source).into()))
+            })?
+            .data;
+        self.with_new_plan(plan)
+            .limit(rows_per_file.saturating_mul(max_files) as u64, false)
+    }
+
     pub fn distinct(&self, columns: Option<Vec<ExprRef>>) -> DaftResult<Self> {
         let distinct_resolver = ExprResolver::default();
         let columns = columns
@@ -569,6 +639,35 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(pivot_logical_plan))
     }
 
+    pub fn resample(
+        &self,
+        ts_column: ExprRef,
+        every: ExprRef,
+        by: Vec<ExprRef>,
+        agg_exprs: Vec<ExprRef>,
+        fill: Option<GapFill>,
+    ) -> DaftResult<Self> {
+        let expr_resolver = ExprResolver::default();
+        let ts_column = expr_resolver.resolve_single(ts_column, self.plan.clone())?;
+        let by = expr_resolver.resolve(by, self.plan.clone())?;
+
+        let agg_resolver = ExprResolver::builder().groupby(&by).build();
+        let agg_exprs = agg_resolver.resolve(agg_exprs, self.plan.clone())?;
+
+        let Some(every) = every.as_literal() else {
+            return Err(DaftError::ValueError(format!(
+                "The interval to resample by must be a literal, received {every}"
+            )));
+        };
+        let ts_dtype = ts_column.to_field(&self.schema())?.dtype;
+        let every = ops::resample_interval(every, &ts_dtype)?;
+
+        let resample_logical_plan: LogicalPlan =
+            ops::Resample::try_new(self.plan.clone(), ts_column, every, by, agg_exprs, fill)?
+                .into();
+        Ok(self.with_new_plan(resample_logical_plan))
+    }
+
     // Helper function to create inner joins more ergonimically.
     #[cfg(test)]
     pub(crate) fn inner_join<Right: Into<LogicalPlanRef>>(
@@ -598,6 +697,7 @@ impl LogicalPlanBuilder {
     ) -> DaftResult<Self> {
         let left_plan = self.plan.clone();
         let right_plan = right.into();
+        let broadcast_side = options.broadcast_side;
 
         let expr_resolver = ExprResolver::default();
         let on = on
@@ -632,6 +732,7 @@ impl LogicalPlanBuilder {
 
         let logical_plan: LogicalPlan =
             ops::Join::try_new(left_plan, right_plan, combined_on, join_type, join_strategy)?
+                .with_broadcast_side(broadcast_side)
                 .into();
         Ok(self.with_new_plan(logical_plan))
     }
@@ -843,28 +944,7 @@ impl LogicalPlanBuilder {
         let (tx, rx) = tokio::sync::oneshot::channel();
 
         std::thread::spawn(move || {
-            let optimizer = OptimizerBuilder::default()
-                .when(
-                    cfg.as_ref()
-                        .map(|conf| conf.enable_strict_filter_pushdown)
-                        .unwrap_or(false),
-                    |builder| {
-                        builder.with_optimizer_config(OptimizerConfig {
-                            strict_pushdown: true,
-                            ..Default::default()
-                        })
-                    },
-                )
-                .with_default_optimizations()
-                .enrich_with_stats(Some(execution_config.clone()))
-                .when(
-                    !cfg.as_ref()
-                        .is_some_and(|conf| conf.disable_join_reordering),
-                    |builder| builder.reorder_joins(Some(execution_config.clone())),
-                )
-                .simplify_expressions()
-                .split_granular_projections()
-                .build();
+            let optimizer = Self::default_optimizer(cfg.as_deref(), execution_config);
 
             let optimized_plan = optimizer.optimize(
                 unoptimized_plan,
@@ -912,32 +992,13 @@ impl LogicalPlanBuilder {
 
         let unoptimized_plan = self.build();
 
-        let optimizer = OptimizerBuilder::default()
-            .when(
-                cfg.as_ref()
-                    .map(|conf| conf.enable_strict_filter_pushdown)
-                    .unwrap_or(false),
-                |builder| {
-                    builder.with_optimizer_config(OptimizerConfig {
-                        strict_pushdown: true,
-                        ..Default::default()
-                    })
-                },
-            )
-            .with_default_optimizations()
-            .enrich_with_stats(Some(execution_config.clone()))
-            .when(
-                !cfg.as_ref()
-                    .is_some_and(|conf| conf.disable_join_reordering),
-                |builder| builder.reorder_joins(Some(execution_config)),
-            )
-            .simplify_expressions()
-            .split_granular_projections()
-            .build();
-
-        let optimized_plan = optimizer.optimize(
-            unoptimized_plan,
-            |new_plan, rule_batch, pass, transformed, seen| {
+        let fingerprint = {
+            let (cfg, execution_config) = (cfg.clone(), execution_config.clone());
+            move |plan: &LogicalPlan| PlanFingerprint::new(plan, cfg.as_deref(), &execution_config)
+        };
+        let optimized_plan = plan_cache::get_or_optimize(fingerprint, unoptimized_plan, |plan| {
+            let optimizer = Self::default_optimizer(cfg.as_deref(), execution_config);
+            optimizer.optimize(plan, |new_plan, rule_batch, pass, transformed, seen| {
                 if transformed {
                     log::debug!(
                         "Rule batch {:?} transformed plan on pass {}, and produced {} plan:\n{}",
@@ -954,8 +1015,8 @@ impl LogicalPlanBuilder {
                         new_plan.repr_ascii(true),
                     );
                 }
-            },
-        )?;
+            })
+        })?;
 
         // Assign node IDs to the optimized plan
         let builder = if std::env::var("DAFT_INSTRUMENT_LOGICAL_PLAN").is_ok() {
@@ -967,6 +1028,44 @@ impl LogicalPlanBuilder {
         Ok(builder)
     }
 
+    /// Optimize the logical plan, recording which optimizer rule produced each plan mutation.
+    ///
+    /// Like `optimize`, this must not be called from the main thread.
+    pub fn optimize_with_trace(
+        &self,
+        execution_config: Arc<DaftExecutionConfig>,
+    ) -> DaftResult<(Self, OptimizerTrace)> {
+        let optimizer = Self::default_optimizer(self.config.as_deref(), execution_config);
+        let (optimized_plan, trace) = optimizer.optimize_with_trace(self.build())?;
+        Ok((Self::new(optimized_plan, self.config.clone()), trace))
+    }
+
+    /// The optimizer used to optimize logical plans before execution.
+    fn default_optimizer(
+        cfg: Option<&DaftPlanningConfig>,
+        execution_config: Arc<DaftExecutionConfig>,
+    ) -> Optimizer {
+        OptimizerBuilder::default()
+            .when(
+                cfg.is_some_and(|conf| conf.enable_strict_filter_pushdown),
+                |builder| {
+                    builder.with_optimizer_config(OptimizerConfig {
+                        strict_pushdown: true,
+                        ..Default::default()
+                    })
+                },
+            )
+            .with_default_optimizations()
+            .enrich_with_stats(Some(execution_config.clone()))
+            .when(
+                !cfg.is_some_and(|conf| conf.disable_join_reordering),
+                |builder| builder.reorder_joins(Some(execution_config)),
+            )
+            .simplify_expressions()
+            .split_granular_projections()
+            .build()
+    }
+
     /// Recursively walk the optimized plan and assign node IDs to each node
     fn assign_node_ids(plan: Arc<LogicalPlan>) -> DaftResult<Arc<LogicalPlan>> {
         use common_treenode::{Transformed, TreeNode, TreeNodeRewriter};
@@ -1030,6 +1129,26 @@ impl LogicalPlanBuilder {
         self.plan.visit(&mut json_vis)?;
         Ok(output)
     }
+
+    /// Returns the column-level lineage of the plan, which should already be optimized.
+    pub fn column_lineage(&self) -> ColumnLineageGraph {
+        ColumnLineageGraph::new(&self.plan)
+    }
+
+    pub fn repr_dot(&self, opts: DotDisplayOptions) -> String {
+        use common_display::dot::DotDisplay;
+        self.plan.repr_dot(opts)
+    }
+
+    pub fn repr_graph_json(&self, simple: bool) -> DaftResult<String> {
+        use common_display::graph::GraphDisplay;
+        let level = if simple {
+            DisplayLevel::Compact
+        } else {
+            DisplayLevel::Default
+        };
+        Ok(serde_json::to_string(&self.plan.repr_graph_json(level))?)
+    }
 }
 
 /// A Python-facing wrapper of the LogicalPlanBuilder.
@@ -1138,8 +1257,21 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.shard(strategy, world_size, rank)?.into())
     }
 
-    pub fn explode(&self, to_explode: Vec<PyExpr>) -> PyResult<Self> {
-        Ok(self.builder.explode(pyexprs_to_exprs(to_explode))?.into())
+    pub fn preview(&self, rows_per_file: usize, max_files: usize) -> PyResult<Self> {
+        Ok(self.builder.preview(rows_per_file, max_files)?.into())
+    }
+
+    #[pyo3(signature = (to_explode, index_column=None, pad_mismatched=false))]
+    pub fn explode(
+        &self,
+        to_explode: Vec<PyExpr>,
+        index_column: Option<String>,
+        pad_mismatched: bool,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .explode(pyexprs_to_exprs(to_explode), index_column, pad_mismatched)?
+            .into())
     }
 
     pub fn unpivot(
@@ -1208,6 +1340,10 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.summarize()?.into())
     }
 
+    pub fn analyze(&self, num_buckets: usize) -> PyResult<Self> {
+        Ok(self.builder.analyze(num_buckets)?.into())
+    }
+
     pub fn distinct(&self, columns: Vec<PyExpr>) -> PyResult<Self> {
         let columns = if columns.is_empty() {
             None
@@ -1257,6 +1393,28 @@ impl PyLogicalPlanBuilder {
             )?
             .into())
     }
+
+    #[pyo3(signature = (ts_column, every, by, agg_exprs, fill=None))]
+    pub fn resample(
+        &self,
+        ts_column: PyExpr,
+        every: PyExpr,
+        by: Vec<PyExpr>,
+        agg_exprs: Vec<PyExpr>,
+        fill: Option<&str>,
+    ) -> PyResult<Self> {
+        let fill = fill.map(GapFill::from_str).transpose()?;
+        Ok(self
+            .builder
+            .resample(
+                ts_column.into(),
+                every.into(),
+                pyexprs_to_exprs(by),
+                pyexprs_to_exprs(agg_exprs),
+                fill,
+            )?
+            .into())
+    }
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         right,
@@ -1311,7 +1469,11 @@ impl PyLogicalPlanBuilder {
                 using,
                 join_type,
                 join_strategy,
-                JoinOptions { prefix, suffix },
+                JoinOptions {
+                    prefix,
+                    suffix,
+                    broadcast_side: None,
+                },
             )?
             .into())
     }
@@ -1499,16 +1661,61 @@ impl PyLogicalPlanBuilder {
         py.detach(|| Ok(self.builder.optimize(execution_config.config)?.into()))
     }
 
+    /// The fingerprint which the optimized plan is cached by, or None if it isn't cached.
+    pub fn fingerprint(
+        &self,
+        py: Python,
+        execution_config: PyDaftExecutionConfig,
+    ) -> Option<String> {
+        py.detach(|| {
+            PlanFingerprint::new(
+                &self.builder.build(),
+                self.builder.config.as_deref(),
+                &execution_config.config,
+            )
+            .map(|fingerprint| fingerprint.to_string())
+        })
+    }
+
+    /// Optimize the underlying logical plan, returning a step-by-step diff of every plan mutation
+    /// along with the optimizer rule that made it.
+    pub fn optimizer_trace(
+        &self,
+        py: Python,
+        execution_config: PyDaftExecutionConfig,
+    ) -> PyResult<String> {
+        py.detach(|| {
+            let (_, trace) = self.builder.optimize_with_trace(execution_config.config)?;
+            Ok(trace.repr_diff())
+        })
+    }
+
     pub fn repr_ascii(&self, simple: bool) -> PyResult<String> {
         Ok(self.builder.repr_ascii(simple))
     }
 
+    /// Returns the column-level lineage of the plan as JSON.
+    pub fn column_lineage(&self) -> PyResult<String> {
+        Ok(self.builder.column_lineage().to_json().to_string())
+    }
+
     pub fn repr_mermaid(&self, opts: MermaidDisplayOptions) -> String {
         self.builder.repr_mermaid(opts)
     }
     pub fn repr_json(&self, include_schema: bool) -> PyResult<String> {
         Ok(self.builder.repr_json(include_schema)?)
     }
+
+    #[pyo3(signature = (simple=false, bottom_up=false))]
+    pub fn repr_dot(&self, simple: bool, bottom_up: bool) -> String {
+        self.builder
+            .repr_dot(DotDisplayOptions { simple, bottom_up })
+    }
+
+    #[pyo3(signature = (simple=false))]
+    pub fn repr_graph_json(&self, simple: bool) -> PyResult<String> {
+        Ok(self.builder.repr_graph_json(simple)?)
+    }
 }
 
 impl From<LogicalPlanBuilder> for PyLogicalPlanBuilder {
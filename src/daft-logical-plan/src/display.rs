@@ -2,8 +2,9 @@ pub mod json;
 use std::fmt::{self, Display};
 
 use common_display::{DisplayLevel, tree::TreeDisplay};
+use serde_json::json;
 
-use crate::display::json::to_json_value;
+use crate::{display::json::to_json_value, stats::StatsState};
 
 impl TreeDisplay for crate::LogicalPlan {
     fn display_as(&self, level: DisplayLevel) -> String {
@@ -17,6 +18,19 @@ impl TreeDisplay for crate::LogicalPlan {
         to_json_value(self)
     }
 
+    fn repr_graph_details(&self) -> serde_json::Value {
+        let mut details = to_json_value(self);
+        details["type"] = json!(self.name());
+        details["node_id"] = json!(self.node_id());
+        details["plan_id"] = json!(self.plan_id());
+        details["schema"] = json!(self.schema().fields());
+        details["stats"] = match self.stats_state() {
+            StatsState::Materialized(stats) => json!(stats.approx_stats),
+            StatsState::NotMaterialized => serde_json::Value::Null,
+        };
+        details
+    }
+
     fn get_name(&self) -> String {
         self.name().to_string()
     }
@@ -38,7 +52,13 @@ impl Display for crate::LogicalPlan {
 pub(crate) mod test {
     use std::sync::Arc;
 
-    use common_display::mermaid::{MermaidDisplay, MermaidDisplayOptions, SubgraphOptions};
+    use common_display::{
+        DisplayLevel,
+        dot::{DotDisplay, DotDisplayOptions},
+        graph::GraphDisplay,
+        mermaid::{MermaidDisplay, MermaidDisplayOptions, SubgraphOptions},
+        tree::TreeDisplay,
+    };
     use common_error::DaftResult;
     use daft_core::prelude::*;
     use daft_dsl::{lit, resolved_col};
@@ -247,4 +267,60 @@ end
         assert_eq!(mermaid_repr, expected);
         Ok(())
     }
+
+    #[test]
+    fn test_dot_display() -> DaftResult<()> {
+        let plan = LogicalPlanBuilder::from(plan_1())
+            .filter(resolved_col("text").eq(lit("a \"quoted\" value")))?
+            .limit(10, false)?
+            .build();
+
+        let dot_repr = plan.repr_dot(DotDisplayOptions {
+            simple: false,
+            bottom_up: true,
+        });
+        let expected = r#"digraph plan {
+  rankdir=BT;
+  node [shape=box, fontname="monospace"];
+  n0 [label="Limit: 10\l"];
+  n1 [label="Filter: col(text) == lit('a \"quoted\" value')\l"];
+  n2 [label="PlaceHolder:\lNum partitions = 0\lOutput schema = text#Utf8, id#Int32\l"];
+  n2 -> n1;
+  n1 -> n0;
+}
+"#;
+        assert_eq!(dot_repr, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_json_display() -> DaftResult<()> {
+        let plan = LogicalPlanBuilder::from(plan_1())
+            .filter(resolved_col("id").eq(lit(1)))?
+            .build();
+
+        let graph = plan.repr_graph_json(DisplayLevel::Compact);
+        let names = graph["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|node| node["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Filter", "Source"]);
+        assert_eq!(graph["nodes"][0]["details"]["type"], "Filter");
+        assert!(graph["nodes"][0]["details"].get("children").is_none());
+        assert_eq!(
+            graph["nodes"][1]["details"]["schema"],
+            serde_json::json!(plan.schema().fields())
+        );
+        // Stats are only known once the plan is optimized.
+        assert!(graph["nodes"][1]["details"]["stats"].is_null());
+        // Only the graph describes the ids, schemas and stats of the nodes.
+        assert!(plan.repr_json().get("schema").is_none());
+        assert_eq!(
+            graph["edges"],
+            serde_json::json!([{"source": 1, "target": 0}])
+        );
+        Ok(())
+    }
 }
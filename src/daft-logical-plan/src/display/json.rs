@@ -39,6 +39,8 @@ pub(crate) fn to_json_value(node: &LogicalPlan) -> serde_json::Value {
         }),
         LogicalPlan::Explode(explode) => json!({
             "to_explode": explode.to_explode.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            "index_column": explode.index_column,
+            "pad_mismatched": explode.pad_mismatched,
         }),
         LogicalPlan::Unpivot(unpivot) => json!({
             "ids": unpivot.ids.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
@@ -66,6 +68,13 @@ pub(crate) fn to_json_value(node: &LogicalPlan) -> serde_json::Value {
             "group_by": pivot.group_by.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
             "names": pivot.names.iter().map(|e| resolved_col(e.clone()).to_string()).collect::<Vec<_>>(),
         }),
+        LogicalPlan::Resample(resample) => json!({
+            "ts_column": resample.ts_column.to_string(),
+            "every": resample.every,
+            "by": resample.by.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            "aggregations": resample.aggregations.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            "fill": resample.fill.map(|fill| fill.to_string()),
+        }),
         LogicalPlan::Concat(_) => json!({}),
         LogicalPlan::Intersect(intersect) => json!({
             "is_all": intersect.is_all,
@@ -78,6 +87,7 @@ pub(crate) fn to_json_value(node: &LogicalPlan) -> serde_json::Value {
             "on": vec![&join.on.inner().map(|e| e.to_string())],
             "type": join.join_type,
             "strategy": join.join_strategy,
+            "broadcast_side": join.broadcast_side,
         }),
         LogicalPlan::Sink(_) => json!({}),
         LogicalPlan::Sample(sample) => json!({
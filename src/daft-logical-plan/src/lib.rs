@@ -2,10 +2,12 @@
 
 pub mod builder;
 pub mod display;
+pub mod lineage;
 pub mod logical_plan;
 pub mod ops;
 pub mod optimization;
 pub mod partitioning;
+pub mod plan_cache;
 pub mod sink_info;
 pub mod source_info;
 pub mod stats;
@@ -19,7 +21,10 @@ use common_file_formats::{
     CsvSourceConfig, DatabaseSourceConfig, JsonSourceConfig, ParquetSourceConfig, WarcSourceConfig,
     python::PyFileFormatConfig,
 };
-pub use daft_core::join::{JoinStrategy, JoinType};
+pub use daft_core::{
+    gap_fill::GapFill,
+    join::{JoinSide, JoinStrategy, JoinType},
+};
 pub use logical_plan::{LogicalPlan, LogicalPlanRef};
 pub use ops::join::JoinOptions;
 pub use partitioning::ClusteringSpec;
@@ -42,6 +47,14 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<CsvSourceConfig>()?;
     parent.add_class::<DatabaseSourceConfig>()?;
     parent.add_class::<JoinOptions>()?;
+    parent.add_function(wrap_pyfunction!(
+        plan_cache::python::plan_cache_entries,
+        parent
+    )?)?;
+    parent.add_function(wrap_pyfunction!(
+        plan_cache::python::evict_from_plan_cache,
+        parent
+    )?)?;
 
     Ok(())
 }
@@ -0,0 +1,529 @@
+//! Column-level lineage of logical plans.
+//!
+//! The lineage of an output column of a plan is the set of source columns which contribute to its values, along with
+//! the expressions which transform them along the way. Lineage is meant to be extracted from optimized plans, so that
+//! it reflects the plan which actually runs, and is used by governance tooling and to analyze the impact of changing a
+//! source column.
+
+use std::collections::BTreeSet;
+
+use common_scan_info::ScanState;
+use daft_dsl::{
+    Expr, ExprRef,
+    optimization::{get_required_columns, requires_computation},
+};
+use indexmap::{IndexMap, IndexSet};
+use itertools::Itertools;
+use serde_json::json;
+
+use crate::{
+    LogicalPlan, SourceInfo,
+    logical_plan::SubqueryAlias,
+    ops::{
+        Aggregate, Concat, Distinct, Explode, Filter, Intersect, IntoBatches, Join, Limit,
+        MonotonicallyIncreasingId, Offset, Pivot, Project, Repartition, Resample, Sample, Shard,
+        Sink, Sort, Source, TopN, UDFProject, Union, Unpivot, VLLMProject, Window,
+    },
+    source_info::{GlobScanInfo, InMemoryInfo, PhysicalScanInfo},
+};
+
+/// A column of one of the sources of a plan.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceColumn {
+    /// The index of the source in [`ColumnLineageGraph::sources`].
+    pub source: usize,
+    pub column: String,
+}
+
+/// The lineage of an output column of a plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnLineage {
+    pub name: String,
+    /// The source columns which contribute to the values of the column.
+    pub inputs: BTreeSet<SourceColumn>,
+    /// The expressions which compute the column from its inputs, from the first applied to the last. Renames and
+    /// columns passed through unchanged aren't transformations.
+    pub transformations: Vec<ExprRef>,
+}
+
+impl ColumnLineage {
+    fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            inputs: BTreeSet::new(),
+            transformations: vec![],
+        }
+    }
+
+    fn renamed(&self, name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..self.clone()
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.inputs.extend(other.inputs.iter().cloned());
+        for transformation in &other.transformations {
+            if !self.transformations.contains(transformation) {
+                self.transformations.push(transformation.clone());
+            }
+        }
+    }
+
+    fn push_transformation(&mut self, transformation: ExprRef) {
+        if !self.transformations.contains(&transformation) {
+            self.transformations.push(transformation);
+        }
+    }
+}
+
+/// The column-level lineage of a plan: the sources which it reads, and the lineage of each of its output columns.
+///
+/// The output columns of a plan which writes its input are the columns which it writes, rather than the columns
+/// describing the written files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnLineageGraph {
+    /// Descriptions of the sources of the plan, in the order in which they're reached from the left of the plan.
+    pub sources: Vec<String>,
+    /// The lineage of each output column of the plan, in the order of the output schema.
+    pub columns: Vec<ColumnLineage>,
+}
+
+impl ColumnLineageGraph {
+    pub fn new(plan: &LogicalPlan) -> Self {
+        let mut sources = IndexSet::new();
+        let columns = plan_lineage(plan, &mut sources).into_values().collect();
+        Self {
+            sources: sources.into_iter().collect(),
+            columns,
+        }
+    }
+
+    /// Returns the lineage of an output column, if the plan has it.
+    pub fn column(&self, name: &str) -> Option<&ColumnLineage> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    /// Returns the graph as JSON, with the transformations displayed as strings.
+    pub fn to_json(&self) -> serde_json::Value {
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| {
+                json!({
+                    "name": column.name,
+                    "inputs": column.inputs.iter().map(|input| json!({
+                        "source": input.source,
+                        "column": input.column,
+                    })).collect::<Vec<_>>(),
+                    "transformations": column
+                        .transformations
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({"sources": self.sources, "columns": columns})
+    }
+}
+
+type Lineages = IndexMap<String, ColumnLineage>;
+
+fn source_description(source: &Source) -> String {
+    match source.source_info.as_ref() {
+        SourceInfo::Physical(PhysicalScanInfo { scan_state, .. }) => match scan_state {
+            ScanState::Operator(scan_op) => scan_op.0.multiline_display().join(", "),
+            ScanState::Tasks(scan_tasks) => format!("ScanTasks({})", scan_tasks.len()),
+        },
+        SourceInfo::InMemory(InMemoryInfo { cache_key, .. }) => format!("InMemory({cache_key})"),
+        SourceInfo::GlobScan(GlobScanInfo { glob_paths, .. }) => {
+            format!("GlobScan({})", glob_paths.join(", "))
+        }
+        SourceInfo::PlaceHolder(..) => "PlaceHolder".to_string(),
+    }
+}
+
+/// Returns the lineage of an expression evaluated against the output of a plan with the given lineages.
+fn expr_lineage(expr: &ExprRef, input: &Lineages) -> ColumnLineage {
+    let mut lineage = ColumnLineage::new(expr.name());
+    for column in get_required_columns(expr).iter().unique() {
+        if let Some(column_lineage) = input.get(column) {
+            lineage.merge(column_lineage);
+        }
+    }
+    if requires_computation(expr) {
+        lineage.push_transformation(expr.clone());
+    }
+    lineage
+}
+
+fn exprs_lineage<'a>(exprs: impl IntoIterator<Item = &'a ExprRef>, input: &Lineages) -> Lineages {
+    exprs
+        .into_iter()
+        .map(|expr| {
+            let lineage = expr_lineage(expr, input);
+            (lineage.name.clone(), lineage)
+        })
+        .collect()
+}
+
+/// Returns the lineage of a plan whose output columns come from either of two inputs, such as a concat or union.
+fn set_operation_lineage(plan: &LogicalPlan, lhs: Lineages, rhs: Lineages) -> Lineages {
+    plan.schema()
+        .field_names()
+        .enumerate()
+        .map(|(i, name)| {
+            let mut lineage = ColumnLineage::new(name);
+            // The inputs of set operations are matched by name, or otherwise by position.
+            for side in [&lhs, &rhs] {
+                if let Some(side_lineage) =
+                    side.get(name).or_else(|| side.get_index(i).map(|(_, l)| l))
+                {
+                    lineage.merge(side_lineage);
+                }
+            }
+            (name.to_string(), lineage)
+        })
+        .collect()
+}
+
+fn plan_lineage(plan: &LogicalPlan, sources: &mut IndexSet<String>) -> Lineages {
+    match plan {
+        LogicalPlan::Source(source) => {
+            let (source_index, _) = sources.insert_full(source_description(source));
+            source
+                .output_schema
+                .field_names()
+                .map(|name| {
+                    let mut lineage = ColumnLineage::new(name);
+                    lineage.inputs.insert(SourceColumn {
+                        source: source_index,
+                        column: name.to_string(),
+                    });
+                    (name.to_string(), lineage)
+                })
+                .collect()
+        }
+        LogicalPlan::Shard(Shard { input, .. })
+        | LogicalPlan::Filter(Filter { input, .. })
+        | LogicalPlan::IntoBatches(IntoBatches { input, .. })
+        | LogicalPlan::Limit(Limit { input, .. })
+        | LogicalPlan::Offset(Offset { input, .. })
+        | LogicalPlan::Sort(Sort { input, .. })
+        | LogicalPlan::Repartition(Repartition { input, .. })
+        | LogicalPlan::Distinct(Distinct { input, .. })
+        | LogicalPlan::Sample(Sample { input, .. })
+        | LogicalPlan::SubqueryAlias(SubqueryAlias { input, .. })
+        | LogicalPlan::TopN(TopN { input, .. })
+        | LogicalPlan::Sink(Sink { input, .. }) => plan_lineage(input, sources),
+        LogicalPlan::Project(Project {
+            input, projection, ..
+        }) => exprs_lineage(projection, &plan_lineage(input, sources)),
+        LogicalPlan::UDFProject(UDFProject {
+            input,
+            expr,
+            passthrough_columns,
+            ..
+        }) => exprs_lineage(
+            passthrough_columns.iter().chain(std::iter::once(expr)),
+            &plan_lineage(input, sources),
+        ),
+        LogicalPlan::Explode(Explode {
+            input,
+            to_explode,
+            index_column,
+            exploded_schema,
+            ..
+        }) => {
+            let input = plan_lineage(input, sources);
+            let exploded = to_explode
+                .iter()
+                .map(|expr| {
+                    let exploded = daft_functions_list::explode(expr.clone());
+                    (exploded.name().to_string(), expr_lineage(&exploded, &input))
+                })
+                .collect::<Lineages>();
+            exploded_schema
+                .field_names()
+                .map(|name| {
+                    let lineage = if index_column.as_deref() == Some(name) {
+                        // The index of each exploded value depends on the lists which were exploded.
+                        let mut lineage = ColumnLineage::new(name);
+                        for exploded_lineage in exploded.values() {
+                            lineage
+                                .inputs
+                                .extend(exploded_lineage.inputs.iter().cloned());
+                        }
+                        lineage
+                    } else if let Some(lineage) = exploded.get(name) {
+                        lineage.clone()
+                    } else {
+                        input
+                            .get(name)
+                            .cloned()
+                            .unwrap_or_else(|| ColumnLineage::new(name))
+                    };
+                    (name.to_string(), lineage)
+                })
+                .collect()
+        }
+        LogicalPlan::Unpivot(Unpivot {
+            input,
+            ids,
+            values,
+            variable_name,
+            value_name,
+            ..
+        }) => {
+            let input = plan_lineage(input, sources);
+            let mut lineages = exprs_lineage(ids, &input);
+            // The variable column holds the names of the unpivoted columns rather than any of their values.
+            lineages.insert(variable_name.clone(), ColumnLineage::new(variable_name));
+            let mut value = ColumnLineage::new(value_name);
+            for value_lineage in exprs_lineage(values, &input).values() {
+                value.merge(value_lineage);
+            }
+            lineages.insert(value_name.clone(), value);
+            lineages
+        }
+        LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) => exprs_lineage(
+            groupby.iter().chain(aggregations),
+            &plan_lineage(input, sources),
+        ),
+        LogicalPlan::Pivot(Pivot {
+            input,
+            group_by,
+            pivot_column,
+            value_column,
+            aggregation,
+            names,
+            ..
+        }) => {
+            let input = plan_lineage(input, sources);
+            let mut lineages = exprs_lineage(group_by, &input);
+            // Each pivoted column aggregates the values of the rows whose pivot column holds its name.
+            let mut pivoted = expr_lineage(pivot_column, &input);
+            pivoted.merge(&expr_lineage(value_column, &input));
+            pivoted.push_transformation(Expr::Agg(aggregation.clone()).into());
+            for name in names {
+                lineages.insert(name.clone(), pivoted.renamed(name));
+            }
+            lineages
+        }
+        LogicalPlan::Resample(
+            resample @ Resample {
+                input,
+                by,
+                aggregations,
+                ..
+            },
+        ) => {
+            // The intervals are computed from the timestamp column, under its name.
+            let bucket = resample.bucket();
+            exprs_lineage(
+                by.iter()
+                    .chain(std::iter::once(&bucket))
+                    .chain(aggregations),
+                &plan_lineage(input, sources),
+            )
+        }
+        LogicalPlan::Concat(Concat { input, other, .. }) => {
+            let lhs = plan_lineage(input, sources);
+            let rhs = plan_lineage(other, sources);
+            set_operation_lineage(plan, lhs, rhs)
+        }
+        LogicalPlan::Intersect(Intersect { lhs, rhs, .. })
+        | LogicalPlan::Union(Union { lhs, rhs, .. }) => {
+            let lhs = plan_lineage(lhs, sources);
+            let rhs = plan_lineage(rhs, sources);
+            set_operation_lineage(plan, lhs, rhs)
+        }
+        LogicalPlan::Join(Join {
+            left,
+            right,
+            output_schema,
+            ..
+        }) => {
+            let left = plan_lineage(left, sources);
+            let right = plan_lineage(right, sources);
+            output_schema
+                .field_names()
+                .map(|name| {
+                    let lineage = left
+                        .get(name)
+                        .or_else(|| right.get(name))
+                        .cloned()
+                        .unwrap_or_else(|| ColumnLineage::new(name));
+                    (name.to_string(), lineage)
+                })
+                .collect()
+        }
+        LogicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
+            input,
+            column_name,
+            ..
+        }) => {
+            let mut lineages = plan_lineage(input, sources);
+            lineages.insert(column_name.clone(), ColumnLineage::new(column_name));
+            lineages
+        }
+        LogicalPlan::Window(Window {
+            input,
+            window_functions,
+            aliases,
+            window_spec,
+            ..
+        }) => {
+            let input = plan_lineage(input, sources);
+            // Every window function depends on the columns which partition and order its window.
+            let mut window = ColumnLineage::new("");
+            for expr in window_spec.partition_by.iter().chain(&window_spec.order_by) {
+                window.merge(&expr_lineage(expr, &input));
+            }
+            let mut lineages = input.clone();
+            for (window_function, alias) in window_functions.iter().zip(aliases) {
+                let over: ExprRef = Expr::Over(window_function.clone(), window_spec.clone()).into();
+                let mut lineage = window.renamed(alias);
+                for column in get_required_columns(&over).iter().unique() {
+                    if let Some(column_lineage) = input.get(column) {
+                        lineage.merge(column_lineage);
+                    }
+                }
+                lineage.push_transformation(over);
+                lineages.insert(alias.clone(), lineage);
+            }
+            lineages
+        }
+        LogicalPlan::VLLMProject(VLLMProject {
+            input,
+            expr,
+            output_column_name,
+            ..
+        }) => {
+            let mut lineages = plan_lineage(input, sources);
+            let mut lineage =
+                expr_lineage(&expr.input, &lineages).renamed(output_column_name.as_ref());
+            lineage.push_transformation(Expr::VLLM(expr.clone()).into());
+            lineages.insert(output_column_name.to_string(), lineage);
+            lineages
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, resolved_col, unresolved_col};
+
+    use super::{ColumnLineageGraph, SourceColumn};
+    use crate::{
+        JoinType,
+        test::{dummy_scan_node, dummy_scan_operator},
+    };
+
+    fn inputs(graph: &ColumnLineageGraph, name: &str) -> Vec<(usize, String)> {
+        graph
+            .column(name)
+            .unwrap()
+            .inputs
+            .iter()
+            .map(|SourceColumn { source, column }| (*source, column.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lineage_of_projection_and_aggregation() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(scan_op)
+            .filter(unresolved_col("a").gt(lit(0)))?
+            .select(vec![
+                unresolved_col("c").alias("key"),
+                unresolved_col("a").add(unresolved_col("b")).alias("total"),
+            ])?
+            .aggregate(
+                vec![unresolved_col("total").sum()],
+                vec![unresolved_col("key")],
+            )?
+            .build();
+
+        let graph = ColumnLineageGraph::new(&plan);
+        assert_eq!(graph.sources.len(), 1);
+        assert_eq!(
+            graph
+                .columns
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["key", "total"]
+        );
+
+        // Renaming a column isn't a transformation.
+        assert_eq!(inputs(&graph, "key"), vec![(0, "c".to_string())]);
+        assert!(graph.column("key").unwrap().transformations.is_empty());
+
+        assert_eq!(
+            inputs(&graph, "total"),
+            vec![(0, "a".to_string()), (0, "b".to_string())]
+        );
+        let transformations = &graph.column("total").unwrap().transformations;
+        assert_eq!(transformations.len(), 2);
+        assert_eq!(
+            transformations[0],
+            resolved_col("a").add(resolved_col("b")).alias("total")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lineage_of_join() -> DaftResult<()> {
+        let left = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::Utf8),
+        ]));
+        let right = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("score", DataType::Float64),
+        ]))
+        .select(vec![
+            unresolved_col("id"),
+            unresolved_col("score").mul(lit(2.0)).alias("score"),
+        ])?;
+        let plan = left
+            .join(
+                right,
+                None,
+                vec!["id".to_string()],
+                JoinType::Inner,
+                None,
+                Default::default(),
+            )?
+            .build();
+
+        let graph = ColumnLineageGraph::new(&plan);
+        // Both scans are described the same way, so they're the same source.
+        assert_eq!(graph.sources.len(), 1);
+        assert_eq!(inputs(&graph, "name"), vec![(0, "name".to_string())]);
+        assert_eq!(inputs(&graph, "score"), vec![(0, "score".to_string())]);
+        assert_eq!(graph.column("score").unwrap().transformations.len(), 1);
+
+        let json = graph.to_json();
+        assert_eq!(json["sources"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            json["columns"].as_array().unwrap().len(),
+            plan.schema().len()
+        );
+        Ok(())
+    }
+}
@@ -40,6 +40,7 @@ pub enum LogicalPlan {
     Distinct(Distinct),
     Aggregate(Aggregate),
     Pivot(Pivot),
+    Resample(Resample),
     Concat(Concat),
     Intersect(Intersect),
     Union(Union),
@@ -161,6 +162,7 @@ impl LogicalPlan {
             Self::Distinct(Distinct { input, .. }) => input.schema(),
             Self::Aggregate(Aggregate { output_schema, .. }) => output_schema.clone(),
             Self::Pivot(Pivot { output_schema, .. }) => output_schema.clone(),
+            Self::Resample(Resample { output_schema, .. }) => output_schema.clone(),
             Self::Concat(Concat { input, .. }) => input.schema(),
             Self::Intersect(Intersect { lhs, .. }) => lhs.schema(),
             Self::Union(Union { lhs, .. }) => lhs.schema(),
@@ -279,6 +281,17 @@ impl LogicalPlan {
                     .collect();
                 RequiredCols::new(res, None)
             }
+            Self::Resample(resample) => {
+                let res = resample
+                    .aggregations
+                    .iter()
+                    .flat_map(|agg| agg.children())
+                    .flat_map(|e| get_required_columns(&e))
+                    .chain(resample.by.iter().flat_map(get_required_columns))
+                    .chain(get_required_columns(&resample.ts_column))
+                    .collect();
+                RequiredCols::new(res, None)
+            }
             Self::Join(join) => {
                 let mut left = IndexSet::new();
                 let mut right = IndexSet::new();
@@ -354,6 +367,7 @@ impl LogicalPlan {
             Self::Distinct(..) => "Distinct",
             Self::Aggregate(..) => "Aggregate",
             Self::Pivot(..) => "Pivot",
+            Self::Resample(..) => "Resample",
             Self::Concat(..) => "Concat",
             Self::Join(..) => "Join",
             Self::Intersect(..) => "Intersect",
@@ -385,6 +399,7 @@ impl LogicalPlan {
             | Self::Distinct(Distinct { stats_state, .. })
             | Self::Aggregate(Aggregate { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
+            | Self::Resample(Resample { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
             | Self::Join(Join { stats_state, .. })
             | Self::Sink(Sink { stats_state, .. })
@@ -425,6 +440,7 @@ impl LogicalPlan {
             Self::Distinct(plan) => Self::Distinct(plan.with_materialized_stats()),
             Self::Aggregate(plan) => Self::Aggregate(plan.with_materialized_stats()),
             Self::Pivot(plan) => Self::Pivot(plan.with_materialized_stats()),
+            Self::Resample(plan) => Self::Resample(plan.with_materialized_stats()),
             Self::Concat(plan) => Self::Concat(plan.with_materialized_stats()),
             Self::Join(plan) => Self::Join(plan.with_materialized_stats()),
             Self::Sink(plan) => Self::Sink(plan.with_materialized_stats()),
@@ -461,6 +477,7 @@ impl LogicalPlan {
             Self::Distinct(distinct) => distinct.multiline_display(),
             Self::Aggregate(aggregate) => aggregate.multiline_display(),
             Self::Pivot(pivot) => pivot.multiline_display(),
+            Self::Resample(resample) => resample.multiline_display(),
             Self::Concat(concat) => concat.multiline_display(),
             Self::Intersect(inner) => inner.multiline_display(),
             Self::Union(inner) => inner.multiline_display(),
@@ -494,6 +511,7 @@ impl LogicalPlan {
             Self::Distinct(Distinct { input, .. }) => vec![input],
             Self::Aggregate(Aggregate { input, .. }) => vec![input],
             Self::Pivot(Pivot { input, .. }) => vec![input],
+            Self::Resample(Resample { input, .. }) => vec![input],
             Self::Concat(Concat { input, other, .. }) => vec![input, other],
             Self::Join(Join { left, right, .. }) => vec![left, right],
             Self::Sink(Sink { input, .. }) => vec![input],
@@ -545,9 +563,20 @@ impl LogicalPlan {
                 Self::Offset(Offset { offset, .. }) => {
                     Self::Offset(Offset::new(input.clone(), *offset))
                 }
-                Self::Explode(Explode { to_explode, .. }) => {
-                    Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap())
-                }
+                Self::Explode(Explode {
+                    to_explode,
+                    index_column,
+                    pad_mismatched,
+                    ..
+                }) => Self::Explode(
+                    Explode::try_new(
+                        input.clone(),
+                        to_explode.clone(),
+                        index_column.clone(),
+                        *pad_mismatched,
+                    )
+                    .unwrap(),
+                ),
                 Self::Sort(Sort {
                     sort_by,
                     descending,
@@ -595,6 +624,24 @@ impl LogicalPlan {
                     )
                     .unwrap(),
                 ),
+                Self::Resample(Resample {
+                    ts_column,
+                    every,
+                    by,
+                    aggregations,
+                    fill,
+                    ..
+                }) => Self::Resample(
+                    Resample::try_new(
+                        input.clone(),
+                        ts_column.clone(),
+                        *every,
+                        by.clone(),
+                        aggregations.clone(),
+                        *fill,
+                    )
+                    .unwrap(),
+                ),
                 Self::Sink(Sink { sink_info, .. }) => {
                     Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap())
                 }
@@ -711,6 +758,7 @@ impl LogicalPlan {
                     on,
                     join_type,
                     join_strategy,
+                    broadcast_side,
                     ..
                 }) => Self::Join(
                     Join::try_new(
@@ -720,7 +768,8 @@ impl LogicalPlan {
                         *join_type,
                         *join_strategy,
                     )
-                    .unwrap(),
+                    .unwrap()
+                    .with_broadcast_side(*broadcast_side),
                 ),
                 _ => panic!("Logical op {} has one input, but got two", self),
             },
@@ -848,6 +897,7 @@ impl LogicalPlan {
             | Self::Distinct(Distinct { plan_id, .. })
             | Self::Aggregate(Aggregate { plan_id, .. })
             | Self::Pivot(Pivot { plan_id, .. })
+            | Self::Resample(Resample { plan_id, .. })
             | Self::Concat(Concat { plan_id, .. })
             | Self::Intersect(Intersect { plan_id, .. })
             | Self::Union(Union { plan_id, .. })
@@ -879,6 +929,7 @@ impl LogicalPlan {
             | Self::Distinct(Distinct { node_id, .. })
             | Self::Aggregate(Aggregate { node_id, .. })
             | Self::Pivot(Pivot { node_id, .. })
+            | Self::Resample(Resample { node_id, .. })
             | Self::Concat(Concat { node_id, .. })
             | Self::Intersect(Intersect { node_id, .. })
             | Self::Union(Union { node_id, .. })
@@ -913,6 +964,7 @@ impl LogicalPlan {
             Self::Distinct(distinct) => Self::Distinct(distinct.with_plan_id(plan_id)),
             Self::Aggregate(aggregate) => Self::Aggregate(aggregate.with_plan_id(plan_id)),
             Self::Pivot(pivot) => Self::Pivot(pivot.with_plan_id(plan_id)),
+            Self::Resample(resample) => Self::Resample(resample.with_plan_id(plan_id)),
             Self::Concat(concat) => Self::Concat(concat.with_plan_id(plan_id)),
             Self::Intersect(intersect) => Self::Intersect(intersect.with_plan_id(plan_id)),
             Self::Union(union) => Self::Union(union.with_plan_id(plan_id)),
@@ -951,6 +1003,7 @@ impl LogicalPlan {
             Self::Distinct(distinct) => Self::Distinct(distinct.with_node_id(node_id)),
             Self::Aggregate(aggregate) => Self::Aggregate(aggregate.with_node_id(node_id)),
             Self::Pivot(pivot) => Self::Pivot(pivot.with_node_id(node_id)),
+            Self::Resample(resample) => Self::Resample(resample.with_node_id(node_id)),
             Self::Concat(concat) => Self::Concat(concat.with_node_id(node_id)),
             Self::Intersect(intersect) => Self::Intersect(intersect.with_node_id(node_id)),
             Self::Union(union) => Self::Union(union.with_node_id(node_id)),
@@ -1065,6 +1118,7 @@ impl_from_data_struct_for_logical_plan!(Repartition);
 impl_from_data_struct_for_logical_plan!(Distinct);
 impl_from_data_struct_for_logical_plan!(Aggregate);
 impl_from_data_struct_for_logical_plan!(Pivot);
+impl_from_data_struct_for_logical_plan!(Resample);
 impl_from_data_struct_for_logical_plan!(Concat);
 impl_from_data_struct_for_logical_plan!(Intersect);
 impl_from_data_struct_for_logical_plan!(Union);
@@ -0,0 +1,66 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{Expr, ExprRef, lit, null_lit};
+use daft_schema::dtype::DataType;
+
+use crate::LogicalPlanBuilder;
+
+/// Computes the statistics collected by `ANALYZE`, with one row per column of the input.
+///
+/// The output is the format expected by `AnalyzedTableStatistics::from_analyze_table`.
+pub fn analyze(input: &LogicalPlanBuilder, num_buckets: usize) -> DaftResult<LogicalPlanBuilder> {
+    if num_buckets == 0 {
+        return Err(DaftError::ValueError(
+            "Expected the number of histogram buckets to be positive".to_string(),
+        ));
+    }
+    let percentiles = (0..=num_buckets)
+        .map(|i| i as f64 / num_buckets as f64)
+        .collect::<Vec<_>>();
+    let histogram_dtype = DataType::FixedSizeList(Box::new(DataType::Float64), percentiles.len());
+
+    let mut cols: Vec<ExprRef> = vec![]; // column             :: utf8
+    let mut rows: Vec<ExprRef> = vec![]; // num_rows           :: uint64
+    let mut nuls: Vec<ExprRef> = vec![]; // null_count         :: uint64
+    let mut ndvs: Vec<ExprRef> = vec![]; // ndv                :: uint64
+    let mut mins: Vec<ExprRef> = vec![]; // min                :: float64
+    let mut maxs: Vec<ExprRef> = vec![]; // max                :: float64
+    let mut hist: Vec<ExprRef> = vec![]; // histogram          :: fixed_size_list[float64]
+    for field in input.schema().as_ref() {
+        let col = daft_dsl::resolved_col(field.name.as_str());
+        cols.push(lit(field.name.clone()));
+        rows.push(col.clone().count(CountMode::All));
+        nuls.push(col.clone().count(CountMode::Null));
+        ndvs.push(col.clone().approx_count_distinct());
+        if field.dtype.is_numeric() {
+            let col = col.cast(&DataType::Float64);
+            mins.push(col.clone().min());
+            maxs.push(col.clone().max());
+            hist.push(col.approx_percentiles(&percentiles, true));
+        } else {
+            mins.push(null_lit().cast(&DataType::Float64));
+            maxs.push(null_lit().cast(&DataType::Float64));
+            hist.push(null_lit().cast(&histogram_dtype));
+        }
+    }
+    // apply aggregations lists
+    let input = input.aggregate(
+        vec![
+            list_(cols, "column"),
+            list_(rows, "num_rows"),
+            list_(nuls, "null_count"),
+            list_(ndvs, "ndv"),
+            list_(mins, "min"),
+            list_(maxs, "max"),
+            list_(hist, "histogram"),
+        ],
+        vec![],
+    )?;
+    // apply explode for all columns
+    input.explode(input.columns(), None, false)
+}
+
+/// Creates a list constructor for the given items.
+fn list_(items: Vec<ExprRef>, alias: &str) -> ExprRef {
+    Expr::List(items).arced().alias(alias)
+}
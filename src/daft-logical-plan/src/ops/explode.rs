@@ -1,13 +1,19 @@
 use std::sync::Arc;
 
+use common_error::DaftError;
 use daft_dsl::{ExprRef, exprs_to_schema};
-use daft_schema::schema::{Schema, SchemaRef};
+use daft_schema::{
+    dtype::DataType,
+    field::Field,
+    schema::{Schema, SchemaRef},
+};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use snafu::ResultExt;
 
 use crate::{
     LogicalPlan,
-    logical_plan::{self},
+    logical_plan::{self, CreationSnafu},
     stats::{ApproxStats, PlanStats, StatsState},
 };
 
@@ -20,6 +26,10 @@ pub struct Explode {
     pub input: Arc<LogicalPlan>,
     // Expressions to explode. e.g. col("a")
     pub to_explode: Vec<ExprRef>,
+    // Name of the column to emit with the position of each element within its list, if any.
+    pub index_column: Option<String>,
+    // Whether to pad lists with nulls when the exploded lists of a row have different lengths, instead of erroring.
+    pub pad_mismatched: bool,
     pub exploded_schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -28,6 +38,8 @@ impl Explode {
     pub(crate) fn try_new(
         input: Arc<LogicalPlan>,
         to_explode: Vec<ExprRef>,
+        index_column: Option<String>,
+        pad_mismatched: bool,
     ) -> logical_plan::Result<Self> {
         let exploded_schema = {
             let explode_exprs = to_explode
@@ -39,10 +51,21 @@ impl Explode {
             let explode_schema = exprs_to_schema(&explode_exprs, input.schema())?;
 
             let input_schema = input.schema();
-            let fields = input_schema
+            let mut fields = input_schema
                 .into_iter()
                 .map(|field| explode_schema.get_field(&field.name).unwrap_or(field))
-                .cloned();
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if let Some(index_column) = &index_column {
+                if input_schema.has_field(index_column) {
+                    return Err(DaftError::ValueError(format!(
+                        "Explode index column `{index_column}` conflicts with an existing column"
+                    ))
+                    .into());
+                }
+                fields.push(Field::new(index_column, DataType::UInt64));
+            }
 
             Schema::new(fields).into()
         };
@@ -52,6 +75,8 @@ impl Explode {
             node_id: None,
             input,
             to_explode,
+            index_column,
+            pad_mismatched,
             exploded_schema,
             stats_state: StatsState::NotMaterialized,
         })
@@ -91,6 +116,12 @@ impl Explode {
             "Explode: {}",
             self.to_explode.iter().map(|e| e.to_string()).join(", ")
         ));
+        if let Some(index_column) = &self.index_column {
+            res.push(format!("Index column = {index_column}"));
+        }
+        if self.pad_mismatched {
+            res.push("Pad mismatched = true".to_string());
+        }
         res.push(format!("Schema = {}", self.exploded_schema.short_string()));
         if let StatsState::Materialized(stats) = &self.stats_state {
             res.push(format!("Stats = {}", stats));
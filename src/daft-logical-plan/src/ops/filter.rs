@@ -59,9 +59,14 @@ impl Filter {
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         // Assume no row/column pruning in cardinality-affecting operations.
-        // TODO(desmond): We can do better estimations here. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        let estimated_selectivity = estimated_selectivity(&self.predicate, &self.input.schema());
+        // Use the column statistics of analyzed tables if we have them, and the fixed estimates otherwise.
+        let estimated_selectivity = match &input_stats.column_stats {
+            Some(column_stats) => {
+                column_stats.estimate_selectivity(&self.predicate, &self.input.schema())
+            }
+            None => estimated_selectivity(&self.predicate, &self.input.schema()),
+        };
         let approx_stats = ApproxStats {
             num_rows: (input_stats.approx_stats.num_rows as f64 * estimated_selectivity).ceil()
                 as usize,
@@ -69,7 +74,11 @@ impl Filter {
                 as usize,
             acc_selectivity: input_stats.approx_stats.acc_selectivity * estimated_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(input_stats.column_stats.clone())
+                .into(),
+        );
         self
     }
 
@@ -8,8 +8,8 @@ use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_algebra::boolean::{combine_conjunction, split_conjunction};
 use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
-    Column, Expr, ExprRef, Operator, ResolvedColumn, join::infer_join_schema, resolved_col,
-    right_col,
+    Column, Expr, ExprRef, Operator, ResolvedColumn, bound_col, expr::bound_expr::BoundExpr,
+    join::infer_join_schema, resolved_col, right_col,
 };
 use indexmap::IndexSet;
 #[cfg(feature = "python")]
@@ -162,6 +162,41 @@ impl JoinPredicate {
 
         (remaining, side_only_pred)
     }
+
+    /// Bind the join side columns of the predicate to the columns of a joined pair of rows,
+    /// which are laid out as the columns of the left side followed by the columns of the right side.
+    ///
+    /// This is used to evaluate the residual predicates that remain after splitting out the equality predicates.
+    pub fn bind_join_sides(
+        &self,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> DaftResult<Option<BoundExpr>> {
+        let Some(pred) = &self.0 else {
+            return Ok(None);
+        };
+
+        let bound = pred
+            .clone()
+            .transform(|e| {
+                if let Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(field, side))) =
+                    e.as_ref()
+                {
+                    let index = match side {
+                        JoinSide::Left => left_schema.get_index(&field.name)?,
+                        JoinSide::Right => {
+                            left_schema.len() + right_schema.get_index(&field.name)?
+                        }
+                    };
+                    Ok(Transformed::yes(bound_col(index, field.clone())))
+                } else {
+                    Ok(Transformed::no(e))
+                }
+            })?
+            .data;
+
+        Ok(Some(BoundExpr::new_unchecked(bound)))
+    }
 }
 
 impl TryFrom<ExprRef> for JoinPredicate {
@@ -184,6 +219,8 @@ pub struct Join {
     pub on: JoinPredicate,
     pub join_type: JoinType,
     pub join_strategy: Option<JoinStrategy>,
+    /// The side which a broadcast hint named, which is broadcast, or which the hash table is built on.
+    pub broadcast_side: Option<JoinSide>,
     pub output_schema: SchemaRef,
     pub stats_state: StatsState,
 }
@@ -210,11 +247,17 @@ impl Join {
             on,
             join_type,
             join_strategy,
+            broadcast_side: None,
             output_schema,
             stats_state: StatsState::NotMaterialized,
         })
     }
 
+    pub fn with_broadcast_side(mut self, broadcast_side: Option<JoinSide>) -> Self {
+        self.broadcast_side = broadcast_side;
+        self
+    }
+
     pub fn with_plan_id(mut self, plan_id: usize) -> Self {
         self.plan_id = Some(plan_id);
         self
@@ -333,10 +376,14 @@ impl Join {
     }
 
     pub(crate) fn with_materialized_stats(mut self) -> Self {
-        // Assume a Primary-key + Foreign-Key join which would yield the max of the two tables.
-        // TODO(desmond): We can do better estimations here. For now, use the old logic.
         let left_stats = self.left.materialized_stats();
         let right_stats = self.right.materialized_stats();
+        if let Some(stats) = self.estimate_stats_from_column_stats(left_stats, right_stats) {
+            self.stats_state = StatsState::Materialized(stats.into());
+            return self;
+        }
+        // Without column statistics, assume a Primary-key + Foreign-Key join which would yield the max of the two
+        // tables.
         // We assume that if one side of a join had its cardinality reduced by some operations
         // (e.g. filters, limits, aggregations), then assuming a pk-fk join, the total number of
         // rows output from the join will be reduced proportionally. Hence, apply the right side's
@@ -359,6 +406,67 @@ impl Join {
         self
     }
 
+    /// Estimates the output of the join from the number of distinct values of the join keys, which is only possible
+    /// when every equi-join key is a column with analyzed statistics on both sides.
+    fn estimate_stats_from_column_stats(
+        &self,
+        left_stats: &PlanStats,
+        right_stats: &PlanStats,
+    ) -> Option<PlanStats> {
+        let (left_column_stats, right_column_stats) = (
+            left_stats.column_stats.as_ref()?,
+            right_stats.column_stats.as_ref()?,
+        );
+        let (_, left_on, right_on, _) = self.on.split_eq_preds();
+        if left_on.is_empty() {
+            return None;
+        }
+        let left_rows = left_stats.approx_stats.num_rows as f64;
+        let right_rows = right_stats.approx_stats.num_rows as f64;
+        // The number of distinct values of a key can't exceed the number of rows that are left after filtering. With
+        // several keys, we use the most selective one.
+        let mut left_ndv = 1.0f64;
+        let mut right_ndv = 1.0f64;
+        for (left_key, right_key) in left_on.iter().zip(right_on.iter()) {
+            let left_key = left_column_stats.column_of(left_key)?;
+            let right_key = right_column_stats.column_of(right_key)?;
+            left_ndv = left_ndv.max((left_key.ndv as f64).min(left_rows));
+            right_ndv = right_ndv.max((right_key.ndv as f64).min(right_rows));
+        }
+
+        // Assume that the keys of the side with fewer distinct values are contained in the other side's.
+        let inner_rows = left_rows * right_rows / left_ndv.max(right_ndv);
+        let semi_rows = left_rows * (right_ndv / left_ndv).min(1.0);
+        let num_rows = match self.join_type {
+            JoinType::Inner => inner_rows,
+            JoinType::Left => inner_rows.max(left_rows),
+            JoinType::Right => inner_rows.max(right_rows),
+            JoinType::Outer => inner_rows.max(left_rows).max(right_rows),
+            JoinType::Semi => semi_rows,
+            JoinType::Anti => left_rows - semi_rows,
+        };
+
+        let bytes_per_row = |stats: &PlanStats| {
+            stats.approx_stats.size_bytes as f64 / stats.approx_stats.num_rows.max(1) as f64
+        };
+        let output_bytes_per_row = match self.join_type {
+            JoinType::Semi | JoinType::Anti => bytes_per_row(left_stats),
+            _ => bytes_per_row(left_stats) + bytes_per_row(right_stats),
+        };
+        let approx_stats = ApproxStats {
+            num_rows: num_rows.ceil() as usize,
+            size_bytes: (num_rows * output_bytes_per_row).ceil() as usize,
+            acc_selectivity: left_stats.approx_stats.acc_selectivity
+                * right_stats.approx_stats.acc_selectivity,
+        };
+        let column_stats = left_column_stats.merge(
+            right_column_stats,
+            approx_stats.num_rows as u64,
+            &self.output_schema,
+        );
+        Some(PlanStats::new(approx_stats).with_column_stats(Some(Arc::new(column_stats))))
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!("Join: Type = {}", self.join_type));
@@ -367,6 +475,9 @@ impl Join {
             self.join_strategy
                 .map_or_else(|| "Auto".to_string(), |s| s.to_string())
         ));
+        if let Some(broadcast_side) = self.broadcast_side {
+            res.push(format!("Broadcast side = {broadcast_side}"));
+        }
 
         if let Some(on_expr) = self.on.inner() {
             res.push(format!("On = {on_expr}",));
@@ -388,6 +499,7 @@ impl Join {
 pub struct JoinOptions {
     pub prefix: Option<String>,
     pub suffix: Option<String>,
+    pub broadcast_side: Option<JoinSide>,
 }
 
 impl JoinOptions {
@@ -400,4 +512,9 @@ impl JoinOptions {
         self.suffix = Some(val.into());
         self
     }
+
+    pub fn broadcast_side(mut self, side: JoinSide) -> Self {
+        self.broadcast_side = Some(side);
+        self
+    }
 }
@@ -75,7 +75,11 @@ impl Limit {
             },
             acc_selectivity: input_stats.approx_stats.acc_selectivity * limit_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(input_stats.column_stats.clone())
+                .into(),
+        );
         self
     }
 
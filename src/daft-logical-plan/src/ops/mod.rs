@@ -1,4 +1,5 @@
 mod agg;
+mod analyze;
 mod concat;
 mod distinct;
 mod explode;
@@ -11,6 +12,7 @@ mod offset;
 mod pivot;
 mod project;
 mod repartition;
+mod resample;
 mod sample;
 mod set_operations;
 mod shard;
@@ -25,6 +27,7 @@ mod vllm;
 mod window;
 
 pub use agg::Aggregate;
+pub use analyze::analyze;
 pub use concat::Concat;
 pub use distinct::Distinct;
 pub use explode::Explode;
@@ -37,6 +40,8 @@ pub use offset::Offset;
 pub use pivot::Pivot;
 pub use project::Project;
 pub use repartition::Repartition;
+pub use resample::Resample;
+pub(crate) use resample::resample_interval;
 pub use sample::Sample;
 pub use set_operations::{Except, Intersect, SetQuantifier, Union, UnionStrategy};
 pub use shard::Shard;
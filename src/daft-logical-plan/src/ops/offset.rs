@@ -57,7 +57,11 @@ impl Offset {
                 * (input_stats.approx_stats.size_bytes / approx_num_rows.max(1)),
             acc_selectivity: input_stats.approx_stats.acc_selectivity * offset_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(input_stats.column_stats.clone())
+                .into(),
+        );
         self
     }
 
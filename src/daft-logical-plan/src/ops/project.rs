@@ -4,11 +4,12 @@ use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_core::prelude::*;
 use daft_dsl::{
-    AggExpr, ApproxPercentileParams, Column, Expr, ExprRef,
+    AggExpr, ApproxPercentileParams, Column, Expr, ExprRef, ResolvedColumn,
     expr::VLLMExpr,
     functions::{FunctionArgs, scalar::ScalarFn},
     optimization, resolved_col,
 };
+use daft_stats::AnalyzedTableStatistics;
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -92,7 +93,30 @@ impl Project {
     pub(crate) fn with_materialized_stats(mut self) -> Self {
         // TODO(desmond): We can do better estimations with the projection schema. For now, reuse the old logic.
         let input_stats = self.input.materialized_stats();
-        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        // Column statistics only carry over for columns that are passed through unchanged.
+        let column_stats = input_stats.column_stats.as_ref().map(|column_stats| {
+            let passthrough = self
+                .projection
+                .iter()
+                .filter_map(|expr| match expr.as_ref() {
+                    Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => {
+                        Some(name.as_ref())
+                    }
+                    _ => None,
+                })
+                .collect::<IndexSet<_>>();
+            Arc::new(AnalyzedTableStatistics::new(
+                column_stats.num_rows,
+                column_stats
+                    .columns
+                    .iter()
+                    .filter(|(name, _)| passthrough.contains(name.as_str()))
+                    .map(|(name, stats)| (name.clone(), stats.clone()))
+                    .collect(),
+            ))
+        });
+        self.stats_state =
+            StatsState::Materialized(input_stats.clone().with_column_stats(column_stats).into());
         self
     }
 
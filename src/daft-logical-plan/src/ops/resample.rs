@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{gap_fill::GapFill, prelude::*};
+use daft_dsl::{ExprRef, Operator, binary_op, expr::agg::extract_agg_expr, lit};
+use daft_schema::schema::{Schema, SchemaRef};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    LogicalPlan,
+    logical_plan::{self},
+    stats::StatsState,
+};
+
+const SECONDS_PER_DAY: i128 = 24 * 60 * 60;
+
+/// Converts the interval to resample by to the physical units of the timestamp column, which are days for dates, the
+/// time unit of timestamps, and the values themselves for integers.
+pub(crate) fn resample_interval(every: &Literal, ts_dtype: &DataType) -> DaftResult<i64> {
+    let interval = match (every, ts_dtype) {
+        (Literal::Duration(duration, unit), DataType::Timestamp(..) | DataType::Date) => {
+            // Units of the timestamp column per second, as a fraction.
+            let (numerator, denominator) = match ts_dtype {
+                DataType::Timestamp(ts_unit, _) => (ts_unit.to_scale_factor() as i128, 1),
+                _ => (1, SECONDS_PER_DAY),
+            };
+            let scaled = *duration as i128 * numerator;
+            let denominator = denominator * unit.to_scale_factor() as i128;
+            if scaled % denominator != 0 {
+                return Err(DaftError::ValueError(format!(
+                    "The interval to resample by must be a whole number of the units of the timestamp column of type {ts_dtype}, got {every}"
+                )));
+            }
+            i64::try_from(scaled / denominator).map_err(|_| {
+                DaftError::ValueError(format!(
+                    "The interval to resample by is too large for the timestamp column of type {ts_dtype}, got {every}"
+                ))
+            })?
+        }
+        (_, dtype) if dtype.is_integer() => every
+            .try_as_usize()?
+            .and_then(|every| i64::try_from(every).ok())
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "The interval to resample an integer column by must be a non-negative integer, got {every}"
+                ))
+            })?,
+        _ => {
+            return Err(DaftError::TypeError(format!(
+                "Expected a duration as the interval to resample a column of type {ts_dtype} by, got {every}"
+            )));
+        }
+    };
+    if interval <= 0 {
+        return Err(DaftError::ValueError(format!(
+            "The interval to resample by must be positive, got {every}"
+        )));
+    }
+    Ok(interval)
+}
+
+/// Aggregates a time series into fixed intervals per group of keys, filling the intervals without any rows.
+///
+/// The intervals are `every` apart in the physical units of the timestamp column, and start at multiples of `every`.
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct Resample {
+    pub plan_id: Option<usize>,
+    pub node_id: Option<usize>,
+    pub input: Arc<LogicalPlan>,
+    pub ts_column: ExprRef,
+    pub every: i64,
+    pub by: Vec<ExprRef>,
+    pub aggregations: Vec<ExprRef>,
+    pub fill: Option<GapFill>,
+    pub output_schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl Resample {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        ts_column: ExprRef,
+        every: i64,
+        by: Vec<ExprRef>,
+        aggregations: Vec<ExprRef>,
+        fill: Option<GapFill>,
+    ) -> logical_plan::Result<Self> {
+        let input_schema = input.schema();
+        let ts_field = ts_column.to_field(&input_schema)?;
+        if !(ts_field.dtype.is_timestamp()
+            || ts_field.dtype.is_date()
+            || ts_field.dtype.is_integer())
+        {
+            return Err(DaftError::TypeError(format!(
+                "Resample only supports timestamp, date and integer columns, received {} of type {}",
+                ts_field.name, ts_field.dtype
+            ))
+            .into());
+        }
+        if every <= 0 {
+            return Err(DaftError::ValueError(format!(
+                "The interval to resample by must be positive, got {every}"
+            ))
+            .into());
+        }
+        for aggregation in &aggregations {
+            if extract_agg_expr(aggregation).is_err() {
+                return Err(DaftError::ValueError(format!(
+                    "Resample only supports using top level aggregation expressions, received {aggregation}",
+                ))
+                .into());
+            }
+        }
+
+        let output_schema = {
+            let by_fields = by
+                .iter()
+                .map(|expr| expr.to_field(&input_schema))
+                .collect::<DaftResult<Vec<_>>>()?;
+            let agg_fields = aggregations
+                .iter()
+                .map(|expr| {
+                    let field = expr.to_field(&input_schema)?;
+                    Ok(match fill {
+                        Some(fill) => {
+                            let dtype = fill.filled_dtype(&field.dtype);
+                            Field::new(field.name, dtype)
+                        }
+                        None => field,
+                    })
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+
+            let fields = by_fields
+                .into_iter()
+                .chain(std::iter::once(ts_field))
+                .chain(agg_fields);
+            Schema::new(fields).into()
+        };
+
+        Ok(Self {
+            plan_id: None,
+            node_id: None,
+            input,
+            ts_column,
+            every,
+            by,
+            aggregations,
+            fill,
+            output_schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    /// The start of the interval of each row, named after the timestamp column.
+    ///
+    /// Intervals start at multiples of `every`, so the remainder is taken as a non-negative value for timestamps
+    /// before the epoch.
+    pub fn bucket(&self) -> ExprRef {
+        let ts_dtype = self
+            .ts_column
+            .to_field(&self.input.schema())
+            .expect("The timestamp column was resolved against the input schema")
+            .dtype;
+        let physical_dtype = ts_dtype.to_physical();
+
+        let ts = self.ts_column.clone().cast(&physical_dtype);
+        let every = lit(self.every).cast(&physical_dtype);
+        let remainder = binary_op(
+            Operator::Modulus,
+            binary_op(
+                Operator::Plus,
+                binary_op(Operator::Modulus, ts.clone(), every.clone()),
+                every.clone(),
+            ),
+            every,
+        );
+        binary_op(Operator::Minus, ts, remainder)
+            .cast(&ts_dtype)
+            .alias(self.ts_column.name())
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub fn with_node_id(mut self, node_id: usize) -> Self {
+        self.node_id = Some(node_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // TODO: Resampling can add rows for the gaps, but for now we estimate with the input stats like a pivot.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push("Resample:".to_string());
+        res.push(format!("Timestamp column = {}", self.ts_column));
+        res.push(format!("Every = {}", self.every));
+        if !self.by.is_empty() {
+            res.push(format!(
+                "By = {}",
+                self.by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        res.push(format!(
+            "Aggregation = {}",
+            self.aggregations.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if let Some(fill) = self.fill {
+            res.push(format!("Fill = {fill}"));
+        }
+        res.push(format!(
+            "Output schema = {}",
+            self.output_schema.short_string()
+        ));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
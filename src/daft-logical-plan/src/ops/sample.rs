@@ -89,7 +89,11 @@ impl Sample {
         } else {
             input_stats.approx_stats.clone()
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(input_stats.column_stats.clone())
+                .into(),
+        );
         self
     }
 
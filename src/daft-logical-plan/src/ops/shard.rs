@@ -49,7 +49,11 @@ impl Shard {
             acc_selectivity: input_stats.approx_stats.acc_selectivity
                 / self.sharder.world_size() as f64,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(input_stats.column_stats.clone())
+                .into(),
+        );
         self
     }
 
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     source_info::{GlobScanInfo, InMemoryInfo, PlaceHolderInfo, SourceInfo},
-    stats::{ApproxStats, PlanStats, StatsState},
+    stats::{AnalyzedStats, ApproxStats, PlanStats, StatsState},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -21,6 +21,8 @@ pub struct Source {
 
     /// Information about the source data location.
     pub source_info: Arc<SourceInfo>,
+    /// Statistics collected by `ANALYZE` on the table this source reads, if any.
+    pub analyzed_stats: AnalyzedStats,
     pub stats_state: StatsState,
 }
 
@@ -31,10 +33,16 @@ impl Source {
             node_id: None,
             output_schema,
             source_info,
+            analyzed_stats: None.into(),
             stats_state: StatsState::NotMaterialized,
         }
     }
 
+    pub fn with_analyzed_stats(mut self, stats: impl Into<AnalyzedStats>) -> Self {
+        self.analyzed_stats = stats.into();
+        self
+    }
+
     pub fn with_plan_id(mut self, plan_id: usize) -> Self {
         self.plan_id = Some(plan_id);
         self
@@ -52,7 +60,7 @@ impl Source {
     pub(crate) fn build_materialized_scan_source(mut self) -> DaftResult<Self> {
         let new_physical_scan_info = match Arc::unwrap_or_clone(self.source_info) {
             SourceInfo::Physical(mut physical_scan_info) => {
-                let scan_tasks = match &physical_scan_info.scan_state {
+                let mut scan_tasks = match &physical_scan_info.scan_state {
                     ScanState::Operator(scan_op) => scan_op
                         .0
                         .to_scan_tasks(physical_scan_info.pushdowns.clone())?,
@@ -60,6 +68,16 @@ impl Source {
                         panic!("Physical scan nodes are being materialized more than once");
                     }
                 };
+                // Scan operators may stop listing files at the file limit, but aren't required to. The limit counts
+                // files rather than tasks, since a task may read several files.
+                if let Some(file_limit) = physical_scan_info.pushdowns.file_limit {
+                    let mut num_files = 0;
+                    scan_tasks.retain(|task| {
+                        let keep = num_files < file_limit;
+                        num_files += task.get_file_paths().len().max(1);
+                        keep
+                    });
+                }
                 physical_scan_info.scan_state = ScanState::Tasks(Arc::new(scan_tasks));
                 physical_scan_info
             }
@@ -98,13 +116,36 @@ impl Source {
                     approx_stats.acc_selectivity = physical_scan_info
                         .pushdowns
                         .estimated_selectivity(self.output_schema.as_ref());
+                    // The scan tasks estimate the effect of pushed down filters with fixed selectivities, so rescale
+                    // their estimates if the table was analyzed.
+                    if let (Some(analyzed_stats), Some(filters)) = (
+                        self.analyzed_stats.as_ref(),
+                        &physical_scan_info.pushdowns.filters,
+                    ) {
+                        let selectivity = analyzed_stats
+                            .estimate_selectivity(filters, self.output_schema.as_ref());
+                        let scale = selectivity / approx_stats.acc_selectivity.max(0.01);
+                        approx_stats = ApproxStats {
+                            num_rows: (approx_stats.num_rows as f64 * scale).ceil() as usize,
+                            size_bytes: (approx_stats.size_bytes as f64 * scale).ceil() as usize,
+                            acc_selectivity: selectivity,
+                        };
+                    }
                     approx_stats
                 }
             },
             SourceInfo::GlobScan(_) => ApproxStats::empty(),
             SourceInfo::PlaceHolder(_) => ApproxStats::empty(),
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        let column_stats = self
+            .analyzed_stats
+            .as_ref()
+            .map(|stats| Arc::new(stats.project(&self.output_schema)));
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(column_stats)
+                .into(),
+        );
         self
     }
 
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use common_error::DaftError;
 use daft_core::prelude::*;
-use daft_dsl::{ExprRef, exprs_to_schema};
+use daft_dsl::{ExprRef, deduplicate_expr_names, exprs_to_schema};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use snafu::ResultExt;
@@ -57,7 +57,9 @@ impl TopN {
             .context(CreationSnafu);
         }
 
-        // TODO(Kevin): make sort by expression names unique so that we can do things like sort(col("a"), col("a") + col("b"))
+        // Sort keys are evaluated into columns by their names when sampling the boundaries of a distributed sort, so
+        // composite keys like sort(col("a"), col("a") + col("b")) are given unique names.
+        let sort_by = deduplicate_expr_names(&sort_by);
         let sort_by_schema = exprs_to_schema(&sort_by, input.schema())?;
 
         for (field, expr) in sort_by_schema.into_iter().zip(sort_by.iter()) {
@@ -118,7 +120,11 @@ impl TopN {
             },
             acc_selectivity: input_stats.approx_stats.acc_selectivity * limit_selectivity,
         };
-        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self.stats_state = StatsState::Materialized(
+            PlanStats::new(approx_stats)
+                .with_column_stats(input_stats.column_stats.clone())
+                .into(),
+        );
         self
     }
 
@@ -4,5 +4,7 @@ mod optimizer;
 mod rules;
 #[cfg(test)]
 mod test;
+mod trace;
 
 pub use optimizer::{Optimizer, OptimizerBuilder, OptimizerConfig};
+pub use trace::{OptimizerStep, OptimizerTrace};
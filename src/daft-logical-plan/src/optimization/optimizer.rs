@@ -11,11 +11,12 @@ use super::{
         EliminateSubqueryAliasRule, EnrichWithStats, ExtractWindowFunction, FilterNullJoinKey,
         LiftProjectFromAgg, MaterializeScans, OptimizerRule, PushDownAggregation,
         PushDownAntiSemiJoin, PushDownFilter, PushDownJoinPredicate, PushDownLimit,
-        PushDownProjection, PushDownShard, ReorderJoins, RewriteCountDistinct, RewriteOffset,
-        ShardScans, SimplifyExpressionsRule, SimplifyNullFilteredJoin, SplitExplodeFromProject,
-        SplitGranularProjection, SplitUDFs, SplitUDFsFromFilters, UnnestPredicateSubquery,
-        UnnestScalarSubquery,
+        PushDownProjection, PushDownShard, RenewFunctionState, ReorderJoins, RewriteCountDistinct,
+        RewriteOffset, ShardScans, SimplifyExpressionsRule, SimplifyNullFilteredJoin,
+        SplitExplodeFromProject, SplitGranularProjection, SplitUDFs, SplitUDFsFromFilters,
+        UnnestPredicateSubquery, UnnestScalarSubquery,
     },
+    trace::OptimizerTrace,
 };
 use crate::{LogicalPlan, optimization::rules::SplitVLLM};
 
@@ -230,6 +231,11 @@ impl OptimizerBuilder {
                 vec![Box::new(ShardScans::new())],
                 RuleExecutionStrategy::Once,
             ),
+            // --- Renew the state of stateful functions ---
+            RuleBatch::new(
+                vec![Box::new(RenewFunctionState::new())],
+                RuleExecutionStrategy::Once,
+            ),
         ]);
         self
     }
@@ -322,6 +328,32 @@ impl Optimizer {
         })
     }
 
+    /// Optimize the provided plan, recording every plan mutation along with the rule that made it.
+    pub fn optimize_with_trace(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<(Arc<LogicalPlan>, OptimizerTrace)> {
+        let mut trace = OptimizerTrace::new(plan.as_ref());
+        let mut plan_tracker = LogicalPlanTracker::new(self.config.default_max_optimizer_passes);
+        plan_tracker.add_plan(plan.as_ref());
+        let plan =
+            self.rule_batches
+                .iter()
+                .enumerate()
+                .try_fold(plan, |plan, (batch_idx, batch)| {
+                    self.optimize_with_rule_batch_observed(
+                        batch,
+                        plan,
+                        &mut |_, _, _, _, _| {},
+                        &mut |rule, pass, new_plan| {
+                            trace.record(batch_idx, pass, rule.name(), new_plan);
+                        },
+                        &mut plan_tracker,
+                    )
+                })?;
+        Ok((plan, trace))
+    }
+
     // Optimize the provided plan with the provided rule batch.
     pub fn optimize_with_rule_batch<F>(
         &self,
@@ -332,11 +364,38 @@ impl Optimizer {
     ) -> DaftResult<Arc<LogicalPlan>>
     where
         F: FnMut(&LogicalPlan, &RuleBatch, usize, bool, bool),
+    {
+        self.optimize_with_rule_batch_observed(
+            batch,
+            plan,
+            observer,
+            &mut |_, _, _| {},
+            plan_tracker,
+        )
+    }
+
+    // Optimize the provided plan with the provided rule batch, calling `rule_observer` with each rule that transforms
+    // the plan, the pass it was applied in, and the plan it produced.
+    fn optimize_with_rule_batch_observed<F, R>(
+        &self,
+        batch: &RuleBatch,
+        plan: Arc<LogicalPlan>,
+        observer: &mut F,
+        rule_observer: &mut R,
+        plan_tracker: &mut LogicalPlanTracker,
+    ) -> DaftResult<Arc<LogicalPlan>>
+    where
+        F: FnMut(&LogicalPlan, &RuleBatch, usize, bool, bool),
+        R: FnMut(&dyn OptimizerRuleInBatch, usize, &LogicalPlan),
     {
         let result = (0..batch.max_passes(&self.config)).try_fold(
             plan,
             |plan, pass| -> ControlFlow<DaftResult<Arc<LogicalPlan>>, Arc<LogicalPlan>> {
-                match self.optimize_with_rules(batch.rules.as_slice(), plan) {
+                match self.optimize_with_rules_observed(
+                    batch.rules.as_slice(),
+                    plan,
+                    &mut |rule, new_plan| rule_observer(rule, pass, new_plan),
+                ) {
                     Ok(Transformed {
                         data: new_plan,
                         transformed: true,
@@ -382,9 +441,32 @@ impl Optimizer {
         rules: &[Box<dyn OptimizerRuleInBatch>],
         plan: Arc<LogicalPlan>,
     ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        self.optimize_with_rules_observed(rules, plan, &mut |_, _| {})
+    }
+
+    /// Optimize the provided plan with each of the provided rules once, calling `rule_observer`
+    /// with each rule that transforms the plan and the plan it produced.
+    fn optimize_with_rules_observed<R>(
+        &self,
+        rules: &[Box<dyn OptimizerRuleInBatch>],
+        plan: Arc<LogicalPlan>,
+        rule_observer: &mut R,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>>
+    where
+        R: FnMut(&dyn OptimizerRuleInBatch, &LogicalPlan),
+    {
         // Fold over the rules, applying each rule to this plan node sequentially.
         rules.iter().try_fold(Transformed::no(plan), |plan, rule| {
-            plan.transform_data(|data| rule.try_optimize(data))
+            let mut transformed_by_rule = false;
+            let plan = plan.transform_data(|data| {
+                let result = rule.try_optimize(data)?;
+                transformed_by_rule = result.transformed;
+                Ok(result)
+            })?;
+            if transformed_by_rule {
+                rule_observer(rule.as_ref(), plan.data.as_ref());
+            }
+            Ok(plan)
         })
     }
 }
@@ -402,15 +484,16 @@ mod tests {
         functions::{FunctionExpr, python::LegacyPythonUDF},
         lit, resolved_col, unresolved_col,
     };
+    use daft_stats::{AnalyzedColumnStatistics, AnalyzedTableStatistics, EquiDepthHistogram};
 
     use super::{Optimizer, OptimizerBuilder, OptimizerConfig, RuleBatch, RuleExecutionStrategy};
     use crate::{
-        LogicalPlan,
+        LogicalPlan, LogicalPlanBuilder,
         ops::{Filter, Project, UDFProject},
         optimization::rules::{EnrichWithStats, MaterializeScans, OptimizerRule},
         test::{
             dummy_scan_node, dummy_scan_node_with_pushdowns, dummy_scan_operator,
-            dummy_scan_operator_for_aggregation,
+            dummy_scan_operator_for_aggregation, dummy_scan_operator_with_size,
         },
     };
 
@@ -599,6 +682,61 @@ mod tests {
         Ok(())
     }
 
+    /// Tests that tracing the optimizer attributes every plan mutation to the rule that made it.
+    #[test]
+    fn trace_attributes_rules() -> DaftResult<()> {
+        let optimizer = Optimizer::with_rule_batches(
+            vec![
+                RuleBatch::new(
+                    vec![
+                        Box::new(NoOp::new()),
+                        Box::new(RotateProjection::new(false)),
+                    ],
+                    RuleExecutionStrategy::FixedPoint(Some(20)),
+                ),
+                RuleBatch::new(
+                    vec![Box::new(FilterAndTrue::new())],
+                    RuleExecutionStrategy::Once,
+                ),
+            ],
+            OptimizerConfig::new(20, false),
+        );
+        let proj_exprs = vec![
+            unresolved_col("a").add(lit(1)),
+            unresolved_col("a").add(lit(2)).alias("b"),
+            unresolved_col("a").add(lit(3)).alias("c"),
+        ];
+        let plan = dummy_scan_node(dummy_scan_operator(vec![Field::new("a", DataType::Int64)]))
+            .select(proj_exprs)?
+            .filter(unresolved_col("a").lt(lit(2)))?
+            .build();
+        let expected_plan = optimizer.optimize(plan.clone(), |_, _, _, _, _| {})?;
+        let (opt_plan, trace) = optimizer.optimize_with_trace(plan)?;
+        assert_eq!(opt_plan, expected_plan);
+
+        let steps = trace
+            .steps
+            .iter()
+            .map(|step| (step.batch, step.pass, step.rule))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            vec![
+                (0, 0, "RotateProjection"),
+                (0, 1, "RotateProjection"),
+                (0, 2, "RotateProjection"),
+                (1, 0, "FilterAndTrue"),
+            ]
+        );
+        assert_eq!(trace.steps[3].plan, opt_plan.repr_ascii(false));
+        assert!(
+            trace
+                .repr_diff()
+                .contains("== Step 4: FilterAndTrue (batch 1, pass 0) ==")
+        );
+        Ok(())
+    }
+
     #[derive(Debug)]
     struct FilterOrFalse {}
 
@@ -704,6 +842,50 @@ mod tests {
         )
     }
 
+    fn analyzed_stats_of_a(histogram: Vec<f64>) -> Arc<AnalyzedTableStatistics> {
+        Arc::new(AnalyzedTableStatistics::new(
+            1000,
+            [(
+                "a".to_string(),
+                AnalyzedColumnStatistics {
+                    ndv: 1000,
+                    null_fraction: 0.0,
+                    min: histogram.first().copied(),
+                    max: histogram.last().copied(),
+                    histogram: Some(EquiDepthHistogram { bounds: histogram }),
+                },
+            )]
+            .into_iter()
+            .collect(),
+        ))
+    }
+
+    /// Tests that the stats collected by `ANALYZE` are used to estimate the cardinality of filters, and that sources
+    /// which read different stats aren't equal.
+    #[test]
+    fn filter_cardinality_uses_analyzed_stats() -> DaftResult<()> {
+        let scan_op =
+            dummy_scan_operator_with_size(vec![Field::new("a", DataType::Int64)], Some(1000));
+        let optimizer = get_scan_materializer_and_stats_enricher();
+        let estimate = |scan: LogicalPlanBuilder| -> DaftResult<usize> {
+            let plan = scan.filter(resolved_col("a").lt(lit(10)))?.build();
+            let plan = optimizer.optimize(plan, |_, _, _, _, _| {})?;
+            Ok(plan.materialized_stats().approx_stats.num_rows)
+        };
+
+        // Without stats, comparisons are estimated to keep a fixed fraction of the rows.
+        assert_eq!(estimate(dummy_scan_node(scan_op.clone()))?, 300);
+        // Half of the values are below 10 according to the histogram.
+        let stats = analyzed_stats_of_a(vec![0.0, 10.0, 1000.0]);
+        let scan = dummy_scan_node(scan_op.clone()).with_analyzed_stats(stats)?;
+        assert_eq!(estimate(scan.clone())?, 500);
+
+        let reanalyzed_scan = dummy_scan_node(scan_op)
+            .with_analyzed_stats(analyzed_stats_of_a(vec![0.0, 100.0, 1000.0]))?;
+        assert_ne!(scan.build(), reanalyzed_scan.build());
+        Ok(())
+    }
+
     /// Tests that Limit commutes with ActorPoolProject.
     ///
     /// Limit-ActorPoolProject-Source -> ActorPoolProject-Source[with_limit]
@@ -17,6 +17,7 @@ mod push_down_join_predicate;
 mod push_down_limit;
 mod push_down_projection;
 mod push_down_shard;
+mod renew_function_state;
 mod reorder_joins;
 mod rewrite_count_distinct;
 mod rewrite_offset;
@@ -47,6 +48,7 @@ pub use push_down_join_predicate::PushDownJoinPredicate;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
 pub use push_down_shard::PushDownShard;
+pub use renew_function_state::RenewFunctionState;
 pub use reorder_joins::ReorderJoins;
 pub use rewrite_count_distinct::RewriteCountDistinct;
 pub use rewrite_offset::RewriteOffset;
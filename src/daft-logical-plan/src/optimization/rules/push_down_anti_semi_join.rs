@@ -113,6 +113,7 @@ impl OptimizerRule for PushDownAntiSemiJoin {
                 on,
                 join_type,
                 join_strategy,
+                broadcast_side,
                 ..
             }) = node.as_ref()
                 && matches!(join_type, JoinType::Anti | JoinType::Semi)
@@ -201,6 +202,7 @@ impl OptimizerRule for PushDownAntiSemiJoin {
                                 *join_type,
                                 *join_strategy,
                             )?
+                            .with_broadcast_side(*broadcast_side)
                             .into();
 
                             return Ok(Transformed::yes(Arc::new(
@@ -248,6 +250,7 @@ impl OptimizerRule for PushDownAntiSemiJoin {
                                     *join_type,
                                     *join_strategy,
                                 )?
+                                .with_broadcast_side(*broadcast_side)
                                 .into();
 
                                 return Ok(Transformed::yes(Arc::new(
@@ -263,6 +266,7 @@ impl OptimizerRule for PushDownAntiSemiJoin {
                                     *join_type,
                                     *join_strategy,
                                 )?
+                                .with_broadcast_side(*broadcast_side)
                                 .into();
 
                                 return Ok(Transformed::yes(Arc::new(
@@ -292,6 +296,7 @@ impl OptimizerRule for PushDownAntiSemiJoin {
                             *join_type,
                             *join_strategy,
                         )?
+                        .with_broadcast_side(*broadcast_side)
                         .into();
 
                         return Ok(Transformed::yes(Arc::new(
@@ -307,6 +312,7 @@ impl OptimizerRule for PushDownAntiSemiJoin {
                     | LogicalPlan::UDFProject(..)
                     | LogicalPlan::Unpivot(..)
                     | LogicalPlan::Pivot(..)
+                    | LogicalPlan::Resample(..)
                     | LogicalPlan::Aggregate(..)
                     | LogicalPlan::Intersect(..)
                     | LogicalPlan::Union(..)
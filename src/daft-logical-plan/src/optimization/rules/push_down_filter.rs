@@ -219,6 +219,7 @@ impl PushDownFilter {
                             source.output_schema.clone(),
                             SourceInfo::Physical(new_external_info).into(),
                         )
+                        .with_analyzed_stats(source.analyzed_stats.clone())
                         .into();
                         if !needing_filter_op.is_empty() {
                             // We need to apply any filter predicates that reference both partition and data columns after the scan.
@@ -325,6 +326,7 @@ impl PushDownFilter {
                 join_type,
                 on,
                 join_strategy,
+                broadcast_side,
                 ..
             }) => {
                 // TODO(Kevin): add more filter pushdowns for joins
@@ -396,13 +398,10 @@ impl PushDownFilter {
                         },
                     );
 
-                    let new_join = Arc::new(LogicalPlan::Join(Join::try_new(
-                        new_left,
-                        new_right,
-                        on.clone(),
-                        *join_type,
-                        *join_strategy,
-                    )?));
+                    let new_join = Arc::new(LogicalPlan::Join(
+                        Join::try_new(new_left, new_right, on.clone(), *join_type, *join_strategy)?
+                            .with_broadcast_side(*broadcast_side),
+                    ));
 
                     if let Some(kept_predicates) = kept_predicates {
                         Filter::try_new(new_join, kept_predicates).unwrap().into()
@@ -422,6 +421,7 @@ impl PushDownFilter {
             | LogicalPlan::UDFProject(..)
             | LogicalPlan::Unpivot(..)
             | LogicalPlan::Pivot(..)
+            | LogicalPlan::Resample(..)
             | LogicalPlan::Aggregate(..)
             | LogicalPlan::Intersect(..)
             | LogicalPlan::Union(..)
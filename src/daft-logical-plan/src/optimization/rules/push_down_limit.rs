@@ -210,6 +210,7 @@ impl PushDownLimit {
                     | LogicalPlan::UDFProject(..)
                     | LogicalPlan::Unpivot(..)
                     | LogicalPlan::Pivot(..)
+                    | LogicalPlan::Resample(..)
                     | LogicalPlan::Aggregate(..)
                     | LogicalPlan::Intersect(..)
                     | LogicalPlan::Union(..)
@@ -233,13 +234,14 @@ mod tests {
     use common_error::DaftResult;
     use common_scan_info::Pushdowns;
     use daft_core::prelude::*;
-    use daft_dsl::unresolved_col;
+    use daft_dsl::{Operator, binary_op, unresolved_col};
     #[cfg(feature = "python")]
     use pyo3::Python;
     use rstest::rstest;
 
     use crate::{
         LogicalPlan, LogicalPlanBuilder,
+        ops::TopN,
         optimization::{
             optimizer::{RuleBatch, RuleExecutionStrategy},
             rules::PushDownLimit,
@@ -473,4 +475,55 @@ mod tests {
         assert_optimized_plan_eq(plan, expected)?;
         Ok(())
     }
+
+    /// Tests that Limit fuses with a Sort by composite and computed keys into a TopN, through a Projection which
+    /// drops the keys.
+    ///
+    /// Limit-Project-Sort-Source -> Project-TopN-Source
+    #[test]
+    fn limit_fuses_with_sort_through_projection() -> DaftResult<()> {
+        let limit = 5;
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]);
+        let sort_by = vec![
+            unresolved_col("a"),
+            binary_op(Operator::Plus, unresolved_col("a"), unresolved_col("b")),
+        ];
+        let descending = vec![false, true];
+        let nulls_first = vec![false, true];
+        let proj = vec![unresolved_col("c")];
+
+        let sorted = dummy_scan_node(scan_op)
+            .sort(sort_by, descending.clone(), nulls_first.clone())?
+            .build();
+        let LogicalPlan::Sort(sort) = sorted.as_ref() else {
+            panic!("Expected a Sort, got {sorted:?}");
+        };
+        // The computed key is named after its first column, so it's renamed to not clash with the first key.
+        assert_eq!(
+            sort.sort_by.iter().map(|e| e.name()).collect::<Vec<_>>(),
+            vec!["a", "a_1"]
+        );
+
+        let plan = LogicalPlanBuilder::new(sorted.clone(), None)
+            .select(proj.clone())?
+            .limit(limit, false)?
+            .build();
+        let top_n = LogicalPlan::TopN(TopN::try_new(
+            sort.input.clone(),
+            sort.sort_by.clone(),
+            descending,
+            nulls_first,
+            limit,
+            None,
+        )?);
+        let expected = LogicalPlanBuilder::new(top_n.into(), None)
+            .select(proj)?
+            .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
 }
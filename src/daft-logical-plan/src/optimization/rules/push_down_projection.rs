@@ -12,7 +12,7 @@ use indexmap::IndexSet;
 use super::OptimizerRule;
 use crate::{
     LogicalPlan, LogicalPlanRef,
-    ops::{Aggregate, Join, Pivot, Project, Source, UDFProject},
+    ops::{Aggregate, Explode, Join, Pivot, Project, Source, UDFProject},
     source_info::SourceInfo,
 };
 
@@ -157,6 +157,7 @@ impl PushDownProjection {
                                     ))),
                                 ))),
                             )
+                            .with_analyzed_stats(source.analyzed_stats.clone())
                             .into();
                             let new_plan = Arc::new(plan.with_new_children(&[new_source.into()]));
                             // Retry optimization now that the upstream node is different.
@@ -278,7 +279,7 @@ impl PushDownProjection {
             | LogicalPlan::Sample(..)
             | LogicalPlan::Explode(..) => {
                 // Get required columns from projection and upstream.
-                let combined_dependencies = plan
+                let mut combined_dependencies = plan
                     .required_columns()
                     .single()
                     .iter()
@@ -286,6 +287,15 @@ impl PushDownProjection {
                     .cloned()
                     .collect::<IndexSet<_>>();
 
+                // An explode's index column is produced by the explode itself.
+                if let LogicalPlan::Explode(Explode {
+                    index_column: Some(index_column),
+                    ..
+                }) = upstream_plan.as_ref()
+                {
+                    combined_dependencies.shift_remove(index_column);
+                }
+
                 // Skip optimization if no columns would be pruned.
                 let grand_upstream_plan = &upstream_plan.arc_children()[0];
                 let grand_upstream_columns = grand_upstream_plan.schema().names();
@@ -485,8 +495,10 @@ impl PushDownProjection {
                 // since Intersect implicitly requires all parent columns.
                 Ok(Transformed::no(plan))
             }
-            LogicalPlan::Pivot(_) | LogicalPlan::MonotonicallyIncreasingId(_) => {
-                // Cannot push down past a Pivot/MonotonicallyIncreasingId because it changes the schema.
+            LogicalPlan::Pivot(_)
+            | LogicalPlan::Resample(_)
+            | LogicalPlan::MonotonicallyIncreasingId(_) => {
+                // Cannot push down past a Pivot/Resample/MonotonicallyIncreasingId because it changes the schema.
                 Ok(Transformed::no(plan))
             }
             LogicalPlan::Window(_) => {
@@ -946,6 +958,7 @@ mod tests {
             scan_op.clone(),
             Pushdowns {
                 limit: None,
+                file_limit: None,
                 partition_filters: None,
                 columns: Some(Arc::new(vec![
                     "year".to_string(),
@@ -52,6 +52,7 @@ impl PushDownShard {
                     | LogicalPlan::Distinct(_)
                     | LogicalPlan::Aggregate(_)
                     | LogicalPlan::Pivot(_)
+                    | LogicalPlan::Resample(_)
                     | LogicalPlan::Sink(_)
                     | LogicalPlan::Sample(_)
                     | LogicalPlan::MonotonicallyIncreasingId(_)
@@ -91,10 +92,13 @@ impl PushDownShard {
                                 let new_pushdowns =
                                     external_info.pushdowns.with_sharder(Some(sharder.clone()));
                                 let new_external_info = external_info.with_pushdowns(new_pushdowns);
-                                let new_source = LogicalPlan::Source(Source::new(
-                                    source.output_schema.clone(),
-                                    SourceInfo::Physical(new_external_info).into(),
-                                ))
+                                let new_source = LogicalPlan::Source(
+                                    Source::new(
+                                        source.output_schema.clone(),
+                                        SourceInfo::Physical(new_external_info).into(),
+                                    )
+                                    .with_analyzed_stats(source.analyzed_stats.clone()),
+                                )
                                 .into();
                                 Ok(Transformed::yes(new_source))
                             }
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::{
+    Expr, ExprRef,
+    functions::{BuiltinScalarFn, BuiltinScalarFnVariant, scalar::ScalarFn},
+};
+
+use super::OptimizerRule;
+use crate::LogicalPlan;
+
+/// Optimization rule which gives a query its own copy of the functions which keep state across the batches that
+/// they're called on, such as the number of values that `try_cast` couldn't cast, so that running a plan again doesn't
+/// start from the state of its earlier runs.
+#[derive(Default, Debug)]
+pub struct RenewFunctionState {}
+
+impl RenewFunctionState {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+fn renew_function_state(expr: ExprRef) -> DaftResult<Transformed<ExprRef>> {
+    expr.transform(|e| match e.as_ref() {
+        Expr::ScalarFn(ScalarFn::Builtin(BuiltinScalarFn {
+            func: BuiltinScalarFnVariant::Sync(udf),
+            inputs,
+        })) => Ok(match udf.with_new_state() {
+            Some(udf) => Transformed::yes(
+                ScalarFn::Builtin(BuiltinScalarFn {
+                    func: BuiltinScalarFnVariant::Sync(udf),
+                    inputs: inputs.clone(),
+                })
+                .into(),
+            ),
+            None => Transformed::no(e),
+        }),
+        _ => Ok(Transformed::no(e)),
+    })
+}
+
+impl OptimizerRule for RenewFunctionState {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform(|plan| plan.map_expressions(|expr, _| renew_function_state(expr)))
+    }
+}
@@ -16,6 +16,7 @@ use daft_dsl::{
 use crate::{
     LogicalPlan, LogicalPlanBuilder, LogicalPlanRef,
     ops::{Filter, Join, Project, join::JoinPredicate},
+    stats::PlanStats,
 };
 
 /// A JoinOrderTree is a tree that describes a join order between relations, which can range from left deep trees
@@ -147,8 +148,9 @@ pub(super) struct JoinAdjList {
     pub equivalence_set_map: HashMap<(usize, String), usize>,
     // Vec of total domains for equivalence sets. Where total_domains[equivalence set id] -> total domain of the equivalence set.
     // The total domain is the number of distinct values in the columns that are part of the equivalence set. For pk-fk joins,
-    // this would be the number of primary keys. In the absence of ndv statistics, we take the smallest table in the equivalence set,
-    // assume it's the primary key table, and use its cardinality as the total domain.
+    // this would be the number of primary keys. For analyzed tables we use the ndv statistics of the join columns. In the absence
+    // of ndv statistics, we take the smallest table in the equivalence set, assume it's the primary key table, and use its
+    // cardinality as the total domain.
     total_domains: Vec<usize>,
 }
 
@@ -257,13 +259,22 @@ impl JoinAdjList {
     }
 
     // Helper function that estimates the total domain for a join between two relations.
-    fn get_estimated_total_domain(
-        &self,
-        left_plan: &LogicalPlanRef,
-        right_plan: &LogicalPlanRef,
-    ) -> usize {
-        let left_stats = left_plan.materialized_stats();
-        let right_stats = right_plan.materialized_stats();
+    fn get_estimated_total_domain(&self, left: &JoinNode, right: &JoinNode) -> usize {
+        let left_stats = left.plan.materialized_stats();
+        let right_stats = right.plan.materialized_stats();
+        let column_ndv = |node: &JoinNode, stats: &PlanStats| {
+            stats
+                .column_stats
+                .as_ref()?
+                .column(&node.relation_name)
+                .map(|column| column.ndv)
+        };
+        if let (Some(left_ndv), Some(right_ndv)) =
+            (column_ndv(left, left_stats), column_ndv(right, right_stats))
+        {
+            // Assume that the values of the column with fewer distinct values are contained in the other column.
+            return left_ndv.max(right_ndv).max(1) as usize;
+        }
         // We multiple the number of rows by the reciprocal of the selectivity to get the original total domain.
         let left_rows = left_stats.approx_stats.num_rows as f64
             / left_stats.approx_stats.acc_selectivity.max(0.01);
@@ -276,7 +287,7 @@ impl JoinAdjList {
         let node1_id = self.get_or_create_plan_id(&node1.plan);
         let node2_id = self.get_or_create_plan_id(&node2.plan);
         // Find the minimal total domain for the join columns, either from the current nodes or from the existing total domains.
-        let mut td = self.get_estimated_total_domain(&node1.plan, &node2.plan);
+        let mut td = self.get_estimated_total_domain(&node1, &node2);
         if let Some(equivalence_set_id) = self
             .equivalence_set_map
             .get(&(node1_id, node1.relation_name.clone()))
@@ -794,16 +805,19 @@ impl JoinGraphBuilder {
                 LogicalPlan::Filter(Filter { input, .. }) => plan = input,
                 // Since we hit a join, we need to process the linear chain of Projects and Filters that were encountered starting
                 // from the plan at the root of the linear chain to the current plan.
-                // We only process joins with predicates that are all columns.
+                // We only process joins with predicates that are all columns, and without a hinted strategy, which
+                // reordering would lose.
                 // TODO: Figure out how to handle joins with non-column predicates, such as aliases.
                 LogicalPlan::Join(Join {
                     on,
                     join_type: JoinType::Inner,
+                    join_strategy,
                     ..
                 }) => {
                     let (remaining_on, left_on, right_on, _) = on.split_eq_preds();
 
-                    if left_on.is_empty()
+                    if join_strategy.is_some()
+                        || left_on.is_empty()
                         || !remaining_on.is_empty()
                         || !left_on
                             .iter()
@@ -10,7 +10,7 @@ use crate::{
 };
 
 /// This rule is mainly used to rewrite the `Offset` node into a `Limit` node.
-/// The `Offset` node won't be passed to the physical plan layer.
+/// Only an `Offset` node without a `Limit` node below it is passed to the physical plan layer.
 #[derive(Default, Debug)]
 pub struct RewriteOffset {}
 
@@ -57,11 +57,8 @@ impl RewriteOffset {
                         )));
                         Ok(Transformed::yes(new_plan))
                     }
-                    // Currently, offset without limit is not supported
-                    _ => Err(DaftError::not_implemented(
-                        // TODO(zhenchao) maybe we should allow users to use offset without limit
-                        "Offset without limit is unsupported now!",
-                    )),
+                    // Offset without limit is executed as is
+                    _ => Ok(Transformed::no(plan)),
                 }
             }
             // Some checks on LIMIT nodes
@@ -243,10 +240,7 @@ mod tests {
             Field::new("a", DataType::Int64),
             Field::new("b", DataType::Utf8),
         ]);
-        let plan = dummy_scan_node(scan_op.clone()).offset(offset)?.build();
-        assert_optimized_plan_err(
-            plan,
-            DaftError::not_implemented("Offset without limit is unsupported now!"),
-        )
+        let plan = dummy_scan_node(scan_op).offset(offset)?.build();
+        assert_optimized_plan_eq(plan.clone(), plan)
     }
 }
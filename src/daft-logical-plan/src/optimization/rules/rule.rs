@@ -11,4 +11,10 @@ pub trait OptimizerRule {
     ///
     /// This returns Transformed::yes(new_plan) if the rule modified the plan, Transformed::no(old_plan) otherwise.
     fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>>;
+
+    /// Name of this rule, used to attribute plan mutations to it when tracing the optimizer.
+    fn name(&self) -> &'static str {
+        let type_name = std::any::type_name::<Self>();
+        type_name.rsplit("::").next().unwrap_or(type_name)
+    }
 }
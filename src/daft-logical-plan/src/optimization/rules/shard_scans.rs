@@ -38,7 +38,8 @@ impl ShardScans {
                             let new_source = Source::new(
                                 source.output_schema.clone(),
                                 SourceInfo::Physical(new_physical_scan_info).into(),
-                            );
+                            )
+                            .with_analyzed_stats(source.analyzed_stats.clone());
                             Ok(Transformed::yes(LogicalPlan::Source(new_source).into()))
                         }
                         None => Ok(Transformed::no(plan)),
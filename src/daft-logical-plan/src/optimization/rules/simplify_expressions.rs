@@ -70,6 +70,7 @@ mod test {
                     source_schema: schema,
                     clustering_spec: Arc::new(ClusteringSpec::unknown()),
                 })),
+                analyzed_stats: None.into(),
                 stats_state: StatsState::NotMaterialized,
             })
             .arced(),
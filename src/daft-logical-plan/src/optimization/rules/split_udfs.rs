@@ -1,4 +1,8 @@
-use std::{any::TypeId, collections::HashSet, sync::Arc};
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode, TreeNodeRecursion, TreeNodeRewriter};
@@ -215,20 +219,28 @@ impl TruncateRootUDF {
 
 // TreeNodeRewriter that assumes the Expression tree has some children which are UDFs
 // which needs to be truncated and replaced with Expr::Columns
-struct TruncateAnyUDFChildren {
+struct TruncateAnyUDFChildren<'a> {
     pub(crate) new_children: Vec<ExprRef>,
     stage_idx: usize,
     expr_idx: usize,
     is_list_map: bool,
+    // Names of the UDFs already truncated in this projection, so that a UDF which appears in several expressions
+    // (such as a UDF returning a struct which is unnested into its fields) is only evaluated once
+    truncated_udfs: &'a mut HashMap<ExprRef, String>,
 }
 
-impl TruncateAnyUDFChildren {
-    fn new(stage_idx: usize, expr_idx: usize) -> Self {
+impl<'a> TruncateAnyUDFChildren<'a> {
+    fn new(
+        stage_idx: usize,
+        expr_idx: usize,
+        truncated_udfs: &'a mut HashMap<ExprRef, String>,
+    ) -> Self {
         Self {
             new_children: Vec::new(),
             stage_idx,
             expr_idx,
             is_list_map: false,
+            truncated_udfs,
         }
     }
 }
@@ -297,10 +309,11 @@ impl TreeNodeRewriter for TruncateRootUDF {
 ///
 /// This TreeNodeRewriter will truncate UDF expressions from the tree like so:
 ///
-/// 1. Add an `alias(...)` to any UDF child and push it onto `self.new_children`
+/// 1. Add an `alias(...)` to any UDF child and push it onto `self.new_children`, unless the same UDF was already
+///    truncated from another expression of the projection
 /// 2. Replace the child with a `col("...")`
 /// 3. Add any `col("...")` leaf nodes to `self.new_children` (only once per unique column name)
-impl TreeNodeRewriter for TruncateAnyUDFChildren {
+impl TreeNodeRewriter for TruncateAnyUDFChildren<'_> {
     type Node = ExprRef;
 
     fn f_down(&mut self, node: Self::Node) -> DaftResult<common_treenode::Transformed<Self::Node>> {
@@ -340,7 +353,9 @@ impl TreeNodeRewriter for TruncateAnyUDFChildren {
                 let mut monotonically_increasing_expr_identifier = 0;
                 let inputs = expr.children();
                 let new_inputs = inputs.iter().map(|e| {
-                    if is_udf(e) {
+                    if let Some(intermediate_expr_name) = self.truncated_udfs.get(e) {
+                        resolved_col(intermediate_expr_name.as_str())
+                    } else if is_udf(e) {
                         let intermediate_expr_name = format!(
                             "__TruncateAnyUDFChildren_{}-{}-{}__",
                             self.stage_idx, self.expr_idx, monotonically_increasing_expr_identifier
@@ -349,6 +364,8 @@ impl TreeNodeRewriter for TruncateAnyUDFChildren {
 
                         self.new_children
                             .push(e.clone().alias(intermediate_expr_name.as_str()));
+                        self.truncated_udfs
+                            .insert(e.clone(), intermediate_expr_name.clone());
 
                         resolved_col(intermediate_expr_name)
                     } else {
@@ -391,6 +408,7 @@ fn split_projection(
     let mut truncated_exprs = Vec::new();
     let (mut new_children_seen, mut new_children): (HashSet<String>, Vec<ExprRef>) =
         (HashSet::new(), Vec::new());
+    let mut truncated_udfs = HashMap::new();
 
     fn is_udf_and_should_truncate_children(expr: &ExprRef) -> bool {
         let mut cond = true;
@@ -421,7 +439,8 @@ fn split_projection(
 
         // Run the TruncateAnyUDFChildren TreeNodeRewriter
         } else if expr.exists(is_udf) {
-            let mut rewriter = TruncateAnyUDFChildren::new(stage_idx, expr_idx);
+            let mut rewriter =
+                TruncateAnyUDFChildren::new(stage_idx, expr_idx, &mut truncated_udfs);
             let rewritten_root = expr.clone().rewrite(&mut rewriter)?.data;
             truncated_exprs.push(rewritten_root);
             for new_child in rewriter.new_children {
@@ -606,7 +625,7 @@ mod tests {
     use common_resource_request::ResourceRequest;
     use daft_core::prelude::*;
     use daft_dsl::{
-        Expr, ExprRef,
+        Expr, ExprRef, Operator, binary_op,
         functions::{
             FunctionExpr,
             python::{LegacyPythonUDF, MaybeInitializedUDF, RuntimePyObject},
@@ -1152,6 +1171,41 @@ Project: col(a), col(c)
         Ok(())
     }
 
+    #[test]
+    fn test_same_udf_in_multiple_exprs_is_evaluated_once() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let scan_plan = dummy_scan_node(scan_op);
+
+        // col("a") + foo(col("a")), col("a") * foo(col("a")), like the fields of a struct returned by a UDF
+        let udf = create_actor_pool_udf(vec![resolved_col("a")]);
+        let project_plan = scan_plan
+            .select(vec![
+                binary_op(Operator::Plus, resolved_col("a"), udf.clone()).alias("x"),
+                binary_op(Operator::Multiply, resolved_col("a"), udf).alias("y"),
+            ])?
+            .build();
+
+        assert_optimized_plan_eq(
+            project_plan,
+            indoc! {"
+        Project: col(x), col(y)
+          Project: col(__TruncateAnyUDFChildren_0-0-0__), col(a), col(a) + col(__TruncateAnyUDFChildren_0-0-0__) as x, col(a) * col(__TruncateAnyUDFChildren_0-0-0__) as y
+            Project: col(__TruncateAnyUDFChildren_0-0-0__), col(a)
+              UDF: foo
+              Expr = py_udf(col(a)) as __TruncateAnyUDFChildren_0-0-0__
+              Passthrough Columns = col(a)
+              Properties = { concurrency = 8, async = false, scalar = false }
+              Resource request = { num_cpus = 8, num_gpus = 1 }
+                Project: col(a)
+                  DummyScanOperator
+                  File schema = a#Int64
+                  Partitioning keys = []
+                  Output schema = a#Int64
+        "},
+        )?;
+        Ok(())
+    }
+
     /// Projection<-UDFProject prunes columns from the UDFProject
     #[test]
     fn test_projection_pushdown_into_udf_project() -> DaftResult<()> {
@@ -537,6 +537,7 @@ fn pull_up_correlated_cols(
         | LogicalPlan::Explode(..)
         | LogicalPlan::Unpivot(..)
         | LogicalPlan::Pivot(..)
+        | LogicalPlan::Resample(..)
         | LogicalPlan::Concat(..)
         | LogicalPlan::Join(..)
         | LogicalPlan::Sink(..)
@@ -0,0 +1,139 @@
+use std::fmt::Write;
+
+use crate::LogicalPlan;
+
+/// A single plan mutation made by the optimizer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimizerStep {
+    /// Index of the rule batch that was being applied.
+    pub batch: usize,
+    /// Pass over the rule batch in which the rule was applied.
+    pub pass: usize,
+    /// Name of the rule that produced this mutation.
+    pub rule: &'static str,
+    /// The full plan after the rule was applied.
+    pub plan: String,
+}
+
+/// A record of every plan mutation made during optimization, attributed to the rule that made it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimizerTrace {
+    pub initial_plan: String,
+    pub steps: Vec<OptimizerStep>,
+}
+
+impl OptimizerTrace {
+    pub fn new(initial_plan: &LogicalPlan) -> Self {
+        Self {
+            initial_plan: initial_plan.repr_ascii(false),
+            steps: Vec::new(),
+        }
+    }
+
+    pub(super) fn record(
+        &mut self,
+        batch: usize,
+        pass: usize,
+        rule: &'static str,
+        plan: &LogicalPlan,
+    ) {
+        self.steps.push(OptimizerStep {
+            batch,
+            pass,
+            rule,
+            plan: plan.repr_ascii(false),
+        });
+    }
+
+    /// Render each step as a line diff against the plan it was applied to.
+    ///
+    /// Rules that report a transformation without changing the rendered plan are still listed,
+    /// since an unnecessary rewrite is often what makes the optimizer fail to reach a fixed-point.
+    pub fn repr_diff(&self) -> String {
+        let mut out = String::new();
+        let mut previous = self.initial_plan.as_str();
+        for (i, step) in self.steps.iter().enumerate() {
+            writeln!(
+                out,
+                "== Step {}: {} (batch {}, pass {}) ==",
+                i + 1,
+                step.rule,
+                step.batch,
+                step.pass
+            )
+            .unwrap();
+            if previous == step.plan {
+                out.push_str("(plan unchanged)\n");
+            } else {
+                for line in diff_lines(previous, &step.plan) {
+                    out.push_str(&line);
+                    out.push('\n');
+                }
+            }
+            out.push('\n');
+            previous = &step.plan;
+        }
+        out
+    }
+}
+
+/// Line-based diff of two texts using the longest common subsequence of their lines.
+/// Every line of both texts is emitted, prefixed with `- `, `+ ` or two spaces.
+fn diff_lines(before: &str, after: &str) -> Vec<String> {
+    let before = before.lines().collect::<Vec<_>>();
+    let after = after.lines().collect::<Vec<_>>();
+
+    // lcs[i][j] is the length of the LCS of before[i..] and after[j..].
+    let mut lcs = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lcs[i][j] = if before[i] == after[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::with_capacity(before.len().max(after.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < before.len() && j < after.len() {
+        if before[i] == after[j] {
+            out.push(format!("  {}", before[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(format!("- {}", before[i]));
+            i += 1;
+        } else {
+            out.push(format!("+ {}", after[j]));
+            j += 1;
+        }
+    }
+    out.extend(before[i..].iter().map(|line| format!("- {line}")));
+    out.extend(after[j..].iter().map(|line| format!("+ {line}")));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_lines;
+
+    #[test]
+    fn test_diff_lines() {
+        let before = "* Project\n|\n* Filter\n|\n* Source";
+        let after = "* Project\n|\n* Source\n|\n* Limit";
+        assert_eq!(
+            diff_lines(before, after),
+            vec![
+                "  * Project",
+                "  |",
+                "- * Filter",
+                "- |",
+                "  * Source",
+                "+ |",
+                "+ * Limit",
+            ]
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! A process-wide cache of optimized logical plans.
+//!
+//! Executing the same query again, such as a dataframe which is collected, shown and then written, optimizes the same
+//! logical plan again with the same configs. Optimization enriches the plan with the statistics of its sources and
+//! reorders its joins, which is slow for large plans, so optimized plans are cached by the fingerprint of the
+//! unoptimized plan and of the configs which they were optimized with.
+//!
+//! Scan operators are identified by the operator object rather than by what they scan, along with the versions of the
+//! data they scan, such as the entity tags of the files which a glob scans. Executing the same dataframe again after
+//! its files have been overwritten therefore gets a new fingerprint, and so does reading a table again, since table
+//! formats create an operator for the snapshot they read. The cache holds the unoptimized plans of its entries, which
+//! keeps their operators alive, so a fingerprint can't be reused by another operator while it's cached. Statistics
+//! collected by `ANALYZE` are part of the plan by their version, so analyzing a table again gets a new fingerprint too.
+//!
+//! Fingerprinting lists the files of each glob again, so the cache is off by default and is enabled by setting
+//! `DAFT_PLAN_CACHE_SIZE`. Plans aren't fingerprinted while it's off.
+
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    sync::{LazyLock, Mutex},
+};
+
+use common_daft_config::{DaftExecutionConfig, DaftPlanningConfig};
+use common_error::DaftResult;
+use common_scan_info::{PhysicalScanInfo, ScanState};
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::{LogicalPlan, LogicalPlanRef, SourceInfo};
+
+/// Environment variable with the number of optimized plans which are cached. 0, the default, disables the cache.
+const PLAN_CACHE_SIZE_VAR: &str = "DAFT_PLAN_CACHE_SIZE";
+const DEFAULT_PLAN_CACHE_SIZE: usize = 0;
+
+/// Identifies an unoptimized logical plan together with the configs which it's optimized with.
+///
+/// Fingerprints are hashed with XXH3, which, unlike the std `DefaultHasher`, hashes the same input to the same
+/// fingerprint across processes and Rust versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlanFingerprint(pub u64);
+
+impl std::fmt::Display for PlanFingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl std::str::FromStr for PlanFingerprint {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s, 16).map(Self)
+    }
+}
+
+impl PlanFingerprint {
+    /// Returns the fingerprint of a plan optimized with the given configs, or None if the configs can't be
+    /// fingerprinted or the versions of the data scanned by the plan can't be determined.
+    pub fn new(
+        plan: &LogicalPlan,
+        planning_config: Option<&DaftPlanningConfig>,
+        execution_config: &DaftExecutionConfig,
+    ) -> Option<Self> {
+        let mut hasher = Xxh3::new();
+        plan.hash(&mut hasher);
+        if let Err(err) = hash_source_versions(plan, &mut hasher) {
+            log::debug!(
+                "Not caching the optimized plan, since its sources can't be versioned: {err}"
+            );
+            return None;
+        }
+        // The configs don't implement `Hash` because they have floats, so their serialized forms are hashed instead.
+        serde_json::to_vec(&planning_config).ok()?.hash(&mut hasher);
+        serde_json::to_vec(execution_config).ok()?.hash(&mut hasher);
+        Some(Self(hasher.finish()))
+    }
+}
+
+/// Hashes the versions of the data scanned by the scan operators of a plan.
+fn hash_source_versions(plan: &LogicalPlan, state: &mut dyn Hasher) -> DaftResult<()> {
+    if let LogicalPlan::Source(source) = plan
+        && let SourceInfo::Physical(PhysicalScanInfo {
+            scan_state: ScanState::Operator(scan_op),
+            ..
+        }) = source.source_info.as_ref()
+    {
+        scan_op.0.hash_source_versions(state)?;
+    }
+    plan.children()
+        .into_iter()
+        .try_for_each(|child| hash_source_versions(child, state))
+}
+
+/// A summary of an optimized plan in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanCacheEntry {
+    pub fingerprint: PlanFingerprint,
+    /// The number of times the optimized plan was reused.
+    pub hits: u64,
+    /// The optimized plan, displayed as a tree.
+    pub plan: String,
+}
+
+struct CachedPlan {
+    unoptimized: LogicalPlanRef,
+    optimized: LogicalPlanRef,
+    hits: u64,
+    last_used: u64,
+}
+
+/// A cache of optimized plans which evicts the least recently used plan once it is full.
+struct PlanCache {
+    capacity: usize,
+    entries: HashMap<PlanFingerprint, CachedPlan>,
+    tick: u64,
+}
+
+impl PlanCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, fingerprint: PlanFingerprint, plan: &LogicalPlan) -> Option<LogicalPlanRef> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(&fingerprint)?;
+        // Guard against plans whose fingerprints collide.
+        if entry.unoptimized.as_ref() != plan {
+            return None;
+        }
+        entry.hits += 1;
+        entry.last_used = self.tick;
+        Some(entry.optimized.clone())
+    }
+
+    fn insert(
+        &mut self,
+        fingerprint: PlanFingerprint,
+        unoptimized: LogicalPlanRef,
+        optimized: LogicalPlanRef,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        // The capacity may have been lowered since the cache was filled, so more than one plan may be evicted.
+        while self.entries.len() >= self.capacity && !self.entries.contains_key(&fingerprint) {
+            let least_recently_used = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(fingerprint, _)| *fingerprint);
+            if let Some(least_recently_used) = least_recently_used {
+                self.entries.remove(&least_recently_used);
+            }
+        }
+        self.tick += 1;
+        self.entries.insert(
+            fingerprint,
+            CachedPlan {
+                unoptimized,
+                optimized,
+                hits: 0,
+                last_used: self.tick,
+            },
+        );
+    }
+}
+
+fn plan_cache_size() -> usize {
+    std::env::var(PLAN_CACHE_SIZE_VAR)
+        .ok()
+        .and_then(|size| size.trim().parse().ok())
+        .unwrap_or(DEFAULT_PLAN_CACHE_SIZE)
+}
+
+static PLAN_CACHE: LazyLock<Mutex<PlanCache>> = LazyLock::new(|| Mutex::new(PlanCache::new(0)));
+
+/// Returns the cached optimized plan of a plan, or optimizes and caches it. The cache isn't locked while optimizing,
+/// and plans are only fingerprinted while it's enabled.
+pub(crate) fn get_or_optimize(
+    fingerprint: impl FnOnce(&LogicalPlan) -> Option<PlanFingerprint>,
+    plan: LogicalPlanRef,
+    optimize: impl FnOnce(LogicalPlanRef) -> DaftResult<LogicalPlanRef>,
+) -> DaftResult<LogicalPlanRef> {
+    // The size is read on every optimization so that the cache can be enabled after the first query.
+    get_or_optimize_in(&PLAN_CACHE, plan_cache_size(), fingerprint, plan, optimize)
+}
+
+fn get_or_optimize_in(
+    cache: &Mutex<PlanCache>,
+    capacity: usize,
+    fingerprint: impl FnOnce(&LogicalPlan) -> Option<PlanFingerprint>,
+    plan: LogicalPlanRef,
+    optimize: impl FnOnce(LogicalPlanRef) -> DaftResult<LogicalPlanRef>,
+) -> DaftResult<LogicalPlanRef> {
+    if capacity == 0 {
+        return optimize(plan);
+    }
+    let Some(fingerprint) = fingerprint(&plan) else {
+        return optimize(plan);
+    };
+    if let Some(optimized) = cache.lock().unwrap().get(fingerprint, &plan) {
+        return Ok(optimized);
+    }
+    let optimized = optimize(plan.clone())?;
+    let mut cache = cache.lock().unwrap();
+    cache.capacity = capacity;
+    cache.insert(fingerprint, plan, optimized.clone());
+    Ok(optimized)
+}
+
+/// Returns the optimized plans in the cache, from the most to the least recently used.
+pub fn plan_cache_entries() -> Vec<PlanCacheEntry> {
+    let cache = PLAN_CACHE.lock().unwrap();
+    let mut entries = cache.entries.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.last_used));
+    entries
+        .into_iter()
+        .map(|(fingerprint, entry)| PlanCacheEntry {
+            fingerprint: *fingerprint,
+            hits: entry.hits,
+            plan: entry.optimized.repr_ascii(true),
+        })
+        .collect()
+}
+
+/// Evicts the optimized plan with a fingerprint from the cache, or every plan if no fingerprint is given. Returns the
+/// number of plans which were evicted.
+pub fn evict_from_plan_cache(fingerprint: Option<PlanFingerprint>) -> usize {
+    let mut cache = PLAN_CACHE.lock().unwrap();
+    match fingerprint {
+        Some(fingerprint) => usize::from(cache.entries.remove(&fingerprint).is_some()),
+        None => {
+            let evicted = cache.entries.len();
+            cache.entries.clear();
+            evicted
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+pub mod python {
+    use pyo3::{exceptions::PyValueError, prelude::*};
+
+    use super::PlanFingerprint;
+
+    /// Returns the fingerprint, number of hits and optimized plan of each plan in the cache.
+    #[pyfunction]
+    pub fn plan_cache_entries() -> Vec<(String, u64, String)> {
+        super::plan_cache_entries()
+            .into_iter()
+            .map(|entry| (entry.fingerprint.to_string(), entry.hits, entry.plan))
+            .collect()
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (fingerprint=None))]
+    pub fn evict_from_plan_cache(fingerprint: Option<&str>) -> PyResult<usize> {
+        let fingerprint = fingerprint
+            .map(|fingerprint| {
+                fingerprint.parse::<PlanFingerprint>().map_err(|_| {
+                    PyValueError::new_err(format!("Invalid plan fingerprint: {fingerprint:?}"))
+                })
+            })
+            .transpose()?;
+        Ok(super::evict_from_plan_cache(fingerprint))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, unresolved_col};
+
+    use super::{PlanCache, PlanFingerprint, get_or_optimize_in};
+    use crate::{
+        LogicalPlanBuilder,
+        test::{dummy_scan_node, dummy_scan_operator},
+    };
+
+    #[test]
+    fn test_optimize_reuses_cached_plan() -> DaftResult<()> {
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .filter(unresolved_col("a").lt(lit(2)))?
+        .select(vec![unresolved_col("b")])?;
+        let config = Arc::new(DaftExecutionConfig::default());
+        let fingerprint = PlanFingerprint::new(&builder.build(), None, &config);
+        let optimize = |cache: &Mutex<PlanCache>, capacity: usize| {
+            get_or_optimize_in(
+                cache,
+                capacity,
+                |_| fingerprint,
+                builder.build(),
+                |plan| {
+                    Ok(LogicalPlanBuilder::from(plan)
+                        .optimize(config.clone())?
+                        .build())
+                },
+            )
+        };
+
+        let cache = Mutex::new(PlanCache::new(0));
+        let first = optimize(&cache, 64)?;
+        let second = optimize(&cache, 64)?;
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.lock().unwrap().entries[&fingerprint.unwrap()].hits, 1);
+        assert_eq!(
+            fingerprint.unwrap().to_string().parse(),
+            Ok(fingerprint.unwrap())
+        );
+
+        // The cache is off by default.
+        let cache = Mutex::new(PlanCache::new(0));
+        optimize(&cache, 0)?;
+        assert!(cache.lock().unwrap().entries.is_empty());
+
+        // A different config gets a different fingerprint.
+        let other_config = DaftExecutionConfig {
+            num_preview_rows: config.num_preview_rows + 1,
+            ..DaftExecutionConfig::default()
+        };
+        assert_ne!(
+            PlanFingerprint::new(&builder.build(), None, &other_config),
+            fingerprint
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_plan_cache_evicts_least_recently_used() {
+        let plan = |name: &str| {
+            dummy_scan_node(dummy_scan_operator(vec![Field::new(name, DataType::Int64)])).build()
+        };
+        let (a, b, c) = (plan("a"), plan("b"), plan("c"));
+        let mut cache = PlanCache::new(2);
+        cache.insert(PlanFingerprint(1), a.clone(), a.clone());
+        cache.insert(PlanFingerprint(2), b.clone(), b.clone());
+        // Using the first plan makes the second the least recently used plan.
+        assert!(cache.get(PlanFingerprint(1), &a).is_some());
+        cache.insert(PlanFingerprint(3), c.clone(), c.clone());
+
+        assert!(cache.get(PlanFingerprint(1), &a).is_some());
+        assert!(cache.get(PlanFingerprint(2), &b).is_none());
+        // A plan whose fingerprint collides with a cached plan's isn't mistaken for it.
+        assert!(cache.get(PlanFingerprint(3), &a).is_none());
+        assert!(cache.get(PlanFingerprint(3), &c).is_some());
+    }
+}
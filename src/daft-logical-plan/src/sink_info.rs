@@ -240,6 +240,67 @@ impl SinkInfo {
     }
 }
 
+/// The name of the directory, under the root directory of a write, that the files of staged writes are written to.
+pub const STAGING_DIR_NAME: &str = "_daft_staging";
+
+impl<E: Clone> OutputFileInfo<E> {
+    /// The directory that the files of the staged write `write_id` are written to until they're committed.
+    pub fn staging_dir(&self, write_id: &str) -> String {
+        format!(
+            "{}/{STAGING_DIR_NAME}/{write_id}",
+            self.root_dir.trim_end_matches('/')
+        )
+    }
+
+    /// Returns this output with its files written to the staging directory of the write `write_id`.
+    pub fn staged(&self, write_id: &str) -> Self {
+        Self {
+            root_dir: self.staging_dir(write_id),
+            ..self.clone()
+        }
+    }
+
+    /// Returns this output with its files written to the directory of an attempt of a task, if it is the staging
+    /// directory of a staged write.
+    ///
+    /// Each attempt of a task writes its files to a directory of its own, so that the files of attempts which failed
+    /// or were superseded can be told apart from the files that are committed.
+    pub fn for_task_attempt(&self, task_id: &str, attempt: &str) -> Option<Self> {
+        self.root_dir
+            .contains(&format!("/{STAGING_DIR_NAME}/"))
+            .then(|| Self {
+                root_dir: format!(
+                    "{}/task_{task_id}_attempt_{attempt}",
+                    self.root_dir.trim_end_matches('/')
+                ),
+                ..self.clone()
+            })
+    }
+}
+
+/// Parses the task id and attempt of the directory that an attempt of a task of a staged write writes its files to.
+pub fn parse_task_attempt_dir(dir: &str) -> Option<(&str, usize)> {
+    let (task_id, attempt) = dir.strip_prefix("task_")?.rsplit_once("_attempt_")?;
+    Some((task_id, attempt.parse().ok()?))
+}
+
+impl<E> SinkInfo<E> {
+    /// Returns the expressions that the written files are partitioned by, if any.
+    pub fn partition_cols(&self) -> Option<&[E]> {
+        match self {
+            Self::OutputFileInfo(info) => info.partition_cols.as_deref(),
+            #[cfg(feature = "python")]
+            Self::CatalogInfo(info) => match &info.catalog {
+                CatalogType::Iceberg(info) => Some(&info.partition_cols),
+                CatalogType::DeltaLake(info) => info.partition_cols.as_deref(),
+                CatalogType::Lance(_) => None,
+            },
+            #[cfg(feature = "python")]
+            Self::DataSinkInfo(_) => None,
+        }
+    }
+}
+
 impl OutputFileInfo {
     pub fn bind(self, schema: &Schema) -> DaftResult<OutputFileInfo<BoundExpr>> {
         Ok(OutputFileInfo {
@@ -1,6 +1,7 @@
-use std::{fmt::Display, hash::Hash, ops::Deref};
+use std::{fmt::Display, hash::Hash, ops::Deref, sync::Arc};
 
 use common_display::utils::bytes_to_human_readable;
+use daft_stats::AnalyzedTableStatistics;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
@@ -20,21 +21,31 @@ impl StatsState {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PlanStats {
-    // Currently we're only putting cardinality stats in the plan stats.
-    // In the future we want to start including column stats, including min, max, NDVs, etc.
     pub approx_stats: ApproxStats,
+    // Column stats (min, max, NDVs, histograms) are only known for tables that were analyzed with `ANALYZE`.
+    pub column_stats: Option<Arc<AnalyzedTableStatistics>>,
 }
 
 impl PlanStats {
     pub fn new(approx_stats: ApproxStats) -> Self {
-        Self { approx_stats }
+        Self {
+            approx_stats,
+            column_stats: None,
+        }
     }
 
     pub fn empty() -> Self {
         Self {
             approx_stats: ApproxStats::empty(),
+            column_stats: None,
         }
     }
+
+    #[must_use]
+    pub fn with_column_stats(mut self, column_stats: Option<Arc<AnalyzedTableStatistics>>) -> Self {
+        self.column_stats = column_stats;
+        self
+    }
 }
 
 impl Default for PlanStats {
@@ -102,6 +113,50 @@ impl<T: Display> Display for AlwaysSame<T> {
     }
 }
 
+/// The stats collected by `ANALYZE` for the table which a source reads. Unlike the stats derived from them, they're part
+/// of the identity of the source, by their version, so that e.g. the plan cache doesn't reuse a plan which was
+/// optimized with older stats.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AnalyzedStats(Option<Arc<AnalyzedTableStatistics>>);
+
+impl AnalyzedStats {
+    fn version(&self) -> Option<u64> {
+        self.0.as_ref().map(|stats| stats.version)
+    }
+}
+
+impl Deref for AnalyzedStats {
+    type Target = Option<Arc<AnalyzedTableStatistics>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Hash for AnalyzedStats {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.version().hash(state);
+    }
+}
+
+impl Eq for AnalyzedStats {}
+
+impl PartialEq for AnalyzedStats {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.version() == other.version()
+    }
+}
+
+impl From<Option<Arc<AnalyzedTableStatistics>>> for AnalyzedStats {
+    #[inline]
+    fn from(value: Option<Arc<AnalyzedTableStatistics>>) -> Self {
+        Self(value)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct ApproxStats {
     pub num_rows: usize,
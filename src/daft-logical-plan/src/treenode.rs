@@ -163,6 +163,8 @@ impl LogicalPlan {
                 node_id,
                 input,
                 to_explode,
+                index_column,
+                pad_mismatched,
                 exploded_schema,
                 stats_state,
             }) => to_explode
@@ -175,6 +177,8 @@ impl LogicalPlan {
                         node_id: *node_id,
                         input: input.clone(),
                         to_explode: new_to_explode,
+                        index_column: index_column.clone(),
+                        pad_mismatched: *pad_mismatched,
                         exploded_schema: exploded_schema.clone(),
                         stats_state: stats_state.clone(),
                     })
@@ -185,6 +189,7 @@ impl LogicalPlan {
                 node_id,
                 output_schema,
                 source_info,
+                analyzed_stats,
                 stats_state,
             }) => match source_info.as_ref() {
                 SourceInfo::Physical(
@@ -214,6 +219,7 @@ impl LogicalPlan {
                                 physical_scan_info
                                     .with_pushdowns(pushdowns.with_filters(Some(new_filter))),
                             )),
+                            analyzed_stats: analyzed_stats.clone(),
                             stats_state: stats_state.clone(),
                         })
                         .into()
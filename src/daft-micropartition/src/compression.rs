@@ -0,0 +1,236 @@
+//! Compression of whole Arrow IPC streams, such as spilled partitions and partitions shuffled between workers.
+//!
+//! Unlike the per-buffer compression of the Arrow IPC format, the codecs here take a compression level. Compressed
+//! streams are recognized by the magic bytes of their codec's frames, which can't be mistaken for the start of an
+//! Arrow IPC stream, so readers don't need to be told which codec a stream was written with.
+
+use std::io::{Read, Write};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::series::Series;
+
+use crate::MicroPartition;
+
+const ZSTD_MAGIC: &[u8] = b"\x28\xB5\x2F\xFD";
+const LZ4_FRAME_MAGIC: &[u8] = b"\x04\x22\x4D\x18";
+
+/// Signatures of formats whose contents are already compressed, as (offset, magic bytes) pairs.
+const COMPRESSED_FORMAT_SIGNATURES: &[(usize, &[u8])] = &[
+    (0, b"\xFF\xD8\xFF"),     // JPEG
+    (0, b"\x89PNG"),          // PNG
+    (0, b"GIF8"),             // GIF
+    (8, b"WEBP"),             // WebP
+    (4, b"ftyp"),             // MP4, MOV, HEIC and AVIF
+    (0, b"\x1A\x45\xDF\xA3"), // WebM and Matroska
+    (0, b"\x1F\x8B"),         // gzip
+    (0, ZSTD_MAGIC),          // zstd
+    (0, b"PK\x03\x04"),       // zip
+];
+
+/// Number of values of a binary column that are sniffed to decide whether it is already compressed.
+const NUM_SNIFFED_VALUES: usize = 16;
+
+/// Compression is skipped when at least this fraction of the bytes of a partition is already compressed.
+const PRECOMPRESSED_FRACTION_THRESHOLD: f64 = 0.5;
+
+/// Codec and level used to compress an Arrow IPC stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpcCompression {
+    /// LZ4 frames, with levels from 0 (the default) to 16.
+    Lz4 { level: u32 },
+    /// Zstandard frames, with levels from 0 (the default) to 22.
+    Zstd { level: i32 },
+}
+
+impl IpcCompression {
+    pub const MAX_LZ4_LEVEL: usize = 16;
+    pub const MAX_ZSTD_LEVEL: usize = 22;
+
+    /// Parses a codec and its level. Both `None` and "none" disable compression, and level 0 is the codec's default.
+    pub fn parse(codec: Option<&str>, level: usize) -> DaftResult<Option<Self>> {
+        let max_level = match codec {
+            Some("none") | None => return Ok(None),
+            Some("lz4") => Self::MAX_LZ4_LEVEL,
+            Some("zstd") => Self::MAX_ZSTD_LEVEL,
+            Some(c) => {
+                return Err(DaftError::ValueError(format!(
+                    "Unsupported compression: {c}, only lz4 and zstd are supported"
+                )));
+            }
+        };
+        if level > max_level {
+            return Err(DaftError::ValueError(format!(
+                "Compression level {level} is out of range for {}, expected 0 to {max_level}",
+                codec.unwrap_or_default()
+            )));
+        }
+        Ok(Some(match codec {
+            Some("lz4") => Self::Lz4 {
+                level: level as u32,
+            },
+            _ => Self::Zstd {
+                level: level as i32,
+            },
+        }))
+    }
+
+    /// Compresses everything written by `f` into `writer`.
+    pub(crate) fn encode<W: Write>(
+        self,
+        writer: W,
+        f: impl FnOnce(&mut dyn Write) -> DaftResult<()>,
+    ) -> DaftResult<W> {
+        match self {
+            Self::Lz4 { level } => {
+                let mut encoder = lz4::EncoderBuilder::new().level(level).build(writer)?;
+                f(&mut encoder)?;
+                let (writer, result) = encoder.finish();
+                result?;
+                Ok(writer)
+            }
+            Self::Zstd { level } => {
+                let mut encoder = zstd::stream::write::Encoder::new(writer, level)?;
+                f(&mut encoder)?;
+                Ok(encoder.finish()?)
+            }
+        }
+    }
+}
+
+/// Returns a reader of the decompressed bytes if `bytes` is a compressed stream, or `None` if it isn't compressed.
+pub(crate) fn decompress(bytes: &[u8]) -> DaftResult<Option<Box<dyn Read + '_>>> {
+    if bytes.starts_with(ZSTD_MAGIC) {
+        Ok(Some(Box::new(zstd::stream::read::Decoder::with_buffer(
+            bytes,
+        )?)))
+    } else if bytes.starts_with(LZ4_FRAME_MAGIC) {
+        Ok(Some(Box::new(lz4::Decoder::new(bytes)?)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns whether a column holds already compressed data such as encoded images or video, which would only waste
+/// CPU to compress again.
+fn is_precompressed(column: &Series) -> bool {
+    let Ok(array) = column.binary() else {
+        return false;
+    };
+    let (sniffed, compressed) = (0..array.len())
+        .filter_map(|i| array.get(i))
+        .take(NUM_SNIFFED_VALUES)
+        .fold((0, 0), |(sniffed, compressed), value| {
+            let is_compressed = COMPRESSED_FORMAT_SIGNATURES.iter().any(|(offset, magic)| {
+                value
+                    .get(*offset..)
+                    .is_some_and(|rest| rest.starts_with(magic))
+            });
+            (sniffed + 1, compressed + usize::from(is_compressed))
+        });
+    sniffed > 0 && compressed * 2 >= sniffed
+}
+
+/// Returns whether a partition is worth compressing, which is not the case when most of its bytes are in columns that
+/// are already compressed.
+pub fn should_compress(data: &MicroPartition) -> bool {
+    let mut total_bytes = 0;
+    let mut precompressed_bytes = 0;
+    for batch in data.record_batches() {
+        for column in batch.columns() {
+            let size_bytes = column.size_bytes();
+            total_bytes += size_bytes;
+            if is_precompressed(column) {
+                precompressed_bytes += size_bytes;
+            }
+        }
+    }
+    (precompressed_bytes as f64) < PRECOMPRESSED_FRACTION_THRESHOLD * total_bytes as f64
+        || total_bytes == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{IpcCompression, is_precompressed, should_compress};
+    use crate::MicroPartition;
+
+    fn binary_series(name: &str, values: &[&[u8]]) -> Series {
+        BinaryArray::from_values(name, values.iter().copied()).into_series()
+    }
+
+    #[test]
+    fn test_is_precompressed() {
+        let jpegs = binary_series(
+            "jpegs",
+            &[b"\xFF\xD8\xFF\xE0 jfif", b"\xFF\xD8\xFF\xE1 exif"],
+        );
+        assert!(is_precompressed(&jpegs));
+
+        let videos = binary_series("videos", &[b"\x00\x00\x00\x20ftypisom"]);
+        assert!(is_precompressed(&videos));
+
+        let text = binary_series("text", &[b"hello", b"world"]);
+        assert!(!is_precompressed(&text));
+
+        let ints = Int64Array::from_values("ints", 0..10).into_series();
+        assert!(!is_precompressed(&ints));
+    }
+
+    #[test]
+    fn test_should_compress() -> DaftResult<()> {
+        let video = [b"\x1A\x45\xDF\xA3".as_slice(), &[0; 1024]].concat();
+        let videos = binary_series("videos", &[video.as_slice(); 8]);
+        let ids = Int64Array::from_values("ids", 0..8).into_series();
+
+        let batch = RecordBatch::from_nonempty_columns(vec![ids.clone(), videos])?;
+        let mp = MicroPartition::new_loaded(batch.schema.clone(), Arc::new(vec![batch]), None);
+        assert!(!should_compress(&mp));
+
+        let batch = RecordBatch::from_nonempty_columns(vec![ids])?;
+        let mp = MicroPartition::new_loaded(batch.schema.clone(), Arc::new(vec![batch]), None);
+        assert!(should_compress(&mp));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_compression() -> DaftResult<()> {
+        assert_eq!(IpcCompression::parse(None, 0)?, None);
+        assert_eq!(IpcCompression::parse(Some("none"), 0)?, None);
+        assert_eq!(
+            IpcCompression::parse(Some("zstd"), 19)?,
+            Some(IpcCompression::Zstd { level: 19 })
+        );
+        assert_eq!(
+            IpcCompression::parse(Some("lz4"), 0)?,
+            Some(IpcCompression::Lz4 { level: 0 })
+        );
+        assert!(IpcCompression::parse(Some("lz4"), 17).is_err());
+        assert!(IpcCompression::parse(Some("gzip"), 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_ipc_stream_roundtrip() -> DaftResult<()> {
+        let ids = Int64Array::from_values("ids", (0..10_000).map(|i| i % 7)).into_series();
+        let batch = RecordBatch::from_nonempty_columns(vec![ids])?;
+        let mp =
+            MicroPartition::new_loaded(batch.schema.clone(), Arc::new(vec![batch.clone()]), None);
+
+        let uncompressed = mp.write_to_ipc_stream(None)?;
+        for compression in [
+            IpcCompression::Zstd { level: 3 },
+            IpcCompression::Lz4 { level: 4 },
+        ] {
+            let compressed = mp.write_to_ipc_stream(Some(compression))?;
+            assert!(compressed.len() < uncompressed.len());
+            let roundtrip = MicroPartition::read_from_ipc_stream(&compressed)?;
+            assert_eq!(roundtrip.record_batches(), [batch.clone()]);
+        }
+        Ok(())
+    }
+}
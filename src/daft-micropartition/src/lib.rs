@@ -3,6 +3,7 @@
 
 use common_error::DaftError;
 use snafu::Snafu;
+pub mod compression;
 mod micropartition;
 mod ops;
 
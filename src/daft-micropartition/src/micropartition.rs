@@ -2,14 +2,18 @@
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
     fmt::Display,
+    path::Path,
     pin::Pin,
+    ptr::NonNull,
     sync::Arc,
     task::{Context, Poll},
 };
 
 use arrow_array::ArrayRef;
+use arrow_buffer::Buffer;
 use common_error::{DaftError, DaftResult};
 use common_runtime::get_io_runtime;
+use daft_arrow::ipc::reader::StreamDecoder;
 use daft_core::prelude::*;
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 use daft_dsl::{AggExpr, Expr, ExprRef};
@@ -26,7 +30,10 @@ use futures::{Future, Stream};
 use parquet2::metadata::FileMetaData;
 use snafu::ResultExt;
 
-use crate::DaftCoreComputeSnafu;
+use crate::{
+    DaftCoreComputeSnafu,
+    compression::{IpcCompression, decompress, should_compress},
+};
 
 pub type MicroPartitionRef = Arc<MicroPartition>;
 
@@ -190,11 +197,34 @@ impl MicroPartition {
         ))
     }
 
-    pub fn write_to_ipc_stream(&self) -> DaftResult<Vec<u8>> {
+    pub fn write_to_ipc_stream(&self, compression: Option<IpcCompression>) -> DaftResult<Vec<u8>> {
         let mut buffer = Vec::with_capacity(self.size_bytes());
+        self.write_ipc_stream(&mut buffer, compression)?;
+        buffer.shrink_to_fit();
+        Ok(buffer)
+    }
+
+    /// Writes the partition in the Arrow IPC streaming format, one record batch at a time.
+    ///
+    /// With a `compression`, the whole stream is compressed, unless most of the partition's bytes are already
+    /// compressed data such as encoded images or video.
+    pub fn write_ipc_stream<W: std::io::Write>(
+        &self,
+        writer: W,
+        compression: Option<IpcCompression>,
+    ) -> DaftResult<()> {
+        match compression {
+            Some(compression) if should_compress(self) => {
+                compression.encode(writer, |writer| self.write_ipc_batches(writer))?;
+                Ok(())
+            }
+            _ => self.write_ipc_batches(writer),
+        }
+    }
+
+    fn write_ipc_batches<W: std::io::Write>(&self, writer: W) -> DaftResult<()> {
         let arrow_schema = self.schema.to_arrow()?;
-        let mut writer =
-            daft_arrow::ipc::writer::StreamWriter::try_new(&mut buffer, &arrow_schema)?;
+        let mut writer = daft_arrow::ipc::writer::StreamWriter::try_new(writer, &arrow_schema)?;
 
         for table in self.record_batches() {
             // Convert daft RecordBatch to arrow-rs RecordBatch
@@ -203,13 +233,21 @@ impl MicroPartition {
         }
 
         writer.finish()?;
-        buffer.shrink_to_fit();
-        Ok(buffer)
+        Ok(())
     }
 
+    /// Reads a partition in the Arrow IPC streaming format, which may be compressed, such as one written by
+    /// [`Self::write_ipc_stream`].
     pub fn read_from_ipc_stream(buffer: &[u8]) -> DaftResult<Self> {
-        let mut cursor = std::io::Cursor::new(buffer);
-        let reader = daft_arrow::ipc::reader::StreamReader::try_new(&mut cursor, None)?;
+        match decompress(buffer)? {
+            Some(reader) => Self::read_ipc_stream(reader),
+            None => Self::read_ipc_stream(std::io::Cursor::new(buffer)),
+        }
+    }
+
+    /// Reads a partition in the uncompressed Arrow IPC streaming format.
+    pub fn read_ipc_stream<R: std::io::Read>(reader: R) -> DaftResult<Self> {
+        let reader = daft_arrow::ipc::reader::StreamReader::try_new(reader, None)?;
 
         let arrow_schema = reader.schema();
         let schema: SchemaRef = Arc::new(arrow_schema.as_ref().try_into()?);
@@ -226,6 +264,58 @@ impl MicroPartition {
 
         Ok(Self::new_loaded(schema, Arc::new(tables), None))
     }
+
+    /// Reads a partition from a local file in the Arrow IPC streaming format, such as one written by
+    /// [`Self::write_ipc_stream`].
+    ///
+    /// The file is memory-mapped and, unless it is compressed, its record batches are decoded in place, so the
+    /// partition's buffers are backed by the page cache instead of being copied onto the heap.
+    pub fn read_ipc_file(path: impl AsRef<Path>) -> DaftResult<Self> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: mmapping is inherently unsafe.
+        // We are trusting that the file is not modified or truncated by other systems while it is mapped.
+        let mmap = unsafe { memmap2::Mmap::map(&file) }?;
+        if let Some(reader) = decompress(&mmap)? {
+            return Self::read_ipc_stream(reader);
+        }
+        let Some(ptr) = NonNull::new(mmap.as_ptr().cast_mut()) else {
+            return Self::read_ipc_stream(&mmap[..]);
+        };
+        let len = mmap.len();
+        // SAFETY: `ptr` points to `len` bytes owned by `mmap`, which the buffer keeps alive.
+        let data = unsafe { Buffer::from_custom_allocation(ptr, len, Arc::new(mmap)) };
+
+        let mut buffer = data.clone();
+
+        let mut decoder = StreamDecoder::new();
+        let mut schema: Option<SchemaRef> = None;
+        let mut tables = Vec::new();
+        while !buffer.is_empty() {
+            let Some(arrow_batch) = decoder.decode(&mut buffer)? else {
+                continue;
+            };
+            let batch_schema = match &schema {
+                Some(schema) => schema.clone(),
+                None => {
+                    let batch_schema: SchemaRef =
+                        Arc::new(arrow_batch.schema().as_ref().try_into()?);
+                    schema = Some(batch_schema.clone());
+                    batch_schema
+                }
+            };
+            tables.push(RecordBatch::from_arrow(
+                batch_schema,
+                arrow_batch.columns().to_vec(),
+            )?);
+        }
+        decoder.finish()?;
+
+        match schema {
+            Some(schema) => Ok(Self::new_loaded(schema, Arc::new(tables), None)),
+            // The decoder only surfaces the schema through its record batches, so re-read a stream without any.
+            None => Self::read_ipc_stream(data.as_slice()),
+        }
+    }
 }
 
 fn prune_fields_from_schema(
@@ -852,7 +942,7 @@ mod tests {
             None,
         );
 
-        let ipc_stream = table.write_to_ipc_stream()?;
+        let ipc_stream = table.write_to_ipc_stream(None)?;
         let roundtrip_table = MicroPartition::read_from_ipc_stream(&ipc_stream)?;
         assert_eq!(batch1, roundtrip_table.record_batches()[0]);
         assert_eq!(batch2, roundtrip_table.record_batches()[1]);
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_core::prelude::Schema;
+use daft_core::prelude::{DataType, Field, Schema};
 use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_recordbatch::FusedProjection;
 use daft_stats::{ColumnRangeStatistics, TableStatistics};
 use snafu::ResultExt;
 
@@ -90,13 +91,84 @@ impl MicroPartition {
         ))
     }
 
+    /// See [`RecordBatch::eval_projection_async`](daft_recordbatch::RecordBatch::eval_projection_async).
+    pub async fn eval_projection_async(&self, projection: &FusedProjection) -> DaftResult<Self> {
+        let exprs = projection.exprs();
+        let expected_schema = infer_schema(exprs, &self.schema)?;
+
+        let evaluated_table_futs = self
+            .record_batches()
+            .iter()
+            .map(|table| table.eval_projection_async(projection));
+
+        let evaluated_tables = futures::future::try_join_all(evaluated_table_futs).await?;
+
+        let eval_stats = self
+            .statistics
+            .as_ref()
+            .map(|table_statistics| table_statistics.eval_expression_list(exprs))
+            .transpose()?;
+
+        Ok(Self::new_loaded(
+            expected_schema.into(),
+            Arc::new(evaluated_tables),
+            eval_stats,
+        ))
+    }
+
+    /// See [`RecordBatch::par_eval_projection`](daft_recordbatch::RecordBatch::par_eval_projection).
+    pub async fn par_eval_projection(
+        &self,
+        projection: &FusedProjection,
+        num_parallel_tasks: usize,
+    ) -> DaftResult<Self> {
+        let exprs = projection.exprs();
+        let expected_schema = infer_schema(exprs, &self.schema)?;
+
+        let evaluated_table_futs = self
+            .record_batches()
+            .iter()
+            .map(|table| table.par_eval_projection(projection, num_parallel_tasks));
+
+        let evaluated_tables = futures::future::try_join_all(evaluated_table_futs).await?;
+
+        let eval_stats = self
+            .statistics
+            .as_ref()
+            .map(|table_statistics| table_statistics.eval_expression_list(exprs))
+            .transpose()?;
+
+        Ok(Self::new_loaded(
+            expected_schema.into(),
+            Arc::new(evaluated_tables),
+            eval_stats,
+        ))
+    }
+
     pub fn explode(&self, exprs: &[BoundExpr]) -> DaftResult<Self> {
+        self.explode_with_options(exprs, None, false)
+    }
+
+    /// See [`RecordBatch::explode_with_options`](daft_recordbatch::RecordBatch::explode_with_options).
+    pub fn explode_with_options(
+        &self,
+        exprs: &[BoundExpr],
+        index_column: Option<&str>,
+        pad_mismatched: bool,
+    ) -> DaftResult<Self> {
         let evaluated_tables = self
             .record_batches()
             .iter()
-            .map(|t| t.explode(exprs))
+            .map(|t| t.explode_with_options(exprs, index_column, pad_mismatched))
             .collect::<DaftResult<Vec<_>>>()?;
-        let expected_new_columns = infer_schema(exprs, &self.schema)?;
+        let mut expected_new_columns = infer_schema(exprs, &self.schema)?;
+        if let Some(index_column) = index_column {
+            expected_new_columns =
+                expected_new_columns.non_distinct_union(&Schema::new(vec![Field::new(
+                    index_column,
+                    DataType::UInt64,
+                )]))?;
+        }
 
         let expected_schema = Arc::new(self.schema.non_distinct_union(&expected_new_columns)?);
 
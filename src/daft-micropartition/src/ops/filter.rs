@@ -1,5 +1,6 @@
 use common_error::DaftResult;
 use daft_dsl::expr::bound_expr::BoundExpr;
+use daft_recordbatch::ConjunctObservation;
 use daft_stats::TruthValue;
 use snafu::ResultExt;
 
@@ -40,4 +41,56 @@ impl MicroPartition {
             self.statistics.clone(), // update these values based off the filter we just ran
         ))
     }
+
+    /// Filters the rows for which all of `conjuncts` are true, evaluating the conjuncts in order with
+    /// [`RecordBatch::filter_conjuncts`](daft_recordbatch::RecordBatch::filter_conjuncts).
+    ///
+    /// If the partition has statistics, conjuncts which they show are true for every row are not evaluated, and the
+    /// partition is emptied without evaluating any conjunct if one is false for every row. The observations passed to
+    /// `observe` index into `conjuncts`.
+    pub fn filter_conjuncts(
+        &self,
+        conjuncts: &[BoundExpr],
+        mut observe: impl FnMut(ConjunctObservation),
+    ) -> DaftResult<Self> {
+        let mut remaining = (0..conjuncts.len()).collect::<Vec<_>>();
+        if let Some(statistics) = &self.statistics {
+            let mut undecided = vec![];
+            for index in remaining {
+                match statistics
+                    .eval_expression(&conjuncts[index])?
+                    .to_truth_value()
+                {
+                    TruthValue::False => return Ok(Self::empty(Some(self.schema.clone()))),
+                    TruthValue::True => {}
+                    TruthValue::Maybe => undecided.push(index),
+                }
+            }
+            remaining = undecided;
+        }
+        let remaining_conjuncts = remaining
+            .iter()
+            .map(|index| conjuncts[*index].clone())
+            .collect::<Vec<_>>();
+
+        let tables = self
+            .record_batches()
+            .iter()
+            .map(|t| {
+                t.filter_conjuncts(&remaining_conjuncts, |observation| {
+                    observe(ConjunctObservation {
+                        index: remaining[observation.index],
+                        ..observation
+                    });
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()
+            .context(DaftCoreComputeSnafu)?;
+
+        Ok(Self::new_loaded(
+            self.schema.clone(),
+            tables.into(),
+            self.statistics.clone(),
+        ))
+    }
 }
@@ -110,8 +110,8 @@ impl MicroPartition {
                           rt: &RecordBatch,
                           lo: &[BoundExpr],
                           ro: &[BoundExpr],
-                          _how: JoinType| {
-            RecordBatch::sort_merge_join(lt, rt, lo, ro, is_sorted)
+                          how: JoinType| {
+            RecordBatch::sort_merge_join(lt, rt, lo, ro, how, is_sorted)
         };
 
         self.join(right, left_on, right_on, how, table_join)
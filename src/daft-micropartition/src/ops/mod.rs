@@ -6,6 +6,7 @@ mod filter;
 mod join;
 mod partition;
 mod pivot;
+mod resample;
 mod slice;
 mod sort;
 mod take;
@@ -0,0 +1,30 @@
+use common_error::DaftResult;
+use daft_core::gap_fill::GapFill;
+use daft_recordbatch::RecordBatch;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    pub fn fill_time_gaps(
+        &self,
+        num_keys: usize,
+        every: i64,
+        fill: Option<GapFill>,
+    ) -> DaftResult<Self> {
+        match self.concat_or_get()? {
+            None => {
+                let empty_table = RecordBatch::empty(Some(self.schema.clone()));
+                let filled = empty_table.fill_time_gaps(num_keys, every, fill)?;
+                Ok(Self::empty(Some(filled.schema)))
+            }
+            Some(t) => {
+                let filled = t.fill_time_gaps(num_keys, every, fill)?;
+                Ok(Self::new_loaded(
+                    filled.schema.clone(),
+                    vec![filled].into(),
+                    None,
+                ))
+            }
+        }
+    }
+}
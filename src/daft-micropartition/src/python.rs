@@ -1,4 +1,9 @@
-use std::sync::Arc;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    sync::Arc,
+};
 
 use common_error::{DaftError, DaftResult};
 use common_partitioning::{Partition, PartitionId, PartitionSet};
@@ -23,7 +28,7 @@ use pyo3::{PyTypeInfo, exceptions::PyValueError, prelude::*, types::PyBytes};
 use snafu::ResultExt;
 
 use crate::{
-    DaftCoreComputeSnafu, PyIOSnafu, micropartition::MicroPartition,
+    DaftCoreComputeSnafu, PyIOSnafu, compression::IpcCompression, micropartition::MicroPartition,
     partitioning::MicroPartitionSet,
 };
 
@@ -351,6 +356,7 @@ impl PyMicroPartition {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
         is_sorted: bool,
     ) -> PyResult<Self> {
         let left_exprs = BoundExpr::bind_all(&left_on, &self.inner.schema)?;
@@ -362,7 +368,7 @@ impl PyMicroPartition {
                     &right.inner,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
-                    JoinType::Inner, // TODO: Expose other join types
+                    how,
                     is_sorted,
                 )?
                 .into())
@@ -918,8 +924,15 @@ impl PyMicroPartition {
         ))
     }
 
-    pub fn write_to_ipc_stream<'a>(&'a self, py: Python<'a>) -> PyResult<Bound<'a, PyBytes>> {
-        let buffer = py.detach(|| self.inner.write_to_ipc_stream())?;
+    #[pyo3(signature = (compression=None, compression_level=0))]
+    pub fn write_to_ipc_stream<'a>(
+        &'a self,
+        py: Python<'a>,
+        compression: Option<&str>,
+        compression_level: usize,
+    ) -> PyResult<Bound<'a, PyBytes>> {
+        let compression = IpcCompression::parse(compression, compression_level)?;
+        let buffer = py.detach(|| self.inner.write_to_ipc_stream(compression))?;
         let bytes = PyBytes::new(py, &buffer);
         Ok(bytes)
     }
@@ -930,6 +943,28 @@ impl PyMicroPartition {
         let mp = py.detach(|| MicroPartition::read_from_ipc_stream(buffer))?;
         Ok(mp.into())
     }
+
+    #[pyo3(signature = (path, compression=None, compression_level=0))]
+    pub fn write_ipc_file(
+        &self,
+        py: Python,
+        path: PathBuf,
+        compression: Option<&str>,
+        compression_level: usize,
+    ) -> PyResult<()> {
+        let compression = IpcCompression::parse(compression, compression_level)?;
+        py.detach(|| {
+            let mut file = BufWriter::new(File::create(path)?);
+            self.inner.write_ipc_stream(&mut file, compression)?;
+            file.flush()?;
+            Ok(())
+        })
+    }
+
+    #[staticmethod]
+    pub fn read_ipc_file(py: Python, path: PathBuf) -> PyResult<Self> {
+        py.detach(|| Ok(MicroPartition::read_ipc_file(path)?.into()))
+    }
 }
 
 pub fn read_json_into_py_table(
@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use parquet2::{
+    compression::Compression,
+    page::{CompressedDictPage, CompressedPage, DictPage, Page},
+    read::decompress,
+};
+
+/// State shared by the tasks which decompress the pages of the column chunks of a single file, so that they can
+/// reuse each other's buffers and dictionaries when row groups are decoded in parallel.
+#[derive(Default)]
+pub(crate) struct FileDecompressionContext {
+    buffers: BufferPool,
+    dictionaries: DictionaryCache,
+}
+
+impl FileDecompressionContext {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Returns the buffer of a page once it has been decoded, so that it can be reused for a later page.
+    pub fn recycle(&self, mut page: Page) {
+        self.buffers.put(std::mem::take(page.buffer()));
+    }
+
+    fn decompress(&self, page: CompressedPage) -> parquet2::error::Result<Page> {
+        if page.compression() == Compression::Uncompressed {
+            // The buffer of an uncompressed page is moved to the decompressed page, so no buffer is needed.
+            return decompress(page, &mut vec![]);
+        }
+        let mut buffer = self.buffers.take();
+        if let CompressedPage::Dict(dict) = &page
+            && dict.uncompressed_size() <= DictionaryCache::MAX_DICTIONARY_SIZE
+        {
+            let key = DictionaryCache::key(dict);
+            if let Some(cached) = self.dictionaries.get(key, dict.buffer()) {
+                buffer.extend_from_slice(&cached.decompressed);
+                return Ok(Page::Dict(DictPage::new(
+                    buffer,
+                    dict.num_values(),
+                    dict.is_sorted,
+                )));
+            }
+            let compressed = dict.buffer().to_vec();
+            let page = decompress(page, &mut buffer)?;
+            if let Page::Dict(decompressed) = &page {
+                self.dictionaries
+                    .insert(key, compressed, decompressed.buffer.clone());
+            }
+            return Ok(page);
+        }
+        decompress(page, &mut buffer)
+    }
+}
+
+/// Decompresses a stream of pages, using buffers and dictionaries shared by the other column chunks of the file.
+pub(crate) fn streaming_decompression<
+    S: futures::Stream<Item = parquet2::error::Result<CompressedPage>>,
+>(
+    input: S,
+    context: Arc<FileDecompressionContext>,
+) -> impl futures::Stream<Item = parquet2::error::Result<Page>> {
+    async_stream::stream! {
+        for await compressed_page in input {
+            let compressed_page = compressed_page?;
+            yield context.decompress(compressed_page);
+        }
+    }
+}
+
+/// A pool of buffers for decompressed pages. Decoded pages return their buffers to the pool, so that decompressing
+/// later pages reuses their allocations.
+#[derive(Default)]
+struct BufferPool {
+    buffers: Mutex<Vec<Vec<u8>>>,
+}
+
+impl BufferPool {
+    /// The number of buffers to keep, which is enough for the column chunks decoded at once in a file.
+    const MAX_BUFFERS: usize = 64;
+    /// Larger buffers are dropped rather than kept, so that a few large pages don't hold on to a lot of memory.
+    const MAX_BUFFER_CAPACITY: usize = 8 * 1024 * 1024;
+
+    fn take(&self) -> Vec<u8> {
+        self.buffers.lock().unwrap().pop().unwrap_or_default()
+    }
+
+    fn put(&self, mut buffer: Vec<u8>) {
+        if buffer.capacity() == 0 || buffer.capacity() > Self::MAX_BUFFER_CAPACITY {
+            return;
+        }
+        buffer.clear();
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() < Self::MAX_BUFFERS {
+            buffers.push(buffer);
+        }
+    }
+}
+
+struct CachedDictionary {
+    compressed: Vec<u8>,
+    decompressed: Vec<u8>,
+}
+
+/// A cache of decompressed dictionary pages, by their compressed data.
+///
+/// Writers often write the same dictionary for a column in every row group, e.g. for low cardinality columns, so
+/// each distinct dictionary is only decompressed once per file. Dictionaries are matched by their compressed bytes,
+/// so a cached dictionary is never used for a different page.
+#[derive(Default)]
+struct DictionaryCache {
+    state: Mutex<DictionaryCacheState>,
+}
+
+#[derive(Default)]
+struct DictionaryCacheState {
+    dictionaries: HashMap<(Compression, u64), Arc<CachedDictionary>>,
+    size_bytes: usize,
+}
+
+impl DictionaryCache {
+    /// Dictionaries larger than this are not cached.
+    const MAX_DICTIONARY_SIZE: usize = 4 * 1024 * 1024;
+    /// The maximum size of the cached dictionaries of a file, compressed and decompressed.
+    const MAX_SIZE: usize = 32 * 1024 * 1024;
+
+    fn key(page: &CompressedDictPage) -> (Compression, u64) {
+        let mut hasher = DefaultHasher::new();
+        page.buffer().hash(&mut hasher);
+        (page.compression(), hasher.finish())
+    }
+
+    fn get(&self, key: (Compression, u64), compressed: &[u8]) -> Option<Arc<CachedDictionary>> {
+        let state = self.state.lock().unwrap();
+        state
+            .dictionaries
+            .get(&key)
+            .filter(|cached| cached.compressed == compressed)
+            .cloned()
+    }
+
+    fn insert(&self, key: (Compression, u64), compressed: Vec<u8>, decompressed: Vec<u8>) {
+        let size = compressed.len() + decompressed.len();
+        let mut state = self.state.lock().unwrap();
+        if state.size_bytes + size > Self::MAX_SIZE || state.dictionaries.contains_key(&key) {
+            return;
+        }
+        state.size_bytes += size;
+        state.dictionaries.insert(
+            key,
+            Arc::new(CachedDictionary {
+                compressed,
+                decompressed,
+            }),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet2::compression::{CompressionOptions, compress};
+
+    use super::*;
+
+    fn dict_page(values: &[u8]) -> CompressedPage {
+        let mut compressed = vec![];
+        compress(CompressionOptions::Snappy, values, &mut compressed).unwrap();
+        CompressedPage::Dict(CompressedDictPage::new(
+            compressed,
+            Compression::Snappy,
+            values.len(),
+            values.len(),
+            false,
+        ))
+    }
+
+    fn dict_buffer(page: Page) -> Vec<u8> {
+        match page {
+            Page::Dict(page) => page.buffer,
+            Page::Data(_) => panic!("Expected a dictionary page"),
+        }
+    }
+
+    #[test]
+    fn test_repeated_dictionaries_are_cached() {
+        let context = FileDecompressionContext::new();
+        let first = dict_buffer(context.decompress(dict_page(b"abcdef")).unwrap());
+        let second = dict_buffer(context.decompress(dict_page(b"abcdef")).unwrap());
+        let other = dict_buffer(context.decompress(dict_page(b"ghijkl")).unwrap());
+
+        assert_eq!(first, b"abcdef");
+        assert_eq!(second, b"abcdef");
+        assert_eq!(other, b"ghijkl");
+        let state = context.dictionaries.state.lock().unwrap();
+        assert_eq!(state.dictionaries.len(), 2);
+    }
+
+    #[test]
+    fn test_recycled_buffers_are_reused() {
+        let context = FileDecompressionContext::new();
+        let page = context.decompress(dict_page(b"abcdef")).unwrap();
+        context.recycle(page);
+        assert_eq!(context.buffers.buffers.lock().unwrap().len(), 1);
+
+        let page = context.decompress(dict_page(b"abcdef")).unwrap();
+        assert!(context.buffers.buffers.lock().unwrap().is_empty());
+        assert_eq!(dict_buffer(page), b"abcdef");
+    }
+}
@@ -9,14 +9,15 @@ use common_runtime::{combine_stream, get_io_runtime};
 use daft_arrow::io::parquet::read::column_iter_to_arrays;
 use daft_core::prelude::*;
 use daft_dsl::{ExprRef, expr::bound_expr::BoundExpr};
-use daft_io::{IOClient, IOStatsRef};
+use daft_io::{GetRange, IOClient, IOStatsRef};
 use daft_recordbatch::RecordBatch;
-use daft_stats::TruthValue;
+use daft_stats::{
+    TruthValue,
+    pruning::{PruneReason, record_pruned},
+};
 use futures::{FutureExt, StreamExt, future::try_join_all, stream::BoxStream};
 use parquet2::{
-    FallibleStreamingIterator,
-    page::{CompressedPage, Page},
-    read::get_owned_page_stream_from_column_start,
+    FallibleStreamingIterator, page::Page, read::get_owned_page_stream_from_column_start,
 };
 use snafu::ResultExt;
 use tokio_stream::wrappers::ReceiverStream;
@@ -25,10 +26,14 @@ use crate::{
     JoinSnafu, OneShotRecvSnafu, PARQUET_MORSEL_SIZE, UnableToBindExpressionSnafu,
     UnableToConvertRowGroupMetadataToStatsSnafu, UnableToCreateParquetPageStreamSnafu,
     UnableToParseSchemaFromMetadataSnafu, UnableToRunExpressionOnStatsSnafu,
-    determine_parquet_parallelism, infer_arrow_schema_from_metadata,
+    decompression::{FileDecompressionContext, streaming_decompression},
+    default_max_row_group_concurrency, determine_parquet_parallelism,
+    infer_arrow_schema_from_metadata,
     metadata::read_parquet_metadata,
+    page_index::{FetchedRange, PageIndexPruner},
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
+    row_selection::{RowSelection, page_filter},
     statistics,
     stream_reader::spawn_column_iters_to_table_task,
 };
@@ -43,38 +48,30 @@ pub struct ParquetReaderBuilder {
     schema_inference_options: ParquetSchemaInferenceOptions,
     predicate: Option<ExprRef>,
     chunk_size: Option<usize>,
-}
-use parquet2::read::decompress;
-
-fn streaming_decompression<S: futures::Stream<Item = parquet2::error::Result<CompressedPage>>>(
-    input: S,
-) -> impl futures::Stream<Item = parquet2::error::Result<Page>> {
-    async_stream::stream! {
-        let mut buffer = vec![];
-
-        for await compressed_page in input {
-            let compressed_page = compressed_page?;
-            yield decompress(compressed_page, &mut buffer);
-        }
-        drop(buffer);
-    }
+    max_row_group_concurrency: Option<usize>,
 }
 
 pub struct StreamIterator<S> {
     curr: Option<Page>,
     src: tokio::sync::Mutex<S>,
     handle: tokio::runtime::Handle,
+    decompression_context: Arc<FileDecompressionContext>,
 }
 
 impl<S> StreamIterator<S>
 where
     S: futures::Stream<Item = parquet2::error::Result<Page>> + std::marker::Unpin,
 {
-    pub fn new(src: S, handle: tokio::runtime::Handle) -> Self {
+    pub(crate) fn new(
+        src: S,
+        handle: tokio::runtime::Handle,
+        decompression_context: Arc<FileDecompressionContext>,
+    ) -> Self {
         Self {
             curr: None,
             src: tokio::sync::Mutex::new(src),
             handle,
+            decompression_context,
         }
     }
 }
@@ -86,6 +83,10 @@ where
     type Error = parquet2::error::Error;
     type Item = Page;
     fn advance(&mut self) -> Result<(), Self::Error> {
+        // The current page has been decoded once the iterator advances, so its buffer can be reused.
+        if let Some(page) = self.curr.take() {
+            self.decompression_context.recycle(page);
+        }
         let val = tokio::task::block_in_place(|| {
             self.handle.block_on(async {
                 let mut s_guard = self.src.lock().await;
@@ -153,6 +154,13 @@ pub fn build_row_ranges(
                     }
                 })?;
                 if evaled.to_truth_value() == TruthValue::False {
+                    record_pruned(
+                        uri,
+                        Some(i),
+                        None,
+                        PruneReason::RowGroupStatistics,
+                        pred.inner(),
+                    );
                     continue;
                 }
             }
@@ -184,6 +192,13 @@ pub fn build_row_ranges(
                         }
                     })?;
                     if evaled.to_truth_value() == TruthValue::False {
+                        record_pruned(
+                            uri,
+                            Some(*i),
+                            None,
+                            PruneReason::RowGroupStatistics,
+                            pred.inner(),
+                        );
                         curr_row_index += rg.num_rows();
                         continue;
                     }
@@ -223,6 +238,7 @@ impl ParquetReaderBuilder {
             schema_inference_options: Default::default(),
             predicate: None,
             chunk_size: None,
+            max_row_group_concurrency: None,
         })
     }
 
@@ -267,6 +283,15 @@ impl ParquetReaderBuilder {
         self
     }
 
+    /// Sets the maximum number of row groups of the file to decode at once, which defaults to the number of cores.
+    pub fn set_max_row_group_concurrency(
+        mut self,
+        max_row_group_concurrency: Option<usize>,
+    ) -> Self {
+        self.max_row_group_concurrency = max_row_group_concurrency;
+        self
+    }
+
     pub fn build(self) -> super::Result<ParquetFileReader> {
         let options = self.schema_inference_options.into();
         let mut arrow_schema = infer_arrow_schema_from_metadata(&self.metadata, Some(options))
@@ -297,6 +322,9 @@ impl ParquetReaderBuilder {
             arrow_schema,
             row_ranges,
             self.chunk_size,
+            self.max_row_group_concurrency
+                .unwrap_or_else(default_max_row_group_concurrency)
+                .max(1),
         )
     }
 }
@@ -308,12 +336,46 @@ pub struct RowGroupRange {
     pub num_rows: usize,
 }
 
+/// Limits how many row groups of a file are decoded at once. The tasks which decode the column chunks of a row group
+/// share a single permit, which is released once all of them are done.
+#[derive(Clone)]
+struct RowGroupPermit {
+    semaphore: Arc<tokio::sync::Semaphore>,
+    permit: Arc<tokio::sync::OnceCell<tokio::sync::OwnedSemaphorePermit>>,
+}
+
+impl RowGroupPermit {
+    fn for_row_ranges(max_concurrency: usize, num_row_ranges: usize) -> Vec<Self> {
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrency));
+        (0..num_row_ranges)
+            .map(|_| Self {
+                semaphore: semaphore.clone(),
+                permit: Default::default(),
+            })
+            .collect()
+    }
+
+    /// Waits until the row group may be decoded, which holds the permit until this and its clones are dropped.
+    async fn acquire(&self) {
+        self.permit
+            .get_or_init(|| async {
+                self.semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("Row group semaphore should never be closed")
+            })
+            .await;
+    }
+}
+
 pub struct ParquetFileReader {
     uri: String,
     metadata: Arc<parquet2::metadata::FileMetaData>,
     arrow_schema: daft_arrow::datatypes::SchemaRef,
     row_ranges: Arc<Vec<RowGroupRange>>,
     chunk_size: Option<usize>,
+    max_row_group_concurrency: usize,
 }
 
 impl ParquetFileReader {
@@ -328,6 +390,7 @@ impl ParquetFileReader {
         arrow_schema: daft_arrow::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
         chunk_size: Option<usize>,
+        max_row_group_concurrency: usize,
     ) -> super::Result<Self> {
         Ok(Self {
             uri,
@@ -335,6 +398,7 @@ impl ParquetFileReader {
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
             chunk_size,
+            max_row_group_concurrency,
         })
     }
 
@@ -342,6 +406,66 @@ impl ParquetFileReader {
         &self.arrow_schema
     }
 
+    /// Skips the row groups in which the page index of the file shows that no page can satisfy the predicate.
+    pub async fn prune_with_page_index(
+        mut self,
+        predicate: &ExprRef,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let Some(pruner) = PageIndexPruner::try_new(predicate, &self.arrow_schema) else {
+            return Ok(self);
+        };
+        let row_groups = self
+            .row_ranges
+            .iter()
+            .map(|row_range| {
+                self.metadata
+                    .row_groups
+                    .get(&row_range.row_group_index)
+                    .expect("Row Group index should be in bounds")
+            })
+            .collect::<Vec<_>>();
+        let indexes = try_join_all(row_groups.iter().map(|row_group| {
+            let range = pruner.index_range(row_group);
+            let io_client = io_client.clone();
+            let io_stats = io_stats.clone();
+            let uri = self.uri.clone();
+            async move {
+                let Some(range) = range else {
+                    return DaftResult::Ok(None);
+                };
+                let bytes = io_client
+                    .single_url_get(uri, Some(GetRange::Bounded(range.clone())), io_stats)
+                    .await?
+                    .bytes()
+                    .await?;
+                Ok(Some(FetchedRange::new(range.start, bytes)))
+            }
+        }))
+        .await?;
+        let row_ranges = self
+            .row_ranges
+            .iter()
+            .zip(row_groups)
+            .zip(indexes)
+            .filter_map(|((row_range, row_group), index)| {
+                index
+                    .is_none_or(|mut index| {
+                        pruner.may_satisfy(
+                            &self.uri,
+                            row_range.row_group_index,
+                            row_group,
+                            &mut index,
+                        )
+                    })
+                    .then_some(*row_range)
+            })
+            .collect();
+        self.row_ranges = Arc::new(row_ranges);
+        Ok(self)
+    }
+
     fn naive_read_plan(&self) -> super::Result<ReadPlanner> {
         let arrow_fields = &self.arrow_schema.fields;
 
@@ -404,10 +528,12 @@ impl ParquetFileReader {
         original_num_rows: Option<usize>,
         delete_rows: Option<Vec<i64>>,
     ) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
-        let daft_schema = Arc::new(self.arrow_schema.as_ref().into());
+        let daft_schema: SchemaRef = Arc::new(self.arrow_schema.as_ref().into());
 
-        let num_parallel_tasks = determine_parquet_parallelism(&daft_schema);
+        let num_parallel_tasks =
+            determine_parquet_parallelism(&daft_schema).min(self.max_row_group_concurrency);
         let semaphore = Arc::new(tokio::sync::Semaphore::new(num_parallel_tasks));
+        let decompression_context = FileDecompressionContext::new();
 
         let (senders, receivers): (Vec<_>, Vec<_>) = self
             .row_ranges
@@ -417,13 +543,32 @@ impl ParquetFileReader {
 
         let uri = self.uri.clone();
         let chunk_size = self.chunk_size.unwrap_or(Self::DEFAULT_CHUNK_SIZE);
+        let selections = self
+            .row_ranges
+            .iter()
+            .map(|row_range| {
+                RowSelection::try_new(
+                    predicate.as_ref(),
+                    &daft_schema,
+                    self.metadata
+                        .row_groups
+                        .get(&row_range.row_group_index)
+                        .expect("Row Group index should be in bounds"),
+                    row_range,
+                    delete_rows.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
         let chunk_iter_handles = <Vec<RowGroupRange> as Clone>::clone(&self.row_ranges)
             .into_iter()
-            .map(move |row_range| {
+            .zip(selections)
+            .map(move |(row_range, selection)| {
                 let metadata = self.metadata.clone();
                 let arrow_schema = self.arrow_schema.clone();
                 let ranges = ranges.clone();
                 let uri = uri.clone();
+                let decompression_context = decompression_context.clone();
+                let task_selection = selection.clone();
 
                 let chunk_iter_task = tokio::task::spawn(async move {
                     let arr_iter_handles = arrow_schema.fields.iter().map(|field| {
@@ -432,6 +577,8 @@ impl ParquetFileReader {
                         let uri = uri.clone();
                         let field = field.clone();
                         let metadata = metadata.clone();
+                        let decompression_context = decompression_context.clone();
+                        let pages_filter = page_filter(task_selection.as_ref(), &field.name);
 
                         tokio::task::spawn(async move {
                             let rg = metadata
@@ -463,7 +610,7 @@ impl ParquetFileReader {
                                         col,
                                         range_reader,
                                         vec![],
-                                        Arc::new(|_, _| true),
+                                        pages_filter.clone(),
                                         Self::MAX_PAGE_SIZE,
                                     )
                                     .with_context(|_| {
@@ -471,10 +618,16 @@ impl ParquetFileReader {
                                             path: uri.clone(),
                                         }
                                     })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
+                                let page_stream = streaming_decompression(
+                                    compressed_page_stream,
+                                    decompression_context.clone(),
+                                );
                                 let pinned_stream = Box::pin(page_stream);
-                                decompressed_iters
-                                    .push(StreamIterator::new(pinned_stream, rt_handle.clone()));
+                                decompressed_iters.push(StreamIterator::new(
+                                    pinned_stream,
+                                    rt_handle.clone(),
+                                    decompression_context.clone(),
+                                ));
                             }
                             let arr_iter = column_iter_to_arrays(
                                 decompressed_iters,
@@ -496,14 +649,16 @@ impl ParquetFileReader {
 
                     DaftResult::Ok(arr_iters)
                 });
-                (row_range, chunk_iter_task)
+                (row_range, selection, chunk_iter_task)
             });
 
         let io_runtime = get_io_runtime(true);
         let uri = self.uri.clone();
         let parquet_task = io_runtime.spawn(async move {
             let mut table_tasks = Vec::with_capacity(chunk_iter_handles.len());
-            for ((row_range, chunk_iter_handle), output_sender) in chunk_iter_handles.zip(senders) {
+            for ((row_range, selection, chunk_iter_handle), output_sender) in
+                chunk_iter_handles.zip(senders)
+            {
                 // We want to ensure that the channel capacity can hold one morsel worth of data for better deserialization performance.
                 let channel_size = {
                     let chunks_per_morsel = max(PARQUET_MORSEL_SIZE / chunk_size, 1);
@@ -519,6 +674,7 @@ impl ParquetFileReader {
                 let table_task = spawn_column_iters_to_table_task(
                     chunk_iter,
                     row_range,
+                    selection,
                     daft_schema.clone(),
                     uri.clone(),
                     predicate.clone(),
@@ -555,6 +711,9 @@ impl ParquetFileReader {
         ranges: Arc<RangesContainer>,
     ) -> DaftResult<RecordBatch> {
         let metadata = self.metadata;
+        let decompression_context = FileDecompressionContext::new();
+        let row_group_permits =
+            RowGroupPermit::for_row_ranges(self.max_row_group_concurrency, self.row_ranges.len());
         let all_handles = self
             .arrow_schema
             .fields
@@ -564,7 +723,8 @@ impl ParquetFileReader {
 
                 let field_handles = owned_row_ranges
                     .iter()
-                    .map(|row_range| {
+                    .zip(row_group_permits.iter())
+                    .map(|(row_range, permit)| {
                         let row_range = *row_range;
                         let rt_handle = tokio::runtime::Handle::current();
                         let field = field.clone();
@@ -597,6 +757,8 @@ impl ParquetFileReader {
                             .collect::<Vec<_>>();
 
                         let ranges = ranges.clone();
+                        let decompression_context = decompression_context.clone();
+                        let permit = permit.clone();
 
                         let handle = tokio::task::spawn(async move {
                             let mut range_readers = Vec::with_capacity(filtered_cols_idx.len());
@@ -636,13 +798,20 @@ impl ParquetFileReader {
                                             path: owned_uri.clone(),
                                         }
                                     })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
+                                let page_stream = streaming_decompression(
+                                    compressed_page_stream,
+                                    decompression_context.clone(),
+                                );
                                 let pinned_stream = Box::pin(page_stream);
-                                decompressed_iters
-                                    .push(StreamIterator::new(pinned_stream, rt_handle.clone()));
+                                decompressed_iters.push(StreamIterator::new(
+                                    pinned_stream,
+                                    rt_handle.clone(),
+                                    decompression_context.clone(),
+                                ));
                             }
 
                             let (send, recv) = tokio::sync::oneshot::channel();
+                            permit.acquire().await;
                             rayon::spawn(move || {
                                 let arr_iter = column_iter_to_arrays(
                                     decompressed_iters,
@@ -677,6 +846,7 @@ impl ParquetFileReader {
                                         .collect::<DaftResult<Vec<Series>>>()
                                 })();
 
+                                drop(permit);
                                 let _ = send.send(series);
                             });
                             recv.await.context(OneShotRecvSnafu {})?
@@ -713,6 +883,7 @@ impl ParquetFileReader {
                 Ok(concated_handle)
             })
             .collect::<DaftResult<Vec<_>>>()?;
+        drop(row_group_permits);
 
         let all_series = try_join_all(all_handles)
             .await
@@ -734,6 +905,9 @@ impl ParquetFileReader {
         ranges: Arc<RangesContainer>,
     ) -> DaftResult<(Vec<Vec<Box<dyn daft_arrow::array::Array>>>, usize)> {
         let metadata = self.metadata;
+        let decompression_context = FileDecompressionContext::new();
+        let row_group_permits =
+            RowGroupPermit::for_row_ranges(self.max_row_group_concurrency, self.row_ranges.len());
         let all_handles = self
             .arrow_schema
             .fields
@@ -743,7 +917,8 @@ impl ParquetFileReader {
 
                 let field_handles = owned_row_ranges
                     .iter()
-                    .map(|row_range| {
+                    .zip(row_group_permits.iter())
+                    .map(|(row_range, permit)| {
                         let row_range = *row_range;
                         let rt_handle = tokio::runtime::Handle::current();
                         let field = field.clone();
@@ -774,6 +949,8 @@ impl ParquetFileReader {
                             .collect::<Vec<_>>();
                         let metadata = metadata.clone();
                         let ranges = ranges.clone();
+                        let decompression_context = decompression_context.clone();
+                        let permit = permit.clone();
                         let handle = tokio::task::spawn(async move {
                             let mut range_readers = Vec::with_capacity(filtered_cols_idx.len());
 
@@ -813,13 +990,20 @@ impl ParquetFileReader {
                                             path: owned_uri.clone(),
                                         }
                                     })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
+                                let page_stream = streaming_decompression(
+                                    compressed_page_stream,
+                                    decompression_context.clone(),
+                                );
                                 let pinned_stream = Box::pin(page_stream);
-                                decompressed_iters
-                                    .push(StreamIterator::new(pinned_stream, rt_handle.clone()));
+                                decompressed_iters.push(StreamIterator::new(
+                                    pinned_stream,
+                                    rt_handle.clone(),
+                                    decompression_context.clone(),
+                                ));
                             }
 
                             let (send, recv) = tokio::sync::oneshot::channel();
+                            permit.acquire().await;
                             rayon::spawn(move || {
                                 let arr_iter = column_iter_to_arrays(
                                     decompressed_iters,
@@ -851,6 +1035,7 @@ impl ParquetFileReader {
                                     Ok(all_arrays)
                                 })();
 
+                                drop(permit);
                                 let _ = send.send(ser);
                             });
                             recv.await.context(OneShotRecvSnafu {})?
@@ -870,6 +1055,7 @@ impl ParquetFileReader {
                 Ok(array_handle)
             })
             .collect::<DaftResult<Vec<_>>>()?;
+        drop(row_group_permits);
 
         let all_field_arrays = try_join_all(all_handles)
             .await
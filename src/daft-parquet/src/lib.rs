@@ -6,14 +6,17 @@ use daft_arrow::io::parquet::read::schema::{SchemaInferenceOptions, infer_schema
 use daft_core::{prelude::SchemaRef, utils::arrow::coerce_to_daft_compatible_schema};
 use snafu::Snafu;
 
+mod decompression;
 mod file;
 pub mod metadata;
+mod page_index;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
 mod statistics;
 pub use statistics::row_group_metadata_to_table_stats;
 mod read_planner;
+mod row_selection;
 mod stream_reader;
 
 #[cfg(feature = "python")]
@@ -35,6 +38,21 @@ fn determine_parquet_parallelism(daft_schema: &SchemaRef) -> usize {
         .ceil() as usize
 }
 
+// This function determines the maximum number of row groups of a single file to decode at once, which bounds the
+// memory used by a large file while its row groups decode in parallel. It defaults to the number of cores available,
+// and can be overridden with the `DAFT_PARQUET_MAX_ROW_GROUP_CONCURRENCY` environment variable.
+fn default_max_row_group_concurrency() -> usize {
+    std::env::var("DAFT_PARQUET_MAX_ROW_GROUP_CONCURRENCY")
+        .ok()
+        .and_then(|val| val.parse::<usize>().ok())
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .unwrap_or(NonZeroUsize::new(2).unwrap())
+                .get()
+        })
+        .max(1)
+}
+
 pub fn infer_arrow_schema_from_metadata(
     metadata: &parquet2::metadata::FileMetaData,
     options: Option<SchemaInferenceOptions>,
@@ -0,0 +1,223 @@
+//! Pruning of row groups with the page indexes of Parquet files.
+//!
+//! The statistics of a row group can include values which none of its rows have, e.g. a row group whose values are
+//! all either below or above the value that a predicate looks for. The page index of a column holds the statistics of
+//! each of its pages, so a row group can be skipped when none of its pages can satisfy the predicate.
+
+use std::{
+    io::{Cursor, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_arrow::{
+    array::Array,
+    datatypes::Field as ArrowField,
+    io::parquet::read::indexes::{FieldPageStatistics, read_columns_indexes},
+};
+use daft_core::prelude::*;
+use daft_dsl::{ExprRef, expr::bound_expr::BoundExpr, optimization::get_required_columns};
+use daft_stats::{
+    ColumnRangeStatistics, TableStatistics, TruthValue,
+    pruning::{PruneReason, record_pruned},
+};
+use parquet2::metadata::{ColumnChunkMetaData, FileMetaData, RowGroupMetaData};
+
+use crate::file::RowGroupRange;
+
+/// Prunes the row groups in which no page of the column that a predicate reads can satisfy it.
+///
+/// Only predicates which read a single, non-nested column are evaluated against page indexes.
+pub(crate) struct PageIndexPruner {
+    predicate: BoundExpr,
+    field: ArrowField,
+    schema: SchemaRef,
+}
+
+impl PageIndexPruner {
+    /// Returns a pruner for a predicate if it reads a single column of `arrow_schema`.
+    pub fn try_new(
+        predicate: &ExprRef,
+        arrow_schema: &daft_arrow::datatypes::Schema,
+    ) -> Option<Self> {
+        let mut columns = get_required_columns(predicate);
+        columns.sort();
+        columns.dedup();
+        let [column] = columns.try_into().ok()?;
+        let field = arrow_schema
+            .fields
+            .iter()
+            .find(|f| f.name == column)?
+            .clone();
+        let schema = Arc::new(Schema::new(vec![Field::from(&field)]));
+        let predicate = BoundExpr::try_new(predicate.clone(), &schema).ok()?;
+        Some(Self {
+            predicate,
+            field,
+            schema,
+        })
+    }
+
+    fn column<'a>(&self, row_group: &'a RowGroupMetaData) -> Option<&'a ColumnChunkMetaData> {
+        match row_group
+            .columns()
+            .iter()
+            .filter(|c| c.descriptor().path_in_schema[0] == self.field.name)
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [column] if column.descriptor().path_in_schema.len() == 1 => Some(column),
+            _ => None,
+        }
+    }
+
+    /// Returns the byte range of the page index of the column in a row group, if it has one.
+    pub fn index_range(&self, row_group: &RowGroupMetaData) -> Option<Range<usize>> {
+        let chunk = self.column(row_group)?.column_chunk();
+        let start = usize::try_from(chunk.column_index_offset?).ok()?;
+        let len = usize::try_from(chunk.column_index_length?).ok()?;
+        Some(start..start + len)
+    }
+
+    /// Returns whether any page of a row group may satisfy the predicate, recording the pages of the row group if none
+    /// can. `index` reads the page index of the column, at its offset in the file.
+    ///
+    /// Page indexes which can't be read or evaluated don't prune anything.
+    pub fn may_satisfy(
+        &self,
+        uri: &str,
+        row_group_index: usize,
+        row_group: &RowGroupMetaData,
+        index: &mut (impl Read + Seek),
+    ) -> bool {
+        let Some(column) = self.column(row_group) else {
+            return true;
+        };
+        match self.num_pruned_pages(column, index) {
+            Ok(Some(num_pages)) => {
+                for page in 0..num_pages {
+                    record_pruned(
+                        uri,
+                        Some(row_group_index),
+                        Some(page),
+                        PruneReason::PageIndex,
+                        self.predicate.inner(),
+                    );
+                }
+                false
+            }
+            Ok(None) => true,
+            Err(err) => {
+                log::debug!(
+                    "Unable to prune row group {row_group_index} of {uri} with its page index: {err}"
+                );
+                true
+            }
+        }
+    }
+
+    /// Returns the number of pages of the column if none of them can satisfy the predicate.
+    fn num_pruned_pages(
+        &self,
+        column: &ColumnChunkMetaData,
+        index: &mut (impl Read + Seek),
+    ) -> DaftResult<Option<usize>> {
+        let mut statistics = read_columns_indexes(
+            index,
+            std::slice::from_ref(column),
+            std::slice::from_ref(&self.field),
+        )?;
+        let Some(FieldPageStatistics::Single(statistics)) = statistics.pop() else {
+            return Ok(None);
+        };
+        let dtype = &self.schema[0].dtype;
+        for page in 0..statistics.min.len() {
+            if statistics.min.is_null(page) || statistics.max.is_null(page) {
+                // The page only has nulls, which its statistics don't describe.
+                return Ok(None);
+            }
+            let bound = |values: &Box<dyn Array>| {
+                Series::try_from((self.field.name.as_str(), values.sliced(page, 1)))?.cast(dtype)
+            };
+            let page_statistics = TableStatistics::new(
+                vec![ColumnRangeStatistics::new(
+                    Some(bound(&statistics.min)?),
+                    Some(bound(&statistics.max)?),
+                )?],
+                self.schema.clone(),
+            );
+            if page_statistics
+                .eval_expression(&self.predicate)?
+                .to_truth_value()
+                != TruthValue::False
+            {
+                return Ok(None);
+            }
+        }
+        Ok(Some(statistics.min.len()))
+    }
+}
+
+/// Reads the bytes of a range of a file which were fetched into memory, at their offsets in the file.
+pub(crate) struct FetchedRange {
+    offset: u64,
+    bytes: Cursor<bytes::Bytes>,
+}
+
+impl FetchedRange {
+    pub fn new(offset: usize, bytes: bytes::Bytes) -> Self {
+        Self {
+            offset: offset as u64,
+            bytes: Cursor::new(bytes),
+        }
+    }
+}
+
+impl Read for FetchedRange {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.bytes.read(buf)
+    }
+}
+
+impl Seek for FetchedRange {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "Seeking before the fetched range",
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.bytes.seek(pos)? + self.offset)
+    }
+}
+
+/// Skips the row ranges of a local file whose row groups can't satisfy a predicate according to their page indexes.
+pub(crate) fn prune_local_row_ranges(
+    row_ranges: Vec<RowGroupRange>,
+    predicate: Option<&ExprRef>,
+    arrow_schema: &daft_arrow::datatypes::Schema,
+    metadata: &FileMetaData,
+    uri: &str,
+    reader: &mut (impl Read + Seek),
+) -> Vec<RowGroupRange> {
+    let Some(pruner) = predicate.and_then(|p| PageIndexPruner::try_new(p, arrow_schema)) else {
+        return row_ranges;
+    };
+    row_ranges
+        .into_iter()
+        .filter(|row_range| {
+            let row_group = metadata
+                .row_groups
+                .get(&row_range.row_group_index)
+                .expect("Row Group index should be in bounds");
+            pruner.index_range(row_group).is_none()
+                || pruner.may_satisfy(uri, row_range.row_group_index, row_group, reader)
+        })
+        .collect()
+}
@@ -236,6 +236,14 @@ async fn read_parquet_single(
         let builder = builder.set_chunk_size(chunk_size);
 
         let parquet_reader = builder.build()?;
+        let parquet_reader = match &predicate {
+            Some(predicate) => {
+                parquet_reader
+                    .prune_with_page_index(predicate, io_client.clone(), io_stats.clone())
+                    .await?
+            }
+            None => parquet_reader,
+        };
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
             Arc::new(metadata),
@@ -467,6 +475,14 @@ async fn stream_parquet_single(
         };
 
         let parquet_reader = builder.build()?;
+        let parquet_reader = match &predicate {
+            Some(predicate) => {
+                parquet_reader
+                    .prune_with_page_index(predicate, io_client.clone(), io_stats.clone())
+                    .await?
+            }
+            None => parquet_reader,
+        };
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
             Arc::new(metadata),
@@ -0,0 +1,252 @@
+//! Selection of the rows of a row group that a predicate selects, for late materialization.
+//!
+//! When a predicate reads only some of the columns of a row group, those columns are decoded first and the predicate
+//! is evaluated on them. The pages of the other columns are then only decoded if they hold any of the selected rows:
+//! each of those columns gets a page filter, which skips the pages whose rows aren't selected before they are
+//! decompressed, and records the rows of the pages which it kept, so that the selected rows can be found among the
+//! decoded ones.
+
+use std::{
+    collections::HashSet,
+    io::{Read, Seek},
+    ops::Range,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use daft_arrow::{
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::Field as ArrowField,
+    io::parquet::read::{
+        self, BasicDecompressor, PageFilter, PageReader, RowGroupMetaData, column_iter_to_arrays,
+    },
+};
+use daft_core::prelude::*;
+use daft_dsl::{ExprRef, optimization::get_required_columns};
+
+use crate::{file::RowGroupRange, read::ArrowChunkIters};
+
+/// The rows of the pages of a column which were kept by its page filter.
+#[derive(Default)]
+struct DecodedPages {
+    /// The first row of the next page.
+    next_row: usize,
+    /// The rows of the kept pages, in order.
+    rows: Vec<Range<usize>>,
+}
+
+pub(crate) struct RowSelection {
+    /// The number of rows of the row group which are read.
+    num_rows: usize,
+    /// The names of the columns of the row group which are read.
+    columns: Vec<String>,
+    /// The columns read by the predicate, which are decoded in full.
+    filter_columns: Vec<usize>,
+    /// The rows that the predicate selects, once the filter columns have been decoded.
+    selected: OnceLock<Bitmap>,
+    /// The pages kept of each column whose pages are skipped, or `None` for columns whose pages are all decoded.
+    decoded_pages: Vec<Option<Mutex<DecodedPages>>>,
+}
+
+impl RowSelection {
+    /// Returns a selection for reading a row group with late materialization, if the predicate reads some but not all
+    /// of the columns of `schema`.
+    ///
+    /// Only row groups which are read from their first row and have no deleted rows are read with late
+    /// materialization, and only pages of flat columns are skipped, as the rows of their pages are their values.
+    pub fn try_new(
+        predicate: Option<&ExprRef>,
+        schema: &Schema,
+        row_group: &RowGroupMetaData,
+        rg_range: &RowGroupRange,
+        delete_rows: Option<&[i64]>,
+    ) -> Option<Arc<Self>> {
+        let predicate = predicate?;
+        if rg_range.start != 0 || delete_rows.is_some_and(|rows| !rows.is_empty()) {
+            return None;
+        }
+        let required = get_required_columns(predicate)
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let filter_columns = schema
+            .field_names()
+            .enumerate()
+            .filter(|(_, name)| required.contains(*name))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if filter_columns.is_empty() || filter_columns.len() == schema.len() {
+            return None;
+        }
+        let decoded_pages = schema
+            .field_names()
+            .enumerate()
+            .map(|(i, name)| {
+                let is_flat = match row_group
+                    .columns()
+                    .iter()
+                    .filter(|c| c.descriptor().path_in_schema[0] == name)
+                    .collect::<Vec<_>>()
+                    .as_slice()
+                {
+                    [column] => {
+                        column.descriptor().path_in_schema.len() == 1
+                            && column.descriptor().descriptor.max_rep_level == 0
+                    }
+                    _ => false,
+                };
+                (is_flat && !filter_columns.contains(&i)).then(Mutex::default)
+            })
+            .collect();
+        Some(Arc::new(Self {
+            num_rows: rg_range.num_rows,
+            columns: schema.field_names().map(String::from).collect(),
+            filter_columns,
+            selected: OnceLock::new(),
+            decoded_pages,
+        }))
+    }
+
+    pub fn is_filter_column(&self, column: usize) -> bool {
+        self.filter_columns.contains(&column)
+    }
+
+    /// Sets the rows that the predicate selects, which must be done before the other columns are decoded.
+    pub fn select(&self, selected: Bitmap) {
+        let _ = self.selected.set(selected);
+    }
+
+    fn is_selected(&self, row: usize) -> bool {
+        self.selected
+            .get()
+            .is_some_and(|selected| row < selected.len() && selected.get_bit(row))
+    }
+
+    /// Returns whether any row of a page may be selected. Pages read before the rows are selected are kept.
+    fn may_select(&self, rows: &Range<usize>) -> bool {
+        let end = rows.end.min(self.num_rows);
+        if rows.start >= end {
+            return false;
+        }
+        match self.selected.get() {
+            Some(selected) => {
+                let end = end.min(selected.len());
+                rows.start < end
+                    && selected.null_count_range(rows.start, end - rows.start) < end - rows.start
+            }
+            None => true,
+        }
+    }
+
+    /// Returns the page filter of a column, which skips the pages whose rows aren't selected.
+    pub fn page_filter(self: &Arc<Self>, name: &str) -> PageFilter {
+        let Some(column) = self
+            .columns
+            .iter()
+            .position(|c| c == name)
+            .filter(|column| self.decoded_pages[*column].is_some())
+        else {
+            return Arc::new(|_, _| true);
+        };
+        let selection = self.clone();
+        Arc::new(move |_, header| {
+            let mut pages = selection.decoded_pages[column]
+                .as_ref()
+                .expect("Only columns whose pages are skipped have page filters")
+                .lock()
+                .unwrap();
+            let rows = pages.next_row..pages.next_row + header.num_values();
+            pages.next_row = rows.end;
+            if !selection.may_select(&rows) {
+                return false;
+            }
+            match pages.rows.last_mut() {
+                Some(last) if last.end == rows.start => last.end = rows.end,
+                _ => pages.rows.push(rows),
+            }
+            true
+        })
+    }
+
+    /// Returns the mask of the selected rows among `len` rows of a column, starting at its `offset`-th decoded row.
+    pub fn decoded_mask(
+        &self,
+        column: usize,
+        offset: usize,
+        len: usize,
+        uri: &str,
+    ) -> super::Result<BooleanArray> {
+        let mut mask = MutableBitmap::with_capacity(len);
+        match &self.decoded_pages[column] {
+            None => (offset..offset + len).for_each(|row| mask.push(self.is_selected(row))),
+            Some(pages) => {
+                // The decoded rows of the column are the rows of its kept pages, one after the other.
+                let mut decoded = 0;
+                for rows in &pages.lock().unwrap().rows {
+                    let start = offset.max(decoded);
+                    let end = (offset + len).min(decoded + rows.len());
+                    (start..end)
+                        .for_each(|i| mask.push(self.is_selected(rows.start + i - decoded)));
+                    decoded += rows.len();
+                }
+            }
+        }
+        if mask.len() != len {
+            return Err(super::Error::ParquetColumnsDontHaveEqualRows {
+                path: uri.to_string(),
+            });
+        }
+        Ok(("selection_mask", Bitmap::from(mask)).into())
+    }
+}
+
+/// Returns the page filter of a column of a row group, which keeps every page unless it's read with late
+/// materialization.
+pub(crate) fn page_filter(selection: Option<&Arc<RowSelection>>, name: &str) -> PageFilter {
+    selection.map_or_else(
+        || Arc::new(|_, _| true) as PageFilter,
+        |s| s.page_filter(name),
+    )
+}
+
+/// Reads the columns of a row group of a local file into memory, returning iterators which decompress and deserialize
+/// the pages of each column that `selection` keeps.
+pub(crate) fn read_columns_with_selection<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: &[ArrowField],
+    chunk_size: usize,
+    num_rows: usize,
+    selection: &Arc<RowSelection>,
+) -> daft_arrow::error::Result<ArrowChunkIters> {
+    fields
+        .iter()
+        .map(|field| {
+            let columns = read::read_columns(reader, row_group.columns(), &field.name)?;
+            let mut pages = Vec::with_capacity(columns.len());
+            let mut ptypes = Vec::with_capacity(columns.len());
+            let mut num_values = Vec::with_capacity(columns.len());
+            for (column, chunk) in columns {
+                let len = chunk.len();
+                num_values.push(column.num_values() as usize);
+                ptypes.push(&column.descriptor().descriptor.primitive_type);
+                pages.push(BasicDecompressor::new(
+                    PageReader::new(
+                        std::io::Cursor::new(chunk),
+                        column,
+                        selection.page_filter(&field.name),
+                        vec![],
+                        len * 2 + 1024,
+                    ),
+                    vec![],
+                ));
+            }
+            column_iter_to_arrays(
+                pages,
+                ptypes,
+                field.clone(),
+                Some(chunk_size.min(num_rows)),
+                num_rows,
+                num_values,
+            )
+        })
+        .collect()
+}
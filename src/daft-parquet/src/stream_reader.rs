@@ -2,7 +2,10 @@ use std::{cmp::max, collections::HashSet, fs::File, sync::Arc};
 
 use common_error::DaftResult;
 use common_runtime::{RuntimeTask, combine_stream, get_compute_runtime};
-use daft_arrow::{bitmap::Bitmap, io::parquet::read};
+use daft_arrow::{
+    bitmap::{Bitmap, MutableBitmap},
+    io::parquet::read,
+};
 use daft_core::prelude::*;
 use daft_dsl::{ExprRef, expr::bound_expr::BoundExpr};
 use daft_io::{CountingReader, IOStatsRef};
@@ -20,7 +23,9 @@ use crate::{
     PARQUET_MORSEL_SIZE, determine_parquet_parallelism,
     file::{RowGroupRange, build_row_ranges},
     infer_arrow_schema_from_metadata,
+    page_index::prune_local_row_ranges,
     read::{ArrowChunk, ArrowChunkIters, ParquetSchemaInferenceOptions},
+    row_selection::{RowSelection, read_columns_with_selection},
 };
 
 fn prune_fields_from_schema(
@@ -134,22 +139,15 @@ fn arrow_chunk_to_table(
     Ok(table)
 }
 
-/// Spawns a task that reads the column iterators and converts them into a table.
-#[allow(clippy::too_many_arguments)]
-pub fn spawn_column_iters_to_table_task(
+type ArrowChunkReceivers = Vec<tokio::sync::mpsc::Receiver<Box<dyn daft_arrow::array::Array>>>;
+
+/// Spawns a task per column iterator on the compute runtime that deserializes its chunks, returning the receivers of
+/// the chunks and the handles of the tasks.
+fn spawn_deserializers(
     arr_iters: ArrowChunkIters,
-    rg_range: RowGroupRange,
-    schema_ref: SchemaRef,
-    uri: String,
-    predicate: Option<ExprRef>,
-    original_columns: Option<Vec<String>>,
-    original_num_rows: Option<usize>,
-    delete_rows: Option<Vec<i64>>,
-    output_sender: tokio::sync::mpsc::Sender<DaftResult<RecordBatch>>,
-    permit: tokio::sync::OwnedSemaphorePermit,
     channel_size: usize,
-) -> RuntimeTask<DaftResult<()>> {
-    let (arrow_chunk_senders, mut arrow_chunk_receivers): (Vec<_>, Vec<_>) = arr_iters
+) -> (ArrowChunkReceivers, Vec<RuntimeTask<DaftResult<()>>>) {
+    let (arrow_chunk_senders, arrow_chunk_receivers): (Vec<_>, Vec<_>) = arr_iters
         .iter()
         .map(|_| tokio::sync::mpsc::channel(channel_size))
         .unzip();
@@ -171,7 +169,57 @@ pub fn spawn_column_iters_to_table_task(
             compute_runtime.spawn(deserialization_task)
         })
         .collect::<Vec<_>>();
+    (arrow_chunk_receivers, deserializer_handles)
+}
+
+/// Receives the next chunk of every column, or `None` once any of the columns has no more chunks.
+async fn recv_chunk(receivers: &mut ArrowChunkReceivers) -> Option<ArrowChunk> {
+    let chunk = futures::future::join_all(receivers.iter_mut().map(|s| s.recv()))
+        .await
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    (chunk.len() == receivers.len()).then_some(chunk)
+}
+
+/// Spawns a task that reads the column iterators and converts them into a table, with late materialization if the
+/// row group has a [`RowSelection`].
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_column_iters_to_table_task(
+    arr_iters: ArrowChunkIters,
+    rg_range: RowGroupRange,
+    selection: Option<Arc<RowSelection>>,
+    schema_ref: SchemaRef,
+    uri: String,
+    predicate: Option<ExprRef>,
+    original_columns: Option<Vec<String>>,
+    original_num_rows: Option<usize>,
+    delete_rows: Option<Vec<i64>>,
+    output_sender: tokio::sync::mpsc::Sender<DaftResult<RecordBatch>>,
+    permit: tokio::sync::OwnedSemaphorePermit,
+    channel_size: usize,
+) -> RuntimeTask<DaftResult<()>> {
+    if let Some(selection) = selection
+        && let Some(predicate) = &predicate
+    {
+        return spawn_late_materialized_table_task(
+            arr_iters,
+            selection,
+            schema_ref,
+            uri,
+            predicate.clone(),
+            original_columns,
+            original_num_rows,
+            output_sender,
+            permit,
+            channel_size,
+        );
+    }
 
+    let (mut arrow_chunk_receivers, deserializer_handles) =
+        spawn_deserializers(arr_iters, channel_size);
+
+    let compute_runtime = get_compute_runtime();
     compute_runtime.spawn(async move {
         if deserializer_handles.is_empty() {
             let empty = RecordBatch::new_with_size(schema_ref.clone(), vec![], rg_range.num_rows);
@@ -183,16 +231,7 @@ pub fn spawn_column_iters_to_table_task(
         // Keep track of the current index in the row group so we can throw away arrays that are not needed
         // and slice arrays that are partially needed.
         let mut index_so_far = 0;
-        loop {
-            let chunk =
-                futures::future::join_all(arrow_chunk_receivers.iter_mut().map(|s| s.recv()))
-                    .await
-                    .into_iter()
-                    .flatten()
-                    .collect::<Vec<_>>();
-            if chunk.len() != deserializer_handles.len() {
-                break;
-            }
+        while let Some(chunk) = recv_chunk(&mut arrow_chunk_receivers).await {
             let table = arrow_chunk_to_table(
                 chunk,
                 &schema_ref,
@@ -221,6 +260,150 @@ pub fn spawn_column_iters_to_table_task(
     })
 }
 
+/// Spawns a task that reads a row group with late materialization.
+///
+/// The columns read by the predicate are decoded first, for the whole row group, and the predicate is evaluated on
+/// each of their chunks. The other columns are only decoded if the predicate selects any rows of the row group, and
+/// then only the pages of them which hold selected rows are decoded, whose chunks are filtered as they are decoded.
+#[allow(clippy::too_many_arguments)]
+fn spawn_late_materialized_table_task(
+    arr_iters: ArrowChunkIters,
+    selection: Arc<RowSelection>,
+    schema_ref: SchemaRef,
+    uri: String,
+    predicate: ExprRef,
+    original_columns: Option<Vec<String>>,
+    original_num_rows: Option<usize>,
+    output_sender: tokio::sync::mpsc::Sender<DaftResult<RecordBatch>>,
+    permit: tokio::sync::OwnedSemaphorePermit,
+    channel_size: usize,
+) -> RuntimeTask<DaftResult<()>> {
+    let (filter_iters, other_iters): (Vec<_>, Vec<_>) = arr_iters
+        .into_iter()
+        .enumerate()
+        .partition(|(i, _)| selection.is_filter_column(*i));
+    let filter_schema = Arc::new(Schema::new(
+        filter_iters.iter().map(|(i, _)| schema_ref[*i].clone()),
+    ));
+    let filter_iters = filter_iters.into_iter().map(|(_, iter)| iter).collect();
+    let (other_columns, other_iters): (Vec<_>, Vec<_>) = other_iters.into_iter().unzip();
+    let output_columns =
+        original_columns.unwrap_or_else(|| schema_ref.field_names().map(String::from).collect());
+
+    let compute_runtime = get_compute_runtime();
+    compute_runtime.spawn(async move {
+        let predicate = BoundExpr::try_new(predicate, &filter_schema)?;
+        let (mut receivers, handles) = spawn_deserializers(filter_iters, channel_size);
+        let mut selected = MutableBitmap::new();
+        let mut filter_tables = vec![];
+        while let Some(chunk) = recv_chunk(&mut receivers).await {
+            let table = chunk_to_table(chunk, &filter_schema, &uri)?;
+            let mask = table.eval_expression(&predicate)?;
+            {
+                let mask = mask.bool()?;
+                (0..mask.len()).for_each(|i| selected.push(mask.get(i) == Some(true)));
+            }
+            filter_tables.push(table.mask_filter(&mask)?);
+        }
+        futures::future::try_join_all(handles)
+            .await?
+            .into_iter()
+            .collect::<DaftResult<()>>()?;
+        let filter_table = RecordBatch::concat_or_empty(&filter_tables, Some(filter_schema))?;
+
+        let project = |table: RecordBatch| -> DaftResult<RecordBatch> {
+            let indices = output_columns
+                .iter()
+                .map(|name| table.schema.get_index(name))
+                .collect::<DaftResult<Vec<_>>>()?;
+            let table = table.get_columns(&indices);
+            match original_num_rows {
+                Some(nr) => table.head(nr),
+                None => Ok(table),
+            }
+        };
+
+        if filter_table.is_empty() {
+            // No rows of the row group are selected, so the other columns don't need to be decoded at all.
+            drop(other_iters);
+            let empty = RecordBatch::empty(Some(schema_ref.clone()));
+            let _ = output_sender.send(project(empty)).await;
+            drop(permit);
+            return Ok(());
+        }
+
+        // The pages of the other columns are filtered as they are read, which must only start once the rows are
+        // selected.
+        selection.select(selected.into());
+        let (receivers, handles) = spawn_deserializers(other_iters, channel_size);
+        let other_series =
+            futures::future::try_join_all(receivers.into_iter().zip(other_columns).map(
+                |(mut receiver, column)| {
+                    let selection = selection.clone();
+                    let field = schema_ref[column].clone();
+                    let uri = uri.clone();
+                    async move {
+                        let mut decoded = 0;
+                        let mut series = vec![];
+                        while let Some(arr) = receiver.recv().await {
+                            let arr = Series::try_from((field.name.as_str(), arr))?;
+                            let mask = selection.decoded_mask(column, decoded, arr.len(), &uri)?;
+                            decoded += arr.len();
+                            series.push(arr.filter(&mask)?);
+                        }
+                        if series.is_empty() {
+                            return DaftResult::Ok(Series::empty(&field.name, &field.dtype));
+                        }
+                        Series::concat(&series.iter().collect::<Vec<_>>())
+                    }
+                },
+            ))
+            .await?;
+        futures::future::try_join_all(handles)
+            .await?
+            .into_iter()
+            .collect::<DaftResult<()>>()?;
+
+        if other_series.iter().any(|s| s.len() != filter_table.len()) {
+            return Err(super::Error::ParquetColumnsDontHaveEqualRows { path: uri }.into());
+        }
+        let other_table = RecordBatch::new_with_size(
+            Schema::new(other_series.iter().map(|s| s.field().clone())),
+            other_series,
+            filter_table.len(),
+        )?;
+        let _ = output_sender
+            .send(project(filter_table.union(&other_table)?))
+            .await;
+        drop(permit);
+        DaftResult::Ok(())
+    })
+}
+
+/// Converts a chunk of arrays, one per field of `schema`, into a table.
+fn chunk_to_table(chunk: ArrowChunk, schema: &Schema, uri: &str) -> DaftResult<RecordBatch> {
+    let all_series = chunk
+        .into_iter()
+        .zip(schema.field_names())
+        .map(|(arr, name)| Series::try_from((name, arr)))
+        .collect::<DaftResult<Vec<_>>>()?;
+    let len = all_series.first().map_or(0, Series::len);
+    if all_series.iter().any(|s| s.len() != len) {
+        return Err(super::Error::ParquetColumnsDontHaveEqualRows {
+            path: uri.to_string(),
+        }
+        .into());
+    }
+    RecordBatch::new_with_size(
+        Schema::new(all_series.iter().map(|s| s.field().clone())),
+        all_series,
+        len,
+    )
+    .with_context(|_| super::UnableToCreateTableFromChunkSnafu {
+        path: uri.to_string(),
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 pub fn local_parquet_read_into_column_iters(
@@ -229,6 +412,7 @@ pub fn local_parquet_read_into_column_iters(
     num_rows: Option<usize>,
     row_groups: Option<Vec<i64>>,
     predicate: Option<ExprRef>,
+    delete_rows: Option<&[i64]>,
     schema_infer_options: ParquetSchemaInferenceOptions,
     metadata: Option<Arc<parquet2::metadata::FileMetaData>>,
     chunk_size: usize,
@@ -237,7 +421,7 @@ pub fn local_parquet_read_into_column_iters(
     Arc<parquet2::metadata::FileMetaData>,
     SchemaRef,
     Vec<RowGroupRange>,
-    impl Iterator<Item = super::Result<ArrowChunkIters>>,
+    impl Iterator<Item = super::Result<(ArrowChunkIters, Option<Arc<RowSelection>>)>> + use<>,
 )> {
     const LOCAL_PROTOCOL: &str = "file://";
     let uri = uri
@@ -280,33 +464,68 @@ pub fn local_parquet_read_into_column_iters(
         num_rows,
         0,
         row_groups.as_deref(),
-        predicate,
+        predicate.clone(),
         &daft_schema,
         &metadata,
         &uri,
     )?;
+    let row_ranges = prune_local_row_ranges(
+        row_ranges,
+        predicate.as_ref(),
+        &schema,
+        &metadata,
+        &uri,
+        &mut reader,
+    );
 
     let all_row_groups = metadata.row_groups.clone();
+    let selections = row_ranges
+        .iter()
+        .map(|rg_range| {
+            RowSelection::try_new(
+                predicate.as_ref(),
+                &daft_schema,
+                all_row_groups.get(&rg_range.row_group_index).unwrap(),
+                rg_range,
+                delete_rows,
+            )
+        })
+        .collect::<Vec<_>>();
 
     // Read all the required row groups into memory sequentially
-    let column_iters_per_rg = row_ranges.clone().into_iter().map(move |rg_range| {
-        let rg_metadata = all_row_groups.get(&rg_range.row_group_index).unwrap();
-
-        // This operation is IO-bounded O(C) where C is the number of columns in the row group.
-        // It reads all the columns to memory from the row group associated to the requested fields,
-        // and returns a Vec of iterators that perform decompression and deserialization for each column.
-        let single_rg_column_iter = read::read_columns_many(
-            &mut reader,
-            rg_metadata,
-            schema.fields.clone(),
-            Some(chunk_size),
-            Some(rg_range.num_rows),
-            None,
-        )
-        .with_context(|_| super::UnableToReadParquetRowGroupSnafu { path: uri.clone() })?;
-        reader.update_count();
-        Ok(single_rg_column_iter)
-    });
+    let column_iters_per_rg =
+        row_ranges
+            .clone()
+            .into_iter()
+            .zip(selections)
+            .map(move |(rg_range, selection)| {
+                let rg_metadata = all_row_groups.get(&rg_range.row_group_index).unwrap();
+
+                // This operation is IO-bounded O(C) where C is the number of columns in the row group.
+                // It reads all the columns to memory from the row group associated to the requested fields,
+                // and returns a Vec of iterators that perform decompression and deserialization for each column.
+                let single_rg_column_iter = match &selection {
+                    Some(selection) => read_columns_with_selection(
+                        &mut reader,
+                        rg_metadata,
+                        &schema.fields,
+                        chunk_size,
+                        rg_range.num_rows.min(rg_metadata.num_rows()),
+                        selection,
+                    ),
+                    None => read::read_columns_many(
+                        &mut reader,
+                        rg_metadata,
+                        schema.fields.clone(),
+                        Some(chunk_size),
+                        Some(rg_range.num_rows),
+                        None,
+                    ),
+                }
+                .with_context(|_| super::UnableToReadParquetRowGroupSnafu { path: uri.clone() })?;
+                reader.update_count();
+                Ok((single_rg_column_iter, selection))
+            });
     Ok((
         metadata,
         Arc::new(daft_schema),
@@ -377,11 +596,19 @@ pub fn local_parquet_read_into_arrow(
         num_rows,
         start_offset.unwrap_or(0),
         row_groups,
-        predicate,
+        predicate.clone(),
         &daft_schema,
         &metadata,
         uri,
     )?;
+    let row_ranges = prune_local_row_ranges(
+        row_ranges,
+        predicate.as_ref(),
+        &schema,
+        &metadata,
+        uri,
+        &mut reader,
+    );
 
     let columns_iters_per_rg = row_ranges
         .iter()
@@ -538,6 +765,7 @@ pub async fn local_parquet_stream(
         num_rows,
         row_groups,
         predicate.clone(),
+        delete_rows.as_deref(),
         schema_infer_options,
         metadata,
         chunk_size,
@@ -564,10 +792,12 @@ pub async fn local_parquet_stream(
                 break;
             }
 
+            let (column_iters, selection) = column_iters.unwrap();
             let permit = semaphore.clone().acquire_owned().await.unwrap();
             let table_task = spawn_column_iters_to_table_task(
-                column_iters.unwrap(),
+                column_iters,
                 rg_range,
+                selection,
                 schema_ref.clone(),
                 uri.clone(),
                 predicate.clone(),
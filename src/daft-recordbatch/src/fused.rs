@@ -0,0 +1,1063 @@
+//! Fused evaluation of arithmetic and comparison heavy expressions.
+//!
+//! Evaluating an expression tree one node at a time materializes a full Series for every intermediate result, e.g.
+//! `(a + b) * c > d` allocates and writes three arrays before the one it returns. [`FusedExpr`] instead compiles such
+//! expressions into a small register program, which is run over the rows of a batch in chunks of [`CHUNK_SIZE`], so
+//! intermediate results stay in a few chunk-sized buffers that are reused across chunks.
+//!
+//! Only expressions whose result is exactly the same as evaluating them node by node are compiled:
+//! - numeric columns and literals, which are loaded as `f64`,
+//! - boolean columns and literals,
+//! - `+`, `-`, `*` and `/` whose result is `Float64`,
+//! - comparisons, when comparing as `f64` gives the same result as comparing as the supertype of the operands,
+//! - `&`, `|` and `~` with Kleene logic, `is_null`, `not_null` and casts to `Float64`,
+//! - `if_else`, `fill_null` and `coalesce`, whose values are selected row by row along with their validity, so chains
+//!   of them don't materialize a mask and a Series for every step.
+//!
+//! Programs are compiled once per projection by a [`FusedProjection`], rather than for every batch it's evaluated on.
+
+use std::sync::{Arc, LazyLock, OnceLock};
+
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_dsl::{
+    Column, Expr, ExprRef, Operator,
+    expr::{BoundColumn, bound_expr::BoundExpr},
+    functions::scalar::ScalarFn,
+};
+use num_traits::AsPrimitive;
+
+use crate::RecordBatch;
+
+/// Whether [`FusedProjection`]s evaluate the expressions they can compile with a [`FusedExpr`].
+pub(crate) static FUSED_EXPRESSIONS_ENABLED: LazyLock<bool> = LazyLock::new(|| {
+    std::env::var("DAFT_ENABLE_FUSED_EXPRESSIONS")
+        .is_ok_and(|val| matches!(val.trim().to_lowercase().as_str(), "1" | "true"))
+});
+
+/// The number of rows each instruction of a program is run over at once.
+const CHUNK_SIZE: usize = 1024;
+
+/// The minimum number of operators in an expression for it to be worth compiling.
+const MIN_OPERATORS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Float,
+    Bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Arithmetic {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// An instruction of a fused program. Registers are assigned in evaluation order, so an instruction only reads
+/// registers lower than the one it writes.
+#[derive(Debug, Clone)]
+enum Instruction {
+    Load {
+        source: usize,
+        dst: usize,
+    },
+    Float {
+        value: f64,
+        dst: usize,
+    },
+    Null {
+        dst: usize,
+    },
+    Bool {
+        value: bool,
+        dst: usize,
+    },
+    Arithmetic {
+        op: Arithmetic,
+        lhs: usize,
+        rhs: usize,
+        dst: usize,
+    },
+    Compare {
+        op: Comparison,
+        lhs: usize,
+        rhs: usize,
+        dst: usize,
+    },
+    And {
+        lhs: usize,
+        rhs: usize,
+        dst: usize,
+    },
+    Or {
+        lhs: usize,
+        rhs: usize,
+        dst: usize,
+    },
+    Not {
+        src: usize,
+        dst: usize,
+    },
+    IsNull {
+        src: usize,
+        dst: usize,
+    },
+    NotNull {
+        src: usize,
+        dst: usize,
+    },
+    Select {
+        predicate: usize,
+        if_true: usize,
+        if_false: usize,
+        dst: usize,
+    },
+    FillNull {
+        src: usize,
+        fill: usize,
+        dst: usize,
+    },
+}
+
+/// An expression compiled into a register program, see the [module docs](self).
+#[derive(Debug, Clone)]
+pub(crate) struct FusedExpr {
+    program: Vec<Instruction>,
+    /// The kind of each register.
+    registers: Vec<Kind>,
+    /// The columns of the record batch read by the program, in the order of the `Load` sources.
+    columns: Vec<usize>,
+    output: usize,
+    field: Field,
+}
+
+/// The expressions of a projection, along with the fused programs of those which can be compiled.
+///
+/// The programs are compiled against the schema of the first batch the projection is evaluated on, and reused for
+/// every batch after it, see [`RecordBatch::eval_projection_async`].
+#[derive(Debug)]
+pub struct FusedProjection {
+    exprs: Vec<BoundExpr>,
+    enabled: bool,
+    programs: OnceLock<(SchemaRef, Vec<Option<Arc<FusedExpr>>>)>,
+}
+
+impl FusedProjection {
+    pub fn new(exprs: Vec<BoundExpr>) -> Self {
+        Self {
+            exprs,
+            enabled: *FUSED_EXPRESSIONS_ENABLED,
+            programs: OnceLock::new(),
+        }
+    }
+
+    pub fn exprs(&self) -> &[BoundExpr] {
+        &self.exprs
+    }
+
+    /// Returns the program of each expression when evaluated on a batch with `schema`, or `None` for the expressions
+    /// which aren't compiled. Batches with another schema than the first one get programs compiled just for them.
+    pub(crate) fn programs(&self, schema: &SchemaRef) -> Vec<Option<Arc<FusedExpr>>> {
+        if !self.enabled {
+            return vec![None; self.exprs.len()];
+        }
+        let compile = || {
+            self.exprs
+                .iter()
+                .map(|expr| FusedExpr::try_compile(expr.inner(), schema).map(Arc::new))
+                .collect::<Vec<_>>()
+        };
+        let (compiled_schema, programs) = self.programs.get_or_init(|| (schema.clone(), compile()));
+        if Arc::ptr_eq(compiled_schema, schema) || compiled_schema == schema {
+            programs.clone()
+        } else {
+            compile()
+        }
+    }
+}
+
+/// A compiled subexpression: the register holding its values, with the type they were loaded from.
+struct Compiled {
+    register: usize,
+    kind: Kind,
+    dtype: DataType,
+    /// Whether every value of the subexpression is exactly representable as an `f64`.
+    exact: bool,
+}
+
+struct Compiler<'a> {
+    schema: &'a Schema,
+    program: Vec<Instruction>,
+    registers: Vec<Kind>,
+    columns: Vec<usize>,
+    operators: usize,
+}
+
+impl FusedExpr {
+    /// Compiles an expression bound to `schema`, returning `None` if it can't be compiled or isn't worth compiling.
+    pub fn try_compile(expr: &ExprRef, schema: &Schema) -> Option<Self> {
+        let field = expr.to_field(schema).ok()?;
+        if !matches!(field.dtype, DataType::Float64 | DataType::Boolean) {
+            return None;
+        }
+        let mut compiler = Compiler {
+            schema,
+            program: vec![],
+            registers: vec![],
+            columns: vec![],
+            operators: 0,
+        };
+        let output = compiler.compile(expr)?;
+        // Expressions without columns are evaluated to a single broadcastable row, which a program doesn't do.
+        if compiler.operators < MIN_OPERATORS || compiler.columns.is_empty() {
+            return None;
+        }
+        let expected_kind = if field.dtype == DataType::Boolean {
+            Kind::Bool
+        } else {
+            Kind::Float
+        };
+        (output.kind == expected_kind).then(|| Self {
+            program: compiler.program,
+            registers: compiler.registers,
+            columns: compiler.columns,
+            output: output.register,
+            field,
+        })
+    }
+
+    pub fn eval(&self, batch: &RecordBatch) -> DaftResult<Series> {
+        let sources = self
+            .columns
+            .iter()
+            .map(|&index| Source::try_new(batch.get_column(index)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mut registers = self
+            .registers
+            .iter()
+            .map(|&kind| Register::new(kind))
+            .collect::<Vec<_>>();
+
+        let num_rows = batch.len();
+        let mut floats = Vec::with_capacity(if self.field.dtype == DataType::Float64 {
+            num_rows
+        } else {
+            0
+        });
+        let mut bools = Vec::with_capacity(if self.field.dtype == DataType::Boolean {
+            num_rows
+        } else {
+            0
+        });
+        let mut validity = Vec::with_capacity(num_rows);
+        for offset in (0..num_rows).step_by(CHUNK_SIZE) {
+            let len = CHUNK_SIZE.min(num_rows - offset);
+            for instruction in &self.program {
+                run(instruction, &mut registers, &sources, offset, len);
+            }
+            let output = &registers[self.output];
+            match output.kind {
+                Kind::Float => floats.extend_from_slice(&output.floats[..len]),
+                Kind::Bool => bools.extend_from_slice(&output.bools[..len]),
+            }
+            validity.extend_from_slice(&output.valid[..len]);
+        }
+
+        let has_nulls = validity.iter().any(|valid| !valid);
+        let name = self.field.name.as_str();
+        let series = if self.field.dtype == DataType::Boolean {
+            let array = BooleanArray::from((name, bools.as_slice()));
+            if has_nulls {
+                array.with_validity_slice(&validity)?.into_series()
+            } else {
+                array.into_series()
+            }
+        } else {
+            let array = Float64Array::from((name, floats));
+            if has_nulls {
+                array.with_validity_slice(&validity)?.into_series()
+            } else {
+                array.into_series()
+            }
+        };
+        Ok(series)
+    }
+}
+
+impl Compiler<'_> {
+    fn register(&mut self, kind: Kind) -> usize {
+        self.registers.push(kind);
+        self.registers.len() - 1
+    }
+
+    fn float(&mut self, value: f64, dtype: DataType, exact: bool) -> Compiled {
+        let dst = self.register(Kind::Float);
+        self.program.push(Instruction::Float { value, dst });
+        Compiled {
+            register: dst,
+            kind: Kind::Float,
+            dtype,
+            exact,
+        }
+    }
+
+    fn compile(&mut self, expr: &ExprRef) -> Option<Compiled> {
+        match expr.as_ref() {
+            Expr::Alias(child, _) => self.compile(child),
+            Expr::Column(Column::Bound(BoundColumn { index, .. })) => {
+                let dtype = self.schema.fields().get(*index)?.dtype.clone();
+                let kind = if dtype == DataType::Boolean {
+                    Kind::Bool
+                } else if is_loadable_numeric(&dtype) {
+                    Kind::Float
+                } else {
+                    return None;
+                };
+                let source = self
+                    .columns
+                    .iter()
+                    .position(|c| c == index)
+                    .unwrap_or_else(|| {
+                        self.columns.push(*index);
+                        self.columns.len() - 1
+                    });
+                let dst = self.register(kind);
+                self.program.push(Instruction::Load { source, dst });
+                let exact = !matches!(dtype, DataType::Int64 | DataType::UInt64);
+                Some(Compiled {
+                    register: dst,
+                    kind,
+                    dtype,
+                    exact,
+                })
+            }
+            Expr::Literal(literal) => self.compile_literal(literal),
+            Expr::BinaryOp { op, left, right } => {
+                let left = self.compile(left)?;
+                let right = self.compile(right)?;
+                self.operators += 1;
+                self.compile_binary_op(*op, expr, left, right)
+            }
+            Expr::Not(child) => {
+                let src = self.compile(child)?;
+                self.operators += 1;
+                (src.kind == Kind::Bool)
+                    .then(|| self.unary(Kind::Bool, |src, dst| Instruction::Not { src, dst }, src))
+            }
+            Expr::IsNull(child) => {
+                let src = self.compile(child)?;
+                self.operators += 1;
+                Some(self.unary(Kind::Bool, |src, dst| Instruction::IsNull { src, dst }, src))
+            }
+            Expr::NotNull(child) => {
+                let src = self.compile(child)?;
+                self.operators += 1;
+                Some(self.unary(
+                    Kind::Bool,
+                    |src, dst| Instruction::NotNull { src, dst },
+                    src,
+                ))
+            }
+            // Numeric casts to `Float64` convert with `as`, which is what loading a column does, so they are free.
+            Expr::Cast(child, DataType::Float64) => {
+                let compiled = self.compile(child)?;
+                (compiled.kind == Kind::Float).then_some(Compiled {
+                    dtype: DataType::Float64,
+                    exact: true,
+                    ..compiled
+                })
+            }
+            Expr::IfElse {
+                if_true,
+                if_false,
+                predicate,
+            } => {
+                let predicate = self.compile(predicate)?;
+                if predicate.kind != Kind::Bool {
+                    return None;
+                }
+                let dtype = expr.get_type(self.schema).ok()?;
+                let branches = self.compile_branches(&[if_true, if_false], &dtype)?;
+                self.operators += 1;
+                let exact = dtype == DataType::Float64 || branches.iter().all(|b| b.exact);
+                let dst = self.register(branches[0].kind);
+                self.program.push(Instruction::Select {
+                    predicate: predicate.register,
+                    if_true: branches[0].register,
+                    if_false: branches[1].register,
+                    dst,
+                });
+                Some(Compiled {
+                    register: dst,
+                    kind: branches[0].kind,
+                    dtype,
+                    exact,
+                })
+            }
+            Expr::FillNull(child, fill_value) => {
+                let dtype = expr.get_type(self.schema).ok()?;
+                let branches = self.compile_branches(&[child, fill_value], &dtype)?;
+                self.operators += 1;
+                self.fill_null(branches, dtype)
+            }
+            // `coalesce(a, b, c)` is `a.fill_null(b).fill_null(c)`.
+            Expr::ScalarFn(ScalarFn::Builtin(func)) if func.name() == "coalesce" => {
+                let dtype = expr.get_type(self.schema).ok()?;
+                let inputs = func.inputs.clone().into_inner();
+                let branches = self.compile_branches(&inputs.iter().collect::<Vec<_>>(), &dtype)?;
+                self.operators += 1;
+                self.fill_null(branches, dtype)
+            }
+            _ => None,
+        }
+    }
+
+    fn compile_literal(&mut self, literal: &Literal) -> Option<Compiled> {
+        let (value, dtype, exact) = match literal {
+            Literal::Boolean(value) => {
+                let dst = self.register(Kind::Bool);
+                self.program.push(Instruction::Bool { value: *value, dst });
+                return Some(Compiled {
+                    register: dst,
+                    kind: Kind::Bool,
+                    dtype: DataType::Boolean,
+                    exact: true,
+                });
+            }
+            Literal::Int8(v) => (*v as f64, DataType::Int8, true),
+            Literal::Int16(v) => (*v as f64, DataType::Int16, true),
+            Literal::Int32(v) => (*v as f64, DataType::Int32, true),
+            Literal::Int64(v) => (*v as f64, DataType::Int64, (*v as f64) as i64 == *v),
+            Literal::UInt8(v) => (*v as f64, DataType::UInt8, true),
+            Literal::UInt16(v) => (*v as f64, DataType::UInt16, true),
+            Literal::UInt32(v) => (*v as f64, DataType::UInt32, true),
+            Literal::UInt64(v) => (*v as f64, DataType::UInt64, (*v as f64) as u64 == *v),
+            Literal::Float32(v) => (*v as f64, DataType::Float32, true),
+            Literal::Float64(v) => (*v, DataType::Float64, true),
+            _ => return None,
+        };
+        Some(self.float(value, dtype, exact))
+    }
+
+    fn compile_binary_op(
+        &mut self,
+        op: Operator,
+        expr: &ExprRef,
+        lhs: Compiled,
+        rhs: Compiled,
+    ) -> Option<Compiled> {
+        let arithmetic = match op {
+            Operator::Plus => Some(Arithmetic::Add),
+            Operator::Minus => Some(Arithmetic::Subtract),
+            Operator::Multiply => Some(Arithmetic::Multiply),
+            Operator::TrueDivide => Some(Arithmetic::Divide),
+            _ => None,
+        };
+        let comparison = match op {
+            Operator::Eq => Some(Comparison::Eq),
+            Operator::NotEq => Some(Comparison::NotEq),
+            Operator::Lt => Some(Comparison::Lt),
+            Operator::LtEq => Some(Comparison::LtEq),
+            Operator::Gt => Some(Comparison::Gt),
+            Operator::GtEq => Some(Comparison::GtEq),
+            _ => None,
+        };
+        let (lhs_register, rhs_register) = (lhs.register, rhs.register);
+        if let Some(op) = arithmetic {
+            // Arithmetic on other types, e.g. integers, doesn't go through `f64`.
+            let dtype = expr.get_type(self.schema).ok()?;
+            if lhs.kind != Kind::Float || rhs.kind != Kind::Float || dtype != DataType::Float64 {
+                return None;
+            }
+            let dst = self.register(Kind::Float);
+            self.program.push(Instruction::Arithmetic {
+                op,
+                lhs: lhs_register,
+                rhs: rhs_register,
+                dst,
+            });
+            return Some(Compiled {
+                register: dst,
+                kind: Kind::Float,
+                dtype,
+                exact: true,
+            });
+        }
+        if let Some(op) = comparison {
+            // Operands are compared as their supertype, which is `Float64` if either of them is, and otherwise gives
+            // the same result as comparing them as `f64` if both are exactly representable.
+            let exact = (lhs.exact && rhs.exact)
+                || lhs.dtype == DataType::Float64
+                || rhs.dtype == DataType::Float64;
+            if lhs.kind != Kind::Float || rhs.kind != Kind::Float || !exact {
+                return None;
+            }
+            let dst = self.register(Kind::Bool);
+            self.program.push(Instruction::Compare {
+                op,
+                lhs: lhs_register,
+                rhs: rhs_register,
+                dst,
+            });
+            return Some(Compiled {
+                register: dst,
+                kind: Kind::Bool,
+                dtype: DataType::Boolean,
+                exact: true,
+            });
+        }
+        if !matches!(op, Operator::And | Operator::Or)
+            || lhs.kind != Kind::Bool
+            || rhs.kind != Kind::Bool
+        {
+            return None;
+        }
+        let dst = self.register(Kind::Bool);
+        self.program.push(if op == Operator::And {
+            Instruction::And {
+                lhs: lhs_register,
+                rhs: rhs_register,
+                dst,
+            }
+        } else {
+            Instruction::Or {
+                lhs: lhs_register,
+                rhs: rhs_register,
+                dst,
+            }
+        });
+        Some(Compiled {
+            register: dst,
+            kind: Kind::Bool,
+            dtype: DataType::Boolean,
+            exact: true,
+        })
+    }
+
+    /// Compiles the expressions a conditional expression of type `dtype` selects its values from, whose values must be
+    /// the same as `f64`s whether or not they are cast to `dtype` first. Null literals, which have no type of their
+    /// own, are compiled to registers of nulls.
+    fn compile_branches(&mut self, exprs: &[&ExprRef], dtype: &DataType) -> Option<Vec<Compiled>> {
+        let kind = if *dtype == DataType::Boolean {
+            Kind::Bool
+        } else if is_loadable_numeric(dtype) {
+            Kind::Float
+        } else {
+            return None;
+        };
+        exprs
+            .iter()
+            .map(|&expr| {
+                if let Expr::Literal(Literal::Null) = expr.as_ref() {
+                    let dst = self.register(kind);
+                    self.program.push(Instruction::Null { dst });
+                    return Some(Compiled {
+                        register: dst,
+                        kind,
+                        dtype: DataType::Null,
+                        exact: true,
+                    });
+                }
+                let compiled = self.compile(expr)?;
+                (compiled.kind == kind && (compiled.dtype == *dtype || *dtype == DataType::Float64))
+                    .then_some(compiled)
+            })
+            .collect()
+    }
+
+    /// Compiles filling the nulls of the first of `branches` with each of the others in turn.
+    fn fill_null(&mut self, branches: Vec<Compiled>, dtype: DataType) -> Option<Compiled> {
+        let exact = dtype == DataType::Float64 || branches.iter().all(|b| b.exact);
+        let mut branches = branches.into_iter();
+        let first = branches.next()?;
+        let kind = first.kind;
+        let register = branches.fold(first.register, |src, fill| {
+            let dst = self.register(kind);
+            self.program.push(Instruction::FillNull {
+                src,
+                fill: fill.register,
+                dst,
+            });
+            dst
+        });
+        Some(Compiled {
+            register,
+            kind,
+            dtype,
+            exact,
+        })
+    }
+
+    fn unary(
+        &mut self,
+        kind: Kind,
+        instruction: impl FnOnce(usize, usize) -> Instruction,
+        src: Compiled,
+    ) -> Compiled {
+        let dst = self.register(kind);
+        self.program.push(instruction(src.register, dst));
+        Compiled {
+            register: dst,
+            kind,
+            dtype: DataType::Boolean,
+            exact: true,
+        }
+    }
+}
+
+fn is_loadable_numeric(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+    )
+}
+
+/// The values of a column read by a program.
+enum Values<'a> {
+    Int8(&'a [i8]),
+    Int16(&'a [i16]),
+    Int32(&'a [i32]),
+    Int64(&'a [i64]),
+    UInt8(&'a [u8]),
+    UInt16(&'a [u16]),
+    UInt32(&'a [u32]),
+    UInt64(&'a [u64]),
+    Float32(&'a [f32]),
+    Float64(&'a [f64]),
+    Bool(&'a BooleanArray),
+}
+
+struct Source<'a> {
+    values: Values<'a>,
+    validity: Option<&'a daft_arrow::buffer::NullBuffer>,
+}
+
+impl<'a> Source<'a> {
+    fn try_new(series: &'a Series) -> DaftResult<Self> {
+        let (values, validity) = match series.data_type() {
+            DataType::Int8 => (
+                Values::Int8(series.i8()?.as_slice()),
+                series.i8()?.validity(),
+            ),
+            DataType::Int16 => (
+                Values::Int16(series.i16()?.as_slice()),
+                series.i16()?.validity(),
+            ),
+            DataType::Int32 => (
+                Values::Int32(series.i32()?.as_slice()),
+                series.i32()?.validity(),
+            ),
+            DataType::Int64 => (
+                Values::Int64(series.i64()?.as_slice()),
+                series.i64()?.validity(),
+            ),
+            DataType::UInt8 => (
+                Values::UInt8(series.u8()?.as_slice()),
+                series.u8()?.validity(),
+            ),
+            DataType::UInt16 => (
+                Values::UInt16(series.u16()?.as_slice()),
+                series.u16()?.validity(),
+            ),
+            DataType::UInt32 => (
+                Values::UInt32(series.u32()?.as_slice()),
+                series.u32()?.validity(),
+            ),
+            DataType::UInt64 => (
+                Values::UInt64(series.u64()?.as_slice()),
+                series.u64()?.validity(),
+            ),
+            DataType::Float32 => (
+                Values::Float32(series.f32()?.as_slice()),
+                series.f32()?.validity(),
+            ),
+            DataType::Float64 => (
+                Values::Float64(series.f64()?.as_slice()),
+                series.f64()?.validity(),
+            ),
+            DataType::Boolean => (Values::Bool(series.bool()?), series.bool()?.validity()),
+            other => unreachable!("Fused expressions can't load columns of type {other}"),
+        };
+        Ok(Self { values, validity })
+    }
+}
+
+/// The values of a register for the current chunk, and whether each of them is valid.
+struct Register {
+    kind: Kind,
+    floats: Vec<f64>,
+    bools: Vec<bool>,
+    valid: Vec<bool>,
+}
+
+impl Register {
+    fn new(kind: Kind) -> Self {
+        let values = |k| if kind == k { CHUNK_SIZE } else { 0 };
+        Self {
+            kind,
+            floats: vec![0.0; values(Kind::Float)],
+            bools: vec![false; values(Kind::Bool)],
+            valid: vec![true; CHUNK_SIZE],
+        }
+    }
+}
+
+fn load<T: AsPrimitive<f64>>(values: &[T], dst: &mut [f64]) {
+    for (dst, value) in dst.iter_mut().zip(values) {
+        *dst = value.as_();
+    }
+}
+
+fn arithmetic(
+    lhs: &Register,
+    rhs: &Register,
+    dst: &mut Register,
+    len: usize,
+    f: impl Fn(f64, f64) -> f64,
+) {
+    for i in 0..len {
+        dst.floats[i] = f(lhs.floats[i], rhs.floats[i]);
+        dst.valid[i] = lhs.valid[i] & rhs.valid[i];
+    }
+}
+
+fn compare(
+    lhs: &Register,
+    rhs: &Register,
+    dst: &mut Register,
+    len: usize,
+    f: impl Fn(f64, f64) -> bool,
+) {
+    for i in 0..len {
+        dst.bools[i] = f(lhs.floats[i], rhs.floats[i]);
+        dst.valid[i] = lhs.valid[i] & rhs.valid[i];
+    }
+}
+
+/// Sets each value of `dst` to the value of the register chosen for its row, which is null if it is null in that
+/// register or if the validity chosen with it is false.
+fn select<'a>(dst: &mut Register, len: usize, choose: impl Fn(usize) -> (&'a Register, bool)) {
+    for i in 0..len {
+        let (src, valid) = choose(i);
+        dst.valid[i] = valid & src.valid[i];
+        match dst.kind {
+            Kind::Float => dst.floats[i] = src.floats[i],
+            Kind::Bool => dst.bools[i] = src.bools[i],
+        }
+    }
+}
+
+/// Runs an instruction over the `len` rows of the chunk starting at row `offset` of the batch.
+fn run(
+    instruction: &Instruction,
+    registers: &mut [Register],
+    sources: &[Source],
+    offset: usize,
+    len: usize,
+) {
+    let dst_index = match instruction {
+        Instruction::Load { dst, .. }
+        | Instruction::Float { dst, .. }
+        | Instruction::Bool { dst, .. }
+        | Instruction::Arithmetic { dst, .. }
+        | Instruction::Compare { dst, .. }
+        | Instruction::And { dst, .. }
+        | Instruction::Or { dst, .. }
+        | Instruction::Not { dst, .. }
+        | Instruction::IsNull { dst, .. }
+        | Instruction::NotNull { dst, .. }
+        | Instruction::Null { dst }
+        | Instruction::Select { dst, .. }
+        | Instruction::FillNull { dst, .. } => *dst,
+    };
+    let (inputs, rest) = registers.split_at_mut(dst_index);
+    let dst = &mut rest[0];
+    match instruction {
+        Instruction::Load { source, .. } => {
+            let source = &sources[*source];
+            let rows = offset..offset + len;
+            match &source.values {
+                Values::Int8(values) => load(&values[rows], &mut dst.floats),
+                Values::Int16(values) => load(&values[rows], &mut dst.floats),
+                Values::Int32(values) => load(&values[rows], &mut dst.floats),
+                Values::Int64(values) => load(&values[rows], &mut dst.floats),
+                Values::UInt8(values) => load(&values[rows], &mut dst.floats),
+                Values::UInt16(values) => load(&values[rows], &mut dst.floats),
+                Values::UInt32(values) => load(&values[rows], &mut dst.floats),
+                Values::UInt64(values) => load(&values[rows], &mut dst.floats),
+                Values::Float32(values) => load(&values[rows], &mut dst.floats),
+                Values::Float64(values) => dst.floats[..len].copy_from_slice(&values[rows]),
+                Values::Bool(array) => {
+                    let bits = array.as_arrow2().values();
+                    for i in 0..len {
+                        dst.bools[i] = bits.get_bit(offset + i);
+                    }
+                }
+            }
+            match source.validity {
+                Some(validity) => {
+                    for i in 0..len {
+                        dst.valid[i] = validity.is_valid(offset + i);
+                    }
+                }
+                None => dst.valid[..len].fill(true),
+            }
+        }
+        Instruction::Float { value, .. } => {
+            dst.floats[..len].fill(*value);
+            dst.valid[..len].fill(true);
+        }
+        Instruction::Null { .. } => dst.valid[..len].fill(false),
+        Instruction::Bool { value, .. } => {
+            dst.bools[..len].fill(*value);
+            dst.valid[..len].fill(true);
+        }
+        Instruction::Arithmetic { op, lhs, rhs, .. } => {
+            let (lhs, rhs) = (&inputs[*lhs], &inputs[*rhs]);
+            match op {
+                Arithmetic::Add => arithmetic(lhs, rhs, dst, len, |a, b| a + b),
+                Arithmetic::Subtract => arithmetic(lhs, rhs, dst, len, |a, b| a - b),
+                Arithmetic::Multiply => arithmetic(lhs, rhs, dst, len, |a, b| a * b),
+                Arithmetic::Divide => arithmetic(lhs, rhs, dst, len, |a, b| a / b),
+            }
+        }
+        Instruction::Compare { op, lhs, rhs, .. } => {
+            let (lhs, rhs) = (&inputs[*lhs], &inputs[*rhs]);
+            match op {
+                Comparison::Eq => compare(lhs, rhs, dst, len, |a, b| a == b),
+                Comparison::NotEq => compare(lhs, rhs, dst, len, |a, b| a != b),
+                Comparison::Lt => compare(lhs, rhs, dst, len, |a, b| a < b),
+                Comparison::LtEq => compare(lhs, rhs, dst, len, |a, b| a <= b),
+                Comparison::Gt => compare(lhs, rhs, dst, len, |a, b| a > b),
+                Comparison::GtEq => compare(lhs, rhs, dst, len, |a, b| a >= b),
+            }
+        }
+        // With Kleene logic, `false & null` is false and `true | null` is true. The values of null rows are arbitrary,
+        // but are only used when the other operand decides the result.
+        Instruction::And { lhs, rhs, .. } => {
+            let (lhs, rhs) = (&inputs[*lhs], &inputs[*rhs]);
+            for i in 0..len {
+                let (l, r) = (lhs.bools[i], rhs.bools[i]);
+                let (l_valid, r_valid) = (lhs.valid[i], rhs.valid[i]);
+                dst.bools[i] = l & r & (l_valid | r_valid);
+                dst.valid[i] = (l_valid & r_valid) | (l_valid & !l) | (r_valid & !r);
+            }
+        }
+        Instruction::Or { lhs, rhs, .. } => {
+            let (lhs, rhs) = (&inputs[*lhs], &inputs[*rhs]);
+            for i in 0..len {
+                let (l, r) = (lhs.bools[i], rhs.bools[i]);
+                let (l_valid, r_valid) = (lhs.valid[i], rhs.valid[i]);
+                dst.bools[i] = (l & l_valid) | (r & r_valid);
+                dst.valid[i] = (l_valid & r_valid) | (l_valid & l) | (r_valid & r);
+            }
+        }
+        Instruction::Not { src, .. } => {
+            let src = &inputs[*src];
+            for i in 0..len {
+                dst.bools[i] = !src.bools[i];
+                dst.valid[i] = src.valid[i];
+            }
+        }
+        Instruction::IsNull { src, .. } => {
+            let src = &inputs[*src];
+            for i in 0..len {
+                dst.bools[i] = !src.valid[i];
+                dst.valid[i] = true;
+            }
+        }
+        Instruction::NotNull { src, .. } => {
+            let src = &inputs[*src];
+            for i in 0..len {
+                dst.bools[i] = src.valid[i];
+                dst.valid[i] = true;
+            }
+        }
+        Instruction::Select {
+            predicate,
+            if_true,
+            if_false,
+            ..
+        } => {
+            let (predicate, if_true, if_false) =
+                (&inputs[*predicate], &inputs[*if_true], &inputs[*if_false]);
+            // A null predicate selects a null.
+            select(dst, len, |i| {
+                let branch = if predicate.bools[i] {
+                    if_true
+                } else {
+                    if_false
+                };
+                (branch, predicate.valid[i])
+            });
+        }
+        Instruction::FillNull { src, fill, .. } => {
+            let (src, fill) = (&inputs[*src], &inputs[*fill]);
+            select(dst, len, |i| (if src.valid[i] { src } else { fill }, true));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{ExprRef, expr::bound_expr::BoundExpr, lit, null_lit, resolved_col};
+
+    use super::{FusedExpr, FusedProjection};
+    use crate::RecordBatch;
+
+    fn record_batch(num_rows: usize) -> DaftResult<RecordBatch> {
+        let a = Float64Array::from_iter(
+            Field::new("a", DataType::Float64),
+            (0..num_rows).map(|i| (i % 7 != 3).then_some(i as f64 / 4.0)),
+        )
+        .into_series();
+        let b = Int32Array::from_iter(
+            Field::new("b", DataType::Int32),
+            (0..num_rows).map(|i| (i % 5 != 1).then_some(i as i32 % 11 - 5)),
+        )
+        .into_series();
+        let c = Int64Array::from(("c", (0..num_rows as i64).collect::<Vec<_>>())).into_series();
+        let d = BooleanArray::from_iter(
+            "d",
+            (0..num_rows).map(|i| (i % 3 != 0).then_some(i % 2 == 0)),
+        )
+        .into_series();
+        RecordBatch::from_nonempty_columns(vec![a, b, c, d])
+    }
+
+    /// Checks that an expression is compiled, and evaluates to the same result fused as node by node.
+    fn assert_fused_matches(expr: ExprRef) -> DaftResult<()> {
+        for num_rows in [0, 5, 3000] {
+            let rb = record_batch(num_rows)?;
+            let expr = BoundExpr::try_new(expr.clone(), &rb.schema)?;
+            let fused = FusedExpr::try_compile(expr.inner(), &rb.schema)
+                .expect("expression should be compiled");
+            assert_eq!(fused.eval(&rb)?, rb.eval_expression(&expr)?, "{expr}");
+        }
+        Ok(())
+    }
+
+    fn compiles(expr: ExprRef) -> DaftResult<bool> {
+        let rb = record_batch(5)?;
+        let expr = BoundExpr::try_new(expr, &rb.schema)?;
+        Ok(FusedExpr::try_compile(expr.inner(), &rb.schema).is_some())
+    }
+
+    #[test]
+    fn test_fused_arithmetic() -> DaftResult<()> {
+        assert_fused_matches(((resolved_col("a") + resolved_col("b")) * lit(2.5)).alias("x"))?;
+        // Division by zero and by null.
+        assert_fused_matches(resolved_col("a") / resolved_col("b") - lit(1.0))?;
+        assert_fused_matches(
+            resolved_col("c").cast(&DataType::Float64) / lit(3) + resolved_col("a"),
+        )
+    }
+
+    #[test]
+    fn test_fused_predicates() -> DaftResult<()> {
+        assert_fused_matches((resolved_col("a") * lit(2.0)).gt(resolved_col("b")))?;
+        assert_fused_matches(
+            resolved_col("b")
+                .lt_eq(lit(2))
+                .and(resolved_col("d"))
+                .or(resolved_col("a").is_null()),
+        )?;
+        assert_fused_matches(resolved_col("d").not().and(resolved_col("b").not_null()))?;
+        assert_fused_matches(
+            resolved_col("d")
+                .or(lit(false))
+                .and(resolved_col("a").not_null()),
+        )
+    }
+
+    #[test]
+    fn test_fused_null_handling() -> DaftResult<()> {
+        assert_fused_matches(
+            resolved_col("d")
+                .if_else(resolved_col("a"), resolved_col("b") * lit(2.0))
+                .fill_null(lit(0.0)),
+        )?;
+        assert_fused_matches(
+            resolved_col("a")
+                .gt(lit(1.0))
+                .if_else(null_lit(), resolved_col("a"))
+                .is_null()
+                .or(resolved_col("d")),
+        )?;
+        assert_fused_matches(
+            resolved_col("d")
+                .fill_null(resolved_col("a").lt(lit(10.0)))
+                .and(resolved_col("b").not_null()),
+        )
+    }
+
+    #[test]
+    fn test_fused_projection_compiles_once() -> DaftResult<()> {
+        let rb = record_batch(5)?;
+        let exprs = [
+            (resolved_col("a") * lit(2.0)).gt(resolved_col("b")),
+            resolved_col("c"),
+        ]
+        .into_iter()
+        .map(|expr| BoundExpr::try_new(expr, &rb.schema))
+        .collect::<DaftResult<Vec<_>>>()?;
+        let projection = FusedProjection {
+            enabled: true,
+            ..FusedProjection::new(exprs.clone())
+        };
+
+        let programs = projection.programs(&rb.schema);
+        assert!(programs[0].is_some());
+        assert!(programs[1].is_none());
+        // A batch with the same schema reuses the programs, rather than compiling them again.
+        let other = record_batch(3000)?;
+        let reused = projection.programs(&other.schema);
+        assert!(Arc::ptr_eq(
+            programs[0].as_ref().unwrap(),
+            reused[0].as_ref().unwrap()
+        ));
+        assert_eq!(
+            reused[0].as_ref().unwrap().eval(&other)?,
+            other.eval_expression(&exprs[0])?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fused_rejects_inexact_expressions() -> DaftResult<()> {
+        // Integer arithmetic isn't done in `f64`.
+        assert!(!compiles(resolved_col("b") + resolved_col("b") * lit(2))?);
+        // Comparing `Int64`s as `f64` loses precision.
+        assert!(!compiles(
+            resolved_col("c").gt(lit(1)).and(resolved_col("d"))
+        )?);
+        // A single operator isn't worth compiling.
+        assert!(!compiles(resolved_col("a") + lit(1.0))?);
+        Ok(())
+    }
+}
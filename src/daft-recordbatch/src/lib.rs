@@ -36,20 +36,25 @@ use daft_dsl::{
 };
 use daft_functions_list::SeriesListExtension;
 use file_info::FileInfos;
+use fused::FusedExpr;
 use futures::{StreamExt, TryStreamExt, future::try_join_all};
 use num_traits::ToPrimitive;
 #[cfg(feature = "python")]
 pub mod ffi;
 mod file_info;
+mod fused;
 mod growable;
 mod ops;
 mod preview;
 mod probeable;
 mod repr_html;
 
+pub use fused::FusedProjection;
 pub use growable::GrowableRecordBatch;
-pub use ops::{get_column_by_name, get_columns_by_name};
-pub use probeable::{ProbeState, Probeable, ProbeableBuilder, make_probeable_builder};
+pub use ops::{ConjunctObservation, MergeJoinFrontier, get_column_by_name, get_columns_by_name};
+pub use probeable::{
+    ProbeMatches, ProbeState, Probeable, ProbeableBuilder, make_probeable_builder,
+};
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -1333,29 +1338,86 @@ impl RecordBatch {
         self.process_eval_results(exprs.as_ref(), result_series)
     }
 
+    /// Evaluates the expressions of a projection, running those which were compiled as fused programs, see
+    /// [`FusedProjection`].
+    pub async fn eval_projection_async(&self, projection: &FusedProjection) -> DaftResult<Self> {
+        let programs = projection.programs(&self.schema);
+        let futs = projection
+            .exprs()
+            .iter()
+            .zip(programs)
+            .map(|(e, program)| async move {
+                match program {
+                    Some(program) => program.eval(self),
+                    None => self.eval_expression_async(e.clone()).await,
+                }
+            });
+
+        let result_series = try_join_all(futs).await?;
+
+        self.process_eval_results(projection.exprs(), result_series)
+    }
+
     pub async fn par_eval_expression_list(
         &self,
         exprs: &[BoundExpr],
         num_parallel_tasks: usize,
+    ) -> DaftResult<Self> {
+        self.par_eval_expression_list_with_programs(
+            exprs,
+            vec![None; exprs.len()],
+            num_parallel_tasks,
+        )
+        .await
+    }
+
+    /// Like [`Self::eval_projection_async`], but evaluates the expressions with compute in up to
+    /// `num_parallel_tasks` parallel tasks.
+    pub async fn par_eval_projection(
+        &self,
+        projection: &FusedProjection,
+        num_parallel_tasks: usize,
+    ) -> DaftResult<Self> {
+        let programs = projection.programs(&self.schema);
+        self.par_eval_expression_list_with_programs(
+            projection.exprs(),
+            programs,
+            num_parallel_tasks,
+        )
+        .await
+    }
+
+    async fn par_eval_expression_list_with_programs(
+        &self,
+        exprs: &[BoundExpr],
+        programs: Vec<Option<Arc<FusedExpr>>>,
+        num_parallel_tasks: usize,
     ) -> DaftResult<Self> {
         // Partition the expressions into compute and non-compute
         let (compute_exprs, non_compute_exprs): (Vec<_>, Vec<_>) = exprs
             .iter()
             .cloned()
+            .zip(programs)
             .enumerate()
-            .partition(|(_, e)| e.inner().has_compute());
+            .partition(|(_, (e, _))| e.inner().has_compute());
 
         // Evaluate non-compute expressions
         let non_compute_results = non_compute_exprs
             .into_iter()
-            .map(|(i, e)| (i, self.eval_expression(&e)))
+            .map(|(i, (e, _))| (i, self.eval_expression(&e)))
             .collect::<Vec<_>>();
 
         let compute_runtime = get_compute_runtime();
 
-        let compute_futures = compute_exprs.into_iter().map(|(i, e)| {
+        let compute_futures = compute_exprs.into_iter().map(|(i, (e, program))| {
             let table = self.clone();
-            compute_runtime.spawn(async move { (i, table.eval_expression_async(e).await) })
+            compute_runtime.spawn(async move {
+                let result = match program {
+                    Some(program) => program.eval(&table),
+                    None => table.eval_expression_async(e).await,
+                };
+                (i, result)
+            })
         });
 
         // Collect the results of the compute expressions
@@ -5,25 +5,69 @@ use common_error::{DaftError, DaftResult};
 use daft_core::{
     array::ops::as_arrow::AsArrow,
     count_mode::CountMode,
-    datatypes::{DataType, UInt64Array},
-    series::Series,
+    datatypes::{DataType, Field, UInt64Array},
+    series::{IntoSeries, Series},
 };
 use daft_dsl::{Expr, expr::bound_expr::BoundExpr, functions::scalar::ScalarFn};
 use daft_functions_list::SeriesListExtension;
 
 use crate::RecordBatch;
 
-fn lengths_to_indices(lengths: &UInt64Array, capacity: usize) -> DaftResult<UInt64Array> {
+/// Number of rows each input row explodes into: the list length, or 1 for empty and null lists.
+fn num_exploded_rows(length: Option<u64>) -> u64 {
+    std::cmp::max(length.unwrap_or(1), 1)
+}
+
+fn lengths_to_indices(lengths: &[Option<u64>], capacity: usize) -> UInt64Array {
     let mut indices = Vec::with_capacity(capacity);
-    for (i, l) in lengths.as_arrow2().iter().enumerate() {
-        let l = std::cmp::max(*l.unwrap_or(&1), 1u64);
-        (0..l).for_each(|_| indices.push(i as u64));
+    for (i, l) in lengths.iter().enumerate() {
+        (0..num_exploded_rows(*l)).for_each(|_| indices.push(i as u64));
     }
-    Ok(UInt64Array::from(("indices", indices)))
+    UInt64Array::from(("indices", indices))
+}
+
+/// Position of each exploded row within its list, or null for rows from empty and null lists.
+fn lengths_to_positions(name: &str, lengths: &[Option<u64>], capacity: usize) -> UInt64Array {
+    let mut positions = Vec::with_capacity(capacity);
+    for l in lengths {
+        match l {
+            Some(l) if *l > 0 => positions.extend((0..*l).map(Some)),
+            _ => positions.push(None),
+        }
+    }
+    UInt64Array::from_iter(Field::new(name, DataType::UInt64), positions.into_iter())
+}
+
+/// Indices into an exploded column that pad each of its lists with nulls up to `row_lengths`.
+fn padded_indices(lengths: &UInt64Array, row_lengths: &[Option<u64>]) -> UInt64Array {
+    let mut indices = Vec::new();
+    let mut offset = 0;
+    for (length, row_length) in lengths.as_arrow2().iter().zip(row_lengths) {
+        let length = length.copied();
+        for j in 0..num_exploded_rows(*row_length) {
+            indices.push((j < length.unwrap_or(0)).then_some(offset + j));
+        }
+        offset += num_exploded_rows(length);
+    }
+    UInt64Array::from_iter(Field::new("indices", DataType::UInt64), indices.into_iter())
 }
 
 impl RecordBatch {
     pub fn explode(&self, exprs: &[BoundExpr]) -> DaftResult<Self> {
+        self.explode_with_options(exprs, None, false)
+    }
+
+    /// Explode the given list columns in lockstep.
+    ///
+    /// If `index_column` is set, a column of that name is appended with the position of each element within its
+    /// list. If the lists of a row have different lengths, this errors unless `pad_mismatched` is set, in which
+    /// case the shorter lists are padded with nulls.
+    pub fn explode_with_options(
+        &self,
+        exprs: &[BoundExpr],
+        index_column: Option<&str>,
+        pad_mismatched: bool,
+    ) -> DaftResult<Self> {
         if exprs.is_empty() {
             return Err(DaftError::ValueError(format!(
                 "Explode needs at least 1 expression, received: {}",
@@ -65,15 +109,13 @@ impl RecordBatch {
                 }
             }
         }
-        let first_len = evaluated_columns
-            .first()
-            .unwrap()
-            .list_count(CountMode::All)?;
-        if evaluated_columns
+        let lengths = evaluated_columns
             .iter()
-            .skip(1)
-            .any(|c| c.list_count(CountMode::All).unwrap().ne(&first_len))
-        {
+            .map(|c| c.list_count(CountMode::All))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let first_len = lengths.first().unwrap();
+        let mismatched = lengths.iter().skip(1).any(|l| l.ne(first_len));
+        if mismatched && !pad_mismatched {
             return Err(DaftError::ValueError(
                 "In multicolumn explode, list length did not match".to_string(),
             ));
@@ -83,8 +125,23 @@ impl RecordBatch {
             .map(daft_core::series::Series::explode)
             .collect::<DaftResult<Vec<_>>>()?;
 
+        let row_lengths = if mismatched {
+            (0..self.len())
+                .map(|i| lengths.iter().filter_map(|l| l.get(i)).max())
+                .collect::<Vec<_>>()
+        } else {
+            first_len.as_arrow2().iter().map(|l| l.copied()).collect()
+        };
+        if mismatched {
+            exploded_columns = exploded_columns
+                .iter()
+                .zip(&lengths)
+                .map(|(c, l)| c.take(&padded_indices(l, &row_lengths)))
+                .collect::<DaftResult<Vec<_>>>()?;
+        }
+
         let capacity_expected = exploded_columns.first().unwrap().len();
-        let take_idx = lengths_to_indices(&first_len, capacity_expected)?;
+        let take_idx = lengths_to_indices(&row_lengths, capacity_expected);
 
         let mut new_series = Arc::unwrap_or_clone(self.columns.clone());
 
@@ -101,6 +158,11 @@ impl RecordBatch {
             }
         }
         new_series.extend_from_slice(exploded_columns.as_slice());
+        if let Some(index_column) = index_column {
+            new_series.push(
+                lengths_to_positions(index_column, &row_lengths, capacity_expected).into_series(),
+            );
+        }
         Self::from_nonempty_columns(new_series)
     }
 }
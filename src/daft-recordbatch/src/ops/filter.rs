@@ -0,0 +1,167 @@
+use std::{
+    collections::BTreeSet,
+    time::{Duration, Instant},
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    Column, Expr, bound_col,
+    common_treenode::{Transformed, TreeNode, TreeNodeRecursion},
+    expr::{BoundColumn, bound_expr::BoundExpr},
+};
+
+use crate::RecordBatch;
+
+/// What was observed evaluating one conjunct of a filter with [`RecordBatch::filter_conjuncts`].
+#[derive(Debug, Clone, Copy)]
+pub struct ConjunctObservation {
+    /// The position of the conjunct in the conjuncts of the filter.
+    pub index: usize,
+    pub rows_in: usize,
+    pub rows_out: usize,
+    pub elapsed: Duration,
+}
+
+impl RecordBatch {
+    /// Filters the rows for which all of `conjuncts` are true, evaluating the conjuncts in order.
+    ///
+    /// The rows selected so far are tracked as a selection vector of row indices. Each conjunct is only evaluated on
+    /// the selected rows of the columns it reads, and the other columns are only gathered once at the end, so
+    /// evaluating the cheap and selective conjuncts first saves evaluating the others on the rows they filter out.
+    /// Evaluation stops early once no rows are selected.
+    pub fn filter_conjuncts(
+        &self,
+        conjuncts: &[BoundExpr],
+        mut observe: impl FnMut(ConjunctObservation),
+    ) -> DaftResult<Self> {
+        let mut selection: Option<UInt64Array> = None;
+        for (index, conjunct) in conjuncts.iter().enumerate() {
+            let start = Instant::now();
+            let (columns, conjunct) = project_conjunct(conjunct)?;
+            let input = self.get_columns(&columns);
+            let input = match &selection {
+                Some(selection) => input.take(selection)?,
+                None => input,
+            };
+            let rows_in = input.len();
+            let mask = input.eval_expression(&conjunct)?;
+            if let Some(selected) = selected_rows(&mask, rows_in)? {
+                let selected = UInt64Array::from(("selection", selected));
+                selection = Some(match selection {
+                    Some(selection) => selection.take(&selected)?,
+                    None => selected,
+                });
+            }
+            let rows_out = selection.as_ref().map_or(self.len(), |s| s.len());
+            observe(ConjunctObservation {
+                index,
+                rows_in,
+                rows_out,
+                elapsed: start.elapsed(),
+            });
+            if rows_out == 0 {
+                break;
+            }
+        }
+        match selection {
+            Some(selection) => self.take(&selection),
+            None => Ok(self.clone()),
+        }
+    }
+}
+
+/// Returns the columns read by a conjunct, and the conjunct bound to a record batch of just those columns.
+fn project_conjunct(conjunct: &BoundExpr) -> DaftResult<(Vec<usize>, BoundExpr)> {
+    let mut columns = BTreeSet::new();
+    conjunct.inner().apply(|e| {
+        if let Expr::Column(Column::Bound(BoundColumn { index, .. })) = e.as_ref() {
+            columns.insert(*index);
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    let columns = columns.into_iter().collect::<Vec<_>>();
+    let projected = conjunct.inner().clone().transform(|e| {
+        if let Expr::Column(Column::Bound(BoundColumn { index, field })) = e.as_ref() {
+            let position = columns.binary_search(index).unwrap();
+            Ok(Transformed::yes(bound_col(position, field.clone())))
+        } else {
+            Ok(Transformed::no(e))
+        }
+    })?;
+    Ok((columns, BoundExpr::new_unchecked(projected.data)))
+}
+
+/// Returns the positions of the rows for which a mask is true, or `None` if it is true for all of them.
+fn selected_rows(mask: &Series, num_rows: usize) -> DaftResult<Option<Vec<u64>>> {
+    if *mask.data_type() != DataType::Boolean {
+        return Err(DaftError::ValueError(format!(
+            "We can only filter a RecordBatch with a Boolean Series, but we got {}",
+            mask.data_type()
+        )));
+    }
+    let mask = mask.bool()?;
+    if mask.len() == 1 {
+        // account for broadcasting of mask
+        return Ok(if mask.get(0).is_some_and(|b| b) {
+            None
+        } else {
+            Some(vec![])
+        });
+    }
+    let selected = (0..num_rows)
+        .filter(|&i| mask.get(i).is_some_and(|b| b))
+        .map(|i| i as u64)
+        .collect::<Vec<_>>();
+    Ok((selected.len() < num_rows).then_some(selected))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{expr::bound_expr::BoundExpr, lit, resolved_col};
+
+    use crate::RecordBatch;
+
+    fn record_batch() -> DaftResult<RecordBatch> {
+        let a = Int64Array::from(("a", vec![1, 2, 3, 4, 5, 6])).into_series();
+        let b = Utf8Array::from_values("b", ["x", "y", "x", "y", "x", "y"].iter()).into_series();
+        RecordBatch::from_nonempty_columns(vec![a, b])
+    }
+
+    #[test]
+    fn test_filter_conjuncts_matches_filter() -> DaftResult<()> {
+        let rb = record_batch()?;
+        let conjuncts = [
+            BoundExpr::try_new(resolved_col("a").gt(lit(1)), &rb.schema)?,
+            BoundExpr::try_new(resolved_col("b").eq(lit("x")), &rb.schema)?,
+        ];
+        let mut observations = vec![];
+        let filtered = rb.filter_conjuncts(&conjuncts, |o| observations.push(o))?;
+
+        assert_eq!(filtered, rb.filter(&conjuncts)?);
+        assert_eq!(filtered.len(), 2);
+        let counts = observations
+            .iter()
+            .map(|o| (o.index, o.rows_in, o.rows_out))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![(0, 6, 5), (1, 5, 2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_conjuncts_stops_when_no_rows_are_selected() -> DaftResult<()> {
+        let rb = record_batch()?;
+        let conjuncts = [
+            BoundExpr::try_new(resolved_col("a").gt(lit(10)), &rb.schema)?,
+            BoundExpr::try_new(resolved_col("b").eq(lit("x")), &rb.schema)?,
+        ];
+        let mut observations = vec![];
+        let filtered = rb.filter_conjuncts(&conjuncts, |o| observations.push(o))?;
+
+        assert!(filtered.is_empty());
+        assert_eq!(observations.len(), 1);
+        Ok(())
+    }
+}
@@ -5,13 +5,35 @@ use daft_core::{
         GroupIndicesPair, IntoGroups, IntoUniqueIdxs, VecIndices,
         arrow::comparison::build_multi_array_is_equal, as_arrow::AsArrow,
     },
-    datatypes::UInt64Array,
-    series::Series,
+    datatypes::{DataType, UInt64Array},
+    prelude::Schema,
+    series::{IntoSeries, Series},
 };
 
 use crate::RecordBatch;
 
 impl RecordBatch {
+    /// Replaces the low-cardinality string columns of this table with the codes of their interned strings.
+    ///
+    /// Rows of group keys are hashed and compared for each row, so grouping by codes compares integers rather than
+    /// strings. Equal strings have equal codes, so the groups are the same as those of the strings.
+    fn intern_strings(&self) -> DaftResult<Self> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|s| {
+                if s.data_type() == &DataType::Utf8
+                    && let Some(codes) = s.utf8()?.intern()
+                {
+                    return Ok(codes.into_series());
+                }
+                Ok(s.clone())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let schema = Schema::new(columns.iter().map(|s| s.field().clone()));
+        Self::new_with_size(schema, columns, self.len())
+    }
+
     fn hash_grouper(&self) -> DaftResult<GroupIndicesPair> {
         // Group equal rows together.
         //
@@ -113,7 +135,13 @@ impl RecordBatch {
 
 impl IntoGroups for RecordBatch {
     fn make_groups(&self) -> DaftResult<GroupIndicesPair> {
-        self.as_physical()?.hash_grouper()
+        let table = self.as_physical()?;
+        // A single column is grouped by its own values, which is as fast as interning them.
+        if table.num_columns() > 1 {
+            table.intern_strings()?.hash_grouper()
+        } else {
+            table.hash_grouper()
+        }
     }
 }
 
@@ -137,3 +165,50 @@ impl IntoUniqueIdxs for RecordBatch {
         Ok(indices)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{array::ops::IntoGroups, prelude::*};
+
+    use crate::RecordBatch;
+
+    /// Sorts groups of row indices, which are in no particular order.
+    fn sorted(groups: Vec<Vec<u64>>) -> Vec<Vec<u64>> {
+        let mut groups = groups
+            .into_iter()
+            .map(|mut group| {
+                group.sort_unstable();
+                group
+            })
+            .collect::<Vec<_>>();
+        groups.sort_unstable();
+        groups
+    }
+
+    #[test]
+    fn test_make_groups_with_interned_strings() -> DaftResult<()> {
+        let keys = (0..3000)
+            .map(|i| {
+                if i % 7 == 0 {
+                    None
+                } else {
+                    Some(["a", "b", "c"][i % 3])
+                }
+            })
+            .collect::<Vec<_>>();
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Utf8Array::from_iter("s", keys.into_iter()).into_series(),
+            Int64Array::from(("i", (0..3000).map(|i| i % 2).collect::<Vec<_>>())).into_series(),
+        ])?;
+        assert!(table.get_column(0).utf8()?.intern().is_some());
+
+        let (_, groups) = table.make_groups()?;
+        let groups = sorted(groups);
+        assert_eq!(groups.len(), 8);
+        // Grouping by the strings themselves gives the same groups.
+        let (_, expected) = table.hash_grouper()?;
+        assert_eq!(groups, sorted(expected));
+        Ok(())
+    }
+}
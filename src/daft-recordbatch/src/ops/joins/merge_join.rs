@@ -1,12 +1,15 @@
 use std::cmp::Ordering;
 
 use common_error::{DaftError, DaftResult};
+use daft_arrow::buffer::NullBufferBuilder;
 use daft_core::{
     array::ops::full::FullNull,
     datatypes::{DataType, UInt64Array},
+    join::JoinSide,
     kernels::search_sorted::build_partial_compare_with_nulls,
 };
 
+use super::sorted_lookup::partition_point;
 use crate::RecordBatch;
 
 /// A state machine for the below merge-join algorithm.
@@ -198,7 +201,7 @@ pub fn merge_inner_join(
                     // If already in a right-side equality run but we've reached the end of the right-side table,
                     // we can't extend the run anymore, so we stage it.
                     MergeJoinState::RightEqualRun(start_right_idx)
-                        if right_idx == left.len() - 1 =>
+                        if right_idx == right.len() - 1 =>
                     {
                         MergeJoinState::StagedRightEqualRun(start_right_idx)
                     }
@@ -246,3 +249,154 @@ pub fn merge_inner_join(
     let right_series = UInt64Array::from(("right_indices", right_indices));
     Ok((left_series, right_series))
 }
+
+/// Extends the matched index pairs of a merge join with a pair for every right row that has no match, where the left
+/// index of such a pair is null.
+pub fn add_unmatched_right_indices(
+    left_indices: UInt64Array,
+    right_indices: UInt64Array,
+    right_len: usize,
+) -> DaftResult<(UInt64Array, UInt64Array)> {
+    let mut right_matched = vec![false; right_len];
+    for r in right_indices.values().iter() {
+        right_matched[*r as usize] = true;
+    }
+
+    let num_rows = left_indices.len() + right_matched.iter().filter(|matched| !**matched).count();
+
+    let mut left_idx = Vec::with_capacity(num_rows);
+    let mut right_idx = Vec::with_capacity(num_rows);
+    let mut l_valid = NullBufferBuilder::new(num_rows);
+
+    left_idx.extend(left_indices.values().iter());
+    right_idx.extend(right_indices.values().iter());
+    l_valid.append_n_non_nulls(left_indices.len());
+
+    for (r, matched) in right_matched.into_iter().enumerate() {
+        if !matched {
+            left_idx.push(0);
+            right_idx.push(r as u64);
+            l_valid.append_null();
+        }
+    }
+
+    Ok((
+        UInt64Array::from(("left_indices", left_idx)).with_validity(l_valid.finish())?,
+        UInt64Array::from(("right_indices", right_idx)),
+    ))
+}
+
+/// Where the buffered prefixes of two streams that are sorted on their join keys can be split for a merge join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeJoinFrontier {
+    /// Number of leading rows of the left prefix that can't match any row still to come from the right stream.
+    pub left_len: usize,
+    /// Number of leading rows of the right prefix that can't match any row still to come from the left stream.
+    pub right_len: usize,
+    /// The stream to read from next, which is the one whose prefix ends at the smaller join key, or `None` once both
+    /// streams are done.
+    pub next: Option<JoinSide>,
+}
+
+/// Builds a comparator of the rows of `left` with the rows of `right`, in the order of a sort on all of their columns
+/// in ascending order with nulls last. Nulls compare equal to each other here, although they never match in a join.
+fn build_row_comparator(
+    left: &RecordBatch,
+    right: &RecordBatch,
+) -> DaftResult<impl Fn(usize, usize) -> Ordering> {
+    let mut cmp_list = Vec::with_capacity(left.num_columns());
+    for (left_series, right_series) in left.columns.iter().zip(right.columns.iter()) {
+        // Columns of the null type are all null, so their rows are all equal.
+        if left_series.data_type().is_null() || right_series.data_type().is_null() {
+            continue;
+        }
+        #[allow(deprecated, reason = "arrow2 migration")]
+        cmp_list.push(build_partial_compare_with_nulls(
+            left_series.to_arrow2().as_ref(),
+            right_series.to_arrow2().as_ref(),
+            false,
+        )?);
+    }
+    Ok(move |i: usize, j: usize| {
+        for comparator in &cmp_list {
+            match comparator(i, j) {
+                Some(Ordering::Equal) | None => {}
+                Some(ordering) => return ordering,
+            }
+        }
+        Ordering::Equal
+    })
+}
+
+/// Finds the frontier of a merge join over the buffered prefixes of two streams, given the join keys of the prefixes,
+/// which are sorted in ascending order with nulls last, and whether each stream is done.
+///
+/// A stream that isn't done may still produce rows equal to the last row of its prefix, so only the rows that sort
+/// before the last row of the prefix that is behind can be joined without reading more of either stream.
+pub fn merge_join_frontier(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    left_done: bool,
+    right_done: bool,
+) -> DaftResult<MergeJoinFrontier> {
+    if left.num_columns() != right.num_columns() {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+            left.num_columns(),
+            right.num_columns()
+        )));
+    }
+
+    let nothing_yet = |next| MergeJoinFrontier {
+        left_len: 0,
+        right_len: 0,
+        next: Some(next),
+    };
+    match (left_done, right_done) {
+        (true, true) => {
+            return Ok(MergeJoinFrontier {
+                left_len: left.len(),
+                right_len: right.len(),
+                next: None,
+            });
+        }
+        (false, _) if left.is_empty() => return Ok(nothing_yet(JoinSide::Left)),
+        (_, false) if right.is_empty() => return Ok(nothing_yet(JoinSide::Right)),
+        _ => {}
+    }
+
+    let left_right = build_row_comparator(left, right)?;
+    let bound_side = if left_done {
+        JoinSide::Right
+    } else if right_done {
+        JoinSide::Left
+    } else if left_right(left.len() - 1, right.len() - 1) == Ordering::Greater {
+        JoinSide::Right
+    } else {
+        JoinSide::Left
+    };
+
+    let (left_len, right_len) = match bound_side {
+        JoinSide::Left => {
+            let bound = left.len() - 1;
+            let left_left = build_row_comparator(left, left)?;
+            (
+                partition_point(left.len(), |i| left_left(i, bound) == Ordering::Less),
+                partition_point(right.len(), |j| left_right(bound, j) == Ordering::Greater),
+            )
+        }
+        JoinSide::Right => {
+            let bound = right.len() - 1;
+            let right_right = build_row_comparator(right, right)?;
+            (
+                partition_point(left.len(), |i| left_right(i, bound) == Ordering::Less),
+                partition_point(right.len(), |j| right_right(j, bound) == Ordering::Less),
+            )
+        }
+    };
+    Ok(MergeJoinFrontier {
+        left_len,
+        right_len,
+        next: Some(bound_side),
+    })
+}
@@ -1,9 +1,13 @@
 #![allow(deprecated, reason = "arrow2 migration")]
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, ops::Not, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
+use daft_arrow::buffer::NullBufferBuilder;
 use daft_core::{
-    array::growable::make_growable, join::JoinSide, prelude::*, utils::supertype::try_get_supertype,
+    array::ops::{DaftIsNull, full::FullNull},
+    join::JoinSide,
+    prelude::*,
+    utils::supertype::try_get_supertype,
 };
 use daft_dsl::{
     expr::bound_expr::BoundExpr,
@@ -15,6 +19,9 @@ use self::hash_join::{hash_inner_join, hash_left_right_join, hash_outer_join};
 use crate::RecordBatch;
 mod hash_join;
 mod merge_join;
+mod sorted_lookup;
+
+pub use merge_join::MergeJoinFrontier;
 
 fn match_types_for_tables(
     left: &RecordBatch,
@@ -74,6 +81,52 @@ fn add_non_join_key_columns(
     Ok(join_series)
 }
 
+/// Materializes the output of a join from its left and right take indices, where a null index marks an output row
+/// without a matching row on that side.
+fn take_join_output(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    join_schema: SchemaRef,
+    lidx: UInt64Array,
+    ridx: UInt64Array,
+    how: JoinType,
+) -> DaftResult<RecordBatch> {
+    let common_cols: Vec<_> = get_common_join_cols(&left.schema, &right.schema).collect();
+
+    let mut join_series = match how {
+        _ if common_cols.is_empty() => vec![],
+        JoinType::Right => Arc::unwrap_or_clone(
+            get_columns_by_name(right, &common_cols)?
+                .take(&ridx)?
+                .columns,
+        ),
+        JoinType::Outer => {
+            // use right side value if left is null
+            let take_from_left = lidx.is_null()?.not()?.into_series();
+
+            common_cols
+                .into_iter()
+                .map(|name| {
+                    let lcol = get_column_by_name(left, name)?.take(&lidx)?;
+                    let rcol = get_column_by_name(right, name)?.take(&ridx)?;
+
+                    lcol.if_else(&rcol, &take_from_left)
+                })
+                .collect::<DaftResult<Vec<_>>>()?
+        }
+        _ => Arc::unwrap_or_clone(
+            get_columns_by_name(left, &common_cols)?
+                .take(&lidx)?
+                .columns,
+        ),
+    };
+
+    let num_rows = lidx.len();
+    join_series = add_non_join_key_columns(left, right, lidx, ridx, join_series)?;
+
+    RecordBatch::new_with_size(join_schema, join_series, num_rows)
+}
+
 impl RecordBatch {
     pub fn hash_join(
         &self,
@@ -120,6 +173,7 @@ impl RecordBatch {
         right: &Self,
         left_on: &[BoundExpr],
         right_on: &[BoundExpr],
+        how: JoinType,
         is_sorted: bool,
     ) -> DaftResult<Self> {
         // sort first and then call join recursively
@@ -153,43 +207,235 @@ impl RecordBatch {
                     .as_slice(),
             )?;
 
-            return left.sort_merge_join(&right, left_on, right_on, true);
+            return left.sort_merge_join(&right, left_on, right_on, how, true);
+        }
+
+        let mut left_matched = vec![false; self.len()];
+        let joined = self.merge_join_run(right, left_on, right_on, how, &mut left_matched)?;
+        match self.merge_join_unmatched(&right.schema, &left_matched, how)? {
+            Some(unmatched) => Self::concat(&[joined, unmatched]),
+            None => Ok(joined),
+        }
+    }
+
+    /// Merge-joins a run of the right side against this left side, where both are sorted in ascending order with
+    /// nulls last on their join keys.
+    ///
+    /// Returns the output rows that are determined by this run alone: the matching pairs, plus the unmatched rows of
+    /// the run for right and outer joins. The left rows that found a match are marked in `left_matched`, so that the
+    /// left rows whose output depends on every run can be emitted by [`Self::merge_join_unmatched`] once all runs of
+    /// the right side have been merged.
+    pub fn merge_join_run(
+        &self,
+        right: &Self,
+        left_on: &[BoundExpr],
+        right_on: &[BoundExpr],
+        how: JoinType,
+        left_matched: &mut [bool],
+    ) -> DaftResult<Self> {
+        if left_matched.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected {} left match flags for merge join, got {}",
+                self.len(),
+                left_matched.len()
+            )));
+        }
+
+        let join_schema = infer_join_schema(&self.schema, &right.schema, how)?;
+        let lkeys = self.eval_expression_list(left_on)?;
+        let rkeys = right.eval_expression_list(right_on)?;
+
+        let (lkeys, rkeys) = match_types_for_tables(&lkeys, &rkeys)?;
+        let (lidx, ridx) = merge_join::merge_inner_join(&lkeys, &rkeys)?;
+
+        drop(lkeys);
+        drop(rkeys);
+
+        for l in lidx.values().iter() {
+            left_matched[*l as usize] = true;
+        }
+
+        match how {
+            JoinType::Inner | JoinType::Left => {
+                take_join_output(self, right, join_schema, lidx, ridx, how)
+            }
+            JoinType::Right | JoinType::Outer => {
+                let (lidx, ridx) =
+                    merge_join::add_unmatched_right_indices(lidx, ridx, right.len())?;
+                take_join_output(self, right, join_schema, lidx, ridx, how)
+            }
+            JoinType::Semi | JoinType::Anti => Ok(Self::empty(Some(join_schema))),
+        }
+    }
+
+    /// Finds how much of the buffered prefixes of two streams that are sorted in ascending order with nulls last on
+    /// their join keys, this prefix of the left stream and `right` of the right stream, can be merge-joined without
+    /// reading more of either stream, and which stream to read next.
+    pub fn merge_join_frontier(
+        &self,
+        right: &Self,
+        left_on: &[BoundExpr],
+        right_on: &[BoundExpr],
+        left_done: bool,
+        right_done: bool,
+    ) -> DaftResult<MergeJoinFrontier> {
+        let lkeys = self.eval_expression_list(left_on)?;
+        let rkeys = right.eval_expression_list(right_on)?;
+        let (lkeys, rkeys) = match_types_for_tables(&lkeys, &rkeys)?;
+        merge_join::merge_join_frontier(&lkeys, &rkeys, left_done, right_done)
+    }
+
+    /// Emits the rows of this left side that depend on every run of the right side, given the left rows marked as
+    /// matched by [`Self::merge_join_run`]: the unmatched rows for left, outer and anti joins, and the matched rows
+    /// for semi joins. Returns `None` for join types that have no such rows.
+    pub fn merge_join_unmatched(
+        &self,
+        right_schema: &SchemaRef,
+        left_matched: &[bool],
+        how: JoinType,
+    ) -> DaftResult<Option<Self>> {
+        let emit_matched = match how {
+            JoinType::Inner | JoinType::Right => return Ok(None),
+            JoinType::Semi => true,
+            JoinType::Left | JoinType::Outer | JoinType::Anti => false,
+        };
+
+        let lidx = left_matched
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| **matched == emit_matched)
+            .map(|(i, _)| i as u64)
+            .collect::<Vec<_>>();
+        let lidx = UInt64Array::from(("left_indices", lidx));
+
+        if matches!(how, JoinType::Semi | JoinType::Anti) {
+            return self.take(&lidx).map(Some);
         }
 
-        let join_schema = infer_join_schema(&self.schema, &right.schema, JoinType::Inner)?;
-        let ltable = self.eval_expression_list(left_on)?;
-        let rtable = right.eval_expression_list(right_on)?;
+        let join_schema = infer_join_schema(&self.schema, right_schema, how)?;
+        let right = Self::empty(Some(right_schema.clone()));
+        let ridx = UInt64Array::full_null("right_indices", &DataType::UInt64, lidx.len());
+
+        take_join_output(self, &right, join_schema, lidx, ridx, how).map(Some)
+    }
 
-        let (ltable, rtable) = match_types_for_tables(&ltable, &rtable)?;
-        let (lidx, ridx) = merge_join::merge_inner_join(&ltable, &rtable)?;
+    /// Joins this left side against a right side that is sorted in ascending order with nulls last on its join keys,
+    /// by binary searching the join keys of the right side for the join keys of each left row.
+    ///
+    /// `right_keys` are the join keys evaluated on `right`, so that they are evaluated once for a right side which is
+    /// looked up by many left tables. Only inner, left, semi and anti joins are supported, since the other join types
+    /// need to know which right rows were matched by every left table.
+    pub fn sorted_lookup_join(
+        &self,
+        right: &Self,
+        left_on: &[BoundExpr],
+        right_keys: &Self,
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        if right_keys.len() != right.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected {} right join keys for sorted lookup join, got {}",
+                right.len(),
+                right_keys.len()
+            )));
+        }
 
-        let mut join_series = get_common_join_cols(&self.schema, &right.schema)
-            .map(|name| {
-                let lcol = get_column_by_name(self, name)?;
-                let rcol = get_column_by_name(right, name)?;
+        let lkeys = self.eval_expression_list(left_on)?;
+        let (lkeys, rkeys) = match_types_for_tables(&lkeys, right_keys)?;
+        let ranges = sorted_lookup::sorted_lookup_ranges(&lkeys, &rkeys)?;
 
-                let mut growable =
-                    make_growable(name, lcol.data_type(), vec![lcol, rcol], false, lcol.len());
+        drop(lkeys);
+        drop(rkeys);
 
-                for (li, ri) in lidx.as_arrow2().iter().zip(ridx.as_arrow2().iter()) {
-                    match (li, ri) {
-                        (Some(i), _) => growable.extend(0, *i as usize, 1),
-                        (None, Some(i)) => growable.extend(1, *i as usize, 1),
-                        (None, None) => unreachable!("Join should not have None for both sides"),
+        match how {
+            JoinType::Inner | JoinType::Left => {
+                let keep_unmatched = how == JoinType::Left;
+                let num_rows = ranges
+                    .iter()
+                    .map(|range| range.len().max(usize::from(keep_unmatched)))
+                    .sum();
+
+                let mut left_idx = Vec::with_capacity(num_rows);
+                let mut right_idx = Vec::with_capacity(num_rows);
+                let mut r_valid = NullBufferBuilder::new(num_rows);
+                for (l, range) in ranges.into_iter().enumerate() {
+                    if range.is_empty() {
+                        if keep_unmatched {
+                            left_idx.push(l as u64);
+                            right_idx.push(0);
+                            r_valid.append_null();
+                        }
+                        continue;
                     }
+                    left_idx.extend(std::iter::repeat_n(l as u64, range.len()));
+                    r_valid.append_n_non_nulls(range.len());
+                    right_idx.extend(range.map(|r| r as u64));
                 }
 
-                growable.build()
-            })
-            .collect::<DaftResult<Vec<_>>>()?;
+                let lidx = UInt64Array::from(("left_indices", left_idx));
+                let ridx = UInt64Array::from(("right_indices", right_idx))
+                    .with_validity(r_valid.finish())?;
+                let join_schema = infer_join_schema(&self.schema, &right.schema, how)?;
+                take_join_output(self, right, join_schema, lidx, ridx, how)
+            }
+            JoinType::Semi | JoinType::Anti => {
+                let keep_matched = how == JoinType::Semi;
+                let lidx = ranges
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, range)| range.is_empty() != keep_matched)
+                    .map(|(l, _)| l as u64)
+                    .collect::<Vec<_>>();
+                self.take(&UInt64Array::from(("left_indices", lidx)))
+            }
+            JoinType::Right | JoinType::Outer => Err(DaftError::not_implemented(format!(
+                "Sorted lookup join for {how} joins"
+            ))),
+        }
+    }
 
-        drop(ltable);
-        drop(rtable);
+    /// Evaluates a residual join predicate over pairs of rows, where row `i` of this table is paired with row `i` of
+    /// `right` and the predicate is bound to the columns of this table followed by the columns of `right`.
+    ///
+    /// Returns whether each pair satisfies the predicate, with null results treated as unsatisfied.
+    pub fn eval_join_residual(&self, right: &Self, residual: &BoundExpr) -> DaftResult<Vec<bool>> {
+        if self.len() != right.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected the same number of left and right rows to evaluate a join residual, got {} and {}",
+                self.len(),
+                right.len()
+            )));
+        }
 
-        let num_rows = lidx.len();
-        join_series = add_non_join_key_columns(self, right, lidx, ridx, join_series)?;
+        let pair_schema = Schema::new(
+            self.schema
+                .fields()
+                .iter()
+                .chain(right.schema.fields())
+                .cloned(),
+        );
+        let pair_columns = self
+            .columns
+            .iter()
+            .chain(right.columns.iter())
+            .cloned()
+            .collect();
+        let pairs = Self::new_unchecked(pair_schema, pair_columns, self.len());
+
+        let mask = pairs.eval_expression(residual)?;
+        if *mask.data_type() != DataType::Boolean {
+            return Err(DaftError::ValueError(format!(
+                "Expected join residual to evaluate to a Boolean, got {}",
+                mask.data_type()
+            )));
+        }
+        let mask = if mask.len() == 1 && pairs.len() != 1 {
+            mask.broadcast(pairs.len())?
+        } else {
+            mask
+        };
 
-        Self::new_with_size(join_schema, join_series, num_rows)
+        Ok(mask.bool()?.into_iter().map(|b| b == Some(true)).collect())
     }
 
     pub fn cross_join(&self, right: &Self, outer_loop_side: JoinSide) -> DaftResult<Self> {
@@ -235,6 +481,74 @@ impl RecordBatch {
 
         Self::new_with_size(join_schema, join_columns, num_rows)
     }
+
+    /// Samples `num` rows uniformly at random from the cross join of this table with `right`.
+    ///
+    /// Positions are drawn from the cartesian product, and only the rows at those positions are taken from each side,
+    /// so the cost grows with the sizes of the tables and of the sample rather than with the size of the product.
+    pub fn cross_join_sample(
+        &self,
+        right: &Self,
+        num: usize,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> DaftResult<Self> {
+        use rand::{Rng, SeedableRng, distributions::Uniform, rngs::StdRng, seq::index::sample};
+
+        let join_schema = self.schema.union(&right.schema)?;
+        let Some(total) = self.len().checked_mul(right.len()) else {
+            return Err(DaftError::ValueError(format!(
+                "Cannot sample from a cross join of {} and {} rows, which has more rows than can be counted",
+                self.len(),
+                right.len()
+            )));
+        };
+        if num > 0 && total == 0 {
+            return Err(DaftError::ValueError(format!(
+                "Cannot sample {num} rows from an empty cross join"
+            )));
+        }
+        if num > total && !with_replacement {
+            return Err(DaftError::ValueError(format!(
+                "Cannot sample {num} rows from a cross join of only {total} rows without replacement"
+            )));
+        }
+
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(rand::thread_rng()).unwrap(),
+        };
+        let positions: Vec<usize> = if with_replacement {
+            if num == 0 {
+                vec![]
+            } else {
+                rng.sample_iter(Uniform::from(0..total)).take(num).collect()
+            }
+        } else {
+            sample(&mut rng, total, num).into_vec()
+        };
+
+        let right_len = right.len().max(1);
+        let left_idx = UInt64Array::from((
+            "left_indices",
+            positions
+                .iter()
+                .map(|position| (position / right_len) as u64)
+                .collect::<Vec<_>>(),
+        ));
+        let right_idx = UInt64Array::from((
+            "right_indices",
+            positions
+                .iter()
+                .map(|position| (position % right_len) as u64)
+                .collect::<Vec<_>>(),
+        ));
+
+        let mut join_columns = Arc::unwrap_or_clone(self.take(&left_idx)?.columns);
+        join_columns.extend(Arc::unwrap_or_clone(right.take(&right_idx)?.columns));
+
+        Self::new_with_size(join_schema, join_columns, num)
+    }
 }
 
 #[deprecated(since = "TBD", note = "name-referenced columns")]
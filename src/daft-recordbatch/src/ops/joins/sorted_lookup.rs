@@ -0,0 +1,96 @@
+use std::{cmp::Ordering, ops::Range};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::kernels::search_sorted::build_partial_compare_with_nulls;
+
+use crate::RecordBatch;
+
+/// Returns the first index in `0..len` for which `pred` is false, where `pred` is true for a prefix of `0..len`.
+pub(super) fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Finds the range of rows of `right` that are equal to each row of `left` on all of their columns, by binary
+/// searching `right`, which must be sorted in ascending order with nulls last.
+///
+/// Nulls are never equal to each other, so the range of a left row with a null in any column is empty.
+pub fn sorted_lookup_ranges(
+    left: &RecordBatch,
+    right: &RecordBatch,
+) -> DaftResult<Vec<Range<usize>>> {
+    if left.num_columns() != right.num_columns() {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+            left.num_columns(),
+            right.num_columns()
+        )));
+    }
+    if left.num_columns() == 0 {
+        return Err(DaftError::ValueError(
+            "No columns were passed in to join on".to_string(),
+        ));
+    }
+
+    // Short-circuit if any of the join keys are all-null (i.e. have the null dtype).
+    let has_null_type = left.columns.iter().any(|s| s.data_type().is_null())
+        || right.columns.iter().any(|s| s.data_type().is_null());
+    if has_null_type || right.is_empty() {
+        return Ok(vec![0..0; left.len()]);
+    }
+
+    let mut cmp_list = Vec::with_capacity(left.num_columns());
+    for (left_series, right_series) in left.columns.iter().zip(right.columns.iter()) {
+        #[allow(deprecated, reason = "arrow2 migration")]
+        cmp_list.push(build_partial_compare_with_nulls(
+            left_series.to_arrow2().as_ref(),
+            right_series.to_arrow2().as_ref(),
+            false,
+        )?);
+    }
+    // A valid left value is less than a null right value, which is consistent with right nulls sorting last.
+    let combined_comparator = |a_idx: usize, b_idx: usize| -> Ordering {
+        for comparator in &cmp_list {
+            match comparator(a_idx, b_idx) {
+                Some(Ordering::Equal) => {}
+                other => return other.unwrap_or(Ordering::Less),
+            }
+        }
+        Ordering::Equal
+    };
+
+    let left_validities = left
+        .columns
+        .iter()
+        .map(|s| s.validity())
+        .collect::<Vec<_>>();
+    let left_is_valid = |i: usize| {
+        left_validities
+            .iter()
+            .all(|v| v.is_none_or(|v| v.is_valid(i)))
+    };
+
+    Ok((0..left.len())
+        .map(|i| {
+            if !left_is_valid(i) {
+                return 0..0;
+            }
+            let start = partition_point(right.len(), |j| {
+                combined_comparator(i, j) == Ordering::Greater
+            });
+            let end = start
+                + partition_point(right.len() - start, |j| {
+                    combined_comparator(i, start + j) == Ordering::Equal
+                });
+            start..end
+        })
+        .collect())
+}
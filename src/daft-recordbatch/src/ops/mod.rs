@@ -1,14 +1,17 @@
 mod agg;
 mod explode;
+mod filter;
 mod groups;
 pub mod hash;
 mod joins;
 mod partition;
 mod pivot;
+mod resample;
 mod search_sorted;
 mod sort;
 mod unpivot;
 mod window;
 mod window_states;
 
-pub use joins::{get_column_by_name, get_columns_by_name};
+pub use filter::ConjunctObservation;
+pub use joins::{MergeJoinFrontier, get_column_by_name, get_columns_by_name};
@@ -0,0 +1,178 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::arrow::comparison::build_multi_array_is_equal, gap_fill::GapFill, prelude::*,
+};
+
+use crate::RecordBatch;
+
+/// The rows of a resampled table, as the rows of the table they are taken from.
+#[derive(Default)]
+struct ResampledRows {
+    /// The row which the keys of each row are taken from.
+    key_rows: Vec<u64>,
+    /// The start of the interval of each row, in the physical units of the timestamp column.
+    timestamps: Vec<i64>,
+    /// The row of each interval which has a row, or None for the gaps.
+    rows: Vec<Option<u64>>,
+    /// The last row at or before each interval.
+    previous_rows: Vec<u64>,
+    /// The first row at or after each interval.
+    next_rows: Vec<u64>,
+    /// How far each interval is from its previous row towards its next row, from 0 to 1.
+    weights: Vec<f64>,
+}
+
+impl ResampledRows {
+    fn push(&mut self, key_row: u64, timestamp: i64, row: Option<u64>, previous: u64, next: u64) {
+        self.key_rows.push(key_row);
+        self.timestamps.push(timestamp);
+        self.rows.push(row);
+        self.previous_rows.push(previous);
+        self.next_rows.push(next);
+    }
+
+    /// Adds the intervals of one group of keys, from the rows of the group sorted by their timestamps.
+    fn push_group(&mut self, group: &[u64], timestamps: &Int64Array, every: i64) {
+        // A table without rows has a single empty group when it has no keys.
+        let Some(&key_row) = group.first() else {
+            return;
+        };
+        let mut previous: Option<(u64, i64)> = None;
+        for &row in group {
+            // Rows without a timestamp don't belong to any interval.
+            let Some(timestamp) = timestamps.get(row as usize) else {
+                continue;
+            };
+            if let Some((previous_row, previous_timestamp)) = previous {
+                let mut gap = previous_timestamp.checked_add(every);
+                while let Some(gap_timestamp) = gap
+                    && gap_timestamp < timestamp
+                {
+                    self.push(key_row, gap_timestamp, None, previous_row, row);
+                    self.weights.push(
+                        (gap_timestamp - previous_timestamp) as f64
+                            / (timestamp - previous_timestamp) as f64,
+                    );
+                    gap = gap_timestamp.checked_add(every);
+                }
+            }
+            self.push(key_row, timestamp, Some(row), row, row);
+            self.weights.push(0.0);
+            previous = Some((row, timestamp));
+        }
+    }
+}
+
+pub(crate) fn take_with_nulls(series: &Series, indices: &[Option<u64>]) -> DaftResult<Series> {
+    let indices_as_arrow = daft_arrow::array::UInt64Array::from_iter(indices.iter());
+    let indices_as_arr = UInt64Array::from(("", Box::new(indices_as_arrow)));
+    series.take(&indices_as_arr)
+}
+
+impl RecordBatch {
+    /// Fills the gaps of the time series of a table with one row per group of keys and interval, where the
+    /// intervals are `every` apart.
+    ///
+    /// The table must have its `num_keys` key columns first, followed by the column of the starts of the intervals
+    /// and then the value columns, which is how grouping by the keys and the intervals lays it out. Each group of
+    /// keys gets a row for every interval between its first and last intervals, sorted by the interval. The values of
+    /// the rows which fill the gaps are null, or filled from the rows around them with `fill`. Rows without an
+    /// interval are dropped.
+    pub fn fill_time_gaps(
+        &self,
+        num_keys: usize,
+        every: i64,
+        fill: Option<GapFill>,
+    ) -> DaftResult<Self> {
+        if every <= 0 {
+            return Err(DaftError::ValueError(format!(
+                "The interval to resample by must be positive, got {every}"
+            )));
+        }
+        if self.num_columns() <= num_keys {
+            return Err(DaftError::ValueError(format!(
+                "Expected a timestamp column after the {num_keys} key columns to fill the gaps of, got {} columns",
+                self.num_columns()
+            )));
+        }
+
+        let keys = &self.columns[..num_keys];
+        let ts_series = &self.columns[num_keys];
+        let values = &self.columns[num_keys + 1..];
+
+        let timestamps = ts_series.as_physical()?.cast(&DataType::Int64)?;
+        let timestamps = timestamps.i64()?;
+
+        let sort_keys = [keys, std::slice::from_ref(ts_series)].concat();
+        let sorted_indices = Series::argsort_multikey(
+            &sort_keys,
+            &vec![false; sort_keys.len()],
+            &vec![false; sort_keys.len()],
+        )?;
+        let sorted_indices = sorted_indices.as_slice();
+
+        let mut resampled = ResampledRows::default();
+        if keys.is_empty() {
+            resampled.push_group(sorted_indices, timestamps, every);
+        } else {
+            let keys_equal = build_multi_array_is_equal(
+                keys,
+                keys,
+                &vec![true; num_keys],
+                &vec![true; num_keys],
+            )?;
+            let mut group_start = 0;
+            while group_start < sorted_indices.len() {
+                let first_row = sorted_indices[group_start] as usize;
+                let group_len = sorted_indices[group_start..]
+                    .iter()
+                    .take_while(|&&row| keys_equal(first_row, row as usize))
+                    .count();
+                resampled.push_group(
+                    &sorted_indices[group_start..group_start + group_len],
+                    timestamps,
+                    every,
+                );
+                group_start += group_len;
+            }
+        }
+
+        let key_rows = UInt64Array::from(("", resampled.key_rows));
+        let previous_rows = UInt64Array::from(("", resampled.previous_rows));
+        let next_rows = UInt64Array::from(("", resampled.next_rows));
+
+        let mut columns = keys
+            .iter()
+            .map(|series| series.take(&key_rows))
+            .collect::<DaftResult<Vec<_>>>()?;
+        columns.push(
+            Int64Array::from((ts_series.name(), resampled.timestamps))
+                .into_series()
+                .cast(&ts_series.data_type().to_physical())?
+                .cast(ts_series.data_type())?,
+        );
+        for series in values {
+            let filled = match fill {
+                None => take_with_nulls(series, &resampled.rows)?,
+                Some(GapFill::Forward) => series.take(&previous_rows)?,
+                Some(GapFill::Backward) => series.take(&next_rows)?,
+                Some(GapFill::Linear)
+                    if series.data_type().is_integer() || series.data_type().is_floating() =>
+                {
+                    let previous = series.take(&previous_rows)?.cast(&DataType::Float64)?;
+                    let next = series.take(&next_rows)?.cast(&DataType::Float64)?;
+                    let weights = Float64Array::from(("", resampled.weights.clone())).into_series();
+                    let interpolated = (&previous + &(&(&next - &previous)? * &weights)?)?;
+                    interpolated
+                        .cast(&GapFill::Linear.filled_dtype(series.data_type()))?
+                        .rename(series.name())
+                }
+                // Only numeric values can be interpolated, so the gaps of other columns are left null.
+                Some(GapFill::Linear) => take_with_nulls(series, &resampled.rows)?,
+            };
+            columns.push(filled);
+        }
+
+        Self::from_nonempty_columns(columns)
+    }
+}
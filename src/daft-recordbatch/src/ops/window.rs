@@ -2,6 +2,7 @@ use common_error::{DaftError, DaftResult};
 use daft_arrow::buffer::NullBufferBuilder;
 use daft_core::{
     array::ops::{IntoGroups, arrow::comparison::build_multi_array_is_equal},
+    gap_fill::GapFill,
     prelude::*,
 };
 use daft_dsl::{
@@ -11,7 +12,10 @@ use daft_dsl::{
 
 use crate::{
     RecordBatch,
-    ops::window_states::{WindowAggStateOps, create_window_agg_state},
+    ops::{
+        resample::take_with_nulls,
+        window_states::{WindowAggStateOps, create_window_agg_state},
+    },
 };
 
 impl RecordBatch {
@@ -696,6 +700,122 @@ impl RecordBatch {
         self.union(&rank_batch)
     }
 
+    /// Assign session ids to the rows of a sorted partition, starting a new session whenever the
+    /// difference between consecutive values of `expr` exceeds `gap`.
+    ///
+    /// Session ids start at 0 for each partition. A null value, or a null gap, always starts a new session.
+    pub fn window_session_id(
+        &self,
+        name: String,
+        expr: BoundExpr,
+        gap: BoundExpr,
+    ) -> DaftResult<Self> {
+        let session_ids: Vec<u64> = if self.len() < 2 {
+            vec![0; self.len()]
+        } else {
+            let values = self.eval_expression(&expr)?;
+            let gap = self.eval_expression(&gap)?;
+            let gap = if gap.len() == 1 {
+                gap
+            } else {
+                gap.slice(1, self.len())?
+            };
+
+            let current = values.slice(1, self.len())?;
+            let previous = values.slice(0, self.len() - 1)?;
+            let is_new_session = (&current - &previous)?.gt(&gap)?;
+
+            std::iter::once(0)
+                .chain(
+                    is_new_session
+                        .as_arrow2()
+                        .iter()
+                        .scan(0, |session_id, is_new| {
+                            if is_new.unwrap_or(true) {
+                                *session_id += 1;
+                            }
+                            Some(*session_id)
+                        }),
+                )
+                .collect()
+        };
+
+        let session_id_series = UInt64Array::from((name.as_str(), session_ids)).into_series();
+        let session_id_batch = Self::from_nonempty_columns(vec![session_id_series])?;
+
+        self.union(&session_id_batch)
+    }
+
+    /// Fill the nulls of `expr` in a sorted partition from the valid values around them, so values are never filled
+    /// across partitions.
+    ///
+    /// Forward fill takes the last valid value before each null, and backward fill the first valid value after it.
+    /// Linear fill interpolates numeric values by position between the valid values on either side of each run of
+    /// nulls, leaving the nulls before the first and after the last valid values of the partition.
+    pub fn window_fill_null(
+        &self,
+        name: String,
+        expr: BoundExpr,
+        strategy: GapFill,
+    ) -> DaftResult<Self> {
+        let values = self.eval_expression(&expr)?;
+
+        let previous_valid = (0..values.len())
+            .scan(None, |last_valid, row| {
+                if values.is_valid(row) {
+                    *last_valid = Some(row as u64);
+                }
+                Some(*last_valid)
+            })
+            .collect::<Vec<_>>();
+        let mut next_valid = (0..values.len())
+            .rev()
+            .scan(None, |next_valid, row| {
+                if values.is_valid(row) {
+                    *next_valid = Some(row as u64);
+                }
+                Some(*next_valid)
+            })
+            .collect::<Vec<_>>();
+        next_valid.reverse();
+
+        let filled = match strategy {
+            GapFill::Forward => take_with_nulls(&values, &previous_valid)?,
+            GapFill::Backward => take_with_nulls(&values, &next_valid)?,
+            GapFill::Linear => {
+                if !values.data_type().is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Linear interpolation requires a numeric input, got {}",
+                        values.data_type()
+                    )));
+                }
+                let weights = previous_valid.iter().zip(&next_valid).enumerate().map(
+                    |(row, (previous, next))| match (previous, next) {
+                        (Some(previous), Some(next)) if previous < next => {
+                            Some((row as u64 - previous) as f64 / (next - previous) as f64)
+                        }
+                        _ => None,
+                    },
+                );
+                let weights =
+                    Float64Array::from_iter(Field::new("weights", DataType::Float64), weights)
+                        .into_series();
+                let previous =
+                    take_with_nulls(&values, &previous_valid)?.cast(&DataType::Float64)?;
+                let next = take_with_nulls(&values, &next_valid)?.cast(&DataType::Float64)?;
+                let interpolated = (&previous + &(&(&next - &previous)? * &weights)?)?;
+                // Valid values are kept as they are, rather than interpolated between themselves.
+                values
+                    .cast(&DataType::Float64)?
+                    .if_else(&interpolated, &values.not_null()?)?
+                    .cast(&strategy.filled_dtype(values.data_type()))?
+            }
+        };
+
+        let filled_batch = Self::from_nonempty_columns(vec![filled.rename(&name)])?;
+        self.union(&filled_batch)
+    }
+
     pub fn window_offset(
         &self,
         name: String,
@@ -1,53 +1,48 @@
-use std::collections::VecDeque;
+use std::{cmp::Ordering, collections::VecDeque};
 
 use common_error::DaftResult;
-use daft_core::prelude::*;
+use daft_arrow::array::ord::DynComparator;
+use daft_core::{kernels::search_sorted::build_compare_with_nan, prelude::*};
 
 use super::WindowAggStateOps;
 
+/// Sliding window min or max, which keeps the indices of the values which can still become the min or max of the
+/// window in a monotonic deque, so each value is added and removed at most once.
+///
+/// NaNs are ordered after every other value, so they are only the min of a window without any other values, and are
+/// the max of any window which has one.
 pub struct MinMaxWindowState {
     source: Series,
+    compare: DynComparator,
     deque: VecDeque<usize>,
-    cur_idx: usize,
     result_idxs: Vec<u64>,
     validity: daft_arrow::buffer::NullBufferBuilder,
     is_min: bool,
 }
 
 impl MinMaxWindowState {
-    #[allow(dead_code)]
-    pub fn new(source: &Series, total_length: usize, is_min: bool) -> Self {
-        Self {
+    /// Returns None for types whose values can't be compared.
+    pub fn try_new(source: &Series, total_length: usize, is_min: bool) -> Option<Self> {
+        let array = source.to_arrow2();
+        let compare = build_compare_with_nan(array.as_ref(), array.as_ref()).ok()?;
+        Some(Self {
             source: source.clone(),
+            compare,
             deque: VecDeque::new(),
-            cur_idx: 0,
             result_idxs: Vec::with_capacity(total_length),
             validity: daft_arrow::buffer::NullBufferBuilder::new(total_length),
             is_min,
-        }
+        })
     }
 
-    fn should_remove(&self, a_idx: usize, b_idx: usize) -> bool {
-        if !self.source.is_valid(a_idx) {
-            return false;
-        }
-        if !self.source.is_valid(b_idx) {
-            return true;
-        }
-
-        let a_value = self.source.slice(a_idx, a_idx + 1).unwrap();
-        let b_value = self.source.slice(b_idx, b_idx + 1).unwrap();
-
+    /// Whether the value at `new_idx` makes the earlier value at `old_idx` unable to become the min or max of any
+    /// window which has both of them.
+    fn supersedes(&self, new_idx: usize, old_idx: usize) -> bool {
+        let ordering = (self.compare)(new_idx, old_idx);
         if self.is_min {
-            match a_value.lt(&b_value) {
-                Ok(result) => result.get(0).unwrap_or(false),
-                Err(_) => false,
-            }
+            ordering != Ordering::Greater
         } else {
-            match a_value.gt(&b_value) {
-                Ok(result) => result.get(0).unwrap_or(false),
-                Err(_) => false,
-            }
+            ordering != Ordering::Less
         }
     }
 }
@@ -59,13 +54,10 @@ impl WindowAggStateOps for MinMaxWindowState {
                 continue;
             }
 
-            while !self.deque.is_empty() {
-                let back = *self.deque.back().unwrap();
-                if self.should_remove(i, back) {
-                    self.deque.pop_back();
-                } else {
-                    break;
-                }
+            while let Some(&back) = self.deque.back()
+                && self.supersedes(i, back)
+            {
+                self.deque.pop_back();
             }
 
             self.deque.push_back(i);
@@ -74,9 +66,10 @@ impl WindowAggStateOps for MinMaxWindowState {
     }
 
     fn remove(&mut self, _start_idx: usize, end_idx: usize) -> DaftResult<()> {
-        self.cur_idx = end_idx;
-
-        while !self.deque.is_empty() && *self.deque.front().unwrap() < self.cur_idx {
+        // The deque is in the order of the values, so the values which left the window are at its front.
+        while let Some(&front) = self.deque.front()
+            && front < end_idx
+        {
             self.deque.pop_front();
         }
 
@@ -84,24 +77,21 @@ impl WindowAggStateOps for MinMaxWindowState {
     }
 
     fn evaluate(&mut self) -> DaftResult<()> {
-        if self.deque.is_empty() {
+        if let Some(&front) = self.deque.front() {
+            self.validity.append_non_null();
+            self.result_idxs.push(front as u64);
+        } else {
             self.validity.append_null();
             self.result_idxs.push(0);
-        } else {
-            self.validity.append_non_null();
-            self.result_idxs.push(*self.deque.front().unwrap() as u64);
         }
         Ok(())
     }
 
     fn build(&self) -> DaftResult<Series> {
-        let result = self
-            .source
-            .take(&DataArray::<UInt64Type>::from((
-                "",
-                self.result_idxs.clone(),
-            )))
-            .unwrap();
+        let result = self.source.take(&DataArray::<UInt64Type>::from((
+            "",
+            self.result_idxs.clone(),
+        )))?;
         result.with_validity(self.validity.finish_cloned())
     }
 }
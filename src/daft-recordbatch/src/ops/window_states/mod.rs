@@ -9,6 +9,7 @@ use count::CountWindowState;
 use count_distinct::CountDistinctWindowState;
 use daft_core::prelude::*;
 use daft_dsl::{AggExpr, expr::bound_expr::BoundAggExpr};
+use minmax::MinMaxWindowState;
 use sum::SumWindowState;
 
 use crate::RecordBatch;
@@ -46,9 +47,18 @@ pub fn create_window_agg_state(
                 *mode,
             ))))
         }
-        // TODO: Implement once proper behavior regarding NaNs is decided
-        // AggExpr::Min(_) => Ok(Some(Box::new(MinMaxWindowState::new(source, total_length, true)))),
-        // AggExpr::Max(_) => Ok(Some(Box::new(MinMaxWindowState::new(source, total_length, false)))),
+        AggExpr::Min(_) | AggExpr::Max(_) => {
+            let [source] = sources.columns() else {
+                unreachable!("min and max should only have one input")
+            };
+            if source.data_type().is_python() {
+                return Ok(None);
+            }
+
+            let is_min = matches!(agg_expr.as_ref(), AggExpr::Min(_));
+            Ok(MinMaxWindowState::try_new(source, total_length, is_min)
+                .map(|state| Box::new(state) as Box<dyn WindowAggStateOps>))
+        }
         AggExpr::CountDistinct(_) => {
             let [source] = sources.columns() else {
                 unreachable!("count distinct should only have one input")
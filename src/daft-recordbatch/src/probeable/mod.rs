@@ -4,11 +4,15 @@ mod probe_table;
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_core::prelude::SchemaRef;
+use daft_core::{
+    prelude::{BooleanArray, SchemaRef, UInt64Array},
+    series::IntoSeries,
+};
+use daft_dsl::expr::bound_expr::BoundExpr;
 use probe_set::{ProbeSet, ProbeSetBuilder};
 use probe_table::{ProbeTable, ProbeTableBuilder};
 
-use crate::RecordBatch;
+use crate::{GrowableRecordBatch, RecordBatch};
 
 struct ArrowTableEntry(Vec<Box<dyn daft_arrow::array::Array>>);
 
@@ -85,6 +89,50 @@ pub trait Probeable: Send + Sync {
     ) -> DaftResult<Box<dyn Iterator<Item = bool> + 'a>>;
 }
 
+/// The pairs of build and probe rows that matched when probing a record batch.
+pub struct ProbeMatches {
+    /// The build side row of each pair.
+    pub build_side: RecordBatch,
+    /// The probe side row of each pair.
+    pub probe_side: RecordBatch,
+    /// The (record batch index, row index) of the build side row of each pair.
+    pub build_idxs: Vec<(u32, u64)>,
+    /// The index of the probe side row of each pair in the probed record batch.
+    pub probe_idxs: Vec<u64>,
+}
+
+impl ProbeMatches {
+    /// Keeps only the pairs that satisfy a residual join predicate, which is bound to the columns of the left side
+    /// followed by the columns of the right side.
+    fn filter_residual(self, residual: &BoundExpr, build_on_left: bool) -> DaftResult<Self> {
+        let mask = if build_on_left {
+            self.build_side
+                .eval_join_residual(&self.probe_side, residual)?
+        } else {
+            self.probe_side
+                .eval_join_residual(&self.build_side, residual)?
+        };
+        let mask_series = BooleanArray::from(("mask", mask.as_slice())).into_series();
+
+        Ok(Self {
+            build_side: self.build_side.mask_filter(&mask_series)?,
+            probe_side: self.probe_side.mask_filter(&mask_series)?,
+            build_idxs: self
+                .build_idxs
+                .into_iter()
+                .zip(mask.iter())
+                .filter_map(|(idx, keep)| keep.then_some(idx))
+                .collect(),
+            probe_idxs: self
+                .probe_idxs
+                .into_iter()
+                .zip(mask.iter())
+                .filter_map(|(idx, keep)| keep.then_some(idx))
+                .collect(),
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct ProbeState {
     probeable: Arc<dyn Probeable>,
@@ -120,6 +168,51 @@ impl ProbeState {
             .map(|indices_mapper| indices_mapper.make_iter())
     }
 
+    /// Probes the input table with its evaluated join keys, and returns the matching pairs of build and probe rows.
+    ///
+    /// If a residual predicate is given, only the pairs that satisfy it are returned. `build_on_left` determines
+    /// whether the build side provides the left or the right columns that the residual is bound to.
+    /// NOTE: This function only works if track_indices is true.
+    pub fn probe_matches(
+        &self,
+        input: &RecordBatch,
+        join_keys: &RecordBatch,
+        residual: Option<&BoundExpr>,
+        build_on_left: bool,
+    ) -> DaftResult<ProbeMatches> {
+        const DEFAULT_GROWABLE_SIZE: usize = 20;
+
+        let build_side_tables = self.record_batches.iter().collect::<Vec<_>>();
+        let mut build_side_growable =
+            GrowableRecordBatch::new(&build_side_tables, false, DEFAULT_GROWABLE_SIZE)?;
+        let mut build_idxs = Vec::new();
+        let mut probe_idxs = Vec::new();
+
+        for (probe_row_idx, inner_iter) in self.probe_indices(join_keys)?.enumerate() {
+            if let Some(inner_iter) = inner_iter {
+                for (build_rb_idx, build_row_idx) in inner_iter {
+                    build_side_growable.extend(build_rb_idx as usize, build_row_idx as usize, 1);
+                    build_idxs.push((build_rb_idx, build_row_idx));
+                    probe_idxs.push(probe_row_idx as u64);
+                }
+            }
+        }
+
+        let build_side = build_side_growable.build()?;
+        let probe_side = input.take(&UInt64Array::from(("", probe_idxs.as_slice())))?;
+        let matches = ProbeMatches {
+            build_side,
+            probe_side,
+            build_idxs,
+            probe_idxs,
+        };
+
+        match residual {
+            Some(residual) => matches.filter_residual(residual, build_on_left),
+            None => Ok(matches),
+        }
+    }
+
     pub fn get_record_batches(&self) -> &[RecordBatch] {
         &self.record_batches
     }
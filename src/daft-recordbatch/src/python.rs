@@ -192,10 +192,11 @@ impl PyRecordBatch {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
         is_sorted: bool,
     ) -> PyResult<Self> {
         let left_exprs = BoundExpr::bind_all(&left_on, &self.record_batch.schema)?;
-        let right_exprs = BoundExpr::bind_all(&right_on, &self.record_batch.schema)?;
+        let right_exprs = BoundExpr::bind_all(&right_on, &right.record_batch.schema)?;
         py.detach(|| {
             Ok(self
                 .record_batch
@@ -203,12 +204,42 @@ impl PyRecordBatch {
                     &right.record_batch,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
+                    how,
                     is_sorted,
                 )?
                 .into())
         })
     }
 
+    pub fn sorted_lookup_join(
+        &self,
+        py: Python,
+        right: &Self,
+        left_on: Vec<PyExpr>,
+        right_on: Vec<PyExpr>,
+        how: JoinType,
+        is_sorted: bool,
+    ) -> PyResult<Self> {
+        let left_exprs = BoundExpr::bind_all(&left_on, &self.record_batch.schema)?;
+        let right_exprs = BoundExpr::bind_all(&right_on, &right.record_batch.schema)?;
+        py.detach(|| {
+            let right = if is_sorted {
+                right.record_batch.clone()
+            } else {
+                right.record_batch.sort(
+                    &right_exprs,
+                    &vec![false; right_exprs.len()],
+                    &vec![false; right_exprs.len()],
+                )?
+            };
+            let right_keys = right.eval_expression_list(&right_exprs)?;
+            Ok(self
+                .record_batch
+                .sorted_lookup_join(&right, left_exprs.as_slice(), &right_keys, how)?
+                .into())
+        })
+    }
+
     pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
         let converted_to_explode = BoundExpr::bind_all(&to_explode, &self.record_batch.schema)?;
 
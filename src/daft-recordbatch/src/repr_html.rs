@@ -113,6 +113,10 @@ pub fn html_value(s: &Series, idx: usize, truncate: bool) -> String {
             let arr = s.downcast::<ExtensionArray>().unwrap();
             arr.html_value(idx, truncate)
         }
+        DataType::Categorical => {
+            let arr = s.categorical().unwrap();
+            arr.html_value(idx, truncate)
+        }
         DataType::Embedding(_, _) => {
             let arr = s.embedding().unwrap();
             arr.html_value(idx, truncate)
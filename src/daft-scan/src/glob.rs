@@ -1,4 +1,8 @@
-use std::{sync::Arc, vec};
+use std::{
+    hash::{Hash, Hasher},
+    sync::Arc,
+    vec,
+};
 
 use common_error::{DaftError, DaftResult};
 use common_file_formats::{CsvSourceConfig, FileFormat, FileFormatConfig, ParquetSourceConfig};
@@ -15,7 +19,10 @@ use daft_schema::{
     field::Field,
     schema::{Schema, SchemaRef},
 };
-use daft_stats::{PartitionSpec, TableMetadata};
+use daft_stats::{
+    PartitionSpec, TableMetadata,
+    pruning::{PruneReason, record_pruned},
+};
 use futures::{Stream, StreamExt, TryStreamExt, stream::BoxStream};
 use snafu::Snafu;
 
@@ -88,6 +95,7 @@ fn generate_metadata_from_manifest(
             filepath: path.clone(),
             size: None,
             filetype: FileType::File,
+            etag: None,
         })
     })
 }
@@ -117,6 +125,7 @@ fn run_glob_parallel(
     runtime: RuntimeRef,
     io_stats: Option<IOStatsRef>,
     file_format: FileFormat,
+    limit: Option<usize>,
 ) -> DaftResult<impl Iterator<Item = DaftResult<FileMetadata>>> {
     let num_parallel_tasks = 64;
 
@@ -129,7 +138,7 @@ fn run_glob_parallel(
 
         runtime.spawn(async move {
             let stream = io_client
-                .glob(glob_input, None, None, None, io_stats, Some(file_format))
+                .glob(glob_input, None, None, limit, io_stats, Some(file_format))
                 .await?;
             let results = stream.map_err(|e| e.into()).collect::<Vec<_>>().await;
             DaftResult::Ok(futures::stream::iter(results))
@@ -488,6 +497,52 @@ impl ScanOperator for GlobScanOperator {
         lines
     }
 
+    fn hash_source_versions(&self, mut state: &mut dyn Hasher) -> DaftResult<()> {
+        let (io_runtime, io_client) = self.storage_config.get_io_client_and_runtime()?;
+        let io_stats = IOStatsContext::new(format!(
+            "GlobScanOperator::hash_source_versions for {:#?}",
+            self.glob_paths
+        ));
+        let files = if self.skip_glob {
+            generate_metadata_from_manifest(&self.glob_paths).collect::<DaftResult<Vec<_>>>()?
+        } else {
+            run_glob_parallel(
+                self.glob_paths.clone(),
+                io_client.clone(),
+                io_runtime.clone(),
+                Some(io_stats.clone()),
+                self.file_format_config.file_format(),
+                None,
+            )?
+            .collect::<DaftResult<Vec<_>>>()?
+        };
+
+        // Files which are named by their path rather than listed, such as those of a manifest, are asked for their
+        // entity tags.
+        let versions = io_runtime.block_on_current_thread(async {
+            futures::stream::iter(files.into_iter().map(|file| {
+                let io_client = io_client.clone();
+                let io_stats = io_stats.clone();
+                async move {
+                    let etag = match file.etag {
+                        Some(etag) => Some(etag),
+                        None => {
+                            io_client
+                                .single_url_get_etag(file.filepath.clone(), Some(io_stats))
+                                .await?
+                        }
+                    };
+                    DaftResult::Ok((file.filepath, file.size, etag))
+                }
+            }))
+            .buffered(64)
+            .try_collect::<Vec<_>>()
+            .await
+        })?;
+        versions.hash(&mut state);
+        Ok(())
+    }
+
     fn to_scan_tasks(&self, pushdowns: Pushdowns) -> DaftResult<Vec<ScanTaskLikeRef>> {
         let (io_runtime, io_client) = self.storage_config.get_io_client_and_runtime()?;
         let io_stats = IOStatsContext::new(format!(
@@ -495,6 +550,10 @@ impl ScanOperator for GlobScanOperator {
             self.glob_paths
         ));
         let file_format = self.file_format_config.file_format();
+        // Files may be skipped by partition filters, so listing can only stop at the file limit without them.
+        let glob_limit = pushdowns
+            .file_limit
+            .filter(|_| pushdowns.partition_filters.is_none());
 
         let files: Box<dyn Iterator<Item = DaftResult<FileMetadata>>> = if self.skip_glob {
             Box::new(generate_metadata_from_manifest(&self.glob_paths))
@@ -505,6 +564,7 @@ impl ScanOperator for GlobScanOperator {
                 io_runtime,
                 Some(io_stats),
                 file_format,
+                glob_limit,
             )?)
         };
 
@@ -564,14 +624,21 @@ impl ScanOperator for GlobScanOperator {
                             RecordBatch::from_nonempty_columns(partition_values)?;
                         // If there are partition values, evaluate them against partition filters, if any.
                         if let Some(partition_filters) = &pushdowns.partition_filters {
-                            let partition_filters = BoundExpr::try_new(
+                            let bound_partition_filters = BoundExpr::try_new(
                                 partition_filters.clone(),
                                 &partition_values_table.schema,
                             )?;
                             let filter_result =
-                                partition_values_table.filter(&[partition_filters])?;
+                                partition_values_table.filter(&[bound_partition_filters])?;
                             if filter_result.is_empty() {
                                 // Skip the current file since it does not satisfy the partition filters.
+                                record_pruned(
+                                    &path,
+                                    None,
+                                    None,
+                                    PruneReason::PartitionValues,
+                                    partition_filters,
+                                );
                                 return Ok(None);
                             }
                         }
@@ -618,6 +685,7 @@ impl ScanOperator for GlobScanOperator {
                     Err(e) => Some(Err(e)),
                 }
             })
+            .take(pushdowns.file_limit.unwrap_or(usize::MAX))
             .collect()
     }
 }
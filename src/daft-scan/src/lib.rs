@@ -2,7 +2,7 @@ use std::{
     any::Any,
     borrow::Cow,
     collections::HashMap,
-    fmt::Debug,
+    fmt::{Debug, Display},
     hash::{Hash, Hasher},
     sync::{Arc, OnceLock},
 };
@@ -133,6 +133,15 @@ impl ChunkSpec {
     }
 }
 
+impl Display for ChunkSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parquet(row_groups) => write!(f, "row groups {row_groups:?}"),
+            Self::Bytes { start, end } => write!(f, "bytes [{start}, {end})"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DataSource {
     File {
@@ -1016,7 +1025,9 @@ mod test {
     use daft_stats::TableMetadata;
     use itertools::Itertools;
 
-    use crate::{DataSource, ScanTask, glob::GlobScanOperator, storage_config::StorageConfig};
+    use crate::{
+        ChunkSpec, DataSource, ScanTask, glob::GlobScanOperator, storage_config::StorageConfig,
+    };
 
     fn make_scan_task(num_sources: usize) -> ScanTask {
         let sources = (0..num_sources)
@@ -1137,6 +1148,18 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_chunk_spec_display() {
+        assert_eq!(
+            ChunkSpec::Parquet(vec![0, 2]).to_string(),
+            "row groups [0, 2]"
+        );
+        assert_eq!(
+            ChunkSpec::Bytes { start: 10, end: 20 }.to_string(),
+            "bytes [10, 20)"
+        );
+    }
+
     #[test]
     fn test_display_no_condense() -> DaftResult<()> {
         let scan_task = make_scan_task(6);
@@ -81,7 +81,10 @@ pub mod pylib {
     use daft_logical_plan::{LogicalPlanBuilder, PyLogicalPlanBuilder};
     use daft_recordbatch::{RecordBatch, python::PyRecordBatch};
     use daft_schema::{python::schema::PySchema, schema::SchemaRef};
-    use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
+    use daft_stats::{
+        PartitionSpec, TableMetadata, TableStatistics,
+        pruning::{PruneReason, record_pruned},
+    };
     use pyo3::{prelude::*, pyclass, types::PyIterator};
     use serde::{Deserialize, Serialize};
 
@@ -479,9 +482,9 @@ pub mod pylib {
                     pushdowns.as_ref().map(|p| &p.0.partition_filters)
             {
                 let table = &pvalues.record_batch;
-                let partition_filters =
+                let bound_partition_filters =
                     BoundExpr::try_new(partition_filters.clone(), &table.schema)?;
-                let eval_pred = table.eval_expression_list(&[partition_filters])?;
+                let eval_pred = table.eval_expression_list(&[bound_partition_filters])?;
                 assert_eq!(eval_pred.num_columns(), 1);
                 let series = eval_pred.get_column(0);
                 assert_eq!(series.data_type(), &daft_core::datatypes::DataType::Boolean);
@@ -489,7 +492,16 @@ pub mod pylib {
                 assert_eq!(boolean.len(), 1);
                 let value = boolean.get(0);
                 match value {
-                    None | Some(false) => return Ok(None),
+                    None | Some(false) => {
+                        record_pruned(
+                            &file,
+                            None,
+                            None,
+                            PruneReason::PartitionValues,
+                            partition_filters,
+                        );
+                        return Ok(None);
+                    }
                     Some(true) => {}
                 }
             }
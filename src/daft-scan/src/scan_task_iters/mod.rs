@@ -324,6 +324,11 @@ fn split_and_merge_pass(
     pushdowns: &Pushdowns,
     cfg: &DaftExecutionConfig,
 ) -> DaftResult<Arc<Vec<ScanTaskLikeRef>>> {
+    // The limit of a scan with a file limit is a limit per file, which only holds as long as each task reads the one
+    // file it was created for, so its tasks are neither split nor merged.
+    if pushdowns.file_limit.is_some() {
+        return Ok(scan_tasks);
+    }
     // Perform scan task splitting and merging if there are only ScanTasks (i.e. no DummyScanTasks).
     if scan_tasks
         .iter()
@@ -136,6 +136,10 @@ pub enum DataType {
     /// A logical type for sparse tensors with the same shape.
     FixedShapeSparseTensor(Box<DataType>, Vec<u64>, bool),
 
+    /// A logical type for strings with few distinct values, stored as [`u32`] codes into a dictionary of the
+    /// distinct strings.
+    Categorical,
+
     #[cfg(feature = "python")]
     Python,
 
@@ -175,6 +179,7 @@ impl Display for DataType {
             Self::Binary => write!(f, "Binary"),
             Self::FixedSizeBinary(size) => write!(f, "Binary[{size}]"),
             Self::Utf8 => write!(f, "String"),
+            Self::Categorical => write!(f, "Categorical"),
             Self::FixedSizeList(child_dtype, size) => write!(f, "List[{child_dtype}; {size}]"),
             Self::List(child_dtype) => write!(f, "List[{child_dtype}]"),
             Self::Struct(fields) => {
@@ -311,6 +316,10 @@ impl DataType {
             }
             Self::Date => arrow_schema::DataType::Date32,
             Self::Time(time_unit) => arrow_schema::DataType::Time64(time_unit.to_arrow()),
+            Self::Categorical => arrow_schema::DataType::Dictionary(
+                Box::new(arrow_schema::DataType::UInt32),
+                Box::new(arrow_schema::DataType::LargeUtf8),
+            ),
 
             _ => {
                 return Err(DaftError::TypeError(format!(
@@ -352,6 +361,11 @@ impl DataType {
             Self::Binary => Ok(ArrowType::LargeBinary),
             Self::FixedSizeBinary(size) => Ok(ArrowType::FixedSizeBinary(*size)),
             Self::Utf8 => Ok(ArrowType::LargeUtf8),
+            Self::Categorical => Ok(ArrowType::Dictionary(
+                daft_arrow::datatypes::IntegerType::UInt32,
+                Box::new(ArrowType::LargeUtf8),
+                false,
+            )),
             Self::FixedSizeList(child_dtype, size) => Ok(ArrowType::FixedSizeList(
                 Box::new(daft_arrow::datatypes::Field::new(
                     "item",
@@ -423,6 +437,7 @@ impl DataType {
         match self {
             Date => Int32,
             Duration(_) | Timestamp(..) | Time(_) => Int64,
+            Categorical => UInt32,
 
             List(child_dtype) => List(Box::new(child_dtype.to_physical())),
             FixedSizeList(child_dtype, size) => {
@@ -644,6 +659,11 @@ impl DataType {
         matches!(self, Self::Utf8)
     }
 
+    #[inline]
+    pub fn is_categorical(&self) -> bool {
+        matches!(self, Self::Categorical)
+    }
+
     #[inline]
     pub fn is_boolean(&self) -> bool {
         matches!(self, Self::Boolean)
@@ -781,6 +801,25 @@ impl DataType {
         }
     }
 
+    /// Whether this type or any type nested within it is [`DataType::Python`].
+    pub fn contains_python(&self) -> bool {
+        match self {
+            #[cfg(feature = "python")]
+            Self::Python => true,
+            Self::List(inner)
+            | Self::FixedSizeList(inner, _)
+            | Self::Extension(_, inner, _)
+            | Self::Embedding(inner, _)
+            | Self::Tensor(inner)
+            | Self::FixedShapeTensor(inner, _)
+            | Self::SparseTensor(inner, _)
+            | Self::FixedShapeSparseTensor(inner, _, _) => inner.contains_python(),
+            Self::Map { key, value } => key.contains_python() || value.contains_python(),
+            Self::Struct(fields) => fields.iter().any(|f| f.dtype.contains_python()),
+            _ => false,
+        }
+    }
+
     #[inline]
     pub fn is_file(&self) -> bool {
         match self {
@@ -892,6 +931,7 @@ impl DataType {
                 | Self::FixedShapeSparseTensor(..)
                 | Self::Map { .. }
                 | Self::File(..)
+                | Self::Categorical
         )
     }
 
@@ -1089,6 +1129,11 @@ impl From<&ArrowType> for DataType {
             ArrowType::FixedSizeBinary(size) => Self::FixedSizeBinary(*size),
             ArrowType::Binary | ArrowType::LargeBinary => Self::Binary,
             ArrowType::Utf8 | ArrowType::LargeUtf8 => Self::Utf8,
+            ArrowType::Dictionary(_, values, _)
+                if matches!(values.as_ref(), ArrowType::Utf8 | ArrowType::LargeUtf8) =>
+            {
+                Self::Categorical
+            }
             ArrowType::Decimal(precision, scale) => Self::Decimal128(*precision, *scale),
             ArrowType::List(field) | ArrowType::LargeList(field) => {
                 Self::List(Box::new(field.as_ref().data_type().into()))
@@ -1173,6 +1218,14 @@ impl TryFrom<&arrow_schema::DataType> for DataType {
             arrow_schema::DataType::LargeBinary => Self::Binary,
 
             arrow_schema::DataType::LargeUtf8 => Self::Utf8,
+            arrow_schema::DataType::Dictionary(_, values)
+                if matches!(
+                    values.as_ref(),
+                    arrow_schema::DataType::Utf8 | arrow_schema::DataType::LargeUtf8
+                ) =>
+            {
+                Self::Categorical
+            }
 
             arrow_schema::DataType::FixedSizeList(field, size) => {
                 Self::FixedSizeList(Box::new(field.as_ref().try_into()?), *size as _)
@@ -1289,6 +1342,7 @@ mod test {
     #[case(DataType::Binary)]
     #[case(DataType::FixedSizeBinary(16))]
     #[case(DataType::Utf8)]
+    #[case(DataType::Categorical)]
     #[case(DataType::Decimal128(10, 2))]
     #[case(DataType::List(Box::new(DataType::Int32)))]
     #[case(DataType::List(Box::new(DataType::Utf8)))]
@@ -174,6 +174,11 @@ impl PyDataType {
         Ok(DataType::Utf8.into())
     }
 
+    #[staticmethod]
+    pub fn categorical() -> PyResult<Self> {
+        Ok(DataType::Categorical.into())
+    }
+
     #[staticmethod]
     pub fn decimal128(precision: usize, scale: usize) -> PyResult<Self> {
         Ok(DataType::Decimal128(precision, scale).into())
@@ -455,6 +460,10 @@ impl PyDataType {
         self.dtype.is_string()
     }
 
+    pub fn is_categorical(&self) -> bool {
+        self.dtype.is_categorical()
+    }
+
     pub fn is_fixed_size_list(&self) -> bool {
         self.dtype.is_fixed_size_list()
     }
@@ -507,6 +516,10 @@ impl PyDataType {
         self.dtype.is_python()
     }
 
+    pub fn contains_python(&self) -> bool {
+        self.dtype.contains_python()
+    }
+
     pub fn is_numeric(&self) -> bool {
         self.dtype.is_numeric()
     }
@@ -6,6 +6,9 @@ use daft_catalog::{
     python::{PyIdentifier, PyTableSource, pyobj_to_catalog, pyobj_to_table},
 };
 use daft_dsl::functions::python::WrappedUDFClass;
+use daft_logical_plan::PyLogicalPlanBuilder;
+use daft_recordbatch::python::PyRecordBatch;
+use daft_stats::AnalyzedTableStatistics;
 use pyo3::prelude::*;
 
 use crate::Session;
@@ -19,6 +22,12 @@ impl PySession {
     }
 }
 
+impl From<Session> for PySession {
+    fn from(session: Session) -> Self {
+        Self(session)
+    }
+}
+
 #[pymethods]
 impl PySession {
     #[staticmethod]
@@ -101,6 +110,33 @@ impl PySession {
         self.0.get_table(ident.as_ref())?.to_py(py)
     }
 
+    /// Stores the statistics collected for a table, after checking that they can be used when it's read by the builder.
+    pub fn set_table_stats(
+        &self,
+        ident: &PyIdentifier,
+        stats: &PyRecordBatch,
+        builder: &PyLogicalPlanBuilder,
+    ) -> PyResult<()> {
+        let stats = AnalyzedTableStatistics::from_analyze_table(&stats.record_batch)?;
+        // Check that the statistics can be used when the table is read before storing them.
+        builder
+            .builder
+            .with_analyzed_stats(Arc::new(stats.clone()))?;
+        self.0.set_table_stats(ident.as_ref(), stats)?;
+        Ok(())
+    }
+
+    pub fn with_table_stats(
+        &self,
+        ident: &PyIdentifier,
+        builder: &PyLogicalPlanBuilder,
+    ) -> PyResult<PyLogicalPlanBuilder> {
+        match self.0.get_table_stats(ident.as_ref())? {
+            Some(stats) => Ok(builder.builder.with_analyzed_stats(stats)?.into()),
+            None => Ok(builder.clone()),
+        }
+    }
+
     pub fn has_catalog(&self, name: &str) -> PyResult<bool> {
         Ok(self.0.has_catalog(name))
     }
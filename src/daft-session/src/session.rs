@@ -1,8 +1,14 @@
-use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
 
+use common_error::DaftError;
 use daft_ai::provider::ProviderRef;
 use daft_catalog::{Bindings, CatalogRef, Identifier, TableRef, TableSource, View};
 use daft_dsl::functions::python::WrappedUDFClass;
+use daft_stats::AnalyzedTableStatistics;
 use uuid::Uuid;
 
 use crate::{
@@ -13,6 +19,10 @@ use crate::{
     unsupported_err,
 };
 
+/// Environment variable with a directory which the statistics collected by `ANALYZE` on catalog tables are persisted to,
+/// as a JSON sidecar file per table, so that later sessions use them too.
+const TABLE_STATS_DIR_ENV_VAR: &str = "DAFT_TABLE_STATS_DIR";
+
 /// Session holds all state for query planning and execution (e.g. connection).
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct Session {
@@ -36,6 +46,9 @@ struct SessionState {
     tables: Bindings<TableRef>,
     /// User defined functions
     functions: Bindings<WrappedUDFClass>,
+    /// Statistics collected by `ANALYZE`, keyed by the identifier the table was analyzed with, including the statistics
+    /// which were loaded from `DAFT_TABLE_STATS_DIR`.
+    table_stats: HashMap<Identifier, Arc<AnalyzedTableStatistics>>,
 }
 
 // TODO: Session should just use a Result not CatalogResult.
@@ -73,6 +86,7 @@ impl Session {
             providers: Bindings::empty(),
             tables: Bindings::empty(),
             functions: Bindings::empty(),
+            table_stats: HashMap::new(),
         };
         let state = RwLock::new(state);
         let state = Arc::new(state);
@@ -171,6 +185,10 @@ impl Session {
             TableSource::Schema(_) => unsupported_err!("temporary table with schema"),
             TableSource::View(plan) => Arc::new(View::new(&name, plan.clone())),
         };
+        // stats of a replaced table no longer describe it.
+        self.state_mut()
+            .table_stats
+            .remove(&Identifier::simple(name.clone()));
         self.state_mut().tables.bind(name, table.clone());
         Ok(table)
     }
@@ -209,6 +227,9 @@ impl Session {
             obj_not_found_err!("Table", &alias.into())
         }
         self.state_mut().tables.remove(alias);
+        self.state_mut()
+            .table_stats
+            .remove(&Identifier::simple(alias));
         Ok(())
     }
 
@@ -313,6 +334,66 @@ impl Session {
         obj_not_found_err!("Table", name)
     }
 
+    /// Returns the statistics collected by `ANALYZE` for the table, if any.
+    pub fn get_table_stats(
+        &self,
+        name: &Identifier,
+    ) -> CatalogResult<Option<Arc<AnalyzedTableStatistics>>> {
+        if let Some(stats) = self.state().table_stats.get(name) {
+            return Ok(Some(stats.clone()));
+        }
+        let Some(path) = self.table_stats_path(name) else {
+            return Ok(None);
+        };
+        let stats = match std::fs::read(&path) {
+            Ok(bytes) => Arc::new(
+                serde_json::from_slice::<AnalyzedTableStatistics>(&bytes)
+                    .map_err(DaftError::from)?,
+            ),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(DaftError::from(e).into()),
+        };
+        self.state_mut()
+            .table_stats
+            .insert(name.clone(), stats.clone());
+        Ok(Some(stats))
+    }
+
+    /// Stores the statistics collected by `ANALYZE` for the table, and persists them if it's a catalog table.
+    ///
+    /// The statistics are merged into those which were previously collected, so analyzing some of the columns of a
+    /// table replaces the statistics of those columns and keeps the statistics of the others.
+    pub fn set_table_stats(
+        &self,
+        name: &Identifier,
+        stats: AnalyzedTableStatistics,
+    ) -> CatalogResult<()> {
+        let stats = match self.get_table_stats(name)? {
+            Some(previous) => previous.update(&stats),
+            None => stats,
+        };
+        if let Some(path) = self.table_stats_path(name) {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(DaftError::from)?;
+            }
+            let bytes = serde_json::to_vec(&stats).map_err(DaftError::from)?;
+            std::fs::write(&path, bytes).map_err(DaftError::from)?;
+        }
+        self.state_mut()
+            .table_stats
+            .insert(name.clone(), Arc::new(stats));
+        Ok(())
+    }
+
+    /// Returns the sidecar file which the statistics of a table are persisted to, if any. Temp tables only live as long
+    /// as the session, so their statistics aren't persisted.
+    fn table_stats_path(&self, name: &Identifier) -> Option<PathBuf> {
+        let dir = std::env::var(TABLE_STATS_DIR_ENV_VAR).ok()?;
+        let is_temp_table = !name.has_qualifier()
+            && matches!(self.state().get_attached_table(name.name()), Ok(Some(_)));
+        (!is_temp_table).then(|| PathBuf::from(dir).join(table_stats_file_name(name)))
+    }
+
     /// Returns true iff the session has access to a matching catalog.
     pub fn has_catalog(&self, name: &str) -> bool {
         self.state().catalogs.contains(name)
@@ -448,6 +529,27 @@ impl Default for Session {
     }
 }
 
+/// Returns the name of the file which the statistics of a table are persisted to.
+///
+/// The parts of the identifier are escaped so that the file is always directly in the statistics directory: every byte
+/// other than an ASCII letter, digit, `_` or `-` is percent-encoded, and the escaped parts are joined with `.`.
+fn table_stats_file_name(name: &Identifier) -> String {
+    let parts = (0..name.len())
+        .map(|i| {
+            name.get(i)
+                .bytes()
+                .map(|b| match b {
+                    b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'-' => {
+                        (b as char).to_string()
+                    }
+                    b => format!("%{b:02X}"),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    format!("{}.json", parts.join("."))
+}
+
 /// Migrated from daft-catalog DaftMetaCatalog tests
 #[cfg(test)]
 mod tests {
@@ -502,4 +604,24 @@ mod tests {
                 .is_err()
         );
     }
+
+    #[test]
+    fn test_table_stats_file_name() {
+        assert_eq!(
+            table_stats_file_name(&Identifier::qualified(["db"], "events")),
+            "db.events.json"
+        );
+        assert_eq!(
+            table_stats_file_name(&Identifier::new(["a.b", "c"])),
+            "a%2Eb.c.json"
+        );
+        assert_eq!(
+            table_stats_file_name(&Identifier::simple("../../etc/passwd")),
+            "%2E%2E%2F%2E%2E%2Fetc%2Fpasswd.json"
+        );
+        assert_eq!(
+            table_stats_file_name(&Identifier::simple("..")),
+            "%2E%2E.json"
+        );
+    }
 }
@@ -26,6 +26,7 @@ pub(crate) fn execute_statement(
     let stmt = SQLPlanner::new(sess).with_ctes(ctes).plan(statement)?;
     match stmt {
         Statement::Select(select) => execute_select(sess, select),
+        Statement::Analyze(analyze) => execute_analyze(sess, analyze),
         Statement::Set(set) => execute_set(sess, set),
         Statement::Use(use_) => execute_use(sess, use_),
         Statement::ShowTables(show_tables) => execute_show_tables(sess, show_tables),
@@ -36,6 +37,54 @@ fn execute_select(_: &Session, select: DataFrame) -> SQLPlannerResult<Option<Dat
     Ok(Some(select))
 }
 
+/// The number of buckets of the histograms collected by `ANALYZE TABLE`.
+#[cfg(feature = "python")]
+const ANALYZE_NUM_BUCKETS: usize = 32;
+
+/// Collects the statistics of the table by executing the analyze plan of its scan, stores them in the session, then
+/// returns them.
+#[cfg(feature = "python")]
+fn execute_analyze(
+    sess: &Session,
+    analyze: statement::Analyze,
+) -> SQLPlannerResult<Option<DataFrame>> {
+    use common_error::DaftError;
+    use daft_dsl::unresolved_col;
+    use daft_stats::AnalyzedTableStatistics;
+
+    let table = sess.get_table(&analyze.table)?.to_logical_plan()?;
+    let scan = match analyze.columns {
+        Some(columns) => table.select(columns.into_iter().map(unresolved_col).collect())?,
+        None => table.clone(),
+    };
+    let plan = scan.analyze(ANALYZE_NUM_BUCKETS)?;
+    let runner = daft_runners::get_or_create_runner()?;
+    let batches = pyo3::Python::attach(|py| {
+        let mut batches = vec![];
+        for part in runner.run_iter_tables(py, plan.clone(), None)? {
+            batches.extend_from_slice(part?.record_batches());
+        }
+        Ok::<_, DaftError>(batches)
+    })?;
+
+    let stats = AnalyzedTableStatistics::from_analyze_table(&RecordBatch::concat_or_empty(
+        &batches,
+        Some(plan.schema()),
+    )?)?;
+    // Check that the statistics can be used when the table is read before storing them.
+    table.with_analyzed_stats(Arc::new(stats.clone()))?;
+    sess.set_table_stats(&analyze.table, stats)?;
+
+    let part = MicroPartition::new_loaded(plan.schema(), Arc::new(batches), None);
+    let scan = logical_plan_from_micropartitions(vec![part])?;
+    Ok(Some(scan.build()))
+}
+
+#[cfg(not(feature = "python"))]
+fn execute_analyze(_: &Session, _: statement::Analyze) -> SQLPlannerResult<Option<DataFrame>> {
+    unsupported_sql_err!("ANALYZE TABLE requires 'python' feature")
+}
+
 fn execute_set(_: &Session, _: statement::Set) -> SQLPlannerResult<Option<DataFrame>> {
     unsupported_sql_err!("SET statement")
 }
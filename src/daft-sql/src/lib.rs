@@ -28,11 +28,11 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
 mod tests {
     use std::sync::{Arc, LazyLock};
 
-    use daft_core::prelude::*;
+    use daft_core::{join::JoinSide, prelude::*};
     use daft_dsl::{Expr, ExprRef, PlanRef, Subquery, UnresolvedColumn, lit, unresolved_col};
     use daft_logical_plan::{
         ClusteringSpec, JoinOptions, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
-        logical_plan::Source, source_info::PlaceHolderInfo,
+        logical_plan::Source, ops::Join, source_info::PlaceHolderInfo,
     };
     use daft_session::Session;
     use error::SQLPlannerResult;
@@ -388,6 +388,79 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::broadcast_right(
+        "select /*+ BROADCAST(tbl3) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id",
+        Some(JoinStrategy::Broadcast),
+        Some(JoinSide::Right)
+    )]
+    #[case::broadcast_left(
+        "select /*+ BROADCAST(tbl2) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id",
+        Some(JoinStrategy::Broadcast),
+        Some(JoinSide::Left)
+    )]
+    #[case::merge_left(
+        "select /*+ MERGE(TBL2) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id",
+        Some(JoinStrategy::SortMerge),
+        None
+    )]
+    #[case::alias(
+        "select /*+ SHUFFLE_HASH(t) */ * from tbl2 join tbl3 as t on tbl2.id = t.id",
+        Some(JoinStrategy::Hash),
+        None
+    )]
+    #[case::sorted_lookup(
+        "select /*+ SORTED_LOOKUP(tbl3) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id",
+        Some(JoinStrategy::SortedLookup),
+        None
+    )]
+    #[case::unknown_hint(
+        "select /*+ NO_SUCH_HINT(tbl3) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id",
+        None,
+        None
+    )]
+    #[case::plain_comment(
+        "select /* BROADCAST(tbl3) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id",
+        None,
+        None
+    )]
+    #[case::subquery_hint(
+        "select * from (select /*+ BROADCAST(tbl2) */ * from tbl2 join tbl3 on tbl2.id = tbl3.id) as t",
+        Some(JoinStrategy::Broadcast),
+        Some(JoinSide::Left)
+    )]
+    #[case::not_inherited_by_subquery(
+        "select /*+ BROADCAST(tbl3) */ * from (select * from tbl2 join tbl3 on tbl2.id = tbl3.id) as t",
+        None,
+        None
+    )]
+    #[case::not_inherited_by_cte(
+        "with t as (select * from tbl2 join tbl3 on tbl2.id = tbl3.id) select /*+ BROADCAST(tbl3) */ * from t",
+        None,
+        None
+    )]
+    fn test_join_hints(
+        mut planner: SQLPlanner,
+        #[case] sql: &str,
+        #[case] expected: Option<JoinStrategy>,
+        #[case] expected_side: Option<JoinSide>,
+    ) -> SQLPlannerResult<()> {
+        fn find_join(plan: &LogicalPlan) -> Option<&Join> {
+            match plan {
+                LogicalPlan::Join(join) => Some(join),
+                _ => plan.children().into_iter().find_map(find_join),
+            }
+        }
+
+        let plan = planner.plan_sql(sql)?;
+        let Some(join) = find_join(&plan) else {
+            panic!("expected a join, got {plan:?}");
+        };
+        assert_eq!(join.join_strategy, expected);
+        assert_eq!(join.broadcast_side, expected_side);
+        Ok(())
+    }
+
     #[rstest]
     fn test_global_agg(mut planner: SQLPlanner, tbl_1: LogicalPlanRef) -> SQLPlannerResult<()> {
         let sql = "select max(i32) from tbl1";
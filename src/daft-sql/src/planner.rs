@@ -8,7 +8,7 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use daft_catalog::Identifier;
-use daft_core::prelude::*;
+use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
     Column, Expr, ExprRef, Operator, PlanRef, Subquery, UnresolvedColumn,
     functions::{ScalarUDF, scalar::ScalarFn},
@@ -18,7 +18,7 @@ use daft_functions::{
     invalid_argument_err,
     numeric::{ceil::ceil, floor::floor},
 };
-use daft_functions_utf8::{ilike, like, to_date, to_datetime};
+use daft_functions_utf8::{Collation, collation_key, ilike, like, to_date, to_datetime};
 use daft_logical_plan::{
     JoinOptions, LogicalPlanBuilder, LogicalPlanRef,
     ops::{SetQuantifier, UnionStrategy},
@@ -34,7 +34,7 @@ use sqlparser::{
     },
     dialect::GenericDialect,
     parser::{Parser, ParserOptions},
-    tokenizer::{Token, Tokenizer},
+    tokenizer::{Location, Token, TokenWithSpan, Tokenizer},
 };
 
 use crate::{
@@ -89,6 +89,9 @@ struct OrderByExprs {
 pub(crate) struct PlannerContext {
     /// Bindings for common table expressions (cte).
     bound_ctes: Bindings<LogicalPlanBuilder>,
+    /// Join strategies from `/*+ ... */` hints, keyed by the location of the `SELECT` which the hints follow, and then
+    /// by the lowercased name or alias of the hinted relation.
+    join_hints: HashMap<Location, HashMap<String, JoinStrategy>>,
 }
 
 impl PlannerContext {
@@ -96,12 +99,14 @@ impl PlannerContext {
     fn new() -> Self {
         Self {
             bound_ctes: Bindings::default(),
+            join_hints: HashMap::new(),
         }
     }
 
     /// Clears the entire statement context
     fn clear(&mut self) {
         self.bound_ctes.clear();
+        self.join_hints.clear();
     }
 }
 
@@ -224,7 +229,10 @@ impl SQLPlanner<'_> {
             Ok(LogicalPlanBuilder::from(plan.clone()).alias(name_str))
         } else {
             let table = self.session().get_table(name)?;
-            let plan = table.to_logical_plan()?;
+            let mut plan = table.to_logical_plan()?;
+            if let Some(stats) = self.session().get_table_stats(name)? {
+                plan = plan.with_analyzed_stats(stats)?;
+            }
             Ok(LogicalPlanBuilder::from(plan).alias(name.name()))
         }
     }
@@ -260,13 +268,13 @@ impl SQLPlanner<'_> {
     }
 
     pub fn plan(&mut self, input: &str) -> SQLPlannerResult<Statement> {
-        let tokens: Vec<sqlparser::tokenizer::Token> =
-            Tokenizer::new(&GenericDialect {}, input).tokenize()?;
+        let tokens: Vec<TokenWithSpan> =
+            Tokenizer::new(&GenericDialect {}, input).tokenize_with_location()?;
 
         let from_positions: Vec<usize> = tokens
             .iter()
             .enumerate()
-            .filter_map(|(i, token)| match token {
+            .filter_map(|(i, token)| match &token.token {
                 Token::Word(w) if w.keyword == sqlparser::keywords::Keyword::FROM => Some(i),
                 _ => None,
             })
@@ -276,6 +284,7 @@ impl SQLPlanner<'_> {
             let next_token = tokens
                 .iter()
                 .skip(pos + 1)
+                .map(|token| &token.token)
                 .find(|token| !matches!(token, Token::Whitespace(_)));
             if let Some(Token::Word(w)) = next_token {
                 match w.keyword {
@@ -292,12 +301,15 @@ impl SQLPlanner<'_> {
             }
         }
 
+        let join_hints = parse_join_hints(&tokens);
+        self.context_mut().join_hints.extend(join_hints);
+
         let mut parser = Parser::new(&GenericDialect {})
             .with_options(ParserOptions {
                 trailing_commas: true,
                 ..Default::default()
             })
-            .with_tokens(tokens);
+            .with_tokens_with_locations(tokens);
 
         // currently only allow one statement
         let statements = parser.parse_statements()?;
@@ -401,9 +413,16 @@ impl SQLPlanner<'_> {
             self.plan_ctes(with)?;
         }
 
-        // FROM/JOIN
+        // FROM/JOIN, with the join hints of this SELECT only, so that they don't apply to its subqueries or CTEs
         let from = selection.clone().from;
-        self.plan_from(&from)?;
+        let join_hints = self
+            .context
+            .borrow()
+            .join_hints
+            .get(&selection.select_token.0.span.start)
+            .cloned()
+            .unwrap_or_default();
+        self.plan_from(&from, &join_hints)?;
 
         // SELECT
         let projections = selection
@@ -421,6 +440,7 @@ impl SQLPlanner<'_> {
 
         // GROUP BY
         let mut groupby_exprs = Vec::new();
+        let mut collated_groupby_values = Vec::new();
         let mut rollup = false;
 
         match &selection.group_by {
@@ -430,17 +450,20 @@ impl SQLPlanner<'_> {
                 }
             }
             GroupByExpr::Expressions(expressions, _) => {
-                groupby_exprs = match expressions.as_slice() {
+                (groupby_exprs, collated_groupby_values) = match expressions.as_slice() {
                     [sqlparser::ast::Expr::Rollup(exprs)] => {
                         rollup = true;
                         let mut out = Vec::new();
+                        let mut collated_values = Vec::new();
                         for expr_list in exprs {
                             if expr_list.len() > 1 {
                                 unsupported_sql_err!("nested ROLLUP's are not supported");
                             }
-                            out.push(self.plan_expr(&expr_list[0])?);
+                            let (expr, collated_value) = self.plan_collated_key(&expr_list[0])?;
+                            out.push(expr);
+                            collated_values.extend(collated_value);
                         }
-                        out
+                        (out, collated_values)
                     }
                     exprs => self.plan_group_by_items(projections.as_slice(), exprs)?,
                 };
@@ -474,7 +497,14 @@ impl SQLPlanner<'_> {
                 .map(|h| self.plan_expr(h))
                 .transpose()?;
 
-            self.plan_aggregate_query(projections, order_by, groupby_exprs, having, rollup)?;
+            self.plan_aggregate_query(
+                projections,
+                order_by,
+                groupby_exprs,
+                collated_groupby_values,
+                having,
+                rollup,
+            )?;
         } else {
             self.plan_non_agg_query(projections, order_by)?;
         }
@@ -536,12 +566,14 @@ impl SQLPlanner<'_> {
         Ok(self.current_plan.clone().unwrap())
     }
 
+    /// Plans the items of a GROUP BY clause, along with the values of the items which are grouped under a collation.
     fn plan_group_by_items(
         &self,
         select_items: &[ExprRef],
         exprs: &[ast::Expr],
-    ) -> SQLPlannerResult<Vec<ExprRef>> {
+    ) -> SQLPlannerResult<(Vec<ExprRef>, Vec<ExprRef>)> {
         let mut group_by_items = vec![];
+        let mut collated_values = vec![];
         for expr in exprs {
             if let ast::Expr::Value(ast::ValueWithSpan {
                 value: ast::Value::Number(number, _),
@@ -567,11 +599,66 @@ impl SQLPlanner<'_> {
                 }
                 group_by_items.push(select_items[pos - 1].clone());
             } else {
-                let group_by_item = self.plan_expr(expr)?;
+                let (group_by_item, collated_value) = self.plan_collated_key(expr)?;
                 group_by_items.push(group_by_item);
+                collated_values.extend(collated_value);
             }
         }
-        Ok(group_by_items)
+        Ok((group_by_items, collated_values))
+    }
+
+    /// Returns the expression and collation name of a `COLLATE` clause, looking through parentheses.
+    fn collated(expr: &ast::Expr) -> Option<(&ast::Expr, &ast::ObjectName)> {
+        match expr {
+            ast::Expr::Collate { expr, collation } => Some((expr, collation)),
+            ast::Expr::Nested(expr) => Self::collated(expr),
+            _ => None,
+        }
+    }
+
+    fn plan_collation(collation: &ast::ObjectName) -> SQLPlannerResult<Collation> {
+        let [ast::ObjectNamePart::Identifier(name)] = collation.0.as_slice() else {
+            unsupported_sql_err!("COLLATE with a qualified collation name: {collation}")
+        };
+        Ok(name.value.parse::<Collation>()?)
+    }
+
+    /// Returns the collation that the operands of a binary operator are compared under, which is given by a `COLLATE`
+    /// clause on either operand.
+    fn plan_comparison_collation(
+        &self,
+        left: &ast::Expr,
+        right: &ast::Expr,
+    ) -> SQLPlannerResult<Option<Collation>> {
+        match (Self::collated(left), Self::collated(right)) {
+            (Some((_, left)), Some((_, right))) => {
+                let left = Self::plan_collation(left)?;
+                let right = Self::plan_collation(right)?;
+                if left != right {
+                    invalid_operation_err!(
+                        "Cannot compare values under the different collations {left} and {right}"
+                    );
+                }
+                Ok(Some(left))
+            }
+            (Some((_, collation)), None) | (None, Some((_, collation))) => {
+                Ok(Some(Self::plan_collation(collation)?))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Plans an ORDER BY or GROUP BY item, which is the collation key of its value if it has a `COLLATE` clause.
+    /// Returns the planned item along with the value, if it's collated.
+    fn plan_collated_key(&self, expr: &ast::Expr) -> SQLPlannerResult<(ExprRef, Option<ExprRef>)> {
+        match Self::collated(expr) {
+            Some((value, collation)) => {
+                let value = self.plan_expr(value)?;
+                let key = collation_key(value.clone(), &Self::plan_collation(collation)?);
+                Ok((key, Some(value)))
+            }
+            None => Ok((self.plan_expr(expr)?, None)),
+        }
     }
 
     fn plan_non_agg_query(
@@ -599,6 +686,7 @@ impl SQLPlanner<'_> {
         projections: Vec<Arc<Expr>>,
         order_by: Option<OrderByExprs>,
         groupby_exprs: Vec<Arc<Expr>>,
+        collated_groupby_values: Vec<Arc<Expr>>,
         having: Option<Arc<Expr>>,
         rollup: bool,
     ) -> Result<(), PlannerError> {
@@ -606,6 +694,14 @@ impl SQLPlanner<'_> {
 
         let schema = self.current_plan_ref().schema();
 
+        // Values grouped under a collation are grouped by their collation keys, so any of the values in a group stands
+        // for the group, rather than the key.
+        let collated_value = |expr: &ExprRef, name: &str| {
+            let agg = expr.clone().any_value(false);
+            let id = agg.semantic_id(&schema).id;
+            (agg.alias(id.clone()), resolved_col(id).alias(name))
+        };
+
         let projections = projections
             .into_iter()
             .map(|expr| {
@@ -616,10 +712,18 @@ impl SQLPlanner<'_> {
                 //  just reuse the existing column
                 } else if groupby_exprs.contains(&expr) {
                     resolved_col(expr.name())
+                } else if collated_groupby_values.contains(&expr) {
+                    let (agg, value) = collated_value(&expr, expr.name());
+                    aggs.insert(agg);
+                    value
                 // similarly, if its the same as above, but an alias, the same logic applies
                 } else if let Expr::Alias(inner, name) = expr.as_ref() {
                     if groupby_exprs.contains(inner) {
                         resolved_col(inner.name()).alias(name.as_ref())
+                    } else if collated_groupby_values.contains(inner) {
+                        let (agg, value) = collated_value(inner, name.as_ref());
+                        aggs.insert(agg);
+                        value
                     } else {
                         expr
                     }
@@ -785,7 +889,7 @@ impl SQLPlanner<'_> {
             if order_by_expr.with_fill.is_some() {
                 unsupported_sql_err!("WITH FILL");
             }
-            let expr = self.plan_expr(&order_by_expr.expr)?;
+            let (expr, _) = self.plan_collated_key(&order_by_expr.expr)?;
 
             exprs.push(expr);
         }
@@ -798,11 +902,16 @@ impl SQLPlanner<'_> {
     }
 
     /// Plans a single set of table and joins in a FROM clause.
-    fn plan_single_from(&self, from: &TableWithJoins) -> SQLPlannerResult<LogicalPlanBuilder> {
+    fn plan_single_from(
+        &self,
+        from: &TableWithJoins,
+        join_hints: &HashMap<String, JoinStrategy>,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
         let relation = from.relation.clone();
         let left_plan = self.plan_relation(&relation)?;
         let mut left_planner = self.new_with_context();
         left_planner.set_plan(left_plan);
+        let mut left_relations: Vec<String> = relation_name(&relation).into_iter().collect();
 
         for join in &from.joins {
             use sqlparser::ast::{
@@ -859,8 +968,35 @@ impl SQLPlanner<'_> {
 
             let left_schema = left_planner.current_plan_ref().schema();
 
+            // a hint on the right relation takes precedence over hints on the relations joined so far, and a broadcast
+            // hint broadcasts the side of the relation that it names
+            let right_relation = relation_name(&join.relation);
+            let join_hint = right_relation
+                .iter()
+                .find_map(|name| join_hints.get(name))
+                .map(|strategy| (*strategy, JoinSide::Right))
+                .or_else(|| {
+                    left_relations
+                        .iter()
+                        .rev()
+                        .find_map(|name| join_hints.get(name))
+                        .map(|strategy| (*strategy, JoinSide::Left))
+                });
+            if let Some((JoinStrategy::Broadcast, side)) = join_hint {
+                join_options = join_options.broadcast_side(side);
+            }
+            let join_strategy = join_hint.map(|(strategy, _)| strategy);
+            left_relations.extend(right_relation);
+
             left_planner.update_plan(|plan| {
-                plan.join(right_plan, on, using, join_type, None, join_options)
+                plan.join(
+                    right_plan,
+                    on,
+                    using,
+                    join_type,
+                    join_strategy,
+                    join_options,
+                )
             })?;
 
             // add a project to reorder columns since `USING` should return [left columns, remaining right columns]
@@ -883,10 +1019,14 @@ impl SQLPlanner<'_> {
 
     /// Plans the FROM clause of a query and populates `self.current_relation`.
     /// Should only be called once per query.
-    fn plan_from(&mut self, from: &[TableWithJoins]) -> SQLPlannerResult<()> {
+    fn plan_from(
+        &mut self,
+        from: &[TableWithJoins],
+        join_hints: &HashMap<String, JoinStrategy>,
+    ) -> SQLPlannerResult<()> {
         let plan = if let Some(plan) = from
             .iter()
-            .map(|f| self.plan_single_from(f))
+            .map(|f| self.plan_single_from(f, join_hints))
             .reduce(|left, right| {
                 let left = left?;
                 let right = right?;
@@ -1312,9 +1452,25 @@ impl SQLPlanner<'_> {
             SQLExpr::Identifier(ident) => self.plan_identifier(std::slice::from_ref(ident)),
             SQLExpr::Value(v) => self.value_to_lit(&v.value).map(Expr::Literal).map(Arc::new),
             SQLExpr::BinaryOp { left, op, right } => {
-                let left = self.plan_expr(left)?;
-                let right = self.plan_expr(right)?;
+                let collation = self.plan_comparison_collation(left, right)?;
+                let mut left = self.plan_expr(left)?;
+                let mut right = self.plan_expr(right)?;
                 let op = self.sql_operator_to_operator(op)?;
+                if let Some(collation) = collation
+                    && matches!(
+                        op,
+                        Operator::Eq
+                            | Operator::EqNullSafe
+                            | Operator::NotEq
+                            | Operator::Lt
+                            | Operator::LtEq
+                            | Operator::Gt
+                            | Operator::GtEq
+                    )
+                {
+                    left = collation_key(left, &collation);
+                    right = collation_key(right, &collation);
+                }
                 Ok(Expr::BinaryOp { left, op, right }.arced())
             }
             SQLExpr::Cast {
@@ -1505,7 +1661,12 @@ impl SQLPlanner<'_> {
             }
             SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM"),
             SQLExpr::Overlay { .. } => unsupported_sql_err!("OVERLAY"),
-            SQLExpr::Collate { .. } => unsupported_sql_err!("COLLATE"),
+            // A collation only changes how a value is compared, sorted or grouped, which comparisons and ORDER BY and
+            // GROUP BY items take care of, so the value itself is left as is.
+            SQLExpr::Collate { expr, collation } => {
+                Self::plan_collation(collation)?;
+                self.plan_expr(expr)
+            }
             SQLExpr::Nested(e) => self.plan_expr(e),
             SQLExpr::Prefixed { .. } => unsupported_sql_err!("PREFIXED"),
             SQLExpr::TypedString(typed_string) => {
@@ -2205,12 +2366,94 @@ fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
     ))
 }
 
+/// The lowercased name that join hints refer to a relation by: its alias, or else its table name.
+fn relation_name(rel: &sqlparser::ast::TableFactor) -> Option<String> {
+    use sqlparser::ast::TableFactor;
+
+    match rel {
+        TableFactor::Table {
+            alias: Some(alias), ..
+        }
+        | TableFactor::Derived {
+            alias: Some(alias), ..
+        } => Some(alias.name.value.to_lowercase()),
+        TableFactor::Table { name, .. } => name.0.last().map(|part| match part {
+            ast::ObjectNamePart::Identifier(ident) => ident.value.to_lowercase(),
+            ast::ObjectNamePart::Function(func) => func.name.value.to_lowercase(),
+        }),
+        _ => None,
+    }
+}
+
+/// Collects the join strategy hints from the `/*+ ... */` comments that directly follow a `SELECT`, such as
+/// `SELECT /*+ BROADCAST(t) */ ...`, keyed by the location of that `SELECT`. Hints only apply to the joins in the FROM
+/// clause of their own `SELECT`. They are best-effort: unknown hints are ignored, and the planner falls back to its own
+/// choice of strategy if a hinted strategy cannot be used for a join.
+fn parse_join_hints(tokens: &[TokenWithSpan]) -> HashMap<Location, HashMap<String, JoinStrategy>> {
+    use sqlparser::{keywords::Keyword, tokenizer::Whitespace};
+
+    let mut hints: HashMap<Location, HashMap<String, JoinStrategy>> = HashMap::new();
+    let mut select = None;
+    for token in tokens {
+        match &token.token {
+            Token::Word(w) => {
+                select = (w.keyword == Keyword::SELECT).then_some(token.span.start);
+            }
+            Token::Whitespace(Whitespace::MultiLineComment(comment)) => {
+                if let (Some(select), Some(comment)) = (select, comment.strip_prefix('+')) {
+                    hints
+                        .entry(select)
+                        .or_default()
+                        .extend(parse_join_hint_comment(comment));
+                }
+            }
+            Token::Whitespace(_) => {}
+            _ => select = None,
+        }
+    }
+    hints
+}
+
+/// Parses the hints of a single hint comment, such as `BROADCAST(t1, t2) MERGE(t3)`.
+fn parse_join_hint_comment(comment: &str) -> Vec<(String, JoinStrategy)> {
+    comment
+        .split(')')
+        .filter_map(|hint| {
+            let (name, relations) = hint.split_once('(')?;
+            let strategy = match name.trim().to_uppercase().as_str() {
+                "BROADCAST" | "BROADCASTJOIN" | "MAPJOIN" => JoinStrategy::Broadcast,
+                "SHUFFLE_HASH" | "SHUFFLE" => JoinStrategy::Hash,
+                "MERGE" | "SHUFFLE_MERGE" | "MERGEJOIN" => JoinStrategy::SortMerge,
+                "SORTED_LOOKUP" | "LOOKUP" => JoinStrategy::SortedLookup,
+                _ => return None,
+            };
+            Some(
+                relations
+                    .split([',', ' ', '\t', '\n'])
+                    .filter(|relation| !relation.is_empty())
+                    .map(|relation| (relation.to_lowercase(), strategy))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use daft_core::prelude::*;
-    use sqlparser::ast::{Ident, ObjectName, ObjectNamePart};
+    use sqlparser::{
+        ast::{Ident, ObjectName, ObjectNamePart},
+        dialect::GenericDialect,
+        tokenizer::{Location, Tokenizer},
+    };
 
-    use crate::{planner::is_table_path, sql_schema};
+    use crate::{
+        planner::{is_table_path, parse_join_hints},
+        sql_schema,
+    };
 
     #[test]
     fn test_sql_schema_creates_expected_schema() {
@@ -2261,4 +2504,32 @@ mod tests {
             ObjectNamePart::Identifier(Ident::new("path/to/file.ext"))
         ])));
     }
+
+    #[test]
+    fn test_parse_join_hints() {
+        let sql = "SELECT /*+ BROADCAST(a, B) merge(c) UNKNOWN(d) */ * FROM a JOIN (SELECT /*+ SHUFFLE_HASH(e) */ * FROM e) AS b ON a.x = b.x /* BROADCAST(f) */";
+        let tokens = Tokenizer::new(&GenericDialect {}, sql)
+            .tokenize_with_location()
+            .unwrap();
+        assert_eq!(
+            parse_join_hints(&tokens),
+            HashMap::from([
+                (
+                    Location { line: 1, column: 1 },
+                    HashMap::from([
+                        ("a".to_string(), JoinStrategy::Broadcast),
+                        ("b".to_string(), JoinStrategy::Broadcast),
+                        ("c".to_string(), JoinStrategy::SortMerge),
+                    ])
+                ),
+                (
+                    Location {
+                        line: 1,
+                        column: 66
+                    },
+                    HashMap::from([("e".to_string(), JoinStrategy::Hash)])
+                ),
+            ])
+        );
+    }
 }
@@ -10,6 +10,8 @@ use crate::{SQLPlanner, error::SQLPlannerResult, unsupported_sql_err};
 pub enum Statement {
     /// select .. from
     Select(Select),
+    /// collect statistics on a table
+    Analyze(Analyze),
     /// set a session variable
     Set(Set),
     /// list tables in a catalog
@@ -21,6 +23,14 @@ pub enum Statement {
 /// SELECT ...
 pub type Select = LogicalPlanRef;
 
+/// ANALYZE TABLE <table> [COMPUTE STATISTICS] [FOR COLUMNS <column> [, ...]]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct Analyze {
+    pub table: Identifier,
+    pub columns: Option<Vec<String>>,
+}
+
 /// SET <option> [TO] <value>
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -75,6 +85,13 @@ impl SQLPlanner<'_> {
                 *has_table_keyword,
                 table_name,
             ),
+            ast::Statement::Analyze {
+                table_name,
+                partitions,
+                columns,
+                noscan,
+                ..
+            } => self.plan_analyze(table_name, partitions.as_deref(), columns, *noscan),
             ast::Statement::Set(_) => {
                 todo!("set_variable")
             }
@@ -155,6 +172,29 @@ impl SQLPlanner<'_> {
         Ok(Statement::Select(describe.build()))
     }
 
+    /// ANALYZE TABLE <table>
+    fn plan_analyze(
+        &self,
+        table_name: &ast::ObjectName,
+        partitions: Option<&[ast::Expr]>,
+        columns: &[ast::Ident],
+        noscan: bool,
+    ) -> SQLPlannerResult<Statement> {
+        // err on `PARTITION (..)`
+        if partitions.is_some() {
+            unsupported_sql_err!("ANALYZE TABLE with PARTITION is not supported")
+        }
+        // err on `NOSCAN`, collecting statistics always scans the table
+        if noscan {
+            unsupported_sql_err!("ANALYZE TABLE with NOSCAN is not supported")
+        }
+        let table = self.normalize(table_name)?;
+        // `FOR COLUMNS` without any columns analyzes all of them
+        let columns =
+            (!columns.is_empty()).then(|| columns.iter().map(|c| c.value.clone()).collect());
+        Ok(Statement::Analyze(Analyze { table, columns }))
+    }
+
     #[allow(dead_code)]
     fn plan_set(&self, _: &ast::SetConfigValue) -> SQLPlannerResult<Statement> {
         unsupported_sql_err!("SET statement is not yet supported.")
@@ -328,6 +368,25 @@ mod test {
         parsed.remove(0)
     }
 
+    #[test]
+    fn test_analyze_table_for_columns() {
+        let sql = "ANALYZE TABLE mytable COMPUTE STATISTICS FOR COLUMNS a, b";
+        let statement = parse_sql(sql);
+        let session = Session::default();
+        let mut planner = SQLPlanner::new(&session);
+        let plan = planner.plan_statement(&statement).unwrap();
+
+        if let Statement::Analyze(analyze) = plan {
+            assert_eq!(analyze.table, Identifier::simple("mytable"));
+            assert_eq!(
+                analyze.columns,
+                Some(vec!["a".to_string(), "b".to_string()])
+            );
+        } else {
+            panic!("Expected Analyze statement");
+        }
+    }
+
     #[test]
     fn test_use_catalog() {
         let sql = "USE mycatalog";
@@ -0,0 +1,548 @@
+use std::hash::{Hash, Hasher};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{Column, Expr, Operator, ResolvedColumn, estimated_selectivity};
+use daft_recordbatch::RecordBatch;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::Xxh3;
+
+/// Default number of buckets in the equi-depth histograms collected by `ANALYZE`.
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 32;
+
+/// An equi-depth histogram over the non-null values of a numeric column.
+///
+/// The `bounds` are ascending quantiles of the column, where each pair of adjacent bounds delimits a bucket holding
+/// roughly the same number of rows. The first bound is approximately the minimum and the last one the maximum.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EquiDepthHistogram {
+    pub bounds: Vec<f64>,
+}
+
+impl EquiDepthHistogram {
+    pub fn num_buckets(&self) -> usize {
+        self.bounds.len().saturating_sub(1)
+    }
+
+    /// Estimates the fraction of the non-null values that are less than `value`, assuming that values are spread
+    /// uniformly within each bucket.
+    pub fn fraction_below(&self, value: f64) -> f64 {
+        let num_buckets = self.num_buckets();
+        if num_buckets == 0 || value <= self.bounds[0] {
+            return 0.0;
+        }
+        if value > self.bounds[num_buckets] {
+            return 1.0;
+        }
+        // The bucket with bounds[bucket] < value <= bounds[bucket + 1].
+        let bucket = self.bounds.partition_point(|bound| *bound < value) - 1;
+        let (lower, upper) = (self.bounds[bucket], self.bounds[bucket + 1]);
+        let within_bucket = if upper > lower {
+            (value - lower) / (upper - lower)
+        } else {
+            1.0
+        };
+        (bucket as f64 + within_bucket) / num_buckets as f64
+    }
+}
+
+/// Statistics for a single column, as collected by `ANALYZE`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnalyzedColumnStatistics {
+    /// Approximate number of distinct non-null values.
+    pub ndv: u64,
+    /// Fraction of the rows for which the column is null.
+    pub null_fraction: f64,
+    /// Minimum and maximum values, only collected for numeric columns.
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// Only collected for numeric columns.
+    pub histogram: Option<EquiDepthHistogram>,
+}
+
+impl AnalyzedColumnStatistics {
+    fn non_null_fraction(&self) -> f64 {
+        1.0 - self.null_fraction
+    }
+
+    /// Estimates the fraction of the rows equal to `value`, assuming that distinct values are equally frequent.
+    pub fn eq_selectivity(&self, value: Option<f64>) -> f64 {
+        if let (Some(value), Some(min), Some(max)) = (value, self.min, self.max)
+            && (value < min || value > max)
+        {
+            return 0.0;
+        }
+        self.non_null_fraction() / self.ndv.max(1) as f64
+    }
+
+    /// Estimates the fraction of the non-null values that are less than `value`, from the histogram if there is one,
+    /// and otherwise by interpolating between the minimum and maximum.
+    fn fraction_below(&self, value: f64) -> Option<f64> {
+        if let Some(histogram) = &self.histogram {
+            return Some(histogram.fraction_below(value));
+        }
+        let (min, max) = (self.min?, self.max?);
+        Some(if value <= min {
+            0.0
+        } else if value > max {
+            1.0
+        } else if max > min {
+            (value - min) / (max - min)
+        } else {
+            1.0
+        })
+    }
+
+    /// Estimates the fraction of the rows for which `column <op> value` holds.
+    ///
+    /// Returns None if the operator is not a comparison, or if a range comparison is made against a column without a
+    /// minimum and maximum.
+    pub fn comparison_selectivity(&self, op: Operator, value: f64) -> Option<f64> {
+        let non_null = self.non_null_fraction();
+        let eq = self.eq_selectivity(Some(value));
+        let below = || {
+            self.fraction_below(value)
+                .map(|fraction| fraction * non_null)
+        };
+        let selectivity = match op {
+            Operator::Eq | Operator::EqNullSafe => eq,
+            Operator::NotEq => non_null - eq,
+            Operator::Lt => below()?,
+            Operator::LtEq => below()? + eq,
+            Operator::Gt => non_null - below()? - eq,
+            Operator::GtEq => non_null - below()?,
+            _ => return None,
+        };
+        Some(selectivity.clamp(0.0, 1.0))
+    }
+}
+
+/// Statistics for a whole table, as collected by `ANALYZE`, which the optimizer uses to estimate cardinalities.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnalyzedTableStatistics {
+    pub num_rows: u64,
+    pub columns: IndexMap<String, AnalyzedColumnStatistics>,
+    /// A hash of the statistics, which identifies them in the plans that read them, so that plans which read different
+    /// statistics of a table, e.g. before and after it was analyzed again, aren't mistaken for each other.
+    pub version: u64,
+}
+
+impl AnalyzedTableStatistics {
+    pub fn new(num_rows: u64, columns: IndexMap<String, AnalyzedColumnStatistics>) -> Self {
+        let mut hasher = Xxh3::new();
+        num_rows.hash(&mut hasher);
+        for (name, column) in &columns {
+            name.hash(&mut hasher);
+            column.ndv.hash(&mut hasher);
+            column.null_fraction.to_bits().hash(&mut hasher);
+            column.min.map(f64::to_bits).hash(&mut hasher);
+            column.max.map(f64::to_bits).hash(&mut hasher);
+            column
+                .histogram
+                .as_ref()
+                .map(|histogram| {
+                    histogram
+                        .bounds
+                        .iter()
+                        .map(|b| b.to_bits())
+                        .collect::<Vec<_>>()
+                })
+                .hash(&mut hasher);
+        }
+        Self {
+            num_rows,
+            columns,
+            version: hasher.finish(),
+        }
+    }
+
+    pub fn column(&self, name: &str) -> Option<&AnalyzedColumnStatistics> {
+        self.columns.get(name)
+    }
+
+    /// Restricts the statistics to the columns in the schema, e.g. after a projection was pushed into a scan.
+    #[must_use]
+    pub fn project(&self, schema: &Schema) -> Self {
+        Self::new(
+            self.num_rows,
+            self.columns
+                .iter()
+                .filter(|(name, _)| schema.has_field(name))
+                .map(|(name, stats)| (name.clone(), stats.clone()))
+                .collect(),
+        )
+    }
+
+    /// Updates the statistics with those of a later `ANALYZE` of the table, which may have only analyzed some of its
+    /// columns. The analyzed columns get their new statistics, while the other columns keep their previous ones.
+    #[must_use]
+    pub fn update(&self, newer: &Self) -> Self {
+        let mut columns = self.columns.clone();
+        for (name, stats) in &newer.columns {
+            columns.insert(name.clone(), stats.clone());
+        }
+        Self::new(newer.num_rows, columns)
+    }
+
+    /// Merges the statistics of two tables, e.g. the sides of a join, keeping the columns in the output schema.
+    ///
+    /// Columns that are in both tables keep the statistics of `self`.
+    #[must_use]
+    pub fn merge(&self, other: &Self, num_rows: u64, schema: &Schema) -> Self {
+        let mut columns = self.project(schema).columns;
+        for (name, stats) in &other.columns {
+            if schema.has_field(name) && !columns.contains_key(name) {
+                columns.insert(name.clone(), stats.clone());
+            }
+        }
+        Self::new(num_rows, columns)
+    }
+
+    /// Estimates the fraction of the rows that satisfy the predicate.
+    ///
+    /// Comparisons, null checks and membership tests of analyzed columns against literals are estimated from the
+    /// statistics, while everything else falls back to the fixed estimates of `estimated_selectivity`.
+    pub fn estimate_selectivity(&self, predicate: &Expr, schema: &Schema) -> f64 {
+        match predicate {
+            Expr::BinaryOp {
+                op: Operator::And,
+                left,
+                right,
+            } => {
+                // Like `estimated_selectivity`, we don't assume that the operands are independent.
+                let left = self.estimate_selectivity(left, schema);
+                let right = self.estimate_selectivity(right, schema);
+                left.min(right)
+            }
+            Expr::BinaryOp {
+                op: Operator::Or,
+                left,
+                right,
+            } => {
+                let left = self.estimate_selectivity(left, schema);
+                let right = self.estimate_selectivity(right, schema);
+                left.mul_add(-right, left + right)
+            }
+            Expr::Not(expr) => 1.0 - self.estimate_selectivity(expr, schema),
+            Expr::Alias(expr, _) => self.estimate_selectivity(expr, schema),
+            _ => self
+                .try_estimate_leaf_selectivity(predicate)
+                .unwrap_or_else(|| estimated_selectivity(predicate, schema)),
+        }
+    }
+
+    fn try_estimate_leaf_selectivity(&self, predicate: &Expr) -> Option<f64> {
+        match predicate {
+            Expr::BinaryOp { op, left, right } => {
+                if let (Some(column), Some(value)) = (self.column_of(left), numeric_value(right)) {
+                    column.comparison_selectivity(*op, value)
+                } else if let (Some(value), Some(column)) =
+                    (numeric_value(left), self.column_of(right))
+                {
+                    column.comparison_selectivity(flip_comparison(*op)?, value)
+                } else {
+                    None
+                }
+            }
+            Expr::IsNull(expr) => Some(self.column_of(expr)?.null_fraction),
+            Expr::NotNull(expr) => Some(self.column_of(expr)?.non_null_fraction()),
+            Expr::IsIn(expr, items) => {
+                let column = self.column_of(expr)?;
+                let selectivity = items
+                    .iter()
+                    .map(|item| match item.as_ref() {
+                        Expr::Literal(_) => Some(column.eq_selectivity(numeric_value(item))),
+                        _ => None,
+                    })
+                    .sum::<Option<f64>>()?;
+                Some(selectivity.min(column.non_null_fraction()))
+            }
+            Expr::Between(expr, lower, upper) => {
+                let column = self.column_of(expr)?;
+                let (lower, upper) = (numeric_value(lower)?, numeric_value(upper)?);
+                let at_most_upper = column.comparison_selectivity(Operator::LtEq, upper)?;
+                let below_lower = column.comparison_selectivity(Operator::Lt, lower)?;
+                Some((at_most_upper - below_lower).max(0.0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the statistics of the column if the expression is a bare reference to an analyzed column.
+    pub fn column_of(&self, expr: &Expr) -> Option<&AnalyzedColumnStatistics> {
+        match expr {
+            Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => self.column(name),
+            _ => None,
+        }
+    }
+
+    pub fn from_analyze_table(table: &RecordBatch) -> DaftResult<Self> {
+        // Assumed format is one row per analyzed column, with the columns:
+        // - column: name of the column.
+        // - num_rows: number of rows in the table, which is the same for every row.
+        // - null_count: number of nulls in the column.
+        // - ndv: approximate number of distinct values in the column.
+        // - min, max: minimum and maximum of the column as floats, null if the column isn't numeric.
+        // - histogram: equi-depth histogram bounds as a list of floats, null if the column isn't numeric.
+        let get = |name: &str, dtype: &DataType| -> DaftResult<Series> {
+            table.get_column(table.schema.get_index(name)?).cast(dtype)
+        };
+        let names = get("column", &DataType::Utf8)?;
+        let num_rows = get("num_rows", &DataType::UInt64)?;
+        let null_counts = get("null_count", &DataType::UInt64)?;
+        let ndvs = get("ndv", &DataType::UInt64)?;
+        let mins = get("min", &DataType::Float64)?;
+        let maxs = get("max", &DataType::Float64)?;
+        let histograms = get("histogram", &DataType::List(Box::new(DataType::Float64)))?;
+
+        let (names, num_rows, null_counts, ndvs) = (
+            names.utf8()?,
+            num_rows.u64()?,
+            null_counts.u64()?,
+            ndvs.u64()?,
+        );
+        let (mins, maxs, histograms) = (mins.f64()?, maxs.f64()?, histograms.list()?);
+
+        let total_rows = if table.is_empty() {
+            0
+        } else {
+            num_rows.get(0).unwrap_or(0)
+        };
+        let mut columns = IndexMap::with_capacity(table.len());
+        for i in 0..table.len() {
+            let name = names.get(i).ok_or_else(|| {
+                DaftError::ValueError("Expected analyzed column names to be non-null".to_string())
+            })?;
+            let null_fraction = if total_rows == 0 {
+                0.0
+            } else {
+                null_counts.get(i).unwrap_or(0) as f64 / total_rows as f64
+            };
+            let histogram = histograms
+                .get(i)
+                .map(|bounds| -> DaftResult<_> {
+                    let bounds = bounds.f64()?;
+                    Ok((0..bounds.len())
+                        .filter_map(|j| bounds.get(j))
+                        .collect::<Vec<_>>())
+                })
+                .transpose()?
+                .filter(|bounds| bounds.len() > 1)
+                .map(|bounds| EquiDepthHistogram { bounds });
+            columns.insert(
+                name.to_string(),
+                AnalyzedColumnStatistics {
+                    ndv: ndvs.get(i).unwrap_or(0),
+                    null_fraction,
+                    min: mins.get(i),
+                    max: maxs.get(i),
+                    histogram,
+                },
+            );
+        }
+        Ok(Self::new(total_rows, columns))
+    }
+}
+
+/// Returns the value of a numeric literal as a float.
+fn numeric_value(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Literal(lit) if lit.get_type().is_numeric() => {
+            lit.clone().cast(&DataType::Float64).ok()?.as_f64()
+        }
+        _ => None,
+    }
+}
+
+/// Returns the comparison that holds for `right <op> left` whenever `left <op> right` holds.
+fn flip_comparison(op: Operator) -> Option<Operator> {
+    match op {
+        Operator::Eq | Operator::EqNullSafe | Operator::NotEq => Some(op),
+        Operator::Lt => Some(Operator::Gt),
+        Operator::LtEq => Some(Operator::GtEq),
+        Operator::Gt => Some(Operator::Lt),
+        Operator::GtEq => Some(Operator::LtEq),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{Operator, lit, resolved_col};
+    use daft_recordbatch::RecordBatch;
+    use indexmap::IndexMap;
+
+    use super::{AnalyzedColumnStatistics, AnalyzedTableStatistics, EquiDepthHistogram};
+
+    fn skewed_column() -> AnalyzedColumnStatistics {
+        // 100 distinct values, half of them in [0, 10] and the other half in [10, 1000].
+        AnalyzedColumnStatistics {
+            ndv: 100,
+            null_fraction: 0.2,
+            min: Some(0.0),
+            max: Some(1000.0),
+            histogram: Some(EquiDepthHistogram {
+                bounds: vec![0.0, 10.0, 1000.0],
+            }),
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_histogram_fraction_below() {
+        let histogram = EquiDepthHistogram {
+            bounds: vec![0.0, 10.0, 1000.0],
+        };
+        assert_close(histogram.fraction_below(-1.0), 0.0);
+        assert_close(histogram.fraction_below(5.0), 0.25);
+        assert_close(histogram.fraction_below(10.0), 0.5);
+        assert_close(histogram.fraction_below(505.0), 0.75);
+        assert_close(histogram.fraction_below(2000.0), 1.0);
+
+        // Repeated bounds come from a frequent value, which takes up the buckets between them.
+        let histogram = EquiDepthHistogram {
+            bounds: vec![0.0, 1.0, 1.0, 1.0, 2.0],
+        };
+        assert_close(histogram.fraction_below(1.0), 0.25);
+        assert_close(histogram.fraction_below(1.5), 0.875);
+    }
+
+    #[test]
+    fn test_comparison_selectivity() {
+        let column = skewed_column();
+        assert_close(
+            column.comparison_selectivity(Operator::Eq, 5.0).unwrap(),
+            0.008,
+        );
+        assert_close(
+            column.comparison_selectivity(Operator::Eq, 5000.0).unwrap(),
+            0.0,
+        );
+        assert_close(
+            column.comparison_selectivity(Operator::Lt, 10.0).unwrap(),
+            0.4,
+        );
+        assert_close(
+            column.comparison_selectivity(Operator::GtEq, 10.0).unwrap(),
+            0.4,
+        );
+        assert_close(
+            column.comparison_selectivity(Operator::Gt, 2000.0).unwrap(),
+            0.0,
+        );
+        assert!(column.comparison_selectivity(Operator::Plus, 1.0).is_none());
+
+        // Without a histogram, values are assumed to be spread uniformly between the minimum and maximum.
+        let column = AnalyzedColumnStatistics {
+            histogram: None,
+            ..skewed_column()
+        };
+        assert_close(
+            column.comparison_selectivity(Operator::Lt, 10.0).unwrap(),
+            0.008,
+        );
+    }
+
+    #[test]
+    fn test_estimate_selectivity() {
+        let stats = AnalyzedTableStatistics::new(
+            1000,
+            IndexMap::from([("a".to_string(), skewed_column())]),
+        );
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let estimate = |expr: daft_dsl::ExprRef| stats.estimate_selectivity(&expr, &schema);
+
+        assert_close(estimate(resolved_col("a").lt(lit(10))), 0.4);
+        assert_close(estimate(lit(10).gt(resolved_col("a"))), 0.4);
+        assert_close(estimate(resolved_col("a").is_null()), 0.2);
+        assert_close(estimate(resolved_col("a").lt(lit(10)).not()), 0.6);
+        assert_close(
+            estimate(resolved_col("a").between(lit(5), lit(505))),
+            0.4 + 0.008,
+        );
+        assert_close(
+            estimate(resolved_col("a").is_in(vec![lit(1), lit(2), lit(3)])),
+            0.024,
+        );
+        // Columns without statistics fall back to the fixed estimates.
+        assert_close(estimate(resolved_col("b").lt(lit(10))), 0.3);
+        assert_close(
+            estimate(
+                resolved_col("a")
+                    .lt(lit(10))
+                    .and(resolved_col("b").eq(lit(1))),
+            ),
+            0.2,
+        );
+    }
+
+    #[test]
+    fn test_from_analyze_table() -> DaftResult<()> {
+        let table = RecordBatch::from_nonempty_columns(vec![
+            Utf8Array::from_iter("column", vec![Some("a"), Some("b")].into_iter()).into_series(),
+            UInt64Array::from(("num_rows", vec![4, 4])).into_series(),
+            UInt64Array::from(("null_count", vec![0, 1])).into_series(),
+            UInt64Array::from(("ndv", vec![4, 2])).into_series(),
+            Float64Array::from_iter(
+                Field::new("min", DataType::Float64),
+                vec![Some(0.0), None].into_iter(),
+            )
+            .into_series(),
+            Float64Array::from_iter(
+                Field::new("max", DataType::Float64),
+                vec![Some(10.0), None].into_iter(),
+            )
+            .into_series(),
+            Series::full_null("histogram", &DataType::List(Box::new(DataType::Float64)), 2),
+        ])?;
+
+        let stats = AnalyzedTableStatistics::from_analyze_table(&table)?;
+        assert_eq!(stats.num_rows, 4);
+
+        let a = stats.column("a").unwrap();
+        assert_eq!(a.ndv, 4);
+        assert_eq!(a.null_fraction, 0.0);
+        assert_eq!((a.min, a.max), (Some(0.0), Some(10.0)));
+
+        let b = stats.column("b").unwrap();
+        assert_eq!(b.ndv, 2);
+        assert_eq!(b.null_fraction, 0.25);
+        assert_eq!((b.min, b.max), (None, None));
+        assert!(b.histogram.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_keeps_columns_which_were_not_analyzed_again() {
+        let column = |ndv| AnalyzedColumnStatistics {
+            ndv,
+            ..skewed_column()
+        };
+        let stats = AnalyzedTableStatistics::new(
+            1000,
+            IndexMap::from([("a".to_string(), column(10)), ("b".to_string(), column(20))]),
+        );
+        let newer =
+            AnalyzedTableStatistics::new(2000, IndexMap::from([("b".to_string(), column(30))]));
+
+        let updated = stats.update(&newer);
+        assert_eq!(updated.num_rows, 2000);
+        assert_eq!(updated.column("a").unwrap().ndv, 10);
+        assert_eq!(updated.column("b").unwrap().ndv, 30);
+        // Statistics with different contents have different versions.
+        assert_ne!(updated.version, stats.version);
+        assert_eq!(updated.version, stats.update(&newer).version);
+    }
+}
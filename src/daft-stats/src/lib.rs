@@ -1,11 +1,17 @@
 use common_error::DaftError;
 use snafu::Snafu;
 
+mod analyzed_stats;
 mod column_stats;
 mod partition_spec;
+pub mod pruning;
 mod table_metadata;
 mod table_stats;
 
+pub use analyzed_stats::{
+    AnalyzedColumnStatistics, AnalyzedTableStatistics, DEFAULT_HISTOGRAM_BUCKETS,
+    EquiDepthHistogram,
+};
 pub use column_stats::{ColumnRangeStatistics, TruthValue};
 pub use partition_spec::PartitionSpec;
 pub use table_metadata::TableMetadata;
@@ -0,0 +1,215 @@
+//! Diagnostics of the files and row groups which scans skip.
+//!
+//! Scans skip files whose partition values can't satisfy their filters, and Parquet row groups whose statistics or page
+//! indexes show that they can't, without reading them. When scan diagnostics are enabled, each of these decisions is
+//! recorded for the queries which are running, so that users can verify that their predicates actually prune what they
+//! expect. The decisions of a query are reported by explain analyze.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    sync::{LazyLock, Mutex},
+};
+
+/// Environment variable which enables scan diagnostics. They are also enabled by explain analyze.
+const SCAN_DIAGNOSTICS_VAR: &str = "DAFT_DEV_ENABLE_SCAN_DIAGNOSTICS";
+const EXPLAIN_ANALYZE_VAR: &str = "DAFT_DEV_ENABLE_EXPLAIN_ANALYZE";
+
+/// Why part of a scan was skipped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PruneReason {
+    /// The partition values of a file don't satisfy the partition filters of the scan.
+    PartitionValues,
+    /// The statistics of a row group show that none of its rows satisfy the filters of the scan.
+    RowGroupStatistics,
+    /// The page index of a row group shows that none of the rows of a page satisfy the filters of the scan.
+    PageIndex,
+}
+
+impl Display for PruneReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PartitionValues => write!(f, "partition values"),
+            Self::RowGroupStatistics => write!(f, "row group statistics"),
+            Self::PageIndex => write!(f, "page index"),
+        }
+    }
+}
+
+/// A file, row group or page which a scan skipped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PruneDecision {
+    pub path: String,
+    /// The row group which was skipped, or None if the whole file was.
+    pub row_group: Option<usize>,
+    /// The page of the row group which was skipped, or None if the whole row group was.
+    pub page: Option<usize>,
+    pub reason: PruneReason,
+    /// The predicate which the file, row group or page can't satisfy.
+    pub predicate: String,
+}
+
+impl Display for PruneDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path)?;
+        if let Some(row_group) = self.row_group {
+            write!(f, " row group {row_group}")?;
+        }
+        if let Some(page) = self.page {
+            write!(f, " page {page}")?;
+        }
+        write!(f, ": skipped by {} for {}", self.reason, self.predicate)
+    }
+}
+
+/// The most decisions which are kept for a query. Scans of many files would otherwise hold on to unbounded
+/// diagnostics, so decisions past this are only counted.
+pub const MAX_PRUNE_DECISIONS_PER_QUERY: usize = 10_000;
+
+/// The pruning decisions recorded for a query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PruneDecisions {
+    pub decisions: Vec<PruneDecision>,
+    /// The number of decisions which were made once the query had already recorded
+    /// [`MAX_PRUNE_DECISIONS_PER_QUERY`], and weren't kept.
+    pub num_dropped: usize,
+}
+
+impl PruneDecisions {
+    fn push(&mut self, decision: PruneDecision) {
+        if self.decisions.len() < MAX_PRUNE_DECISIONS_PER_QUERY {
+            self.decisions.push(decision);
+        } else {
+            self.num_dropped += 1;
+        }
+    }
+}
+
+/// The pruning decisions of the queries which are running, by query ID.
+static PRUNE_DECISIONS: LazyLock<Mutex<HashMap<String, PruneDecisions>>> =
+    LazyLock::new(Default::default);
+
+fn is_env_var_enabled(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|val| matches!(val.trim().to_lowercase().as_str(), "1" | "true"))
+}
+
+/// Returns whether pruning decisions are recorded.
+#[must_use]
+pub fn scan_diagnostics_enabled() -> bool {
+    is_env_var_enabled(SCAN_DIAGNOSTICS_VAR) || is_env_var_enabled(EXPLAIN_ANALYZE_VAR)
+}
+
+/// Starts recording the pruning decisions of a query, if scan diagnostics are enabled.
+///
+/// Scans don't know which query they belong to, so while queries run concurrently in a process, each of them records
+/// the decisions of all of their scans.
+pub fn start_recording_pruning(query_id: &str) {
+    if scan_diagnostics_enabled() {
+        PRUNE_DECISIONS
+            .lock()
+            .unwrap()
+            .insert(query_id.to_string(), PruneDecisions::default());
+    }
+}
+
+/// Stops recording the pruning decisions of a query, and returns the decisions recorded for it.
+#[must_use]
+pub fn take_pruning_decisions(query_id: &str) -> PruneDecisions {
+    PRUNE_DECISIONS
+        .lock()
+        .unwrap()
+        .remove(query_id)
+        .unwrap_or_default()
+}
+
+/// Records that a scan skipped a file, row group or page, for every query that is recording pruning decisions.
+///
+/// The predicate is only formatted if the decision is recorded.
+pub fn record_pruned(
+    path: &str,
+    row_group: Option<usize>,
+    page: Option<usize>,
+    reason: PruneReason,
+    predicate: &impl Display,
+) {
+    let mut queries = PRUNE_DECISIONS.lock().unwrap();
+    if queries.is_empty() {
+        return;
+    }
+    let decision = PruneDecision {
+        path: path.to_string(),
+        row_group,
+        page,
+        reason,
+        predicate: predicate.to_string(),
+    };
+    for decisions in queries.values_mut() {
+        decisions.push(decision.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MAX_PRUNE_DECISIONS_PER_QUERY, PRUNE_DECISIONS, PruneDecision, PruneDecisions, PruneReason,
+        record_pruned, take_pruning_decisions,
+    };
+
+    #[test]
+    fn test_display_prune_decision() {
+        let file = PruneDecision {
+            path: "s3://bucket/year=2023/0.parquet".to_string(),
+            row_group: None,
+            page: None,
+            reason: PruneReason::PartitionValues,
+            predicate: "col(year) == lit(2024)".to_string(),
+        };
+        assert_eq!(
+            file.to_string(),
+            "s3://bucket/year=2023/0.parquet: skipped by partition values for col(year) == lit(2024)"
+        );
+
+        let row_group = PruneDecision {
+            row_group: Some(3),
+            reason: PruneReason::RowGroupStatistics,
+            ..file
+        };
+        assert_eq!(
+            row_group.to_string(),
+            "s3://bucket/year=2023/0.parquet row group 3: skipped by row group statistics for col(year) == lit(2024)"
+        );
+
+        let page = PruneDecision {
+            page: Some(1),
+            reason: PruneReason::PageIndex,
+            ..row_group
+        };
+        assert_eq!(
+            page.to_string(),
+            "s3://bucket/year=2023/0.parquet row group 3 page 1: skipped by page index for col(year) == lit(2024)"
+        );
+    }
+
+    #[test]
+    fn test_pruning_decisions_are_bounded_per_query() {
+        PRUNE_DECISIONS
+            .lock()
+            .unwrap()
+            .insert("bounded".to_string(), PruneDecisions::default());
+        for row_group in 0..MAX_PRUNE_DECISIONS_PER_QUERY + 5 {
+            record_pruned(
+                "0.parquet",
+                Some(row_group),
+                None,
+                PruneReason::RowGroupStatistics,
+                &"col(x) > lit(0)",
+            );
+        }
+
+        let recorded = take_pruning_decisions("bounded");
+        assert_eq!(recorded.decisions.len(), MAX_PRUNE_DECISIONS_PER_QUERY);
+        assert_eq!(recorded.num_dropped, 5);
+        // Decisions aren't recorded for queries once they're taken.
+        assert_eq!(take_pruning_decisions("bounded"), PruneDecisions::default());
+    }
+}
@@ -6,7 +6,7 @@ use daft_core::{
     prelude::{DataType, Field, Schema},
     series::Series,
 };
-use daft_micropartition::MicroPartition;
+use daft_micropartition::{MicroPartition, compression::should_compress};
 use daft_recordbatch::RecordBatch;
 
 use crate::{AsyncFileWriter, RETURN_PATHS_COLUMN_NAME, WriteResult, WriterFactory};
@@ -62,6 +62,10 @@ impl AsyncFileWriter for IPCWriter {
 
         let size_bytes = data.size_bytes();
         let rows_written = data.len();
+        // The compression of a stream is fixed when it is created, so decide based on the first partition.
+        if self.writer.is_none() && self.compression.is_some() && !should_compress(&data) {
+            self.compression = None;
+        }
         let writer = self.get_or_create_writer(&data.schema())?;
 
         // Write each record batch
@@ -182,22 +182,27 @@ pub fn make_physical_writer_factory(
     }
 }
 
+/// Parses the codec used to compress IPC files such as shuffle files. Both `None` and "none" disable compression.
+pub fn parse_ipc_compression(
+    compression: Option<&str>,
+) -> DaftResult<Option<daft_arrow::ipc::CompressionType>> {
+    match compression {
+        Some("lz4") => Ok(Some(daft_arrow::ipc::CompressionType::LZ4_FRAME)),
+        Some("zstd") => Ok(Some(daft_arrow::ipc::CompressionType::ZSTD)),
+        Some("none") | None => Ok(None),
+        Some(c) => Err(DaftError::ValueError(format!(
+            "Unsupported compression for ipc writer: {}, only lz4 and zstd are supported",
+            c
+        ))),
+    }
+}
+
 pub fn make_ipc_writer(
     dir: &str,
     target_filesize: usize,
     compression: Option<&str>,
 ) -> DaftResult<Box<dyn AsyncFileWriter<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>>> {
-    let compression = match compression {
-        Some("lz4") => Some(daft_arrow::ipc::CompressionType::LZ4_FRAME),
-        Some("zstd") => Some(daft_arrow::ipc::CompressionType::ZSTD),
-        Some(c) => {
-            return Err(DaftError::ValueError(format!(
-                "Unsupported compression for ipc writer: {}, only lz4 and zstd are supported",
-                c
-            )));
-        }
-        None => None,
-    };
+    let compression = parse_ipc_compression(compression)?;
     let base_writer_factory = IPCWriterFactory::new(dir.to_string(), compression);
     let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
         target_filesize,
@@ -5,25 +5,62 @@ use common_error::DaftResult;
 use daft_logical_plan::DataSinkInfo;
 use daft_micropartition::{MicroPartition, python::PyMicroPartition};
 use daft_recordbatch::{RecordBatch, python::PyRecordBatch};
-use pyo3::{Python, types::PyAnyMethods};
+use pyo3::{Bound, Py, PyAny, PyResult, Python, types::PyAnyMethods};
 
 use crate::{AsyncFileWriter, WriteResult, WriterFactory};
 
+/// Writes a partition to a data sink, through the `_PartitionWrite` returned by the sink's `_open_partition` method.
+///
+/// The partition write is committed when the writer is closed, and aborted if the writer is dropped without being
+/// closed, e.g. because the write of the partition failed.
 pub struct DataSinkWriter {
     is_closed: bool,
-    data_sink_info: DataSinkInfo,
+    partition_write: Py<PyAny>,
     results: Vec<RecordBatch>,
     bytes_written: usize,
 }
 
 impl DataSinkWriter {
-    pub fn new(data_sink_info: DataSinkInfo) -> Self {
-        Self {
+    pub fn try_new(data_sink_info: &DataSinkInfo) -> DaftResult<Self> {
+        let partition_write = Python::attach(|py| {
+            data_sink_info
+                .sink
+                .call_method0(py, pyo3::intern!(py, "_open_partition"))
+        })?;
+        Ok(Self {
             is_closed: false,
-            data_sink_info,
+            partition_write,
             results: vec![],
             bytes_written: 0,
+        })
+    }
+
+    /// Saves the write results returned by the sink into a record batch, returning the number of bytes they wrote.
+    fn save_results<'py>(
+        &mut self,
+        py: Python<'py>,
+        result_list: Bound<'py, PyAny>,
+    ) -> PyResult<usize> {
+        let mut bytes_written = 0;
+        for result in result_list.try_iter()? {
+            bytes_written += result?.getattr("bytes_written")?.extract::<usize>()?;
+        }
+
+        let results_dict = pyo3::types::PyDict::new(py);
+        results_dict.set_item("write_results", result_list)?;
+        let results: PyRecordBatch = py
+            .import(pyo3::intern!(py, "daft.recordbatch"))?
+            .getattr(pyo3::intern!(py, "RecordBatch"))?
+            .getattr(pyo3::intern!(py, "from_pydict"))?
+            .call1((results_dict,))?
+            .getattr(pyo3::intern!(py, "_recordbatch"))?
+            .extract()?;
+        let results: RecordBatch = results.into();
+        if !results.is_empty() {
+            self.results.push(results);
         }
+        self.bytes_written += bytes_written;
+        Ok(bytes_written)
     }
 }
 
@@ -34,44 +71,19 @@ impl AsyncFileWriter for DataSinkWriter {
 
     async fn write(&mut self, data: Self::Input) -> DaftResult<WriteResult> {
         let rows_written = data.len();
-        let mut bytes_written = 0;
-        let mp_result: PyRecordBatch = Python::attach(|py| -> pyo3::PyResult<_> {
+        let bytes_written = Python::attach(|py| -> PyResult<_> {
             // Grab the current micropartition and pass it to the data sink.
             let py_micropartition = py
                 .import(pyo3::intern!(py, "daft.recordbatch"))?
                 .getattr(pyo3::intern!(py, "MicroPartition"))?
                 .getattr(pyo3::intern!(py, "_from_pymicropartition"))?
                 .call1((PyMicroPartition::from(data),))?;
-            let py_list = pyo3::types::PyList::new(py, &[py_micropartition])?;
-            let py_iter = py_list.try_iter()?;
-
-            let result =
-                self.data_sink_info
-                    .sink
-                    .call_method(py, "safe_write", (py_iter,), None)?;
-            let result_list = py
-                .import(pyo3::intern!(py, "builtins"))?
-                .call_method1("list", (result,))?;
-            for result in result_list.try_iter()? {
-                bytes_written += result?.getattr("bytes_written")?.extract::<usize>()?;
-            }
-
-            // Save return values into a record batch.
-            let results_dict = pyo3::types::PyDict::new(py);
-            results_dict.set_item("write_results", result_list)?;
-            Ok(py
-                .import(pyo3::intern!(py, "daft.recordbatch"))?
-                .getattr(pyo3::intern!(py, "RecordBatch"))?
-                .getattr(pyo3::intern!(py, "from_pydict"))?
-                .call1((results_dict,))?
-                .getattr(pyo3::intern!(py, "_recordbatch"))?
-                .extract()?)
+            let result_list = self
+                .partition_write
+                .bind(py)
+                .call_method1(pyo3::intern!(py, "write"), (py_micropartition,))?;
+            self.save_results(py, result_list)
         })?;
-
-        let mp_result: RecordBatch = mp_result.into();
-        if !mp_result.is_empty() {
-            self.results.push(mp_result);
-        }
         Ok(WriteResult {
             bytes_written,
             rows_written,
@@ -88,10 +100,29 @@ impl AsyncFileWriter for DataSinkWriter {
 
     async fn close(&mut self) -> DaftResult<Self::Result> {
         self.is_closed = true;
+        Python::attach(|py| -> PyResult<_> {
+            let result_list = self
+                .partition_write
+                .bind(py)
+                .call_method0(pyo3::intern!(py, "commit"))?;
+            self.save_results(py, result_list)
+        })?;
         Ok(std::mem::take(&mut self.results))
     }
 }
 
+impl Drop for DataSinkWriter {
+    fn drop(&mut self) {
+        if !self.is_closed {
+            // Errors can't be raised from a drop, and the error which failed the write is raised instead.
+            let _ = Python::attach(|py| {
+                self.partition_write
+                    .call_method0(py, pyo3::intern!(py, "abort"))
+            });
+        }
+    }
+}
+
 pub fn make_data_sink_writer_factory(
     data_sink_info: DataSinkInfo,
 ) -> Arc<dyn WriterFactory<Input = Arc<MicroPartition>, Result = Vec<RecordBatch>>> {
@@ -112,7 +143,7 @@ impl WriterFactory for DataSinkWriterFactory {
         _file_idx: usize,
         _partition_values: Option<&RecordBatch>,
     ) -> DaftResult<Box<dyn AsyncFileWriter<Input = Self::Input, Result = Self::Result>>> {
-        let writer = DataSinkWriter::new(self.data_sink_info.clone());
+        let writer = DataSinkWriter::try_new(&self.data_sink_info)?;
         Ok(Box::new(writer))
     }
 }
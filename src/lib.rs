@@ -118,6 +118,7 @@ pub mod pylib {
         common_scan_info::register_modules(m)?;
         common_metrics::register_modules(m)?;
         daft_ai::register_modules(m)?;
+        daft_benchmarking::register_modules(m)?;
         daft_catalog::register_modules(m)?;
         daft_context::register_modules(m)?;
         daft_runners::register_modules(m)?;
@@ -177,6 +178,8 @@ pub mod pylib {
         functions_registry.add_fn(daft_file::Size);
         functions_registry.add_fn(daft_file::VideoFile);
         functions_registry.add_fn(daft_file::AudioFile);
+        functions_registry.add_fn(daft_file::BlobFile);
+        functions_registry.add_fn(daft_file::BlobRead);
         functions_registry
             .add_fn(daft_functions::monotonically_increasing_id::MonotonicallyIncreasingId);
         functions_registry.register::<daft_functions::distance::DistanceFunctions>();
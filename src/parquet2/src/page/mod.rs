@@ -242,7 +242,9 @@ pub enum Page {
 }
 
 impl Page {
-    pub(crate) fn buffer(&mut self) -> &mut Vec<u8> {
+    /// Returns a mutable reference to the internal buffer.
+    /// Useful to recover the buffer after the page has been decoded.
+    pub fn buffer(&mut self) -> &mut Vec<u8> {
         match self {
             Self::Data(page) => &mut page.buffer,
             Self::Dict(page) => &mut page.buffer,
@@ -267,7 +269,7 @@ impl CompressedPage {
         }
     }
 
-    pub(crate) fn compression(&self) -> Compression {
+    pub fn compression(&self) -> Compression {
         match self {
             CompressedPage::Data(page) => page.compression(),
             CompressedPage::Dict(page) => page.compression(),
@@ -345,6 +347,20 @@ impl CompressedDictPage {
     pub fn compression(&self) -> Compression {
         self.compression
     }
+
+    /// The compressed data of this page.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// The size of the data of this page once decompressed.
+    pub fn uncompressed_size(&self) -> usize {
+        self.uncompressed_page_size
+    }
+
+    pub fn num_values(&self) -> usize {
+        self.num_values
+    }
 }
 
 /// Splits the page buffer into 3 slices corresponding to (encoded rep levels, encoded def levels, encoded values) for v1 pages.
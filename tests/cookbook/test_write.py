@@ -10,7 +10,7 @@ import pytest
 from pyarrow import dataset as pads
 
 import daft
-from tests.conftest import assert_df_equals
+from tests.conftest import assert_df_equals, get_tests_daft_runner_name
 from tests.cookbook.assets import COOKBOOK_DATA_CSV
 
 PYARROW_GE_7_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (7, 0, 0)
@@ -221,6 +221,22 @@ def test_parquet_partitioned_write_with_some_empty_partitions(tmp_path, with_mor
     assert read_back == data
 
 
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() == "native",
+    reason="Only distributed writes shuffle the rows of each bucket to a single task",
+)
+def test_parquet_bucketed_write_writes_one_file_per_bucket(tmp_path):
+    data = {"x": list(range(40))}
+    # Every input partition has rows of every bucket, which are shuffled together before they're written.
+    df = daft.from_pydict(data).into_partitions(3)
+    bucket = daft.col("x").partition_iceberg_bucket(4).alias("bucket")
+    output_files = df.write_parquet(tmp_path, partition_cols=[bucket])
+
+    assert len(output_files) == 4
+    read_back = daft.read_parquet(tmp_path.as_posix() + "/**/*.parquet").sort("x").to_pydict()
+    assert read_back == data
+
+
 def test_csv_write(tmp_path, with_morsel_size):
     df = daft.read_csv(COOKBOOK_DATA_CSV)
 
@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+import gc
+import os
+
+import pytest
+
+import daft
+from daft import col
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Spilling collected results is only supported by the native runner"
+)
+
+
+def test_collect_spill():
+    data = {"a": list(range(100)), "b": [str(i) for i in range(100)], "c": [[i, i + 1] for i in range(100)]}
+    with daft.execution_config_ctx(collect_spill_threshold_bytes=1):
+        df = daft.from_pydict(data).into_partitions(4).with_column("d", col("a") * 2).collect()
+        assert df.to_pydict() == {**data, "d": [i * 2 for i in range(100)]}
+
+
+def test_collect_spill_python_dtype():
+    data = {"a": list(range(10)), "obj": [object() for _ in range(10)]}
+    with daft.execution_config_ctx(collect_spill_threshold_bytes=1):
+        df = daft.from_pydict(data).into_partitions(4).collect()
+        assert df.to_pydict()["obj"] == data["obj"]
+
+
+def test_collect_spill_nested_python_dtype():
+    data = {"a": list(range(10)), "objs": [[object()] for _ in range(10)], "s": [{"obj": object()} for _ in range(10)]}
+    with daft.execution_config_ctx(collect_spill_threshold_bytes=1):
+        df = daft.from_pydict(data).into_partitions(4).collect()
+        assert not df._result._spilled
+        assert df.to_pydict() == data
+
+
+def test_spilled_partition_is_reused_while_referenced():
+    with daft.execution_config_ctx(collect_spill_threshold_bytes=1):
+        df = daft.from_pydict({"a": list(range(100))}).into_partitions(4).collect()
+
+    spilled = next(iter(df._result._spilled.values()))
+    part = spilled.partition()
+    assert spilled.partition() is part
+    assert part.to_pydict()["a"] == spilled.partition().to_pydict()["a"]
+
+    del part
+    gc.collect()
+    assert spilled._cached() is None
+    assert len(spilled.partition()) == spilled.metadata().num_rows
+
+
+def test_collect_spill_keeps_spilled_partitions_on_disk():
+    data = {"a": list(range(100))}
+    with daft.execution_config_ctx(collect_spill_threshold_bytes=1):
+        df = daft.from_pydict(data).into_partitions(4).collect()
+
+    pset = df._result
+    # The first partition is kept in memory, and the rest are only read back when they're accessed.
+    spilled = sorted(pset._spilled)
+    assert spilled and 0 not in spilled
+    spill_dir = os.path.dirname(pset._spilled[spilled[0]]._path)
+    assert sorted(os.listdir(spill_dir)) == sorted(f"{i}.arrow" for i in spilled)
+
+    assert len(df) == 100
+    assert df.to_pydict() == data
+    assert sorted(pset._spilled) == spilled
+
+    del df, pset
+    gc.collect()
+    assert not os.path.exists(spill_dir)
+
+
+@pytest.mark.parametrize(["compression", "magic"], [("zstd", b"\x28\xb5\x2f\xfd"), ("lz4", b"\x04\x22\x4d\x18")])
+def test_collect_spill_compressed(compression, magic):
+    data = {"a": [i % 7 for i in range(10_000)], "b": ["daft" * (i % 3) for i in range(10_000)]}
+
+    def spill_sizes(**config):
+        with daft.execution_config_ctx(collect_spill_threshold_bytes=1, **config):
+            df = daft.from_pydict(data).into_partitions(4).collect()
+        assert df.to_pydict() == data
+        paths = [spilled._path for spilled in df._result._spilled.values()]
+        assert paths
+        sizes = []
+        for path in paths:
+            with open(path, "rb") as f:
+                sizes.append((f.read(4), os.path.getsize(path)))
+        return sizes
+
+    uncompressed = spill_sizes()
+    compressed = spill_sizes(shuffle_compression=compression, shuffle_compression_level=1)
+    assert all(header == magic for header, _ in compressed)
+    assert all(header != magic for header, _ in uncompressed)
+    assert sum(size for _, size in compressed) < sum(size for _, size in uncompressed)
+
+
+def test_collect_without_spill_threshold_does_not_spill():
+    df = daft.from_pydict({"a": list(range(100))}).into_partitions(4).collect()
+    assert not df._result._spilled
@@ -0,0 +1,48 @@
+from __future__ import annotations
+
+import daft
+from daft import col
+from daft.context import get_context
+
+
+def _lineage(df: daft.DataFrame) -> dict:
+    builder = df._builder.optimize(get_context().daft_execution_config)
+    return builder.column_lineage()
+
+
+def _inputs(lineage: dict, name: str) -> list[tuple[str, str]]:
+    (column,) = [c for c in lineage["columns"] if c["name"] == name]
+    return sorted((lineage["sources"][i["source"]], i["column"]) for i in column["inputs"])
+
+
+def test_column_lineage_through_projections_and_aggregations():
+    df = daft.from_pydict({"a": [1, 2, 3], "b": [4, 5, 6], "c": ["x", "y", "x"]})
+    df = df.where(col("a") > 1).select(col("c").alias("key"), (col("a") + col("b")).alias("total"))
+    df = df.groupby("key").agg(col("total").sum())
+
+    lineage = _lineage(df)
+    assert [c["name"] for c in lineage["columns"]] == ["key", "total"]
+    assert len(lineage["sources"]) == 1
+    source = lineage["sources"][0]
+
+    assert _inputs(lineage, "key") == [(source, "c")]
+    assert _inputs(lineage, "total") == [(source, "a"), (source, "b")]
+
+    (total,) = [c for c in lineage["columns"] if c["name"] == "total"]
+    assert len(total["transformations"]) == 2
+    assert all(isinstance(t, str) for t in total["transformations"])
+
+
+def test_column_lineage_through_joins():
+    users = daft.from_pydict({"id": [1, 2], "name": ["a", "b"]})
+    scores = daft.from_pydict({"id": [1, 2], "score": [0.5, 0.7]})
+    df = users.join(scores.with_column("score", col("score") * 2), on="id")
+
+    lineage = _lineage(df)
+    assert len(lineage["sources"]) == 2
+
+    [(users_source, name)] = _inputs(lineage, "name")
+    [(scores_source, score)] = _inputs(lineage, "score")
+    assert (name, score) == ("name", "score")
+    assert users_source != scores_source
+    assert [c["name"] for c in lineage["columns"]] == df.column_names
@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+native_only = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Sampling by size only works on the native runner"
+)
+
+
+@native_only
+def test_cross_join_sample_rows_are_from_the_product(make_df):
+    left = make_df({"a": list(range(20)), "x": [i * 10 for i in range(20)]}, repartition=2)
+    right = make_df({"b": list(range(30)), "x": [i * 100 for i in range(30)]}, repartition=3)
+
+    result = left.cross_join_sample(right, n=50, seed=42).to_pydict()
+
+    assert set(result.keys()) == {"a", "x", "b", "right.x"}
+    pairs = list(zip(result["a"], result["b"]))
+    assert len(pairs) == 50
+    assert len(set(pairs)) == 50
+    assert all(x == a * 10 for a, x in zip(result["a"], result["x"]))
+    assert all(x == b * 100 for b, x in zip(result["b"], result["right.x"]))
+
+
+@native_only
+def test_cross_join_sample_is_seeded():
+    left = daft.from_pydict({"a": list(range(10))})
+    right = daft.from_pydict({"b": list(range(10))})
+
+    def sample():
+        result = left.cross_join_sample(right, n=10, seed=7).to_pydict()
+        return sorted(zip(result["a"], result["b"]))
+
+    assert sample() == sample()
+
+
+@native_only
+def test_cross_join_sample_entire_product():
+    left = daft.from_pydict({"a": [1, 2]})
+    right = daft.from_pydict({"b": ["x", "y", "z"]})
+
+    result = left.cross_join_sample(right, n=6).to_pydict()
+    assert sorted(zip(result["a"], result["b"])) == [(a, b) for a in [1, 2] for b in ["x", "y", "z"]]
+
+
+@native_only
+def test_cross_join_sample_with_replacement():
+    left = daft.from_pydict({"a": [1]})
+    right = daft.from_pydict({"b": [2]})
+
+    result = left.cross_join_sample(right, n=3, with_replacement=True).to_pydict()
+    assert result == {"a": [1, 1, 1], "b": [2, 2, 2]}
+
+
+@native_only
+def test_cross_join_sample_too_many_rows():
+    left = daft.from_pydict({"a": [1, 2]})
+    right = daft.from_pydict({"b": [3]})
+
+    sampled = left.cross_join_sample(right, n=3)
+    with pytest.raises(ValueError, match="Cannot sample 3 rows"):
+        sampled.collect()
+    with pytest.raises(ValueError, match="non-negative"):
+        left.cross_join_sample(right, n=-1)
+
+
+def test_cross_join_small_morsels(make_df):
+    left = make_df({"a": list(range(100))})
+    right = make_df({"b": list(range(50))})
+
+    with daft.execution_config_ctx(default_morsel_size=7):
+        result = left.join(right, how="cross").to_pydict()
+
+    assert len(result["a"]) == 5000
+    assert len(set(zip(result["a"], result["b"]))) == 5000
+
+
+@native_only
+def test_cross_join_sample_is_planned_as_a_sample():
+    left = daft.from_pydict({"a": list(range(1000))})
+    right = daft.from_pydict({"b": list(range(1000))})
+
+    sampled = left.cross_join_sample(right, n=4, seed=1)
+
+    # The sample is part of the plan, so nothing is executed until the DataFrame is.
+    assert sampled._result is None
+    assert len(sampled.to_pydict()["a"]) == 4
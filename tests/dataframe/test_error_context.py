@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import sys
+
+import pytest
+
+import daft
+from daft import col
+from daft.errors import UDFException
+from tests.conftest import get_tests_daft_runner_name
+
+pytestmark = pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Errors are given the context of operators by the native runner"
+)
+
+
+@pytest.mark.skipif(sys.version_info < (3, 11), reason="Exception notes require Python 3.11")
+def test_udf_error_has_operator_note():
+    @daft.udf(return_dtype=daft.DataType.string())
+    def fail(x):
+        raise ValueError("AN ERROR OCCURRED!")
+
+    df = daft.from_pydict({"a": ["foo", "bar"]}).select(fail(col("a")))
+
+    with pytest.raises(UDFException) as exc_info:
+        df.collect()
+
+    notes = getattr(exc_info.value, "__notes__", [])
+    assert any(note.startswith("In ") and "pipeline node" in note for note in notes)
+    assert str(exc_info.value.__cause__) == "AN ERROR OCCURRED!"
+
+
+
+@pytest.mark.skipif(sys.version_info < (3, 11), reason="Exception notes require Python 3.11")
+def test_row_wise_udf_error_has_row_note():
+    @daft.func(return_dtype=daft.DataType.int64(), use_process=False)
+    def fail_on_two(x: int) -> int:
+        if x == 2:
+            raise ValueError("AN ERROR OCCURRED!")
+        return x
+
+    df = daft.from_pydict({"a": [1, 2, 3]}).select(fail_on_two(col("a")))
+
+    with pytest.raises(Exception) as exc_info:
+        df.collect()
+
+    notes = []
+    exc = exc_info.value
+    while exc is not None:
+        notes.extend(getattr(exc, "__notes__", []))
+        exc = exc.__cause__
+    assert "On row 1 with arguments a=2" in notes
+    assert any(note.startswith("In ") and "pipeline node" in note for note in notes)
+
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import io
+import json
 
 import pytest
 
@@ -118,3 +119,51 @@ def test_explain_with_ray_options(input_df):
     assert "{'runtime_env': {'conda': {'name': 'simple', 'channels': ['conda-forge']}}}" in text, (
         f"Unexpected Explain result: {text}"
     )
+
+
+def test_explain_graph_json():
+    df = daft.from_pydict({"x": [1, 2, 3]}).where(col("x") > 1)
+    graph = json.loads(df._builder.pretty_print(format="json"))
+
+    assert [node["name"] for node in graph["nodes"]] == ["Filter", "Source"]
+    assert [field["name"] for field in graph["nodes"][1]["details"]["schema"]] == ["x"]
+    assert graph["edges"] == [{"source": 1, "target": 0}]
+    # The JSON representation of the plan doesn't change.
+    assert "schema" not in json.loads(df._builder._builder.repr_json(False))
+
+
+def test_explain_unknown_format():
+    string_io = io.StringIO()
+    with pytest.raises(ValueError, match="Unknown format"):
+        daft.from_pydict({"x": [1]}).explain(True, format="svg", file=string_io)
+    assert string_io.getvalue() == ""
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "native", reason="Native physical plans support every format")
+@pytest.mark.parametrize("format", ["dot", "json"])
+def test_explain_distributed_unsupported_format(format):
+    string_io = io.StringIO()
+    with pytest.raises(ValueError, match="not supported for distributed physical plans"):
+        daft.from_pydict({"x": [1]}).explain(True, format=format, file=string_io)
+    # Nothing is printed before the format is rejected.
+    assert string_io.getvalue() == ""
+
+
+def test_explain_trace_optimizer():
+    df = daft.from_pydict({"x": [1, 2, 3], "y": [4, 5, 6]}).select("x", "y").where(col("x") > 1).select("x")
+    string_io = io.StringIO()
+    df.explain(trace_optimizer=True, file=string_io)
+    text = string_io.getvalue()
+
+    assert "== Optimizer Trace ==" in text
+    assert "== Step 1: " in text
+    # Pushing the filter down is attributed to the rule that did it.
+    assert "PushDownFilter" in text
+    assert "\n- * " in text and "\n+ * " in text
+
+
+def test_explain_trace_optimizer_unsupported_format():
+    string_io = io.StringIO()
+    with pytest.raises(ValueError, match="only supported with the 'ascii' format"):
+        daft.from_pydict({"x": [1]}).explain(format="json", trace_optimizer=True, file=string_io)
+    assert string_io.getvalue() == ""
@@ -151,3 +151,36 @@ def test_explode_bad_col_type(make_df):
     df = make_df({"a": [1, 2, 3]})
     with pytest.raises(ValueError, match="Input must be a list"):
         df = df.explode(col("a"))
+
+
+def test_explode_with_index_column(make_df):
+    df = make_df({"nested": [[1, 2], [3], None, []], "sidecar": ["a", "b", "c", "d"]})
+    df = df.explode(col("nested"), index_column="pos")
+    assert df.to_pydict() == {
+        "nested": [1, 2, 3, None, None],
+        "sidecar": ["a", "a", "b", "c", "d"],
+        "pos": [0, 1, 0, None, None],
+    }
+
+
+def test_explode_index_column_conflict(make_df):
+    df = make_df({"nested": [[1, 2]], "sidecar": ["a"]})
+    with pytest.raises(Exception, match="conflicts with an existing column"):
+        df.explode(col("nested"), index_column="sidecar")
+
+
+def test_explode_mismatched_lengths(make_df):
+    df = make_df({"x": [[1, 2], [3]], "y": [["a"], ["b", "c"]]})
+    with pytest.raises(Exception, match="list length did not match"):
+        df.explode(col("x"), col("y")).collect()
+
+
+def test_explode_pad_mismatched_lengths(make_df):
+    df = make_df({"x": [[1, 2], [3], None, []], "y": [["a"], ["b", "c"], ["d"], None], "id": [1, 2, 3, 4]})
+    df = df.explode(col("x"), col("y"), index_column="pos", pad_mismatched=True)
+    assert df.to_pydict() == {
+        "x": [1, 2, 3, None, None, None],
+        "y": ["a", None, "b", "c", "d", None],
+        "id": [1, 1, 2, 2, 3, 4],
+        "pos": [0, 1, 0, 1, 0, None],
+    }
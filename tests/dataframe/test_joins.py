@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import io
+
 import pyarrow as pa
 import pytest
 
@@ -12,12 +14,10 @@ from tests.utils import sort_arrow_table
 
 def skip_invalid_join_strategies(join_strategy, join_type):
     if get_tests_daft_runner_name() == "native":
-        if join_strategy not in [None, "hash"]:
+        if join_strategy not in [None, "hash", "sort_merge"]:
             pytest.skip("Native executor fails for these tests")
     else:
-        if (join_strategy == "sort_merge") and join_type != "inner":
-            pytest.skip("Sort merge currently only supports inner joins")
-        elif join_strategy == "broadcast" and join_type == "outer":
+        if join_strategy == "broadcast" and join_type == "outer":
             pytest.skip("Broadcast join does not support outer joins")
 
 
@@ -29,10 +29,6 @@ def test_invalid_join_strategies(make_df):
         },
     )
 
-    for join_type in ["left", "right", "outer"]:
-        with pytest.raises(ValueError):
-            df.join(df, on="A", strategy="sort_merge", how=join_type)
-
     with pytest.raises(ValueError):
         df.join(df, on="A", strategy="broadcast", how="outer")
 
@@ -85,8 +81,8 @@ def test_joins(join_strategy, join_type, make_df, n_partitions, with_morsel_size
 
     print(f"join_strategy: {join_strategy}, join_type: {join_type}")
     joined = df.join(df, on="A", strategy=join_strategy, how=join_type)
-    # We shouldn't need to sort the joined output if using a sort-merge join.
-    if join_strategy != "sort_merge":
+    # We shouldn't need to sort the joined output if using an inner sort-merge join.
+    if join_strategy != "sort_merge" or join_type != "inner":
         joined = joined.sort("A")
     joined_data = joined.to_pydict()
 
@@ -117,8 +113,8 @@ def test_multicol_joins(join_strategy, join_type, make_df, n_partitions: int, wi
     )
 
     joined = df.join(df, on=["A", "B"], strategy=join_strategy, how=join_type)
-    # We shouldn't need to sort the joined output if using a sort-merge join.
-    if join_strategy != "sort_merge":
+    # We shouldn't need to sort the joined output if using an inner sort-merge join.
+    if join_strategy != "sort_merge" or join_type != "inner":
         joined = joined.sort("A")
     joined_data = joined.to_pydict()
 
@@ -377,8 +373,8 @@ def test_joins_no_overlap_interleaved(
         joined = df2.join(df1, on="A", strategy=join_strategy, how=join_type)
     else:
         joined = df1.join(df2, on="A", strategy=join_strategy, how=join_type)
-    # We shouldn't need to sort the joined output if using a sort-merge join.
-    if join_strategy != "sort_merge":
+    # We shouldn't need to sort the joined output if using an inner sort-merge join.
+    if join_strategy != "sort_merge" or join_type != "inner":
         joined = joined.sort("A")
     joined_data = joined.to_pydict()
 
@@ -1395,3 +1391,118 @@ def test_self_join_with_projection():
     }
 
     assert pa.Table.from_pydict(result_df.to_pydict()) == pa.Table.from_pydict(expected)
+
+
+@pytest.mark.parametrize("join_type", ["left", "right", "outer", "semi", "anti"])
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_hash_join_with_small_kept_side(join_type, repartition_nparts, make_df, with_morsel_size):
+    # The side whose unmatched rows are kept is much smaller than the other side, so it is built on by the hash join,
+    # and its unmatched rows can only be emitted after the other side has been fully probed.
+    small = {"a": [1, 2, 3, 100], "b": ["w", "x", "y", "z"]}
+    large = {"c": [i % 10 for i in range(1000)], "d": list(range(1000))}
+
+    if join_type == "right":
+        (left, left_on), (right, right_on) = (large, "c"), (small, "a")
+    else:
+        (left, left_on), (right, right_on) = (small, "a"), (large, "c")
+
+    left_df = make_df(left, repartition=repartition_nparts, repartition_columns=[left_on])
+    right_df = make_df(right, repartition=repartition_nparts, repartition_columns=[right_on])
+    result = left_df.join(right_df, left_on=left_on, right_on=right_on, how=join_type, strategy="hash").to_pydict()
+
+    left_rows = [dict(zip(left, row)) for row in zip(*left.values())]
+    right_rows = [dict(zip(right, row)) for row in zip(*right.values())]
+    null_left = dict.fromkeys(left)
+    null_right = dict.fromkeys(right)
+
+    expected_rows = []
+    if join_type in ("semi", "anti"):
+        right_keys = {row[right_on] for row in right_rows}
+        expected_rows = [row for row in left_rows if (row[left_on] in right_keys) == (join_type == "semi")]
+    else:
+        matched_right = set()
+        for left_row in left_rows:
+            matches = [i for i, right_row in enumerate(right_rows) if left_row[left_on] == right_row[right_on]]
+            matched_right.update(matches)
+            expected_rows.extend({**left_row, **right_rows[i]} for i in matches)
+            if not matches and join_type in ("left", "outer"):
+                expected_rows.append({**left_row, **null_right})
+        if join_type in ("right", "outer"):
+            expected_rows.extend(
+                {**null_left, **right_row} for i, right_row in enumerate(right_rows) if i not in matched_right
+            )
+
+    expected = {name: [row[name] for row in expected_rows] for name in result}
+    sort_by = list(result)
+    assert sort_arrow_table(pa.Table.from_pydict(result), *sort_by) == sort_arrow_table(
+        pa.Table.from_pydict(expected), *sort_by
+    )
+
+
+@pytest.mark.parametrize("join_type", ["inner", "semi"])
+@pytest.mark.parametrize("swap_sides", [False, True])
+def test_hash_join_with_bloom_filter_pruning(join_type, swap_sides, make_df):
+    # Only a few keys of the large side match the small side, so most of it is pruned by the Bloom filter before the
+    # shuffle. Null keys never match and must be pruned as well.
+    small = {"a": [1, 2, None, 500], "b": ["w", "x", "y", "z"]}
+    large = {"c": [i if i % 7 else None for i in range(1000)], "d": list(range(1000))}
+    (left, left_on), (right, right_on) = ((large, "c"), (small, "a")) if swap_sides else ((small, "a"), (large, "c"))
+
+    with daft.execution_config_ctx(enable_bloom_filter_join_pruning=True, broadcast_join_size_bytes_threshold=0):
+        left_df = make_df(left, repartition=3)
+        right_df = make_df(right, repartition=2)
+        result = left_df.join(right_df, left_on=left_on, right_on=right_on, how=join_type).to_pydict()
+
+    right_keys = {key for key in right[right_on] if key is not None}
+    expected_rows = []
+    for i, key in enumerate(left[left_on]):
+        left_row = {name: values[i] for name, values in left.items()}
+        if join_type == "semi":
+            if key in right_keys:
+                expected_rows.append(left_row)
+            continue
+        for j, right_key in enumerate(right[right_on]):
+            if key is not None and key == right_key:
+                expected_rows.append({**left_row, **{name: values[j] for name, values in right.items()}})
+
+    expected = {name: [row[name] for row in expected_rows] for name in result}
+    sort_by = list(result)
+    assert sort_arrow_table(pa.Table.from_pydict(result), *sort_by) == sort_arrow_table(
+        pa.Table.from_pydict(expected), *sort_by
+    )
+
+
+@pytest.mark.parametrize("join_type", ["inner", "left", "right", "outer", "semi", "anti"])
+@pytest.mark.parametrize("presorted", [False, True])
+def test_sort_merge_join_streams_small_morsels(join_type, presorted):
+    # Small morsels make the merge stream each side over many steps, with runs of duplicate keys that span morsels
+    left = daft.from_pydict({"k": [i // 3 if i % 11 else None for i in range(200)], "v": list(range(200))})
+    right = daft.from_pydict({"k": [i // 2 if i % 13 else None for i in range(150, -50, -1)], "w": list(range(200))})
+    if presorted:
+        left, right = left.sort("k"), right.sort("k")
+
+    with daft.execution_config_ctx(default_morsel_size=4):
+        expected = left.join(right, on="k", how=join_type, strategy="hash").to_arrow()
+        actual = left.join(right, on="k", how=join_type, strategy="sort_merge").to_arrow()
+
+    sort_by = expected.column_names
+    assert sort_arrow_table(actual, *sort_by) == sort_arrow_table(expected, *sort_by)
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Join strategies are chosen by the native runner")
+def test_sort_merge_join_chosen_for_sorted_inputs():
+    left = daft.from_pydict({"k": [3, 1, 2], "v": [30, 10, 20]})
+    right = daft.from_pydict({"k": [2, 3, 4], "w": ["b", "c", "d"]})
+
+    def physical_plan(df: daft.DataFrame) -> str:
+        string_io = io.StringIO()
+        df.explain(True, file=string_io)
+        return string_io.getvalue().split("== Physical Plan ==")[-1]
+
+    assert "Sort Merge Join" in physical_plan(left.sort("k").join(right.sort("k"), on="k"))
+    # Only one side is sorted, or the sides are sorted in the wrong order
+    assert "Sort Merge Join" not in physical_plan(left.join(right.sort("k"), on="k"))
+    assert "Sort Merge Join" not in physical_plan(left.sort("k", desc=True).join(right.sort("k"), on="k"))
+
+    df = left.sort("k").join(right.sort("k"), on="k")
+    assert df.sort("k").to_pydict() == {"k": [2, 3], "v": [20, 30], "w": ["b", "c"]}
@@ -155,9 +155,17 @@ def test_negative_offset(input_df):
 
 
 def test_offset_without_limit(input_df):
-    with pytest.raises(Exception) as excinfo:
-        input_df.select("name").offset(17).collect()
-    assert "Not Yet Implemented: Offset without limit is unsupported now!" in str(excinfo.value)
+    df = input_df.select("id").offset(17)
+    assert df.count_rows() == 1024 - 17
+
+    df = input_df.select("id").sort(by="id").offset(1020)
+    assert df.to_pydict() == {"id": [1020, 1021, 1022, 1023]}
+
+    df = input_df.select("id").offset(1024)
+    assert df.to_pydict() == {"id": []}
+
+    df = input_df.select("id").sort(by="id").offset(3).offset(4).filter(col("id") < 10)
+    assert df.to_pydict() == {"id": [7, 8, 9]}
 
 
 def test_limit_before_offset(input_df):
@@ -498,3 +506,12 @@ def test_multiple_limits():
 
     df = df.to_pydict()
     assert df["id"] == [i for i in range(901, 1000)]
+
+
+def test_paging_without_maintain_order():
+    df = daft.range(1000, partitions=100)
+    with daft.execution_config_ctx(maintain_order=False):
+        pages = [df.offset(offset).limit(100).to_pydict()["id"] for offset in range(0, 1000, 100)]
+        assert pages == [list(range(offset, offset + 100)) for offset in range(0, 1000, 100)]
+
+        assert df.offset(990).to_pydict()["id"] == list(range(990, 1000))
@@ -0,0 +1,68 @@
+from __future__ import annotations
+
+import time
+
+import pytest
+
+import daft
+from daft import col
+from daft.errors import ExecutionTimeoutError
+
+
+@daft.func
+def slow_identity(x: int) -> int:
+    time.sleep(0.1)
+    return x
+
+
+def test_collect_stops_at_max_execution_time():
+    num_rows = 400
+    df = daft.from_pydict({"a": list(range(num_rows))}).into_partitions(40).with_column("b", slow_identity(col("a")))
+
+    start = time.monotonic()
+    with pytest.raises(ExecutionTimeoutError) as exc_info:
+        df.collect(max_execution_time=0.5)
+    elapsed = time.monotonic() - start
+
+    completeness = exc_info.value.completeness
+    assert completeness is not None
+    assert not completeness.complete
+    assert elapsed < num_rows * 0.1
+    assert completeness.elapsed_seconds <= elapsed
+    assert completeness.num_rows < num_rows
+    assert completeness.num_partitions == len(completeness.partition_num_rows)
+
+    # The partial results are handed over as a separate DataFrame, which is used by later operations.
+    partial = exc_info.value.partial_result
+    assert isinstance(partial, daft.DataFrame)
+    assert partial.completeness == completeness
+    result = partial.to_pydict()
+    assert len(result["a"]) == completeness.num_rows
+    assert result["a"] == result["b"]
+    assert partial.count_rows() == completeness.num_rows
+
+    # The DataFrame which timed out is left unmaterialized, so its partial results can't pass for its full results.
+    assert df._result is None
+    assert df.completeness is None
+
+
+def test_collect_finishes_before_max_execution_time():
+    df = daft.from_pydict({"a": [1, 2, 3]}).into_partitions(2).collect(max_execution_time=60)
+
+    completeness = df.completeness
+    assert completeness is not None
+    assert completeness.complete
+    assert completeness.num_rows == 3
+    assert sum(completeness.partition_num_rows) == 3
+    assert df.to_pydict() == {"a": [1, 2, 3]}
+
+
+def test_collect_without_max_execution_time_has_no_completeness():
+    df = daft.from_pydict({"a": [1, 2, 3]}).collect()
+    assert df.completeness is None
+
+
+@pytest.mark.parametrize("max_execution_time", [0, -1.0])
+def test_collect_invalid_max_execution_time(max_execution_time):
+    with pytest.raises(ValueError, match="max_execution_time must be positive"):
+        daft.from_pydict({"a": [1]}).collect(max_execution_time=max_execution_time)
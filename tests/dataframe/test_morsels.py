@@ -372,3 +372,17 @@ def test_batch_size_from_into_batches_before_udf():
 
 """
     assert clean_explain_output(string_io.getvalue().split("== Physical Plan ==")[-1]) == clean_explain_output(expected)
+
+
+def test_udf_batch_size_bounds_dynamic_batches():
+    @daft.udf(return_dtype=daft.DataType.int64(), batch_size=8)
+    def batch_len(x: daft.Series) -> list[int]:
+        return [len(x)] * len(x)
+
+    with daft.execution_config_ctx(enable_dynamic_batching=True):
+        df = daft.from_pydict({"a": list(range(100))}).into_batches(30)
+        df = df.select(daft.col("a"), batch_len(daft.col("a")).alias("batch_len"))
+        result = df.sort("a").to_pydict()
+
+    assert result["a"] == list(range(100))
+    assert 0 < max(result["batch_len"]) <= 8
@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+import os
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import daft
+from daft import col
+from daft.context import get_context
+from daft.logical.plan_cache import evict_from_plan_cache, plan_cache_entries
+
+
+def _entry(fingerprint: str):
+    return next((entry for entry in plan_cache_entries() if entry.fingerprint == fingerprint), None)
+
+
+def test_plan_cache_is_off_by_default(monkeypatch):
+    monkeypatch.delenv("DAFT_PLAN_CACHE_SIZE", raising=False)
+    df = daft.from_pydict({"a": [1, 2, 3]}).where(col("a") > 2)
+    config = get_context().daft_execution_config
+    fingerprint = df._builder.fingerprint(config)
+
+    df._builder.optimize(config)
+    df._builder.optimize(config)
+    assert _entry(fingerprint) is None
+
+
+def test_plan_cache_reuses_optimized_plan(monkeypatch):
+    monkeypatch.setenv("DAFT_PLAN_CACHE_SIZE", "64")
+    df = daft.from_pydict({"a": [1, 2, 3], "b": ["x", "y", "z"]}).where(col("a") > 1).select("b")
+    config = get_context().daft_execution_config
+    fingerprint = df._builder.fingerprint(config)
+    assert fingerprint is not None
+
+    first = df._builder.optimize(config)
+    second = df._builder.optimize(config)
+    assert repr(first) == repr(second)
+
+    entry = _entry(fingerprint)
+    assert entry is not None
+    assert entry.hits >= 1
+
+    assert evict_from_plan_cache(fingerprint) == 1
+    assert _entry(fingerprint) is None
+    assert evict_from_plan_cache(fingerprint) == 0
+
+
+def test_plan_cache_fingerprints_differ_by_plan():
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    config = get_context().daft_execution_config
+    assert df._builder.fingerprint(config) == df._builder.fingerprint(config)
+    assert df.where(col("a") > 1)._builder.fingerprint(config) != df._builder.fingerprint(config)
+
+
+def test_plan_cache_fingerprint_changes_with_files(monkeypatch, tmp_path):
+    monkeypatch.setenv("DAFT_PLAN_CACHE_SIZE", "64")
+    path = tmp_path / "data.parquet"
+    pq.write_table(pa.table({"a": [1, 2, 3]}), path)
+    df = daft.read_parquet(str(path)).where(col("a") > 1)
+    config = get_context().daft_execution_config
+    fingerprint = df._builder.fingerprint(config)
+    assert df._builder.fingerprint(config) == fingerprint
+
+    # Overwriting the file changes its modification time, so the same dataframe isn't served its stale plan
+    pq.write_table(pa.table({"a": [4, 5, 6, 7]}), path)
+    stat = os.stat(path)
+    os.utime(path, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000_000))
+    assert df._builder.fingerprint(config) != fingerprint
+    assert df.to_pydict() == {"a": [4, 5, 6, 7]}
+
+
+def test_evict_from_plan_cache_rejects_invalid_fingerprints():
+    with pytest.raises(ValueError, match="Invalid plan fingerprint"):
+        evict_from_plan_cache("not a fingerprint")
@@ -0,0 +1,67 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft import col
+
+
+@pytest.fixture
+def parquet_dir(tmp_path):
+    for i in range(5):
+        table = pa.table({"file": [i] * 100, "x": list(range(100))})
+        papq.write_table(table, tmp_path / f"part-{i}.parquet")
+    return str(tmp_path)
+
+
+def test_preview_reads_bounded_files_and_rows(parquet_dir):
+    df = daft.read_parquet(parquet_dir, file_path_column="path")
+
+    preview = df.preview(n_rows_per_file=3, max_files=2)
+    result = preview.to_pydict()
+
+    assert preview.schema() == df.schema()
+    assert 0 < len(result["x"]) <= 6
+    assert len(set(result["path"])) <= 2
+    # Each file is read from its first rows.
+    assert all(x < 3 for x in result["x"])
+
+
+def test_preview_limits_rows_per_file_of_small_files(tmp_path):
+    # Small files are usually merged into a single scan task, which would apply both limits to the merged task.
+    for i in range(20):
+        papq.write_table(pa.table({"file": [i] * 4, "x": list(range(4))}), tmp_path / f"part-{i:02}.parquet")
+
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=1 << 30, scan_tasks_max_size_bytes=1 << 31):
+        result = daft.read_parquet(str(tmp_path)).preview(n_rows_per_file=2, max_files=3).to_pydict()
+
+    assert len(set(result["file"])) == 3
+    assert sorted(result["x"]) == [0, 0, 0, 1, 1, 1]
+
+
+def test_preview_applies_downstream_operations(parquet_dir):
+    df = daft.read_parquet(parquet_dir).where(col("x") % 2 == 0).with_column("y", col("x") * 10)
+
+    result = df.preview(n_rows_per_file=4, max_files=1).to_pydict()
+
+    assert set(result) == {"file", "x", "y"}
+    assert all(x in (0, 2) for x in result["x"])
+    assert result["y"] == [x * 10 for x in result["x"]]
+
+
+def test_preview_in_memory_is_limited():
+    df = daft.from_pydict({"a": list(range(100))})
+    assert df.preview(n_rows_per_file=2, max_files=3).count_rows() == 6
+
+
+def test_preview_plan_has_file_limit_pushdown(parquet_dir):
+    builder = daft.read_parquet(parquet_dir)._builder.preview(3, 2)
+    assert "File limit pushdown = 2" in builder.pretty_print(simple=False)
+
+
+@pytest.mark.parametrize("n_rows_per_file, max_files", [(0, 1), (1, 0)])
+def test_preview_invalid_arguments(n_rows_per_file, max_files):
+    with pytest.raises(ValueError, match="must be positive"):
+        daft.from_pydict({"a": [1]}).preview(n_rows_per_file, max_files)
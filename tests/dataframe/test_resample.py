@@ -0,0 +1,152 @@
+from __future__ import annotations
+
+import datetime
+
+import pytest
+
+from daft import col
+from daft.exceptions import DaftCoreException
+
+
+def sorted_pydict(df, keys):
+    return df.sort(keys).to_pydict()
+
+
+@pytest.mark.parametrize(
+    "fill, values",
+    [
+        (None, [1, 3, None, 10, 5, None, 8]),
+        ("forward", [1, 3, 3, 10, 5, 5, 8]),
+        ("backward", [1, 3, 10, 10, 5, 8, 8]),
+        ("linear", [1.0, 3.0, 6.5, 10.0, 5.0, 6.5, 8.0]),
+    ],
+)
+@pytest.mark.parametrize("repartition", [1, 3])
+def test_resample_fills_gaps_per_key(make_df, fill, values, repartition):
+    df = make_df(
+        {"sensor": ["a", "a", "a", "b", "b"], "ts": [0, 2, 7, 1, 4], "value": [1, 3, 10, 5, 8]},
+        repartition=repartition,
+    )
+
+    df = df.resample("ts", every=2, agg_exprs=col("value").sum(), by="sensor", fill=fill)
+
+    assert sorted_pydict(df, ["sensor", "ts"]) == {
+        "sensor": ["a", "a", "a", "a", "b", "b", "b"],
+        "ts": [0, 2, 4, 6, 0, 2, 4],
+        "value": values,
+    }
+
+
+@pytest.mark.parametrize("by", [None, "sensor"])
+def test_resample_empty_input(make_df, by):
+    df = make_df({"sensor": ["a", "b"], "ts": [0, 3], "value": [1, 2]}).where(col("ts") > 10)
+
+    df = df.resample("ts", every=2, agg_exprs=col("value").sum(), by=by, fill="forward")
+
+    expected = {"ts": [], "value": []}
+    assert df.to_pydict() == ({"sensor": [], **expected} if by else expected)
+
+
+def test_resample_multiple_aggregations(make_df):
+    df = make_df({"ts": [0, 1, 1, 5], "value": [1.0, 2.0, 4.0, 8.0]}, repartition=2)
+
+    df = df.resample(
+        "ts",
+        every=2,
+        agg_exprs=[col("value").mean().alias("mean"), col("value").count().alias("count")],
+        fill="forward",
+    )
+
+    assert df.schema().column_names() == ["ts", "mean", "count"]
+    assert sorted_pydict(df, "ts") == {"ts": [0, 2, 4], "mean": [7.0 / 3, 7.0 / 3, 8.0], "count": [3, 3, 1]}
+
+
+def test_resample_timestamps(make_df):
+    start = datetime.datetime(2024, 1, 1)
+    df = make_df(
+        {
+            "ts": [
+                start + datetime.timedelta(minutes=10),
+                start + datetime.timedelta(minutes=50),
+                start + datetime.timedelta(hours=2, minutes=30),
+            ],
+            "value": [1, 2, 3],
+        }
+    )
+
+    df = df.resample("ts", every=datetime.timedelta(hours=1), agg_exprs=col("value").sum())
+
+    assert sorted_pydict(df, "ts") == {
+        "ts": [start, start + datetime.timedelta(hours=1), start + datetime.timedelta(hours=2)],
+        "value": [3, None, 3],
+    }
+
+
+def test_resample_dates(make_df):
+    df = make_df({"day": [datetime.date(2024, 1, 1), datetime.date(2024, 1, 4)], "value": [1, 4]})
+
+    df = df.resample("day", every=datetime.timedelta(days=1), agg_exprs=col("value").max(), fill="linear")
+
+    assert sorted_pydict(df, "day") == {
+        "day": [datetime.date(2024, 1, d) for d in range(1, 5)],
+        "value": [1.0, 2.0, 3.0, 4.0],
+    }
+
+
+def test_resample_intervals_before_the_epoch(make_df):
+    df = make_df({"ts": [-3, -1, 2], "value": [1, 2, 3]})
+
+    df = df.resample("ts", every=2, agg_exprs=col("value").sum())
+
+    assert sorted_pydict(df, "ts") == {"ts": [-4, -2, 0, 2], "value": [1, 2, None, 3]}
+
+
+def test_resample_drops_null_timestamps(make_df):
+    df = make_df({"ts": [0, None, 4], "value": [1, 2, 3]})
+
+    df = df.resample("ts", every=2, agg_exprs=col("value").sum(), fill="backward")
+
+    assert sorted_pydict(df, "ts") == {"ts": [0, 2, 4], "value": [1, 3, 3]}
+
+
+def test_resample_linear_fill_leaves_non_numeric_values_null(make_df):
+    df = make_df({"ts": [0, 4], "name": ["x", "y"]})
+
+    df = df.resample("ts", every=2, agg_exprs=col("name").any_value(), fill="linear")
+
+    assert sorted_pydict(df, "ts") == {"ts": [0, 2, 4], "name": ["x", None, "y"]}
+
+
+@pytest.mark.parametrize(
+    "every, match",
+    [
+        (0, "must be positive"),
+        (datetime.timedelta(hours=1), "non-negative integer"),
+    ],
+)
+def test_resample_invalid_interval(make_df, every, match):
+    df = make_df({"ts": [0, 1], "value": [1, 2]})
+
+    with pytest.raises(DaftCoreException, match=match):
+        df.resample("ts", every=every, agg_exprs=col("value").sum())
+
+
+def test_resample_interval_must_be_whole_units(make_df):
+    df = make_df({"day": [datetime.date(2024, 1, 1)], "value": [1]})
+
+    with pytest.raises(DaftCoreException, match="whole number"):
+        df.resample("day", every=datetime.timedelta(hours=12), agg_exprs=col("value").sum())
+
+
+def test_resample_invalid_fill(make_df):
+    df = make_df({"ts": [0, 1], "value": [1, 2]})
+
+    with pytest.raises(DaftCoreException, match="Gap fill"):
+        df.resample("ts", every=1, agg_exprs=col("value").sum(), fill="nearest")
+
+
+def test_resample_requires_aggregations(make_df):
+    df = make_df({"ts": [0, 1], "value": [1, 2]})
+
+    with pytest.raises(DaftCoreException, match="top level aggregation"):
+        df.resample("ts", every=1, agg_exprs=col("value").sum() + 1)
@@ -0,0 +1,111 @@
+from __future__ import annotations
+
+import datetime
+import math
+
+import pytest
+
+from daft import col
+
+
+def sorted_pydict(df, keys):
+    return df.sort(keys).to_pydict()
+
+
+@pytest.mark.parametrize("repartition", [1, 3])
+def test_rolling_rows_per_key(make_df, repartition):
+    df = make_df(
+        {"sensor": ["a", "a", "a", "a", "b", "b"], "ts": [4, 1, 3, 2, 1, 2], "value": [4, 1, 3, 2, 10, 5]},
+        repartition=repartition,
+    )
+
+    df = df.rolling(2, on="ts", by="sensor").agg(
+        col("value").sum().alias("sum"),
+        col("value").mean().alias("mean"),
+        col("value").min().alias("min"),
+        col("value").max().alias("max"),
+        col("value").count().alias("count"),
+    )
+
+    assert sorted_pydict(df, ["sensor", "ts"]) == {
+        "sensor": ["a", "a", "a", "a", "b", "b"],
+        "ts": [1, 2, 3, 4, 1, 2],
+        "sum": [1, 3, 5, 7, 10, 15],
+        "mean": [1.0, 1.5, 2.5, 3.5, 10.0, 7.5],
+        "min": [1, 1, 2, 3, 10, 5],
+        "max": [1, 2, 3, 4, 10, 10],
+        "count": [1, 2, 2, 2, 1, 2],
+    }
+
+
+def test_rolling_min_max_over_larger_windows(make_df):
+    values = [5, 3, 8, 1, 9, 2, 7, 7, 4, 6]
+    df = make_df({"key": [0] * len(values), "ts": list(range(len(values))), "value": values})
+
+    df = df.rolling(4, on="ts", by="key").agg(col("value").min().alias("min"), col("value").max().alias("max"))
+
+    result = sorted_pydict(df, "ts")
+    assert result["min"] == [min(values[max(0, i - 3) : i + 1]) for i in range(len(values))]
+    assert result["max"] == [max(values[max(0, i - 3) : i + 1]) for i in range(len(values))]
+
+
+def test_rolling_time_window(make_df):
+    start = datetime.datetime(2024, 1, 1)
+    minutes = [0, 20, 30, 90, 100]
+    df = make_df(
+        {
+            "sensor": ["a"] * len(minutes),
+            "ts": [start + datetime.timedelta(minutes=m) for m in minutes],
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+
+    df = df.rolling(datetime.timedelta(minutes=30), on="ts", by="sensor").agg(col("value").sum())
+
+    assert sorted_pydict(df, "ts")["value"] == [1, 3, 6, 4, 9]
+
+
+def test_rolling_min_periods(make_df):
+    df = make_df({"key": ["a", "a", "a", "b"], "ts": [1, 2, 3, 1], "value": [1, 2, 3, 4]})
+
+    df = df.rolling(3, on="ts", by="key", min_periods=2).agg(col("value").max())
+
+    assert sorted_pydict(df, ["key", "ts"])["value"] == [None, 2, 3, None]
+
+
+def test_rolling_min_max_with_nulls_and_nans(make_df):
+    nan = float("nan")
+    df = make_df({"key": [0] * 5, "ts": [0, 1, 2, 3, 4], "value": [1.0, None, nan, 2.0, None]})
+
+    df = df.rolling(2, on="ts", by="key").agg(col("value").min().alias("min"), col("value").max().alias("max"))
+
+    result = sorted_pydict(df, "ts")
+    # NaNs are ordered after every other value, so they are only the min of a window without other values.
+    assert result["min"][:2] == [1.0, 1.0]
+    assert math.isnan(result["min"][2])
+    assert result["min"][3:] == [2.0, 2.0]
+    assert result["max"][:2] == [1.0, 1.0]
+    assert all(math.isnan(v) for v in result["max"][2:4])
+    assert result["max"][4] == 2.0
+
+
+@pytest.mark.parametrize(
+    "kwargs, match",
+    [
+        ({"window": 0}, "window of at least 1 row"),
+        ({"window": 2, "min_periods": 0}, "min_periods to be at least 1"),
+        ({"window": 2, "by": []}, "at least one key column"),
+    ],
+)
+def test_rolling_invalid_arguments(make_df, kwargs, match):
+    df = make_df({"key": ["a"], "ts": [1], "value": [1]})
+
+    with pytest.raises(ValueError, match=match):
+        df.rolling(on="ts", **{"by": "key", **kwargs})
+
+
+def test_rolling_requires_aggregations(make_df):
+    df = make_df({"key": ["a"], "ts": [1], "value": [1]})
+
+    with pytest.raises(ValueError, match="at least one aggregation"):
+        df.rolling(2, on="ts", by="key").agg()
@@ -504,3 +504,52 @@ def test_sort_with_constant_keys_and_small_partitions(make_df, with_morsel_size)
     out.collect()
     assert len(out) == 4
     assert set(out.to_pydict()["k"]) == {5}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_sort_by_composite_computed_keys(make_df, repartition_nparts, with_morsel_size):
+    df = make_df({"a": [2, 1, 2, 1, 2], "b": [1, 5, 3, 2, 2]}, repartition=repartition_nparts)
+
+    # The computed key is also named "a", and must not be confused with the first key.
+    result = df.sort(["a", daft.col("a") + daft.col("b")], desc=[False, True])
+
+    assert result.to_pydict() == {"a": [1, 1, 2, 2, 2], "b": [5, 2, 3, 2, 1]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_sort_by_computed_key_with_limit(make_df, repartition_nparts):
+    df = make_df({"x": [3, 2, 1, 4], "y": [1, 5, 3, 2]}, repartition=repartition_nparts)
+
+    result = df.sort(daft.col("x") * daft.col("y"), desc=True).limit(2)
+
+    assert result.to_pydict() == {"x": [2, 4], "y": [5, 2]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2])
+@pytest.mark.parametrize("limit", [None, 2])
+def test_sort_keep_keys(make_df, repartition_nparts, limit):
+    df = make_df({"x": [3, 2, 1], "y": [6, 4, 5]}, repartition=repartition_nparts)
+
+    result = df.sort(["x", (daft.col("x") + daft.col("y")).alias("total")], desc=[False, True], keep_keys=True)
+    if limit is not None:
+        result = result.limit(limit)
+
+    # Existing columns used as keys are not duplicated, and only the computed key is added.
+    expected = {"x": [1, 2, 3], "y": [5, 4, 6], "total": [6, 6, 9]}
+    assert result.to_pydict() == {k: v[:limit] for k, v in expected.items()}
+
+
+def test_sort_without_keep_keys_drops_computed_keys(make_df):
+    df = make_df({"x": [3, 2, 1]})
+
+    result = df.sort((daft.col("x") * -1).alias("neg"))
+
+    assert result.column_names == ["x"]
+    assert result.to_pydict() == {"x": [3, 2, 1]}
+
+
+def test_sort_keep_keys_clashing_names(make_df):
+    df = make_df({"x": [3, 2, 1], "y": [6, 4, 5]})
+
+    with pytest.raises(ValueError, match="alias them to unique names"):
+        df.sort(daft.col("x") + daft.col("y"), keep_keys=True)
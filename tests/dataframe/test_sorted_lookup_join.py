@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+
+def sorted_rows(df: daft.DataFrame) -> list[tuple]:
+    rows = zip(*df.to_pydict().values())
+    return sorted(rows, key=lambda row: tuple((v is None, v) for v in row))
+
+
+def physical_plan(df: daft.DataFrame) -> str:
+    string_io = io.StringIO()
+    df.explain(True, file=string_io)
+    return string_io.getvalue().split("== Physical Plan ==")[-1]
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "semi", "anti", "right", "outer"])
+@pytest.mark.parametrize("repartition", [1, 3])
+def test_sorted_lookup_join_matches_hash_join(make_df, how, repartition):
+    left = make_df({"k": [5, 1, None, 3, 0, 1, 7], "v": list(range(7))}, repartition=repartition)
+    right = make_df({"k": [3, 1, 2, None, 3, 1], "w": ["a", "b", "c", "d", "e", "f"]})
+
+    expected = left.join(right, on="k", how=how, strategy="hash")
+    actual = left.join(right, on="k", how=how, strategy="sorted_lookup")
+
+    assert actual.column_names == expected.column_names
+    assert sorted_rows(actual) == sorted_rows(expected)
+
+
+def test_sorted_lookup_join_multiple_keys(make_df):
+    left = make_df({"a": [1, 1, 2, None], "b": ["x", "y", "x", "x"], "v": [0, 1, 2, 3]})
+    right = make_df({"a": [2, 1, 1, 1], "b": ["x", "y", None, "y"], "w": [10, 11, 12, 13]})
+
+    df = left.join(right, on=["a", "b"], strategy="sorted_lookup")
+
+    assert sorted_rows(df) == [(1, "y", 1, 11), (1, "y", 1, 13), (2, "x", 2, 10)]
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Sorted lookup joins are run by the native runner")
+def test_sorted_lookup_join_hint():
+    left = daft.from_pydict({"k": [1, 2, 3]})
+    right = daft.from_pydict({"k": [3, 1], "w": ["a", "b"]})
+
+    assert "Sorted Lookup Join" in physical_plan(left.join(right, on="k", strategy="sorted_lookup"))
+    # Outer joins fall back to a hash join
+    assert "Sorted Lookup Join" not in physical_plan(left.join(right, on="k", how="outer", strategy="sorted_lookup"))
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Sorted lookup joins are run by the native runner")
+def test_sorted_lookup_join_chosen_for_sorted_right_side():
+    left = daft.from_pydict({"k": [1, 2, 3], "v": [10, 20, 30]})
+    right = daft.from_pydict({"k": [3, 1], "v": ["a", "b"]})
+
+    # A right side which is already sorted on the join keys picks the strategy without a hint
+    df = left.join(right.sort("k"), on="k", how="left")
+    plan = physical_plan(df)
+    assert "Sorted Lookup Join" in plan
+    assert "Already Sorted = true" in plan
+    assert sorted_rows(df) == [(1, 10, "b"), (2, 20, None), (3, 30, "a")]
+
+    assert "Sorted Lookup Join" not in physical_plan(left.join(right, on="k", how="left"))
+    assert "Sorted Lookup Join" not in physical_plan(left.join(right.sort("k", desc=True), on="k", how="left"))
+    # Outer joins aren't supported by the lookup
+    assert "Sorted Lookup Join" not in physical_plan(left.join(right.sort("k"), on="k", how="outer"))
+
+    df = left.join(right.sort("k"), on="k", how="left", strategy="sorted_lookup")
+    plan = physical_plan(df)
+    assert "Sorted Lookup Join" in plan
+    assert "Already Sorted = true" in plan
+    assert sorted_rows(df) == [(1, 10, "b"), (2, 20, None), (3, 30, "a")]
+
+    plan = physical_plan(left.join(right.sort("k", desc=True), on="k", how="left", strategy="sorted_lookup"))
+    assert "Already Sorted = false" in plan
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "semi", "anti"])
+def test_sorted_lookup_join_of_sorted_right_side_matches_hash_join(make_df, how):
+    left = make_df({"k": [5, 1, None, 3, 0, 1, 7], "v": list(range(7))}, repartition=3)
+    right = make_df({"k": [3, 1, 2, None, 3, 1], "w": ["a", "b", "c", "d", "e", "f"]}).sort("k")
+
+    expected = left.join(right, on="k", how=how, strategy="hash")
+    actual = left.join(right, on="k", how=how, strategy="sorted_lookup")
+
+    assert sorted_rows(actual) == sorted_rows(expected)
@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import col
+from daft.functions import fold_assign
+from tests.conftest import get_tests_daft_runner_name
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_split_is_a_partition_of_the_rows(make_df, repartition_nparts: int) -> None:
+    df = make_df({"id": list(range(200)), "value": [i * 2 for i in range(200)]}, repartition=repartition_nparts)
+
+    splits = df.split([0.6, 0.2, 0.2], seed=7)
+
+    assert len(splits) == 3
+    ids = [sorted(split.to_pydict()["id"]) for split in splits]
+    assert sorted(sum(ids, [])) == list(range(200))
+    assert all(split.column_names == ["id", "value"] for split in splits)
+    assert 80 <= len(ids[0]) <= 160
+
+
+def test_split_is_deterministic(make_df) -> None:
+    data = {"id": list(range(50))}
+    first = [split.to_pydict() for split in make_df(data, repartition=1).split([0.5, 0.5], seed=3)]
+    second = [split.to_pydict() for split in make_df(data, repartition=2).split([0.5, 0.5], seed=3)]
+    assert [sorted(s["id"]) for s in first] == [sorted(s["id"]) for s in second]
+
+
+def test_split_separates_duplicate_rows(make_df) -> None:
+    df = make_df({"x": [1] * 100})
+
+    splits = df.split([0.5, 0.5], seed=5)
+
+    counts = [split.count_rows() for split in splits]
+    assert sum(counts) == 100
+    assert all(count > 0 for count in counts)
+
+
+def test_split_stratified_duplicate_rows(make_df) -> None:
+    df = make_df({"label": ["a"] * 40 + ["b"] * 20})
+
+    train, test = df.split([3, 1], seed=2, stratify_by="label")
+
+    assert train.where(col("label") == "a").count_rows() == 30
+    assert test.where(col("label") == "a").count_rows() == 10
+    assert test.where(col("label") == "b").count_rows() == 5
+
+
+def test_split_stratified(make_df) -> None:
+    df = make_df({"id": list(range(100)), "label": ["a" if i < 80 else "b" for i in range(100)]})
+
+    train, test = df.split([3, 1], seed=1, stratify_by="label")
+
+    assert train.count_rows() == 75
+    assert test.where(col("label") == "a").count_rows() == 20
+    assert test.where(col("label") == "b").count_rows() == 5
+
+
+def test_split_invalid_fractions() -> None:
+    df = daft.from_pydict({"x": [1, 2, 3]})
+    with pytest.raises(ValueError, match="at least one fraction"):
+        df.split([])
+    with pytest.raises(ValueError, match="non-negative"):
+        df.split([0.5, -0.5])
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 4])
+def test_fold_assign(make_df, repartition_nparts: int) -> None:
+    df = make_df({"id": list(range(100))}, repartition=repartition_nparts)
+    folds = df.with_column("fold", fold_assign(col("id"), k=5, seed=11)).sort("id").to_pydict()["fold"]
+
+    assert set(folds) == {0, 1, 2, 3, 4}
+    expected = daft.from_pydict({"id": list(range(100))}).select(fold_assign(col("id"), k=5, seed=11))
+    assert folds == expected.to_pydict()["id"]
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="Counts UDF calls in the driver process")
+def test_split_executes_upstream_once() -> None:
+    calls = []
+
+    @daft.func(return_dtype=daft.DataType.int64(), use_process=False)
+    def record(x: int) -> int:
+        calls.append(x)
+        return x
+
+    df = daft.from_pydict({"id": list(range(50))}).select(record(col("id")).alias("id"))
+
+    splits = df.split([0.5, 0.3, 0.2], seed=4)
+
+    assert sorted(sum((split.to_pydict()["id"] for split in splits), [])) == list(range(50))
+    assert sorted(calls) == list(range(50))
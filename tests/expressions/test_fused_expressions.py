@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import json
+import os
+import subprocess
+import sys
+
+# Fused expressions are enabled when daft is imported, so the projections are evaluated in a fresh interpreter.
+FUSED_PROJECTIONS_SCRIPT = """
+import json
+
+import daft
+from daft import col, lit
+from daft.functions import coalesce, when
+
+df = daft.from_pydict(
+    {
+        "a": [1.5, None, -2.0, 4.0, None],
+        "b": [1, 2, None, 0, None],
+        "c": [True, None, False, True, None],
+    }
+)
+df = df.select(
+    ((col("a") + col("b")) * 2.0 / col("b")).alias("arithmetic"),
+    ((col("a") > 0.0) & col("c") | col("b").is_null()).alias("predicate"),
+    when(col("c"), col("a")).otherwise(col("b") * 1.5).fill_null(0.0).alias("if_else"),
+    (coalesce(col("a"), col("b").cast(daft.DataType.float64()), lit(-1.0)) * 10.0).alias("coalesce"),
+)
+print(json.dumps(df.to_pydict()))
+"""
+
+
+def _run(env_value):
+    env = {**os.environ, "DAFT_ENABLE_FUSED_EXPRESSIONS": env_value}
+    result = subprocess.run(
+        [sys.executable, "-c", FUSED_PROJECTIONS_SCRIPT], capture_output=True, env=env, check=True
+    )
+    return json.loads(result.stdout.decode().strip().splitlines()[-1])
+
+
+def test_fused_expressions_match_unfused():
+    fused = _run("1")
+    assert fused == _run("0")
+    assert fused["if_else"] == [1.5, 0.0, 0.0, 4.0, 0.0]
+    assert fused["coalesce"] == [15.0, 20.0, -20.0, 40.0, -10.0]
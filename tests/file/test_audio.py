@@ -77,3 +77,79 @@ def test_resample(sample_audio_path):
     actual = df.to_pydict()["audio"][0]
 
     assert np.allclose(actual, manual_resample())
+
+
+@pytest.fixture
+def sine_wave_path(tmp_path):
+    # A 1kHz tone sampled at 16kHz, with the tone only in the left channel.
+    sample_rate = 16000
+    t = np.arange(sample_rate) / sample_rate
+    tone = 0.5 * np.sin(2 * np.pi * 1000 * t)
+    path = tmp_path / "sine.wav"
+    sf.write(path, np.stack([tone, np.zeros_like(tone)], axis=1), sample_rate, subtype="FLOAT")
+    return str(path)
+
+
+def test_audio_waveform(sine_wave_path):
+    pytest.importorskip("av")
+    df = daft.from_pydict({"path": [sine_wave_path]})
+    df = df.select(daft.functions.audio_waveform(daft.functions.audio_file(df["path"])).alias("waveform"))
+
+    assert df.schema()["waveform"].dtype == daft.DataType.tensor(daft.DataType.float32())
+    actual = df.to_pydict()["waveform"][0]
+
+    expected, _ = sf.read(sine_wave_path, dtype="float32")
+    assert actual.shape == (2, 16000)
+    np.testing.assert_allclose(actual, expected.T, atol=1e-6)
+
+
+def test_audio_waveform_mono_and_resampled(sample_audio_path):
+    pytest.importorskip("av")
+    waveform = daft.AudioFile(sample_audio_path).waveform(sample_rate=16000, mono=True)
+
+    assert waveform.dtype == np.float32
+    assert waveform.shape[0] == 1
+    # The sample is 434176 frames at 44.1kHz, and decoders may differ by a frame of priming samples.
+    assert abs(waveform.shape[1] - 434176 * 16000 / 44100) < 1200
+
+
+@pytest.mark.parametrize("n_fft, hop", [(400, None), (512, 128), (256, 200)])
+def test_audio_spectrogram(sine_wave_path, n_fft, hop):
+    pytest.importorskip("av")
+    df = daft.from_pydict({"path": [sine_wave_path]})
+    df = df.select(
+        daft.functions.audio_spectrogram(daft.functions.audio_file(df["path"]), n_fft=n_fft, hop=hop).alias("spec")
+    )
+
+    spectrogram = df.to_pydict()["spec"][0]
+
+    hop = n_fft // 4 if hop is None else hop
+    assert spectrogram.dtype == np.float32
+    assert spectrogram.shape == (n_fft // 2 + 1, 1 + 16000 // hop)
+    # The power of each frame peaks at the bin of the tone.
+    peaks = spectrogram[:, 1:-1].argmax(axis=0)
+    assert (peaks == round(1000 * n_fft / 16000)).all()
+
+
+def test_audio_spectrogram_matches_librosa(tmp_path):
+    pytest.importorskip("av")
+    # A single channel, so the spectrogram doesn't depend on how the channels are mixed down.
+    signal = np.random.default_rng(0).uniform(-0.5, 0.5, 8000).astype(np.float32)
+    path = str(tmp_path / "noise.wav")
+    sf.write(path, signal, 16000, subtype="FLOAT")
+
+    spectrogram = daft.AudioFile(path).spectrogram(n_fft=512, hop=160)
+
+    expected = np.abs(librosa.stft(signal, n_fft=512, hop_length=160, pad_mode="constant")) ** 2
+    np.testing.assert_allclose(spectrogram, expected, rtol=1e-3, atol=1e-3)
+
+
+@pytest.mark.parametrize(
+    "kwargs, match",
+    [({"n_fft": 0}, "n_fft must be positive"), ({"hop": -1}, "hop must be positive")],
+)
+def test_audio_spectrogram_invalid_parameters(kwargs, match):
+    df = daft.from_pydict({"path": ["tests/assets/sample_audio.mp3"]})
+
+    with pytest.raises(ValueError, match=match):
+        daft.functions.audio_spectrogram(daft.functions.audio_file(df["path"]), **kwargs)
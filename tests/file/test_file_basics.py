@@ -9,7 +9,7 @@ import pytest
 
 import daft
 from daft import DataType as dt
-from daft.functions import file, file_size
+from daft.functions import blob_file, blob_read, file, file_size
 from tests.conftest import get_tests_daft_runner_name
 
 
@@ -169,6 +169,45 @@ def test_filesize_expr(tmp_path: Path):
     assert res == 2048
 
 
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="local only test")
+def test_blob_file(tmp_path: Path):
+    blobs = [b"\x1a\x45\xdf\xa3" + bytes(1024), b"hello world"]
+    df = daft.from_pydict({"data": blobs})
+    df = df.select(blob_file(df["data"], str(tmp_path)))
+    assert df.schema() == daft.Schema.from_pydict({"data": dt.file()})
+
+    @daft.func
+    def read_bytes(file: daft.File) -> bytes:
+        with file.open() as f:
+            return f.read()
+
+    df = df.select(read_bytes(df["data"]).alias("data"), file_size(df["data"]).alias("size"))
+    assert df.to_pydict() == {"data": blobs, "size": [1028, 11]}
+    assert len(list(tmp_path.iterdir())) == 2
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="local only test")
+def test_blob_read(tmp_path: Path):
+    blobs = [bytes([i]) * (i * 100) for i in range(1, 6)]
+    df = daft.from_pydict({"id": list(range(5)), "data": blobs})
+    # Only the references to the blobs are carried through the shuffle.
+    df = df.with_column("data", blob_file(df["data"], str(tmp_path))).repartition(2, "id")
+    df = df.with_column("data", blob_read(df["data"])).sort("id")
+    assert df.schema()["data"].dtype == dt.binary()
+    assert df.to_pydict() == {"id": list(range(5)), "data": blobs}
+
+
+def test_blob_read_of_files(tmp_path: Path):
+    paths = []
+    for i, data in enumerate([b"first", b"second"]):
+        path = tmp_path / f"{i}.bin"
+        path.write_bytes(data)
+        paths.append(str(path))
+    df = daft.from_pydict({"path": [paths[0], None, paths[1]]})
+    df = df.select(blob_read(file(df["path"])))
+    assert df.to_pydict() == {"path": [b"first", None, b"second"]}
+
+
 @pytest.mark.parametrize(
     "file_info",
     [
@@ -1,5 +1,9 @@
 from __future__ import annotations
 
+import io
+import shutil
+import subprocess
+
 import pytest
 
 pytest.importorskip("av")
@@ -49,10 +53,117 @@ def test_get_metadata(sample_video_path):
     df = df.select(daft.functions.video_metadata(df["video"]))
 
     expected = {
-        "video": [{"width": 192, "height": 144, "fps": 30.0, "frame_count": 290, "time_base": 1.1111111111111112e-05}]
+        "width": 192,
+        "height": 144,
+        "fps": 30.0,
+        "frame_count": 290,
+        "frames_estimated": False,
+        "time_base": 1.1111111111111112e-05,
+        "rotation": 0,
     }
 
-    assert df.to_pydict() == expected
+    metadata = df.to_pydict()["video"][0]
+    streams = metadata.pop("streams")
+    assert metadata == expected
+    assert [(s["index"], s["width"], s["height"], s["fps"]) for s in streams] == [(0, 192, 144, 30.0)]
+
+
+def test_metadata_probe_size(sample_video_path):
+    file = daft.VideoFile(sample_video_path)
+
+    assert file.metadata(probe_size=64 * 1024) == file.metadata()
+
+
+def test_metadata_probe_size_smaller_than_headers(sample_video_path):
+    # The sample video's moov atom is about 9KB, so the rest of it is read beyond the prefetched bytes.
+    file = daft.VideoFile(sample_video_path)
+
+    metadata = file.metadata(probe_size=1024)
+    assert (metadata["width"], metadata["height"], metadata["fps"]) == (192, 144, 30.0)
+
+
+def test_video_metadata_probe_size(sample_video_path):
+    df = daft.from_pydict({"path": [sample_video_path]})
+    df = df.select(daft.functions.video_metadata(daft.functions.video_file(df["path"]), probe_size=64 * 1024))
+
+    metadata = df.to_pydict()["video"][0]
+    assert (metadata["width"], metadata["height"], metadata["frame_count"]) == (192, 144, 290)
+
+
+def test_prefetched_file_serves_reads_of_its_prefix():
+    from daft.file.video import _PrefetchedFile
+
+    data = bytes(range(100))
+    f = io.BytesIO(data)
+    prefetched = _PrefetchedFile(f, 10)
+    f.seek(50)
+
+    # Reads within the prefix don't move the file.
+    assert prefetched.read(4) == data[:4]
+    assert prefetched.read(6) == data[4:10]
+    assert f.tell() == 50
+
+    assert prefetched.read(5) == data[10:15]
+    assert prefetched.seek(-3, 2) == 97
+    assert prefetched.read() == data[97:]
+    assert prefetched.seek(2) == 2
+    assert prefetched.read(3) == data[2:5]
+    assert prefetched.tell() == 5
+
+
+@pytest.mark.parametrize(
+    "kwargs, match",
+    [
+        ({"probe_size": 1024, "count_frames": True}, "count_frames"),
+        ({"probe_size": 0}, "must be positive"),
+    ],
+)
+def test_metadata_invalid_probe_size(sample_video_path, kwargs, match):
+    with pytest.raises(ValueError, match=match):
+        daft.VideoFile(sample_video_path).metadata(**kwargs)
+
+
+def _write_variable_frame_rate_video(path):
+    """Write a video whose first 10 frames are 100ms apart and whose last 10 frames are 20ms apart."""
+    import av
+
+    np = pytest.importorskip("numpy")
+    with av.open(str(path), mode="w") as container:
+        # A rate of 1000 makes the timestamps of the frames milliseconds.
+        stream = container.add_stream("ffv1", rate=1000)
+        stream.width = 64
+        stream.height = 48
+        stream.pix_fmt = "yuv420p"
+        timestamps = [i * 100 for i in range(10)] + [900 + i * 20 for i in range(1, 11)]
+        for pts in timestamps:
+            frame = av.VideoFrame.from_ndarray(np.zeros((48, 64, 3), dtype=np.uint8), format="rgb24")
+            frame.pts = pts
+            container.mux(stream.encode(frame))
+        container.mux(stream.encode())
+
+
+def test_video_file_count_frames(tmp_path):
+    path = tmp_path / "variable_frame_rate.mkv"
+    _write_variable_frame_rate_video(path)
+    file = daft.VideoFile(str(path))
+
+    # Matroska doesn't store the number of frames, so it's estimated without counting them.
+    assert file.metadata()["frames_estimated"]
+
+    metadata = file.metadata(count_frames=True)
+    assert metadata["frame_count"] == 20
+    assert not metadata["frames_estimated"]
+    assert metadata["fps"] == pytest.approx(19 / 1.1)
+
+
+def test_video_metadata_count_frames(tmp_path):
+    path = tmp_path / "variable_frame_rate.mkv"
+    _write_variable_frame_rate_video(path)
+    df = daft.from_pydict({"path": [str(path)]})
+    df = df.select(daft.functions.video_metadata(daft.functions.video_file(df["path"]), count_frames=True))
+
+    metadata = df.to_pydict()["video"][0]
+    assert (metadata["frame_count"], metadata["frames_estimated"]) == (20, False)
 
 
 def test_keyframes(sample_video_path):
@@ -66,3 +177,425 @@ def test_keyframes(sample_video_path):
 
     values = df.to_pydict()["video"][0]
     assert len(values) == 13
+
+
+def _sequential_frames(path):
+    import av
+
+    with av.open(path) as container:
+        return [frame.to_image() for frame in container.decode(video=0)]
+
+
+@pytest.mark.parametrize("index", [0, 1, 45, 100, 289])
+def test_video_file_frame_at_index(sample_video_path, index):
+    expected = _sequential_frames(sample_video_path)[index]
+    frame = daft.VideoFile(sample_video_path).frame_at_index(index)
+    assert frame.tobytes() == expected.tobytes()
+
+
+def test_video_file_frame_at(sample_video_path):
+    frames = _sequential_frames(sample_video_path)
+    file = daft.VideoFile(sample_video_path)
+    # A time between two frames is within the earlier of them.
+    assert file.frame_at(100.5 / 30).tobytes() == frames[100].tobytes()
+    assert file.frame_at(0).tobytes() == frames[0].tobytes()
+
+
+def test_video_file_iter_frames(sample_video_path):
+    frames = _sequential_frames(sample_video_path)
+    iterated = daft.VideoFile(sample_video_path).iter_frames(autorotate=False)
+    assert [frame.tobytes() for frame in iterated] == [frame.tobytes() for frame in frames]
+
+
+def test_video_file_iter_frames_stops_early(sample_video_path):
+    frames = daft.VideoFile(sample_video_path).iter_frames()
+    first = next(frames)
+    second = next(frames)
+    # Closing the iterator closes the video without decoding the rest of it.
+    frames.close()
+    expected = _sequential_frames(sample_video_path)
+    assert [first.tobytes(), second.tobytes()] == [expected[0].tobytes(), expected[1].tobytes()]
+
+
+def test_video_file_iter_frames_raises_video_errors(tmp_path):
+    path = tmp_path / "not_a_video.mp4"
+    path.write_bytes(b"this is not a video" * 100)
+
+    frames = daft.VideoFile(str(path)).iter_frames()
+    with pytest.raises(daft.file.VideoContainerError):
+        next(frames)
+
+
+def test_video_file_keyframes_from_start_time(sample_video_path):
+    file = daft.VideoFile(sample_video_path)
+    all_keyframes = list(file.keyframes())
+    later_keyframes = list(file.keyframes(start_time=5.0))
+    assert 0 < len(later_keyframes) < len(all_keyframes)
+    assert later_keyframes[-1].tobytes() == all_keyframes[-1].tobytes()
+
+
+def test_video_file_frame_at_negative_index(sample_video_path):
+    with pytest.raises(ValueError, match="non-negative"):
+        daft.VideoFile(sample_video_path).frame_at_index(-1)
+
+
+@pytest.mark.parametrize("format", ["mp4", "webm"])
+def test_encode_video(sample_video_path, format, tmp_path):
+    df = daft.from_pydict({"path": [sample_video_path]})
+    df = df.select(daft.functions.video_keyframes(daft.functions.video_file(df["path"])).alias("frames"))
+    df = df.select(daft.functions.encode_video(df["frames"], fps=2, format=format).alias("clip"))
+    assert df.schema()["clip"].dtype == daft.DataType.binary()
+
+    clip = df.to_pydict()["clip"][0]
+    path = tmp_path / f"clip.{format}"
+    path.write_bytes(clip)
+    keyframes = list(daft.VideoFile(sample_video_path).keyframes())
+    frames = _sequential_frames(str(path))
+    assert len(frames) == len(keyframes)
+    assert frames[0].size == keyframes[0].size
+
+
+def test_encode_video_rejects_mismatched_frames():
+    from daft.functions.video import encode_video_impl
+
+    np = pytest.importorskip("numpy")
+    frames = [np.zeros((4, 4, 3), dtype=np.uint8), np.zeros((6, 4, 3), dtype=np.uint8)]
+    with pytest.raises(ValueError, match="same size"):
+        encode_video_impl(frames, fps=30)
+    with pytest.raises(ValueError, match="even width and height"):
+        encode_video_impl([np.zeros((3, 4, 3), dtype=np.uint8)], fps=30)
+
+
+def test_video_file_scene_changes(tmp_path):
+    from daft.functions.video import encode_video_impl
+
+    np = pytest.importorskip("numpy")
+    # Three scenes of one second each: black, then white, then black again.
+    black = np.zeros((64, 64, 3), dtype=np.uint8)
+    white = np.full((64, 64, 3), 255, dtype=np.uint8)
+    path = tmp_path / "scenes.mp4"
+    path.write_bytes(encode_video_impl([black] * 10 + [white] * 10 + [black] * 10, fps=10))
+
+    df = daft.from_pydict({"path": [str(path)]})
+    df = df.select(daft.functions.video_scene_changes(daft.functions.video_file(df["path"])).alias("scenes"))
+    assert df.schema()["scenes"].dtype == daft.DataType.list(daft.DataType.float64())
+    assert df.to_pydict()["scenes"][0] == pytest.approx([1.0, 2.0], abs=0.05)
+
+
+def test_video_extract_frames(tmp_path):
+    from daft.functions.video import encode_video_impl
+
+    np = pytest.importorskip("numpy")
+    # Three seconds of ten frames each, whose brightness increases every second.
+    seconds = [np.full((64, 64, 3), value, dtype=np.uint8) for value in (0, 128, 255)]
+    path = tmp_path / "steps.mp4"
+    path.write_bytes(encode_video_impl([frame for frame in seconds for _ in range(10)], fps=10))
+
+    df = daft.from_pydict({"path": [str(path)]})
+    video = daft.functions.video_file(df["path"])
+    df = df.select(
+        daft.functions.video_extract_frames(video, every_n=10).alias("every_n"),
+        daft.functions.video_extract_frames(video, fps=2).alias("fps"),
+        daft.functions.video_extract_frames(video, timestamps=[2.5, 0.5]).alias("timestamps"),
+    )
+    assert df.schema()["every_n"].dtype == daft.DataType.list(daft.DataType.image())
+
+    result = df.to_pydict()
+    brightness = {name: [int(np.asarray(frame).mean()) for frame in frames[0]] for name, frames in result.items()}
+    assert brightness["every_n"] == pytest.approx([0, 128, 255], abs=8)
+    assert len(brightness["fps"]) == 6
+    assert brightness["timestamps"] == pytest.approx([255, 0], abs=8)
+
+
+def _hamming(a, b):
+    return bin(a ^ b).count("1")
+
+
+def test_video_phash_frames(tmp_path):
+    from daft.functions.video import encode_video_impl
+
+    np = pytest.importorskip("numpy")
+    # One second of a gradient which gets brighter to the right, then one second of it mirrored.
+    gradient = np.repeat(np.tile(np.linspace(0, 255, 64, dtype=np.uint8), (64, 1))[:, :, None], 3, axis=2)
+    path = tmp_path / "gradients.mp4"
+    path.write_bytes(encode_video_impl([gradient] * 10 + [gradient[:, ::-1]] * 10, fps=10))
+
+    df = daft.from_pydict({"path": [str(path)]})
+    df = df.select(daft.functions.video_phash_frames(daft.functions.video_file(df["path"]), 5).alias("hashes"))
+    assert df.schema()["hashes"].dtype == daft.DataType.list(daft.DataType.uint64())
+
+    hashes = df.to_pydict()["hashes"][0]
+    assert len(hashes) == 4
+    assert _hamming(hashes[0], hashes[1]) <= 4
+    assert _hamming(hashes[2], hashes[3]) <= 4
+    # Each pixel is compared with the one to its right, so mirroring the frame flips almost every bit.
+    assert _hamming(hashes[0], hashes[2]) >= 56
+
+
+def test_video_file_phash_frames_robust_to_brightness(tmp_path):
+    from daft.functions.video import encode_video_impl
+
+    np = pytest.importorskip("numpy")
+    rng = np.random.default_rng(0)
+    # Large blocks of random gray levels, which survive compression and scaling down.
+    blocks = rng.integers(40, 160, (8, 8), dtype=np.uint8)
+    pattern = np.repeat(np.kron(blocks, np.ones((8, 8), dtype=np.uint8))[:, :, None], 3, axis=2)
+    other = np.repeat(np.kron(blocks.T, np.ones((8, 8), dtype=np.uint8))[:, :, None], 3, axis=2)
+    path = tmp_path / "blocks.mp4"
+    path.write_bytes(encode_video_impl([pattern, pattern + 60, other], fps=1))
+
+    hashes = daft.VideoFile(str(path)).phash_frames(method="phash")
+
+    assert len(hashes) == 3
+    assert _hamming(hashes[0], hashes[1]) <= 6
+    assert _hamming(hashes[0], hashes[2]) > 12
+
+
+@pytest.mark.parametrize(
+    "kwargs, match",
+    [({"every_n": 0}, "every_n must be positive"), ({"method": "ahash"}, "must be 'dhash' or 'phash'")],
+)
+def test_video_phash_frames_invalid_arguments(sample_video_path, kwargs, match):
+    df = daft.from_pydict({"path": [sample_video_path]})
+    video = daft.functions.video_file(df["path"])
+
+    with pytest.raises(ValueError, match=match):
+        daft.functions.video_phash_frames(video, **kwargs)
+    with pytest.raises(ValueError, match=match):
+        daft.VideoFile(sample_video_path).phash_frames(**kwargs)
+
+
+def test_video_frame_at(sample_video_path):
+    frames = _sequential_frames(sample_video_path)
+    df = daft.from_pydict({"path": [sample_video_path, sample_video_path], "times": [[100.5 / 30], None]})
+    video = daft.functions.video_file(df["path"])
+    df = df.select(
+        daft.functions.video_frame_at(video, [100.5 / 30, 0.0]).alias("fixed"),
+        daft.functions.video_frame_at(video, df["times"]).alias("per_row"),
+    )
+    assert df.schema()["fixed"].dtype == daft.DataType.list(daft.DataType.image())
+
+    result = df.to_pydict()
+    fixed = result["fixed"][0]
+    assert [frame.tobytes() for frame in fixed] == [frames[100].tobytes(), frames[0].tobytes()]
+    assert result["per_row"][0][0].tobytes() == frames[100].tobytes()
+    assert result["per_row"][1] is None
+
+
+def test_video_extract_frames_requires_one_sampling(sample_video_path):
+    video = daft.functions.video_file(daft.col("path"))
+    with pytest.raises(ValueError, match="Exactly one of"):
+        daft.functions.video_extract_frames(video)
+    with pytest.raises(ValueError, match="Exactly one of"):
+        daft.functions.video_extract_frames(video, every_n=2, fps=1)
+    with pytest.raises(ValueError, match="every_n must be positive"):
+        daft.VideoFile(sample_video_path).extract_frames(every_n=0)
+
+
+def test_video_file_scene_changes_without_cuts(sample_video_path):
+    file = daft.VideoFile(sample_video_path)
+    assert file.scene_changes(threshold=1.0) == []
+    with pytest.raises(ValueError, match="between 0 and 1"):
+        file.scene_changes(threshold=2.0)
+
+
+def test_video_file_subtitles_without_subtitle_streams(sample_video_path):
+    file = daft.VideoFile(sample_video_path)
+    assert file.subtitles() == []
+    with pytest.raises(ValueError, match="Subtitle stream 1 not found"):
+        file.subtitles(stream_index=1)
+
+
+@pytest.mark.skipif(shutil.which("ffmpeg") is None, reason="requires ffmpeg to mux subtitles")
+def test_video_extract_subtitles(sample_video_path, tmp_path):
+    srt = tmp_path / "subtitles.srt"
+    srt.write_text("1\n00:00:01,000 --> 00:00:02,500\nHello <i>world</i>\n\n2\n00:00:03,000 --> 00:00:04,000\nBye\n")
+    path = tmp_path / "subtitled.mkv"
+    subprocess.run(
+        ["ffmpeg", "-y", "-i", sample_video_path, "-i", str(srt), "-c:v", "copy", "-c:s", "ass", str(path)],
+        check=True,
+        capture_output=True,
+    )
+
+    df = daft.from_pydict({"path": [str(path)]})
+    df = df.select(daft.functions.video_extract_subtitles(daft.functions.video_file(df["path"])).alias("subtitles"))
+    subtitles = df.to_pydict()["subtitles"][0]
+    assert [subtitle["text"] for subtitle in subtitles] == ["Hello world", "Bye"]
+    assert subtitles[0]["start"] == pytest.approx(1.0)
+    assert subtitles[0]["end"] == pytest.approx(2.5)
+    assert subtitles[1]["start"] == pytest.approx(3.0)
+
+
+def test_subtitle_text_strips_ass_formatting():
+    from types import SimpleNamespace
+
+    from daft.file.video import _subtitle_text
+
+    ass = SimpleNamespace(type=b"ass", ass=b"0,0,Default,,0,0,0,,{\\i1}Hello{\\i0}\\Nworld, again")
+    assert _subtitle_text(ass) == "Hello\nworld, again"
+    legacy = SimpleNamespace(type=b"ass", ass=b"Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Hi")
+    assert _subtitle_text(legacy) == "Hi"
+    text = SimpleNamespace(type=b"text", text=b" plain ")
+    assert _subtitle_text(text) == "plain"
+    assert _subtitle_text(SimpleNamespace(type=b"bitmap")) == ""
+
+
+def _write_multi_stream_video(path):
+    """Write a video with a black video stream tagged `eng`, followed by a white video stream tagged `fra`."""
+    import av
+
+    np = pytest.importorskip("numpy")
+    with av.open(str(path), mode="w") as container:
+        streams = []
+        for language in ("eng", "fra"):
+            stream = container.add_stream("libx264", rate=10)
+            stream.width = 64
+            stream.height = 48
+            stream.pix_fmt = "yuv420p"
+            stream.metadata["language"] = language
+            streams.append(stream)
+        for _ in range(5):
+            for stream, value in zip(streams, (0, 255)):
+                pixels = np.full((48, 64, 3), value, dtype=np.uint8)
+                container.mux(stream.encode(av.VideoFrame.from_ndarray(pixels, format="rgb24")))
+        for stream in streams:
+            container.mux(stream.encode())
+
+
+def test_video_file_select_stream(tmp_path):
+    np = pytest.importorskip("numpy")
+    path = tmp_path / "multi_stream.mp4"
+    _write_multi_stream_video(path)
+    file = daft.VideoFile(str(path))
+
+    streams = file.metadata()["streams"]
+    assert [(s["index"], s["language"]) for s in streams] == [(0, "eng"), (1, "fra")]
+
+    def brightness(image):
+        return np.asarray(image).mean()
+
+    assert brightness(next(file.keyframes())) < 16
+    assert brightness(next(file.keyframes(stream=1))) > 240
+    assert brightness(file.frame_at(0, stream="fra")) > 240
+    assert brightness(file.frame_at_index(0, stream="eng")) < 16
+
+    with pytest.raises(ValueError, match="Video stream 2 not found"):
+        file.metadata(stream=2)
+    with pytest.raises(ValueError, match="language 'deu'"):
+        list(file.keyframes(stream="deu"))
+
+
+def test_read_video_frames_select_stream(tmp_path):
+    path = tmp_path / "multi_stream.mp4"
+    _write_multi_stream_video(path)
+
+    df = daft.read_video_frames(str(path), image_height=48, image_width=64, max_frames=1, stream="fra")
+    frame = df.to_pydict()["data"][0]
+    assert frame.mean() > 240
+
+
+def _write_rotated_video(path, tmp_path):
+    """Write a video whose frames are white on their right half, with a display matrix rotating them a quarter turn
+    counterclockwise, like a video recorded by a phone held upright.
+    """
+    import av
+
+    np = pytest.importorskip("numpy")
+    unrotated = tmp_path / "unrotated.mp4"
+    with av.open(str(unrotated), mode="w") as container:
+        stream = container.add_stream("libx264", rate=10)
+        stream.width = 64
+        stream.height = 48
+        stream.pix_fmt = "yuv420p"
+        pixels = np.zeros((48, 64, 3), dtype=np.uint8)
+        pixels[:, 32:] = 255
+        for _ in range(5):
+            container.mux(stream.encode(av.VideoFrame.from_ndarray(pixels, format="rgb24")))
+        container.mux(stream.encode())
+    subprocess.run(
+        ["ffmpeg", "-y", "-display_rotation", "90", "-i", str(unrotated), "-c", "copy", str(path)],
+        check=True,
+        capture_output=True,
+    )
+
+
+@pytest.mark.skipif(shutil.which("ffmpeg") is None, reason="requires ffmpeg to set the rotation of a video")
+def test_video_file_autorotate(tmp_path):
+    np = pytest.importorskip("numpy")
+    path = tmp_path / "rotated.mp4"
+    _write_rotated_video(path, tmp_path)
+    file = daft.VideoFile(str(path))
+
+    metadata = file.metadata()
+    assert (metadata["width"], metadata["height"], metadata["rotation"]) == (64, 48, 270)
+
+    # Turning the frames counterclockwise moves their white right half to the top.
+    frame = np.asarray(file.frame_at_index(0))
+    assert frame.shape[:2] == (64, 48)
+    assert frame[:32].mean() > 200 and frame[32:].mean() < 50
+    assert np.asarray(next(file.keyframes())).shape[:2] == (64, 48)
+
+    assert np.asarray(file.frame_at_index(0, autorotate=False)).shape[:2] == (48, 64)
+
+
+@pytest.mark.skipif(shutil.which("ffmpeg") is None, reason="requires ffmpeg to set the rotation of a video")
+def test_read_video_frames_autorotate(tmp_path):
+    path = tmp_path / "rotated.mp4"
+    _write_rotated_video(path, tmp_path)
+
+    frame = daft.read_video_frames(str(path), image_height=64, image_width=48, max_frames=1).to_pydict()["data"][0]
+    assert frame.shape == (64, 48, 3)
+    assert frame[:32].mean() > 200 and frame[32:].mean() < 50
+
+    # Crops are of the frames as displayed.
+    frame = daft.read_video_frames(
+        str(path), image_height=32, image_width=48, max_frames=1, crop=(0, 0, 48, 32)
+    ).to_pydict()["data"][0]
+    assert frame.mean() > 200
+
+    frame = daft.read_video_frames(
+        str(path), image_height=48, image_width=64, max_frames=1, autorotate=False
+    ).to_pydict()["data"][0]
+    assert frame[:, :32].mean() < 50 and frame[:, 32:].mean() > 200
+
+
+def test_video_file_container_error(tmp_path):
+    import av
+
+    path = tmp_path / "not_a_video.mp4"
+    path.write_bytes(b"this is not a video" * 100)
+
+    with pytest.raises(daft.file.VideoContainerError) as excinfo:
+        daft.VideoFile(str(path)).metadata()
+
+    error = excinfo.value
+    assert not error.recoverable
+    assert isinstance(error.__cause__, av.FFmpegError)
+    assert error.code == error.__cause__.errno
+    assert error.stream is None
+    assert str(error).startswith(f"Failed to open video {path}")
+
+
+def test_video_decode_error_preserves_position(sample_video_path):
+    import av
+
+    from daft.file.video import _ReadState
+
+    state = _ReadState()
+    with av.open(sample_video_path) as container:
+        video = container.streams.video[0]
+        state.decode(video)
+        for _ in zip(range(3), state.track(container.decode(video))):
+            pass
+
+    cause = av.error.InvalidDataError(-1094995529, "Invalid data found when processing input")
+    error = state.error(sample_video_path, cause)
+
+    assert isinstance(error, daft.file.VideoDecodeError)
+    assert isinstance(error, daft.file.VideoError)
+    assert error.recoverable
+    assert error.code == -1094995529
+    assert error.reason == "Invalid data found when processing input"
+    assert error.stream == video.index
+    assert error.timestamp == pytest.approx(2 / 30, abs=0.02)
+    assert f"in stream {video.index} at " in str(error)
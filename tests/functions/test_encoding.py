@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import DataType
+from daft.functions import LabelEncoder, feature_hash, label_encode, one_hot
+
+
+def test_one_hot():
+    df = daft.from_pydict({"color": ["red", "blue", "green", None, "pink"]})
+    df = df.select(one_hot(df["color"], ["red", "green", "blue"]))
+    assert df.schema()["color"].dtype == DataType.fixed_size_list(DataType.uint8(), 3)
+    assert df.to_pydict() == {"color": [[1, 0, 0], [0, 0, 1], [0, 1, 0], [0, 0, 0], [0, 0, 0]]}
+
+
+def test_one_hot_sparse():
+    df = daft.from_pydict({"color": ["red", "blue", None, "pink"]})
+    df = df.select(one_hot(df["color"], ["red", "green", "blue"], sparse=True))
+    assert df.schema()["color"].dtype == DataType.struct(
+        {
+            "size": DataType.uint64(),
+            "indices": DataType.list(DataType.uint64()),
+            "values": DataType.list(DataType.uint8()),
+        }
+    )
+    assert df.to_pydict() == {
+        "color": [
+            {"size": 3, "indices": [0], "values": [1]},
+            {"size": 3, "indices": [2], "values": [1]},
+            {"size": 3, "indices": [], "values": []},
+            {"size": 3, "indices": [], "values": []},
+        ]
+    }
+
+
+def test_one_hot_empty_vocabulary():
+    with pytest.raises(ValueError, match="non-empty vocabulary"):
+        one_hot(daft.col("x"), [])
+
+
+def test_feature_hash():
+    df = daft.from_pydict({"a": ["x", "y", "x", None], "b": [1, 2, 1, None]})
+    actual = df.select(feature_hash(df["a"], df["b"], n_buckets=4).alias("bucket")).to_pydict()["bucket"]
+    assert all(0 <= bucket < 4 for bucket in actual)
+    assert actual[0] == actual[2]
+
+    with pytest.raises(ValueError, match="positive number of buckets"):
+        feature_hash(daft.col("a"), n_buckets=0)
+
+
+def test_label_encode_fit_and_reapply(tmp_path):
+    train = daft.from_pydict({"color": ["red", "blue", None, "red", "green"]})
+    encoder = LabelEncoder.fit(train, "color")
+    assert encoder.vocabulary == ["blue", "green", "red"]
+
+    path = tmp_path / "encoder.json"
+    encoder.save(path)
+    restored = LabelEncoder.load(path)
+    assert restored.vocabulary == encoder.vocabulary
+
+    df = daft.from_pydict({"color": ["green", "purple", None, "blue"]})
+    assert df.select(label_encode(df["color"], restored)).to_pydict() == {"color": [1, None, None, 0]}
+    assert df.select(label_encode(df["color"], restored, default=3)).to_pydict() == {"color": [1, 3, 3, 0]}
+
+
+def test_label_encode_vocabulary():
+    df = daft.from_pydict({"n": [10, 20, 30]})
+    assert df.select(label_encode(df["n"], [30, 10])).to_pydict() == {"n": [1, None, 0]}
+
+    with pytest.raises(ValueError, match="duplicate"):
+        LabelEncoder([1, 1])
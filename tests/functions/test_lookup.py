@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+import daft
+from daft.functions import lookup, map_lookup
+
+
+def test_lookup():
+    df = daft.from_pydict({"code": [1, 2, None, 3, 1]})
+    actual = df.select(lookup(df["code"], {1: "low", 2: "high"})).to_pydict()
+    assert actual == {"code": ["low", "high", None, None, "low"]}
+
+
+def test_lookup_broadcast():
+    table = daft.broadcast({"a": 1.5, "b": 2.5})
+    df = daft.from_pydict({"key": ["b", "c", "a"]})
+    actual = df.select(lookup(df["key"], table).alias("value")).to_pydict()
+    assert actual == {"value": [2.5, None, 1.5]}
+
+
+def test_lookup_broadcast_default():
+    table = daft.broadcast({"a": 1, "b": 2})
+    df = daft.from_pydict({"key": ["b", "c", None]})
+    actual = df.select(lookup(df["key"], table, default=0)).to_pydict()
+    assert actual == {"key": [2, 0, 0]}
+
+
+def test_lookup_broadcast_is_referenced_by_plan():
+    table = daft.broadcast({"a": "first-value", "b": "second-value"})
+    expr = lookup(daft.col("key"), table)
+    assert "broadcast_lookup" in repr(expr)
+    assert "first-value" not in repr(expr)
+
+
+def test_broadcast_lookup_tables_are_bounded():
+    from daft.broadcast import _LOOKUP_TABLES, _MAX_CACHED
+
+    broadcasts = [daft.broadcast({i: i}) for i in range(_MAX_CACHED + 2)]
+    for table in broadcasts:
+        lookup(daft.col("key"), table)
+    assert len(_LOOKUP_TABLES) == _MAX_CACHED
+    assert broadcasts[0]._id not in _LOOKUP_TABLES
+
+    broadcasts[-1].unpersist()
+    assert broadcasts[-1]._id not in _LOOKUP_TABLES
+    df = daft.from_pydict({"key": [0, _MAX_CACHED + 1]})
+    assert df.select(lookup(df["key"], broadcasts[-1])).to_pydict() == {"key": [None, _MAX_CACHED + 1]}
+
+
+def test_lookup_empty_table():
+    df = daft.from_pydict({"code": [1, 2]})
+    actual = df.select(lookup(df["code"], {})).to_pydict()
+    assert actual == {"code": [None, None]}
+
+
+def test_broadcast_in_udf():
+    labels = daft.broadcast({1: "low", 2: "high"})
+
+    @daft.func
+    def label(code: int) -> str:
+        return labels.value.get(code, "unknown")
+
+    df = daft.from_pydict({"code": [1, 2, 3]})
+    assert df.select(label(df["code"])).to_pydict() == {"code": ["low", "high", "unknown"]}
+
+
+def test_broadcast_pickle_roundtrip():
+    config = daft.broadcast({"threshold": 0.5})
+    restored = pickle.loads(pickle.dumps(config))
+    assert isinstance(restored, daft.Broadcast)
+    assert restored.value == {"threshold": 0.5}
+    assert restored._id == config._id
+
+
+def test_lookup_default():
+    df = daft.from_pydict({"code": [1, None, 3]})
+    actual = df.select(lookup(df["code"], {1: 10, 3: None}, default=-1)).to_pydict()
+    assert actual == {"code": [10, -1, None]}
+
+
+@pytest.mark.parametrize("as_dataframe", [True, False])
+def test_map_lookup(as_dataframe):
+    columns = {"code": ["FR", "JP", "FR"], "name": ["France", "Japan", "Francia"], "population": [68, 124, 68]}
+    table = daft.from_pydict(columns) if as_dataframe else columns
+    df = daft.from_pydict({"country": ["JP", "FR", "XX", None]})
+    actual = df.select(map_lookup(df["country"], table, key="code", value="name", default="Unknown")).to_pydict()
+    assert actual == {"country": ["Japan", "France", "Unknown", "Unknown"]}
+
+
+def test_map_lookup_missing_column():
+    with pytest.raises(ValueError, match="Column `id` not found"):
+        map_lookup(daft.col("x"), {"code": [1], "name": ["a"]}, key="id", value="name")
@@ -0,0 +1,36 @@
+from __future__ import annotations
+
+import daft
+from daft.functions import html_to_text, markdown_to_text
+
+HTML = """<html><head><title>Page</title><script>alert("hi")</script></head>
+<body>
+  <header><a href="/">Site</a></header>
+  <h1>Heading</h1>
+  <p>First&nbsp;paragraph with <a href="https://daft.ai">a link</a>.</p>
+  <p>Second<br/>paragraph</p>
+  <footer>&copy; 2024</footer>
+</body></html>"""
+
+
+def test_html_to_text():
+    df = daft.from_pydict({"html": [HTML, None]})
+    assert df.select(html_to_text(df["html"])).to_pydict() == {
+        "html": ["Site\n\nHeading\n\nFirst paragraph with a link.\n\nSecond\nparagraph\n\n© 2024", None]
+    }
+    assert df.select(html_to_text(df["html"], remove_boilerplate=True)).to_pydict() == {
+        "html": ["Heading\n\nFirst paragraph with a link.\n\nSecond\nparagraph", None]
+    }
+
+
+def test_html_to_text_binary():
+    df = daft.from_pydict({"html": [b"<p>caf\xc3\xa9</p>"]})
+    assert df.select(html_to_text(df["html"])).to_pydict() == {"html": ["café"]}
+
+
+def test_markdown_to_text():
+    markdown = "## Setup\n\nRun `pip install daft` and see the [docs](https://docs.daft.ai).\n\n- **Fast**\n- _Simple_\n"
+    df = daft.from_pydict({"md": [markdown, None]})
+    assert df.select(markdown_to_text(df["md"])).to_pydict() == {
+        "md": ["Setup\n\nRun pip install daft and see the docs.\n\nFast\nSimple", None]
+    }
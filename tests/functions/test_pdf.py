@@ -0,0 +1,60 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.functions import extract_pdf_text
+
+
+def make_pdf(pages: list[str]) -> bytes:
+    """Builds a minimal PDF with one line of Helvetica text per page."""
+    n = len(pages)
+    font_id = 3 + 2 * n
+    kids = " ".join(f"{3 + 2 * i} 0 R" for i in range(n))
+    objects = [
+        b"<< /Type /Catalog /Pages 2 0 R >>",
+        f"<< /Type /Pages /Kids [{kids}] /Count {n} >>".encode(),
+    ]
+    for i, text in enumerate(pages):
+        content = f"BT /F1 24 Tf 72 720 Td ({text}) Tj ET".encode()
+        objects.append(
+            f"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents {4 + 2 * i} 0 R "
+            f"/Resources << /Font << /F1 {font_id} 0 R >> >> >>".encode()
+        )
+        objects.append(b"<< /Length %d >>\nstream\n%s\nendstream" % (len(content), content))
+    objects.append(b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>")
+
+    out = bytearray(b"%PDF-1.4\n")
+    offsets = []
+    for i, obj in enumerate(objects, start=1):
+        offsets.append(len(out))
+        out += b"%d 0 obj\n%s\nendobj\n" % (i, obj)
+    xref = len(out)
+    out += b"xref\n0 %d\n0000000000 65535 f \n" % (len(objects) + 1)
+    for offset in offsets:
+        out += b"%010d 00000 n \n" % offset
+    out += b"trailer\n<< /Size %d /Root 1 0 R >>\nstartxref\n%d\n%%%%EOF\n" % (len(objects) + 1, xref)
+    return bytes(out)
+
+
+def test_extract_pdf_text():
+    df = daft.from_pydict({"pdf": [make_pdf(["Hello", "World"]), make_pdf(["Single page"]), None]})
+    pages = df.select(extract_pdf_text(df["pdf"])).to_pydict()["pdf"]
+    assert [[page.strip() for page in doc] for doc in pages[:2]] == [["Hello", "World"], ["Single page"]]
+    assert pages[2] is None
+
+
+def test_extract_pdf_text_on_error():
+    df = daft.from_pydict({"pdf": [b"not a pdf", make_pdf(["Hello"])]})
+    with pytest.raises(Exception, match="PDF"):
+        df.select(extract_pdf_text(df["pdf"])).collect()
+
+    pages = df.select(extract_pdf_text(df["pdf"], on_error="null")).to_pydict()["pdf"]
+    assert pages[0] is None
+    assert [page.strip() for page in pages[1]] == ["Hello"]
+
+
+def test_extract_pdf_text_invalid_on_error():
+    df = daft.from_pydict({"pdf": [make_pdf(["Hello"])]})
+    with pytest.raises(Exception, match="on_error"):
+        df.select(extract_pdf_text(df["pdf"], on_error="ignore")).collect()
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.functions import crop_points, voxel_downsample
+
+
+def point(x, y, z, intensity=None):
+    return {"x": x, "y": y, "z": z, "intensity": intensity}
+
+
+def test_voxel_downsample():
+    df = daft.from_pydict(
+        {
+            "points": [
+                [point(0.1, 0.1, 0.1, 1.0), point(0.3, 0.5, 0.1, 2.0), point(1.5, 0.0, 0.0, 3.0)],
+                [point(-0.5, 0.0, 0.0, 4.0), point(0.5, 0.0, 0.0, 5.0)],
+                [],
+                None,
+            ]
+        }
+    )
+    result = df.select(voxel_downsample(df["points"], voxel_size=1.0)).to_pydict()["points"]
+
+    assert result[0] == [point(0.2, 0.3, 0.1, 1.0), point(1.5, 0.0, 0.0, 3.0)]
+    # Voxels are aligned with the origin, so points either side of it are in different voxels.
+    assert result[1] == [point(-0.5, 0.0, 0.0, 4.0), point(0.5, 0.0, 0.0, 5.0)]
+    assert result[2] == []
+    assert result[3] is None
+
+
+def test_voxel_downsample_drops_null_coordinates():
+    df = daft.from_pydict({"points": [[point(0.0, 0.0, None), point(0.2, 0.2, 0.2)]]})
+    result = df.select(voxel_downsample(df["points"], voxel_size=1.0)).to_pydict()["points"]
+    assert result == [[point(0.2, 0.2, 0.2)]]
+
+
+def test_voxel_downsample_invalid_voxel_size():
+    df = daft.from_pydict({"points": [[point(0.0, 0.0, 0.0)]]})
+    with pytest.raises(ValueError, match="positive"):
+        df.select(voxel_downsample(df["points"], voxel_size=0))
+
+
+def test_crop_points():
+    df = daft.from_pydict(
+        {
+            "points": [
+                [point(0.0, 0.0, 0.0, 1.0), point(1.0, 1.0, 1.0, 2.0), point(1.5, 0.0, 0.0, 3.0)],
+                [point(-2.0, 0.0, 0.0, 4.0)],
+                None,
+            ]
+        }
+    )
+    result = df.select(crop_points(df["points"], (-1, -1, -1), (1, 1, 1))).to_pydict()["points"]
+    assert result == [[point(0.0, 0.0, 0.0, 1.0), point(1.0, 1.0, 1.0, 2.0)], [], None]
+
+
+def test_crop_points_integer_coordinates():
+    df = daft.from_pydict({"points": [[{"x": 1, "y": 2, "z": 3}, {"x": 4, "y": 5, "z": 6}]]})
+    result = df.select(crop_points(df["points"], (0, 0, 0), (3, 3, 3))).to_pydict()["points"]
+    assert result == [[{"x": 1, "y": 2, "z": 3}]]
+
+
+def test_point_cloud_functions_require_coordinates():
+    df = daft.from_pydict({"points": [[{"x": 1.0, "y": 2.0}]]})
+    with pytest.raises(Exception, match="`x`, `y` and `z`"):
+        df.select(crop_points(df["points"], (0, 0, 0), (1, 1, 1))).collect()
+    with pytest.raises(ValueError, match="3 coordinates"):
+        crop_points(df["points"], (0, 0), (1, 1))
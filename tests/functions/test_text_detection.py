@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.functions import decode_text, detect_encoding, detect_language
+
+RUSSIAN = "Привет, как дела? Это проверка кодировки."
+
+
+def test_detect_language():
+    df = daft.from_pydict(
+        {
+            "text": [
+                "The quick brown fox jumps over the lazy dog.",
+                "Der schnelle braune Fuchs springt über den faulen Hund.",
+                "El rápido zorro marrón salta sobre el perro perezoso.",
+                RUSSIAN,
+                "",
+                None,
+            ]
+        }
+    )
+    actual = df.select(detect_language(df["text"])).to_pydict()
+    assert actual == {"text": ["eng", "deu", "spa", "rus", None, None]}
+
+
+def test_detect_language_min_confidence():
+    df = daft.from_pydict({"text": ["The quick brown fox jumps over the lazy dog.", "ok"]})
+    actual = df.select(detect_language(df["text"], min_confidence=0.5)).to_pydict()
+    assert actual == {"text": ["eng", None]}
+
+    with pytest.raises(ValueError):
+        detect_language(df["text"], min_confidence=2.0)
+
+
+def test_detect_encoding():
+    df = daft.from_pydict({"bytes": [RUSSIAN.encode("utf-8"), RUSSIAN.encode("cp1251"), None]})
+    actual = df.select(detect_encoding(df["bytes"])).to_pydict()
+    assert actual == {"bytes": ["UTF-8", "windows-1251", None]}
+
+
+def test_decode_text():
+    df = daft.from_pydict({"bytes": [RUSSIAN.encode("cp1251"), None]})
+    assert df.select(decode_text(df["bytes"], "windows-1251")).to_pydict() == {"bytes": [RUSSIAN, None]}
+    # Without an encoding, the encoding of each value is detected.
+    assert df.select(decode_text(df["bytes"])).to_pydict() == {"bytes": [RUSSIAN, None]}
+
+
+def test_decode_text_replaces_malformed_bytes():
+    df = daft.from_pydict({"bytes": [b"ok\xff"]})
+    assert df.select(decode_text(df["bytes"], "utf-8")).to_pydict() == {"bytes": ["ok�"]}
+
+
+def test_decode_text_unknown_encoding():
+    df = daft.from_pydict({"bytes": [b"ok"]})
+    with pytest.raises(Exception, match="Unsupported character encoding"):
+        df.select(decode_text(df["bytes"], "klingon")).collect()
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft import DataType, col
+from daft.functions import try_cast
+from tests.conftest import get_tests_daft_runner_name
+
+
+def test_try_cast_collects_errors():
+    df = daft.from_pydict({"a": ["1", "x", None, "4"]})
+    df = df.select(try_cast(col("a"), DataType.int64()).unnest())
+
+    assert df.schema()["value"].dtype == DataType.int64()
+    result = df.to_pydict()
+    assert result["value"] == [1, None, None, 4]
+    assert result["error"] == [
+        None,
+        {"value": "x", "reason": "Cannot cast String to Int64"},
+        None,
+        None,
+    ]
+
+
+def test_try_cast_expression_method():
+    df = daft.from_pydict({"a": ["1.5", "nope"]})
+    result = df.select(col("a").try_cast(float)["value"]).to_pydict()
+    assert result["value"] == [1.5, None]
+
+
+def test_try_cast_max_errors():
+    df = daft.from_pydict({"a": ["1", "x", "y"]})
+
+    result = df.select(try_cast(col("a"), DataType.int64(), max_errors=2)["value"]).to_pydict()
+    assert result["value"] == [1, None, None]
+    with pytest.raises(Exception, match="more than the 1 errors allowed"):
+        df.select(try_cast(col("a"), DataType.int64(), max_errors=1)).collect()
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Each worker of a distributed query counts its own errors"
+)
+def test_try_cast_max_errors_across_batches():
+    df = daft.from_pydict({"a": ["1", "x", "y", "z"]}).into_batches(1)
+    with pytest.raises(Exception, match="Cannot cast 3 values of a from String to Int64, which is more than the 2"):
+        df.select(try_cast(col("a"), DataType.int64(), max_errors=2)).collect()
+
+    # Every query counts its own errors, even when it runs the same expression as an earlier query.
+    expr = try_cast(col("a"), DataType.int64(), max_errors=3)["value"]
+    for _ in range(2):
+        assert df.select(expr).to_pydict() == {"value": [1, None, None, None]}
+
+
+def test_try_cast_strict():
+    df = daft.from_pydict({"a": ["1", "2"]})
+    result = df.select(try_cast(col("a"), DataType.int64(), max_errors=0)["value"]).to_pydict()
+    assert result["value"] == [1, 2]
+
+    with pytest.raises(Exception, match="The first value which can't be cast is \"x\""):
+        daft.from_pydict({"a": ["1", "x"]}).select(try_cast(col("a"), DataType.int64(), max_errors=0)).collect()
+
+
+def test_try_cast_rejects_negative_max_errors():
+    with pytest.raises(ValueError, match="max_errors"):
+        try_cast(col("a"), DataType.int64(), max_errors=-1)
@@ -0,0 +1,22 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from tests.integration.io.conftest import minio_create_bucket
+
+
+@pytest.mark.integration()
+@pytest.mark.parametrize("file_format", ["parquet", "csv", "json"])
+@pytest.mark.parametrize("scan_read_ahead", [0, 1, 4])
+def test_read_many_files_from_s3_with_read_ahead(minio_io_config, file_format, scan_read_ahead):
+    bucket_name = "bucket"
+    data = {"a": list(range(100)), "b": [str(i % 7) for i in range(100)]}
+    with minio_create_bucket(minio_io_config, bucket_name=bucket_name):
+        df = daft.from_pydict(data).into_partitions(10)
+        getattr(df, f"write_{file_format}")(f"s3://{bucket_name}", io_config=minio_io_config)
+
+        read = getattr(daft, f"read_{file_format}")
+        with daft.execution_config_ctx(scan_read_ahead=scan_read_ahead, scan_io_concurrency=2):
+            read_back = read(f"s3://{bucket_name}/**", io_config=minio_io_config).sort("a").to_pydict()
+        assert read_back == data
@@ -1,12 +1,28 @@
 from __future__ import annotations
 
+import contextlib
+import dataclasses
+import functools
+import http.server
+import tarfile
+import threading
+import zipfile
 from unittest.mock import MagicMock, patch
 
 import av
+import numpy as np
 import pytest
 
 import daft
-from daft.io.av._read_video_frames import _VideoFramesSourceTask
+from daft.io.av._read_video_frames import (
+    DecodeOptions,
+    _decode_in_background,
+    _FrameSampler,
+    _open_stream,
+    _VideoFramesBuffer,
+    _VideoFramesSourceTask,
+    decode_many,
+)
 
 
 def test_read_video_eof():
@@ -40,6 +56,350 @@ def test_read_video_eof():
     mock_container.close.assert_called_once()
 
 
+def _read_frame_indices(**kwargs):
+    df = daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, **kwargs)
+    return df.select("frame_index", "frame_time").to_pydict()
+
+
+def test_read_video_frames_sample_every_n():
+    all_frames = _read_frame_indices()
+    sampled = _read_frame_indices(sample_every_n=10)
+    assert sampled["frame_index"] == all_frames["frame_index"][::10]
+    assert sampled["frame_time"] == all_frames["frame_time"][::10]
+
+
+def test_read_video_frames_target_fps():
+    all_frames = _read_frame_indices()
+    sampled = _read_frame_indices(target_fps=2)
+    times = sampled["frame_time"]
+    # About one frame is selected in each half second of the video.
+    duration = all_frames["frame_time"][-1] - all_frames["frame_time"][0]
+    assert abs(len(times) - duration * 2) <= 1.5
+    assert all(later - earlier > 0.4 for earlier, later in zip(times, times[1:]))
+
+
+def test_read_video_frames_frame_buffer_size():
+    assert _read_frame_indices(frame_buffer_size=2) == _read_frame_indices()
+
+
+def test_decode_in_background_is_bounded_and_stops_when_closed():
+    decoded = []
+    closed = threading.Event()
+
+    def frames():
+        try:
+            for i in range(100):
+                decoded.append(i)
+                yield i
+        finally:
+            closed.set()
+
+    buffered = _decode_in_background(frames(), buffer_size=2)
+    assert next(buffered) == 0
+    buffered.close()
+    assert closed.is_set()
+    # Decoding blocked once the buffer was full, rather than decoding every frame ahead of the consumer.
+    assert len(decoded) < 10
+
+
+def test_decode_in_background_raises_decode_errors():
+    def frames():
+        yield 0
+        raise av.EOFError(0, "mock message", "mock.mp4")
+
+    with pytest.raises(av.EOFError):
+        list(_decode_in_background(frames(), buffer_size=2))
+
+
+def _sample_video_task(**options):
+    return _VideoFramesSourceTask(
+        path="tests/assets/sample_video.mp4",
+        image_height=48,
+        image_width=64,
+        is_key_frame=None,
+        io_config=None,
+        decode_options=DecodeOptions(**options),
+    )
+
+
+def test_decode_many_bounds_open_videos():
+    open_videos = 0
+    max_open_videos = 0
+    lock = threading.Lock()
+    open_video = _VideoFramesSourceTask._open
+
+    @contextlib.contextmanager
+    def counting_open(self):
+        nonlocal open_videos, max_open_videos
+        with lock:
+            open_videos += 1
+            max_open_videos = max(max_open_videos, open_videos)
+        try:
+            with open_video(self) as file:
+                yield file
+        finally:
+            with lock:
+                open_videos -= 1
+
+    sources = [_sample_video_task(sample_every_n=index + 1, max_frames=4) for index in range(5)]
+    with patch.object(_VideoFramesSourceTask, "_open", counting_open):
+        decoded = list(decode_many(sources, max_concurrency=2))
+
+    assert max_open_videos <= 2
+    for index in range(5):
+        frame_indices = [frame.frame_index for i, frame in decoded if i == index]
+        assert frame_indices == [i * (index + 1) for i in range(4)]
+
+
+def test_decode_many_raises_decode_errors():
+    sources = [_sample_video_task(max_frames=2), _sample_video_task()]
+    sources[1].path = "tests/assets/does_not_exist.mp4"
+    with pytest.raises(Exception):
+        list(decode_many(sources, max_concurrency=2))
+
+
+def test_decode_many_rejects_invalid_concurrency():
+    with pytest.raises(ValueError, match="max_concurrency"):
+        list(decode_many([_sample_video_task()], max_concurrency=0))
+
+
+class _FailingContainer:
+    """Wraps a container, whose calls to `decode` with the given numbers fail as if their packets were corrupt."""
+
+    def __init__(self, container, failing_calls):
+        self._container = container
+        self._failing_calls = failing_calls
+        self._calls = 0
+
+    def decode(self, *args, **kwargs):
+        self._calls += 1
+        if self._calls in self._failing_calls:
+            raise av.error.InvalidDataError(-1094995529, "Invalid data found when processing input")
+        return self._container.decode(*args, **kwargs)
+
+    def __getattr__(self, name):
+        return getattr(self._container, name)
+
+
+@contextlib.contextmanager
+def _fail_decoding_at(*calls):
+    open_container = av.open
+    with patch("av.open", lambda *args, **kwargs: _FailingContainer(open_container(*args, **kwargs), calls)):
+        yield
+
+
+def test_read_video_frames_on_error_fail():
+    task = _sample_video_task(max_frames=5)
+    with _fail_decoding_at(3), pytest.raises(daft.file.VideoDecodeError):
+        list(task._list_frames(task.path, task.path))
+
+
+def test_read_video_frames_on_error_skip():
+    task = _sample_video_task(max_frames=5, on_error="skip")
+    with _fail_decoding_at(3, 4):
+        frames = list(task._list_frames(task.path, task.path))
+
+    assert [frame.frame_index for frame in frames] == [0, 1, 4, 5, 6]
+    assert task.stats.frames_decoded == 5
+    assert task.stats.frames_skipped == 2
+    assert isinstance(task.stats.last_error, daft.file.VideoDecodeError)
+
+
+def test_read_video_frames_on_error_null():
+    task = _sample_video_task(max_frames=4, on_error="null")
+    with _fail_decoding_at(2):
+        partitions = list(task.get_micro_partitions())
+
+    frames = partitions[0].to_pydict()
+    assert frames["frame_index"] == [0, 1, 2, 3]
+    assert [data is None for data in frames["data"]] == [False, True, False, False]
+    assert frames["frame_time"][1] is None
+    assert frames["frame_pts"][1] is None
+    assert task.stats.frames_skipped == 1
+
+
+def test_read_video_frames_gives_up_after_consecutive_errors():
+    task = _sample_video_task(on_error="skip")
+    with _fail_decoding_at(*range(1, 100)), pytest.raises(daft.file.VideoDecodeError):
+        list(task._list_frames(task.path, task.path))
+
+
+def test_read_video_frames_max_frames():
+    sampled = _read_frame_indices(sample_every_n=3, max_frames=5)
+    assert sampled["frame_index"] == [0, 3, 6, 9, 12]
+
+
+def test_read_video_frames_key_frames():
+    df = daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, is_key_frame=True)
+    key_frames = df.select("is_key_frame").to_pydict()["is_key_frame"]
+    assert len(key_frames) == 13
+    assert all(key_frames)
+
+
+def test_read_video_frames_non_key_frames():
+    all_frames = _read_frame_indices()
+    df = daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, is_key_frame=False)
+    result = df.select("frame_index", "is_key_frame").to_pydict()
+    assert len(result["frame_index"]) == len(all_frames["frame_index"]) - 13
+    assert not any(result["is_key_frame"])
+
+
+def test_read_video_frames_time_range():
+    all_frames = _read_frame_indices()
+    clipped = _read_frame_indices(start_time=4.0, end_time=5.0)
+    expected = [
+        (index, time)
+        for index, time in zip(all_frames["frame_index"], all_frames["frame_time"])
+        if 4.0 <= time <= 5.0
+    ]
+    assert list(zip(clipped["frame_index"], clipped["frame_time"])) == expected
+
+
+def _sorted_frames(frames):
+    return sorted(zip(frames["frame_index"], frames["frame_time"]))
+
+
+@pytest.mark.parametrize("kwargs", [{}, {"sample_every_n": 7}, {"target_fps": 2}, {"start_time": 3.0, "end_time": 7.5}])
+def test_read_video_frames_in_chunks(kwargs):
+    unchunked = _read_frame_indices(**kwargs)
+    chunked = _read_frame_indices(chunk_duration=2.0, **kwargs)
+    assert _sorted_frames(chunked) == _sorted_frames(unchunked)
+
+
+def test_video_file_chunks():
+    chunks = daft.VideoFile("tests/assets/sample_video.mp4").chunks(target_duration=2.0)
+    all_frames = _read_frame_indices()
+    assert len(chunks) > 1
+    assert chunks[0]["start_frame"] == 0
+    assert chunks[-1]["end_time"] is None
+    # The chunks are contiguous, and cover every frame of the video.
+    for chunk, next_chunk in zip(chunks, chunks[1:]):
+        assert chunk["end_time"] == next_chunk["start_time"]
+        assert chunk["start_frame"] + chunk["frame_count"] == next_chunk["start_frame"]
+        assert chunk["end_time"] - chunk["start_time"] >= 2.0
+        if chunk["start_byte"] is not None and next_chunk["start_byte"] is not None:
+            assert chunk["start_byte"] < next_chunk["start_byte"]
+    assert sum(chunk["frame_count"] for chunk in chunks) == len(all_frames["frame_index"])
+
+
+def test_read_video_frames_chunks_reject_max_frames():
+    with pytest.raises(ValueError, match="max_frames can't be used with chunk_duration"):
+        _read_frame_indices(chunk_duration=2.0, max_frames=5)
+
+
+def test_read_video_frames_chunks_reject_streaming():
+    with pytest.raises(ValueError, match="streaming can't be used with chunk_duration"):
+        _read_frame_indices(chunk_duration=2.0, streaming=True)
+
+
+def test_frame_sampler_combines_options():
+    sampler = _FrameSampler(DecodeOptions(sample_every_n=2, target_fps=1, max_frames=3))
+    times = [i * 0.25 for i in range(20)]
+    selected = []
+    for index, time in enumerate(times):
+        if sampler.done():
+            break
+        if sampler.select(index, time):
+            selected.append(index)
+    assert selected == [0, 4, 8]
+
+
+@pytest.mark.parametrize(
+    "options",
+    [
+        {"sample_every_n": 0},
+        {"target_fps": 0},
+        {"max_frames": -1},
+        {"start_time": -1.0},
+        {"start_time": 2.0, "end_time": 1.0},
+        {"frame_buffer_size": 0},
+        {"on_error": "ignore"},
+    ],
+)
+def test_invalid_decode_options(options):
+    with pytest.raises(ValueError):
+        DecodeOptions(**options)
+
+
+@pytest.fixture
+def video_http_server():
+    handler = functools.partial(http.server.SimpleHTTPRequestHandler, directory="tests/assets")
+    with http.server.ThreadingHTTPServer(("127.0.0.1", 0), handler) as server:
+        thread = threading.Thread(target=server.serve_forever, daemon=True)
+        thread.start()
+        yield f"http://127.0.0.1:{server.server_address[1]}"
+        server.shutdown()
+
+
+def _list_task_frame_indices(path):
+    task = _VideoFramesSourceTask(path=path, image_height=48, image_width=64, is_key_frame=None, io_config=None)
+    with task._open() as file:
+        return [frame.frame_index for frame in task._list_frames(path, file)]
+
+
+def test_read_video_frames_over_http(video_http_server):
+    """Videos on remote storage are read through daft-io, rather than downloaded to a local file first."""
+    remote = _list_task_frame_indices(f"{video_http_server}/sample_video.mp4")
+    assert len(remote) > 0
+    assert remote == _list_task_frame_indices("tests/assets/sample_video.mp4")
+
+
+@pytest.mark.parametrize("kwargs", [{}, {"sample_every_n": 7}, {"start_time": 3.0, "end_time": 7.5}])
+def test_read_video_frames_streaming(kwargs):
+    """Streamed videos are decoded without seeking, so the frames before the start time are decoded and skipped."""
+    assert _read_frame_indices(streaming=True, **kwargs) == _read_frame_indices(**kwargs)
+
+
+def test_read_video_frames_streaming_over_http(video_http_server):
+    path = f"{video_http_server}/sample_video.mp4"
+    task = _VideoFramesSourceTask(
+        path=path, image_height=48, image_width=64, is_key_frame=None, io_config=None, streaming=True
+    )
+    with task._open() as file:
+        assert not hasattr(file, "seek")
+        streamed = [frame.frame_index for frame in task._list_frames(path, file)]
+    assert streamed == _list_task_frame_indices("tests/assets/sample_video.mp4")
+
+
+def _read_archive_frame_indices(url):
+    df = daft.read_video_frames(url, image_height=48, image_width=64)
+    return df.select("frame_index", "frame_time").to_pydict()
+
+
+@pytest.mark.parametrize("mode", ["w", "w:gz"])
+def test_read_video_frames_from_tar(tmp_path, mode):
+    archive = tmp_path / "videos.tar"
+    with tarfile.open(archive, mode) as tar:
+        tar.add("tests/assets/sample_video.mp4", arcname="clips/sample_video.mp4")
+
+    frames = _read_archive_frame_indices(f"tar://clips/sample_video.mp4::{archive}")
+    assert frames == _read_frame_indices()
+
+
+def test_read_video_frames_from_zip(tmp_path):
+    archive = tmp_path / "videos.zip"
+    with zipfile.ZipFile(archive, "w", compression=zipfile.ZIP_DEFLATED) as archive_file:
+        archive_file.write("tests/assets/sample_video.mp4", arcname="sample_video.mp4")
+
+    frames = _read_archive_frame_indices(f"zip://sample_video.mp4::{archive}")
+    assert frames == _read_frame_indices()
+
+
+@pytest.mark.parametrize("scheme", ["tar", "zip"])
+def test_open_stream_missing_archive_member(tmp_path, scheme):
+    archive = tmp_path / f"videos.{scheme}"
+    if scheme == "tar":
+        with tarfile.open(archive, "w") as tar:
+            tar.add("tests/assets/sample_video.mp4", arcname="sample_video.mp4")
+    else:
+        with zipfile.ZipFile(archive, "w") as archive_file:
+            archive_file.write("tests/assets/sample_video.mp4", arcname="sample_video.mp4")
+
+    with pytest.raises(FileNotFoundError, match="No file missing.mp4"):
+        with _open_stream(f"{scheme}://missing.mp4::{archive}", io_config=None):
+            pass
+
+
 @pytest.mark.integration()
 def test_read_video_frames_s3(pytestconfig):
     """Test that we can read video frames from S3."""
@@ -78,3 +438,233 @@ def test_read_video_frames_s3(pytestconfig):
 
     # Verify frame_index is sequential starting from 0
     assert df["frame_index"] == list(range(231))
+
+
+@pytest.mark.parametrize(
+    "pixel_format, mode, channels",
+    [("rgb24", daft.ImageMode.RGB, 3), ("rgba", daft.ImageMode.RGBA, 4), ("gray8", daft.ImageMode.L, 1)],
+)
+def test_read_video_frames_pixel_format(pixel_format, mode, channels):
+    df = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=48, image_width=64, max_frames=2, pixel_format=pixel_format
+    )
+    assert df.schema()["data"].dtype == daft.DataType.image(mode=mode, height=48, width=64)
+
+    frames = df.select("data").to_pydict()["data"]
+    assert len(frames) == 2
+    assert frames[0].shape[:2] == (48, 64)
+    assert (frames[0].shape[2] if frames[0].ndim == 3 else 1) == channels
+
+
+@pytest.mark.parametrize("pixel_format, av_format", [("rgb24", "rgb24"), ("gray8", "gray")])
+def test_read_video_frames_pixels_match_pyav(pixel_format, av_format):
+    # An odd width makes FFmpeg pad the rows of the frames, which are dropped when they're copied into the column.
+    df = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=30, image_width=45, max_frames=3, pixel_format=pixel_format
+    )
+    frames = df.select("data").to_pydict()["data"]
+
+    with av.open("tests/assets/sample_video.mp4") as container:
+        decoded = container.decode(video=0)
+        for frame in frames:
+            expected = next(decoded).reformat(width=45, height=30, format=av_format).to_ndarray()
+            np.testing.assert_array_equal(frame.reshape(expected.shape), expected)
+
+
+def test_video_frames_buffer_grows_past_its_capacity():
+    task = _sample_video_task(max_frames=3)
+    with task._open() as file:
+        frames = list(task._list_frames(task.path, file))
+
+    # Without a max size the buffer holds one frame, so it's grown to hold the rest.
+    buffer = _VideoFramesBuffer(image_height=48, image_width=64)
+    for frame in frames:
+        buffer.append(frame)
+    data = buffer.to_micropartition().to_pydict()["data"]
+
+    assert [frame.frame_index for frame in frames] == [0, 1, 2]
+    for frame, image in zip(frames, data):
+        np.testing.assert_array_equal(image, frame.data.to_ndarray())
+
+
+def test_read_video_frames_yuv_pixel_format():
+    df = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=48, image_width=64, max_frames=2, pixel_format="yuv"
+    )
+    assert df.schema()["data"].dtype == daft.DataType.tensor(daft.DataType.uint8())
+
+    frames = df.select("data").to_pydict()["data"]
+    # The sample video is planar YUV 4:2:0, with the Y plane followed by the quarter size U and V planes.
+    assert frames[0].shape == (48 * 3 // 2, 64)
+
+
+def test_read_video_frames_motion_vectors():
+    df = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=48, image_width=64, max_frames=30, export_mvs=True
+    )
+    assert df.schema().column_names()[-2:] == ["motion_vectors", "picture_type"]
+
+    frames = df.select("is_key_frame", "motion_vectors", "picture_type").to_pydict()
+    with av.open("tests/assets/sample_video.mp4") as container:
+        width, height = container.streams.video[0].width, container.streams.video[0].height
+
+    predicted_vectors = 0
+    for is_key_frame, vectors, picture_type in zip(*frames.values()):
+        if is_key_frame:
+            assert picture_type == "I"
+            assert vectors == []
+        else:
+            assert picture_type in ("P", "B")
+            predicted_vectors += len(vectors)
+        for vector in vectors:
+            assert vector["source"] in (-1, 1)
+            assert 0 <= vector["dst_x"] < width and 0 <= vector["dst_y"] < height
+    # The sample video moves, so its predicted frames have motion vectors.
+    assert predicted_vectors > 0
+
+
+def test_read_video_frames_without_motion_vectors():
+    df = daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, max_frames=2)
+    assert "motion_vectors" not in df.schema().column_names()
+    assert "picture_type" not in df.schema().column_names()
+
+
+def test_video_frames_buffer_motion_vectors_of_null_frames():
+    task = _VideoFramesSourceTask(
+        path="tests/assets/sample_video.mp4",
+        image_height=48,
+        image_width=64,
+        is_key_frame=None,
+        io_config=None,
+        decode_options=DecodeOptions(max_frames=2),
+        export_mvs=True,
+    )
+    with task._open() as file:
+        frames = list(task._list_frames(task.path, file))
+
+    buffer = _VideoFramesBuffer(image_height=48, image_width=64, export_mvs=True)
+    buffer.append(frames[0])
+    buffer.append(dataclasses.replace(frames[1], data=None, motion_vectors=None, picture_type=None))
+    columns = buffer.to_micropartition().to_pydict()
+
+    assert columns["motion_vectors"] == [[], None]
+    assert columns["picture_type"] == ["I", None]
+
+
+def test_read_video_frames_invalid_pixel_format():
+    with pytest.raises(ValueError, match="pixel_format"):
+        daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, pixel_format="bgr24")
+
+
+# The PQ signal of HDR reference white, which is 203 nits.
+_PQ_REFERENCE_WHITE = 0.5807
+
+
+def _write_hdr_video(path):
+    """Write a 10-bit PQ video of BT.2020 frames which are all HDR reference white."""
+    with av.open(str(path), mode="w") as container:
+        stream = container.add_stream("ffv1", rate=10)
+        stream.width = 64
+        stream.height = 48
+        stream.pix_fmt = "yuv420p10le"
+        stream.codec_context.color_primaries = 9  # BT.2020
+        stream.codec_context.color_trc = 16  # PQ
+        stream.codec_context.colorspace = 9  # BT.2020 non-constant luminance
+        pixels = np.full((48, 64, 3), round(_PQ_REFERENCE_WHITE * 65535), dtype=np.uint16)
+        for _ in range(3):
+            container.mux(stream.encode(av.VideoFrame.from_ndarray(pixels, format="rgb48le")))
+        container.mux(stream.encode())
+
+
+@pytest.fixture
+def hdr_video(tmp_path):
+    path = tmp_path / "hdr.mkv"
+    _write_hdr_video(path)
+    return str(path)
+
+
+def _mean_pixel(path, **kwargs):
+    df = daft.read_video_frames(path, image_height=48, image_width=64, max_frames=1, **kwargs)
+    return df.to_pydict()["data"][0].mean()
+
+
+def test_read_video_frames_tone_maps_hdr(hdr_video):
+    # Reference white is SDR white, which is clipped to full brightness and compressed by the tone curves.
+    assert _mean_pixel(hdr_video, tone_map="clip") == pytest.approx(255, abs=3)
+    assert _mean_pixel(hdr_video, tone_map="hable") < _mean_pixel(hdr_video, tone_map="reinhard") < 250
+    # Without tone-mapping, the PQ signal is read as if it were SDR, which washes it out.
+    assert _mean_pixel(hdr_video, tone_map=None) == pytest.approx(_PQ_REFERENCE_WHITE * 255, abs=4)
+
+
+def test_read_video_frames_tone_maps_hdr_to_gray(hdr_video):
+    assert _mean_pixel(hdr_video, tone_map="clip", pixel_format="gray8") == pytest.approx(255, abs=3)
+
+
+def test_read_video_frames_16_bit_keeps_hdr_signal(hdr_video):
+    df = daft.read_video_frames(hdr_video, image_height=48, image_width=64, max_frames=1, pixel_format="rgb48")
+    assert df.schema()["data"].dtype == daft.DataType.image(mode=daft.ImageMode.RGB16, height=48, width=64)
+
+    frame = df.to_pydict()["data"][0]
+    assert frame.dtype == np.uint16
+    assert frame.shape == (48, 64, 3)
+    # The 10-bit signal is kept as it is, rather than tone-mapped.
+    np.testing.assert_allclose(frame, _PQ_REFERENCE_WHITE * 65535, atol=256)
+
+
+def test_read_video_frames_yuv_of_10_bit_video(hdr_video):
+    df = daft.read_video_frames(hdr_video, image_height=48, image_width=64, max_frames=1, pixel_format="yuv")
+    frame = df.to_pydict()["data"][0]
+    # 10-bit planar YUV 4:2:0 is read with the same layout, with 8 bits per sample.
+    assert frame.dtype == np.uint8
+    assert frame.shape == (48 * 3 // 2, 64)
+
+
+def test_read_video_frames_invalid_tone_map():
+    with pytest.raises(ValueError, match="tone_map"):
+        daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, tone_map="aces")
+
+
+def test_read_video_frames_crop():
+    with av.open("tests/assets/sample_video.mp4") as container:
+        stream = container.streams.video[0]
+        width, height = stream.width, stream.height
+
+    full = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=height, image_width=width, max_frames=2
+    ).to_pydict()["data"]
+    cropped = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=16, image_width=32, max_frames=2, crop=(0, 0, 32, 16)
+    ).to_pydict()["data"]
+
+    assert len(cropped) == 2
+    for full_frame, cropped_frame in zip(full, cropped):
+        assert cropped_frame.shape == (16, 32, 3)
+        np.testing.assert_allclose(cropped_frame.astype(int), full_frame[:16, :32].astype(int), atol=8)
+
+
+def test_read_video_frames_crop_is_resized():
+    frames = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=24, image_width=24, max_frames=1, crop=(8, 8, 64, 64)
+    ).to_pydict()["data"]
+    assert frames[0].shape == (24, 24, 3)
+
+
+@pytest.mark.parametrize(
+    "crop, match",
+    [
+        ((0, 0, 32), "rectangle"),
+        ((-1, 0, 32, 32), "non-negative position"),
+        ((0, 0, 0, 32), "positive size"),
+    ],
+)
+def test_read_video_frames_invalid_crop(crop, match):
+    with pytest.raises(ValueError, match=match):
+        daft.read_video_frames("tests/assets/sample_video.mp4", image_height=48, image_width=64, crop=crop)
+
+
+def test_read_video_frames_crop_outside_of_frames():
+    df = daft.read_video_frames(
+        "tests/assets/sample_video.mp4", image_height=48, image_width=64, crop=(0, 0, 100_000, 32)
+    )
+    with pytest.raises(Exception, match="outside of the frames"):
+        df.collect()
@@ -261,6 +261,51 @@ def test_read_after_write_with_empty_partition(local_catalog):
     assert as_arrow == read_back.to_arrow()
 
 
+def test_write_records_bucket_of_each_file(local_catalog):
+    schema = Schema(NestedField(field_id=1, name="x", type=LongType()))
+    spec = PartitionSpec(PartitionField(source_id=1, field_id=1000, transform=BucketTransform(4), name="x_bucket"))
+    table = local_catalog.create_table("default.test", schema, partition_spec=spec)
+
+    result = daft.from_pydict({"x": list(range(20))}).write_iceberg(table).to_pydict()
+
+    bucket = BucketTransform(4).transform(LongType())
+    assert sorted(p["x"] for p in result["partitioning"]) == sorted({bucket(x) for x in range(20)})
+    manifest_buckets = {task.file.file_path: task.file.partition[0] for task in table.scan().plan_files()}
+    assert manifest_buckets == {file_name: p["x"] for file_name, p in zip(result["file_name"], result["partitioning"])}
+
+
+def test_write_records_partitions_of_fields_with_same_source_column(local_catalog):
+    schema = Schema(NestedField(field_id=1, name="x", type=LongType()))
+    spec = PartitionSpec(
+        PartitionField(source_id=1, field_id=1000, transform=BucketTransform(4), name="x_bucket"),
+        PartitionField(source_id=1, field_id=1001, transform=IdentityTransform(), name="x_identity"),
+    )
+    table = local_catalog.create_table("default.test", schema, partition_spec=spec)
+
+    result = daft.from_pydict({"x": [1, 2, 3]}).write_iceberg(table).to_pydict()
+
+    bucket = BucketTransform(4).transform(LongType())
+    assert sorted((p["x_identity"], p["x"]) for p in result["partitioning"]) == [(x, bucket(x)) for x in [1, 2, 3]]
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() == "native",
+    reason="Native executor does not support into_partitions",
+)
+def test_bucketed_write_writes_one_file_per_bucket(local_catalog):
+    schema = Schema(NestedField(field_id=1, name="x", type=LongType()))
+    spec = PartitionSpec(PartitionField(source_id=1, field_id=1000, transform=BucketTransform(4), name="x_bucket"))
+    table = local_catalog.create_table("default.test", schema, partition_spec=spec)
+
+    # Every input partition has rows of every bucket, which are shuffled together before they're written.
+    df = daft.from_pydict({"x": list(range(40))}).into_partitions(3)
+    result = df.write_iceberg(table).to_pydict()
+
+    assert len(result["operation"]) == 4
+    assert sorted(p["x"] for p in result["partitioning"]) == [0, 1, 2, 3]
+    assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == list(range(40))
+
+
 @pytest.fixture
 def complex_table() -> tuple[pa.Table, Schema]:
     table = pa.table(
@@ -98,3 +98,82 @@ def test_mcap_read_s3(data_from_s3):
     assert len(pdf) == 100
     assert pdf["sequence"].nunique() == 100
     assert pdf["data"].startswith("Chatter #").all()
+
+
+@pytest.fixture(scope="function")
+def multi_topic_mcap_path(tmp_path):
+    file_path = tmp_path / "multi.mcap"
+
+    with open(file_path, "wb") as f:
+        writer = Writer(f)
+        string_schema = writer.register_msgdef(datatype="std_msgs/msg/String", msgdef_text="string data")
+        point_schema = writer.register_msgdef(datatype="geometry_msgs/msg/Point", msgdef_text="float64 x\nfloat64 y")
+
+        for i in range(10):
+            writer.write_message(
+                topic="/chatter", schema=string_schema, message={"data": f"Chatter #{i}"}, log_time=i * 100
+            )
+            writer.write_message(
+                topic="/point", schema=point_schema, message={"x": float(i), "y": -float(i)}, log_time=i * 100 + 50
+            )
+        writer.finish()
+
+    yield file_path
+
+
+def test_mcap_read_struct(multi_topic_mcap_path):
+    df = daft.read_mcap(str(multi_topic_mcap_path), message_format="struct")
+    assert df.column_names == ["topic", "log_time", "publish_time", "sequence", "/chatter", "/point"]
+    assert df.schema()["/point"].dtype == daft.DataType.struct(
+        {"x": daft.DataType.float64(), "y": daft.DataType.float64()}
+    )
+
+    result = df.where(df["topic"] == "/point").select(df["/point"]["x"], df["/chatter"]).to_pydict()
+    assert result["x"] == [float(i) for i in range(10)]
+    assert result["/chatter"] == [None] * 10
+
+    result = df.where(df["topic"] == "/chatter").select(df["/chatter"]["data"]).to_pydict()
+    assert result["data"] == [f"Chatter #{i}" for i in range(10)]
+
+
+def test_mcap_read_raw(multi_topic_mcap_path):
+    df = daft.read_mcap(str(multi_topic_mcap_path), topics=["/point"], message_format="raw")
+    assert df.column_names == [
+        "topic",
+        "log_time",
+        "publish_time",
+        "sequence",
+        "schema_name",
+        "message_encoding",
+        "data",
+    ]
+
+    result = df.to_pydict()
+    assert result["schema_name"] == ["geometry_msgs/msg/Point"] * 10
+    assert result["message_encoding"] == ["cdr"] * 10
+    assert all(isinstance(data, bytes) for data in result["data"])
+
+
+def test_rosbag2_db3_read_raw(tmp_path):
+    import sqlite3
+
+    bag_dir = tmp_path / "bag"
+    bag_dir.mkdir()
+    (bag_dir / "metadata.yaml").write_text("rosbag2_bagfile_information: {}\n")
+    with sqlite3.connect(bag_dir / "bag_0.db3") as conn:
+        conn.execute("CREATE TABLE topics (id INTEGER PRIMARY KEY, name TEXT, type TEXT, serialization_format TEXT)")
+        conn.execute("CREATE TABLE messages (id INTEGER PRIMARY KEY, topic_id INTEGER, timestamp INTEGER, data BLOB)")
+        conn.execute("INSERT INTO topics VALUES (1, '/chatter', 'std_msgs/msg/String', 'cdr')")
+        conn.executemany(
+            "INSERT INTO messages (topic_id, timestamp, data) VALUES (1, ?, ?)",
+            [(i * 100, f"message {i}".encode()) for i in range(5)],
+        )
+
+    df = daft.read_mcap(str(bag_dir), start_time=100, message_format="raw")
+    result = df.to_pydict()
+    assert result["topic"] == ["/chatter"] * 4
+    assert result["log_time"] == [100, 200, 300, 400]
+    assert result["data"] == [f"message {i}".encode() for i in range(1, 5)]
+
+    with pytest.raises(ValueError, match="message_format='raw'"):
+        daft.read_mcap(str(bag_dir))
@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import io
+import tarfile
+import zipfile
+from datetime import datetime, timezone
+
+import pytest
+
+import daft
+from daft.functions import archive_members
+
+MEMBERS = {"0001.jpg": b"jpeg", "0001.json": b"{}", "0002.jpg": b"more jpeg"}
+MTIME = 1_700_000_000
+
+
+def make_tar(mode: str = "w") -> bytes:
+    buf = io.BytesIO()
+    with tarfile.open(fileobj=buf, mode=mode) as tf:
+        directory = tarfile.TarInfo("shard")
+        directory.type = tarfile.DIRTYPE
+        tf.addfile(directory)
+        for name, data in MEMBERS.items():
+            info = tarfile.TarInfo(f"shard/{name}")
+            info.size = len(data)
+            info.mtime = MTIME
+            tf.addfile(info, io.BytesIO(data))
+    return buf.getvalue()
+
+
+def make_zip() -> bytes:
+    buf = io.BytesIO()
+    with zipfile.ZipFile(buf, "w") as zf:
+        zf.writestr("shard/", "")
+        for name, data in MEMBERS.items():
+            zf.writestr(zipfile.ZipInfo(f"shard/{name}", date_time=(2023, 11, 14, 22, 13, 20)), data)
+    return buf.getvalue()
+
+
+@pytest.mark.parametrize(
+    "filename,archive",
+    [
+        ("shard.tar", make_tar()),
+        ("shard.tar.gz", make_tar("w:gz")),
+        ("shard.zip", make_zip()),
+    ],
+)
+def test_read_archives(tmp_path, filename, archive):
+    path = tmp_path / filename
+    path.write_bytes(archive)
+
+    df = daft.read_archives(str(path)).sort("member_path")
+    assert df.schema().column_names() == ["archive_path", "member_path", "bytes", "size", "mtime"]
+
+    result = df.to_pydict()
+    assert [p.endswith(filename) for p in result["archive_path"]] == [True] * len(MEMBERS)
+    assert result["member_path"] == [f"shard/{name}" for name in MEMBERS]
+    assert result["bytes"] == list(MEMBERS.values())
+    assert result["size"] == [len(data) for data in MEMBERS.values()]
+    assert result["mtime"] == [datetime.fromtimestamp(MTIME, tz=timezone.utc)] * len(MEMBERS)
+
+
+def test_read_archives_glob(tmp_path):
+    (tmp_path / "a.tar").write_bytes(make_tar())
+    (tmp_path / "b.tar").write_bytes(make_tar())
+
+    df = daft.read_archives(str(tmp_path / "*.tar"))
+    assert df.count_rows() == 2 * len(MEMBERS)
+
+
+def test_read_archives_invalid_format(tmp_path):
+    with pytest.raises(ValueError, match="Unsupported archive format"):
+        daft.read_archives(str(tmp_path / "shard.7z"), format="7z")
+
+
+@pytest.mark.parametrize("archive", [make_tar(), make_tar("w:gz"), make_zip()])
+def test_archive_members(archive):
+    df = daft.from_pydict({"archive": [archive, None]})
+    df = df.select(archive_members(df["archive"]).alias("members"))
+    result = df.to_pydict()["members"]
+
+    assert result[1] is None
+    assert [m["path"] for m in result[0]] == [f"shard/{name}" for name in MEMBERS]
+    assert [m["bytes"] for m in result[0]] == list(MEMBERS.values())
+    assert [m["size"] for m in result[0]] == [len(data) for data in MEMBERS.values()]
+    assert [m["mtime"] for m in result[0]] == [datetime.fromtimestamp(MTIME, tz=timezone.utc)] * len(MEMBERS)
+
+
+def test_archive_members_explode():
+    df = daft.from_pydict({"archive": [make_tar(), make_zip()]})
+    df = df.select(archive_members(df["archive"]).explode().alias("member")).select(daft.col("member").unnest())
+    assert df.count_rows() == 2 * len(MEMBERS)
+    assert df.column_names == ["path", "bytes", "size", "mtime"]
+
+
+def test_archive_members_on_error():
+    df = daft.from_pydict({"archive": [b"PK\x03\x04 not really a zip", make_zip()]})
+    with pytest.raises(Exception, match="Failed to read archive"):
+        df.select(archive_members(df["archive"])).collect()
+
+    result = df.select(archive_members(df["archive"], on_error="null")).to_pydict()["archive"]
+    assert result[0] is None
+    assert len(result[1]) == len(MEMBERS)
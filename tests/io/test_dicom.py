@@ -0,0 +1,111 @@
+from __future__ import annotations
+
+import hashlib
+
+import numpy as np
+import pytest
+
+import daft
+
+pydicom = pytest.importorskip("pydicom")
+
+from pydicom.dataset import Dataset, FileMetaDataset  # noqa: E402
+from pydicom.uid import CTImageStorage, ExplicitVRLittleEndian, generate_uid  # noqa: E402
+
+PIXELS = np.arange(16, dtype=np.uint16).reshape(4, 4)
+
+
+def write_dicom(path, patient_id: str = "PAT-001", with_pixels: bool = True) -> None:
+    file_meta = FileMetaDataset()
+    file_meta.MediaStorageSOPClassUID = CTImageStorage
+    file_meta.MediaStorageSOPInstanceUID = generate_uid()
+    file_meta.TransferSyntaxUID = ExplicitVRLittleEndian
+
+    ds = Dataset()
+    ds.file_meta = file_meta
+    ds.SOPClassUID = CTImageStorage
+    ds.SOPInstanceUID = file_meta.MediaStorageSOPInstanceUID
+    ds.PatientID = patient_id
+    ds.PatientName = "Doe^Jane"
+    ds.PatientBirthDate = "19700101"
+    ds.Modality = "CT"
+    ds.StudyInstanceUID = generate_uid()
+    ds.PixelSpacing = [0.5, 0.5]
+    ds.RescaleSlope = 2
+    ds.RescaleIntercept = -1024
+    ds.WindowCenter = -1000
+    ds.WindowWidth = 20
+    if with_pixels:
+        ds.Rows, ds.Columns = PIXELS.shape
+        ds.SamplesPerPixel = 1
+        ds.PhotometricInterpretation = "MONOCHROME2"
+        ds.BitsAllocated = 16
+        ds.BitsStored = 16
+        ds.HighBit = 15
+        ds.PixelRepresentation = 0
+        ds.PixelData = PIXELS.tobytes()
+    ds.save_as(path, enforce_file_format=True)
+
+
+def test_read_dicom(tmp_path):
+    write_dicom(tmp_path / "a.dcm")
+
+    df = daft.read_dicom(str(tmp_path / "a.dcm"))
+    assert df.column_names == ["path", "pixels", "metadata"]
+
+    result = df.to_pydict()
+    np.testing.assert_array_equal(result["pixels"][0], PIXELS.astype(np.float32) * 2 - 1024)
+    metadata = result["metadata"][0]
+    assert metadata["PatientID"] == "PAT-001"
+    assert metadata["PatientName"] == "Doe^Jane"
+    assert metadata["Modality"] == "CT"
+    assert metadata["Rows"] == 4
+    assert metadata["PixelSpacing"] == [0.5, 0.5]
+    assert metadata["WindowCenter"] == [-1000.0]
+    assert metadata["StudyDescription"] is None
+
+
+def test_read_dicom_image(tmp_path):
+    write_dicom(tmp_path / "a.dcm")
+
+    df = daft.read_dicom(str(tmp_path / "a.dcm"), pixel_format="image")
+    image = df.to_pydict()["pixels"][0]
+    # Rescaled values range from -1024 to -994, and the window from -1010 to -990.
+    assert image.dtype == np.uint8
+    assert image[0, 0] == 0
+    assert abs(int(image[3, 3]) - 204) <= 1
+
+
+def test_read_dicom_without_pixels(tmp_path):
+    write_dicom(tmp_path / "a.dcm")
+    write_dicom(tmp_path / "b.dcm", with_pixels=False)
+
+    df = daft.read_dicom(str(tmp_path / "*.dcm"), pixel_format="none")
+    assert df.column_names == ["path", "metadata"]
+    assert df.count_rows() == 2
+
+    pixels = daft.read_dicom(str(tmp_path / "*.dcm")).sort("path").to_pydict()["pixels"]
+    assert pixels[1] is None
+
+
+@pytest.mark.parametrize("anonymize", ["remove", "hash"])
+def test_read_dicom_anonymize(tmp_path, anonymize):
+    write_dicom(tmp_path / "a.dcm")
+
+    metadata = daft.read_dicom(str(tmp_path / "a.dcm"), pixel_format="none", anonymize=anonymize).to_pydict()[
+        "metadata"
+    ][0]
+    assert metadata["PatientName"] is None
+    assert metadata["PatientBirthDate"] is None
+    assert metadata["Modality"] == "CT"
+    if anonymize == "hash":
+        assert metadata["PatientID"] == hashlib.sha256(b"PAT-001").hexdigest()
+    else:
+        assert metadata["PatientID"] is None
+
+
+def test_read_dicom_invalid_options(tmp_path):
+    with pytest.raises(ValueError, match="pixel_format"):
+        daft.read_dicom(str(tmp_path / "*.dcm"), pixel_format="png")
+    with pytest.raises(ValueError, match="anonymize"):
+        daft.read_dicom(str(tmp_path / "*.dcm"), anonymize="blur")
@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+import json
+import struct
+
+import numpy as np
+import pytest
+
+import daft
+from daft import DataType
+
+
+def write_safetensors(path, tensors: dict[str, np.ndarray]) -> None:
+    dtypes = {np.dtype("float32"): "F32", np.dtype("float16"): "F16", np.dtype("int64"): "I64"}
+    header = {"__metadata__": {"format": "pt"}}
+    data = b""
+    for name, tensor in tensors.items():
+        raw = np.ascontiguousarray(tensor).tobytes()
+        header[name] = {
+            "dtype": dtypes[tensor.dtype],
+            "shape": list(tensor.shape),
+            "data_offsets": [len(data), len(data) + len(raw)],
+        }
+        data += raw
+    encoded = json.dumps(header).encode()
+    path.write_bytes(struct.pack("<Q", len(encoded)) + encoded + data)
+
+
+def test_read_npy(tmp_path):
+    embeddings = np.arange(12, dtype=np.float32).reshape(4, 3)
+    np.save(tmp_path / "embeddings.npy", embeddings)
+
+    df = daft.read_npy(str(tmp_path / "embeddings.npy"), column="embedding")
+    assert df.schema()["embedding"].dtype == DataType.tensor(DataType.float32(), (3,))
+    result = df.to_pydict()["embedding"]
+    np.testing.assert_array_equal(np.stack(result), embeddings)
+
+
+def test_read_npy_1d_glob(tmp_path):
+    np.save(tmp_path / "a.npy", np.array([1, 2, 3], dtype=np.int64))
+    np.save(tmp_path / "b.npy", np.array([4, 5], dtype=np.int64))
+
+    df = daft.read_npy(str(tmp_path / "*.npy"))
+    assert df.schema()["data"].dtype == DataType.int64()
+    assert sorted(df.to_pydict()["data"]) == [1, 2, 3, 4, 5]
+
+
+def test_read_npz(tmp_path):
+    ids = np.array([10, 11], dtype=np.int64)
+    images = np.arange(16, dtype=np.uint8).reshape(2, 2, 4)
+    np.savez(tmp_path / "dump.npz", ids=ids, images=images, other=np.zeros(5))
+
+    df = daft.read_npz(str(tmp_path / "dump.npz"), columns={"ids": "id", "images": "image"})
+    assert df.column_names == ["id", "image"]
+    assert df.schema()["image"].dtype == DataType.tensor(DataType.uint8(), (2, 4))
+    result = df.to_pydict()
+    assert result["id"] == [10, 11]
+    np.testing.assert_array_equal(np.stack(result["image"]), images)
+
+
+def test_read_npz_mismatched_lengths(tmp_path):
+    np.savez(tmp_path / "dump.npz", a=np.zeros(2), b=np.zeros(3))
+
+    with pytest.raises(ValueError, match="same length"):
+        daft.read_npz(str(tmp_path / "dump.npz"))
+    with pytest.raises(ValueError, match="not found"):
+        daft.read_npz(str(tmp_path / "dump.npz"), columns=["c"])
+
+    assert daft.read_npz(str(tmp_path / "dump.npz"), columns=["b"]).count_rows() == 3
+
+
+def test_read_safetensors(tmp_path):
+    embeddings = np.arange(8, dtype=np.float16).reshape(4, 2)
+    ids = np.arange(4, dtype=np.int64)
+    write_safetensors(tmp_path / "model.safetensors", {"embeddings": embeddings, "ids": ids})
+
+    df = daft.read_safetensors(str(tmp_path / "model.safetensors"))
+    assert df.schema()["embeddings"].dtype == DataType.tensor(DataType.float32(), (2,))
+    assert df.schema()["ids"].dtype == DataType.int64()
+    result = df.to_pydict()
+    assert result["ids"] == [0, 1, 2, 3]
+    np.testing.assert_array_equal(np.stack(result["embeddings"]), embeddings.astype(np.float32))
+
+
+def test_read_safetensors_columns(tmp_path):
+    write_safetensors(
+        tmp_path / "model.safetensors",
+        {"weight": np.ones((3, 2), dtype=np.float32), "bias": np.zeros(2, dtype=np.float32)},
+    )
+
+    df = daft.read_safetensors(str(tmp_path / "model.safetensors"), columns={"weight": "w"})
+    assert df.column_names == ["w"]
+    assert df.count_rows() == 3
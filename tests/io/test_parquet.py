@@ -16,6 +16,7 @@ from daft.datatype import DataType, TimeUnit
 from daft.expressions import col
 from daft.logical.schema import Schema
 from daft.recordbatch import MicroPartition
+from tests.conftest import get_tests_daft_runner_name
 
 from ..integration.io.conftest import minio_create_bucket
 
@@ -202,7 +203,8 @@ def test_parquet_rows_cross_page_boundaries(tmpdir, minio_io_config, chunk_size)
         after = after.sort(col("_index"))
         if is_arrow:
             if has_dict:
-                # Compare using to_pydict if there is a dictionary column because Daft does not support dictionary columns.
+                # Compare using to_pydict if there is a dictionary column because Daft reads it as a categorical, whose Arrow
+                # dictionary has different index and value types.
                 assert before.to_pydict() == after.to_pydict()
             else:
                 assert before == after.to_arrow()
@@ -218,7 +220,8 @@ def test_parquet_rows_cross_page_boundaries(tmpdir, minio_io_config, chunk_size)
             before_limit_50 = before.take(list(range(min(before.num_rows, 50))))
             before_limit_2050 = before.take(list(range(min(before.num_rows, 2050))))
             if has_dict:
-                # Compare using to_pydict if there is a dictionary column because Daft does not support dictionary columns.
+                # Compare using to_pydict if there is a dictionary column because Daft reads it as a categorical, whose Arrow
+                # dictionary has different index and value types.
                 assert before_limit_50.to_pydict() == after_limit_50.to_pydict()
                 assert before_limit_2050.to_pydict() == after_limit_2050.to_pydict()
             else:
@@ -468,3 +471,77 @@ def test_write_and_read_empty_parquet(tmp_path_factory):
     df.write_parquet(empty_parquet_files, write_mode="overwrite")
 
     assert daft.read_parquet(empty_parquet_files).to_pydict() == {"a": []}
+
+
+@pytest.mark.parametrize("max_row_group_concurrency", [None, "1", "2"])
+def test_parquet_read_many_row_groups(tmp_path, monkeypatch, max_row_group_concurrency):
+    if max_row_group_concurrency is not None:
+        monkeypatch.setenv("DAFT_PARQUET_MAX_ROW_GROUP_CONCURRENCY", max_row_group_concurrency)
+    # Low cardinality columns are dictionary encoded with the same dictionary in every row group.
+    table = pa.table(
+        {
+            "id": pa.array(range(10_000), type=pa.int64()),
+            "category": pa.array([f"category-{i % 7}" for i in range(10_000)]),
+            "value": pa.array([float(i % 13) for i in range(10_000)]),
+        }
+    )
+    path = str(tmp_path / "file.parquet")
+    papq.write_table(table, path, row_group_size=500, compression="zstd")
+    assert papq.ParquetFile(path).num_row_groups == 20
+
+    assert daft.read_parquet(path).to_pydict() == table.to_pydict()
+    assert daft.read_parquet(path).limit(1234).to_pydict() == table.slice(0, 1234).to_pydict()
+
+
+@pytest.mark.parametrize(
+    "predicate",
+    [
+        # Rows of every row group are selected, which statistics can't prune.
+        lambda row: row["value"] == 3.0,
+        # Only rows of every other row group are selected, so the other columns of half of them are never decoded.
+        lambda row: row["id"] % 1000 >= 990,
+        # No rows are selected.
+        lambda row: row["value"] > 100.0,
+    ],
+)
+# Small pages let the pages of the other columns which hold no selected rows be skipped.
+@pytest.mark.parametrize("small_pages", [False, True])
+def test_parquet_read_with_filter_on_some_columns(tmp_path, predicate, small_pages):
+    table = pa.table(
+        {
+            "id": pa.array(range(10_000), type=pa.int64()),
+            "category": pa.array([None if i % 11 == 0 else f"category-{i % 7}" for i in range(10_000)]),
+            "value": pa.array([float(i % 13) for i in range(10_000)]),
+        }
+    )
+    path = str(tmp_path / "file.parquet")
+    if small_pages:
+        papq.write_table(table, path, row_group_size=500, data_page_size=1, write_batch_size=20)
+    else:
+        papq.write_table(table, path, row_group_size=500)
+
+    rows = [row for row in table.to_pylist() if predicate(row)]
+    expected = {"category": [row["category"] for row in rows], "id": [row["id"] for row in rows]}
+    df = daft.read_parquet(path).where(predicate({"id": col("id"), "value": col("value")})).select("category", "id")
+    assert df.to_pydict() == expected
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Explain analyze is only written by the native runner"
+)
+def test_parquet_read_prunes_row_groups_with_page_index(tmp_path, monkeypatch):
+    # The statistics of the row group span 0 to 29, but its two pages only hold 0 to 9 and 20 to 29.
+    table = pa.table(
+        {"x": pa.array([*range(10), *range(20, 30)], type=pa.int64()), "y": [str(i) for i in range(20)]}
+    )
+    path = str(tmp_path / "file.parquet")
+    papq.write_table(table, path, write_page_index=True, data_page_size=1, write_batch_size=10, use_dictionary=False)
+
+    assert daft.read_parquet(path).where(col("x") == 25).to_pydict() == {"x": [25], "y": ["15"]}
+
+    monkeypatch.setenv("DAFT_DEV_ENABLE_EXPLAIN_ANALYZE", "1")
+    monkeypatch.chdir(tmp_path)
+    assert daft.read_parquet(path).where(col("x") == 15).to_pydict() == {"x": [], "y": []}
+    [report] = tmp_path.glob("explain-analyze-*-mermaid.md")
+    assert "file.parquet row group 0 page 0: skipped by page index" in report.read_text()
+    assert "file.parquet row group 0 page 1: skipped by page index" in report.read_text()
@@ -0,0 +1,111 @@
+from __future__ import annotations
+
+import json
+import threading
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft.io import _manifest
+from daft.io._manifest import MANIFEST_DIR_NAME, commit_files, committed_files
+
+
+def test_read_only_committed_files(tmp_path):
+    daft.from_pydict({"x": [1, 2]}).write_parquet(tmp_path, manifest=True)
+    daft.from_pydict({"x": [3]}).write_parquet(tmp_path, manifest=True)
+    # A file of a write which is still running, or failed, isn't in the manifest.
+    papq.write_table(pa.table({"x": [100]}), tmp_path / "uncommitted.parquet")
+
+    df = daft.read_parquet(str(tmp_path), manifest=True)
+    assert sorted(df.to_pydict()["x"]) == [1, 2, 3]
+
+    # The manifest directory isn't read when the directory is listed.
+    assert sorted(daft.read_parquet(str(tmp_path)).to_pydict()["x"]) == [1, 2, 3, 100]
+
+
+def test_read_partitioned_committed_files(tmp_path):
+    df = daft.from_pydict({"x": [1, 2, 3], "part": ["a", "b", "b"]})
+    written = df.write_parquet(tmp_path, partition_cols=["part"], manifest=True).to_pydict()["path"]
+
+    files = committed_files(str(tmp_path), io_config=None)
+    assert sorted(files) == sorted(f"{tmp_path}/part={path.split('part=')[1]}" for path in written)
+    read = daft.read_parquet(str(tmp_path), manifest=True, hive_partitioning=True)
+    assert sorted(read.to_pydict()["x"]) == [1, 2, 3]
+
+
+def test_overwrite_replaces_manifest(tmp_path):
+    daft.from_pydict({"x": [1, 2]}).write_parquet(tmp_path, manifest=True)
+    daft.from_pydict({"x": [3]}).write_parquet(tmp_path, write_mode="overwrite", manifest=True)
+
+    assert daft.read_parquet(str(tmp_path), manifest=True).to_pydict() == {"x": [3]}
+    assert len(list((tmp_path / MANIFEST_DIR_NAME).iterdir())) == 1
+
+
+def test_overwrite_commits_before_removing_files(tmp_path):
+    daft.from_pydict({"x": [1, 2]}).write_parquet(tmp_path, manifest=True)
+    daft.from_pydict({"x": [3]}).write_parquet(tmp_path, manifest=True)
+    commits = sorted((tmp_path / MANIFEST_DIR_NAME).iterdir())
+    daft.from_pydict({"x": [4]}).write_parquet(tmp_path, write_mode="overwrite", manifest=True)
+
+    # Reinstating the overwritten commits, as if the overwrite failed before removing them, doesn't change what's read.
+    for commit in commits:
+        commit.write_text(json.dumps({"version": 1, "files": ["missing.parquet"]}))
+    assert daft.read_parquet(str(tmp_path), manifest=True).to_pydict() == {"x": [4]}
+
+
+def test_failed_overwrite_keeps_previous_snapshot(tmp_path, monkeypatch):
+    daft.from_pydict({"x": [1, 2]}).write_parquet(tmp_path, manifest=True)
+
+    def fail(*args, **kwargs):
+        raise OSError("failed to commit")
+
+    # The overwrite fails after its files are written, before they're committed.
+    monkeypatch.setattr(_manifest, "commit_files", fail)
+    with pytest.raises(OSError, match="failed to commit"):
+        daft.from_pydict({"x": [3]}).write_parquet(tmp_path, write_mode="overwrite", manifest=True)
+    monkeypatch.undo()
+    assert sorted(daft.read_parquet(str(tmp_path), manifest=True).to_pydict()["x"]) == [1, 2]
+
+    # The overwrite fails after it's committed, while removing the overwritten files.
+    monkeypatch.setattr(_manifest, "remove_overwritten_files", fail)
+    with pytest.raises(OSError, match="failed to commit"):
+        daft.from_pydict({"x": [4]}).write_parquet(tmp_path, write_mode="overwrite", manifest=True)
+    monkeypatch.undo()
+    assert daft.read_parquet(str(tmp_path), manifest=True).to_pydict() == {"x": [4]}
+
+    # The next overwrite removes the files which were left behind.
+    daft.from_pydict({"x": [5]}).write_parquet(tmp_path, write_mode="overwrite", manifest=True)
+    assert daft.read_parquet(str(tmp_path)).to_pydict() == {"x": [5]}
+    assert len(list((tmp_path / MANIFEST_DIR_NAME).iterdir())) == 1
+
+
+def test_concurrent_commits(tmp_path):
+    def commit(i):
+        commit_files(tmp_path, [f"{tmp_path}/file-{i}.parquet"], num_partition_cols=0, io_config=None)
+
+    threads = [threading.Thread(target=commit, args=(i,)) for i in range(8)]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+
+    files = committed_files(str(tmp_path), io_config=None)
+    assert sorted(files) == sorted(f"{tmp_path}/file-{i}.parquet" for i in range(8))
+    # Commit files are moved into place, so no temporary files are left behind.
+    assert all(path.suffix == ".json" for path in (tmp_path / MANIFEST_DIR_NAME).iterdir())
+
+
+def test_read_directory_without_manifest(tmp_path):
+    daft.from_pydict({"x": [1]}).write_parquet(tmp_path)
+
+    with pytest.raises(FileNotFoundError, match=MANIFEST_DIR_NAME):
+        daft.read_parquet(str(tmp_path), manifest=True)
+
+
+def test_manifest_with_overwrite_partitions(tmp_path):
+    df = daft.from_pydict({"x": [1], "part": ["a"]})
+
+    with pytest.raises(ValueError, match="manifest"):
+        df.write_parquet(tmp_path, write_mode="overwrite-partitions", partition_cols=["part"], manifest=True)
@@ -71,6 +71,19 @@ def test_roundtrip_simple_arrow_types(tmp_path, data, pa_type, expected_dtype):
     assert before.to_arrow() == after.to_arrow()
 
 
+def test_binary_is_read_and_written_as_large_binary(tmp_path):
+    # Binary columns have 64-bit offsets, so they aren't limited to 2GB of data per array.
+    papq.write_table(pa.table({"foo": pa.array([b"a", None, b"bc"], type=pa.binary())}), tmp_path / "in.parquet")
+    df = daft.read_parquet(str(tmp_path / "in.parquet"))
+    assert df.schema()["foo"].dtype == DataType.binary()
+    assert df.to_arrow().schema.field("foo").type == pa.large_binary()
+
+    df.write_parquet(str(tmp_path / "out"))
+    written = papq.read_table(str(tmp_path / "out"))
+    assert written.schema.field("foo").type == pa.large_binary()
+    assert written.column("foo").to_pylist() == [b"a", None, b"bc"]
+
+
 @pytest.mark.parametrize(
     ["data", "pa_type", "expected_dtype"],
     [
@@ -0,0 +1,116 @@
+from __future__ import annotations
+
+import struct
+
+import numpy as np
+import pytest
+
+import daft
+from daft import DataType
+from daft.io._point_cloud import _lzf_decompress
+
+XYZ = np.array([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]], dtype=np.float32)
+INTENSITY = np.array([10.0, 20.0, 30.0], dtype=np.float32)
+
+
+def pcd_header(fields: str, sizes: str, types: str, encoding: str) -> bytes:
+    return (
+        "# .PCD v0.7 - Point Cloud Data file format\n"
+        "VERSION 0.7\n"
+        f"FIELDS {fields}\n"
+        f"SIZE {sizes}\n"
+        f"TYPE {types}\n"
+        f"COUNT {' '.join('1' for _ in fields.split())}\n"
+        f"WIDTH {len(XYZ)}\n"
+        "HEIGHT 1\n"
+        "VIEWPOINT 0 0 0 1 0 0 0\n"
+        f"POINTS {len(XYZ)}\n"
+        f"DATA {encoding}\n"
+    ).encode()
+
+
+def lzf_literals(data: bytes) -> bytes:
+    """Compresses data with LZF using only literal runs, which is valid if not actually smaller."""
+    out = b""
+    for i in range(0, len(data), 32):
+        chunk = data[i : i + 32]
+        out += bytes([len(chunk) - 1]) + chunk
+    return out
+
+
+def write_pcd(path, encoding: str) -> None:
+    header = pcd_header("x y z intensity", "4 4 4 4", "F F F F", encoding)
+    if encoding == "ascii":
+        body = "".join(f"{x} {y} {z} {i}\n" for (x, y, z), i in zip(XYZ, INTENSITY)).encode()
+    elif encoding == "binary":
+        body = np.concatenate([XYZ, INTENSITY[:, None]], axis=1).tobytes()
+    else:
+        raw = np.concatenate([XYZ[:, 0], XYZ[:, 1], XYZ[:, 2], INTENSITY]).tobytes()
+        compressed = lzf_literals(raw)
+        body = struct.pack("<II", len(compressed), len(raw)) + compressed
+    path.write_bytes(header + body)
+
+
+@pytest.mark.parametrize("encoding", ["ascii", "binary", "binary_compressed"])
+def test_read_pcd(tmp_path, encoding):
+    write_pcd(tmp_path / "scan.pcd", encoding)
+
+    df = daft.read_pcd(str(tmp_path / "scan.pcd"))
+    assert df.column_names == ["path", "points"]
+    points = df.to_pydict()["points"][0]
+    assert [(p["x"], p["y"], p["z"]) for p in points] == [tuple(row) for row in XYZ.tolist()]
+    assert [p["intensity"] for p in points] == INTENSITY.tolist()
+
+
+def test_read_pcd_tensor_without_intensity(tmp_path):
+    header = pcd_header("x y z", "8 8 8", "F F F", "binary")
+    (tmp_path / "scan.pcd").write_bytes(header + XYZ.astype(np.float64).tobytes())
+
+    df = daft.read_pcd(str(tmp_path / "scan.pcd"), layout="tensor")
+    assert df.schema()["points"].dtype == DataType.tensor(DataType.float64())
+    tensor = df.to_pydict()["points"][0]
+    assert tensor.shape == (3, 4)
+    np.testing.assert_array_equal(tensor[:, :3], XYZ)
+    assert np.isnan(tensor[:, 3]).all()
+
+
+def test_read_pcd_missing_coordinates(tmp_path):
+    header = pcd_header("x y", "4 4", "F F", "binary")
+    (tmp_path / "scan.pcd").write_bytes(header + XYZ[:, :2].tobytes())
+
+    with pytest.raises(Exception, match="missing the fields"):
+        daft.read_pcd(str(tmp_path / "scan.pcd")).collect()
+
+
+def test_lzf_decompress_back_references():
+    # A literal run of "abc", then a back-reference to it of 6 bytes, which overlaps the bytes it writes.
+    assert _lzf_decompress(bytes([2]) + b"abc" + bytes([0x80, 2]), 9) == b"abcabcabc"
+    # A back-reference of 12 bytes, whose length takes an extra byte.
+    assert _lzf_decompress(bytes([2]) + b"abc" + bytes([0xE0, 3, 2]), 15) == b"abc" * 5
+    with pytest.raises(ValueError, match="expected 10 bytes"):
+        _lzf_decompress(bytes([2]) + b"abc" + bytes([0x80, 2]), 10)
+
+
+def test_read_las(tmp_path):
+    laspy = pytest.importorskip("laspy")
+
+    header = laspy.LasHeader(point_format=3, version="1.2")
+    header.scales = np.array([0.01, 0.01, 0.01])
+    header.offsets = np.array([1000.0, 2000.0, 0.0])
+    las = laspy.LasData(header)
+    las.x = XYZ[:, 0] + 1000.0
+    las.y = XYZ[:, 1] + 2000.0
+    las.z = XYZ[:, 2]
+    las.intensity = INTENSITY.astype(np.uint16)
+    las.write(tmp_path / "tile.las")
+
+    points = daft.read_las(str(tmp_path / "*.las")).to_pydict()["points"][0]
+    np.testing.assert_allclose([p["x"] for p in points], XYZ[:, 0] + 1000.0)
+    np.testing.assert_allclose([p["y"] for p in points], XYZ[:, 1] + 2000.0)
+    np.testing.assert_allclose([p["z"] for p in points], XYZ[:, 2])
+    assert [p["intensity"] for p in points] == INTENSITY.tolist()
+
+
+def test_read_point_cloud_invalid_layout(tmp_path):
+    with pytest.raises(ValueError, match="layout"):
+        daft.read_pcd(str(tmp_path / "*.pcd"), layout="voxels")
@@ -1,12 +1,13 @@
 from __future__ import annotations
 
+import uuid
 from collections.abc import Iterator
 
 import pytest
 import ray
 
 import daft
-from daft.io.sink import DataSink, WriteResult
+from daft.io.sink import DataSink, DataSinkWriter, WriteResult
 from daft.recordbatch import MicroPartition
 
 
@@ -45,3 +46,74 @@ def test_sink_raises_unserializable_exception():
     e = exc_info.value
     assert isinstance(e, (RuntimeError, ray.exceptions.RayTaskError))
     assert "UnserializableException" in str(e)
+
+
+class PartitionFileWriter(DataSinkWriter[str]):
+    """Stages the rows of a partition in a file, which is renamed into place when the partition is committed."""
+
+    def __init__(self, directory, fail: bool):
+        self.staged = directory / f"{uuid.uuid4()}.staged"
+        self.fail = fail
+        self.rows = 0
+
+    def write(self, micropartition: MicroPartition) -> None:
+        if self.fail:
+            raise ValueError("Failed to write partition")
+        with open(self.staged, "a") as f:
+            for id in micropartition.to_pydict()["id"]:
+                f.write(f"{id}\n")
+        self.rows += len(micropartition)
+
+    def commit(self) -> WriteResult[str]:
+        path = self.staged.with_suffix(".txt")
+        self.staged.rename(path)
+        return WriteResult(result=str(path), bytes_written=path.stat().st_size, rows_written=self.rows)
+
+    def abort(self) -> None:
+        (self.staged.parent / f"{self.staged.stem}.aborted").touch()
+
+
+class PartitionFileSink(DataSink[str]):
+    def __init__(self, directory, fail: bool = False):
+        self.directory = directory
+        self.fail = fail
+        self.aborted = False
+
+    def schema(self) -> daft.Schema:
+        return daft.Schema.from_pydict({"path": daft.DataType.string(), "rows": daft.DataType.int64()})
+
+    def writer(self) -> DataSinkWriter[str]:
+        return PartitionFileWriter(self.directory, self.fail)
+
+    def finalize(self, write_results: list[WriteResult[str]]) -> MicroPartition:
+        return MicroPartition.from_pydict(
+            {
+                "path": [result.result for result in write_results],
+                "rows": [result.rows_written for result in write_results],
+            }
+        )
+
+    def abort(self) -> None:
+        self.aborted = True
+
+
+def test_sink_writer_commits_partitions(tmp_path):
+    df = daft.from_pydict({"id": list(range(100))}).into_partitions(4)
+    result = df.write_sink(PartitionFileSink(tmp_path)).to_pydict()
+
+    assert sum(result["rows"]) == 100
+    assert sorted(str(path) for path in tmp_path.glob("*.txt")) == sorted(result["path"])
+    ids = [int(line) for path in result["path"] for line in open(path)]
+    assert sorted(ids) == list(range(100))
+    assert not list(tmp_path.glob("*.staged"))
+
+
+def test_sink_writer_aborts_failed_write(tmp_path):
+    df = daft.from_pydict({"id": list(range(100))}).into_partitions(4)
+    sink = PartitionFileSink(tmp_path, fail=True)
+    with pytest.raises(Exception, match="Failed to write partition"):
+        df.write_sink(sink)
+
+    assert sink.aborted
+    assert list(tmp_path.glob("*.aborted"))
+    assert not list(tmp_path.glob("*.txt"))
@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import gzip
+import struct
+
+import pytest
+
+import daft
+
+
+def _varint(value: int) -> bytes:
+    value &= (1 << 64) - 1
+    out = bytearray()
+    while True:
+        b = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(b | 0x80)
+        else:
+            out.append(b)
+            return bytes(out)
+
+
+def _field(number: int, data: bytes) -> bytes:
+    return _varint(number << 3 | 2) + _varint(len(data)) + data
+
+
+def make_example(features: dict[str, list[bytes] | list[float] | list[int]]) -> bytes:
+    """Serializes a `tf.train.Example` without depending on TensorFlow or protobuf."""
+    entries = b""
+    for name, values in features.items():
+        if isinstance(values[0], bytes):
+            feature = _field(1, b"".join(_field(1, v) for v in values))
+        elif isinstance(values[0], float):
+            feature = _field(2, _field(1, struct.pack(f"<{len(values)}f", *values)))
+        else:
+            feature = _field(3, _field(1, b"".join(_varint(v) for v in values)))
+        entries += _field(1, _field(1, name.encode()) + _field(2, feature))
+    return _field(1, entries)
+
+
+def write_tfrecord(path, records: list[bytes], compress: bool = False) -> None:
+    # CRCs aren't verified by the reader, so they are left as zeros.
+    data = b"".join(struct.pack("<Q", len(r)) + b"\0" * 4 + r + b"\0" * 4 for r in records)
+    path.write_bytes(gzip.compress(data) if compress else data)
+
+
+EXAMPLES = [
+    make_example({"image": [b"jpeg 1"], "label": [3], "bbox": [0.5, 0.25]}),
+    make_example({"image": [b"jpeg 2"], "label": [-1]}),
+]
+
+
+@pytest.mark.parametrize("filename,compress", [("data.tfrecord", False), ("data.tfrecord.gz", True)])
+def test_read_tfrecord(tmp_path, filename, compress):
+    write_tfrecord(tmp_path / filename, EXAMPLES, compress=compress)
+
+    df = daft.read_tfrecord(str(tmp_path / filename))
+    assert df.schema()["image"].dtype == daft.DataType.list(daft.DataType.binary())
+    assert df.schema()["label"].dtype == daft.DataType.list(daft.DataType.int64())
+    assert df.schema()["bbox"].dtype == daft.DataType.list(daft.DataType.float32())
+    assert df.to_pydict() == {
+        "image": [[b"jpeg 1"], [b"jpeg 2"]],
+        "label": [[3], [-1]],
+        "bbox": [[0.5, 0.25], None],
+    }
+
+
+def test_read_tfrecord_raw(tmp_path):
+    write_tfrecord(tmp_path / "data.tfrecord", [b"hello", b"", b"world"])
+
+    df = daft.read_tfrecord(str(tmp_path / "data.tfrecord"), parse_examples=False)
+    assert df.to_pydict() == {"record": [b"hello", b"", b"world"]}
+
+
+def test_read_tfrecord_glob(tmp_path):
+    write_tfrecord(tmp_path / "a.tfrecord", EXAMPLES)
+    write_tfrecord(tmp_path / "b.tfrecord", EXAMPLES)
+
+    df = daft.read_tfrecord(str(tmp_path / "*.tfrecord"))
+    assert df.count_rows() == 4
+
+
+def test_read_tfrecord_truncated(tmp_path):
+    (tmp_path / "data.tfrecord").write_bytes(struct.pack("<Q", 100) + b"\0" * 4 + b"short")
+
+    with pytest.raises(Exception, match="Truncated TFRecord file"):
+        daft.read_tfrecord(str(tmp_path / "data.tfrecord"), parse_examples=False).collect()
@@ -0,0 +1,121 @@
+from __future__ import annotations
+
+import datetime
+import os
+import time
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft.io._manifest import MANIFEST_DIR_NAME
+
+DAY = 24 * 60 * 60
+
+
+def _write_old_file(path, age=2 * DAY):
+    path.parent.mkdir(parents=True, exist_ok=True)
+    papq.write_table(pa.table({"x": [100]}), path)
+    modified = time.time() - age
+    os.utime(path, (modified, modified))
+
+
+def _age_files(root, age=2 * DAY):
+    modified = time.time() - age
+    for path in root.rglob("*"):
+        if path.is_file():
+            os.utime(path, (modified, modified))
+
+
+def test_vacuum_staging_directory(tmp_path):
+    daft.from_pydict({"x": [1, 2]}).write_parquet(tmp_path)
+    _age_files(tmp_path)
+    leftover = tmp_path / "_daft_staging" / "query-1" / "task_0_attempt_0" / "a.parquet"
+    _write_old_file(leftover)
+    recent = tmp_path / "_daft_staging" / "query-2" / "b.parquet"
+    _write_old_file(recent, age=0)
+
+    candidates = daft.io.vacuum(str(tmp_path), retention=datetime.timedelta(days=1)).to_pydict()
+    assert candidates["path"] == [str(leftover)]
+    assert candidates["size"] == [leftover.stat().st_size]
+    # A dry run doesn't remove anything.
+    assert leftover.exists()
+
+    removed = daft.io.vacuum(str(tmp_path), retention=datetime.timedelta(days=1), dry_run=False)
+    assert removed.to_pydict()["path"] == [str(leftover)]
+    assert not leftover.exists()
+    assert recent.exists()
+    assert sorted(daft.read_parquet(str(tmp_path)).to_pydict()["x"]) == [1, 2]
+
+
+def test_vacuum_manifest_directory(tmp_path):
+    daft.from_pydict({"x": [1, 2]}).write_parquet(tmp_path, manifest=True)
+    _age_files(tmp_path)
+    uncommitted = tmp_path / "uncommitted.parquet"
+    _write_old_file(uncommitted)
+    temp_commit = tmp_path / MANIFEST_DIR_NAME / ".commit.json.tmp"
+    temp_commit.write_text("{}")
+    _age_files(tmp_path / MANIFEST_DIR_NAME)
+
+    removed = daft.io.vacuum(str(tmp_path), retention=datetime.timedelta(days=1), dry_run=False).to_pydict()
+
+    assert sorted(removed["path"]) == sorted([str(uncommitted), str(temp_commit)])
+    assert sorted(daft.read_parquet(str(tmp_path), manifest=True).to_pydict()["x"]) == [1, 2]
+    assert sorted(daft.read_parquet(str(tmp_path)).to_pydict()["x"]) == [1, 2]
+
+
+def test_vacuum_keeps_files_of_plain_directories(tmp_path):
+    # Without a manifest, there's no telling which of a directory's files are referenced.
+    _write_old_file(tmp_path / "a.parquet")
+
+    assert daft.io.vacuum(str(tmp_path), retention=datetime.timedelta(0)).to_pydict() == {
+        "path": [],
+        "size": [],
+        "last_modified": [],
+    }
+
+
+def test_vacuum_invalid_retention(tmp_path):
+    with pytest.raises(ValueError, match="non-negative"):
+        daft.io.vacuum(str(tmp_path), retention=datetime.timedelta(days=-1))
+
+
+def test_vacuum_deltalake(tmp_path):
+    deltalake = pytest.importorskip("deltalake")
+
+    daft.from_pydict({"x": [1, 2]}).write_deltalake(str(tmp_path))
+    daft.from_pydict({"x": [3]}).write_deltalake(str(tmp_path), mode="overwrite")
+    removed_by_overwrite = deltalake.DeltaTable(str(tmp_path)).vacuum(
+        retention_hours=0, dry_run=True, enforce_retention_duration=False
+    )
+
+    candidates = daft.io.vacuum(str(tmp_path), retention=datetime.timedelta(0)).to_pydict()
+    assert sorted(os.path.basename(path) for path in candidates["path"]) == sorted(
+        os.path.basename(path) for path in removed_by_overwrite
+    )
+
+    daft.io.vacuum(deltalake.DeltaTable(str(tmp_path)), retention=datetime.timedelta(0), dry_run=False)
+    assert all(not (tmp_path / path).exists() for path in removed_by_overwrite)
+    assert daft.read_deltalake(str(tmp_path)).to_pydict() == {"x": [3]}
+
+
+def test_vacuum_iceberg(tmp_path):
+    pytest.importorskip("pyiceberg")
+    from pyiceberg.catalog.sql import SqlCatalog
+
+    catalog = SqlCatalog("default", uri=f"sqlite:///{tmp_path}/catalog.db", warehouse=f"file://{tmp_path}")
+    catalog.create_namespace("default")
+    table = catalog.create_table("default.vacuum", pa.schema([("x", pa.int64())]))
+    daft.from_pydict({"x": [1, 2]}).write_iceberg(table)
+    table = catalog.load_table("default.vacuum")
+
+    data_dir = tmp_path / "default.db" / "vacuum" / "data"
+    _age_files(data_dir)
+    orphan = data_dir / "orphan.parquet"
+    _write_old_file(orphan)
+
+    removed = daft.io.vacuum(table, retention=datetime.timedelta(days=1), dry_run=False).to_pydict()
+
+    assert removed["path"] == [str(orphan)]
+    assert sorted(daft.read_iceberg(table).to_pydict()["x"]) == [1, 2]
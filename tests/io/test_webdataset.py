@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import io
+import tarfile
+
+import pytest
+
+import daft
+
+
+def make_shard(path, samples: dict[str, dict[str, bytes]], mode: str = "w") -> None:
+    with tarfile.open(path, mode=mode) as tf:
+        for key, fields in samples.items():
+            for field, data in fields.items():
+                info = tarfile.TarInfo(f"{key}.{field}")
+                info.size = len(data)
+                tf.addfile(info, io.BytesIO(data))
+
+
+SAMPLES = {
+    "images/0001": {"jpg": b"jpeg 1", "cls": b"3", "json": b'{"a": 1}'},
+    "images/0002": {"jpg": b"jpeg 2", "cls": b"5"},
+    "images/0003": {"jpg": b"jpeg 3", "cls": b"7", "json": b'{"a": 3}'},
+}
+
+
+@pytest.mark.parametrize("filename,mode", [("shard.tar", "w"), ("shard.tar.gz", "w:gz")])
+def test_read_webdataset(tmp_path, filename, mode):
+    make_shard(tmp_path / filename, SAMPLES, mode=mode)
+
+    df = daft.read_webdataset(str(tmp_path / filename))
+    assert df.column_names == ["__key__", "__url__", "jpg", "cls", "json"]
+
+    result = df.sort("__key__").to_pydict()
+    assert result["__key__"] == ["images/0001", "images/0002", "images/0003"]
+    assert all(url.endswith(filename) for url in result["__url__"])
+    assert result["jpg"] == [b"jpeg 1", b"jpeg 2", b"jpeg 3"]
+    assert result["cls"] == [b"3", b"5", b"7"]
+    assert result["json"] == [b'{"a": 1}', None, b'{"a": 3}']
+
+
+def test_read_webdataset_multipart_extension(tmp_path):
+    make_shard(tmp_path / "shard.tar", {"0001": {"seg.png": b"png", "txt": b"hello"}, "._0001": {"txt": b""}})
+
+    result = daft.read_webdataset(str(tmp_path / "shard.tar")).to_pydict()
+    assert result["__key__"] == ["0001"]
+    assert result["seg.png"] == [b"png"]
+    assert result["txt"] == [b"hello"]
+
+
+def test_read_webdataset_fields(tmp_path):
+    make_shard(tmp_path / "a.tar", dict(list(SAMPLES.items())[:2]))
+    make_shard(tmp_path / "b.tar", dict(list(SAMPLES.items())[2:]))
+
+    df = daft.read_webdataset(str(tmp_path / "*.tar"), fields=["cls", "txt"])
+    assert df.column_names == ["__key__", "__url__", "cls", "txt"]
+    result = df.sort("__key__").to_pydict()
+    assert result["cls"] == [b"3", b"5", b"7"]
+    assert result["txt"] == [None, None, None]
+
+
+def test_read_webdataset_reserved_field(tmp_path):
+    make_shard(tmp_path / "shard.tar", SAMPLES)
+    with pytest.raises(ValueError, match="__key__"):
+        daft.read_webdataset(str(tmp_path / "shard.tar"), fields=["__key__"])
+
+
+def test_read_webdataset_no_shards(tmp_path):
+    with pytest.raises(FileNotFoundError):
+        daft.read_webdataset(str(tmp_path / "*.tar"))
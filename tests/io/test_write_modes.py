@@ -9,6 +9,7 @@ import pytest
 import s3fs
 
 import daft
+from tests.conftest import get_tests_daft_runner_name
 
 
 @pytest.fixture(scope="function")
@@ -160,6 +161,29 @@ def test_append_and_overwrite_local_relative_path(
     )
 
 
+@pytest.mark.skipif(get_tests_daft_runner_name() != "ray", reason="Staged writes only apply to the Ray runner")
+@pytest.mark.parametrize("write_mode", ["append", "overwrite"])
+@pytest.mark.parametrize("format", ["csv", "parquet"])
+@pytest.mark.parametrize("partition_cols", [None, ["id"]])
+def test_staged_writes_local(tmp_path, write_mode, format, partition_cols):
+    with daft.execution_config_ctx(staged_writes=True):
+        _run_append_overwrite_test(
+            path=str(tmp_path),
+            write_mode=write_mode,
+            format=format,
+            num_partitions=10,
+            partition_cols=partition_cols,
+            io_config=None,
+        )
+        written = write(daft.range(30, 45, partitions=3), str(tmp_path), format, "append", partition_cols)
+
+    # The written files are moved out of the staging directory, which is removed once the write is committed.
+    paths = written.to_pydict()["path"]
+    assert len(paths) > 0
+    assert all("_daft_staging" not in path and os.path.exists(path.removeprefix("file://")) for path in paths)
+    assert not (tmp_path / "_daft_staging").exists()
+
+
 @pytest.mark.integration()
 @pytest.mark.parametrize("write_mode", ["append", "overwrite"])
 @pytest.mark.parametrize("format", ["csv", "parquet"])
@@ -0,0 +1,215 @@
+"""Random logical plans over small integer tables, along with a naive oracle interpreter.
+
+Each plan is a list of operations applied in order to a base table. Every operation can be applied both to a Daft
+DataFrame and to a plain Python table (a dict of column name to list of values), so a plan's results on any runner
+can be compared against the oracle's.
+
+All tables have an int64 "key" column, which operations never drop so that it can always be used to join and group on.
+"""
+
+from __future__ import annotations
+
+import dataclasses
+from typing import Any
+
+from hypothesis.strategies import (
+    composite,
+    integers,
+    lists,
+    none,
+    one_of,
+    sampled_from,
+)
+
+import daft
+from daft import DataFrame, col
+
+Table = dict[str, list[Any]]
+
+KEY = "key"
+
+# Keep values small so that keys collide and aggregations cannot overflow.
+_keys = one_of(none(), integers(min_value=0, max_value=4))
+_values = one_of(none(), integers(min_value=-100, max_value=100))
+
+
+@composite
+def tables(draw, value_columns: list[str], max_rows: int = 8) -> Table:
+    """Generate a table with a "key" column and the given nullable int64 value columns."""
+    num_rows = draw(integers(min_value=0, max_value=max_rows), label="Number of rows")
+    table = {KEY: draw(lists(_keys, min_size=num_rows, max_size=num_rows), label="Key column")}
+    for name in value_columns:
+        table[name] = draw(lists(_values, min_size=num_rows, max_size=num_rows), label=f"Column {name}")
+    return table
+
+
+def to_dataframe(table: Table) -> DataFrame:
+    return daft.from_pydict(table).with_columns({name: col(name).cast(daft.DataType.int64()) for name in table})
+
+
+def sorted_rows(table: Table) -> list[tuple]:
+    """Rows of a table in a canonical order, for comparison irrespective of row order."""
+    names = sorted(table)
+    rows = zip(*(table[name] for name in names)) if names else []
+    return sorted(rows, key=lambda row: tuple((value is None, value if value is not None else 0) for value in row))
+
+
+def _take(table: Table, indices: list[int]) -> Table:
+    return {name: [values[i] for i in indices] for name, values in table.items()}
+
+
+def _num_rows(table: Table) -> int:
+    return len(table[KEY])
+
+
+@dataclasses.dataclass(frozen=True)
+class Filter:
+    column: str
+    value: int
+
+    def apply(self, df: DataFrame) -> DataFrame:
+        return df.where(col(self.column) > self.value)
+
+    def oracle(self, table: Table) -> Table:
+        values = table[self.column]
+        return _take(table, [i for i, v in enumerate(values) if v is not None and v > self.value])
+
+
+@dataclasses.dataclass(frozen=True)
+class AddColumn:
+    name: str
+    column: str
+    value: int
+
+    def apply(self, df: DataFrame) -> DataFrame:
+        return df.with_column(self.name, col(self.column) + self.value)
+
+    def oracle(self, table: Table) -> Table:
+        return {**table, self.name: [None if v is None else v + self.value for v in table[self.column]]}
+
+
+@dataclasses.dataclass(frozen=True)
+class DropColumn:
+    column: str
+
+    def apply(self, df: DataFrame) -> DataFrame:
+        return df.exclude(self.column)
+
+    def oracle(self, table: Table) -> Table:
+        return {name: values for name, values in table.items() if name != self.column}
+
+
+@dataclasses.dataclass(frozen=True)
+class Aggregate:
+    name: str
+    column: str
+    agg: str
+
+    def apply(self, df: DataFrame) -> DataFrame:
+        # Counts are unsigned, so cast them back to int64 to keep every column the same type.
+        agg = getattr(col(self.column), self.agg)().cast(daft.DataType.int64())
+        return df.groupby(KEY).agg(agg.alias(self.name))
+
+    def oracle(self, table: Table) -> Table:
+        groups: dict[int | None, list[int | None]] = {}
+        for key, value in zip(table[KEY], table[self.column]):
+            groups.setdefault(key, []).append(value)
+
+        def aggregate(values: list[int | None]) -> int | None:
+            valid = [v for v in values if v is not None]
+            if self.agg == "count":
+                return len(valid)
+            if not valid:
+                return None
+            return {"sum": sum, "min": min, "max": max}[self.agg](valid)
+
+        return {KEY: list(groups), self.name: [aggregate(values) for values in groups.values()]}
+
+
+@dataclasses.dataclass(frozen=True)
+class Join:
+    right: tuple[tuple[int | None, int | None], ...]
+    name: str
+    how: str
+
+    def right_table(self) -> Table:
+        return {KEY: [key for key, _ in self.right], self.name: [value for _, value in self.right]}
+
+    def apply(self, df: DataFrame) -> DataFrame:
+        return df.join(to_dataframe(self.right_table()), on=KEY, how=self.how)
+
+    def oracle(self, table: Table) -> Table:
+        out: Table = {name: [] for name in [*table, self.name]}
+        for i in range(_num_rows(table)):
+            key = table[KEY][i]
+            matches = [value for right_key, value in self.right if key is not None and right_key == key]
+            if not matches and self.how == "left":
+                matches = [None]
+            for value in matches:
+                for name, values in table.items():
+                    out[name].append(values[i])
+                out[self.name].append(value)
+        return out
+
+
+Operation = Filter | AddColumn | DropColumn | Aggregate | Join
+
+
+@dataclasses.dataclass
+class Plan:
+    base: Table
+    operations: list[Operation]
+    num_partitions: int
+
+    def execute(self) -> Table:
+        df = to_dataframe(self.base)
+        if self.num_partitions > 1:
+            df = df.into_partitions(self.num_partitions)
+        for op in self.operations:
+            df = op.apply(df)
+        return df.to_pydict()
+
+    def oracle(self) -> Table:
+        table = self.base
+        for op in self.operations:
+            table = op.oracle(table)
+        return table
+
+
+@composite
+def plans(draw, max_operations: int = 5) -> Plan:
+    """Generate a random plan of filters, projections, joins and aggregations over a random base table."""
+    base = draw(tables(["a", "b"]), label="Base table")
+    columns = ["a", "b"]
+    operations: list[Operation] = []
+
+    num_operations = draw(integers(min_value=0, max_value=max_operations), label="Number of operations")
+    for i in range(num_operations):
+        name = f"c{i}"
+        kinds = ["filter", "add_column", "join"]
+        if columns:
+            kinds += ["drop_column", "aggregate"]
+        kind = draw(sampled_from(kinds), label=f"Operation {i}")
+        any_column = sampled_from([KEY, *columns])
+
+        if kind == "filter":
+            operations.append(Filter(draw(any_column), draw(integers(min_value=-50, max_value=50))))
+        elif kind == "add_column":
+            operations.append(AddColumn(name, draw(any_column), draw(integers(min_value=-10, max_value=10))))
+            columns.append(name)
+        elif kind == "drop_column":
+            column = draw(sampled_from(columns))
+            operations.append(DropColumn(column))
+            columns.remove(column)
+        elif kind == "aggregate":
+            agg = draw(sampled_from(["sum", "count", "min", "max"]), label="Aggregation")
+            operations.append(Aggregate(name, draw(sampled_from(columns)), agg))
+            columns = [name]
+        else:
+            right = draw(tables([name]), label="Right table")
+            how = draw(sampled_from(["inner", "left"]), label="Join type")
+            operations.append(Join(tuple(zip(right[KEY], right[name])), name, how))
+            columns.append(name)
+
+    num_partitions = draw(sampled_from([1, 3]), label="Number of partitions")
+    return Plan(base, operations, num_partitions)
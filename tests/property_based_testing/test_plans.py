@@ -0,0 +1,25 @@
+from __future__ import annotations
+
+import os
+
+from hypothesis import given, note, settings
+
+from tests.property_based_testing.plans import Plan, plans, sorted_rows
+
+
+@settings(max_examples=int(os.getenv("HYPOTHESIS_MAX_EXAMPLES", 100)), deadline=None)
+@given(plan=plans())
+def test_plan_matches_oracle(plan: Plan):
+    """Random plans of filters, projections, joins and aggregations return the same rows as a naive interpreter.
+
+    This runs on whichever runner the test suite is configured with, so running the suite on both the native and
+    Ray runners checks both engines against the same oracle.
+    """
+    for op in plan.operations:
+        note(repr(op))
+
+    result = plan.execute()
+    expected = plan.oracle()
+
+    assert set(result) == set(expected)
+    assert sorted_rows(result) == sorted_rows(expected)
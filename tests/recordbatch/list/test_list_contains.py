@@ -0,0 +1,91 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col, lit
+from daft.datatype import DataType
+from daft.recordbatch import MicroPartition
+
+
+@pytest.fixture
+def table():
+    return MicroPartition.from_pydict(
+        {
+            "col": [None, [], ["a"], ["a", None], [None], ["a", "b"], ["b", "c"]],
+            "values": ["a", "a", None, "a", "a", "b", "a"],
+        }
+    )
+
+
+def test_list_contains_column(table):
+    result = table.eval_expression_list([col("col").list_contains(col("values"))])
+    assert result.to_pydict()["col"] == [None, False, None, True, False, True, False]
+
+
+def test_list_contains_literal(table):
+    result = table.eval_expression_list([col("col").list_contains(lit("a"))])
+    assert result.to_pydict()["col"] == [None, False, True, True, False, True, False]
+
+
+def test_list_contains_literal_list(table):
+    result = table.eval_expression_list([lit(["a", "b"]).list_contains(col("values")).alias("contains")])
+    assert result.to_pydict()["contains"] == [True, True, None, True, True, True, True]
+
+
+def test_list_contains_fixed_size_list():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3, None], None], "values": [2, 2, 2]})
+    fixed_dtype = DataType.fixed_size_list(DataType.int64(), 2)
+    table = table.eval_expression_list([col("col").cast(fixed_dtype), col("values")])
+    result = table.eval_expression_list([col("col").list_contains(col("values"))])
+    assert result.to_pydict()["col"] == [True, False, None]
+
+
+def test_list_contains_numeric_item():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3]]})
+    result = table.eval_expression_list([col("col").list_contains(2.0)])
+    assert result.to_pydict()["col"] == [True, False]
+
+
+def test_list_slice_by_expression():
+    table = MicroPartition.from_pydict({"col": [[1, 2, 3], [4, 5, 6, 7], None], "n": [1, -1, 2]})
+    result = table.eval_expression_list([col("col").list_slice(col("n")).alias("tail")])
+    assert result.to_pydict()["tail"] == [[2, 3], [7], None]
+
+
+def test_list_slice_bounds():
+    table = MicroPartition.from_pydict(
+        {
+            "col": [[1, 2, 3], [4, 5, 6, 7], [8], None, [9, 10]],
+            "start": [0, 1, None, 0, -5],
+            "end": [-1, 10, 1, 1, None],
+        }
+    )
+    result = table.eval_expression_list([col("col").list_slice(col("start"), col("end"))])
+    assert result.to_pydict()["col"] == [[1, 2], [5, 6, 7], None, None, [9, 10]]
+
+    result = table.eval_expression_list([col("col").list_slice(-2)])
+    assert result.to_pydict()["col"] == [[2, 3], [6, 7], [8], None, [9, 10]]
+
+    result = table.eval_expression_list([col("col").list_slice(2, 1)])
+    assert result.to_pydict()["col"] == [[], [], [], None, []]
+
+
+def test_list_slice_literal_list():
+    table = MicroPartition.from_pydict({"n": [0, 1, 2]})
+    result = table.eval_expression_list([lit([1, 2, 3]).list_slice(col("n")).alias("tail")])
+    assert result.to_pydict()["tail"] == [[1, 2, 3], [2, 3], [3]]
+
+
+def test_list_slice_fixed_size_list():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3, None], None]})
+    fixed_dtype = DataType.fixed_size_list(DataType.int64(), 2)
+    table = table.eval_expression_list([col("col").cast(fixed_dtype)])
+    result = table.eval_expression_list([col("col").list_slice(1)])
+    assert result.schema()["col"].dtype == DataType.list(DataType.int64())
+    assert result.to_pydict()["col"] == [[2], [None], None]
+
+
+def test_list_slice_requires_list():
+    table = MicroPartition.from_pydict({"col": [b"abc"]})
+    with pytest.raises(Exception, match="must be a list"):
+        table.eval_expression_list([col("col").list_slice(1)])
@@ -8,7 +8,7 @@ from daft import utils
 from daft.daft import JoinType
 from daft.datatype import DataType
 from daft.expressions import col
-from daft.recordbatch import MicroPartition
+from daft.recordbatch import MicroPartition, RecordBatch
 from daft.series import Series
 
 daft_int_types = [
@@ -352,3 +352,68 @@ def test_table_join_anti_different_names() -> None:
     assert result_table.column_names() == ["x", "y"]
     result_sorted = result_table.sort([col("x")])
     assert result_sorted.get_column_by_name("y").to_pylist() == [3, 6]
+
+
+@pytest.mark.parametrize(
+    "how", [JoinType.Inner, JoinType.Left, JoinType.Right, JoinType.Outer, JoinType.Semi, JoinType.Anti]
+)
+@pytest.mark.parametrize("is_sorted", [True, False])
+def test_table_sort_merge_join_matches_hash_join(how, is_sorted) -> None:
+    left = {"x": [0, 1, 1, 3, 5, None], "y": [0, 1, 2, 3, 4, 5]}
+    right = {"x": [1, 1, 2, 3, 3, None], "z": [10, 11, 12, 13, 14, 15]}
+    if not is_sorted:
+        left = {k: v[::-1] for k, v in left.items()}
+        right = {k: v[::-1] for k, v in right.items()}
+    left_table = MicroPartition.from_pydict(left)
+    right_table = MicroPartition.from_pydict(right)
+
+    def sorted_rows(table: MicroPartition) -> list[tuple]:
+        rows = zip(*table.to_pydict().values())
+        return sorted(rows, key=lambda row: tuple((v is None, v) for v in row))
+
+    smj = left_table.sort_merge_join(right_table, left_on=[col("x")], right_on=[col("x")], how=how, is_sorted=is_sorted)
+    hj = left_table.hash_join(right_table, left_on=[col("x")], right_on=[col("x")], how=how)
+
+    assert smj.column_names() == hj.column_names()
+    assert sorted_rows(smj) == sorted_rows(hj)
+
+
+@pytest.mark.parametrize("how", [JoinType.Inner, JoinType.Left, JoinType.Semi, JoinType.Anti])
+@pytest.mark.parametrize("is_sorted", [True, False])
+def test_table_sorted_lookup_join_matches_hash_join(how, is_sorted) -> None:
+    left = {"x": [5, 1, None, 3, 0, 1], "y": [0, 1, 2, 3, 4, 5]}
+    right = {"x": [1, 1, 2, 3, 3, None], "z": [10, 11, 12, 13, 14, 15]}
+    if not is_sorted:
+        right = {k: v[::-1] for k, v in right.items()}
+    left_table = RecordBatch.from_pydict(left)
+    right_table = RecordBatch.from_pydict(right)
+
+    def sorted_rows(table: RecordBatch) -> list[tuple]:
+        rows = zip(*table.to_pydict().values())
+        return sorted(rows, key=lambda row: tuple((v is None, v) for v in row))
+
+    slj = left_table.sorted_lookup_join(
+        right_table, left_on=[col("x")], right_on=[col("x")], how=how, is_sorted=is_sorted
+    )
+    hj = left_table.hash_join(right_table, left_on=[col("x")], right_on=[col("x")], how=how)
+
+    assert slj.schema().column_names() == hj.schema().column_names()
+    assert sorted_rows(slj) == sorted_rows(hj)
+
+
+def test_table_sorted_lookup_join_multicolumn() -> None:
+    left = RecordBatch.from_pydict({"a": [1, 1, 2, None], "b": ["x", "y", "x", "x"]})
+    right = RecordBatch.from_pydict({"a": [2, 1, 1, 1], "b": ["x", "y", None, "y"], "c": [0, 1, 2, 3]})
+
+    joined = left.sorted_lookup_join(right, left_on=[col("a"), col("b")], right_on=[col("a"), col("b")])
+
+    assert sorted(zip(*joined.to_pydict().values())) == [(1, "y", 1), (1, "y", 3), (2, "x", 0)]
+
+
+@pytest.mark.parametrize("how", [JoinType.Right, JoinType.Outer])
+def test_table_sorted_lookup_join_unsupported_join_type(how) -> None:
+    left = RecordBatch.from_pydict({"x": [1, 2]})
+    right = RecordBatch.from_pydict({"x": [1, 3]})
+
+    with pytest.raises(Exception, match="Sorted lookup join"):
+        left.sorted_lookup_join(right, left_on=[col("x")], right_on=[col("x")], how=how)
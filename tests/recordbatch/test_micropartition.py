@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import copy
+import pickle
 
 import pyarrow as pa
 import pytest
@@ -22,6 +23,22 @@ def test_pickling_loaded(mp) -> None:
     assert mp.to_arrow() == copy.deepcopy(mp).to_arrow()
 
 
+@pytest.mark.parametrize("compression", ["lz4", "zstd"])
+def test_pickling_compressed(compression) -> None:
+    mp = MicroPartition.from_pydict({"a": pa.array([i % 7 for i in range(10_000)], type=pa.int64())})
+    uncompressed = pickle.dumps(mp)
+    compressed = pickle.dumps(mp._compress_when_pickled(compression, 1))
+    assert len(compressed) < len(uncompressed)
+    assert pickle.loads(compressed).to_arrow() == mp.to_arrow()
+
+
+def test_pickling_python_dtype_is_not_compressed() -> None:
+    objs = [object() for _ in range(3)]
+    mp = MicroPartition.from_pydict({"obj": objs})._compress_when_pickled("zstd", 0)
+    assert mp._pickle_compression is None
+    assert copy.deepcopy(mp).to_pydict() == {"obj": objs}
+
+
 def test_pickling_unloaded() -> None:
     mp = MicroPartition.read_parquet("tests/assets/parquet-data/parquet-with-schema-metadata.parquet")
     assert copy.deepcopy(mp).to_arrow() == mp.to_arrow()
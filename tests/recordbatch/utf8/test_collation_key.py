@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.recordbatch import MicroPartition
+
+
+@pytest.mark.parametrize(
+    ["collation", "expected"],
+    [
+        ("binary", ["Émile", None, "Straße", "abc"]),
+        ("case_insensitive", ["émile", None, "straße", "abc"]),
+        ("accent_insensitive", ["emile", None, "straße", "abc"]),
+    ],
+)
+def test_utf8_collation_key(collation, expected):
+    table = MicroPartition.from_pydict({"col": ["Émile", None, "Straße", "abc"]})
+    result = table.eval_expression_list([col("col").collation_key(collation)])
+    assert result.to_pydict() == {"col": expected}
+
+
+def test_utf8_collation_key_invalid():
+    table = MicroPartition.from_pydict({"col": ["a"]})
+    with pytest.raises(Exception, match="Unsupported collation"):
+        table.eval_expression_list([col("col").collation_key("not a locale!")])
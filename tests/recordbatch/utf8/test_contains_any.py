@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.functions import contains_any
+from daft.recordbatch import MicroPartition
+
+test_data = [
+    "the quick brown fox",
+    "THE LAZY DOG",
+    "a lazy cat",
+    None,
+    "",
+]
+
+
+@pytest.mark.parametrize("case_sensitive", [False, True])
+def test_table_contains_any(case_sensitive):
+    patterns = ["fox", "dog", "bird"]
+    table = MicroPartition.from_pydict({"a": test_data})
+    res = table.eval_expression_list([contains_any(col("a"), patterns, case_sensitive=case_sensitive)])
+
+    def expected(s):
+        if s is None:
+            return None
+        if not case_sensitive:
+            s = s.lower()
+        return any(p in s for p in patterns)
+
+    assert res.to_pydict()["a"] == [expected(s) for s in test_data]
+
+
+def test_table_contains_any_single_pattern():
+    table = MicroPartition.from_pydict({"a": test_data})
+    res = table.eval_expression_list([contains_any(col("a"), "lazy")])
+    assert res.to_pydict()["a"] == [False, False, True, None, False]
+
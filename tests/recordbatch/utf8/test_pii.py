@@ -0,0 +1,46 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.recordbatch import MicroPartition
+
+DATA = [
+    "Contact jane.doe@example.com or +1 (555) 123-4567.",
+    "Card 4111-1111-1111-1111, SSN 123-45-6789",
+    "Invalid card 4111 1111 1111 1112 and SSN 666-12-3456",
+    "Nothing to see here",
+    None,
+]
+
+
+def test_detect_pii():
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").detect_pii()])
+    assert result.to_pydict() == {"col": [["email", "phone"], ["credit_card", "ssn"], [], [], None]}
+
+
+def test_detect_pii_kinds():
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").detect_pii(["email", "ssn"])])
+    assert result.to_pydict() == {"col": [["email"], ["ssn"], [], [], None]}
+
+
+@pytest.mark.parametrize(
+    ["strategy", "expected"],
+    [
+        ("tag", ["Contact [EMAIL] or [PHONE].", "Card [CREDIT_CARD], SSN [SSN]"]),
+        ("mask", ["Contact ****.***@*******.*** or +* (***) ***-****.", "Card ****-****-****-****, SSN ***-**-****"]),
+        ("remove", ["Contact  or .", "Card , SSN "]),
+    ],
+)
+def test_redact_pii(strategy, expected):
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").redact_pii(strategy=strategy)])
+    assert result.to_pydict() == {"col": [*expected, DATA[2], DATA[3], None]}
+
+
+def test_pii_invalid_kind():
+    table = MicroPartition.from_pydict({"col": DATA})
+    with pytest.raises(Exception, match="Unsupported PII kind"):
+        table.eval_expression_list([col("col").detect_pii("passport")])
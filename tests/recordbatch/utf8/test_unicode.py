@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import unicodedata
+
+import pytest
+
+from daft.expressions import col
+from daft.recordbatch import MicroPartition
+
+DATA = ["café", "Ｆｕｌｌ ﬁne", "Crème brûlée", "Straße", None, "👍🏽!", "東京"]
+
+
+@pytest.mark.parametrize("form", ["NFC", "NFD", "NFKC", "NFKD"])
+def test_utf8_normalize_form(form):
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").normalize(form=form)])
+    assert result.to_pydict() == {"col": [None if s is None else unicodedata.normalize(form, s) for s in DATA]}
+
+
+def test_utf8_normalize_invalid_form():
+    table = MicroPartition.from_pydict({"col": DATA})
+    with pytest.raises(Exception, match="normalization form"):
+        table.eval_expression_list([col("col").normalize(form="NFX")])
+
+
+def test_utf8_strip_accents():
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").strip_accents()])
+    assert result.to_pydict() == {"col": ["cafe", "Ｆｕｌｌ ﬁne", "Creme brulee", "Straße", None, "👍🏽!", "東京"]}
+
+
+def test_utf8_transliterate_to_ascii():
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").transliterate_to_ascii()])
+    assert result.to_pydict() == {"col": ["cafe", "Full fine", "Creme brulee", "Strasse", None, "??!", "??"]}
+
+    result = table.eval_expression_list([col("col").transliterate_to_ascii(replacement="")])
+    assert result.to_pydict()["col"][-1] == ""
+
+
+def test_utf8_length_graphemes():
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").length_graphemes()])
+    assert result.to_pydict() == {"col": [4, 8, 12, 6, None, 2, 2]}
+
+
+@pytest.mark.parametrize(
+    ["start", "length", "expected"],
+    [
+        (3, 1, ["é", "ｌ", "m", "a", None, "", ""]),
+        (1, None, ["afé", "ｕｌｌ ﬁne", "rème brûlée", "traße", None, "!", "京"]),
+        (10, 2, ["", "", "ée", "", None, "", ""]),
+    ],
+)
+def test_utf8_substr_graphemes(start, length, expected):
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").substr_graphemes(start, length)])
+    assert result.to_pydict() == {"col": expected}
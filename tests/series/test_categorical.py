@@ -0,0 +1,75 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+
+import daft
+from daft import DataType, Series, col
+
+
+def test_categorical_cast_roundtrip() -> None:
+    strings = Series.from_pylist(["b", None, "a", "b"])
+    categories = strings.cast(DataType.categorical())
+
+    assert categories.datatype() == DataType.categorical()
+    assert categories.to_pylist() == ["b", None, "a", "b"]
+    assert categories.cast(DataType.string()).to_pylist() == ["b", None, "a", "b"]
+
+
+def test_categorical_to_arrow_only_holds_own_categories() -> None:
+    # Categories seen elsewhere in the process don't leak into the exported dictionary.
+    Series.from_pylist(["unrelated"]).cast(DataType.categorical())
+    categories = Series.from_pylist(["x", "y", "x", None]).cast(DataType.categorical())
+
+    arrow = categories.to_arrow()
+    assert pa.types.is_dictionary(arrow.type)
+    assert arrow.dictionary.to_pylist() == ["x", "y"]
+    assert arrow.to_pylist() == ["x", "y", "x", None]
+
+
+def test_categorical_from_arrow_dictionary() -> None:
+    arrow = pa.array(["a", "b", "a", None]).dictionary_encode()
+    categories = Series.from_arrow(arrow)
+
+    assert categories.datatype() == DataType.categorical()
+    assert categories.to_pylist() == ["a", "b", "a", None]
+
+
+def test_categorical_concat_merges_dictionaries() -> None:
+    left = Series.from_arrow(pa.array(["a", "b"]).dictionary_encode())
+    right = Series.from_arrow(pa.array(["c", "a"]).dictionary_encode())
+
+    concatenated = Series.concat([left, right])
+    assert concatenated.datatype() == DataType.categorical()
+    assert concatenated.to_pylist() == ["a", "b", "c", "a"]
+
+
+def test_categorical_sorts_and_compares_as_strings() -> None:
+    # Codes are assigned in the order strings are first seen, which isn't their sort order.
+    categories = Series.from_pylist(["zebra", "apple", None, "mango"]).cast(DataType.categorical())
+
+    assert categories.sort().to_pylist() == ["apple", "mango", "zebra", None]
+    assert (categories < Series.from_pylist(["b"])).to_pylist() == [False, True, None, False]
+    assert (categories == Series.from_pylist(["apple"])).to_pylist() == [False, True, None, False]
+
+
+def test_categorical_hash_matches_strings() -> None:
+    strings = Series.from_pylist(["a", None, "b", "a"])
+    assert strings.cast(DataType.categorical()).hash().to_pylist() == strings.hash().to_pylist()
+
+
+def test_categorical_groupby() -> None:
+    df = daft.from_pydict({"key": ["a", "b", "a", None, "b", "a"], "value": [1, 2, 3, 4, 5, 6]})
+    df = df.with_column("key", col("key").cast(DataType.categorical()))
+    result = df.groupby("key").agg(col("value").sum()).sort("key").to_pydict()
+
+    assert result == {"key": ["a", "b", None], "value": [10, 7, 4]}
+
+
+def test_categorical_parquet_read_keeps_dictionary(tmp_path) -> None:
+    table = pa.table({"key": pa.array(["a", "b", "a", None]).dictionary_encode()})
+    pq.write_table(table, tmp_path / "data.parquet")
+
+    df = daft.read_parquet(str(tmp_path / "data.parquet"))
+    assert df.schema()["key"].dtype == DataType.categorical()
+    assert df.to_pydict() == {"key": ["a", "b", "a", None]}
@@ -0,0 +1,153 @@
+from __future__ import annotations
+
+import json
+
+import pytest
+
+import daft
+from daft.catalog import Catalog, Identifier
+from daft.session import Session
+
+
+@pytest.fixture
+def sess():
+    sess = Session()
+    sess.create_temp_table(
+        "T",
+        daft.from_pydict(
+            {
+                "ints": [1, 2, 3, 4, None, 2, 3, 4],
+                "floats": [0.5, 1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5],
+                "strings": ["a", "b", "a", None, None, "b", "a", "c"],
+            }
+        ),
+    )
+    return sess
+
+
+def _stats_by_column(stats):
+    return {row["column"]: row for row in stats.to_pylist()}
+
+
+def test_analyze_table(sess):
+    stats = _stats_by_column(sess.analyze_table("T", num_buckets=4))
+    assert set(stats) == {"ints", "floats", "strings"}
+
+    ints = stats["ints"]
+    assert ints["num_rows"] == 8
+    assert ints["null_count"] == 1
+    assert ints["ndv"] == 4
+    assert (ints["min"], ints["max"]) == (1.0, 4.0)
+    assert len(ints["histogram"]) == 5
+    assert ints["histogram"] == sorted(ints["histogram"])
+
+    floats = stats["floats"]
+    assert floats["null_count"] == 0
+    assert floats["ndv"] == 8
+    assert (floats["min"], floats["max"]) == (0.5, 7.5)
+
+    strings = stats["strings"]
+    assert strings["null_count"] == 2
+    assert strings["ndv"] == 3
+    assert strings["min"] is None
+    assert strings["max"] is None
+    assert strings["histogram"] is None
+
+
+def test_analyze_table_columns(sess):
+    stats = _stats_by_column(sess.analyze_table("T", columns=["floats"]))
+    assert set(stats) == {"floats"}
+
+
+def test_analyze_table_invalid_buckets(sess):
+    with pytest.raises(Exception, match="histogram buckets"):
+        sess.analyze_table("T", num_buckets=0)
+
+
+def test_analyze_table_missing(sess):
+    with pytest.raises(Exception):
+        sess.analyze_table("missing")
+
+
+def test_sql_analyze_table(sess):
+    stats = _stats_by_column(sess.sql("ANALYZE TABLE T COMPUTE STATISTICS"))
+    assert set(stats) == {"ints", "floats", "strings"}
+    assert stats["ints"]["null_count"] == 1
+
+
+def test_sql_analyze_table_for_columns(sess):
+    stats = _stats_by_column(sess.sql("ANALYZE TABLE T COMPUTE STATISTICS FOR COLUMNS ints, strings"))
+    assert set(stats) == {"ints", "strings"}
+
+
+def test_reads_after_analyze(sess):
+    expected = sess.read_table("T").sort("floats").to_pydict()
+    sess.analyze_table("T")
+    assert sess.read_table("T").sort("floats").to_pydict() == expected
+    assert sess.sql("SELECT * FROM T ORDER BY floats").to_pydict() == expected
+    assert sess.sql("SELECT ints FROM T WHERE floats > 3").count_rows() == 4
+
+
+def _persisted_stats(stats_dir):
+    [path] = stats_dir.iterdir()
+    return json.loads(path.read_text())
+
+
+def test_analyze_catalog_table_persists_merged_stats(tmp_path, monkeypatch):
+    monkeypatch.setenv("DAFT_TABLE_STATS_DIR", str(tmp_path))
+    catalog = Catalog.from_pydict({"T": {"x": [1, 2, 3], "y": ["a", "b", None]}}, "cat")
+
+    sess = Session()
+    sess.attach_catalog(catalog)
+    sess.analyze_table("T", columns=["x"])
+    sess.analyze_table("T", columns=["y"])
+    # Analyzing some of the columns keeps the statistics of the others.
+    stats = _persisted_stats(tmp_path)
+    assert set(stats["columns"]) == {"x", "y"}
+    assert stats["columns"]["y"]["null_fraction"] == pytest.approx(1 / 3)
+
+    # Later sessions load the persisted statistics.
+    sess = Session()
+    sess.attach_catalog(catalog)
+    sess.analyze_table("T", columns=["x"])
+    assert set(_persisted_stats(tmp_path)["columns"]) == {"x", "y"}
+    assert sorted(sess.read_table("T").to_pydict()["x"]) == [1, 2, 3]
+
+
+def test_sql_analyze_catalog_table_persists_stats(tmp_path, monkeypatch):
+    monkeypatch.setenv("DAFT_TABLE_STATS_DIR", str(tmp_path))
+    catalog = Catalog.from_pydict({"T": {"x": [1, 2, 3], "y": ["a", "b", None]}}, "cat")
+
+    sess = Session()
+    sess.attach_catalog(catalog)
+    stats = _stats_by_column(sess.sql("ANALYZE TABLE T COMPUTE STATISTICS FOR COLUMNS y"))
+    assert set(stats) == {"y"}
+    assert set(_persisted_stats(tmp_path)["columns"]) == {"y"}
+
+
+def test_analyze_stats_file_stays_in_stats_dir(tmp_path, monkeypatch):
+    stats_dir = tmp_path / "stats"
+    monkeypatch.setenv("DAFT_TABLE_STATS_DIR", str(stats_dir))
+    catalog = Catalog.from_pydict({Identifier("../T"): {"x": [1, 2, 3]}}, "cat")
+
+    sess = Session()
+    sess.attach_catalog(catalog)
+    sess.analyze_table(Identifier("../T"))
+    [path] = stats_dir.iterdir()
+    assert path.name == "%2E%2E%2FT.json"
+    assert sorted(p.name for p in tmp_path.iterdir()) == ["stats"]
+
+
+def test_analyze_temp_table_doesnt_persist_stats(sess, tmp_path, monkeypatch):
+    monkeypatch.setenv("DAFT_TABLE_STATS_DIR", str(tmp_path))
+    sess.analyze_table("T")
+    assert list(tmp_path.iterdir()) == []
+
+
+def test_analyze_view_raises():
+    sess = Session()
+    sess.create_temp_table("V", daft.from_pydict({"x": [1, 2, 3]}).where(daft.col("x") > 1))
+    with pytest.raises(Exception, match="read a source as is"):
+        sess.analyze_table("V")
+    with pytest.raises(Exception, match="read a source as is"):
+        sess.sql("ANALYZE TABLE V COMPUTE STATISTICS")
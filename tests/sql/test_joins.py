@@ -1,8 +1,11 @@
 from __future__ import annotations
 
+import io
+
 import pytest
 
 import daft
+from tests.conftest import get_tests_daft_runner_name
 from tests.utils import sort_pydict
 
 
@@ -197,3 +200,142 @@ def test_cross_join():
         "C": [2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8],
         "D": ["d", "e", "f", "g", "d", "e", "f", "g", "d", "e", "f", "g"],
     }
+
+
+@pytest.mark.parametrize(
+    "hint",
+    [
+        "/*+ BROADCAST(b) */",
+        "/*+ SHUFFLE_HASH(a) */",
+        "/*+ MERGE(a, b) */",
+        "/*+ NO_SUCH_HINT(b) */",
+    ],
+)
+def test_join_hints(hint):
+    a = daft.from_pydict({"x": [1, 2, 3], "val": [10, 20, 30]})
+    b = daft.from_pydict({"y": [1, 2, 4], "score": [0.1, 0.2, 0.4]})
+
+    df_sql = daft.sql(f"select {hint} x, val, score from a join b on a.x = b.y order by x", a=a, b=b)
+
+    assert df_sql.to_pydict() == {"x": [1, 2], "val": [10, 20], "score": [0.1, 0.2]}
+
+
+def test_join_hint_falls_back_for_unsupported_join():
+    a = daft.from_pydict({"x": [1, 2], "val": [10, 20]})
+    b = daft.from_pydict({"y": [2, 3], "score": [0.2, 0.3]})
+
+    # broadcast joins don't support full outer joins, so the hint is ignored
+    df_sql = daft.sql("select /*+ BROADCAST(b) */ * from a full join b on a.x = b.y order by x", a=a, b=b)
+
+    assert df_sql.to_pydict() == {"x": [1, 2, None], "val": [10, 20, None], "y": [None, 2, 3], "score": [None, 0.2, 0.3]}
+
+
+def _explain(df, section):
+    string_io = io.StringIO()
+    df.explain(True, file=string_io)
+    return string_io.getvalue().split(f"== {section} ==")[1].split("\n== ")[0]
+
+
+def test_join_hint_only_applies_to_its_select():
+    a = daft.from_pydict({"x": [1, 2], "val": [10, 20]})
+    b = daft.from_pydict({"y": [2, 3], "score": [0.2, 0.3]})
+
+    outer_hint = daft.sql("select /*+ BROADCAST(b) */ * from (select * from a join b on a.x = b.y) as t", a=a, b=b)
+    assert "Strategy = Broadcast" not in _explain(outer_hint, "Unoptimized Logical Plan")
+
+    cte_hint = daft.sql("with t as (select * from a join b on a.x = b.y) select /*+ BROADCAST(b) */ * from t", a=a, b=b)
+    assert "Strategy = Broadcast" not in _explain(cte_hint, "Unoptimized Logical Plan")
+
+    inner_hint = daft.sql("select * from (select /*+ BROADCAST(b) */ * from a join b on a.x = b.y) as t", a=a, b=b)
+    plan = _explain(inner_hint, "Unoptimized Logical Plan")
+    assert "Strategy = Broadcast" in plan
+    assert "Broadcast side = right" in plan
+    assert inner_hint.to_pydict() == {"x": [2], "val": [20], "y": [2], "score": [0.2]}
+
+
+@pytest.mark.parametrize("hinted,build_on_left", [("a", True), ("b", False)])
+def test_broadcast_hint_picks_the_broadcast_side(hinted, build_on_left):
+    a = daft.from_pydict({"x": [1, 2, 3], "val": [10, 20, 30]})
+    b = daft.from_pydict({"y": [1, 2, 4], "score": [0.1, 0.2, 0.4]})
+
+    df = daft.sql(f"select /*+ BROADCAST({hinted}) */ x, val, score from a join b on a.x = b.y order by x", a=a, b=b)
+
+    assert f"Broadcast side = {'left' if build_on_left else 'right'}" in _explain(df, "Optimized Logical Plan")
+    if get_tests_daft_runner_name() == "native":
+        # The native runner builds the hash table on the side that would be broadcast
+        assert f"Build on left: {str(build_on_left).lower()}" in _explain(df, "Physical Plan")
+    assert df.to_pydict() == {"x": [1, 2], "val": [10, 20], "score": [0.1, 0.2]}
+
+
+def _sorted_rows(pydict):
+    rows = zip(*pydict.values())
+    return sorted(rows, key=lambda row: [(v is None, v) for v in row])
+
+
+@pytest.mark.parametrize(
+    "join,expected",
+    [
+        ("join", [(1, 0, 1, 3), (1, 0, 1, 7), (1, 5, 1, 7), (2, 0, 2, 1)]),
+        (
+            "left join",
+            [(1, 0, 1, 3), (1, 0, 1, 7), (1, 5, 1, 7), (2, 0, 2, 1), (3, 0, None, None), (None, 0, None, None)],
+        ),
+        (
+            "right join",
+            [(1, 0, 1, 3), (1, 0, 1, 7), (1, 5, 1, 7), (2, 0, 2, 1), (None, None, 4, 9), (None, None, None, 3)],
+        ),
+        (
+            "full join",
+            [
+                (1, 0, 1, 3),
+                (1, 0, 1, 7),
+                (1, 5, 1, 7),
+                (2, 0, 2, 1),
+                (3, 0, None, None),
+                (None, 0, None, None),
+                (None, None, 4, 9),
+                (None, None, None, 3),
+            ],
+        ),
+    ],
+)
+def test_joins_with_residual_predicate(join, expected):
+    df1 = daft.from_pydict({"id": [1, 1, 2, 3, None], "v": [0, 5, 0, 0, 0]})
+    df2 = daft.from_pydict({"id": [1, 1, 2, 4, None], "v": [3, 7, 1, 9, 3]})
+
+    df_sql = daft.sql(
+        f"select df1.id as l_id, df1.v as l_v, df2.id as r_id, df2.v as r_v from df1 {join} df2 on df1.id = df2.id and df1.v < df2.v",
+        df1=df1,
+        df2=df2,
+    )
+
+    assert _sorted_rows(df_sql.to_pydict()) == expected
+
+
+@pytest.mark.parametrize(
+    "join,expected",
+    [
+        ("left semi join", [(1, 0), (1, 5), (2, 0)]),
+        ("left anti join", [(3, 0), (None, 0)]),
+    ],
+)
+def test_semi_anti_joins_with_residual_predicate(join, expected):
+    df1 = daft.from_pydict({"id": [1, 1, 2, 3, None], "v": [0, 5, 0, 0, 0]})
+    df2 = daft.from_pydict({"id": [1, 1, 2, 4, None], "w": [3, 7, 1, 9, 3]})
+
+    df_sql = daft.sql(f"select * from df1 {join} df2 on df1.id = df2.id and df1.v < df2.w", df1=df1, df2=df2)
+
+    assert _sorted_rows(df_sql.to_pydict()) == expected
+
+
+def test_null_safe_outer_join_with_residual_predicate():
+    df1 = daft.from_pydict({"id": [1, None, None], "v": [0, 0, 5]})
+    df2 = daft.from_pydict({"id": [1, None], "v": [3, 3]})
+
+    df_sql = daft.sql(
+        "select df1.id as l_id, df1.v as l_v, df2.id as r_id, df2.v as r_v from df1 full join df2 on df1.id <=> df2.id and df1.v < df2.v",
+        df1=df1,
+        df2=df2,
+    )
+
+    assert _sorted_rows(df_sql.to_pydict()) == [(1, 0, 1, 3), (None, 0, None, 3), (None, 5, None, None)]
@@ -103,3 +103,45 @@ def test_groupby_orderby_count_star(df):
     """)
 
     assert df.collect().to_pydict() == {"text": ["g2", "g3", "g1"], "n": [3, 7, 103]}
+
+
+def test_orderby_collate():
+    names = daft.from_pydict({"name": ["banana", "Apple", "émile", "Cherry"]})
+    df = daft.sql("SELECT name FROM names ORDER BY name COLLATE accent_insensitive", names=names)
+    assert df.to_pydict() == {"name": ["Apple", "banana", "Cherry", "émile"]}
+
+
+def test_collate_keeps_projected_values():
+    names = daft.from_pydict({"name": ["Émile", "Apple"]})
+    df = daft.sql("SELECT name COLLATE accent_insensitive AS name FROM names", names=names)
+    assert df.to_pydict() == {"name": ["Émile", "Apple"]}
+
+
+def test_collate_comparison():
+    names = daft.from_pydict({"name": ["Émile", "emile", "Apple"]})
+    df = daft.sql("SELECT name FROM names WHERE name = 'EMILE' COLLATE accent_insensitive", names=names)
+    assert df.to_pydict() == {"name": ["Émile", "emile"]}
+    df = daft.sql("SELECT name FROM names WHERE name COLLATE nocase < 'b'", names=names)
+    assert df.to_pydict() == {"name": ["Apple"]}
+
+
+def test_collate_group_by():
+    names = daft.from_pydict({"name": ["Apple", "apple", "APPLE", "Cherry"]})
+    df = daft.sql(
+        "SELECT name, count(*) AS n FROM names GROUP BY name COLLATE nocase ORDER BY n",
+        names=names,
+    ).to_pydict()
+    assert df["n"] == [1, 3]
+    assert df["name"][0] == "Cherry"
+    assert df["name"][1] in {"Apple", "apple", "APPLE"}
+
+
+def test_collate_join():
+    left = daft.from_pydict({"a": ["Émile", "Apple"], "x": [1, 2]})
+    right = daft.from_pydict({"b": ["emile", "cherry"], "y": [3, 4]})
+    df = daft.sql(
+        "SELECT a, b, x, y FROM left JOIN right ON a COLLATE accent_insensitive = b",
+        left=left,
+        right=right,
+    )
+    assert df.to_pydict() == {"a": ["Émile"], "b": ["emile"], "x": [1], "y": [3]}
@@ -311,3 +311,52 @@ def test_set_scantask_max_parallelism_greater_than_partition_num():
         df = daft.range(start=0, end=1024, partitions=10)
         df.explain(show_all=True, file=str_io)
         assert "Num Parallel Scan Tasks = 10" in str_io.getvalue().strip()
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() != "native", reason="requires Native Runner to be in use")
+def test_set_scan_read_ahead():
+    with daft.execution_config_ctx(scan_read_ahead=4):
+        str_io = io.StringIO()
+        df = daft.range(start=0, end=1024, partitions=10)
+        df.explain(show_all=True, file=str_io)
+        assert "Scan Read Ahead = 4" in str_io.getvalue().strip()
+
+
+def test_scan_io_concurrency_must_be_positive():
+    with pytest.raises(ValueError, match="scan_io_concurrency"):
+        with daft.execution_config_ctx(scan_io_concurrency=0):
+            pass
+
+
+def test_config_ctx_overrides_configs_by_name():
+    original = {name: value for name, value in daft.get_config().items() if name != "default_io_config"}
+    with daft.config_ctx(num_preview_rows=3, enable_strict_filter_pushdown=True, json_target_filesize=1024):
+        config = daft.get_config()
+        assert config["num_preview_rows"] == 3
+        assert config["enable_strict_filter_pushdown"] is True
+        assert config["json_target_filesize"] == 1024
+        assert daft.context.get_context().daft_execution_config.num_preview_rows == 3
+    assert {name: value for name, value in daft.get_config().items() if name != "default_io_config"} == original
+
+
+def test_set_config_rejects_invalid_values():
+    with pytest.raises(ValueError, match="num_preview_rowz"):
+        daft.set_config(num_preview_rowz=3)
+    with pytest.raises(ValueError, match="shuffle_compression"):
+        daft.set_config(shuffle_compression="gzip")
+    with pytest.raises(ValueError, match="shuffle_compression_level"):
+        daft.set_config(shuffle_compression="lz4", shuffle_compression_level=17)
+    with pytest.raises(ValueError, match="Invalid config value"):
+        daft.set_config(num_preview_rows="three")
+
+
+def test_get_config_from_env():
+    get_config_script = """
+import daft
+config = daft.get_config()
+print(config["num_preview_rows"], config["staged_writes"])
+    """
+
+    env = {**os.environ, "DAFT_NUM_PREVIEW_ROWS": "3", "DAFT_STAGED_WRITES": "1"}
+    result = subprocess.run([sys.executable, "-c", get_config_script], capture_output=True, env=env)
+    assert result.stdout.decode().strip() == "3 True"
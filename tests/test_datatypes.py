@@ -254,6 +254,23 @@ def test_is_python(test_type):
     assert test_type.is_python() == (test_type == DataType.python())
 
 
+@pytest.mark.parametrize(
+    ["test_type", "expected"],
+    [
+        (DataType.python(), True),
+        (DataType.list(DataType.python()), True),
+        (DataType.fixed_size_list(DataType.python(), 2), True),
+        (DataType.struct({"a": DataType.int64(), "b": DataType.list(DataType.python())}), True),
+        (DataType.map(DataType.string(), DataType.python()), True),
+        (DataType.list(DataType.int64()), False),
+        (DataType.struct({"a": DataType.int64()}), False),
+        (DataType.int64(), False),
+    ],
+)
+def test_contains_python(test_type, expected):
+    assert test_type.contains_python() == expected
+
+
 @pytest.mark.parametrize("test_type", all_daft_types)
 def test_is_numeric(test_type):
     assert test_type.is_numeric() == (test_type in daft_numeric_types)
@@ -7,6 +7,7 @@ import pytest
 
 import daft
 from daft import DataType
+from tests.conftest import get_tests_daft_runner_name
 
 
 def test_cls():
@@ -312,3 +313,84 @@ def test_cls_max_concurrency_zero():
         df = daft.from_pydict({"a": [1, 2, 3]})
         result = df.select(MaxConcurrencyZero()(df["a"])).to_pydict()
         assert result == {"a": [1, 2, 3]}
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="Hooks are recorded in the driver process")
+def test_cls_lifecycle_hooks():
+    events = []
+
+    @daft.cls(use_process=False, max_concurrency=1)
+    class Resource:
+        def __init__(self):
+            events.append("init")
+
+        def open_partition(self):
+            events.append("open_partition")
+
+        def close_partition(self):
+            events.append("close_partition")
+
+        def teardown(self):
+            events.append("teardown")
+
+        def __call__(self, x: int) -> int:
+            return x + 1
+
+    resource = Resource()
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    assert df.select(resource(df["a"])).to_pydict() == {"a": [2, 3, 4]}
+    assert events == ["init", "open_partition", "close_partition", "teardown"]
+
+    # The instance was torn down, so the next query sets up a new one.
+    events.clear()
+    assert df.select(resource(df["a"])).to_pydict() == {"a": [2, 3, 4]}
+    assert events == ["init", "open_partition", "close_partition", "teardown"]
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="Instances are created in the driver process")
+def test_cls_without_teardown_reuses_instance():
+    inits = []
+
+    @daft.cls(use_process=False, max_concurrency=1)
+    class Resource:
+        def __init__(self):
+            inits.append(1)
+
+        def __call__(self, x: int) -> int:
+            return x + 1
+
+    resource = Resource()
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    df.select(resource(df["a"])).collect()
+    df.select(resource(df["a"])).collect()
+    assert len(inits) == 1
+
+
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="Partitions may run in several processes on Ray")
+def test_cls_lifecycle_hooks_in_process(tmp_path):
+    events = tmp_path / "events.txt"
+
+    @daft.cls(use_process=True, max_concurrency=1)
+    class Resource:
+        def __init__(self, path: str):
+            self.path = path
+
+        def _record(self, event: str):
+            with open(self.path, "a") as f:
+                f.write(event + "\n")
+
+        def open_partition(self):
+            self._record("open_partition")
+
+        def close_partition(self):
+            self._record("close_partition")
+
+        def teardown(self):
+            self._record("teardown")
+
+        def __call__(self, x: int) -> int:
+            return x + 1
+
+    df = daft.from_pydict({"a": [1, 2, 3]})
+    assert df.select(Resource(str(events))(df["a"])).to_pydict() == {"a": [2, 3, 4]}
+    assert events.read_text().splitlines() == ["open_partition", "close_partition", "teardown"]
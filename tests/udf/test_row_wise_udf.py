@@ -156,6 +156,27 @@ def test_row_wise_udf_unnest():
     assert result == expected
 
 
+@pytest.mark.skipif(get_tests_daft_runner_name() == "ray", reason="Calls are counted in the driver process")
+def test_row_wise_udf_unnest_evaluates_once():
+    calls = []
+
+    @daft.func(
+        return_dtype=daft.DataType.struct({"double": daft.DataType.int64(), "square": daft.DataType.int64()}),
+        unnest=True,
+        use_process=False,
+    )
+    def expensive(value: int):
+        calls.append(value)
+        return {"double": value * 2, "square": value * value}
+
+    df = daft.from_pydict({"value": [1, 2, 3]})
+    result = df.select("value", expensive(col("value"))).sort("value").to_pydict()
+
+    assert result == {"value": [1, 2, 3], "double": [2, 4, 6], "square": [1, 4, 9]}
+    # Each row is evaluated once, rather than once for each field of the struct.
+    assert sorted(calls) == [1, 2, 3]
+
+
 def test_row_wise_udf_unnest_error_non_struct():
     with pytest.raises(
         ValueError,
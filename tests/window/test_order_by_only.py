@@ -103,6 +103,26 @@ def test_order_by_only_row_number(make_df, repartition_nparts, with_morsel_size)
         assert row["row_by_xy_mixed"] == row["x"], f"row_by_xy_mixed {row['row_by_xy_mixed']} should equal x {row['x']}"
 
 
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 20])
+def test_order_by_only_agg(make_df, repartition_nparts, with_morsel_size):
+    """Aggregations over an order by only window are computed over all rows."""
+    values = list(range(1, 101))
+    df = make_df({"value": values}, repartition=repartition_nparts)
+
+    window_spec = Window().order_by("value")
+    result = df.select(
+        col("value"),
+        col("value").sum().over(window_spec).alias("total"),
+        col("value").max().over(window_spec).alias("max"),
+        row_number().over(window_spec).alias("row_number"),
+    ).to_pydict()
+
+    assert sorted(result["value"]) == values
+    assert result["total"] == [sum(values)] * len(values)
+    assert result["max"] == [100] * len(values)
+    assert sorted(zip(result["value"], result["row_number"])) == list(zip(values, values))
+
+
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 20, 50, 100])
 def test_rank(make_df, repartition_nparts, with_morsel_size):
     """Test rank function with order_by only (no partition_by)."""
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+from daft.functions import iqr_outlier, zscore
+
+
+def test_zscore_global(make_df):
+    df = make_df({"x": [2, 4, 4, 4, 5, 5, 7, 9, None]})
+    result = df.with_column("z", zscore(col("x"))).sort("x").to_pydict()
+    assert result["z"] == [-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0, None]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_zscore_global_over_many_batches(make_df, repartition_nparts, with_morsel_size):
+    df = make_df({"x": [2, 4, 4, 4, 5, 5, 7, 9]}, repartition=repartition_nparts)
+    result = df.with_column("z", zscore(col("x"))).sort("x").to_pydict()
+    assert result["z"] == [-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0]
+
+
+def test_zscore_partitioned(make_df):
+    df = make_df({"group": ["a", "a", "b", "b", "c"], "x": [1.0, 3.0, 10.0, 30.0, 5.0]})
+    result = df.with_column("z", zscore(col("x"), partition_by="group")).sort(["group", "x"]).to_pydict()
+    assert result["z"] == [-1.0, 1.0, -1.0, 1.0, None]
+
+
+def test_iqr_outlier(make_df):
+    df = make_df(
+        {
+            "group": ["a"] * 6 + ["b"] * 6,
+            "x": [10, 12, 11, 13, 12, 95, 100, 101, 99, 102, 100, 98],
+        }
+    )
+    result = df.with_column("outlier", iqr_outlier(col("x"), partition_by="group")).sort(["group", "x"]).to_pydict()
+    assert result["outlier"] == [False] * 5 + [True] + [False] * 6
+
+
+def test_iqr_outlier_global(make_df):
+    df = make_df({"x": [10, 12, 11, 13, 12, 95, None]})
+    result = df.with_column("outlier", iqr_outlier(col("x"))).sort("x").to_pydict()
+    assert result["outlier"] == [False] * 5 + [True, None]
+
+
+def test_iqr_outlier_invalid_k():
+    with pytest.raises(ValueError, match="non-negative k"):
+        iqr_outlier(col("x"), k=-1)
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import DataType, col
+from daft.functions import cumcount, cummax, cummin, cumsum
+
+
+@pytest.fixture
+def events(make_df):
+    return make_df(
+        {
+            "user": ["a", "b", "a", "a", "b", "a", "b", "a"],
+            "ts": [4, 1, 1, 2, 3, 3, 2, 5],
+            "value": [2, None, 5, None, 6, 1, 4, 7],
+        },
+        repartition=3,
+        repartition_columns=["user"],
+    )
+
+
+@pytest.mark.parametrize(
+    "func, expected",
+    [
+        # Sorted by user and ts, user a has [5, None, 1, 2, 7] and user b has [None, 4, 6].
+        (cumsum, [5, 5, 6, 8, 15, None, 4, 10]),
+        (cummin, [5, 5, 1, 1, 1, None, 4, 4]),
+        (cummax, [5, 5, 5, 5, 7, None, 4, 6]),
+        (cumcount, [1, 1, 2, 3, 4, 0, 1, 2]),
+    ],
+)
+def test_cumulative_per_partition(events, func, expected):
+    df = events.with_column("result", func(col("value"), order_by="ts", over="user"))
+
+    assert df.sort(["user", "ts"]).to_pydict()["result"] == expected
+
+
+def test_cumulative_without_partitions(make_df):
+    df = make_df({"ts": [3, 1, 4, 2], "value": [3.0, 1.0, 4.0, 2.0]}, repartition=2)
+    df = df.select(
+        "ts",
+        cumsum(col("value"), order_by="ts").alias("sum"),
+        cummax(col("value"), order_by="ts", over=None).alias("max"),
+    )
+
+    assert df.sort("ts").to_pydict() == {
+        "ts": [1, 2, 3, 4],
+        "sum": [1.0, 3.0, 6.0, 10.0],
+        "max": [1.0, 2.0, 3.0, 4.0],
+    }
+
+
+def test_cumulative_ties_in_order_by(make_df):
+    df = make_df({"ts": [1, 1, 1], "value": [1, 1, 1]})
+    df = df.with_column("total", cumsum(col("value"), order_by="ts"))
+
+    # Each row gets the running total up to itself, even among rows with the same order by value.
+    assert sorted(df.to_pydict()["total"]) == [1, 2, 3]
+
+
+def test_cumulative_types(make_df):
+    df = make_df({"ts": [1, 2], "value": [1, 2]})
+    df = df.select(
+        cumsum(col("value").cast(DataType.int32()), order_by="ts").alias("sum"),
+        cummin(col("value").cast(DataType.int32()), order_by="ts").alias("min"),
+        cumcount(col("value"), order_by="ts").alias("count"),
+    )
+
+    assert df.schema()["sum"].dtype == DataType.int64()
+    assert df.schema()["min"].dtype == DataType.int32()
+    assert df.schema()["count"].dtype == DataType.uint64()
@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import DataType, col
+from daft.exceptions import DaftTypeError
+from daft.functions import fill_backward, fill_forward, interpolate
+
+
+@pytest.fixture
+def readings(make_df):
+    return make_df(
+        {
+            "sensor": ["a", "b", "a", "a", "b", "a", "b", "a"],
+            "ts": [4, 1, 1, 2, 3, 3, 2, 5],
+            "value": [None, None, 1, None, 6, None, 2, 7],
+        },
+        repartition=3,
+        repartition_columns=["sensor"],
+    )
+
+
+def test_fill_forward(readings):
+    df = readings.with_column("filled", fill_forward(col("value"), order_by="ts", over="sensor"))
+
+    assert df.sort(["sensor", "ts"]).to_pydict()["filled"] == [1, 1, 1, 1, 7, None, 2, 6]
+
+
+def test_fill_backward(readings):
+    df = readings.with_column("filled", fill_backward(col("value"), order_by="ts", over="sensor"))
+
+    assert df.sort(["sensor", "ts"]).to_pydict()["filled"] == [1, 7, 7, 7, 7, 2, 2, 6]
+
+
+def test_interpolate(readings):
+    df = readings.with_column("filled", interpolate(col("value"), order_by="ts", over="sensor"))
+
+    # Nulls are interpolated by their positions between the valid values of the same sensor, and the nulls before the
+    # first valid value of a sensor are left null.
+    assert df.sort(["sensor", "ts"]).to_pydict()["filled"] == [1.0, 2.5, 4.0, 5.5, 7.0, None, 2.0, 6.0]
+
+
+def test_interpolate_keeps_float_type(make_df):
+    df = make_df({"ts": [1, 2, 3], "value": [1.5, None, 2.5]})
+    df = df.with_column("value", interpolate(col("value").cast(DataType.float32()), order_by="ts"))
+
+    assert df.schema()["value"].dtype == DataType.float32()
+    assert df.sort("ts").to_pydict()["value"] == [1.5, 2.0, 2.5]
+
+
+def test_fill_without_partitions(make_df):
+    df = make_df({"ts": [3, 1, 2, 4], "value": ["c", "a", None, None]}, repartition=2)
+    df = df.with_column("filled", fill_forward(col("value"), order_by="ts"))
+
+    assert df.sort("ts").to_pydict()["filled"] == ["a", "a", "c", "c"]
+
+
+def test_fill_by_multiple_order_by_columns(make_df):
+    df = make_df({"day": [1, 1, 2, 2], "hour": [1, 0, 0, 1], "value": [None, 1, None, 4]})
+    df = df.with_column("filled", fill_forward(col("value"), order_by=["day", "hour"]))
+
+    assert df.sort(["day", "hour"]).to_pydict()["filled"] == [1, 1, 1, 4]
+
+
+def test_interpolate_requires_numeric_input(make_df):
+    df = make_df({"ts": [1, 2], "value": ["a", None]})
+
+    with pytest.raises(DaftTypeError, match="numeric"):
+        df.with_column("filled", interpolate(col("value"), order_by="ts")).collect()
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pandas as pd
+
+from daft import Window, col
+from daft.functions import session_id
+from tests.conftest import assert_df_equals
+
+
+def test_session_id(make_df):
+    df = make_df(
+        {
+            "user": ["a", "b", "a", "a", "b", "a", "b"],
+            "ts": [1, 2, 3, 20, 30, 25, 35],
+        }
+    )
+
+    window_spec = Window().partition_by("user").order_by("ts")
+    result = df.with_column("session", session_id(col("ts"), 10).over(window_spec)).collect()
+
+    expected = {
+        "user": ["a", "a", "a", "a", "b", "b", "b"],
+        "ts": [1, 3, 20, 25, 2, 30, 35],
+        "session": [0, 0, 1, 1, 0, 1, 1],
+    }
+    assert_df_equals(result.to_pandas(), pd.DataFrame(expected), sort_key=["user", "ts"], check_dtype=False)
+
+
+def test_sessionize_timestamps(make_df):
+    start = datetime(2024, 1, 1)
+    df = make_df(
+        {
+            "user": [1, 1, 1, 2, 2],
+            "ts": [
+                start,
+                start + timedelta(minutes=20),
+                start + timedelta(minutes=90),
+                start,
+                start + timedelta(minutes=29),
+            ],
+        }
+    )
+
+    result = df.sessionize("user", "ts", gap=timedelta(minutes=30), session_col="session").sort(["user", "ts"])
+    assert result.to_pydict()["session"] == [0, 0, 1, 0, 0]
+
+
+def test_sessionize_multiple_keys(make_df):
+    df = make_df({"k1": [1, 1, 1, 1], "k2": ["x", "y", "x", "y"], "ts": [0, 1, 100, 2]})
+
+    result = df.sessionize(["k1", "k2"], "ts", gap=10).sort(["k2", "ts"])
+    assert result.to_pydict() == {
+        "k1": [1, 1, 1, 1],
+        "k2": ["x", "x", "y", "y"],
+        "ts": [0, 100, 1, 2],
+        "session_id": [0, 1, 0, 0],
+    }
+
+
+def test_sessionize_null_timestamps(make_df):
+    df = make_df({"user": ["a", "a", "a", "a"], "ts": [1, None, 2, None]})
+
+    result = df.sessionize("user", "ts", gap=5).sort("ts")
+    assert sorted(result.to_pydict()["session_id"]) == [0, 0, 1, 2]